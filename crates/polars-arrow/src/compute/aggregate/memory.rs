@@ -1,9 +1,13 @@
+use polars_utils::aliases::PlHashSet;
+
 use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
 use crate::datatypes::PhysicalType;
 use crate::types::Index;
 pub use crate::types::PrimitiveType;
 use crate::{match_integer_type, with_match_primitive_type_full};
+
 fn validity_size(validity: Option<&Bitmap>) -> usize {
     validity.as_ref().map(|b| b.as_slice().0.len()).unwrap_or(0)
 }
@@ -144,3 +148,182 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         },
     }
 }
+
+/// The memory regions already counted by [`estimated_bytes_size_dedup`].
+///
+/// Sharing a [`SeenBuffers`] between calls makes sure that storage shared between arrays, e.g.
+/// the same column occurring twice or the data buffers of a view array shared between its
+/// chunks, is only counted once.
+#[derive(Default)]
+pub struct SeenBuffers {
+    seen: PlHashSet<(usize, usize)>,
+}
+
+impl SeenBuffers {
+    /// Returns `num_bytes` if the region starting at `ptr` hasn't been seen before, 0 otherwise.
+    pub fn count<T>(&mut self, ptr: *const T, num_bytes: usize) -> usize {
+        if num_bytes == 0 || !self.seen.insert((ptr as usize, num_bytes)) {
+            return 0;
+        }
+        num_bytes
+    }
+
+    fn count_buffer<T>(&mut self, buffer: &Buffer<T>) -> usize {
+        self.count(buffer.as_ptr(), std::mem::size_of_val(buffer.as_slice()))
+    }
+
+    fn count_validity(&mut self, validity: Option<&Bitmap>) -> usize {
+        validity.map_or(0, |b| self.count_bitmap(b))
+    }
+
+    fn count_bitmap(&mut self, bitmap: &Bitmap) -> usize {
+        let bytes = bitmap.as_slice().0;
+        self.count(bytes.as_ptr(), bytes.len())
+    }
+}
+
+macro_rules! dyn_binary_dedup {
+    ($array:expr, $ty:ty, $o:ty, $seen:expr) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        let offsets = array.offsets().buffer();
+
+        // in case of Binary/Utf8/List the offsets are sliced,
+        // not the values buffer
+        let values_start = offsets[0] as usize;
+        let values_end = offsets[offsets.len() - 1] as usize;
+
+        $seen.count(
+            array.values().as_ptr().wrapping_add(values_start),
+            values_end - values_start,
+        ) + $seen.count_buffer(offsets)
+            + $seen.count_validity(array.validity())
+    }};
+}
+
+fn binview_size_dedup<T: ViewType + ?Sized>(
+    array: &BinaryViewArrayGeneric<T>,
+    seen: &mut SeenBuffers,
+) -> usize {
+    // The data buffers are referenced as a whole by the views, and are usually shared between
+    // the slices (and chunks) of an array, so they are counted in full but only once.
+    seen.count_buffer(array.views())
+        + array
+            .data_buffers()
+            .iter()
+            .map(|buffer| seen.count_buffer(buffer))
+            .sum::<usize>()
+        + seen.count_validity(array.validity())
+}
+
+/// Returns the (heap) allocated size of the array in bytes, skipping the buffers already counted
+/// in `seen`.
+///
+/// Unlike [`estimated_bytes_size`], which counts the visible bytes, this counts every buffer the
+/// array references, so the data buffers of view arrays are counted in full. Sharing `seen`
+/// between calls makes sure that storage shared between multiple arrays is only counted once.
+pub fn estimated_bytes_size_dedup(array: &dyn Array, seen: &mut SeenBuffers) -> usize {
+    use PhysicalType::*;
+    match array.dtype().to_physical_type() {
+        Null => 0,
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            seen.count_bitmap(array.values()) + seen.count_validity(array.validity())
+        },
+        Primitive(PrimitiveType::DaysMs) => {
+            let array = array.as_any().downcast_ref::<DaysMsArray>().unwrap();
+            seen.count_buffer(array.values()) + seen.count_validity(array.validity())
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+
+            seen.count_buffer(array.values()) + seen.count_validity(array.validity())
+        }),
+        Binary => dyn_binary_dedup!(array, BinaryArray<i32>, i32, seen),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            seen.count_buffer(array.values()) + seen.count_validity(array.validity())
+        },
+        LargeBinary => dyn_binary_dedup!(array, BinaryArray<i64>, i64, seen),
+        Utf8 => dyn_binary_dedup!(array, Utf8Array<i32>, i32, seen),
+        LargeUtf8 => dyn_binary_dedup!(array, Utf8Array<i64>, i64, seen),
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            estimated_bytes_size_dedup(
+                array
+                    .values()
+                    .sliced(
+                        array.offsets().first().to_usize(),
+                        array.offsets().range().to_usize(),
+                    )
+                    .as_ref(),
+                seen,
+            ) + seen.count_buffer(array.offsets().buffer())
+                + seen.count_validity(array.validity())
+        },
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            estimated_bytes_size_dedup(array.values().as_ref(), seen)
+                + seen.count_validity(array.validity())
+        },
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            estimated_bytes_size_dedup(
+                array
+                    .values()
+                    .sliced(
+                        array.offsets().first().to_usize(),
+                        array.offsets().range().to_usize(),
+                    )
+                    .as_ref(),
+                seen,
+            ) + seen.count_buffer(array.offsets().buffer())
+                + seen.count_validity(array.validity())
+        },
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|x| estimated_bytes_size_dedup(x.as_ref(), seen))
+                .sum::<usize>()
+                + seen.count_validity(array.validity())
+        },
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let types = seen.count_buffer(array.types());
+            let offsets = array
+                .offsets()
+                .as_ref()
+                .map(|x| seen.count_buffer(x))
+                .unwrap_or_default();
+            let fields = array
+                .fields()
+                .iter()
+                .map(|x| estimated_bytes_size_dedup(x.as_ref(), seen))
+                .sum::<usize>();
+            types + offsets + fields
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            estimated_bytes_size_dedup(array.keys(), seen)
+                + estimated_bytes_size_dedup(array.values().as_ref(), seen)
+        }),
+        Utf8View => binview_size_dedup::<str>(array.as_any().downcast_ref().unwrap(), seen),
+        BinaryView => binview_size_dedup::<[u8]>(array.as_any().downcast_ref().unwrap(), seen),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            seen.count_buffer(array.offsets().buffer())
+                + estimated_bytes_size_dedup(array.field().as_ref(), seen)
+                + seen.count_validity(array.validity())
+        },
+    }
+}
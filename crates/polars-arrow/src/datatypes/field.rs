@@ -21,6 +21,14 @@ pub static PARQUET_EMPTY_STRUCT: &str = "_PL_EMPTY_STRUCT";
 pub static MAINTAIN_PL_TYPE: &str = "maintain_type";
 pub static PL_KEY: &str = "pl";
 
+/// Whether `key` is a metadata key Polars or Arrow use to encode data types in Arrow fields.
+pub fn is_reserved_metadata_key(key: &str) -> bool {
+    key.starts_with("_PL_")
+        || key.starts_with("ARROW:extension:")
+        || key == MAINTAIN_PL_TYPE
+        || key == PL_KEY
+}
+
 /// Represents Arrow's metadata of a "column".
 ///
 /// A [`Field`] is the closest representation of the traditional "column": a logical type
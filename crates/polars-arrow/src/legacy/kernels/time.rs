@@ -3,14 +3,15 @@ use std::str::FromStr;
 #[cfg(feature = "timezones")]
 use chrono::{LocalResult, NaiveDateTime, TimeZone};
 #[cfg(feature = "timezones")]
-use chrono_tz::Tz;
-#[cfg(feature = "timezones")]
 use polars_error::PolarsResult;
 use polars_error::{PolarsError, polars_bail};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
 
+#[cfg(feature = "timezones")]
+use crate::legacy::time_zone::Tz;
+
 pub enum Ambiguous {
     Earliest,
     Latest,
@@ -3,4 +3,11 @@
 #[derive(Copy, Clone)]
 pub enum Tz {}
 #[cfg(feature = "timezones")]
-pub use chrono_tz::Tz;
+mod posix;
+#[cfg(feature = "timezones")]
+mod tz;
+#[cfg(feature = "timezones")]
+mod tzif;
+
+#[cfg(feature = "timezones")]
+pub use tz::{Tz, TzOffset};
@@ -0,0 +1,301 @@
+//! POSIX TZ strings such as `CET-1CEST,M3.5.0,M10.5.0/3`, which describe the offsets of a time
+//! zone after the last transition of a TZif file.
+use chrono::{DateTime, Datelike, NaiveDate};
+
+use super::tzif::ZoneOffset;
+
+#[derive(Debug)]
+pub(super) struct PosixTz {
+    std: ZoneOffset,
+    dst: Option<Dst>,
+}
+
+#[derive(Debug)]
+struct Dst {
+    offset: ZoneOffset,
+    /// Start of daylight saving time, in local standard time.
+    start: Rule,
+    /// End of daylight saving time, in local daylight saving time.
+    end: Rule,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Rule {
+    date: RuleDate,
+    /// Seconds after midnight, may be negative or exceed a day.
+    time: i64,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleDate {
+    /// `Jn`: day of the year in `1..=365`, February 29 is never counted.
+    Julian1(u16),
+    /// `n`: day of the year in `0..=365`, February 29 is counted in leap years.
+    Julian0(u16),
+    /// `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` (5 is the last week) of month `m`.
+    MonthWeekDay { month: u32, week: u32, weekday: u32 },
+}
+
+impl PosixTz {
+    /// Parse a TZ string, `leak` turns the abbreviations into static strings.
+    pub(super) fn parse(s: &str, leak: impl Fn(&str) -> &'static str) -> Option<Self> {
+        let mut cursor = Cursor {
+            s: s.as_bytes(),
+            pos: 0,
+        };
+
+        // The offsets are west of UTC, so their sign is the reverse of a UTC offset.
+        let std_abbreviation = cursor.abbreviation()?;
+        let std_offset = -cursor.offset()?;
+        let std = ZoneOffset::new(std_offset, 0, leak(std_abbreviation))?;
+        if cursor.is_done() {
+            return Some(Self { std, dst: None });
+        }
+
+        let dst_abbreviation = cursor.abbreviation()?;
+        let dst_offset = match cursor.peek() {
+            None | Some(b',') => std_offset + 3600,
+            Some(_) => -cursor.offset()?,
+        };
+        let offset = ZoneOffset::new(dst_offset, dst_offset - std_offset, leak(dst_abbreviation))?;
+        let (start, end) = if cursor.eat(b',') {
+            let start = cursor.rule()?;
+            if !cursor.eat(b',') {
+                return None;
+            }
+            (start, cursor.rule()?)
+        } else {
+            // The POSIX default, daylight saving time in the United States.
+            let rule = |month, week| Rule {
+                date: RuleDate::MonthWeekDay {
+                    month,
+                    week,
+                    weekday: 0,
+                },
+                time: 2 * 3600,
+            };
+            (rule(3, 2), rule(11, 1))
+        };
+
+        cursor.is_done().then_some(Self {
+            std,
+            dst: Some(Dst { offset, start, end }),
+        })
+    }
+
+    pub(super) fn std_offset(&self) -> i32 {
+        self.std.utc_offset
+    }
+
+    /// All offsets this TZ string can produce.
+    pub(super) fn offsets(&self) -> impl Iterator<Item = ZoneOffset> + '_ {
+        std::iter::once(self.std).chain(self.dst.as_ref().map(|dst| dst.offset))
+    }
+
+    /// The offset in effect at `timestamp` in seconds since the epoch.
+    pub(super) fn offset_at_utc(&self, timestamp: i64) -> ZoneOffset {
+        let Some(dst) = &self.dst else {
+            return self.std;
+        };
+        let Some(year) =
+            DateTime::from_timestamp(timestamp + self.std.utc_offset as i64, 0).map(|dt| dt.year())
+        else {
+            return self.std;
+        };
+        let (Some(start), Some(end)) = (
+            dst.start.to_utc(year, self.std.utc_offset),
+            dst.end.to_utc(year, dst.offset.utc_offset),
+        ) else {
+            return self.std;
+        };
+
+        // On the southern hemisphere daylight saving time spans new year.
+        let is_dst = if start < end {
+            start <= timestamp && timestamp < end
+        } else {
+            !(end <= timestamp && timestamp < start)
+        };
+        if is_dst { dst.offset } else { self.std }
+    }
+}
+
+impl Rule {
+    /// The transition in `year` in seconds since the epoch, given the UTC offset in effect before
+    /// it.
+    fn to_utc(self, year: i32, utc_offset: i32) -> Option<i64> {
+        let date = match self.date {
+            RuleDate::Julian1(day) => {
+                let is_leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                let ordinal = if is_leap && day >= 60 { day + 1 } else { day };
+                NaiveDate::from_yo_opt(year, ordinal as u32)?
+            },
+            RuleDate::Julian0(day) => NaiveDate::from_yo_opt(year, day as u32 + 1)
+                .or_else(|| NaiveDate::from_ymd_opt(year, 12, 31))?,
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                let first_weekday = first.weekday().num_days_from_sunday();
+                let mut day = 1 + (weekday + 7 - first_weekday) % 7 + (week - 1) * 7;
+                // The fifth week means the last one, which might be the fourth.
+                while NaiveDate::from_ymd_opt(year, month, day).is_none() {
+                    day -= 7;
+                }
+                NaiveDate::from_ymd_opt(year, month, day)?
+            },
+        };
+        let midnight = date.and_hms_opt(0, 0, 0)?.and_utc().timestamp();
+        Some(midnight + self.time - utc_offset as i64)
+    }
+}
+
+struct Cursor<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos == self.s.len()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        let eaten = self.peek() == Some(c);
+        self.pos += eaten as usize;
+        eaten
+    }
+
+    fn take_while(&mut self, f: impl Fn(u8) -> bool) -> &'a [u8] {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+        &self.s[start..self.pos]
+    }
+
+    fn number(&mut self) -> Option<i64> {
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        std::str::from_utf8(digits).ok()?.parse().ok()
+    }
+
+    /// Either alphabetic, or quoted in `<>` and also containing digits and signs.
+    fn abbreviation(&mut self) -> Option<&'a str> {
+        let abbreviation = if self.eat(b'<') {
+            let abbreviation = self.take_while(|c| c != b'>');
+            if !self.eat(b'>') {
+                return None;
+            }
+            abbreviation
+        } else {
+            self.take_while(|c| c.is_ascii_alphabetic())
+        };
+        (!abbreviation.is_empty())
+            .then(|| std::str::from_utf8(abbreviation).ok())
+            .flatten()
+    }
+
+    /// `[+-]hh[:mm[:ss]]` in seconds.
+    fn offset(&mut self) -> Option<i64> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let mut seconds = self.number()? * 3600;
+        if self.eat(b':') {
+            seconds += self.number()? * 60;
+            if self.eat(b':') {
+                seconds += self.number()?;
+            }
+        }
+        Some(sign * seconds)
+    }
+
+    /// `date[/time]`
+    fn rule(&mut self) -> Option<Rule> {
+        let date = if self.eat(b'J') {
+            let day = self.number().filter(|day| (1..=365).contains(day))?;
+            RuleDate::Julian1(day as u16)
+        } else if self.eat(b'M') {
+            let month = self.number().filter(|m| (1..=12).contains(m))?;
+            let week = self
+                .eat(b'.')
+                .then(|| self.number())
+                .flatten()
+                .filter(|w| (1..=5).contains(w))?;
+            let weekday = self
+                .eat(b'.')
+                .then(|| self.number())
+                .flatten()
+                .filter(|d| (0..=6).contains(d))?;
+            RuleDate::MonthWeekDay {
+                month: month as u32,
+                week: week as u32,
+                weekday: weekday as u32,
+            }
+        } else {
+            let day = self.number().filter(|day| (0..=365).contains(day))?;
+            RuleDate::Julian0(day as u16)
+        };
+        let time = if self.eat(b'/') {
+            self.offset()?
+        } else {
+            2 * 3600
+        };
+        Some(Rule { date, time })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(s: &str) -> Option<PosixTz> {
+        PosixTz::parse(s, |s| s.to_string().leak())
+    }
+
+    #[test]
+    fn test_posix_tz() {
+        // 2024-03-31T00:59:59Z and 2024-10-27T01:00:00Z, the last seconds of CET and CEST.
+        let tz = parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let offset = |ts| {
+            let offset = tz.offset_at_utc(ts);
+            (offset.utc_offset, offset.dst_offset, offset.abbreviation)
+        };
+        assert_eq!(offset(1_711_846_799), (3600, 0, "CET"));
+        assert_eq!(offset(1_711_846_800), (7200, 3600, "CEST"));
+        assert_eq!(offset(1_729_990_799), (7200, 3600, "CEST"));
+        assert_eq!(offset(1_729_990_800), (3600, 0, "CET"));
+
+        // Daylight saving time spans new year.
+        let tz = parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert_eq!(tz.offset_at_utc(1_704_067_200).utc_offset, 39600);
+        assert_eq!(tz.offset_at_utc(1_719_792_000).utc_offset, 36000);
+
+        let tz = parse("<+0330>-3:30").unwrap();
+        assert_eq!(tz.offset_at_utc(0).utc_offset, 12600);
+        assert_eq!(tz.offset_at_utc(0).abbreviation, "+0330");
+
+        // Default rules and offset of daylight saving time.
+        let tz = parse("EST5EDT").unwrap();
+        assert_eq!(tz.offset_at_utc(1_719_792_000).utc_offset, -14400);
+
+        for s in [
+            "",
+            "CET",
+            "CET-1CEST,M3.5.0",
+            "CET-1CEST,M13.5.0,M10.5.0",
+            "<CET-1",
+            "CET-25",
+        ] {
+            assert!(parse(s).is_none(), "{s}");
+        }
+    }
+}
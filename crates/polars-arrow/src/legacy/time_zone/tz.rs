@@ -0,0 +1,307 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{LazyLock, RwLock};
+
+use chrono::{Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::OffsetComponents;
+use polars_config::TzdbSource;
+use polars_error::{PolarsError, PolarsResult, polars_ensure, polars_err};
+use polars_utils::aliases::PlHashMap;
+
+use super::tzif::{LocalOffset, Zone, ZoneOffset};
+
+/// Locations of the time zone database on Unix-like systems.
+const SYSTEM_TZDB_DIRS: &[&str] = &[
+    "/usr/share/zoneinfo",
+    "/usr/lib/zoneinfo",
+    "/usr/share/lib/zoneinfo",
+    "/etc/zoneinfo",
+];
+
+/// A time zone of the IANA time zone database.
+///
+/// Parsing a [`Tz`] reads it from the database configured through `POLARS_TZDB`, which is the
+/// one compiled into Polars by default. Time zones read from a directory are loaded once and
+/// kept for the lifetime of the process.
+#[derive(Clone, Copy)]
+pub enum Tz {
+    Bundled(chrono_tz::Tz),
+    Loaded(&'static Zone),
+}
+
+impl Tz {
+    pub const UTC: Tz = Tz::Bundled(chrono_tz::UTC);
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Self::Bundled(tz) => tz.name(),
+            Self::Loaded(zone) => zone.name(),
+        }
+    }
+
+    /// Read the time zone `name` from `source`.
+    pub fn from_source(name: &str, source: &TzdbSource) -> PolarsResult<Self> {
+        let dir = match source {
+            TzdbSource::Bundled => {
+                return name
+                    .parse::<chrono_tz::Tz>()
+                    .map(Self::Bundled)
+                    .map_err(|_| polars_err!(ComputeError: "unable to parse time zone: '{name}'"));
+            },
+            // UTC doesn't depend on the database.
+            _ if name == "UTC" => return Ok(Self::UTC),
+            TzdbSource::System => system_tzdb_dir()?,
+            TzdbSource::Path(dir) => dir.clone(),
+        };
+        load_zone(dir, name).map(Self::Loaded)
+    }
+}
+
+fn system_tzdb_dir() -> PolarsResult<PathBuf> {
+    if let Some(dir) = std::env::var_os("TZDIR").filter(|dir| !dir.is_empty()) {
+        return Ok(dir.into());
+    }
+    SYSTEM_TZDB_DIRS
+        .iter()
+        .map(Path::new)
+        .find(|dir| dir.is_dir())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            polars_err!(
+                ComputeError: "unable to find the time zone database of the system. \
+                Set `TZDIR` or point `POLARS_TZDB` to the directory containing it."
+            )
+        })
+}
+
+fn load_zone(dir: PathBuf, name: &str) -> PolarsResult<&'static Zone> {
+    static ZONES: LazyLock<RwLock<PlHashMap<(PathBuf, String), &'static Zone>>> =
+        LazyLock::new(Default::default);
+
+    let key = (dir, name.to_string());
+    if let Some(zone) = ZONES.read().unwrap().get(&key) {
+        return Ok(zone);
+    }
+
+    // Don't let the name escape the database directory.
+    let is_valid_name = name.split('/').all(|part| {
+        !matches!(part, "" | "." | "..")
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+    });
+    polars_ensure!(is_valid_name, ComputeError: "unable to parse time zone: '{name}'");
+
+    let path = key.0.join(name);
+    let data = std::fs::read(&path).map_err(|e| {
+        polars_err!(
+            ComputeError: "unable to read time zone '{name}' from '{}': {e}", path.display()
+        )
+    })?;
+    let leak = |s: &str| -> &'static str { Box::leak(s.into()) };
+    let zone = Zone::parse(name.to_string(), &data, leak).map_err(
+        |e| polars_err!(ComputeError: "unable to parse time zone file '{}': {e}", path.display()),
+    )?;
+
+    let mut zones = ZONES.write().unwrap();
+    Ok(*zones
+        .entry(key)
+        .or_insert_with(|| Box::leak(Box::new(zone))))
+}
+
+impl FromStr for Tz {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_source(s, &polars_config::config().tzdb())
+    }
+}
+
+impl PartialEq for Tz {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for Tz {}
+
+impl Hash for Tz {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state)
+    }
+}
+
+impl fmt::Debug for Tz {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for Tz {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The offset of a [`Tz`] at some point in time.
+#[derive(Clone, Copy)]
+pub enum TzOffset {
+    Bundled(chrono_tz::TzOffset),
+    Loaded(&'static Zone, ZoneOffset),
+}
+
+impl Offset for TzOffset {
+    fn fix(&self) -> FixedOffset {
+        match self {
+            Self::Bundled(offset) => offset.fix(),
+            Self::Loaded(_, offset) => FixedOffset::east_opt(offset.utc_offset).unwrap(),
+        }
+    }
+}
+
+impl OffsetComponents for TzOffset {
+    fn base_utc_offset(&self) -> Duration {
+        match self {
+            Self::Bundled(offset) => offset.base_utc_offset(),
+            Self::Loaded(_, offset) => {
+                Duration::seconds((offset.utc_offset - offset.dst_offset) as i64)
+            },
+        }
+    }
+
+    fn dst_offset(&self) -> Duration {
+        match self {
+            Self::Bundled(offset) => offset.dst_offset(),
+            Self::Loaded(_, offset) => Duration::seconds(offset.dst_offset as i64),
+        }
+    }
+}
+
+impl fmt::Debug for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bundled(offset) => fmt::Debug::fmt(offset, f),
+            Self::Loaded(_, offset) => f.write_str(offset.abbreviation),
+        }
+    }
+}
+
+impl fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bundled(offset) => fmt::Display::fmt(offset, f),
+            Self::Loaded(_, offset) => f.write_str(offset.abbreviation),
+        }
+    }
+}
+
+impl TimeZone for Tz {
+    type Offset = TzOffset;
+
+    fn from_offset(offset: &TzOffset) -> Self {
+        match *offset {
+            TzOffset::Bundled(offset) => Self::Bundled(chrono_tz::Tz::from_offset(&offset)),
+            TzOffset::Loaded(zone, _) => Self::Loaded(zone),
+        }
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TzOffset> {
+        match self {
+            Self::Bundled(tz) => tz.offset_from_local_date(local).map(TzOffset::Bundled),
+            Self::Loaded(_) => {
+                self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+            },
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TzOffset> {
+        match *self {
+            Self::Bundled(tz) => tz.offset_from_local_datetime(local).map(TzOffset::Bundled),
+            Self::Loaded(zone) => match zone.offset_at_local(local.and_utc().timestamp()) {
+                LocalOffset::None => LocalResult::None,
+                LocalOffset::Single(offset) => LocalResult::Single(TzOffset::Loaded(zone, offset)),
+                LocalOffset::Ambiguous(earliest, latest) => LocalResult::Ambiguous(
+                    TzOffset::Loaded(zone, earliest),
+                    TzOffset::Loaded(zone, latest),
+                ),
+            },
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TzOffset {
+        match self {
+            Self::Bundled(tz) => TzOffset::Bundled(tz.offset_from_utc_date(utc)),
+            Self::Loaded(_) => self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap()),
+        }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzOffset {
+        match *self {
+            Self::Bundled(tz) => TzOffset::Bundled(tz.offset_from_utc_datetime(utc)),
+            Self::Loaded(zone) => {
+                TzOffset::Loaded(zone, zone.offset_at_utc(utc.and_utc().timestamp()))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::DateTime;
+
+    use super::*;
+
+    #[test]
+    fn test_loaded_matches_bundled() {
+        let Ok(dir) = system_tzdb_dir() else {
+            return;
+        };
+        let source = TzdbSource::Path(dir);
+        for name in [
+            "Europe/Amsterdam",
+            "America/New_York",
+            "Australia/Sydney",
+            "Asia/Kolkata",
+        ] {
+            let bundled = Tz::from_source(name, &TzdbSource::Bundled).unwrap();
+            let Ok(loaded) = Tz::from_source(name, &source) else {
+                continue;
+            };
+            assert_eq!(loaded, bundled);
+
+            // Hourly over 2024 and over 2090, which is past the last transition of the files.
+            for start in [1_704_067_200, 3_786_912_000] {
+                for hour in 0..366 * 24 {
+                    let utc = DateTime::from_timestamp(start + hour * 3600, 0)
+                        .unwrap()
+                        .naive_utc();
+                    let local = bundled.from_utc_datetime(&utc).naive_local();
+                    assert_eq!(loaded.from_utc_datetime(&utc).naive_local(), local);
+                    assert_eq!(
+                        loaded.offset_from_utc_datetime(&utc).dst_offset(),
+                        bundled.offset_from_utc_datetime(&utc).dst_offset(),
+                    );
+
+                    let to_utc = |tz: &Tz| tz.from_local_datetime(&local).map(|dt| dt.naive_utc());
+                    assert_eq!(to_utc(&loaded), to_utc(&bundled));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_zone_names() {
+        let source = TzdbSource::Path(PathBuf::from(SYSTEM_TZDB_DIRS[0]));
+        for name in [
+            "",
+            "../etc/passwd",
+            "Europe//Amsterdam",
+            "Europe/./Amsterdam",
+        ] {
+            assert!(Tz::from_source(name, &source).is_err());
+        }
+        assert_eq!(Tz::from_source("UTC", &source).unwrap(), Tz::UTC);
+    }
+}
@@ -0,0 +1,298 @@
+//! Parser for the TZif files of a time zone database, see RFC 8536.
+use super::posix::PosixTz;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZoneOffset {
+    /// Offset from UTC in seconds.
+    pub utc_offset: i32,
+    /// Daylight saving time offset in seconds, part of `utc_offset`.
+    pub dst_offset: i32,
+    pub abbreviation: &'static str,
+}
+
+impl ZoneOffset {
+    pub(super) fn new(
+        utc_offset: i64,
+        dst_offset: i64,
+        abbreviation: &'static str,
+    ) -> Option<Self> {
+        const DAY: i64 = 86_400;
+        (utc_offset.abs() < DAY && dst_offset.abs() < DAY).then_some(Self {
+            utc_offset: utc_offset as i32,
+            dst_offset: dst_offset as i32,
+            abbreviation,
+        })
+    }
+}
+
+/// The offsets for a local time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalOffset {
+    None,
+    Single(ZoneOffset),
+    /// The earliest offset first.
+    Ambiguous(ZoneOffset, ZoneOffset),
+}
+
+#[derive(Debug)]
+pub struct Zone {
+    name: String,
+    /// Transition times in seconds since the epoch, sorted.
+    transitions: Vec<i64>,
+    /// `offsets[i]` is in effect before `transitions[i]`, the last one after all transitions
+    /// unless there is a footer.
+    offsets: Vec<ZoneOffset>,
+    footer: Option<PosixTz>,
+}
+
+impl Zone {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Parse the contents of a TZif file, `leak` turns the abbreviations into static strings.
+    pub fn parse(
+        name: String,
+        data: &[u8],
+        leak: impl Fn(&str) -> &'static str,
+    ) -> Result<Self, &'static str> {
+        let header = Header::parse(data)?;
+        let (header, body) = if header.version >= 2 {
+            // Skip the version 1 data, which only has 32-bit times.
+            let data = data
+                .get(Header::LEN + header.body_len(4)..)
+                .ok_or("unexpected end of file")?;
+            (Header::parse(data)?, data)
+        } else {
+            (header, data)
+        };
+        let time_size = if header.version >= 2 { 8 } else { 4 };
+        let mut reader = Reader {
+            data: body,
+            pos: Header::LEN,
+        };
+
+        let transitions = (0..header.timecnt)
+            .map(|_| reader.int(time_size))
+            .collect::<Option<Vec<_>>>()
+            .ok_or("unexpected end of file")?;
+        let indices = reader
+            .bytes(header.timecnt)
+            .ok_or("unexpected end of file")?
+            .to_vec();
+        let types = (0..header.typecnt)
+            .map(|_| {
+                let utc_offset = reader.int(4)?;
+                let is_dst = reader.bytes(1)?[0] != 0;
+                let abbreviation_index = reader.bytes(1)?[0] as usize;
+                Some((utc_offset, is_dst, abbreviation_index))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or("unexpected end of file")?;
+        let abbreviations = reader
+            .bytes(header.charcnt)
+            .ok_or("unexpected end of file")?;
+        reader
+            .bytes(header.leapcnt * (time_size + 4) + header.isstdcnt + header.isutcnt)
+            .ok_or("unexpected end of file")?;
+
+        let footer = if header.version >= 2 {
+            let footer = reader.footer().ok_or("invalid footer")?;
+            if footer.is_empty() {
+                None
+            } else {
+                Some(PosixTz::parse(footer, &leak).ok_or("invalid footer")?)
+            }
+        } else {
+            None
+        };
+
+        if types.is_empty() || indices.iter().any(|&i| i as usize >= types.len()) {
+            return Err("invalid local time type");
+        }
+        if transitions.windows(2).any(|w| w[0] >= w[1]) {
+            return Err("transition times are not sorted");
+        }
+
+        let offsets = (0..types.len())
+            .map(|i| {
+                let (utc_offset, is_dst, abbreviation_index) = types[i];
+                let abbreviation = abbreviations
+                    .get(abbreviation_index..)
+                    .and_then(|s| s.split(|&c| c == 0).next())
+                    .and_then(|s| std::str::from_utf8(s).ok())?;
+                let dst_offset = if is_dst {
+                    utc_offset - base_utc_offset(&types, &indices, i, footer.as_ref())
+                } else {
+                    0
+                };
+                ZoneOffset::new(utc_offset, dst_offset, leak(abbreviation))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or("invalid local time type")?;
+        let offsets = std::iter::once(offsets[0])
+            .chain(indices.iter().map(|&i| offsets[i as usize]))
+            .collect();
+
+        Ok(Self {
+            name,
+            transitions,
+            offsets,
+            footer,
+        })
+    }
+
+    /// The offset in effect at `timestamp` in seconds since the epoch.
+    pub fn offset_at_utc(&self, timestamp: i64) -> ZoneOffset {
+        let i = self.transitions.partition_point(|&t| t <= timestamp);
+        match &self.footer {
+            Some(footer) if i == self.transitions.len() => footer.offset_at_utc(timestamp),
+            _ => self.offsets[i],
+        }
+    }
+
+    /// The offsets that map `local` in seconds since the epoch to UTC.
+    pub fn offset_at_local(&self, local: i64) -> LocalOffset {
+        // Offsets are less than a day, so only those in this window can apply.
+        const WINDOW: i64 = 26 * 3600;
+
+        let start = self.transitions.partition_point(|&t| t <= local - WINDOW);
+        let end = self.transitions.partition_point(|&t| t <= local + WINDOW);
+        let mut candidates = vec![
+            self.offset_at_utc(local - WINDOW),
+            self.offset_at_utc(local + WINDOW),
+        ];
+        candidates.extend(
+            self.transitions[start..end]
+                .iter()
+                .map(|&t| self.offset_at_utc(t)),
+        );
+        if let Some(footer) = &self.footer {
+            // Transitions of the footer, e.g. daylight saving time, don't show up in
+            // `self.transitions`.
+            candidates.extend(footer.offsets());
+        }
+
+        let mut valid = candidates
+            .into_iter()
+            .filter(|offset| self.offset_at_utc(local - offset.utc_offset as i64) == *offset)
+            .collect::<Vec<_>>();
+        valid.sort_by_key(|offset| std::cmp::Reverse(offset.utc_offset));
+        valid.dedup();
+        match valid.as_slice() {
+            [] => LocalOffset::None,
+            [offset] => LocalOffset::Single(*offset),
+            [earliest, .., latest] => LocalOffset::Ambiguous(*earliest, *latest),
+        }
+    }
+}
+
+/// The standard time offset underlying the daylight saving time type `i`, taken from the
+/// surrounding transitions. Zones that crossed the date line can have a standard time type on
+/// the other side of it, those are skipped.
+fn base_utc_offset(
+    types: &[(i64, bool, usize)],
+    indices: &[u8],
+    i: usize,
+    footer: Option<&PosixTz>,
+) -> i64 {
+    let utc_offset = types[i].0;
+    let is_base = |&&j: &&u8| {
+        let (base, is_dst, _) = types[j as usize];
+        !is_dst && (utc_offset - base).abs() < 12 * 3600
+    };
+    let preceding = indices
+        .iter()
+        .position(|&j| j as usize == i)
+        .and_then(|pos| indices[..pos].iter().rev().find(is_base));
+    let following = || {
+        indices
+            .iter()
+            .rposition(|&j| j as usize == i)
+            .and_then(|pos| indices[pos..].iter().find(is_base))
+    };
+    match preceding.or_else(following) {
+        Some(&j) => types[j as usize].0,
+        None => footer
+            .map(|f| f.std_offset() as i64)
+            .filter(|base| (utc_offset - base).abs() < 12 * 3600)
+            .unwrap_or(utc_offset - 3600),
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    const LEN: usize = 44;
+
+    fn parse(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < Self::LEN || &data[..4] != b"TZif" {
+            return Err("not a TZif file");
+        }
+        let version = match data[4] {
+            0 => 1,
+            c @ b'2'..=b'9' => c - b'0',
+            _ => return Err("unsupported TZif version"),
+        };
+        let count = |i: usize| {
+            let start = 20 + 4 * i;
+            u32::from_be_bytes(data[start..start + 4].try_into().unwrap()) as usize
+        };
+        Ok(Self {
+            version,
+            isutcnt: count(0),
+            isstdcnt: count(1),
+            leapcnt: count(2),
+            timecnt: count(3),
+            typecnt: count(4),
+            charcnt: count(5),
+        })
+    }
+
+    fn body_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    /// A big-endian signed integer of 4 or 8 bytes.
+    fn int(&mut self, size: usize) -> Option<i64> {
+        let bytes = self.bytes(size)?;
+        Some(match size {
+            4 => i32::from_be_bytes(bytes.try_into().unwrap()) as i64,
+            _ => i64::from_be_bytes(bytes.try_into().unwrap()),
+        })
+    }
+
+    /// The TZ string enclosed in newlines.
+    fn footer(&mut self) -> Option<&'a str> {
+        let rest = self.data.get(self.pos..)?.strip_prefix(b"\n")?;
+        let end = rest.iter().position(|&c| c == b'\n')?;
+        std::str::from_utf8(&rest[..end]).ok()
+    }
+}
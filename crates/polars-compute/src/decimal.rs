@@ -599,6 +599,230 @@ pub fn dec128_div(l: i128, r: i128, p: usize, s: usize) -> Option<i128> {
     }
 }
 
+/// The minimum scale kept when the precision of an arithmetic result has to be bounded.
+const DEC128_MIN_BOUNDED_SCALE: usize = 6;
+
+/// How a Decimal128 arithmetic result is rounded to its scale.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Dec128RoundMode {
+    #[default]
+    HalfToEven,
+    HalfAwayFromZero,
+    ToZero,
+}
+
+/// Bounds the precision of an arithmetic result to the maximum precision, sacrificing scale
+/// (down to a minimum of 6, if available) to keep the integral digits.
+fn dec128_bound_prec_scale(p: usize, s: usize) -> (usize, usize) {
+    if p <= DEC128_MAX_PREC {
+        return (p, s);
+    }
+    let int_digits = p - s;
+    let min_scale = s.min(DEC128_MIN_BOUNDED_SCALE);
+    let s = DEC128_MAX_PREC.saturating_sub(int_digits).max(min_scale);
+    (DEC128_MAX_PREC, s)
+}
+
+/// The precision and scale of the sum or difference of two Decimal128s.
+pub fn dec128_add_prec_scale(lp: usize, ls: usize, rp: usize, rs: usize) -> (usize, usize) {
+    let s = ls.max(rs);
+    let p = (lp - ls).max(rp - rs) + s + 1;
+    dec128_bound_prec_scale(p, s)
+}
+
+/// The precision and scale of the product of two Decimal128s.
+pub fn dec128_mul_prec_scale(lp: usize, ls: usize, rp: usize, rs: usize) -> (usize, usize) {
+    dec128_bound_prec_scale(lp + rp + 1, ls + rs)
+}
+
+/// The precision and scale of the quotient of two Decimal128s.
+pub fn dec128_div_prec_scale(lp: usize, ls: usize, rp: usize, rs: usize) -> (usize, usize) {
+    let s = DEC128_MIN_BOUNDED_SCALE.max(ls + rp + 1);
+    let p = lp - ls + rs + s;
+    dec128_bound_prec_scale(p, s)
+}
+
+/// Compares a remainder 0 <= rem < d with half of d.
+#[inline]
+fn cmp_half(rem: u128, d: u128) -> Ordering {
+    rem.cmp(&(d - rem))
+}
+
+/// Rounds the magnitude of a truncated quotient, given how its remainder compares to half of
+/// the divisor.
+#[inline]
+fn round_quotient(q: u128, rem_vs_half: Ordering, mode: Dec128RoundMode) -> u128 {
+    let round_up = match mode {
+        Dec128RoundMode::HalfToEven => {
+            rem_vs_half == Ordering::Greater || (rem_vs_half == Ordering::Equal && q % 2 == 1)
+        },
+        Dec128RoundMode::HalfAwayFromZero => rem_vs_half != Ordering::Less,
+        Dec128RoundMode::ToZero => false,
+    };
+    q + round_up as u128
+}
+
+#[inline]
+fn dec128_from_magnitude(mag: u128, negative: bool, p: usize) -> Option<i128> {
+    if mag >= POW10_I128[p] as u128 {
+        return None;
+    }
+    Some(if negative {
+        -(mag as i128)
+    } else {
+        mag as i128
+    })
+}
+
+/// Adds two Decimal128s with scales ls and rs, rounding the result to precision p and scale s
+/// with the given mode. Returns None if the result doesn't fit.
+pub fn dec128_add_rounded(
+    l: i128,
+    ls: usize,
+    r: i128,
+    rs: usize,
+    p: usize,
+    s: usize,
+    mode: Dec128RoundMode,
+) -> Option<i128> {
+    let cs = ls.max(rs);
+    if s >= cs {
+        // Exact.
+        let sum = mul_128_pow10(l, s - ls)?.checked_add(mul_128_pow10(r, s - rs)?)?;
+        return dec128_fits(sum, p).then_some(sum);
+    }
+
+    // Split both operands into a part with scale s and the digits beyond it (with scale cs),
+    // such that the sum can be computed exactly before rounding.
+    let split = |x: i128, xs: usize| -> Option<(i128, i128)> {
+        if xs <= s {
+            Some((mul_128_pow10(x, s - xs)?, 0))
+        } else {
+            let d = POW10_I128[xs - s];
+            Some((x / d, (x % d) * POW10_I128[cs - xs]))
+        }
+    };
+    let (l_hi, l_lo) = split(l, ls)?;
+    let (r_hi, r_lo) = split(r, rs)?;
+
+    // The sum is q * d + rem with 0 <= rem < d.
+    let d = POW10_I128[cs - s];
+    let lo = l_lo.checked_add(r_lo)?;
+    let q = l_hi.checked_add(r_hi)?.checked_add(lo.div_euclid(d))?;
+    let rem = lo.rem_euclid(d) as u128;
+    let negative = q < 0;
+    let (mag, frac) = if negative && rem > 0 {
+        (q.unsigned_abs() - 1, d as u128 - rem)
+    } else {
+        (q.unsigned_abs(), rem)
+    };
+    let mag = round_quotient(mag, cmp_half(frac, d as u128), mode);
+    dec128_from_magnitude(mag, negative, p)
+}
+
+/// Subtracts two Decimal128s with scales ls and rs, rounding the result to precision p and
+/// scale s with the given mode. Returns None if the result doesn't fit.
+pub fn dec128_sub_rounded(
+    l: i128,
+    ls: usize,
+    r: i128,
+    rs: usize,
+    p: usize,
+    s: usize,
+    mode: Dec128RoundMode,
+) -> Option<i128> {
+    dec128_add_rounded(l, ls, -r, rs, p, s, mode)
+}
+
+/// Multiplies two Decimal128s with scales ls and rs, rounding the result to precision p and
+/// scale s <= ls + rs with the given mode. Returns None if the result doesn't fit.
+pub fn dec128_mul_rounded(
+    l: i128,
+    ls: usize,
+    r: i128,
+    rs: usize,
+    p: usize,
+    s: usize,
+    mode: Dec128RoundMode,
+) -> Option<i128> {
+    debug_assert!(s <= ls + rs);
+    let negative = (l < 0) ^ (r < 0);
+    let (lo, hi) = widening_mul_128(l.unsigned_abs(), r.unsigned_abs());
+
+    // The exact product has scale ls + rs, drop the excess digits (at most 38 at a time).
+    let k = ls + rs - s;
+    let mag = if k == 0 {
+        if hi != 0 {
+            return None;
+        }
+        lo
+    } else {
+        let k1 = k.min(DEC128_MAX_PREC);
+        let d1 = POW10_I128[k1] as u128;
+        let (q, rem) = divrem_256_128(lo, hi, d1)?;
+        if k1 == k {
+            round_quotient(q, cmp_half(rem, d1), mode)
+        } else {
+            let d2 = POW10_I128[k - k1] as u128;
+            let mut rem_vs_half = cmp_half(q % d2, d2);
+            if rem_vs_half == Ordering::Equal && rem != 0 {
+                rem_vs_half = Ordering::Greater;
+            }
+            round_quotient(q / d2, rem_vs_half, mode)
+        }
+    };
+    dec128_from_magnitude(mag, negative, p)
+}
+
+/// Divides two Decimal128s with scales ls and rs, rounding the result to precision p and scale
+/// s with the given mode. Returns None if r is zero or the result doesn't fit.
+pub fn dec128_div_rounded(
+    l: i128,
+    ls: usize,
+    r: i128,
+    rs: usize,
+    p: usize,
+    s: usize,
+    mode: Dec128RoundMode,
+) -> Option<i128> {
+    if r == 0 {
+        return None;
+    }
+
+    let negative = (l < 0) ^ (r < 0);
+    let lu = l.unsigned_abs();
+    let ru = r.unsigned_abs();
+
+    // Computes round(l * 10^e / r), with e = s + rs - ls.
+    let (q, rem_vs_half) = if s + rs >= ls {
+        // Long division, shifting in at most 38 digits at a time.
+        let mut e = s + rs - ls;
+        let k = e.min(DEC128_MAX_PREC);
+        let (lo, hi) = widening_mul_128(lu, POW10_I128[k] as u128);
+        let (mut q, mut rem) = divrem_256_128(lo, hi, ru)?;
+        e -= k;
+        while e > 0 {
+            let k = e.min(DEC128_MAX_PREC);
+            let (lo, hi) = widening_mul_128(rem, POW10_I128[k] as u128);
+            let (q_k, rem_k) = divrem_256_128(lo, hi, ru)?;
+            q = q.checked_mul(POW10_I128[k] as u128)?.checked_add(q_k)?;
+            rem = rem_k;
+            e -= k;
+        }
+        (q, cmp_half(rem, ru))
+    } else {
+        let d = POW10_I128[ls - s - rs] as u128;
+        let (q, rem) = (lu / ru, lu % ru);
+        let mut rem_vs_half = cmp_half(q % d, d);
+        if rem_vs_half == Ordering::Equal && rem != 0 {
+            rem_vs_half = Ordering::Greater;
+        }
+        (q / d, rem_vs_half)
+    };
+    let mag = round_quotient(q, rem_vs_half, mode);
+    dec128_from_magnitude(mag, negative, p)
+}
+
 /// Checks if two Decimal128s are equal in value.
 #[inline]
 pub fn dec128_eq(mut lv: i128, ls: usize, mut rv: i128, rs: usize) -> bool {
@@ -1062,6 +1286,106 @@ mod test {
         assert_eq!(str_to_dec128_dot(b".", 8, 2), None);
     }
 
+    #[test]
+    fn test_arith_prec_scale() {
+        assert_eq!(dec128_add_prec_scale(10, 2, 5, 4), (13, 4));
+        assert_eq!(dec128_add_prec_scale(38, 0, 38, 10), (38, 6));
+        assert_eq!(dec128_mul_prec_scale(10, 2, 5, 4), (16, 6));
+        assert_eq!(dec128_mul_prec_scale(38, 10, 38, 10), (38, 6));
+        assert_eq!(dec128_div_prec_scale(10, 2, 5, 4), (20, 8));
+        assert_eq!(dec128_div_prec_scale(5, 0, 5, 0), (11, 6));
+        assert_eq!(dec128_div_prec_scale(38, 10, 10, 2), (38, 8));
+    }
+
+    #[test]
+    fn test_arith_rounded() {
+        use Dec128RoundMode::*;
+
+        // 1.25 + 0.005 = 1.255 at scale 2.
+        assert_eq!(
+            dec128_add_rounded(125, 2, 5, 3, 10, 2, HalfToEven),
+            Some(126)
+        );
+        assert_eq!(dec128_add_rounded(125, 2, 5, 3, 10, 2, ToZero), Some(125));
+        assert_eq!(
+            dec128_add_rounded(-125, 2, -5, 3, 10, 2, HalfAwayFromZero),
+            Some(-126)
+        );
+        // -1.25 + 0.005 = -1.245 at scale 2.
+        assert_eq!(
+            dec128_add_rounded(-125, 2, 5, 3, 10, 2, HalfToEven),
+            Some(-124)
+        );
+        assert_eq!(
+            dec128_add_rounded(-125, 2, 5, 3, 10, 2, HalfAwayFromZero),
+            Some(-125)
+        );
+        assert_eq!(dec128_sub_rounded(125, 2, 5, 3, 10, 2, ToZero), Some(124));
+        assert_eq!(dec128_add_rounded(999, 2, 1, 2, 3, 2, HalfToEven), None);
+
+        // 0.15 * 0.5 = 0.075 at scale 2.
+        assert_eq!(dec128_mul_rounded(15, 2, 5, 1, 10, 2, HalfToEven), Some(8));
+        assert_eq!(dec128_mul_rounded(15, 2, 5, 1, 10, 2, ToZero), Some(7));
+        assert_eq!(
+            dec128_mul_rounded(-15, 2, 5, 1, 10, 2, HalfAwayFromZero),
+            Some(-8)
+        );
+        assert_eq!(dec128_mul_rounded(15, 2, 5, 1, 10, 3, HalfToEven), Some(75));
+
+        // 1 / 8 = 0.125 at scale 2, 2 / 3 at scale 6.
+        assert_eq!(dec128_div_rounded(1, 0, 8, 0, 10, 2, HalfToEven), Some(12));
+        assert_eq!(
+            dec128_div_rounded(1, 0, 8, 0, 10, 2, HalfAwayFromZero),
+            Some(13)
+        );
+        assert_eq!(
+            dec128_div_rounded(-1, 0, 8, 0, 10, 2, HalfAwayFromZero),
+            Some(-13)
+        );
+        assert_eq!(dec128_div_rounded(2, 0, 3, 0, 10, 6, ToZero), Some(666666));
+        assert_eq!(
+            dec128_div_rounded(2, 0, 3, 0, 10, 6, HalfToEven),
+            Some(666667)
+        );
+        // 12.5 / 1 at scale 0, where the scale of the quotient is reduced.
+        assert_eq!(
+            dec128_div_rounded(1250, 2, 1, 0, 10, 0, HalfToEven),
+            Some(12)
+        );
+        assert_eq!(
+            dec128_div_rounded(1251, 3, 1, 0, 10, 0, HalfToEven),
+            Some(1)
+        );
+        assert_eq!(dec128_div_rounded(1, 0, 0, 0, 10, 2, HalfToEven), None);
+    }
+
+    #[test]
+    fn test_arith_rounded_against_ref() {
+        let mut r = SmallRng::seed_from_u64(42);
+        for _ in 0..1_000 {
+            let (lp, rp) = (r.random_range(1..=38), r.random_range(1..=38));
+            let (ls, rs) = (r.random_range(0..=lp), r.random_range(0..=rp));
+            let l = r.random::<i128>() % POW10_I128[r.random_range(0..=lp)];
+            let y = r.random::<i128>() % POW10_I128[r.random_range(0..=rp)];
+            let (lb, yb) = (dec128_to_bigdecimal(l, ls), dec128_to_bigdecimal(y, rs));
+
+            let (p, s) = dec128_add_prec_scale(lp, ls, rp, rs);
+            let ours = dec128_add_rounded(l, ls, y, rs, p, s, Dec128RoundMode::HalfToEven);
+            assert_eq!(ours, bigdecimal_to_dec128(&(&lb + &yb), p, s));
+
+            let (p, s) = dec128_mul_prec_scale(lp, ls, rp, rs);
+            let ours = dec128_mul_rounded(l, ls, y, rs, p, s, Dec128RoundMode::HalfToEven);
+            assert_eq!(ours, bigdecimal_to_dec128(&(&lb * &yb), p, s));
+
+            if y != 0 {
+                let (p, s) = dec128_div_prec_scale(lp, ls, rp, rs);
+                let ours = dec128_div_rounded(l, ls, y, rs, p, s, Dec128RoundMode::HalfToEven);
+                let exact = (&lb / &yb).with_scale_round(s as i64 + 10, RoundingMode::Down);
+                assert_eq!(ours, bigdecimal_to_dec128(&exact, p, s));
+            }
+        }
+    }
+
     #[test]
     #[ignore = "very slow, meant for development"]
     fn test_str_to_dec_against_ref() {
@@ -22,20 +22,27 @@ use std::marker::PhantomData;
 
 use polars_utils::aliases::PlFixedStateQuality;
 
-/// The greater is P, the smaller the error.
-const HLL_P: usize = 14_usize;
-/// The number of bits of the hash value used determining the number of leading zeros
-const HLL_Q: usize = 64_usize - HLL_P;
-const NUM_REGISTERS: usize = 1_usize << HLL_P;
-/// Mask to obtain index into the registers
-const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
+/// The default precision P, the greater is P, the smaller the error.
+pub const HLL_DEFAULT_PRECISION: u8 = 14;
+/// The smallest supported precision.
+pub const HLL_MIN_PRECISION: u8 = 4;
+/// The largest supported precision.
+pub const HLL_MAX_PRECISION: u8 = 18;
+/// Magic bytes that start the serialized form, see [`HyperLogLog::to_bytes`].
+pub const HLL_MAGIC: [u8; 3] = *b"HLL";
+/// Version of the serialized form, see [`HyperLogLog::to_bytes`].
+///
+/// The registers depend on the hashes of the inserted values, so this version has to change when
+/// either the layout or the hashing of the values changes.
+pub const HLL_SERIALIZATION_VERSION: u8 = 1;
 
 #[derive(Clone, Debug)]
 pub struct HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    registers: [u8; NUM_REGISTERS],
+    precision: u8,
+    registers: Box<[u8]>,
     phantom: PhantomData<T>,
 }
 
@@ -60,22 +67,38 @@ impl<T> HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    /// Creates a new, empty HyperLogLog.
+    /// Creates a new, empty HyperLogLog with the default precision.
     pub fn new() -> Self {
-        let registers = [0; NUM_REGISTERS];
-        Self::new_with_registers(registers)
+        Self::with_precision(HLL_DEFAULT_PRECISION)
     }
 
-    /// Creates a HyperLogLog from already populated registers
-    /// note that this method should not be invoked in untrusted environment
-    /// because the internal structure of registers are not examined.
-    pub(crate) fn new_with_registers(registers: [u8; NUM_REGISTERS]) -> Self {
+    /// Creates a new, empty HyperLogLog with `2^precision` registers.
+    ///
+    /// # Panics
+    /// Panics if `precision` is not between [`HLL_MIN_PRECISION`] and [`HLL_MAX_PRECISION`].
+    pub fn with_precision(precision: u8) -> Self {
+        assert!(
+            (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+            "HyperLogLog precision must be between {HLL_MIN_PRECISION} and {HLL_MAX_PRECISION}, got {precision}"
+        );
         Self {
-            registers,
+            precision,
+            registers: vec![0; 1 << precision].into_boxed_slice(),
             phantom: PhantomData,
         }
     }
 
+    /// The precision P of this HyperLogLog, it has `2^P` registers.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// The number of bits of the hash value used determining the number of leading zeros
+    #[inline]
+    fn q(&self) -> usize {
+        64 - self.precision as usize
+    }
+
     #[inline]
     fn hash_value(&self, obj: &T) -> u64 {
         SEED.hash_one(obj)
@@ -84,19 +107,26 @@ where
     /// Adds an element to the HyperLogLog.
     pub fn add(&mut self, obj: &T) {
         let hash = self.hash_value(obj);
-        let index = (hash & HLL_P_MASK) as usize;
-        let p = ((hash >> HLL_P) | (1_u64 << HLL_Q)).trailing_zeros() + 1;
+        self.add_hash(hash);
+    }
+
+    /// Adds an already hashed element to the HyperLogLog.
+    ///
+    /// The hash should be of good quality, as both its low and its high bits are used.
+    pub fn add_hash(&mut self, hash: u64) {
+        let mask = (self.registers.len() as u64) - 1;
+        let index = (hash & mask) as usize;
+        let p = ((hash >> self.precision) | (1_u64 << self.q())).trailing_zeros() + 1;
         self.registers[index] = self.registers[index].max(p as u8);
     }
 
     /// Get the register histogram (each value in register index into
-    /// the histogram; u32 is enough because we only have 2**14=16384 registers
+    /// the histogram; u32 is enough because we have at most 2**18 registers
     #[inline]
-    fn get_histogram(&self) -> [u32; HLL_Q + 2] {
-        let mut histogram = [0; HLL_Q + 2];
-        // hopefully this can be unrolled
-        for r in self.registers {
-            histogram[r as usize] += 1;
+    fn get_histogram(&self) -> Vec<u32> {
+        let mut histogram = vec![0; self.q() + 2];
+        for r in self.registers.iter() {
+            histogram[*r as usize] += 1;
         }
         histogram
     }
@@ -116,16 +146,64 @@ where
 
     /// Guess the number of unique elements seen by the HyperLogLog.
     pub fn count(&self) -> usize {
+        let q = self.q();
         let histogram = self.get_histogram();
-        let m = NUM_REGISTERS as f64;
-        let mut z = m * hll_tau((m - histogram[HLL_Q + 1] as f64) / m);
-        for i in histogram[1..=HLL_Q].iter().rev() {
+        let m = self.registers.len() as f64;
+        let mut z = m * hll_tau((m - histogram[q + 1] as f64) / m);
+        for i in histogram[1..=q].iter().rev() {
             z += *i as f64;
             z *= 0.5;
         }
         z += m * hll_sigma(histogram[0] as f64 / m);
         (0.5 / 2_f64.ln() * m * m / z).round() as usize
     }
+
+    /// Serialize the HyperLogLog.
+    ///
+    /// The serialized form is [`HLL_MAGIC`] and a version byte, followed by the precision and the
+    /// registers. It can be turned back into a HyperLogLog with [`HyperLogLog::from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HLL_MAGIC.len() + 2 + self.registers.len());
+        out.extend_from_slice(&HLL_MAGIC);
+        out.push(HLL_SERIALIZATION_VERSION);
+        out.push(self.precision);
+        out.extend_from_slice(&self.registers);
+        out
+    }
+
+    /// Deserialize a HyperLogLog created with [`HyperLogLog::to_bytes`].
+    ///
+    /// Returns `None` if `bytes` is not a valid serialized HyperLogLog of the current
+    /// [`HLL_SERIALIZATION_VERSION`].
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if Self::serialized_version(bytes)? != HLL_SERIALIZATION_VERSION {
+            return None;
+        }
+        let [precision, registers @ ..] = &bytes[HLL_MAGIC.len() + 1..] else {
+            return None;
+        };
+        if !(HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(precision)
+            || registers.len() != 1 << precision
+        {
+            return None;
+        }
+        let max_register = 64 - *precision + 1;
+        if registers.iter().any(|r| *r > max_register) {
+            return None;
+        }
+        Some(Self {
+            precision: *precision,
+            registers: registers.into(),
+            phantom: PhantomData,
+        })
+    }
+
+    /// The version of a serialized HyperLogLog, or `None` if `bytes` doesn't start with
+    /// [`HLL_MAGIC`] and a version.
+    pub fn serialized_version(bytes: &[u8]) -> Option<u8> {
+        let rest = bytes.strip_prefix(&HLL_MAGIC)?;
+        rest.first().copied()
+    }
 }
 
 /// Helper function sigma as defined in
@@ -209,7 +287,9 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{HyperLogLog, NUM_REGISTERS};
+    use super::{HLL_DEFAULT_PRECISION, HLL_SERIALIZATION_VERSION, HyperLogLog};
+
+    const NUM_REGISTERS: usize = 1 << HLL_DEFAULT_PRECISION;
 
     fn compare_with_delta(got: usize, expected: usize) {
         let expected = expected as f64;
@@ -334,6 +414,47 @@ mod tests {
         compare_with_delta(hll.count(), 1000);
     }
 
+    #[test]
+    fn test_precision() {
+        for precision in [4, 10, 18] {
+            let mut hll = HyperLogLog::<u64>::with_precision(precision);
+            hll.extend(0..100_000);
+            let margin = 1.04 / ((1_u64 << precision) as f64).sqrt() * 6.0;
+            let diff = (hll.count() as f64 - 100_000.0).abs() / 100_000.0;
+            assert!(diff <= margin, "precision {precision}: got {}", hll.count());
+        }
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let mut hll = HyperLogLog::<u64>::with_precision(8);
+        hll.extend(0..1000);
+        let bytes = hll.to_bytes();
+        assert_eq!(bytes.len(), 5 + 256);
+        assert_eq!(
+            HyperLogLog::<u64>::serialized_version(&bytes),
+            Some(HLL_SERIALIZATION_VERSION)
+        );
+
+        let mut other = HyperLogLog::<u64>::from_bytes(&bytes).unwrap();
+        assert_eq!(other.precision(), 8);
+        assert_eq!(other.count(), hll.count());
+
+        other.merge(&hll);
+        assert_eq!(other.count(), hll.count());
+
+        assert!(HyperLogLog::<u64>::from_bytes(&bytes[..100]).is_none());
+        assert!(HyperLogLog::<u64>::from_bytes(&[]).is_none());
+        let mut invalid = bytes.clone();
+        invalid[5] = 60;
+        assert!(HyperLogLog::<u64>::from_bytes(&invalid).is_none());
+
+        // Sketches of other versions are rejected.
+        let mut other_version = bytes.clone();
+        other_version[3] = HLL_SERIALIZATION_VERSION + 1;
+        assert!(HyperLogLog::<u64>::from_bytes(&other_version).is_none());
+    }
+
     #[test]
     fn test_repetition() {
         let mut hll = HyperLogLog::<u32>::new();
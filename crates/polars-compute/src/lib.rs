@@ -33,6 +33,8 @@ pub mod propagate_nulls;
 pub mod rolling;
 pub mod size;
 pub mod sum;
+#[cfg(feature = "approx_quantile")]
+pub mod tdigest;
 pub mod trim_lists_to_normalized_offsets;
 pub mod unique;
 
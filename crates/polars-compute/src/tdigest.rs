@@ -0,0 +1,282 @@
+//! # t-digest
+//!
+//! A merging t-digest for approximate quantiles, as described in "Computing Extremely Accurate
+//! Quantiles Using t-Digests" by Ted Dunning and Otmar Ertl, arXiv:1902.04023.
+//!
+//! The digest summarizes the values in centroids, which are small near the tails and larger in
+//! the middle of the distribution. The number of centroids is bounded by the compression, so the
+//! memory usage does not depend on the number of values. Digests can be merged, which makes them
+//! suitable for parallel and streaming aggregations.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_compute::tdigest::*;
+//!     let mut digest = TDigest::new(100.0);
+//!     digest.extend([4.0, 1.0, 5.0, 2.0, 3.0]);
+//!
+//!     assert_eq!(digest.quantile(0.5), Some(3.0));
+//! ```
+
+use std::f64::consts::PI;
+
+/// The default compression, the greater it is, the smaller the error.
+pub const TDIGEST_DEFAULT_COMPRESSION: f64 = 100.0;
+
+#[derive(Copy, Clone, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// Compressed centroids sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values and centroids that are not yet merged into `centroids`.
+    buffer: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(TDIGEST_DEFAULT_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    /// Creates a new, empty t-digest.
+    ///
+    /// The number of centroids is at most about `compression * PI / 2`.
+    pub fn new(compression: f64) -> Self {
+        assert!(compression > 0.0, "t-digest compression must be positive");
+        Self {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn compression(&self) -> f64 {
+        self.compression
+    }
+
+    /// Whether no values have been added to the t-digest.
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// The number of values added to the t-digest.
+    pub fn count(&self) -> f64 {
+        self.centroids
+            .iter()
+            .chain(self.buffer.iter())
+            .map(|c| c.weight)
+            .sum()
+    }
+
+    #[inline]
+    fn buffer_capacity(&self) -> usize {
+        (self.compression * 5.0) as usize + 16
+    }
+
+    #[inline]
+    fn push(&mut self, centroid: Centroid) {
+        self.buffer.push(centroid);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.compress();
+        }
+    }
+
+    /// Adds a value to the t-digest. NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+    }
+
+    /// Merge the other [`TDigest`] into this one.
+    pub fn merge(&mut self, other: &TDigest) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        for centroid in other.centroids.iter().chain(other.buffer.iter()) {
+            self.push(*centroid);
+        }
+    }
+
+    /// The scale function `k_1`, which maps a quantile to the index scale.
+    #[inline]
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    /// The inverse of [`TDigest::k`].
+    #[inline]
+    fn k_inv(&self, k: f64) -> f64 {
+        let k = k.min(self.compression / 4.0);
+        ((2.0 * PI * k / self.compression).sin() + 1.0) / 2.0
+    }
+
+    /// Merge the buffer into the centroids.
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut items = std::mem::take(&mut self.centroids);
+        items.append(&mut self.buffer);
+        items.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = items.iter().map(|c| c.weight).sum();
+        let mut items = items.into_iter();
+        let mut current = items.next().unwrap();
+        let mut weight_before = 0.0;
+        let mut weight_limit = total * self.k_inv(self.k(0.0) + 1.0);
+        for item in items {
+            if weight_before + current.weight + item.weight <= weight_limit {
+                let weight = current.weight + item.weight;
+                current.mean += (item.mean - current.mean) * item.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_before += current.weight;
+                self.centroids.push(current);
+                weight_limit = total * self.k_inv(self.k(weight_before / total) + 1.0);
+                current = item;
+            }
+        }
+        self.centroids.push(current);
+    }
+
+    /// Estimate the `quantile` of the added values, which must be between 0.0 and 1.0.
+    ///
+    /// The quantile is interpolated linearly between the centroids, such that it is exact if
+    /// every centroid holds a single value. Returns `None` if no values have been added.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        self.compress();
+        if self.centroids.is_empty() {
+            return None;
+        }
+
+        // The position of the quantile in the sorted values, where a centroid is centered at the
+        // average position of its values.
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let target = quantile * (total - 1.0);
+        let mut prev_position = 0.0;
+        let mut prev_value = self.min;
+        let mut weight_before = 0.0;
+        for centroid in &self.centroids {
+            let position = weight_before + (centroid.weight - 1.0) / 2.0;
+            if target <= position {
+                return Some(interpolate(
+                    prev_position,
+                    prev_value,
+                    position,
+                    centroid.mean,
+                    target,
+                ));
+            }
+            prev_position = position;
+            prev_value = centroid.mean;
+            weight_before += centroid.weight;
+        }
+        Some(interpolate(
+            prev_position,
+            prev_value,
+            total - 1.0,
+            self.max,
+            target,
+        ))
+    }
+}
+
+#[inline]
+fn interpolate(x0: f64, y0: f64, x1: f64, y1: f64, x: f64) -> f64 {
+    if x1 <= x0 {
+        y1
+    } else {
+        y0 + (y1 - y0) * ((x - x0) / (x1 - x0)).clamp(0.0, 1.0)
+    }
+}
+
+impl Extend<f64> for TDigest {
+    fn extend<T: IntoIterator<Item = f64>>(&mut self, iter: T) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TDigest;
+
+    #[test]
+    fn test_empty() {
+        let mut digest = TDigest::new(100.0);
+        assert!(digest.is_empty());
+        assert_eq!(digest.quantile(0.5), None);
+        digest.add(f64::NAN);
+        assert_eq!(digest.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_small_is_exact() {
+        let mut digest = TDigest::new(100.0);
+        digest.extend([4.0, 1.0, 5.0, 2.0, 3.0]);
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.25), Some(2.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(0.875), Some(4.5));
+        assert_eq!(digest.quantile(1.0), Some(5.0));
+
+        let mut single = TDigest::new(100.0);
+        single.add(7.0);
+        assert_eq!(single.quantile(0.3), Some(7.0));
+    }
+
+    #[test]
+    fn test_large() {
+        let n: u64 = 1_000_000;
+        let mut digest = TDigest::new(100.0);
+        // A permutation of 0..n, so values are not inserted in sorted order.
+        digest.extend((0..n).map(|i| ((i * 7919) % n) as f64));
+        assert_eq!(digest.count(), n as f64);
+        assert!(digest.centroids.len() + digest.buffer.len() < 1000);
+
+        for q in [0.001, 0.01, 0.25, 0.5, 0.75, 0.99, 0.999] {
+            let expected = q * (n - 1) as f64;
+            let got = digest.quantile(q).unwrap();
+            // The error is relative to the distance to the closest tail.
+            let tolerance = n as f64 * 0.01 * (q * (1.0 - q)).sqrt();
+            assert!(
+                (got - expected).abs() <= tolerance,
+                "q={q}: {got} vs {expected}"
+            );
+        }
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some((n - 1) as f64));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = TDigest::new(50.0);
+        a.extend((0..50_000).map(|v| v as f64));
+        let mut b = TDigest::new(50.0);
+        b.extend((50_000..100_000).map(|v| v as f64));
+        a.merge(&b);
+
+        assert_eq!(a.count(), 100_000.0);
+        let median = a.quantile(0.5).unwrap();
+        assert!((median - 49_999.5).abs() < 500.0, "{median}");
+        assert_eq!(a.quantile(1.0), Some(99_999.0));
+    }
+}
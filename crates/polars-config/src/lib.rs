@@ -1,15 +1,20 @@
-use std::sync::LazyLock;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64, Ordering};
+use std::sync::{LazyLock, RwLock};
 
 mod engine;
+mod lossy_casts;
 mod parse;
 mod spill_format;
 mod spill_policy;
+mod tzdb;
 
 pub use engine::Engine;
+pub use lossy_casts::LossyCasts;
 use polars_error::polars_warn;
 pub use spill_format::SpillFormat;
 pub use spill_policy::SpillPolicy;
+pub use tzdb::TzdbSource;
 
 // Public.
 const VERBOSE: &str = "POLARS_VERBOSE";
@@ -33,6 +38,11 @@ const PARQUET_BINARY_STATISTICS_TRUNCATE_LENGTH: &str =
     "POLARS_PARQUET_BINARY_STATISTICS_TRUNCATE_LEN";
 const DEFAULT_PARQUET_BINARY_STATISTICS_TRUNCATE_LENGTH: u64 = 64;
 
+const LOSSY_CASTS: &str = "POLARS_LOSSY_CASTS";
+const DEFAULT_LOSSY_CASTS: LossyCasts = LossyCasts::Ignore;
+
+const TZDB: &str = "POLARS_TZDB";
+
 // Private.
 const VERBOSE_SENSITIVE: &str = "POLARS_VERBOSE_SENSITIVE";
 const DEFAULT_VERBOSE_SENSITIVE: bool = false;
@@ -52,6 +62,11 @@ const DEFAULT_OOC_SPILL_POLICY: SpillPolicy = SpillPolicy::NoSpill;
 const OOC_SPILL_FORMAT: &str = "POLARS_OOC_SPILL_FORMAT";
 const DEFAULT_OOC_SPILL_FORMAT: SpillFormat = SpillFormat::Ipc;
 
+const OOC_SPILL_DIR: &str = "POLARS_OOC_SPILL_DIR";
+
+const OOC_SORT_MEMORY_BUDGET: &str = "POLARS_OOC_SORT_MEMORY_BUDGET";
+const DEFAULT_OOC_SORT_MEMORY_BUDGET: u64 = 0;
+
 static KNOWN_OPTIONS: &[&str] = &[
     // Public.
     VERBOSE,
@@ -61,6 +76,8 @@ static KNOWN_OPTIONS: &[&str] = &[
     STREAMING_CHUNK_SIZE,
     ENGINE_AFFINITY,
     PARQUET_BINARY_STATISTICS_TRUNCATE_LENGTH,
+    LOSSY_CASTS,
+    TZDB,
     /*
     Not yet supported public options:
 
@@ -90,6 +107,8 @@ static KNOWN_OPTIONS: &[&str] = &[
     OOC_DRIFT_THRESHOLD,
     OOC_SPILL_POLICY,
     OOC_SPILL_FORMAT,
+    OOC_SPILL_DIR,
+    OOC_SORT_MEMORY_BUDGET,
 ];
 
 pub struct Config {
@@ -100,6 +119,8 @@ pub struct Config {
     ideal_morsel_size: AtomicU64,
     engine_affinity: AtomicU8,
     parquet_binary_statistics_truncate_length: AtomicU64,
+    lossy_casts: AtomicU8,
+    tzdb: RwLock<TzdbSource>,
 
     // Private.
     verbose_sensitive: AtomicBool,
@@ -108,6 +129,8 @@ pub struct Config {
     ooc_drift_threshold: AtomicU64,
     ooc_spill_policy: AtomicU8,
     ooc_spill_format: AtomicU8,
+    ooc_spill_dir: RwLock<Option<PathBuf>>,
+    ooc_sort_memory_budget: AtomicU64,
 }
 
 impl Config {
@@ -122,6 +145,8 @@ impl Config {
             parquet_binary_statistics_truncate_length: AtomicU64::new(
                 DEFAULT_PARQUET_BINARY_STATISTICS_TRUNCATE_LENGTH,
             ),
+            lossy_casts: AtomicU8::new(DEFAULT_LOSSY_CASTS as u8),
+            tzdb: RwLock::new(TzdbSource::Bundled),
 
             // Private.
             verbose_sensitive: AtomicBool::new(DEFAULT_VERBOSE_SENSITIVE),
@@ -130,6 +155,8 @@ impl Config {
             ooc_drift_threshold: AtomicU64::new(DEFAULT_OOC_DRIFT_THRESHOLD),
             ooc_spill_policy: AtomicU8::new(DEFAULT_OOC_SPILL_POLICY as u8),
             ooc_spill_format: AtomicU8::new(DEFAULT_OOC_SPILL_FORMAT as u8),
+            ooc_spill_dir: RwLock::new(None),
+            ooc_sort_memory_budget: AtomicU64::new(DEFAULT_OOC_SORT_MEMORY_BUDGET),
         };
         cfg.reload_env_vars();
         cfg
@@ -185,6 +212,16 @@ impl Config {
                     Ordering::Relaxed,
                 )
             },
+            LOSSY_CASTS => self.lossy_casts.store(
+                val.and_then(|x| parse::parse_lossy_casts(var, x))
+                    .unwrap_or(DEFAULT_LOSSY_CASTS) as u8,
+                Ordering::Relaxed,
+            ),
+            TZDB => {
+                *self.tzdb.write().unwrap() = val
+                    .and_then(|x| parse::parse_tzdb(var, x))
+                    .unwrap_or_default()
+            },
 
             // Private flags.
             VERBOSE_SENSITIVE => self.verbose_sensitive.store(
@@ -217,6 +254,16 @@ impl Config {
                     .unwrap_or(DEFAULT_OOC_SPILL_FORMAT) as u8,
                 Ordering::Relaxed,
             ),
+            OOC_SPILL_DIR => {
+                *self.ooc_spill_dir.write().unwrap() = val
+                    .filter(|x| !x.trim_ascii().is_empty())
+                    .map(PathBuf::from)
+            },
+            OOC_SORT_MEMORY_BUDGET => self.ooc_sort_memory_budget.store(
+                val.and_then(|x| parse::parse_u64(var, x))
+                    .unwrap_or(DEFAULT_OOC_SORT_MEMORY_BUDGET),
+                Ordering::Relaxed,
+            ),
 
             _ => {
                 if var.starts_with("POLARS_") {
@@ -256,6 +303,16 @@ impl Config {
             .load(Ordering::Relaxed)
     }
 
+    /// How implicit casts that may lose information are reported.
+    pub fn lossy_casts(&self) -> LossyCasts {
+        LossyCasts::from_discriminant(self.lossy_casts.load(Ordering::Relaxed))
+    }
+
+    /// Where the time zone database is read from.
+    pub fn tzdb(&self) -> TzdbSource {
+        self.tzdb.read().unwrap().clone()
+    }
+
     /// Whether we should do verbose printing on sensitive information.
     pub fn verbose_sensitive(&self) -> bool {
         self.verbose_sensitive.load(Ordering::Relaxed)
@@ -280,6 +337,17 @@ impl Config {
     pub fn ooc_spill_format(&self) -> SpillFormat {
         SpillFormat::from_discriminant(self.ooc_spill_format.load(Ordering::Relaxed))
     }
+
+    /// The directory to spill to, if set.
+    pub fn ooc_spill_dir(&self) -> Option<PathBuf> {
+        self.ooc_spill_dir.read().unwrap().clone()
+    }
+
+    /// The memory budget of a sort in bytes before it spills to disk, 0 means it is derived from
+    /// the available memory.
+    pub fn ooc_sort_memory_budget(&self) -> u64 {
+        self.ooc_sort_memory_budget.load(Ordering::Relaxed)
+    }
 }
 
 pub fn config() -> &'static Config {
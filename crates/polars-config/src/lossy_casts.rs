@@ -0,0 +1,52 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// How implicit casts that may lose information are reported.
+#[repr(u8)]
+#[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
+pub enum LossyCasts {
+    #[default]
+    Ignore = 0,
+    Warn = 1,
+    Raise = 2,
+}
+
+impl fmt::Display for LossyCasts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_static_str())
+    }
+}
+
+impl FromStr for LossyCasts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ignore" => Ok(Self::Ignore),
+            "warn" => Ok(Self::Warn),
+            "raise" => Ok(Self::Raise),
+            v => Err(format!(
+                "`lossy_casts` must be one of {{'ignore', 'warn', 'raise'}}, got {v}",
+            )),
+        }
+    }
+}
+
+impl LossyCasts {
+    pub(crate) fn from_discriminant(d: u8) -> Self {
+        match d {
+            0 => Self::Ignore,
+            1 => Self::Warn,
+            2 => Self::Raise,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_static_str(&self) -> &'static str {
+        match self {
+            Self::Ignore => "ignore",
+            Self::Warn => "warn",
+            Self::Raise => "raise",
+        }
+    }
+}
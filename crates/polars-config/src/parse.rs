@@ -1,6 +1,6 @@
 use polars_error::polars_warn;
 
-use crate::{Engine, SpillFormat, SpillPolicy};
+use crate::{Engine, LossyCasts, SpillFormat, SpillPolicy, TzdbSource};
 
 pub fn parse_bool(var: &str, val: &str) -> Option<bool> {
     match val.trim_ascii() {
@@ -50,3 +50,23 @@ pub fn parse_spill_format(var: &str, val: &str) -> Option<SpillFormat> {
         },
     }
 }
+
+pub fn parse_lossy_casts(var: &str, val: &str) -> Option<LossyCasts> {
+    match val.trim_ascii().parse::<LossyCasts>() {
+        Ok(x) => Some(x),
+        Err(e) => {
+            polars_warn!("illegal value '{val}' found while parsing option '{var}' ({e})");
+            None
+        },
+    }
+}
+
+pub fn parse_tzdb(var: &str, val: &str) -> Option<TzdbSource> {
+    match val.trim_ascii().parse::<TzdbSource>() {
+        Ok(x) => Some(x),
+        Err(e) => {
+            polars_warn!("illegal value '{val}' found while parsing option '{var}' ({e})");
+            None
+        },
+    }
+}
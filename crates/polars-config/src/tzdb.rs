@@ -0,0 +1,41 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where the IANA time zone database is read from.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum TzdbSource {
+    /// The database compiled into Polars.
+    #[default]
+    Bundled,
+    /// The database of the operating system, found through `TZDIR` or the usual locations.
+    System,
+    /// A directory of TZif files, laid out like `/usr/share/zoneinfo`.
+    Path(PathBuf),
+}
+
+impl fmt::Display for TzdbSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bundled => write!(f, "bundled"),
+            Self::System => write!(f, "system"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl FromStr for TzdbSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bundled" => Ok(Self::Bundled),
+            "system" => Ok(Self::System),
+            "" => Err(
+                "`tzdb` must be one of {'bundled', 'system'} or a directory, got an empty string"
+                    .to_string(),
+            ),
+            v => Ok(Self::Path(PathBuf::from(v))),
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::borrow::Cow;
 
+#[cfg(feature = "dtype-array")]
+use arrow::bitmap::Bitmap;
 use polars_compute::cast::CastOptionsImpl;
 #[cfg(feature = "serde-lazy")]
 use serde::{Deserialize, Serialize};
@@ -464,17 +466,26 @@ impl ChunkCast for ListChunked {
             },
             #[cfg(feature = "dtype-array")]
             Array(child_type, width) => {
-                let physical_type = dtype.to_physical();
+                // Cast the inner type first, such that logical inner types are handled.
+                let list = ca.cast_with_options(&List(child_type.clone()), options)?;
+                let list = list.list().unwrap();
+                // Non-strict casts set the lists that don't have the requested width to null.
+                let list = if options == CastOptions::NonStrict {
+                    Cow::Owned(null_width_mismatches(list, *width))
+                } else {
+                    Cow::Borrowed(list)
+                };
+                let dtype = Array(Box::new(list.inner_dtype().clone()), *width);
 
                 // cast to the physical type to avoid logical chunks.
-                let chunks = cast_chunks(ca.chunks(), &physical_type, options)?;
+                let chunks = cast_chunks(list.chunks(), &dtype.to_physical(), options)?;
                 // SAFETY: we just cast so the dtype matches.
                 // we must take this path to correct for physical types.
                 unsafe {
                     Ok(Series::from_chunks_and_dtype_unchecked(
                         ca.name().clone(),
                         chunks,
-                        &Array(child_type.clone(), *width),
+                        &dtype,
                     ))
                 }
             },
@@ -558,16 +569,20 @@ impl ChunkCast for ArrayChunked {
                 }
             },
             List(child_type) => {
-                let physical_type = dtype.to_physical();
+                // Cast the inner type first, such that logical inner types are handled.
+                let arr = ca.cast_with_options(&Array(child_type.clone(), ca.width()), options)?;
+                let arr = arr.array().unwrap();
+                let dtype = List(Box::new(arr.inner_dtype().clone()));
+
                 // cast to the physical type to avoid logical chunks.
-                let chunks = cast_chunks(ca.chunks(), &physical_type, options)?;
+                let chunks = cast_chunks(arr.chunks(), &dtype.to_physical(), options)?;
                 // SAFETY: we just cast so the dtype matches.
                 // we must take this path to correct for physical types.
                 unsafe {
                     Ok(Series::from_chunks_and_dtype_unchecked(
                         ca.name().clone(),
                         chunks,
-                        &List(child_type.clone()),
+                        &dtype,
                     ))
                 }
             },
@@ -652,6 +667,26 @@ unsafe fn cast_list_unchecked(ca: &ListChunked, child_type: &DataType) -> Polars
     .into_series())
 }
 
+// Sets the lists that don't have the given width to null.
+#[cfg(feature = "dtype-array")]
+fn null_width_mismatches(ca: &ListChunked, width: usize) -> ListChunked {
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let has_width: Bitmap = arr.offsets().lengths().map(|len| len == width).collect();
+            let validity = match arr.validity() {
+                Some(validity) => validity & &has_width,
+                None => has_width,
+            };
+            arr.clone().with_validity(Some(validity)).boxed()
+        })
+        .collect();
+    // SAFETY: only the validity changed.
+    unsafe {
+        ListChunked::from_chunks_and_dtype_unchecked(ca.name().clone(), chunks, ca.dtype().clone())
+    }
+}
+
 // Returns inner data type. This is needed because a cast can instantiate the dtype inner
 // values for instance with categoricals
 #[cfg(feature = "dtype-array")]
@@ -708,6 +743,34 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_cast_list_to_array_width_mismatch() -> PolarsResult<()> {
+        let mut builder = ListPrimitiveChunkedBuilder::<Int32Type>::new(
+            PlSmallStr::from_static("a"),
+            10,
+            10,
+            DataType::Int32,
+        );
+        builder.append_opt_slice(Some(&[1i32, 2]));
+        builder.append_opt_slice(Some(&[1i32, 2, 3]));
+        builder.append_opt_slice(None);
+        let ca = builder.finish();
+
+        let dtype = DataType::Array(Box::new(DataType::Int64), 2);
+        assert!(ca.cast_with_options(&dtype, CastOptions::Strict).is_err());
+
+        let out = ca.cast_with_options(&dtype, CastOptions::NonStrict)?;
+        assert_eq!(out.dtype(), &dtype);
+        assert_eq!(out.null_count(), 2);
+
+        let dtype = DataType::List(Box::new(DataType::Int8));
+        let out = out.cast_with_options(&dtype, CastOptions::Strict)?;
+        assert_eq!(out.dtype(), &dtype);
+        assert_eq!(out.null_count(), 2);
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_cast_noop() {
@@ -723,4 +786,35 @@ mod test {
         let out = out.cast(&DataType::from_categories(cats)).unwrap();
         assert!(matches!(out.dtype(), &DataType::Categorical(_, _)))
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_cast_categorical_recode() -> PolarsResult<()> {
+        // There are fewer categories than rows, so the category ids are recoded through a
+        // lookup table.
+        let values = [Some("x"), None, Some("y"), Some("x"), Some("z"), Some("y")];
+        let ca = StringChunked::new(PlSmallStr::from_static("a"), &values);
+        let cats = Categories::new(
+            PlSmallStr::from_static("test_cast_categorical_recode"),
+            PlSmallStr::EMPTY,
+            CategoricalPhysical::U32,
+        );
+        let cat = ca.cast(&DataType::from_categories(cats))?;
+
+        let enum_dtype = DataType::from_frozen_categories(FrozenCategories::new(["y", "x"])?);
+        assert!(
+            cat.cast_with_options(&enum_dtype, CastOptions::Strict)
+                .is_err()
+        );
+        let out = cat.cast_with_options(&enum_dtype, CastOptions::NonStrict)?;
+        assert_eq!(out.dtype(), &enum_dtype);
+        let expected = [Some("x"), None, Some("y"), Some("x"), None, Some("y")];
+        let expected = StringChunked::new(PlSmallStr::from_static("a"), &expected).into_series();
+        assert!(out.cast(&DataType::String)?.equals_missing(&expected));
+
+        let out = out.cast(&DataType::from_categories(Categories::global()))?;
+        assert!(matches!(out.dtype(), &DataType::Categorical(_, _)));
+        assert!(out.cast(&DataType::String)?.equals_missing(&expected));
+        Ok(())
+    }
 }
@@ -63,9 +63,13 @@ where
             .into_iter()
             .map(|x| Box::new(x) as Box<dyn Array>)
             .collect();
-        unsafe {
+        let mut out = unsafe {
             Self::from_chunks_and_dtype_unchecked(ca.name().clone(), chunks, ca.dtype().clone())
+        };
+        if let Some(md) = ca.metadata() {
+            out.set_metadata(Some(md.clone()));
         }
+        out
     }
 
     pub fn try_from_chunk_iter<I, A, E>(name: PlSmallStr, iter: I) -> Result<Self, E>
@@ -13,6 +13,11 @@ use crate::prelude::*;
 use crate::series::IsSorted;
 use crate::utils::handle_casting_failures;
 
+/// Categorical data stored as physical category ids, with the mapping to the categories held by
+/// the dtype.
+///
+/// Gather, filter and concatenation work on the category ids and share the mapping of the dtype,
+/// also across frames using the same [`Categories`], so they never rebuild or re-hash categories.
 pub type CategoricalChunked<T> = Logical<T, <T as PolarsCategoricalType>::PolarsPhysical>;
 pub type Categorical8Chunked = CategoricalChunked<Categorical8Type>;
 pub type Categorical16Chunked = CategoricalChunked<Categorical16Type>;
@@ -32,6 +37,60 @@ impl CategoricalPhysicalDtypeExt for CategoricalPhysical {
     }
 }
 
+/// Returns the dtype that results from adding `categories` to the categories of `dtype`.
+///
+/// For an Enum the new categories are appended after the existing ones, skipping those that
+/// already exist, such that all existing category ids remain valid. The physical type is widened
+/// if the extended categories no longer fit. A Categorical dtype is returned unchanged, as its
+/// categories grow on insertion.
+pub fn add_categories_to_dtype<'a, I: IntoIterator<Item = &'a str>>(
+    dtype: &DataType,
+    categories: I,
+) -> PolarsResult<DataType> {
+    match dtype {
+        DataType::Enum(fcats, mapping) => {
+            let mut new_categories: Vec<&str> = fcats.categories().values_iter().collect();
+            let mut seen = PlHashSet::new();
+            for s in categories {
+                if mapping.get_cat(s).is_none() && seen.insert(s) {
+                    new_categories.push(s);
+                }
+            }
+            if new_categories.len() == fcats.categories().len() {
+                return Ok(dtype.clone());
+            }
+            let fcats = FrozenCategories::new(new_categories)?;
+            Ok(DataType::from_frozen_categories(fcats))
+        },
+        DataType::Categorical(_, _) => Ok(dtype.clone()),
+        dt => polars_bail!(InvalidOperation: "cannot add categories to dtype `{dt}`"),
+    }
+}
+
+/// Returns the dtype that results from adding the categories of `other` to the categories of
+/// `dtype`, see [`add_categories_to_dtype`].
+///
+/// The categories of a Categorical dtype may still grow until the query is executed, so they
+/// can't be added to an Enum, whose categories are fixed when the query is planned.
+pub fn union_categories_dtype(dtype: &DataType, other: &DataType) -> PolarsResult<DataType> {
+    match other {
+        DataType::Enum(fcats, _) => {
+            add_categories_to_dtype(dtype, fcats.categories().values_iter())
+        },
+        DataType::Categorical(_, _) => match dtype {
+            DataType::Enum(_, _) => polars_bail!(
+                InvalidOperation: "cannot take the union of Enum categories with the categories of \
+                a Categorical, as the latter are only known at execution time; cast it to an Enum \
+                or use `add_categories` instead"
+            ),
+            dt => add_categories_to_dtype(dt, std::iter::empty()),
+        },
+        dt => {
+            polars_bail!(InvalidOperation: "cannot take the union of categories with dtype `{dt}`")
+        },
+    }
+}
+
 impl<T: PolarsCategoricalType> CategoricalChunked<T> {
     pub fn is_enum(&self) -> bool {
         matches!(self.dtype(), DataType::Enum(_, _))
@@ -137,6 +196,67 @@ impl<T: PolarsCategoricalType> CategoricalChunked<T> {
         }
     }
 
+    /// Add `categories` to the categories of this [`CategoricalChunked`].
+    ///
+    /// The existing category ids are kept as-is, so this does not recode the data, see
+    /// [`add_categories_to_dtype`] for how the new dtype is formed.
+    pub fn add_categories<'a, I: IntoIterator<Item = &'a str>>(
+        &self,
+        categories: I,
+    ) -> PolarsResult<Series> {
+        if let DataType::Categorical(_, mapping) = self.dtype() {
+            for s in categories {
+                mapping.insert_cat(s)?;
+            }
+            return Ok(self.clone().into_series());
+        }
+        let dtype = add_categories_to_dtype(self.dtype(), categories)?;
+        self.with_extended_dtype(dtype)
+    }
+
+    /// Add the categories of `other`, an Enum or Categorical dtype, to the categories of this
+    /// [`CategoricalChunked`], see [`CategoricalChunked::add_categories`].
+    pub fn union_categories(&self, other: &DataType) -> PolarsResult<Series> {
+        if let DataType::Categorical(_, mapping) = self.dtype() {
+            let other_mapping = other.cat_mapping()?;
+            if !Arc::ptr_eq(mapping, other_mapping) {
+                for i in 0..other_mapping.num_cats_upper_bound() {
+                    if let Some(s) = other_mapping.cat_to_str(i as CatSize) {
+                        mapping.insert_cat(s)?;
+                    }
+                }
+            }
+            return Ok(self.clone().into_series());
+        }
+        let dtype = union_categories_dtype(self.dtype(), other)?;
+        self.with_extended_dtype(dtype)
+    }
+
+    /// Reinterpret the category ids under `dtype`, whose categories must be a superset of ours
+    /// with all our category ids unchanged.
+    fn with_extended_dtype(&self, dtype: DataType) -> PolarsResult<Series> {
+        if &dtype == self.dtype() {
+            return Ok(self.clone().into_series());
+        }
+        let physical = dtype.cat_physical()?;
+        if physical == T::physical() {
+            // SAFETY: the existing category ids remain valid under the extended categories.
+            let out = unsafe { Self::from_cats_and_dtype_unchecked(self.phys.clone(), dtype) };
+            return Ok(out.into_series());
+        }
+
+        // The extended categories don't fit our physical type anymore, widen the category ids.
+        let phys = self.phys.cast(&physical.dtype())?;
+        Ok(with_match_categorical_physical_type!(physical, |$C| {
+            let phys = phys
+                .unpack::<<$C as PolarsCategoricalType>::PolarsPhysical>()?
+                .clone();
+            // SAFETY: the existing category ids remain valid under the extended categories.
+            unsafe { CategoricalChunked::<$C>::from_cats_and_dtype_unchecked(phys, dtype) }
+                .into_series()
+        }))
+    }
+
     /// Get a reference to the mapping of categorical types to the string values.
     pub fn get_mapping(&self) -> &Arc<CategoricalMapping> {
         let (DataType::Categorical(_, mapping) | DataType::Enum(_, mapping)) = self.dtype() else {
@@ -200,6 +320,59 @@ impl<T: PolarsCategoricalType> CategoricalChunked<T> {
         Ok(unsafe { Self::from_cats_and_dtype_unchecked(phys, dtype) })
     }
 
+    /// Whether recoding through a lookup table of all our categories is cheaper than looking up
+    /// every row.
+    fn recode_is_cheaper(&self) -> bool {
+        self.len() >= self.get_mapping().num_cats_upper_bound()
+    }
+
+    /// Recode the category ids into those of the Enum or Categorical `dtype`.
+    ///
+    /// Each distinct category is looked up in the new mapping only once, instead of once per row.
+    /// If `dtype` is an Enum any non-existing categories get mapped to null.
+    fn recode<C: PolarsCategoricalType>(
+        &self,
+        dtype: DataType,
+    ) -> PolarsResult<CategoricalChunked<C>> {
+        let mapping = self.get_mapping();
+        let new_mapping = dtype.cat_mapping()?;
+        assert!(dtype.cat_physical()? == C::physical());
+        let is_enum = dtype.is_enum();
+
+        let mut lookup: Vec<Option<Option<CatSize>>> = vec![None; mapping.num_cats_upper_bound()];
+        let mut recode_cat = |cat: CatSize| -> PolarsResult<Option<CatSize>> {
+            let slot = &mut lookup[cat as usize];
+            if let Some(new_cat) = *slot {
+                return Ok(new_cat);
+            }
+            // SAFETY: the category ids of a CategoricalChunked are valid.
+            let s = unsafe { mapping.cat_to_str_unchecked(cat) };
+            let new_cat = if is_enum {
+                new_mapping.get_cat(s)
+            } else {
+                Some(new_mapping.insert_cat(s)?)
+            };
+            *slot = Some(new_cat);
+            Ok(new_cat)
+        };
+
+        let mut cat_ids = Vec::with_capacity(self.len());
+        let mut validity = BitmapBuilder::with_capacity(self.len());
+        for opt_cat_id in self.phys.iter() {
+            let new_cat = match opt_cat_id {
+                Some(cat_id) => recode_cat(cat_id.as_cat())?,
+                None => None,
+            };
+            cat_ids.push(new_cat.map_or(C::Native::zero(), C::Native::from_cat));
+            validity.push(new_cat.is_some());
+        }
+
+        let arr = <C::PolarsPhysical as PolarsDataType>::Array::from_vec(cat_ids)
+            .with_validity(validity.into_opt_validity());
+        let phys = ChunkedArray::<C::PolarsPhysical>::with_chunk(self.name().clone(), arr);
+        Ok(unsafe { CategoricalChunked::<C>::from_cats_and_dtype_unchecked(phys, dtype) })
+    }
+
     pub fn to_arrow(&self, compat_level: CompatLevel) -> DictionaryArray<T::Native> {
         let keys = self.physical().rechunk();
         let keys = keys.downcast_as_array();
@@ -263,13 +436,16 @@ impl<T: PolarsCategoricalType> LogicalType for CategoricalChunked<T> {
             },
 
             DataType::Enum(fcats, _mapping) => {
-                // TODO @ cat-rework: if len >= self.mapping().upper_bound(), remap categories then index into array.
                 let ret = with_match_categorical_physical_type!(fcats.physical(), |$C| {
-                    CategoricalChunked::<$C>::from_str_iter(
-                        self.name().clone(),
-                        dtype.clone(),
-                        self.iter_str()
-                    )?.into_series()
+                    if self.recode_is_cheaper() {
+                        self.recode::<$C>(dtype.clone())?.into_series()
+                    } else {
+                        CategoricalChunked::<$C>::from_str_iter(
+                            self.name().clone(),
+                            dtype.clone(),
+                            self.iter_str()
+                        )?.into_series()
+                    }
                 });
 
                 if options.is_strict() && self.null_count() != ret.null_count() {
@@ -279,18 +455,19 @@ impl<T: PolarsCategoricalType> LogicalType for CategoricalChunked<T> {
                 Ok(ret)
             },
 
-            DataType::Categorical(cats, _mapping) => {
-                // TODO @ cat-rework: if len >= self.mapping().upper_bound(), remap categories then index into array.
-                Ok(
-                    with_match_categorical_physical_type!(cats.physical(), |$C| {
+            DataType::Categorical(cats, _mapping) => Ok(
+                with_match_categorical_physical_type!(cats.physical(), |$C| {
+                    if self.recode_is_cheaper() {
+                        self.recode::<$C>(dtype.clone())?.into_series()
+                    } else {
                         CategoricalChunked::<$C>::from_str_iter(
                             self.name().clone(),
                             dtype.clone(),
                             self.iter_str()
                         )?.into_series()
-                    }),
-                )
-            },
+                    }
+                }),
+            ),
 
             // LEGACY
             // TODO @ cat-rework: remove after exposing to/from physical functions.
@@ -27,6 +27,7 @@ impl ExtensionChunked {
 
     pub fn field(&self) -> Field {
         Field::new(self.storage.name().clone(), self.dtype.clone())
+            .with_metadata(self.storage.metadata())
     }
 
     pub fn dtype(&self) -> &DataType {
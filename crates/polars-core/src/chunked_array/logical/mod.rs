@@ -148,6 +148,7 @@ where
     pub fn field(&self) -> Field {
         let name = self.phys.ref_field().name();
         Field::new(name.clone(), LogicalType::dtype(self).clone())
+            .with_metadata(self.phys.metadata().cloned())
     }
 
     #[inline(always)]
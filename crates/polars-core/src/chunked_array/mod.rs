@@ -6,6 +6,7 @@ use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::compute::concatenate::concatenate_unchecked;
 use arrow::compute::utils::combine_validities_and;
+use arrow::datatypes::Metadata;
 use polars_compute::filter::filter_with_bitmap;
 
 use crate::prelude::{ChunkTakeUnchecked, *};
@@ -40,6 +41,7 @@ pub(crate) mod logical;
 pub mod object;
 #[cfg(feature = "random")]
 mod random;
+mod statistics;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 #[cfg(any(
@@ -56,6 +58,7 @@ use arrow::legacy::prelude::*;
 pub use struct_::StructChunked;
 
 use self::flags::{StatisticsFlags, StatisticsFlagsIM};
+use self::statistics::StatisticsCache;
 use crate::series::IsSorted;
 use crate::utils::{first_non_null, first_null, last_non_null};
 
@@ -140,6 +143,7 @@ pub struct ChunkedArray<T: PolarsDataType> {
     pub(crate) chunks: Vec<ArrayRef>,
 
     pub(crate) flags: StatisticsFlagsIM,
+    statistics: StatisticsCache,
 
     length: usize,
     null_count: usize,
@@ -204,6 +208,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
             field,
             chunks,
             flags: StatisticsFlagsIM::empty(),
+            statistics: StatisticsCache::default(),
 
             _pd: Default::default(),
             length,
@@ -470,6 +475,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     /// And the `null_count` remains correct.
     #[inline]
     pub unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.statistics.clear();
         &mut self.chunks
     }
 
@@ -492,7 +498,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     }
 
     pub(crate) unsafe fn set_dtype(&mut self, dtype: DataType) {
-        self.field = Arc::new(Field::new(self.name().clone(), dtype))
+        self.field = Arc::new(self.field.as_ref().clone().with_dtype(dtype))
     }
 
     /// Name of the [`ChunkedArray`].
@@ -507,7 +513,17 @@ impl<T: PolarsDataType> ChunkedArray<T> {
 
     /// Rename this [`ChunkedArray`].
     pub fn rename(&mut self, name: PlSmallStr) {
-        self.field = Arc::new(Field::new(name, self.field.dtype().clone()));
+        self.field = Arc::new(self.field.as_ref().clone().with_name(name));
+    }
+
+    /// Get the custom key-value metadata of this [`ChunkedArray`].
+    pub fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.field.metadata()
+    }
+
+    /// Set the custom key-value metadata of this [`ChunkedArray`].
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        Arc::make_mut(&mut self.field).set_metadata(metadata);
     }
 
     /// Return this [`ChunkedArray`] with a new name.
@@ -969,6 +985,7 @@ impl<T: PolarsDataType> Clone for ChunkedArray<T> {
             field: self.field.clone(),
             chunks: self.chunks.clone(),
             flags: self.flags.clone(),
+            statistics: self.statistics.clone(),
 
             _pd: Default::default(),
             length: self.length,
@@ -1042,6 +1059,7 @@ impl<T: PolarsDataType> Default for ChunkedArray<T> {
             // Invariant: always has 1 chunk.
             chunks: vec![new_empty_array(arrow_dtype)],
             flags: StatisticsFlagsIM::empty(),
+            statistics: StatisticsCache::default(),
 
             _pd: Default::default(),
             length: 0,
@@ -38,6 +38,8 @@ pub trait PolarsObjectSafe: Any + Debug + Send + Sync + Display {
     fn to_boxed(&self) -> Box<dyn PolarsObjectSafe>;
 
     fn equal(&self, other: &dyn PolarsObjectSafe) -> bool;
+
+    fn estimated_size(&self) -> usize;
 }
 
 impl PartialEq for &dyn PolarsObjectSafe {
@@ -52,6 +54,12 @@ pub trait PolarsObject:
 {
     /// This should be used as type information. Consider this a part of the type system.
     fn type_name() -> &'static str;
+
+    /// Estimated size in bytes of the memory owned by this value outside of its slot in the
+    /// array, e.g. the heap allocation of a foreign object it points to.
+    fn estimated_size(&self) -> usize {
+        0
+    }
 }
 
 impl<T: PolarsObject> PolarsObjectSafe for T {
@@ -73,6 +81,10 @@ impl<T: PolarsObject> PolarsObjectSafe for T {
         };
         self == other
     }
+
+    fn estimated_size(&self) -> usize {
+        PolarsObject::estimated_size(self)
+    }
 }
 
 pub type ObjectValueIter<'a, T> = std::slice::Iter<'a, T>;
@@ -156,6 +156,7 @@ where
             .checked_add(other.length)
             .ok_or_else(|| polars_err!(ComputeError: LENGTH_LIMIT_MSG))?;
         self.null_count += other.null_count;
+        self.statistics.clear();
         new_chunks(&mut self.chunks, &other.chunks, len);
         Ok(())
     }
@@ -171,6 +172,7 @@ where
             .checked_add(other.length)
             .ok_or_else(|| polars_err!(ComputeError: LENGTH_LIMIT_MSG))?;
         self.null_count += other.null_count;
+        self.statistics.clear();
         new_chunks_owned(&mut self.chunks, std::mem::take(&mut other.chunks), len);
         Ok(())
     }
@@ -184,7 +186,7 @@ impl ListChunked {
 
     pub fn append_owned(&mut self, mut other: Self) -> PolarsResult<()> {
         let dtype = merge_dtypes(self.dtype(), other.dtype())?;
-        self.field = Arc::new(Field::new(self.name().clone(), dtype));
+        self.field = Arc::new(self.field.as_ref().clone().with_dtype(dtype));
 
         let len = self.len();
         self.length = self
@@ -192,6 +194,7 @@ impl ListChunked {
             .checked_add(other.length)
             .ok_or_else(|| polars_err!(ComputeError: LENGTH_LIMIT_MSG))?;
         self.null_count += other.null_count;
+        self.statistics.clear();
         self.set_sorted_flag(IsSorted::Not);
         if !other.get_fast_explode_list() {
             self.unset_fast_explode_list()
@@ -211,7 +214,7 @@ impl ArrayChunked {
 
     pub fn append_owned(&mut self, mut other: Self) -> PolarsResult<()> {
         let dtype = merge_dtypes(self.dtype(), other.dtype())?;
-        self.field = Arc::new(Field::new(self.name().clone(), dtype));
+        self.field = Arc::new(self.field.as_ref().clone().with_dtype(dtype));
 
         let len = self.len();
 
@@ -220,6 +223,7 @@ impl ArrayChunked {
             .checked_add(other.length)
             .ok_or_else(|| polars_err!(ComputeError: LENGTH_LIMIT_MSG))?;
         self.null_count += other.null_count;
+        self.statistics.clear();
 
         self.set_sorted_flag(IsSorted::Not);
 
@@ -237,7 +241,7 @@ impl StructChunked {
 
     pub fn append_owned(&mut self, mut other: Self) -> PolarsResult<()> {
         let dtype = merge_dtypes(self.dtype(), other.dtype())?;
-        self.field = Arc::new(Field::new(self.name().clone(), dtype));
+        self.field = Arc::new(self.field.as_ref().clone().with_dtype(dtype));
 
         let len = self.len();
 
@@ -246,6 +250,7 @@ impl StructChunked {
             .checked_add(other.length)
             .ok_or_else(|| polars_err!(ComputeError: LENGTH_LIMIT_MSG))?;
         self.null_count += other.null_count;
+        self.statistics.clear();
 
         self.set_sorted_flag(IsSorted::Not);
 
@@ -282,6 +287,7 @@ impl<T: PolarsObject> ObjectChunked<T> {
             .checked_add(other.length)
             .ok_or_else(|| polars_err!(ComputeError: LENGTH_LIMIT_MSG))?;
         self.null_count += other.null_count;
+        self.statistics.clear();
         self.set_sorted_flag(IsSorted::Not);
 
         new_chunks_owned(&mut self.chunks, std::mem::take(&mut other.chunks), len);
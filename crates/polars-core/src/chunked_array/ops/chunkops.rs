@@ -152,6 +152,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
             panic!("{}", LENGTH_LIMIT_MSG);
         }
         self.length = len;
+        self.statistics.clear();
         self.null_count = self
             .chunks
             .iter()
@@ -379,4 +380,35 @@ mod test {
         let a = a.rechunk();
         assert!(a.cat32().unwrap().get_mapping().num_cats_upper_bound() > 0);
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_categorical_ops_share_mapping() -> PolarsResult<()> {
+        let cats = Categories::new(
+            PlSmallStr::from_static("test_categorical_ops_share_mapping"),
+            PlSmallStr::EMPTY,
+            CategoricalPhysical::U32,
+        );
+        let dtype = DataType::from_categories(cats);
+        let s = Series::new(PlSmallStr::EMPTY, &["foo", "bar", "spam"]).cast(&dtype)?;
+        let other = Series::new(PlSmallStr::EMPTY, &["bar", "foo"]).cast(&dtype)?;
+        let mapping = s.cat32()?.get_mapping().clone();
+        let num_cats = mapping.num_cats_upper_bound();
+
+        let mask = BooleanChunked::new(PlSmallStr::EMPTY, &[true, false, true]);
+        let mut out = s.take_slice(&[2, 0])?;
+        out.append(&s.filter(&mask)?)?;
+        out.append(&other)?;
+
+        // The category ids are reused as is, no categories are added to the mapping.
+        assert!(Arc::ptr_eq(out.cat32()?.get_mapping(), &mapping));
+        assert_eq!(mapping.num_cats_upper_bound(), num_cats);
+        let ids = out
+            .cat32()?
+            .physical()
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(ids, [2, 0, 0, 2, 1, 0]);
+        Ok(())
+    }
 }
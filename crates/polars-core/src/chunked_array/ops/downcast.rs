@@ -89,6 +89,7 @@ impl<T: PolarsDataType> ChunkedArray<T> {
     /// * The dtype remains the same.
     #[inline]
     pub unsafe fn downcast_iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T::Array> {
+        self.statistics.clear();
         self.chunks.iter_mut().map(|arr| {
             // SAFETY: T::Array guarantees this is correct.
             let arr = &mut **arr;
@@ -86,6 +86,68 @@ where
         self.compute_len();
         Ok(())
     }
+
+    /// Append `values` to the last chunk of this [`ChunkedArray`].
+    ///
+    /// If the last chunk's memory isn't shared, the values are written into its buffer in place.
+    /// Otherwise the last chunk is copied once into a new buffer that later calls can grow. No
+    /// chunks are added either way, so many small appends neither fragment the array nor need an
+    /// `O(n)` [`rechunk`](Self::rechunk).
+    pub fn extend_from_slice(&mut self, values: &[T::Native]) {
+        self.extend_last_chunk(values.len(), |mutable| mutable.extend_from_slice(values));
+    }
+
+    /// Append optional `values` to the last chunk of this [`ChunkedArray`].
+    ///
+    /// See [`ChunkedArray::extend_from_slice`].
+    pub fn extend_values<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = Option<T::Native>>,
+    {
+        let values = values.into_iter();
+        self.extend_last_chunk(values.size_hint().0, |mutable| mutable.extend(values));
+    }
+
+    fn extend_last_chunk<F>(&mut self, additional: usize, f: F)
+    where
+        F: FnOnce(&mut MutablePrimitiveArray<T::Native>),
+    {
+        let mut mutable = match self.chunks.pop() {
+            None => {
+                MutablePrimitiveArray::with_capacity_from(additional, T::Native::PRIMITIVE.into())
+            },
+            Some(chunk) => {
+                // Drop the chunk after taking an owned array, so that its buffers can be unique.
+                let arr = chunk
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<T::Native>>()
+                    .unwrap()
+                    .clone();
+                drop(chunk);
+
+                match arr.into_mut() {
+                    Either::Right(mutable) => mutable,
+                    Either::Left(arr) => {
+                        let mut mutable = MutablePrimitiveArray::with_capacity_from(
+                            arr.len() + additional,
+                            arr.dtype().clone(),
+                        );
+                        mutable.extend_trusted_len(arr.iter());
+                        mutable
+                    },
+                }
+            },
+        };
+
+        let old_len = mutable.len();
+        f(&mut mutable);
+        if mutable.len() != old_len {
+            self.set_sorted_flag(IsSorted::Not);
+        }
+        let arr: PrimitiveArray<T::Native> = mutable.into();
+        self.chunks.push(Box::new(arr) as ArrayRef);
+        self.compute_len();
+    }
 }
 
 #[doc(hidden)]
@@ -225,6 +287,42 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_extend_from_slice() {
+        let mut values = Vec::with_capacity(32);
+        values.extend_from_slice(&[1, 2, 3]);
+        let mut ca = Int32Chunked::from_vec(PlSmallStr::from_static("a"), values);
+        let location = ca.cont_slice().unwrap().as_ptr();
+
+        // The buffer is unique, so the values are written in place.
+        ca.extend_from_slice(&[4, 5]);
+        assert_eq!(ca.cont_slice().unwrap().as_ptr(), location);
+        assert_eq!(ca.cont_slice().unwrap(), [1, 2, 3, 4, 5]);
+
+        // A shared buffer is copied, but no chunk is added.
+        let other = Int32Chunked::new(PlSmallStr::from_static("a"), &[6]);
+        ca.append(&other).unwrap();
+        let shared = ca.clone();
+        ca.extend_values([None, Some(7)]);
+        assert_eq!(ca.chunks().len(), 2);
+        assert_eq!(ca.null_count(), 1);
+        assert_eq!(shared.len(), 6);
+        let vals = ca.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            vals,
+            [
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+                None,
+                Some(7)
+            ]
+        );
+    }
+
     #[test]
     fn test_extend_string() -> PolarsResult<()> {
         let mut ca = StringChunked::new(PlSmallStr::from_static("a"), &["a", "b", "c"]);
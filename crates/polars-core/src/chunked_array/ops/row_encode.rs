@@ -317,3 +317,240 @@ pub fn row_encoding_decode(
         )
     })
 }
+
+/// Version of the order-preserving row encoding produced by [`encode_rows_ordered`].
+///
+/// Keys encoded with the same version decode to the same values and compare the same across
+/// Polars releases, so they can be persisted, e.g. as keys in an external key-value store. Any
+/// change to the byte layout of the encoding bumps this version.
+pub const ROW_ENCODING_VERSION: u32 = 1;
+
+fn check_row_encoding_args(
+    dtypes: &mut dyn Iterator<Item = &DataType>,
+    num_columns: usize,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<()> {
+    polars_ensure!(num_columns > 0, InvalidOperation: "row encoding needs at least one column");
+    polars_ensure!(
+        descending.len() == num_columns && nulls_last.len() == num_columns,
+        ShapeMismatch: "the length of `descending` ({}) and `nulls_last` ({}) must match the number of columns ({})",
+        descending.len(), nulls_last.len(), num_columns
+    );
+    for dtype in dtypes {
+        polars_ensure!(
+            !dtype.contains_objects() && !dtype.contains_unknown(),
+            InvalidOperation: "row encoding is not supported for dtype '{}'", dtype
+        );
+    }
+    Ok(())
+}
+
+/// Encode the rows of `by` to binary keys of which the bytewise (lexicographic) order equals the
+/// order of sorting by `by` with the given `descending` and `nulls_last` per column.
+///
+/// The keys can be decoded back with [`decode_rows_ordered`] given the same schema and options.
+/// The format is stable for a given [`ROW_ENCODING_VERSION`].
+pub fn encode_rows_ordered(
+    name: PlSmallStr,
+    by: &[Column],
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<BinaryChunked> {
+    check_row_encoding_args(
+        &mut by.iter().map(|c| c.dtype()),
+        by.len(),
+        descending,
+        nulls_last,
+    )?;
+    let height = by[0].len();
+    polars_ensure!(
+        by.iter().all(|c| c.len() == height),
+        ShapeMismatch: "all columns to row encode must have the same length"
+    );
+
+    let rows = _get_rows_encoded_ca(name, by, descending, nulls_last, false)?;
+    Ok(rows.cast(&DataType::Binary)?.binary()?.clone())
+}
+
+/// Decode binary keys created by [`encode_rows_ordered`] back to the columns in `schema`.
+///
+/// The `schema`, `descending` and `nulls_last` must be the same as the ones used to encode the
+/// keys. Null keys are not allowed.
+#[cfg(feature = "dtype-struct")]
+pub fn decode_rows_ordered(
+    keys: &BinaryChunked,
+    schema: &Schema,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<DataFrame> {
+    check_row_encoding_args(
+        &mut schema.iter_values(),
+        schema.len(),
+        descending,
+        nulls_last,
+    )?;
+    polars_ensure!(
+        !keys.has_nulls(),
+        ComputeError: "cannot decode null row encoded keys"
+    );
+
+    let keys = keys.cast(&DataType::BinaryOffset)?;
+    let fields = schema.iter_fields().collect::<Vec<_>>();
+    let opts = descending
+        .iter()
+        .zip(nulls_last)
+        .map(|(d, n)| RowEncodingOptions::new_sorted(*d, *n))
+        .collect::<Vec<_>>();
+    let ca = row_encoding_decode(keys.binary_offset()?, &fields, &opts)?;
+    Ok(ca.unnest())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_row_encoding_roundtrip() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1i32), None, Some(-1)],
+            "b" => [Some("x"), Some("y"), None],
+        ]?;
+        for (descending, nulls_last) in [(false, false), (true, false), (false, true)] {
+            let descending = [descending; 2];
+            let nulls_last = [nulls_last; 2];
+            let keys =
+                encode_rows_ordered(PlSmallStr::EMPTY, df.columns(), &descending, &nulls_last)?;
+            let decoded = decode_rows_ordered(&keys, df.schema(), &descending, &nulls_last)?;
+            assert!(decoded.equals_missing(&df));
+        }
+        Ok(())
+    }
+
+    fn assert_keys(
+        by: &[Column],
+        descending: &[bool],
+        nulls_last: &[bool],
+        expected: &[&[u8]],
+    ) -> PolarsResult<()> {
+        let keys = encode_rows_ordered(PlSmallStr::EMPTY, by, descending, nulls_last)?;
+        let keys = keys.into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(keys, expected);
+        Ok(())
+    }
+
+    // The bytes of the encoding must not change without bumping `ROW_ENCODING_VERSION`.
+    #[test]
+    fn test_row_encoding_stable_format() -> PolarsResult<()> {
+        let c = Column::new("a".into(), [Some(1i32), None]);
+        assert_keys(
+            &[c.clone()],
+            &[false],
+            &[false],
+            &[&[0x01, 0x80, 0x00, 0x00, 0x01], &[0x00, 0x00, 0x00, 0x00, 0x00]],
+        )?;
+        assert_keys(
+            &[c],
+            &[true],
+            &[true],
+            &[&[0x01, 0x7F, 0xFF, 0xFF, 0xFE], &[0xFF, 0x00, 0x00, 0x00, 0x00]],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_row_encoding_stable_format_strings() -> PolarsResult<()> {
+        // Every byte is shifted by 2 and the string is terminated, inverted if descending.
+        let c = Column::new("s".into(), [Some("ab"), Some(""), None]);
+        assert_keys(
+            &[c.clone()],
+            &[false],
+            &[false],
+            &[&[0x63, 0x64, 0x01], &[0x01], &[0x00]],
+        )?;
+        assert_keys(
+            &[c.clone()],
+            &[true],
+            &[true],
+            &[&[0x9C, 0x9B, 0xFE], &[0xFE], &[0xFF]],
+        )?;
+
+        // Multiple columns are concatenated, each with its own order.
+        let a = Column::new("a".into(), [1i32]);
+        assert_keys(
+            &[a, c.head(Some(1))],
+            &[false, true],
+            &[false, false],
+            &[&[0x01, 0x80, 0x00, 0x00, 0x01, 0x9C, 0x9B, 0xFE]],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_row_encoding_stable_format_lists() -> PolarsResult<()> {
+        // Every element is preceded by a continuation token and the list is terminated. The
+        // elements are encoded with nulls last if descending.
+        let c = ListChunked::from_iter([
+            Some(Series::new("".into(), [1i32, 2])),
+            Some(Series::new_empty("".into(), &DataType::Int32)),
+            None,
+        ])
+        .with_name("l".into())
+        .into_column();
+        assert_keys(
+            &[c.clone()],
+            &[false],
+            &[false],
+            &[
+                &[
+                    0xFE, 0x01, 0x80, 0x00, 0x00, 0x01, 0xFE, 0x01, 0x80, 0x00, 0x00, 0x02, 0x01,
+                ],
+                &[0x01],
+                &[0x00],
+            ],
+        )?;
+        assert_keys(
+            &[c],
+            &[true],
+            &[true],
+            &[
+                &[
+                    0x01, 0x01, 0x7F, 0xFF, 0xFF, 0xFE, 0x01, 0x01, 0x7F, 0xFF, 0xFF, 0xFD, 0xFE,
+                ],
+                &[0xFE],
+                &[0xFF],
+            ],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_row_encoding_stable_format_structs() -> PolarsResult<()> {
+        // A validity byte is followed by the fields, which are encoded with nulls last if
+        // descending.
+        let a = Column::new("a".into(), [Some(1i32), None]);
+        let b = Column::new("b".into(), [Some("x"), None]);
+        let c = StructChunked::from_columns("st".into(), 2, &[a, b])?.into_column();
+        assert_keys(
+            &[c.clone()],
+            &[false],
+            &[false],
+            &[
+                &[0x01, 0x01, 0x80, 0x00, 0x00, 0x01, 0x7A, 0x01],
+                &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            ],
+        )?;
+        assert_keys(
+            &[c],
+            &[true],
+            &[false],
+            &[
+                &[0x01, 0x01, 0x7F, 0xFF, 0xFF, 0xFE, 0x85, 0xFE],
+                &[0x01, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF],
+            ],
+        )?;
+        Ok(())
+    }
+}
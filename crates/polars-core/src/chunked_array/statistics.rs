@@ -0,0 +1,341 @@
+//! Lazily computed statistics that are cached on a [`ChunkedArray`].
+//!
+//! The cache is cleared whenever the values of the array may change, which is every time the
+//! chunks are accessed mutably or appended to.
+use std::sync::{Arc, OnceLock};
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+use crate::with_match_physical_numeric_polars_type;
+
+#[derive(Clone, Default)]
+pub(crate) struct StatisticsCache {
+    /// `None` inside the lock means that there are no non-null values, or that the values can't be
+    /// bounded by short statistics.
+    min_max: OnceLock<Option<Arc<(Scalar, Scalar)>>>,
+}
+
+impl StatisticsCache {
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.min_max.take();
+    }
+}
+
+/// Upper bound on the length in bytes of cached string and binary statistics.
+///
+/// Longer values are truncated to a prefix, which for the maximum is incremented to remain an
+/// upper bound.
+const MAX_STATISTICS_LEN: usize = 64;
+
+fn truncate_min(value: &AnyValue) -> Option<AnyValue<'static>> {
+    Some(match value {
+        AnyValue::String(_) | AnyValue::StringOwned(_) => {
+            let v = value.extract_str().unwrap();
+            if v.len() <= MAX_STATISTICS_LEN {
+                return None;
+            }
+            let mut end = MAX_STATISTICS_LEN;
+            while !v.is_char_boundary(end) {
+                end -= 1;
+            }
+            AnyValue::StringOwned(v[..end].into())
+        },
+        AnyValue::Binary(_) | AnyValue::BinaryOwned(_) => {
+            let v = value.extract_bytes().unwrap();
+            if v.len() <= MAX_STATISTICS_LEN {
+                return None;
+            }
+            AnyValue::BinaryOwned(v[..MAX_STATISTICS_LEN].to_vec())
+        },
+        _ => return None,
+    })
+}
+
+/// Returns `Ok(None)` if the value doesn't need to be truncated and `Err(())` if there is no
+/// short upper bound.
+fn truncate_max(value: &AnyValue) -> Result<Option<AnyValue<'static>>, ()> {
+    match value {
+        AnyValue::String(_) | AnyValue::StringOwned(_) => {
+            let v = value.extract_str().unwrap();
+            if v.len() <= MAX_STATISTICS_LEN {
+                return Ok(None);
+            }
+            let mut prefix: Vec<char> = Vec::new();
+            let mut len = 0;
+            for c in v.chars() {
+                len += c.len_utf8();
+                if len > MAX_STATISTICS_LEN {
+                    break;
+                }
+                prefix.push(c);
+            }
+            while let Some(c) = prefix.pop() {
+                if let Some(next) = char::from_u32(c as u32 + 1) {
+                    prefix.push(next);
+                    let max: String = prefix.into_iter().collect();
+                    return Ok(Some(AnyValue::StringOwned(max.into())));
+                }
+            }
+            Err(())
+        },
+        AnyValue::Binary(_) | AnyValue::BinaryOwned(_) => {
+            let v = value.extract_bytes().unwrap();
+            if v.len() <= MAX_STATISTICS_LEN {
+                return Ok(None);
+            }
+            let mut prefix = v[..MAX_STATISTICS_LEN].to_vec();
+            while let Some(b) = prefix.pop() {
+                if b < u8::MAX {
+                    prefix.push(b + 1);
+                    return Ok(Some(AnyValue::BinaryOwned(prefix)));
+                }
+            }
+            Err(())
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Bound the size of the cached statistics, or `None` if they can't be bounded.
+fn bound_min_max(min: Scalar, max: Scalar) -> Option<Arc<(Scalar, Scalar)>> {
+    if min.is_null() || max.is_null() || min.value().is_nan() || max.value().is_nan() {
+        return None;
+    }
+    let min = match truncate_min(min.value()) {
+        Some(v) => Scalar::new(min.dtype().clone(), v),
+        None => min,
+    };
+    let max = match truncate_max(max.value()).ok()? {
+        Some(v) => Scalar::new(max.dtype().clone(), v),
+        None => max,
+    };
+    Some(Arc::new((min, max)))
+}
+
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: IntoSeries,
+{
+    /// The minimum and maximum non-null value, or `None` if there are no non-null values.
+    ///
+    /// NaN values are ignored, like in the statistics written to Parquet. Long strings and binary
+    /// values are truncated to a prefix that still bounds the values. The result is computed on
+    /// first use and cached until the array is mutated.
+    pub fn min_max_statistics(&self) -> Option<(Scalar, Scalar)> {
+        if let Some(min_max) = self.cached_min_max_statistics() {
+            return Some(min_max);
+        }
+        self.statistics
+            .min_max
+            .get_or_init(|| {
+                if self.null_count() == self.len() {
+                    return None;
+                }
+                let s = self.clone().into_series();
+                let min = s.min_reduce().ok()?;
+                let max = s.max_reduce().ok()?;
+                bound_min_max(min, max)
+            })
+            .as_deref()
+            .cloned()
+    }
+
+    /// The minimum and maximum non-null value if they are known without scanning the values.
+    ///
+    /// That is if they were computed by [`ChunkedArray::min_max_statistics`] before, or if the
+    /// array is sorted.
+    pub fn cached_min_max_statistics(&self) -> Option<(Scalar, Scalar)> {
+        if let Some(min_max) = self.statistics.min_max.get() {
+            return min_max.as_deref().cloned();
+        }
+        let (first, last) = match self.is_sorted_flag() {
+            IsSorted::Ascending => (self.first_non_null()?, self.last_non_null()?),
+            IsSorted::Descending => (self.last_non_null()?, self.first_non_null()?),
+            IsSorted::Not => return None,
+        };
+        let s = self.clone().into_series();
+        let value = |idx| {
+            s.get(idx)
+                .ok()
+                .map(|av| Scalar::new(s.dtype().clone(), av.into_static()))
+        };
+        // NaN is sorted as the largest value, so it can't be ignored here.
+        let min_max = bound_min_max(value(first)?, value(last)?)?;
+        self.statistics
+            .min_max
+            .get_or_init(|| Some(min_max))
+            .as_deref()
+            .cloned()
+    }
+}
+
+/// Apply `f` to the physical [`ChunkedArray`] of `s` and cast the result back to the data type
+/// of `s`.
+fn series_min_max(
+    s: &Series,
+    f: impl Fn(&dyn MinMaxStatistics) -> Option<(Scalar, Scalar)>,
+) -> Option<(Scalar, Scalar)> {
+    let dtype = s.dtype();
+    let (min, max) = match dtype {
+        dt if dt.is_primitive_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref();
+                f(ca)
+            })
+        },
+        DataType::Boolean => f(s.bool().unwrap()),
+        DataType::String => f(s.str().unwrap()),
+        DataType::Binary => f(s.binary().unwrap()),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => f(s.date().unwrap().physical()),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, _) => f(s.datetime().unwrap().physical()),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(_) => f(s.duration().unwrap().physical()),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => f(s.time().unwrap().physical()),
+        _ => None,
+    }?;
+
+    // Logical types are cached as their physical values.
+    let min = min.cast_with_options(dtype, CastOptions::Strict).ok()?;
+    let max = max.cast_with_options(dtype, CastOptions::Strict).ok()?;
+    Some((min, max))
+}
+
+trait MinMaxStatistics {
+    fn min_max_statistics(&self) -> Option<(Scalar, Scalar)>;
+    fn cached_min_max_statistics(&self) -> Option<(Scalar, Scalar)>;
+}
+
+impl<T: PolarsDataType> MinMaxStatistics for ChunkedArray<T>
+where
+    ChunkedArray<T>: IntoSeries,
+{
+    fn min_max_statistics(&self) -> Option<(Scalar, Scalar)> {
+        ChunkedArray::min_max_statistics(self)
+    }
+
+    fn cached_min_max_statistics(&self) -> Option<(Scalar, Scalar)> {
+        ChunkedArray::cached_min_max_statistics(self)
+    }
+}
+
+impl Series {
+    /// The minimum and maximum non-null value, or `None` if there are no non-null values or the
+    /// data type has no supported ordering.
+    ///
+    /// The statistics are cached on the underlying [`ChunkedArray`], see
+    /// [`ChunkedArray::min_max_statistics`].
+    pub fn min_max_statistics(&self) -> Option<(Scalar, Scalar)> {
+        series_min_max(self, |ca| ca.min_max_statistics())
+    }
+
+    /// The minimum and maximum non-null value if they are known without scanning the values, see
+    /// [`ChunkedArray::cached_min_max_statistics`].
+    pub fn cached_min_max_statistics(&self) -> Option<(Scalar, Scalar)> {
+        series_min_max(self, |ca| ca.cached_min_max_statistics())
+    }
+}
+
+impl Column {
+    /// The minimum and maximum non-null value, see [`Series::min_max_statistics`].
+    pub fn min_max_statistics(&self) -> Option<(Scalar, Scalar)> {
+        match self {
+            Column::Series(s) => s.min_max_statistics(),
+            Column::Scalar(_) => self.cached_min_max_statistics(),
+        }
+    }
+
+    /// The minimum and maximum non-null value if they are known without scanning the values, see
+    /// [`Series::cached_min_max_statistics`].
+    pub fn cached_min_max_statistics(&self) -> Option<(Scalar, Scalar)> {
+        match self {
+            Column::Series(s) => s.cached_min_max_statistics(),
+            Column::Scalar(s) => {
+                let scalar = s.scalar();
+                (!s.is_empty() && !scalar.is_null()).then(|| (scalar.clone(), scalar.clone()))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_min_max_statistics() {
+        let mut ca = Int32Chunked::new("a".into(), [Some(3), None, Some(1)]);
+        let (min, max) = ca.min_max_statistics().unwrap();
+        assert_eq!(
+            (min.value(), max.value()),
+            (&AnyValue::Int32(1), &AnyValue::Int32(3))
+        );
+
+        // Mutations invalidate the cache.
+        ca.append(&Int32Chunked::new("a".into(), [10])).unwrap();
+        let (_, max) = ca.min_max_statistics().unwrap();
+        assert_eq!(max.value(), &AnyValue::Int32(10));
+        ca.apply_mut(|v| -v);
+        let (min, max) = ca.min_max_statistics().unwrap();
+        assert_eq!(
+            (min.value(), max.value()),
+            (&AnyValue::Int32(-10), &AnyValue::Int32(-1))
+        );
+
+        let ca = Float64Chunked::new("a".into(), [Some(f64::NAN), None, Some(2.0)]);
+        let (min, max) = ca.min_max_statistics().unwrap();
+        assert_eq!(
+            (min.value(), max.value()),
+            (&AnyValue::Float64(2.0), &AnyValue::Float64(2.0))
+        );
+
+        let ca = Int32Chunked::full_null("a".into(), 2);
+        assert!(ca.min_max_statistics().is_none());
+    }
+
+    #[test]
+    fn test_cached_min_max_statistics() {
+        let mut ca = Int32Chunked::new("a".into(), [None, Some(1), Some(3)]);
+        assert!(ca.cached_min_max_statistics().is_none());
+        ca.min_max_statistics().unwrap();
+        assert!(ca.cached_min_max_statistics().is_some());
+
+        // Sorted arrays don't have to be scanned.
+        let mut ca = Int32Chunked::new("a".into(), [Some(3), Some(2), None]);
+        ca.set_sorted_flag(IsSorted::Descending);
+        let (min, max) = ca.cached_min_max_statistics().unwrap();
+        assert_eq!(
+            (min.value(), max.value()),
+            (&AnyValue::Int32(2), &AnyValue::Int32(3))
+        );
+
+        // NaN can't be ignored without scanning.
+        let mut ca = Float64Chunked::new("a".into(), [1.0, f64::NAN]);
+        ca.set_sorted_flag(IsSorted::Ascending);
+        assert!(ca.cached_min_max_statistics().is_none());
+    }
+
+    #[test]
+    fn test_min_max_statistics_bounded() {
+        let long = "a".repeat(100);
+        let ca = StringChunked::new("a".into(), [long.as_str(), "b"]);
+        let (min, max) = ca.min_max_statistics().unwrap();
+        let (min, max) = (min.value().extract_str().unwrap(), max.value().extract_str().unwrap());
+        assert_eq!(min, &long[..MAX_STATISTICS_LEN]);
+        assert_eq!(max, "b");
+
+        let long = "\u{10FFFF}".repeat(20);
+        let ca = StringChunked::new("a".into(), ["b", long.as_str()]);
+        assert!(ca.min_max_statistics().is_none());
+
+        let long = format!("{}b", "a".repeat(100));
+        let ca = StringChunked::new("a".into(), ["a", long.as_str()]);
+        let (_, max) = ca.min_max_statistics().unwrap();
+        let max = max.value().extract_str().unwrap();
+        assert_eq!(max, format!("{}b", "a".repeat(MAX_STATISTICS_LEN - 1)));
+        assert!(max > long.as_str());
+    }
+}
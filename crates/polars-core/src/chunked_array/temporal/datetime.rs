@@ -59,7 +59,7 @@ impl DatetimeChunked {
         let mut ca: StringChunked = match self.time_zone() {
             #[cfg(feature = "timezones")]
             Some(time_zone) => {
-                let parsed_time_zone = time_zone.parse::<Tz>().expect("already validated");
+                let parsed_time_zone = time_zone.parse::<Tz>()?;
                 let datefmt_f = |ndt| parsed_time_zone.from_utc_datetime(&ndt).format(&format);
                 self.physical().try_apply_into_string_amortized(|val, buf| {
                     let ndt = conversion_f(val);
@@ -9,14 +9,14 @@ mod duration;
 #[cfg(feature = "dtype-time")]
 mod time;
 
+#[cfg(feature = "timezones")]
+use arrow::legacy::time_zone::Tz;
 #[cfg(feature = "dtype-date")]
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
 #[cfg(any(feature = "dtype-time", feature = "dtype-date"))]
 use chrono::NaiveTime;
 #[cfg(feature = "timezones")]
-use chrono_tz::Tz;
-#[cfg(feature = "timezones")]
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "dtype-time")]
 pub use time::time_to_time64ns;
@@ -1723,7 +1723,9 @@ impl From<DateTime<chrono_tz::Tz>> for AnyValue<'static> {
         AnyValue::DatetimeOwned(
             value.timestamp_nanos_opt().unwrap(),
             TimeUnit::Nanoseconds,
-            Some(Arc::from(TimeZone::from_chrono(&value.timezone()))),
+            Some(Arc::from(TimeZone::from_chrono(&arrow::legacy::time_zone::Tz::Bundled(
+                value.timezone(),
+            )))),
         )
     }
 }
@@ -460,17 +460,17 @@ impl DataType {
             (D::Array(from, l_width), D::Array(to, r_width)) => {
                 l_width == r_width && from.can_cast_to(to)?
             },
+            #[cfg(feature = "dtype-array")]
+            (D::List(from), D::Array(to, _)) | (D::Array(from, _), D::List(to)) => {
+                from.can_cast_to(to)?
+            },
             #[cfg(feature = "dtype-struct")]
             (D::Struct(l_fields), D::Struct(r_fields)) => {
-                if l_fields.is_empty() {
-                    return Some(true);
-                }
-
-                if l_fields.len() != r_fields.len() {
-                    return Some(false);
-                }
-
-                for (l, r) in l_fields.iter().zip(r_fields) {
+                // Fields are matched by name, fields missing from `self` become null.
+                for r in r_fields {
+                    let Some(l) = l_fields.iter().find(|l| l.name() == r.name()) else {
+                        continue;
+                    };
                     if !l.dtype().can_cast_to(r.dtype())? {
                         return Some(false);
                     }
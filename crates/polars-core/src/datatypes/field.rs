@@ -1,4 +1,4 @@
-use arrow::datatypes::{IntervalUnit, Metadata};
+use arrow::datatypes::{IntervalUnit, Metadata, is_reserved_metadata_key};
 use polars_dtype::categorical::CategoricalPhysical;
 use polars_error::feature_gated;
 use polars_utils::pl_str::PlSmallStr;
@@ -9,7 +9,10 @@ pub static POLARS_OBJECT_EXTENSION_NAME: &str = "_POLARS_PYTHON_OBJECT";
 pub static ARROW_UUID_EXTENSION_NAME: &str = "arrow.uuid";
 
 /// Characterizes the name and the [`DataType`] of a column.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// A `Field` can carry custom key-value metadata, e.g. the unit or provenance of a column. The
+/// metadata doesn't take part in equality or hashing, and it is stored in IPC and Parquet files.
+#[derive(Clone, Debug)]
 #[cfg_attr(
     any(feature = "serde", feature = "serde-lazy"),
     derive(Serialize, Deserialize)
@@ -18,6 +21,26 @@ pub static ARROW_UUID_EXTENSION_NAME: &str = "arrow.uuid";
 pub struct Field {
     pub name: PlSmallStr,
     pub dtype: DataType,
+    #[cfg_attr(
+        any(feature = "serde", feature = "serde-lazy"),
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub metadata: Option<Arc<Metadata>>,
+}
+
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.dtype == other.dtype
+    }
+}
+
+impl Eq for Field {}
+
+impl Hash for Field {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.dtype.hash(state);
+    }
 }
 
 impl From<Field> for (PlSmallStr, DataType) {
@@ -41,7 +64,11 @@ impl Field {
     /// ```
     #[inline]
     pub fn new(name: PlSmallStr, dtype: DataType) -> Self {
-        Field { name, dtype }
+        Field {
+            name,
+            dtype,
+            metadata: None,
+        }
     }
 
     /// Returns a reference to the `Field` name.
@@ -116,6 +143,41 @@ impl Field {
         self
     }
 
+    /// Returns the custom key-value metadata of the `Field`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_core::utils::arrow::datatypes::Metadata;
+    /// let md = Metadata::from([("unit".into(), "m/s".into())]);
+    /// let f = Field::new("Speed".into(), DataType::Float64).with_metadata(Some(md.into()));
+    ///
+    /// assert_eq!(f.metadata().unwrap().get("unit").unwrap(), "m/s");
+    /// ```
+    #[inline]
+    pub fn metadata(&self) -> Option<&Arc<Metadata>> {
+        self.metadata.as_ref()
+    }
+
+    /// Sets the custom key-value metadata of the `Field`. Empty metadata is stored as `None`.
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.metadata = metadata.filter(|md| !md.is_empty());
+    }
+
+    /// Returns this `Field`, with different custom key-value metadata.
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Self {
+        self.set_metadata(metadata);
+        self
+    }
+
+    /// Merges the custom key-value metadata of `other` into this `Field`.
+    ///
+    /// Keys of `self` take precedence, keys only present in `other` are added.
+    pub fn merge_metadata(&mut self, other: Option<&Arc<Metadata>>) {
+        self.metadata = merge_metadata(self.metadata.as_ref(), other);
+    }
+
     /// Converts the `Field` to an `arrow::datatypes::Field`.
     ///
     /// # Example
@@ -128,17 +190,57 @@ impl Field {
     /// assert_eq!(f.to_arrow(CompatLevel::newest()), af);
     /// ```
     pub fn to_arrow(&self, compat_level: CompatLevel) -> ArrowField {
-        self.dtype.to_arrow_field(self.name.clone(), compat_level)
+        let field = self.dtype.to_arrow_field(self.name.clone(), compat_level);
+        let Some(md) = &self.metadata else {
+            return field;
+        };
+        // The metadata Polars uses to encode the data type takes precedence.
+        let metadata = merge_metadata(field.metadata.as_ref(), Some(md));
+        ArrowField { metadata, ..field }
     }
 
     pub fn to_physical(&self) -> Field {
         Self {
             name: self.name.clone(),
             dtype: self.dtype().to_physical(),
+            metadata: self.metadata.clone(),
         }
     }
 }
 
+/// Left-biased union of two metadata maps, reusing either side if the other adds nothing.
+pub fn merge_metadata(
+    left: Option<&Arc<Metadata>>,
+    right: Option<&Arc<Metadata>>,
+) -> Option<Arc<Metadata>> {
+    match (left, right) {
+        (None, md) | (md, None) => md.cloned(),
+        (Some(l), Some(r)) => {
+            if r.keys().all(|k| l.contains_key(k)) {
+                return Some(l.clone());
+            }
+            let mut merged = (**r).clone();
+            merged.extend(l.iter().map(|(k, v)| (k.clone(), v.clone())));
+            Some(Arc::new(merged))
+        },
+    }
+}
+
+/// The custom key-value metadata of an Arrow field, without the keys Polars uses to encode the
+/// data type.
+pub fn custom_arrow_metadata(field: &ArrowField) -> Option<Arc<Metadata>> {
+    let md = field.metadata.as_ref()?;
+    if !md.keys().any(|k| is_reserved_metadata_key(k)) {
+        return Some(md.clone());
+    }
+    let md = md
+        .iter()
+        .filter(|(k, _)| !is_reserved_metadata_key(k))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<Metadata>();
+    Some(Arc::new(md))
+}
+
 impl AsRef<DataType> for Field {
     fn as_ref(&self) -> &DataType {
         &self.dtype
@@ -318,5 +420,6 @@ impl DataType {
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
         Field::new(f.name.clone(), DataType::from_arrow_field(f))
+            .with_metadata(custom_arrow_metadata(f))
     }
 }
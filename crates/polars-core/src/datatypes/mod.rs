@@ -46,8 +46,8 @@ use polars_compute::arithmetic::HasPrimitiveArithmeticKernel;
 use polars_compute::float_sum::FloatSum;
 #[cfg(feature = "dtype-categorical")]
 pub use polars_dtype::categorical::{
-    CatNative, CatSize, CategoricalMapping, CategoricalPhysical, Categories, FrozenCategories,
-    ensure_same_categories, ensure_same_frozen_categories,
+    CatNative, CatSize, CategoricalMapping, CategoricalPhysical, Categories, CategoriesScope,
+    FrozenCategories, ensure_same_categories, ensure_same_frozen_categories,
 };
 use polars_utils::abs_diff::AbsDiff;
 use polars_utils::float::IsFloat;
@@ -1,3 +1,5 @@
+#[cfg(feature = "timezones")]
+use arrow::legacy::time_zone::Tz;
 use polars_error::{PolarsResult, polars_bail};
 use polars_utils::pl_str::PlSmallStr;
 
@@ -99,7 +101,7 @@ impl TimeZone {
     }
 
     #[cfg(feature = "timezones")]
-    pub fn from_chrono(tz: &chrono_tz::Tz) -> Self {
+    pub fn from_chrono(tz: &Tz) -> Self {
         use polars_utils::format_pl_smallstr;
 
         Self {
@@ -108,7 +110,7 @@ impl TimeZone {
     }
 
     #[cfg(feature = "timezones")]
-    pub fn to_chrono(&self) -> PolarsResult<chrono_tz::Tz> {
+    pub fn to_chrono(&self) -> PolarsResult<Tz> {
         parse_time_zone(self)
     }
 
@@ -213,11 +215,17 @@ fn _levenshtein(a: &str, b: &str) -> usize {
     result
 }
 
-/// Parse a time zone string to [`chrono_tz::Tz`]
+/// Parse a time zone string to a [`Tz`], read from the time zone database configured through
+/// `POLARS_TZDB`.
 #[cfg(feature = "timezones")]
-pub fn parse_time_zone(tz: &str) -> PolarsResult<chrono_tz::Tz> {
-    if let Ok(tz_parsed) = tz.parse::<chrono_tz::Tz>() {
-        return Ok(tz_parsed);
+pub fn parse_time_zone(tz: &str) -> PolarsResult<Tz> {
+    match tz.parse::<Tz>() {
+        Ok(tz_parsed) => return Ok(tz_parsed),
+        // Reading from a directory fails for more reasons than a misspelled name.
+        Err(e) if polars_config::config().tzdb() != polars_config::TzdbSource::Bundled => {
+            return Err(e);
+        },
+        Err(_) => {},
     }
 
     let mut best: Option<(&str, usize)> = None;
@@ -256,7 +264,7 @@ pub fn parse_fixed_offset(tz: &str) -> PolarsResult<PlSmallStr> {
         };
         let hour = caps.name("hour").unwrap().as_str().parse::<i32>().unwrap();
         let etc_tz = format_pl_smallstr!("Etc/GMT{}{}", sign, hour);
-        if etc_tz.parse::<chrono_tz::Tz>().is_ok() {
+        if etc_tz.parse::<Tz>().is_ok() {
             return Ok(etc_tz);
         }
     }
@@ -1249,7 +1249,7 @@ impl Display for PlTzAware<'_> {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         #[cfg(feature = "timezones")]
-        match self.tz.parse::<chrono_tz::Tz>() {
+        match self.tz.parse::<arrow::legacy::time_zone::Tz>() {
             Ok(tz) => {
                 let dt_utc = chrono::Utc.from_local_datetime(&self.ndt).unwrap();
                 let dt_tz_aware = dt_utc.with_timezone(&tz);
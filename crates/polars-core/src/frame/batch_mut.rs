@@ -0,0 +1,114 @@
+use polars_utils::aliases::PlHashMap;
+
+use crate::prelude::*;
+
+/// Batches column additions, replacements, drops and renames on a [`DataFrame`].
+///
+/// Columns are looked up through a name index that is built once, instead of recomputing the
+/// schema of the [`DataFrame`] after every mutation. The mutations are only applied to the
+/// [`DataFrame`] on [`BatchMut::finish`].
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// let mut df = df!("a" => [1, 2, 3], "b" => [4, 5, 6])?;
+///
+/// let mut batch = df.batch_mut();
+/// for i in 0..3 {
+///     batch.with_column(Column::new(format!("c{i}").into(), [i, i, i]))?;
+/// }
+/// batch.drop("a")?;
+/// batch.rename("b", "a".into())?;
+/// batch.finish();
+///
+/// assert_eq!(df.get_column_names(), &["a", "c0", "c1", "c2"]);
+/// # Ok::<(), PolarsError>(())
+/// ```
+pub struct BatchMut<'a> {
+    df: &'a mut DataFrame,
+    height: usize,
+    /// Dropped columns are left as `None` until the batch is finished.
+    columns: Vec<Option<Column>>,
+    index: PlHashMap<PlSmallStr, usize>,
+}
+
+impl<'a> BatchMut<'a> {
+    pub(super) fn new(df: &'a mut DataFrame) -> Self {
+        let columns = df.columns().iter().cloned().map(Some).collect();
+        let index = df
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.name().clone(), i))
+            .collect();
+        Self {
+            height: df.height(),
+            df,
+            columns,
+            index,
+        }
+    }
+
+    /// Add a new column or replace an existing one. Broadcasts unit-length columns.
+    pub fn with_column(&mut self, mut column: Column) -> PolarsResult<&mut Self> {
+        if self.height == 0 && self.index.is_empty() {
+            self.height = column.len();
+        }
+
+        if column.len() != self.height && column.len() == 1 {
+            column = column.new_from_index(0, self.height);
+        }
+
+        polars_ensure!(
+            column.len() == self.height,
+            ShapeMismatch: "unable to add a column of length {} to a DataFrame of height {}",
+            column.len(), self.height,
+        );
+
+        if let Some(&i) = self.index.get(column.name()) {
+            self.columns[i] = Some(column);
+        } else {
+            self.index.insert(column.name().clone(), self.columns.len());
+            self.columns.push(Some(column));
+        }
+        Ok(self)
+    }
+
+    /// Remove a column by name and return it.
+    pub fn drop(&mut self, name: &str) -> PolarsResult<Column> {
+        let i = self
+            .index
+            .remove(name)
+            .ok_or_else(|| polars_err!(col_not_found = name))?;
+        Ok(self.columns[i].take().unwrap())
+    }
+
+    /// Rename a column.
+    pub fn rename(&mut self, column: &str, name: PlSmallStr) -> PolarsResult<&mut Self> {
+        if column == name.as_str() {
+            return Ok(self);
+        }
+        polars_ensure!(
+            !self.index.contains_key(&name),
+            Duplicate: "column rename attempted with already existing name \"{name}\""
+        );
+
+        let i = self
+            .index
+            .remove(column)
+            .ok_or_else(|| polars_err!(col_not_found = column))?;
+        self.columns[i].as_mut().unwrap().rename(name.clone());
+        self.index.insert(name, i);
+        Ok(self)
+    }
+
+    /// Apply the mutations to the [`DataFrame`].
+    pub fn finish(self) -> &'a mut DataFrame {
+        let columns = self.columns.into_iter().flatten().collect();
+        // SAFETY: all columns have length `self.height` and the names are unique because they
+        // are keys of `self.index`.
+        *self.df = unsafe { DataFrame::new_unchecked(self.height, columns) };
+        self.df
+    }
+}
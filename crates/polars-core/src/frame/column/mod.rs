@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use arrow::bitmap::{Bitmap, BitmapBuilder};
+use arrow::datatypes::Metadata;
 use arrow::trusted_len::TrustMyLength;
 use num_traits::{Num, NumCast};
 use polars_compute::rolling::QuantileMethod;
@@ -207,6 +208,21 @@ impl Column {
         }
     }
 
+    /// Get the custom key-value metadata of this column.
+    pub fn metadata(&self) -> Option<Arc<Metadata>> {
+        self.field().metadata.clone()
+    }
+
+    /// Set the custom key-value metadata of this column.
+    ///
+    /// This materializes scalar columns if there is metadata to set.
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        if metadata.is_none() && self.metadata().is_none() {
+            return;
+        }
+        self.into_materialized_series().set_metadata(metadata);
+    }
+
     #[inline]
     pub fn name(&self) -> &PlSmallStr {
         match self {
@@ -1663,6 +1679,14 @@ impl Column {
         self.as_materialized_series().estimated_size()
     }
 
+    pub(crate) fn estimated_size_dedup(
+        &self,
+        seen: &mut arrow::compute::aggregate::SeenBuffers,
+    ) -> usize {
+        // @scalar-opt
+        self.as_materialized_series().estimated_size_dedup(seen)
+    }
+
     pub fn sort_with(&self, options: SortOptions) -> PolarsResult<Self> {
         match self {
             Column::Series(s) => s.sort_with(options).map(Self::from),
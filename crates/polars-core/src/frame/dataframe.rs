@@ -85,7 +85,10 @@ use crate::schema::{Schema, SchemaRef};
 pub struct DataFrame {
     height: usize,
     /// All columns must have length equal to `self.height`.
-    columns: Vec<Column>,
+    ///
+    /// The columns are shared between clones and only copied when mutated. This is `None` for a
+    /// `DataFrame` created in a const context.
+    columns: Option<Arc<Vec<Column>>>,
     /// Cached schema. Must be cleared if column names / dtypes in `self.columns` change.
     cached_schema: OnceLock<SchemaRef>,
 }
@@ -112,7 +115,7 @@ impl DataFrame {
     pub const fn empty_with_height(height: usize) -> Self {
         DataFrame {
             height,
-            columns: vec![],
+            columns: None,
             cached_schema: OnceLock::new(),
         }
     }
@@ -154,10 +157,10 @@ impl DataFrame {
     /// is temporarily constructed containing duplicates for dispatching to functions. A DataFrame
     /// constructed with this method is generally highly unsafe and should not be long-lived.
     #[expect(clippy::missing_safety_doc)]
-    pub const unsafe fn _new_unchecked_impl(height: usize, columns: Vec<Column>) -> DataFrame {
+    pub unsafe fn _new_unchecked_impl(height: usize, columns: Vec<Column>) -> DataFrame {
         DataFrame {
             height,
-            columns,
+            columns: Some(Arc::new(columns)),
             cached_schema: OnceLock::new(),
         }
     }
@@ -230,7 +233,7 @@ impl DataFrame {
     /// Get the number of columns in this [`DataFrame`].
     #[inline]
     pub fn width(&self) -> usize {
-        self.columns.len()
+        self.columns().len()
     }
 
     /// Get (height, width) of the [`DataFrame`].
@@ -262,14 +265,17 @@ impl DataFrame {
 
     #[inline]
     pub fn columns(&self) -> &[Column] {
-        self.columns.as_slice()
+        self.columns.as_deref().map_or(&[], Vec::as_slice)
     }
 
+    /// Returns the columns, cloning them if they are shared with another `DataFrame`.
     #[inline]
     pub fn into_columns(self) -> Vec<Column> {
-        self.columns
+        self.columns.map_or_else(Vec::new, Arc::unwrap_or_clone)
     }
 
+    /// Copies the columns first if they are shared with another `DataFrame`.
+    ///
     /// # Safety
     ///
     /// The caller must ensure the length of all [`Column`]s remains equal to `self.height`, or
@@ -277,7 +283,7 @@ impl DataFrame {
     #[inline]
     pub unsafe fn columns_mut(&mut self) -> &mut Vec<Column> {
         self.clear_schema();
-        &mut self.columns
+        Arc::make_mut(self.columns.get_or_insert_default())
     }
 
     /// # Safety
@@ -285,7 +291,7 @@ impl DataFrame {
     /// names remains unchanged.
     #[inline]
     pub unsafe fn columns_mut_retain_schema(&mut self) -> &mut Vec<Column> {
-        &mut self.columns
+        Arc::make_mut(self.columns.get_or_insert_default())
     }
 
     /// Get the schema of this [`DataFrame`].
@@ -296,7 +302,7 @@ impl DataFrame {
         let out = self.cached_schema.get_or_init(|| {
             Arc::new(
                 Schema::from_iter_check_duplicates(
-                    self.columns
+                    self.columns()
                         .iter()
                         .map(|x| (x.name().clone(), x.dtype().clone())),
                 )
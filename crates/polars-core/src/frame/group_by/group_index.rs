@@ -0,0 +1,229 @@
+#[cfg(feature = "serde")]
+use polars_utils::idx_vec::IdxVec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The groups computed by a [`GroupBy`], detached from the borrowed [`DataFrame`].
+///
+/// Keys are hashed only once, when the [`GroupBy`] is created. A [`GroupIndex`] can be applied
+/// to any [`DataFrame`] with the same rows with [`DataFrame::group_by_index`] to run further
+/// aggregations on the same groups, and can be serialized to reuse the groups later.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// # fn example(df: &DataFrame) -> PolarsResult<()> {
+/// let index = df.group_by(["key"])?.group_index();
+///
+/// // Later, without hashing the keys again.
+/// let groups = df.group_by_index(&index)?.groups()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(into = "GroupIndexRepr", try_from = "GroupIndexRepr")
+)]
+pub struct GroupIndex {
+    keys: Vec<Column>,
+    groups: GroupPositions,
+    height: usize,
+}
+
+impl GroupIndex {
+    /// The key columns, with one value for every row.
+    pub fn keys(&self) -> &[Column] {
+        &self.keys
+    }
+
+    pub fn groups(&self) -> &GroupPositions {
+        &self.groups
+    }
+
+    /// Number of rows in the [`DataFrame`] the groups were computed on.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of groups.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+impl GroupBy<'_> {
+    /// Detach the computed groups from the [`DataFrame`], see [`GroupIndex`].
+    pub fn group_index(&self) -> GroupIndex {
+        GroupIndex {
+            keys: self.selected_keys.clone(),
+            groups: self.get_groups().clone(),
+            height: self.df.height(),
+        }
+    }
+}
+
+impl DataFrame {
+    /// Group this [`DataFrame`] by groups that were computed before, see [`GroupIndex`].
+    ///
+    /// The rows of this [`DataFrame`] must correspond to the rows the groups were computed on.
+    pub fn group_by_index(&self, index: &GroupIndex) -> PolarsResult<GroupBy<'_>> {
+        polars_ensure!(
+            self.height() == index.height,
+            ShapeMismatch: "group index was computed on {} rows, but the DataFrame has {} rows",
+            index.height, self.height()
+        );
+        Ok(GroupBy::new(
+            self,
+            index.keys.clone(),
+            index.groups.clone(),
+            None,
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+enum GroupsRepr {
+    Idx {
+        first: Vec<IdxSize>,
+        all: Vec<Vec<IdxSize>>,
+        sorted: bool,
+    },
+    Slice {
+        groups: GroupsSlice,
+        overlapping: bool,
+        monotonic: bool,
+    },
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct GroupIndexRepr {
+    keys: Vec<Column>,
+    groups: GroupsRepr,
+    height: usize,
+}
+
+#[cfg(feature = "serde")]
+impl From<GroupIndex> for GroupIndexRepr {
+    fn from(index: GroupIndex) -> Self {
+        let groups = match index.groups.as_ref() {
+            GroupsType::Idx(idx) => GroupsRepr::Idx {
+                first: idx.first().to_vec(),
+                all: idx.all().iter().map(|g| g.to_vec()).collect(),
+                sorted: idx.is_sorted_flag(),
+            },
+            GroupsType::Slice {
+                groups,
+                overlapping,
+                monotonic,
+            } => GroupsRepr::Slice {
+                groups: groups.clone(),
+                overlapping: *overlapping,
+                monotonic: *monotonic,
+            },
+        };
+        Self {
+            keys: index.keys,
+            groups,
+            height: index.height,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<GroupIndexRepr> for GroupIndex {
+    type Error = PolarsError;
+
+    /// Check that the groups are in bounds, as aggregations rely on it.
+    fn try_from(repr: GroupIndexRepr) -> PolarsResult<Self> {
+        let height = repr.height;
+        polars_ensure!(
+            repr.keys.iter().all(|c| c.len() == height),
+            ComputeError: "group index keys don't match its height of {height}"
+        );
+        let in_bounds = |i: IdxSize| (i as usize) < height;
+
+        let groups = match repr.groups {
+            GroupsRepr::Idx { first, all, sorted } => {
+                polars_ensure!(
+                    first.len() == all.len()
+                        && first.iter().zip(&all).all(|(&f, g)| g.first() == Some(&f))
+                        && all.iter().flatten().all(|&i| in_bounds(i)),
+                    ComputeError: "invalid group index: group positions are out of bounds"
+                );
+                let all = all.into_iter().map(IdxVec::from).collect();
+                GroupsType::Idx(GroupsIdx::new(first, all, sorted))
+            },
+            GroupsRepr::Slice {
+                groups,
+                overlapping,
+                monotonic,
+            } => {
+                polars_ensure!(
+                    groups
+                        .iter()
+                        .all(|&[start, len]| start as usize + len as usize <= height),
+                    ComputeError: "invalid group index: group slices are out of bounds"
+                );
+                GroupsType::new_slice(groups, overlapping, monotonic)
+            },
+        };
+        Ok(GroupIndex {
+            keys: repr.keys,
+            groups: groups.into_sliceable(),
+            height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_index() -> PolarsResult<()> {
+        let df = df![
+            "key" => ["a", "b", "a", "c"],
+            "value" => [1, 2, 3, 4],
+        ]?;
+        let index = df.group_by_stable(["key"])?.group_index();
+        assert_eq!(index.len(), 3);
+
+        let expected = df.group_by_stable(["key"])?.groups()?;
+        assert!(df.group_by_index(&index)?.groups()?.equals(&expected));
+
+        // The groups can be applied to other columns of the same rows.
+        let other = df!["value" => [5, 6, 7, 8]]?;
+        assert_eq!(
+            other.group_by_index(&index)?.keys(),
+            [expected.column("key")?.clone()]
+        );
+
+        assert!(df.head(Some(2)).group_by_index(&index).is_err());
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&index).unwrap();
+            let out = serde_json::from_str::<GroupIndex>(&json).unwrap();
+            assert!(df.group_by_index(&out)?.groups()?.equals(&expected));
+
+            // Out of bounds groups are rejected.
+            let mut repr = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+            repr["height"] = 1.into();
+            repr["keys"] = serde_json::to_value(df.head(Some(1)).columns()).unwrap();
+            assert!(serde_json::from_value::<GroupIndex>(repr).is_err());
+        }
+        Ok(())
+    }
+}
@@ -13,10 +13,12 @@ use crate::prelude::*;
 use crate::utils::{_set_partition_size, accumulate_dataframes_vertical};
 
 pub mod aggregations;
+mod group_index;
 pub(crate) mod hashing;
 mod into_groups;
 mod position;
 
+pub use group_index::*;
 pub use into_groups::*;
 pub use position::*;
 
@@ -1,6 +1,7 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 //! DataFrame module.
 
+use arrow::compute::aggregate::SeenBuffers;
 use arrow::datatypes::ArrowSchemaRef;
 use polars_row::ArrayRef;
 use polars_utils::UnitVec;
@@ -19,6 +20,7 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
+pub mod batch_mut;
 pub mod builder;
 mod chunks;
 pub use chunks::chunk_df_for_writing;
@@ -39,6 +41,7 @@ pub(crate) mod horizontal;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
+pub mod unnest;
 mod upstream_traits;
 mod validation;
 
@@ -107,6 +110,18 @@ impl DataFrame {
         self.columns().iter().map(Column::estimated_size).sum()
     }
 
+    /// Returns an estimation of all the (heap) allocated storage referenced by the `DataFrame` in
+    /// bytes, skipping the storage already counted in `seen`.
+    ///
+    /// Storage shared between columns, e.g. a column that occurs twice or the categorical mapping
+    /// of multiple categorical columns, is only counted once. See [`Series::estimated_size_dedup`].
+    pub fn estimated_size_dedup(&self, seen: &mut SeenBuffers) -> usize {
+        self.columns()
+            .iter()
+            .map(|c| c.estimated_size_dedup(seen))
+            .sum()
+    }
+
     pub fn try_apply_columns(
         &self,
         func: impl Fn(&Column) -> PolarsResult<Column> + Send + Sync,
@@ -1009,6 +1024,13 @@ impl DataFrame {
         Ok(())
     }
 
+    /// Start a batch of column mutations that are applied together, see [`BatchMut`].
+    ///
+    /// [`BatchMut`]: batch_mut::BatchMut
+    pub fn batch_mut(&mut self) -> batch_mut::BatchMut<'_> {
+        batch_mut::BatchMut::new(self)
+    }
+
     fn with_column_and_schema_mut(
         &mut self,
         mut column: Column,
@@ -1514,9 +1536,19 @@ impl DataFrame {
             return Ok(out);
         }
 
-        if let Some((0, k)) = slice {
+        // Only the first `offset + len` rows of the sorted frame are needed, which a bounded
+        // top-k selects without fully sorting.
+        if let Some((offset, len)) = slice
+            && offset >= 0
+        {
+            let k = (offset as usize).saturating_add(len);
             if k < self.height() {
-                return self.bottom_k_impl(k, by_column, sort_options);
+                let out = self.bottom_k_impl(k, by_column, sort_options)?;
+                return Ok(if offset == 0 {
+                    out
+                } else {
+                    out.slice(offset, len)
+                });
             }
         }
         // Check if the required column is already sorted; if so we can exit early
@@ -2662,48 +2694,7 @@ impl DataFrame {
         cols: impl IntoIterator<Item = impl Into<PlSmallStr>>,
         separator: Option<&str>,
     ) -> PolarsResult<DataFrame> {
-        self.unnest_impl(cols.into_iter().map(Into::into).collect(), separator)
-    }
-
-    #[cfg(feature = "dtype-struct")]
-    fn unnest_impl(
-        &self,
-        cols: PlHashSet<PlSmallStr>,
-        separator: Option<&str>,
-    ) -> PolarsResult<DataFrame> {
-        let mut new_cols = Vec::with_capacity(std::cmp::min(self.width() * 2, self.width() + 128));
-        let mut count = 0;
-        for s in self.columns() {
-            if cols.contains(s.name()) {
-                let ca = s.struct_()?.clone();
-                new_cols.extend(ca.fields_as_series().into_iter().map(|mut f| {
-                    if let Some(separator) = &separator {
-                        f.rename(polars_utils::format_pl_smallstr!(
-                            "{}{}{}",
-                            s.name(),
-                            separator,
-                            f.name()
-                        ));
-                    }
-                    Column::from(f)
-                }));
-                count += 1;
-            } else {
-                new_cols.push(s.clone())
-            }
-        }
-        if count != cols.len() {
-            // one or more columns not found
-            // the code below will return an error with the missing name
-            let schema = self.schema();
-            for col in cols {
-                let _ = schema
-                    .get(col.as_str())
-                    .ok_or_else(|| polars_err!(col_not_found = col))?;
-            }
-        }
-
-        DataFrame::new(self.height(), new_cols)
+        self.unnest_with_options(cols, separator, &UnnestOptions::default())
     }
 
     pub fn append_record_batch(&mut self, rb: RecordBatchT<ArrayRef>) -> PolarsResult<()> {
@@ -3070,4 +3061,38 @@ mod test {
         df.apply("x", |f| f.cast(&DataType::Int8).unwrap()).unwrap();
         assert_ne!(&schema_before, df.schema());
     }
+
+    #[test]
+    fn test_columns_copy_on_write() {
+        let df = create_frame();
+        let mut other = df.clone();
+        assert!(std::ptr::eq(df.columns(), other.columns()));
+
+        other.rename("days", "day".into()).unwrap();
+        assert!(!std::ptr::eq(df.columns(), other.columns()));
+        assert_eq!(df.get_column_names(), &["days", "temp"]);
+        assert_eq!(other.get_column_names(), &["day", "temp"]);
+    }
+
+    #[test]
+    fn test_batch_mut() {
+        let mut df = create_frame();
+        let mut batch = df.batch_mut();
+        batch
+            .with_column(Column::new("days".into(), [3, 4, 5]))
+            .unwrap();
+        batch.with_column(Column::new("rain".into(), [1])).unwrap();
+        batch.drop("temp").unwrap();
+        batch.rename("rain", "temp".into()).unwrap();
+        assert!(batch.rename("days", "temp".into()).is_err());
+        assert!(batch.with_column(Column::new("x".into(), [1, 2])).is_err());
+        batch.finish();
+
+        let expected = df! {
+            "days" => [3, 4, 5],
+            "temp" => [1, 1, 1]
+        }
+        .unwrap();
+        assert!(df.equals(&expected));
+    }
 }
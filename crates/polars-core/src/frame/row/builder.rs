@@ -0,0 +1,392 @@
+use std::any::Any;
+
+use crate::prelude::*;
+
+/// Builds a [`DataFrame`] row by row from native Rust values, without going through
+/// [`AnyValue`].
+///
+/// Every column of the schema gets a typed buffer, values are appended to it directly. Temporal
+/// columns take their physical representation: days since the epoch for `Date`, the integer in
+/// the column's time unit for `Datetime` and `Duration` and nanoseconds since midnight for
+/// `Time`.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_core::frame::row::RowBuilder;
+/// let schema = Schema::from_iter([
+///     Field::new("id".into(), DataType::Int64),
+///     Field::new("name".into(), DataType::String),
+/// ]);
+/// let mut builder = RowBuilder::new(&schema, 2)?;
+/// builder.row().push(1i64)?.push("a")?.finish()?;
+/// builder.row().push(2i64)?.push(None::<&str>)?.finish()?;
+/// let df = builder.finish()?;
+///
+/// assert_eq!(df.shape(), (2, 2));
+/// # Ok::<(), PolarsError>(())
+/// ```
+pub struct RowBuilder {
+    columns: Vec<ColumnAppender>,
+    height: usize,
+    /// Set when a row was only partially written, the columns no longer have the same length.
+    poisoned: bool,
+}
+
+impl RowBuilder {
+    /// Create a builder for `schema`, reserving room for `capacity` rows.
+    pub fn new(schema: &Schema, capacity: usize) -> PolarsResult<Self> {
+        let columns = schema
+            .iter()
+            .map(|(name, dtype)| ColumnAppender::new(name.clone(), dtype, capacity))
+            .collect::<PolarsResult<_>>()?;
+        Ok(Self {
+            columns,
+            height: 0,
+            poisoned: false,
+        })
+    }
+
+    /// Start a new row, its values are pushed in the order of the schema.
+    pub fn row(&mut self) -> RowWriter<'_> {
+        RowWriter {
+            builder: self,
+            column: 0,
+            done: false,
+        }
+    }
+
+    /// The number of finished rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Build the [`DataFrame`] out of the finished rows.
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            !self.poisoned,
+            ComputeError: "cannot finish a RowBuilder after a row was only partially written"
+        );
+        let columns = self
+            .columns
+            .into_iter()
+            .map(|c| c.finish().map(Column::from))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        // SAFETY: every column got a value for each finished row and the names come from a
+        // schema.
+        Ok(unsafe { DataFrame::new_unchecked(self.height, columns) })
+    }
+}
+
+/// Writes the values of a single row of a [`RowBuilder`].
+///
+/// A row must get a value for every column and be finished with [`RowWriter::finish`]. If it is
+/// dropped halfway, for instance because a value had the wrong type, the builder can no longer
+/// be finished.
+pub struct RowWriter<'a> {
+    builder: &'a mut RowBuilder,
+    column: usize,
+    done: bool,
+}
+
+impl RowWriter<'_> {
+    /// Append `value` to the next column.
+    pub fn push<V: RowValue>(&mut self, value: V) -> PolarsResult<&mut Self> {
+        let width = self.builder.columns.len();
+        let Some(column) = self.builder.columns.get_mut(self.column) else {
+            polars_bail!(ShapeMismatch: "row has more values than the {width} columns of the schema");
+        };
+        value.append_to(column)?;
+        self.column += 1;
+        Ok(self)
+    }
+
+    /// Append a null to the next column.
+    pub fn push_null(&mut self) -> PolarsResult<&mut Self> {
+        self.push(None::<bool>)
+    }
+
+    /// Complete the row, erroring if not every column got a value.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        let width = self.builder.columns.len();
+        polars_ensure!(
+            self.column == width,
+            ShapeMismatch: "row has {} values, but the schema has {width} columns", self.column
+        );
+        if !self.done {
+            self.builder.height += 1;
+            self.done = true;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for RowWriter<'_> {
+    fn drop(&mut self) {
+        if !self.done && self.column > 0 {
+            self.builder.poisoned = true;
+        }
+    }
+}
+
+/// A typed buffer for one column of a [`RowBuilder`].
+pub struct ColumnAppender {
+    name: PlSmallStr,
+    dtype: DataType,
+    buffer: Buffer,
+}
+
+enum Buffer {
+    Null(usize),
+    Boolean(BooleanChunkedBuilder),
+    Numeric(Box<dyn NumericBuffer>),
+    String(StringChunkedBuilder),
+    Binary(BinaryChunkedBuilder),
+}
+
+trait NumericBuffer: Send + Sync {
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn append_null(&mut self);
+
+    fn finish(self: Box<Self>) -> Series;
+}
+
+impl<T: PolarsNumericType> NumericBuffer for PrimitiveChunkedBuilder<T> {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn append_null(&mut self) {
+        ChunkedBuilder::append_null(self)
+    }
+
+    fn finish(self: Box<Self>) -> Series {
+        ChunkedBuilder::finish(*self).into_series()
+    }
+}
+
+impl ColumnAppender {
+    fn new(name: PlSmallStr, dtype: &DataType, capacity: usize) -> PolarsResult<Self> {
+        let buffer = match dtype {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => Self::numeric_buffer(&DataType::Int32, capacity),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => Self::numeric_buffer(&DataType::Int64, capacity),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => Self::numeric_buffer(&DataType::Int64, capacity),
+            #[cfg(feature = "dtype-time")]
+            DataType::Time => Self::numeric_buffer(&DataType::Int64, capacity),
+            DataType::Null => Buffer::Null(0),
+            DataType::Boolean => {
+                Buffer::Boolean(BooleanChunkedBuilder::new(name.clone(), capacity))
+            },
+            DataType::String => Buffer::String(StringChunkedBuilder::new(name.clone(), capacity)),
+            DataType::Binary => Buffer::Binary(BinaryChunkedBuilder::new(name.clone(), capacity)),
+            dt if dt.is_primitive_numeric() => Self::numeric_buffer(dt, capacity),
+            dt => polars_bail!(
+                InvalidOperation: "RowBuilder doesn't support column '{name}' of type {dt}"
+            ),
+        };
+        Ok(Self {
+            name,
+            dtype: dtype.clone(),
+            buffer,
+        })
+    }
+
+    fn numeric_buffer(physical: &DataType, capacity: usize) -> Buffer {
+        Buffer::Numeric(with_match_physical_numeric_polars_type!(physical, |$T| {
+            Box::new(PrimitiveChunkedBuilder::<$T>::new(PlSmallStr::EMPTY, capacity))
+        }))
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub fn append_null(&mut self) {
+        match &mut self.buffer {
+            Buffer::Null(len) => *len += 1,
+            Buffer::Boolean(b) => b.append_null(),
+            Buffer::Numeric(b) => b.append_null(),
+            Buffer::String(b) => b.append_null(),
+            Buffer::Binary(b) => b.append_null(),
+        }
+    }
+
+    fn mismatch<V>(&self) -> PolarsError {
+        polars_err!(
+            SchemaMismatch: "cannot append a value of type '{}' to column '{}' of type {}",
+            std::any::type_name::<V>(), self.name, self.dtype
+        )
+    }
+
+    fn finish(self) -> PolarsResult<Series> {
+        let s = match self.buffer {
+            Buffer::Null(len) => return Ok(Series::full_null(self.name, len, &DataType::Null)),
+            Buffer::Boolean(b) => b.finish().into_series(),
+            Buffer::Numeric(b) => b.finish(),
+            Buffer::String(b) => b.finish().into_series(),
+            Buffer::Binary(b) => b.finish().into_series(),
+        };
+        // SAFETY: every value of the physical type is valid for the supported logical types.
+        let s = unsafe { s.from_physical_unchecked(&self.dtype)? };
+        Ok(s.with_name(self.name))
+    }
+}
+
+/// A value that can be appended to a column of a [`RowBuilder`].
+pub trait RowValue {
+    /// Append `self` to `column`, erroring if it doesn't match the type of the column.
+    fn append_to(self, column: &mut ColumnAppender) -> PolarsResult<()>;
+}
+
+impl<N> RowValue for N
+where
+    N: NumericNative,
+    N::PolarsType: PolarsNumericType<Native = N>,
+{
+    fn append_to(self, column: &mut ColumnAppender) -> PolarsResult<()> {
+        if let Buffer::Numeric(b) = &mut column.buffer {
+            if let Some(b) = b
+                .as_any_mut()
+                .downcast_mut::<PrimitiveChunkedBuilder<N::PolarsType>>()
+            {
+                b.append_value(self);
+                return Ok(());
+            }
+        }
+        Err(column.mismatch::<N>())
+    }
+}
+
+impl RowValue for bool {
+    fn append_to(self, column: &mut ColumnAppender) -> PolarsResult<()> {
+        match &mut column.buffer {
+            Buffer::Boolean(b) => b.append_value(self),
+            _ => return Err(column.mismatch::<bool>()),
+        }
+        Ok(())
+    }
+}
+
+impl RowValue for &str {
+    fn append_to(self, column: &mut ColumnAppender) -> PolarsResult<()> {
+        match &mut column.buffer {
+            Buffer::String(b) => b.append_value(self),
+            _ => return Err(column.mismatch::<&str>()),
+        }
+        Ok(())
+    }
+}
+
+impl RowValue for String {
+    fn append_to(self, column: &mut ColumnAppender) -> PolarsResult<()> {
+        self.as_str().append_to(column)
+    }
+}
+
+impl RowValue for &[u8] {
+    fn append_to(self, column: &mut ColumnAppender) -> PolarsResult<()> {
+        match &mut column.buffer {
+            Buffer::Binary(b) => b.append_value(self),
+            _ => return Err(column.mismatch::<&[u8]>()),
+        }
+        Ok(())
+    }
+}
+
+impl RowValue for Vec<u8> {
+    fn append_to(self, column: &mut ColumnAppender) -> PolarsResult<()> {
+        self.as_slice().append_to(column)
+    }
+}
+
+impl<V: RowValue> RowValue for Option<V> {
+    fn append_to(self, column: &mut ColumnAppender) -> PolarsResult<()> {
+        match self {
+            Some(v) => v.append_to(column),
+            None => {
+                column.append_null();
+                Ok(())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_row_builder() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+            Field::new("c".into(), DataType::Boolean),
+            Field::new("d".into(), DataType::Float32),
+            Field::new("e".into(), DataType::Null),
+        ]);
+        let mut builder = RowBuilder::new(&schema, 0)?;
+        builder
+            .row()
+            .push(1i64)?
+            .push("x")?
+            .push(true)?
+            .push(Some(0.5f32))?
+            .push_null()?
+            .finish()?;
+        builder
+            .row()
+            .push(2i64)?
+            .push(None::<String>)?
+            .push_null()?
+            .push(None::<f32>)?
+            .push_null()?
+            .finish()?;
+        assert_eq!(builder.height(), 2);
+
+        let df = builder.finish()?;
+        let expected = df!(
+            "a" => [1i64, 2],
+            "b" => [Some("x"), None],
+            "c" => [Some(true), None],
+            "d" => [Some(0.5f32), None],
+        )?;
+        assert!(df.select(["a", "b", "c", "d"])?.equals_missing(&expected));
+        assert_eq!(df.column("e")?.dtype(), &DataType::Null);
+        assert_eq!(df.column("e")?.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_row_builder_errors() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+        ]);
+        let mut builder = RowBuilder::new(&schema, 0)?;
+
+        // The value has the wrong type, nothing was written yet.
+        assert!(builder.row().push(1i32).is_err());
+        assert!(builder.row().push(1i64)?.finish().is_err());
+        assert!(builder.finish().is_err());
+
+        let mut builder = RowBuilder::new(&schema, 0)?;
+        assert!(builder.row().push(1i64)?.push("x")?.push(1i64).is_err());
+        assert!(builder.finish().is_err());
+
+        let list = Schema::from_iter([Field::new(
+            "a".into(),
+            DataType::List(Box::new(DataType::Int64)),
+        )]);
+        assert!(RowBuilder::new(&list, 0).is_err());
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 mod av_buffer;
+mod builder;
 mod dataframe;
 mod transpose;
 
@@ -9,6 +10,7 @@ use std::hash::{Hash, Hasher};
 
 use arrow::bitmap::Bitmap;
 pub use av_buffer::*;
+pub use builder::*;
 use polars_utils::format_pl_smallstr;
 #[cfg(feature = "object")]
 use polars_utils::total_ord::TotalHash;
@@ -101,13 +101,39 @@ impl DataFrame {
             )),
         };
 
-        self.transpose_impl(keep_names_as, new_col_names)
+        self.transpose_impl(keep_names_as, new_col_names, None)
     }
+
+    /// Transpose a DataFrame into columns of data type `dtype`.
+    ///
+    /// Every column is strictly cast to `dtype` first. Without a data type, see
+    /// [`DataFrame::transpose`], the supertype of all columns is used.
+    pub fn transpose_with_dtype(
+        &mut self,
+        keep_names_as: Option<&str>,
+        new_col_names: Option<Either<String, Vec<String>>>,
+        dtype: &DataType,
+    ) -> PolarsResult<DataFrame> {
+        let new_col_names = match new_col_names {
+            None => None,
+            Some(Either::Left(v)) => Some(Either::Left(v.into())),
+            Some(Either::Right(v)) => Some(Either::Right(
+                v.into_iter().map(Into::into).collect::<Vec<_>>(),
+            )),
+        };
+
+        self.transpose_impl(keep_names_as, new_col_names, Some(dtype))
+    }
+
     /// Transpose a DataFrame. This is a very expensive operation.
+    ///
+    /// The transposed columns get data type `dtype` if given, otherwise the supertype of all
+    /// columns. A uniform data type, e.g. all `Float64`, is thus preserved.
     pub fn transpose_impl(
         &mut self,
         keep_names_as: Option<&str>,
         new_col_names: Option<Either<PlSmallStr, Vec<PlSmallStr>>>,
+        dtype: Option<&DataType>,
     ) -> PolarsResult<DataFrame> {
         // We must iterate columns as [`AnyValue`], so we must be contiguous.
         self.rechunk_mut_par();
@@ -142,7 +168,19 @@ impl DataFrame {
             df.height() != 0 && df.width() != 0,
             NoData: "unable to transpose an empty DataFrame"
         );
-        let dtype = df.get_supertype().unwrap()?;
+        let dtype = match dtype {
+            None => df.get_supertype().unwrap()?,
+            Some(dtype) => {
+                let columns = df
+                    .columns()
+                    .iter()
+                    .map(|c| c.strict_cast(dtype))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                // SAFETY: casting doesn't change the height or the names of the columns.
+                df = Cow::Owned(unsafe { DataFrame::new_unchecked(df.height(), columns) });
+                dtype.clone()
+            },
+        };
         df.transpose_from_dtype(&dtype, keep_names_as.map(PlSmallStr::from_str), &names_out)
     }
 }
@@ -309,4 +347,32 @@ mod test {
         assert!(out.equals_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_transpose_with_dtype() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1.5f32, 2.5],
+            "b" => [10.0f32, 20.0],
+        ]?;
+
+        // A uniform data type is preserved.
+        let out = df.transpose(None, None)?;
+        assert!(out.dtypes().iter().all(|dt| dt == &DataType::Float32));
+
+        let out = df.transpose_with_dtype(None, None, &DataType::Float64)?;
+        let expected = df![
+            "column_0" => [1.5f64, 10.0],
+            "column_1" => [2.5f64, 20.0],
+        ]?;
+        assert!(out.equals_missing(&expected));
+        assert!(out.dtypes().iter().all(|dt| dt == &DataType::Float64));
+
+        // The cast to the data type is strict.
+        let mut df = df!["a" => ["1", "x"]]?;
+        assert!(
+            df.transpose_with_dtype(None, None, &DataType::Int32)
+                .is_err()
+        );
+        Ok(())
+    }
 }
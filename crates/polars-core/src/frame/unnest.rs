@@ -0,0 +1,144 @@
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+use crate::prelude::*;
+
+/// What to do when an unnested field gets the name of a column that is already in the output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum UnnestCollision {
+    /// Raise a duplicate error.
+    #[default]
+    Raise,
+    /// The unnested field replaces the existing column. If fields of multiple structs collide,
+    /// the last one is kept.
+    Overwrite,
+    /// The existing column is kept and the unnested field is dropped. If fields of multiple
+    /// structs collide, the first one is kept.
+    Keep,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct UnnestOptions {
+    /// Only unnest these fields, the other fields are dropped.
+    pub fields: Option<Arc<[PlSmallStr]>>,
+    /// Prefix the names of the unnested fields with this. If unset, the name of the struct
+    /// column is used as prefix when a separator is given.
+    pub prefix: Option<PlSmallStr>,
+    pub on_collision: UnnestCollision,
+}
+
+impl UnnestOptions {
+    /// Get the output name of `field` of the struct column `column`, or `None` if the field is
+    /// not unnested.
+    pub fn output_name(
+        &self,
+        column: &str,
+        field: &PlSmallStr,
+        separator: Option<&str>,
+    ) -> Option<PlSmallStr> {
+        if let Some(fields) = &self.fields
+            && !fields.contains(field)
+        {
+            return None;
+        }
+        let prefix = match (&self.prefix, separator) {
+            (Some(prefix), _) => prefix.as_str(),
+            (None, Some(_)) => column,
+            (None, None) => return Some(field.clone()),
+        };
+        Some(format_pl_smallstr!(
+            "{prefix}{}{field}",
+            separator.unwrap_or_default()
+        ))
+    }
+
+    /// Decide which of the output columns to keep. Every column is given by its name and whether
+    /// it is an unnested field.
+    pub fn resolve_collisions<'a>(
+        &self,
+        columns: impl ExactSizeIterator<Item = (&'a PlSmallStr, bool)>,
+    ) -> PolarsResult<Vec<bool>> {
+        let mut keep = vec![true; columns.len()];
+        let mut seen: PlHashMap<&PlSmallStr, (usize, bool)> = PlHashMap::new();
+        for (i, (name, is_field)) in columns.enumerate() {
+            let Some((j, j_is_field)) = seen.insert(name, (i, is_field)) else {
+                continue;
+            };
+            let keep_later = match self.on_collision {
+                UnnestCollision::Raise => {
+                    polars_bail!(Duplicate: "column with name '{name}' has more than one occurrence")
+                },
+                UnnestCollision::Overwrite => is_field,
+                UnnestCollision::Keep => !is_field,
+            };
+            if keep_later {
+                keep[j] = false;
+            } else {
+                keep[i] = false;
+                seen.insert(name, (j, j_is_field));
+            }
+        }
+        Ok(keep)
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+impl DataFrame {
+    /// Unnest the given `Struct` columns, see [`UnnestOptions`] for which fields are inserted as
+    /// columns and under which names.
+    pub fn unnest_with_options(
+        &self,
+        cols: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+        separator: Option<&str>,
+        options: &UnnestOptions,
+    ) -> PolarsResult<DataFrame> {
+        let cols: PlHashSet<PlSmallStr> = cols.into_iter().map(Into::into).collect();
+        let mut new_cols = Vec::with_capacity(std::cmp::min(self.width() * 2, self.width() + 128));
+        let mut is_field = Vec::with_capacity(new_cols.capacity());
+        let mut count = 0;
+        for s in self.columns() {
+            if cols.contains(s.name()) {
+                let ca = s.struct_()?.clone();
+                for mut f in ca.fields_as_series() {
+                    let Some(name) = options.output_name(s.name(), f.name(), separator) else {
+                        continue;
+                    };
+                    f.rename(name);
+                    new_cols.push(Column::from(f));
+                    is_field.push(true);
+                }
+                count += 1;
+            } else {
+                new_cols.push(s.clone());
+                is_field.push(false);
+            }
+        }
+        if count != cols.len() {
+            // one or more columns not found
+            // the code below will return an error with the missing name
+            let schema = self.schema();
+            for col in cols {
+                let _ = schema
+                    .get(col.as_str())
+                    .ok_or_else(|| polars_err!(col_not_found = col))?;
+            }
+        }
+
+        if options.on_collision != UnnestCollision::Raise {
+            let keep =
+                options.resolve_collisions(new_cols.iter().map(|c| c.name()).zip(is_field))?;
+            let mut keep = keep.into_iter();
+            new_cols.retain(|_| keep.next().unwrap());
+        }
+
+        DataFrame::new(self.height(), new_cols)
+    }
+}
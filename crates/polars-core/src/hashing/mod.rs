@@ -1,4 +1,6 @@
 mod identity;
+#[cfg(feature = "row_hash")]
+pub mod stable;
 pub(crate) mod vector_hasher;
 
 use std::hash::{BuildHasherDefault, Hash, Hasher};
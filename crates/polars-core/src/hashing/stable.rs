@@ -0,0 +1,262 @@
+//! Hashes that don't change across Polars versions, for values that are persisted such as
+//! partition or deduplication keys.
+//!
+//! Version 1 of the algorithm hashes the encoding of every value with 64-bit XXH3, seeded with
+//! the given seed. A null is encoded as the byte `0`, any other value as the byte `1` followed by
+//! its payload:
+//!
+//! - `Boolean`: one byte, `0` or `1`.
+//! - Integers: the little-endian bytes of the value, in the width of its data type.
+//! - `Float32` and `Float64`: the little-endian bytes of the IEEE 754 representation, with `-0.0`
+//!   written as `0.0` and every NaN as the canonical quiet NaN. `Float16` is hashed as `Float32`.
+//! - `String` and `Binary`: the bytes of the value, UTF-8 for strings.
+//! - `Categorical` and `Enum`: the string value.
+//! - `Date`, `Datetime`, `Duration`, `Time` and `Decimal`: the physical integer. Time units,
+//!   time zones and scales are not part of the hash.
+//! - `List` and `Array`: the number of elements as a little-endian `u64`, followed by the
+//!   little-endian hash of every element.
+//! - `Struct`: the little-endian hash of every field.
+//!
+//! A row is hashed by hashing the little-endian hashes of its values in column order, with the
+//! same seed.
+use arrow::array::Array;
+use rayon::prelude::*;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+use crate::prelude::*;
+use crate::{POOL, with_match_physical_integer_polars_type};
+
+/// Version of the algorithm behind [`Series::stable_hash`] and [`DataFrame::hash_rows_stable`].
+///
+/// The hashes only change together with this version, which would be a breaking change.
+pub const STABLE_HASH_VERSION: u32 = 1;
+
+const NULL: u8 = 0;
+const VALID: u8 = 1;
+
+struct StableHasher {
+    seed: u64,
+    buf: Vec<u8>,
+    hashes: Vec<u64>,
+}
+
+impl StableHasher {
+    fn new(seed: u64, capacity: usize) -> Self {
+        Self {
+            seed,
+            buf: Vec::new(),
+            hashes: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn push_with(&mut self, is_valid: bool, write_payload: impl FnOnce(&mut Vec<u8>)) {
+        self.buf.clear();
+        if is_valid {
+            self.buf.push(VALID);
+            write_payload(&mut self.buf);
+        } else {
+            self.buf.push(NULL);
+        }
+        self.hashes.push(xxh3_64_with_seed(&self.buf, self.seed));
+    }
+
+    fn extend<B: AsRef<[u8]>>(&mut self, values: impl Iterator<Item = Option<B>>) {
+        for value in values {
+            match value {
+                Some(v) => self.push_with(true, |buf| buf.extend_from_slice(v.as_ref())),
+                None => self.push_with(false, |_| {}),
+            }
+        }
+    }
+}
+
+fn extend_hashes(buf: &mut Vec<u8>, hashes: &[u64]) {
+    for h in hashes {
+        buf.extend_from_slice(&h.to_le_bytes());
+    }
+}
+
+fn canonical_f32(v: f32) -> u32 {
+    if v.is_nan() {
+        0x7FC0_0000
+    } else if v == 0.0 {
+        0
+    } else {
+        v.to_bits()
+    }
+}
+
+fn canonical_f64(v: f64) -> u64 {
+    if v.is_nan() {
+        0x7FF8_0000_0000_0000
+    } else if v == 0.0 {
+        0
+    } else {
+        v.to_bits()
+    }
+}
+
+fn stable_hash_values(s: &Series, seed: u64) -> PolarsResult<Vec<u64>> {
+    let mut hasher = StableHasher::new(seed, s.len());
+    match s.dtype() {
+        DataType::Null => hasher.extend(std::iter::repeat_n(None::<[u8; 0]>, s.len())),
+        DataType::Boolean => hasher.extend(s.bool()?.iter().map(|v| v.map(|v| [v as u8]))),
+        DataType::String => hasher.extend(s.str()?.iter().map(|v| v.map(str::as_bytes))),
+        DataType::Binary => hasher.extend(s.binary()?.iter()),
+        DataType::Float32 => hasher.extend(
+            s.f32()?
+                .iter()
+                .map(|v| v.map(|v| canonical_f32(v).to_le_bytes())),
+        ),
+        DataType::Float64 => hasher.extend(
+            s.f64()?
+                .iter()
+                .map(|v| v.map(|v| canonical_f64(v).to_le_bytes())),
+        ),
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => return stable_hash_values(&s.cast(&DataType::Float32)?, seed),
+        dt if dt.is_integer() => with_match_physical_integer_polars_type!(dt, |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref();
+            hasher.extend(ca.iter().map(|v| v.map(|v| v.to_le_bytes())))
+        }),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+            return stable_hash_values(&s.cast(&DataType::String)?, seed);
+        },
+        dt if dt.is_temporal() || dt.is_decimal() => {
+            return stable_hash_values(&s.to_physical_repr(), seed);
+        },
+        DataType::List(_) => {
+            let ca = s.list()?.rechunk();
+            let arr = ca.downcast_as_array();
+            let inner = stable_hash_values(&ca.get_inner(), seed)?;
+            for i in 0..arr.len() {
+                let (start, end) = arr.offsets().start_end(i);
+                hasher.push_with(arr.is_valid(i), |buf| {
+                    buf.extend_from_slice(&((end - start) as u64).to_le_bytes());
+                    extend_hashes(buf, &inner[start..end]);
+                });
+            }
+        },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(_, width) => {
+            let ca = s.array()?.rechunk();
+            let arr = ca.downcast_as_array();
+            let inner = stable_hash_values(&ca.get_inner(), seed)?;
+            for i in 0..arr.len() {
+                hasher.push_with(arr.is_valid(i), |buf| {
+                    buf.extend_from_slice(&(*width as u64).to_le_bytes());
+                    extend_hashes(buf, &inner[i * width..(i + 1) * width]);
+                });
+            }
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => {
+            let fields = s
+                .struct_()?
+                .fields_as_series()
+                .iter()
+                .map(|field| stable_hash_values(field, seed))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            for (i, is_valid) in s.is_not_null().into_no_null_iter().enumerate() {
+                hasher.push_with(is_valid, |buf| {
+                    for field in &fields {
+                        buf.extend_from_slice(&field[i].to_le_bytes());
+                    }
+                });
+            }
+        },
+        dt => polars_bail!(InvalidOperation: "stable hashing is not supported for dtype {dt}"),
+    }
+    Ok(hasher.hashes)
+}
+
+impl Series {
+    /// Hash every value with version [`STABLE_HASH_VERSION`] of the stable hash algorithm, see
+    /// the [module docs](self).
+    ///
+    /// Unlike [`VecHash`](super::VecHash), the hashes are the same across Polars versions and
+    /// platforms.
+    pub fn stable_hash(&self, seed: u64) -> PolarsResult<UInt64Chunked> {
+        let hashes = stable_hash_values(self, seed)?;
+        Ok(UInt64Chunked::from_vec(self.name().clone(), hashes))
+    }
+}
+
+impl DataFrame {
+    /// Hash every row with version [`STABLE_HASH_VERSION`] of the stable hash algorithm, see
+    /// the [module docs](self).
+    ///
+    /// Unlike [`DataFrame::hash_rows`], the hashes are the same across Polars versions and
+    /// platforms, so they can be persisted.
+    pub fn hash_rows_stable(&self, seed: u64) -> PolarsResult<UInt64Chunked> {
+        let columns = POOL.install(|| {
+            self.columns()
+                .par_iter()
+                .map(|c| stable_hash_values(c.as_materialized_series(), seed))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut buf = Vec::with_capacity(columns.len() * 8);
+        let hashes = (0..self.height())
+            .map(|i| {
+                buf.clear();
+                for column in &columns {
+                    buf.extend_from_slice(&column[i].to_le_bytes());
+                }
+                xxh3_64_with_seed(&buf, seed)
+            })
+            .collect();
+        Ok(UInt64Chunked::from_vec(PlSmallStr::EMPTY, hashes))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stable_hash() -> PolarsResult<()> {
+        // These values are part of the stable hash contract and must never change.
+        let s = Series::new("a".into(), [Some(1i64), None]);
+        let expected = [
+            xxh3_64_with_seed(&[VALID, 1, 0, 0, 0, 0, 0, 0, 0], 7),
+            xxh3_64_with_seed(&[NULL], 7),
+        ];
+        assert_eq!(s.stable_hash(7)?.to_vec(), expected.map(Some));
+
+        let s = Series::new("a".into(), ["x"]);
+        let expected = xxh3_64_with_seed(&[VALID, b'x'], 0);
+        assert_eq!(s.stable_hash(0)?.get(0), Some(expected));
+
+        // Signed zeros and NaNs hash the same.
+        let s = Series::new("a".into(), [0.0f64, -0.0, f64::NAN, -f64::NAN]);
+        let h = s.stable_hash(0)?;
+        assert_eq!(h.get(0), h.get(1));
+        assert_eq!(h.get(2), h.get(3));
+
+        let df = df!("a" => [1i64], "b" => ["x"])?;
+        let mut row = Vec::new();
+        row.extend_from_slice(
+            &df.column("a")?
+                .as_materialized_series()
+                .stable_hash(3)?
+                .get(0)
+                .unwrap()
+                .to_le_bytes(),
+        );
+        row.extend_from_slice(
+            &df.column("b")?
+                .as_materialized_series()
+                .stable_hash(3)?
+                .get(0)
+                .unwrap()
+                .to_le_bytes(),
+        );
+        assert_eq!(
+            df.hash_rows_stable(3)?.get(0),
+            Some(xxh3_64_with_seed(&row, 3))
+        );
+        Ok(())
+    }
+}
@@ -50,6 +50,7 @@ pub use crate::frame::explode::UnpivotArgsIR;
 pub(crate) use crate::frame::group_by::aggregations::*;
 #[cfg(feature = "algorithm_group_by")]
 pub use crate::frame::group_by::*;
+pub use crate::frame::unnest::{UnnestCollision, UnnestOptions};
 pub use crate::frame::{DataFrame, UniqueKeepStrategy};
 pub use crate::hashing::VecHash;
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
@@ -2,6 +2,7 @@ use std::any::Any;
 use std::borrow::Cow;
 
 use arrow::bitmap::Bitmap;
+use arrow::datatypes::Metadata;
 
 use self::compare_inner::{TotalEqInner, TotalOrdInner};
 use self::sort::arg_sort_row_fmt;
@@ -103,6 +104,10 @@ impl SeriesTrait for SeriesWrap<ArrayChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter<'_> {
         self.0.chunk_lengths()
     }
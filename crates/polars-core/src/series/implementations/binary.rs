@@ -104,6 +104,10 @@ impl SeriesTrait for SeriesWrap<BinaryChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter<'_> {
         self.0.chunk_lengths()
     }
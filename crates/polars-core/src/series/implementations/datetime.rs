@@ -172,6 +172,10 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.physical_mut().set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter<'_> {
         self.0.physical().chunk_lengths()
     }
@@ -105,6 +105,10 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.storage_mut().set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter<'_> {
         self.0.storage().chunk_lengths()
     }
@@ -31,6 +31,7 @@ use std::any::Any;
 use std::borrow::Cow;
 
 use arrow::bitmap::Bitmap;
+use arrow::datatypes::Metadata;
 use polars_compute::rolling::QuantileMethod;
 use polars_utils::aliases::PlSeedableRandomStateQuality;
 
@@ -234,6 +235,10 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+                self.0.set_metadata(metadata);
+            }
+
             fn chunk_lengths(&self) -> ChunkLenIter<'_> {
                 self.0.chunk_lengths()
             }
@@ -182,6 +182,10 @@ impl SeriesTrait for NullChunked {
         self.name = name
     }
 
+    fn set_metadata(&mut self, _metadata: Option<Arc<Metadata>>) {
+        // Null series don't hold metadata.
+    }
+
     fn chunks(&self) -> &Vec<ArrayRef> {
         &self.chunks
     }
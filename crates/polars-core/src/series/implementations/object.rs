@@ -96,6 +96,10 @@ where
         ObjectChunked::rename(&mut self.0, name)
     }
 
+    fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkLenIter<'_> {
         ObjectChunked::chunk_lengths(&self.0)
     }
@@ -34,7 +34,8 @@ use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::compute::aggregate::{SeenBuffers, estimated_bytes_size, estimated_bytes_size_dedup};
+use arrow::datatypes::Metadata;
 use arrow::offset::Offsets;
 pub use from::*;
 pub use iterator::{SeriesIter, SeriesPhysIter};
@@ -296,6 +297,42 @@ impl Series {
         self
     }
 
+    /// Get the custom key-value metadata of the series, e.g. its unit or provenance.
+    ///
+    /// The metadata is kept when the series is renamed, gathered from (e.g. in a join) or
+    /// appended to, but not by operations that compute new values. Series of the `Null` data type
+    /// don't hold metadata.
+    pub fn metadata(&self) -> Option<Arc<Metadata>> {
+        self.field().metadata().cloned()
+    }
+
+    /// Set the custom key-value metadata of the series.
+    pub fn set_metadata(&mut self, metadata: Option<Arc<Metadata>>) -> &mut Series {
+        self._get_inner_mut().set_metadata(metadata);
+        self
+    }
+
+    /// Return this Series with different custom key-value metadata.
+    pub fn with_metadata(mut self, metadata: Option<Arc<Metadata>>) -> Series {
+        self.set_metadata(metadata);
+        self
+    }
+
+    /// Merges the metadata of `other` into this series, see [`Field::merge_metadata`].
+    pub fn merge_metadata_from(&mut self, other: &Series) {
+        let Some(other_md) = other.metadata() else {
+            return;
+        };
+        let md = self.metadata();
+        let merged = merge_metadata(md.as_ref(), Some(&other_md));
+        if !md
+            .zip(merged.as_ref())
+            .is_some_and(|(l, r)| Arc::ptr_eq(&l, r))
+        {
+            self.set_metadata(merged);
+        }
+    }
+
     pub fn from_arrow_chunks(name: PlSmallStr, arrays: Vec<ArrayRef>) -> PolarsResult<Series> {
         Self::try_from((name, arrays))
     }
@@ -313,6 +350,7 @@ impl Series {
     ///
     /// See [`ChunkedArray::append`] and [`ChunkedArray::extend`].
     pub fn append(&mut self, other: &Series) -> PolarsResult<&mut Self> {
+        self.merge_metadata_from(other);
         let must_cast = other.dtype().matches_schema_type(self.dtype())?;
         if must_cast {
             let other = other.cast(self.dtype())?;
@@ -327,6 +365,7 @@ impl Series {
     ///
     /// See [`ChunkedArray::append_owned`] and [`ChunkedArray::extend`].
     pub fn append_owned(&mut self, other: Series) -> PolarsResult<&mut Self> {
+        self.merge_metadata_from(&other);
         let must_cast = other.dtype().matches_schema_type(self.dtype())?;
         if must_cast {
             let other = other.cast(self.dtype())?;
@@ -346,6 +385,7 @@ impl Series {
     ///
     /// See [`ChunkedArray::extend`] and [`ChunkedArray::append`].
     pub fn extend(&mut self, other: &Series) -> PolarsResult<&mut Self> {
+        self.merge_metadata_from(other);
         let must_cast = other.dtype().matches_schema_type(self.dtype())?;
         if must_cast {
             let other = other.cast(self.dtype())?;
@@ -356,6 +396,53 @@ impl Series {
         Ok(self)
     }
 
+    /// Append `values` to the last chunk of this numeric series without adding chunks.
+    ///
+    /// Errors if the data type of the series isn't the one of `T`.
+    ///
+    /// See [`ChunkedArray::extend_from_slice`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # fn main() -> PolarsResult<()> {
+    /// let mut s = Series::new("a".into(), [1i32, 2]);
+    /// s.extend_from_slice(&[3i32, 4])?;
+    /// assert_eq!(s.n_chunks(), 1);
+    /// assert_eq!(s, Series::new("a".into(), [1i32, 2, 3, 4]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn extend_from_slice<T>(&mut self, values: &[T]) -> PolarsResult<&mut Self>
+    where
+        T: NumericNative,
+        T::PolarsType: PolarsNumericType<Native = T>,
+    {
+        self.unpack_numeric_mut::<T::PolarsType>()?
+            .extend_from_slice(values);
+        Ok(self)
+    }
+
+    /// Append optional `values` to the last chunk of this numeric series without adding chunks.
+    ///
+    /// See [`Series::extend_from_slice`].
+    pub fn extend_values<T, I>(&mut self, values: I) -> PolarsResult<&mut Self>
+    where
+        T: NumericNative,
+        T::PolarsType: PolarsNumericType<Native = T>,
+        I: IntoIterator<Item = Option<T>>,
+    {
+        self.unpack_numeric_mut::<T::PolarsType>()?
+            .extend_values(values);
+        Ok(self)
+    }
+
+    fn unpack_numeric_mut<T: PolarsNumericType>(&mut self) -> PolarsResult<&mut ChunkedArray<T>> {
+        polars_ensure!(&T::get_static_dtype() == self.dtype(), extend);
+        Ok(self._get_inner_mut().as_mut())
+    }
+
     /// Sort the series with specific options.
     ///
     /// # Example
@@ -427,20 +514,14 @@ impl Series {
                         if let Some(dtype) = cast_dtype(&field.dtype) {
                             let mut new_fields = Vec::with_capacity(fields.len());
                             new_fields.extend(fields.iter().take(i).cloned());
-                            new_fields.push(Field {
-                                name: field.name.clone(),
-                                dtype,
-                            });
+                            new_fields.push(field.clone().with_dtype(dtype));
                             break new_fields;
                         }
                     };
 
                     new_fields.extend(fields.iter().skip(new_fields.len()).cloned().map(|field| {
-                        let dtype = cast_dtype(&field.dtype).unwrap_or(field.dtype);
-                        Field {
-                            name: field.name,
-                            dtype,
-                        }
+                        let dtype = cast_dtype(&field.dtype).unwrap_or_else(|| field.dtype.clone());
+                        field.with_dtype(dtype)
                     }));
 
                     Some(D::Struct(new_fields))
@@ -1033,31 +1114,89 @@ impl Series {
     /// However, this function will yield a smaller number. This is because this function returns
     /// the visible size of the buffer, not its total capacity.
     ///
-    /// FFI buffers are included in this estimation.
+    /// FFI buffers are included in this estimation. For [`DataType::Object`], the size the
+    /// objects report through [`PolarsObject::estimated_size`] is added, extrapolated from a
+    /// sample of the objects of every chunk. Objects referenced from multiple rows are counted
+    /// multiple times.
+    ///
+    /// See [`Series::estimated_size_dedup`] to count all the storage referenced by the `Series`.
     pub fn estimated_size(&self) -> usize {
-        let mut size = 0;
-        match self.dtype() {
-            // TODO @ cat-rework: include mapping size here?
-            #[cfg(feature = "object")]
-            DataType::Object(_) => {
-                let ArrowDataType::FixedSizeBinary(size) = self.chunks()[0].dtype() else {
-                    unreachable!()
-                };
-                // This is only the pointer size in python. So will be a huge underestimation.
-                return self.len() * *size;
-            },
-            _ => {},
+        #[cfg(feature = "object")]
+        if let Some(size) = self.object_estimated_size() {
+            return size;
         }
 
-        size += self
-            .chunks()
+        self.chunks()
             .iter()
             .map(|arr| estimated_bytes_size(&**arr))
+            .sum::<usize>()
+    }
+
+    /// Returns an estimation of all the (heap) allocated storage referenced by the `Series` in
+    /// bytes, skipping the storage already counted in `seen`.
+    ///
+    /// Unlike [`Series::estimated_size`], this counts the data buffers of string and binary views
+    /// in full, and includes the size of the categorical mappings. Sharing `seen` between calls
+    /// makes sure that storage shared between chunks, columns or categorical data types is only
+    /// counted once.
+    pub fn estimated_size_dedup(&self, seen: &mut SeenBuffers) -> usize {
+        #[cfg(feature = "object")]
+        if let Some(size) = self.object_estimated_size() {
+            return size;
+        }
+
+        let mut size = self
+            .chunks()
+            .iter()
+            .map(|arr| estimated_bytes_size_dedup(&**arr, seen))
             .sum::<usize>();
 
+        #[cfg(feature = "dtype-categorical")]
+        {
+            size += categorical_mappings_size(self.dtype(), seen);
+        }
+
         size
     }
 
+    /// The size of the object pointers and of the objects they point to.
+    ///
+    /// Asking an object for its size may be expensive, e.g. for Python objects, so only a few
+    /// evenly spaced objects of every chunk are asked and their mean size is extrapolated to the
+    /// valid objects of the chunk.
+    #[cfg(feature = "object")]
+    fn object_estimated_size(&self) -> Option<usize> {
+        const SAMPLES_PER_CHUNK: usize = 8;
+
+        let DataType::Object(_) = self.dtype() else {
+            return None;
+        };
+        let ArrowDataType::FixedSizeBinary(size) = self.chunks()[0].dtype() else {
+            unreachable!()
+        };
+        // The slots only hold pointers; add the size the objects themselves report.
+        let mut objects_size = 0;
+        for (chunk_idx, arr) in self.chunks().iter().enumerate() {
+            let num_valid = arr.len() - arr.null_count();
+            if num_valid == 0 {
+                continue;
+            }
+            let step = arr.len().div_ceil(SAMPLES_PER_CHUNK);
+            let (mut sampled, mut sampled_size) = (0, 0);
+            for idx in (0..arr.len()).step_by(step) {
+                // SAFETY: idx is within the bounds of this chunk.
+                if let Some(obj) = unsafe { self.get_object_chunked_unchecked(chunk_idx, idx) } {
+                    sampled += 1;
+                    sampled_size += obj.estimated_size();
+                }
+            }
+            if sampled > 0 {
+                objects_size += sampled_size * num_valid / sampled;
+            }
+        }
+        Some(self.len() * *size + objects_size)
+    }
+
     /// Packs every element into a list.
     pub fn as_list(&self) -> ListChunked {
         let s = self.rechunk();
@@ -1097,6 +1236,30 @@ impl Series {
     }
 }
 
+/// The size of the categorical mappings used by `dtype` (including nested data types) which
+/// weren't counted yet.
+#[cfg(feature = "dtype-categorical")]
+fn categorical_mappings_size(dtype: &DataType, seen: &mut SeenBuffers) -> usize {
+    match dtype {
+        DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
+            let mapping_size = size_of::<CategoricalMapping>();
+            if seen.count(Arc::as_ptr(mapping), mapping_size) == 0 {
+                return 0;
+            }
+            mapping_size + mapping.estimated_size()
+        },
+        DataType::List(inner) => categorical_mappings_size(inner, seen),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, _) => categorical_mappings_size(inner, seen),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => fields
+            .iter()
+            .map(|field| categorical_mappings_size(field.dtype(), seen))
+            .sum(),
+        _ => 0,
+    }
+}
+
 impl Default for Series {
     fn default() -> Self {
         NullChunked::new(PlSmallStr::EMPTY, 0).into_series()
@@ -1266,4 +1429,36 @@ mod test {
         let _ = series.slice(-6, 2);
         let _ = series.slice(4, 2);
     }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn estimated_size_dedup() -> PolarsResult<()> {
+        let s = Series::new("a".into(), ["x".repeat(100), "x".repeat(100)]);
+        let cats = Categories::random(PlSmallStr::EMPTY, CategoricalPhysical::U32);
+        let cat = s.cast(&DataType::from_categories(cats))?;
+
+        // By default only the visible bytes are counted.
+        assert_eq!(s.estimated_size(), 200);
+        assert_eq!(
+            cat.estimated_size(),
+            cat.to_physical_repr().estimated_size()
+        );
+
+        // The categorical mapping is counted, but only once.
+        let mut seen = SeenBuffers::default();
+        let cat_size = cat.estimated_size_dedup(&mut seen);
+        assert!(cat_size > cat.estimated_size() + 100);
+        assert_eq!(cat.clone().estimated_size_dedup(&mut seen), 0);
+
+        let df = DataFrame::new(vec![
+            cat.clone().into(),
+            cat.clone().with_name("b".into()).into(),
+        ])?;
+        assert_eq!(df.estimated_size(), 2 * cat.estimated_size());
+        assert_eq!(
+            df.estimated_size_dedup(&mut SeenBuffers::default()),
+            cat_size
+        );
+        Ok(())
+    }
 }
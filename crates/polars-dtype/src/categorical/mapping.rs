@@ -151,6 +151,20 @@ impl CategoricalMapping {
         self.len() == 0
     }
 
+    /// Returns an estimation of the (heap) allocated size of this mapping in bytes.
+    ///
+    /// This counts the strings and the entries of both the forward and reverse mapping, not
+    /// the unused capacity of the underlying tables.
+    pub fn estimated_size(&self) -> usize {
+        let n = self.num_cats_upper_bound();
+        let entry_size = size_of::<(&str, u64)>() + size_of::<(&str, CatSize)>();
+        let str_size = (0..n)
+            .filter_map(|i| self.cat_to_str(i as CatSize))
+            .map(str::len)
+            .sum::<usize>();
+        n * entry_size + str_size
+    }
+
     pub fn to_arrow(&self, as_views: bool) -> Box<dyn Array> {
         let n = self.num_cats_upper_bound();
         if as_views {
@@ -1,6 +1,9 @@
+use std::cell::RefCell;
 use std::fmt;
 use std::hash::{BuildHasher, Hash, Hasher};
+use std::marker::PhantomData;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock, Mutex, Weak};
 
 use arrow::array::builder::StaticArrayBuilder;
@@ -120,6 +123,14 @@ static GLOBAL_CATEGORIES: LazyLock<Arc<Categories>> = LazyLock::new(|| {
     global
 });
 
+thread_local! {
+    // The stack of Categories scopes entered on this thread with their ids, innermost last.
+    static CATEGORIES_SCOPES: RefCell<Vec<(u64, Arc<Categories>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+static NEXT_CATEGORIES_SCOPE_ID: AtomicU64 = AtomicU64::new(0);
+
 /// A (named) object which is used to indicate which categorical data types have the same mapping.
 pub struct Categories {
     id: CategoricalId,
@@ -164,6 +175,30 @@ impl Categories {
         Arc::ptr_eq(self, &*GLOBAL_CATEGORIES)
     }
 
+    /// Returns the Categories of the innermost scope entered on this thread, or the global
+    /// Categories if no scope is entered.
+    ///
+    /// This is the Categories used for categorical data types that don't specify one. Entering a
+    /// scope with [`Categories::enter_scope`] thus keeps categoricals of unrelated queries out of
+    /// the global mapping, while categoricals created within the same scope can still be combined.
+    pub fn scoped_or_global() -> Arc<Self> {
+        CATEGORIES_SCOPES
+            .with_borrow(|scopes| scopes.last().map(|(_, categories)| categories.clone()))
+            .unwrap_or_else(Self::global)
+    }
+
+    /// Enters a scope on this thread in which [`Categories::scoped_or_global`] returns these
+    /// Categories. The scope is exited when the returned guard is dropped, even if scopes that
+    /// were entered later are still alive.
+    pub fn enter_scope(self: &Arc<Self>) -> CategoriesScope {
+        let id = NEXT_CATEGORIES_SCOPE_ID.fetch_add(1, Ordering::Relaxed);
+        CATEGORIES_SCOPES.with_borrow_mut(|scopes| scopes.push((id, self.clone())));
+        CategoriesScope {
+            id,
+            _not_send: PhantomData,
+        }
+    }
+
     /// Generates a Categories with a random (UUID) name.
     pub fn random(namespace: PlSmallStr, physical: CategoricalPhysical) -> Arc<Self> {
         Self::new(uuid::Uuid::new_v4().to_string().into(), namespace, physical)
@@ -208,6 +243,24 @@ impl Categories {
     }
 }
 
+/// Guard of a scope entered with [`Categories::enter_scope`], exits the scope when dropped.
+#[must_use]
+pub struct CategoriesScope {
+    id: u64,
+    // Scopes are per thread.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for CategoriesScope {
+    fn drop(&mut self) {
+        CATEGORIES_SCOPES.with_borrow_mut(|scopes| {
+            if let Some(idx) = scopes.iter().rposition(|(id, _)| *id == self.id) {
+                scopes.remove(idx);
+            }
+        });
+    }
+}
+
 impl fmt::Debug for Categories {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Categories")
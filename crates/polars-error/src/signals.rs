@@ -61,11 +61,20 @@ pub fn register_polars_keyboard_interrupt_hook() {
 /// keyboard interrupt. This function is very cheap.
 #[inline(always)]
 pub fn try_raise_keyboard_interrupt() {
-    if INTERRUPT_STATE.load(Ordering::Relaxed) & 1 != 0 {
+    if keyboard_interrupt_pending() {
         try_raise_keyboard_interrupt_slow()
     }
 }
 
+/// Returns whether a keyboard interrupt was received that has not been caught yet.
+///
+/// This can be used to cancel work running on other threads before raising the
+/// interrupt with [`try_raise_keyboard_interrupt`].
+#[inline(always)]
+pub fn keyboard_interrupt_pending() -> bool {
+    INTERRUPT_STATE.load(Ordering::Relaxed) & 1 != 0
+}
+
 #[inline(never)]
 #[cold]
 fn try_raise_keyboard_interrupt_slow() {
@@ -23,6 +23,7 @@ pub enum PolarsWarning {
     UserWarning,
     CategoricalRemappingWarning,
     MapWithoutReturnDtypeWarning,
+    LossyCastWarning,
 }
 
 #[macro_export]
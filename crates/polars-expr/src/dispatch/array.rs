@@ -38,6 +38,12 @@ pub fn function_expr_to_udf(func: IRArrayFunction) -> SpecialEq<Arc<dyn ColumnsU
         Contains { nulls_equal } => map_as_slice!(contains, nulls_equal),
         #[cfg(feature = "array_count")]
         CountMatches => map_as_slice!(count_matches),
+        #[cfg(feature = "array_linalg")]
+        Dot => map_as_slice!(dot),
+        #[cfg(feature = "array_linalg")]
+        L2Norm => map!(l2_norm),
+        #[cfg(feature = "array_linalg")]
+        CosineSimilarity => map_as_slice!(cosine_similarity),
         Shift => map_as_slice!(shift),
         Explode(options) => map_as_slice!(explode, options),
         Slice(offset, length) => map!(slice, offset, length),
@@ -188,6 +194,34 @@ pub(super) fn count_matches(args: &[Column]) -> PolarsResult<Column> {
         .map(Column::from)
 }
 
+/// Allow the right hand side to be a list, e.g. a vector literal, with the same width.
+#[cfg(feature = "array_linalg")]
+fn linalg_rhs(other: &Column, width: usize) -> PolarsResult<Column> {
+    match other.dtype() {
+        DataType::List(inner) => other.cast(&DataType::Array(inner.clone(), width)),
+        _ => Ok(other.clone()),
+    }
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn dot(args: &[Column]) -> PolarsResult<Column> {
+    let ca = args[0].array()?;
+    let other = linalg_rhs(&args[1], ca.width())?;
+    ca.array_dot(other.array()?).map(Column::from)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn l2_norm(s: &Column) -> PolarsResult<Column> {
+    s.array()?.array_l2_norm().map(Column::from)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn cosine_similarity(args: &[Column]) -> PolarsResult<Column> {
+    let ca = args[0].array()?;
+    let other = linalg_rhs(&args[1], ca.width())?;
+    ca.array_cosine_similarity(other.array()?).map(Column::from)
+}
+
 pub(super) fn shift(s: &[Column]) -> PolarsResult<Column> {
     let ca = s[0].array()?;
     let n = &s[1];
@@ -2,7 +2,11 @@ use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::{Column, DataType, IntoColumn};
+#[cfg(feature = "binary_encoding")]
+use polars_ops::prelude::Base64Alphabet;
 use polars_ops::prelude::BinaryNameSpaceImpl;
+#[cfg(feature = "ip_address")]
+use polars_ops::prelude::IpSubnet;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
 use polars_plan::plans::IRBinaryFunction;
 
@@ -23,9 +27,9 @@ pub fn function_expr_to_udf(func: IRBinaryFunction) -> SpecialEq<Arc<dyn Columns
         #[cfg(feature = "binary_encoding")]
         HexEncode => map!(hex_encode),
         #[cfg(feature = "binary_encoding")]
-        Base64Decode(strict) => map!(base64_decode, strict),
+        Base64Decode { alphabet, strict } => map!(base64_decode, alphabet, strict),
         #[cfg(feature = "binary_encoding")]
-        Base64Encode => map!(base64_encode),
+        Base64Encode { alphabet, padding } => map!(base64_encode, alphabet, padding),
         Size => map!(size_bytes),
         #[cfg(feature = "binary_encoding")]
         Reinterpret(dtype, is_little_endian) => map!(reinterpret, &dtype, is_little_endian),
@@ -41,6 +45,12 @@ pub fn function_expr_to_udf(func: IRBinaryFunction) -> SpecialEq<Arc<dyn Columns
         Get(null_on_oob) => {
             map_as_slice!(bin_get, null_on_oob)
         },
+        #[cfg(feature = "ip_address")]
+        IpIsInSubnet(subnets) => map!(ip_is_in_subnet, &subnets),
+        #[cfg(feature = "ip_address")]
+        IpToInt => map!(ip_to_int),
+        #[cfg(feature = "uuid")]
+        ToUuidStr => map!(to_uuid_str),
     }
 }
 
@@ -91,15 +101,24 @@ pub(super) fn hex_encode(s: &Column) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "binary_encoding")]
-pub(super) fn base64_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+pub(super) fn base64_decode(
+    s: &Column,
+    alphabet: Base64Alphabet,
+    strict: bool,
+) -> PolarsResult<Column> {
     let ca = s.binary()?;
-    ca.base64_decode(strict).map(|ok| ok.into_column())
+    ca.base64_decode_with_options(alphabet, strict)
+        .map(|ok| ok.into_column())
 }
 
 #[cfg(feature = "binary_encoding")]
-pub(super) fn base64_encode(s: &Column) -> PolarsResult<Column> {
+pub(super) fn base64_encode(
+    s: &Column,
+    alphabet: Base64Alphabet,
+    padding: bool,
+) -> PolarsResult<Column> {
     let ca = s.binary()?;
-    Ok(ca.base64_encode().into())
+    Ok(ca.base64_encode_with_options(alphabet, padding).into())
 }
 
 #[cfg(feature = "binary_encoding")]
@@ -142,3 +161,21 @@ pub(super) fn bin_get(s: &mut [Column], null_on_oob: bool) -> PolarsResult<Colum
     let index = s[1].cast(&DataType::Int64)?;
     polars_ops::prelude::bin_get(ca, index.i64()?, null_on_oob)
 }
+
+#[cfg(feature = "ip_address")]
+pub(super) fn ip_is_in_subnet(s: &Column, subnets: &[IpSubnet]) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    polars_ops::prelude::ip_is_in_subnet(ca, subnets).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "ip_address")]
+pub(super) fn ip_to_int(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    polars_ops::prelude::ip_to_int(ca).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "uuid")]
+pub(super) fn to_uuid_str(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    polars_ops::prelude::uuid_to_str(ca).map(|ok| ok.into_column())
+}
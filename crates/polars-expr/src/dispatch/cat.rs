@@ -2,20 +2,25 @@ use std::sync::Arc;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::{
+    Categorical8Type, Categorical16Type, Categorical32Type, CategoricalPhysical,
     ChunkTakeUnchecked, ChunkedArray, Column, FalseT, IDX_DTYPE, IntoColumn, PolarsPhysicalType,
     StringChunked,
 };
 use polars_core::series::Series;
+use polars_core::with_match_categorical_physical_type;
 use polars_ops::prelude::BinaryNameSpaceImpl;
 #[cfg(feature = "strings")]
 use polars_ops::prelude::StringNameSpaceImpl;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
 use polars_plan::plans::IRCategoricalFunction;
+use polars_utils::pl_str::PlSmallStr;
 
 pub fn function_expr_to_udf(func: IRCategoricalFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
     use IRCategoricalFunction::*;
     match func {
         GetCategories => map!(get_categories),
+        AddCategories(categories) => map!(add_categories, &categories),
+        UnionCategories => map_as_slice!(union_categories),
         #[cfg(feature = "strings")]
         LenBytes => map!(len_bytes),
         #[cfg(feature = "strings")]
@@ -35,6 +40,24 @@ fn get_categories(s: &Column) -> PolarsResult<Column> {
     Ok(ca.into_column())
 }
 
+fn add_categories(s: &Column, categories: &[PlSmallStr]) -> PolarsResult<Column> {
+    let s = s.as_materialized_series();
+    let out = with_match_categorical_physical_type!(s.dtype().cat_physical()?, |$C| {
+        s.cat::<$C>()?
+            .add_categories(categories.iter().map(|s| s.as_str()))?
+    });
+    Ok(out.into_column())
+}
+
+fn union_categories(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].as_materialized_series();
+    let other = s[1].dtype();
+    let out = with_match_categorical_physical_type!(ca.dtype().cat_physical()?, |$C| {
+        ca.cat::<$C>()?.union_categories(other)?
+    });
+    Ok(out.into_column())
+}
+
 // Determine mapping between categories and underlying physical. For local, this is just 0..n.
 // For global, this is the global indexes.
 fn _get_cat_phys_map(col: &Column) -> (StringChunked, Series) {
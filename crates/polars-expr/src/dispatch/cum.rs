@@ -1,5 +1,6 @@
 use polars_core::error::PolarsResult;
 use polars_core::prelude::Column;
+use polars_ops::prelude::ListCumFunction;
 
 pub(super) fn cum_count(s: &Column, reverse: bool) -> PolarsResult<Column> {
     // @scalar-opt
@@ -25,3 +26,22 @@ pub(super) fn cum_max(s: &Column, reverse: bool) -> PolarsResult<Column> {
     // @scalar-opt
     polars_ops::prelude::cum_max(s.as_materialized_series(), reverse).map(Column::from)
 }
+
+pub(super) fn cum_agg_reset(
+    s: &[Column],
+    function: ListCumFunction,
+    reverse: bool,
+) -> PolarsResult<Column> {
+    let values = &s[0];
+    let mut reset = s[1].clone();
+    if reset.len() == 1 && values.len() != 1 {
+        reset = reset.new_from_index(0, values.len());
+    }
+    polars_ops::prelude::cum_agg_reset(
+        values.as_materialized_series(),
+        reset.bool()?,
+        function,
+        reverse,
+    )
+    .map(Column::from)
+}
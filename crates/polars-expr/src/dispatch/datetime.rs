@@ -48,8 +48,20 @@ pub(super) fn quarter(s: &Column) -> PolarsResult<Column> {
         .quarter()
         .map(|ca| ca.into_column())
 }
-pub(super) fn week(s: &Column) -> PolarsResult<Column> {
-    s.as_materialized_series().week().map(|ca| ca.into_column())
+pub(super) fn fiscal_year(s: &Column, start_month: i8) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .fiscal_year(start_month)
+        .map(|ca| ca.into_column())
+}
+pub(super) fn fiscal_quarter(s: &Column, start_month: i8) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .fiscal_quarter(start_month)
+        .map(|ca| ca.into_column())
+}
+pub(super) fn week(s: &Column, convention: WeekConvention) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .week_with_convention(convention)
+        .map(|ca| ca.into_column())
 }
 pub(super) fn weekday(s: &Column) -> PolarsResult<Column> {
     s.as_materialized_series()
@@ -375,9 +387,7 @@ pub(super) fn month_end(s: &Column) -> PolarsResult<Column> {
 pub(super) fn base_utc_offset(s: &Column) -> PolarsResult<Column> {
     match s.dtype() {
         DataType::Datetime(time_unit, Some(tz)) => {
-            let tz = tz
-                .parse::<Tz>()
-                .expect("Time zone has already been validated");
+            let tz = tz.parse::<Tz>()?;
             Ok(polars_time::base_utc_offset(s.datetime().unwrap(), time_unit, &tz).into_column())
         },
         dt => polars_bail!(
@@ -391,9 +401,7 @@ pub(super) fn base_utc_offset(s: &Column) -> PolarsResult<Column> {
 pub(super) fn dst_offset(s: &Column) -> PolarsResult<Column> {
     match s.dtype() {
         DataType::Datetime(time_unit, Some(tz)) => {
-            let tz = tz
-                .parse::<Tz>()
-                .expect("Time zone has already been validated");
+            let tz = tz.parse::<Tz>()?;
             Ok(polars_time::dst_offset(s.datetime().unwrap(), time_unit, &tz).into_column())
         },
         dt => polars_bail!(
@@ -40,6 +40,10 @@ pub fn function_expr_to_udf(func: IRListFunction) -> SpecialEq<Arc<dyn ColumnsUd
         GatherEvery => map_as_slice!(gather_every),
         #[cfg(feature = "list_count")]
         CountMatches => map_as_slice!(count_matches),
+        #[cfg(feature = "index_of")]
+        IndexOf => map_as_slice!(index_of),
+        #[cfg(feature = "index_of")]
+        Find => map!(find),
         Sum => map!(sum),
         Length => map!(length),
         Max => map!(max),
@@ -52,11 +56,20 @@ pub fn function_expr_to_udf(func: IRListFunction) -> SpecialEq<Arc<dyn ColumnsUd
         ArgMax => map!(arg_max),
         #[cfg(feature = "diff")]
         Diff { n, null_behavior } => map!(diff, n, null_behavior),
+        #[cfg(feature = "cum_agg")]
+        CumAgg { function, reverse } => map!(cum_agg, function, reverse),
+        #[cfg(feature = "rolling_window")]
+        Rolling {
+            function,
+            window_size,
+            min_periods,
+            center,
+        } => map!(rolling, function, window_size, min_periods, center),
         Sort(options) => map!(sort, options),
         Reverse => map!(reverse),
         Unique(is_stable) => map!(unique, is_stable),
         #[cfg(feature = "list_sets")]
-        SetOperation(s) => map_as_slice!(set_operation, s),
+        SetOperation { op, multiset } => map_as_slice!(set_operation, op, multiset),
         #[cfg(feature = "list_any_all")]
         Any => map!(lst_any),
         #[cfg(feature = "list_any_all")]
@@ -296,6 +309,18 @@ pub(super) fn count_matches(args: &[Column]) -> PolarsResult<Column> {
     polars_ops::prelude::list_count_matches(ca, element.get(0).unwrap()).map(Column::from)
 }
 
+#[cfg(feature = "index_of")]
+pub(super) fn index_of(args: &[Column]) -> PolarsResult<Column> {
+    let ca = args[0].list()?;
+    let value = args[1].as_materialized_series();
+    polars_ops::prelude::list_index_of(ca, value).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "index_of")]
+pub(super) fn find(s: &Column) -> PolarsResult<Column> {
+    polars_ops::prelude::list_find(s.list()?).map(|ca| ca.into_column())
+}
+
 pub(super) fn sum(s: &Column) -> PolarsResult<Column> {
     s.list()?.lst_sum().map(Column::from)
 }
@@ -345,6 +370,66 @@ pub(super) fn diff(
     Ok(s.list()?.lst_diff(n, null_behavior)?.into_column())
 }
 
+#[cfg(feature = "cum_agg")]
+pub(super) fn cum_agg(
+    s: &Column,
+    function: polars_ops::prelude::ListCumFunction,
+    reverse: bool,
+) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_cum_agg(function, reverse)?.into_column())
+}
+
+#[cfg(feature = "rolling_window")]
+pub(super) fn rolling(
+    s: &Column,
+    function: polars_ops::prelude::ListRollingFunction,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+) -> PolarsResult<Column> {
+    use polars_compute::rolling::{
+        QuantileMethod, RollingFnParams, RollingQuantileParams, RollingVarParams,
+    };
+    use polars_core::prelude::RollingOptionsFixedWindow;
+    use polars_ops::prelude::ListRollingFunction as R;
+
+    use super::rolling as r;
+
+    let mut options = RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..Default::default()
+    };
+    let f: fn(&Column, RollingOptionsFixedWindow) -> PolarsResult<Column> = match function {
+        R::Min => r::rolling_min,
+        R::Max => r::rolling_max,
+        R::Mean => r::rolling_mean,
+        R::Sum => r::rolling_sum,
+        R::Median => {
+            options.fn_params = Some(RollingFnParams::Quantile(RollingQuantileParams {
+                prob: 0.5,
+                method: QuantileMethod::Linear,
+            }));
+            r::rolling_quantile
+        },
+        R::Std(ddof) => {
+            options.fn_params = Some(RollingFnParams::Var(RollingVarParams { ddof }));
+            r::rolling_std
+        },
+        R::Var(ddof) => {
+            options.fn_params = Some(RollingFnParams::Var(RollingVarParams { ddof }));
+            r::rolling_var
+        },
+    };
+
+    let out = s.list()?.try_apply_amortized(|s| {
+        let s = s.as_ref().clone().into_column();
+        f(&s, options.clone()).map(Column::take_materialized_series)
+    })?;
+    Ok(out.into_column())
+}
+
 pub(super) fn sort(s: &Column, options: SortOptions) -> PolarsResult<Column> {
     Ok(s.list()?.lst_sort(options)?.into_column())
 }
@@ -365,6 +450,7 @@ pub(super) fn unique(s: &Column, is_stable: bool) -> PolarsResult<Column> {
 pub(super) fn set_operation(
     s: &[Column],
     set_type: polars_ops::prelude::SetOperation,
+    multiset: bool,
 ) -> PolarsResult<Column> {
     let s0 = &s[0];
     let s1 = &s[1];
@@ -391,7 +477,7 @@ pub(super) fn set_operation(
         };
     }
 
-    polars_ops::prelude::list_set_operation(s0.list()?, s1.list()?, set_type)
+    polars_ops::prelude::list_set_operation(s0.list()?, s1.list()?, set_type, multiset)
         .map(|ca| ca.into_column())
 }
 
@@ -1,15 +1,21 @@
-use polars_core::error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_core::error::{PolarsResult, polars_bail, polars_ensure, polars_err, polars_warn};
 use polars_core::prelude::row_encode::{_get_rows_encoded_ca, _get_rows_encoded_ca_unordered};
 use polars_core::prelude::*;
 use polars_core::scalar::Scalar;
 use polars_core::series::Series;
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::try_get_supertype;
+#[cfg(feature = "mode")]
+use polars_ops::prelude::ModeTies;
+#[cfg(feature = "approx_unique")]
+use polars_ops::series::HllFunction;
+#[cfg(feature = "interpolate_by")]
+use polars_ops::series::InterpolateByMethod;
 #[cfg(feature = "interpolate")]
 use polars_ops::series::InterpolationMethod;
 #[cfg(feature = "rank")]
 use polars_ops::series::RankOptions;
-use polars_ops::series::{ArgAgg, NullStrategy, SeriesMethods};
+use polars_ops::series::{ArgAgg, CheckedArithmeticOp, NullStrategy, SeriesMethods};
 #[cfg(feature = "dtype-array")]
 use polars_plan::dsl::ReshapeDimension;
 #[cfg(feature = "fused")]
@@ -31,9 +37,49 @@ pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "approx_unique")]
-pub(super) fn approx_n_unique(s: &Column) -> PolarsResult<Column> {
-    s.approx_n_unique()
-        .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
+pub(super) fn approx_n_unique(s: &Column, precision: Option<u8>) -> PolarsResult<Column> {
+    match precision {
+        None => s.approx_n_unique(),
+        Some(precision) => {
+            polars_ops::series::hll_approx_n_unique(s.as_materialized_series(), precision)
+        },
+    }
+    .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
+}
+
+#[cfg(feature = "approx_unique")]
+pub(super) fn hll(s: &Column, f: HllFunction) -> PolarsResult<Column> {
+    use polars_ops::series::{hll_estimate, hll_merge, hll_sketch};
+
+    let sketch = match f {
+        HllFunction::Sketch { precision } => {
+            Some(hll_sketch(s.as_materialized_series(), precision)?)
+        },
+        HllFunction::Merge => hll_merge(s.binary()?)?,
+        HllFunction::Estimate => return Ok(hll_estimate(s.binary()?)?.into_column()),
+    };
+    let value = sketch.map_or(AnyValue::Null, AnyValue::BinaryOwned);
+    Ok(Column::new_scalar(
+        s.name().clone(),
+        Scalar::new(DataType::Binary, value),
+        1,
+    ))
+}
+
+#[cfg(feature = "approx_quantile")]
+pub(super) fn approx_quantile(s: &Column, quantile: f64, compression: f64) -> PolarsResult<Column> {
+    let out =
+        polars_ops::series::approx_quantile(s.as_materialized_series(), quantile, compression)?;
+    let out = Column::new_scalar(
+        s.name().clone(),
+        Scalar::new(DataType::Float64, out.into()),
+        1,
+    );
+    if s.dtype().is_float() {
+        out.cast(s.dtype())
+    } else {
+        Ok(out)
+    }
 }
 
 #[cfg(feature = "diff")]
@@ -64,12 +110,12 @@ pub(super) fn interpolate(s: &Column, method: InterpolationMethod) -> PolarsResu
 }
 
 #[cfg(feature = "interpolate_by")]
-pub(super) fn interpolate_by(s: &[Column]) -> PolarsResult<Column> {
+pub(super) fn interpolate_by(s: &[Column], method: InterpolateByMethod) -> PolarsResult<Column> {
     use polars_ops::series::SeriesMethods;
 
     let by = &s[1];
     let by_is_sorted = by.as_materialized_series().is_sorted(Default::default())?;
-    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
+    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted, method, s.get(2))
 }
 
 pub(super) fn to_physical(s: &Column) -> PolarsResult<Column> {
@@ -181,8 +227,15 @@ pub fn append(s: &[Column], upcast: bool) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "mode")]
-pub(super) fn mode(s: &Column, maintain_order: bool) -> PolarsResult<Column> {
-    polars_ops::prelude::mode::mode(s.as_materialized_series(), maintain_order).map(Column::from)
+pub(super) fn mode(s: &Column, maintain_order: bool, ties: ModeTies) -> PolarsResult<Column> {
+    polars_ops::prelude::mode::mode(s.as_materialized_series(), maintain_order, ties)
+        .map(Column::from)
+}
+
+#[cfg(feature = "mode")]
+pub(super) fn modes(s: &Column, n: IdxSize) -> PolarsResult<Column> {
+    let out = polars_ops::prelude::mode::modes(s.as_materialized_series(), n as usize)?;
+    Ok(out.implode()?.into_column())
 }
 
 #[cfg(feature = "moment")]
@@ -366,6 +419,20 @@ pub(super) fn extend_constant(s: &[Column]) -> PolarsResult<Column> {
     }
 }
 
+#[cfg(feature = "hash_digest")]
+pub(super) fn hash_digest(
+    c: &Column,
+    algorithm: polars_ops::series::HashAlgorithm,
+    hex: bool,
+) -> PolarsResult<Column> {
+    polars_ops::series::hash_digest(c.as_materialized_series(), algorithm, hex).map(Column::from)
+}
+
+#[cfg(feature = "row_hash")]
+pub(super) fn stable_hash(c: &Column, seed: u64) -> PolarsResult<Column> {
+    Ok(c.as_materialized_series().stable_hash(seed)?.into_column())
+}
+
 #[cfg(feature = "row_hash")]
 pub(super) fn row_hash(c: &Column, k0: u64, k1: u64, k2: u64, k3: u64) -> PolarsResult<Column> {
     use std::hash::BuildHasher;
@@ -501,6 +568,18 @@ pub(super) fn search_sorted_impl(
     .map(|ca| ca.into_column())
 }
 
+#[cfg(feature = "search_sorted")]
+pub(super) fn search_sorted_by(
+    s: &mut [Column],
+    side: polars_ops::series::SearchSortedSide,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+) -> PolarsResult<Column> {
+    let (by, search_values) = s.split_at(s.len() / 2);
+    polars_ops::series::search_sorted_multiple(by, search_values, side, &descending, &nulls_last)
+        .map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "sign")]
 pub(super) fn sign(s: &Column) -> PolarsResult<Column> {
     use num_traits::{One, Zero};
@@ -878,6 +957,35 @@ pub(super) fn peak_max(s: &Column) -> PolarsResult<Column> {
         .map(IntoColumn::into_column)
 }
 
+#[cfg(feature = "weighted_agg")]
+pub(super) fn weighted(
+    s: &[Column],
+    agg: polars_ops::series::WeightedAggregation,
+) -> PolarsResult<Column> {
+    use polars_ops::series::{WeightedAggregation, weighted_mean, weighted_quantile, weighted_var};
+
+    let values = s[0].as_materialized_series();
+    let weights = s[1].as_materialized_series();
+    let out = match agg {
+        WeightedAggregation::Mean => weighted_mean(values, weights)?,
+        WeightedAggregation::Var { ddof } => weighted_var(values, weights, ddof)?,
+        WeightedAggregation::Quantile => {
+            let quantile = &s[2];
+            polars_ensure!(
+                quantile.len() == 1,
+                ComputeError: "expected a single quantile, got {} values", quantile.len()
+            );
+            let quantile: f64 = quantile.get(0).unwrap().try_extract()?;
+            weighted_quantile(values, weights, quantile)?
+        },
+    };
+    let dtype = match s[0].dtype() {
+        dt if dt.is_float() => dt.clone(),
+        _ => DataType::Float64,
+    };
+    Column::new(s[0].name().clone(), [out]).cast(&dtype)
+}
+
 #[cfg(feature = "cutqcut")]
 pub(super) fn cut(
     s: &Column,
@@ -885,6 +993,7 @@ pub(super) fn cut(
     labels: Option<Vec<PlSmallStr>>,
     left_closed: bool,
     include_breaks: bool,
+    include_bins: bool,
 ) -> PolarsResult<Column> {
     polars_ops::prelude::cut(
         s.as_materialized_series(),
@@ -892,6 +1001,7 @@ pub(super) fn cut(
         labels,
         left_closed,
         include_breaks,
+        include_bins,
     )
     .map(Column::from)
 }
@@ -904,6 +1014,7 @@ pub(super) fn qcut(
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    include_bins: bool,
 ) -> PolarsResult<Column> {
     polars_ops::prelude::qcut(
         s.as_materialized_series(),
@@ -912,6 +1023,7 @@ pub(super) fn qcut(
         left_closed,
         allow_duplicates,
         include_breaks,
+        include_bins,
     )
     .map(Column::from)
 }
@@ -960,6 +1072,26 @@ pub(super) fn ewm_mean_by(s: &[Column], half_life: polars_time::Duration) -> Pol
     .map(Column::from)
 }
 
+pub(super) fn checked_arithmetic(
+    s: &[Column],
+    op: CheckedArithmeticOp,
+    warn: bool,
+) -> PolarsResult<Column> {
+    let (out, overflow_count) = polars_ops::series::checked_arithmetic(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        op,
+    )?;
+    if warn && overflow_count > 0 {
+        polars_warn!(
+            "{} value(s) overflowed in checked {} and were set to null",
+            overflow_count,
+            <&str>::from(op)
+        );
+    }
+    Ok(out.into_column())
+}
+
 pub fn row_encode(
     c: &mut [Column],
     dts: Vec<DataType>,
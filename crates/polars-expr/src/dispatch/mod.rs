@@ -179,6 +179,12 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Hash(k0, k1, k2, k3) => {
             map!(misc::row_hash, k0, k1, k2, k3)
         },
+        #[cfg(feature = "row_hash")]
+        F::StableHash { seed } => map!(misc::stable_hash, seed),
+        #[cfg(feature = "hash_digest")]
+        F::HashDigest { algorithm, hex } => {
+            map!(misc::hash_digest, algorithm, hex)
+        },
         #[cfg(feature = "arg_where")]
         F::ArgWhere => {
             wrap!(misc::arg_where)
@@ -191,6 +197,17 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::SearchSorted { side, descending } => {
             map_as_slice!(misc::search_sorted_impl, side, descending)
         },
+        #[cfg(feature = "search_sorted")]
+        F::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        } => map_as_slice!(
+            misc::search_sorted_by,
+            side,
+            descending.clone(),
+            nulls_last.clone()
+        ),
         #[cfg(feature = "range")]
         F::Range(func) => range::function_expr_to_udf(func),
 
@@ -242,6 +259,18 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
                 Map(f) => {
                     map!(rolling::rolling_map, options.clone(), f.clone())
                 },
+                #[cfg(feature = "dtype-array")]
+                MapBatches {
+                    function,
+                    batch_size,
+                } => {
+                    map!(
+                        rolling::rolling_map_batches,
+                        options.clone(),
+                        function.clone(),
+                        *batch_size
+                    )
+                },
             }
         },
         #[cfg(feature = "rolling_window_by")]
@@ -284,7 +313,12 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
             map_as_slice!(misc::clip, has_min, has_max)
         },
         #[cfg(feature = "mode")]
-        F::Mode { maintain_order } => map!(misc::mode, maintain_order),
+        F::Mode {
+            maintain_order,
+            ties,
+        } => map!(misc::mode, maintain_order, ties),
+        #[cfg(feature = "mode")]
+        F::Modes(n) => map!(misc::modes, n),
         #[cfg(feature = "moment")]
         F::Skew(bias) => map!(misc::skew, bias),
         #[cfg(feature = "moment")]
@@ -325,6 +359,10 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::CumMin { reverse } => map!(cum::cum_min, reverse),
         #[cfg(feature = "cum_agg")]
         F::CumMax { reverse } => map!(cum::cum_max, reverse),
+        #[cfg(feature = "cum_agg")]
+        F::CumAggReset { function, reverse } => {
+            map_as_slice!(cum::cum_agg_reset, function, reverse)
+        },
         #[cfg(feature = "dtype-struct")]
         F::ValueCounts {
             sort,
@@ -336,7 +374,14 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::UniqueCounts => map!(misc::unique_counts),
         F::Reverse => map!(misc::reverse),
         #[cfg(feature = "approx_unique")]
-        F::ApproxNUnique => map!(misc::approx_n_unique),
+        F::ApproxNUnique { precision } => map!(misc::approx_n_unique, precision),
+        #[cfg(feature = "approx_unique")]
+        F::Hll(f) => map!(misc::hll, f),
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile {
+            quantile,
+            compression,
+        } => map!(misc::approx_quantile, quantile, compression),
         F::Coalesce => map_as_slice!(misc::coalesce),
         #[cfg(feature = "diff")]
         F::Diff(null_behavior) => map_as_slice!(misc::diff, null_behavior),
@@ -347,8 +392,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
             map!(misc::interpolate, method)
         },
         #[cfg(feature = "interpolate_by")]
-        F::InterpolateBy => {
-            map_as_slice!(misc::interpolate_by)
+        F::InterpolateBy(method) => {
+            map_as_slice!(misc::interpolate_by, method)
         },
         #[cfg(feature = "log")]
         F::Entropy { base, normalize } => map!(misc::entropy, base, normalize),
@@ -369,11 +414,18 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         F::Floor => map!(round::floor),
         #[cfg(feature = "round_series")]
         F::Ceil => map!(round::ceil),
+        #[cfg(feature = "decimal_arithmetic")]
+        F::DecimalArithmetic { op, options } => {
+            map_as_slice!(round::decimal_arithmetic, op, options)
+        },
+        F::CheckedArithmetic { op, warn } => map_as_slice!(misc::checked_arithmetic, op, warn),
         #[cfg(feature = "fused")]
         F::Fused(op) => map_as_slice!(misc::fused, op),
         F::ConcatExpr(rechunk) => map_as_slice!(misc::concat_expr, rechunk),
         #[cfg(feature = "cov")]
         F::Correlation { method } => map_as_slice!(misc::corr, method),
+        #[cfg(feature = "weighted_agg")]
+        F::Weighted(agg) => map_as_slice!(misc::weighted, agg),
         #[cfg(feature = "peaks")]
         F::PeakMin => map!(misc::peak_min),
         #[cfg(feature = "peaks")]
@@ -388,12 +440,14 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
             labels,
             left_closed,
             include_breaks,
+            include_bins,
         } => map!(
             misc::cut,
             breaks.clone(),
             labels.clone(),
             left_closed,
-            include_breaks
+            include_breaks,
+            include_bins
         ),
         #[cfg(feature = "cutqcut")]
         F::QCut {
@@ -402,13 +456,15 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bins,
         } => map!(
             misc::qcut,
             probs.clone(),
             labels.clone(),
             left_closed,
             allow_duplicates,
-            include_breaks
+            include_breaks,
+            include_bins
         ),
         #[cfg(feature = "rle")]
         F::RLE => map!(polars_ops::series::rle),
@@ -432,6 +488,8 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
                         map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                     }
                 },
+                #[cfg(feature = "uuid")]
+                Uuid4 => map!(random::uuid4, seed),
             }
         },
         F::SetSortedFlag(sortedness) => map!(misc::set_sorted_flag, sortedness),
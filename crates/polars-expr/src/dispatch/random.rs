@@ -1,5 +1,7 @@
 use polars_core::error::{PolarsResult, polars_ensure};
 use polars_core::prelude::DataType::Float64;
+#[cfg(feature = "uuid")]
+use polars_core::prelude::IntoColumn;
 use polars_core::prelude::{Column, IDX_DTYPE};
 
 pub(super) fn shuffle(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
@@ -51,3 +53,15 @@ pub(super) fn sample_n(
         None => Ok(Column::new_empty(src.name().clone(), src.dtype())),
     }
 }
+
+#[cfg(feature = "uuid")]
+pub(super) fn uuid4(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
+    polars_ensure!(
+        s.len() == 1,
+        ComputeError: "Number of UUIDs must be a single value."
+    );
+
+    let n_s = s.strict_cast(&IDX_DTYPE)?;
+    let n = n_s.idx()?.get(0).unwrap_or(0);
+    Ok(polars_ops::prelude::uuid4(s.name().clone(), 0, n as usize, seed).into_column())
+}
@@ -230,3 +230,109 @@ pub fn rolling_map(
         )
         .map(Column::from)
 }
+
+/// Calls `f` with batches of up to `batch_size` windows at once, as an array column with a row
+/// per window, which has to return a value per window.
+#[cfg(feature = "dtype-array")]
+pub fn rolling_map_batches(
+    c: &Column,
+    rolling_options: RollingOptionsFixedWindow,
+    f: PlanCallback<Series, Series>,
+    batch_size: usize,
+) -> PolarsResult<Column> {
+    use polars_core::error::polars_ensure;
+    use polars_core::prelude::{IdxCa, IdxSize, NewChunkedArray};
+    use polars_plan::dsl::ReshapeDimension;
+
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        weights,
+        ..
+    } = rolling_options;
+    polars_ensure!(
+        window_size >= min_periods,
+        ComputeError: "`window_size`: {} should be >= `min_periods`: {}",
+        window_size, min_periods
+    );
+    polars_ensure!(batch_size > 0, InvalidOperation: "`batch_size` should be positive");
+
+    let s = c.as_materialized_series();
+    let s = if weights.is_some() && !s.dtype().is_float() {
+        s.cast(&DataType::Float64)?
+    } else {
+        s.rechunk()
+    };
+    let weights = weights
+        .map(|w| Series::new(PlSmallStr::from_static("weights"), w).cast(s.dtype()))
+        .transpose()?;
+
+    let len = s.len();
+    let window_size = window_size.min(len);
+    // The windows with enough values, as (row, start, size).
+    let windows: Vec<_> = (0..len)
+        .filter_map(|idx| {
+            let (start, size) = if center {
+                let right_window = window_size.div_ceil(2);
+                let start = idx.saturating_sub(window_size - right_window);
+                (start, len.min(idx + right_window) - start)
+            } else {
+                let start = idx.saturating_sub(window_size - 1);
+                (start, idx + 1 - start)
+            };
+            let valid = size >= min_periods
+                && size - s.slice(start as i64, size).null_count() >= min_periods;
+            valid.then_some((idx, start, size))
+        })
+        .collect();
+
+    let mut rows = vec![None; len];
+    let mut values = Series::new_empty(s.name().clone(), s.dtype());
+    // The windows of a batch need to have the same size to form an array.
+    let batches = windows
+        .chunk_by(|a, b| a.2 == b.2)
+        .flat_map(|w| w.chunks(batch_size));
+    for batch in batches {
+        let size = batch[0].2;
+        let idx: Vec<IdxSize> = batch
+            .iter()
+            .flat_map(|&(_, start, size)| (start..start + size).map(|i| i as IdxSize))
+            .collect();
+        let mut flat = s.take_slice(&idx)?;
+        if let Some(weights) = &weights {
+            // Windows cut off at the start use the last weights.
+            let offset = |start: usize| {
+                if start == 0 {
+                    weights.len().saturating_sub(size)
+                } else {
+                    0
+                }
+            };
+            let idx: Vec<IdxSize> = batch
+                .iter()
+                .flat_map(|&(_, start, _)| offset(start)..offset(start) + size)
+                .map(|i| i as IdxSize)
+                .collect();
+            flat = (&flat * &weights.take_slice(&idx)?)?;
+        }
+        let dims = [
+            ReshapeDimension::Infer,
+            ReshapeDimension::new_dimension(size as u64),
+        ];
+
+        let out = f.call(flat.reshape_array(&dims)?)?;
+        polars_ensure!(
+            out.len() == batch.len(),
+            ShapeMismatch: "rolling_map expected {} values for a batch of windows, got {}",
+            batch.len(), out.len()
+        );
+        for (i, &(row, _, _)) in batch.iter().enumerate() {
+            rows[row] = Some((values.len() + i) as IdxSize);
+        }
+        values.append(&out.strict_cast(s.dtype())?)?;
+    }
+
+    let idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, rows.into_iter());
+    Ok(values.take(&idx)?.into_column())
+}
@@ -3,6 +3,8 @@ use polars_core::prelude::Column;
 use polars_core::series::Series;
 use polars_ops::series::RoundSeries;
 use polars_ops::series::round::RoundMode;
+#[cfg(feature = "decimal_arithmetic")]
+use polars_ops::series::{DecimalArithmeticOp, DecimalArithmeticOptions};
 
 pub(super) fn round(c: &Column, decimals: u32, mode: RoundMode) -> PolarsResult<Column> {
     c.try_apply_unary_elementwise(|s| s.round(decimals, mode))
@@ -23,3 +25,18 @@ pub(super) fn floor(c: &Column) -> PolarsResult<Column> {
 pub(super) fn ceil(c: &Column) -> PolarsResult<Column> {
     c.try_apply_unary_elementwise(Series::ceil)
 }
+
+#[cfg(feature = "decimal_arithmetic")]
+pub(super) fn decimal_arithmetic(
+    s: &[Column],
+    op: DecimalArithmeticOp,
+    options: DecimalArithmeticOptions,
+) -> PolarsResult<Column> {
+    polars_ops::series::decimal_arithmetic(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        op,
+        options,
+    )
+    .map(Column::from)
+}
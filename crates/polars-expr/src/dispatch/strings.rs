@@ -5,10 +5,12 @@ use polars_core::prelude::*;
 use polars_core::utils::{CustomIterTools, handle_casting_failures};
 #[cfg(feature = "regex")]
 use polars_ops::chunked_array::strings::split_regex_helper;
+#[cfg(any(feature = "binary_encoding", feature = "string_encoding"))]
+use polars_ops::prelude::Base64Alphabet;
 use polars_ops::prelude::{BinaryNameSpaceImpl, StringNameSpaceImpl};
-#[cfg(feature = "temporal")]
-use polars_plan::dsl::StrptimeOptions;
 use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+#[cfg(feature = "temporal")]
+use polars_plan::dsl::{StrptimeMultiOptions, StrptimeOptions};
 use polars_plan::plans::IRStringFunction;
 use polars_time::prelude::StringMethods;
 #[cfg(feature = "regex")]
@@ -57,12 +59,20 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         Strptime(dtype, options) => {
             map_as_slice!(strings::strptime, dtype.clone(), &options)
         },
+        #[cfg(feature = "temporal")]
+        StrptimeMulti(dtype, options) => {
+            map_as_slice!(strings::strptime_multi, dtype.clone(), &options)
+        },
         Split(inclusive) => {
             map_as_slice!(strings::split, inclusive)
         },
         #[cfg(feature = "regex")]
-        SplitRegex { inclusive, strict } => {
-            map_as_slice!(strings::split_regex, inclusive, strict)
+        SplitRegex {
+            n,
+            inclusive,
+            strict,
+        } => {
+            map_as_slice!(strings::split_regex, n, inclusive, strict)
         },
         #[cfg(feature = "dtype-struct")]
         SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
@@ -84,8 +94,27 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         Normalize { form } => map!(strings::normalize, form.clone()),
         #[cfg(feature = "string_reverse")]
         Reverse => map!(strings::reverse),
+        #[cfg(feature = "string_similarity")]
+        LevenshteinDistance => map_as_slice!(strings::levenshtein_distance),
+        #[cfg(feature = "string_similarity")]
+        HammingDistance => map_as_slice!(strings::hamming_distance),
+        #[cfg(feature = "string_similarity")]
+        JaroWinkler => map_as_slice!(strings::jaro_winkler),
+        #[cfg(feature = "string_tokenize")]
+        Tokenize {
+            method,
+            lowercase,
+            stop_words,
+        } => map!(strings::tokenize, &method, lowercase, &stop_words),
+        #[cfg(feature = "string_url")]
+        UrlPart(part) => map!(strings::url_part, part),
+        #[cfg(feature = "string_url")]
+        UrlQueryParams(keys) => map!(strings::url_query_params, keys.as_deref()),
+        #[cfg(feature = "string_url")]
+        UrlDecodePercent => map!(strings::url_decode_percent),
         Uppercase => map!(uppercase),
         Lowercase => map!(lowercase),
+        Casefold => map!(casefold),
         #[cfg(feature = "nightly")]
         Titlecase => map!(strings::titlecase),
         StripChars => map_as_slice!(strings::strip_chars),
@@ -105,15 +134,25 @@ pub fn function_expr_to_udf(func: IRStringFunction) -> SpecialEq<Arc<dyn Columns
         #[cfg(feature = "binary_encoding")]
         HexDecode(strict) => map!(strings::hex_decode, strict),
         #[cfg(feature = "string_encoding")]
-        Base64Encode => map!(strings::base64_encode),
+        Base64Encode { alphabet, padding } => map!(strings::base64_encode, alphabet, padding),
         #[cfg(feature = "binary_encoding")]
-        Base64Decode(strict) => map!(strings::base64_decode, strict),
+        Base64Decode { alphabet, strict } => map!(strings::base64_decode, alphabet, strict),
+        #[cfg(feature = "ip_address")]
+        ParseIp { strict } => map!(strings::parse_ip, strict),
+        #[cfg(feature = "uuid")]
+        ParseUuid { strict } => map!(strings::parse_uuid, strict),
         #[cfg(feature = "dtype-decimal")]
         ToDecimal { scale } => map!(strings::to_decimal, scale),
         #[cfg(feature = "extract_jsonpath")]
         JsonDecode(dtype) => map!(strings::json_decode, dtype.clone()),
         #[cfg(feature = "extract_jsonpath")]
         JsonPathMatch => map_as_slice!(strings::json_path_match),
+        #[cfg(feature = "extract_jsonpath")]
+        JsonPathMatchAll(dtype) => map_as_slice!(strings::json_path_match_all, dtype.as_ref()),
+        #[cfg(feature = "extract_jsonpath")]
+        JsonExtractMany { paths, dtype } => {
+            map!(strings::json_extract_many, paths.as_ref(), dtype.as_ref())
+        },
         #[cfg(feature = "find_many")]
         ContainsAny {
             ascii_case_insensitive,
@@ -225,6 +264,11 @@ fn lowercase(s: &Column) -> PolarsResult<Column> {
     Ok(ca.to_lowercase().into_column())
 }
 
+fn casefold(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.casefold().into_column())
+}
+
 #[cfg(feature = "nightly")]
 pub(super) fn titlecase(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -425,6 +469,108 @@ pub(super) fn strptime(
     }
 }
 
+/// Parse the values that are still `pending`, leaving the others null.
+#[cfg(feature = "temporal")]
+fn strptime_pending(
+    ca: &StringChunked,
+    pending: &BooleanChunked,
+    ambiguous: &Column,
+    dtype: &DataType,
+    options: &StrptimeOptions,
+) -> PolarsResult<Column> {
+    let values = ca.zip_with(
+        pending,
+        &StringChunked::full_null(ca.name().clone(), ca.len()),
+    )?;
+    let parsed = strptime(
+        &[values.into_column(), ambiguous.clone()],
+        dtype.clone(),
+        options,
+    )?;
+    parsed.cast(dtype)
+}
+
+#[cfg(feature = "temporal")]
+pub(super) fn strptime_multi(
+    s: &[Column],
+    dtype: DataType,
+    options: &StrptimeMultiOptions,
+) -> PolarsResult<Column> {
+    let ambiguous = &s[1];
+    let strings = match s[0].len() {
+        1 if ambiguous.len() > 1 => s[0].new_from_index(0, ambiguous.len()),
+        _ => s[0].clone(),
+    };
+    let ca = strings.str()?;
+    let name = ca.name().clone();
+
+    let mut pending = ca.is_not_null();
+    let mut out = Column::full_null(name.clone(), ca.len(), &dtype);
+    // Index of the format that parsed each value.
+    let mut matched = UInt32Chunked::full_null(name.clone(), ca.len());
+
+    for (idx, format) in options.formats.iter().enumerate() {
+        if !pending.any() {
+            break;
+        }
+        let parsed = strptime_pending(
+            ca,
+            &pending,
+            ambiguous,
+            &dtype,
+            &options.single(Some(format.clone())),
+        )?;
+        let hit = parsed.is_not_null();
+        out = parsed.zip_with(&hit, &out)?;
+        matched =
+            UInt32Chunked::full(name.clone(), idx as u32, ca.len()).zip_with(&hit, &matched)?;
+        pending = &pending & &!&hit;
+    }
+
+    // Every pass infers the format from the first pending value that it recognises,
+    // so keep going for as long as new values get parsed.
+    if options.best_effort {
+        while pending.any() {
+            let Ok(parsed) =
+                strptime_pending(ca, &pending, ambiguous, &dtype, &options.single(None))
+            else {
+                break;
+            };
+            let hit = parsed.is_not_null();
+            if !hit.any() {
+                break;
+            }
+            out = parsed.zip_with(&hit, &out)?;
+            pending = &pending & &!&hit;
+        }
+    }
+
+    if options.strict && pending.any() {
+        handle_casting_failures(
+            strings.as_materialized_series(),
+            out.as_materialized_series(),
+        )?;
+    }
+
+    if options.include_format {
+        #[cfg(feature = "dtype-struct")]
+        {
+            let format = StringChunked::from_iter_options(
+                PlSmallStr::from_static("format"),
+                matched
+                    .iter()
+                    .map(|idx| idx.map(|idx| options.formats[idx as usize].as_str())),
+            );
+            let value = out.with_name(PlSmallStr::from_static("value"));
+            let out = StructChunked::from_columns(name, ca.len(), &[value, format.into_column()])?;
+            return Ok(out.into_column());
+        }
+        #[cfg(not(feature = "dtype-struct"))]
+        polars_bail!(InvalidOperation: "activate 'dtype-struct' feature");
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "dtype-struct")]
 pub(super) fn split_exact(s: &[Column], n: usize, inclusive: bool) -> PolarsResult<Column> {
     let ca = s[0].str()?;
@@ -457,11 +603,16 @@ pub(super) fn split(s: &[Column], inclusive: bool) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "regex")]
-pub(super) fn split_regex(s: &[Column], inclusive: bool, strict: bool) -> PolarsResult<Column> {
+pub(super) fn split_regex(
+    s: &[Column],
+    n: Option<usize>,
+    inclusive: bool,
+    strict: bool,
+) -> PolarsResult<Column> {
     let ca = s[0].str()?;
     let by = s[1].str()?;
 
-    let out = split_regex_helper(ca, by, inclusive, strict)?;
+    let out = split_regex_helper(ca, by, n, inclusive, strict)?;
     Ok(out.into_column())
 }
 
@@ -770,6 +921,57 @@ pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein_distance(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "levenshtein_distance")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_levenshtein_distance(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming_distance(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "hamming")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_hamming_distance(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "jaro_winkler")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_jaro_winkler(other).into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn tokenize(
+    s: &Column,
+    method: &polars_ops::prelude::TokenizeMethod,
+    lowercase: bool,
+    stop_words: &[PlSmallStr],
+) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.str_tokenize(method, lowercase, stop_words)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_part(s: &Column, part: polars_ops::prelude::UrlPart) -> PolarsResult<Column> {
+    Ok(polars_ops::prelude::url_part(s.str()?, part).into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_query_params(s: &Column, keys: Option<&[PlSmallStr]>) -> PolarsResult<Column> {
+    polars_ops::prelude::url_query_params(s.str()?, keys).map(Column::from)
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_decode_percent(s: &Column) -> PolarsResult<Column> {
+    Ok(polars_ops::prelude::url_decode_percent(s.str()?).into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(
     s: &[Column],
@@ -836,13 +1038,35 @@ pub(super) fn hex_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "string_encoding")]
-pub(super) fn base64_encode(s: &Column) -> PolarsResult<Column> {
-    Ok(s.str()?.base64_encode().into_column())
+pub(super) fn base64_encode(
+    s: &Column,
+    alphabet: Base64Alphabet,
+    padding: bool,
+) -> PolarsResult<Column> {
+    Ok(s.str()?
+        .base64_encode_with_options(alphabet, padding)
+        .into_column())
 }
 
 #[cfg(feature = "binary_encoding")]
-pub(super) fn base64_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
-    s.str()?.base64_decode(strict).map(|ca| ca.into_column())
+pub(super) fn base64_decode(
+    s: &Column,
+    alphabet: Base64Alphabet,
+    strict: bool,
+) -> PolarsResult<Column> {
+    s.str()?
+        .base64_decode_with_options(alphabet, strict)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "ip_address")]
+pub(super) fn parse_ip(s: &Column, strict: bool) -> PolarsResult<Column> {
+    polars_ops::prelude::parse_ip(s.str()?, strict).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "uuid")]
+pub(super) fn parse_uuid(s: &Column, strict: bool) -> PolarsResult<Column> {
+    polars_ops::prelude::parse_uuid(s.str()?, strict).map(|ca| ca.into_column())
 }
 
 #[cfg(feature = "dtype-decimal")]
@@ -870,6 +1094,28 @@ pub(super) fn json_path_match(s: &[Column]) -> PolarsResult<Column> {
     Ok(ca.json_path_match(pat)?.into_column())
 }
 
+#[cfg(feature = "extract_jsonpath")]
+pub(super) fn json_path_match_all(s: &[Column], dtype: Option<&DataType>) -> PolarsResult<Column> {
+    use polars_ops::prelude::Utf8JsonPathImpl;
+
+    _check_same_length(s, "json_path_match")?;
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+    Ok(ca.json_path_match_all(pat, dtype)?.into_column())
+}
+
+#[cfg(feature = "extract_jsonpath")]
+pub(super) fn json_extract_many(
+    s: &Column,
+    paths: &[PlSmallStr],
+    dtype: Option<&DataType>,
+) -> PolarsResult<Column> {
+    use polars_ops::prelude::Utf8JsonPathImpl;
+
+    let ca = s.str()?;
+    Ok(ca.json_extract_many(paths, dtype)?.into_column())
+}
+
 #[cfg(feature = "regex")]
 pub(super) fn escape_regex(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -16,7 +16,9 @@ pub fn temporal_func_to_udf(func: IRTemporalFunction) -> SpecialEq<Arc<dyn Colum
         Month => map!(datetime::month),
         DaysInMonth => map!(datetime::days_in_month),
         Quarter => map!(datetime::quarter),
-        Week => map!(datetime::week),
+        FiscalYear(start_month) => map!(datetime::fiscal_year, start_month),
+        FiscalQuarter(start_month) => map!(datetime::fiscal_quarter, start_month),
+        Week(convention) => map!(datetime::week, convention),
         WeekDay => map!(datetime::weekday),
         #[cfg(feature = "dtype-duration")]
         Duration(tu) => map_as_slice!(polars_ops::series::impl_duration, tu),
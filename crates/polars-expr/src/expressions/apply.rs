@@ -110,8 +110,14 @@ impl ApplyExpr {
     }
 
     /// Evaluates and flattens `Option<Column>` to `Column`.
+    ///
+    /// Elementwise functions compute new values, so the metadata of their input is dropped.
     fn eval_and_flatten(&self, inputs: &mut [Column]) -> PolarsResult<Column> {
-        self.function.call_udf(inputs)
+        let mut out = self.function.call_udf(inputs)?;
+        if self.flags.is_elementwise() {
+            out.set_metadata(None);
+        }
+        Ok(out)
     }
 
     fn apply_single_group_aware<'a>(
@@ -232,7 +238,7 @@ impl ApplyExpr {
             .iter()
             .map(|ac| ac.get_values().clone())
             .collect::<Vec<_>>();
-        let out = self.function.call_udf(&mut cols)?;
+        let out = self.eval_and_flatten(&mut cols)?;
         polars_ensure!(
             out.len() == 1,
             ComputeError: "elementwise expression {:?} must return exactly 1 value on literals, got {}",
@@ -322,7 +328,7 @@ impl ApplyExpr {
                     .collect::<Vec<_>>();
 
                 let input_len = cols[base_ac_idx].len();
-                let out = self.function.call_udf(&mut cols)?;
+                let out = self.eval_and_flatten(&mut cols)?;
                 if self.check_lengths {
                     check_map_output_len(input_len, out.len(), &self.expr)?;
                 }
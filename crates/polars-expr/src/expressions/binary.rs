@@ -146,7 +146,8 @@ impl BinaryExpr {
                 let out = ca.apply_to_inner(&|_| {
                     apply_operator(&col_l, &col_r, self.op).map(|c| c.take_materialized_series())
                 })?;
-                let out = out.into_column();
+                let mut out = out.into_column();
+                out.set_metadata(None);
 
                 if ac_l.is_literal() {
                     std::mem::swap(&mut ac_l, &mut ac_r);
@@ -161,7 +162,8 @@ impl BinaryExpr {
                 let lhs = ac_l.get_values().clone();
                 let rhs = ac_r.get_values().clone();
 
-                let out = apply_operator_owned(lhs, rhs, self.op)?;
+                let mut out = apply_operator_owned(lhs, rhs, self.op)?;
+                out.set_metadata(None);
 
                 if ac_l.is_literal() {
                     std::mem::swap(&mut ac_l, &mut ac_r);
@@ -187,7 +189,8 @@ impl BinaryExpr {
 
         let left_c = ac_l.get_values().rechunk().into_column();
         let right_c = ac_r.get_values().rechunk().into_column();
-        let res_c = apply_operator(&left_c, &right_c, self.op)?;
+        let mut res_c = apply_operator(&left_c, &right_c, self.op)?;
+        res_c.set_metadata(None);
         polars_ensure!(res_c.len() == 1,
             ComputeError: "binary operation on literals expected 1 value, found {}", res_c.len());
 
@@ -260,7 +263,10 @@ impl PhysicalExpr for BinaryExpr {
             ShapeMismatch: "cannot evaluate two Series of different lengths ({} and {})",
             lhs.len(), rhs.len(),
         );
-        apply_operator_owned(lhs, rhs, self.op)
+        // The result holds new values, so the metadata of the inputs no longer applies.
+        let mut out = apply_operator_owned(lhs, rhs, self.op)?;
+        out.set_metadata(None);
+        Ok(out)
     }
 
     #[allow(clippy::ptr_arg)]
@@ -21,6 +21,8 @@ mod sortby;
 #[cfg(feature = "dtype-struct")]
 mod structeval;
 mod ternary;
+#[cfg(feature = "uuid")]
+mod uuid;
 mod window;
 
 use std::borrow::Cow;
@@ -54,6 +56,8 @@ pub(crate) use sortby::*;
 #[cfg(feature = "dtype-struct")]
 pub(crate) use structeval::*;
 pub(crate) use ternary::*;
+#[cfg(feature = "uuid")]
+pub(crate) use uuid::*;
 pub use window::window_function_format_order_by;
 pub(crate) use window::*;
 
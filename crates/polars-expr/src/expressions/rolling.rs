@@ -105,7 +105,7 @@ impl PhysicalExpr for RollingExpr {
 
         // @NOTE: This is a bit strange since it ignores errors, but it mirrors the in-memory
         // engine.
-        let tz = time_zone.and_then(|tz| tz.parse::<chrono_tz::Tz>().ok());
+        let tz = time_zone.and_then(|tz| tz.parse::<arrow::legacy::time_zone::Tz>().ok());
 
         polars_ensure!(
             index_column_data.null_count() == 0,
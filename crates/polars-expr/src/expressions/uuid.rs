@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+
+use super::*;
+use crate::expressions::{AggregationContext, PhysicalExpr};
+
+/// Generates a random UUID for every row of the [`DataFrame`].
+///
+/// The UUIDs are derived from the seed and the position of every row, such that evaluating on
+/// groups gives every row its own UUID, instead of repeating the same UUIDs in every group.
+pub struct Uuid4Expr {
+    seed: Option<u64>,
+    expr: Expr,
+    output_field: Field,
+}
+
+impl Uuid4Expr {
+    pub(crate) fn new(seed: Option<u64>, expr: Expr, output_field: Field) -> Self {
+        Self {
+            seed,
+            expr,
+            output_field,
+        }
+    }
+}
+
+impl PhysicalExpr for Uuid4Expr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate_impl(&self, df: &DataFrame, _state: &ExecutionState) -> PolarsResult<Column> {
+        let name = self.output_field.name().clone();
+        Ok(polars_ops::prelude::uuid4(name, 0, df.height(), self.seed).into_column())
+    }
+
+    fn evaluate_on_groups_impl<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let c = self.evaluate(df, state)?;
+        Ok(AggregationContext::new(c, Cow::Borrowed(groups), false))
+    }
+
+    fn to_field(&self, _input_schema: &Schema) -> PolarsResult<Field> {
+        Ok(self.output_field.clone())
+    }
+
+    fn is_scalar(&self) -> bool {
+        false
+    }
+}
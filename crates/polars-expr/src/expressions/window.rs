@@ -293,6 +293,69 @@ impl WindowExpr {
         agg_col
     }
 
+    /// The column, rank options and seed if the window function is a plain `col().rank()` that
+    /// is mapped back to the rows and has no `order_by`.
+    #[cfg(feature = "rank")]
+    fn partitioned_rank(&self) -> Option<(PlSmallStr, RankOptions, Option<u64>)> {
+        if self.order_by.is_some() || !matches!(self.mapping, WindowMapping::GroupsToRows) {
+            return None;
+        }
+        for e in &self.expr {
+            if let Expr::Over { function, .. } = e {
+                let mut function = &**function;
+                while let Expr::Alias(inner, _) = function {
+                    function = inner;
+                }
+                if let Expr::Function {
+                    input,
+                    function: FunctionExpr::Rank { options, seed },
+                } = function
+                    && let [Expr::Column(name)] = input.as_slice()
+                {
+                    return Some((name.clone(), *options, *seed));
+                }
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Ranks within all partitions at once without sorting the rows, instead of materializing and
+    /// sorting every group on its own.
+    #[cfg(feature = "rank")]
+    fn evaluate_partitioned_rank(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+        name: &PlSmallStr,
+        options: RankOptions,
+        seed: Option<u64>,
+    ) -> PolarsResult<Column> {
+        let mut partition_by_columns = self
+            .group_by
+            .iter()
+            .map(|e| e.evaluate(df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        for col in partition_by_columns.iter_mut() {
+            if col.len() != df.height() {
+                polars_ensure!(
+                    col.len() == 1,
+                    ShapeMismatch: "columns used as `partition_by` must have the same length as the DataFrame"
+                );
+                *col = col.new_from_index(0, df.height())
+            }
+        }
+
+        let s = df.column(name)?.as_materialized_series();
+        let mut ranker = polars_ops::series::PartitionedRanker::new(options, seed);
+        ranker.update(s, &partition_by_columns)?;
+        ranker.finish();
+        let out = ranker.rank(s, &partition_by_columns)?;
+        Ok(out
+            .with_name(self.output_field.name().clone())
+            .into_column())
+    }
+
     fn determine_map_strategy(
         &self,
         ac: &mut AggregationContext,
@@ -396,6 +459,11 @@ impl PhysicalExpr for WindowExpr {
             }
         }
 
+        #[cfg(feature = "rank")]
+        if let Some((name, options, seed)) = self.partitioned_rank() {
+            return self.evaluate_partitioned_rank(df, state, &name, options, seed);
+        }
+
         let mut group_by_columns = self
             .group_by
             .iter()
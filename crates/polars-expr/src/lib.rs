@@ -15,7 +15,7 @@ pub mod state;
 
 use polars_utils::IdxSize;
 
-pub use crate::planner::{ExpressionConversionState, create_physical_expr};
+pub use crate::planner::{ExpressionConversionState, create_physical_expr, evaluate_constant_expr};
 
 /// An index where the top bit indicates whether a value should be evicted.
 #[derive(Copy, Clone, Debug)]
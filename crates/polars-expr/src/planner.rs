@@ -145,6 +145,28 @@ pub fn create_physical_expr(
     }
 }
 
+/// Evaluate an expression that doesn't depend on any columns to a single [`Scalar`].
+///
+/// Returns `None` if the expression can't be evaluated or doesn't produce exactly one value.
+pub fn evaluate_constant_expr(node: Node, expr_arena: &mut Arena<AExpr>) -> Option<Scalar> {
+    let mut state = ExpressionConversionState::new(false);
+    let phys_expr =
+        create_physical_expr_inner(node, expr_arena, &Default::default(), &mut state).ok()?;
+
+    let out = phys_expr
+        .evaluate(
+            &DataFrame::empty_with_height(1),
+            &crate::state::ExecutionState::new(),
+        )
+        .ok()?;
+    if out.len() != 1 {
+        return None;
+    }
+
+    let value = out.get(0).ok()?.into_static();
+    Some(Scalar::new(out.dtype().clone(), value))
+}
+
 #[recursive]
 fn create_physical_expr_inner(
     expression: Node,
@@ -402,6 +424,24 @@ fn create_physical_expr_inner(
                 output_field,
             )))
         },
+        #[cfg(feature = "uuid")]
+        Function {
+            function:
+                IRFunctionExpr::Random {
+                    method: IRRandomMethod::Uuid4,
+                    seed,
+                },
+            ..
+        } => {
+            let output_field = expr_arena
+                .get(expression)
+                .to_field(&ToFieldContext::new(expr_arena, schema))?;
+            Ok(Arc::new(Uuid4Expr::new(
+                seed,
+                node_to_expr(expression, expr_arena),
+                output_field,
+            )))
+        },
         Function {
             input,
             function: function @ (IRFunctionExpr::ArgMin | IRFunctionExpr::ArgMax),
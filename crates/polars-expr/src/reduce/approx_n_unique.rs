@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
 use polars_core::with_match_physical_numeric_polars_type;
+use polars_ops::series::{HllHash, HllSketch, hll_cast_series, hll_hash};
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::total_ord::{BuildHasherTotalExt, TotalHash};
 
@@ -111,3 +112,119 @@ where
         Ok(ca.into_series())
     }
 }
+
+/// A reduction into a HyperLogLog sketch of `2^precision` registers, finished into either the
+/// approximate count of unique values or the serialized sketch.
+pub fn new_hll_reduction(
+    dtype: DataType,
+    precision: u8,
+    output_sketch: bool,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    use DataType::*;
+    use {HllReducer as R, VecGroupedReduction as VGR};
+    let (p, o) = (precision, output_sketch);
+    Ok(match dtype {
+        Boolean | Null => Box::new(VGR::new(dtype, R::<BooleanType>::new(p, o))),
+        _ if dtype.is_primitive_numeric() || dtype.is_temporal() => {
+            with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
+                Box::new(VGR::new(dtype, R::<$T>::new(p, o)))
+            })
+        },
+        String => Box::new(VGR::new(dtype, R::<StringType>::new(p, o))),
+        Binary => Box::new(VGR::new(dtype, R::<BinaryType>::new(p, o))),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => Box::new(VGR::new(dtype, R::<Int128Type>::new(p, o))),
+        // Categoricals are inserted as their strings, see `hll_cast_series`.
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Enum(_, _) | DataType::Categorical(_, _) => {
+            Box::new(VGR::new(dtype, R::<StringType>::new(p, o)))
+        },
+        _ => {
+            polars_bail!(InvalidOperation: "`approx_n_unique` operation not supported for dtype `{dtype}`")
+        },
+    })
+}
+
+struct HllReducer<T> {
+    precision: u8,
+    output_sketch: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T> HllReducer<T> {
+    fn new(precision: u8, output_sketch: bool) -> Self {
+        Self {
+            precision,
+            output_sketch,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for HllReducer<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.precision, self.output_sketch)
+    }
+}
+
+impl<T> Reducer for HllReducer<T>
+where
+    T: PolarsPhysicalType,
+    for<'a> T::Physical<'a>: HllHash,
+{
+    type Dtype = T;
+    type Value = HllSketch;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        HllSketch::with_precision(self.precision)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        // Consistent with `polars_ops::series::hll_insert`, so sketches can be merged.
+        hll_cast_series(s).unwrap()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.merge(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(
+        &self,
+        a: &mut Self::Value,
+        b: Option<<Self::Dtype as PolarsDataType>::Physical<'_>>,
+        _seq_id: u64,
+    ) {
+        a.add_hash(hll_hash(b));
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for val in ca.iter() {
+            v.add_hash(hll_hash(val));
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        _dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        if self.output_sketch {
+            let ca = BinaryChunked::from_iter_values(
+                PlSmallStr::EMPTY,
+                v.into_iter().map(|sketch| sketch.to_bytes()),
+            );
+            Ok(ca.into_series())
+        } else {
+            let ca: IdxCa = v
+                .into_iter()
+                .map(|sketch| sketch.count().min(IdxSize::MAX as usize) as IdxSize)
+                .collect_ca(PlSmallStr::EMPTY);
+            Ok(ca.into_series())
+        }
+    }
+}
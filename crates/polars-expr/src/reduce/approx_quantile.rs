@@ -0,0 +1,77 @@
+use polars_ops::series::TDigest;
+
+use super::*;
+
+/// A reduction into a t-digest with the given `compression`, finished into the approximate
+/// `quantile` of the values.
+pub fn new_approx_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+    compression: f64,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    polars_ensure!(
+        dtype.is_primitive_numeric() || dtype.is_decimal() || dtype.is_null(),
+        InvalidOperation: "`approx_quantile` operation not supported for dtype `{dtype}`"
+    );
+    let reducer = ApproxQuantileReducer {
+        quantile,
+        compression,
+    };
+    Ok(Box::new(VecGroupedReduction::new(dtype, reducer)))
+}
+
+#[derive(Clone)]
+struct ApproxQuantileReducer {
+    quantile: f64,
+    compression: f64,
+}
+
+impl Reducer for ApproxQuantileReducer {
+    type Dtype = Float64Type;
+    type Value = TDigest;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        TDigest::new(self.compression)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        Cow::Owned(s.cast(&DataType::Float64).unwrap())
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.merge(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<f64>, _seq_id: u64) {
+        if let Some(v) = b {
+            a.add(v);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            v.extend(arr.non_null_values_iter());
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let ca: Float64Chunked = v
+            .into_iter()
+            .map(|mut digest| digest.quantile(self.quantile))
+            .collect_ca(PlSmallStr::EMPTY);
+        if dtype.is_float() {
+            ca.into_series().cast(dtype)
+        } else {
+            Ok(ca.into_series())
+        }
+    }
+}
@@ -1,11 +1,15 @@
 // use polars_core::error::feature_gated;
+#[cfg(feature = "approx_unique")]
+use polars_ops::series::HllFunction;
 use polars_plan::prelude::*;
 use polars_utils::arena::{Arena, Node};
 
 use super::*;
 use crate::reduce::any_all::{new_all_reduction, new_any_reduction};
 #[cfg(feature = "approx_unique")]
-use crate::reduce::approx_n_unique::new_approx_n_unique_reduction;
+use crate::reduce::approx_n_unique::{new_approx_n_unique_reduction, new_hll_reduction};
+#[cfg(feature = "approx_quantile")]
+use crate::reduce::approx_quantile::new_approx_quantile_reduction;
 #[cfg(feature = "bitwise")]
 use crate::reduce::bitwise::{
     new_bitwise_and_reduction, new_bitwise_or_reduction, new_bitwise_xor_reduction,
@@ -119,12 +123,43 @@ pub fn into_reduction(
         #[cfg(feature = "approx_unique")]
         AExpr::Function {
             input: inner_exprs,
-            function: IRFunctionExpr::ApproxNUnique,
+            function: IRFunctionExpr::ApproxNUnique { precision },
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let out = match precision {
+                None => new_approx_n_unique_reduction(get_dt(input)?)?,
+                Some(precision) => new_hll_reduction(get_dt(input)?, *precision, false)?,
+            };
+            (out, input)
+        },
+
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::Hll(HllFunction::Sketch { precision }),
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let out = new_hll_reduction(get_dt(input)?, *precision, true)?;
+            (out, input)
+        },
+
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input: inner_exprs,
+            function:
+                IRFunctionExpr::ApproxQuantile {
+                    quantile,
+                    compression,
+                },
             options: _,
         } => {
             assert!(inner_exprs.len() == 1);
             let input = inner_exprs[0].node();
-            let out = new_approx_n_unique_reduction(get_dt(input)?)?;
+            let out = new_approx_quantile_reduction(get_dt(input)?, *quantile, *compression)?;
             (out, input)
         },
 
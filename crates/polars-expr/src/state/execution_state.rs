@@ -191,6 +191,11 @@ impl ExecutionState {
         self.stop.clone()
     }
 
+    /// Stop the query once `token` is set, instead of on the token of this state.
+    pub fn set_cancel_token(&mut self, token: Arc<RelaxedCell<bool>>) {
+        self.stop = token;
+    }
+
     pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
         match &self.node_timer {
             None => func(),
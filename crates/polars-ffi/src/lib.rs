@@ -6,8 +6,8 @@ use std::mem::ManuallyDrop;
 use arrow::array::ArrayRef;
 use arrow::ffi;
 use arrow::ffi::{ArrowArray, ArrowSchema};
-use polars_core::error::PolarsResult;
-use polars_core::prelude::Series;
+use polars_core::error::{PolarsResult, polars_ensure};
+use polars_core::prelude::{CompatLevel, Series};
 
 pub const MAJOR: u16 = 0;
 pub const MINOR: u16 = 1;
@@ -16,6 +16,67 @@ pub const fn get_version() -> (u16, u16) {
     (MAJOR, MINOR)
 }
 
+/// Encode a version as returned by `_polars_plugin_get_version`.
+pub const fn encode_version(major: u16, minor: u16) -> u32 {
+    ((major as u32) << 16) | minor as u32
+}
+
+/// Decode a version as returned by `_polars_plugin_get_version`.
+pub const fn decode_version(version: u32) -> (u16, u16) {
+    ((version >> 16) as u16, version as u16)
+}
+
+/// What both sides of the FFI boundary support, given the version the other side was compiled
+/// against.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    pub major: u16,
+    pub minor: u16,
+    /// The other side understands the `BinaryView`/`Utf8View` arrow layout.
+    pub views: bool,
+    /// The field function of expression plugins receives the serialized kwargs.
+    pub field_kwargs: bool,
+}
+
+impl Capabilities {
+    /// The compatibility level data should be exported with to be readable by the other side.
+    pub fn compat_level(&self) -> CompatLevel {
+        if self.views {
+            CompatLevel::newest()
+        } else {
+            CompatLevel::oldest()
+        }
+    }
+}
+
+/// Negotiate the capabilities of the FFI with a peer compiled against version `major.minor`.
+///
+/// Minor versions are backwards compatible, so peers compiled against an older minor version
+/// are supported with reduced capabilities. Peers compiled against a newer minor version or a
+/// different major version are rejected.
+pub fn negotiate(major: u16, minor: u16) -> PolarsResult<Capabilities> {
+    polars_ensure!(
+        major == MAJOR,
+        ComputeError: "incompatible polars FFI version\n\nThe other side was compiled against FFI version {}.{}, \
+        but this Polars version uses FFI version {}.{}. Recompile it against a compatible 'pyo3-polars' version.",
+        major, minor, MAJOR, MINOR
+    );
+    polars_ensure!(
+        minor <= MINOR,
+        ComputeError: "incompatible polars FFI version\n\nThe other side was compiled against FFI version {}.{}, \
+        which is newer than FFI version {}.{} used by this Polars version. Upgrade Polars or recompile \
+        it against an older 'pyo3-polars' version.",
+        major, minor, MAJOR, MINOR
+    );
+
+    Ok(Capabilities {
+        major,
+        minor,
+        views: minor >= 1,
+        field_kwargs: minor >= 1,
+    })
+}
+
 // A utility that helps releasing/owning memory.
 #[allow(dead_code)]
 struct PrivateData {
@@ -33,3 +94,24 @@ unsafe fn import_array(
     let out = ffi::import_array_from_c(array, field.dtype)?;
     Ok(out)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_negotiate() {
+        let (major, minor) = get_version();
+        assert_eq!(decode_version(encode_version(major, minor)), (major, minor));
+
+        let caps = negotiate(major, minor).unwrap();
+        assert!(caps.views && caps.field_kwargs);
+
+        let caps = negotiate(0, 0).unwrap();
+        assert!(!caps.views && !caps.field_kwargs);
+        assert_eq!(caps.compat_level(), CompatLevel::oldest());
+
+        assert!(negotiate(major, minor + 1).is_err());
+        assert!(negotiate(major + 1, 0).is_err());
+    }
+}
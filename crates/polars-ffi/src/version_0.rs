@@ -59,15 +59,19 @@ pub fn export_column(c: &Column) -> SeriesExport {
 }
 
 pub fn export_series(s: &Series) -> SeriesExport {
-    let field = s
-        .dtype()
-        .to_arrow_field(s.name().clone(), CompatLevel::newest());
+    export_series_with_compat(s, CompatLevel::newest())
+}
+
+/// Export a `Series` in a layout readable by a peer that supports `compat_level`, see
+/// [`Capabilities::compat_level`].
+pub fn export_series_with_compat(s: &Series, compat_level: CompatLevel) -> SeriesExport {
+    let field = s.dtype().to_arrow_field(s.name().clone(), compat_level);
     let schema = Box::new(ffi::export_field_to_c(&field));
 
     let mut arrays = (0..s.chunks().len())
         .map(|i| {
             // Make sure we export the logical type.
-            let arr = s.to_arrow(i, CompatLevel::newest());
+            let arr = s.to_arrow(i, compat_level);
             Box::into_raw(Box::new(ffi::export_array_to_c(arr.clone())))
         })
         .collect::<Box<_>>();
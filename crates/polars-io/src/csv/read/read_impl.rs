@@ -334,10 +334,7 @@ impl<'a> CoreReader<'a> {
                     &projection
                         .iter()
                         .map(|&i| self.schema.get_at_index(i).unwrap())
-                        .map(|(name, dtype)| Field {
-                            name: name.clone(),
-                            dtype: dtype.clone(),
-                        })
+                        .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
                         .collect::<Schema>(),
                 )
             };
@@ -49,7 +49,7 @@ use crate::hive::materialize_hive_partitions;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
-use crate::shared::{ArrowReader, finish_reader};
+use crate::shared::{ArrowReader, apply_column_metadata, finish_reader};
 
 #[derive(Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -316,6 +316,8 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             Ok(df)
         })()?;
 
+        apply_column_metadata(&mut df, reader_schema);
+
         if let Some(hive_cols) = hive_partition_columns {
             materialize_hive_partitions(&mut df, reader_schema, Some(hive_cols.as_slice()));
         };
@@ -8,7 +8,7 @@ use polars_core::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
-use crate::shared::schema_to_arrow_checked;
+use crate::shared::{schema_to_arrow_checked, with_column_metadata};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -116,6 +116,16 @@ impl<W: Write> IpcWriter<W> {
         ipc_fields: Vec<IpcField>,
     ) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
+        self.batched_arrow(schema, ipc_fields)
+    }
+
+    /// Like [`IpcWriter::batched`], but writes the given Arrow schema, e.g. one that holds the
+    /// metadata of the columns.
+    pub fn batched_arrow(
+        self,
+        schema: ArrowSchema,
+        ipc_fields: Vec<IpcField>,
+    ) -> PolarsResult<BatchedWriter<W>> {
         let mut writer = write::FileWriter::new(
             self.writer,
             Arc::new(schema),
@@ -156,6 +166,7 @@ where
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         let schema = schema_to_arrow_checked(df.schema(), self.compat_level, "ipc")?;
+        let schema = with_column_metadata(schema, df);
         let mut ipc_writer = write::FileWriter::try_new(
             &mut self.writer,
             Arc::new(schema),
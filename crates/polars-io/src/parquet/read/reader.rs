@@ -12,6 +12,7 @@ use crate::RowIndex;
 use crate::mmap::MmapBytesReader;
 use crate::parquet::metadata::FileMetadataRef;
 use crate::prelude::*;
+use crate::shared::apply_column_metadata;
 
 /// Read Apache parquet format into a DataFrame.
 #[must_use]
@@ -219,6 +220,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.row_index,
             self.hive_partition_columns.as_deref(),
         )?;
+        apply_column_metadata(&mut df, &schema);
 
         if self.rechunk {
             df.rechunk_mut_par();
@@ -12,7 +12,7 @@ use polars_parquet::write::{
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
 use super::{KeyValueMetadata, ParquetWriteOptions};
-use crate::shared::schema_to_arrow_checked;
+use crate::shared::{schema_to_arrow_checked, with_column_metadata};
 
 impl ParquetWriteOptions {
     pub fn to_writer<F>(&self, f: F) -> ParquetWriter<F>
@@ -117,6 +117,10 @@ where
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        self.batched_arrow(schema)
+    }
+
+    fn batched_arrow(self, schema: ArrowSchema) -> PolarsResult<BatchedWriter<W>> {
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
         let options = self.materialize_options();
@@ -144,8 +148,11 @@ where
     /// Write the given DataFrame in the writer `W`.
     /// Returns the total size of the file.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
+        // Take the column metadata before chunking, as re-chunking may not preserve it.
+        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::newest(), "parquet")?;
+        let schema = with_column_metadata(schema, df);
         let chunked_df = chunk_df_for_writing(df, self.row_group_size.unwrap_or(512 * 512))?;
-        let mut batched = self.batched(chunked_df.schema())?;
+        let mut batched = self.batched_arrow(schema)?;
         batched.write_batch(&chunked_df)?;
         batched.finish()
     }
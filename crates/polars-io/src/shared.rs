@@ -130,3 +130,29 @@ pub fn schema_to_arrow_checked(
         })
         .collect::<PolarsResult<ArrowSchema>>()
 }
+
+/// Attaches the custom key-value metadata of the columns of `df` to the fields of `schema`.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+pub fn with_column_metadata(mut schema: ArrowSchema, df: &DataFrame) -> ArrowSchema {
+    for (field, column) in schema.iter_values_mut().zip(df.columns()) {
+        if let Some(md) = column.metadata() {
+            field.metadata = merge_metadata(field.metadata.as_ref(), Some(&md));
+        }
+    }
+    schema
+}
+
+/// Applies the custom key-value metadata of the fields of `schema` to the matching columns of
+/// `df`.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+pub fn apply_column_metadata(df: &mut DataFrame, schema: &ArrowSchema) {
+    if schema.iter_values().all(|f| f.metadata.is_none()) {
+        return;
+    }
+    // SAFETY: setting metadata doesn't change the schema.
+    for column in unsafe { df.columns_mut_retain_schema() } {
+        if let Some(field) = schema.get(column.name()) {
+            column.set_metadata(custom_arrow_metadata(field));
+        }
+    }
+}
@@ -36,4 +36,4 @@ pub mod functions;
 
 pub use functions::*;
 pub use polars_plan::dsl::*;
-pub use polars_plan::plans::UdfSchema;
+pub use polars_plan::plans::{UdfFlags, UdfSchema};
@@ -1,14 +1,57 @@
+use std::panic::AssertUnwindSafe;
 use std::sync::Mutex;
-use std::sync::mpsc::{Receiver, channel};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, channel};
+use std::time::Duration;
 
 use polars_core::POOL;
+use polars_core::error::signals::{
+    KeyboardInterrupt, catch_keyboard_interrupt, keyboard_interrupt_pending,
+    try_raise_keyboard_interrupt,
+};
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use super::*;
 
+/// How often a blocking fetch checks for keyboard interrupts.
+const INTERRUPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Executes the plan, turning a keyboard interrupt into an error as the query
+/// doesn't run on the thread that handles the interrupt.
+fn execute_interruptible(
+    physical_plan: &mut Box<dyn Executor>,
+    state: &mut ExecutionState,
+) -> PolarsResult<DataFrame> {
+    match catch_keyboard_interrupt(AssertUnwindSafe(|| physical_plan.execute(state))) {
+        Ok(result) => result,
+        Err(KeyboardInterrupt) => Err(polars_err!(ComputeError: "query interrupted")),
+    }
+}
+
 impl LazyFrame {
     pub fn collect_concurrently(self) -> PolarsResult<InProcessQuery> {
-        let (mut state, mut physical_plan, _) = self.prepare_collect(false, None)?;
+        self.collect_concurrently_with_engine(Engine::InMemory)
+    }
+
+    /// Runs the query in the background with the given `engine`.
+    ///
+    /// The returned [`InProcessQuery`] can cancel the query, which also stops the streaming
+    /// engine at earliest convenience.
+    pub fn collect_concurrently_with_engine(self, engine: Engine) -> PolarsResult<InProcessQuery> {
+        let (mut state, mut physical_plan) = match engine {
+            Engine::Streaming => feature_gated!("new_streaming", {
+                let mut ir_plan = self.with_new_streaming(true).to_alp_optimized()?;
+                let physical_plan = polars_stream::build_streaming_query_executor(
+                    ir_plan.lp_top,
+                    &mut ir_plan.lp_arena,
+                    &mut ir_plan.expr_arena,
+                )?;
+                (ExecutionState::new(), physical_plan)
+            }),
+            _ => {
+                let (state, physical_plan, _) = self.prepare_collect(false, None)?;
+                (state, physical_plan)
+            },
+        };
 
         let (tx, rx) = channel();
         let token = state.cancel_token();
@@ -17,20 +60,20 @@ impl LazyFrame {
             #[cfg(feature = "async")]
             {
                 polars_io::pl_async::get_runtime().spawn_blocking(move || {
-                    let result = physical_plan.execute(&mut state);
+                    let result = execute_interruptible(&mut physical_plan, &mut state);
                     tx.send(result).unwrap();
                 });
             }
             #[cfg(not(feature = "async"))]
             {
                 std::thread::spawn(move || {
-                    let result = physical_plan.execute(&mut state);
+                    let result = execute_interruptible(&mut physical_plan, &mut state);
                     tx.send(result).unwrap();
                 });
             }
         } else {
             POOL.spawn_fifo(move || {
-                let result = physical_plan.execute(&mut state);
+                let result = execute_interruptible(&mut physical_plan, &mut state);
                 tx.send(result).unwrap();
             });
         }
@@ -64,9 +107,26 @@ impl InProcessQuery {
     }
 
     /// Await the result synchronously.
+    ///
+    /// If a keyboard interrupt is received while waiting, the query is
+    /// cancelled and the interrupt is raised.
     pub fn fetch_blocking(&self) -> PolarsResult<DataFrame> {
         let rx = self.rx.lock().unwrap();
-        rx.recv().unwrap()
+        loop {
+            match rx.recv_timeout(INTERRUPT_POLL_INTERVAL) {
+                Ok(result) => {
+                    try_raise_keyboard_interrupt();
+                    return result;
+                },
+                Err(RecvTimeoutError::Timeout) => {
+                    if keyboard_interrupt_pending() {
+                        self.cancel();
+                        try_raise_keyboard_interrupt();
+                    }
+                },
+                Err(RecvTimeoutError::Disconnected) => unreachable!(),
+            }
+        }
     }
 }
 
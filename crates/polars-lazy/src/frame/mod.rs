@@ -6,6 +6,7 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod update;
 
 use std::num::NonZeroUsize;
 use std::sync::mpsc::{Receiver, sync_channel};
@@ -27,6 +28,7 @@ use polars_core::error::feature_gated;
 use polars_core::frame::PivotColumnNaming;
 use polars_core::prelude::*;
 use polars_core::query_result::QueryResult;
+use polars_expr::evaluate_constant_expr;
 use polars_io::RowIndex;
 use polars_mem_engine::scan_predicate::functions::apply_scan_predicate_to_scan_ir;
 use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
@@ -34,7 +36,15 @@ use polars_ops::frame::{JoinBuildSide, JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
+pub use polars_plan::plans::cardinality::{CardinalityEstimate, estimate_cardinality};
+pub use polars_plan::plans::{
+    OptimizerRewriteRule, OptimizerRuleStage, register_optimizer_rule, unregister_optimizer_rule,
+    visitor,
+};
+#[cfg(feature = "new_streaming")]
+pub use polars_stream::{NodeMetrics, QueryMetrics, set_query_metrics_callback};
 use polars_utils::pl_str::PlSmallStr;
+pub use update::UpdateWhere;
 
 use crate::frame::cached_arenas::CachedArena;
 use crate::prelude::*;
@@ -532,6 +542,7 @@ impl LazyFrame {
             expr_arena,
             scratch,
             apply_scan_predicate_to_scan_ir,
+            evaluate_constant_expr,
         )?;
 
         Ok(lp_top)
@@ -959,6 +970,49 @@ impl LazyFrame {
         self.filter(predicate.neq_missing(lit(true)))
     }
 
+    /// Split the frame into a [`LazyFrame`] per unique combination of the `keys` columns.
+    ///
+    /// Only the key columns are collected to find the partitions; every partition is a
+    /// filter over the shared plan, so the predicate can still be pushed down to the scans.
+    /// The partitions are returned with their key values, in order of first appearance.
+    /// Null is treated as a key value of its own.
+    pub fn partition_by_lazy<I, S>(
+        self,
+        keys: I,
+    ) -> PolarsResult<Vec<(Vec<AnyValue<'static>>, LazyFrame)>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let keys: Vec<PlSmallStr> = keys.into_iter().map(Into::into).collect();
+        polars_ensure!(!keys.is_empty(), InvalidOperation: "partition_by_lazy needs at least one key");
+
+        let key_exprs: Vec<Expr> = keys.iter().map(|k| col(k.clone())).collect();
+        let unique_keys = self
+            .clone()
+            .select(key_exprs)
+            .unique_stable(None, UniqueKeepStrategy::Any)
+            .collect()?;
+
+        (0..unique_keys.height())
+            .map(|i| {
+                let mut values = Vec::with_capacity(keys.len());
+                let mut predicate: Option<Expr> = None;
+                for c in unique_keys.columns() {
+                    let value = c.get(i)?.into_static();
+                    let eq = col(c.name().clone())
+                        .eq_missing(lit(Scalar::new(c.dtype().clone(), value.clone())));
+                    predicate = Some(match predicate {
+                        Some(p) => p.and(eq),
+                        None => eq,
+                    });
+                    values.push(value);
+                }
+                Ok((values, self.clone().filter(predicate.unwrap())))
+            })
+            .collect()
+    }
+
     /// Select (and optionally rename, with [`alias`](crate::dsl::Expr::alias)) columns from the query.
     ///
     /// Columns can be selected with [`col`];
@@ -1050,6 +1104,7 @@ impl LazyFrame {
                 keys,
                 predicates: vec![],
                 maintain_order: false,
+                nulls_equal: true,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1063,6 +1118,7 @@ impl LazyFrame {
                 keys,
                 predicates: vec![],
                 maintain_order: false,
+                nulls_equal: true,
             }
         }
     }
@@ -1100,6 +1156,7 @@ impl LazyFrame {
             predicates: vec![],
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
+            nulls_equal: true,
             dynamic_options: None,
             rolling_options: Some(options),
         }
@@ -1146,6 +1203,7 @@ impl LazyFrame {
             predicates: vec![],
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
+            nulls_equal: true,
             dynamic_options: Some(options),
             rolling_options: None,
         }
@@ -1168,6 +1226,7 @@ impl LazyFrame {
                 keys,
                 predicates: vec![],
                 maintain_order: true,
+                nulls_equal: true,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1181,6 +1240,7 @@ impl LazyFrame {
                 keys,
                 predicates: vec![],
                 maintain_order: true,
+                nulls_equal: true,
             }
         }
     }
@@ -1828,6 +1888,30 @@ impl LazyFrame {
         schema: Option<Arc<dyn UdfSchema>>,
         name: Option<&'static str>,
     ) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
+    {
+        self.map_with_flags(function, optimizations, UdfFlags::empty(), schema, name)
+    }
+
+    /// Apply a function/closure once the logical plan get executed, declaring properties of
+    /// the function that allow the optimizer to push operations past it.
+    ///
+    /// E.g. a function that is declared [`UdfFlags::PRESERVES_ORDER`] and
+    /// [`UdfFlags::PRESERVES_ROW_COUNT`] allows a `head`/`slice` to be pushed to its input, and
+    /// [`UdfFlags::PRESERVES_SCHEMA`] allows projections to be pushed to its input.
+    ///
+    /// ## Warning
+    /// The optimizer trusts the declared flags. Declaring properties the function doesn't
+    /// uphold leads to wrong results.
+    pub fn map_with_flags<F>(
+        self,
+        function: F,
+        optimizations: AllowedOptimizations,
+        flags: UdfFlags,
+        schema: Option<Arc<dyn UdfSchema>>,
+        name: Option<&'static str>,
+    ) -> LazyFrame
     where
         F: 'static + Fn(DataFrame) -> PolarsResult<DataFrame> + Send + Sync,
     {
@@ -1837,6 +1921,7 @@ impl LazyFrame {
             .map(
                 function,
                 optimizations,
+                flags,
                 schema,
                 PlSmallStr::from_static(name.unwrap_or("ANONYMOUS UDF")),
             )
@@ -1849,13 +1934,14 @@ impl LazyFrame {
         self,
         function: polars_utils::python_function::PythonFunction,
         optimizations: AllowedOptimizations,
+        flags: UdfFlags,
         schema: Option<SchemaRef>,
         validate_output: bool,
     ) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .map_python(function, optimizations, schema, validate_output)
+            .map_python(function, optimizations, flags, schema, validate_output)
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
@@ -1927,9 +2013,22 @@ impl LazyFrame {
     /// inserted as columns.
     #[cfg(feature = "dtype-struct")]
     pub fn unnest(self, cols: Selector, separator: Option<PlSmallStr>) -> Self {
+        self.unnest_with_options(cols, separator, UnnestOptions::default())
+    }
+
+    /// Unnest the given `Struct` columns, see [`UnnestOptions`] for which fields are inserted
+    /// as columns and under which names.
+    #[cfg(feature = "dtype-struct")]
+    pub fn unnest_with_options(
+        self,
+        cols: Selector,
+        separator: Option<PlSmallStr>,
+        options: UnnestOptions,
+    ) -> Self {
         self.map_private(DslFunction::Unnest {
             columns: cols,
             separator,
+            options,
         })
     }
 
@@ -1955,6 +2054,20 @@ impl LazyFrame {
         };
         Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
     }
+
+    /// Hint the optimizer that this `LazyFrame` produces roughly `n_rows` rows.
+    ///
+    /// This overrides the cardinality estimate of the plan up to this point.
+    pub fn set_estimated_rows(self, n_rows: usize) -> PolarsResult<LazyFrame> {
+        self.hint(HintIR::EstimatedRows(n_rows))
+    }
+
+    /// Estimate the number of output rows of the optimized plan.
+    pub fn estimate_cardinality(self) -> PolarsResult<CardinalityEstimate> {
+        let plan = self.to_alp_optimized()?;
+        let estimates = estimate_cardinality(plan.lp_top, &plan.lp_arena, &plan.expr_arena);
+        Ok(estimates.get(plan.lp_top).cloned().unwrap_or_default())
+    }
 }
 
 /// Utility struct for lazy group_by operation.
@@ -1965,6 +2078,7 @@ pub struct LazyGroupBy {
     keys: Vec<Expr>,
     predicates: Vec<Expr>,
     maintain_order: bool,
+    nulls_equal: bool,
     #[cfg(feature = "dynamic_group_by")]
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
@@ -2007,6 +2121,15 @@ impl LazyGroupBy {
         self
     }
 
+    /// Whether rows with null keys are grouped together (the default).
+    ///
+    /// If `false`, every row that has a null in any of its keys ends up in a group of its own,
+    /// mirroring the `nulls_equal` option of joins.
+    pub fn nulls_equal(mut self, nulls_equal: bool) -> Self {
+        self.nulls_equal = nulls_equal;
+        self
+    }
+
     /// Group by and aggregate.
     ///
     /// Select a column with [col] and choose an aggregation.
@@ -2028,7 +2151,30 @@ impl LazyGroupBy {
     ///        ])
     /// }
     /// ```
-    pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> LazyFrame {
+    pub fn agg<E: AsRef<[Expr]>>(mut self, aggs: E) -> LazyFrame {
+        // Give every row with a null key a unique extra key, so that these rows don't share a
+        // group. The extra key is null for all other rows and dropped after aggregation.
+        let mut null_group_key = None;
+        if !self.nulls_equal && !self.keys.is_empty() {
+            let name = PlSmallStr::from_static("__POLARS_NULL_GROUP_KEY");
+            let has_null = self
+                .keys
+                .iter()
+                .map(|key| key.clone().is_null())
+                .reduce(|acc, e| acc.or(e))
+                .unwrap();
+            self.logical_plan = LazyFrame::from_logical_plan(self.logical_plan, self.opt_state)
+                .with_row_index(name.clone(), None)
+                .logical_plan;
+            self.keys.push(
+                when(has_null)
+                    .then(col(name.clone()))
+                    .otherwise(lit(NULL))
+                    .alias(name.clone()),
+            );
+            null_group_key = Some(name);
+        }
+
         #[cfg(feature = "dynamic_group_by")]
         let lp = DslBuilder::from(self.logical_plan)
             .group_by(
@@ -2046,7 +2192,11 @@ impl LazyGroupBy {
         let lp = DslBuilder::from(self.logical_plan)
             .group_by(self.keys, self.predicates, aggs, None, self.maintain_order)
             .build();
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        let lf = LazyFrame::from_logical_plan(lp, self.opt_state);
+        match null_group_key {
+            Some(name) => lf.drop(by_name([name], true, false)),
+            None => lf,
+        }
     }
 
     /// Return first n rows of each group
@@ -2089,10 +2239,21 @@ impl LazyGroupBy {
     ///
     /// **It is not recommended that you use this as materializing the DataFrame is very
     /// expensive.**
-    pub fn apply(self, f: PlanCallback<DataFrame, DataFrame>, schema: SchemaRef) -> LazyFrame {
-        if !self.predicates.is_empty() {
-            panic!("not yet implemented: `apply` cannot be used with `having` predicates");
-        }
+    ///
+    /// This is not supported yet with `having` predicates or `nulls_equal(false)`.
+    pub fn apply(
+        self,
+        f: PlanCallback<DataFrame, DataFrame>,
+        schema: SchemaRef,
+    ) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            self.predicates.is_empty(),
+            InvalidOperation: "`apply` cannot be used with `having` predicates"
+        );
+        polars_ensure!(
+            self.nulls_equal,
+            InvalidOperation: "`apply` cannot be used with `nulls_equal(false)`"
+        );
 
         #[cfg(feature = "dynamic_group_by")]
         let options = GroupbyOptions {
@@ -2113,7 +2274,7 @@ impl LazyGroupBy {
             maintain_order: self.maintain_order,
             options: Arc::new(options),
         };
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
     }
 }
 
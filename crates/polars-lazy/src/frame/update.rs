@@ -0,0 +1,175 @@
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_ops::prelude::ChunkedSet;
+
+use crate::prelude::*;
+
+pub trait UpdateWhere {
+    /// Set the columns in `assignments` to their values in the rows where `predicate` is true.
+    ///
+    /// Every assignment is an expression whose output name is the column it updates. The
+    /// expressions are evaluated on the matching rows only, as in
+    /// `df.filter(predicate).select(assignments)`, and must produce a value for each of those rows
+    /// or a single value that is broadcast. Values are cast to the data type of their column and
+    /// nulls are written as nulls. Rows where the predicate is null are left untouched.
+    ///
+    /// Only the updated columns are modified, in place where their data type allows it. This is
+    /// far cheaper than rebuilding whole columns with `when/then/otherwise` when few rows match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// let mut df = df!(
+    ///     "id" => [1, 2, 3],
+    ///     "price" => [10.0, 20.0, 30.0],
+    /// )?;
+    /// df.update_where(col("id").eq(lit(2)), [(col("price") * lit(2.0)).alias("price")])?;
+    ///
+    /// assert_eq!(
+    ///     df.column("price")?.f64()?.to_vec(),
+    ///     [Some(10.0), Some(40.0), Some(30.0)]
+    /// );
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    fn update_where<E: AsRef<[Expr]>>(
+        &mut self,
+        predicate: Expr,
+        assignments: E,
+    ) -> PolarsResult<()>;
+}
+
+impl UpdateWhere for DataFrame {
+    fn update_where<E: AsRef<[Expr]>>(
+        &mut self,
+        predicate: Expr,
+        assignments: E,
+    ) -> PolarsResult<()> {
+        let assignments = assignments.as_ref();
+        if assignments.is_empty() {
+            return Ok(());
+        }
+        let height = self.height();
+
+        let mask = self.clone().lazy().select([predicate]).collect()?;
+        let mask = mask.columns()[0].as_materialized_series();
+        let mask = match mask.len() {
+            1 => mask.new_from_index(0, height),
+            len if len == height => mask.clone(),
+            len => polars_bail!(
+                ShapeMismatch: "predicate of length {len} doesn't match the DataFrame height {height}"
+            ),
+        };
+        let idx = mask
+            .bool()?
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| (v == Some(true)).then_some(i as IdxSize))
+            .collect::<Vec<_>>();
+
+        let values = self
+            .clone()
+            .lazy()
+            .filter(lit(mask))
+            .select(assignments)
+            .collect()?;
+
+        // Check all assignments before modifying any column.
+        let updates = values
+            .columns()
+            .iter()
+            .map(|values| {
+                let name = values.name();
+                let index = self.try_get_column_index(name)?;
+                let dtype = self.columns()[index].dtype();
+                let values = values.as_materialized_series().strict_cast(dtype)?;
+                let values = match values.len() {
+                    1 => values.new_from_index(0, idx.len()),
+                    len if len == idx.len() => values,
+                    len => polars_bail!(
+                        ShapeMismatch:
+                        "assignment to '{name}' has {len} values, but {} rows match the predicate",
+                        idx.len()
+                    ),
+                };
+                Ok((index, values))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if idx.is_empty() {
+            return Ok(());
+        }
+
+        // SAFETY: the columns keep their name, data type and length.
+        let columns = unsafe { self.columns_mut_retain_schema() };
+        for (index, values) in updates {
+            let s = std::mem::take(&mut columns[index]).take_materialized_series();
+            match scatter(s, &idx, &values) {
+                Ok(s) => columns[index] = s.into_column(),
+                Err((s, e)) => {
+                    columns[index] = s.into_column();
+                    return Err(e);
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write `values` at `idx` into `s`, which is given back on error.
+fn scatter(s: Series, idx: &[IdxSize], values: &Series) -> Result<Series, (Series, PolarsError)> {
+    let dtype = s.dtype().clone();
+    let physical = dtype.to_physical();
+    if !(physical.is_primitive_numeric()
+        || matches!(
+            physical,
+            DataType::Boolean | DataType::String | DataType::Binary
+        ))
+    {
+        // Other types are rebuilt by gathering from the old and the new values.
+        let len = s.len();
+        let mut take_idx = (0..len as IdxSize).collect::<Vec<_>>();
+        for (i, &row) in idx.iter().enumerate() {
+            take_idx[row as usize] = (len + i) as IdxSize;
+        }
+        let mut out = s.clone();
+        return match out.append(values).and_then(|out| out.take_slice(&take_idx)) {
+            Ok(out) => Ok(out),
+            Err(e) => Err((s, e)),
+        };
+    }
+
+    let values = values.to_physical_repr();
+    let mut phys = s.to_physical_repr().into_owned();
+    // Reduce the refcount so the data can be mutated in place.
+    drop(s);
+
+    let inner = phys._get_inner_mut();
+    let out = match &physical {
+        dt if dt.is_primitive_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &mut ChunkedArray<$T> = inner.as_mut();
+                let values: &ChunkedArray<$T> = values.as_ref().as_ref();
+                ca.scatter(idx, values)
+            })
+        },
+        DataType::Boolean => {
+            let ca: &mut BooleanChunked = inner.as_mut();
+            ca.scatter(idx, values.bool().unwrap())
+        },
+        DataType::String => {
+            let ca: &mut StringChunked = inner.as_mut();
+            ca.scatter(idx, values.str().unwrap())
+        },
+        DataType::Binary => {
+            let ca: &mut BinaryChunked = inner.as_mut();
+            ca.scatter(idx, values.binary().unwrap())
+        },
+        _ => unreachable!(),
+    };
+    // SAFETY: the physical values come from a series of `dtype`.
+    match out {
+        Ok(s) => Ok(unsafe { s.from_physical_unchecked(&dtype).unwrap() }),
+        Err(e) => Err((unsafe { phys.from_physical_unchecked(&dtype).unwrap() }, e)),
+    }
+}
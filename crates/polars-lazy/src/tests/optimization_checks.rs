@@ -94,6 +94,51 @@ fn test_pred_pd_1() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_pred_pd_group_by_expr_keys() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    let q = df
+        .lazy()
+        .group_by([(col("A") % lit(2)).alias("parity")])
+        .agg([col("B").sum()])
+        .filter(col("parity").eq(lit(1)));
+
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    assert_eq!(out.height(), 1);
+    assert_eq!(out.column("parity")?.get(0)?, AnyValue::Int32(1));
+    assert_eq!(out.column("B")?.get(0)?, AnyValue::Int32(9));
+
+    Ok(())
+}
+
+#[test]
+fn test_pred_pd_group_by_shadowing_keys() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 1, 2],
+    ]?;
+
+    // The predicate on `a` refers to the key `b` of the input, not to the key named `b`.
+    let q = df
+        .lazy()
+        .group_by([col("b").alias("a"), (col("a") * lit(10)).alias("b")])
+        .agg([len()])
+        .filter(col("a").eq(lit(1)));
+
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.sort(["b"], Default::default()).collect()?;
+    assert_eq!(
+        out.column("b")?.as_materialized_series(),
+        &Series::new("b".into(), [10, 20])
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_no_left_join_pass() -> PolarsResult<()> {
     let df1 = df![
@@ -183,6 +228,124 @@ pub fn test_slice_pushdown_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+pub fn test_redundant_sort_elimination() -> PolarsResult<()> {
+    use polars_ops::frame::MaintainOrderJoin;
+
+    let count_sorts = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        lp_arena
+            .iter(lp)
+            .filter(|(_, lp)| matches!(lp, IR::Sort { .. }))
+            .count()
+    };
+
+    let df = df![
+        "a" => [3, 1, 2, 1],
+        "b" => [1, 2, 3, 4],
+    ]?;
+    let other = df![
+        "a" => [1, 2, 3],
+        "c" => ["x", "y", "z"],
+    ]?;
+
+    // The second sort is redundant as the filter and projection keep the order.
+    let q = df
+        .clone()
+        .lazy()
+        .sort(["a"], Default::default())
+        .filter(col("b").gt(lit(1)))
+        .select([col("a"), col("b")])
+        .sort(["a"], Default::default());
+    assert_eq!(count_sorts(q.clone()), 1);
+    assert_eq!(
+        q.collect()?.column("a")?.as_materialized_series(),
+        &Series::new("a".into(), [1, 1, 2])
+    );
+
+    // A different order has to be sorted again.
+    let q = df.clone().lazy().sort(["a"], Default::default()).sort(
+        ["a"],
+        SortMultipleOptions::default().with_order_descending(true),
+    );
+    assert_eq!(count_sorts(q), 2);
+
+    // A left join that maintains the left order keeps its sortedness.
+    let join = |maintain_order: MaintainOrderJoin| {
+        df.clone()
+            .lazy()
+            .sort(["a"], Default::default())
+            .join_builder()
+            .with(other.clone().lazy())
+            .on([col("a")])
+            .how(JoinType::Left)
+            .maintain_order(maintain_order)
+            .finish()
+            .sort(["a"], Default::default())
+    };
+    assert_eq!(count_sorts(join(MaintainOrderJoin::Left)), 1);
+    assert_eq!(count_sorts(join(MaintainOrderJoin::None)), 2);
+
+    // A sort with a slice becomes that slice.
+    let q = df
+        .lazy()
+        .sort(["a"], Default::default())
+        .sort(["a"], Default::default())
+        .slice(1, 2);
+    assert_eq!(count_sorts(q.clone()), 1);
+    assert_eq!(q.collect()?.height(), 2);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+pub fn test_slice_pushdown_into_join_inputs() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+
+    let scan_slices = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        lp_arena
+            .iter(lp)
+            .filter_map(|(_, lp)| match lp {
+                IR::Scan {
+                    unified_scan_args, ..
+                } => Some(unified_scan_args.pre_slice.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let join = |how: JoinType| {
+        scan_foods_parquet(false)
+            .join(
+                scan_foods_parquet(false),
+                [col("category")],
+                [col("category")],
+                how.into(),
+            )
+            .slice(1, 3)
+            .with_comm_subplan_elim(false)
+    };
+
+    // Only the left input is limited, to `offset + len` rows.
+    let slices = scan_slices(join(JoinType::Left));
+    assert_eq!(slices.iter().filter(|s| s.is_some()).count(), 1);
+    assert!(slices.contains(&Some(polars_utils::slice_enum::Slice::Positive {
+        offset: 0,
+        len: 4,
+    })));
+    assert_eq!(join(JoinType::Left).collect()?.shape(), (3, 7));
+
+    // Inner joins can drop rows of both inputs.
+    let slices = scan_slices(join(JoinType::Inner));
+    assert!(slices.iter().all(|s| s.is_none()));
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 pub fn test_slice_pushdown_group_by() -> PolarsResult<()> {
@@ -242,6 +405,75 @@ pub fn test_slice_pushdown_sort() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+pub fn test_min_max_from_parquet_statistics() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let q = scan_foods_parquet(false).select([
+        col("calories").min(),
+        col("calories").max().alias("max"),
+        len(),
+    ]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(lp_arena.iter(lp).any(|(_, lp)| matches!(
+        lp,
+        IR::MapFunction {
+            function: FunctionIR::FastStatistics { .. },
+            ..
+        }
+    )));
+    assert!(!lp_arena.iter(lp).any(|(_, lp)| matches!(lp, IR::Scan { .. })));
+
+    let out = q.clone().collect()?;
+    let expected = q.with_projection_pushdown(false).collect()?;
+    assert!(out.equals(&expected));
+    itertools::assert_equal(out.get_column_names(), &["calories", "max", "len"]);
+
+    // Float statistics are not used.
+    let q = scan_foods_parquet(false).select([col("fats_g").max()]);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(lp_arena.iter(lp).any(|(_, lp)| matches!(lp, IR::Scan { .. })));
+
+    Ok(())
+}
+
+#[test]
+pub fn test_top_k_after_group_by() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "c", "a", "d", "b", "e"],
+        "v" => [1, 2, 3, 4, 9, 6, 7],
+    ]?;
+    let sorted = |df: DataFrame| {
+        df.lazy().group_by([col("g")]).agg([col("v").sum()]).sort(
+            ["v"],
+            SortMultipleOptions::default().with_order_descending(true),
+        )
+    };
+
+    // The slice is pushed into the sort, which selects the top rows with a bounded top-k
+    // instead of sorting the aggregated output.
+    let q = sorted(df.clone()).slice(1, 2);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(lp_arena.iter(lp).all(|(_, lp)| match lp {
+        IR::Sort { slice, .. } => matches!(slice, Some((1, 2, _))),
+        IR::Slice { .. } => false,
+        _ => true,
+    }));
+
+    let expected = sorted(df).collect()?.slice(1, 2);
+    assert!(q.collect()?.equals(&expected));
+    assert_eq!(
+        expected.column("g")?.as_materialized_series(),
+        &Series::new("g".into(), ["b", "e"])
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-i16")]
 pub fn test_predicate_block_cast() -> PolarsResult<()> {
@@ -649,3 +881,417 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_user_optimizer_rule() -> PolarsResult<()> {
+    struct LimitMarked;
+
+    impl OptimizerRewriteRule for LimitMarked {
+        fn rewrite(
+            &self,
+            root: Node,
+            ir_arena: &mut Arena<IR>,
+            _expr_arena: &mut Arena<AExpr>,
+        ) -> PolarsResult<Node> {
+            // Only touch plans of this test, the registry is global.
+            if !ir_arena
+                .get(root)
+                .schema(ir_arena)
+                .contains("__user_rule_marker")
+            {
+                return Ok(root);
+            }
+            Ok(ir_arena.add(IR::Slice {
+                input: root,
+                offset: 0,
+                len: 1,
+            }))
+        }
+    }
+
+    let name = "test_user_optimizer_rule";
+    register_optimizer_rule(name, OptimizerRuleStage::Final, Arc::new(LimitMarked))?;
+    assert!(
+        register_optimizer_rule(name, OptimizerRuleStage::Final, Arc::new(LimitMarked)).is_err()
+    );
+
+    let df = df!["__user_rule_marker" => [1, 2, 3]]?;
+    let out = df.clone().lazy().collect()?;
+    assert_eq!(out.height(), 1);
+
+    unregister_optimizer_rule(name)?;
+    let out = df.lazy().collect()?;
+    assert_eq!(out.height(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_cardinality_estimation() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..100).collect::<Vec<i32>>(),
+    ]?;
+
+    let est = df.clone().lazy().estimate_cardinality()?;
+    assert_eq!(est.rows, 100);
+    assert!(est.exact);
+
+    let est = df.clone().lazy().slice(10, 20).estimate_cardinality()?;
+    assert_eq!(est.rows, 20);
+    assert!(est.exact);
+
+    let est = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(50)))
+        .estimate_cardinality()?;
+    assert!(est.rows < 100);
+    assert!(!est.exact);
+
+    let est = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(50)))
+        .set_estimated_rows(49)?
+        .estimate_cardinality()?;
+    assert_eq!(est.rows, 49);
+
+    // Only projections that keep the height keep an exact row count.
+    let est = df
+        .clone()
+        .lazy()
+        .with_column((col("a") * lit(2)).alias("b"))
+        .estimate_cardinality()?;
+    assert_eq!(est.rows, 100);
+    assert!(est.exact);
+
+    let est = df.clone().lazy().select([col("a").sum()]).estimate_cardinality()?;
+    assert_eq!(est.rows, 1);
+    assert!(est.exact);
+
+    let est = df
+        .lazy()
+        .select([col("a").filter(col("a").gt(lit(50)))])
+        .estimate_cardinality()?;
+    assert!(!est.exact);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_join_prefers_smaller_build_side() -> PolarsResult<()> {
+    use polars_ops::frame::JoinBuildSide;
+
+    let large = df!["a" => (0..1000).collect::<Vec<i32>>()]?;
+    let small = df!["a" => [1, 2, 3]]?;
+
+    let build_side = |left: &DataFrame, right: &DataFrame| -> PolarsResult<_> {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = left
+            .clone()
+            .lazy()
+            .with_new_streaming(true)
+            .inner_join(right.clone().lazy(), col("a"), col("a"))
+            .optimize(&mut lp_arena, &mut expr_arena)?;
+        Ok(lp_arena.iter(lp).find_map(|(_, ir)| match ir {
+            IR::Join { options, .. } => Some(options.args.build_side.clone()),
+            _ => None,
+        }))
+    };
+
+    assert_eq!(build_side(&large, &small)?, Some(Some(JoinBuildSide::PreferRight)));
+    assert_eq!(build_side(&small, &large)?, Some(Some(JoinBuildSide::PreferLeft)));
+    assert_eq!(build_side(&small, &small)?, Some(None));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "strings", feature = "temporal", feature = "dtype-date"))]
+fn test_constant_folding_temporal() -> PolarsResult<()> {
+    let df = df![
+        "date" => ["2024-01-01", "2024-01-02", "2024-01-03"],
+    ]?
+    .lazy()
+    .with_column(col("date").str().to_date(StrptimeOptions::default()))
+    .collect()?;
+
+    let q = df.lazy().filter(
+        col("date").gt_eq(
+            lit("2024-01-02")
+                .str()
+                .to_date(StrptimeOptions::default()),
+        ),
+    );
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let folded = lp_arena.iter(lp).any(|(_, lp)| match lp {
+        IR::Filter { predicate, .. } => matches!(
+            expr_arena.get(predicate.node()),
+            AExpr::BinaryExpr { right, .. } if matches!(expr_arena.get(*right), AExpr::Literal(_))
+        ),
+        _ => false,
+    });
+    assert!(folded);
+
+    let out = q.collect()?;
+    assert_eq!(out.height(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_ir_rewriter_splice() -> PolarsResult<()> {
+    use polars_plan::plans::AExprBuilder;
+    use polars_plan::plans::visitor::*;
+
+    // Injects a row-level predicate above every in-memory scan.
+    struct RowFilter;
+
+    impl RewritingVisitor for RowFilter {
+        type Node = IRNode;
+        type Arena = IRNodeArena;
+
+        fn mutate(&mut self, mut node: IRNode, arena: &mut IRNodeArena) -> PolarsResult<IRNode> {
+            if matches!(node.to_alp(&arena.0), IR::DataFrameScan { .. }) {
+                let predicate = AExprBuilder::col("a", &mut arena.1)
+                    .gt(AExprBuilder::lit_scalar(Scalar::from(1i32), &mut arena.1), &mut arena.1)
+                    .expr_ir_retain_name(&arena.1);
+                node.splice_above(|input| IR::Filter { input, predicate }, &mut arena.0);
+            }
+            Ok(node)
+        }
+    }
+
+    let df = df!["a" => [1, 2, 3]]?;
+    let q = df.lazy().select([col("a")]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    let root = try_with_ir_arena(&mut lp_arena, &mut expr_arena, |arena| {
+        Ok(IRNode::new_mutate(root).rewrite(&mut RowFilter, arena)?.node())
+    })?;
+
+    let filters = |lp_arena: &Arena<IR>| {
+        lp_arena
+            .iter(root)
+            .filter_map(|(node, lp)| match lp {
+                IR::Filter { input, .. } => Some((node, *input)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let found = filters(&lp_arena);
+    assert_eq!(found.len(), 1);
+    let (filter, input) = found[0];
+    assert!(matches!(lp_arena.get(input), IR::DataFrameScan { .. }));
+
+    // Splicing out the filter puts the scan back in its place.
+    IRNode::new(filter).splice_out(&mut lp_arena)?;
+    assert!(filters(&lp_arena).is_empty());
+    assert!(matches!(lp_arena.get(filter), IR::DataFrameScan { .. }));
+    assert!(IRNode::new(filter).splice_out(&mut lp_arena).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_filter_range_simplification() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4, 5],
+        "b" => [true, true, false, true, true],
+    ]?;
+
+    let n_comparisons = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        lp_arena
+            .iter(lp)
+            .filter_map(|(_, lp)| match lp {
+                IR::Filter { predicate, .. } => Some(predicate.node()),
+                _ => None,
+            })
+            .map(|predicate| {
+                expr_arena
+                    .iter(predicate)
+                    .filter(|(_, e)| {
+                        matches!(e, AExpr::BinaryExpr { op, .. } if op.is_comparison())
+                    })
+                    .count()
+            })
+            .sum::<usize>()
+    };
+
+    // `a > 1 & a >= 3 & b & a < 10 & a <= 4` => `a >= 3 & a <= 4 & b`
+    let q = df.clone().lazy().filter(
+        col("a")
+            .gt(lit(1))
+            .and(col("a").gt_eq(lit(3)))
+            .and(col("b"))
+            .and(col("a").lt(lit(10)))
+            .and(col("a").lt_eq(lit(4))),
+    );
+    assert_eq!(n_comparisons(q.clone()), 2);
+    assert_eq!(
+        q.collect()?.column("a")?.as_materialized_series(),
+        &Series::new("a".into(), [4])
+    );
+
+    // A closed range on a single value becomes an equality.
+    let q = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt_eq(lit(2)).and(col("a").lt_eq(lit(2))));
+    assert_eq!(n_comparisons(q.clone()), 1);
+    assert_eq!(q.collect()?.height(), 1);
+
+    // Negations are pushed down first: `~(a <= 1 | a > 4) & a > 2` => `a > 2 & a <= 4`.
+    let q = df.clone().lazy().filter(
+        col("a")
+            .lt_eq(lit(1))
+            .or(col("a").gt(lit(4)))
+            .not()
+            .and(col("a").gt(lit(2))),
+    );
+    assert_eq!(n_comparisons(q.clone()), 2);
+    assert_eq!(
+        q.collect()?.column("a")?.as_materialized_series(),
+        &Series::new("a".into(), [3, 4])
+    );
+
+    // Contradicting ranges replace the filter and its input by an empty frame.
+    let q = df.lazy().filter(col("a").gt(lit(3)).and(col("a").lt_eq(lit(3))));
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(
+        !lp_arena
+            .iter(lp)
+            .any(|(_, lp)| matches!(lp, IR::Filter { .. }))
+    );
+    let out = q.collect()?;
+    assert_eq!(out.shape(), (0, 2));
+    assert_eq!(out.column("b")?.dtype(), &DataType::Boolean);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_filter_range_simplification_enum() -> PolarsResult<()> {
+    // Enums are ordered by their categories, not by their string values.
+    let dtype = DataType::from_frozen_categories(FrozenCategories::new(["c", "a", "b"])?);
+    let e = Series::new("e".into(), ["a", "b", "c"]).cast(&dtype)?;
+    let df = DataFrame::new(vec![e.into_column()])?;
+
+    // `"c" < e < "b"` is a contradiction on the strings, but holds for "a".
+    let out = df
+        .lazy()
+        .filter(col("e").gt(lit("c")).and(col("e").lt(lit("b"))))
+        .collect()?;
+    assert_eq!(
+        out.column("e")?
+            .cast(&DataType::String)?
+            .as_materialized_series(),
+        &Series::new("e".into(), ["a"])
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cse")]
+fn test_self_join_to_window() -> PolarsResult<()> {
+    let lf = df![
+        "g" => [Some("a"), Some("b"), None, Some("a"), None, Some("b")],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?
+    .lazy();
+
+    let self_join = |how: JoinType| {
+        lf.clone().join(
+            lf.clone()
+                .group_by([col("g")])
+                .agg([col("v").sum(), col("v").max().alias("max")]),
+            [col("g")],
+            [col("g")],
+            JoinArgs::new(how),
+        )
+    };
+    let n_joins = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        lp_arena
+            .iter(lp)
+            .filter(|(_, lp)| matches!(lp, IR::Join { .. }))
+            .count()
+    };
+
+    for how in [JoinType::Left, JoinType::Inner] {
+        let q = self_join(how);
+        assert_eq!(n_joins(q.clone()), 0);
+
+        let out = q.clone().sort(["v"], Default::default()).collect()?;
+        let expected = q
+            .with_comm_subplan_elim(false)
+            .sort(["v"], Default::default())
+            .collect()?;
+        itertools::assert_equal(out.get_column_names(), &["g", "v", "v_right", "max"]);
+        assert!(out.equals_missing(&expected));
+    }
+
+    // A join on another input is kept.
+    let other = lf.clone().filter(col("v").gt(lit(1)));
+    let q = lf.join(
+        other.group_by([col("g")]).agg([col("v").sum()]),
+        [col("g")],
+        [col("g")],
+        JoinArgs::new(JoinType::Left),
+    );
+    assert_eq!(n_joins(q), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_prune_overwritten_with_columns() -> PolarsResult<()> {
+    let lf = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    ]?
+    .lazy();
+
+    let n_with_columns = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        lp_arena
+            .iter(lp)
+            .map(|(_, lp)| match lp {
+                IR::HStack { exprs, .. } => exprs.len(),
+                _ => 0,
+            })
+            .sum::<usize>()
+    };
+
+    // The helper columns, and the helper column they are computed from, are overwritten.
+    let q = lf
+        .clone()
+        .with_columns([(col("a") + lit(1)).alias("h1")])
+        .with_columns([(col("h1") * lit(2)).alias("h2")])
+        .with_columns([lit(0).alias("h1"), col("b").alias("h2")]);
+    assert_eq!(n_with_columns(q.clone()), 2);
+    let out = q.clone().collect()?;
+    let expected = q.with_projection_pushdown(false).collect()?;
+    itertools::assert_equal(out.get_column_names(), &["a", "b", "h1", "h2"]);
+    assert!(out.equals(&expected));
+
+    // An overwritten input column keeps its position.
+    let q = lf.with_columns([(col("b") * lit(10)).alias("a")]);
+    let out = q.clone().collect()?;
+    let expected = q.with_projection_pushdown(false).collect()?;
+    itertools::assert_equal(out.get_column_names(), &["a", "b"]);
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
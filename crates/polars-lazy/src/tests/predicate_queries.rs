@@ -314,3 +314,51 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_partition_by_lazy() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [Some("x"), Some("y"), None, Some("x")],
+        "b" => [1, 2, 3, 4],
+    }?;
+    let partitions = df.lazy().partition_by_lazy(["a"])?;
+    assert_eq!(partitions.len(), 3);
+
+    let (key, lf) = &partitions[0];
+    assert_eq!(key, &[AnyValue::StringOwned("x".into())]);
+    assert!(predicate_at_all_scans(lf.clone()));
+    let out = lf.clone().collect()?;
+    assert_eq!(out.column("b")?.i32()?.to_vec(), &[Some(1), Some(4)]);
+
+    let (key, lf) = &partitions[2];
+    assert_eq!(key, &[AnyValue::Null]);
+    assert_eq!(
+        lf.clone().collect()?.column("b")?.i32()?.to_vec(),
+        &[Some(3)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_filter_skipped_by_in_memory_statistics() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [1, 2, 3],
+        "b" => [1.0, f64::NAN, 2.0],
+    }?;
+    // Only statistics that are already known are used.
+    for c in df.columns() {
+        c.min_max_statistics();
+    }
+
+    let out = df.clone().lazy().filter(col("a").gt(lit(10))).collect()?;
+    assert_eq!(out.shape(), (0, 2));
+    assert_eq!(out.schema(), df.schema());
+
+    // NaN is excluded from the statistics, but still matches.
+    let out = df.clone().lazy().filter(col("b").gt(lit(5.0))).collect()?;
+    assert_eq!(out.column("a")?.i32()?.to_vec(), &[Some(2)]);
+
+    let out = df.lazy().filter(col("a").gt_eq(lit(2))).collect()?;
+    assert_eq!(out.column("a")?.i32()?.to_vec(), &[Some(2), Some(3)]);
+    Ok(())
+}
@@ -964,6 +964,33 @@ fn test_lazy_group_by_filter() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_lazy_group_by_nulls_not_equal() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [Some("a"), None, Some("a"), None, Some("b")],
+        "b" => [1, 2, 3, 4, 5]
+    }?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("a")])
+        .nulls_equal(false)
+        .agg([col("b").sum()])
+        .collect()?;
+
+    assert_eq!(out.get_column_names(), &["a", "b"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.str()?),
+        [Some("a"), None, None, Some("b")]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        [Some(4), Some(2), Some(4), Some(5)]
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_group_by_projection_pd_same_column() -> PolarsResult<()> {
     // this query failed when projection pushdown was enabled
@@ -2047,3 +2074,53 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_update_where() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1, 2, 3, 4],
+        "b" => ["w", "x", "y", "z"],
+        "c" => [Some(1.0), None, Some(3.0), Some(4.0)],
+        "d" => [true, false, true, false],
+    ]?;
+    let original = df.clone();
+
+    df.update_where(
+        col("a").gt(lit(2)),
+        [
+            (col("a") * lit(10)).alias("a"),
+            lit(NULL).alias("b"),
+            col("c").sum().alias("c"),
+        ],
+    )?;
+    let expected = df![
+        "a" => [1, 2, 30, 40],
+        "b" => [Some("w"), Some("x"), None, None],
+        "c" => [Some(1.0), None, Some(7.0), Some(7.0)],
+        "d" => [true, false, true, false],
+    ]?;
+    assert!(df.equals_missing(&expected));
+    // Clones don't see the update.
+    assert_eq!(
+        original.column("a")?.i32()?.to_vec(),
+        [Some(1), Some(2), Some(3), Some(4)]
+    );
+
+    // Null predicates leave the row untouched.
+    df.update_where(col("c").lt(lit(5.0)), [lit(0).alias("a")])?;
+    assert_eq!(
+        df.column("a")?.i32()?.to_vec(),
+        [Some(0), Some(2), Some(30), Some(40)]
+    );
+
+    // Nothing matches.
+    df.update_where(lit(false), [lit(1).alias("a")])?;
+    assert_eq!(
+        df.column("a")?.i32()?.to_vec(),
+        [Some(0), Some(2), Some(30), Some(40)]
+    );
+
+    assert!(df.update_where(lit(true), [lit(1).alias("e")]).is_err());
+    assert!(df.update_where(lit(true), [lit("x").alias("a")]).is_err());
+    Ok(())
+}
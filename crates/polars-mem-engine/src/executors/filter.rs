@@ -1,4 +1,5 @@
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::predicates::{ColumnStatistics, SkipBatchPredicate};
 
 use super::*;
 
@@ -8,6 +9,9 @@ pub struct FilterExec {
     // if the predicate contains a window function
     has_window: bool,
     streamable: bool,
+    /// Predicate on the column statistics of the input that is `true` if no row can match, with
+    /// the columns it reads.
+    skip_batch_predicate: Option<(Arc<dyn SkipBatchPredicate>, PlIndexSet<PlSmallStr>)>,
 }
 
 pub fn column_to_mask<'a>(
@@ -54,9 +58,52 @@ impl FilterExec {
             input,
             has_window,
             streamable,
+            skip_batch_predicate: None,
         }
     }
 
+    /// Skip the filter if the cached column statistics of the input show that no row matches.
+    ///
+    /// Statistics are never computed for this, they are only known if the input is sorted or if
+    /// they were computed before, see [`Column::cached_min_max_statistics`].
+    pub(crate) fn with_skip_batch_predicate(
+        mut self,
+        skip_batch_predicate: Arc<dyn SkipBatchPredicate>,
+        live_columns: PlIndexSet<PlSmallStr>,
+    ) -> Self {
+        self.skip_batch_predicate = Some((skip_batch_predicate, live_columns));
+        self
+    }
+
+    fn can_skip(&self, df: &DataFrame) -> PolarsResult<bool> {
+        let Some((skip_batch_predicate, live_columns)) = &self.skip_batch_predicate else {
+            return Ok(false);
+        };
+        if df.height() == 0 {
+            return Ok(false);
+        }
+
+        let mut statistics = PlIndexMap::with_capacity(live_columns.len());
+        for name in live_columns {
+            let column = df.column(name)?;
+            let (min, max) = column
+                .cached_min_max_statistics()
+                .map_or((AnyValue::Null, AnyValue::Null), |(min, max)| {
+                    (min.into_value(), max.into_value())
+                });
+            statistics.insert(
+                name.clone(),
+                ColumnStatistics {
+                    dtype: column.dtype().clone(),
+                    min,
+                    max,
+                    null_count: Some(column.null_count() as IdxSize),
+                },
+            );
+        }
+        skip_batch_predicate.can_skip_batch(df.height() as IdxSize, live_columns, statistics)
+    }
+
     fn execute_hor(
         &mut self,
         df: DataFrame,
@@ -125,6 +172,13 @@ impl Executor for FilterExec {
         }
         let df = self.input.execute(state)?;
 
+        if self.can_skip(&df)? {
+            if state.verbose() {
+                eprintln!("filter skipped: no row matches the column statistics");
+            }
+            return Ok(df.clear());
+        }
+
         let profile_name = if state.has_node_timer() {
             Cow::Owned(format!(".filter({})", &self.predicate.as_ref()))
         } else {
@@ -133,25 +133,21 @@ impl Executor for PythonScanExec {
                     let args = (
                         python_scan_function,
                         with_columns
-                            .map(|x| x.into_iter().map(|x| x.to_string()).collect::<Vec<_>>()),
+                            .as_ref()
+                            .map(|x| x.iter().map(|x| x.to_string()).collect::<Vec<_>>()),
                         predicate,
                         n_rows,
                         batch_size,
                     );
 
                     let generator_init = callable.call1(args)?;
-                    let generator = generator_init.get_item(0).map_err(
-                        |_| polars_err!(ComputeError: "expected tuple got {}", generator_init),
-                    )?;
-                    let can_parse_predicate = generator_init.get_item(1).map_err(
-                        |_| polars_err!(ComputeError: "expected tuple got {}", generator),
-                    )?;
-                    let can_parse_predicate = can_parse_predicate.extract::<bool>().map_err(
-                        |_| polars_err!(ComputeError: "expected bool got {}", can_parse_predicate),
-                    )? && could_serialize_predicate;
+                    let (generator, applied) = io_plugin_init(generator_init)?;
+                    let can_parse_predicate = applied.predicate && could_serialize_predicate;
+                    let projection = with_columns.filter(|_| !applied.projection);
+                    let mut remaining_rows = n_rows.filter(|_| !applied.n_rows);
 
                     let mut chunks = vec![];
-                    loop {
+                    while remaining_rows != Some(0) {
                         match generator.call_method0(intern!(py, "__next__")) {
                             Ok(out) => {
                                 let mut df = python_df_to_rust(py, out)?;
@@ -163,6 +159,13 @@ impl Executor for PythonScanExec {
                                         PolarsResult::Ok(())
                                     })?
                                 }
+                                if let Some(projection) = &projection {
+                                    df = df.select(projection.iter().cloned())?;
+                                }
+                                if let Some(remaining) = &mut remaining_rows {
+                                    df = df.head(Some(*remaining));
+                                    *remaining -= df.height();
+                                }
                                 chunks.push(df)
                             },
                             Err(err) if err.matches(py, PyStopIteration::type_object(py))? => break,
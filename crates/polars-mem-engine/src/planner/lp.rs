@@ -11,7 +11,8 @@ use recursive::recursive;
 use self::python_dsl::PythonScanSource;
 use super::*;
 use crate::executors::{self, CachePrefiller, Executor, GroupByStreamingExec, SinkExecutor};
-use crate::scan_predicate::functions::create_scan_predicate;
+use crate::scan_predicate::SkipBatchPredicateHelper;
+use crate::scan_predicate::functions::{create_scan_predicate, create_skip_batch_predicate_expr};
 
 pub type StreamingExecutorBuilder =
     fn(Node, &mut Arena<IR>, &mut Arena<AExpr>) -> PolarsResult<Box<dyn Executor>>;
@@ -408,16 +409,35 @@ fn create_physical_plan_impl(
         Filter { input, predicate } => {
             let streamable = is_elementwise_rec(predicate.node(), expr_arena);
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+            let input_is_in_memory = matches!(lp_arena.get(input), DataFrameScan { .. });
             let input = recurse!(input, state)?;
             let mut state = ExpressionConversionState::new(true);
-            let predicate =
+            let phys_predicate =
                 create_physical_expr(&predicate, expr_arena, &input_schema, &mut state)?;
-            Ok(Box::new(executors::FilterExec::new(
-                predicate,
-                input,
-                state.has_windows,
-                streamable,
-            )))
+            let mut exec =
+                executors::FilterExec::new(phys_predicate, input, state.has_windows, streamable);
+
+            // In-memory frames cache the statistics of their columns, use them like the
+            // statistics of a Parquet row group to skip the filter entirely.
+            if input_is_in_memory && !state.has_windows {
+                let live_columns = PlIndexSet::from_iter(
+                    aexpr_to_leaf_names_iter(predicate.node(), expr_arena).cloned(),
+                );
+                if let Some(skip_batch_predicate) = create_skip_batch_predicate_expr(
+                    &predicate,
+                    expr_arena,
+                    &input_schema,
+                    &live_columns,
+                    &mut state,
+                )? {
+                    let skip_batch_predicate = Arc::new(SkipBatchPredicateHelper {
+                        skip_batch_predicate,
+                        schema: input_schema,
+                    });
+                    exec = exec.with_skip_batch_predicate(skip_batch_predicate, live_columns);
+                }
+            }
+            Ok(Box::new(exec))
         },
         #[allow(unused_variables)]
         Scan {
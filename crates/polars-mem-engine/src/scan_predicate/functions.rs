@@ -6,6 +6,7 @@ use polars_core::error::PolarsResult;
 use polars_core::prelude::{IDX_DTYPE, IdxCa, InitHashMaps, PlHashMap, PlIndexMap, PlIndexSet};
 use polars_core::schema::Schema;
 use polars_error::polars_warn;
+use polars_expr::prelude::PhysicalExpr;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::default_values::{
@@ -27,6 +28,43 @@ use polars_utils::{IdxSize, format_pl_smallstr};
 use crate::scan_predicate::skip_files_mask::SkipFilesMask;
 use crate::scan_predicate::{PhysicalColumnPredicates, ScanPredicate};
 
+/// Create the physical expression that evaluates to `true` for batches that can be skipped based
+/// on the `len` and the `{col}_min`, `{col}_max` and `{col}_nc` statistics of the `live_columns`.
+///
+/// Returns `None` if the statistics cannot be used to skip batches for this `predicate`.
+pub(crate) fn create_skip_batch_predicate_expr(
+    predicate: &ExprIR,
+    expr_arena: &mut Arena<AExpr>,
+    schema: &Schema,
+    live_columns: &PlIndexSet<PlSmallStr>,
+    state: &mut ExpressionConversionState,
+) -> PolarsResult<Option<Arc<dyn PhysicalExpr>>> {
+    let Some(node) = aexpr_to_skip_batch_predicate(predicate.node(), expr_arena, schema) else {
+        return Ok(None);
+    };
+    let expr = ExprIR::new(node, predicate.output_name_inner().clone());
+
+    if std::env::var("POLARS_OUTPUT_SKIP_BATCH_PRED").as_deref() == Ok("1") {
+        eprintln!("predicate: {}", predicate.display(expr_arena));
+        eprintln!("skip_batch_predicate: {}", expr.display(expr_arena));
+    }
+
+    let mut skip_batch_schema = Schema::with_capacity(1 + live_columns.len());
+
+    skip_batch_schema.insert(PlSmallStr::from_static("len"), IDX_DTYPE);
+    for (col, dtype) in schema.iter() {
+        if !live_columns.contains(col) {
+            continue;
+        }
+
+        skip_batch_schema.insert(format_pl_smallstr!("{col}_min"), dtype.clone());
+        skip_batch_schema.insert(format_pl_smallstr!("{col}_max"), dtype.clone());
+        skip_batch_schema.insert(format_pl_smallstr!("{col}_nc"), IDX_DTYPE);
+    }
+
+    create_physical_expr(&expr, expr_arena, &Arc::new(skip_batch_schema), state).map(Some)
+}
+
 pub fn create_scan_predicate(
     predicate: &ExprIR,
     expr_arena: &mut Arena<AExpr>,
@@ -60,11 +98,22 @@ pub fn create_scan_predicate(
             }
         }
 
+        let hive_predicate_is_exact = non_hive_predicate_parts.is_empty();
+
+        // Parts that also reference non-hive columns can still imply a condition on the hive
+        // columns, e.g. `(date >= '2024-01-01' & x > 1) | date.is_in([...])`. These only prune
+        // files, the original part is still applied by the readers.
+        for &predicate_part in &non_hive_predicate_parts {
+            if let Some(implied) = implied_hive_predicate(predicate_part, expr_arena, hive_schema) {
+                hive_predicate_parts.push(implied)
+            }
+        }
+
         if hive_predicate_parts.is_empty() {
             break;
         }
 
-        if non_hive_predicate_parts.is_empty() {
+        if hive_predicate_is_exact {
             hive_predicate_is_full_predicate = true;
             break;
         }
@@ -117,38 +166,11 @@ pub fn create_scan_predicate(
         aexpr_to_leaf_names_iter(predicate.node(), expr_arena).cloned(),
     ));
 
-    let mut skip_batch_predicate = None;
-
-    if create_skip_batch_predicate {
-        if let Some(node) = aexpr_to_skip_batch_predicate(predicate.node(), expr_arena, schema) {
-            let expr = ExprIR::new(node, predicate.output_name_inner().clone());
-
-            if std::env::var("POLARS_OUTPUT_SKIP_BATCH_PRED").as_deref() == Ok("1") {
-                eprintln!("predicate: {}", predicate.display(expr_arena));
-                eprintln!("skip_batch_predicate: {}", expr.display(expr_arena));
-            }
-
-            let mut skip_batch_schema = Schema::with_capacity(1 + live_columns.len());
-
-            skip_batch_schema.insert(PlSmallStr::from_static("len"), IDX_DTYPE);
-            for (col, dtype) in schema.iter() {
-                if !live_columns.contains(col) {
-                    continue;
-                }
-
-                skip_batch_schema.insert(format_pl_smallstr!("{col}_min"), dtype.clone());
-                skip_batch_schema.insert(format_pl_smallstr!("{col}_max"), dtype.clone());
-                skip_batch_schema.insert(format_pl_smallstr!("{col}_nc"), IDX_DTYPE);
-            }
-
-            skip_batch_predicate = Some(create_physical_expr(
-                &expr,
-                expr_arena,
-                &Arc::new(skip_batch_schema),
-                state,
-            )?);
-        }
-    }
+    let skip_batch_predicate = if create_skip_batch_predicate {
+        create_skip_batch_predicate_expr(&predicate, expr_arena, schema, &live_columns, state)?
+    } else {
+        None
+    };
 
     let column_predicates = if create_column_predicates {
         let column_predicates = aexpr_to_column_predicates(predicate.node(), expr_arena, schema);
@@ -206,6 +228,55 @@ pub fn create_scan_predicate(
     })
 }
 
+/// Derive a predicate that only references hive columns and that is implied by `node`, i.e. it
+/// holds for every row for which `node` holds.
+fn implied_hive_predicate(
+    node: Node,
+    expr_arena: &mut Arena<AExpr>,
+    hive_schema: &Schema,
+) -> Option<Node> {
+    let only_hive_columns = {
+        let mut leaf_names = aexpr_to_leaf_names_iter(node, expr_arena).peekable();
+        leaf_names.peek().is_some() && leaf_names.all(|name| hive_schema.contains(name))
+    };
+    if only_hive_columns {
+        return Some(node);
+    }
+
+    let AExpr::BinaryExpr { left, op, right } = expr_arena.get(node) else {
+        return None;
+    };
+    let (left, op, right) = (*left, *op, *right);
+
+    match op {
+        Operator::And | Operator::LogicalAnd => {
+            let left = implied_hive_predicate(left, expr_arena, hive_schema);
+            let right = implied_hive_predicate(right, expr_arena, hive_schema);
+
+            match (left, right) {
+                (Some(left), Some(right)) => Some(expr_arena.add(AExpr::BinaryExpr {
+                    left,
+                    op: Operator::And,
+                    right,
+                })),
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            }
+        },
+        Operator::Or | Operator::LogicalOr => {
+            let left = implied_hive_predicate(left, expr_arena, hive_schema)?;
+            let right = implied_hive_predicate(right, expr_arena, hive_schema)?;
+
+            Some(expr_arena.add(AExpr::BinaryExpr {
+                left,
+                op: Operator::Or,
+                right,
+            }))
+        },
+        _ => None,
+    }
+}
+
 /// # Returns
 /// (skip_files_mask, predicate)
 pub fn initialize_scan_predicate<'a>(
@@ -56,9 +56,9 @@ pub struct PhysicalColumnPredicates {
 }
 
 /// Helper to implement [`SkipBatchPredicate`].
-struct SkipBatchPredicateHelper {
-    skip_batch_predicate: Arc<dyn PhysicalExpr>,
-    schema: SchemaRef,
+pub(crate) struct SkipBatchPredicateHelper {
+    pub(crate) skip_batch_predicate: Arc<dyn PhysicalExpr>,
+    pub(crate) schema: SchemaRef,
 }
 
 /// Helper for the [`PhysicalExpr`] trait to include constant columns.
@@ -1,6 +1,8 @@
 mod memory_manager;
+mod sort;
 mod spiller;
 mod token;
 
 pub use memory_manager::{AccessPattern, MemoryManager, mm};
+pub use sort::{ExternalSortOptions, ExternalSortOutput, ExternalSorter, SortedRunMerger};
 pub use token::Token;
@@ -0,0 +1,496 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use arrow::array::BinaryArray;
+use arrow::io::ipc::read::{StreamReader, StreamState, read_stream_metadata};
+use arrow::io::ipc::write::{StreamWriter, WriteOptions};
+use polars_core::prelude::row_encode::_get_rows_encoded_arr;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::format_pl_smallstr;
+
+/// Fraction of the total memory a sort may buffer before it spills to disk, if no budget is
+/// configured. This leaves room for the sort itself, which needs about as much again.
+const SORT_MEMORY_BUDGET_FRACTION: f64 = 0.3;
+
+static RUN_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Options of an [`ExternalSorter`].
+#[derive(Clone, Debug)]
+pub struct ExternalSortOptions {
+    /// Number of bytes that may be buffered before a sorted run is spilled to disk.
+    pub memory_budget: usize,
+    /// Directory the sorted runs are spilled to.
+    pub spill_dir: PathBuf,
+}
+
+impl Default for ExternalSortOptions {
+    /// The options set by `POLARS_OOC_SORT_MEMORY_BUDGET` and `POLARS_OOC_SPILL_DIR`.
+    fn default() -> Self {
+        let cfg = polars_config::config();
+        let memory_budget = match cfg.ooc_sort_memory_budget() {
+            0 => (polars_utils::sys::total_memory() as f64 * SORT_MEMORY_BUDGET_FRACTION) as usize,
+            budget => budget as usize,
+        };
+        let spill_dir = cfg
+            .ooc_spill_dir()
+            .unwrap_or_else(|| std::env::temp_dir().join("polars").join("spill"));
+        Self {
+            memory_budget,
+            spill_dir,
+        }
+    }
+}
+
+/// A sorted run spilled to disk, the file is deleted on drop.
+struct SortedRun {
+    path: PathBuf,
+}
+
+impl Drop for SortedRun {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Sorts frames that may not fit in memory together.
+///
+/// Frames are buffered until the memory budget is exceeded, then the buffer is sorted and
+/// spilled to disk as a sorted run. If anything was spilled, the runs are merged when finishing.
+/// The sort keys are passed alongside the frames and are not part of the output.
+///
+/// Frames with dtypes that can't be written to IPC, such as objects, are never spilled. Neither
+/// are frames if the first run can't be written to the spill directory; these are sorted in
+/// memory instead.
+pub struct ExternalSorter {
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    sort_options: SortMultipleOptions,
+    options: ExternalSortOptions,
+    key_names: Vec<PlSmallStr>,
+    buffer: Vec<DataFrame>,
+    buffer_size: usize,
+    runs: Vec<SortedRun>,
+    width: Option<usize>,
+    can_spill: bool,
+}
+
+/// The result of an [`ExternalSorter`].
+pub enum ExternalSortOutput {
+    /// Nothing was spilled, the sorted frame.
+    InMemory(DataFrame),
+    /// The sorted runs, merged lazily in chunks.
+    Spilled(SortedRunMerger),
+}
+
+impl ExternalSorter {
+    pub fn new(
+        num_keys: usize,
+        sort_options: SortMultipleOptions,
+        options: ExternalSortOptions,
+    ) -> Self {
+        let broadcast = |v: &[bool]| match v {
+            [b] => vec![*b; num_keys],
+            v => v.to_vec(),
+        };
+        let descending = broadcast(&sort_options.descending);
+        let nulls_last = broadcast(&sort_options.nulls_last);
+        let key_names = (0..num_keys)
+            .map(|i| format_pl_smallstr!("__POLARS_SORT_KEY_{i}"))
+            .collect();
+        let sort_options = sort_options
+            .with_order_descending_multi(descending.clone())
+            .with_nulls_last_multi(nulls_last.clone());
+        Self {
+            descending,
+            nulls_last,
+            sort_options,
+            options,
+            key_names,
+            buffer: Vec::new(),
+            buffer_size: 0,
+            runs: Vec::new(),
+            width: None,
+            can_spill: true,
+        }
+    }
+
+    /// Number of runs spilled to disk so far.
+    pub fn num_spilled_runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Add a frame with its sort keys, spilling to disk if the memory budget is exceeded.
+    pub fn push(&mut self, df: DataFrame, keys: Vec<Column>) -> PolarsResult<()> {
+        polars_ensure!(
+            keys.len() == self.key_names.len(),
+            ComputeError: "expected {} sort keys, got {}", self.key_names.len(), keys.len()
+        );
+        self.width = Some(df.width());
+        let height = df.height();
+        let mut columns = df.into_columns();
+        for (key, name) in keys.into_iter().zip(&self.key_names) {
+            polars_ensure!(
+                key.len() == height,
+                ShapeMismatch: "sort key has length {} while the frame has height {height}", key.len()
+            );
+            columns.push(key.with_name(name.clone()));
+        }
+        let df = DataFrame::new(height, columns)?;
+        if df.columns().iter().any(|c| c.dtype().contains_objects()) {
+            self.can_spill = false;
+        }
+
+        self.buffer_size += df.estimated_size();
+        self.buffer.push(df);
+        if self.can_spill && self.buffer_size > self.options.memory_budget {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// Take over the buffered frames and spilled runs of `other`.
+    pub fn combine(&mut self, other: ExternalSorter) -> PolarsResult<()> {
+        self.width = self.width.or(other.width);
+        self.can_spill &= other.can_spill;
+        self.runs.extend(other.runs);
+        self.buffer.extend(other.buffer);
+        self.buffer_size += other.buffer_size;
+        if self.can_spill && !self.runs.is_empty() && self.buffer_size > self.options.memory_budget
+        {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn sort_buffer(&mut self) -> PolarsResult<DataFrame> {
+        let buffer = std::mem::take(&mut self.buffer);
+        self.buffer_size = 0;
+        if buffer.is_empty() {
+            return Ok(DataFrame::empty());
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(buffer);
+        df.sort_in_place(self.key_names.clone(), self.sort_options.clone())?;
+        df.rechunk_mut_par();
+        Ok(df)
+    }
+
+    fn spill(&mut self) -> PolarsResult<()> {
+        let df = self.sort_buffer()?;
+        if df.height() == 0 {
+            return Ok(());
+        }
+
+        match self.write_run(&df) {
+            Ok(run) => {
+                self.runs.push(run);
+                Ok(())
+            },
+            // Nothing depends on the spill directory yet, so keep sorting in memory.
+            Err(_) if self.runs.is_empty() => {
+                self.can_spill = false;
+                self.buffer_size = df.estimated_size();
+                self.buffer.push(df);
+                Ok(())
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    fn write_run(&self, df: &DataFrame) -> PolarsResult<SortedRun> {
+        std::fs::create_dir_all(&self.options.spill_dir)?;
+        let path = self.options.spill_dir.join(format!(
+            "sort-{}-{}.arrows",
+            std::process::id(),
+            RUN_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let run = SortedRun { path };
+
+        let file = File::create(&run.path)?;
+        let mut writer =
+            StreamWriter::new(BufWriter::new(file), WriteOptions { compression: None });
+        let batch_size = (polars_config::config().ideal_morsel_size() as usize).max(1);
+        let mut offset = 0;
+        let mut started = false;
+        while offset < df.height() {
+            let batch = df.slice(offset as i64, batch_size);
+            for rb in batch.iter_chunks(CompatLevel::newest(), false) {
+                if !started {
+                    writer.start(rb.schema(), None)?;
+                    started = true;
+                }
+                writer.write(&rb, None)?;
+            }
+            offset += batch.height();
+        }
+        writer.finish()?;
+        writer.into_inner().flush()?;
+        Ok(run)
+    }
+
+    /// Sort everything that was pushed, the output doesn't contain the sort keys.
+    ///
+    /// If nothing was pushed, the output is an empty frame without columns.
+    pub fn finish(mut self) -> PolarsResult<ExternalSortOutput> {
+        let width = self.width.unwrap_or(0);
+        if self.runs.is_empty() {
+            let df = self.sort_buffer()?;
+            return Ok(ExternalSortOutput::InMemory(select_first(df, width)));
+        }
+        self.spill()?;
+
+        let runs = std::mem::take(&mut self.runs)
+            .into_iter()
+            .map(|run| RunReader::try_new(run, &self.descending, &self.nulls_last, width))
+            .filter_map(|r| r.transpose())
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(ExternalSortOutput::Spilled(SortedRunMerger::new(runs)))
+    }
+}
+
+fn select_first(df: DataFrame, width: usize) -> DataFrame {
+    let height = df.height();
+    let mut columns = df.into_columns();
+    columns.truncate(width);
+    // SAFETY: we only removed columns.
+    unsafe { DataFrame::new_unchecked(height, columns) }
+}
+
+/// Reads a sorted run a batch at a time.
+struct RunReader {
+    reader: StreamReader<BufReader<File>>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    width: usize,
+    /// The current batch without the sort keys.
+    batch: DataFrame,
+    /// The row-encoded sort keys of the current batch.
+    keys: BinaryArray<i64>,
+    offset: usize,
+    _run: SortedRun,
+}
+
+impl RunReader {
+    /// Open a run, returns `None` if it is empty.
+    fn try_new(
+        run: SortedRun,
+        descending: &[bool],
+        nulls_last: &[bool],
+        width: usize,
+    ) -> PolarsResult<Option<Self>> {
+        let mut file = BufReader::new(File::open(&run.path)?);
+        let metadata = read_stream_metadata(&mut file)?;
+        let mut reader = Self {
+            reader: StreamReader::new(file, metadata, None),
+            descending: descending.to_vec(),
+            nulls_last: nulls_last.to_vec(),
+            width,
+            batch: DataFrame::empty(),
+            keys: BinaryArray::new_empty(arrow::datatypes::ArrowDataType::LargeBinary),
+            offset: 0,
+            _run: run,
+        };
+        Ok(reader.next_batch()?.then_some(reader))
+    }
+
+    /// Load the next batch, returns `false` if the run is exhausted.
+    fn next_batch(&mut self) -> PolarsResult<bool> {
+        while let Some(state) = self.reader.next() {
+            let StreamState::Some(rb) = state? else {
+                break;
+            };
+            let df = DataFrame::from(rb);
+            if df.height() == 0 {
+                continue;
+            }
+            self.keys = _get_rows_encoded_arr(
+                &df.columns()[self.width..],
+                &self.descending,
+                &self.nulls_last,
+                false,
+            )?;
+            self.batch = select_first(df, self.width);
+            self.offset = 0;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn head(&self) -> &[u8] {
+        self.keys.value(self.offset)
+    }
+}
+
+/// Merges sorted runs, yielding the sorted rows in chunks.
+///
+/// Equal keys are yielded in the order the runs were spilled in.
+pub struct SortedRunMerger {
+    runs: Vec<RunReader>,
+    /// Indices of the runs that are not exhausted, ordered by their head key.
+    order: Vec<usize>,
+    chunk_size: usize,
+}
+
+impl SortedRunMerger {
+    fn new(runs: Vec<RunReader>) -> Self {
+        let mut order: Vec<usize> = (0..runs.len()).collect();
+        order.sort_by(|a, b| runs[*a].head().cmp(runs[*b].head()).then(a.cmp(b)));
+        Self {
+            runs,
+            order,
+            chunk_size: (polars_config::config().ideal_morsel_size() as usize).max(1),
+        }
+    }
+
+    /// Insert run `i` into `order`, behind the runs with a smaller or equal head.
+    fn insert(&mut self, i: usize) {
+        let runs = &self.runs;
+        let head = runs[i].head();
+        let pos = self.order.partition_point(|j| {
+            let cmp = runs[*j].head().cmp(head);
+            cmp.is_lt() || (cmp.is_eq() && *j < i)
+        });
+        self.order.insert(pos, i);
+    }
+
+    fn next_chunk(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let mut slices = Vec::new();
+        let mut height = 0;
+        while height < self.chunk_size && !self.order.is_empty() {
+            let i = self.order.remove(0);
+            let run = &self.runs[i];
+
+            // Take all rows of this run that come before the head of the next run.
+            let end = match self.order.first() {
+                None => run.batch.height(),
+                Some(&j) => {
+                    let bound = self.runs[j].head();
+                    let (mut lo, mut hi) = (run.offset, run.batch.height());
+                    while lo < hi {
+                        let mid = lo + (hi - lo) / 2;
+                        let cmp = run.keys.value(mid).cmp(bound);
+                        if cmp.is_lt() || (cmp.is_eq() && i < j) {
+                            lo = mid + 1;
+                        } else {
+                            hi = mid;
+                        }
+                    }
+                    lo
+                },
+            };
+            let end = end.min(run.offset + self.chunk_size - height);
+
+            let run = &mut self.runs[i];
+            slices.push(run.batch.slice(run.offset as i64, end - run.offset));
+            height += end - run.offset;
+            run.offset = end;
+
+            if run.offset < run.batch.height() || run.next_batch()? {
+                self.insert(i);
+            }
+        }
+
+        if slices.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(accumulate_dataframes_vertical_unchecked(slices)))
+    }
+}
+
+impl Iterator for SortedRunMerger {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sort_external(
+        df: &DataFrame,
+        by: &str,
+        sort_options: SortMultipleOptions,
+        memory_budget: usize,
+    ) -> PolarsResult<(DataFrame, usize)> {
+        let options = ExternalSortOptions {
+            memory_budget,
+            ..Default::default()
+        };
+        let mut sorter = ExternalSorter::new(1, sort_options, options);
+        for offset in (0..df.height()).step_by(7) {
+            let chunk = df.slice(offset as i64, 7);
+            let key = chunk.column(by)?.clone();
+            sorter.push(chunk, vec![key])?;
+        }
+        let runs = sorter.num_spilled_runs();
+        let out = match sorter.finish()? {
+            ExternalSortOutput::InMemory(df) => df,
+            ExternalSortOutput::Spilled(merger) => {
+                accumulate_dataframes_vertical_unchecked(merger.collect::<PolarsResult<Vec<_>>>()?)
+            },
+        };
+        Ok((out, runs))
+    }
+
+    #[test]
+    fn test_external_sort() -> PolarsResult<()> {
+        let a: Vec<Option<i64>> = (0..100)
+            .map(|i| (i % 13 != 0).then_some((i * 37) % 23))
+            .collect();
+        let b: Vec<i64> = (0..100).collect();
+        let df = DataFrame::new(
+            100,
+            vec![Column::new("a".into(), a), Column::new("b".into(), b)],
+        )?;
+
+        for descending in [false, true] {
+            for nulls_last in [false, true] {
+                let sort_options = SortMultipleOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last)
+                    .with_maintain_order(true);
+                let expected = df.sort(["a"], sort_options.clone())?;
+
+                let (out, runs) = sort_external(&df, "a", sort_options.clone(), 1)?;
+                assert!(runs > 1);
+                assert!(out.equals_missing(&expected));
+
+                let (out, runs) = sort_external(&df, "a", sort_options, usize::MAX)?;
+                assert_eq!(runs, 0);
+                assert!(out.equals_missing(&expected));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_external_sort_unwritable_spill_dir() -> PolarsResult<()> {
+        // A directory can't be created below a file.
+        let file = std::env::temp_dir().join(format!("polars-sort-test-{}", std::process::id()));
+        File::create(&file)?;
+        let options = ExternalSortOptions {
+            memory_budget: 1,
+            spill_dir: file.join("spill"),
+        };
+
+        let df = polars_core::df!("a" => [3i64, 1, 2])?;
+        let mut sorter = ExternalSorter::new(1, SortMultipleOptions::default(), options);
+        for offset in 0..3 {
+            let chunk = df.slice(offset, 1);
+            let key = chunk.column("a")?.clone();
+            sorter.push(chunk, vec![key])?;
+        }
+        assert_eq!(sorter.num_spilled_runs(), 0);
+        let ExternalSortOutput::InMemory(out) = sorter.finish()? else {
+            panic!("expected the sort to stay in memory");
+        };
+        std::fs::remove_file(&file)?;
+        assert!(out.equals(&polars_core::df!("a" => [1i64, 2, 3])?));
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::types::NativeType;
+use num_traits::Float;
+use polars_core::prelude::*;
+
+/// Number of independent accumulators, this lets the compiler vectorize the reductions.
+const LANES: usize = 8;
+
+fn dot_slice<T: Float>(a: &[T], b: &[T]) -> T {
+    debug_assert_eq!(a.len(), b.len());
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let rem = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .fold(T::zero(), |acc, (x, y)| acc + *x * *y);
+
+    let mut acc = [T::zero(); LANES];
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for ((acc, x), y) in acc.iter_mut().zip(a).zip(b) {
+            *acc = *acc + *x * *y;
+        }
+    }
+    acc.into_iter().fold(rem, |acc, x| acc + x)
+}
+
+#[derive(Clone, Copy)]
+enum LinalgOp {
+    Dot,
+    L2Norm,
+    CosineSimilarity,
+}
+
+impl LinalgOp {
+    fn name(self) -> &'static str {
+        match self {
+            LinalgOp::Dot => "arr.dot",
+            LinalgOp::L2Norm => "arr.l2_norm",
+            LinalgOp::CosineSimilarity => "arr.cosine_similarity",
+        }
+    }
+
+    fn apply<T: Float>(self, a: &[T], b: &[T]) -> T {
+        match self {
+            LinalgOp::Dot => dot_slice(a, b),
+            LinalgOp::L2Norm => dot_slice(a, a).sqrt(),
+            // A zero vector has no direction, so this is NaN.
+            LinalgOp::CosineSimilarity => {
+                dot_slice(a, b) / (dot_slice(a, a).sqrt() * dot_slice(b, b).sqrt())
+            },
+        }
+    }
+}
+
+/// Get the float dtype the linear algebra kernels compute in for arrays with the given inner
+/// dtypes. This is `Float32` if all inputs are `Float32` and `Float64` otherwise.
+pub fn linalg_float_dtype<'a>(mut inner_dtypes: impl Iterator<Item = &'a DataType>) -> DataType {
+    if inner_dtypes.all(|dt| dt == &DataType::Float32) {
+        DataType::Float32
+    } else {
+        DataType::Float64
+    }
+}
+
+fn cast_to_float(ca: &ArrayChunked, dtype: &DataType) -> PolarsResult<ArrayChunked> {
+    polars_ensure!(
+        ca.inner_dtype().is_primitive_numeric(),
+        InvalidOperation: "linear algebra operations are not supported for arrays of dtype {}", ca.dtype()
+    );
+    let out = ca.cast(&DataType::Array(Box::new(dtype.clone()), ca.width()))?;
+    Ok(out.array()?.rechunk().into_owned())
+}
+
+/// Get the values of row `i`, or `None` if the row or any of its elements is null.
+fn row<'a, T: NativeType>(
+    arr: &FixedSizeListArray,
+    values: &'a PrimitiveArray<T>,
+    width: usize,
+    i: usize,
+) -> Option<&'a [T]> {
+    let start = i * width;
+    let valid = arr.is_valid(i)
+        && values
+            .validity()
+            .is_none_or(|v| v.null_count_range(start, width) == 0);
+    valid.then(|| &values.values()[start..start + width])
+}
+
+/// Applies `op` to the rows of `a` and `b`, broadcasting a side of length 1 to `len` rows.
+fn linalg_impl<T>(a: &ArrayChunked, b: &ArrayChunked, len: usize, op: LinalgOp) -> Series
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let width = a.width();
+    let arr_a = a.downcast_as_array();
+    let arr_b = b.downcast_as_array();
+    let values_a = arr_a
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T::Native>>()
+        .unwrap();
+    let values_b = arr_b
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T::Native>>()
+        .unwrap();
+
+    let out: ChunkedArray<T> = (0..len)
+        .map(|i| {
+            let i_a = if a.len() == 1 { 0 } else { i };
+            let i_b = if b.len() == 1 { 0 } else { i };
+            let a = row(arr_a, values_a, width, i_a)?;
+            let b = row(arr_b, values_b, width, i_b)?;
+            Some(op.apply(a, b))
+        })
+        .collect();
+    out.with_name(a.name().clone()).into_series()
+}
+
+fn linalg(a: &ArrayChunked, b: Option<&ArrayChunked>, op: LinalgOp) -> PolarsResult<Series> {
+    let Some(b) = b else {
+        let dtype = linalg_float_dtype(std::iter::once(a.inner_dtype()));
+        let a = cast_to_float(a, &dtype)?;
+        return Ok(match dtype {
+            DataType::Float32 => linalg_impl::<Float32Type>(&a, &a, a.len(), op),
+            _ => linalg_impl::<Float64Type>(&a, &a, a.len(), op),
+        });
+    };
+
+    // A side of length 1 is broadcast to the length of the other side, which may be empty.
+    let len = match (a.len(), b.len()) {
+        (1, len) | (len, 1) => len,
+        (len_a, len_b) => {
+            polars_ensure!(len_a == len_b, length_mismatch = op.name(), len_a, len_b);
+            len_a
+        },
+    };
+    polars_ensure!(
+        a.width() == b.width(),
+        ShapeMismatch: "arrays in `{}` must have the same width, got {} and {}",
+        op.name(), a.width(), b.width()
+    );
+    let dtype = linalg_float_dtype([a.inner_dtype(), b.inner_dtype()].into_iter());
+    let a = cast_to_float(a, &dtype)?;
+    let b = cast_to_float(b, &dtype)?;
+
+    Ok(match dtype {
+        DataType::Float32 => linalg_impl::<Float32Type>(&a, &b, len, op),
+        _ => linalg_impl::<Float64Type>(&a, &b, len, op),
+    })
+}
+
+pub(super) fn array_dot(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series> {
+    linalg(a, Some(b), LinalgOp::Dot)
+}
+
+pub(super) fn array_l2_norm(ca: &ArrayChunked) -> PolarsResult<Series> {
+    linalg(ca, None, LinalgOp::L2Norm)
+}
+
+pub(super) fn array_cosine_similarity(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<Series> {
+    linalg(a, Some(b), LinalgOp::CosineSimilarity)
+}
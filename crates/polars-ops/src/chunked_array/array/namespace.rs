@@ -14,6 +14,8 @@ use crate::chunked_array::array::sum_mean::sum_with_nulls;
 use crate::prelude::array::any_all::{array_all, array_any};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
+#[cfg(feature = "array_linalg")]
+use crate::prelude::array::linalg::{array_cosine_similarity, array_dot, array_l2_norm};
 use crate::prelude::array::sum_mean::sum_array_numerical;
 use crate::series::ArgAgg;
 
@@ -149,6 +151,24 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    #[cfg(feature = "array_linalg")]
+    fn array_dot(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_dot(ca, other)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn array_l2_norm(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_l2_norm(ca)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn array_cosine_similarity(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_cosine_similarity(ca, other)
+    }
+
     fn array_shift(&self, n: &Series) -> PolarsResult<Series> {
         let ca = self.as_array();
         let n_s = n.cast(&DataType::Int64)?;
@@ -0,0 +1,180 @@
+use arrow::array::{Array, MutableBinaryViewArray};
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::{Engine as _, alphabet};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The alphabet used to base64 encode and decode values.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum Base64Alphabet {
+    /// The standard alphabet of RFC 4648, using `+` and `/`.
+    #[default]
+    Standard,
+    /// The URL and filename safe alphabet of RFC 4648, using `-` and `_`.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    pub fn name(self) -> &'static str {
+        match self {
+            Base64Alphabet::Standard => "base64",
+            Base64Alphabet::UrlSafe => "base64url",
+        }
+    }
+
+    /// Engine that encodes with or without padding and decodes both padded and unpadded input.
+    fn engine(self, padding: bool) -> &'static GeneralPurpose {
+        const fn engine(alphabet: &alphabet::Alphabet, padding: bool) -> GeneralPurpose {
+            let config = GeneralPurposeConfig::new()
+                .with_encode_padding(padding)
+                .with_decode_padding_mode(DecodePaddingMode::Indifferent);
+            GeneralPurpose::new(alphabet, config)
+        }
+        static STANDARD: GeneralPurpose = engine(&alphabet::STANDARD, true);
+        static STANDARD_NO_PAD: GeneralPurpose = engine(&alphabet::STANDARD, false);
+        static URL_SAFE: GeneralPurpose = engine(&alphabet::URL_SAFE, true);
+        static URL_SAFE_NO_PAD: GeneralPurpose = engine(&alphabet::URL_SAFE, false);
+
+        match (self, padding) {
+            (Base64Alphabet::Standard, true) => &STANDARD,
+            (Base64Alphabet::Standard, false) => &STANDARD_NO_PAD,
+            (Base64Alphabet::UrlSafe, true) => &URL_SAFE,
+            (Base64Alphabet::UrlSafe, false) => &URL_SAFE_NO_PAD,
+        }
+    }
+}
+
+/// Apply `f` to every non-null value, one `BinaryView` chunk at a time.
+///
+/// `f` writes its output into a scratch buffer that is reused across values and returns `false`
+/// if the value could not be transcoded. Such values raise an error if `strict` is set and
+/// become null otherwise.
+fn transcode(
+    ca: &BinaryChunked,
+    strict: bool,
+    encoding: &str,
+    mut f: impl FnMut(&[u8], &mut Vec<u8>) -> bool,
+) -> PolarsResult<BinaryChunked> {
+    let mut buf = Vec::new();
+    let chunks = ca.downcast_iter().map(|arr| {
+        let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+        for opt_v in arr.iter() {
+            let Some(v) = opt_v else {
+                out.push_null();
+                continue;
+            };
+            buf.clear();
+            if f(v, &mut buf) {
+                out.push_value(buf.as_slice());
+            } else if strict {
+                let end = v.len().min(32);
+                polars_bail!(
+                    ComputeError:
+                    "invalid `{}` encoding found in value {:?}{}; try setting `strict=false` to ignore",
+                    encoding, String::from_utf8_lossy(&v[..end]), if end < v.len() { "..." } else { "" }
+                );
+            } else {
+                out.push_null();
+            }
+        }
+        PolarsResult::Ok(out.freeze())
+    });
+    ChunkedArray::try_from_chunk_iter(ca.name().clone(), chunks)
+}
+
+pub(crate) fn hex_encode(ca: &BinaryChunked) -> StringChunked {
+    let out = transcode(ca, true, "hex", |v, buf| {
+        buf.resize(v.len() * 2, 0);
+        hex::encode_to_slice(v, buf).is_ok()
+    })
+    .unwrap();
+    // SAFETY: hex digits are valid UTF-8.
+    unsafe { out.to_string_unchecked() }
+}
+
+pub(crate) fn hex_decode(ca: &BinaryChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    transcode(ca, strict, "hex", |v, buf| {
+        buf.resize(v.len() / 2, 0);
+        v.len() % 2 == 0 && hex::decode_to_slice(v, buf).is_ok()
+    })
+}
+
+pub(crate) fn base64_encode(
+    ca: &BinaryChunked,
+    alphabet: Base64Alphabet,
+    padding: bool,
+) -> StringChunked {
+    let engine = alphabet.engine(padding);
+    let out = transcode(ca, true, alphabet.name(), |v, buf| {
+        let Some(len) = base64::encoded_len(v.len(), padding) else {
+            return false;
+        };
+        buf.resize(len, 0);
+        engine.encode_slice(v, buf).is_ok()
+    })
+    .unwrap();
+    // SAFETY: the base64 alphabets are valid UTF-8.
+    unsafe { out.to_string_unchecked() }
+}
+
+pub(crate) fn base64_decode(
+    ca: &BinaryChunked,
+    alphabet: Base64Alphabet,
+    strict: bool,
+) -> PolarsResult<BinaryChunked> {
+    let engine = alphabet.engine(true);
+    transcode(ca, strict, alphabet.name(), |v, buf| {
+        engine.decode_vec(v, buf).is_ok()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_base64_alphabet_and_padding() -> PolarsResult<()> {
+        let ca = BinaryChunked::from_slice_options(
+            "a".into(),
+            &[Some(b"\xfb\xff".as_slice()), None, Some(b"".as_slice())],
+        );
+
+        let out = base64_encode(&ca, Base64Alphabet::Standard, true);
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some("+/8="), None, Some("")]
+        );
+        let out = base64_encode(&ca, Base64Alphabet::UrlSafe, false);
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some("-_8"), None, Some("")]
+        );
+
+        // Padded and unpadded input are both accepted.
+        for encoded in ["-_8", "-_8="] {
+            let input = BinaryChunked::from_slice("a".into(), &[encoded.as_bytes()]);
+            let out = base64_decode(&input, Base64Alphabet::UrlSafe, true)?;
+            assert_eq!(out.get(0), Some(b"\xfb\xff".as_slice()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_error_policy() -> PolarsResult<()> {
+        let ca = BinaryChunked::from_slice(
+            "a".into(),
+            &[b"00ff".as_slice(), b"0g".as_slice(), b"abc".as_slice()],
+        );
+        assert!(hex_decode(&ca, true).is_err());
+
+        let out = hex_decode(&ca, false)?;
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some(b"\x00\xff".as_slice()), None, None]
+        );
+        Ok(())
+    }
+}
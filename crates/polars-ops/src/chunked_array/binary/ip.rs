@@ -0,0 +1,186 @@
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+fn mask_v4(prefix_len: u8) -> u32 {
+    u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0)
+}
+
+fn mask_v6(prefix_len: u8) -> u128 {
+    u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or(0)
+}
+
+/// An IPv4 or IPv6 network in CIDR notation, e.g. `10.0.0.0/8`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct IpSubnet {
+    network: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpSubnet {
+    /// Create the subnet of all addresses that share the first `prefix_len` bits with `addr`.
+    pub fn new(addr: IpAddr, prefix_len: u8) -> PolarsResult<Self> {
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        polars_ensure!(
+            prefix_len <= max_len,
+            InvalidOperation: "prefix length {} of subnet {}/{} exceeds the maximum of {}",
+            prefix_len, addr, prefix_len, max_len
+        );
+        // Clear the host bits, containment then only has to compare the masked address.
+        let network = match addr {
+            IpAddr::V4(a) => IpAddr::V4((u32::from(a) & mask_v4(prefix_len)).into()),
+            IpAddr::V6(a) => IpAddr::V6((u128::from(a) & mask_v6(prefix_len)).into()),
+        };
+        Ok(Self {
+            network,
+            prefix_len,
+        })
+    }
+
+    /// Whether `addr` lies within this subnet. Addresses never lie within a subnet of the other
+    /// IP version.
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        match (self.network, addr) {
+            (IpAddr::V4(net), IpAddr::V4(a)) => {
+                u32::from(a) & mask_v4(self.prefix_len) == net.into()
+            },
+            (IpAddr::V6(net), IpAddr::V6(a)) => {
+                u128::from(a) & mask_v6(self.prefix_len) == net.into()
+            },
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpSubnet {
+    type Err = PolarsError;
+
+    /// Parse a subnet in CIDR notation. A plain address is the subnet of just that address.
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        let invalid = || {
+            polars_err!(
+                InvalidOperation: "invalid IP subnet {:?}, expected CIDR notation such as \"10.0.0.0/8\"", s
+            )
+        };
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, len)) => (addr, Some(len.parse::<u8>().map_err(|_| invalid())?)),
+            None => (s, None),
+        };
+        let addr = IpAddr::from_str(addr).map_err(|_| invalid())?;
+        let prefix_len = prefix_len.unwrap_or(if addr.is_ipv4() { 32 } else { 128 });
+        Self::new(addr, prefix_len)
+    }
+}
+
+impl Display for IpSubnet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.network, self.prefix_len)
+    }
+}
+
+/// Decode an address from its 4 (IPv4) or 16 (IPv6) byte representation in network byte order.
+fn decode_ip(v: &[u8]) -> PolarsResult<IpAddr> {
+    if let Ok(octets) = <[u8; 4]>::try_from(v) {
+        Ok(IpAddr::from(octets))
+    } else if let Ok(octets) = <[u8; 16]>::try_from(v) {
+        Ok(IpAddr::from(octets))
+    } else {
+        polars_bail!(
+            InvalidOperation: "expected an IP address of 4 or 16 bytes, got {} bytes", v.len()
+        )
+    }
+}
+
+/// Parse IPv4 and IPv6 addresses into their 4 or 16 byte representation in network byte order.
+///
+/// Values that are not valid addresses raise an error if `strict` is set and become null
+/// otherwise.
+pub fn parse_ip(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let parse = |s: &str| -> Option<Vec<u8>> {
+        match IpAddr::from_str(s).ok()? {
+            IpAddr::V4(a) => Some(a.octets().to_vec()),
+            IpAddr::V6(a) => Some(a.octets().to_vec()),
+        }
+    };
+    if strict {
+        ca.try_apply_nonnull_values_generic(|s| {
+            parse(s).ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "invalid IP address {:?}; try setting `strict=false` to ignore", s
+                )
+            })
+        })
+    } else {
+        Ok(BinaryChunked::from_iter_options(
+            ca.name().clone(),
+            ca.iter().map(|opt_s| opt_s.and_then(parse)),
+        ))
+    }
+}
+
+/// Check whether the addresses produced by [`parse_ip`] lie within any of the `subnets`.
+pub fn ip_is_in_subnet(ca: &BinaryChunked, subnets: &[IpSubnet]) -> PolarsResult<BooleanChunked> {
+    ca.try_apply_nonnull_values_generic(|v| {
+        let addr = decode_ip(v)?;
+        PolarsResult::Ok(subnets.iter().any(|subnet| subnet.contains(addr)))
+    })
+}
+
+/// Convert the addresses produced by [`parse_ip`] to their integer value.
+pub fn ip_to_int(ca: &BinaryChunked) -> PolarsResult<UInt128Chunked> {
+    ca.try_apply_nonnull_values_generic(|v| {
+        PolarsResult::Ok(match decode_ip(v)? {
+            IpAddr::V4(a) => u32::from(a) as u128,
+            IpAddr::V6(a) => u128::from(a),
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ip_subnet() -> PolarsResult<()> {
+        let subnet: IpSubnet = "10.1.2.3/8".parse()?;
+        assert_eq!(subnet.to_string(), "10.0.0.0/8");
+        assert!(subnet.contains("10.255.0.1".parse().unwrap()));
+        assert!(!subnet.contains("11.0.0.1".parse().unwrap()));
+        assert!(!subnet.contains("::a00:1".parse().unwrap()));
+
+        let subnet: IpSubnet = "2001:db8::/32".parse()?;
+        assert!(subnet.contains("2001:db8::1".parse().unwrap()));
+        assert!(!subnet.contains("2001:db9::1".parse().unwrap()));
+
+        let everything: IpSubnet = "0.0.0.0/0".parse()?;
+        assert!(everything.contains("255.255.255.255".parse().unwrap()));
+
+        assert!("10.0.0.0/33".parse::<IpSubnet>().is_err());
+        assert!("10.0.0/8".parse::<IpSubnet>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ip() -> PolarsResult<()> {
+        let ca = StringChunked::new("ip".into(), [Some("192.168.0.1"), None, Some("::1")]);
+        let parsed = parse_ip(&ca, true)?;
+        assert_eq!(parsed.get(0), Some([192, 168, 0, 1].as_slice()));
+        assert_eq!(parsed.get(2).map(|v| v.len()), Some(16));
+
+        let ints = ip_to_int(&parsed)?;
+        assert_eq!(ints.get(0), Some(0xc0a8_0001));
+        assert_eq!(ints.get(1), None);
+        assert_eq!(ints.get(2), Some(1));
+
+        let invalid = StringChunked::new("ip".into(), ["1.2.3.4", "not an ip"]);
+        assert!(parse_ip(&invalid, true).is_err());
+        assert_eq!(parse_ip(&invalid, false)?.null_count(), 1);
+        Ok(())
+    }
+}
@@ -1,11 +1,24 @@
+#[cfg(any(feature = "binary_encoding", feature = "string_encoding"))]
+pub(crate) mod encoding;
 mod get;
+#[cfg(feature = "ip_address")]
+mod ip;
 mod namespace;
 mod slice;
+#[cfg(feature = "uuid")]
+mod uuid;
 
+#[cfg(any(feature = "binary_encoding", feature = "string_encoding"))]
+pub use encoding::Base64Alphabet;
 pub use get::bin_get;
+#[cfg(feature = "ip_address")]
+pub use ip::*;
 pub use namespace::*;
 use polars_core::prelude::*;
 
+#[cfg(feature = "uuid")]
+pub use self::uuid::*;
+
 pub trait AsBinary {
     fn as_binary(&self) -> &BinaryChunked;
 }
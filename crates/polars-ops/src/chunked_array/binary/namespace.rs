@@ -1,12 +1,5 @@
-#[cfg(feature = "binary_encoding")]
-use std::borrow::Cow;
-
 #[cfg(feature = "binary_encoding")]
 use arrow::array::Array;
-#[cfg(feature = "binary_encoding")]
-use base64::Engine as _;
-#[cfg(feature = "binary_encoding")]
-use base64::engine::general_purpose;
 use memchr::memmem::find;
 use polars_compute::cast::{binview_to_fixed_size_list_dyn, binview_to_primitive_dyn};
 use polars_compute::size::binary_size_bytes;
@@ -135,57 +128,39 @@ pub trait BinaryNameSpaceImpl: AsBinary {
     #[cfg(feature = "binary_encoding")]
     fn hex_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
         let ca = self.as_binary();
-        if strict {
-            ca.try_apply_nonnull_values_generic(|s| {
-                hex::decode(s).map_err(|_| {
-                    polars_err!(
-                        ComputeError:
-                        "invalid `hex` encoding found; try setting `strict=false` to ignore"
-                    )
-                })
-            })
-        } else {
-            Ok(ca.apply(|opt_s| opt_s.and_then(|s| hex::decode(s).ok().map(Cow::Owned))))
-        }
+        encoding::hex_decode(ca, strict)
     }
 
     #[cfg(feature = "binary_encoding")]
     fn hex_encode(&self) -> Series {
         let ca = self.as_binary();
-        unsafe {
-            ca.apply_values(|s| hex::encode(s).into_bytes().into())
-                .cast_unchecked(&DataType::String)
-                .unwrap()
-        }
+        encoding::hex_encode(ca).into_series()
     }
 
     #[cfg(feature = "binary_encoding")]
     fn base64_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        self.base64_decode_with_options(Base64Alphabet::Standard, strict)
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base64_decode_with_options(
+        &self,
+        alphabet: Base64Alphabet,
+        strict: bool,
+    ) -> PolarsResult<BinaryChunked> {
         let ca = self.as_binary();
-        if strict {
-            ca.try_apply_nonnull_values_generic(|s| {
-                general_purpose::STANDARD.decode(s).map_err(|_e| {
-                    polars_err!(
-                        ComputeError:
-                        "invalid `base64` encoding found; try setting `strict=false` to ignore"
-                    )
-                })
-            })
-        } else {
-            Ok(ca.apply(|opt_s| {
-                opt_s.and_then(|s| general_purpose::STANDARD.decode(s).ok().map(Cow::Owned))
-            }))
-        }
+        encoding::base64_decode(ca, alphabet, strict)
     }
 
     #[cfg(feature = "binary_encoding")]
     fn base64_encode(&self) -> Series {
+        self.base64_encode_with_options(Base64Alphabet::Standard, true)
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base64_encode_with_options(&self, alphabet: Base64Alphabet, padding: bool) -> Series {
         let ca = self.as_binary();
-        unsafe {
-            ca.apply_values(|s| general_purpose::STANDARD.encode(s).into_bytes().into())
-                .cast_unchecked(&DataType::String)
-                .unwrap()
-        }
+        encoding::base64_encode(ca, alphabet, padding).into_series()
     }
 
     #[cfg(feature = "binary_encoding")]
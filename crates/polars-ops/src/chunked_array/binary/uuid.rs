@@ -0,0 +1,131 @@
+use polars_core::prelude::*;
+use polars_core::random::get_global_random_u64;
+use uuid::{Builder, Uuid};
+
+const SPLITMIX64_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The `i`-th output of the SplitMix64 generator started at `seed`.
+fn splitmix64(seed: u64, i: u64) -> u64 {
+    let mut z = seed.wrapping_add(i.wrapping_add(1).wrapping_mul(SPLITMIX64_GAMMA));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Generate random (version 4) UUIDs for the rows `offset..offset + n`, in their canonical
+/// lowercase hyphenated form.
+///
+/// The UUID of every row only depends on the seed and the position of the row, so evaluating
+/// disjoint ranges of rows never repeats a UUID. The output is deterministic if a `seed` is
+/// given, otherwise the global random state is used.
+pub fn uuid4(name: PlSmallStr, offset: usize, n: usize, seed: Option<u64>) -> StringChunked {
+    let seed = seed.unwrap_or_else(get_global_random_u64);
+    let mut builder = StringChunkedBuilder::new(name, n);
+    let mut buf = Uuid::encode_buffer();
+    for row in offset as u64..(offset + n) as u64 {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&splitmix64(seed, 2 * row).to_le_bytes());
+        bytes[8..].copy_from_slice(&splitmix64(seed, 2 * row + 1).to_le_bytes());
+        let uuid = Builder::from_random_bytes(bytes).into_uuid();
+        builder.append_value(uuid.hyphenated().encode_lower(&mut buf));
+    }
+    builder.finish()
+}
+
+/// Decode a UUID from its 16 byte representation.
+fn decode_uuid(v: &[u8]) -> PolarsResult<Uuid> {
+    Uuid::from_slice(v).map_err(
+        |_| polars_err!(InvalidOperation: "expected a UUID of 16 bytes, got {} bytes", v.len()),
+    )
+}
+
+/// Parse UUIDs into their 16 byte representation.
+///
+/// Besides the canonical hyphenated form, the simple (`67e55044...`), braced (`{...}`) and URN
+/// (`urn:uuid:...`) forms are accepted, in any case. Values that are not valid UUIDs raise an
+/// error if `strict` is set and become null otherwise.
+pub fn parse_uuid(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let parse = |s: &str| Uuid::try_parse(s).ok().map(Uuid::into_bytes);
+    if strict {
+        ca.try_apply_nonnull_values_generic(|s| {
+            parse(s).ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "invalid UUID {:?}; try setting `strict=false` to ignore", s
+                )
+            })
+        })
+    } else {
+        Ok(BinaryChunked::from_iter_options(
+            ca.name().clone(),
+            ca.iter().map(|opt_s| opt_s.and_then(parse)),
+        ))
+    }
+}
+
+/// Format the UUIDs produced by [`parse_uuid`] in their canonical lowercase hyphenated form.
+pub fn uuid_to_str(ca: &BinaryChunked) -> PolarsResult<StringChunked> {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    let mut buf = Uuid::encode_buffer();
+    for opt_v in ca.iter() {
+        match opt_v {
+            Some(v) => builder.append_value(decode_uuid(v)?.hyphenated().encode_lower(&mut buf)),
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uuid4() {
+        let a = uuid4("uuid".into(), 0, 3, Some(0));
+        let b = uuid4("uuid".into(), 0, 3, Some(0));
+        assert_eq!(a.iter().collect::<Vec<_>>(), b.iter().collect::<Vec<_>>());
+        assert_ne!(a.get(0), a.get(1));
+
+        // Rows at an offset continue the same sequence.
+        let c = uuid4("uuid".into(), 1, 2, Some(0));
+        assert_eq!(c.iter().collect::<Vec<_>>(), a.iter().skip(1).collect::<Vec<_>>());
+        for s in a.into_no_null_iter() {
+            let uuid = Uuid::parse_str(s).unwrap();
+            assert_eq!(uuid.get_version_num(), 4);
+            assert_eq!(uuid.hyphenated().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_parse_uuid() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "uuid".into(),
+            [
+                Some("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+                None,
+                Some("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            ],
+        );
+        let parsed = parse_uuid(&ca, true)?;
+        assert_eq!(parsed.get(0).map(|v| v.len()), Some(16));
+        assert_eq!(parsed.get(0), parsed.get(2));
+
+        let formatted = uuid_to_str(&parsed)?;
+        assert_eq!(
+            formatted.iter().collect::<Vec<_>>(),
+            [
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                None,
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            ]
+        );
+
+        let invalid = StringChunked::new("uuid".into(), ["67e55044-10b1-426f-9247", "x"]);
+        assert!(parse_uuid(&invalid, true).is_err());
+        assert_eq!(parse_uuid(&invalid, false)?.null_count(), 2);
+
+        let wrong_len = BinaryChunked::new("uuid".into(), [[1u8, 2, 3].as_slice()]);
+        assert!(uuid_to_str(&wrong_len).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::series::{cum_count, cum_max, cum_min, cum_prod, cum_sum};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ListCumFunction {
+    Sum,
+    Prod,
+    Min,
+    Max,
+    Count,
+}
+
+impl Display for ListCumFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ListCumFunction::Sum => "cum_sum",
+            ListCumFunction::Prod => "cum_prod",
+            ListCumFunction::Min => "cum_min",
+            ListCumFunction::Max => "cum_max",
+            ListCumFunction::Count => "cum_count",
+        };
+        write!(f, "{s}")
+    }
+}
+
+pub(super) fn list_cum_agg(
+    ca: &ListChunked,
+    function: ListCumFunction,
+    reverse: bool,
+) -> PolarsResult<ListChunked> {
+    let f = match function {
+        ListCumFunction::Sum => cum_sum,
+        ListCumFunction::Prod => cum_prod,
+        ListCumFunction::Min => cum_min,
+        ListCumFunction::Max => cum_max,
+        ListCumFunction::Count => cum_count,
+    };
+    ca.try_apply_amortized(|s| f(s.as_ref(), reverse))
+}
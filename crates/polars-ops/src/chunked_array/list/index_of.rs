@@ -0,0 +1,96 @@
+use arrow::array::Array;
+use polars_core::chunked_array::cast::CastOptions;
+
+use super::*;
+
+/// Find the index of the first element equal to `value` in every list, or null if it can't be
+/// found.
+///
+/// `value` is either a unit-length series or holds a value for every list. A null `value`
+/// matches null elements. A `value` that can't be cast losslessly to the type of the elements,
+/// e.g. because it is out of range, is never found.
+pub fn list_index_of(ca: &ListChunked, value: &Series) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        value.len() == 1 || value.len() == ca.len(),
+        ShapeMismatch: "argument in `list.index_of` must have length 1 or {}, got {}",
+        ca.len(), value.len()
+    );
+    let (value, lost) = cast_lossless(value, ca.inner_dtype())?;
+
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let offsets = arr.offsets().as_slice();
+    let elements = ca.get_inner();
+
+    let mask = if value.len() == 1 {
+        elements.equal_missing(&value)?
+    } else {
+        // Broadcast every value over the elements of its list.
+        let mut idx = vec![0 as IdxSize; elements.len()];
+        for (i, w) in offsets.windows(2).enumerate() {
+            idx[w[0] as usize..w[1] as usize].fill(i as IdxSize);
+        }
+        // SAFETY: all indices are row indices and `value` has a value for every row.
+        let value = unsafe { value.take_slice_unchecked(&idx) };
+        elements.equal_missing(&value)?
+    };
+    let mask = mask.rechunk();
+    let mask = mask.downcast_as_array();
+    debug_assert_eq!(mask.null_count(), 0);
+    let mask = mask.values();
+
+    let out = offsets.windows(2).enumerate().map(|(i, w)| {
+        let is_lost = lost
+            .as_ref()
+            .is_some_and(|lost| lost.get(if lost.len() == 1 { 0 } else { i }).unwrap());
+        if !arr.is_valid(i) || is_lost {
+            return None;
+        }
+        let (start, len) = (w[0] as usize, (w[1] - w[0]) as usize);
+        let idx = mask.clone().sliced(start, len).leading_zeros();
+        (idx < len).then_some(idx as IdxSize)
+    });
+    Ok(IdxCa::from_iter_options(ca.name().clone(), out))
+}
+
+/// Find the index of the first `true` in every list of booleans, or null if there is none.
+///
+/// This is used with a predicate evaluated on the elements of every list.
+pub fn list_find(ca: &ListChunked) -> PolarsResult<IdxCa> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let offsets = arr.offsets().as_slice();
+    let mask = ca.get_inner().cast(&DataType::Boolean)?;
+    let mask = mask.bool()?.rechunk();
+    let mask = mask.downcast_as_array();
+    // A null is not a match.
+    let mask = match mask.validity() {
+        Some(validity) => mask.values() & validity,
+        None => mask.values().clone(),
+    };
+
+    let out = offsets.windows(2).enumerate().map(|(i, w)| {
+        if !arr.is_valid(i) {
+            return None;
+        }
+        let (start, len) = (w[0] as usize, (w[1] - w[0]) as usize);
+        let idx = mask.clone().sliced(start, len).leading_zeros();
+        (idx < len).then_some(idx as IdxSize)
+    });
+    Ok(IdxCa::from_iter_options(ca.name().clone(), out))
+}
+
+/// Cast `value` to `dtype`, returning for every value whether it was lost in the cast, i.e. it
+/// became null or changed.
+fn cast_lossless(
+    value: &Series,
+    dtype: &DataType,
+) -> PolarsResult<(Series, Option<BooleanChunked>)> {
+    if value.dtype() == dtype {
+        return Ok((value.clone(), None));
+    }
+    let cast = value.cast_with_options(dtype, CastOptions::NonStrict)?;
+    let roundtrip = cast.cast_with_options(value.dtype(), CastOptions::NonStrict)?;
+    let lost = roundtrip.not_equal_missing(value)?.rechunk().into_owned();
+    Ok((cast, Some(lost)))
+}
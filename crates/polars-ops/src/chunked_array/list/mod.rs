@@ -3,10 +3,16 @@ use polars_core::prelude::*;
 #[cfg(feature = "list_any_all")]
 mod any_all;
 mod count;
+#[cfg(feature = "cum_agg")]
+mod cum_agg;
 mod dispersion;
 mod get;
+#[cfg(feature = "index_of")]
+mod index_of;
 mod min_max;
 mod namespace;
+#[cfg(feature = "rolling_window")]
+mod rolling;
 #[cfg(feature = "list_sets")]
 mod sets;
 mod sum_mean;
@@ -17,8 +23,14 @@ mod to_struct;
 pub use count::*;
 #[cfg(not(feature = "list_count"))]
 use count::*;
+#[cfg(feature = "cum_agg")]
+pub use cum_agg::ListCumFunction;
 pub use get::*;
+#[cfg(feature = "index_of")]
+pub use index_of::*;
 pub use namespace::*;
+#[cfg(feature = "rolling_window")]
+pub use rolling::*;
 #[cfg(feature = "list_sets")]
 pub use sets::*;
 #[cfg(feature = "list_to_struct")]
@@ -300,6 +300,13 @@ pub trait ListNameSpaceImpl: AsList {
         ca.try_apply_amortized(|s| diff(s.as_ref(), n, null_behavior))
     }
 
+    /// Compute a cumulative aggregation within every sub-list.
+    #[cfg(feature = "cum_agg")]
+    fn lst_cum_agg(&self, function: ListCumFunction, reverse: bool) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        super::cum_agg::list_cum_agg(ca, function, reverse)
+    }
+
     fn lst_shift(&self, periods: &Column) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
         let periods_s = periods.cast(&DataType::Int64)?;
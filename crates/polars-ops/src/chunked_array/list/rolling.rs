@@ -0,0 +1,35 @@
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Aggregation applied over a fixed-size window that slides within every list.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ListRollingFunction {
+    Min,
+    Max,
+    Mean,
+    Sum,
+    Median,
+    /// Standard deviation with the given delta degrees of freedom.
+    Std(u8),
+    /// Variance with the given delta degrees of freedom.
+    Var(u8),
+}
+
+impl Display for ListRollingFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ListRollingFunction::Min => "rolling_min",
+            ListRollingFunction::Max => "rolling_max",
+            ListRollingFunction::Mean => "rolling_mean",
+            ListRollingFunction::Sum => "rolling_sum",
+            ListRollingFunction::Median => "rolling_median",
+            ListRollingFunction::Std(_) => "rolling_std",
+            ListRollingFunction::Var(_) => "rolling_var",
+        };
+        write!(f, "{s}")
+    }
+}
@@ -101,6 +101,69 @@ where
     }
 }
 
+fn count_into<K: Eq + Hash + Copy>(counts: &mut PlHashMap<K, usize>, values: &[K]) {
+    counts.clear();
+    for v in values {
+        *counts.entry(*v).or_default() += 1;
+    }
+}
+
+/// Decrement the count of `v`, returning whether an occurrence was left.
+fn take_one<K: Eq + Hash>(counts: &mut PlHashMap<K, usize>, v: &K) -> bool {
+    match counts.get_mut(v) {
+        Some(n) if *n > 0 => {
+            *n -= 1;
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Set operations with bag semantics: every occurrence of a value counts and the output
+/// follows the order of the left operand.
+fn multiset_operation<I, J, K, R>(
+    counts: &mut PlHashMap<K, usize>,
+    buf: &mut Vec<K>,
+    a: &mut I,
+    b: &mut J,
+    out: &mut R,
+    set_op: SetOperation,
+) -> usize
+where
+    K: Eq + Hash + Copy,
+    I: Iterator<Item = K>,
+    J: Iterator<Item = K>,
+    R: MaterializeValues<K>,
+{
+    buf.clear();
+    buf.extend(b);
+
+    match set_op {
+        SetOperation::Intersection => {
+            count_into(counts, buf);
+            out.extend_buf(a.filter(|v| take_one(counts, v)))
+        },
+        SetOperation::Difference => {
+            count_into(counts, buf);
+            out.extend_buf(a.filter(|v| !take_one(counts, v)))
+        },
+        SetOperation::Union => {
+            counts.clear();
+            out.extend_buf(a.inspect(|v| *counts.entry(*v).or_default() += 1));
+            out.extend_buf(buf.iter().copied().filter(|v| !take_one(counts, v)))
+        },
+        SetOperation::SymmetricDifference => {
+            let n_b = buf.len();
+            buf.extend(a);
+            let (rhs, lhs) = buf.split_at(n_b);
+            count_into(counts, rhs);
+            out.extend_buf(lhs.iter().copied().filter(|v| !take_one(counts, v)));
+            count_into(counts, lhs);
+            out.extend_buf(rhs.iter().copied().filter(|v| !take_one(counts, v)))
+        },
+    }
+}
+
 fn copied_wrapper_opt<T: Copy + TotalEq + TotalHash>(
     v: Option<&T>,
 ) -> <Option<T> as ToTotalOrd>::TotalOrdItem {
@@ -135,6 +198,7 @@ fn primitive<T>(
     offsets_a: &[i64],
     offsets_b: &[i64],
     set_op: SetOperation,
+    multiset: bool,
     validity: Option<Bitmap>,
 ) -> PolarsResult<ListArray<i64>>
 where
@@ -146,6 +210,8 @@ where
 
     let mut set = Default::default();
     let mut set2: PlIndexSet<<Option<T> as ToTotalOrd>::TotalOrdItem> = Default::default();
+    let mut counts = Default::default();
+    let mut buf = vec![];
 
     let mut values_out = MutablePrimitiveArray::with_capacity(std::cmp::max(
         *offsets_a.last().unwrap(),
@@ -163,7 +229,7 @@ where
     let second_a = offsets_a[1];
     let first_b = offsets_b[0];
     let second_b = offsets_b[1];
-    if broadcast_rhs {
+    if broadcast_rhs && !multiset {
         set2.extend(
             b.into_iter()
                 .skip(first_b as usize)
@@ -211,18 +277,32 @@ where
                 .map(copied_wrapper_opt)
         };
 
-        let offset = set_operation(
-            &mut set,
-            &mut set2,
-            &mut iter_a,
-            &mut iter_b,
-            &mut values_out,
-            set_op,
-            broadcast_rhs,
-        );
+        let offset = if multiset {
+            multiset_operation(
+                &mut counts,
+                &mut buf,
+                &mut iter_a,
+                &mut iter_b,
+                &mut values_out,
+                set_op,
+            )
+        } else {
+            set_operation(
+                &mut set,
+                &mut set2,
+                &mut iter_a,
+                &mut iter_b,
+                &mut values_out,
+                set_op,
+                broadcast_rhs,
+            )
+        };
 
         assert!(iter_a.next().is_none());
-        if !broadcast_rhs || matches!(set_op, SetOperation::Union | SetOperation::Difference) {
+        if !broadcast_rhs
+            || multiset
+            || matches!(set_op, SetOperation::Union | SetOperation::Difference)
+        {
             assert!(iter_b.next().is_none());
         };
 
@@ -235,12 +315,14 @@ where
     Ok(ListArray::new(dtype, offsets, values.boxed(), validity))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn binary(
     a: &BinaryViewArray,
     b: &BinaryViewArray,
     offsets_a: &[i64],
     offsets_b: &[i64],
     set_op: SetOperation,
+    multiset: bool,
     validity: Option<Bitmap>,
     as_utf8: bool,
 ) -> PolarsResult<ListArray<i64>> {
@@ -248,6 +330,8 @@ fn binary(
     let broadcast_rhs = offsets_b.len() == 2;
     let mut set: PlIndexSet<Option<&[u8]>> = Default::default();
     let mut set2: PlIndexSet<Option<&[u8]>> = Default::default();
+    let mut counts = Default::default();
+    let mut buf = vec![];
 
     let mut values_out = MutablePlBinary::with_capacity(std::cmp::max(
         *offsets_a.last().unwrap(),
@@ -266,7 +350,7 @@ fn binary(
     let first_b = offsets_b[0];
     let second_b = offsets_b[1];
 
-    if broadcast_rhs {
+    if broadcast_rhs && !multiset {
         // set2.extend(b_iter)
         set2.extend(
             b.into_iter()
@@ -306,18 +390,32 @@ fn binary(
             iter_b.by_ref().take(end_b - start_b)
         };
 
-        let offset = set_operation(
-            &mut set,
-            &mut set2,
-            &mut iter_a,
-            &mut iter_b,
-            &mut values_out,
-            set_op,
-            broadcast_rhs,
-        );
+        let offset = if multiset {
+            multiset_operation(
+                &mut counts,
+                &mut buf,
+                &mut iter_a,
+                &mut iter_b,
+                &mut values_out,
+                set_op,
+            )
+        } else {
+            set_operation(
+                &mut set,
+                &mut set2,
+                &mut iter_a,
+                &mut iter_b,
+                &mut values_out,
+                set_op,
+                broadcast_rhs,
+            )
+        };
 
         assert!(iter_a.next().is_none());
-        if !broadcast_rhs || matches!(set_op, SetOperation::Union | SetOperation::Difference) {
+        if !broadcast_rhs
+            || multiset
+            || matches!(set_op, SetOperation::Union | SetOperation::Difference)
+        {
             assert!(iter_b.next().is_none());
         };
 
@@ -340,6 +438,7 @@ fn array_set_operation(
     a: &ListArray<i64>,
     b: &ListArray<i64>,
     set_op: SetOperation,
+    multiset: bool,
 ) -> PolarsResult<ListArray<i64>> {
     let offsets_a = a.offsets().as_slice();
     let offsets_b = b.offsets().as_slice();
@@ -364,12 +463,16 @@ fn array_set_operation(
                 .unwrap()
                 .to_binview();
 
-            binary(&a, &b, offsets_a, offsets_b, set_op, validity, true)
+            binary(
+                &a, &b, offsets_a, offsets_b, set_op, multiset, validity, true,
+            )
         },
         ArrowDataType::BinaryView => {
             let a = values_a.as_any().downcast_ref::<BinaryViewArray>().unwrap();
             let b = values_b.as_any().downcast_ref::<BinaryViewArray>().unwrap();
-            binary(a, b, offsets_a, offsets_b, set_op, validity, false)
+            binary(
+                a, b, offsets_a, offsets_b, set_op, multiset, validity, false,
+            )
         },
         ArrowDataType::Boolean => {
             polars_bail!(InvalidOperation: "boolean type not yet supported in list 'set' operations")
@@ -379,16 +482,21 @@ fn array_set_operation(
                 let a = values_a.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
                 let b = values_b.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
 
-                primitive(&a, &b, offsets_a, offsets_b, set_op, validity)
+                primitive(&a, &b, offsets_a, offsets_b, set_op, multiset, validity)
             })
         },
     }
 }
 
+/// Apply `set_op` row-wise on two list columns.
+///
+/// If `multiset` is set, the lists are treated as bags: duplicates are kept, every occurrence
+/// counts against one occurrence in the other operand and the output follows the order of `a`.
 pub fn list_set_operation(
     a: &ListChunked,
     b: &ListChunked,
     set_op: SetOperation,
+    multiset: bool,
 ) -> PolarsResult<ListChunked> {
     polars_ensure!(a.len() == b.len() || b.len() == 1 || a.len() == 1, ShapeMismatch: "column lengths don't match");
     polars_ensure!(a.dtype() == b.dtype(), InvalidOperation: "cannot do 'set' operation on dtypes: {} and {}", a.dtype(), b.dtype());
@@ -408,7 +516,7 @@ pub fn list_set_operation(
         arity::try_binary_unchecked_same_type(
             &a,
             &b,
-            |a, b| array_set_operation(a, b, set_op).map(|arr| arr.boxed()),
+            |a, b| array_set_operation(a, b, set_op, multiset).map(|arr| arr.boxed()),
             false,
             false,
         )
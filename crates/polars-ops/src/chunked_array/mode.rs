@@ -1,5 +1,36 @@
 use polars_core::POOL;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Which values [`mode`] returns if multiple values are the most occurring.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ModeTies {
+    /// All most occurring values.
+    #[default]
+    All,
+    /// The most occurring value that occurs first.
+    First,
+    /// The smallest most occurring value.
+    Smallest,
+}
+
+/// The first index and length of every group, in order of first occurrence.
+fn group_firsts_and_lens(s: &Series) -> PolarsResult<Vec<(IdxSize, IdxSize)>> {
+    let parallel = !POOL.current_thread_has_pending_tasks().unwrap_or(false);
+    Ok(match s.group_tuples(parallel, true)? {
+        GroupsType::Idx(groups) => groups
+            .into_iter()
+            .map(|(first, idx)| (first, idx.len() as IdxSize))
+            .collect(),
+        GroupsType::Slice { groups, .. } => groups
+            .into_iter()
+            .map(|[first, len]| (first, len))
+            .collect(),
+    })
+}
 
 fn mode_indices(groups: GroupsType) -> Vec<IdxSize> {
     match groups {
@@ -26,12 +57,86 @@ fn mode_indices(groups: GroupsType) -> Vec<IdxSize> {
     }
 }
 
-pub fn mode(s: &Series, maintain_order: bool) -> PolarsResult<Series> {
+/// Compute the most occurring value(s) of `s`.
+///
+/// With [`ModeTies::All`] every most occurring value is returned, in order of first occurrence
+/// if `maintain_order` is set. Otherwise a single value is returned, which is null if `s` is
+/// empty.
+pub fn mode(s: &Series, maintain_order: bool, ties: ModeTies) -> PolarsResult<Series> {
     let parallel = !POOL.current_thread_has_pending_tasks().unwrap_or(false);
+    let maintain_order = maintain_order || ties == ModeTies::First;
     let groups = s.group_tuples(parallel, maintain_order).unwrap();
-    let idx = mode_indices(groups);
+    let mut idx = mode_indices(groups);
+    if ties == ModeTies::First {
+        idx.truncate(1);
+    }
+    let idx = IdxCa::from_vec("".into(), idx);
+    // SAFETY:
+    // group indices are in bounds
+    let out = unsafe { s.take_unchecked(&idx) };
+    match ties {
+        ModeTies::All => Ok(out),
+        _ if out.is_empty() => Ok(Series::full_null(s.name().clone(), 1, s.dtype())),
+        ModeTies::First => Ok(out),
+        ModeTies::Smallest => {
+            let options = SortOptions::default().with_nulls_last(true);
+            Ok(out.sort(options)?.head(Some(1)))
+        },
+    }
+}
+
+/// Compute the `n` most occurring values of `s`.
+///
+/// The values are ordered by descending number of occurrences, values that occur equally often
+/// are ordered by their first occurrence in `s`.
+pub fn modes(s: &Series, n: usize) -> PolarsResult<Series> {
+    let mut groups = group_firsts_and_lens(s)?;
+    // The sort is stable, so equally often occurring values keep their order of first occurrence.
+    groups.sort_by_key(|(_, len)| std::cmp::Reverse(*len));
+    let idx = groups
+        .into_iter()
+        .take(n)
+        .map(|(first, _)| first)
+        .collect::<Vec<_>>();
     let idx = IdxCa::from_vec("".into(), idx);
     // SAFETY:
     // group indices are in bounds
     Ok(unsafe { s.take_unchecked(&idx) })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mode_ties() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(3), None, Some(1), Some(3), None, Some(1), Some(2)],
+        );
+
+        let out = mode(&s, true, ModeTies::All)?;
+        assert_eq!(out.i32()?.to_vec(), [Some(3), None, Some(1)]);
+        let out = mode(&s, false, ModeTies::First)?;
+        assert_eq!(out.i32()?.to_vec(), [Some(3)]);
+        let out = mode(&s, false, ModeTies::Smallest)?;
+        assert_eq!(out.i32()?.to_vec(), [Some(1)]);
+
+        let empty = s.clear();
+        assert_eq!(mode(&empty, false, ModeTies::All)?.len(), 0);
+        assert_eq!(mode(&empty, false, ModeTies::Smallest)?.null_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_modes() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [2, 5, 1, 5, 1, 1, 2, 7]);
+        assert_eq!(modes(&s, 3)?.i32()?.to_vec(), [Some(1), Some(2), Some(5)]);
+        assert_eq!(
+            modes(&s, 10)?.i32()?.to_vec(),
+            [Some(1), Some(2), Some(5), Some(7)]
+        );
+        assert_eq!(modes(&s, 0)?.len(), 0);
+        Ok(())
+    }
+}
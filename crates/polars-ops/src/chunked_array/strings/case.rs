@@ -131,6 +131,58 @@ pub(super) fn to_uppercase<'a>(ca: &'a StringChunked) -> StringChunked {
     ca.apply_mut(f)
 }
 
+/// Push the full (language-independent) case folding of `c` to `out`.
+fn casefold_char(c: char, out: &mut String) {
+    match c as u32 {
+        // Cherokee letters fold to their uppercase form.
+        0x13A0..=0x13F5 => out.push(c),
+        0x13F8..=0x13FD => out.extend(char::from_u32(c as u32 - 0x8)),
+        0xAB70..=0xABBF => out.extend(char::from_u32(c as u32 - 0xAB70 + 0x13A0)),
+        // Dotless i has no case folding (it only exists in the Turkic mappings).
+        0x0131 => out.push(c),
+        // Round-tripping through uppercase handles the special foldings, such as
+        // ß -> ss, ﬁ -> fi, ſ -> s, ς -> σ and ᾳ -> αι.
+        _ => {
+            for lower in c.to_lowercase() {
+                for upper in lower.to_uppercase() {
+                    out.extend(upper.to_lowercase());
+                }
+            }
+        },
+    }
+}
+
+pub(super) fn casefold<'a>(ca: &'a StringChunked) -> StringChunked {
+    // Amortize allocation.
+    let mut buf = Vec::new();
+    let f = |s: &'a str| -> &'a str {
+        convert_while_ascii(s.as_bytes(), u8::to_ascii_lowercase, &mut buf);
+
+        // SAFETY: we know this is a valid char boundary since
+        // out.len() is only progressed if ascii bytes are found.
+        let rest = unsafe { s.get_unchecked(buf.len()..) };
+
+        // SAFETY: We have written only valid ASCII to our vec.
+        let mut s = unsafe { String::from_utf8_unchecked(std::mem::take(&mut buf)) };
+
+        for c in rest.chars() {
+            if c.is_ascii() {
+                s.push(c.to_ascii_lowercase());
+            } else {
+                casefold_char(c, &mut s);
+            }
+        }
+
+        // Put buf back for next iteration.
+        buf = s.into_bytes();
+
+        // SAFETY: apply_mut will copy value from buf before next iteration.
+        let slice = unsafe { std::str::from_utf8_unchecked(&buf) };
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
+
 #[cfg(feature = "nightly")]
 pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
     // Amortize allocation.
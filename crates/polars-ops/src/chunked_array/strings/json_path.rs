@@ -11,14 +11,64 @@ pub fn extract_json(expr: &PathCompiled, json_str: &str) -> Option<String> {
     serde_json::from_str(json_str).ok().and_then(|value| {
         // TODO: a lot of heap allocations here. Improve json path by adding a take?
         let result = expr.select(&value).ok()?;
-        let first = *result.first()?;
+        json_value_to_string(result.first()?)
+    })
+}
+
+/// Represent a matched JSON value as string: strings are unquoted and `null` is missing.
+fn json_value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Null => None,
+        v => Some(v.to_string()),
+    }
+}
+
+fn compile_json_path(path: &str) -> PolarsResult<PathCompiled> {
+    PathCompiled::compile(path)
+        .map_err(|e| polars_err!(ComputeError: "error compiling JSON path expression {}", e))
+}
 
-        match first {
-            Value::String(s) => Some(s.clone()),
-            Value::Null => None,
-            v => Some(v.to_string()),
+/// Call `f` with all matches of the JSON path for every document, or with `None` if the document
+/// or path is null or the document is invalid JSON.
+fn for_each_json_path_matches(
+    ca: &StringChunked,
+    json_path: &StringChunked,
+    mut f: impl FnMut(Option<Vec<&Value>>),
+) -> PolarsResult<()> {
+    let (len_ca, len_path) = (ca.len(), json_path.len());
+    polars_ensure!(
+        len_ca == len_path || len_ca == 1 || len_path == 1,
+        length_mismatch = "json_path_match",
+        len_ca,
+        len_path
+    );
+    let ca = ca.rechunk();
+    let json_path = json_path.rechunk();
+    let broadcast_path = if len_path == 1 {
+        Some(json_path.get(0).map(compile_json_path).transpose()?)
+    } else {
+        None
+    };
+
+    let len = if len_ca == 1 { len_path } else { len_ca };
+    for i in 0..len {
+        let compiled;
+        let path = match &broadcast_path {
+            Some(path) => path.as_ref(),
+            None => {
+                compiled = json_path.get(i).map(compile_json_path).transpose()?;
+                compiled.as_ref()
+            },
+        };
+        let doc = ca.get(if len_ca == 1 { 0 } else { i });
+        let value = doc.and_then(|doc| serde_json::from_str::<Value>(doc).ok());
+        match (value, path) {
+            (Some(value), Some(path)) => f(path.select(&value).ok()),
+            _ => f(None),
         }
-    })
+    }
+    Ok(())
 }
 
 /// Returns a string of the most specific value given the compiled JSON path expression.
@@ -76,6 +126,107 @@ pub trait Utf8JsonPathImpl: AsString {
         }
     }
 
+    /// Extract json path, all matches.
+    ///
+    /// Without a `dtype`, the matches are returned as strings like [`Self::json_path_match`]
+    /// does. Otherwise they are decoded as values of `dtype`.
+    fn json_path_match_all(
+        &self,
+        json_path: &StringChunked,
+        dtype: Option<&DataType>,
+    ) -> PolarsResult<Series> {
+        let ca = self.as_string();
+        let len = ca.len().max(json_path.len());
+        match dtype {
+            None => {
+                let mut builder =
+                    ListStringChunkedBuilder::new(ca.name().clone(), len, ca.get_values_size());
+                for_each_json_path_matches(ca, json_path, |matches| match matches {
+                    None => builder.append_null(),
+                    Some(matches) => {
+                        let values: Vec<_> =
+                            matches.into_iter().map(json_value_to_string).collect();
+                        builder.append_trusted_len_iter(values.iter().map(|v| v.as_deref()))
+                    },
+                })?;
+                Ok(builder.finish().into_series())
+            },
+            Some(dtype) => {
+                let mut builder = StringChunkedBuilder::new(ca.name().clone(), len);
+                for_each_json_path_matches(ca, json_path, |matches| {
+                    builder.append_option(matches.and_then(|m| serde_json::to_string(&m).ok()))
+                })?;
+                let out = builder
+                    .finish()
+                    .json_decode(Some(DataType::List(Box::new(dtype.clone()))), None)?;
+                Ok(out.with_name(ca.name().clone()))
+            },
+        }
+    }
+
+    /// Extract the first match of each of the json `paths` into a struct with a field per path.
+    /// Every document is parsed once.
+    ///
+    /// Without a `dtype`, the matches are returned as strings like [`Self::json_path_match`]
+    /// does. Otherwise `dtype` must be a struct with a field per path and the matches are decoded
+    /// as values of these fields.
+    fn json_extract_many(
+        &self,
+        paths: &[PlSmallStr],
+        dtype: Option<&DataType>,
+    ) -> PolarsResult<StructChunked> {
+        let ca = self.as_string().rechunk();
+        let compiled = paths
+            .iter()
+            .map(|path| compile_json_path(path))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let fields = match dtype {
+            None => None,
+            Some(DataType::Struct(fields)) if fields.len() == paths.len() => Some(fields),
+            Some(dtype) => polars_bail!(
+                SchemaMismatch: "`json_extract_many` expected a struct dtype with {} fields, got {}",
+                paths.len(), dtype
+            ),
+        };
+
+        let mut builders = paths
+            .iter()
+            .map(|path| StringChunkedBuilder::new(path.clone(), ca.len()))
+            .collect::<Vec<_>>();
+        for opt_doc in ca.iter() {
+            let value = opt_doc.and_then(|doc| serde_json::from_str::<Value>(doc).ok());
+            for (path, builder) in compiled.iter().zip(builders.iter_mut()) {
+                let first = value.as_ref().and_then(|value| {
+                    let matches = path.select(value).ok()?;
+                    let first = *matches.first()?;
+                    if fields.is_some() {
+                        serde_json::to_string(first).ok()
+                    } else {
+                        json_value_to_string(first)
+                    }
+                });
+                builder.append_option(first);
+            }
+        }
+
+        let columns = builders
+            .into_iter()
+            .enumerate()
+            .map(|(i, builder)| {
+                let out = builder.finish();
+                match fields {
+                    None => Ok(out.into_series()),
+                    Some(fields) => {
+                        let s = out.json_decode(Some(fields[i].dtype().clone()), None)?;
+                        Ok(s.with_name(fields[i].name().clone()))
+                    },
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), columns.iter())?;
+        Ok(out.with_outer_validity(ca.rechunk_validity()))
+    }
+
     /// Returns the inferred DataType for JSON values for each row
     /// in the StringChunked, with an optional number of rows to inspect.
     /// When None is passed for the number of rows, all rows are inspected.
@@ -249,6 +400,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_json_path_match_all() {
+        let s = Series::new(
+            "json".into(),
+            [
+                Some(r#"{"a":[{"b":1},{"b":null},{"b":3}]}"#),
+                Some(r#"{"a":[]}"#),
+                None,
+            ],
+        );
+        let ca = s.str().unwrap();
+        let path = StringChunked::from_slice("".into(), &["$.a[*].b"]);
+
+        let out = ca.json_path_match_all(&path, None).unwrap();
+        let expected = Series::new(
+            "json".into(),
+            [
+                Series::new("".into(), [Some("1"), None, Some("3")]),
+                Series::new_empty("".into(), &DataType::String),
+            ],
+        )
+        .extend_constant(AnyValue::Null, 1)
+        .unwrap();
+        assert!(out.equals_missing(&expected));
+
+        let out = ca
+            .json_path_match_all(&path, Some(&DataType::Int64))
+            .unwrap();
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int64)));
+        let first = out.list().unwrap().get_as_series(0).unwrap();
+        assert!(first.equals_missing(&Series::new("".into(), [Some(1i64), None, Some(3)])));
+    }
+
+    #[test]
+    fn test_json_extract_many() {
+        let s = Series::new(
+            "json".into(),
+            [Some(r#"{"a":1,"b":{"c":"x"}}"#), Some(r#"{"a":2}"#), None],
+        );
+        let ca = s.str().unwrap();
+        let paths: [PlSmallStr; 2] = ["$.a".into(), "$.b.c".into()];
+
+        let out = ca.json_extract_many(&paths, None).unwrap();
+        let fields = out.fields_as_series();
+        assert!(fields[0].equals_missing(&Series::new("$.a".into(), [Some("1"), Some("2"), None])));
+        assert!(fields[1].equals_missing(&Series::new("$.b.c".into(), [Some("x"), None, None])));
+        assert_eq!(out.null_count(), 1);
+
+        let dtype = DataType::Struct(vec![
+            Field::new("a".into(), DataType::Int64),
+            Field::new("c".into(), DataType::String),
+        ]);
+        let out = ca.json_extract_many(&paths, Some(&dtype)).unwrap();
+        assert_eq!(out.dtype(), &dtype);
+        assert!(
+            out.fields_as_series()[0]
+                .equals_missing(&Series::new("a".into(), [Some(1i64), Some(2), None]))
+        );
+    }
+
     #[test]
     fn test_json_path_select() {
         let s = Series::new(
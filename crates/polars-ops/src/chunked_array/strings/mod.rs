@@ -18,14 +18,20 @@ mod normalize;
 mod pad;
 #[cfg(feature = "string_reverse")]
 mod reverse;
+#[cfg(feature = "string_similarity")]
+mod similarity;
 #[cfg(feature = "strings")]
 mod split;
 #[cfg(feature = "strings")]
 mod strip;
 #[cfg(feature = "strings")]
 mod substring;
+#[cfg(feature = "string_tokenize")]
+mod tokenize;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
+#[cfg(feature = "string_url")]
+mod url;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
@@ -40,12 +46,19 @@ pub use namespace::*;
 #[cfg(feature = "string_normalize")]
 pub use normalize::*;
 use polars_core::prelude::*;
+#[cfg(feature = "string_similarity")]
+pub use similarity::*;
 #[cfg(feature = "strings")]
 pub use split::*;
 #[cfg(feature = "strings")]
 pub use strip::*;
 #[cfg(feature = "strings")]
 pub use substring::{substring_ternary_offsets_value, update_view};
+#[cfg(feature = "string_tokenize")]
+pub use tokenize::*;
+
+#[cfg(feature = "string_url")]
+pub use self::url::*;
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
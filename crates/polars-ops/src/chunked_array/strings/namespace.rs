@@ -1,17 +1,17 @@
 use arrow::array::ValueSize;
 use arrow::legacy::kernels::string::*;
-#[cfg(feature = "string_encoding")]
-use base64::Engine as _;
-#[cfg(feature = "string_encoding")]
-use base64::engine::general_purpose;
 #[cfg(feature = "string_to_integer")]
 use num_traits::Num;
 use polars_core::prelude::arity::*;
 use polars_utils::regex_cache::{compile_regex, with_regex_cache};
 
 use super::*;
+#[cfg(any(feature = "binary_encoding", feature = "string_encoding"))]
+use crate::chunked_array::binary::Base64Alphabet;
 #[cfg(feature = "binary_encoding")]
 use crate::chunked_array::binary::BinaryNameSpaceImpl;
+#[cfg(feature = "string_encoding")]
+use crate::chunked_array::binary::encoding;
 #[cfg(feature = "string_normalize")]
 use crate::prelude::strings::normalize::UnicodeForm;
 
@@ -118,7 +118,7 @@ pub trait StringNameSpaceImpl: AsString {
     #[cfg(feature = "string_encoding")]
     fn hex_encode(&self) -> StringChunked {
         let ca = self.as_string();
-        ca.apply_values(|s| hex::encode(s).into())
+        encoding::hex_encode(&ca.as_binary())
     }
 
     #[cfg(not(feature = "binary_encoding"))]
@@ -128,15 +128,30 @@ pub trait StringNameSpaceImpl: AsString {
 
     #[cfg(feature = "binary_encoding")]
     fn base64_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        self.base64_decode_with_options(Base64Alphabet::Standard, strict)
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base64_decode_with_options(
+        &self,
+        alphabet: Base64Alphabet,
+        strict: bool,
+    ) -> PolarsResult<BinaryChunked> {
         let ca = self.as_string();
-        ca.as_binary().base64_decode(strict)
+        ca.as_binary().base64_decode_with_options(alphabet, strict)
     }
 
     #[must_use]
     #[cfg(feature = "string_encoding")]
     fn base64_encode(&self) -> StringChunked {
+        self.base64_encode_with_options(Base64Alphabet::Standard, true)
+    }
+
+    #[must_use]
+    #[cfg(feature = "string_encoding")]
+    fn base64_encode_with_options(&self, alphabet: Base64Alphabet, padding: bool) -> StringChunked {
         let ca = self.as_string();
-        ca.apply_values(|s| general_purpose::STANDARD.encode(s).into())
+        encoding::base64_encode(&ca.as_binary(), alphabet, padding)
     }
 
     #[cfg(feature = "string_to_integer")]
@@ -635,6 +650,13 @@ pub trait StringNameSpaceImpl: AsString {
         case::to_uppercase(ca)
     }
 
+    /// Apply full Unicode case folding to the strings, for caseless comparisons.
+    #[must_use]
+    fn casefold(&self) -> StringChunked {
+        let ca = self.as_string();
+        case::casefold(ca)
+    }
+
     /// Modify the strings to their titlecase equivalent.
     #[must_use]
     #[cfg(feature = "nightly")]
@@ -666,6 +688,41 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Levenshtein distance between the string values and `other`, in characters.
+    #[cfg(feature = "string_similarity")]
+    fn str_levenshtein_distance(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::levenshtein_distance(ca, other)
+    }
+
+    /// Hamming distance between the string values and `other`, in characters.
+    /// Null where the strings differ in length.
+    #[cfg(feature = "string_similarity")]
+    fn str_hamming_distance(&self, other: &StringChunked) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::hamming_distance(ca, other)
+    }
+
+    /// Jaro-Winkler similarity between the string values and `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_jaro_winkler(&self, other: &StringChunked) -> Float64Chunked {
+        let ca = self.as_string();
+        similarity::jaro_winkler_similarity(ca, other)
+    }
+
+    /// Split the string values into lists of tokens, optionally lowercasing them and removing
+    /// stop words.
+    #[cfg(feature = "string_tokenize")]
+    fn str_tokenize(
+        &self,
+        method: &TokenizeMethod,
+        lowercase: bool,
+        stop_words: &[PlSmallStr],
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        tokenize::tokenize(ca, method, lowercase, stop_words)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -0,0 +1,165 @@
+use polars_core::prelude::arity::{
+    broadcast_binary_elementwise, broadcast_binary_elementwise_values,
+};
+use polars_core::prelude::*;
+
+/// Number of single-character insertions, deletions and substitutions needed to turn `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> u32 {
+    if a == b {
+        return 0;
+    }
+    if a.is_ascii() && b.is_ascii() {
+        return levenshtein_slice(a.as_bytes(), b.as_bytes());
+    }
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    levenshtein_slice(&a, &b)
+}
+
+fn levenshtein_slice<T: PartialEq>(a: &[T], b: &[T]) -> u32 {
+    // Keep the shortest input in the inner loop to bound the row size.
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if b.is_empty() {
+        return a.len() as u32;
+    }
+
+    let mut row = (0..=b.len() as u32).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i as u32 + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            let substitution = diag + (ca != cb) as u32;
+            row[j + 1] = substitution.min(above + 1).min(row[j] + 1);
+            diag = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// Number of positions at which `a` and `b` differ, or `None` if they differ in length.
+fn hamming(a: &str, b: &str) -> Option<u32> {
+    if a.is_ascii() && b.is_ascii() {
+        return (a.len() == b.len())
+            .then(|| a.bytes().zip(b.bytes()).filter(|(ca, cb)| ca != cb).count() as u32);
+    }
+    let mut a = a.chars();
+    let mut b = b.chars();
+    let mut distance = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (Some(ca), Some(cb)) => distance += (ca != cb) as u32,
+            (None, None) => return Some(distance),
+            _ => return None,
+        }
+    }
+}
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0usize;
+    for (i, ca) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_matched[j] && b[j] == *ca {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // Count the matched characters that appear in a different order.
+    let a_seq = a.iter().zip(&a_matched).filter(|(_, m)| **m);
+    let b_seq = b.iter().zip(&b_matched).filter(|(_, m)| **m);
+    let transpositions = a_seq
+        .zip(b_seq)
+        .filter(|((ca, _), (cb, _))| ca != cb)
+        .count()
+        / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Jaro-Winkler similarity between `a` and `b`, in the range `[0, 1]`.
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    const PREFIX_SCALE: f64 = 0.1;
+    const MAX_PREFIX: usize = 4;
+
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let similarity = jaro(&a, &b);
+    let prefix = a
+        .iter()
+        .zip(&b)
+        .take(MAX_PREFIX)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    similarity + prefix as f64 * PREFIX_SCALE * (1.0 - similarity)
+}
+
+/// Levenshtein (edit) distance between the string values, counted in characters.
+pub fn levenshtein_distance(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise_values(ca, other, levenshtein)
+}
+
+/// Hamming distance between the string values, counted in characters.
+///
+/// Pairs of strings with a different number of characters result in a null.
+pub fn hamming_distance(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        hamming(a?, b?)
+    })
+}
+
+/// Jaro-Winkler similarity between the string values.
+pub fn jaro_winkler_similarity(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    broadcast_binary_elementwise_values(ca, other, jaro_winkler)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("café", "cafe"), 1);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_hamming() {
+        assert_eq!(hamming("karolin", "kathrin"), Some(3));
+        assert_eq!(hamming("naïve", "naive"), Some(1));
+        assert_eq!(hamming("abc", "ab"), None);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-3;
+        assert!(close(jaro_winkler("martha", "marhta"), 0.961));
+        assert!(close(jaro_winkler("dwayne", "duane"), 0.84));
+        assert!(close(jaro_winkler("dixon", "dicksonx"), 0.813));
+        assert!(close(jaro_winkler("abc", "abc"), 1.0));
+        assert!(close(jaro_winkler("abc", "xyz"), 0.0));
+        assert!(close(jaro_winkler("", ""), 1.0));
+    }
+}
@@ -36,7 +36,6 @@ impl<'a> Iterator for SplitNChars<'a> {
 ///
 /// Returns at most n strings, where the last string is the entire remainder
 /// of the string if keep_remainder is True, and just the nth character otherwise.
-#[cfg(feature = "dtype-struct")]
 fn splitn_chars(s: &str, n: usize, keep_remainder: bool) -> SplitNChars<'_> {
     SplitNChars {
         s,
@@ -225,26 +224,35 @@ where
     })
 }
 
+/// Splits a string on a regex, keeping each match at the end of the preceding substring.
+///
+/// Returns at most `limit` strings, where the last string is the remainder of the string.
 #[inline]
-fn split_inclusive<'a>(re: &'a Regex, s: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+fn split_inclusive<'a>(
+    re: &'a Regex,
+    s: &'a str,
+    limit: usize,
+) -> impl Iterator<Item = &'a str> + 'a {
     let mut it = re.find_iter(s);
     let mut last_end: usize = 0;
+    let mut remaining = limit;
     let mut yielded_any = false;
-    let mut done_tail = false;
 
     std::iter::from_fn(move || {
-        if let Some(m) = it.next() {
-            let end = m.end();
-            let out = &s[last_end..end];
-            last_end = end;
-            yielded_any = true;
-            return Some(out);
-        }
-
-        if done_tail {
+        if remaining == 0 {
             return None;
         }
-        done_tail = true;
+        if remaining > 1 {
+            if let Some(m) = it.next() {
+                let end = m.end();
+                let out = &s[last_end..end];
+                last_end = end;
+                remaining -= 1;
+                yielded_any = true;
+                return Some(out);
+            }
+        }
+        remaining = 0;
 
         if last_end < s.len() {
             Some(&s[last_end..])
@@ -266,12 +274,21 @@ fn append_split_compiled(
     builder: &mut ListStringChunkedBuilder,
     s: &str,
     re: &Regex,
+    n: Option<usize>,
     inclusive: bool,
 ) {
-    if inclusive {
-        builder.append_values_iter(split_inclusive(re, s));
-    } else {
-        builder.append_values_iter(re.split(s));
+    match (n, inclusive) {
+        (_, true) => builder.append_values_iter(split_inclusive(re, s, n.unwrap_or(usize::MAX))),
+        (Some(n), false) => builder.append_values_iter(re.splitn(s, n)),
+        (None, false) => builder.append_values_iter(re.split(s)),
+    }
+}
+
+#[inline]
+fn append_split_chars(builder: &mut ListStringChunkedBuilder, s: &str, n: Option<usize>) {
+    match n {
+        Some(n) => builder.append_values_iter(splitn_chars(s, n, true)),
+        None => builder.append_values_iter(split_chars(s)),
     }
 }
 
@@ -280,17 +297,18 @@ fn append_split(
     builder: &mut ListStringChunkedBuilder,
     s: &str,
     pat: &str,
+    n: Option<usize>,
     inclusive: bool,
     strict: bool,
 ) -> PolarsResult<()> {
     if pat.is_empty() {
-        builder.append_values_iter(split_chars(s));
+        append_split_chars(builder, s, n);
         return Ok(());
     }
 
     match compile_regex(pat) {
         Ok(re) => {
-            append_split_compiled(builder, s, &re, inclusive);
+            append_split_compiled(builder, s, &re, n, inclusive);
             Ok(())
         },
         Err(_) if strict => Err(invalid_regex_err(pat)),
@@ -301,9 +319,12 @@ fn append_split(
     }
 }
 
+/// Split each string on a regex pattern, returning at most `n` substrings per value
+/// (with the remainder of the string kept intact in the final substring).
 pub fn split_regex_helper(
     ca: &StringChunked,
     by: &StringChunked,
+    n: Option<usize>,
     inclusive: bool,
     strict: bool,
 ) -> PolarsResult<ListChunked> {
@@ -317,7 +338,9 @@ pub fn split_regex_helper(
 
             for (opt_s, opt_pat) in ca.into_iter().zip(by.into_iter()) {
                 match (opt_s, opt_pat) {
-                    (Some(s), Some(pat)) => append_split(&mut builder, s, pat, inclusive, strict)?,
+                    (Some(s), Some(pat)) => {
+                        append_split(&mut builder, s, pat, n, inclusive, strict)?
+                    },
                     _ => builder.append_null(),
                 }
             }
@@ -336,7 +359,7 @@ pub fn split_regex_helper(
 
                 for opt_pat in by.into_iter() {
                     match opt_pat {
-                        Some(pat) => append_split(&mut builder, s0, pat, inclusive, strict)?,
+                        Some(pat) => append_split(&mut builder, s0, pat, n, inclusive, strict)?,
                         None => builder.append_null(),
                     }
                 }
@@ -358,7 +381,7 @@ pub fn split_regex_helper(
 
                 if pat0.is_empty() {
                     ca.for_each(|opt_s| match opt_s {
-                        Some(s) => builder.append_values_iter(split_chars(s)),
+                        Some(s) => append_split_chars(&mut builder, s, n),
                         None => builder.append_null(),
                     });
                     builder.finish()
@@ -376,7 +399,7 @@ pub fn split_regex_helper(
                     };
 
                     ca.for_each(|opt_s| match opt_s {
-                        Some(s) => append_split_compiled(&mut builder, s, &re, inclusive),
+                        Some(s) => append_split_compiled(&mut builder, s, &re, n, inclusive),
                         None => builder.append_null(),
                     });
 
@@ -0,0 +1,114 @@
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+use polars_utils::regex_cache::compile_regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum TokenizeMethod {
+    /// Words as delimited by the Unicode word boundary rules (UAX #29), skipping punctuation
+    /// and whitespace.
+    UnicodeWords,
+    /// Runs of non-whitespace characters.
+    Whitespace,
+    /// Every non-overlapping match of a regular expression.
+    Pattern(PlSmallStr),
+}
+
+/// Split every string into a list of tokens.
+///
+/// If `lowercase` is set the strings are lowercased before they are tokenized. Tokens that are
+/// equal to one of the `stop_words` are removed from the output.
+pub fn tokenize(
+    ca: &StringChunked,
+    method: &TokenizeMethod,
+    lowercase: bool,
+    stop_words: &[PlSmallStr],
+) -> PolarsResult<ListChunked> {
+    let stop_words = stop_words
+        .iter()
+        .map(|w| w.as_str())
+        .collect::<PlHashSet<_>>();
+    let regex = match method {
+        TokenizeMethod::Pattern(pat) => Some(compile_regex(pat)?),
+        _ => None,
+    };
+
+    let mut builder =
+        ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+    for opt_s in ca.iter() {
+        let Some(s) = opt_s else {
+            builder.append_null();
+            continue;
+        };
+        let s = if lowercase {
+            Cow::Owned(s.to_lowercase())
+        } else {
+            Cow::Borrowed(s)
+        };
+        let keep = |token: &&str| !stop_words.contains(token);
+        match method {
+            TokenizeMethod::UnicodeWords => {
+                builder.append_values_iter(s.unicode_words().filter(keep))
+            },
+            TokenizeMethod::Whitespace => {
+                builder.append_values_iter(s.split_whitespace().filter(keep))
+            },
+            TokenizeMethod::Pattern(_) => {
+                let tokens = regex.as_ref().unwrap().find_iter(&s).map(|m| m.as_str());
+                builder.append_values_iter(tokens.filter(keep))
+            },
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tokens(s: &str, method: TokenizeMethod, lowercase: bool, stop: &[&str]) -> Vec<String> {
+        let ca = StringChunked::new(PlSmallStr::EMPTY, &[s]);
+        let stop = stop
+            .iter()
+            .map(|w| PlSmallStr::from(*w))
+            .collect::<Vec<_>>();
+        let out = tokenize(&ca, &method, lowercase, &stop).unwrap();
+        let s = out.get_as_series(0).unwrap();
+        s.str()
+            .unwrap()
+            .into_no_null_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let text = "The quick (\"brown\") fox can't jump 32.3 feet, right?";
+        assert_eq!(
+            tokens(text, TokenizeMethod::UnicodeWords, false, &[]),
+            [
+                "The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"
+            ]
+        );
+        assert_eq!(
+            tokens(text, TokenizeMethod::UnicodeWords, true, &["the", "can't"]),
+            ["quick", "brown", "fox", "jump", "32.3", "feet", "right"]
+        );
+        assert_eq!(
+            tokens("  a\tb \n c  ", TokenizeMethod::Whitespace, false, &[]),
+            ["a", "b", "c"]
+        );
+        assert_eq!(
+            tokens(
+                "Hello, World-Wide Web",
+                TokenizeMethod::Pattern("[a-z]+".into()),
+                true,
+                &["web"]
+            ),
+            ["hello", "world", "wide"]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use arrow::array::{IntoBoxedArray, ListArray};
+use arrow::bitmap::BitmapBuilder;
+use arrow::offset::Offsets;
+use arrow::pushable::Pushable;
+use percent_encoding::percent_decode_str;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A component of a URL that can be extracted with [`url_part`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UrlPart {
+    /// The lowercase scheme, e.g. `https`.
+    Scheme,
+    /// The host name or IP address, without the port.
+    Host,
+    /// The percent-encoded path, e.g. `/a/b%20c`.
+    Path,
+}
+
+impl UrlPart {
+    pub fn name(self) -> &'static str {
+        match self {
+            UrlPart::Scheme => "scheme",
+            UrlPart::Host => "host",
+            UrlPart::Path => "path",
+        }
+    }
+}
+
+/// Only absolute URLs are parsed, relative references and invalid URLs give `None`.
+fn parse_url(s: &str) -> Option<Url> {
+    Url::parse(s).ok()
+}
+
+/// Extract `part` of every URL. Values that are not absolute URLs, or that lack the part
+/// (such as the host of a `mailto:` URL), become null.
+pub fn url_part(ca: &StringChunked, part: UrlPart) -> StringChunked {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        let url = opt_s.and_then(parse_url);
+        let value = url.as_ref().and_then(|url| match part {
+            UrlPart::Scheme => Some(url.scheme()),
+            UrlPart::Host => url.host_str(),
+            UrlPart::Path => Some(url.path()),
+        });
+        builder.append_option(value);
+    }
+    builder.finish()
+}
+
+/// Decode the query string of every URL into its key/value pairs. Keys and values are
+/// percent-decoded and `+` is decoded as a space.
+///
+/// Without `keys` the output is a list of `{key, value}` structs holding all pairs in order of
+/// appearance. With `keys` the output is a struct with a field per key, holding the first value
+/// of that key. Values that are not absolute URLs become null.
+pub fn url_query_params(ca: &StringChunked, keys: Option<&[PlSmallStr]>) -> PolarsResult<Series> {
+    match keys {
+        Some(keys) => query_params_struct(ca, keys).map(|ca| ca.into_series()),
+        None => query_params_list(ca).map(|ca| ca.into_series()),
+    }
+}
+
+fn query_params_struct(ca: &StringChunked, keys: &[PlSmallStr]) -> PolarsResult<StructChunked> {
+    let mut builders = keys
+        .iter()
+        .map(|key| StringChunkedBuilder::new(key.clone(), ca.len()))
+        .collect::<Vec<_>>();
+    let mut validity = BitmapBuilder::with_capacity(ca.len());
+    let mut values: Vec<Option<String>> = vec![None; keys.len()];
+    for opt_s in ca.iter() {
+        values.iter_mut().for_each(|v| *v = None);
+        let url = opt_s.and_then(parse_url);
+        validity.push(url.is_some());
+        if let Some(url) = url {
+            for (k, v) in url.query_pairs() {
+                if let Some(idx) = keys.iter().position(|key| key.as_str() == k) {
+                    values[idx].get_or_insert_with(|| v.into_owned());
+                }
+            }
+        }
+        for (builder, value) in builders.iter_mut().zip(values.iter()) {
+            builder.append_option(value.as_deref());
+        }
+    }
+
+    let fields = builders
+        .into_iter()
+        .map(|builder| builder.finish().into_series())
+        .collect::<Vec<_>>();
+    let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+    Ok(out.with_outer_validity(validity.into_opt_validity()))
+}
+
+fn query_params_list(ca: &StringChunked) -> PolarsResult<ListChunked> {
+    let mut keys = StringChunkedBuilder::new(PlSmallStr::from_static("key"), ca.len());
+    let mut values = StringChunkedBuilder::new(PlSmallStr::from_static("value"), ca.len());
+    let mut offsets = Offsets::<i64>::with_capacity(ca.len());
+    let mut validity = BitmapBuilder::with_capacity(ca.len());
+    let mut n_pairs = 0;
+    for opt_s in ca.iter() {
+        match opt_s.and_then(parse_url) {
+            Some(url) => {
+                let mut n = 0;
+                for (k, v) in url.query_pairs() {
+                    keys.append_value(&k);
+                    values.append_value(&v);
+                    n += 1;
+                }
+                n_pairs += n;
+                offsets.push(n);
+                validity.push(true);
+            },
+            None => {
+                offsets.push_null();
+                validity.push(false);
+            },
+        }
+    }
+
+    let fields = [keys.finish().into_series(), values.finish().into_series()];
+    let pairs = StructChunked::from_series(PlSmallStr::EMPTY, n_pairs, fields.iter())?.rechunk();
+    let inner_dtype = pairs.dtype().clone();
+    let array = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(inner_dtype.to_arrow(CompatLevel::newest())),
+        offsets.into(),
+        pairs.downcast_as_array().clone().into_boxed(),
+        validity.into_opt_validity(),
+    );
+    // SAFETY: the array was built with the arrow type of `inner_dtype`.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name().clone(),
+            vec![array.into_boxed()],
+            DataType::List(Box::new(inner_dtype)),
+        )
+    })
+}
+
+/// Decode `%XX` escapes. Values that do not decode to valid UTF-8 become null. Unlike
+/// [`url_query_params`], `+` is left as is.
+pub fn url_decode_percent(ca: &StringChunked) -> StringChunked {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        let decoded = opt_s.and_then(|s| percent_decode_str(s).decode_utf8().ok());
+        builder.append_option(decoded.as_deref());
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_url_part() {
+        let ca = StringChunked::new(
+            "url".into(),
+            [
+                Some("HTTPS://Example.com:8080/a/b?q=1#frag"),
+                Some("mailto:someone@example.com"),
+                Some("/relative/path"),
+                None,
+            ],
+        );
+        let scheme = url_part(&ca, UrlPart::Scheme);
+        assert_eq!(
+            scheme.iter().collect::<Vec<_>>(),
+            [Some("https"), Some("mailto"), None, None]
+        );
+        let host = url_part(&ca, UrlPart::Host);
+        assert_eq!(
+            host.iter().collect::<Vec<_>>(),
+            [Some("example.com"), None, None, None]
+        );
+        let path = url_part(&ca, UrlPart::Path);
+        assert_eq!(
+            path.iter().collect::<Vec<_>>(),
+            [Some("/a/b"), Some("someone@example.com"), None, None]
+        );
+    }
+
+    #[test]
+    fn test_url_query_params() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "url".into(),
+            [
+                Some("https://a.com/?x=1&y=a+b&x=2"),
+                Some("https://a.com/"),
+                Some("not a url"),
+            ],
+        );
+        let keys = [PlSmallStr::from_static("x"), PlSmallStr::from_static("y")];
+        let out = url_query_params(&ca, Some(&keys))?;
+        let out = out.struct_()?;
+        assert_eq!(out.null_count(), 1);
+        let fields = out.fields_as_series();
+        assert_eq!(fields[0].str()?.get(0), Some("1"));
+        assert_eq!(fields[1].str()?.get(0), Some("a b"));
+        assert_eq!(fields[1].str()?.get(1), None);
+
+        let out = url_query_params(&ca, None)?;
+        let out = out.list()?;
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(out.get_as_series(0).unwrap().len(), 3);
+        assert_eq!(out.get_as_series(1).unwrap().len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_decode_percent() {
+        let ca = StringChunked::new("a".into(), ["a%20b+c", "%E2%9C%93", "%FF"]);
+        let out = url_decode_percent(&ca);
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some("a b+c"), Some("✓"), None]
+        );
+    }
+}
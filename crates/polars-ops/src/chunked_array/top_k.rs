@@ -147,23 +147,32 @@ fn top_k_binary_impl(
     ChunkedArray::with_chunk_like(ca, arr)
 }
 
-pub fn top_k(s: &[Column], descending: bool) -> PolarsResult<Column> {
-    fn extract_target_and_k(s: &[Column]) -> PolarsResult<(usize, &Column)> {
-        let k_s = &s[1];
-        polars_ensure!(
-            k_s.len() == 1,
-            ComputeError: "`k` must be a single value for `top_k`."
-        );
-
-        let Some(k) = k_s.strict_cast(&IDX_DTYPE)?.idx()?.get(0) else {
-            polars_bail!(ComputeError: "`k` must be set for `top_k`")
-        };
-
-        let src = &s[0];
-        Ok((k as usize, src))
+/// Extract `k` from a single value, or from a column of the length of the source which is
+/// constant, e.g. another column that is constant within the groups of a `group_by`.
+fn extract_k(k_s: &Column, src_len: usize) -> PolarsResult<usize> {
+    polars_ensure!(
+        k_s.len() == 1 || k_s.len() == src_len,
+        ComputeError: "`k` must be a single value or match the length of the input for `top_k`, got {} values for {} rows",
+        k_s.len(), src_len
+    );
+    if k_s.is_empty() {
+        return Ok(0);
     }
 
-    let (k, src) = extract_target_and_k(s)?;
+    let k_ca = k_s.strict_cast(&IDX_DTYPE)?;
+    let k_ca = k_ca.idx()?;
+    polars_ensure!(k_ca.null_count() == 0, ComputeError: "`k` must be set for `top_k`");
+    let k = k_ca.min().unwrap();
+    polars_ensure!(
+        k_ca.max() == Some(k),
+        ComputeError: "`k` must be constant for `top_k`, got multiple values"
+    );
+    Ok(k as usize)
+}
+
+pub fn top_k(s: &[Column], descending: bool) -> PolarsResult<Column> {
+    let src = &s[0];
+    let k = extract_k(&s[1], src.len())?;
 
     if src.is_empty() {
         return Ok(src.clone());
@@ -222,27 +231,9 @@ pub fn top_k(s: &[Column], descending: bool) -> PolarsResult<Column> {
 }
 
 pub fn top_k_by(s: &[Column], descending: Vec<bool>) -> PolarsResult<Column> {
-    /// Return (k, src, by)
-    fn extract_parameters(s: &[Column]) -> PolarsResult<(usize, &Column, &[Column])> {
-        let k_s = &s[1];
-
-        polars_ensure!(
-            k_s.len() == 1,
-            ComputeError: "`k` must be a single value for `top_k`."
-        );
-
-        let Some(k) = k_s.strict_cast(&IDX_DTYPE)?.idx()?.get(0) else {
-            polars_bail!(ComputeError: "`k` must be set for `top_k`")
-        };
-
-        let src = &s[0];
-
-        let by = &s[2..];
-
-        Ok((k as usize, src, by))
-    }
-
-    let (k, src, by) = extract_parameters(s)?;
+    let src = &s[0];
+    let k = extract_k(&s[1], src.len())?;
+    let by = &s[2..];
 
     if src.is_empty() {
         return Ok(src.clone());
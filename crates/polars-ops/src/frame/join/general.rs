@@ -83,7 +83,10 @@ pub fn _coalesce_full_join(
         let l = columns[pos_l].clone();
         let r = columns[pos_r].clone();
 
-        columns[pos_l] = coalesce_columns(&[l, r]).unwrap();
+        let metadata = merge_metadata(l.metadata().as_ref(), r.metadata().as_ref());
+        let mut out = coalesce_columns(&[l, r]).unwrap();
+        out.set_metadata(metadata);
+        columns[pos_l] = out;
         to_remove.push(pos_r);
     }
     // sort in reverse order, so the indexes remain correct if we remove.
@@ -1,6 +1,7 @@
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod unpivot;
+mod upsert;
 
 pub use join::*;
 #[cfg(feature = "to_dummies")]
@@ -10,6 +11,10 @@ use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_horizontal;
 #[cfg(feature = "to_dummies")]
 use rayon::prelude::*;
+pub use upsert::*;
+
+#[cfg(feature = "search_sorted")]
+use crate::series::{SearchSortedSide, search_sorted_multiple};
 
 pub trait IntoDf {
     fn to_df(&self) -> &DataFrame;
@@ -24,6 +29,27 @@ impl IntoDf for DataFrame {
 impl<T: IntoDf> DataFrameOps for T {}
 
 pub trait DataFrameOps: IntoDf {
+    /// Find the indices where the rows of the `by` columns of `search_values` should be inserted
+    /// into the rows of the `by` columns of this [`DataFrame`] to maintain order, comparing the
+    /// columns lexicographically.
+    ///
+    /// This [`DataFrame`] must be sorted by the `by` columns with the given `descending` and
+    /// `nulls_last` flags, e.g. with [`DataFrame::sort`]. The flags are given per column, or as a
+    /// single flag for all columns.
+    #[cfg(feature = "search_sorted")]
+    fn search_sorted(
+        &self,
+        by: &[PlSmallStr],
+        search_values: &DataFrame,
+        side: SearchSortedSide,
+        descending: &[bool],
+        nulls_last: &[bool],
+    ) -> PolarsResult<IdxCa> {
+        let by_columns = self.to_df().select_to_vec(by)?;
+        let search_columns = search_values.select_to_vec(by)?;
+        search_sorted_multiple(&by_columns, &search_columns, side, descending, nulls_last)
+    }
+
     /// Create dummy variables.
     ///
     /// # Example
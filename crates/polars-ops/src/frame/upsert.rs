@@ -0,0 +1,283 @@
+use polars_core::prelude::*;
+
+use crate::frame::join::*;
+
+const OTHER_IDX: &str = "__POLARS_UPSERT_OTHER_IDX";
+const SELF_IDX: &str = "__POLARS_UPSERT_SELF_IDX";
+
+/// How an upsert resolves rows of which the key already exists.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UpsertStrategy {
+    /// Replace the values of the existing row.
+    #[default]
+    Overwrite,
+    /// Keep the existing row as is.
+    Keep,
+    /// Raise an error.
+    Error,
+    /// Only replace the values of the existing row with the values that aren't null.
+    CoalesceNulls,
+}
+
+/// The number of rows inserted and updated by an upsert.
+///
+/// Both count rows of the upserted [`DataFrame`], a row that matches multiple existing rows with
+/// the same key is counted once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UpsertCounts {
+    pub inserted: usize,
+    pub updated: usize,
+}
+
+pub trait DataFrameUpsert {
+    /// Upsert the rows of `other` into this [`DataFrame`] by the key columns `on`.
+    ///
+    /// Rows of `other` with a key that doesn't exist yet are appended, rows with a key that
+    /// already exists are merged into the existing rows with the given `strategy`.
+    ///
+    /// The columns of `other` must be a subset of the columns of this [`DataFrame`] and include
+    /// the key columns. Columns missing from `other` are null for inserted rows and left as is
+    /// for updated rows. The keys of `other` must be unique, null keys never match.
+    fn upsert(
+        &mut self,
+        other: &DataFrame,
+        on: &[PlSmallStr],
+        strategy: UpsertStrategy,
+    ) -> PolarsResult<UpsertCounts>;
+}
+
+impl DataFrameUpsert for DataFrame {
+    fn upsert(
+        &mut self,
+        other: &DataFrame,
+        on: &[PlSmallStr],
+        strategy: UpsertStrategy,
+    ) -> PolarsResult<UpsertCounts> {
+        polars_ensure!(!on.is_empty(), InvalidOperation: "upsert needs at least one key column");
+        for key in on {
+            self.column(key)?;
+            other.column(key)?;
+        }
+
+        // Align the dtypes of `other` with this DataFrame.
+        let schema = self.schema().clone();
+        let columns = other
+            .columns()
+            .iter()
+            .map(|c| {
+                let dtype = schema.try_get(c.name())?;
+                c.strict_cast(dtype)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let other = DataFrame::new(other.height(), columns)?;
+
+        let other_keys = other.select(on.iter().cloned())?;
+        polars_ensure!(
+            other_keys.is_unique()?.all(),
+            Duplicate: "the keys of the DataFrame to upsert must be unique"
+        );
+
+        // Find the existing rows matching the rows of `other`.
+        let joined = other_keys
+            .with_row_index(PlSmallStr::from_static(OTHER_IDX), None)?
+            .join(
+                &self
+                    .select(on.iter().cloned())?
+                    .with_row_index(PlSmallStr::from_static(SELF_IDX), None)?,
+                on,
+                on,
+                JoinArgs::new(JoinType::Left),
+                None,
+            )?;
+        let other_idx = joined.column(OTHER_IDX)?.idx()?;
+        let self_idx = joined.column(SELF_IDX)?.idx()?;
+
+        let is_match = self_idx.is_not_null();
+        let insert_idx = other_idx.filter(&!&is_match)?;
+
+        // Every row of `other` occurs at least once in the left join, more often if this
+        // DataFrame has duplicate keys.
+        let n_matches = other.height() - insert_idx.len();
+        polars_ensure!(
+            n_matches == 0 || strategy != UpsertStrategy::Error,
+            Duplicate: "{n_matches} key(s) to upsert already exist"
+        );
+
+        let updated = match strategy {
+            UpsertStrategy::Overwrite | UpsertStrategy::CoalesceNulls if n_matches > 0 => {
+                let other_idx = other_idx.filter(&is_match)?;
+                let self_idx = self_idx.filter(&is_match)?;
+                upsert_update(self, &other, on, &other_idx, &self_idx, strategy)?;
+                n_matches
+            },
+            _ => 0,
+        };
+
+        if !insert_idx.is_empty() {
+            let inserts = other.take(&insert_idx)?;
+            let height = inserts.height();
+            let columns = self
+                .columns()
+                .iter()
+                .map(|c| match inserts.column(c.name()) {
+                    Ok(col) => col.clone(),
+                    Err(_) => Column::full_null(c.name().clone(), height, c.dtype()),
+                })
+                .collect();
+            self.vstack_mut_owned(DataFrame::new(height, columns)?)?;
+        }
+
+        Ok(UpsertCounts {
+            inserted: insert_idx.len(),
+            updated,
+        })
+    }
+}
+
+/// Replace the values of the rows at `self_idx` by the values of `other` at `other_idx`.
+fn upsert_update(
+    df: &mut DataFrame,
+    other: &DataFrame,
+    on: &[PlSmallStr],
+    other_idx: &IdxCa,
+    self_idx: &IdxCa,
+    strategy: UpsertStrategy,
+) -> PolarsResult<()> {
+    let height = df.height();
+    let columns = df
+        .columns()
+        .iter()
+        .map(|c| {
+            let Ok(new) = other.column(c.name()) else {
+                return Ok(c.clone());
+            };
+            if on.contains(c.name()) {
+                return Ok(c.clone());
+            }
+            let is_valid = (strategy == UpsertStrategy::CoalesceNulls)
+                .then(|| new.is_not_null().into_no_null_iter().collect::<Vec<_>>());
+
+            // Gather from the existing values followed by the new values.
+            let mut idx: Vec<IdxSize> = (0..height as IdxSize).collect();
+            for (s, o) in self_idx
+                .into_no_null_iter()
+                .zip(other_idx.into_no_null_iter())
+            {
+                if is_valid.as_ref().is_none_or(|v| v[o as usize]) {
+                    idx[s as usize] = height as IdxSize + o;
+                }
+            }
+            let mut values = c.clone();
+            values.append(new)?;
+            values.take(&IdxCa::from_vec(PlSmallStr::EMPTY, idx))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    *df = DataFrame::new(height, columns)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    fn reference() -> DataFrame {
+        df![
+            "id" => [1, 2, 3],
+            "name" => ["a", "b", "c"],
+            "value" => [Some(10), Some(20), Some(30)],
+        ]
+        .unwrap()
+    }
+
+    fn updates() -> DataFrame {
+        df![
+            "id" => [2, 4],
+            "value" => [None, Some(40)],
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn test_upsert_strategies() -> PolarsResult<()> {
+        let on = [PlSmallStr::from_static("id")];
+
+        let mut df = reference();
+        let counts = df.upsert(&updates(), &on, UpsertStrategy::Overwrite)?;
+        assert_eq!(
+            counts,
+            UpsertCounts {
+                inserted: 1,
+                updated: 1
+            }
+        );
+        let expected = df![
+            "id" => [1, 2, 3, 4],
+            "name" => [Some("a"), Some("b"), Some("c"), None],
+            "value" => [Some(10), None, Some(30), Some(40)],
+        ]?;
+        assert!(df.equals_missing(&expected));
+
+        let mut df = reference();
+        let counts = df.upsert(&updates(), &on, UpsertStrategy::CoalesceNulls)?;
+        assert_eq!(
+            counts,
+            UpsertCounts {
+                inserted: 1,
+                updated: 1
+            }
+        );
+        assert_eq!(df.column("value")?.i32()?.get(1), Some(20));
+
+        let mut df = reference();
+        let counts = df.upsert(&updates(), &on, UpsertStrategy::Keep)?;
+        assert_eq!(
+            counts,
+            UpsertCounts {
+                inserted: 1,
+                updated: 0
+            }
+        );
+        assert_eq!(df.height(), 4);
+        assert_eq!(df.column("value")?.i32()?.get(1), Some(20));
+
+        let mut df = reference();
+        assert!(df.upsert(&updates(), &on, UpsertStrategy::Error).is_err());
+        assert!(df.equals_missing(&reference()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_upsert_duplicate_existing_keys() -> PolarsResult<()> {
+        let mut df = df![
+            "id" => [1, 2, 2],
+            "value" => [10, 20, 21],
+        ]?;
+        let other = df!["id" => [2], "value" => [30]]?;
+        let on = [PlSmallStr::from_static("id")];
+
+        let counts = df.upsert(&other, &on, UpsertStrategy::Overwrite)?;
+        assert_eq!(
+            counts,
+            UpsertCounts {
+                inserted: 0,
+                updated: 1
+            }
+        );
+        let expected = df![
+            "id" => [1, 2, 2],
+            "value" => [10, 30, 30],
+        ]?;
+        assert!(df.equals(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_upsert_duplicate_keys() {
+        let mut df = reference();
+        let other = df!["id" => [4, 4], "value" => [1, 2]].unwrap();
+        let on = [PlSmallStr::from_static("id")];
+        assert!(df.upsert(&other, &on, UpsertStrategy::Overwrite).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+pub use polars_compute::tdigest::{TDIGEST_DEFAULT_COMPRESSION, TDigest};
+use polars_core::prelude::*;
+
+pub fn check_approx_quantile_args(quantile: f64, compression: f64) -> PolarsResult<()> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0, got {}", quantile
+    );
+    polars_ensure!(
+        compression > 0.0 && compression.is_finite(),
+        ComputeError: "`compression` must be positive, got {}", compression
+    );
+    Ok(())
+}
+
+/// Insert all non-null, non-NaN values of `s` into `digest`.
+pub fn tdigest_insert(digest: &mut TDigest, s: &Series) -> PolarsResult<()> {
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() || s.dtype().is_decimal() || s.dtype().is_null(),
+        InvalidOperation: "`approx_quantile` operation not supported for dtype `{}`", s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    for ca_arr in s.f64()?.downcast_iter() {
+        digest.extend(ca_arr.non_null_values_iter());
+    }
+    Ok(())
+}
+
+/// Compute the approximate `quantile` of the values with a t-digest.
+///
+/// The `compression` bounds the number of centroids of the t-digest, a higher compression gives
+/// a smaller error at the cost of more memory. Null and NaN values are ignored. Returns `None`
+/// if there are no other values.
+pub fn approx_quantile(s: &Series, quantile: f64, compression: f64) -> PolarsResult<Option<f64>> {
+    check_approx_quantile_args(quantile, compression)?;
+    let mut digest = TDigest::new(compression);
+    tdigest_insert(&mut digest, s)?;
+    Ok(digest.quantile(quantile))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_quantile() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(3), None, Some(1), Some(2), Some(4)]);
+        assert_eq!(approx_quantile(&s, 0.5, 100.0)?, Some(2.5));
+        assert_eq!(approx_quantile(&s, 1.0, 100.0)?, Some(4.0));
+
+        let s = Series::new("a".into(), (0..100_000).rev().collect::<Vec<i64>>());
+        let median = approx_quantile(&s, 0.5, 100.0)?.unwrap();
+        assert!((median - 49_999.5).abs() < 100.0, "{median}");
+
+        let s = Series::new("a".into(), [f64::NAN, 1.0]);
+        assert_eq!(approx_quantile(&s, 0.5, 100.0)?, Some(1.0));
+        let s = Series::new_null("a".into(), 3);
+        assert_eq!(approx_quantile(&s, 0.5, 100.0)?, None);
+
+        assert!(approx_quantile(&s, 1.5, 100.0).is_err());
+        assert!(approx_quantile(&s, 0.5, 0.0).is_err());
+        let s = Series::new("a".into(), ["a"]);
+        assert!(approx_quantile(&s, 0.5, 100.0).is_err());
+        Ok(())
+    }
+}
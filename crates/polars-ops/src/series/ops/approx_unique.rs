@@ -0,0 +1,295 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+pub use polars_compute::hyperloglogplus::HLL_DEFAULT_PRECISION;
+use polars_compute::hyperloglogplus::{
+    HLL_MAX_PRECISION, HLL_MIN_PRECISION, HLL_SERIALIZATION_VERSION, HyperLogLog,
+};
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::float16::pf16;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+/// A HyperLogLog sketch of the hashes of the values of a column.
+pub type HllSketch = HyperLogLog<u64>;
+
+/// A function on HyperLogLog sketches, see [`hll_sketch`], [`hll_merge`] and [`hll_estimate`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HllFunction {
+    Sketch { precision: u8 },
+    Merge,
+    Estimate,
+}
+
+impl Display for HllFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Sketch { .. } => "hll_sketch",
+            Self::Merge => "hll_merge",
+            Self::Estimate => "hll_estimate",
+        };
+        write!(f, "{s}")
+    }
+}
+
+pub fn check_hll_precision(precision: u8) -> PolarsResult<()> {
+    polars_ensure!(
+        (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+        InvalidOperation: "`precision` must be between {} and {}, got {}",
+        HLL_MIN_PRECISION, HLL_MAX_PRECISION, precision
+    );
+    Ok(())
+}
+
+/// Seed of the hashes of the values inserted into a [`HllSketch`].
+const HLL_HASH_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+/// Seed of the hash of null, so that it differs from the hash of any value.
+const HLL_NULL_HASH_SEED: u64 = 0x6a09_e667_f3bc_c909;
+
+/// A physical value that can be inserted into a [`HllSketch`].
+///
+/// Values are hashed with XXH3 and a fixed seed, so that sketches built by different engines,
+/// processes or Polars versions can be merged. Integers are hashed as 128-bit little-endian
+/// integers and floats as canonical 64-bit floats, so a value has the same hash regardless of
+/// its width. Changing these hashes requires a new serialization version of the sketches.
+pub trait HllHash {
+    fn hll_hash(&self) -> u64;
+}
+
+macro_rules! impl_hll_hash_int {
+    ($($T:ty),*) => {$(
+        impl HllHash for $T {
+            #[inline]
+            fn hll_hash(&self) -> u64 {
+                xxh3_64_with_seed(&self.to_le_bytes(), HLL_HASH_SEED)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_hll_hash_small_int {
+    ($($T:ty),*) => {$(
+        impl HllHash for $T {
+            #[inline]
+            fn hll_hash(&self) -> u64 {
+                (*self as i128).hll_hash()
+            }
+        }
+    )*};
+}
+
+impl_hll_hash_int!(i128, u128);
+impl_hll_hash_small_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl HllHash for f64 {
+    #[inline]
+    fn hll_hash(&self) -> u64 {
+        // All NaNs and both zeros are the same value.
+        let v = if self.is_nan() {
+            f64::NAN
+        } else if *self == 0.0 {
+            0.0
+        } else {
+            *self
+        };
+        xxh3_64_with_seed(&v.to_le_bytes(), HLL_HASH_SEED)
+    }
+}
+
+impl HllHash for f32 {
+    #[inline]
+    fn hll_hash(&self) -> u64 {
+        (*self as f64).hll_hash()
+    }
+}
+
+impl HllHash for pf16 {
+    #[inline]
+    fn hll_hash(&self) -> u64 {
+        f32::from(*self).hll_hash()
+    }
+}
+
+impl HllHash for bool {
+    #[inline]
+    fn hll_hash(&self) -> u64 {
+        xxh3_64_with_seed(&[*self as u8], HLL_HASH_SEED)
+    }
+}
+
+impl HllHash for &[u8] {
+    #[inline]
+    fn hll_hash(&self) -> u64 {
+        xxh3_64_with_seed(self, HLL_HASH_SEED)
+    }
+}
+
+impl HllHash for &str {
+    #[inline]
+    fn hll_hash(&self) -> u64 {
+        self.as_bytes().hll_hash()
+    }
+}
+
+/// The hash of a physical value that is inserted into a [`HllSketch`], see [`HllHash`].
+#[inline]
+pub fn hll_hash<T: HllHash>(value: Option<T>) -> u64 {
+    match value {
+        Some(value) => value.hll_hash(),
+        None => xxh3_64_with_seed(&[], HLL_NULL_HASH_SEED),
+    }
+}
+
+/// Cast `s` to the physical representation whose values are inserted into a [`HllSketch`].
+///
+/// Categoricals are inserted as their strings rather than their physical ids, as those depend on
+/// the order in which the categories were seen.
+pub fn hll_cast_series(s: &Series) -> PolarsResult<Cow<'_, Series>> {
+    match s.dtype() {
+        DataType::Null => Ok(Cow::Owned(s.cast(&DataType::Boolean)?)),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+            Ok(Cow::Owned(s.cast(&DataType::String)?))
+        },
+        _ => Ok(s.to_physical_repr()),
+    }
+}
+
+fn insert_ca<T>(sketch: &mut HllSketch, ca: &ChunkedArray<T>)
+where
+    T: PolarsDataType,
+    for<'a> T::Physical<'a>: HllHash,
+{
+    for value in ca.iter() {
+        sketch.add_hash(hll_hash(value));
+    }
+}
+
+/// Insert all values of `s` into `sketch`. Nulls are counted as a distinct value.
+pub fn hll_insert(sketch: &mut HllSketch, s: &Series) -> PolarsResult<()> {
+    let s = hll_cast_series(s)?;
+    match s.dtype() {
+        DataType::Boolean => insert_ca(sketch, s.bool()?),
+        DataType::String => insert_ca(sketch, s.str()?),
+        DataType::Binary => insert_ca(sketch, s.binary()?),
+        dt if dt.is_primitive_numeric() => {
+            with_match_physical_numeric_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                insert_ca(sketch, ca)
+            })
+        },
+        dt => polars_bail!(
+            InvalidOperation: "`approx_n_unique` operation not supported for dtype `{dt}`"
+        ),
+    }
+    Ok(())
+}
+
+/// Get the approximate number of unique values with a HyperLogLog sketch with `2^precision`
+/// registers.
+pub fn hll_approx_n_unique(s: &Series, precision: u8) -> PolarsResult<IdxSize> {
+    let sketch = hll_sketch_of(s, precision)?;
+    Ok(sketch.count().min(IdxSize::MAX as usize) as IdxSize)
+}
+
+fn hll_sketch_of(s: &Series, precision: u8) -> PolarsResult<HllSketch> {
+    check_hll_precision(precision)?;
+    let mut sketch = HllSketch::with_precision(precision);
+    hll_insert(&mut sketch, s)?;
+    Ok(sketch)
+}
+
+/// Build the serialized HyperLogLog sketch of the values of `s`.
+///
+/// The sketch can be merged with [`hll_merge`] and turned into an estimate of the number of
+/// unique values with [`hll_estimate`].
+pub fn hll_sketch(s: &Series, precision: u8) -> PolarsResult<Vec<u8>> {
+    Ok(hll_sketch_of(s, precision)?.to_bytes())
+}
+
+fn parse_sketch(bytes: &[u8]) -> PolarsResult<HllSketch> {
+    if let Some(version) = HllSketch::serialized_version(bytes) {
+        polars_ensure!(
+            version == HLL_SERIALIZATION_VERSION,
+            ComputeError: "unsupported HyperLogLog sketch version {}, expected version {}",
+            version, HLL_SERIALIZATION_VERSION
+        );
+    }
+    HllSketch::from_bytes(bytes)
+        .ok_or_else(|| polars_err!(ComputeError: "invalid HyperLogLog sketch"))
+}
+
+/// Merge serialized HyperLogLog sketches into a single sketch.
+///
+/// Null sketches are ignored. Returns `None` if all sketches are null.
+pub fn hll_merge(sketches: &BinaryChunked) -> PolarsResult<Option<Vec<u8>>> {
+    let mut out: Option<HllSketch> = None;
+    for bytes in sketches.iter().flatten() {
+        let sketch = parse_sketch(bytes)?;
+        out = Some(match out.take() {
+            None => sketch,
+            Some(mut out) => {
+                polars_ensure!(
+                    out.precision() == sketch.precision(),
+                    ComputeError: "cannot merge HyperLogLog sketches with different precisions ({} and {})",
+                    out.precision(), sketch.precision()
+                );
+                out.merge(&sketch);
+                out
+            },
+        });
+    }
+    Ok(out.map(|sketch| sketch.to_bytes()))
+}
+
+/// Estimate the number of unique values of every serialized HyperLogLog sketch.
+pub fn hll_estimate(sketches: &BinaryChunked) -> PolarsResult<IdxCa> {
+    sketches.try_apply_nonnull_values_generic(|bytes| {
+        let count = parse_sketch(bytes)?.count();
+        Ok(count.min(IdxSize::MAX as usize) as IdxSize)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hll_sketch_merge() -> PolarsResult<()> {
+        let a = Series::new("a".into(), (0..1000).collect::<Vec<i32>>());
+        let b = Series::new("a".into(), (500..1500).collect::<Vec<i32>>());
+
+        let approx = hll_approx_n_unique(&a, 12)?;
+        assert!(approx.abs_diff(1000) <= 50, "{approx}");
+
+        let sketches = BinaryChunked::from_iter_options(
+            "s".into(),
+            [Some(hll_sketch(&a, 12)?), None, Some(hll_sketch(&b, 12)?)].into_iter(),
+        );
+        let merged = hll_merge(&sketches)?.unwrap();
+        let merged = BinaryChunked::from_slice("s".into(), &[merged]);
+        let estimate = hll_estimate(&merged)?.get(0).unwrap();
+        assert!(estimate.abs_diff(1500) <= 75, "{estimate}");
+
+        // Merging gives the same sketch as sketching all values at once.
+        let mut all = a.clone();
+        all.append(&b)?;
+        assert_eq!(merged.get(0).unwrap(), hll_sketch(&all, 12)?.as_slice());
+
+        let estimates = hll_estimate(&sketches)?;
+        assert_eq!(estimates.null_count(), 1);
+
+        let other_precision = BinaryChunked::from_iter_values(
+            "s".into(),
+            [hll_sketch(&a, 12)?, hll_sketch(&a, 10)?].into_iter(),
+        );
+        assert!(hll_merge(&other_precision).is_err());
+        assert!(hll_sketch(&a, 2).is_err());
+        assert!(hll_estimate(&BinaryChunked::from_slice("s".into(), &[b"x"])).is_err());
+        Ok(())
+    }
+}
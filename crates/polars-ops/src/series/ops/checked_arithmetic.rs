@@ -0,0 +1,111 @@
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckedArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+fn checked_arithmetic_ca<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    op: CheckedArithmeticOp,
+    overflow_count: &mut usize,
+) -> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: CheckedAdd + CheckedSub + CheckedMul,
+{
+    let kernel = match op {
+        CheckedArithmeticOp::Add => <T::Native as CheckedAdd>::checked_add,
+        CheckedArithmeticOp::Sub => <T::Native as CheckedSub>::checked_sub,
+        CheckedArithmeticOp::Mul => <T::Native as CheckedMul>::checked_mul,
+    };
+    broadcast_binary_elementwise(lhs, rhs, |opt_l: Option<T::Native>, opt_r| {
+        let (l, r) = (opt_l?, opt_r?);
+        let out = kernel(&l, &r);
+        *overflow_count += out.is_none() as usize;
+        out
+    })
+}
+
+/// Applies an integer arithmetic operation that produces null instead of wrapping around when
+/// the result overflows the supertype of the operands.
+///
+/// Returns the result together with the number of values that overflowed.
+pub fn checked_arithmetic(
+    lhs: &Series,
+    rhs: &Series,
+    op: CheckedArithmeticOp,
+) -> PolarsResult<(Series, usize)> {
+    polars_ensure!(
+        lhs.dtype().is_integer() && rhs.dtype().is_integer(),
+        InvalidOperation: "checked arithmetic is only supported for integers, got {} and {}",
+        lhs.dtype(), rhs.dtype()
+    );
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        length_mismatch = "checked_arithmetic",
+        lhs.len(),
+        rhs.len()
+    );
+    let dtype = try_get_supertype(lhs.dtype(), rhs.dtype())?;
+    let lhs = lhs.cast(&dtype)?;
+    let rhs = rhs.cast(&dtype)?;
+
+    let mut overflow_count = 0;
+    let out = with_match_physical_integer_polars_type!(&dtype, |$T| {
+        let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref();
+        let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref();
+        checked_arithmetic_ca(lhs, rhs, op, &mut overflow_count).into_series()
+    });
+    Ok((out, overflow_count))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checked_arithmetic() -> PolarsResult<()> {
+        let lhs = Series::new("a".into(), [Some(i32::MAX), Some(1), None, Some(i32::MIN)]);
+        let rhs = Series::new("b".into(), [1i32, 2, 3, 1]);
+
+        let (out, overflow_count) = checked_arithmetic(&lhs, &rhs, CheckedArithmeticOp::Add)?;
+        let expected = Series::new("a".into(), [None, Some(3), None, Some(i32::MIN + 1)]);
+        assert!(out.equals_missing(&expected));
+        assert_eq!(overflow_count, 1);
+
+        let (out, overflow_count) = checked_arithmetic(&lhs, &rhs, CheckedArithmeticOp::Sub)?;
+        let expected = Series::new("a".into(), [Some(i32::MAX - 1), Some(-1), None, None]);
+        assert!(out.equals_missing(&expected));
+        assert_eq!(overflow_count, 1);
+
+        // Operands are cast to their supertype first.
+        let rhs = Series::new("b".into(), [2i64]);
+        let (out, overflow_count) = checked_arithmetic(&lhs, &rhs, CheckedArithmeticOp::Mul)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some(2 * i32::MAX as i64),
+                Some(2),
+                None,
+                Some(2 * i32::MIN as i64),
+            ],
+        );
+        assert!(out.equals_missing(&expected));
+        assert_eq!(overflow_count, 0);
+        Ok(())
+    }
+}
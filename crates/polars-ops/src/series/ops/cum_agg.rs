@@ -1,16 +1,20 @@
 use std::ops::{AddAssign, Mul};
 
 use arity::unary_elementwise_values;
+use arrow::array::builder::ShareStrategy;
 use arrow::array::{Array, BooleanArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder};
 use num_traits::{Bounded, One, Zero};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
+use polars_core::series::builder::SeriesBuilder;
 use polars_core::utils::{CustomIterTools, NoNull};
 use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::float::IsFloat;
 use polars_utils::min_max::MinMax;
 
+use crate::chunked_array::list::ListCumFunction;
+
 fn det_max<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
 where
     T: Copy + MinMax,
@@ -455,6 +459,95 @@ pub fn cum_count_with_init(s: &Series, reverse: bool, init: IdxSize) -> PolarsRe
     Ok(out)
 }
 
+fn cum_agg_with_init(
+    s: &Series,
+    function: ListCumFunction,
+    reverse: bool,
+    init: &AnyValue<'static>,
+) -> PolarsResult<Series> {
+    match function {
+        ListCumFunction::Sum => cum_sum_with_init(s, reverse, init),
+        ListCumFunction::Prod => cum_prod_with_init(s, reverse, init),
+        ListCumFunction::Min => cum_min_with_init(s, reverse, init),
+        ListCumFunction::Max => cum_max_with_init(s, reverse, init),
+        ListCumFunction::Count => {
+            cum_count_with_init(s, reverse, init.extract().unwrap_or_default())
+        },
+    }
+}
+
+pub fn cum_agg_reset_with_init(
+    s: &Series,
+    reset: &BooleanChunked,
+    function: ListCumFunction,
+    reverse: bool,
+    init: &AnyValue<'static>,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == reset.len(),
+        length_mismatch = "cum_agg_reset",
+        s.len(),
+        reset.len()
+    );
+
+    // Every run starts at an element for which `reset` is true, nulls don't start a new run.
+    let mut run_bounds = vec![0];
+    run_bounds.extend(
+        reset
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(i, r)| (r == Some(true)).then_some(i)),
+    );
+    run_bounds.push(s.len());
+    let num_runs = run_bounds.len() - 1;
+    let first_starts_run = reset.get(0) == Some(true);
+    if num_runs == 1 {
+        let init = if first_starts_run && !reverse {
+            &AnyValue::Null
+        } else {
+            init
+        };
+        return cum_agg_with_init(s, function, reverse, init);
+    }
+
+    // The runs are written into a single builder, instead of appending a chunk for every run.
+    let mut builder: Option<SeriesBuilder> = None;
+    for (run_idx, bounds) in run_bounds.windows(2).enumerate() {
+        let run = s.slice(bounds[0] as i64, bounds[1] - bounds[0]);
+        // The initial value continues the run in which the scan starts.
+        let continues_init = if reverse {
+            run_idx == num_runs - 1
+        } else {
+            run_idx == 0 && !first_starts_run
+        };
+        let run_init = if continues_init {
+            init
+        } else {
+            &AnyValue::Null
+        };
+        let run_out = cum_agg_with_init(&run, function, reverse, run_init)?;
+        let builder = builder.get_or_insert_with(|| {
+            let mut builder = SeriesBuilder::new(run_out.dtype().clone());
+            builder.reserve(s.len());
+            builder
+        });
+        builder.extend(&run_out, ShareStrategy::Never);
+    }
+    Ok(builder.unwrap().freeze(s.name().clone()))
+}
+
+/// Get an array with the cumulative aggregation computed at every element, where the
+/// aggregation restarts at every element for which `reset` is true.
+pub fn cum_agg_reset(
+    s: &Series,
+    reset: &BooleanChunked,
+    function: ListCumFunction,
+    reverse: bool,
+) -> PolarsResult<Series> {
+    cum_agg_reset_with_init(s, reset, function, reverse, &AnyValue::Null)
+}
+
 fn cum_count_no_nulls(name: PlSmallStr, len: usize, reverse: bool, init: IdxSize) -> Series {
     let start = 1 as IdxSize;
     let end = len as IdxSize + 1;
@@ -9,7 +9,12 @@ fn map_cats(
     sorted_breaks: &[f64],
     left_closed: bool,
     include_breaks: bool,
+    include_bins: bool,
 ) -> PolarsResult<Series> {
+    polars_ensure!(
+        !(include_breaks && include_bins),
+        InvalidOperation: "`include_breaks` and `include_bins` cannot both be set"
+    );
     let out_name = PlSmallStr::from_static("category");
 
     let s2 = s.cast(&DataType::Float64)?;
@@ -22,7 +27,45 @@ fn map_cats(
         PartialOrd::gt
     };
 
-    if include_breaks {
+    if include_bins {
+        let left_ends = [&[f64::NEG_INFINITY], sorted_breaks].concat();
+        let right_ends = [sorted_breaks, &[f64::INFINITY]].concat();
+        let mut bld = CategoricalChunkedBuilder::<Categorical32Type>::new(
+            out_name.clone(),
+            DataType::from_categories(Categories::global()),
+        );
+        let mut lower =
+            PrimitiveChunkedBuilder::<Float64Type>::new(PlSmallStr::from_static("lower"), s.len());
+        let mut upper =
+            PrimitiveChunkedBuilder::<Float64Type>::new(PlSmallStr::from_static("upper"), s.len());
+        let mut bin_index =
+            PrimitiveChunkedBuilder::<IdxType>::new(PlSmallStr::from_static("bin_index"), s.len());
+        for opt in s_iter {
+            match opt.filter(|x| !x.is_nan()) {
+                None => {
+                    bld.append_null();
+                    lower.append_null();
+                    upper.append_null();
+                    bin_index.append_null();
+                },
+                Some(x) => {
+                    let idx = sorted_breaks.partition_point(|v| op(&x, v));
+                    bld.append_str(&labels[idx])?;
+                    lower.append_value(left_ends[idx]);
+                    upper.append_value(right_ends[idx]);
+                    bin_index.append_value(idx as IdxSize);
+                },
+            }
+        }
+
+        let fields = [
+            bld.finish().into_series(),
+            lower.finish().into_series(),
+            upper.finish().into_series(),
+            bin_index.finish().into_series(),
+        ];
+        Ok(StructChunked::from_series(out_name, s.len(), fields.iter())?.into_series())
+    } else if include_breaks {
         // This is to replicate the behavior of the old buggy version that only worked on series and
         // returned a dataframe. That included a column of the right endpoint of the interval. So we
         // return a struct series instead which can be turned into a dataframe later.
@@ -91,6 +134,7 @@ pub fn cut(
     labels: Option<Vec<PlSmallStr>>,
     left_closed: bool,
     include_breaks: bool,
+    include_bins: bool,
 ) -> PolarsResult<Series> {
     // Breaks must be sorted to cut inputs properly.
     polars_ensure!(!breaks.iter().any(|x| x.is_nan()), ComputeError: "breaks cannot be NaN");
@@ -108,7 +152,14 @@ pub fn cut(
     } else {
         compute_labels(&breaks, left_closed)?
     };
-    map_cats(s, &cut_labels, &breaks, left_closed, include_breaks)
+    map_cats(
+        s,
+        &cut_labels,
+        &breaks,
+        left_closed,
+        include_breaks,
+        include_bins,
+    )
 }
 
 pub fn qcut(
@@ -118,16 +169,24 @@ pub fn qcut(
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    include_bins: bool,
 ) -> PolarsResult<Series> {
     polars_ensure!(!probs.iter().any(|x| x.is_nan()), ComputeError: "quantiles cannot be NaN");
 
     if s.null_count() == s.len() {
         // If we only have nulls we don't have any breakpoints.
-        return Ok(Series::full_null(
-            s.name().clone(),
-            s.len(),
-            &DataType::from_categories(Categories::global()),
-        ));
+        let categories = DataType::from_categories(Categories::global());
+        let dtype = if include_bins {
+            DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("category"), categories),
+                Field::new(PlSmallStr::from_static("lower"), DataType::Float64),
+                Field::new(PlSmallStr::from_static("upper"), DataType::Float64),
+                Field::new(PlSmallStr::from_static("bin_index"), IDX_DTYPE),
+            ])
+        } else {
+            categories
+        };
+        return Ok(Series::full_null(s.name().clone(), s.len(), &dtype));
     }
 
     let s = s.cast(&DataType::Float64)?;
@@ -153,7 +212,14 @@ pub fn qcut(
         compute_labels(&qbreaks, left_closed)?
     };
 
-    map_cats(&s, &cut_labels, &qbreaks, left_closed, include_breaks)
+    map_cats(
+        &s,
+        &cut_labels,
+        &qbreaks,
+        left_closed,
+        include_breaks,
+        include_bins,
+    )
 }
 
 mod test {
@@ -174,12 +240,43 @@ mod test {
         let left_closed = false;
 
         let include_breaks = false;
-        let out = map_cats(&s, labels, breaks, left_closed, include_breaks).unwrap();
+        let out = map_cats(&s, labels, breaks, left_closed, include_breaks, false).unwrap();
         out.cat32().unwrap();
 
         let include_breaks = true;
-        let out = map_cats(&s, labels, breaks, left_closed, include_breaks).unwrap();
+        let out = map_cats(&s, labels, breaks, left_closed, include_breaks, false).unwrap();
         let out = out.struct_().unwrap().fields_as_series()[1].clone();
         out.cat32().unwrap();
     }
+
+    #[test]
+    fn test_cut_include_bins() {
+        use polars_core::prelude::*;
+
+        use super::cut;
+
+        let s = Series::new(
+            "x".into(),
+            &[Some(1.0), None, Some(3.0), Some(f64::NAN), Some(5.0)],
+        );
+        let out = cut(&s, vec![4.0, 2.0], None, false, false, true).unwrap();
+        let fields = out.struct_().unwrap().fields_as_series();
+
+        let names = fields.iter().map(|s| s.name().as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["category", "lower", "upper", "bin_index"]);
+        assert_eq!(
+            fields[1].f64().unwrap().to_vec(),
+            [Some(f64::NEG_INFINITY), None, Some(2.0), None, Some(4.0)]
+        );
+        assert_eq!(
+            fields[2].f64().unwrap().to_vec(),
+            [Some(2.0), None, Some(4.0), None, Some(f64::INFINITY)]
+        );
+        assert_eq!(
+            fields[3].idx().unwrap().to_vec(),
+            [Some(0), None, Some(1), None, Some(2)]
+        );
+
+        assert!(cut(&s, vec![2.0], None, false, true, true).is_err());
+    }
 }
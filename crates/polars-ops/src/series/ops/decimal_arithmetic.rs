@@ -0,0 +1,139 @@
+use polars_compute::decimal::{
+    DEC128_MAX_PREC, Dec128RoundMode, dec128_add_prec_scale, dec128_add_rounded,
+    dec128_div_prec_scale, dec128_div_rounded, dec128_mul_prec_scale, dec128_mul_rounded,
+    dec128_sub_rounded,
+};
+use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+use super::RoundMode;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum DecimalArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// What to do when the result of a decimal operation doesn't fit its precision.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum DecimalOverflow {
+    #[default]
+    Raise,
+    Null,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DecimalArithmeticOptions {
+    pub rounding: RoundMode,
+    pub overflow: DecimalOverflow,
+}
+
+impl From<RoundMode> for Dec128RoundMode {
+    fn from(mode: RoundMode) -> Self {
+        match mode {
+            RoundMode::HalfToEven => Dec128RoundMode::HalfToEven,
+            RoundMode::HalfAwayFromZero => Dec128RoundMode::HalfAwayFromZero,
+            RoundMode::ToZero => Dec128RoundMode::ToZero,
+        }
+    }
+}
+
+/// The precision and scale an operand of a decimal operation is treated as. Integers are treated
+/// as decimals with scale 0 and enough precision to hold all their values.
+fn operand_prec_scale(dtype: &DataType) -> PolarsResult<(usize, usize)> {
+    use DataType::*;
+    let digits = match dtype {
+        Decimal(p, s) => return Ok((*p, *s)),
+        Unknown(kind @ UnknownKind::Int(_)) => {
+            return operand_prec_scale(&kind.materialize().unwrap());
+        },
+        Int8 | UInt8 => 3,
+        Int16 | UInt16 => 5,
+        Int32 | UInt32 => 10,
+        Int64 => 19,
+        UInt64 => 20,
+        Int128 | UInt128 => DEC128_MAX_PREC,
+        dt => polars_bail!(
+            InvalidOperation: "decimal arithmetic is not supported for dtype {dt}, cast to Decimal first"
+        ),
+    };
+    Ok((digits, 0))
+}
+
+/// The output dtype of a decimal operation on operands of the given dtypes.
+pub fn decimal_arithmetic_dtype(
+    lhs: &DataType,
+    rhs: &DataType,
+    op: DecimalArithmeticOp,
+) -> PolarsResult<DataType> {
+    let (lp, ls) = operand_prec_scale(lhs)?;
+    let (rp, rs) = operand_prec_scale(rhs)?;
+    let (p, s) = match op {
+        DecimalArithmeticOp::Add | DecimalArithmeticOp::Sub => {
+            dec128_add_prec_scale(lp, ls, rp, rs)
+        },
+        DecimalArithmeticOp::Mul => dec128_mul_prec_scale(lp, ls, rp, rs),
+        DecimalArithmeticOp::Div => dec128_div_prec_scale(lp, ls, rp, rs),
+    };
+    Ok(DataType::Decimal(p, s))
+}
+
+/// Applies a decimal operation with SQL-style precision and scale rules: the result is exact
+/// where the maximum precision allows, and otherwise rounded with the given mode.
+pub fn decimal_arithmetic(
+    lhs: &Series,
+    rhs: &Series,
+    op: DecimalArithmeticOp,
+    options: DecimalArithmeticOptions,
+) -> PolarsResult<Series> {
+    let (lp, ls) = operand_prec_scale(lhs.dtype())?;
+    let (rp, rs) = operand_prec_scale(rhs.dtype())?;
+    let DataType::Decimal(p, s) = decimal_arithmetic_dtype(lhs.dtype(), rhs.dtype(), op)? else {
+        unreachable!()
+    };
+    let lhs = lhs.cast(&DataType::Decimal(lp, ls))?;
+    let rhs = rhs.cast(&DataType::Decimal(rp, rs))?;
+    let lhs = lhs.decimal()?.physical();
+    let rhs = rhs.decimal()?.physical();
+
+    let mode = options.rounding.into();
+    let kernel = match op {
+        DecimalArithmeticOp::Add => dec128_add_rounded,
+        DecimalArithmeticOp::Sub => dec128_sub_rounded,
+        DecimalArithmeticOp::Mul => dec128_mul_rounded,
+        DecimalArithmeticOp::Div => dec128_div_rounded,
+    };
+    let op_name: &'static str = op.into();
+    let phys: Int128Chunked = broadcast_try_binary_elementwise(lhs, rhs, |opt_l, opt_r| {
+        let (Some(l), Some(r)) = (opt_l, opt_r) else {
+            return PolarsResult::Ok(None);
+        };
+        if op == DecimalArithmeticOp::Div && r == 0 {
+            polars_bail!(ComputeError: "division by zero Decimal");
+        }
+        match kernel(l, ls, r, rs, p, s, mode) {
+            Some(v) => Ok(Some(v)),
+            None if options.overflow == DecimalOverflow::Null => Ok(None),
+            None => polars_bail!(
+                ComputeError: "overflow in decimal {op_name}, result does not fit Decimal({p}, {s})"
+            ),
+        }
+    })?;
+    Ok(phys
+        .with_name(lhs.name().clone())
+        .into_decimal_unchecked(p, s)
+        .into_series())
+}
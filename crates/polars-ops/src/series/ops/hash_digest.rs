@@ -0,0 +1,115 @@
+use md5::Md5;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HashAlgorithm {
+    Sha256,
+    Md5,
+    /// 64-bit XXH3 with the given seed, the digest is the big-endian hash.
+    Xxh3 {
+        seed: u64,
+    },
+}
+
+impl HashAlgorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Md5 => "md5",
+            HashAlgorithm::Xxh3 { .. } => "xxh3",
+        }
+    }
+
+    fn digest_into(self, value: &[u8], out: &mut Vec<u8>) {
+        out.clear();
+        match self {
+            HashAlgorithm::Sha256 => out.extend_from_slice(&Sha256::digest(value)),
+            HashAlgorithm::Md5 => out.extend_from_slice(&Md5::digest(value)),
+            HashAlgorithm::Xxh3 { seed } => {
+                out.extend_from_slice(&xxh3_64_with_seed(value, seed).to_be_bytes())
+            },
+        }
+    }
+}
+
+/// Compute the `algorithm` digest of every value of a string or binary column.
+///
+/// Strings are hashed as their UTF-8 bytes. The output is a lowercase hexadecimal `String`
+/// column if `hex` is set and a `Binary` column otherwise. Nulls are propagated.
+pub fn hash_digest(s: &Series, algorithm: HashAlgorithm, hex: bool) -> PolarsResult<Series> {
+    let ca = match s.dtype() {
+        DataType::String => s.str()?.as_binary(),
+        DataType::Binary => s.binary()?.clone(),
+        dt => polars_bail!(
+            InvalidOperation: "`hash_{}` is only supported for string and binary columns, got {}",
+            algorithm.name(), dt
+        ),
+    };
+
+    let mut buf = Vec::new();
+    let out = if hex {
+        StringChunked::from_iter_options(
+            s.name().clone(),
+            ca.iter().map(|opt_v| {
+                opt_v.map(|v| {
+                    algorithm.digest_into(v, &mut buf);
+                    hex::encode(&buf)
+                })
+            }),
+        )
+        .into_series()
+    } else {
+        BinaryChunked::from_iter_options(
+            s.name().clone(),
+            ca.iter().map(|opt_v| {
+                opt_v.map(|v| {
+                    algorithm.digest_into(v, &mut buf);
+                    buf.clone()
+                })
+            }),
+        )
+        .into_series()
+    };
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hash_digest() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some("abc"), None, Some("")]);
+
+        let out = hash_digest(&s, HashAlgorithm::Md5, true)?;
+        let expected = Series::new(
+            "a".into(),
+            [
+                Some("900150983cd24fb0d6963f7d28e17f72"),
+                None,
+                Some("d41d8cd98f00b204e9800998ecf8427e"),
+            ],
+        );
+        assert!(out.equals_missing(&expected));
+
+        let out = hash_digest(&s, HashAlgorithm::Sha256, true)?;
+        assert_eq!(
+            out.str()?.get(0),
+            Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+
+        let out = hash_digest(&s, HashAlgorithm::Xxh3 { seed: 0 }, false)?;
+        assert_eq!(out.dtype(), &DataType::Binary);
+        assert_eq!(
+            out.binary()?.get(0),
+            Some(xxh3_64_with_seed(b"abc", 0).to_be_bytes().as_slice())
+        );
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@ use bytemuck::allocation::zeroed_vec;
 use num_traits::{NumCast, Zero};
 use polars_core::prelude::*;
 use polars_utils::slice::SliceAble;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::linear_itp;
 
@@ -263,7 +265,7 @@ where
     }
 }
 
-pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
+fn interpolate_by_linear(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
     polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
 
     fn func<T, F>(
@@ -327,13 +329,15 @@ pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResu
             func(s.f32().unwrap(), by.u32().unwrap(), by_is_sorted)
         },
         #[cfg(feature = "dtype-date")]
-        (_, DataType::Date) => interpolate_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted),
+        (_, DataType::Date) => {
+            interpolate_by_linear(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted)
+        },
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(_, _)) => {
-            interpolate_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
+            interpolate_by_linear(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
         },
         (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            interpolate_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
+            interpolate_by_linear(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
         },
         _ => {
             polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
@@ -342,3 +346,338 @@ pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResu
         },
     }
 }
+
+/// How [`interpolate_by`] fills null values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum InterpolateByMethod {
+    /// Linear interpolation between the surrounding values.
+    Linear,
+    /// The surrounding value that is closest in `by`.
+    Nearest,
+    /// The previous value.
+    Forward,
+    /// The next value.
+    Backward,
+    /// A natural cubic spline through all non-null values.
+    CubicSpline,
+}
+
+/// Convert `max_gap` to the units of the physical representation of `by`.
+fn max_gap_in_by_units(max_gap: &Column, by_dtype: &DataType) -> PolarsResult<Option<f64>> {
+    polars_ensure!(
+        max_gap.len() == 1,
+        ComputeError: "`max_gap` must be a single value, got {} values", max_gap.len()
+    );
+    let (max_gap, scale) = match (by_dtype, max_gap.dtype()) {
+        #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+        (DataType::Datetime(tu, _), DataType::Duration(_)) => {
+            (max_gap.cast(&DataType::Duration(*tu))?, 1.0)
+        },
+        #[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+        // Dates are physically stored as days.
+        (DataType::Date, DataType::Duration(_)) => (
+            max_gap.cast(&DataType::Duration(TimeUnit::Milliseconds))?,
+            86_400_000.0,
+        ),
+        #[cfg(feature = "dtype-duration")]
+        (_, DataType::Duration(_)) => polars_bail!(
+            InvalidOperation: "a duration `max_gap` requires `by` to be Date or Datetime, got {}",
+            by_dtype
+        ),
+        _ => (max_gap.clone(), 1.0),
+    };
+    let max_gap = max_gap.to_physical_repr().strict_cast(&DataType::Float64)?;
+    let max_gap = max_gap.f64()?.get(0).map(|v| v / scale);
+    if let Some(max_gap) = max_gap {
+        polars_ensure!(
+            max_gap >= 0.0,
+            InvalidOperation: "`max_gap` must be non-negative, got {}", max_gap
+        );
+    }
+    Ok(max_gap)
+}
+
+/// The values of `by` as `f64`, relative to the minimum so that large integers such as
+/// nanosecond timestamps keep their precision.
+fn by_offsets(by: &Series) -> PolarsResult<Vec<f64>> {
+    let by = by.to_physical_repr();
+    if by.dtype().is_float() {
+        let by = by.cast(&DataType::Float64)?;
+        return Ok(by.f64()?.into_no_null_iter().collect());
+    }
+    let by = by.strict_cast(&DataType::Int64)?;
+    let by = by.i64()?;
+    let min = by.min().unwrap_or(0);
+    Ok(by
+        .into_no_null_iter()
+        .map(|v| v.wrapping_sub(min) as u64 as f64)
+        .collect())
+}
+
+/// Evaluate the natural cubic spline through the points `(xs, ys)` on the segment
+/// `[xs[i], xs[i + 1]]`, where `m` holds the second derivatives at the points.
+fn eval_spline(xs: &[f64], ys: &[f64], m: &[f64], i: usize, x: f64) -> f64 {
+    let h = xs[i + 1] - xs[i];
+    let t = x - xs[i];
+    let u = xs[i + 1] - x;
+    m[i] * u * u * u / (6.0 * h)
+        + m[i + 1] * t * t * t / (6.0 * h)
+        + (ys[i] / h - m[i] * h / 6.0) * u
+        + (ys[i + 1] / h - m[i + 1] * h / 6.0) * t
+}
+
+/// The second derivatives of the natural cubic spline through the points `(xs, ys)`.
+fn spline_second_derivatives(xs: &[f64], ys: &[f64]) -> PolarsResult<Vec<f64>> {
+    let n = xs.len();
+    let mut m = vec![0.0; n];
+    if n < 3 {
+        return Ok(m);
+    }
+    let h = xs.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+    polars_ensure!(
+        h.iter().all(|h| *h > 0.0),
+        InvalidOperation: "cubic spline interpolation requires the `by` values of the non-null values to be unique"
+    );
+
+    // Solve the tridiagonal system for the interior points with the Thomas algorithm, the
+    // second derivatives at both ends are zero.
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+    for i in 1..n - 1 {
+        let a = h[i - 1];
+        let b = 2.0 * (h[i - 1] + h[i]);
+        let rhs = 6.0 * ((ys[i + 1] - ys[i]) / h[i] - (ys[i] - ys[i - 1]) / h[i - 1]);
+        let denom = b - a * c[i - 1];
+        c[i] = h[i] / denom;
+        d[i] = (rhs - a * d[i - 1]) / denom;
+    }
+    for i in (1..n - 1).rev() {
+        m[i] = d[i] - c[i] * m[i + 1];
+    }
+    Ok(m)
+}
+
+fn interpolate_by_impl(
+    s: &Series,
+    by: &Series,
+    by_is_sorted: bool,
+    method: InterpolateByMethod,
+    max_gap: Option<f64>,
+) -> PolarsResult<Series> {
+    use InterpolateByMethod as M;
+
+    polars_ensure!(by.null_count() == 0, InvalidOperation: "null values in `by` column are not yet supported in 'interpolate_by' expression");
+    let n = s.len();
+    let x = by_offsets(by)?;
+    let order: Vec<IdxSize> = if by_is_sorted {
+        (0..n as IdxSize).collect()
+    } else {
+        by.arg_sort(Default::default())
+            .cont_slice()
+            .expect("arg sort produces single chunk")
+            .to_vec()
+    };
+    let is_valid = s.is_not_null().rechunk();
+    let is_valid = is_valid.downcast_as_array().values();
+    // The positions in sorted order of the non-null values.
+    let valid = (0..n)
+        .filter(|&p| is_valid.get_bit(order[p] as usize))
+        .collect::<Vec<_>>();
+    let x_at = |p: usize| x[order[p] as usize];
+    let fits = |gap: f64| max_gap.is_none_or(|max_gap| gap <= max_gap);
+
+    // For every null in sorted order, its position and the indices into `valid` of the
+    // surrounding non-null values.
+    let mut nulls = Vec::with_capacity(n - valid.len());
+    let mut next = 0;
+    for p in 0..n {
+        if next < valid.len() && valid[next] == p {
+            next += 1;
+        } else {
+            nulls.push((p, next.checked_sub(1), (next < valid.len()).then_some(next)));
+        }
+    }
+
+    match method {
+        M::Forward | M::Backward | M::Nearest => {
+            let mut idx = (0..n as IdxSize).map(Some).collect::<Vec<_>>();
+            for &(p, low, high) in &nulls {
+                let low = low.map(|i| valid[i]);
+                let high = high.map(|i| valid[i]);
+                let src = match method {
+                    M::Forward => low.filter(|&l| fits(x_at(p) - x_at(l))),
+                    M::Backward => high.filter(|&h| fits(x_at(h) - x_at(p))),
+                    _ => low.zip(high).and_then(|(l, h)| {
+                        let (to_low, to_high) = (x_at(p) - x_at(l), x_at(h) - x_at(p));
+                        fits(x_at(h) - x_at(l)).then_some(if to_high > to_low { l } else { h })
+                    }),
+                };
+                idx[order[p] as usize] = src.map(|q| order[q]);
+            }
+            let idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, idx.into_iter());
+            s.take(&idx)
+        },
+        M::Linear | M::CubicSpline => {
+            let y = s.cast(&DataType::Float64)?;
+            let y = y.f64()?.rechunk();
+            let y_values = y.downcast_as_array().values().as_slice();
+            let y_at = |p: usize| y_values[order[p] as usize];
+            let xs = valid.iter().map(|&p| x_at(p)).collect::<Vec<_>>();
+            let ys = valid.iter().map(|&p| y_at(p)).collect::<Vec<_>>();
+            let m = if method == M::CubicSpline {
+                spline_second_derivatives(&xs, &ys)?
+            } else {
+                vec![]
+            };
+
+            let mut out = y.iter().collect::<Vec<_>>();
+            for &(p, low, high) in &nulls {
+                let (Some(low), Some(high)) = (low, high) else {
+                    continue;
+                };
+                if !fits(xs[high] - xs[low]) {
+                    continue;
+                }
+                let v = if method == M::CubicSpline {
+                    eval_spline(&xs, &ys, &m, low, x_at(p))
+                } else {
+                    let slope = (ys[high] - ys[low]) / (xs[high] - xs[low]);
+                    linear_itp(ys[low], x_at(p) - xs[low], slope)
+                };
+                out[order[p] as usize] = Some(v);
+            }
+            let out = Float64Chunked::from_iter_options(s.name().clone(), out.into_iter());
+            match s.dtype() {
+                dt if dt.is_float() => out.cast(dt),
+                _ => Ok(out.into_series()),
+            }
+        },
+    }
+}
+
+/// Fill the null values of `s` based on the x-coordinates in `by`, which must not contain nulls.
+///
+/// If given, `max_gap` is a scalar in the units of `by` or a duration if `by` is temporal. For
+/// [`InterpolateByMethod::Forward`] and [`InterpolateByMethod::Backward`] it is the maximum
+/// distance to the value that is propagated, for the other methods it is the maximum distance
+/// between the surrounding values. Nulls without a value to interpolate from remain null.
+pub fn interpolate_by(
+    s: &Column,
+    by: &Column,
+    by_is_sorted: bool,
+    method: InterpolateByMethod,
+    max_gap: Option<&Column>,
+) -> PolarsResult<Column> {
+    polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
+    let max_gap = max_gap
+        .map(|max_gap| max_gap_in_by_units(max_gap, by.dtype()))
+        .transpose()?
+        .flatten();
+
+    if method == InterpolateByMethod::Linear && max_gap.is_none() {
+        return interpolate_by_linear(s, by, by_is_sorted);
+    }
+    if !s.has_nulls() || s.null_count() == s.len() {
+        return match method {
+            InterpolateByMethod::Linear | InterpolateByMethod::CubicSpline => match s.dtype() {
+                dt if dt.is_float() => Ok(s.clone()),
+                _ => s.cast(&DataType::Float64),
+            },
+            _ => Ok(s.clone()),
+        };
+    }
+    interpolate_by_impl(
+        s.as_materialized_series(),
+        by.as_materialized_series(),
+        by_is_sorted,
+        method,
+        max_gap,
+    )
+    .map(Column::from)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn interpolate(
+        values: &[Option<f64>],
+        by: &[i64],
+        method: InterpolateByMethod,
+        max_gap: Option<i64>,
+    ) -> Vec<Option<f64>> {
+        let s = Column::new("a".into(), values);
+        let by = Column::new("by".into(), by);
+        let max_gap = max_gap.map(|v| Column::new("max_gap".into(), [v]));
+        let out = interpolate_by(&s, &by, false, method, max_gap.as_ref()).unwrap();
+        out.f64().unwrap().iter().collect()
+    }
+
+    #[test]
+    fn test_interpolate_by_fill_methods() {
+        use InterpolateByMethod as M;
+
+        let values = [Some(1.0), None, None, Some(4.0), None];
+        let by = [0, 1, 3, 4, 10];
+        assert_eq!(
+            interpolate(&values, &by, M::Forward, None),
+            [Some(1.0), Some(1.0), Some(1.0), Some(4.0), Some(4.0)]
+        );
+        assert_eq!(
+            interpolate(&values, &by, M::Forward, Some(2)),
+            [Some(1.0), Some(1.0), None, Some(4.0), None]
+        );
+        assert_eq!(
+            interpolate(&values, &by, M::Backward, Some(1)),
+            [Some(1.0), None, Some(4.0), Some(4.0), None]
+        );
+        assert_eq!(
+            interpolate(&values, &by, M::Nearest, None),
+            [Some(1.0), Some(1.0), Some(4.0), Some(4.0), None]
+        );
+        assert_eq!(
+            interpolate(&values, &by, M::Nearest, Some(3)),
+            [Some(1.0), None, None, Some(4.0), None]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_by_unsorted() {
+        let values = [None, Some(4.0), Some(0.0), None];
+        let by = [2, 4, 0, 1];
+        assert_eq!(
+            interpolate(&values, &by, InterpolateByMethod::Linear, Some(10)),
+            [Some(2.0), Some(4.0), Some(0.0), Some(1.0)]
+        );
+        assert_eq!(
+            interpolate(&values, &by, InterpolateByMethod::Backward, None),
+            [Some(4.0), Some(4.0), Some(0.0), Some(4.0)]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_by_cubic_spline() {
+        // A natural cubic spline reproduces straight lines.
+        let values = [Some(1.0), None, Some(5.0), None, None, Some(11.0)];
+        let by = [0, 1, 2, 3, 4, 5];
+        assert_eq!(
+            interpolate(&values, &by, InterpolateByMethod::CubicSpline, None),
+            [
+                Some(1.0),
+                Some(3.0),
+                Some(5.0),
+                Some(7.0),
+                Some(9.0),
+                Some(11.0)
+            ]
+        );
+
+        let values = [Some(0.0), None, Some(1.0), Some(0.0)];
+        let by = [0, 1, 2, 3];
+        let out = interpolate(&values, &by, InterpolateByMethod::CubicSpline, None);
+        // The second derivative at the middle point is -1.5, which bends the curve upwards.
+        assert!((out[1].unwrap() - 0.875).abs() < 1e-12);
+    }
+}
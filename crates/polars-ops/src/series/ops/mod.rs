@@ -1,14 +1,21 @@
 #[cfg(feature = "abs")]
 mod abs;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
+#[cfg(feature = "approx_unique")]
+mod approx_unique;
 pub mod arg_min_max;
 mod bitwise;
 #[cfg(feature = "business")]
 mod business;
+mod checked_arithmetic;
 mod clip;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
 #[cfg(feature = "cutqcut")]
 mod cut;
+#[cfg(feature = "decimal_arithmetic")]
+mod decimal_arithmetic;
 #[cfg(feature = "diff")]
 mod diff;
 mod eager;
@@ -20,6 +27,8 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "hash_digest")]
+mod hash_digest;
 mod horizontal;
 mod index;
 #[cfg(feature = "index_of")]
@@ -65,18 +74,27 @@ mod to_dummies;
 #[cfg(feature = "unique_counts")]
 mod unique;
 mod various;
+#[cfg(feature = "weighted_agg")]
+mod weighted;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
+#[cfg(feature = "approx_quantile")]
+pub use approx_quantile::*;
+#[cfg(feature = "approx_unique")]
+pub use approx_unique::*;
 pub use arg_min_max::ArgAgg;
 pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
+pub use checked_arithmetic::*;
 pub use clip::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
 #[cfg(feature = "cutqcut")]
 pub use cut::*;
+#[cfg(feature = "decimal_arithmetic")]
+pub use decimal_arithmetic::*;
 #[cfg(feature = "diff")]
 pub use diff::*;
 pub use eager::*;
@@ -88,6 +106,8 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "hash_digest")]
+pub use hash_digest::*;
 pub use horizontal::*;
 pub use index::*;
 #[cfg(feature = "index_of")]
@@ -139,6 +159,8 @@ pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
 pub use unique::*;
 pub use various::*;
+#[cfg(feature = "weighted_agg")]
+pub use weighted::*;
 mod not;
 
 #[cfg(feature = "dtype-array")]
@@ -151,10 +173,14 @@ pub use not::*;
 
 pub trait SeriesSealed {
     fn as_series(&self) -> &Series;
+    fn as_series_mut(&mut self) -> &mut Series;
 }
 
 impl SeriesSealed for Series {
     fn as_series(&self) -> &Series {
         self
     }
+    fn as_series_mut(&mut self) -> &mut Series {
+        self
+    }
 }
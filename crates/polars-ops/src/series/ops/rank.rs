@@ -1,6 +1,9 @@
 #![allow(unsafe_op_in_unsafe_fn)]
-use arrow::array::BooleanArray;
+use arrow::array::{BinaryArray, BooleanArray};
 use arrow::compute::concatenate::concatenate_validities;
+use polars_core::chunked_array::ops::row_encode::{
+    _get_rows_encoded_arr, _get_rows_encoded_unordered,
+};
 use polars_core::prelude::*;
 use rand::prelude::*;
 #[cfg(feature = "serde")]
@@ -19,6 +22,8 @@ pub enum RankMethod {
     Ordinal,
     #[cfg(feature = "random")]
     Random,
+    /// The relative rank `(min_rank - 1) / (n - 1)` in the range `[0, 1]`.
+    Percent,
 }
 
 // We might want to add a `nulls_last` or `null_behavior` field.
@@ -65,13 +70,24 @@ unsafe fn rank_impl<F: FnMut(&mut [IdxSize])>(idxs: &IdxCa, neq: &BooleanArray,
     flush_ties(&mut ties_indices);
 }
 
+/// Whether the values at consecutive positions of the sort order differ.
+fn not_consecutive_same(s: &Series, sort_idx_ca: &IdxCa) -> BooleanChunked {
+    let sorted_values = unsafe { s.take_unchecked(sort_idx_ca) };
+    sorted_values
+        .slice(1, sorted_values.len() - 1)
+        .not_equal(&sorted_values.slice(0, sorted_values.len() - 1))
+        .unwrap()
+        .rechunk()
+        .into_owned()
+}
+
 fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) -> Series {
     let len = s.len();
     let null_count = s.null_count();
 
     if null_count == len {
         let dt = match method {
-            Average => DataType::Float64,
+            Average | Percent => DataType::Float64,
             _ => IDX_DTYPE,
         };
         return Series::full_null(s.name().clone(), s.len(), &dt);
@@ -81,12 +97,15 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
         1 => {
             return match method {
                 Average => Series::new(s.name().clone(), &[1.0f64]),
+                Percent => Series::new(s.name().clone(), &[0.0f64]),
                 _ => Series::new(s.name().clone(), &[1 as IdxSize]),
             };
         },
         0 => {
             return match method {
-                Average => Float64Chunked::from_slice(s.name().clone(), &[]).into_series(),
+                Average | Percent => {
+                    Float64Chunked::from_slice(s.name().clone(), &[]).into_series()
+                },
                 _ => IdxCa::from_slice(s.name().clone(), &[]).into_series(),
             };
         },
@@ -95,7 +114,7 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
 
     if null_count == len {
         return match method {
-            Average => Float64Chunked::full_null(s.name().clone(), len).into_series(),
+            Average | Percent => Float64Chunked::full_null(s.name().clone(), len).into_series(),
             _ => IdxCa::full_null(s.name().clone(), len).into_series(),
         };
     }
@@ -122,12 +141,7 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
         }
         IdxCa::from_vec_validity(s.name().clone(), out, validity).into_series()
     } else {
-        let sorted_values = unsafe { s.take_unchecked(&sort_idx_ca) };
-        let not_consecutive_same = sorted_values
-            .slice(1, sorted_values.len() - 1)
-            .not_equal(&sorted_values.slice(0, sorted_values.len() - 1))
-            .unwrap();
-        let neq = not_consecutive_same.rechunk();
+        let neq = not_consecutive_same(s, &sort_idx_ca);
         let neq = neq.downcast_as_array();
 
         let mut rank = 1;
@@ -158,6 +172,22 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
                 });
                 Float64Chunked::from_vec_validity(s.name().clone(), out, validity).into_series()
             },
+            Percent => unsafe {
+                let n = (len - null_count) as f64;
+                let mut out = vec![0.0; s.len()];
+                rank_impl(&sort_idx_ca, neq, |ties| {
+                    let pct = if n > 1.0 {
+                        (rank - 1) as f64 / (n - 1.0)
+                    } else {
+                        0.0
+                    };
+                    for i in ties.iter() {
+                        *out.get_unchecked_mut(*i as usize) = pct;
+                    }
+                    rank += ties.len() as IdxSize;
+                });
+                Float64Chunked::from_vec_validity(s.name().clone(), out, validity).into_series()
+            },
             Min => unsafe {
                 let mut out = vec![0 as IdxSize; s.len()];
                 rank_impl(&sort_idx_ca, neq, |ties| {
@@ -201,6 +231,200 @@ pub trait SeriesRank: SeriesSealed {
 
 impl SeriesRank for Series {}
 
+/// Ranks values within partitions without sorting the rows.
+///
+/// The values are first counted per partition with [`PartitionedRanker::update`], which can be
+/// called for any number of chunks. [`PartitionedRanker::finish`] then only orders the distinct
+/// values of every partition, after which [`PartitionedRanker::rank`] ranks the same chunks. The
+/// chunks must be ranked in their original order, as ordinal ranks break ties by row order.
+///
+/// Partitions and values are identified by their row encoding, so the chunks can be streamed
+/// without ever materializing the partitions.
+pub struct PartitionedRanker {
+    options: RankOptions,
+    #[cfg_attr(not(feature = "random"), allow(unused))]
+    seed: Option<u64>,
+    partitions: PlHashMap<Box<[u8]>, RankPartition>,
+}
+
+#[derive(Default)]
+struct RankPartition {
+    /// Number of non-null values.
+    len: IdxSize,
+    ties: PlHashMap<Box<[u8]>, Ties>,
+}
+
+/// The occurrences of a single value in a partition.
+#[derive(Default)]
+struct Ties {
+    count: IdxSize,
+    /// Number of smaller values in the partition.
+    offset: IdxSize,
+    /// Number of smaller distinct values in the partition.
+    dense: IdxSize,
+    /// Number of these ties that are ranked already, for ordinal and random ranks.
+    ranked: IdxSize,
+    /// The random order in which these ties are ranked.
+    #[cfg(feature = "random")]
+    order: Vec<IdxSize>,
+}
+
+impl Ties {
+    fn idx_rank(&mut self, method: RankMethod) -> IdxSize {
+        use RankMethod::*;
+        match method {
+            Min => self.offset + 1,
+            Max => self.offset + self.count,
+            Dense => self.dense + 1,
+            Ordinal => {
+                self.ranked += 1;
+                self.offset + self.ranked
+            },
+            #[cfg(feature = "random")]
+            Random => {
+                let rank = self.offset + self.order[self.ranked as usize] + 1;
+                self.ranked += 1;
+                rank
+            },
+            Average | Percent => unreachable!(),
+        }
+    }
+
+    fn float_rank(&self, method: RankMethod, partition_len: IdxSize) -> f64 {
+        match method {
+            RankMethod::Average => self.offset as f64 + (self.count as f64 + 1.0) / 2.0,
+            RankMethod::Percent if partition_len > 1 => {
+                self.offset as f64 / (partition_len - 1) as f64
+            },
+            RankMethod::Percent => 0.0,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// The row encoded values and partitions of a chunk.
+struct RankKeys {
+    values: BinaryArray<i64>,
+    partitions: Option<BinaryArray<i64>>,
+}
+
+impl RankKeys {
+    fn new(values: &Series, partition_by: &[Column], descending: bool) -> PolarsResult<Self> {
+        // The bytewise order of the encoded values is their sort order.
+        let values =
+            _get_rows_encoded_arr(&[values.clone().into_column()], &[descending], &[false], false)?;
+        let partitions = if partition_by.is_empty() {
+            None
+        } else {
+            Some(_get_rows_encoded_unordered(partition_by)?.into_array())
+        };
+        Ok(Self { values, partitions })
+    }
+
+    fn value(&self, i: usize) -> &[u8] {
+        self.values.value(i)
+    }
+
+    fn partition(&self, i: usize) -> &[u8] {
+        self.partitions.as_ref().map_or(&[], |p| p.value(i))
+    }
+}
+
+impl PartitionedRanker {
+    pub fn new(options: RankOptions, seed: Option<u64>) -> Self {
+        Self {
+            options,
+            seed,
+            partitions: PlHashMap::new(),
+        }
+    }
+
+    pub fn dtype(&self) -> DataType {
+        match self.options.method {
+            RankMethod::Average | RankMethod::Percent => DataType::Float64,
+            _ => IDX_DTYPE,
+        }
+    }
+
+    /// Count the values of a chunk, `partition_by` must have the same length as `values`.
+    pub fn update(&mut self, values: &Series, partition_by: &[Column]) -> PolarsResult<()> {
+        let keys = RankKeys::new(values, partition_by, self.options.descending)?;
+        let validity = values.rechunk_validity();
+        for i in 0..values.len() {
+            if validity.as_ref().is_some_and(|v| !v.get_bit(i)) {
+                continue;
+            }
+            let partition = self.partitions.entry_ref(keys.partition(i)).or_default();
+            partition.len += 1;
+            partition.ties.entry_ref(keys.value(i)).or_default().count += 1;
+        }
+        Ok(())
+    }
+
+    /// Order the distinct values of every partition, after all chunks are counted.
+    pub fn finish(&mut self) {
+        #[cfg(feature = "random")]
+        let mut rng = SmallRng::seed_from_u64(self.seed.unwrap_or_else(get_random_seed));
+
+        for partition in self.partitions.values_mut() {
+            let mut ties = partition.ties.iter_mut().collect::<Vec<_>>();
+            ties.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+            let mut offset = 0;
+            for (dense, (_, ties)) in ties.into_iter().enumerate() {
+                ties.offset = offset;
+                ties.dense = dense as IdxSize;
+                offset += ties.count;
+
+                #[cfg(feature = "random")]
+                if let RankMethod::Random = self.options.method {
+                    ties.order = (0..ties.count).collect();
+                    ties.order.shuffle(&mut rng);
+                }
+            }
+        }
+    }
+
+    /// Rank the values of a chunk that was counted before.
+    pub fn rank(&mut self, values: &Series, partition_by: &[Column]) -> PolarsResult<Series> {
+        let keys = RankKeys::new(values, partition_by, self.options.descending)?;
+        let validity = values.rechunk_validity();
+        let method = self.options.method;
+        let is_float = matches!(method, RankMethod::Average | RankMethod::Percent);
+
+        let len = values.len();
+        let mut idx_out = Vec::with_capacity(if is_float { 0 } else { len });
+        let mut float_out = Vec::with_capacity(if is_float { len } else { 0 });
+        for i in 0..len {
+            let ties = if validity.as_ref().is_some_and(|v| !v.get_bit(i)) {
+                None
+            } else {
+                let partition = self.partitions.get_mut(keys.partition(i));
+                let ties = partition.and_then(|p| Some((p.len, p.ties.get_mut(keys.value(i))?)));
+                Some(ties.ok_or_else(
+                    || polars_err!(ComputeError: "rank: values must be counted before ranking"),
+                )?)
+            };
+
+            match ties {
+                Some((partition_len, ties)) if is_float => {
+                    float_out.push(ties.float_rank(method, partition_len))
+                },
+                Some((_, ties)) => idx_out.push(ties.idx_rank(method)),
+                None if is_float => float_out.push(0.0),
+                None => idx_out.push(0),
+            }
+        }
+
+        let name = values.name().clone();
+        Ok(if is_float {
+            Float64Chunked::from_vec_validity(name, float_out, validity).into_series()
+        } else {
+            IdxCa::from_vec_validity(name, idx_out, validity).into_series()
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -346,4 +570,53 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rank_partitioned() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[1, 2, 3, 2, 2, 3, 0]);
+        let partition_by = [Column::new("p".into(), &[0, 1, 0, 1, 0, 1, 0])];
+        let rank = |method| -> PolarsResult<Series> {
+            let options = RankOptions {
+                method,
+                descending: false,
+            };
+            let mut ranker = PartitionedRanker::new(options, None);
+            // Chunks are counted and ranked separately.
+            for (offset, len) in [(0, 3), (3, 4)] {
+                let by = [partition_by[0].slice(offset, len)];
+                ranker.update(&s.slice(offset, len), &by)?;
+            }
+            ranker.finish();
+            let mut out = ranker.rank(&s.slice(0, 3), &[partition_by[0].slice(0, 3)])?;
+            out.append(&ranker.rank(&s.slice(3, 4), &[partition_by[0].slice(3, 4)])?)?;
+            Ok(out)
+        };
+        let rank_idx = |method| -> PolarsResult<Vec<IdxSize>> {
+            Ok(rank(method)?.idx()?.into_no_null_iter().collect())
+        };
+        let rank_f64 = |method| -> PolarsResult<Vec<f64>> {
+            Ok(rank(method)?.f64()?.into_no_null_iter().collect())
+        };
+
+        assert_eq!(rank_idx(RankMethod::Ordinal)?, &[2, 1, 4, 2, 3, 3, 1]);
+        assert_eq!(rank_idx(RankMethod::Dense)?, &[2, 1, 4, 1, 3, 2, 1]);
+        assert_eq!(rank_idx(RankMethod::Min)?, &[2, 1, 4, 1, 3, 3, 1]);
+        assert_eq!(rank_idx(RankMethod::Max)?, &[2, 2, 4, 2, 3, 3, 1]);
+        assert_eq!(rank_f64(RankMethod::Average)?, &[2.0, 1.5, 4.0, 1.5, 3.0, 3.0, 1.0]);
+        assert_eq!(
+            rank_f64(RankMethod::Percent)?,
+            &[1.0 / 3.0, 0.0, 1.0, 0.0, 2.0 / 3.0, 1.0, 0.0]
+        );
+
+        // Nulls are not ranked and values that weren't counted are an error.
+        let s = Series::new("a".into(), &[Some(2), None, Some(1)]);
+        let mut ranker = PartitionedRanker::new(RankOptions::default(), None);
+        ranker.update(&s, &[])?;
+        ranker.finish();
+        let out = ranker.rank(&s, &[])?;
+        assert_eq!(out.idx()?.to_vec(), &[Some(2), None, Some(1)]);
+        assert!(ranker.rank(&Series::new("a".into(), &[3]), &[]).is_err());
+
+        Ok(())
+    }
 }
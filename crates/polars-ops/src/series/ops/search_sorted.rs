@@ -114,3 +114,177 @@ pub fn search_sorted(
         _ => polars_bail!(opq = search_sorted, original_dtype),
     }
 }
+
+/// Broadcast a single flag to `n` columns, or check that there is a flag per column.
+fn broadcast_flags(flags: &[bool], n: usize, name: &str) -> PolarsResult<Vec<bool>> {
+    match flags.len() {
+        1 => Ok(vec![flags[0]; n]),
+        len => {
+            polars_ensure!(
+                len == n,
+                InvalidOperation: "expected {} `{}` values for {} columns, got {}", n, name, n, len
+            );
+            Ok(flags.to_vec())
+        },
+    }
+}
+
+/// The row encoded columns of a frame that is sorted lexicographically by these columns, which
+/// can be searched multiple times without encoding the sorted columns again.
+pub struct SearchSortedKeys {
+    name: PlSmallStr,
+    dtypes: Vec<DataType>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    encoded: BinaryOffsetChunked,
+}
+
+impl SearchSortedKeys {
+    /// Encode the sorted columns `by`.
+    ///
+    /// The rows of `by` must be sorted by all columns with the given `descending` and
+    /// `nulls_last` flags, as done by a multi-column sort. The flags are given per column, or as
+    /// a single flag for all columns.
+    pub fn new(by: &[Column], descending: &[bool], nulls_last: &[bool]) -> PolarsResult<Self> {
+        polars_ensure!(!by.is_empty(), InvalidOperation: "`search_sorted` requires at least one column");
+        let descending = broadcast_flags(descending, by.len(), "descending")?;
+        let nulls_last = broadcast_flags(nulls_last, by.len(), "nulls_last")?;
+        let encoded = _get_rows_encoded_ca("".into(), by, &descending, &nulls_last, false)?;
+        Ok(Self {
+            name: by[0].name().clone(),
+            dtypes: by.iter().map(|s| s.dtype().clone()).collect(),
+            descending,
+            nulls_last,
+            encoded,
+        })
+    }
+
+    /// Find the indices where the rows of `search_values` should be inserted into the sorted
+    /// rows to maintain order. The `search_values` are cast to the data types of the sorted
+    /// columns.
+    pub fn search(&self, search_values: &[Column], side: SearchSortedSide) -> PolarsResult<IdxCa> {
+        polars_ensure!(
+            self.dtypes.len() == search_values.len(),
+            InvalidOperation: "`search_sorted` got {} sorted columns, but {} columns to search",
+            self.dtypes.len(), search_values.len()
+        );
+        let search_values = self
+            .dtypes
+            .iter()
+            .zip(search_values)
+            .map(|(dtype, v)| v.strict_cast(dtype))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let search_values = _get_rows_encoded_ca(
+            "".into(),
+            &search_values,
+            &self.descending,
+            &self.nulls_last,
+            false,
+        )?;
+        let idx = binary_search_ca(&self.encoded, search_values.iter(), side, false);
+        Ok(IdxCa::new_vec(self.name.clone(), idx))
+    }
+}
+
+/// Find the indices where the rows of `search_values` should be inserted into the rows of `by`
+/// to maintain order, comparing the columns lexicographically.
+///
+/// The rows of `by` must be sorted by all columns with the given `descending` and `nulls_last`
+/// flags, as done by a multi-column sort. The flags are given per column, or as a single flag for
+/// all columns. The `search_values` are cast to the data types of `by`.
+///
+/// Use [`SearchSortedKeys`] to search the same sorted columns multiple times.
+pub fn search_sorted_multiple(
+    by: &[Column],
+    search_values: &[Column],
+    side: SearchSortedSide,
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> PolarsResult<IdxCa> {
+    SearchSortedKeys::new(by, descending, nulls_last)?.search(search_values, side)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search_sorted_multiple() -> PolarsResult<()> {
+        let symbol = Column::new("symbol".into(), ["a", "a", "a", "b", "b"]);
+        let ts = Column::new("ts".into(), [1, 3, 5, 2, 4]);
+        let search_symbol = Column::new("symbol".into(), ["a", "a", "b", "b", "c", "0"]);
+        let search_ts = Column::new("ts".into(), [3, 4, 0, 9, 1, 1]);
+        let by = [symbol, ts];
+        let search_values = [search_symbol, search_ts];
+
+        let idx = search_sorted_multiple(
+            &by,
+            &search_values,
+            SearchSortedSide::Left,
+            &[false],
+            &[false],
+        )?;
+        assert_eq!(
+            Vec::from(&idx),
+            &[Some(1), Some(2), Some(3), Some(5), Some(5), Some(0)]
+        );
+        let idx = search_sorted_multiple(
+            &by,
+            &search_values,
+            SearchSortedSide::Right,
+            &[false],
+            &[false],
+        )?;
+        assert_eq!(
+            Vec::from(&idx),
+            &[Some(2), Some(2), Some(3), Some(5), Some(5), Some(0)]
+        );
+
+        // The timestamps are sorted descending within the symbols.
+        let by = [by[0].clone(), Column::new("ts".into(), [5, 3, 1, 4, 2])];
+        let idx = search_sorted_multiple(
+            &by,
+            &search_values,
+            SearchSortedSide::Left,
+            &[false, true],
+            &[false, false],
+        )?;
+        assert_eq!(
+            Vec::from(&idx),
+            &[Some(1), Some(1), Some(5), Some(3), Some(5), Some(0)]
+        );
+
+        // The sorted columns are encoded once and can be searched repeatedly.
+        let keys = SearchSortedKeys::new(&by, &[false, true], &[false])?;
+        assert_eq!(
+            Vec::from(&keys.search(&search_values, SearchSortedSide::Left)?),
+            Vec::from(&idx)
+        );
+        assert_eq!(
+            Vec::from(&keys.search(&search_values, SearchSortedSide::Right)?),
+            &[Some(2), Some(1), Some(5), Some(3), Some(5), Some(0)]
+        );
+
+        assert!(
+            search_sorted_multiple(
+                &by,
+                &search_values[..1],
+                SearchSortedSide::Left,
+                &[false],
+                &[false]
+            )
+            .is_err()
+        );
+        assert!(
+            search_sorted_multiple(
+                &by,
+                &search_values,
+                SearchSortedSide::Left,
+                &[false; 3],
+                &[false]
+            )
+            .is_err()
+        );
+        Ok(())
+    }
+}
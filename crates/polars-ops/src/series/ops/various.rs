@@ -139,6 +139,102 @@ pub trait SeriesMethods: SeriesSealed {
         };
         Ok(cmp_op(&s1, &s2)?.all())
     }
+
+    /// Returns the index of the first value that is out of order with respect to the values
+    /// before it, or `None` if the [`Series`] is sorted.
+    fn check_sorted(&self, options: SortOptions) -> PolarsResult<Option<usize>> {
+        let s = self.as_series();
+
+        // for struct types we row-encode and recurse
+        #[cfg(feature = "dtype-struct")]
+        if matches!(s.dtype(), DataType::Struct(_)) {
+            let encoded = _get_rows_encoded_ca(
+                PlSmallStr::EMPTY,
+                &[s.clone().into()],
+                &[options.descending],
+                &[options.nulls_last],
+                false,
+            )?;
+            return encoded.into_series().check_sorted(options);
+        }
+
+        let s_len = s.len();
+        let null_count = s.null_count();
+        if null_count == s_len {
+            return Ok(None);
+        }
+        // With nulls last the first value after a null is out of order, with nulls first the
+        // first null after a value is.
+        if null_count > 0 {
+            let validity = s.is_not_null();
+            let mut is_valid = validity.into_no_null_iter().enumerate();
+            let out_of_order = if options.nulls_last {
+                is_valid.find(|(_, valid)| !valid);
+                is_valid.find(|(_, valid)| *valid)
+            } else {
+                is_valid.find(|(_, valid)| *valid);
+                is_valid.find(|(_, valid)| !valid)
+            };
+            if let Some((idx, _)) = out_of_order {
+                return Ok(Some(idx));
+            }
+        }
+
+        let offset = if options.nulls_last { 0 } else { null_count };
+        let values = s.slice(offset as i64, s_len - null_count);
+        let out_of_order = if values.dtype().is_primitive_numeric() {
+            with_match_physical_numeric_polars_type!(values.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = values.as_ref().as_ref().as_ref();
+                first_unsorted_ca_num(ca, options.descending)
+            })
+        } else {
+            let cmp_len = values.len() - 1;
+            let (s1, s2) = (values.slice(0, cmp_len), values.slice(1, cmp_len));
+            let cmp = if options.descending {
+                s1.gt_eq(&s2)?
+            } else {
+                s1.lt_eq(&s2)?
+            };
+            cmp.iter().position(|v| v == Some(false)).map(|idx| idx + 1)
+        };
+        Ok(out_of_order.map(|idx| idx + offset))
+    }
+
+    /// Set the sorted flag of the [`Series`] after verifying that its values are in `order`.
+    ///
+    /// Nulls may either be placed first or last. If `sample` is given, only that many evenly
+    /// spaced values are checked, which is cheaper but may miss values that are out of order.
+    fn set_sorted_checked(&mut self, order: IsSorted, sample: Option<usize>) -> PolarsResult<()> {
+        let s = self.as_series();
+        if order != IsSorted::Not && s.len() > 1 {
+            let nulls_first = s.null_count() > 0 && s.get(0)?.is_null();
+            let options = SortOptions::default()
+                .with_order_descending(order == IsSorted::Descending)
+                .with_nulls_last(!nulls_first);
+
+            let out_of_order = match sample {
+                Some(n) if n < s.len() => {
+                    let n = n.max(2);
+                    let idx = (0..n)
+                        .map(|i| (i * (s.len() - 1) / (n - 1)) as IdxSize)
+                        .collect::<Vec<_>>();
+                    let sampled = s.take_slice(&idx)?;
+                    sampled.check_sorted(options)?.map(|i| idx[i] as usize)
+                },
+                _ => s.check_sorted(options)?,
+            };
+            if let Some(idx) = out_of_order {
+                polars_bail!(
+                    InvalidOperation: "series '{}' is not sorted {}: the value at index {} is out of order",
+                    s.name(),
+                    if options.descending { "descending" } else { "ascending" },
+                    idx
+                );
+            }
+        }
+        self.as_series_mut().set_sorted_flag(order);
+        Ok(())
+    }
 }
 
 fn check_cmp<T: NumericNative, Cmp: Fn(&T, &T) -> bool>(
@@ -164,6 +260,26 @@ fn check_cmp<T: NumericNative, Cmp: Fn(&T, &T) -> bool>(
     sorted
 }
 
+// Assumes there are no nulls.
+fn first_unsorted_ca_num<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    descending: bool,
+) -> Option<usize> {
+    let mut values = ca.into_no_null_iter();
+    let mut previous = values.next()?;
+    values
+        .position(|v| {
+            let out_of_order = if descending {
+                previous.tot_lt(&v)
+            } else {
+                previous.tot_gt(&v)
+            };
+            previous = v;
+            out_of_order
+        })
+        .map(|idx| idx + 1)
+}
+
 // Assumes nulls last/first is already checked.
 fn is_sorted_ca_num<T: PolarsNumericType>(ca: &ChunkedArray<T>, options: SortOptions) -> bool {
     if let Ok(vals) = ca.cont_slice() {
@@ -208,3 +324,38 @@ fn is_sorted_ca_num<T: PolarsNumericType>(ca: &ChunkedArray<T>, options: SortOpt
 }
 
 impl SeriesMethods for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check_sorted() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1), Some(2), Some(2), Some(1), None]);
+        let options = SortOptions::default().with_nulls_last(true);
+        assert_eq!(s.check_sorted(options)?, Some(3));
+        assert_eq!(s.head(Some(3)).check_sorted(options)?, None);
+
+        let s = Series::new("a".into(), [None, Some("c"), None, Some("a")]);
+        let options = SortOptions::default().with_order_descending(true);
+        assert_eq!(s.check_sorted(options)?, Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_sorted_checked() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), [None, Some(3.0), Some(2.0), Some(f64::NAN)]);
+        assert!(s.set_sorted_checked(IsSorted::Descending, None).is_err());
+        assert_eq!(s.is_sorted_flag(), IsSorted::Not);
+
+        // The sampled check skips the value that is out of order.
+        s.set_sorted_checked(IsSorted::Ascending, Some(2))?;
+        assert_eq!(s.is_sorted_flag(), IsSorted::Ascending);
+
+        let mut s = s.sort(SortOptions::default())?;
+        s.set_sorted_flag(IsSorted::Not);
+        s.set_sorted_checked(IsSorted::Ascending, None)?;
+        assert_eq!(s.is_sorted_flag(), IsSorted::Ascending);
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An aggregation of values with weights, see [`weighted_mean`], [`weighted_var`] and
+/// [`weighted_quantile`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum WeightedAggregation {
+    Mean,
+    Var { ddof: u8 },
+    Quantile,
+}
+
+impl Display for WeightedAggregation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Mean => "weighted_mean",
+            Self::Var { .. } => "weighted_var",
+            Self::Quantile => "weighted_quantile",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// The `(value, weight)` pairs where neither is null, with the weights validated.
+fn weighted_pairs(s: &Series, weights: &Series) -> PolarsResult<Vec<(f64, f64)>> {
+    polars_ensure!(
+        s.len() == weights.len(),
+        ShapeMismatch: "`weights` must have the same length as the values ({}), got {}",
+        s.len(), weights.len()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    let weights = weights.cast(&DataType::Float64)?;
+    s.f64()?
+        .iter()
+        .zip(weights.f64()?.iter())
+        .filter_map(|(v, w)| Some((v?, w?)))
+        .map(|(v, w)| {
+            polars_ensure!(
+                w >= 0.0,
+                ComputeError: "weights must be non-negative and not NaN, got {}", w
+            );
+            Ok((v, w))
+        })
+        .collect()
+}
+
+/// Compute the weighted mean `sum(w * x) / sum(w)`.
+///
+/// Pairs where the value or the weight is null are ignored. Returns `None` if the weights of the
+/// remaining pairs sum to zero.
+pub fn weighted_mean(s: &Series, weights: &Series) -> PolarsResult<Option<f64>> {
+    let pairs = weighted_pairs(s, weights)?;
+    let (sum, weight_sum) = pairs.iter().fold((0.0, 0.0), |(sum, weight_sum), (v, w)| {
+        (sum + v * w, weight_sum + w)
+    });
+    Ok((weight_sum > 0.0).then(|| sum / weight_sum))
+}
+
+/// Compute the weighted variance, treating the weights as frequencies.
+///
+/// This is `sum(w * (x - mean)^2) / (sum(w) - ddof)` with `mean` the weighted mean. Pairs where
+/// the value or the weight is null are ignored. Returns `None` if the denominator is not positive.
+pub fn weighted_var(s: &Series, weights: &Series, ddof: u8) -> PolarsResult<Option<f64>> {
+    let pairs = weighted_pairs(s, weights)?;
+    let weight_sum = pairs.iter().map(|(_, w)| w).sum::<f64>();
+    let denom = weight_sum - ddof as f64;
+    if weight_sum <= 0.0 || denom <= 0.0 {
+        return Ok(None);
+    }
+    let mean = pairs.iter().map(|(v, w)| v * w).sum::<f64>() / weight_sum;
+    let sum_sq = pairs
+        .iter()
+        .map(|(v, w)| w * (v - mean) * (v - mean))
+        .sum::<f64>();
+    Ok(Some(sum_sq / denom))
+}
+
+/// Compute the weighted quantile with the inverted cumulative distribution function.
+///
+/// This is the smallest value for which the cumulative weight of all values up to and including
+/// it is at least `quantile` times the total weight. Pairs where the value or the weight is null
+/// are ignored. Returns `None` if the weights of the remaining pairs sum to zero.
+pub fn weighted_quantile(s: &Series, weights: &Series, quantile: f64) -> PolarsResult<Option<f64>> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0, got {}", quantile
+    );
+    let mut pairs = weighted_pairs(s, weights)?;
+    pairs.retain(|(_, w)| *w > 0.0);
+    if pairs.is_empty() {
+        return Ok(None);
+    }
+    pairs.sort_unstable_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let target = quantile * pairs.iter().map(|(_, w)| w).sum::<f64>();
+    let mut cum_weight = 0.0;
+    for (v, w) in &pairs {
+        cum_weight += w;
+        if cum_weight >= target {
+            return Ok(Some(*v));
+        }
+    }
+    // Only reachable through rounding errors in the cumulative sum.
+    Ok(pairs.last().map(|(v, _)| *v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_aggregations() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(1.0), Some(2.0), None, Some(4.0), Some(8.0)],
+        );
+        let w = Series::new("w".into(), [Some(1), Some(2), Some(3), Some(1), None]);
+
+        assert_eq!(weighted_mean(&s, &w)?, Some(9.0 / 4.0));
+        // sum(w * (x - mean)^2) = 1.5625 + 0.125 + 3.0625
+        assert_eq!(weighted_var(&s, &w, 0)?, Some(4.75 / 4.0));
+        assert_eq!(weighted_var(&s, &w, 1)?, Some(4.75 / 3.0));
+        assert_eq!(weighted_var(&s, &w, 4)?, None);
+
+        assert_eq!(weighted_quantile(&s, &w, 0.0)?, Some(1.0));
+        assert_eq!(weighted_quantile(&s, &w, 0.25)?, Some(1.0));
+        assert_eq!(weighted_quantile(&s, &w, 0.5)?, Some(2.0));
+        assert_eq!(weighted_quantile(&s, &w, 0.8)?, Some(4.0));
+        assert_eq!(weighted_quantile(&s, &w, 1.0)?, Some(4.0));
+        assert!(weighted_quantile(&s, &w, 1.5).is_err());
+
+        let zero = Series::new("w".into(), [0, 0, 0, 0, 0]);
+        assert_eq!(weighted_mean(&s, &zero)?, None);
+        assert_eq!(weighted_quantile(&s, &zero, 0.5)?, None);
+
+        let negative = Series::new("w".into(), [1, -1, 1, 1, 1]);
+        assert!(weighted_mean(&s, &negative).is_err());
+        assert!(weighted_mean(&s, &w.slice(0, 2)).is_err());
+        Ok(())
+    }
+}
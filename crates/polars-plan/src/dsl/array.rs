@@ -148,6 +148,28 @@ impl ArrayNameSpace {
         )
     }
 
+    #[cfg(feature = "array_linalg")]
+    /// Compute the dot product of every subarray with the subarray in ``other``.
+    pub fn dot<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ArrayExpr(ArrayFunction::Dot), other.into())
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the Euclidean norm of every subarray.
+    pub fn l2_norm(self) -> Expr {
+        self.0.map_unary(ArrayFunction::L2Norm)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the cosine similarity of every subarray with the subarray in ``other``.
+    pub fn cosine_similarity<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::CosineSimilarity),
+            other.into(),
+        )
+    }
+
     #[cfg(feature = "array_to_struct")]
     pub fn to_struct(self, name_generator: Option<DslNameGenerator>) -> Expr {
         self.0.map_unary(ArrayFunction::ToStruct(name_generator))
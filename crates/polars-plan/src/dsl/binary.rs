@@ -41,16 +41,33 @@ impl BinaryNameSpace {
 
     #[cfg(feature = "binary_encoding")]
     pub fn base64_decode(self, strict: bool) -> Expr {
+        self.base64_decode_with_options(Base64Alphabet::Standard, strict)
+    }
+
+    /// Decode base64 encoded values using the given alphabet. Both padded and unpadded values
+    /// are accepted. Invalid values raise an error if `strict` is set and become null otherwise.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base64_decode_with_options(self, alphabet: Base64Alphabet, strict: bool) -> Expr {
         self.0
-            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Decode(
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Decode {
+                alphabet,
                 strict,
-            )))
+            }))
     }
 
     #[cfg(feature = "binary_encoding")]
     pub fn base64_encode(self) -> Expr {
+        self.base64_encode_with_options(Base64Alphabet::Standard, true)
+    }
+
+    /// Base64 encode the values using the given alphabet, optionally without `=` padding.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base64_encode_with_options(self, alphabet: Base64Alphabet, padding: bool) -> Expr {
         self.0
-            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode {
+                alphabet,
+                padding,
+            }))
     }
 
     #[cfg(feature = "binary_encoding")]
@@ -86,4 +103,28 @@ impl BinaryNameSpace {
             index,
         )
     }
+
+    /// Check whether the IP addresses produced by `str.parse_ip` lie within any of the
+    /// given subnets.
+    #[cfg(feature = "ip_address")]
+    pub fn ip_is_in_subnet(self, subnets: Arc<[IpSubnet]>) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::IpIsInSubnet(
+                subnets,
+            )))
+    }
+
+    /// Convert the IP addresses produced by `str.parse_ip` to their integer value.
+    #[cfg(feature = "ip_address")]
+    pub fn ip_to_int(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::IpToInt))
+    }
+
+    /// Format the UUIDs produced by `str.parse_uuid` in their canonical hyphenated form.
+    #[cfg(feature = "uuid")]
+    pub fn to_uuid_str(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::ToUuidStr))
+    }
 }
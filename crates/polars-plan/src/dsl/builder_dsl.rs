@@ -423,6 +423,7 @@ impl DslBuilder {
         self,
         function: PythonFunction,
         optimizations: AllowedOptimizations,
+        flags: UdfFlags,
         schema: Option<SchemaRef>,
         validate_output: bool,
     ) -> Self {
@@ -435,6 +436,7 @@ impl DslBuilder {
                 projection_pd: optimizations.contains(OptFlags::PROJECTION_PUSHDOWN),
                 streamable: optimizations.contains(OptFlags::NEW_STREAMING),
                 validate_output,
+                flags,
             }),
         }
         .into()
@@ -444,6 +446,7 @@ impl DslBuilder {
         self,
         function: F,
         optimizations: AllowedOptimizations,
+        flags: UdfFlags,
         schema: Option<Arc<dyn UdfSchema>>,
         name: PlSmallStr,
     ) -> Self
@@ -460,6 +463,7 @@ impl DslBuilder {
                 predicate_pd: optimizations.contains(OptFlags::PREDICATE_PUSHDOWN),
                 projection_pd: optimizations.contains(OptFlags::PROJECTION_PUSHDOWN),
                 streamable: optimizations.contains(OptFlags::NEW_STREAMING),
+                flags,
                 fmt_str: name,
             }),
         }
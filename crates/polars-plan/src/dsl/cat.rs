@@ -8,6 +8,21 @@ impl CategoricalNameSpace {
         self.0.map_unary(CategoricalFunction::GetCategories)
     }
 
+    /// Add categories to an Enum or Categorical without recoding the existing values.
+    ///
+    /// For an Enum the new categories are appended after the existing ones.
+    pub fn add_categories(self, categories: Vec<PlSmallStr>) -> Expr {
+        self.0
+            .map_unary(CategoricalFunction::AddCategories(categories))
+    }
+
+    /// Add the categories of `other`, an Enum or Categorical expression, without recoding the
+    /// existing values.
+    pub fn union_categories(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(CategoricalFunction::UnionCategories, other)
+    }
+
     #[cfg(feature = "strings")]
     pub fn len_bytes(self) -> Expr {
         self.0.map_unary(CategoricalFunction::LenBytes)
@@ -121,14 +121,41 @@ impl DateLikeNameSpace {
             .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::Quarter))
     }
 
+    /// Get the fiscal year of a Date/Datetime for fiscal years starting in `start_month`.
+    /// The fiscal year is named after the calendar year in which it ends.
+    pub fn fiscal_year(self, start_month: i8) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalYear(
+                start_month,
+            )))
+    }
+
+    /// Get the quarter of the fiscal year of a Date/Datetime for fiscal years starting in
+    /// `start_month`. Quarters range from 1 to 4.
+    pub fn fiscal_quarter(self, start_month: i8) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalQuarter(
+                start_month,
+            )))
+    }
+
     /// Extract the week from the underlying Date representation.
     /// Can be performed on Date and Datetime
     ///
     /// Returns the ISO week number starting from 1.
     /// The return value ranges from 1 to 53. (The last week of year differs by years.)
     pub fn week(self) -> Expr {
+        self.week_with_convention(WeekConvention::Iso)
+    }
+
+    /// Extract the week from the underlying Date representation, numbered according to the
+    /// given convention.
+    /// Can be performed on Date and Datetime.
+    pub fn week_with_convention(self, convention: WeekConvention) -> Expr {
         self.0
-            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::Week))
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::Week(
+                convention,
+            )))
     }
 
     /// Extract the ISO week day from the underlying Date representation.
@@ -50,7 +50,10 @@ impl Serialize for SpecialEq<Arc<dyn AnonymousAgg>> {
     where
         S: serde::Serializer,
     {
-        unreachable!("should not be hit")
+        use serde::ser::Error;
+        Err(S::Error::custom(
+            "serialization not supported for this 'opaque' aggregation",
+        ))
     }
 }
 
@@ -189,6 +189,16 @@ pub enum Expr {
         #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
         fmt_str: Box<PlSmallStr>,
     },
+    /// An aggregation that is computed by an opaque reduction.
+    AnonymousAgg {
+        /// aggregation arguments
+        input: Vec<Expr>,
+        /// reduction to aggregate with
+        function: OpaqueStreamingAgg,
+        /// used for formatting
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+        fmt_str: Box<PlSmallStr>,
+    },
     /// Evaluates the `evaluation` expression on the output of the `expr`.
     ///
     /// Consequently, `expr` is an input and `evaluation` is not and needs a different schema.
@@ -424,6 +434,14 @@ impl Hash for Expr {
                 options.hash(state);
                 fmt_str.hash(state);
             },
+            Expr::AnonymousAgg {
+                input,
+                function: _,
+                fmt_str,
+            } => {
+                input.hash(state);
+                fmt_str.hash(state);
+            },
             Expr::Eval {
                 expr: input,
                 evaluation,
@@ -243,6 +243,11 @@ impl fmt::Debug for Expr {
                     _ => write!(f, "{:?}.{name}({:?})", input[0], &input[1..]),
                 }
             },
+            AnonymousAgg { input, fmt_str, .. } => match input.len() {
+                0 => write!(f, "{fmt_str}()"),
+                1 => write!(f, "{:?}.{fmt_str}()", input[0]),
+                _ => write!(f, "{:?}.{fmt_str}({:?})", input[0], &input[1..]),
+            },
             Eval {
                 expr: input,
                 evaluation,
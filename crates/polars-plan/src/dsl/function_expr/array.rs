@@ -36,6 +36,12 @@ pub enum ArrayFunction {
     },
     #[cfg(feature = "array_count")]
     CountMatches,
+    #[cfg(feature = "array_linalg")]
+    Dot,
+    #[cfg(feature = "array_linalg")]
+    L2Norm,
+    #[cfg(feature = "array_linalg")]
+    CosineSimilarity,
     Shift,
     Explode(ExplodeOptions),
     Concat,
@@ -74,6 +80,12 @@ impl fmt::Display for ArrayFunction {
             Contains { nulls_equal: _ } => "contains",
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
+            #[cfg(feature = "array_linalg")]
+            Dot => "dot",
+            #[cfg(feature = "array_linalg")]
+            L2Norm => "l2_norm",
+            #[cfg(feature = "array_linalg")]
+            CosineSimilarity => "cosine_similarity",
             Shift => "shift",
             Explode { .. } => "explode",
             #[cfg(feature = "array_to_struct")]
@@ -15,9 +15,15 @@ pub enum BinaryFunction {
     #[cfg(feature = "binary_encoding")]
     HexEncode,
     #[cfg(feature = "binary_encoding")]
-    Base64Decode(bool),
+    Base64Decode {
+        alphabet: Base64Alphabet,
+        strict: bool,
+    },
     #[cfg(feature = "binary_encoding")]
-    Base64Encode,
+    Base64Encode {
+        alphabet: Base64Alphabet,
+        padding: bool,
+    },
     Size,
     #[cfg(feature = "binary_encoding")]
     /// The parameters are destination type, and whether to use little endian
@@ -27,6 +33,12 @@ pub enum BinaryFunction {
     Head,
     Tail,
     Get(bool),
+    #[cfg(feature = "ip_address")]
+    IpIsInSubnet(Arc<[IpSubnet]>),
+    #[cfg(feature = "ip_address")]
+    IpToInt,
+    #[cfg(feature = "uuid")]
+    ToUuidStr,
 }
 
 impl Display for BinaryFunction {
@@ -41,9 +53,9 @@ impl Display for BinaryFunction {
             #[cfg(feature = "binary_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
-            Base64Decode(_) => "base64_decode",
+            Base64Decode { .. } => "base64_decode",
             #[cfg(feature = "binary_encoding")]
-            Base64Encode => "base64_encode",
+            Base64Encode { .. } => "base64_encode",
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
@@ -51,6 +63,12 @@ impl Display for BinaryFunction {
             Head => "head",
             Tail => "tail",
             Get(_) => "get",
+            #[cfg(feature = "ip_address")]
+            IpIsInSubnet(_) => "ip_is_in_subnet",
+            #[cfg(feature = "ip_address")]
+            IpToInt => "ip_to_int",
+            #[cfg(feature = "uuid")]
+            ToUuidStr => "to_uuid_str",
         };
         write!(f, "bin.{s}")
     }
@@ -5,6 +5,8 @@ use super::*;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum CategoricalFunction {
     GetCategories,
+    AddCategories(Vec<PlSmallStr>),
+    UnionCategories,
     #[cfg(feature = "strings")]
     LenBytes,
     #[cfg(feature = "strings")]
@@ -22,6 +24,8 @@ impl Display for CategoricalFunction {
         use CategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            AddCategories(_) => "add_categories",
+            UnionCategories => "union_categories",
             #[cfg(feature = "strings")]
             LenBytes => "len_bytes",
             #[cfg(feature = "strings")]
@@ -9,10 +9,12 @@ pub enum TemporalFunction {
     Year,
     IsLeapYear,
     IsoYear,
+    FiscalYear(i8),
     Quarter,
+    FiscalQuarter(i8),
     Month,
     DaysInMonth,
-    Week,
+    Week(WeekConvention),
     WeekDay,
     Day,
     OrdinalDay,
@@ -92,10 +94,12 @@ impl Display for TemporalFunction {
             Year => "year",
             IsLeapYear => "is_leap_year",
             IsoYear => "iso_year",
+            FiscalYear(_) => "fiscal_year",
             Quarter => "quarter",
+            FiscalQuarter(_) => "fiscal_quarter",
             Month => "month",
             DaysInMonth => "days_in_month",
-            Week => "week",
+            Week(_) => "week",
             WeekDay => "weekday",
             Day => "day",
             OrdinalDay => "ordinal_day",
@@ -27,6 +27,10 @@ pub enum ListFunction {
     GatherEvery,
     #[cfg(feature = "list_count")]
     CountMatches,
+    #[cfg(feature = "index_of")]
+    IndexOf,
+    #[cfg(feature = "index_of")]
+    Find,
     Sum,
     Length,
     Max,
@@ -42,12 +46,27 @@ pub enum ListFunction {
         n: i64,
         null_behavior: NullBehavior,
     },
+    #[cfg(feature = "cum_agg")]
+    CumAgg {
+        function: ListCumFunction,
+        reverse: bool,
+    },
+    #[cfg(feature = "rolling_window")]
+    Rolling {
+        function: ListRollingFunction,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    },
     Sort(SortOptions),
     Reverse,
     Unique(bool),
     NUnique,
     #[cfg(feature = "list_sets")]
-    SetOperation(SetOperation),
+    SetOperation {
+        op: SetOperation,
+        multiset: bool,
+    },
     #[cfg(feature = "list_any_all")]
     Any,
     #[cfg(feature = "list_any_all")]
@@ -86,6 +105,10 @@ impl Display for ListFunction {
             GatherEvery => "gather_every",
             #[cfg(feature = "list_count")]
             CountMatches => "count_matches",
+            #[cfg(feature = "index_of")]
+            IndexOf => "index_of",
+            #[cfg(feature = "index_of")]
+            Find => "find",
             Sum => "sum",
             Min => "min",
             Max => "max",
@@ -97,6 +120,10 @@ impl Display for ListFunction {
             ArgMax => "arg_max",
             #[cfg(feature = "diff")]
             Diff { .. } => "diff",
+            #[cfg(feature = "cum_agg")]
+            CumAgg { function, .. } => return write!(f, "list.{function}"),
+            #[cfg(feature = "rolling_window")]
+            Rolling { function, .. } => return write!(f, "list.{function}"),
             Length => "length",
             Sort(_) => "sort",
             Reverse => "reverse",
@@ -109,7 +136,13 @@ impl Display for ListFunction {
             },
             NUnique => "n_unique",
             #[cfg(feature = "list_sets")]
-            SetOperation(s) => return write!(f, "list.{s}"),
+            SetOperation { op, multiset } => {
+                return if *multiset {
+                    write!(f, "list.multiset_{op}")
+                } else {
+                    write!(f, "list.{op}")
+                };
+            },
             #[cfg(feature = "list_any_all")]
             Any => "any",
             #[cfg(feature = "list_any_all")]
@@ -112,6 +112,17 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    /// Hash with version [`STABLE_HASH_VERSION`](polars_core::hashing::stable::STABLE_HASH_VERSION)
+    /// of the stable hash algorithm.
+    #[cfg(feature = "row_hash")]
+    StableHash {
+        seed: u64,
+    },
+    #[cfg(feature = "hash_digest")]
+    HashDigest {
+        algorithm: HashAlgorithm,
+        hex: bool,
+    },
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -121,6 +132,12 @@ pub enum FunctionExpr {
         side: SearchSortedSide,
         descending: bool,
     },
+    #[cfg(feature = "search_sorted")]
+    SearchSortedBy {
+        side: SearchSortedSide,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    },
     #[cfg(feature = "range")]
     Range(RangeFunction),
     #[cfg(feature = "trigonometry")]
@@ -152,7 +169,10 @@ pub enum FunctionExpr {
     #[cfg(feature = "mode")]
     Mode {
         maintain_order: bool,
+        ties: ModeTies,
     },
+    #[cfg(feature = "mode")]
+    Modes(IdxSize),
     #[cfg(feature = "moment")]
     Skew(bool),
     #[cfg(feature = "moment")]
@@ -212,6 +232,11 @@ pub enum FunctionExpr {
     CumMax {
         reverse: bool,
     },
+    #[cfg(feature = "cum_agg")]
+    CumAggReset {
+        function: ListCumFunction,
+        reverse: bool,
+    },
     Reverse,
     #[cfg(feature = "dtype-struct")]
     ValueCounts {
@@ -223,7 +248,16 @@ pub enum FunctionExpr {
     #[cfg(feature = "unique_counts")]
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
-    ApproxNUnique,
+    ApproxNUnique {
+        precision: Option<u8>,
+    },
+    #[cfg(feature = "approx_unique")]
+    Hll(HllFunction),
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile {
+        quantile: f64,
+        compression: f64,
+    },
     Coalesce,
     #[cfg(feature = "diff")]
     Diff(NullBehavior),
@@ -232,7 +266,7 @@ pub enum FunctionExpr {
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolateByMethod),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -262,6 +296,15 @@ pub enum FunctionExpr {
     Floor,
     #[cfg(feature = "round_series")]
     Ceil,
+    #[cfg(feature = "decimal_arithmetic")]
+    DecimalArithmetic {
+        op: DecimalArithmeticOp,
+        options: DecimalArithmeticOptions,
+    },
+    CheckedArithmetic {
+        op: CheckedArithmeticOp,
+        warn: bool,
+    },
     UpperBound,
     LowerBound,
     ConcatExpr(bool),
@@ -269,6 +312,8 @@ pub enum FunctionExpr {
     Correlation {
         method: correlation::CorrelationMethod,
     },
+    #[cfg(feature = "weighted_agg")]
+    Weighted(WeightedAggregation),
     #[cfg(feature = "peaks")]
     PeakMin,
     #[cfg(feature = "peaks")]
@@ -279,6 +324,7 @@ pub enum FunctionExpr {
         labels: Option<Vec<PlSmallStr>>,
         left_closed: bool,
         include_breaks: bool,
+        include_bins: bool,
     },
     #[cfg(feature = "cutqcut")]
     QCut {
@@ -287,6 +333,7 @@ pub enum FunctionExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bins: bool,
     },
     #[cfg(feature = "rle")]
     RLE,
@@ -414,10 +461,22 @@ impl Hash for FunctionExpr {
                 side.hash(state);
                 descending.hash(state);
             },
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy {
+                side,
+                descending,
+                nulls_last,
+            } => {
+                side.hash(state);
+                descending.hash(state);
+                nulls_last.hash(state);
+            },
             #[cfg(feature = "random")]
             Random { method, .. } => method.hash(state),
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
+            #[cfg(feature = "weighted_agg")]
+            Weighted(agg) => agg.hash(state),
             #[cfg(feature = "range")]
             Range(f) => f.hash(state),
             #[cfg(feature = "trigonometry")]
@@ -427,7 +486,7 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -490,7 +549,15 @@ impl Hash for FunctionExpr {
                 nulls_last.hash(state);
             },
             #[cfg(feature = "mode")]
-            Mode { maintain_order } => maintain_order.hash(state),
+            Mode {
+                maintain_order,
+                ties,
+            } => {
+                maintain_order.hash(state);
+                ties.hash(state);
+            },
+            #[cfg(feature = "mode")]
+            Modes(n) => n.hash(state),
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
@@ -505,6 +572,13 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "row_hash")]
+            StableHash { seed } => seed.hash(state),
+            #[cfg(feature = "hash_digest")]
+            HashDigest { algorithm, hex } => {
+                algorithm.hash(state);
+                hex.hash(state);
+            },
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
@@ -549,6 +623,11 @@ impl Hash for FunctionExpr {
             CumMin { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
             CumMax { reverse } => reverse.hash(state),
+            #[cfg(feature = "cum_agg")]
+            CumAggReset { function, reverse } => {
+                function.hash(state);
+                reverse.hash(state);
+            },
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort,
@@ -564,7 +643,17 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => {},
+            ApproxNUnique { precision } => precision.hash(state),
+            #[cfg(feature = "approx_unique")]
+            Hll(f) => f.hash(state),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile {
+                quantile,
+                compression,
+            } => {
+                quantile.to_bits().hash(state);
+                compression.to_bits().hash(state);
+            },
             Coalesce => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
@@ -593,6 +682,15 @@ impl Hash for FunctionExpr {
             FunctionExpr::Floor => {},
             #[cfg(feature = "round_series")]
             Ceil => {},
+            #[cfg(feature = "decimal_arithmetic")]
+            DecimalArithmetic { op, options } => {
+                op.hash(state);
+                options.hash(state);
+            },
+            CheckedArithmetic { op, warn } => {
+                op.hash(state);
+                warn.hash(state);
+            },
             UpperBound => {},
             LowerBound => {},
             ConcatExpr(a) => a.hash(state),
@@ -606,12 +704,14 @@ impl Hash for FunctionExpr {
                 labels,
                 left_closed,
                 include_breaks,
+                include_bins,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(breaks);
                 slice.hash(state);
                 labels.hash(state);
                 left_closed.hash(state);
                 include_breaks.hash(state);
+                include_bins.hash(state);
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
@@ -624,6 +724,7 @@ impl Hash for FunctionExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                include_bins,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
@@ -631,6 +732,7 @@ impl Hash for FunctionExpr {
                 left_closed.hash(state);
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
+                include_bins.hash(state);
             },
             #[cfg(feature = "rle")]
             RLE => {},
@@ -714,12 +816,18 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            StableHash { .. } => "stable_hash",
+            #[cfg(feature = "hash_digest")]
+            HashDigest { algorithm, .. } => return write!(f, "hash_{}", algorithm.name()),
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
             IndexOf => "index_of",
             #[cfg(feature = "search_sorted")]
             SearchSorted { .. } => "search_sorted",
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy { .. } => "search_sorted_by",
             #[cfg(feature = "range")]
             Range(func) => return write!(f, "{func}"),
             #[cfg(feature = "trigonometry")]
@@ -739,13 +847,15 @@ impl Display for FunctionExpr {
             DropNans => "drop_nans",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
-            Mode { maintain_order } => {
+            Mode { maintain_order, .. } => {
                 if *maintain_order {
                     "mode_stable"
                 } else {
                     "mode"
                 }
             },
+            #[cfg(feature = "mode")]
+            Modes(_) => "modes",
             #[cfg(feature = "moment")]
             Skew(_) => "skew",
             #[cfg(feature = "moment")]
@@ -790,13 +900,19 @@ impl Display for FunctionExpr {
             CumMin { .. } => "cum_min",
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => "cum_max",
+            #[cfg(feature = "cum_agg")]
+            CumAggReset { function, .. } => return write!(f, "{function}_reset"),
             #[cfg(feature = "dtype-struct")]
             ValueCounts { .. } => "value_counts",
             #[cfg(feature = "unique_counts")]
             UniqueCounts => "unique_counts",
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => "approx_n_unique",
+            ApproxNUnique { .. } => "approx_n_unique",
+            #[cfg(feature = "approx_unique")]
+            Hll(hll) => return Display::fmt(hll, f),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => "approx_quantile",
             Coalesce => "coalesce",
             #[cfg(feature = "diff")]
             Diff(_) => "diff",
@@ -805,7 +921,7 @@ impl Display for FunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -831,11 +947,16 @@ impl Display for FunctionExpr {
             Floor => "floor",
             #[cfg(feature = "round_series")]
             Ceil => "ceil",
+            #[cfg(feature = "decimal_arithmetic")]
+            DecimalArithmetic { op, .. } => return write!(f, "decimal_{}", <&str>::from(op)),
+            CheckedArithmetic { op, .. } => return write!(f, "checked_{}", <&str>::from(op)),
             UpperBound => "upper_bound",
             LowerBound => "lower_bound",
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
+            #[cfg(feature = "weighted_agg")]
+            Weighted(agg) => return Display::fmt(agg, f),
             #[cfg(feature = "peaks")]
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
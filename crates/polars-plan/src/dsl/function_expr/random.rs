@@ -15,6 +15,8 @@ pub enum RandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    #[cfg(feature = "uuid")]
+    Uuid4,
 }
 
 impl Hash for RandomMethod {
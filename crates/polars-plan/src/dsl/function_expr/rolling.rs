@@ -23,6 +23,12 @@ pub enum RollingFunction {
         is_corr: bool,
     },
     Map(PlanCallback<Series, Series>),
+    /// Map over batches of windows at once.
+    #[cfg(feature = "dtype-array")]
+    MapBatches {
+        function: PlanCallback<Series, Series>,
+        batch_size: usize,
+    },
 }
 
 impl Display for RollingFunction {
@@ -51,6 +57,8 @@ impl Display for RollingFunction {
                 }
             },
             Map(_) => "map",
+            #[cfg(feature = "dtype-array")]
+            MapBatches { .. } => "map_batches",
         };
 
         write!(f, "rolling_{name}")
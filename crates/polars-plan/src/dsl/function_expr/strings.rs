@@ -48,10 +48,18 @@ pub enum StringFunction {
     LenBytes,
     LenChars,
     Lowercase,
+    Casefold,
     #[cfg(feature = "extract_jsonpath")]
     JsonDecode(DataTypeExpr),
     #[cfg(feature = "extract_jsonpath")]
     JsonPathMatch,
+    #[cfg(feature = "extract_jsonpath")]
+    JsonPathMatchAll(Option<DataTypeExpr>),
+    #[cfg(feature = "extract_jsonpath")]
+    JsonExtractMany {
+        paths: Arc<[PlSmallStr]>,
+        dtype: Option<DataTypeExpr>,
+    },
     #[cfg(feature = "regex")]
     Replace {
         // negative is replace all
@@ -65,6 +73,26 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    LevenshteinDistance,
+    #[cfg(feature = "string_similarity")]
+    HammingDistance,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_tokenize")]
+    Tokenize {
+        method: TokenizeMethod,
+        lowercase: bool,
+        stop_words: Arc<[PlSmallStr]>,
+    },
+    #[cfg(feature = "string_url")]
+    UrlPart(UrlPart),
+    /// Decode the URL query into a struct with a field per key, or into a list of key/value
+    /// structs if no keys are given.
+    #[cfg(feature = "string_url")]
+    UrlQueryParams(Option<Arc<[PlSmallStr]>>),
+    #[cfg(feature = "string_url")]
+    UrlDecodePercent,
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -81,9 +109,23 @@ pub enum StringFunction {
     #[cfg(feature = "binary_encoding")]
     HexDecode(bool),
     #[cfg(feature = "string_encoding")]
-    Base64Encode,
+    Base64Encode {
+        alphabet: Base64Alphabet,
+        padding: bool,
+    },
     #[cfg(feature = "binary_encoding")]
-    Base64Decode(bool),
+    Base64Decode {
+        alphabet: Base64Alphabet,
+        strict: bool,
+    },
+    #[cfg(feature = "ip_address")]
+    ParseIp {
+        strict: bool,
+    },
+    #[cfg(feature = "uuid")]
+    ParseUuid {
+        strict: bool,
+    },
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -99,9 +141,12 @@ pub enum StringFunction {
     SplitN(usize),
     #[cfg(feature = "temporal")]
     Strptime(DataTypeExpr, StrptimeOptions),
+    #[cfg(feature = "temporal")]
+    StrptimeMulti(DataTypeExpr, StrptimeMultiOptions),
     Split(bool),
     #[cfg(feature = "regex")]
     SplitRegex {
+        n: Option<usize>,
         inclusive: bool,
         strict: bool,
     },
@@ -166,8 +211,13 @@ impl Display for StringFunction {
             JsonDecode { .. } => "json_decode",
             #[cfg(feature = "extract_jsonpath")]
             JsonPathMatch => "json_path_match",
+            #[cfg(feature = "extract_jsonpath")]
+            JsonPathMatchAll(_) => "json_path_match_all",
+            #[cfg(feature = "extract_jsonpath")]
+            JsonExtractMany { .. } => "json_extract_many",
             LenBytes => "len_bytes",
             Lowercase => "to_lowercase",
+            Casefold => "casefold",
             LenChars => "len_chars",
             #[cfg(feature = "string_pad")]
             PadEnd { .. } => "pad_end",
@@ -179,14 +229,32 @@ impl Display for StringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance => "levenshtein_distance",
+            #[cfg(feature = "string_similarity")]
+            HammingDistance => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_tokenize")]
+            Tokenize { .. } => "tokenize",
+            #[cfg(feature = "string_url")]
+            UrlPart(part) => return write!(f, "str.url.{}", part.name()),
+            #[cfg(feature = "string_url")]
+            UrlQueryParams(_) => "url.query_params",
+            #[cfg(feature = "string_url")]
+            UrlDecodePercent => "url.decode_percent",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) => "hex_decode",
             #[cfg(feature = "string_encoding")]
-            Base64Encode => "base64_encode",
+            Base64Encode { .. } => "base64_encode",
             #[cfg(feature = "binary_encoding")]
-            Base64Decode(_) => "base64_decode",
+            Base64Decode { .. } => "base64_decode",
+            #[cfg(feature = "ip_address")]
+            ParseIp { .. } => "parse_ip",
+            #[cfg(feature = "uuid")]
+            ParseUuid { .. } => "parse_uuid",
             Slice => "slice",
             StartsWith => "starts_with",
             StripChars => "strip_chars",
@@ -206,6 +274,8 @@ impl Display for StringFunction {
             SplitN(_) => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "temporal")]
+            StrptimeMulti(_, _) => "strptime_multi",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
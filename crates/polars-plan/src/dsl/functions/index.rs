@@ -19,3 +19,33 @@ pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -
 pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
     condition.into().map_unary(FunctionExpr::ArgWhere)
 }
+
+/// Find the indices where the rows of `search_values` should be inserted into the rows of the
+/// sorted `by` columns to maintain order, comparing the columns lexicographically.
+///
+/// The rows of `by` must be sorted with the given `descending` and `nulls_last` flags, which are
+/// given per column or as a single flag for all columns. `search_values` must have a column for
+/// every column in `by`.
+#[cfg(feature = "search_sorted")]
+pub fn search_sorted_by<E: AsRef<[Expr]>, V: AsRef<[Expr]>>(
+    by: E,
+    search_values: V,
+    side: SearchSortedSide,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+) -> Expr {
+    let input = by
+        .as_ref()
+        .iter()
+        .chain(search_values.as_ref())
+        .cloned()
+        .collect();
+    Expr::n_ary(
+        FunctionExpr::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        },
+        input,
+    )
+}
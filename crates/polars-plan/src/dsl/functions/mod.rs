@@ -9,7 +9,7 @@ mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
 pub(crate) mod horizontal;
-#[cfg(any(feature = "range", feature = "arg_where"))]
+#[cfg(any(feature = "range", feature = "arg_where", feature = "search_sorted"))]
 mod index;
 #[cfg(feature = "range")]
 mod range;
@@ -18,6 +18,8 @@ mod selectors;
 mod syntactic_sugar;
 #[cfg(feature = "temporal")]
 mod temporal;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 pub use arity::*;
 #[cfg(all(feature = "business", feature = "dtype-date"))]
@@ -33,7 +35,7 @@ pub use horizontal::{
 };
 #[cfg(feature = "dtype-struct")]
 pub use horizontal::{cum_fold_exprs, cum_reduce_exprs};
-#[cfg(any(feature = "range", feature = "arg_where"))]
+#[cfg(any(feature = "range", feature = "arg_where", feature = "search_sorted"))]
 pub use index::*;
 #[cfg(all(
     feature = "range",
@@ -50,6 +52,8 @@ pub use syntactic_sugar::*;
 #[cfg(feature = "temporal")]
 pub use temporal::*;
 
+#[cfg(feature = "uuid")]
+pub use self::uuid::*;
 #[cfg(feature = "arg_where")]
 use crate::dsl::function_expr::FunctionExpr;
 use crate::dsl::function_expr::ListFunction;
@@ -0,0 +1,13 @@
+use super::*;
+
+/// Generate a random (version 4) UUID for every row, in the canonical lowercase hyphenated form.
+///
+/// The output is deterministic if a `seed` is given.
+pub fn uuid4(seed: Option<u64>) -> Expr {
+    len()
+        .map_unary(FunctionExpr::Random {
+            method: RandomMethod::Uuid4,
+            seed,
+        })
+        .alias(PlSmallStr::from_static("uuid"))
+}
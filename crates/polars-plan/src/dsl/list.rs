@@ -204,6 +204,67 @@ impl ListNameSpace {
         }))
     }
 
+    /// Compute a cumulative aggregation within every sublist.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_agg(self, function: ListCumFunction, reverse: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::CumAgg {
+                function,
+                reverse,
+            }))
+    }
+
+    /// Get the cumulative sum within every sublist.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_sum(self, reverse: bool) -> Expr {
+        self.cum_agg(ListCumFunction::Sum, reverse)
+    }
+
+    /// Get the cumulative product within every sublist.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_prod(self, reverse: bool) -> Expr {
+        self.cum_agg(ListCumFunction::Prod, reverse)
+    }
+
+    /// Get the cumulative minimum within every sublist.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_min(self, reverse: bool) -> Expr {
+        self.cum_agg(ListCumFunction::Min, reverse)
+    }
+
+    /// Get the cumulative maximum within every sublist.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_max(self, reverse: bool) -> Expr {
+        self.cum_agg(ListCumFunction::Max, reverse)
+    }
+
+    /// Get the cumulative count of non-null values within every sublist.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_count(self, reverse: bool) -> Expr {
+        self.cum_agg(ListCumFunction::Count, reverse)
+    }
+
+    /// Apply a rolling aggregation over a window of `window_size` elements within
+    /// every sublist. Every output sublist has the same length as its input.
+    ///
+    /// If `min_periods` is `None` it is set to `window_size`.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling(
+        self,
+        function: ListRollingFunction,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::Rolling {
+                function,
+                window_size,
+                min_periods: min_periods.unwrap_or(window_size),
+                center,
+            }))
+    }
+
     /// Shift every sublist.
     pub fn shift(self, periods: Expr) -> Expr {
         self.0
@@ -266,10 +327,25 @@ impl ListNameSpace {
         )
     }
 
+    #[cfg(feature = "index_of")]
+    /// Find the index of the first occurrence of ``value`` in every list, or null if absent.
+    pub fn index_of<E: Into<Expr>>(self, value: E) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ListExpr(ListFunction::IndexOf), value.into())
+    }
+
+    #[cfg(feature = "index_of")]
+    /// Find the index of the first element in every list for which `predicate` is true, or null
+    /// if there is none. The predicate is evaluated as in [`ListNameSpace::eval`].
+    pub fn find<E: Into<Expr>>(self, predicate: E) -> Expr {
+        self.eval(predicate)
+            .map_unary(FunctionExpr::ListExpr(ListFunction::Find))
+    }
+
     #[cfg(feature = "list_sets")]
-    fn set_operation(self, other: Expr, set_operation: SetOperation) -> Expr {
+    fn set_operation(self, other: Expr, op: SetOperation, multiset: bool) -> Expr {
         self.0.map_binary(
-            FunctionExpr::ListExpr(ListFunction::SetOperation(set_operation)),
+            FunctionExpr::ListExpr(ListFunction::SetOperation { op, multiset }),
             other,
         )
     }
@@ -277,25 +353,55 @@ impl ListNameSpace {
     /// Return the SET UNION between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
-        self.set_operation(other.into(), SetOperation::Union)
+        self.set_operation(other.into(), SetOperation::Union, false)
     }
 
     /// Return the SET DIFFERENCE between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn set_difference<E: Into<Expr>>(self, other: E) -> Expr {
-        self.set_operation(other.into(), SetOperation::Difference)
+        self.set_operation(other.into(), SetOperation::Difference, false)
     }
 
     /// Return the SET INTERSECTION between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn set_intersection<E: Into<Expr>>(self, other: E) -> Expr {
-        self.set_operation(other.into(), SetOperation::Intersection)
+        self.set_operation(other.into(), SetOperation::Intersection, false)
     }
 
     /// Return the SET SYMMETRIC DIFFERENCE between both list arrays.
     #[cfg(feature = "list_sets")]
     pub fn set_symmetric_difference<E: Into<Expr>>(self, other: E) -> Expr {
-        self.set_operation(other.into(), SetOperation::SymmetricDifference)
+        self.set_operation(other.into(), SetOperation::SymmetricDifference, false)
+    }
+
+    /// Return the MULTISET UNION between both list arrays.
+    ///
+    /// Duplicates are kept: a value occurs as often as in the operand where it occurs most.
+    #[cfg(feature = "list_sets")]
+    pub fn multiset_union<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Union, true)
+    }
+
+    /// Return the MULTISET DIFFERENCE between both list arrays.
+    ///
+    /// Every occurrence in `other` removes a single occurrence from the left list.
+    #[cfg(feature = "list_sets")]
+    pub fn multiset_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Difference, true)
+    }
+
+    /// Return the MULTISET INTERSECTION between both list arrays.
+    ///
+    /// A value occurs as often as in the operand where it occurs least.
+    #[cfg(feature = "list_sets")]
+    pub fn multiset_intersection<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Intersection, true)
+    }
+
+    /// Return the MULTISET SYMMETRIC DIFFERENCE between both list arrays.
+    #[cfg(feature = "list_sets")]
+    pub fn multiset_symmetric_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::SymmetricDifference, true)
     }
 
     pub fn eval<E: Into<Expr>>(self, other: E) -> Expr {
@@ -216,6 +216,40 @@ impl Expr {
         .into()
     }
 
+    #[cfg(feature = "weighted_agg")]
+    /// Compute the weighted mean per group.
+    ///
+    /// Values for which the value or the weight is null are ignored.
+    pub fn weighted_mean<E: Into<Expr>>(self, weights: E) -> Self {
+        self.map_binary(
+            FunctionExpr::Weighted(WeightedAggregation::Mean),
+            weights.into(),
+        )
+    }
+
+    #[cfg(feature = "weighted_agg")]
+    /// Compute the weighted variance per group, treating the weights as frequencies.
+    ///
+    /// Values for which the value or the weight is null are ignored.
+    pub fn weighted_var<E: Into<Expr>>(self, weights: E, ddof: u8) -> Self {
+        self.map_binary(
+            FunctionExpr::Weighted(WeightedAggregation::Var { ddof }),
+            weights.into(),
+        )
+    }
+
+    #[cfg(feature = "weighted_agg")]
+    /// Compute the weighted quantile per group.
+    ///
+    /// Values for which the value or the weight is null are ignored.
+    pub fn weighted_quantile<E: Into<Expr>>(self, weights: E, quantile: Expr) -> Self {
+        self.map_ternary(
+            FunctionExpr::Weighted(WeightedAggregation::Quantile),
+            weights.into(),
+            quantile,
+        )
+    }
+
     /// Get the group indexes of the group by operation.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Arc::new(self)).into()
@@ -422,7 +456,11 @@ impl Expr {
         self.map_binary(FunctionExpr::TopK { descending: false }, k)
     }
 
-    /// Returns the `k` largest rows by given column.
+    /// Returns the `k` largest rows by given columns.
+    ///
+    /// The `descending` flags are given per `by` column, or as a single flag for all columns.
+    /// `k` can be an expression, which must be a single value or constant, e.g. a column that is
+    /// constant within the groups of a `group_by`.
     ///
     /// For single column, use [`Expr::top_k`].
     #[cfg(feature = "top_k")]
@@ -448,7 +486,9 @@ impl Expr {
         self.map_binary(FunctionExpr::TopK { descending: true }, k)
     }
 
-    /// Returns the `k` smallest rows by given column.
+    /// Returns the `k` smallest rows by given columns.
+    ///
+    /// See [`Expr::top_k_by`] for the `descending` flags and `k`.
     ///
     /// For single column, use [`Expr::bottom_k`].
     #[cfg(feature = "top_k")]
@@ -698,6 +738,28 @@ impl Expr {
         self.map_unary(FunctionExpr::CumMax { reverse })
     }
 
+    /// Get an array with the cumulative aggregation computed at every element, where the
+    /// aggregation restarts at every element for which `reset` is true.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_agg_reset(self, reset: Expr, function: ListCumFunction, reverse: bool) -> Self {
+        self.map_binary(FunctionExpr::CumAggReset { function, reverse }, reset)
+    }
+
+    /// Get an array with the cumulative sum computed at every element, where the sum restarts
+    /// at every element for which `reset` is true.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_sum_reset(self, reset: Expr, reverse: bool) -> Self {
+        self.cum_agg_reset(reset, ListCumFunction::Sum, reverse)
+    }
+
+    /// Get an array with the cumulative aggregation computed over every run of equal values in
+    /// `by`.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_agg_over_runs(self, by: Expr, function: ListCumFunction, reverse: bool) -> Self {
+        let reset = by.clone().neq_missing(by.shift(lit(1)));
+        self.cum_agg_reset(reset, function, reverse)
+    }
+
     /// Get the product aggregation of an expression.
     pub fn product(self) -> Self {
         self.map_unary(FunctionExpr::Product)
@@ -739,6 +801,26 @@ impl Expr {
         self.map_unary(FunctionExpr::Ceil)
     }
 
+    /// Apply a decimal arithmetic operation, deriving the result precision and scale with SQL
+    /// rules and rounding the result with the given mode.
+    ///
+    /// The arithmetic operators don't apply these rules, they keep the maximum precision.
+    #[cfg(feature = "decimal_arithmetic")]
+    pub fn decimal_arithmetic(
+        self,
+        other: Expr,
+        op: DecimalArithmeticOp,
+        options: DecimalArithmeticOptions,
+    ) -> Self {
+        self.map_binary(FunctionExpr::DecimalArithmetic { op, options }, other)
+    }
+
+    /// Apply an integer arithmetic operation that produces null instead of wrapping around on
+    /// overflow. If `warn` is set, a warning with the number of overflowed values is raised.
+    pub fn checked_arithmetic(self, other: Expr, op: CheckedArithmeticOp, warn: bool) -> Self {
+        self.map_binary(FunctionExpr::CheckedArithmetic { op, warn }, other)
+    }
+
     /// Clip underlying values to a set boundary.
     #[cfg(feature = "round_series")]
     pub fn clip(self, min: Expr, max: Expr) -> Self {
@@ -980,7 +1062,49 @@ impl Expr {
     /// Get the approximate count of unique values.
     #[cfg(feature = "approx_unique")]
     pub fn approx_n_unique(self) -> Self {
-        self.map_unary(FunctionExpr::ApproxNUnique)
+        self.map_unary(FunctionExpr::ApproxNUnique { precision: None })
+    }
+
+    /// Get the approximate count of unique values with a HyperLogLog sketch of `2^precision`
+    /// registers. A higher precision gives a smaller error, at the cost of more memory.
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_with_precision(self, precision: u8) -> Self {
+        self.map_unary(FunctionExpr::ApproxNUnique {
+            precision: Some(precision),
+        })
+    }
+
+    /// Get the HyperLogLog sketch of `2^precision` registers of the values as a binary.
+    ///
+    /// Sketches can be merged with [`Expr::hll_merge`], also across datasets, and turned into an
+    /// approximate count of unique values with [`Expr::hll_estimate`].
+    #[cfg(feature = "approx_unique")]
+    pub fn hll_sketch(self, precision: u8) -> Self {
+        self.map_unary(FunctionExpr::Hll(HllFunction::Sketch { precision }))
+    }
+
+    /// Merge binary HyperLogLog sketches created with [`Expr::hll_sketch`] into one sketch.
+    #[cfg(feature = "approx_unique")]
+    pub fn hll_merge(self) -> Self {
+        self.map_unary(FunctionExpr::Hll(HllFunction::Merge))
+    }
+
+    /// Get the approximate count of unique values of each binary HyperLogLog sketch.
+    #[cfg(feature = "approx_unique")]
+    pub fn hll_estimate(self) -> Self {
+        self.map_unary(FunctionExpr::Hll(HllFunction::Estimate))
+    }
+
+    /// Get the approximate quantile with a t-digest with the given `compression`.
+    ///
+    /// A higher compression gives a smaller error, at the cost of more memory. The t-digests of
+    /// partitions are merged, so this is cheap in streaming and group-by contexts.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: f64, compression: f64) -> Self {
+        self.map_unary(FunctionExpr::ApproxQuantile {
+            quantile,
+            compression,
+        })
     }
 
     /// Bitwise "and" operation.
@@ -1103,8 +1227,20 @@ impl Expr {
 
     #[cfg(feature = "mode")]
     /// Compute the mode(s) of this column. This is the most occurring value.
-    pub fn mode(self, maintain_order: bool) -> Expr {
-        self.map_unary(FunctionExpr::Mode { maintain_order })
+    ///
+    /// `ties` determines which values are returned if multiple values occur equally often.
+    pub fn mode(self, maintain_order: bool, ties: ModeTies) -> Expr {
+        self.map_unary(FunctionExpr::Mode {
+            maintain_order,
+            ties,
+        })
+    }
+
+    #[cfg(feature = "mode")]
+    /// Get the `n` most occurring values as a list, ordered by descending count. Ties are broken
+    /// by the order of first occurrence.
+    pub fn modes(self, n: IdxSize) -> Expr {
+        self.map_unary(FunctionExpr::Modes(n))
     }
 
     #[cfg(feature = "interpolate")]
@@ -1133,10 +1269,20 @@ impl Expr {
 
     #[cfg(feature = "interpolate_by")]
     /// Interpolate intermediate values.
-    /// Nulls at the beginning and end of the series remain null.
+    /// Nulls at the beginning and end of the series remain null, unless filled by the
+    /// `Forward` or `Backward` method.
     /// The `by` column provides the x-coordinates for interpolation and must not contain nulls.
-    pub fn interpolate_by(self, by: Expr) -> Expr {
-        self.map_binary(FunctionExpr::InterpolateBy, by)
+    /// If `max_gap` is given, only gaps whose distance in `by` does not exceed it are filled.
+    pub fn interpolate_by(
+        self,
+        by: Expr,
+        method: InterpolateByMethod,
+        max_gap: Option<Expr>,
+    ) -> Expr {
+        self.map_n_ary(
+            FunctionExpr::InterpolateBy(method),
+            [by].into_iter().chain(max_gap),
+        )
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1319,6 +1465,26 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Map(f))
     }
 
+    #[cfg(all(feature = "rolling_window", feature = "dtype-array"))]
+    /// Apply a custom function over batches of up to `batch_size` windows at once.
+    ///
+    /// The function receives an array column with a window per row, and must return a value per
+    /// window. This amortizes the overhead of calling the function for every window.
+    pub fn rolling_map_batches(
+        self,
+        f: PlanCallback<Series, Series>,
+        options: RollingOptionsFixedWindow,
+        batch_size: usize,
+    ) -> Expr {
+        self.finish_rolling(
+            options,
+            RollingFunction::MapBatches {
+                function: f,
+                batch_size,
+            },
+        )
+    }
+
     #[cfg(feature = "peaks")]
     pub fn peak_min(self) -> Expr {
         self.map_unary(FunctionExpr::PeakMin)
@@ -1366,18 +1532,23 @@ impl Expr {
 
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories.
+    ///
+    /// If `include_bins` is set, a struct with the `category`, the `lower` and `upper` edge and
+    /// the `bin_index` of every bin is returned instead.
     pub fn cut(
         self,
         breaks: Vec<f64>,
         labels: Option<impl IntoVec<PlSmallStr>>,
         left_closed: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> Expr {
         self.map_unary(FunctionExpr::Cut {
             breaks,
             labels: labels.map(|x| x.into_vec()),
             left_closed,
             include_breaks,
+            include_bins,
         })
     }
 
@@ -1390,6 +1561,7 @@ impl Expr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> Expr {
         self.map_unary(FunctionExpr::QCut {
             probs,
@@ -1397,6 +1569,7 @@ impl Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bins,
         })
     }
 
@@ -1409,6 +1582,7 @@ impl Expr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> Expr {
         let probs = (1..n_bins).map(|b| b as f64 / n_bins as f64).collect();
         self.map_unary(FunctionExpr::QCut {
@@ -1417,6 +1591,7 @@ impl Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bins,
         })
     }
 
@@ -1601,6 +1776,23 @@ impl Expr {
         self.map_unary(FunctionExpr::Hash(k0, k1, k2, k3))
     }
 
+    #[cfg(feature = "row_hash")]
+    /// Compute a hash of every element that is the same across Polars versions.
+    ///
+    /// See [`polars_core::hashing::stable`] for the algorithm.
+    pub fn stable_hash(self, seed: u64) -> Expr {
+        self.map_unary(FunctionExpr::StableHash { seed })
+    }
+
+    #[cfg(feature = "hash_digest")]
+    /// Compute the `algorithm` digest of every string or binary element.
+    ///
+    /// The digest is returned as a lowercase hexadecimal string if `hex` is set and as binary
+    /// otherwise.
+    pub fn hash_digest(self, algorithm: HashAlgorithm, hex: bool) -> Expr {
+        self.map_unary(FunctionExpr::HashDigest { algorithm, hex })
+    }
+
     pub fn to_physical(self) -> Expr {
         self.map_unary(FunctionExpr::ToPhysical)
     }
@@ -74,6 +74,49 @@ impl Default for StrptimeOptions {
     }
 }
 
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct StrptimeMultiOptions {
+    /// Formatting strings, tried in order for every value; the first one that parses wins
+    pub formats: Arc<[PlSmallStr]>,
+    /// Infer the format of the values that could not be parsed by any of the `formats`
+    pub best_effort: bool,
+    /// Return a struct with the parsed `value` and the `format` that matched it
+    pub include_format: bool,
+    /// If set then polars will return an error if any value could not be parsed
+    pub strict: bool,
+    /// If polars may parse matches that not contain the whole string
+    pub exact: bool,
+    /// use a cache of unique, converted dates to apply the datetime conversion.
+    pub cache: bool,
+}
+
+impl StrptimeMultiOptions {
+    /// The options used to parse the values with a single format (or by inferring it).
+    pub fn single(&self, format: Option<PlSmallStr>) -> StrptimeOptions {
+        StrptimeOptions {
+            format,
+            strict: false,
+            exact: self.exact,
+            cache: self.cache,
+        }
+    }
+}
+
+impl Default for StrptimeMultiOptions {
+    fn default() -> Self {
+        StrptimeMultiOptions {
+            formats: Arc::from([]),
+            best_effort: false,
+            include_format: false,
+            strict: true,
+            exact: true,
+            cache: true,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, IntoStaticStr, Debug)]
 #[cfg_attr(feature = "ir_serde", derive(Serialize, Deserialize))]
 #[strum(serialize_all = "snake_case")]
@@ -142,12 +142,41 @@ impl StringNameSpace {
 
     #[cfg(feature = "string_encoding")]
     pub fn base64_encode(self) -> Expr {
-        self.0.map_unary(StringFunction::Base64Encode)
+        self.base64_encode_with_options(Base64Alphabet::Standard, true)
+    }
+
+    /// Base64 encode the values using the given alphabet, optionally without `=` padding.
+    #[cfg(feature = "string_encoding")]
+    pub fn base64_encode_with_options(self, alphabet: Base64Alphabet, padding: bool) -> Expr {
+        self.0
+            .map_unary(StringFunction::Base64Encode { alphabet, padding })
     }
 
     #[cfg(feature = "binary_encoding")]
     pub fn base64_decode(self, strict: bool) -> Expr {
-        self.0.map_unary(StringFunction::Base64Decode(strict))
+        self.base64_decode_with_options(Base64Alphabet::Standard, strict)
+    }
+
+    /// Decode base64 encoded values using the given alphabet. Both padded and unpadded values
+    /// are accepted.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base64_decode_with_options(self, alphabet: Base64Alphabet, strict: bool) -> Expr {
+        self.0
+            .map_unary(StringFunction::Base64Decode { alphabet, strict })
+    }
+
+    /// Parse IPv4 and IPv6 addresses into their 4 or 16 byte binary representation in network
+    /// byte order. Invalid addresses raise an error if `strict` is set and become null otherwise.
+    #[cfg(feature = "ip_address")]
+    pub fn parse_ip(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::ParseIp { strict })
+    }
+
+    /// Parse UUIDs into their 16 byte binary representation. Invalid UUIDs raise an error if
+    /// `strict` is set and become null otherwise.
+    #[cfg(feature = "uuid")]
+    pub fn parse_uuid(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::ParseUuid { strict })
     }
 
     /// Extract a regex pattern from the a string value. If `group_index` is out of bounds, null is returned.
@@ -286,21 +315,57 @@ impl StringNameSpace {
         // If time_unit is None, try to infer it from the format or set a default
         let time_unit = match (&options.format, time_unit) {
             (_, Some(time_unit)) => time_unit,
-            (Some(format), None) => {
-                if format.contains("%.9f") || format.contains("%9f") {
-                    TimeUnit::Nanoseconds
-                } else if format.contains("%.3f") || format.contains("%3f") {
-                    TimeUnit::Milliseconds
-                } else {
-                    TimeUnit::Microseconds
-                }
-            },
+            (Some(format), None) => time_unit_from_format(format),
             (None, None) => TimeUnit::Microseconds,
         };
 
         self.strptime(DataType::Datetime(time_unit, time_zone), options, ambiguous)
     }
 
+    /// Convert a String column into a Date/Datetime/Time column, trying each of the
+    /// formats in order for every value.
+    #[cfg(feature = "temporal")]
+    pub fn strptime_multi(
+        self,
+        dtype: impl Into<DataTypeExpr>,
+        options: StrptimeMultiOptions,
+        ambiguous: Expr,
+    ) -> Expr {
+        self.0.map_binary(
+            StringFunction::StrptimeMulti(dtype.into(), options),
+            ambiguous,
+        )
+    }
+
+    /// Convert a String column into a Datetime column, trying each of the formats in
+    /// order for every value.
+    #[cfg(feature = "dtype-datetime")]
+    pub fn to_datetime_multi(
+        self,
+        time_unit: Option<TimeUnit>,
+        time_zone: Option<TimeZone>,
+        options: StrptimeMultiOptions,
+        ambiguous: Expr,
+    ) -> Expr {
+        // If time_unit is None, use the finest unit required by any of the formats
+        let time_unit = time_unit.unwrap_or_else(|| {
+            options
+                .formats
+                .iter()
+                .map(|format| time_unit_from_format(format))
+                .reduce(|a, b| match (a, b) {
+                    (TimeUnit::Nanoseconds, _) | (_, TimeUnit::Nanoseconds) => {
+                        TimeUnit::Nanoseconds
+                    },
+                    (TimeUnit::Milliseconds, TimeUnit::Milliseconds) => TimeUnit::Milliseconds,
+                    _ => TimeUnit::Microseconds,
+                })
+                .unwrap_or(TimeUnit::Microseconds)
+        });
+
+        self.strptime_multi(DataType::Datetime(time_unit, time_zone), options, ambiguous)
+    }
+
     /// Convert a String column into a Time column.
     #[cfg(feature = "dtype-time")]
     pub fn to_time(self, options: StrptimeOptions) -> Expr {
@@ -367,6 +432,7 @@ impl StringNameSpace {
     pub fn split_regex(self, pat: Expr, strict: bool) -> Expr {
         self.0.map_binary(
             StringFunction::SplitRegex {
+                n: None,
                 inclusive: false,
                 strict,
             },
@@ -380,6 +446,7 @@ impl StringNameSpace {
     pub fn split_regex_inclusive(self, pat: Expr, strict: bool) -> Expr {
         self.0.map_binary(
             StringFunction::SplitRegex {
+                n: None,
                 inclusive: true,
                 strict,
             },
@@ -387,6 +454,21 @@ impl StringNameSpace {
         )
     }
 
+    #[cfg(feature = "regex")]
+    /// Split the string by a regex pattern, returning at most `n` items. If there are more
+    /// possible splits, keeps the remainder of the string intact in the last item.
+    /// The resulting dtype is `List<String>`.
+    pub fn splitn_regex(self, pat: Expr, n: usize, inclusive: bool, strict: bool) -> Expr {
+        self.0.map_binary(
+            StringFunction::SplitRegex {
+                n: Some(n),
+                inclusive,
+                strict,
+            },
+            pat,
+        )
+    }
+
     #[cfg(feature = "regex")]
     /// Replace values that match a regex `pat` with a `value`.
     pub fn replace(self, pat: Expr, value: Expr, literal: bool) -> Expr {
@@ -420,6 +502,64 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Levenshtein (edit) distance to `other`, counted in characters.
+    /// The resulting dtype is [`DataType::UInt32`].
+    pub fn levenshtein_distance(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(StringFunction::LevenshteinDistance, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Hamming distance to `other`, counted in characters. Strings of a
+    /// different length result in null. The resulting dtype is [`DataType::UInt32`].
+    pub fn hamming(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::HammingDistance, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Jaro-Winkler similarity to `other`, between 0 (no similarity)
+    /// and 1 (exact match). The resulting dtype is [`DataType::Float64`].
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::JaroWinkler, other)
+    }
+
+    #[cfg(feature = "string_tokenize")]
+    /// Split each string into a list of tokens. The strings are lowercased first if
+    /// `lowercase` is set, and tokens equal to one of the `stop_words` are dropped.
+    pub fn tokenize(
+        self,
+        method: TokenizeMethod,
+        lowercase: bool,
+        stop_words: Arc<[PlSmallStr]>,
+    ) -> Expr {
+        self.0.map_unary(StringFunction::Tokenize {
+            method,
+            lowercase,
+            stop_words,
+        })
+    }
+
+    /// Extract a part of each URL. Values that are not absolute URLs or lack the part become
+    /// null.
+    #[cfg(feature = "string_url")]
+    pub fn url_part(self, part: UrlPart) -> Expr {
+        self.0.map_unary(StringFunction::UrlPart(part))
+    }
+
+    /// Decode the query of each URL. With `keys` the output is a struct with the first value
+    /// of every key, otherwise a list of all key/value pairs.
+    #[cfg(feature = "string_url")]
+    pub fn url_query_params(self, keys: Option<Arc<[PlSmallStr]>>) -> Expr {
+        self.0.map_unary(StringFunction::UrlQueryParams(keys))
+    }
+
+    /// Decode the `%XX` escapes of each string.
+    #[cfg(feature = "string_url")]
+    pub fn url_decode_percent(self) -> Expr {
+        self.0.map_unary(StringFunction::UrlDecodePercent)
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
@@ -450,6 +590,11 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Lowercase)
     }
 
+    /// Apply full Unicode case folding, for caseless matching of strings.
+    pub fn casefold(self) -> Expr {
+        self.0.map_unary(StringFunction::Casefold)
+    }
+
     /// Convert all characters to uppercase.
     pub fn to_uppercase(self) -> Expr {
         self.0.map_unary(StringFunction::Uppercase)
@@ -517,8 +662,39 @@ impl StringNameSpace {
         self.0.map_binary(StringFunction::JsonPathMatch, pat)
     }
 
+    /// Extract all matches of the JSON path as a list, decoded as `dtype` if given.
+    #[cfg(feature = "extract_jsonpath")]
+    pub fn json_path_match_all(self, pat: Expr, dtype: Option<DataTypeExpr>) -> Expr {
+        self.0
+            .map_binary(StringFunction::JsonPathMatchAll(dtype), pat)
+    }
+
+    /// Extract the first match of each of the JSON `paths` into a struct, parsing every
+    /// document only once.
+    #[cfg(feature = "extract_jsonpath")]
+    pub fn json_extract_many<I, S>(self, paths: I, dtype: Option<DataTypeExpr>) -> Expr
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let paths = paths.into_iter().map(Into::into).collect();
+        self.0
+            .map_unary(StringFunction::JsonExtractMany { paths, dtype })
+    }
+
     #[cfg(feature = "regex")]
     pub fn escape_regex(self) -> Expr {
         self.0.map_unary(StringFunction::EscapeRegex)
     }
 }
+
+#[cfg(feature = "dtype-datetime")]
+fn time_unit_from_format(format: &str) -> TimeUnit {
+    if format.contains("%.9f") || format.contains("%9f") {
+        TimeUnit::Nanoseconds
+    } else if format.contains("%.3f") || format.contains("%3f") {
+        TimeUnit::Milliseconds
+    } else {
+        TimeUnit::Microseconds
+    }
+}
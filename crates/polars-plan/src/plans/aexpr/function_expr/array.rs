@@ -33,6 +33,12 @@ pub enum IRArrayFunction {
     },
     #[cfg(feature = "array_count")]
     CountMatches,
+    #[cfg(feature = "array_linalg")]
+    Dot,
+    #[cfg(feature = "array_linalg")]
+    L2Norm,
+    #[cfg(feature = "array_linalg")]
+    CosineSimilarity,
     Shift,
     Explode(ExplodeOptions),
     Concat,
@@ -97,6 +103,17 @@ impl IRArrayFunction {
             Contains { nulls_equal: _ } => mapper.ensure_is_array()?.with_dtype(DataType::Boolean),
             #[cfg(feature = "array_count")]
             CountMatches => mapper.ensure_is_array()?.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "array_linalg")]
+            Dot | L2Norm | CosineSimilarity => {
+                let mapper = mapper.ensure_is_array()?;
+                let dtype = linalg_float_dtype(
+                    mapper
+                        .args()
+                        .iter()
+                        .map(|f| f.dtype().inner_dtype().unwrap_or(f.dtype())),
+                );
+                mapper.with_dtype(dtype)
+            },
             Shift => mapper.ensure_is_array()?.with_same_dtype(),
             Explode { .. } => mapper.ensure_is_array()?.try_map_to_array_inner_dtype(),
             Slice(offset, length) => mapper
@@ -131,6 +148,8 @@ impl IRArrayFunction {
             A::Contains { nulls_equal: _ } => FunctionOptions::elementwise(),
             #[cfg(feature = "array_count")]
             A::CountMatches => FunctionOptions::elementwise(),
+            #[cfg(feature = "array_linalg")]
+            A::Dot | A::L2Norm | A::CosineSimilarity => FunctionOptions::elementwise(),
             A::Concat => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             A::Length
@@ -218,6 +237,12 @@ impl Display for IRArrayFunction {
             Contains { nulls_equal: _ } => "contains",
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
+            #[cfg(feature = "array_linalg")]
+            Dot => "dot",
+            #[cfg(feature = "array_linalg")]
+            L2Norm => "l2_norm",
+            #[cfg(feature = "array_linalg")]
+            CosineSimilarity => "cosine_similarity",
             Shift => "shift",
             Slice(_, _) => "slice",
             Explode { .. } => "explode",
@@ -11,9 +11,15 @@ pub enum IRBinaryFunction {
     #[cfg(feature = "binary_encoding")]
     HexEncode,
     #[cfg(feature = "binary_encoding")]
-    Base64Decode(bool),
+    Base64Decode {
+        alphabet: Base64Alphabet,
+        strict: bool,
+    },
     #[cfg(feature = "binary_encoding")]
-    Base64Encode,
+    Base64Encode {
+        alphabet: Base64Alphabet,
+        padding: bool,
+    },
     Size,
     #[cfg(feature = "binary_encoding")]
     Reinterpret(DataType, bool),
@@ -21,6 +27,12 @@ pub enum IRBinaryFunction {
     Head,
     Tail,
     Get(bool),
+    #[cfg(feature = "ip_address")]
+    IpIsInSubnet(Arc<[IpSubnet]>),
+    #[cfg(feature = "ip_address")]
+    IpToInt,
+    #[cfg(feature = "uuid")]
+    ToUuidStr,
 }
 
 impl IRBinaryFunction {
@@ -30,14 +42,20 @@ impl IRBinaryFunction {
             Contains => mapper.with_dtype(DataType::Boolean),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "binary_encoding")]
-            HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
+            HexDecode(_) | Base64Decode { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
-            HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
+            HexEncode | Base64Encode { .. } => mapper.with_dtype(DataType::String),
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             Reinterpret(dtype, _) => mapper.with_dtype(dtype.clone()),
             Slice | Head | Tail => mapper.with_same_dtype(),
             Get(_) => mapper.with_dtype(DataType::UInt8),
+            #[cfg(feature = "ip_address")]
+            IpIsInSubnet(_) => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "ip_address")]
+            IpToInt => mapper.with_dtype(DataType::UInt128),
+            #[cfg(feature = "uuid")]
+            ToUuidStr => mapper.with_dtype(DataType::String),
         }
     }
 
@@ -51,10 +69,14 @@ impl IRBinaryFunction {
             #[cfg(feature = "binary_encoding")]
             B::HexDecode(_)
             | B::HexEncode
-            | B::Base64Decode(_)
-            | B::Base64Encode
+            | B::Base64Decode { .. }
+            | B::Base64Encode { .. }
             | B::Reinterpret(_, _) => FunctionOptions::elementwise(),
             B::Slice | B::Head | B::Tail | B::Get(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "ip_address")]
+            B::IpIsInSubnet(_) | B::IpToInt => FunctionOptions::elementwise(),
+            #[cfg(feature = "uuid")]
+            B::ToUuidStr => FunctionOptions::elementwise(),
         }
     }
 }
@@ -71,9 +93,9 @@ impl Display for IRBinaryFunction {
             #[cfg(feature = "binary_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
-            Base64Decode(_) => "base64_decode",
+            Base64Decode { .. } => "base64_decode",
             #[cfg(feature = "binary_encoding")]
-            Base64Encode => "base64_encode",
+            Base64Encode { .. } => "base64_encode",
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
@@ -81,6 +103,12 @@ impl Display for IRBinaryFunction {
             Head => "head",
             Tail => "tail",
             Get(_) => "get",
+            #[cfg(feature = "ip_address")]
+            IpIsInSubnet(_) => "ip_is_in_subnet",
+            #[cfg(feature = "ip_address")]
+            IpToInt => "ip_to_int",
+            #[cfg(feature = "uuid")]
+            ToUuidStr => "to_uuid_str",
         };
         write!(f, "bin.{s}")
     }
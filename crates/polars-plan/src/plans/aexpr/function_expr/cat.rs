@@ -4,6 +4,8 @@ use super::*;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum IRCategoricalFunction {
     GetCategories,
+    AddCategories(Vec<PlSmallStr>),
+    UnionCategories,
     #[cfg(feature = "strings")]
     LenBytes,
     #[cfg(feature = "strings")]
@@ -21,6 +23,13 @@ impl IRCategoricalFunction {
         use IRCategoricalFunction::*;
         match self {
             GetCategories => mapper.with_dtype(DataType::String),
+            AddCategories(categories) => mapper.try_map_dtype(|dt| {
+                add_categories_to_dtype(dt, categories.iter().map(|s| s.as_str()))
+            }),
+            UnionCategories => {
+                let other = mapper.args()[1].dtype();
+                mapper.try_map_dtype(|dt| union_categories_dtype(dt, other))
+            },
             #[cfg(feature = "strings")]
             LenBytes => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "strings")]
@@ -38,6 +47,7 @@ impl IRCategoricalFunction {
         use IRCategoricalFunction as C;
         match self {
             C::GetCategories => FunctionOptions::groupwise(),
+            C::AddCategories(_) | C::UnionCategories => FunctionOptions::elementwise(),
             #[cfg(feature = "strings")]
             C::LenBytes | C::LenChars | C::StartsWith(_) | C::EndsWith(_) | C::Slice(_, _) => {
                 FunctionOptions::elementwise()
@@ -51,6 +61,8 @@ impl Display for IRCategoricalFunction {
         use IRCategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            AddCategories(_) => "add_categories",
+            UnionCategories => "union_categories",
             #[cfg(feature = "strings")]
             LenBytes => "len_bytes",
             #[cfg(feature = "strings")]
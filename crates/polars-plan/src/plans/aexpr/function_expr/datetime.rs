@@ -8,10 +8,12 @@ pub enum IRTemporalFunction {
     Year,
     IsLeapYear,
     IsoYear,
+    FiscalYear(i8),
     Quarter,
+    FiscalQuarter(i8),
     Month,
     DaysInMonth,
-    Week,
+    Week(WeekConvention),
     WeekDay,
     Day,
     OrdinalDay,
@@ -86,11 +88,12 @@ impl IRTemporalFunction {
     pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
         use IRTemporalFunction::*;
         match self {
-            Millennium | Century | Year | IsoYear => mapper.with_dtype(DataType::Int32),
-            OrdinalDay => mapper.with_dtype(DataType::Int16),
-            Month | DaysInMonth | Quarter | Week | WeekDay | Day | Hour | Minute | Second => {
-                mapper.with_dtype(DataType::Int8)
+            Millennium | Century | Year | IsoYear | FiscalYear(_) => {
+                mapper.with_dtype(DataType::Int32)
             },
+            OrdinalDay => mapper.with_dtype(DataType::Int16),
+            Month | DaysInMonth | Quarter | FiscalQuarter(_) | Week(_) | WeekDay | Day | Hour
+            | Minute | Second => mapper.with_dtype(DataType::Int8),
             Millisecond | Microsecond | Nanosecond => mapper.with_dtype(DataType::Int32),
             #[cfg(feature = "dtype-duration")]
             TotalDays { fractional }
@@ -167,10 +170,12 @@ impl IRTemporalFunction {
             | T::Year
             | T::IsLeapYear
             | T::IsoYear
+            | T::FiscalYear(_)
             | T::Quarter
+            | T::FiscalQuarter(_)
             | T::Month
             | T::DaysInMonth
-            | T::Week
+            | T::Week(_)
             | T::WeekDay
             | T::Day
             | T::OrdinalDay
@@ -229,10 +234,12 @@ impl Display for IRTemporalFunction {
             Year => "year",
             IsLeapYear => "is_leap_year",
             IsoYear => "iso_year",
+            FiscalYear(_) => "fiscal_year",
             Quarter => "quarter",
+            FiscalQuarter(_) => "fiscal_quarter",
             Month => "month",
             DaysInMonth => "days_in_month",
-            Week => "week",
+            Week(_) => "week",
             WeekDay => "weekday",
             Day => "day",
             OrdinalDay => "ordinal_day",
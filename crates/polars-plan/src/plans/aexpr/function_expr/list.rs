@@ -28,6 +28,10 @@ pub enum IRListFunction {
     GatherEvery,
     #[cfg(feature = "list_count")]
     CountMatches,
+    #[cfg(feature = "index_of")]
+    IndexOf,
+    #[cfg(feature = "index_of")]
+    Find,
     Sum,
     Length,
     Max,
@@ -43,12 +47,27 @@ pub enum IRListFunction {
         n: i64,
         null_behavior: NullBehavior,
     },
+    #[cfg(feature = "cum_agg")]
+    CumAgg {
+        function: ListCumFunction,
+        reverse: bool,
+    },
+    #[cfg(feature = "rolling_window")]
+    Rolling {
+        function: ListRollingFunction,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+    },
     Sort(SortOptions),
     Reverse,
     Unique(bool),
     NUnique,
     #[cfg(feature = "list_sets")]
-    SetOperation(SetOperation),
+    SetOperation {
+        op: SetOperation,
+        multiset: bool,
+    },
     #[cfg(feature = "list_any_all")]
     Any,
     #[cfg(feature = "list_any_all")]
@@ -93,6 +112,16 @@ impl IRListFunction {
             GatherEvery => mapper.ensure_is_list()?.with_same_dtype(),
             #[cfg(feature = "list_count")]
             CountMatches => mapper.ensure_is_list()?.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "index_of")]
+            IndexOf => mapper.ensure_is_list()?.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "index_of")]
+            Find => mapper.try_map_dtype(|dt| match dt {
+                DataType::List(inner) if inner.is_bool() || inner.is_null() => Ok(IDX_DTYPE),
+                dt => polars_bail!(
+                    InvalidOperation: "the predicate of `list.find` must evaluate to booleans, got {}",
+                    dt
+                ),
+            }),
             Sum => mapper.nested_sum_type(),
             Min => mapper.ensure_is_list()?.map_to_list_and_array_inner_dtype(),
             Max => mapper.ensure_is_list()?.map_to_list_and_array_inner_dtype(),
@@ -123,12 +152,46 @@ impl IRListFunction {
 
                 Ok(DataType::List(Box::new(inner_dt)))
             }),
+            #[cfg(feature = "cum_agg")]
+            CumAgg { function, .. } => mapper.try_map_dtype(|dt| {
+                let DataType::List(inner) = dt else {
+                    polars_bail!(op = "list.cum_agg", dt);
+                };
+
+                let inner_dt = match function {
+                    ListCumFunction::Sum => super::cum::dtypes::cum_sum(inner),
+                    ListCumFunction::Prod => super::cum::dtypes::cum_prod(inner),
+                    ListCumFunction::Min | ListCumFunction::Max => inner.as_ref().clone(),
+                    ListCumFunction::Count => IDX_DTYPE,
+                };
+
+                Ok(DataType::List(Box::new(inner_dt)))
+            }),
+            #[cfg(feature = "rolling_window")]
+            Rolling { function, .. } => mapper.try_map_dtype(|dt| {
+                let DataType::List(inner) = dt else {
+                    polars_bail!(op = "list.rolling", dt);
+                };
+
+                let fields = [Field::new(PlSmallStr::EMPTY, inner.as_ref().clone())];
+                let mapper = FieldsMapper::new(&fields);
+                let inner = match function {
+                    ListRollingFunction::Min | ListRollingFunction::Max => mapper.with_same_dtype(),
+                    ListRollingFunction::Mean
+                    | ListRollingFunction::Median
+                    | ListRollingFunction::Std(_) => mapper.moment_dtype(),
+                    ListRollingFunction::Var(_) => mapper.var_dtype(),
+                    ListRollingFunction::Sum => mapper.sum_dtype(),
+                }?;
+
+                Ok(DataType::List(Box::new(inner.dtype)))
+            }),
             Sort(_) => mapper.ensure_is_list()?.with_same_dtype(),
             Reverse => mapper.ensure_is_list()?.with_same_dtype(),
             Unique(_) => mapper.ensure_is_list()?.with_same_dtype(),
             Length => mapper.ensure_is_list()?.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_sets")]
-            SetOperation(_) => mapper.ensure_is_list()?.with_same_dtype(),
+            SetOperation { .. } => mapper.ensure_is_list()?.with_same_dtype(),
             #[cfg(feature = "list_any_all")]
             Any => mapper.ensure_is_list()?.with_dtype(DataType::Boolean),
             #[cfg(feature = "list_any_all")]
@@ -184,17 +247,27 @@ impl IRListFunction {
             #[cfg(feature = "list_gather")]
             L::GatherEvery => FunctionOptions::elementwise(),
             #[cfg(feature = "list_sets")]
-            L::SetOperation(_) => FunctionOptions::elementwise()
+            L::SetOperation { .. } => FunctionOptions::elementwise()
                 .with_casting_rules(CastingRules::Supertype(SuperTypeOptions {
                     flags: SuperTypeFlags::default() | SuperTypeFlags::ALLOW_IMPLODE_LIST,
                 }))
                 .with_flags(|f| f & !FunctionFlags::RETURNS_SCALAR),
             #[cfg(feature = "diff")]
             L::Diff { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "cum_agg")]
+            L::CumAgg { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "rolling_window")]
+            L::Rolling { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "list_drop_nulls")]
             L::DropNulls => FunctionOptions::elementwise(),
             #[cfg(feature = "list_count")]
             L::CountMatches => FunctionOptions::elementwise(),
+            #[cfg(feature = "index_of")]
+            L::IndexOf => {
+                FunctionOptions::elementwise().with_casting_rules(CastingRules::FirstArgLossless)
+            },
+            #[cfg(feature = "index_of")]
+            L::Find => FunctionOptions::elementwise(),
             L::Sum
             | L::Slice
             | L::Shift
@@ -259,6 +332,10 @@ impl Display for IRListFunction {
             GatherEvery => "gather_every",
             #[cfg(feature = "list_count")]
             CountMatches => "count_matches",
+            #[cfg(feature = "index_of")]
+            IndexOf => "index_of",
+            #[cfg(feature = "index_of")]
+            Find => "find",
             Sum => "sum",
             Min => "min",
             Max => "max",
@@ -270,6 +347,10 @@ impl Display for IRListFunction {
             ArgMax => "arg_max",
             #[cfg(feature = "diff")]
             Diff { .. } => "diff",
+            #[cfg(feature = "cum_agg")]
+            CumAgg { function, .. } => return write!(f, "list.{function}"),
+            #[cfg(feature = "rolling_window")]
+            Rolling { function, .. } => return write!(f, "list.{function}"),
             Length => "length",
             Sort(_) => "sort",
             Reverse => "reverse",
@@ -282,7 +363,13 @@ impl Display for IRListFunction {
             },
             NUnique => "n_unique",
             #[cfg(feature = "list_sets")]
-            SetOperation(s) => return write!(f, "list.{s}"),
+            SetOperation { op, multiset } => {
+                return if *multiset {
+                    write!(f, "list.multiset_{op}")
+                } else {
+                    write!(f, "list.{op}")
+                };
+            },
             #[cfg(feature = "list_any_all")]
             Any => "any",
             #[cfg(feature = "list_any_all")]
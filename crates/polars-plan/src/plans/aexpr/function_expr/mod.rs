@@ -126,6 +126,17 @@ pub enum IRFunctionExpr {
     Pow(IRPowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    /// Hash with version [`STABLE_HASH_VERSION`](polars_core::hashing::stable::STABLE_HASH_VERSION)
+    /// of the stable hash algorithm.
+    #[cfg(feature = "row_hash")]
+    StableHash {
+        seed: u64,
+    },
+    #[cfg(feature = "hash_digest")]
+    HashDigest {
+        algorithm: HashAlgorithm,
+        hex: bool,
+    },
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -135,6 +146,12 @@ pub enum IRFunctionExpr {
         side: SearchSortedSide,
         descending: bool,
     },
+    #[cfg(feature = "search_sorted")]
+    SearchSortedBy {
+        side: SearchSortedSide,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    },
     #[cfg(feature = "range")]
     Range(IRRangeFunction),
     #[cfg(feature = "trigonometry")]
@@ -166,7 +183,10 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "mode")]
     Mode {
         maintain_order: bool,
+        ties: ModeTies,
     },
+    #[cfg(feature = "mode")]
+    Modes(IdxSize),
     #[cfg(feature = "moment")]
     Skew(bool),
     #[cfg(feature = "moment")]
@@ -226,6 +246,11 @@ pub enum IRFunctionExpr {
     CumMax {
         reverse: bool,
     },
+    #[cfg(feature = "cum_agg")]
+    CumAggReset {
+        function: ListCumFunction,
+        reverse: bool,
+    },
     Reverse,
     #[cfg(feature = "dtype-struct")]
     ValueCounts {
@@ -237,7 +262,16 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "unique_counts")]
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
-    ApproxNUnique,
+    ApproxNUnique {
+        precision: Option<u8>,
+    },
+    #[cfg(feature = "approx_unique")]
+    Hll(HllFunction),
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile {
+        quantile: f64,
+        compression: f64,
+    },
     Coalesce,
     #[cfg(feature = "diff")]
     Diff(NullBehavior),
@@ -246,7 +280,7 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolateByMethod),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -276,6 +310,15 @@ pub enum IRFunctionExpr {
     Floor,
     #[cfg(feature = "round_series")]
     Ceil,
+    #[cfg(feature = "decimal_arithmetic")]
+    DecimalArithmetic {
+        op: DecimalArithmeticOp,
+        options: DecimalArithmeticOptions,
+    },
+    CheckedArithmetic {
+        op: CheckedArithmeticOp,
+        warn: bool,
+    },
     #[cfg(feature = "fused")]
     Fused(fused::FusedOperator),
     ConcatExpr(bool),
@@ -283,6 +326,8 @@ pub enum IRFunctionExpr {
     Correlation {
         method: correlation::IRCorrelationMethod,
     },
+    #[cfg(feature = "weighted_agg")]
+    Weighted(WeightedAggregation),
     #[cfg(feature = "peaks")]
     PeakMin,
     #[cfg(feature = "peaks")]
@@ -293,6 +338,7 @@ pub enum IRFunctionExpr {
         labels: Option<Vec<PlSmallStr>>,
         left_closed: bool,
         include_breaks: bool,
+        include_bins: bool,
     },
     #[cfg(feature = "cutqcut")]
     QCut {
@@ -301,6 +347,7 @@ pub enum IRFunctionExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bins: bool,
     },
     #[cfg(feature = "rle")]
     RLE,
@@ -431,10 +478,22 @@ impl Hash for IRFunctionExpr {
                 side.hash(state);
                 descending.hash(state);
             },
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy {
+                side,
+                descending,
+                nulls_last,
+            } => {
+                side.hash(state);
+                descending.hash(state);
+                nulls_last.hash(state);
+            },
             #[cfg(feature = "random")]
             Random { method, .. } => method.hash(state),
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
+            #[cfg(feature = "weighted_agg")]
+            Weighted(agg) => agg.hash(state),
             #[cfg(feature = "range")]
             Range(f) => f.hash(state),
             #[cfg(feature = "trigonometry")]
@@ -446,7 +505,7 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -512,9 +571,15 @@ impl Hash for IRFunctionExpr {
                 nulls_last.hash(state);
             },
             #[cfg(feature = "mode")]
-            Mode { maintain_order } => {
+            Mode {
+                maintain_order,
+                ties,
+            } => {
                 maintain_order.hash(state);
+                ties.hash(state);
             },
+            #[cfg(feature = "mode")]
+            Modes(n) => n.hash(state),
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
@@ -529,6 +594,13 @@ impl Hash for IRFunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "row_hash")]
+            StableHash { seed } => seed.hash(state),
+            #[cfg(feature = "hash_digest")]
+            HashDigest { algorithm, hex } => {
+                algorithm.hash(state);
+                hex.hash(state);
+            },
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
@@ -573,6 +645,11 @@ impl Hash for IRFunctionExpr {
             CumMin { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
             CumMax { reverse } => reverse.hash(state),
+            #[cfg(feature = "cum_agg")]
+            CumAggReset { function, reverse } => {
+                function.hash(state);
+                reverse.hash(state);
+            },
             #[cfg(feature = "dtype-struct")]
             ValueCounts {
                 sort,
@@ -588,7 +665,17 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => {},
+            ApproxNUnique { precision } => precision.hash(state),
+            #[cfg(feature = "approx_unique")]
+            Hll(f) => f.hash(state),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile {
+                quantile,
+                compression,
+            } => {
+                quantile.to_bits().hash(state);
+                compression.to_bits().hash(state);
+            },
             Coalesce => {},
             #[cfg(feature = "pct_change")]
             PctChange => {},
@@ -617,6 +704,15 @@ impl Hash for IRFunctionExpr {
             IRFunctionExpr::Floor => {},
             #[cfg(feature = "round_series")]
             Ceil => {},
+            #[cfg(feature = "decimal_arithmetic")]
+            DecimalArithmetic { op, options } => {
+                op.hash(state);
+                options.hash(state);
+            },
+            CheckedArithmetic { op, warn } => {
+                op.hash(state);
+                warn.hash(state);
+            },
             ConcatExpr(a) => a.hash(state),
             #[cfg(feature = "peaks")]
             PeakMin => {},
@@ -628,12 +724,14 @@ impl Hash for IRFunctionExpr {
                 labels,
                 left_closed,
                 include_breaks,
+                include_bins,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(breaks);
                 slice.hash(state);
                 labels.hash(state);
                 left_closed.hash(state);
                 include_breaks.hash(state);
+                include_bins.hash(state);
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
@@ -646,6 +744,7 @@ impl Hash for IRFunctionExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                include_bins,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
@@ -653,6 +752,7 @@ impl Hash for IRFunctionExpr {
                 left_closed.hash(state);
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
+                include_bins.hash(state);
             },
             #[cfg(feature = "rle")]
             RLE => {},
@@ -739,12 +839,18 @@ impl Display for IRFunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            StableHash { .. } => "stable_hash",
+            #[cfg(feature = "hash_digest")]
+            HashDigest { algorithm, .. } => return write!(f, "hash_{}", algorithm.name()),
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
             IndexOf => "index_of",
             #[cfg(feature = "search_sorted")]
             SearchSorted { .. } => "search_sorted",
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy { .. } => "search_sorted_by",
             #[cfg(feature = "range")]
             Range(func) => return write!(f, "{func}"),
             #[cfg(feature = "trigonometry")]
@@ -764,13 +870,15 @@ impl Display for IRFunctionExpr {
             DropNans => "drop_nans",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
-            Mode { maintain_order } => {
+            Mode { maintain_order, .. } => {
                 if *maintain_order {
                     "mode_stable"
                 } else {
                     "mode"
                 }
             },
+            #[cfg(feature = "mode")]
+            Modes(_) => "modes",
             #[cfg(feature = "moment")]
             Skew(_) => "skew",
             #[cfg(feature = "moment")]
@@ -815,13 +923,19 @@ impl Display for IRFunctionExpr {
             CumMin { .. } => "cum_min",
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => "cum_max",
+            #[cfg(feature = "cum_agg")]
+            CumAggReset { function, .. } => return write!(f, "{function}_reset"),
             #[cfg(feature = "dtype-struct")]
             ValueCounts { .. } => "value_counts",
             #[cfg(feature = "unique_counts")]
             UniqueCounts => "unique_counts",
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => "approx_n_unique",
+            ApproxNUnique { .. } => "approx_n_unique",
+            #[cfg(feature = "approx_unique")]
+            Hll(hll) => return Display::fmt(hll, f),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => "approx_quantile",
             Coalesce => "coalesce",
             #[cfg(feature = "diff")]
             Diff(_) => "diff",
@@ -830,7 +944,7 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -856,11 +970,16 @@ impl Display for IRFunctionExpr {
             Floor => "floor",
             #[cfg(feature = "round_series")]
             Ceil => "ceil",
+            #[cfg(feature = "decimal_arithmetic")]
+            DecimalArithmetic { op, .. } => return write!(f, "decimal_{}", <&str>::from(op)),
+            CheckedArithmetic { op, .. } => return write!(f, "checked_{}", <&str>::from(op)),
             #[cfg(feature = "fused")]
             Fused(fused) => return Display::fmt(fused, f),
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
+            #[cfg(feature = "weighted_agg")]
+            Weighted(agg) => return Display::fmt(agg, f),
             #[cfg(feature = "peaks")]
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
@@ -1040,6 +1159,10 @@ impl IRFunctionExpr {
             F::NullCount => FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING),
             #[cfg(feature = "row_hash")]
             F::Hash(_, _, _, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "row_hash")]
+            F::StableHash { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "hash_digest")]
+            F::HashDigest { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "arg_where")]
             F::ArgWhere => FunctionOptions::groupwise(),
             #[cfg(feature = "index_of")]
@@ -1050,6 +1173,8 @@ impl IRFunctionExpr {
             F::SearchSorted { .. } => FunctionOptions::groupwise().with_supertyping(
                 (SuperTypeFlags::default() & !SuperTypeFlags::ALLOW_PRIMITIVE_TO_STRING).into(),
             ),
+            #[cfg(feature = "search_sorted")]
+            F::SearchSortedBy { .. } => FunctionOptions::groupwise(),
             #[cfg(feature = "trigonometry")]
             F::Trigonometry(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "trigonometry")]
@@ -1075,7 +1200,10 @@ impl IRFunctionExpr {
             F::DropNulls => FunctionOptions::row_separable()
                 .flag(FunctionFlags::ALLOW_EMPTY_INPUTS | FunctionFlags::NON_ORDER_PRODUCING),
             #[cfg(feature = "mode")]
-            F::Mode { maintain_order } => FunctionOptions::groupwise().with_flags(|f| {
+            F::Mode {
+                maintain_order,
+                ties: ModeTies::All,
+            } => FunctionOptions::groupwise().with_flags(|f| {
                 let f = f | FunctionFlags::NON_ORDER_PRODUCING;
 
                 if !*maintain_order {
@@ -1084,6 +1212,17 @@ impl IRFunctionExpr {
                     f
                 }
             }),
+            #[cfg(feature = "mode")]
+            F::Mode {
+                ties: ModeTies::First,
+                ..
+            }
+            | F::Modes(_) => FunctionOptions::aggregation(),
+            #[cfg(feature = "mode")]
+            F::Mode {
+                ties: ModeTies::Smallest,
+                ..
+            } => FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING),
             #[cfg(feature = "moment")]
             F::Skew(_) => FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING),
             #[cfg(feature = "moment")]
@@ -1126,7 +1265,8 @@ impl IRFunctionExpr {
             | F::CumSum { .. }
             | F::CumProd { .. }
             | F::CumMin { .. }
-            | F::CumMax { .. } => FunctionOptions::length_preserving(),
+            | F::CumMax { .. }
+            | F::CumAggReset { .. } => FunctionOptions::length_preserving(),
             F::Reverse => FunctionOptions::length_preserving()
                 .with_flags(|f| f | FunctionFlags::NON_ORDER_OBSERVING),
             #[cfg(feature = "dtype-struct")]
@@ -1139,7 +1279,13 @@ impl IRFunctionExpr {
             #[cfg(feature = "unique_counts")]
             F::UniqueCounts => FunctionOptions::groupwise(),
             #[cfg(feature = "approx_unique")]
-            F::ApproxNUnique => {
+            F::ApproxNUnique { .. } | F::Hll(HllFunction::Sketch { .. } | HllFunction::Merge) => {
+                FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
+            },
+            #[cfg(feature = "approx_unique")]
+            F::Hll(HllFunction::Estimate) => FunctionOptions::elementwise(),
+            #[cfg(feature = "approx_quantile")]
+            F::ApproxQuantile { .. } => {
                 FunctionOptions::aggregation().flag(FunctionFlags::NON_ORDER_OBSERVING)
             },
             F::Coalesce => FunctionOptions::elementwise()
@@ -1154,7 +1300,7 @@ impl IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             F::Interpolate(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "interpolate_by")]
-            F::InterpolateBy => FunctionOptions::length_preserving(),
+            F::InterpolateBy(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "log")]
             F::Log | F::Log1p | F::Exp => FunctionOptions::elementwise(),
             #[cfg(feature = "log")]
@@ -1174,6 +1320,11 @@ impl IRFunctionExpr {
             F::Round { .. } | F::RoundSF { .. } | F::Truncate { .. } | F::Floor | F::Ceil => {
                 FunctionOptions::elementwise()
             },
+            #[cfg(feature = "decimal_arithmetic")]
+            F::DecimalArithmetic { .. } => FunctionOptions::elementwise(),
+            F::CheckedArithmetic { .. } => {
+                FunctionOptions::elementwise().with_supertyping(Default::default())
+            },
             #[cfg(feature = "fused")]
             F::Fused(_) => FunctionOptions::elementwise(),
             F::ConcatExpr(_) => FunctionOptions::groupwise()
@@ -1183,6 +1334,8 @@ impl IRFunctionExpr {
             F::Correlation { .. } => {
                 FunctionOptions::aggregation().with_supertyping(Default::default())
             },
+            #[cfg(feature = "weighted_agg")]
+            F::Weighted(_) => FunctionOptions::aggregation(),
             #[cfg(feature = "peaks")]
             F::PeakMin | F::PeakMax => FunctionOptions::length_preserving(),
             #[cfg(feature = "cutqcut")]
@@ -1203,6 +1356,11 @@ impl IRFunctionExpr {
                 method: IRRandomMethod::Shuffle,
                 ..
             } => FunctionOptions::length_preserving(),
+            #[cfg(feature = "uuid")]
+            F::Random {
+                method: IRRandomMethod::Uuid4,
+                ..
+            } => FunctionOptions::groupwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME),
             F::SetSortedFlag(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "ffi_plugin")]
             F::FfiPlugin { flags, .. } => *flags,
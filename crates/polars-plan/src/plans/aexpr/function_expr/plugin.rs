@@ -4,12 +4,13 @@ use std::sync::{LazyLock, RwLock};
 
 use arrow::ffi::{ArrowSchema, import_field_from_c};
 use libloading::Library;
+use polars_ffi::Capabilities;
 #[cfg(feature = "python")]
 use pyo3::{Python, types::PyAnyMethods};
 
 use super::*;
 
-type PluginAndVersion = (Library, u16, u16);
+type PluginAndVersion = (Library, Capabilities);
 static LOADED: LazyLock<RwLock<PlHashMap<String, PluginAndVersion>>> =
     LazyLock::new(Default::default);
 
@@ -45,21 +46,34 @@ fn get_lib(lib: &str) -> PolarsResult<&'static PluginAndVersion> {
         let version_function: libloading::Symbol<unsafe extern "C" fn() -> u32> = unsafe {
             library
                 .get("_polars_plugin_get_version".as_bytes())
-                .unwrap()
+                .map_err(|_| {
+                    polars_err!(ComputeError: "dynamic library '{}' is not a Polars plugin: it doesn't export '_polars_plugin_get_version'", load_path)
+                })?
         };
 
-        let version = unsafe { version_function() };
-        let major = (version >> 16) as u16;
-        let minor = version as u16;
+        let (major, minor) = polars_ffi::decode_version(unsafe { version_function() });
+        let capabilities = polars_ffi::negotiate(major, minor)
+            .map_err(|e| e.wrap_msg(|msg| format!("cannot load plugin '{load_path}': {msg}")))?;
 
         let mut lib_map = LOADED.write().unwrap();
-        lib_map.insert(lib.to_string(), (library, major, minor));
+        lib_map.insert(lib.to_string(), (library, capabilities));
         drop(lib_map);
 
         get_lib(lib)
     }
 }
 
+/// # Safety
+/// `T` must match the signature of the exported function.
+unsafe fn get_symbol<'a, T>(
+    lib: &'a Library,
+    name: &str,
+) -> PolarsResult<libloading::Symbol<'a, T>> {
+    lib.get(name.as_bytes()).map_err(|_| {
+        polars_err!(ComputeError: "the plugin doesn't export function '{}'\n\nMake sure the function exists and was compiled with a compatible 'pyo3-polars' version.", name)
+    })
+}
+
 fn retrieve_error_msg(lib: &Library) -> String {
     unsafe {
         // SAFETY: _polars_plugin_get_last_error_message returns data stored
@@ -78,11 +92,9 @@ pub unsafe fn call_plugin(
     symbol: &str,
     kwargs: &[u8],
 ) -> PolarsResult<Column> {
-    let plugin = get_lib(lib)?;
-    let lib = &plugin.0;
-    let major = plugin.1;
+    let (lib, capabilities) = get_lib(lib)?;
 
-    if major == 0 {
+    if capabilities.major == 0 {
         use polars_ffi::version_0::*;
         // *const SeriesExport: pointer to Box<SeriesExport>
         // * usize: length of that pointer
@@ -99,12 +111,14 @@ pub unsafe fn call_plugin(
                 *mut SeriesExport,
                 *const CallerContext,
             ),
-        > = lib
-            .get(format!("_polars_plugin_{symbol}").as_bytes())
-            .unwrap();
+        > = get_symbol(lib, &format!("_polars_plugin_{symbol}"))?;
 
         // @scalar-correctness?
-        let input = s.iter().map(export_column).collect::<Vec<_>>();
+        let compat_level = capabilities.compat_level();
+        let input = s
+            .iter()
+            .map(|c| export_series_with_compat(c.as_materialized_series(), compat_level))
+            .collect::<Vec<_>>();
         let input_len = s.len();
         let slice_ptr = input.as_ptr();
 
@@ -137,7 +151,7 @@ pub unsafe fn call_plugin(
             polars_bail!(ComputeError: "the plugin failed with message: {}", msg)
         }
     } else {
-        polars_bail!(ComputeError: "this polars engine doesn't support plugin version: {}", major)
+        polars_bail!(ComputeError: "this polars engine doesn't support plugin version: {}", capabilities.major)
     }
 }
 
@@ -147,15 +161,13 @@ pub(super) unsafe fn plugin_field(
     symbol: &str,
     kwargs: &[u8],
 ) -> PolarsResult<Field> {
-    let plugin = get_lib(lib)?;
-    let lib = &plugin.0;
-    let major = plugin.1;
-    let minor = plugin.2;
+    let (lib, capabilities) = get_lib(lib)?;
 
     // we deallocate the fields buffer
+    let compat_level = capabilities.compat_level();
     let ffi_fields = fields
         .iter()
-        .map(|field| arrow::ffi::export_field_to_c(&field.to_arrow(CompatLevel::newest())))
+        .map(|field| arrow::ffi::export_field_to_c(&field.to_arrow(compat_level)))
         .collect::<Vec<_>>()
         .into_boxed_slice();
     let n_args = ffi_fields.len();
@@ -164,48 +176,29 @@ pub(super) unsafe fn plugin_field(
     let mut return_value = ArrowSchema::empty();
     let return_value_ptr = &mut return_value as *mut ArrowSchema;
 
-    if major == 0 {
-        match minor {
-            0 => {
-                let views = fields.iter().any(|field| field.dtype.contains_views());
-                polars_ensure!(!views, ComputeError: "cannot call plugin\n\nThis Polars' version has a different 'binary/string' layout. Please compile with latest 'pyo3-polars'");
-
-                // *const ArrowSchema: pointer to heap Box<ArrowSchema>
-                // usize: length of the boxed slice
-                // *mut ArrowSchema: pointer where the return value can be written
-                let symbol: libloading::Symbol<
-                    unsafe extern "C" fn(*const ArrowSchema, usize, *mut ArrowSchema),
-                > = lib
-                    .get((format!("_polars_plugin_field_{symbol}")).as_bytes())
-                    .unwrap();
-                symbol(slice_ptr, n_args, return_value_ptr);
-            },
-            1 => {
-                // *const ArrowSchema: pointer to heap Box<ArrowSchema>
-                // usize: length of the boxed slice
-                // *mut ArrowSchema: pointer where the return value can be written
-                // *const u8: pointer to &[u8] (kwargs)
-                // usize: length of the u8 slice
-                let symbol: libloading::Symbol<
-                    unsafe extern "C" fn(
-                        *const ArrowSchema,
-                        usize,
-                        *mut ArrowSchema,
-                        *const u8,
-                        usize,
-                    ),
-                > = lib
-                    .get((format!("_polars_plugin_field_{symbol}")).as_bytes())
-                    .unwrap();
-
-                let kwargs_ptr = kwargs.as_ptr();
-                let kwargs_len = kwargs.len();
-
-                symbol(slice_ptr, n_args, return_value_ptr, kwargs_ptr, kwargs_len);
-            },
-            _ => {
-                polars_bail!(ComputeError: "this Polars engine doesn't support plugin version: {}-{}", major, minor)
-            },
+    if capabilities.major == 0 {
+        if capabilities.field_kwargs {
+            // *const ArrowSchema: pointer to heap Box<ArrowSchema>
+            // usize: length of the boxed slice
+            // *mut ArrowSchema: pointer where the return value can be written
+            // *const u8: pointer to &[u8] (kwargs)
+            // usize: length of the u8 slice
+            let symbol: libloading::Symbol<
+                unsafe extern "C" fn(*const ArrowSchema, usize, *mut ArrowSchema, *const u8, usize),
+            > = get_symbol(lib, &format!("_polars_plugin_field_{symbol}"))?;
+
+            let kwargs_ptr = kwargs.as_ptr();
+            let kwargs_len = kwargs.len();
+
+            symbol(slice_ptr, n_args, return_value_ptr, kwargs_ptr, kwargs_len);
+        } else {
+            // *const ArrowSchema: pointer to heap Box<ArrowSchema>
+            // usize: length of the boxed slice
+            // *mut ArrowSchema: pointer where the return value can be written
+            let symbol: libloading::Symbol<
+                unsafe extern "C" fn(*const ArrowSchema, usize, *mut ArrowSchema),
+            > = get_symbol(lib, &format!("_polars_plugin_field_{symbol}"))?;
+            symbol(slice_ptr, n_args, return_value_ptr);
         }
         if !return_value.is_null() {
             let arrow_field = import_field_from_c(&return_value)?;
@@ -217,7 +210,7 @@ pub(super) unsafe fn plugin_field(
             polars_bail!(ComputeError: "the plugin failed with message: {}", msg)
         }
     } else {
-        polars_bail!(ComputeError: "this Polars engine doesn't support plugin version: {}", major)
+        polars_bail!(ComputeError: "this Polars engine doesn't support plugin version: {}", capabilities.major)
     }
 }
 
@@ -12,6 +12,8 @@ pub enum IRRandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    #[cfg(feature = "uuid")]
+    Uuid4,
 }
 
 impl Hash for IRRandomMethod {
@@ -22,6 +22,12 @@ pub enum IRRollingFunction {
         is_corr: bool,
     },
     Map(PlanCallback<Series, Series>),
+    /// Map over batches of windows at once.
+    #[cfg(feature = "dtype-array")]
+    MapBatches {
+        function: PlanCallback<Series, Series>,
+        batch_size: usize,
+    },
 }
 
 impl Display for IRRollingFunction {
@@ -50,6 +56,8 @@ impl Display for IRRollingFunction {
                 }
             },
             Map(_) => "map",
+            #[cfg(feature = "dtype-array")]
+            MapBatches { .. } => "map_batches",
         };
 
         write!(f, "rolling_{name}")
@@ -47,12 +47,22 @@ impl IRFunctionExpr {
             Coalesce => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "row_hash")]
+            StableHash { .. } => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "hash_digest")]
+            HashDigest { hex, .. } => mapper.with_dtype(if *hex {
+                DataType::String
+            } else {
+                DataType::Binary
+            }),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "index_of")]
             IndexOf => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "search_sorted")]
             SearchSorted { .. } => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "search_sorted")]
+            SearchSortedBy { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "range")]
             Range(func) => func.get_field(mapper),
             #[cfg(feature = "trigonometry")]
@@ -67,6 +77,20 @@ impl IRFunctionExpr {
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
                 use IRRollingFunction::*;
+                // Weighted windows of integers are mapped as floats.
+                let map_field = |field: &Field| -> PolarsResult<Field> {
+                    if options.weights.is_some() {
+                        let dtype = match field.dtype() {
+                            #[cfg(feature = "dtype-f16")]
+                            DataType::Float16 => DataType::Float16,
+                            DataType::Float32 => DataType::Float32,
+                            _ => DataType::Float64,
+                        };
+                        Ok(Field::new(field.name().clone(), dtype))
+                    } else {
+                        Ok(field.clone())
+                    }
+                };
                 match function {
                     Min | Max => mapper.with_same_dtype(),
                     Mean | Quantile | Std => mapper.moment_dtype(),
@@ -89,19 +113,9 @@ impl IRFunctionExpr {
                     }),
                     #[cfg(feature = "moment")]
                     Skew | Kurtosis => mapper.map_to_float_dtype(),
-                    Map(_) => mapper.try_map_field(|field| {
-                        if options.weights.is_some() {
-                            let dtype = match field.dtype() {
-                                #[cfg(feature = "dtype-f16")]
-                                DataType::Float16 => DataType::Float16,
-                                DataType::Float32 => DataType::Float32,
-                                _ => DataType::Float64,
-                            };
-                            Ok(Field::new(field.name().clone(), dtype))
-                        } else {
-                            Ok(field.clone())
-                        }
-                    }),
+                    Map(_) => mapper.try_map_field(map_field),
+                    #[cfg(feature = "dtype-array")]
+                    MapBatches { .. } => mapper.try_map_field(map_field),
                 }
             },
             #[cfg(feature = "rolling_window_by")]
@@ -143,7 +157,9 @@ impl IRFunctionExpr {
                 has_max: _,
             } => mapper.with_same_dtype(),
             #[cfg(feature = "mode")]
-            Mode { maintain_order: _ } => mapper.with_same_dtype(),
+            Mode { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "mode")]
+            Modes(_) => mapper.map_dtype(|dt| DataType::List(Box::new(dt.clone()))),
             #[cfg(feature = "moment")]
             Skew(_) => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "moment")]
@@ -168,7 +184,7 @@ impl IRFunctionExpr {
             Repeat => mapper.with_same_dtype(),
             #[cfg(feature = "rank")]
             Rank { options, .. } => mapper.with_dtype(match options.method {
-                RankMethod::Average => DataType::Float64,
+                RankMethod::Average | RankMethod::Percent => DataType::Float64,
                 _ => IDX_DTYPE,
             }),
             #[cfg(feature = "dtype-struct")]
@@ -223,8 +239,23 @@ impl IRFunctionExpr {
             CumMin { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "cum_agg")]
+            CumAggReset { function, .. } => match function {
+                ListCumFunction::Sum => mapper.map_dtype(cum::dtypes::cum_sum),
+                ListCumFunction::Prod => mapper.map_dtype(cum::dtypes::cum_prod),
+                ListCumFunction::Min | ListCumFunction::Max => mapper.with_same_dtype(),
+                ListCumFunction::Count => mapper.with_dtype(IDX_DTYPE),
+            },
+            #[cfg(feature = "approx_unique")]
+            ApproxNUnique { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            Hll(HllFunction::Sketch { .. } | HllFunction::Merge) => {
+                mapper.with_dtype(DataType::Binary)
+            },
+            #[cfg(feature = "approx_unique")]
+            Hll(HllFunction::Estimate) => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -279,7 +310,14 @@ impl IRFunctionExpr {
                 InterpolationMethod::Nearest => mapper.with_same_dtype(),
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => mapper.map_numeric_to_float_dtype(true),
+            InterpolateBy(method) => match method {
+                InterpolateByMethod::Linear | InterpolateByMethod::CubicSpline => {
+                    mapper.map_numeric_to_float_dtype(true)
+                },
+                InterpolateByMethod::Nearest
+                | InterpolateByMethod::Forward
+                | InterpolateByMethod::Backward => mapper.with_same_dtype(),
+            },
             #[cfg(feature = "log")]
             Entropy { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
             #[cfg(feature = "log")]
@@ -289,14 +327,41 @@ impl IRFunctionExpr {
             Round { .. } | RoundSF { .. } | Truncate { .. } | Floor | Ceil => {
                 mapper.with_same_dtype()
             },
+            #[cfg(feature = "decimal_arithmetic")]
+            DecimalArithmetic { op, .. } => mapper.with_dtype(decimal_arithmetic_dtype(
+                mapper.args()[0].dtype(),
+                mapper.args()[1].dtype(),
+                *op,
+            )?),
+            CheckedArithmetic { .. } => mapper.map_to_supertype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
             ConcatExpr(_) => mapper.map_to_supertype(),
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "weighted_agg")]
+            Weighted(_) => mapper.map_to_float_dtype(),
             #[cfg(feature = "peaks")]
             PeakMin | PeakMax => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "cutqcut")]
+            Cut {
+                include_bins: true, ..
+            }
+            | QCut {
+                include_bins: true, ..
+            } => {
+                let struct_dt = DataType::Struct(vec![
+                    Field::new(
+                        PlSmallStr::from_static("category"),
+                        DataType::from_categories(Categories::global()),
+                    ),
+                    Field::new(PlSmallStr::from_static("lower"), DataType::Float64),
+                    Field::new(PlSmallStr::from_static("upper"), DataType::Float64),
+                    Field::new(PlSmallStr::from_static("bin_index"), IDX_DTYPE),
+                ]);
+                mapper.with_dtype(struct_dt)
+            },
+            #[cfg(feature = "cutqcut")]
             Cut {
                 include_breaks: false,
                 ..
@@ -357,6 +422,11 @@ impl IRFunctionExpr {
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
             ToPhysical => mapper.to_physical_type(),
+            #[cfg(feature = "uuid")]
+            Random {
+                method: IRRandomMethod::Uuid4,
+                ..
+            } => mapper.with_dtype(DataType::String),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
             SetSortedFlag(_) => mapper.with_same_dtype(),
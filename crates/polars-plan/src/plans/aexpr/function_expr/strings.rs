@@ -54,10 +54,18 @@ pub enum IRStringFunction {
     LenBytes,
     LenChars,
     Lowercase,
+    Casefold,
     #[cfg(feature = "extract_jsonpath")]
     JsonDecode(DataType),
     #[cfg(feature = "extract_jsonpath")]
     JsonPathMatch,
+    #[cfg(feature = "extract_jsonpath")]
+    JsonPathMatchAll(Option<DataType>),
+    #[cfg(feature = "extract_jsonpath")]
+    JsonExtractMany {
+        paths: Arc<[PlSmallStr]>,
+        dtype: Option<DataType>,
+    },
     #[cfg(feature = "regex")]
     Replace {
         // negative is replace all
@@ -71,6 +79,26 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    LevenshteinDistance,
+    #[cfg(feature = "string_similarity")]
+    HammingDistance,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_tokenize")]
+    Tokenize {
+        method: TokenizeMethod,
+        lowercase: bool,
+        stop_words: Arc<[PlSmallStr]>,
+    },
+    #[cfg(feature = "string_url")]
+    UrlPart(UrlPart),
+    /// Decode the URL query into a struct with a field per key, or into a list of key/value
+    /// structs if no keys are given.
+    #[cfg(feature = "string_url")]
+    UrlQueryParams(Option<Arc<[PlSmallStr]>>),
+    #[cfg(feature = "string_url")]
+    UrlDecodePercent,
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -87,9 +115,23 @@ pub enum IRStringFunction {
     #[cfg(feature = "binary_encoding")]
     HexDecode(bool),
     #[cfg(feature = "string_encoding")]
-    Base64Encode,
+    Base64Encode {
+        alphabet: Base64Alphabet,
+        padding: bool,
+    },
     #[cfg(feature = "binary_encoding")]
-    Base64Decode(bool),
+    Base64Decode {
+        alphabet: Base64Alphabet,
+        strict: bool,
+    },
+    #[cfg(feature = "ip_address")]
+    ParseIp {
+        strict: bool,
+    },
+    #[cfg(feature = "uuid")]
+    ParseUuid {
+        strict: bool,
+    },
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -106,9 +148,13 @@ pub enum IRStringFunction {
     #[cfg(feature = "temporal")]
     // DataType can only be Date/Datetime/Time
     Strptime(DataType, StrptimeOptions),
+    #[cfg(feature = "temporal")]
+    // DataType is the resolved type of the parsed values
+    StrptimeMulti(DataType, StrptimeMultiOptions),
     Split(bool),
     #[cfg(feature = "regex")]
     SplitRegex {
+        n: Option<usize>,
         inclusive: bool,
         strict: bool,
     },
@@ -169,6 +215,21 @@ impl IRStringFunction {
             JsonDecode(dtype) => mapper.with_dtype(dtype.clone()),
             #[cfg(feature = "extract_jsonpath")]
             JsonPathMatch => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "extract_jsonpath")]
+            JsonPathMatchAll(dtype) => mapper.with_dtype(DataType::List(Box::new(
+                dtype.clone().unwrap_or(DataType::String),
+            ))),
+            #[cfg(feature = "extract_jsonpath")]
+            JsonExtractMany { paths, dtype } => {
+                mapper.with_dtype(dtype.clone().unwrap_or_else(|| {
+                    DataType::Struct(
+                        paths
+                            .iter()
+                            .map(|path| Field::new(path.clone(), DataType::String))
+                            .collect(),
+                    )
+                }))
+            },
             LenBytes => mapper.with_dtype(DataType::UInt32),
             LenChars => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "regex")]
@@ -177,6 +238,26 @@ impl IRStringFunction {
             Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance | HammingDistance => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "string_tokenize")]
+            Tokenize { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            #[cfg(feature = "string_url")]
+            UrlPart(_) | UrlDecodePercent => mapper.with_same_dtype(),
+            #[cfg(feature = "string_url")]
+            UrlQueryParams(keys) => mapper.with_dtype(match keys {
+                Some(keys) => DataType::Struct(
+                    keys.iter()
+                        .map(|key| Field::new(key.clone(), DataType::String))
+                        .collect(),
+                ),
+                None => DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new(PlSmallStr::from_static("key"), DataType::String),
+                    Field::new(PlSmallStr::from_static("value"), DataType::String),
+                ]))),
+            }),
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => match dtype {
                 #[cfg(feature = "dtype-datetime")]
@@ -195,6 +276,24 @@ impl IRStringFunction {
                 },
                 _ => mapper.with_dtype(dtype.clone()),
             },
+            #[cfg(feature = "temporal")]
+            StrptimeMulti(dtype, options) => {
+                if options.include_format {
+                    #[cfg(feature = "dtype-struct")]
+                    {
+                        mapper.with_dtype(DataType::Struct(vec![
+                            Field::new(PlSmallStr::from_static("value"), dtype.clone()),
+                            Field::new(PlSmallStr::from_static("format"), DataType::String),
+                        ]))
+                    }
+                    #[cfg(not(feature = "dtype-struct"))]
+                    {
+                        polars_bail!(InvalidOperation: "activate 'dtype-struct' feature")
+                    }
+                } else {
+                    mapper.with_dtype(dtype.clone())
+                }
+            },
             Split(_) => mapper.with_dtype(DataType::List(DataType::String.into())),
             #[cfg(feature = "regex")]
             SplitRegex { .. } => mapper.with_dtype(DataType::List(DataType::String.into())),
@@ -207,11 +306,15 @@ impl IRStringFunction {
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "string_encoding")]
-            Base64Encode => mapper.with_same_dtype(),
+            Base64Encode { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
-            Base64Decode(_) => mapper.with_dtype(DataType::Binary),
-            Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
-            | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
+            Base64Decode { .. } => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "ip_address")]
+            ParseIp { .. } => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "uuid")]
+            ParseUuid { .. } => mapper.with_dtype(DataType::Binary),
+            Uppercase | Lowercase | Casefold | StripChars | StripCharsStart | StripCharsEnd
+            | StripPrefix | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
             #[cfg(feature = "string_pad")]
             PadStart { .. } | PadEnd { .. } | ZFill => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
@@ -266,7 +369,9 @@ impl IRStringFunction {
             #[cfg(feature = "extract_jsonpath")]
             S::JsonDecode { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "extract_jsonpath")]
-            S::JsonPathMatch => FunctionOptions::elementwise(),
+            S::JsonPathMatch | S::JsonPathMatchAll(_) | S::JsonExtractMany { .. } => {
+                FunctionOptions::elementwise()
+            },
             S::LenBytes | S::LenChars => FunctionOptions::elementwise(),
             #[cfg(feature = "regex")]
             S::Replace { .. } => {
@@ -276,20 +381,38 @@ impl IRStringFunction {
             S::Normalize { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_similarity")]
+            S::LevenshteinDistance | S::HammingDistance | S::JaroWinkler => {
+                FunctionOptions::elementwise()
+            },
+            #[cfg(feature = "string_tokenize")]
+            S::Tokenize { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_url")]
+            S::UrlPart(_) | S::UrlQueryParams(_) | S::UrlDecodePercent => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, _) => FunctionOptions::elementwise_with_infer(),
+            #[cfg(feature = "temporal")]
+            S::StrptimeMulti(_, options) if !options.best_effort => FunctionOptions::elementwise(),
+            #[cfg(feature = "temporal")]
+            S::StrptimeMulti(_, _) => FunctionOptions::elementwise_with_infer(),
             S::Split(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "nightly")]
             S::Titlecase => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-decimal")]
             S::ToDecimal { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_encoding")]
-            S::HexEncode | S::Base64Encode => FunctionOptions::elementwise(),
+            S::HexEncode | S::Base64Encode { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "binary_encoding")]
-            S::HexDecode(_) | S::Base64Decode(_) => FunctionOptions::elementwise(),
-            S::Uppercase | S::Lowercase => FunctionOptions::elementwise(),
+            S::HexDecode(_) | S::Base64Decode { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "ip_address")]
+            S::ParseIp { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "uuid")]
+            S::ParseUuid { .. } => FunctionOptions::elementwise(),
+            S::Uppercase | S::Lowercase | S::Casefold => FunctionOptions::elementwise(),
             S::StripChars
             | S::StripCharsStart
             | S::StripCharsEnd
@@ -347,8 +470,13 @@ impl Display for IRStringFunction {
             JsonDecode(..) => "json_decode",
             #[cfg(feature = "extract_jsonpath")]
             JsonPathMatch => "json_path_match",
+            #[cfg(feature = "extract_jsonpath")]
+            JsonPathMatchAll(_) => "json_path_match_all",
+            #[cfg(feature = "extract_jsonpath")]
+            JsonExtractMany { .. } => "json_extract_many",
             LenBytes => "len_bytes",
             Lowercase => "to_lowercase",
+            Casefold => "casefold",
             LenChars => "len_chars",
             #[cfg(feature = "string_pad")]
             PadEnd { .. } => "pad_end",
@@ -360,14 +488,32 @@ impl Display for IRStringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            LevenshteinDistance => "levenshtein_distance",
+            #[cfg(feature = "string_similarity")]
+            HammingDistance => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_tokenize")]
+            Tokenize { .. } => "tokenize",
+            #[cfg(feature = "string_url")]
+            UrlPart(part) => return write!(f, "str.url.{}", part.name()),
+            #[cfg(feature = "string_url")]
+            UrlQueryParams(_) => "url.query_params",
+            #[cfg(feature = "string_url")]
+            UrlDecodePercent => "url.decode_percent",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) => "hex_decode",
             #[cfg(feature = "string_encoding")]
-            Base64Encode => "base64_encode",
+            Base64Encode { .. } => "base64_encode",
             #[cfg(feature = "binary_encoding")]
-            Base64Decode(_) => "base64_decode",
+            Base64Decode { .. } => "base64_decode",
+            #[cfg(feature = "ip_address")]
+            ParseIp { .. } => "parse_ip",
+            #[cfg(feature = "uuid")]
+            ParseUuid { .. } => "parse_uuid",
             Slice => "slice",
             StartsWith => "starts_with",
             StripChars => "strip_chars",
@@ -387,6 +533,8 @@ impl Display for IRStringFunction {
             SplitN(_) => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "temporal")]
+            StrptimeMulti(_, _) => "strptime_multi",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
@@ -477,6 +477,9 @@ impl AExpr {
                         !matches!(arena.get(input[0].node()), AExpr::Literal(_))
                             && (strptime_options.strict || ambiguous_is_fallible)
                     },
+                    IRStringFunction::StrptimeMulti(_, _) => {
+                        !matches!(arena.get(input[0].node()), AExpr::Literal(_))
+                    },
                     _ => false,
                 },
                 _ => false,
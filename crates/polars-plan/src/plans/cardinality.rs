@@ -0,0 +1,509 @@
+//! Row-count and number-of-distinct-values estimation of IR nodes.
+//!
+//! Estimates are derived from exact sizes where these are known (in-memory frames, file
+//! metadata), from user hints ([`HintIR::EstimatedRows`]) and otherwise from classic selectivity
+//! heuristics. They steer decisions such as the build side of joins in the streaming engine, and
+//! must never be relied upon for correctness.
+use std::fmt;
+
+use polars_core::prelude::*;
+use recursive::recursive;
+
+use crate::prelude::*;
+
+/// Selectivity of a predicate we know nothing about.
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+/// Selectivity of an equality predicate if the number of distinct values is unknown.
+const EQ_SELECTIVITY: f64 = 0.1;
+/// Selectivity of a range predicate (`<`, `<=`, `>`, `>=`).
+const RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+/// Selectivity of `is_null`.
+const NULL_SELECTIVITY: f64 = 0.1;
+/// Ratio of groups to input rows if the number of distinct keys is unknown.
+const GROUP_BY_REDUCTION: f64 = 0.1;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CardinalityEstimate {
+    /// Estimated number of output rows of the node.
+    pub rows: usize,
+    /// Whether `rows` is the exact number of output rows.
+    pub exact: bool,
+    /// Estimated number of distinct values for the columns where it is known.
+    pub n_distinct: PlHashMap<PlSmallStr, usize>,
+}
+
+impl CardinalityEstimate {
+    fn exact(rows: usize) -> Self {
+        Self {
+            rows,
+            exact: true,
+            n_distinct: Default::default(),
+        }
+    }
+
+    fn estimated(rows: usize) -> Self {
+        Self {
+            rows,
+            exact: false,
+            n_distinct: Default::default(),
+        }
+    }
+
+    /// Scale the row count by `selectivity`, capping the distinct counts by the new row count.
+    fn scaled(&self, selectivity: f64) -> Self {
+        let rows = scale(self.rows, selectivity);
+        Self {
+            rows,
+            exact: self.exact && selectivity == 1.0,
+            n_distinct: self
+                .n_distinct
+                .iter()
+                .map(|(k, v)| (k.clone(), (*v).min(rows)))
+                .collect(),
+        }
+    }
+
+    /// Limit the row count to `len`.
+    fn limited(&self, len: usize) -> Self {
+        let mut out = self.clone();
+        out.rows = out.rows.min(len);
+        out.n_distinct.values_mut().for_each(|v| *v = (*v).min(len));
+        out
+    }
+
+    pub fn n_distinct(&self, column: &str) -> Option<usize> {
+        self.n_distinct.get(column).copied()
+    }
+}
+
+impl fmt::Display for CardinalityEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.exact {
+            write!(f, "rows: {}", self.rows)
+        } else {
+            write!(f, "rows: ~{}", self.rows)
+        }
+    }
+}
+
+fn scale(rows: usize, factor: f64) -> usize {
+    let out = (rows as f64 * factor).ceil();
+    if out >= usize::MAX as f64 {
+        usize::MAX
+    } else {
+        out as usize
+    }
+}
+
+/// Cardinality estimates of every node reachable from a root.
+#[derive(Clone, Debug, Default)]
+pub struct CardinalityEstimates {
+    estimates: PlHashMap<Node, CardinalityEstimate>,
+}
+
+impl CardinalityEstimates {
+    pub fn get(&self, node: Node) -> Option<&CardinalityEstimate> {
+        self.estimates.get(&node)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Node, &CardinalityEstimate)> {
+        self.estimates.iter().map(|(n, e)| (*n, e))
+    }
+}
+
+/// Estimate the cardinality of `root` and all its inputs.
+pub fn estimate_cardinality(
+    root: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> CardinalityEstimates {
+    let mut out = CardinalityEstimates::default();
+    estimate_rec(root, ir_arena, expr_arena, &mut out);
+    out
+}
+
+#[recursive]
+fn estimate_rec(
+    node: Node,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    out: &mut CardinalityEstimates,
+) -> CardinalityEstimate {
+    if let Some(e) = out.estimates.get(&node) {
+        // Shared subplans (e.g. caches) are only estimated once.
+        return e.clone();
+    }
+
+    let input =
+        |node: Node, out: &mut CardinalityEstimates| estimate_rec(node, ir_arena, expr_arena, out);
+
+    use IR::*;
+    let estimate = match ir_arena.get(node) {
+        #[cfg(feature = "python")]
+        PythonScan { options } => match options.n_rows {
+            Some(n) => CardinalityEstimate::estimated(n),
+            None => CardinalityEstimate::estimated(usize::MAX),
+        },
+        DataFrameScan { df, .. } => CardinalityEstimate::exact(df.height()),
+        Scan {
+            file_info,
+            predicate,
+            unified_scan_args,
+            ..
+        } => {
+            let mut e = match file_info.row_estimation {
+                (Some(n), _) => CardinalityEstimate::exact(n),
+                (None, usize::MAX) => CardinalityEstimate::estimated(usize::MAX),
+                (None, n) => CardinalityEstimate::estimated(n),
+            };
+            if let Some(slice) = &unified_scan_args.pre_slice {
+                e = e.limited(slice.len());
+            }
+            if let Some(predicate) = predicate {
+                e = e.scaled(predicate_selectivity(predicate.node(), expr_arena, &e));
+            }
+            e
+        },
+        Slice {
+            input: i,
+            offset,
+            len,
+        } => {
+            let e = input(*i, out);
+            let available = if *offset >= 0 {
+                e.rows.saturating_sub(*offset as usize)
+            } else {
+                e.rows.min(offset.unsigned_abs() as usize)
+            };
+            e.limited(available.min(*len as usize))
+        },
+        Filter {
+            input: i,
+            predicate,
+        } => {
+            let e = input(*i, out);
+            let selectivity = predicate_selectivity(predicate.node(), expr_arena, &e);
+            let mut e = e.scaled(selectivity);
+            // An equality on a literal leaves a single distinct value.
+            for name in equality_columns(predicate.node(), expr_arena) {
+                e.n_distinct.insert(name, 1.min(e.rows));
+            }
+            e
+        },
+        Select { input: i, expr, .. } => project(&input(*i, out), expr, expr_arena, false),
+        HStack { input: i, exprs, .. } => project(&input(*i, out), exprs, expr_arena, true),
+        SimpleProjection { input: i, .. }
+        | Cache { input: i, .. }
+        | ExtContext { input: i, .. }
+        | Sink { input: i, .. } => input(*i, out),
+        Sort {
+            input: i, slice, ..
+        } => {
+            let e = input(*i, out);
+            match slice {
+                Some((_, len, _)) => e.limited(*len),
+                None => e,
+            }
+        },
+        GroupBy {
+            input: i,
+            keys,
+            options,
+            ..
+        } => {
+            let e = input(*i, out);
+            let n_distinct = keys
+                .iter()
+                .map(|k| match expr_arena.get(k.node()) {
+                    AExpr::Column(name) => e.n_distinct(name),
+                    AExpr::Literal(_) => Some(1),
+                    _ => None,
+                })
+                .try_fold(1usize, |acc, v| v.map(|v| acc.saturating_mul(v)));
+            let rows = match n_distinct {
+                Some(n) => n.min(e.rows),
+                None => scale(e.rows, GROUP_BY_REDUCTION).max(1.min(e.rows)),
+            };
+            let mut out = CardinalityEstimate::estimated(rows);
+            for k in keys {
+                let name = k.output_name();
+                let ndv = match expr_arena.get(k.node()) {
+                    AExpr::Column(c) => e.n_distinct(c),
+                    _ => None,
+                };
+                out.n_distinct
+                    .insert(name.clone(), ndv.unwrap_or(rows).min(rows));
+            }
+            match options.slice {
+                Some((_, len)) => out.limited(len),
+                None => out,
+            }
+        },
+        Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } => {
+            let l = input(*input_left, out);
+            let r = input(*input_right, out);
+
+            let key_ndv = |keys: &[ExprIR], e: &CardinalityEstimate| {
+                keys.iter()
+                    .map(|k| match expr_arena.get(k.node()) {
+                        AExpr::Column(name) => e.n_distinct(name),
+                        _ => None,
+                    })
+                    .try_fold(1usize, |acc, v| v.map(|v| acc.saturating_mul(v)))
+            };
+
+            // Classic estimate: |L| * |R| / max(ndv(L.key), ndv(R.key)). Without distinct
+            // counts we assume a foreign-key join onto the smaller side.
+            let inner = match (key_ndv(left_on, &l), key_ndv(right_on, &r)) {
+                (Some(a), Some(b)) => {
+                    let denom = a.max(b).max(1);
+                    (l.rows as f64 * r.rows as f64 / denom as f64).ceil() as usize
+                },
+                _ => l.rows.max(r.rows),
+            };
+
+            let rows = match &options.args.how {
+                JoinType::Inner => inner,
+                JoinType::Left => inner.max(l.rows),
+                JoinType::Right => inner.max(r.rows),
+                JoinType::Full => inner.max(l.rows).saturating_add(r.rows),
+                JoinType::Cross => l.rows.saturating_mul(r.rows),
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi | JoinType::Anti => scale(l.rows, DEFAULT_SELECTIVITY),
+                #[allow(unreachable_patterns)]
+                _ => l.rows,
+            };
+
+            let mut e = CardinalityEstimate::estimated(rows);
+            e.n_distinct.extend(
+                l.n_distinct
+                    .iter()
+                    .chain(r.n_distinct.iter())
+                    .map(|(k, v)| (k.clone(), (*v).min(rows))),
+            );
+            match options.args.slice {
+                Some((_, len)) => e.limited(len),
+                None => e,
+            }
+        },
+        Distinct { input: i, options } => {
+            let e = input(*i, out);
+            let rows = match &options.subset {
+                Some(subset) => subset
+                    .iter()
+                    .map(|c| e.n_distinct(c))
+                    .try_fold(1usize, |acc, v| v.map(|v| acc.saturating_mul(v)))
+                    .map_or(e.rows, |n| n.min(e.rows)),
+                None => e.rows,
+            };
+            let mut out = e.clone();
+            out.rows = rows;
+            out.exact = false;
+            match options.slice {
+                Some((_, len)) => out.limited(len),
+                None => out,
+            }
+        },
+        MapFunction { input: i, function } => {
+            let e = input(*i, out);
+            match function {
+                FunctionIR::Hint(HintIR::EstimatedRows(n)) => {
+                    let mut e = e.clone();
+                    e.rows = *n;
+                    e.exact = false;
+                    e
+                },
+                FunctionIR::FastCount { .. } | FunctionIR::FastStatistics { .. } => {
+                    CardinalityEstimate::exact(1)
+                },
+                FunctionIR::Rechunk
+                | FunctionIR::RowIndex { .. }
+                | FunctionIR::Unnest { .. }
+                | FunctionIR::Hint(_) => e,
+                // Exploding and user-defined functions may change the number of rows.
+                _ => CardinalityEstimate::estimated(e.rows),
+            }
+        },
+        Union { inputs, options } => {
+            let mut rows = 0usize;
+            let mut exact = true;
+            for i in inputs {
+                let e = input(*i, out);
+                rows = rows.saturating_add(e.rows);
+                exact &= e.exact;
+            }
+            let e = CardinalityEstimate {
+                rows,
+                exact,
+                n_distinct: Default::default(),
+            };
+            match options.slice {
+                Some((_, len)) => e.limited(len),
+                None => e,
+            }
+        },
+        HConcat { inputs, .. } => {
+            let mut rows = 0usize;
+            let mut exact = true;
+            let mut n_distinct = PlHashMap::new();
+            for i in inputs {
+                let e = input(*i, out);
+                rows = rows.max(e.rows);
+                exact &= e.exact;
+                n_distinct.extend(e.n_distinct);
+            }
+            CardinalityEstimate {
+                rows,
+                exact,
+                n_distinct,
+            }
+        },
+        SinkMultiple { inputs } => {
+            for i in inputs {
+                input(*i, out);
+            }
+            CardinalityEstimate::estimated(0)
+        },
+        #[cfg(feature = "merge_sorted")]
+        MergeSorted {
+            input_left,
+            input_right,
+            ..
+        } => {
+            let l = input(*input_left, out);
+            let r = input(*input_right, out);
+            CardinalityEstimate {
+                rows: l.rows.saturating_add(r.rows),
+                exact: l.exact && r.exact,
+                n_distinct: Default::default(),
+            }
+        },
+        Invalid => unreachable!(),
+    };
+
+    out.estimates.insert(node, estimate.clone());
+    estimate
+}
+
+/// Estimate of projecting `exprs` on `input`, next to the input columns if `keep_input` is set.
+///
+/// The row count is only exact if the projection provably keeps the height of its input.
+fn project(
+    input: &CardinalityEstimate,
+    exprs: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+    keep_input: bool,
+) -> CardinalityEstimate {
+    let is_scalar = |e: &ExprIR| e.is_scalar(expr_arena);
+    let mut out = if !keep_input && !exprs.is_empty() && exprs.iter().all(is_scalar) {
+        CardinalityEstimate::exact(1)
+    } else if exprs.iter().all(|e| e.is_length_preserving(expr_arena) || is_scalar(e)) {
+        CardinalityEstimate {
+            rows: input.rows,
+            exact: input.exact,
+            n_distinct: Default::default(),
+        }
+    } else {
+        CardinalityEstimate::estimated(input.rows)
+    };
+
+    if keep_input {
+        out.n_distinct = input.n_distinct.clone();
+        for e in exprs {
+            out.n_distinct.remove(e.output_name());
+        }
+    }
+    for e in exprs {
+        let ndv = match expr_arena.get(e.node()) {
+            AExpr::Column(name) => input.n_distinct(name),
+            _ => None,
+        };
+        if let Some(n) = ndv {
+            out.n_distinct.insert(e.output_name().clone(), n.min(out.rows));
+        }
+    }
+    out
+}
+
+/// Columns that are compared for equality with a literal in the (AND-ed) predicate.
+fn equality_columns(node: Node, expr_arena: &Arena<AExpr>) -> Vec<PlSmallStr> {
+    let mut out = vec![];
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        if let AExpr::BinaryExpr { left, op, right } = expr_arena.get(node) {
+            match op {
+                Operator::And | Operator::LogicalAnd => {
+                    stack.push(*left);
+                    stack.push(*right);
+                },
+                Operator::Eq | Operator::EqValidity => {
+                    match (expr_arena.get(*left), expr_arena.get(*right)) {
+                        (AExpr::Column(name), AExpr::Literal(_))
+                        | (AExpr::Literal(_), AExpr::Column(name)) => out.push(name.clone()),
+                        _ => {},
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+    out
+}
+
+/// Estimated fraction of rows of `input` for which `predicate` holds.
+pub fn predicate_selectivity(
+    predicate: Node,
+    expr_arena: &Arena<AExpr>,
+    input: &CardinalityEstimate,
+) -> f64 {
+    let selectivity = |node: Node| predicate_selectivity(node, expr_arena, input);
+
+    let s = match expr_arena.get(predicate) {
+        AExpr::BinaryExpr { left, op, right } => match op {
+            Operator::And | Operator::LogicalAnd => selectivity(*left) * selectivity(*right),
+            Operator::Or | Operator::LogicalOr => {
+                let (a, b) = (selectivity(*left), selectivity(*right));
+                a + b - a * b
+            },
+            Operator::Eq | Operator::EqValidity => {
+                let column = match (expr_arena.get(*left), expr_arena.get(*right)) {
+                    (AExpr::Column(name), _) | (_, AExpr::Column(name)) => Some(name),
+                    _ => None,
+                };
+                match column.and_then(|c| input.n_distinct(c)) {
+                    Some(n) if n > 0 => 1.0 / n as f64,
+                    _ => EQ_SELECTIVITY,
+                }
+            },
+            Operator::NotEq | Operator::NotEqValidity => 1.0 - EQ_SELECTIVITY,
+            Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => RANGE_SELECTIVITY,
+            _ => DEFAULT_SELECTIVITY,
+        },
+        AExpr::Function {
+            input: args,
+            function: IRFunctionExpr::Boolean(f),
+            ..
+        } => match f {
+            IRBooleanFunction::Not => 1.0 - selectivity(args[0].node()),
+            IRBooleanFunction::IsNull => NULL_SELECTIVITY,
+            IRBooleanFunction::IsNotNull => 1.0 - NULL_SELECTIVITY,
+            #[cfg(feature = "is_between")]
+            IRBooleanFunction::IsBetween { .. } => RANGE_SELECTIVITY * RANGE_SELECTIVITY * 2.0,
+            _ => DEFAULT_SELECTIVITY,
+        },
+        AExpr::Literal(lv) => match lv.bool() {
+            Some(true) => 1.0,
+            Some(false) => 0.0,
+            None => DEFAULT_SELECTIVITY,
+        },
+        _ => DEFAULT_SELECTIVITY,
+    };
+    s.clamp(0.0, 1.0)
+}
@@ -864,6 +864,22 @@ fn expand_expression_rec(
                 )?;
             }
         },
+        Expr::AnonymousAgg {
+            input,
+            function,
+            fmt_str,
+        } => expand_expression_by_combination(
+            input,
+            ignored_selector_columns,
+            schema,
+            out,
+            opt_flags,
+            |e| Expr::AnonymousAgg {
+                input: e.to_vec(),
+                function: function.clone(),
+                fmt_str: fmt_str.clone(),
+            },
+        )?,
         Expr::DataTypeFunction(v) => out.push(Expr::DataTypeFunction(v.clone())),
         Expr::Eval {
             expr,
@@ -1,4 +1,6 @@
 use super::functions::convert_functions;
+#[cfg(feature = "dtype-struct")]
+use super::functions::struct_field_path;
 use super::*;
 use crate::constants::{get_pl_element_name, get_pl_structfields_name};
 use crate::plans::iterator::ArenaExprIter;
@@ -410,6 +412,27 @@ pub(super) fn to_aexpr_impl(
                 output_name,
             )
         },
+        Expr::AnonymousAgg {
+            input,
+            function,
+            fmt_str,
+        } => {
+            let input = to_expr_irs(input, ctx)?;
+            polars_ensure!(
+                input.len() == 1,
+                InvalidOperation: "expression: '{}' expects a single input, got {}", fmt_str, input.len()
+            );
+            let output_name = input[0].output_name().clone();
+
+            (
+                AExpr::AnonymousAgg {
+                    input,
+                    function,
+                    fmt_str,
+                },
+                output_name,
+            )
+        },
         Expr::Function { input, function } => {
             return convert_functions(input, function, ctx);
         },
@@ -570,6 +593,7 @@ pub(super) fn to_aexpr_impl(
                     Duplicate: "field with name `{field_name}` has more than one occurrence");
                 eval_ir.push(exprir);
             }
+            let eval_ir = resolve_nested_with_fields(eval_ir, fields, ctx.arena)?;
 
             (
                 AExpr::StructEval {
@@ -614,6 +638,11 @@ pub(super) fn to_aexpr_impl(
             };
 
             if !with_fields.contains(name) {
+                // `pl.field("a.b")` addresses `b` in the struct field `a`.
+                let fields = with_fields.iter_fields().collect::<Vec<_>>();
+                if let Some(path) = struct_field_path(&fields, name) {
+                    return Ok((field_path_node(&path, ctx.arena), name.clone()));
+                }
                 polars_bail!(
                     InvalidOperation: "field `{name}` does not exist on struct with fields {:?}",
                     with_fields.iter_names_cloned().collect::<Vec<_>>().as_slice()
@@ -636,3 +665,152 @@ pub(super) fn to_aexpr_impl(
     };
     Ok((ctx.arena.add(v), output_name))
 }
+
+/// Builds the expression that gets the (nested) field at `path` in the struct context.
+#[cfg(feature = "dtype-struct")]
+fn field_path_node(path: &[PlSmallStr], arena: &mut Arena<AExpr>) -> Node {
+    let mut node = arena.add(AExpr::StructField(path[0].clone()));
+    for name in &path[1..] {
+        let function = IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(name.clone()));
+        let options = function.function_options();
+        let input = ExprIR::from_node(node, arena);
+        node = arena.add(AExpr::Function {
+            input: vec![input],
+            function,
+            options,
+        });
+    }
+    node
+}
+
+/// Rewrites the evaluations of `struct.with_fields` that write to a dotted path like
+/// `"meta.tags"` into a rebuild of the top-level field they are nested in.
+#[cfg(feature = "dtype-struct")]
+fn resolve_nested_with_fields(
+    eval_ir: Vec<ExprIR>,
+    fields: &[Field],
+    arena: &mut Arena<AExpr>,
+) -> PolarsResult<Vec<ExprIR>> {
+    let mut out = Vec::with_capacity(eval_ir.len());
+    let mut nested: PlIndexMap<PlSmallStr, Vec<(Vec<PlSmallStr>, ExprIR)>> = PlIndexMap::new();
+    for e in eval_ir {
+        let name = e.output_name();
+        let path = if name.contains('.') && !fields.iter().any(|fld| fld.name() == name) {
+            nested_update_path(fields, name)
+        } else {
+            None
+        };
+        match path {
+            Some(path) => nested.entry(path[0].clone()).or_default().push((path, e)),
+            None => out.push(e),
+        }
+    }
+
+    for (name, updates) in nested {
+        polars_ensure!(
+            !out.iter().any(|e| e.output_name() == &name),
+            Duplicate: "field `{name}` is both replaced and updated through a nested path"
+        );
+        let fld = fields.iter().find(|fld| fld.name() == &name).unwrap();
+        let node = rebuild_struct(&[name.clone()], fld.dtype(), updates, arena)?;
+        out.push(ExprIR::new(node, OutputName::Alias(name)));
+    }
+    Ok(out)
+}
+
+/// Gets the path of the field that `name` updates. The last segment may be a new field.
+#[cfg(feature = "dtype-struct")]
+fn nested_update_path(fields: &[Field], name: &str) -> Option<Vec<PlSmallStr>> {
+    if let Some(path) = struct_field_path(fields, name) {
+        return Some(path);
+    }
+    let (parent, new_field) = name.rsplit_once('.')?;
+    let mut path = struct_field_path(fields, parent)?;
+
+    let mut fields = fields;
+    for name in &path {
+        match fields.iter().find(|fld| fld.name() == name)?.dtype() {
+            DataType::Struct(inner) => fields = inner,
+            _ => return None,
+        }
+    }
+    path.push(new_field.into());
+    Some(path)
+}
+
+/// Rebuilds the struct at `path` with the given `updates`, which are keyed by their full path.
+#[cfg(feature = "dtype-struct")]
+fn rebuild_struct(
+    path: &[PlSmallStr],
+    dtype: &DataType,
+    mut updates: Vec<(Vec<PlSmallStr>, ExprIR)>,
+    arena: &mut Arena<AExpr>,
+) -> PolarsResult<Node> {
+    let DataType::Struct(fields) = dtype else {
+        unreachable!()
+    };
+    let depth = path.len();
+
+    let mut input = Vec::with_capacity(fields.len());
+    for fld in fields {
+        let name = fld.name();
+        let (own, rest): (Vec<_>, Vec<_>) = updates
+            .into_iter()
+            .partition(|(update_path, _)| &update_path[depth] == name);
+        updates = rest;
+
+        let mut field_path = path.to_vec();
+        field_path.push(name.clone());
+        let e = match own
+            .iter()
+            .position(|(update_path, _)| update_path.len() == depth + 1)
+        {
+            Some(idx) => {
+                polars_ensure!(
+                    own.len() == 1,
+                    Duplicate: "field `{name}` is updated more than once"
+                );
+                own[idx].1.with_alias(name.clone())
+            },
+            None if own.is_empty() => ExprIR::new(
+                field_path_node(&field_path, arena),
+                OutputName::Field(name.clone()),
+            ),
+            None => ExprIR::new(
+                rebuild_struct(&field_path, fld.dtype(), own, arena)?,
+                OutputName::Alias(name.clone()),
+            ),
+        };
+        input.push(e);
+    }
+    // The remaining updates add new fields.
+    input.extend(
+        updates
+            .into_iter()
+            .map(|(update_path, e)| e.with_alias(update_path[depth].clone())),
+    );
+
+    let function = IRFunctionExpr::AsStruct;
+    let options = function.function_options();
+    let rebuilt = arena.add(AExpr::Function {
+        input,
+        function,
+        options,
+    });
+
+    // Keep the struct null where it was null.
+    let function = IRFunctionExpr::Boolean(IRBooleanFunction::IsNotNull);
+    let options = function.function_options();
+    let input = ExprIR::from_node(field_path_node(path, arena), arena);
+    let predicate = arena.add(AExpr::Function {
+        input: vec![input],
+        function,
+        options,
+    });
+    let falsy = arena.add(AExpr::Literal(LiteralValue::untyped_null()));
+    Ok(arena.add(AExpr::Ternary {
+        predicate,
+        truthy: rebuilt,
+        falsy,
+    }))
+}
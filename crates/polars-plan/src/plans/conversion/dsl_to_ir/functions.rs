@@ -19,7 +19,8 @@ pub(super) fn convert_functions(
 
     // Converts inputs
     let input_is_empty = input.is_empty();
-    let e = to_expr_irs(input, ctx)?;
+    #[allow(unused_mut)]
+    let mut e = to_expr_irs(input, ctx)?;
     let mut set_elementwise = false;
 
     // Return before converting inputs
@@ -53,6 +54,12 @@ pub(super) fn convert_functions(
                 A::Contains { nulls_equal } => IA::Contains { nulls_equal },
                 #[cfg(feature = "array_count")]
                 A::CountMatches => IA::CountMatches,
+                #[cfg(feature = "array_linalg")]
+                A::Dot => IA::Dot,
+                #[cfg(feature = "array_linalg")]
+                A::L2Norm => IA::L2Norm,
+                #[cfg(feature = "array_linalg")]
+                A::CosineSimilarity => IA::CosineSimilarity,
                 A::Shift => IA::Shift,
                 A::Explode(options) => IA::Explode(options),
                 A::Concat => IA::Concat,
@@ -72,9 +79,9 @@ pub(super) fn convert_functions(
                 #[cfg(feature = "binary_encoding")]
                 B::HexEncode => IB::HexEncode,
                 #[cfg(feature = "binary_encoding")]
-                B::Base64Decode(v) => IB::Base64Decode(v),
+                B::Base64Decode { alphabet, strict } => IB::Base64Decode { alphabet, strict },
                 #[cfg(feature = "binary_encoding")]
-                B::Base64Encode => IB::Base64Encode,
+                B::Base64Encode { alphabet, padding } => IB::Base64Encode { alphabet, padding },
                 B::Size => IB::Size,
                 #[cfg(feature = "binary_encoding")]
                 B::Reinterpret(dtype_expr, v) => {
@@ -95,6 +102,12 @@ pub(super) fn convert_functions(
                 B::Head => IB::Head,
                 B::Tail => IB::Tail,
                 B::Get(null_on_oob) => IB::Get(null_on_oob),
+                #[cfg(feature = "ip_address")]
+                B::IpIsInSubnet(subnets) => IB::IpIsInSubnet(subnets),
+                #[cfg(feature = "ip_address")]
+                B::IpToInt => IB::IpToInt,
+                #[cfg(feature = "uuid")]
+                B::ToUuidStr => IB::ToUuidStr,
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -102,6 +115,8 @@ pub(super) fn convert_functions(
             use {CategoricalFunction as C, IRCategoricalFunction as IC};
             I::Categorical(match categorical_function {
                 C::GetCategories => IC::GetCategories,
+                C::AddCategories(categories) => IC::AddCategories(categories),
+                C::UnionCategories => IC::UnionCategories,
                 #[cfg(feature = "strings")]
                 C::LenBytes => IC::LenBytes,
                 #[cfg(feature = "strings")]
@@ -157,6 +172,10 @@ pub(super) fn convert_functions(
                 L::GatherEvery => IL::GatherEvery,
                 #[cfg(feature = "list_count")]
                 L::CountMatches => IL::CountMatches,
+                #[cfg(feature = "index_of")]
+                L::IndexOf => IL::IndexOf,
+                #[cfg(feature = "index_of")]
+                L::Find => IL::Find,
                 L::Sum => IL::Sum,
                 L::Length => IL::Length,
                 L::Max => IL::Max,
@@ -169,12 +188,26 @@ pub(super) fn convert_functions(
                 L::ArgMax => IL::ArgMax,
                 #[cfg(feature = "diff")]
                 L::Diff { n, null_behavior } => IL::Diff { n, null_behavior },
+                #[cfg(feature = "cum_agg")]
+                L::CumAgg { function, reverse } => IL::CumAgg { function, reverse },
+                #[cfg(feature = "rolling_window")]
+                L::Rolling {
+                    function,
+                    window_size,
+                    min_periods,
+                    center,
+                } => IL::Rolling {
+                    function,
+                    window_size,
+                    min_periods,
+                    center,
+                },
                 L::Sort(sort_options) => IL::Sort(sort_options),
                 L::Reverse => IL::Reverse,
                 L::Unique(v) => IL::Unique(v),
                 L::NUnique => IL::NUnique,
                 #[cfg(feature = "list_sets")]
-                L::SetOperation(set_operation) => IL::SetOperation(set_operation),
+                L::SetOperation { op, multiset } => IL::SetOperation { op, multiset },
                 #[cfg(feature = "list_any_all")]
                 L::Any => IL::Any,
                 #[cfg(feature = "list_any_all")]
@@ -238,16 +271,55 @@ pub(super) fn convert_functions(
                 S::LenBytes => IS::LenBytes,
                 S::LenChars => IS::LenChars,
                 S::Lowercase => IS::Lowercase,
+                S::Casefold => IS::Casefold,
                 #[cfg(feature = "extract_jsonpath")]
                 S::JsonDecode(dtype) => IS::JsonDecode(dtype.into_datatype(ctx.schema)?),
                 #[cfg(feature = "extract_jsonpath")]
                 S::JsonPathMatch => IS::JsonPathMatch,
+                #[cfg(feature = "extract_jsonpath")]
+                S::JsonPathMatchAll(dtype) => {
+                    IS::JsonPathMatchAll(dtype.try_map(|dtype| dtype.into_datatype(ctx.schema))?)
+                },
+                #[cfg(feature = "extract_jsonpath")]
+                S::JsonExtractMany { paths, dtype } => {
+                    let dtype = dtype.try_map(|dtype| dtype.into_datatype(ctx.schema))?;
+                    if let Some(dtype) = &dtype {
+                        polars_ensure!(
+                            matches!(dtype, DataType::Struct(fields) if fields.len() == paths.len()),
+                            SchemaMismatch: "`json_extract_many` expected a struct dtype with {} fields, got {}",
+                            paths.len(), dtype
+                        );
+                    }
+                    IS::JsonExtractMany { paths, dtype }
+                },
                 #[cfg(feature = "regex")]
                 S::Replace { n, literal } => IS::Replace { n, literal },
                 #[cfg(feature = "string_normalize")]
                 S::Normalize { form } => IS::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
+                #[cfg(feature = "string_similarity")]
+                S::LevenshteinDistance => IS::LevenshteinDistance,
+                #[cfg(feature = "string_similarity")]
+                S::HammingDistance => IS::HammingDistance,
+                #[cfg(feature = "string_similarity")]
+                S::JaroWinkler => IS::JaroWinkler,
+                #[cfg(feature = "string_tokenize")]
+                S::Tokenize {
+                    method,
+                    lowercase,
+                    stop_words,
+                } => IS::Tokenize {
+                    method,
+                    lowercase,
+                    stop_words,
+                },
+                #[cfg(feature = "string_url")]
+                S::UrlPart(part) => IS::UrlPart(part),
+                #[cfg(feature = "string_url")]
+                S::UrlQueryParams(keys) => IS::UrlQueryParams(keys),
+                #[cfg(feature = "string_url")]
+                S::UrlDecodePercent => IS::UrlDecodePercent,
                 #[cfg(feature = "string_pad")]
                 S::PadStart { fill_char } => IS::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -260,9 +332,13 @@ pub(super) fn convert_functions(
                 #[cfg(feature = "binary_encoding")]
                 S::HexDecode(v) => IS::HexDecode(v),
                 #[cfg(feature = "string_encoding")]
-                S::Base64Encode => IS::Base64Encode,
+                S::Base64Encode { alphabet, padding } => IS::Base64Encode { alphabet, padding },
                 #[cfg(feature = "binary_encoding")]
-                S::Base64Decode(v) => IS::Base64Decode(v),
+                S::Base64Decode { alphabet, strict } => IS::Base64Decode { alphabet, strict },
+                #[cfg(feature = "ip_address")]
+                S::ParseIp { strict } => IS::ParseIp { strict },
+                #[cfg(feature = "uuid")]
+                S::ParseUuid { strict } => IS::ParseUuid { strict },
                 S::StartsWith => IS::StartsWith,
                 S::StripChars => IS::StripChars,
                 S::StripCharsStart => IS::StripCharsStart,
@@ -274,7 +350,15 @@ pub(super) fn convert_functions(
                 #[cfg(feature = "dtype-struct")]
                 S::SplitN(v) => IS::SplitN(v),
                 #[cfg(feature = "regex")]
-                S::SplitRegex { inclusive, strict } => IS::SplitRegex { inclusive, strict },
+                S::SplitRegex {
+                    n,
+                    inclusive,
+                    strict,
+                } => IS::SplitRegex {
+                    n,
+                    inclusive,
+                    strict,
+                },
                 #[cfg(feature = "temporal")]
                 S::Strptime(data_type, strptime_options) => {
                     let is_column_independent = is_column_independent_aexpr(e[0].node(), ctx.arena);
@@ -290,6 +374,45 @@ pub(super) fn convert_functions(
                     );
                     IS::Strptime(dtype, strptime_options)
                 },
+                #[cfg(feature = "temporal")]
+                S::StrptimeMulti(data_type, options) => {
+                    let is_column_independent = is_column_independent_aexpr(e[0].node(), ctx.arena);
+                    set_elementwise = is_column_independent;
+                    let dtype = data_type.into_datatype(ctx.schema)?;
+                    polars_ensure!(
+                        matches!(dtype,
+                            DataType::Date |
+                            DataType::Datetime(_, _) |
+                            DataType::Time
+                        ),
+                        InvalidOperation: "`strptime` expects a `date`, `datetime` or `time` got {dtype}"
+                    );
+                    polars_ensure!(
+                        !options.formats.is_empty() || options.best_effort,
+                        InvalidOperation: "`strptime` expects at least one format, or `best_effort` parsing"
+                    );
+                    #[cfg(all(feature = "regex", feature = "timezones"))]
+                    let dtype = match dtype {
+                        DataType::Datetime(time_unit, None) => {
+                            let n_tz_aware = options
+                                .formats
+                                .iter()
+                                .filter(|format| {
+                                    crate::plans::TZ_AWARE_RE.is_match(format.as_str())
+                                })
+                                .count();
+                            polars_ensure!(
+                                n_tz_aware == 0 || n_tz_aware == options.formats.len(),
+                                InvalidOperation: "cannot mix formats with and without a UTC offset directive; \
+                                set `time_zone` to parse them into a common time zone"
+                            );
+                            let time_zone = (n_tz_aware > 0).then_some(TimeZone::UTC);
+                            DataType::Datetime(time_unit, time_zone)
+                        },
+                        dtype => dtype,
+                    };
+                    IS::StrptimeMulti(dtype, options)
+                },
                 S::Split(v) => IS::Split(v),
                 #[cfg(feature = "dtype-decimal")]
                 S::ToDecimal { scale } => IS::ToDecimal { scale },
@@ -340,7 +463,7 @@ pub(super) fn convert_functions(
         F::StructExpr(struct_function) => {
             use {IRStructFunction as IS, StructFunction as S};
             I::StructExpr(match struct_function {
-                S::FieldByName(pl_small_str) => IS::FieldByName(pl_small_str),
+                S::FieldByName(name) => IS::FieldByName(resolve_nested_field(&mut e, name, ctx)?),
                 S::RenameFields(pl_small_strs) => IS::RenameFields(pl_small_strs),
                 S::PrefixFields(pl_small_str) => IS::PrefixFields(pl_small_str),
                 S::SuffixFields(pl_small_str) => IS::SuffixFields(pl_small_str),
@@ -360,9 +483,11 @@ pub(super) fn convert_functions(
                 T::IsLeapYear => IT::IsLeapYear,
                 T::IsoYear => IT::IsoYear,
                 T::Quarter => IT::Quarter,
+                T::FiscalYear(start_month) => IT::FiscalYear(start_month),
+                T::FiscalQuarter(start_month) => IT::FiscalQuarter(start_month),
                 T::Month => IT::Month,
                 T::DaysInMonth => IT::DaysInMonth,
-                T::Week => IT::Week,
+                T::Week(convention) => IT::Week(convention),
                 T::WeekDay => IT::WeekDay,
                 T::Day => IT::Day,
                 T::OrdinalDay => IT::OrdinalDay,
@@ -564,6 +689,10 @@ pub(super) fn convert_functions(
         }),
         #[cfg(feature = "row_hash")]
         F::Hash(s0, s1, s2, s3) => I::Hash(s0, s1, s2, s3),
+        #[cfg(feature = "row_hash")]
+        F::StableHash { seed } => I::StableHash { seed },
+        #[cfg(feature = "hash_digest")]
+        F::HashDigest { algorithm, hex } => I::HashDigest { algorithm, hex },
         #[cfg(feature = "arg_where")]
         F::ArgWhere => I::ArgWhere,
         #[cfg(feature = "index_of")]
@@ -573,6 +702,36 @@ pub(super) fn convert_functions(
         },
         #[cfg(feature = "search_sorted")]
         F::SearchSorted { side, descending } => I::SearchSorted { side, descending },
+        #[cfg(feature = "search_sorted")]
+        F::SearchSortedBy {
+            side,
+            mut descending,
+            mut nulls_last,
+        } => {
+            polars_ensure!(
+                !e.is_empty() && e.len() % 2 == 0,
+                InvalidOperation: "`search_sorted_by` requires the same number of sorted and search columns"
+            );
+            let n_by = e.len() / 2;
+            for (flags, name) in [
+                (&mut descending, "descending"),
+                (&mut nulls_last, "nulls_last"),
+            ] {
+                if flags.len() == 1 {
+                    *flags = vec![flags[0]; n_by];
+                }
+                polars_ensure!(
+                    flags.len() == n_by,
+                    InvalidOperation: "expected {} `{}` values for {} columns, got {}",
+                    n_by, name, n_by, flags.len()
+                );
+            }
+            I::SearchSortedBy {
+                side,
+                descending,
+                nulls_last,
+            }
+        },
         #[cfg(feature = "range")]
         F::Range(range_function) => I::Range(match range_function {
             RangeFunction::IntRange { step, dtype } => {
@@ -738,6 +897,14 @@ pub(super) fn convert_functions(
                         is_corr,
                     },
                     R::Map(f) => IR::Map(f),
+                    #[cfg(feature = "dtype-array")]
+                    R::MapBatches {
+                        function,
+                        batch_size,
+                    } => IR::MapBatches {
+                        function,
+                        batch_size,
+                    },
                 },
                 options,
             }
@@ -778,7 +945,15 @@ pub(super) fn convert_functions(
         F::DropNans => I::DropNans,
         F::DropNulls => I::DropNulls,
         #[cfg(feature = "mode")]
-        F::Mode { maintain_order } => I::Mode { maintain_order },
+        F::Mode {
+            maintain_order,
+            ties,
+        } => I::Mode {
+            maintain_order,
+            ties,
+        },
+        #[cfg(feature = "mode")]
+        F::Modes(n) => I::Modes(n),
         #[cfg(feature = "moment")]
         F::Skew(v) => I::Skew(v),
         #[cfg(feature = "moment")]
@@ -814,7 +989,19 @@ pub(super) fn convert_functions(
         #[cfg(feature = "top_k")]
         F::TopK { descending } => I::TopK { descending },
         #[cfg(feature = "top_k")]
-        F::TopKBy { descending } => I::TopKBy { descending },
+        F::TopKBy { mut descending } => {
+            let n_by = e.len() - 2;
+            polars_ensure!(n_by > 0, InvalidOperation: "`top_k_by` requires at least one `by` column");
+            if descending.len() == 1 {
+                descending = vec![descending[0]; n_by];
+            }
+            polars_ensure!(
+                descending.len() == n_by,
+                InvalidOperation: "expected {1} `descending` values for {1} `by` columns, got {0}",
+                descending.len(), n_by
+            );
+            I::TopKBy { descending }
+        },
         #[cfg(feature = "cum_agg")]
         F::CumCount { reverse } => I::CumCount { reverse },
         #[cfg(feature = "cum_agg")]
@@ -825,6 +1012,8 @@ pub(super) fn convert_functions(
         F::CumMin { reverse } => I::CumMin { reverse },
         #[cfg(feature = "cum_agg")]
         F::CumMax { reverse } => I::CumMax { reverse },
+        #[cfg(feature = "cum_agg")]
+        F::CumAggReset { function, reverse } => I::CumAggReset { function, reverse },
         F::Reverse => I::Reverse,
         #[cfg(feature = "dtype-struct")]
         F::ValueCounts {
@@ -841,7 +1030,30 @@ pub(super) fn convert_functions(
         #[cfg(feature = "unique_counts")]
         F::UniqueCounts => I::UniqueCounts,
         #[cfg(feature = "approx_unique")]
-        F::ApproxNUnique => I::ApproxNUnique,
+        F::ApproxNUnique { precision } => {
+            if let Some(precision) = precision {
+                check_hll_precision(precision)?;
+            }
+            I::ApproxNUnique { precision }
+        },
+        #[cfg(feature = "approx_unique")]
+        F::Hll(f) => {
+            if let HllFunction::Sketch { precision } = f {
+                check_hll_precision(precision)?;
+            }
+            I::Hll(f)
+        },
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile {
+            quantile,
+            compression,
+        } => {
+            check_approx_quantile_args(quantile, compression)?;
+            I::ApproxQuantile {
+                quantile,
+                compression,
+            }
+        },
         F::Coalesce => I::Coalesce,
         #[cfg(feature = "diff")]
         F::Diff(n) => {
@@ -853,7 +1065,7 @@ pub(super) fn convert_functions(
         #[cfg(feature = "interpolate")]
         F::Interpolate(interpolation_method) => I::Interpolate(interpolation_method),
         #[cfg(feature = "interpolate_by")]
-        F::InterpolateBy => I::InterpolateBy,
+        F::InterpolateBy(method) => I::InterpolateBy(method),
         #[cfg(feature = "log")]
         F::Entropy { base, normalize } => I::Entropy { base, normalize },
         #[cfg(feature = "log")]
@@ -873,6 +1085,9 @@ pub(super) fn convert_functions(
         F::Floor => I::Floor,
         #[cfg(feature = "round_series")]
         F::Ceil => I::Ceil,
+        #[cfg(feature = "decimal_arithmetic")]
+        F::DecimalArithmetic { op, options } => I::DecimalArithmetic { op, options },
+        F::CheckedArithmetic { op, warn } => I::CheckedArithmetic { op, warn },
         F::UpperBound => {
             let field = e[0].field(ctx.schema, ctx.arena)?;
             return Ok((
@@ -902,6 +1117,8 @@ pub(super) fn convert_functions(
                 },
             }
         },
+        #[cfg(feature = "weighted_agg")]
+        F::Weighted(agg) => I::Weighted(agg),
         #[cfg(feature = "peaks")]
         F::PeakMin => I::PeakMin,
         #[cfg(feature = "peaks")]
@@ -912,11 +1129,13 @@ pub(super) fn convert_functions(
             labels,
             left_closed,
             include_breaks,
+            include_bins,
         } => I::Cut {
             breaks,
             labels,
             left_closed,
             include_breaks,
+            include_bins,
         },
         #[cfg(feature = "cutqcut")]
         F::QCut {
@@ -925,12 +1144,14 @@ pub(super) fn convert_functions(
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bins,
         } => I::QCut {
             probs,
             labels,
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bins,
         },
         #[cfg(feature = "rle")]
         F::RLE => I::RLE,
@@ -952,6 +1173,8 @@ pub(super) fn convert_functions(
                         with_replacement,
                         shuffle,
                     },
+                    #[cfg(feature = "uuid")]
+                    R::Uuid4 => IR::Uuid4,
                 },
                 seed,
             }
@@ -1130,3 +1353,56 @@ pub(super) fn convert_functions(
     };
     Ok((ctx.arena.add(ae_function), output_name))
 }
+
+/// Resolves a dotted path like `"a.b.c"` in `struct.field`. This points `e[0]` at the innermost
+/// struct and returns the name of the field to get from it. A field that is named exactly like
+/// the path takes precedence.
+#[cfg(feature = "dtype-struct")]
+fn resolve_nested_field(
+    e: &mut [ExprIR],
+    name: PlSmallStr,
+    ctx: &mut ExprToIRContext,
+) -> PolarsResult<PlSmallStr> {
+    if !name.contains('.') {
+        return Ok(name);
+    }
+    let fields = match e[0].dtype(ctx.schema, ctx.arena)? {
+        DataType::Struct(fields) => fields.clone(),
+        _ => return Ok(name),
+    };
+    let Some(mut path) = struct_field_path(&fields, &name) else {
+        return Ok(name);
+    };
+
+    let last = path.pop().unwrap();
+    for field in path {
+        let function = IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(field.clone()));
+        let options = function.function_options();
+        let node = ctx.arena.add(AExpr::Function {
+            input: vec![e[0].clone()],
+            function,
+            options,
+        });
+        e[0] = ExprIR::new(node, OutputName::Field(field));
+    }
+    Ok(last)
+}
+
+/// Splits `path` into the names of the (nested) struct fields it addresses.
+#[cfg(feature = "dtype-struct")]
+pub(super) fn struct_field_path(fields: &[Field], path: &str) -> Option<Vec<PlSmallStr>> {
+    if let Some(fld) = fields.iter().find(|fld| fld.name().as_str() == path) {
+        return Some(vec![fld.name().clone()]);
+    }
+    // Try the longest prefix first, so that field names containing dots still resolve.
+    for (i, _) in path.rmatch_indices('.') {
+        if let Some(fld) = fields.iter().find(|fld| fld.name().as_str() == &path[..i])
+            && let DataType::Struct(inner) = fld.dtype()
+            && let Some(mut rest) = struct_field_path(inner, &path[i + 1..])
+        {
+            rest.insert(0, fld.name().clone());
+            return Some(rest);
+        }
+    }
+    None
+}
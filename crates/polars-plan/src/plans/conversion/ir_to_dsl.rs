@@ -215,13 +215,11 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
         AExpr::AnonymousAgg {
             input,
             fmt_str,
-            function: _,
-        } => {
-            let inputs = expr_irs_to_exprs(input.clone(), expr_arena);
-            Expr::Display {
-                inputs,
-                fmt_str: fmt_str.clone(),
-            }
+            function,
+        } => Expr::AnonymousAgg {
+            input: expr_irs_to_exprs(input, expr_arena),
+            function,
+            fmt_str,
         },
         AExpr::AnonymousFunction {
             input,
@@ -342,6 +340,12 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IA::Contains { nulls_equal } => A::Contains { nulls_equal },
                 #[cfg(feature = "array_count")]
                 IA::CountMatches => A::CountMatches,
+                #[cfg(feature = "array_linalg")]
+                IA::Dot => A::Dot,
+                #[cfg(feature = "array_linalg")]
+                IA::L2Norm => A::L2Norm,
+                #[cfg(feature = "array_linalg")]
+                IA::CosineSimilarity => A::CosineSimilarity,
                 IA::Shift => A::Shift,
                 IA::Slice(offset, length) => A::Slice(offset, length),
                 IA::Explode(options) => A::Explode(options),
@@ -360,9 +364,9 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 #[cfg(feature = "binary_encoding")]
                 IB::HexEncode => B::HexEncode,
                 #[cfg(feature = "binary_encoding")]
-                IB::Base64Decode(v) => B::Base64Decode(v),
+                IB::Base64Decode { alphabet, strict } => B::Base64Decode { alphabet, strict },
                 #[cfg(feature = "binary_encoding")]
-                IB::Base64Encode => B::Base64Encode,
+                IB::Base64Encode { alphabet, padding } => B::Base64Encode { alphabet, padding },
                 IB::Size => B::Size,
                 #[cfg(feature = "binary_encoding")]
                 IB::Reinterpret(data_type, v) => B::Reinterpret(data_type.into(), v),
@@ -370,6 +374,12 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Head => B::Head,
                 IB::Tail => B::Tail,
                 IB::Get(null_on_oob) => B::Get(null_on_oob),
+                #[cfg(feature = "ip_address")]
+                IB::IpIsInSubnet(subnets) => B::IpIsInSubnet(subnets),
+                #[cfg(feature = "ip_address")]
+                IB::IpToInt => B::IpToInt,
+                #[cfg(feature = "uuid")]
+                IB::ToUuidStr => B::ToUuidStr,
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -377,6 +387,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             use {CategoricalFunction as C, IRCategoricalFunction as IC};
             F::Categorical(match f {
                 IC::GetCategories => C::GetCategories,
+                IC::AddCategories(categories) => C::AddCategories(categories),
+                IC::UnionCategories => C::UnionCategories,
                 #[cfg(feature = "strings")]
                 IC::LenBytes => C::LenBytes,
                 #[cfg(feature = "strings")]
@@ -426,6 +438,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::GatherEvery => L::GatherEvery,
                 #[cfg(feature = "list_count")]
                 IL::CountMatches => L::CountMatches,
+                #[cfg(feature = "index_of")]
+                IL::IndexOf => L::IndexOf,
+                #[cfg(feature = "index_of")]
+                IL::Find => L::Find,
                 IL::Sum => L::Sum,
                 IL::Length => L::Length,
                 IL::Max => L::Max,
@@ -438,12 +454,26 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::ArgMax => L::ArgMax,
                 #[cfg(feature = "diff")]
                 IL::Diff { n, null_behavior } => L::Diff { n, null_behavior },
+                #[cfg(feature = "cum_agg")]
+                IL::CumAgg { function, reverse } => L::CumAgg { function, reverse },
+                #[cfg(feature = "rolling_window")]
+                IL::Rolling {
+                    function,
+                    window_size,
+                    min_periods,
+                    center,
+                } => L::Rolling {
+                    function,
+                    window_size,
+                    min_periods,
+                    center,
+                },
                 IL::Sort(sort_options) => L::Sort(sort_options),
                 IL::Reverse => L::Reverse,
                 IL::Unique(v) => L::Unique(v),
                 IL::NUnique => L::NUnique,
                 #[cfg(feature = "list_sets")]
-                IL::SetOperation(set_operation) => L::SetOperation(set_operation),
+                IL::SetOperation { op, multiset } => L::SetOperation { op, multiset },
                 #[cfg(feature = "list_any_all")]
                 IL::Any => L::Any,
                 #[cfg(feature = "list_any_all")]
@@ -491,16 +521,46 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::LenBytes => B::LenBytes,
                 IB::LenChars => B::LenChars,
                 IB::Lowercase => B::Lowercase,
+                IB::Casefold => B::Casefold,
                 #[cfg(feature = "extract_jsonpath")]
                 IB::JsonDecode(dtype) => B::JsonDecode(dtype.into()),
                 #[cfg(feature = "extract_jsonpath")]
                 IB::JsonPathMatch => B::JsonPathMatch,
+                #[cfg(feature = "extract_jsonpath")]
+                IB::JsonPathMatchAll(dtype) => B::JsonPathMatchAll(dtype.map(Into::into)),
+                #[cfg(feature = "extract_jsonpath")]
+                IB::JsonExtractMany { paths, dtype } => B::JsonExtractMany {
+                    paths,
+                    dtype: dtype.map(Into::into),
+                },
                 #[cfg(feature = "regex")]
                 IB::Replace { n, literal } => B::Replace { n, literal },
                 #[cfg(feature = "string_normalize")]
                 IB::Normalize { form } => B::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
+                #[cfg(feature = "string_similarity")]
+                IB::LevenshteinDistance => B::LevenshteinDistance,
+                #[cfg(feature = "string_similarity")]
+                IB::HammingDistance => B::HammingDistance,
+                #[cfg(feature = "string_similarity")]
+                IB::JaroWinkler => B::JaroWinkler,
+                #[cfg(feature = "string_tokenize")]
+                IB::Tokenize {
+                    method,
+                    lowercase,
+                    stop_words,
+                } => B::Tokenize {
+                    method,
+                    lowercase,
+                    stop_words,
+                },
+                #[cfg(feature = "string_url")]
+                IB::UrlPart(part) => B::UrlPart(part),
+                #[cfg(feature = "string_url")]
+                IB::UrlQueryParams(keys) => B::UrlQueryParams(keys),
+                #[cfg(feature = "string_url")]
+                IB::UrlDecodePercent => B::UrlDecodePercent,
                 #[cfg(feature = "string_pad")]
                 IB::PadStart { fill_char } => B::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -513,9 +573,13 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 #[cfg(feature = "binary_encoding")]
                 IB::HexDecode(v) => B::HexDecode(v),
                 #[cfg(feature = "string_encoding")]
-                IB::Base64Encode => B::Base64Encode,
+                IB::Base64Encode { alphabet, padding } => B::Base64Encode { alphabet, padding },
                 #[cfg(feature = "binary_encoding")]
-                IB::Base64Decode(v) => B::Base64Decode(v),
+                IB::Base64Decode { alphabet, strict } => B::Base64Decode { alphabet, strict },
+                #[cfg(feature = "ip_address")]
+                IB::ParseIp { strict } => B::ParseIp { strict },
+                #[cfg(feature = "uuid")]
+                IB::ParseUuid { strict } => B::ParseUuid { strict },
                 IB::StartsWith => B::StartsWith,
                 IB::StripChars => B::StripChars,
                 IB::StripCharsStart => B::StripCharsStart,
@@ -530,9 +594,19 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Strptime(dtype, strptime_options) => {
                     B::Strptime(dtype.into(), strptime_options)
                 },
+                #[cfg(feature = "temporal")]
+                IB::StrptimeMulti(dtype, options) => B::StrptimeMulti(dtype.into(), options),
                 IB::Split(v) => B::Split(v),
                 #[cfg(feature = "regex")]
-                IB::SplitRegex { inclusive, strict } => B::SplitRegex { inclusive, strict },
+                IB::SplitRegex {
+                    n,
+                    inclusive,
+                    strict,
+                } => B::SplitRegex {
+                    n,
+                    inclusive,
+                    strict,
+                },
                 #[cfg(feature = "dtype-decimal")]
                 IB::ToDecimal { scale } => B::ToDecimal { scale },
                 #[cfg(feature = "nightly")]
@@ -601,9 +675,11 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::IsLeapYear => B::IsLeapYear,
                 IB::IsoYear => B::IsoYear,
                 IB::Quarter => B::Quarter,
+                IB::FiscalYear(start_month) => B::FiscalYear(start_month),
+                IB::FiscalQuarter(start_month) => B::FiscalQuarter(start_month),
                 IB::Month => B::Month,
                 IB::DaysInMonth => B::DaysInMonth,
-                IB::Week => B::Week,
+                IB::Week(convention) => B::Week(convention),
                 IB::WeekDay => B::WeekDay,
                 IB::Day => B::Day,
                 IB::OrdinalDay => B::OrdinalDay,
@@ -751,12 +827,26 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         },
         #[cfg(feature = "row_hash")]
         IF::Hash(s0, s1, s2, s3) => F::Hash(s0, s1, s2, s3),
+        #[cfg(feature = "row_hash")]
+        IF::StableHash { seed } => F::StableHash { seed },
+        #[cfg(feature = "hash_digest")]
+        IF::HashDigest { algorithm, hex } => F::HashDigest { algorithm, hex },
         #[cfg(feature = "arg_where")]
         IF::ArgWhere => F::ArgWhere,
         #[cfg(feature = "index_of")]
         IF::IndexOf => F::IndexOf,
         #[cfg(feature = "search_sorted")]
         IF::SearchSorted { side, descending } => F::SearchSorted { side, descending },
+        #[cfg(feature = "search_sorted")]
+        IF::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        } => F::SearchSortedBy {
+            side,
+            descending,
+            nulls_last,
+        },
         #[cfg(feature = "range")]
         IF::Range(f) => {
             use {IRRangeFunction as IR, RangeFunction as R};
@@ -883,6 +973,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                         is_corr,
                     },
                     IR::Map(f) => R::Map(f),
+                    #[cfg(feature = "dtype-array")]
+                    IR::MapBatches {
+                        function,
+                        batch_size,
+                    } => R::MapBatches {
+                        function,
+                        batch_size,
+                    },
                 },
                 options,
             }
@@ -914,7 +1012,15 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::DropNans => F::DropNans,
         IF::DropNulls => F::DropNulls,
         #[cfg(feature = "mode")]
-        IF::Mode { maintain_order } => F::Mode { maintain_order },
+        IF::Mode {
+            maintain_order,
+            ties,
+        } => F::Mode {
+            maintain_order,
+            ties,
+        },
+        #[cfg(feature = "mode")]
+        IF::Modes(n) => F::Modes(n),
         #[cfg(feature = "moment")]
         IF::Skew(v) => F::Skew(v),
         #[cfg(feature = "moment")]
@@ -957,6 +1063,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::CumMin { reverse } => F::CumMin { reverse },
         #[cfg(feature = "cum_agg")]
         IF::CumMax { reverse } => F::CumMax { reverse },
+        #[cfg(feature = "cum_agg")]
+        IF::CumAggReset { function, reverse } => F::CumAggReset { function, reverse },
         IF::Reverse => F::Reverse,
         #[cfg(feature = "dtype-struct")]
         IF::ValueCounts {
@@ -973,7 +1081,17 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         #[cfg(feature = "unique_counts")]
         IF::UniqueCounts => F::UniqueCounts,
         #[cfg(feature = "approx_unique")]
-        IF::ApproxNUnique => F::ApproxNUnique,
+        IF::ApproxNUnique { precision } => F::ApproxNUnique { precision },
+        #[cfg(feature = "approx_unique")]
+        IF::Hll(f) => F::Hll(f),
+        #[cfg(feature = "approx_quantile")]
+        IF::ApproxQuantile {
+            quantile,
+            compression,
+        } => F::ApproxQuantile {
+            quantile,
+            compression,
+        },
         IF::Coalesce => F::Coalesce,
         #[cfg(feature = "diff")]
         IF::Diff(nb) => F::Diff(nb),
@@ -982,7 +1100,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         #[cfg(feature = "interpolate")]
         IF::Interpolate(m) => F::Interpolate(m),
         #[cfg(feature = "interpolate_by")]
-        IF::InterpolateBy => F::InterpolateBy,
+        IF::InterpolateBy(m) => F::InterpolateBy(m),
         #[cfg(feature = "log")]
         IF::Entropy { base, normalize } => F::Entropy { base, normalize },
         #[cfg(feature = "log")]
@@ -1002,6 +1120,9 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Floor => F::Floor,
         #[cfg(feature = "round_series")]
         IF::Ceil => F::Ceil,
+        #[cfg(feature = "decimal_arithmetic")]
+        IF::DecimalArithmetic { op, options } => F::DecimalArithmetic { op, options },
+        IF::CheckedArithmetic { op, warn } => F::CheckedArithmetic { op, warn },
         #[cfg(feature = "fused")]
         IF::Fused(f) => {
             assert_eq!(input.len(), 3);
@@ -1028,6 +1149,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 },
             }
         },
+        #[cfg(feature = "weighted_agg")]
+        IF::Weighted(agg) => F::Weighted(agg),
         #[cfg(feature = "peaks")]
         IF::PeakMin => F::PeakMin,
         #[cfg(feature = "peaks")]
@@ -1038,11 +1161,13 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             labels,
             left_closed,
             include_breaks,
+            include_bins,
         } => F::Cut {
             breaks,
             labels,
             left_closed,
             include_breaks,
+            include_bins,
         },
         #[cfg(feature = "cutqcut")]
         IF::QCut {
@@ -1051,12 +1176,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bins,
         } => F::QCut {
             probs,
             labels,
             left_closed,
             allow_duplicates,
             include_breaks,
+            include_bins,
         },
         #[cfg(feature = "rle")]
         IF::RLE => F::RLE,
@@ -1078,6 +1205,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                         with_replacement,
                         shuffle,
                     },
+                    #[cfg(feature = "uuid")]
+                    IR::Uuid4 => R::Uuid4,
                 },
                 seed,
             }
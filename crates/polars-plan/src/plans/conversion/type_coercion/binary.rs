@@ -334,6 +334,7 @@ pub(super) fn process_binary(
     // in a group_by context. To be able to cast the groups need to be
     // flattened
     let new_node_left = if type_left != st {
+        check_implicit_cast(node_left, &type_left, &st, expr_arena)?;
         expr_arena.add(AExpr::Cast {
             expr: node_left,
             dtype: st.clone(),
@@ -343,6 +344,7 @@ pub(super) fn process_binary(
         node_left
     };
     let new_node_right = if type_right != st {
+        check_implicit_cast(node_right, &type_right, &st, expr_arena)?;
         expr_arena.add(AExpr::Cast {
             expr: node_right,
             dtype: st,
@@ -3,6 +3,7 @@ use polars_core::prelude::*;
 use polars_time::Duration;
 use polars_utils::arena::Arena;
 
+use super::check_implicit_cast;
 use crate::plans::{AExpr, ExprIR, IRFunctionExpr, IRTemporalFunction, LiteralValue};
 
 #[macro_export]
@@ -64,6 +65,7 @@ pub fn coerce_temporal_dt(
                 _ => (),
             }
         }
+        check_implicit_cast(expr.node(), from_type, to_type, expr_arena)?;
         expr.set_node(expr_arena.add(AExpr::Cast {
             expr: expr.node(),
             dtype: to_type.clone(),
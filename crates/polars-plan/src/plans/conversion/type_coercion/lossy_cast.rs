@@ -0,0 +1,118 @@
+use polars_config::LossyCasts;
+
+use super::*;
+
+/// Bits of magnitude an integer type can hold.
+fn integer_bits(dtype: &DataType) -> Option<u32> {
+    use DataType::*;
+    Some(match dtype {
+        Int8 => 7,
+        UInt8 => 8,
+        Int16 => 15,
+        UInt16 => 16,
+        Int32 => 31,
+        UInt32 => 32,
+        Int64 => 63,
+        UInt64 => 64,
+        Int128 => 127,
+        UInt128 => 128,
+        _ => return None,
+    })
+}
+
+/// Bits of the significand of a float type, including the implicit bit.
+fn significand_bits(dtype: &DataType) -> Option<u32> {
+    use DataType::*;
+    Some(match dtype {
+        Float16 => 11,
+        Float32 => 24,
+        Float64 => 53,
+        _ => return None,
+    })
+}
+
+fn time_unit_rank(tu: TimeUnit) -> u8 {
+    match tu {
+        TimeUnit::Nanoseconds => 0,
+        TimeUnit::Microseconds => 1,
+        TimeUnit::Milliseconds => 2,
+    }
+}
+
+/// Returns why casting `from` to `to` may lose information, if it may.
+fn lossy_cast_reason(from: &DataType, to: &DataType) -> Option<String> {
+    use DataType::*;
+    match (from, to) {
+        (from, to) if from.is_integer() && to.is_float() => {
+            let bits = significand_bits(to)?;
+            (integer_bits(from)? > bits)
+                .then(|| format!("integers above 2^{bits} can't be represented exactly"))
+        },
+        (from, to) if from.is_float() && to.is_float() => {
+            let lossy = significand_bits(from)? > significand_bits(to)?;
+            lossy.then(|| "floats lose precision".to_string())
+        },
+        (Datetime(from_tu, _), Datetime(to_tu, _)) | (Duration(from_tu), Duration(to_tu))
+            if time_unit_rank(*to_tu) > time_unit_rank(*from_tu) =>
+        {
+            Some(format!("values are truncated to {to_tu}"))
+        },
+        (Datetime(_, _), Date) => Some("the time of day is dropped".to_string()),
+        #[cfg(feature = "dtype-decimal")]
+        (Decimal(from_precision, from_scale), Decimal(to_precision, to_scale)) => {
+            if to_scale < from_scale {
+                Some(format!(
+                    "the scale is reduced from {from_scale} to {to_scale}"
+                ))
+            } else if to_precision - to_scale < from_precision - from_scale {
+                Some("integer digits may not fit".to_string())
+            } else {
+                None
+            }
+        },
+        #[cfg(feature = "dtype-decimal")]
+        (Decimal(precision, _), to) if to.is_float() => {
+            // Number of decimal digits a float represents exactly.
+            let digits = match to {
+                Float16 => 3,
+                Float32 => 6,
+                _ => 15,
+            };
+            (*precision > digits).then(|| {
+                format!("decimals with more than {digits} digits can't be represented exactly")
+            })
+        },
+        _ => None,
+    }
+}
+
+/// Report an implicit cast of `node` that may lose information, as configured with
+/// `POLARS_LOSSY_CASTS`.
+///
+/// Casts of literals are not reported, as those fail on values that don't fit.
+pub(super) fn check_implicit_cast(
+    node: Node,
+    from: &DataType,
+    to: &DataType,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<()> {
+    let lossy_casts = polars_config::config().lossy_casts();
+    if lossy_casts == LossyCasts::Ignore || matches!(expr_arena.get(node), AExpr::Literal(_)) {
+        return Ok(());
+    }
+    let Some(reason) = lossy_cast_reason(from, to) else {
+        return Ok(());
+    };
+
+    match lossy_casts {
+        LossyCasts::Ignore => {},
+        LossyCasts::Warn => polars_warn!(
+            LossyCastWarning,
+            "implicit cast from {from} to {to} may lose information: {reason}\n\nConsider casting explicitly."
+        ),
+        LossyCasts::Raise => polars_bail!(
+            InvalidOperation: "implicit cast from {from} to {to} may lose information: {reason}\n\nConsider casting explicitly."
+        ),
+    }
+    Ok(())
+}
@@ -7,6 +7,7 @@ mod datetime;
 mod functions;
 #[cfg(feature = "is_in")]
 mod is_in;
+mod lossy_cast;
 
 use binary::process_binary;
 #[cfg(all(
@@ -16,6 +17,7 @@ use binary::process_binary;
 use datetime::coerce_temporal_dt;
 #[cfg(all(feature = "range", feature = "dtype-datetime"))]
 use datetime::{ensure_datetime, ensure_int, temporal_range_output_type};
+use lossy_cast::check_implicit_cast;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::*;
 #[cfg(all(
@@ -207,6 +209,7 @@ impl OptimizationRule for TypeCoercionRule {
                 // in a group_by context. To be able to cast the groups need to be
                 // flattened
                 let new_node_truthy = if type_true != st {
+                    check_implicit_cast(truthy_node, &type_true, &st, expr_arena)?;
                     expr_arena.add(AExpr::Cast {
                         expr: truthy_node,
                         dtype: st.clone(),
@@ -217,6 +220,7 @@ impl OptimizationRule for TypeCoercionRule {
                 };
 
                 let new_node_falsy = if type_false != st {
+                    check_implicit_cast(falsy_node, &type_false, &st, expr_arena)?;
                     expr_arena.add(AExpr::Cast {
                         expr: falsy_node,
                         dtype: st,
@@ -649,6 +653,7 @@ impl OptimizationRule for TypeCoercionRule {
                     let (self_ae, type_self) =
                         unpack!(get_aexpr_and_type(expr_arena, self_e.node(), schema));
                     let mut super_type = type_self.clone();
+                    let mut cast_inputs = true;
                     match casting_rules {
                         CastingRules::Supertype(super_type_opts) => {
                             for other in &input[1..] {
@@ -680,9 +685,24 @@ impl OptimizationRule for TypeCoercionRule {
                             }
                         },
                         CastingRules::FirstArgLossless => {
-                            for other in &input[1..] {
-                                let other = other.dtype(schema, expr_arena)?;
-                                can_cast_to_lossless(&super_type, other)?;
+                            if let (IRFunctionExpr::ListExpr(_), DataType::List(inner)) =
+                                (&function, &type_self)
+                            {
+                                // List functions look up the other arguments in the elements,
+                                // they are cast when executing, as values that are out of range
+                                // are simply never found.
+                                for other in &input[1..] {
+                                    let other = other.dtype(schema, expr_arena)?;
+                                    if !matches!(other, DataType::Unknown(UnknownKind::Int(_))) {
+                                        can_cast_to_lossless(inner, other)?;
+                                    }
+                                }
+                                cast_inputs = false;
+                            } else {
+                                for other in &input[1..] {
+                                    let other = other.dtype(schema, expr_arena)?;
+                                    can_cast_to_lossless(&super_type, other)?;
+                                }
                             }
                         },
                     }
@@ -700,8 +720,16 @@ impl OptimizationRule for TypeCoercionRule {
                         _ => {},
                     }
 
-                    for (e, dtype) in input.iter_mut().zip(dtypes) {
-                        cast_expr_ir(e, &dtype, &super_type, expr_arena, CastOptions::NonStrict)?;
+                    if cast_inputs {
+                        for (e, dtype) in input.iter_mut().zip(dtypes) {
+                            cast_expr_ir(
+                                e,
+                                &dtype,
+                                &super_type,
+                                expr_arena,
+                                CastOptions::NonStrict,
+                            )?;
+                        }
                     }
                 }
 
@@ -1479,6 +1507,7 @@ fn cast_expr_ir(
         }
     }
 
+    check_implicit_cast(e.node(), from_dtype, to_dtype, expr_arena)?;
     e.set_node(expr_arena.add(AExpr::Cast {
         expr: e.node(),
         dtype: to_dtype.clone(),
@@ -16,6 +16,9 @@ pub struct OpaquePythonUdf {
     pub projection_pd: bool,
     pub streamable: bool,
     pub validate_output: bool,
+    /// properties of the function that allow further optimizations
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flags: UdfFlags,
 }
 
 // Except for Opaque functions, this only has the DSL name of the function.
@@ -48,6 +51,7 @@ pub enum DslFunction {
     Unnest {
         columns: Selector,
         separator: Option<PlSmallStr>,
+        options: UnnestOptions,
     },
     Stats(StatsFunction),
     /// FillValue
@@ -148,7 +152,11 @@ impl DslFunction {
                     schema: Default::default(),
                 }
             },
-            DslFunction::Unnest { columns, separator } => {
+            DslFunction::Unnest {
+                columns,
+                separator,
+                options,
+            } => {
                 let columns = columns.into_columns(input_schema, &Default::default())?;
                 let columns: Arc<[PlSmallStr]> = columns.into_iter().collect();
                 for col in columns.iter() {
@@ -158,7 +166,23 @@ impl DslFunction {
                         InvalidOperation: "invalid dtype: expected 'Struct', got '{:?}' for '{}'", dtype, col
                     );
                 }
-                FunctionIR::Unnest { columns, separator }
+                #[cfg(feature = "dtype-struct")]
+                if let Some(fields) = &options.fields {
+                    for field in fields.iter() {
+                        let found = columns.iter().any(|col| match input_schema.get(col) {
+                            Some(DataType::Struct(flds)) => {
+                                flds.iter().any(|fld| fld.name() == field)
+                            },
+                            _ => false,
+                        });
+                        polars_ensure!(found, StructFieldNotFound: "{field}");
+                    }
+                }
+                FunctionIR::Unnest {
+                    columns,
+                    separator,
+                    options,
+                }
             },
             DslFunction::Hint(h) => FunctionIR::Hint(h),
             #[cfg(feature = "python")]
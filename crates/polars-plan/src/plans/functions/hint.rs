@@ -25,6 +25,8 @@ pub struct Sorted {
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum HintIR {
     Sorted(Arc<[Sorted]>),
+    /// User provided estimate of the number of rows.
+    EstimatedRows(usize),
 }
 
 impl HintIR {
@@ -50,6 +52,7 @@ impl HintIR {
                 );
                 Some(Self::Sorted(sorted.into()))
             },
+            Self::EstimatedRows(_) => Some(self.clone()),
         }
     }
 }
@@ -95,6 +98,7 @@ impl fmt::Display for HintIR {
                 }
                 write!(f, ")")
             },
+            HintIR::EstimatedRows(n) => write!(f, "estimated_rows({n})"),
         }
     }
 }
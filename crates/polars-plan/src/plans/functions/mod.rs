@@ -4,12 +4,14 @@ mod hint;
 #[cfg(feature = "python")]
 mod python_udf;
 mod schema;
+mod statistics;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use bitflags::bitflags;
 pub use dsl::*;
 pub use hint::*;
 use polars_core::error::feature_gated;
@@ -18,6 +20,7 @@ use polars_core::series::IsSorted;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+pub use statistics::StatisticsAgg;
 use strum_macros::IntoStaticStr;
 
 #[cfg(feature = "python")]
@@ -25,6 +28,67 @@ use crate::dsl::python_dsl::PythonFunction;
 use crate::plans::ir::ScanSourcesDisplay;
 use crate::prelude::*;
 
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for UdfFlags {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "UdfFlags".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "UdfFlags"))
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        use schemars::json_schema;
+        use serde_json::{Map, Value};
+
+        // Add a map of flag names and bit patterns to detect schema changes
+        let name_to_bits: Map<String, Value> = Self::all()
+            .iter_names()
+            .map(|(name, flag)| (name.to_owned(), flag.bits().into()))
+            .collect();
+
+        json_schema!({
+            "type": "string",
+            "format": "bitflags",
+            "bitflags": name_to_bits
+        })
+    }
+}
+
+bitflags!(
+        /// Properties a user defined function can declare about its output.
+        ///
+        /// By default a UDF is an optimization barrier. These flags tell the optimizer
+        /// which operations it may move past the function. It is up to the caller to
+        /// ensure that the declared properties hold.
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub struct UdfFlags: u8 {
+            /// Output row `i` is derived from input row `i`; rows are never reordered.
+            const PRESERVES_ORDER = 1 << 0;
+            /// The output schema is equal to the input schema and every column is
+            /// computed independently from the other columns. The function may thus be
+            /// applied to any subset of its input columns.
+            const PRESERVES_SCHEMA = 1 << 1;
+            /// Every input row produces exactly one output row, without looking at the
+            /// other rows.
+            const PRESERVES_ROW_COUNT = 1 << 2;
+        }
+);
+
+impl UdfFlags {
+    /// Whether the function may be applied to any contiguous slice of its input.
+    pub fn is_row_aligned(self) -> bool {
+        self.contains(Self::PRESERVES_ORDER | Self::PRESERVES_ROW_COUNT)
+    }
+
+    pub fn preserves_schema(self) -> bool {
+        self.contains(Self::PRESERVES_SCHEMA)
+    }
+}
+
 #[cfg_attr(feature = "ir_serde", derive(Serialize, Deserialize))]
 #[derive(Clone, IntoStaticStr)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
@@ -46,9 +110,17 @@ pub enum FunctionIR {
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
 
+    /// Answers aggregations over a scan from the metadata of its files.
+    FastStatistics {
+        sources: ScanSources,
+        aggs: Arc<[StatisticsAgg]>,
+        schema: SchemaRef,
+    },
+
     Unnest {
         columns: Arc<[PlSmallStr]>,
         separator: Option<PlSmallStr>,
+        options: UnnestOptions,
     },
     Rechunk,
     Explode {
@@ -72,6 +144,8 @@ pub enum FunctionIR {
         ///  allow projection pushdown optimizations
         projection_pd: bool,
         streamable: bool,
+        /// properties of the function that allow further optimizations
+        flags: UdfFlags,
         // used for formatting
         fmt_str: PlSmallStr,
     },
@@ -102,9 +176,22 @@ impl Hash for FunctionIR {
                 scan_type.hash(state);
                 alias.hash(state);
             },
-            FunctionIR::Unnest { columns, separator } => {
+            FunctionIR::FastStatistics {
+                sources,
+                aggs,
+                schema: _,
+            } => {
+                sources.hash(state);
+                aggs.hash(state);
+            },
+            FunctionIR::Unnest {
+                columns,
+                separator,
+                options,
+            } => {
                 columns.hash(state);
                 separator.hash(state);
+                options.hash(state);
             },
             FunctionIR::Rechunk => {},
             FunctionIR::Explode {
@@ -136,7 +223,7 @@ impl FunctionIR {
         use FunctionIR::*;
         match self {
             Rechunk => false,
-            FastCount { .. } | Unnest { .. } | Explode { .. } => true,
+            FastCount { .. } | FastStatistics { .. } | Unnest { .. } | Explode { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Opaque { streamable, .. } => *streamable,
@@ -167,23 +254,67 @@ impl FunctionIR {
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Rechunk | Unnest { .. } | Explode { .. } | Hint(_) => true,
-            RowIndex { .. } | FastCount { .. } => false,
+            RowIndex { .. } | FastCount { .. } | FastStatistics { .. } => false,
         }
     }
 
     pub(crate) fn allow_projection_pd(&self) -> bool {
         use FunctionIR::*;
         match self {
-            Opaque { projection_pd, .. } => *projection_pd,
+            Opaque {
+                projection_pd,
+                flags,
+                ..
+            } => *projection_pd || flags.preserves_schema(),
             #[cfg(feature = "python")]
-            OpaquePython(OpaquePythonUdf { projection_pd, .. }) => *projection_pd,
-            Rechunk | FastCount { .. } | Unnest { .. } | Explode { .. } | Hint(_) => true,
+            OpaquePython(OpaquePythonUdf {
+                projection_pd,
+                flags,
+                ..
+            }) => *projection_pd || flags.preserves_schema(),
+            Rechunk
+            | FastCount { .. }
+            | FastStatistics { .. }
+            | Unnest { .. }
+            | Explode { .. }
+            | Hint(_) => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             RowIndex { .. } => true,
         }
     }
 
+    pub(crate) fn allow_slice_pd(&self) -> bool {
+        use FunctionIR::*;
+        match self {
+            Opaque {
+                predicate_pd,
+                flags,
+                ..
+            } => *predicate_pd || flags.is_row_aligned(),
+            #[cfg(feature = "python")]
+            OpaquePython(OpaquePythonUdf {
+                predicate_pd,
+                flags,
+                ..
+            }) => *predicate_pd || flags.is_row_aligned(),
+            Rechunk | Unnest { .. } | Hint(_) => true,
+            #[cfg(feature = "pivot")]
+            Unpivot { .. } => false,
+            Explode { .. } | RowIndex { .. } | FastCount { .. } | FastStatistics { .. } => false,
+        }
+    }
+
+    /// The declared properties of a user defined function.
+    pub fn udf_flags(&self) -> UdfFlags {
+        match self {
+            FunctionIR::Opaque { flags, .. } => *flags,
+            #[cfg(feature = "python")]
+            FunctionIR::OpaquePython(OpaquePythonUdf { flags, .. }) => *flags,
+            _ => UdfFlags::empty(),
+        }
+    }
+
     pub(crate) fn additional_projection_pd_columns(&self) -> Cow<'_, [PlSmallStr]> {
         use FunctionIR::*;
         match self {
@@ -210,14 +341,23 @@ impl FunctionIR {
                 alias,
                 cloud_options,
             } => count::count_rows(sources, scan_type, alias.clone(), cloud_options.as_ref()),
+            FastStatistics {
+                sources,
+                aggs,
+                schema,
+            } => statistics::compute_statistics(sources, aggs, schema),
             Rechunk => {
                 df.rechunk_mut_par();
                 Ok(df)
             },
-            Unnest { columns, separator } => {
+            Unnest {
+                columns,
+                separator,
+                options,
+            } => {
                 feature_gated!(
                     "dtype-struct",
-                    df.unnest(columns.iter().cloned(), separator.as_deref())
+                    df.unnest_with_options(columns.iter().cloned(), separator.as_deref(), options)
                 )
             },
             Explode {
@@ -231,7 +371,6 @@ impl FunctionIR {
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.clone(), *offset),
             Hint(hint) => {
-                #[expect(irrefutable_let_patterns)]
                 if let HintIR::Sorted(s) = &hint
                     && let Some(s) = s.first()
                 {
@@ -255,15 +394,19 @@ impl FunctionIR {
     pub fn is_order_producing(&self, is_input_ordered: bool) -> bool {
         match self {
             FunctionIR::RowIndex { .. } => true,
-            FunctionIR::FastCount { .. } => false,
+            FunctionIR::FastCount { .. } | FunctionIR::FastStatistics { .. } => false,
             FunctionIR::Unnest { .. } => is_input_ordered,
             FunctionIR::Rechunk => is_input_ordered,
             #[cfg(feature = "python")]
-            FunctionIR::OpaquePython(..) => true,
+            FunctionIR::OpaquePython(OpaquePythonUdf { flags, .. }) => {
+                !flags.contains(UdfFlags::PRESERVES_ORDER) || is_input_ordered
+            },
             FunctionIR::Explode { .. } => true,
             #[cfg(feature = "pivot")]
             FunctionIR::Unpivot { .. } => true,
-            FunctionIR::Opaque { .. } => true,
+            FunctionIR::Opaque { flags, .. } => {
+                !flags.contains(UdfFlags::PRESERVES_ORDER) || is_input_ordered
+            },
             FunctionIR::Hint(_) => is_input_ordered,
         }
     }
@@ -277,6 +420,7 @@ impl FunctionIR {
             Self::Unpivot { .. } => false,
             Self::RowIndex { .. }
             | Self::FastCount { .. }
+            | Self::FastStatistics { .. }
             | Self::Rechunk
             | Self::Explode { .. }
             | Self::Opaque { .. } => false,
@@ -292,13 +436,16 @@ impl FunctionIR {
         match self {
             Self::Unnest { .. } | Self::Rechunk | Self::Hint(_) => true,
             #[cfg(feature = "python")]
-            Self::OpaquePython(..) => false,
+            Self::OpaquePython(OpaquePythonUdf { flags, .. }) => {
+                flags.contains(UdfFlags::PRESERVES_ORDER)
+            },
+            Self::Opaque { flags, .. } => flags.contains(UdfFlags::PRESERVES_ORDER),
             #[cfg(feature = "pivot")]
             Self::Unpivot { .. } => false,
             Self::RowIndex { .. }
             | Self::FastCount { .. }
-            | Self::Explode { .. }
-            | Self::Opaque { .. } => false,
+            | Self::FastStatistics { .. }
+            | Self::Explode { .. } => false,
         }
     }
 }
@@ -317,13 +464,28 @@ impl Display for FunctionIR {
                 write!(f, "hint.{hint}")
             },
             Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
-            Unnest { columns, separator } => {
+            Unnest {
+                columns,
+                separator,
+                options,
+            } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
                 fmt_column_delimited(f, columns, "[", "]")?;
                 if let Some(separator) = separator {
                     write!(f, ", separator: {separator}")?;
                 }
+                if let Some(fields) = &options.fields {
+                    write!(f, ", fields: ")?;
+                    fmt_column_delimited(f, fields.as_ref(), "[", "]")?;
+                }
+                if let Some(prefix) = &options.prefix {
+                    write!(f, ", prefix: {prefix}")?;
+                }
+                if options.on_collision != UnnestCollision::Raise {
+                    let on_collision: &str = options.on_collision.into();
+                    write!(f, ", on_collision: {on_collision}")?;
+                }
                 Ok(())
             },
             FastCount {
@@ -342,6 +504,20 @@ impl Display for FunctionIR {
                     ScanSourcesDisplay(sources)
                 )
             },
+            FastStatistics { sources, aggs, .. } => {
+                write!(
+                    f,
+                    "FAST STATISTICS (parquet) {} [",
+                    ScanSourcesDisplay(sources)
+                )?;
+                for (i, agg) in aggs.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{agg}")?;
+                }
+                f.write_str("]")
+            },
             RowIndex {
                 name,
                 offset,
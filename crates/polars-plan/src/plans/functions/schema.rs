@@ -44,26 +44,31 @@ impl FunctionIR {
                 schema.insert_at_index(0, name, IDX_DTYPE)?;
                 Ok(Cow::Owned(Arc::new(schema)))
             },
+            FastStatistics { schema, .. } => Ok(Cow::Owned(schema.clone())),
             Rechunk => Ok(Cow::Borrowed(input_schema)),
-            Unnest { columns, separator } => {
+            Unnest {
+                columns,
+                separator,
+                options,
+            } => {
                 #[cfg(feature = "dtype-struct")]
                 {
-                    let mut new_schema = Schema::with_capacity(input_schema.len() * 2);
+                    let mut new_fields = Vec::with_capacity(input_schema.len() * 2);
+                    let mut is_field = Vec::with_capacity(input_schema.len() * 2);
                     for (name, dtype) in input_schema.iter() {
                         if columns.iter().any(|item| item == name) {
                             match dtype {
                                 DataType::Struct(flds) => {
                                     for fld in flds {
-                                        let fld_name = match separator {
-                                            None => fld.name().clone(),
-                                            Some(sep) => {
-                                                polars_utils::format_pl_smallstr!(
-                                                    "{name}{sep}{}",
-                                                    fld.name()
-                                                )
-                                            },
+                                        let Some(fld_name) = options.output_name(
+                                            name,
+                                            fld.name(),
+                                            separator.as_deref(),
+                                        ) else {
+                                            continue;
                                         };
-                                        new_schema.with_column(fld_name, fld.dtype().clone());
+                                        new_fields.push(Field::new(fld_name, fld.dtype().clone()));
+                                        is_field.push(true);
                                     }
                                 },
                                 DataType::Unknown(_) => {
@@ -76,10 +81,24 @@ impl FunctionIR {
                                 },
                             }
                         } else {
-                            new_schema.with_column(name.clone(), dtype.clone());
+                            new_fields.push(Field::new(name.clone(), dtype.clone()));
+                            is_field.push(false);
                         }
                     }
 
+                    let keep = if options.on_collision == UnnestCollision::Raise {
+                        vec![true; new_fields.len()]
+                    } else {
+                        options.resolve_collisions(
+                            new_fields.iter().map(|fld| fld.name()).zip(is_field),
+                        )?
+                    };
+                    let new_schema: Schema = new_fields
+                        .into_iter()
+                        .zip(keep)
+                        .filter_map(|(fld, keep)| keep.then_some(fld))
+                        .collect();
+
                     Ok(Cow::Owned(Arc::new(new_schema)))
                 }
                 #[cfg(not(feature = "dtype-struct"))]
@@ -0,0 +1,137 @@
+use std::fmt;
+
+use super::*;
+
+/// An aggregation that can be answered from the metadata of a file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ir_serde", derive(Serialize, Deserialize))]
+pub enum StatisticsAgg {
+    Len,
+    Min(PlSmallStr),
+    Max(PlSmallStr),
+}
+
+impl fmt::Display for StatisticsAgg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Len => f.write_str("len()"),
+            Self::Min(column) => write!(f, "col(\"{column}\").min()"),
+            Self::Max(column) => write!(f, "col(\"{column}\").max()"),
+        }
+    }
+}
+
+/// Computes `aggs` over all `sources` from the row group statistics in their metadata. The
+/// column of a file with row groups without statistics is read instead.
+///
+/// `schema` is the output schema, with a column per aggregation.
+pub fn compute_statistics(
+    sources: &ScanSources,
+    aggs: &[StatisticsAgg],
+    schema: &SchemaRef,
+) -> PolarsResult<DataFrame> {
+    feature_gated!("parquet", {
+        use std::io::Cursor;
+
+        use polars_io::SerReader;
+        use polars_io::prelude::ParquetReader;
+
+        let mut len = 0usize;
+        // Candidate values of every min/max aggregation, reduced at the end.
+        let mut candidates: Vec<Vec<Series>> = vec![vec![]; aggs.len()];
+
+        for source in sources.iter() {
+            let memslice = source.to_memslice()?;
+            let mut reader = ParquetReader::new(Cursor::new(memslice.clone()));
+            let arrow_schema = reader.schema()?;
+            let metadata = reader.get_metadata()?.clone();
+            len += metadata.num_rows;
+
+            for (agg, candidates) in aggs.iter().zip(&mut candidates) {
+                let (column, is_max) = match agg {
+                    StatisticsAgg::Len => continue,
+                    StatisticsAgg::Min(column) => (column, false),
+                    StatisticsAgg::Max(column) => (column, true),
+                };
+                let Some(field) = arrow_schema.get(column) else {
+                    polars_bail!(ColumnNotFound: "{column}");
+                };
+
+                match row_group_statistics(field, &metadata.row_groups, is_max)? {
+                    Some(values) => candidates.push(values),
+                    None => {
+                        let df = ParquetReader::new(Cursor::new(memslice.clone()))
+                            .with_columns(Some(vec![column.to_string()]))
+                            .finish()?;
+                        candidates.push(df.column(column)?.as_materialized_series().clone());
+                    },
+                }
+            }
+        }
+
+        let columns = aggs
+            .iter()
+            .zip(candidates)
+            .zip(schema.iter())
+            .map(|((agg, candidates), (name, dtype))| {
+                let value = match agg {
+                    StatisticsAgg::Len => {
+                        let len: IdxSize = len.try_into().map_err(
+                            |_| polars_err!(ComputeError: "count of {len} exceeded maximum row size"),
+                        )?;
+                        Scalar::from(len)
+                    },
+                    StatisticsAgg::Min(_) | StatisticsAgg::Max(_) => {
+                        let mut values = Series::new_empty(name.clone(), dtype);
+                        for s in candidates {
+                            values.append(&s.strict_cast(dtype)?)?;
+                        }
+                        match agg {
+                            StatisticsAgg::Min(_) => values.min_reduce()?,
+                            _ => values.max_reduce()?,
+                        }
+                    },
+                };
+                Ok(Column::new_scalar(name.clone(), value, 1))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(unsafe { DataFrame::new_unchecked(1, columns) })
+    })
+}
+
+/// Returns the minimum or maximum of every row group of a file, or `None` if a row group with
+/// values doesn't have it.
+#[cfg(feature = "parquet")]
+fn row_group_statistics(
+    field: &ArrowField,
+    row_groups: &[polars_parquet::read::RowGroupMetadata],
+    is_max: bool,
+) -> PolarsResult<Option<Series>> {
+    use polars_parquet::read::statistics::deserialize_all;
+
+    let Some(first) = row_groups.first() else {
+        return Ok(None);
+    };
+    // Nested columns have statistics per leaf.
+    let Some(&[idx]) = first.columns_idxs_under_root_iter(&field.name) else {
+        return Ok(None);
+    };
+    let Some(statistics) = deserialize_all(field, row_groups, idx)? else {
+        return Ok(None);
+    };
+
+    let values = if is_max {
+        statistics.max_value
+    } else {
+        statistics.min_value
+    };
+    // A row group without a value only has nulls if all of its values are counted as null.
+    let all_null =
+        |i: usize| statistics.null_count.get(i) == IdxSize::try_from(row_groups[i].num_rows()).ok();
+    if (0..row_groups.len()).any(|i| !values.is_valid(i) && !all_null(i)) {
+        return Ok(None);
+    }
+
+    Ok(Some(Series::try_from((field, values))?))
+}
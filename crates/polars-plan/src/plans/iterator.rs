@@ -75,6 +75,7 @@ macro_rules! push_expr {
             // as the root columns/ input columns by `_suffix` and `_keep_name` etc.
             Display { inputs, .. } => inputs.$iter().rev().for_each(|e| $push_owned($c, e)),
             AnonymousFunction { input, .. } => input.$iter().rev().for_each(|e| $push_owned($c, e)),
+            AnonymousAgg { input, .. } => input.$iter().rev().for_each(|e| $push_owned($c, e)),
             Eval {
                 expr, evaluation, ..
             } => {
@@ -10,6 +10,7 @@ pub(crate) mod ir;
 
 mod apply;
 mod builder_ir;
+pub mod cardinality;
 pub(crate) mod conversion;
 #[cfg(feature = "debugging")]
 pub(crate) mod debug;
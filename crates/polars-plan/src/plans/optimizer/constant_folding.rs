@@ -0,0 +1,76 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Evaluates an expression whose leaves are all scalar literals. Returns `None` if the expression
+/// could not be evaluated, in which case it is left as is and any error surfaces at execution.
+///
+/// The optimizer has no access to the expression engine, so this is injected by the caller of
+/// [`optimize`](super::optimize).
+pub type ConstantEvaluator = fn(Node, &mut Arena<AExpr>) -> Option<Scalar>;
+
+/// Folds temporal, string, cast and binary expressions on scalar literals into a single literal.
+///
+/// E.g. `lit("2024-01-02").str.to_date() + duration(days=1)` becomes `lit(2024-01-03)`, which
+/// no longer has to be evaluated per row and can be used for skipping files and partitions.
+pub(super) struct ConstantFoldingRule {
+    evaluate: ConstantEvaluator,
+}
+
+impl ConstantFoldingRule {
+    pub(super) fn new(evaluate: ConstantEvaluator) -> Self {
+        Self { evaluate }
+    }
+}
+
+fn is_scalar_literal(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    matches!(
+        expr_arena.get(node),
+        AExpr::Literal(LiteralValue::Scalar(_))
+    )
+}
+
+fn is_foldable_function(function: &IRFunctionExpr) -> bool {
+    match function {
+        #[cfg(feature = "temporal")]
+        IRFunctionExpr::TemporalExpr(_) => true,
+        #[cfg(feature = "strings")]
+        IRFunctionExpr::StringExpr(_) => true,
+        _ => false,
+    }
+}
+
+impl OptimizationRule for ConstantFoldingRule {
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        _schema: &Schema,
+        _ctx: OptimizeExprContext,
+    ) -> PolarsResult<Option<AExpr>> {
+        let foldable = match expr_arena.get(expr_node) {
+            AExpr::Function {
+                input,
+                function,
+                options,
+            } => {
+                options.is_elementwise()
+                    && is_foldable_function(function)
+                    && input
+                        .iter()
+                        .all(|e| is_scalar_literal(e.node(), expr_arena))
+            },
+            AExpr::Cast { expr, .. } => is_scalar_literal(*expr, expr_arena),
+            AExpr::BinaryExpr { left, right, .. } => {
+                is_scalar_literal(*left, expr_arena) && is_scalar_literal(*right, expr_arena)
+            },
+            _ => false,
+        };
+
+        if !foldable {
+            return Ok(None);
+        }
+
+        Ok((self.evaluate)(expr_node, expr_arena).map(|sc| AExpr::Literal(sc.into())))
+    }
+}
@@ -185,6 +185,28 @@ impl LpIdentifierVisitor<'_> {
     }
 }
 
+#[recursive::recursive]
+fn subplan_identifier(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Identifier {
+    let mut id = Identifier::new();
+    for input in lp_arena.get(node).inputs() {
+        id.combine(&subplan_identifier(input, lp_arena, expr_arena));
+    }
+    id.add_alp_node(&IRNode::new(node), lp_arena, expr_arena)
+}
+
+/// Whether the plans rooted at `left` and `right` are equal, identified in the same way as the
+/// common subplans that are eliminated.
+pub(super) fn is_same_subplan(
+    left: Node,
+    right: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    left == right
+        || subplan_identifier(left, lp_arena, expr_arena)
+            .is_equal(&subplan_identifier(right, lp_arena, expr_arena))
+}
+
 fn skip_children(lp: &IR) -> bool {
     match lp {
         // Don't visit all the files in a `scan *` operation.
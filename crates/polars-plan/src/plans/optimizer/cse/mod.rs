@@ -6,6 +6,7 @@ use cache_states::set_cache_states;
 pub(super) use csee::CommonSubExprOptimizer;
 pub use csee::NaiveExprMerger;
 use cspe::elim_cmn_subplans;
+pub(super) use cspe::is_same_subplan;
 
 use super::*;
 
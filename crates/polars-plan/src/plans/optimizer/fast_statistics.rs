@@ -0,0 +1,128 @@
+use polars_core::prelude::*;
+use polars_utils::itertools::Itertools;
+
+use crate::prelude::*;
+
+/// Answers selections of only `len()`, `min()` and `max()` of columns directly from a Parquet
+/// scan from the file metadata, without reading any data pages.
+///
+/// Only integer and temporal columns are considered, for which the statistics are exact. Hive
+/// partitioned scans are supported, but aggregations of the partition columns themselves are not
+/// answered from statistics.
+pub(super) fn answer_from_statistics(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let selects = ir_arena
+        .iter(root)
+        .filter_map(|(node, ir)| matches!(ir, IR::Select { .. }).then_some(node))
+        .collect_vec();
+
+    for node in selects {
+        let IR::Select {
+            input,
+            expr,
+            schema,
+            ..
+        } = ir_arena.get(node)
+        else {
+            continue;
+        };
+        let Some((sources, hive_schema)) = statistics_sources(ir_arena.get(*input)) else {
+            continue;
+        };
+        let input_schema = ir_arena.get(*input).schema(ir_arena);
+        let Some(aggs) = expr
+            .iter()
+            .map(|e| as_statistics_agg(e, &input_schema, hive_schema.as_deref(), expr_arena))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        if aggs.iter().all(|agg| matches!(agg, StatisticsAgg::Len)) {
+            continue;
+        }
+
+        let function = FunctionIR::FastStatistics {
+            sources,
+            aggs: aggs.into(),
+            schema: schema.clone(),
+        };
+        // MapFunction needs a leaf node, hence we create a dummy placeholder node.
+        let placeholder = ir_arena.add(IR::DataFrameScan {
+            df: Arc::new(Default::default()),
+            schema: Arc::new(Default::default()),
+            output_schema: None,
+        });
+        ir_arena.replace(
+            node,
+            IR::MapFunction {
+                input: placeholder,
+                function,
+            },
+        );
+    }
+}
+
+/// Returns the sources and the hive partition schema of a scan that reads every row of local
+/// Parquet files as is.
+fn statistics_sources(ir: &IR) -> Option<(ScanSources, Option<SchemaRef>)> {
+    let IR::Scan {
+        sources,
+        hive_parts,
+        predicate: None,
+        scan_type,
+        unified_scan_args,
+        ..
+    } = ir
+    else {
+        return None;
+    };
+    let args = unified_scan_args.as_ref();
+    let reads_as_is = args.row_index.is_none()
+        && args.pre_slice.is_none()
+        && args.include_file_paths.is_none()
+        && args.deletion_files.is_none()
+        && args.column_mapping.is_none()
+        && args.default_values.is_none()
+        && args.missing_columns_policy == MissingColumnsPolicy::Raise;
+
+    let hive_schema = hive_parts.as_ref().map(|h| h.schema().clone());
+
+    (matches!(scan_type.as_ref(), FileScanIR::Parquet { .. })
+        && !sources.is_cloud_url()
+        && reads_as_is)
+        .then(|| (sources.clone(), hive_schema))
+}
+
+fn as_statistics_agg(
+    e: &ExprIR,
+    input_schema: &Schema,
+    hive_schema: Option<&Schema>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<StatisticsAgg> {
+    let (input, is_max) = match expr_arena.get(e.node()) {
+        AExpr::Len => return Some(StatisticsAgg::Len),
+        AExpr::Agg(IRAggExpr::Min { input, .. }) => (input, false),
+        AExpr::Agg(IRAggExpr::Max { input, .. }) => (input, true),
+        _ => return None,
+    };
+    let AExpr::Column(column) = expr_arena.get(*input) else {
+        return None;
+    };
+    // The values of partition columns aren't stored in the files.
+    if hive_schema.is_some_and(|schema| schema.contains(column)) {
+        return None;
+    }
+    let dtype = input_schema.get(column)?;
+    if !(dtype.is_integer() || dtype.is_temporal()) {
+        return None;
+    }
+
+    Some(if is_max {
+        StatisticsAgg::Max(column.clone())
+    } else {
+        StatisticsAgg::Min(column.clone())
+    })
+}
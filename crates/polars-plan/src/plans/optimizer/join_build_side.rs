@@ -0,0 +1,59 @@
+use polars_ops::frame::{JoinBuildSide, MaintainOrderJoin};
+use polars_utils::itertools::Itertools;
+
+use super::*;
+use crate::plans::cardinality::estimate_cardinality;
+
+/// How many times smaller the estimated number of rows of one side must be before it is preferred
+/// as build side.
+const BUILD_SIDE_RATIO: usize = 10;
+
+/// Prefer the side of a join that is estimated to be much smaller as build side.
+///
+/// The streaming engine otherwise estimates the cardinality of both sides from samples once they
+/// turn out to be too big to be kept in memory. An explicit build side is never changed.
+pub(super) fn prefer_smaller_build_side(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let estimates = estimate_cardinality(root, ir_arena, expr_arena);
+
+    let joins = ir_arena
+        .iter(root)
+        .filter_map(|(node, ir)| matches!(ir, IR::Join { .. }).then_some(node))
+        .collect_vec();
+
+    for node in joins {
+        let IR::Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } = ir_arena.get_mut(node)
+        else {
+            unreachable!()
+        };
+        if options.args.build_side.is_some()
+            || options.args.maintain_order != MaintainOrderJoin::None
+        {
+            continue;
+        }
+        let (Some(left), Some(right)) = (estimates.get(*input_left), estimates.get(*input_right))
+        else {
+            continue;
+        };
+        if left.rows == usize::MAX || right.rows == usize::MAX {
+            continue;
+        }
+
+        let build_side = if left.rows.saturating_mul(BUILD_SIDE_RATIO) <= right.rows {
+            JoinBuildSide::PreferLeft
+        } else if right.rows.saturating_mul(BUILD_SIDE_RATIO) <= left.rows {
+            JoinBuildSide::PreferRight
+        } else {
+            continue;
+        };
+        Arc::make_mut(options).args.build_side = Some(build_side);
+    }
+}
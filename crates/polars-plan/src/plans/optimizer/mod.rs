@@ -8,27 +8,37 @@ mod delay_rechunk;
 mod cluster_with_columns;
 mod collapse_and_project;
 mod collect_members;
+mod constant_folding;
 mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_build_side;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
+#[cfg(feature = "parquet")]
+mod fast_statistics;
 #[cfg(feature = "python")]
 pub use expand_datasets::ExpandedPythonScan;
 mod predicate_pushdown;
+mod predicate_ranges;
 mod projection_pushdown;
+#[cfg(feature = "cse")]
+mod self_join;
 pub mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod sortedness;
 mod stack_opt;
+mod user_rules;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
+pub use constant_folding::ConstantEvaluator;
+use constant_folding::ConstantFoldingRule;
 #[cfg(feature = "cse")]
 pub use cse::NaiveExprMerger;
 use delay_rechunk::DelayRechunk;
@@ -40,6 +50,9 @@ pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use sortedness::{AExprSorted, IRSorted, are_keys_sorted_any, expr_is_sorted, is_sorted};
 pub use stack_opt::{OptimizationRule, OptimizeExprContext, StackOptimizer};
+pub use user_rules::{
+    OptimizerRewriteRule, OptimizerRuleStage, register_optimizer_rule, unregister_optimizer_rule,
+};
 
 use self::flatten_union::FlattenUnionRule;
 pub use crate::frame::{AllowedOptimizations, OptFlags};
@@ -109,6 +122,7 @@ pub fn optimize(
         &mut Arena<IR>,
         &mut Arena<AExpr>,
     ) -> PolarsResult<()>,
+    evaluate_constant_expr: ConstantEvaluator,
 ) -> PolarsResult<Node> {
     #[allow(dead_code)]
     let verbose = verbose();
@@ -125,6 +139,18 @@ pub fn optimize(
         opt_flags &= !(OptFlags::COMM_SUBEXPR_ELIM | OptFlags::COMM_SUBEXPR_ELIM);
     }
     let mut root = to_alp(logical_plan, expr_arena, ir_arena, &mut opt_flags)?;
+    root = user_rules::run_user_rules(
+        OptimizerRuleStage::BeforePushdown,
+        root,
+        ir_arena,
+        expr_arena,
+        verbose,
+    )?;
+
+    // Before pushdown, as a contradicting filter prunes its input entirely.
+    if opt_flags.simplify_expr() {
+        predicate_ranges::simplify_filter_ranges(root, ir_arena, expr_arena);
+    }
 
     #[allow(unused_assignments)]
     let mut comm_subplan_elim = false;
@@ -155,6 +181,12 @@ pub fn optimize(
         };
     }
 
+    // Before subplan elimination, as it removes the duplicated input of the join.
+    #[cfg(feature = "cse")]
+    if comm_subplan_elim {
+        self_join::rewrite_self_joins(root, ir_arena, expr_arena);
+    }
+
     // Run before slice pushdown
     if opt_flags.simplify_expr() {
         #[cfg(feature = "fused")]
@@ -231,6 +263,10 @@ pub fn optimize(
         rules.push(Box::new(DelayRechunk::new()));
     }
 
+    if opt_flags.simplify_expr() {
+        rules.push(Box::new(ConstantFoldingRule::new(evaluate_constant_expr)));
+    }
+
     // This optimization removes branches, so we must do it when type coercion
     // is completed.
     if opt_flags.simplify_expr() {
@@ -250,6 +286,14 @@ pub fn optimize(
         ir_arena.replace(root, ir);
     }
 
+    root = user_rules::run_user_rules(
+        OptimizerRuleStage::AfterPushdown,
+        root,
+        ir_arena,
+        expr_arena,
+        verbose,
+    )?;
+
     if opt_flags.cluster_with_columns() && get_or_init_members!().with_columns_count > 1 {
         cluster_with_columns::optimize(root, ir_arena, expr_arena)
     }
@@ -267,6 +311,16 @@ pub fn optimize(
         })?;
     }
 
+    // After projection pushdown, as the aggregations then directly select from the scan.
+    #[cfg(feature = "parquet")]
+    if opt_flags.contains(OptFlags::PROJECTION_PUSHDOWN) {
+        fast_statistics::answer_from_statistics(root, ir_arena, expr_arena);
+    }
+
+    if opt_flags.contains(OptFlags::CHECK_ORDER_OBSERVE) && get_or_init_members!().has_sort {
+        sortedness::eliminate_redundant_sorts(root, ir_arena, expr_arena);
+    }
+
     if opt_flags.contains(OptFlags::CHECK_ORDER_OBSERVE) {
         let members = get_or_init_members!();
         if members.has_group_by
@@ -301,8 +355,24 @@ pub fn optimize(
         }
     }
 
+    // Only the streaming engine respects the build side of joins.
+    if opt_flags.contains(OptFlags::ROW_ESTIMATE)
+        && opt_flags.new_streaming()
+        && get_or_init_members!().has_joins_or_unions
+    {
+        join_build_side::prefer_smaller_build_side(root, ir_arena, expr_arena);
+    }
+
     expand_datasets::expand_datasets(root, ir_arena, expr_arena, apply_scan_predicate_to_scan_ir)?;
 
+    root = user_rules::run_user_rules(
+        OptimizerRuleStage::Final,
+        root,
+        ir_arena,
+        expr_arena,
+        verbose,
+    )?;
+
     // During debug we check if the optimizations have not modified the final schema.
     #[cfg(debug_assertions)]
     {
@@ -42,19 +42,44 @@ pub(super) fn process_group_by(
     //
     // For aliased column keys (e.g. col("A").alias("key")), we can still push down by
     // rewriting the predicate to reference the original column name.
+    //
+    // For elementwise expression keys (e.g. col("date").dt.year()), all rows of a group have the
+    // same key value, so a predicate on the key either keeps or drops the group as a whole. We
+    // push those down by substituting the key expression into the predicate.
     let mut local_predicates = Vec::with_capacity(acc_predicates.len());
     let input_schema = lp_arena.get(input).schema(lp_arena);
-    let mut alias_rename_map: PlHashMap<PlSmallStr, PlSmallStr> = PlHashMap::new();
+    let mut key_expr_map: PlHashMap<PlSmallStr, Node> = PlHashMap::new();
     let mut key_schema = Schema::with_capacity(keys.len());
     for key in &keys {
-        if let AExpr::Column(c) = expr_arena.get(key.node()) {
-            let output = key.output_name();
-            if let Some(dtype) = input_schema.get(c) {
-                key_schema.insert(output.clone(), dtype.clone());
-                if c != output {
-                    alias_rename_map.insert(output.clone(), c.clone());
+        let output = key.output_name();
+        match expr_arena.get(key.node()) {
+            AExpr::Column(c) => {
+                if let Some(dtype) = input_schema.get(c) {
+                    key_schema.insert(output.clone(), dtype.clone());
+                    if c != output {
+                        key_expr_map.insert(output.clone(), key.node());
+                    }
                 }
-            }
+            },
+            ae => {
+                let is_elementwise = !matches!(
+                    ExprPushdownGroup::Pushable.update_with_expr_rec(
+                        ae,
+                        expr_arena,
+                        Some(opt.empty_nodes_scratch_mut())
+                    ),
+                    ExprPushdownGroup::Barrier
+                );
+                let inputs_available = aexpr_to_leaf_names_iter(key.node(), expr_arena)
+                    .all(|name| input_schema.contains(name.as_ref()));
+
+                if is_elementwise && inputs_available {
+                    if let Some(dtype) = schema.get(output) {
+                        key_schema.insert(output.clone(), dtype.clone());
+                        key_expr_map.insert(output.clone(), key.node());
+                    }
+                }
+            },
         }
     }
 
@@ -78,9 +103,11 @@ pub(super) fn process_group_by(
         }
     }
 
-    if !alias_rename_map.is_empty() {
+    // Aliased and computed keys are substituted in a single pass: the substituted expressions
+    // refer to input columns, which may share their names with other (aliased) keys.
+    if !key_expr_map.is_empty() {
         for (_, predicate) in new_acc_predicates.iter_mut() {
-            map_column_references(predicate, expr_arena, &alias_rename_map);
+            substitute_column_references(predicate, expr_arena, &key_expr_map);
         }
     }
 
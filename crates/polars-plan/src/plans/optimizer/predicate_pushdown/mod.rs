@@ -525,12 +525,15 @@ impl PredicatePushDown {
                         FunctionIR::Unnest {
                             columns,
                             separator: _,
+                            options,
                         } => {
                             let exclude = columns.iter().cloned().collect::<PlHashSet<_>>();
+                            // Unnested fields may replace any existing column.
+                            let overwrites = options.on_collision == UnnestCollision::Overwrite;
 
                             let local_predicates =
                                 transfer_to_local_by_name(expr_arena, &mut acc_predicates, |x| {
-                                    exclude.contains(x)
+                                    overwrites || exclude.contains(x)
                                 });
 
                             let lp = self.pushdown_and_continue(
@@ -542,3 +542,69 @@ pub(super) fn map_column_references(
         }
     }
 }
+
+/// Replaces column references within an expression with (a copy of) the given expressions. Used
+/// to push predicates on computed group-by keys to the input of the group-by.
+///
+/// This will add a new expression tree in the arena (i.e. it won't mutate the existing node in-place).
+pub(super) fn substitute_column_references(
+    expr: &mut ExprIR,
+    expr_arena: &mut Arena<AExpr>,
+    substitutions: &PlHashMap<PlSmallStr, Node>,
+) {
+    if substitutions.is_empty() {
+        return;
+    }
+
+    let node = AexprNode::new(expr.node())
+        .rewrite(
+            &mut SubstituteColumnReferences { substitutions },
+            expr_arena,
+        )
+        .unwrap()
+        .node();
+
+    *expr = ExprIR::from_node(node, expr_arena);
+
+    struct SubstituteColumnReferences<'a> {
+        substitutions: &'a PlHashMap<PlSmallStr, Node>,
+    }
+
+    impl RewritingVisitor for SubstituteColumnReferences<'_> {
+        type Node = AexprNode;
+        type Arena = Arena<AExpr>;
+
+        fn pre_visit(
+            &mut self,
+            node: &Self::Node,
+            arena: &mut Self::Arena,
+        ) -> polars_core::prelude::PolarsResult<crate::prelude::visitor::RewriteRecursion> {
+            let AExpr::Column(colname) = arena.get(node.node()) else {
+                return Ok(RewriteRecursion::NoMutateAndContinue);
+            };
+
+            if !self.substitutions.contains_key(colname) {
+                return Ok(RewriteRecursion::NoMutateAndContinue);
+            }
+
+            Ok(RewriteRecursion::MutateAndContinue)
+        }
+
+        fn mutate(
+            &mut self,
+            node: Self::Node,
+            arena: &mut Self::Arena,
+        ) -> polars_core::prelude::PolarsResult<Self::Node> {
+            let AExpr::Column(colname) = arena.get(node.node()) else {
+                unreachable!();
+            };
+
+            // Safety: Checked in pre_visit()
+            let substitute = *self.substitutions.get(colname).unwrap();
+
+            // Copy the expression so that the predicate doesn't share nodes with the original
+            // expression, which may be mutated in place by later optimizations.
+            Ok(AexprNode::new(deep_clone_ae(substitute, arena)))
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use std::cmp::Ordering;
+
+use polars_core::prelude::*;
+use polars_utils::itertools::Itertools;
+
+use crate::dsl::Operator;
+use crate::prelude::*;
+
+/// Merges the comparisons of a column with literals in the conjunction of every filter into a
+/// single range, e.g. `a > 1 & b & a > 3 & a <= 10` becomes `a > 3 & a <= 10 & b`. Negations are
+/// pushed down first, so `~(a <= 1 | a > 10)` is merged as `a > 1 & a <= 10`.
+///
+/// A filter whose ranges contradict, e.g. `a > 3 & a < 2`, can't select any row and is replaced
+/// by an empty scan, so that its input doesn't have to be read at all.
+pub(super) fn simplify_filter_ranges(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let filters = ir_arena
+        .iter(root)
+        .filter_map(|(node, ir)| matches!(ir, IR::Filter { .. }).then_some(node))
+        .collect_vec();
+
+    for node in filters {
+        let IR::Filter { input, predicate } = ir_arena.get(node) else {
+            continue;
+        };
+        let input = *input;
+        let predicate = predicate.node();
+        let schema = ir_arena.get(input).schema(ir_arena).into_owned();
+
+        match simplify_conjunction(predicate, &schema, expr_arena) {
+            Simplified::Unchanged => {},
+            Simplified::Predicate(new_predicate) => {
+                if let IR::Filter { predicate, .. } = ir_arena.get_mut(node) {
+                    predicate.set_node(new_predicate);
+                }
+            },
+            Simplified::Contradiction => {
+                ir_arena.replace(
+                    node,
+                    IR::DataFrameScan {
+                        df: Arc::new(DataFrame::empty_with_schema(&schema)),
+                        schema,
+                        output_schema: None,
+                    },
+                );
+            },
+        }
+    }
+}
+
+enum Simplified {
+    Unchanged,
+    Predicate(Node),
+    Contradiction,
+}
+
+/// The values a column may take according to its comparisons with literals.
+struct Range {
+    /// Position of the first comparison in the conjunction.
+    position: usize,
+    comparisons: Vec<Node>,
+    /// Bound with whether it is inclusive.
+    lower: Option<(Scalar, bool)>,
+    upper: Option<(Scalar, bool)>,
+    /// Whether all bounds are of the same type and comparable.
+    valid: bool,
+}
+
+impl Range {
+    fn new(position: usize) -> Self {
+        Self {
+            position,
+            comparisons: vec![],
+            lower: None,
+            upper: None,
+            valid: true,
+        }
+    }
+
+    fn add(&mut self, node: Node, op: Operator, value: &Scalar) {
+        self.comparisons.push(node);
+        match op {
+            Operator::Eq => {
+                self.tighten(value, true, Ordering::Greater, true);
+                self.tighten(value, true, Ordering::Less, false);
+            },
+            Operator::Gt => self.tighten(value, false, Ordering::Greater, true),
+            Operator::GtEq => self.tighten(value, true, Ordering::Greater, true),
+            Operator::Lt => self.tighten(value, false, Ordering::Less, false),
+            Operator::LtEq => self.tighten(value, true, Ordering::Less, false),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Replace a bound if the new one is tighter, that is, ordered as `tighter` relative to it.
+    fn tighten(&mut self, value: &Scalar, inclusive: bool, tighter: Ordering, lower: bool) {
+        let bound = if lower {
+            &mut self.lower
+        } else {
+            &mut self.upper
+        };
+        match bound {
+            None => *bound = Some((value.clone(), inclusive)),
+            Some((current, current_inclusive)) => {
+                if current.dtype() != value.dtype() {
+                    self.valid = false;
+                    return;
+                }
+                match value.as_any_value().partial_cmp(&current.as_any_value()) {
+                    None => self.valid = false,
+                    Some(Ordering::Equal) => *current_inclusive &= inclusive,
+                    Some(ord) if ord == tighter => *bound = Some((value.clone(), inclusive)),
+                    Some(_) => {},
+                }
+            },
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        let (Some((lower, lower_inclusive)), Some((upper, upper_inclusive))) =
+            (&self.lower, &self.upper)
+        else {
+            return false;
+        };
+        if lower.dtype() != upper.dtype() {
+            return false;
+        }
+        match lower.as_any_value().partial_cmp(&upper.as_any_value()) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Equal) => !(*lower_inclusive && *upper_inclusive),
+            _ => false,
+        }
+    }
+
+    fn is_point(&self) -> bool {
+        matches!(
+            (&self.lower, &self.upper),
+            (Some((lower, true)), Some((upper, true))) if lower == upper
+        )
+    }
+
+    fn n_merged_comparisons(&self) -> usize {
+        if self.is_point() {
+            1
+        } else {
+            self.lower.is_some() as usize + self.upper.is_some() as usize
+        }
+    }
+
+    fn to_comparisons(&self, column: &PlSmallStr, expr_arena: &mut Arena<AExpr>) -> Vec<Node> {
+        let mut compare = |op: Operator, value: &Scalar| {
+            let left = expr_arena.add(AExpr::Column(column.clone()));
+            let right = expr_arena.add(AExpr::Literal(value.clone().into()));
+            expr_arena.add(AExpr::BinaryExpr { left, op, right })
+        };
+
+        if self.is_point() {
+            return vec![compare(Operator::Eq, &self.lower.as_ref().unwrap().0)];
+        }
+        let lower = self.lower.as_ref().map(|(v, inclusive)| {
+            let op = if *inclusive {
+                Operator::GtEq
+            } else {
+                Operator::Gt
+            };
+            compare(op, v)
+        });
+        let upper = self.upper.as_ref().map(|(v, inclusive)| {
+            let op = if *inclusive {
+                Operator::LtEq
+            } else {
+                Operator::Lt
+            };
+            compare(op, v)
+        });
+        lower.into_iter().chain(upper).collect()
+    }
+}
+
+/// Collects the conjuncts of `node`, pushing negations down with De Morgan's laws so that
+/// `~(a | b)` gives the conjuncts `~a` and `~b`, and `~(a < 1)` gives `a >= 1`.
+#[recursive::recursive]
+fn collect_conjuncts(node: Node, expr_arena: &mut Arena<AExpr>, out: &mut Vec<Node>) {
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            let (left, right) = (*left, *right);
+            collect_conjuncts(left, expr_arena, out);
+            collect_conjuncts(right, expr_arena, out);
+        },
+        AExpr::Function {
+            input,
+            function: IRFunctionExpr::Boolean(IRBooleanFunction::Not),
+            options,
+        } => {
+            let options = *options;
+            match expr_arena.get(input[0].node()) {
+                AExpr::BinaryExpr {
+                    left,
+                    op: Operator::Or | Operator::LogicalOr,
+                    right,
+                } => {
+                    let (left, right) = (*left, *right);
+                    for input in [left, right] {
+                        let negated = expr_arena.add(AExpr::Function {
+                            input: vec![ExprIR::from_node(input, expr_arena)],
+                            function: IRFunctionExpr::Boolean(IRBooleanFunction::Not),
+                            options,
+                        });
+                        collect_conjuncts(negated, expr_arena, out);
+                    }
+                },
+                AExpr::Function {
+                    input,
+                    function: IRFunctionExpr::Boolean(IRBooleanFunction::Not),
+                    ..
+                } => {
+                    let input = input[0].node();
+                    collect_conjuncts(input, expr_arena, out);
+                },
+                AExpr::BinaryExpr {
+                    left,
+                    op: op @ (Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq),
+                    right,
+                } => {
+                    let op = match op {
+                        Operator::Lt => Operator::GtEq,
+                        Operator::LtEq => Operator::Gt,
+                        Operator::Gt => Operator::LtEq,
+                        _ => Operator::Lt,
+                    };
+                    let (left, right) = (*left, *right);
+                    out.push(expr_arena.add(AExpr::BinaryExpr { left, op, right }));
+                },
+                _ => out.push(node),
+            }
+        },
+        _ => out.push(node),
+    }
+}
+
+/// Returns `(column, op, value)` for `column <op> value` or `value <op> column`, where `value`
+/// is a non-null literal of the same type as the column, and that type is totally ordered by
+/// its values.
+///
+/// Categoricals and enums are not, they compare by category order or lexically depending on the
+/// data type, so their ranges can't be merged.
+fn as_column_comparison<'a>(
+    node: Node,
+    schema: &Schema,
+    expr_arena: &'a Arena<AExpr>,
+) -> Option<(&'a PlSmallStr, Operator, &'a Scalar)> {
+    let AExpr::BinaryExpr { left, op, right } = expr_arena.get(node) else {
+        return None;
+    };
+    if !matches!(
+        op,
+        Operator::Eq | Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq
+    ) {
+        return None;
+    }
+
+    let (column, op, value) = match (expr_arena.get(*left), expr_arena.get(*right)) {
+        (AExpr::Column(column), AExpr::Literal(LiteralValue::Scalar(value))) => {
+            (column, *op, value)
+        },
+        (AExpr::Literal(LiteralValue::Scalar(value)), AExpr::Column(column)) => {
+            (column, op.swap_operands(), value)
+        },
+        _ => return None,
+    };
+
+    let dtype = value.dtype();
+    let ordered = dtype.is_primitive_numeric() || dtype.is_temporal() || dtype.is_string();
+    (ordered && !value.is_null() && schema.get(column) == Some(dtype))
+        .then_some((column, op, value))
+}
+
+fn simplify_conjunction(
+    predicate: Node,
+    schema: &Schema,
+    expr_arena: &mut Arena<AExpr>,
+) -> Simplified {
+    let mut conjuncts = vec![];
+    collect_conjuncts(predicate, expr_arena, &mut conjuncts);
+    if conjuncts.len() < 2 {
+        return Simplified::Unchanged;
+    }
+
+    let mut ranges: PlIndexMap<PlSmallStr, Range> = PlIndexMap::default();
+    let mut other = vec![];
+    for (position, &node) in conjuncts.iter().enumerate() {
+        match as_column_comparison(node, schema, expr_arena) {
+            Some((column, op, value)) => {
+                let range = ranges
+                    .entry(column.clone())
+                    .or_insert_with(|| Range::new(position));
+                range.add(node, op, value);
+            },
+            None => other.push((position, node)),
+        }
+    }
+
+    let mut changed = false;
+    for range in ranges.values().filter(|r| r.valid) {
+        if range.is_empty() {
+            return Simplified::Contradiction;
+        }
+        changed |= range.n_merged_comparisons() < range.comparisons.len();
+    }
+    if !changed {
+        return Simplified::Unchanged;
+    }
+
+    for (column, range) in &ranges {
+        if range.valid {
+            for node in range.to_comparisons(column, expr_arena) {
+                other.push((range.position, node));
+            }
+        } else {
+            other.extend(range.comparisons.iter().map(|&node| (range.position, node)));
+        }
+    }
+    // Stable, so merged comparisons keep their order.
+    other.sort_by_key(|(position, _)| *position);
+
+    let predicate = other
+        .into_iter()
+        .map(|(_, node)| node)
+        .reduce(|left, right| {
+            expr_arena.add(AExpr::BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            })
+        })
+        .unwrap();
+    Simplified::Predicate(predicate)
+}
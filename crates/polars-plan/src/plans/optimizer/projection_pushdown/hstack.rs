@@ -10,6 +10,27 @@ pub(super) fn process_hstack(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<IR> {
+    if !ctx.has_pushed_down() {
+        // Nothing is projected, so every output column is used. The columns of the input that
+        // this `with_column` overwrites are not though, so we project all columns explicitly
+        // such that those are pruned upstream.
+        let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+        if exprs.iter().any(|e| input_schema.contains(e.output_name())) {
+            let names = input_schema
+                .iter_names()
+                .chain(exprs.iter().map(|e| e.output_name()));
+            for name in names {
+                let node = expr_arena.add(AExpr::Column(name.clone()));
+                add_expr_to_accumulated(
+                    node,
+                    &mut ctx.acc_projections,
+                    &mut ctx.projected_names,
+                    expr_arena,
+                );
+            }
+        }
+    }
+
     if ctx.has_pushed_down() {
         let mut pruned_with_cols = Vec::with_capacity(exprs.len());
 
@@ -28,16 +49,39 @@ pub(super) fn process_hstack(
             return Ok(lp_arena.take(input));
         }
 
+        // Columns that this `with_column` overwrites are only needed from the input if one of
+        // the expressions uses them.
+        let is_overwritten = |c: &ColumnNode| {
+            let name = column_node_to_name(*c, expr_arena);
+            pruned_with_cols.iter().any(|e| e.output_name() == name)
+        };
+        let mut acc_projections: Vec<_> = ctx
+            .acc_projections
+            .iter()
+            .copied()
+            .filter(|c| !is_overwritten(c))
+            .collect();
+        let mut projected_names: PlHashSet<_> = acc_projections
+            .iter()
+            .map(|c| column_node_to_name(*c, expr_arena).clone())
+            .collect();
+
         // Make sure that columns selected with_columns are available
         // only if not empty. If empty we already select everything.
         for e in &pruned_with_cols {
             add_expr_to_accumulated(
                 e.node(),
-                &mut ctx.acc_projections,
-                &mut ctx.projected_names,
+                &mut acc_projections,
+                &mut projected_names,
                 expr_arena,
             );
         }
+        // If nothing is left we keep projecting the overwritten columns, as an empty projection
+        // would select everything.
+        if !acc_projections.is_empty() {
+            ctx.acc_projections = acc_projections;
+            ctx.projected_names = projected_names;
+        }
 
         exprs = pruned_with_cols
     }
@@ -57,11 +101,35 @@ pub(super) fn process_hstack(
         true, // expands_schema
     );
 
+    let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
     let ctx = ProjectionContext::new(acc_projections, names, ctx.inner);
     proj_pd.pushdown_and_assign(input, ctx, lp_arena, expr_arena)?;
 
-    let lp = IRBuilder::new(input, expr_arena, lp_arena)
+    // Overwritten columns that are no longer read from the input are appended instead of
+    // replaced in place, so we restore their position.
+    let new_input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+    let moves_columns = exprs.iter().any(|e| {
+        input_schema.contains(e.output_name()) && !new_input_schema.contains(e.output_name())
+    });
+    let output_names: Vec<_> = exprs.iter().map(|e| e.output_name().clone()).collect();
+    let node = IRBuilder::new(input, expr_arena, lp_arena)
         .with_columns(exprs, options)
-        .build();
-    Ok(lp)
+        .node();
+    if !moves_columns {
+        return Ok(IRBuilder::new(node, expr_arena, lp_arena).build());
+    }
+
+    let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+    let names = input_schema
+        .iter_names()
+        .chain(&output_names)
+        .filter(|name| schema.contains(name))
+        .cloned()
+        .collect::<PlIndexSet<_>>();
+    let builder = IRBuilder::new(node, expr_arena, lp_arena);
+    if schema.iter_names().eq(names.iter()) {
+        Ok(builder.build())
+    } else {
+        Ok(builder.project_simple(names)?.build())
+    }
 }
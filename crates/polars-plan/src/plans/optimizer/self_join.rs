@@ -0,0 +1,168 @@
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+use polars_utils::itertools::Itertools;
+
+use super::cse::is_same_subplan;
+use crate::prelude::*;
+
+/// Rewrites self-joins that attach a per-group aggregate back to the rows it was computed from
+/// into window expressions, e.g.
+///
+/// `df.join(df.group_by("g").agg(s=col("v").sum()), on="g", how="left")`
+///
+/// becomes `df.with_columns(s=col("v").sum().over("g"))`, so that `df` is only computed once.
+pub(super) fn rewrite_self_joins(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let joins = ir_arena
+        .iter(root)
+        .filter_map(|(node, ir)| matches!(ir, IR::Join { .. }).then_some(node))
+        .collect_vec();
+
+    for node in joins {
+        if let Some(ir) = as_window(node, ir_arena, expr_arena) {
+            ir_arena.replace(node, ir);
+        }
+    }
+}
+
+/// Returns whether `agg` produces a single value per group, which an `over` broadcasts back to
+/// the rows of the group.
+fn is_scalar_agg(agg: &AExpr) -> bool {
+    match agg {
+        AExpr::Agg(agg) => !matches!(agg, IRAggExpr::Implode { .. } | IRAggExpr::AggGroups(_)),
+        AExpr::Len => true,
+        _ => false,
+    }
+}
+
+fn as_window(node: Node, ir_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) -> Option<IR> {
+    let IR::Join {
+        input_left,
+        input_right,
+        schema,
+        left_on,
+        right_on,
+        options,
+    } = ir_arena.get(node).clone()
+    else {
+        return None;
+    };
+    let args = &options.args;
+    if !matches!(args.how, JoinType::Inner | JoinType::Left)
+        || !matches!(
+            args.validation,
+            JoinValidation::ManyToMany | JoinValidation::ManyToOne
+        )
+        || matches!(
+            args.maintain_order,
+            MaintainOrderJoin::Right | MaintainOrderJoin::RightLeft
+        )
+        || args.slice.is_some()
+        || !args.should_coalesce()
+        || options.options.is_some()
+    {
+        return None;
+    }
+
+    let IR::GroupBy {
+        input,
+        keys,
+        aggs,
+        options: group_by_options,
+        apply: None,
+        ..
+    } = ir_arena.get(input_right).clone()
+    else {
+        return None;
+    };
+    if group_by_options.slice.is_some()
+        || group_by_options.is_dynamic()
+        || group_by_options.is_rolling()
+        || !aggs.iter().all(|e| is_scalar_agg(expr_arena.get(e.node())))
+    {
+        return None;
+    }
+
+    // Every key has to be joined with itself.
+    let column = |e: &ExprIR| match expr_arena.get(e.node()) {
+        AExpr::Column(name) if name == e.output_name() => Some(name.clone()),
+        _ => None,
+    };
+    let key_names = keys.iter().map(column).collect::<Option<PlHashSet<_>>>()?;
+    if left_on.len() != right_on.len() || key_names.len() != keys.len() {
+        return None;
+    }
+    let mut joined_names = PlHashSet::with_capacity(left_on.len());
+    for (l, r) in left_on.iter().zip(&right_on) {
+        let name = column(l)?;
+        if column(r)? != name {
+            return None;
+        }
+        joined_names.insert(name);
+    }
+    if joined_names != key_names || !is_same_subplan(input_left, input, ir_arena, expr_arena) {
+        return None;
+    }
+
+    let key_nodes = |expr_arena: &mut Arena<AExpr>| {
+        keys.iter()
+            .map(|k| expr_arena.add(AExpr::Column(k.output_name().clone())))
+            .collect_vec()
+    };
+    // Rows with a null key don't find a match unless nulls are equal.
+    let keys_not_null = (!args.nulls_equal).then(|| {
+        let exprs = key_nodes(expr_arena)
+            .into_iter()
+            .map(|k| AExprBuilder::new_from_node(k).is_not_null(expr_arena))
+            .map(|e| e.expr_ir_unnamed())
+            .collect_vec();
+        AExprBuilder::all_horizontal(exprs, expr_arena).node()
+    });
+
+    let left_schema = ir_arena.get(input_left).schema(ir_arena).into_owned();
+    let exprs = aggs
+        .iter()
+        .map(|agg| {
+            let name = agg.output_name();
+            let name = if left_schema.contains(name) {
+                format_pl_smallstr!("{name}{}", args.suffix())
+            } else {
+                name.clone()
+            };
+
+            let mut node = expr_arena.add(AExpr::Over {
+                function: agg.node(),
+                partition_by: key_nodes(expr_arena),
+                order_by: None,
+                mapping: WindowMapping::GroupsToRows,
+            });
+            if let (JoinType::Left, Some(predicate)) = (&args.how, keys_not_null) {
+                let dtype = schema.get(&name)?.clone();
+                let null = AExprBuilder::lit_scalar(Scalar::null(dtype), expr_arena);
+                node = AExprBuilder::new_from_node(predicate)
+                    .ternary(node, null, expr_arena)
+                    .node();
+            }
+            Some(ExprIR::new(node, OutputName::Alias(name)))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // Inner joins drop the rows without a match.
+    let input = match (&args.how, keys_not_null) {
+        (JoinType::Inner, Some(predicate)) => ir_arena.add(IR::Filter {
+            input: input_left,
+            predicate: ExprIR::from_node(predicate, expr_arena),
+        }),
+        _ => input_left,
+    };
+
+    Some(IR::HStack {
+        input,
+        exprs,
+        schema,
+        options: ProjectionOptions::default(),
+    })
+}
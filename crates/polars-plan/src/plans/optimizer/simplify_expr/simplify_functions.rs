@@ -148,6 +148,49 @@ pub(super) fn optimize_functions(
                 None
             }
         },
+        // Regexes without other syntax than anchors don't need the regex engine:
+        // contains("^foo$") -> == "foo"
+        // contains("^foo") -> starts_with("foo")
+        // contains("foo$") -> ends_with("foo")
+        // contains("foo") -> contains("foo", literal=true)
+        #[cfg(all(feature = "strings", feature = "regex"))]
+        IRFunctionExpr::StringExpr(IRStringFunction::Contains {
+            literal: false,
+            strict,
+        }) => {
+            let pattern = match expr_arena.get(input[1].node()) {
+                AExpr::Literal(lv) => lv.extract_str(),
+                _ => None,
+            };
+            match pattern.and_then(regex_as_literal) {
+                Some((starts, literal, ends)) => {
+                    let literal = expr_arena.add(AExpr::Literal(
+                        Scalar::from(PlSmallStr::from_string(literal)).into(),
+                    ));
+                    let function = match (starts, ends) {
+                        (true, true) => {
+                            return Ok(Some(AExpr::BinaryExpr {
+                                left: input[0].node(),
+                                op: Operator::Eq,
+                                right: literal,
+                            }));
+                        },
+                        (true, false) => IRStringFunction::StartsWith,
+                        (false, true) => IRStringFunction::EndsWith,
+                        (false, false) => IRStringFunction::Contains {
+                            literal: true,
+                            strict,
+                        },
+                    };
+                    Some(AExpr::Function {
+                        input: vec![input[0].clone(), ExprIR::from_node(literal, expr_arena)],
+                        function: IRFunctionExpr::StringExpr(function),
+                        options,
+                    })
+                },
+                None => None,
+            }
+        },
         IRFunctionExpr::Boolean(IRBooleanFunction::Not) => {
             let y = expr_arena.get(input[0].node());
 
@@ -382,3 +425,29 @@ fn get_string_concat_input(
         _ => None,
     }
 }
+
+/// Returns the literal a regex matches, with whether it is anchored at the start and at the end,
+/// if the regex has no other syntax than escaped characters.
+#[cfg(all(feature = "strings", feature = "regex"))]
+fn regex_as_literal(pattern: &str) -> Option<(bool, String, bool)> {
+    let is_meta = |c: char| "\\.+*?()|[]{}^$#&-~".contains(c);
+
+    let (starts, pattern) = match pattern.strip_prefix('^') {
+        Some(pattern) => (true, pattern),
+        None => (false, pattern),
+    };
+    let mut literal = String::with_capacity(pattern.len());
+    let mut ends = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            // Escaped letters are classes or assertions, e.g. `\d` or `\b`.
+            '\\' => literal.push(chars.next().filter(|c| is_meta(*c))?),
+            '$' if chars.as_str().is_empty() => ends = true,
+            '#' | '&' | '-' | '~' => literal.push(c),
+            c if is_meta(c) => return None,
+            c => literal.push(c),
+        }
+    }
+    Some((starts, literal, ends))
+}
@@ -88,6 +88,50 @@ fn combine_outer_inner_slice(outer_slice: State, inner_slice: State) -> Option<S
     None
 }
 
+/// Determine the slices that can be pushed into the inputs of a join with a slice `state` on its
+/// output.
+///
+/// Every row of the preserved side of a left (right) or cross join produces at least one output
+/// row (if any rows are produced at all), so the first `offset + len` output rows only depend on
+/// the first `offset + len` rows of that side. This requires the output to not be ordered by the
+/// other side and no uniqueness validation on the sliced side.
+fn join_input_slices(args: &JoinArgs, state: State) -> (Option<State>, Option<State>) {
+    use MaintainOrderJoin as MO;
+
+    let Some(input_slice) = (state.offset >= 0)
+        .then(|| state.len.checked_add(state.offset.try_into().ok()?))
+        .flatten()
+        .map(|len| State { offset: 0, len })
+    else {
+        return (None, None);
+    };
+
+    let left_ordered = matches!(args.maintain_order, MO::None | MO::Left | MO::LeftRight);
+    let right_ordered = matches!(args.maintain_order, MO::None | MO::Right | MO::RightLeft);
+
+    match args.how {
+        JoinType::Left | JoinType::Cross
+            if left_ordered
+                && matches!(
+                    args.validation,
+                    JoinValidation::ManyToMany | JoinValidation::ManyToOne
+                ) =>
+        {
+            (Some(input_slice), None)
+        },
+        JoinType::Right
+            if right_ordered
+                && matches!(
+                    args.validation,
+                    JoinValidation::ManyToMany | JoinValidation::OneToMany
+                ) =>
+        {
+            (None, Some(input_slice))
+        },
+        _ => (None, None),
+    }
+}
+
 /// Can push down slice when:
 /// * all projections are elementwise
 /// * at least 1 projection is based on a column (for height broadcast)
@@ -449,11 +493,14 @@ impl SlicePushDown {
                     };
                 }
 
-                // first restart optimization in both inputs and get the updated LP
-                let lp_left = self.pushdown(input_left, None, lp_arena, expr_arena)?;
+                // first restart optimization in both inputs and get the updated LP, limiting
+                // the inputs where the join type allows it
+                let (left_state, right_state) = join_input_slices(&options.args, state);
+
+                let lp_left = self.pushdown(input_left, left_state, lp_arena, expr_arena)?;
                 let input_left = lp_arena.add(lp_left);
 
-                let lp_right = self.pushdown(input_right, None, lp_arena, expr_arena)?;
+                let lp_right = self.pushdown(input_right, right_state, lp_arena, expr_arena)?;
                 let input_right = lp_arena.add(lp_right);
 
                 // then assign the slice state to the join operation
@@ -689,7 +736,7 @@ impl SlicePushDown {
                 let (lp, state) = m;
                 self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
             },
-            (MapFunction { input, function }, _) if function.allow_predicate_pd() => {
+            (MapFunction { input, function }, _) if function.allow_slice_pd() => {
                 let lp = MapFunction { input, function };
                 self.pushdown_and_continue(lp, state, lp_arena, expr_arena)
             },
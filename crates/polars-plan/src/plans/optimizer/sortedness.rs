@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
 use polars_core::chunked_array::cast::CastOptions;
-use polars_core::prelude::{FillNullStrategy, PlHashMap, PlHashSet};
+use polars_core::prelude::{FillNullStrategy, PlHashMap, PlHashSet, SortMultipleOptions};
 use polars_core::schema::Schema;
 use polars_core::series::IsSorted;
+use polars_ops::frame::{JoinType, MaintainOrderJoin};
+use polars_utils::IdxSize;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
@@ -14,7 +16,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(all(feature = "strings", feature = "concat_str"))]
 use crate::plans::IRStringFunction;
 use crate::plans::{
-    AExpr, ExprIR, FunctionIR, HintIR, IR, IRFunctionExpr, Sorted, ToFieldContext,
+    AExpr, ArenaLpIter, ExprIR, FunctionIR, HintIR, IR, IRFunctionExpr, Sorted, ToFieldContext,
     constant_evaluate, into_column,
 };
 
@@ -394,11 +396,39 @@ fn is_sorted_rec(
         },
 
         IR::GroupBy { .. } => None,
-        IR::Join { .. } => None,
+        IR::Join {
+            input_left,
+            schema,
+            options,
+            ..
+        } => {
+            use MaintainOrderJoin as MO;
+
+            // Joins that only drop or repeat left rows keep them in the left order when asked to.
+            let how = &options.args.how;
+            let keeps_left_order =
+                matches!(how, JoinType::Inner | JoinType::Left | JoinType::Cross)
+                    && matches!(options.args.maintain_order, MO::Left | MO::LeftRight);
+            if !keeps_left_order {
+                return None;
+            }
+
+            let input_sorted = rec!(*input_left)?;
+
+            // Left columns are never renamed, but they may be absent from the output.
+            let first_dropped_key = input_sorted
+                .0
+                .iter()
+                .position(|v| !schema.contains(&v.column));
+            match first_dropped_key {
+                None => Some(input_sorted),
+                Some(0) => None,
+                Some(i) => Some(IRSorted(input_sorted.0.iter().take(i).cloned().collect())),
+            }
+        },
         IR::MapFunction { input, function } => match function {
             FunctionIR::Hint(hint) => match hint {
                 HintIR::Sorted(v) => Some(IRSorted(v.clone())),
-                #[expect(unreachable_patterns)]
                 _ => rec!(*input),
             },
             _ => None,
@@ -432,6 +462,71 @@ fn is_sorted_rec(
     sorted
 }
 
+/// Removes `Sort` nodes whose input is already sorted on the same keys in the same order.
+///
+/// A sort that also slices is replaced by that slice.
+pub(super) fn eliminate_redundant_sorts(
+    root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) {
+    // Visit children before their parents, so that nested sorts are resolved bottom-up.
+    let sorts = ir_arena
+        .iter(root)
+        .filter_map(|(node, ir)| matches!(ir, IR::Sort { .. }).then_some(node))
+        .collect_vec();
+
+    for node in sorts.into_iter().rev() {
+        let IR::Sort {
+            input,
+            by_column,
+            slice,
+            sort_options,
+        } = ir_arena.get(node)
+        else {
+            continue;
+        };
+        let input = *input;
+
+        let Some(input_sorted) = is_sorted(input, ir_arena, expr_arena) else {
+            continue;
+        };
+        if !sort_is_redundant(by_column, sort_options, &input_sorted, expr_arena) {
+            continue;
+        }
+
+        let slice = slice
+            .as_ref()
+            .map(|(offset, len, _)| (*offset, *len as IdxSize))
+            .or_else(|| sort_options.limit.map(|limit| (0, limit)));
+        let ir = match slice {
+            Some((offset, len)) => IR::Slice { input, offset, len },
+            None => ir_arena.get(input).clone(),
+        };
+        ir_arena.replace(node, ir);
+    }
+}
+
+fn sort_is_redundant(
+    by_column: &[ExprIR],
+    sort_options: &SortMultipleOptions,
+    input_sorted: &IRSorted,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let option_at = |v: &[bool], i: usize| if v.len() == 1 { v[0] } else { v[i] };
+
+    by_column.len() <= input_sorted.0.len()
+        && by_column
+            .iter()
+            .zip(input_sorted.0.iter())
+            .enumerate()
+            .all(|(i, (e, s))| {
+                into_column(e.node(), expr_arena).is_some_and(|c| c == &s.column)
+                    && s.descending == Some(option_at(&sort_options.descending, i))
+                    && s.nulls_last == Some(option_at(&sort_options.nulls_last, i))
+            })
+}
+
 fn first_expr_ir_sorted(
     exprs: &[ExprIR],
     arena: &Arena<AExpr>,
@@ -568,6 +663,7 @@ pub fn function_expr_sortedness(
         #[cfg(feature = "mode")]
         IRFunctionExpr::Mode {
             maintain_order: true,
+            ..
         } => {
             let [e] = inputs else {
                 return None;
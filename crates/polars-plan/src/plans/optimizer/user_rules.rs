@@ -0,0 +1,96 @@
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// The point in the optimizer pipeline where a user defined rewrite rule runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OptimizerRuleStage {
+    /// After conversion to IR and type coercion, before any pushdown optimization.
+    BeforePushdown,
+    /// After predicate, projection and slice pushdown and expression simplification.
+    AfterPushdown,
+    /// After all built-in optimizations, right before the plan is handed to an engine.
+    Final,
+}
+
+/// A user defined rewrite of the IR.
+///
+/// Registered rules run on every optimized query, so implementations should return the root
+/// unchanged for plans they don't apply to. A rule must not change the output schema of the
+/// plan.
+pub trait OptimizerRewriteRule: Send + Sync {
+    /// Rewrite the plan rooted at `root` and return the (possibly new) root.
+    fn rewrite(
+        &self,
+        root: Node,
+        ir_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<Node>;
+}
+
+struct RegisteredRule {
+    stage: OptimizerRuleStage,
+    rule: Arc<dyn OptimizerRewriteRule>,
+}
+
+static REGISTRY: LazyLock<RwLock<PlIndexMap<PlSmallStr, RegisteredRule>>> =
+    LazyLock::new(Default::default);
+
+/// Register a rewrite rule under `name` that runs at `stage` of the optimizer.
+///
+/// Rules of the same stage run in registration order.
+pub fn register_optimizer_rule(
+    name: &str,
+    stage: OptimizerRuleStage,
+    rule: Arc<dyn OptimizerRewriteRule>,
+) -> PolarsResult<()> {
+    let mut registry = REGISTRY.write().unwrap();
+    polars_ensure!(
+        !registry.contains_key(name),
+        ComputeError: "attempted to register duplicate optimizer rule with name '{name}'"
+    );
+    registry.insert(name.into(), RegisteredRule { stage, rule });
+    Ok(())
+}
+
+pub fn unregister_optimizer_rule(name: &str) -> PolarsResult<Arc<dyn OptimizerRewriteRule>> {
+    REGISTRY
+        .write()
+        .unwrap()
+        .shift_remove(name)
+        .map(|r| r.rule)
+        .ok_or_else(
+            || polars_err!(ComputeError: "attempted to unregister unknown optimizer rule with name '{name}'"),
+        )
+}
+
+pub(super) fn run_user_rules(
+    stage: OptimizerRuleStage,
+    mut root: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    verbose: bool,
+) -> PolarsResult<Node> {
+    // Clone the rules so that a rule may (un)register rules without deadlocking.
+    let rules = {
+        let registry = REGISTRY.read().unwrap();
+        if registry.is_empty() {
+            return Ok(root);
+        }
+        registry
+            .iter()
+            .filter(|(_, r)| r.stage == stage)
+            .map(|(name, r)| (name.clone(), r.rule.clone()))
+            .collect::<Vec<_>>()
+    };
+
+    for (name, rule) in rules {
+        if verbose {
+            eprintln!("run user optimizer rule '{name}' ({stage:?})");
+        }
+        root = rule.rewrite(root, ir_arena, expr_arena)?;
+    }
+    Ok(root)
+}
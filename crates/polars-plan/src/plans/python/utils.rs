@@ -31,6 +31,51 @@ pub fn python_df_to_rust(py: Python, df: Bound<PyAny>) -> PolarsResult<DataFrame
     unsafe { polars_ffi::version_0::import_df(location, width) }
 }
 
+/// Pushdowns that an IO plugin reported to have applied itself.
+///
+/// The engine applies the remaining ones to the batches the plugin produces.
+#[derive(Clone, Copy, Debug)]
+pub struct IOPluginApplied {
+    pub predicate: bool,
+    pub projection: bool,
+    pub n_rows: bool,
+}
+
+/// Split the value returned when initializing an IO plugin into its generator and the pushdowns
+/// it applied.
+///
+/// This is either `(generator, predicate_applied)`, in which case the projection and `n_rows`
+/// are assumed to be applied, or
+/// `(generator, predicate_applied, projection_applied, n_rows_applied)`.
+pub fn io_plugin_init<'py>(
+    init: Bound<'py, PyAny>,
+) -> PolarsResult<(Bound<'py, PyAny>, IOPluginApplied)> {
+    let err = || polars_err!(ComputeError: "expected tuple got {}", init);
+    let flag = |idx: usize| -> PolarsResult<bool> {
+        let value = init.get_item(idx).map_err(|_| err())?;
+        value
+            .extract::<bool>()
+            .map_err(|_| polars_err!(ComputeError: "expected bool got {}", value))
+    };
+
+    let generator = init.get_item(0).map_err(|_| err())?;
+    let predicate = flag(1)?;
+    let (projection, n_rows) = if init.len().map_err(|_| err())? > 2 {
+        (flag(2)?, flag(3)?)
+    } else {
+        (true, true)
+    };
+
+    Ok((
+        generator,
+        IOPluginApplied {
+            predicate,
+            projection,
+            n_rows,
+        },
+    ))
+}
+
 pub(crate) fn python_schema_to_rust(py: Python, schema: Bound<PyAny>) -> PolarsResult<SchemaRef> {
     let err = |_| polars_err!(ComputeError: "expected a polars.Schema; got {}", schema);
     let df = schema.call_method0("to_frame").map_err(err)?;
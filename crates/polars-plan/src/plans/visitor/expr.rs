@@ -99,6 +99,9 @@ impl TreeWalker for Expr {
             AnonymousFunction { input, function, options, fmt_str } => {
                 AnonymousFunction { input: input.into_iter().map(f).collect::<Result<_, _>>()?, function, options, fmt_str }
             },
+            AnonymousAgg { input, function, fmt_str } => {
+                AnonymousAgg { input: input.into_iter().map(f).collect::<Result<_, _>>()?, function, fmt_str }
+            },
             Eval { expr: input, evaluation, variant } => Eval { expr: am(input, &mut f)?, evaluation: am(evaluation, f)?, variant },
             #[cfg(feature = "dtype-struct")]
             StructEval { expr: input, evaluation } => {
@@ -144,6 +147,20 @@ impl AexprNode {
         self.node = node;
     }
 
+    /// Replace the current `Node` with a new `AExpr`.
+    pub fn replace(&mut self, ae: AExpr, arena: &mut Arena<AExpr>) {
+        arena.replace(self.node, ae);
+    }
+
+    /// Wrap the expression of this node, e.g. in a cast or a function.
+    ///
+    /// The current `AExpr` is moved to a new `Node`, which is passed to `build` to construct the
+    /// `AExpr` that takes its place.
+    pub fn splice_above<F: FnOnce(Node) -> AExpr>(&mut self, build: F, arena: &mut Arena<AExpr>) {
+        let input = arena.duplicate(self.node);
+        arena.replace(self.node, build(input));
+    }
+
     pub(crate) fn is_leaf(&self, arena: &Arena<AExpr>) -> bool {
         matches!(self.to_aexpr(arena), AExpr::Column(_) | AExpr::Literal(_))
     }
@@ -49,6 +49,29 @@ impl IRNode {
         let node = arena.add(ir_node);
         self.node = node;
     }
+
+    /// Insert a new `IR` between this node and its parents.
+    ///
+    /// The current `IR` is moved to a new `Node`, which is passed to `build` to construct the
+    /// `IR` that takes its place. Parents referencing this `Node` get the new `IR` as input.
+    pub fn splice_above<F: FnOnce(Node) -> IR>(&mut self, build: F, arena: &mut Arena<IR>) {
+        let input = arena.duplicate(self.node);
+        arena.replace(self.node, build(input));
+    }
+
+    /// Remove the `IR` of this node from the plan, putting its single input in its place.
+    ///
+    /// The `IR` must have exactly one input and the same schema as that input.
+    pub fn splice_out(&mut self, arena: &mut Arena<IR>) -> PolarsResult<()> {
+        let inputs = arena.get(self.node).get_inputs();
+        polars_ensure!(
+            inputs.len() == 1,
+            InvalidOperation: "can only splice out a node with a single input, got {}", inputs.len()
+        );
+        let input = arena.get(inputs[0]).clone();
+        arena.replace(self.node, input);
+        Ok(())
+    }
 }
 
 pub type IRNodeArena = (Arena<IR>, Arena<AExpr>);
@@ -105,8 +128,8 @@ impl TreeWalker for IRNode {
     }
 }
 
-#[cfg(feature = "cse")]
-pub(crate) fn with_ir_arena<F: FnOnce(&mut IRNodeArena) -> T, T>(
+/// Run `func` with ownership of both arenas, as required by the [`TreeWalker`] of [`IRNode`].
+pub fn with_ir_arena<F: FnOnce(&mut IRNodeArena) -> T, T>(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     func: F,
@@ -114,8 +137,8 @@ pub(crate) fn with_ir_arena<F: FnOnce(&mut IRNodeArena) -> T, T>(
     try_with_ir_arena(lp_arena, expr_arena, |a| Ok(func(a))).unwrap()
 }
 
-#[cfg(feature = "cse")]
-pub(crate) fn try_with_ir_arena<F: FnOnce(&mut IRNodeArena) -> PolarsResult<T>, T>(
+/// Fallible version of [`with_ir_arena`].
+pub fn try_with_ir_arena<F: FnOnce(&mut IRNodeArena) -> PolarsResult<T>, T>(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     func: F,
@@ -38,6 +38,7 @@ use crate::expr::selector::PySelector;
 use crate::functions::PyStringCacheHolder;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::lazyframe::PyInProcessQuery;
+use crate::lazyframe::plan::PyQueryPlanNode;
 use crate::lazyframe::{PyLazyFrame, PyOptFlags};
 use crate::lazygroupby::PyLazyGroupBy;
 use crate::series::PySeries;
@@ -112,6 +113,7 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyDataFrame>().unwrap();
     m.add_class::<PyLazyFrame>().unwrap();
     m.add_class::<PyOptFlags>().unwrap();
+    m.add_class::<PyQueryPlanNode>().unwrap();
     #[cfg(not(target_arch = "wasm32"))]
     m.add_class::<PyInProcessQuery>().unwrap();
     m.add_class::<PyLazyGroupBy>().unwrap();
@@ -186,6 +188,9 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::arg_where))
         .unwrap();
+    #[cfg(feature = "search_sorted")]
+    m.add_wrapped(wrap_pyfunction!(functions::search_sorted_by))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::as_struct))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::coalesce))
@@ -231,6 +236,8 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::lit)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::map_expr))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::python_aggregation))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::pearson_corr))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::rolling_corr))
@@ -292,6 +299,10 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::thread_pool_size))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::row_encoding_version))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::stable_hash_version))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::enable_string_cache))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::disable_string_cache))
@@ -329,10 +340,16 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::gen_uuid_v7))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::register_udf_serializer))
+        .unwrap();
+    #[cfg(feature = "new_streaming")]
+    m.add_wrapped(wrap_pyfunction!(functions::set_streaming_metrics_callback))
+        .unwrap();
 
     // Functions - random
     m.add_wrapped(wrap_pyfunction!(functions::set_random_seed))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::uuid4)).unwrap();
 
     // Functions - escape_regex
     m.add_wrapped(wrap_pyfunction!(functions::escape_regex))
@@ -435,6 +452,11 @@ pub fn _polars_runtime(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         py.get_type::<exceptions::MapWithoutReturnDtypeWarning>(),
     )
     .unwrap();
+    m.add(
+        "LossyCastWarning",
+        py.get_type::<exceptions::LossyCastWarning>(),
+    )
+    .unwrap();
 
     // Exceptions - Panic
     m.add(
@@ -32,6 +32,13 @@ impl PyCategories {
         }
     }
 
+    #[staticmethod]
+    pub fn scoped_or_global() -> Self {
+        Self {
+            categories: Categories::scoped_or_global(),
+        }
+    }
+
     #[staticmethod]
     pub fn random(namespace: String, physical: String) -> Self {
         Self {
@@ -401,7 +401,7 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DataType> {
                     "Boolean" => DataType::Boolean,
                     "String" => DataType::String,
                     "Binary" => DataType::Binary,
-                    "Categorical" => DataType::from_categories(Categories::global()),
+                    "Categorical" => DataType::from_categories(Categories::scoped_or_global()),
                     "Enum" => DataType::from_frozen_categories(FrozenCategories::new([]).unwrap()),
                     "Date" => DataType::Date,
                     "Time" => DataType::Time,
@@ -771,6 +771,21 @@ impl PolarsObject for ObjectValue {
     fn type_name() -> &'static str {
         "object"
     }
+
+    fn estimated_size(&self) -> usize {
+        static GETSIZEOF: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+        Python::attach(|py| {
+            let getsizeof = GETSIZEOF.get_or_try_init(py, || {
+                PyResult::Ok(py.import("sys")?.getattr("getsizeof")?.unbind())
+            })?;
+            getsizeof
+                .bind(py)
+                .call1((&self.inner, 0))?
+                .extract::<usize>()
+        })
+        .unwrap_or(0)
+    }
 }
 
 impl From<Py<PyAny>> for ObjectValue {
@@ -869,6 +884,64 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<InterpolationMethod> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<InterpolateByMethod> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "linear" => InterpolateByMethod::Linear,
+            "nearest" => InterpolateByMethod::Nearest,
+            "forward" => InterpolateByMethod::Forward,
+            "backward" => InterpolateByMethod::Backward,
+            "cubic_spline" => InterpolateByMethod::CubicSpline,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "interpolation `method` must be one of {{'linear', 'nearest', 'forward', 'backward', 'cubic_spline'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<ModeTies> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "all" => ModeTies::All,
+            "first" => ModeTies::First,
+            "smallest" => ModeTies::Smallest,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`ties` must be one of {{'all', 'first', 'smallest'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<ListCumFunction> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "sum" => ListCumFunction::Sum,
+            "prod" => ListCumFunction::Prod,
+            "min" => ListCumFunction::Min,
+            "max" => ListCumFunction::Max,
+            "count" => ListCumFunction::Count,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`function` must be one of {{'sum', 'prod', 'min', 'max', 'count'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "avro")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<AvroCompression>> {
     type Error = PyErr;
@@ -938,9 +1011,64 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<RoundMode> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "half_to_even" => RoundMode::HalfToEven,
             "half_away_from_zero" => RoundMode::HalfAwayFromZero,
+            "to_zero" => RoundMode::ToZero,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`mode` must be one of {{'half_to_even', 'half_away_from_zero', 'to_zero'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DecimalArithmeticOp> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "add" => DecimalArithmeticOp::Add,
+            "sub" => DecimalArithmeticOp::Sub,
+            "mul" => DecimalArithmeticOp::Mul,
+            "div" => DecimalArithmeticOp::Div,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "`mode` must be one of {{'half_to_even', 'half_away_from_zero'}}, got {v}",
+                    "`op` must be one of {{'add', 'sub', 'mul', 'div'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<DecimalOverflow> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => DecimalOverflow::Raise,
+            "null" => DecimalOverflow::Null,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`overflow` must be one of {{'raise', 'null'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CheckedArithmeticOp> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "add" => CheckedArithmeticOp::Add,
+            "sub" => CheckedArithmeticOp::Sub,
+            "mul" => CheckedArithmeticOp::Mul,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`op` must be one of {{'add', 'sub', 'mul'}}, got {v}",
                 )));
             },
         };
@@ -1164,9 +1292,10 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<RankMethod> {
             "dense" => RankMethod::Dense,
             "ordinal" => RankMethod::Ordinal,
             "random" => RankMethod::Random,
+            "percent" => RankMethod::Percent,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "rank `method` must be one of {{'min', 'max', 'average', 'dense', 'ordinal', 'random'}}, got {v}",
+                    "rank `method` must be one of {{'min', 'max', 'average', 'dense', 'ordinal', 'random', 'percent'}}, got {v}",
                 )));
             },
         };
@@ -1212,6 +1341,23 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Roll> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<WeekConvention> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "iso" => WeekConvention::Iso,
+            "us" => WeekConvention::Us,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`convention` must be one of {{'iso', 'us'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<TimeUnit> {
     type Error = PyErr;
 
@@ -1249,6 +1395,41 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<UniqueKeepStrategy> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<UnnestCollision> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => UnnestCollision::Raise,
+            "overwrite" => UnnestCollision::Overwrite,
+            "keep" => UnnestCollision::Keep,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`on_collision` must be one of {{'raise', 'overwrite', 'keep'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Base64Alphabet> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "base64" => Base64Alphabet::Standard,
+            "base64url" => Base64Alphabet::UrlSafe,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`encoding` must be one of {{'base64', 'base64url'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "search_sorted")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SearchSortedSide> {
     type Error = PyErr;
@@ -1687,6 +1868,24 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<UnicodeForm> {
     }
 }
 
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<UrlPart> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "scheme" => UrlPart::Scheme,
+            "host" => UrlPart::Host,
+            "path" => UrlPart::Path,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`part` must be one of {{'scheme', 'host', 'path'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "parquet")]
 impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<KeyValueMetadata>> {
     type Error = PyErr;
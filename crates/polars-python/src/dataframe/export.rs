@@ -1,9 +1,10 @@
 use arrow::datatypes::IntegerType;
 use arrow::record_batch::RecordBatch;
-use parking_lot::RwLockWriteGuard;
+use parking_lot::{Mutex, RwLockWriteGuard};
 use polars::prelude::*;
 use polars_compute::cast::CastOptionsImpl;
 use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyList, PyTuple};
 
@@ -13,7 +14,7 @@ use crate::error::PyPolarsErr;
 use crate::interop;
 use crate::interop::arrow::to_py::dataframe_to_stream;
 use crate::prelude::PyCompatLevel;
-use crate::utils::EnterPolarsExt;
+use crate::utils::{BufferedValues, EnterPolarsExt};
 
 #[pymethods]
 impl PyDataFrame {
@@ -42,38 +43,21 @@ impl PyDataFrame {
 
     #[cfg(feature = "object")]
     pub fn row_tuples<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
-        let df = self.df.read();
-        let mut rechunked;
-        // Rechunk if random access would become rather expensive.
-        // TODO: iterate over the chunks directly instead of using random access.
-        let df = if df.max_n_chunks() > 16 {
-            rechunked = df.clone();
-            py.enter_polars_ok(|| rechunked.rechunk_mut_par())?;
-            &rechunked
-        } else {
-            &df
-        };
-        PyList::new(
-            py,
-            (0..df.height()).map(|idx| {
-                PyTuple::new(
-                    py,
-                    df.columns().iter().map(|c| match c.dtype() {
-                        DataType::Null => py.None(),
-                        DataType::Object(_) => {
-                            let obj: Option<&ObjectValue> = c.get_object(idx).map(|any| any.into());
-                            obj.into_py_any(py).unwrap()
-                        },
-                        _ => {
-                            // SAFETY: we are in bounds.
-                            let av = unsafe { c.get_unchecked(idx) };
-                            Wrap(av).into_py_any(py).unwrap()
-                        },
-                    }),
-                )
-                .unwrap()
-            }),
-        )
+        rows_to_list(py, &self.df.read())
+    }
+
+    /// Iterate over the rows of this DataFrame as tuples.
+    /// At most `buffer_size` rows are converted at a time.
+    #[cfg(feature = "object")]
+    pub fn iter_rows(&self, buffer_size: usize) -> PyResult<PyRowIter> {
+        if buffer_size == 0 {
+            return Err(PyValueError::new_err("'buffer_size' must be positive"));
+        }
+        Ok(PyRowIter {
+            df: self.df.read().clone(),
+            buffer_size,
+            state: Mutex::new(BufferedValues::default()),
+        })
     }
 
     #[allow(clippy::wrong_self_convention)]
@@ -185,3 +169,67 @@ impl PyDataFrame {
         dataframe_to_stream(&self.df.read(), py)
     }
 }
+
+/// Iterator over the rows of a DataFrame that converts at most `buffer_size`
+/// rows to Python tuples at a time.
+#[cfg(feature = "object")]
+#[pyclass(frozen)]
+pub struct PyRowIter {
+    df: DataFrame,
+    buffer_size: usize,
+    state: Mutex<BufferedValues>,
+}
+
+#[cfg(feature = "object")]
+#[pymethods]
+impl PyRowIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        BufferedValues::next(&self.state, py, |offset| {
+            if offset >= self.df.height() {
+                return Ok(None);
+            }
+            let rows = self.df.slice(offset as i64, self.buffer_size);
+            rows_to_list(py, &rows).map(Some)
+        })
+    }
+}
+
+/// Convert the rows of a DataFrame to a Python list of tuples.
+#[cfg(feature = "object")]
+fn rows_to_list<'py>(py: Python<'py>, df: &DataFrame) -> PyResult<Bound<'py, PyList>> {
+    let mut rechunked;
+    // Rechunk if random access would become rather expensive.
+    // TODO: iterate over the chunks directly instead of using random access.
+    let df = if df.max_n_chunks() > 16 {
+        rechunked = df.clone();
+        py.enter_polars_ok(|| rechunked.rechunk_mut_par())?;
+        &rechunked
+    } else {
+        df
+    };
+    PyList::new(
+        py,
+        (0..df.height()).map(|idx| {
+            PyTuple::new(
+                py,
+                df.columns().iter().map(|c| match c.dtype() {
+                    DataType::Null => py.None(),
+                    DataType::Object(_) => {
+                        let obj: Option<&ObjectValue> = c.get_object(idx).map(|any| any.into());
+                        obj.into_py_any(py).unwrap()
+                    },
+                    _ => {
+                        // SAFETY: we are in bounds.
+                        let av = unsafe { c.get_unchecked(idx) };
+                        Wrap(av).into_py_any(py).unwrap()
+                    },
+                }),
+            )
+            .unwrap()
+        }),
+    )
+}
@@ -1,6 +1,7 @@
 use std::hash::BuildHasher;
 
 use arrow::bitmap::MutableBitmap;
+use arrow::compute::aggregate::SeenBuffers;
 use either::Either;
 use parking_lot::RwLock;
 use polars::prelude::*;
@@ -10,7 +11,7 @@ use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyList, PyType};
 
-use self::row_encode::{_get_rows_encoded_ca, _get_rows_encoded_ca_unordered};
+use self::row_encode::{_get_rows_encoded_ca, _get_rows_encoded_ca_unordered, encode_rows_ordered};
 use super::PyDataFrame;
 use crate::PyLazyFrame;
 use crate::conversion::Wrap;
@@ -40,8 +41,13 @@ impl PyDataFrame {
         )))
     }
 
-    pub fn estimated_size(&self) -> usize {
-        self.df.read().estimated_size()
+    pub fn estimated_size(&self, deep: bool) -> usize {
+        let df = self.df.read();
+        if deep {
+            df.estimated_size_dedup(&mut SeenBuffers::default())
+        } else {
+            df.estimated_size()
+        }
     }
 
     pub fn dtype_strings(&self) -> Vec<String> {
@@ -484,6 +490,25 @@ impl PyDataFrame {
         })
     }
 
+    #[cfg(feature = "search_sorted")]
+    pub fn search_sorted(
+        &self,
+        py: Python<'_>,
+        by: Vec<PyBackedStr>,
+        search_values: &PyDataFrame,
+        side: Wrap<SearchSortedSide>,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    ) -> PyResult<PySeries> {
+        let by = strings_to_pl_smallstr(by);
+        let search_values = search_values.df.read();
+        py.enter_polars_series(|| {
+            self.df
+                .read()
+                .search_sorted(&by, &search_values, side.0, &descending, &nulls_last)
+        })
+    }
+
     pub fn null_count(&self, py: Python) -> PyResult<Self> {
         py.enter_polars_df(|| Ok(self.df.read().null_count()))
     }
@@ -506,12 +531,17 @@ impl PyDataFrame {
         py.enter_polars_series(|| self.df.write().hash_rows(Some(hb)))
     }
 
-    #[pyo3(signature = (keep_names_as, column_names))]
+    pub fn hash_rows_stable(&self, py: Python<'_>, seed: u64) -> PyResult<PySeries> {
+        py.enter_polars_series(|| self.df.read().hash_rows_stable(seed))
+    }
+
+    #[pyo3(signature = (keep_names_as, column_names, dtype=None))]
     pub fn transpose(
         &self,
         py: Python<'_>,
         keep_names_as: Option<&str>,
         column_names: &Bound<PyAny>,
+        dtype: Option<Wrap<DataType>>,
     ) -> PyResult<Self> {
         let new_col_names = if let Ok(name) = column_names.extract::<Vec<String>>() {
             Some(Either::Right(name))
@@ -520,7 +550,14 @@ impl PyDataFrame {
         } else {
             None
         };
-        py.enter_polars_df(|| self.df.write().transpose(keep_names_as, new_col_names))
+        py.enter_polars_df(|| match dtype {
+            None => self.df.write().transpose(keep_names_as, new_col_names),
+            Some(dtype) => {
+                self.df
+                    .write()
+                    .transpose_with_dtype(keep_names_as, new_col_names, &dtype.0)
+            },
+        })
     }
 
     pub fn upsample(
@@ -571,21 +608,33 @@ impl PyDataFrame {
     /// Export the columns via polars-ffi
     /// # Safety
     /// Needs a preallocated *mut SeriesExport that has allocated space for n_columns.
-    pub unsafe fn _export_columns(&self, location: usize) {
-        use polars_ffi::version_0::export_column;
-
+    #[pyo3(signature = (location, ffi_version=None))]
+    pub unsafe fn _export_columns(
+        &self,
+        location: usize,
+        ffi_version: Option<(u16, u16)>,
+    ) -> PyResult<()> {
+        use polars_ffi::version_0::export_series_with_compat;
+
+        let compat_level = match ffi_version {
+            Some((major, minor)) => polars_ffi::negotiate(major, minor)
+                .map_err(PyPolarsErr::from)?
+                .compat_level(),
+            None => CompatLevel::newest(),
+        };
         let df = self.df.read();
         let cols = df.columns();
 
         let location = location as *mut SeriesExport;
 
         for (i, col) in cols.iter().enumerate() {
-            let e = export_column(col);
+            let e = export_series_with_compat(col.as_materialized_series(), compat_level);
             // SAFETY:
             // Caller should ensure address is allocated.
             // Be careful not to drop `e` here as that should be dropped by the ffi consumer
             unsafe { core::ptr::write(location.add(i), e) };
         }
+        Ok(())
     }
 
     /// Import [`Self`] via polars-ffi
@@ -593,13 +642,19 @@ impl PyDataFrame {
     /// [`location`] should be an address that contains [`width`] properly initialized
     /// [`SeriesExport`]s
     #[classmethod]
+    #[pyo3(signature = (location, width, ffi_version=None))]
     pub unsafe fn _import_columns(
         _cls: &Bound<PyType>,
         location: usize,
         width: usize,
+        ffi_version: Option<(u16, u16)>,
     ) -> PyResult<Self> {
         use polars_ffi::version_0::import_df;
 
+        if let Some((major, minor)) = ffi_version {
+            polars_ffi::negotiate(major, minor).map_err(PyPolarsErr::from)?;
+        }
+
         let location = location as *mut SeriesExport;
 
         let df = unsafe { import_df(location, width) }.map_err(PyPolarsErr::from)?;
@@ -631,4 +686,21 @@ impl PyDataFrame {
             Ok(ca)
         })
     }
+
+    #[pyo3(signature = (descending, nulls_last))]
+    fn row_encode(
+        &self,
+        py: Python<'_>,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    ) -> PyResult<PySeries> {
+        py.enter_polars_series(|| {
+            encode_rows_ordered(
+                PlSmallStr::from_static("row_encoded"),
+                self.df.read().columns(),
+                &descending,
+                &nulls_last,
+            )
+        })
+    }
 }
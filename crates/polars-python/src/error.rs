@@ -14,9 +14,9 @@ use pyo3::prelude::*;
 use crate::Wrap;
 use crate::exceptions::{
     CategoricalRemappingWarning, ColumnNotFoundError, ComputeError, DuplicateError,
-    InvalidOperationError, MapWithoutReturnDtypeWarning, NoDataError, OutOfBoundsError,
-    SQLInterfaceError, SQLSyntaxError, SchemaError, SchemaFieldNotFoundError, ShapeError,
-    StringCacheMismatchError, StructFieldNotFoundError,
+    InvalidOperationError, LossyCastWarning, MapWithoutReturnDtypeWarning, NoDataError,
+    OutOfBoundsError, SQLInterfaceError, SQLSyntaxError, SchemaError, SchemaFieldNotFoundError,
+    ShapeError, StringCacheMismatchError, StructFieldNotFoundError,
 };
 
 pub enum PyPolarsErr {
@@ -152,6 +152,7 @@ impl<'py> IntoPyObject<'py> for Wrap<PolarsWarning> {
             PolarsWarning::MapWithoutReturnDtypeWarning => {
                 Ok(MapWithoutReturnDtypeWarning::type_object(py).into_any())
             },
+            PolarsWarning::LossyCastWarning => Ok(LossyCastWarning::type_object(py).into_any()),
             PolarsWarning::UserWarning => Ok(PyUserWarning::type_object(py).into_any()),
             PolarsWarning::Deprecation => Ok(PyDeprecationWarning::type_object(py).into_any()),
         }
@@ -32,3 +32,4 @@ create_exception!(
     MapWithoutReturnDtypeWarning,
     PolarsWarning
 );
+create_exception!(polars.exceptions, LossyCastWarning, PolarsWarning);
@@ -118,6 +118,25 @@ impl PyExpr {
         self.inner.clone().arr().count_matches(expr.inner).into()
     }
 
+    #[cfg(feature = "array_linalg")]
+    fn arr_dot(&self, other: PyExpr) -> Self {
+        self.inner.clone().arr().dot(other.inner).into()
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn arr_l2_norm(&self) -> Self {
+        self.inner.clone().arr().l2_norm().into()
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn arr_cosine_similarity(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .cosine_similarity(other.inner)
+            .into()
+    }
+
     #[pyo3(signature = (name_gen))]
     fn arr_to_struct(&self, name_gen: Option<Py<PyAny>>) -> Self {
         let name_gen = name_gen.map(|o| PlanCallback::new_python(PythonObject(o)));
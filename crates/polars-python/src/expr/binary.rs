@@ -1,7 +1,9 @@
+use polars::prelude::Base64Alphabet;
 use pyo3::prelude::*;
 
 use super::datatype::PyDataTypeExpr;
 use crate::PyExpr;
+use crate::conversion::Wrap;
 
 #[pymethods]
 impl PyExpr {
@@ -27,8 +29,12 @@ impl PyExpr {
     }
 
     #[cfg(feature = "binary_encoding")]
-    fn bin_base64_decode(&self, strict: bool) -> Self {
-        self.inner.clone().binary().base64_decode(strict).into()
+    fn bin_base64_decode(&self, strict: bool, alphabet: Wrap<Base64Alphabet>) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .base64_decode_with_options(alphabet.0, strict)
+            .into()
     }
 
     #[cfg(feature = "binary_encoding")]
@@ -37,8 +43,12 @@ impl PyExpr {
     }
 
     #[cfg(feature = "binary_encoding")]
-    fn bin_base64_encode(&self) -> Self {
-        self.inner.clone().binary().base64_encode().into()
+    fn bin_base64_encode(&self, alphabet: Wrap<Base64Alphabet>, padding: bool) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .base64_encode_with_options(alphabet.0, padding)
+            .into()
     }
 
     #[cfg(feature = "binary_encoding")]
@@ -89,4 +99,8 @@ impl PyExpr {
             .get(index.inner, null_on_oob)
             .into()
     }
+
+    fn bin_to_uuid_str(&self) -> Self {
+        self.inner.clone().binary().to_uuid_str().into()
+    }
 }
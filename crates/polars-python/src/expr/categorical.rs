@@ -1,3 +1,4 @@
+use polars_utils::pl_str::PlSmallStr;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
@@ -8,6 +9,19 @@ impl PyExpr {
         self.inner.clone().cat().get_categories().into()
     }
 
+    fn cat_add_categories(&self, categories: Vec<String>) -> Self {
+        let categories = categories.into_iter().map(PlSmallStr::from).collect();
+        self.inner.clone().cat().add_categories(categories).into()
+    }
+
+    fn cat_union_categories(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .cat()
+            .union_categories(other.inner)
+            .into()
+    }
+
     fn cat_len_bytes(&self) -> Self {
         self.inner.clone().cat().len_bytes().into()
     }
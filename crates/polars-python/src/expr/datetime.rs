@@ -158,14 +158,24 @@ impl PyExpr {
     fn dt_quarter(&self) -> Self {
         self.inner.clone().dt().quarter().into()
     }
+    fn dt_fiscal_year(&self, start_month: i8) -> Self {
+        self.inner.clone().dt().fiscal_year(start_month).into()
+    }
+    fn dt_fiscal_quarter(&self, start_month: i8) -> Self {
+        self.inner.clone().dt().fiscal_quarter(start_month).into()
+    }
     fn dt_month(&self) -> Self {
         self.inner.clone().dt().month().into()
     }
     fn dt_days_in_month(&self) -> Self {
         self.inner.clone().dt().days_in_month().into()
     }
-    fn dt_week(&self) -> Self {
-        self.inner.clone().dt().week().into()
+    fn dt_week(&self, convention: Wrap<WeekConvention>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .week_with_convention(convention.0)
+            .into()
     }
     fn dt_weekday(&self) -> Self {
         self.inner.clone().dt().weekday().into()
@@ -190,8 +190,20 @@ impl PyExpr {
             .quantile(quantile.inner, interpolation.0)
             .into()
     }
+    fn weighted_mean(&self, weights: Self) -> Self {
+        self.inner.clone().weighted_mean(weights.inner).into()
+    }
+    fn weighted_var(&self, weights: Self, ddof: u8) -> Self {
+        self.inner.clone().weighted_var(weights.inner, ddof).into()
+    }
+    fn weighted_quantile(&self, weights: Self, quantile: Self) -> Self {
+        self.inner
+            .clone()
+            .weighted_quantile(weights.inner, quantile.inner)
+            .into()
+    }
 
-    #[pyo3(signature = (breaks, labels, left_closed, include_breaks))]
+    #[pyo3(signature = (breaks, labels, left_closed, include_breaks, include_bins))]
     #[cfg(feature = "cutqcut")]
     fn cut(
         &self,
@@ -199,13 +211,16 @@ impl PyExpr {
         labels: Option<Vec<String>>,
         left_closed: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> Self {
         self.inner
             .clone()
-            .cut(breaks, labels, left_closed, include_breaks)
+            .cut(breaks, labels, left_closed, include_breaks, include_bins)
             .into()
     }
-    #[pyo3(signature = (probs, labels, left_closed, allow_duplicates, include_breaks))]
+    #[pyo3(signature = (
+        probs, labels, left_closed, allow_duplicates, include_breaks, include_bins
+    ))]
     #[cfg(feature = "cutqcut")]
     fn qcut(
         &self,
@@ -214,13 +229,23 @@ impl PyExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> Self {
         self.inner
             .clone()
-            .qcut(probs, labels, left_closed, allow_duplicates, include_breaks)
+            .qcut(
+                probs,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                include_bins,
+            )
             .into()
     }
-    #[pyo3(signature = (n_bins, labels, left_closed, allow_duplicates, include_breaks))]
+    #[pyo3(signature = (
+        n_bins, labels, left_closed, allow_duplicates, include_breaks, include_bins
+    ))]
     #[cfg(feature = "cutqcut")]
     fn qcut_uniform(
         &self,
@@ -229,6 +254,7 @@ impl PyExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> Self {
         self.inner
             .clone()
@@ -238,6 +264,7 @@ impl PyExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                include_bins,
             )
             .into()
     }
@@ -454,8 +481,38 @@ impl PyExpr {
     }
 
     #[cfg(feature = "approx_unique")]
-    fn approx_n_unique(&self) -> Self {
-        self.inner.clone().approx_n_unique().into()
+    fn approx_n_unique(&self, precision: Option<u8>) -> Self {
+        match precision {
+            None => self.inner.clone().approx_n_unique().into(),
+            Some(precision) => self
+                .inner
+                .clone()
+                .approx_n_unique_with_precision(precision)
+                .into(),
+        }
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn hll_sketch(&self, precision: u8) -> Self {
+        self.inner.clone().hll_sketch(precision).into()
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn hll_merge(&self) -> Self {
+        self.inner.clone().hll_merge().into()
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn hll_estimate(&self) -> Self {
+        self.inner.clone().hll_estimate().into()
+    }
+
+    #[cfg(feature = "approx_quantile")]
+    fn approx_quantile(&self, quantile: f64, compression: f64) -> Self {
+        self.inner
+            .clone()
+            .approx_quantile(quantile, compression)
+            .into()
     }
 
     fn is_first_distinct(&self) -> Self {
@@ -496,6 +553,30 @@ impl PyExpr {
         self.inner.clone().round(decimals, mode.0).into()
     }
 
+    fn decimal_arithmetic(
+        &self,
+        other: Self,
+        op: Wrap<DecimalArithmeticOp>,
+        rounding: Wrap<RoundMode>,
+        overflow: Wrap<DecimalOverflow>,
+    ) -> Self {
+        let options = DecimalArithmeticOptions {
+            rounding: rounding.0,
+            overflow: overflow.0,
+        };
+        self.inner
+            .clone()
+            .decimal_arithmetic(other.inner, op.0, options)
+            .into()
+    }
+
+    fn checked_arithmetic(&self, other: Self, op: Wrap<CheckedArithmeticOp>, warn: bool) -> Self {
+        self.inner
+            .clone()
+            .checked_arithmetic(other.inner, op.0, warn)
+            .into()
+    }
+
     fn round_sig_figs(&self, digits: i32) -> Self {
         self.clone().inner.round_sig_figs(digits).into()
     }
@@ -708,6 +789,12 @@ impl PyExpr {
     fn cum_sum(&self, reverse: bool) -> Self {
         self.inner.clone().cum_sum(reverse).into()
     }
+    fn cum_agg_reset(&self, reset: Self, function: Wrap<ListCumFunction>, reverse: bool) -> Self {
+        self.inner
+            .clone()
+            .cum_agg_reset(reset.inner, function.0, reverse)
+            .into()
+    }
     fn cum_max(&self, reverse: bool) -> Self {
         self.inner.clone().cum_max(reverse).into()
     }
@@ -742,14 +829,26 @@ impl PyExpr {
             .reinterpret(signed, dtype.map(|dt| dt.0))
             .into()
     }
-    fn mode(&self, maintain_order: bool) -> Self {
-        self.inner.clone().mode(maintain_order).into()
+    fn mode(&self, maintain_order: bool, ties: Wrap<ModeTies>) -> Self {
+        self.inner.clone().mode(maintain_order, ties.0).into()
+    }
+    fn modes(&self, n: IdxSize) -> Self {
+        self.inner.clone().modes(n).into()
     }
     fn interpolate(&self, method: Wrap<InterpolationMethod>) -> Self {
         self.inner.clone().interpolate(method.0).into()
     }
-    fn interpolate_by(&self, by: PyExpr) -> Self {
-        self.inner.clone().interpolate_by(by.inner).into()
+    #[pyo3(signature = (by, method, max_gap))]
+    fn interpolate_by(
+        &self,
+        by: PyExpr,
+        method: Wrap<InterpolateByMethod>,
+        max_gap: Option<PyExpr>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .interpolate_by(by.inner, method.0, max_gap.map(|e| e.inner))
+            .into()
     }
 
     fn lower_bound(&self) -> Self {
@@ -906,6 +1005,27 @@ impl PyExpr {
     fn hash(&self, seed: u64, seed_1: u64, seed_2: u64, seed_3: u64) -> Self {
         self.inner.clone().hash(seed, seed_1, seed_2, seed_3).into()
     }
+    fn stable_hash(&self, seed: u64) -> Self {
+        self.inner.clone().stable_hash(seed).into()
+    }
+    fn hash_sha256(&self, hex: bool) -> Self {
+        self.inner
+            .clone()
+            .hash_digest(HashAlgorithm::Sha256, hex)
+            .into()
+    }
+    fn hash_md5(&self, hex: bool) -> Self {
+        self.inner
+            .clone()
+            .hash_digest(HashAlgorithm::Md5, hex)
+            .into()
+    }
+    fn hash_xxh3(&self, seed: u64, hex: bool) -> Self {
+        self.inner
+            .clone()
+            .hash_digest(HashAlgorithm::Xxh3 { seed }, hex)
+            .into()
+    }
     fn set_sorted_flag(&self, descending: bool, nulls_last: bool) -> Self {
         let sortedness = AExprSorted::default()
             .with_desc(Some(descending))
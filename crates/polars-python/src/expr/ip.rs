@@ -0,0 +1,21 @@
+use polars::prelude::*;
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+use crate::error::PyPolarsErr;
+
+#[pymethods]
+impl PyExpr {
+    fn ip_is_in_subnet(&self, subnets: Vec<String>) -> PyResult<Self> {
+        let subnets = subnets
+            .iter()
+            .map(|s| s.parse::<IpSubnet>())
+            .collect::<PolarsResult<Arc<[_]>>>()
+            .map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().binary().ip_is_in_subnet(subnets).into())
+    }
+
+    fn ip_to_int(&self) -> Self {
+        self.inner.clone().binary().ip_to_int().into()
+    }
+}
@@ -36,6 +36,11 @@ impl PyExpr {
             .into()
     }
 
+    #[cfg(feature = "index_of")]
+    fn list_index_of(&self, value: PyExpr) -> Self {
+        self.inner.clone().list().index_of(value.inner).into()
+    }
+
     #[cfg(feature = "list_count")]
     fn list_count_matches(&self, expr: PyExpr) -> Self {
         self.inner.clone().list().count_matches(expr.inner).into()
@@ -45,6 +50,141 @@ impl PyExpr {
         Ok(self.inner.clone().list().diff(n, null_behavior.0).into())
     }
 
+    fn list_cum_sum(&self, reverse: bool) -> Self {
+        self.inner.clone().list().cum_sum(reverse).into()
+    }
+
+    fn list_cum_prod(&self, reverse: bool) -> Self {
+        self.inner.clone().list().cum_prod(reverse).into()
+    }
+
+    fn list_cum_min(&self, reverse: bool) -> Self {
+        self.inner.clone().list().cum_min(reverse).into()
+    }
+
+    fn list_cum_max(&self, reverse: bool) -> Self {
+        self.inner.clone().list().cum_max(reverse).into()
+    }
+
+    fn list_cum_count(&self, reverse: bool) -> Self {
+        self.inner.clone().list().cum_count(reverse).into()
+    }
+
+    #[pyo3(signature = (window_size, min_periods=None, center=false))]
+    fn list_rolling_min(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling(ListRollingFunction::Min, window_size, min_periods, center)
+            .into()
+    }
+
+    #[pyo3(signature = (window_size, min_periods=None, center=false))]
+    fn list_rolling_max(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling(ListRollingFunction::Max, window_size, min_periods, center)
+            .into()
+    }
+
+    #[pyo3(signature = (window_size, min_periods=None, center=false))]
+    fn list_rolling_mean(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling(ListRollingFunction::Mean, window_size, min_periods, center)
+            .into()
+    }
+
+    #[pyo3(signature = (window_size, min_periods=None, center=false))]
+    fn list_rolling_sum(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling(ListRollingFunction::Sum, window_size, min_periods, center)
+            .into()
+    }
+
+    #[pyo3(signature = (window_size, min_periods=None, center=false))]
+    fn list_rolling_median(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling(
+                ListRollingFunction::Median,
+                window_size,
+                min_periods,
+                center,
+            )
+            .into()
+    }
+
+    #[pyo3(signature = (window_size, min_periods=None, center=false, ddof=1))]
+    fn list_rolling_std(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+        ddof: u8,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling(
+                ListRollingFunction::Std(ddof),
+                window_size,
+                min_periods,
+                center,
+            )
+            .into()
+    }
+
+    #[pyo3(signature = (window_size, min_periods=None, center=false, ddof=1))]
+    fn list_rolling_var(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+        ddof: u8,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .rolling(
+                ListRollingFunction::Var(ddof),
+                window_size,
+                min_periods,
+                center,
+            )
+            .into()
+    }
+
     fn list_eval(&self, expr: PyExpr, _parallel: bool) -> Self {
         self.inner.clone().list().eval(expr.inner).into()
     }
@@ -62,6 +202,11 @@ impl PyExpr {
             .into()
     }
 
+    #[cfg(feature = "index_of")]
+    fn list_find(&self, predicate: PyExpr) -> Self {
+        self.inner.clone().list().find(predicate.inner).into()
+    }
+
     fn list_get(&self, index: PyExpr, null_on_oob: bool) -> Self {
         self.inner
             .clone()
@@ -232,13 +377,24 @@ impl PyExpr {
     }
 
     #[cfg(feature = "list_sets")]
-    fn list_set_operation(&self, other: PyExpr, operation: Wrap<SetOperation>) -> Self {
+    fn list_set_operation(
+        &self,
+        other: PyExpr,
+        operation: Wrap<SetOperation>,
+        multiset: bool,
+    ) -> Self {
         let e = self.inner.clone().list();
-        match operation.0 {
-            SetOperation::Intersection => e.set_intersection(other.inner),
-            SetOperation::Difference => e.set_difference(other.inner),
-            SetOperation::Union => e.union(other.inner),
-            SetOperation::SymmetricDifference => e.set_symmetric_difference(other.inner),
+        match (operation.0, multiset) {
+            (SetOperation::Intersection, false) => e.set_intersection(other.inner),
+            (SetOperation::Difference, false) => e.set_difference(other.inner),
+            (SetOperation::Union, false) => e.union(other.inner),
+            (SetOperation::SymmetricDifference, false) => e.set_symmetric_difference(other.inner),
+            (SetOperation::Intersection, true) => e.multiset_intersection(other.inner),
+            (SetOperation::Difference, true) => e.multiset_difference(other.inner),
+            (SetOperation::Union, true) => e.multiset_union(other.inner),
+            (SetOperation::SymmetricDifference, true) => {
+                e.multiset_symmetric_difference(other.inner)
+            },
         }
         .into()
     }
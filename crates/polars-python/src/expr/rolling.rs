@@ -410,7 +410,7 @@ impl PyExpr {
         self.inner.clone().rolling_kurtosis(options).into()
     }
 
-    #[pyo3(signature = (lambda, window_size, weights, min_periods, center))]
+    #[pyo3(signature = (lambda, window_size, weights, min_periods, center, batch_size=None))]
     fn rolling_map(
         &self,
         lambda: Py<PyAny>,
@@ -418,6 +418,7 @@ impl PyExpr {
         weights: Option<Vec<f64>>,
         min_periods: Option<usize>,
         center: bool,
+        batch_size: Option<usize>,
     ) -> Self {
         let min_periods = min_periods.unwrap_or(window_size);
         let options = RollingOptionsFixedWindow {
@@ -429,6 +430,13 @@ impl PyExpr {
         };
         let function = PlanCallback::new_python(PythonObject(lambda));
 
-        self.inner.clone().rolling_map(function, options).into()
+        match batch_size {
+            Some(batch_size) => self
+                .inner
+                .clone()
+                .rolling_map_batches(function, options, batch_size)
+                .into(),
+            None => self.inner.clone().rolling_map(function, options).into(),
+        }
     }
 }
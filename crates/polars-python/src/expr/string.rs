@@ -1,4 +1,5 @@
 use polars::prelude::*;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use super::datatype::PyDataTypeExpr;
@@ -61,6 +62,39 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (formats, time_unit, time_zone, strict, exact, cache, best_effort, include_format, ambiguous))]
+    fn str_to_datetime_multi(
+        &self,
+        formats: Vec<String>,
+        time_unit: Option<Wrap<TimeUnit>>,
+        time_zone: Wrap<Option<TimeZone>>,
+        strict: bool,
+        exact: bool,
+        cache: bool,
+        best_effort: bool,
+        include_format: bool,
+        ambiguous: Self,
+    ) -> Self {
+        let options = StrptimeMultiOptions {
+            formats: formats.into_iter().map(PlSmallStr::from).collect(),
+            best_effort,
+            include_format,
+            strict,
+            exact,
+            cache,
+        };
+        self.inner
+            .clone()
+            .str()
+            .to_datetime_multi(
+                time_unit.map(|tu| tu.0),
+                time_zone.0,
+                options,
+                ambiguous.inner,
+            )
+            .into()
+    }
+
     #[pyo3(signature = (format, strict, cache))]
     fn str_to_time(&self, format: Option<String>, strict: bool, cache: bool) -> Self {
         let format = format.map(|x| x.into());
@@ -126,6 +160,10 @@ impl PyExpr {
         self.inner.clone().str().to_lowercase().into()
     }
 
+    fn str_casefold(&self) -> Self {
+        self.inner.clone().str().casefold().into()
+    }
+
     #[cfg(feature = "nightly")]
     fn str_to_titlecase(&self) -> Self {
         self.inner.clone().str().to_titlecase().into()
@@ -165,6 +203,73 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_levenshtein_distance(&self, other: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .levenshtein_distance(other.inner)
+            .into()
+    }
+
+    fn str_hamming(&self, other: Self) -> Self {
+        self.inner.clone().str().hamming(other.inner).into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
+    #[pyo3(signature = (method, pattern, lowercase, stop_words))]
+    fn str_tokenize(
+        &self,
+        method: &str,
+        pattern: Option<&str>,
+        lowercase: bool,
+        stop_words: Vec<String>,
+    ) -> PyResult<Self> {
+        let method = match (method, pattern) {
+            ("unicode_words", None) => TokenizeMethod::UnicodeWords,
+            ("whitespace", None) => TokenizeMethod::Whitespace,
+            ("pattern", Some(pattern)) => TokenizeMethod::Pattern(pattern.into()),
+            ("pattern", None) => {
+                return Err(PyValueError::new_err(
+                    "`pattern` must be given if `method` is 'pattern'",
+                ));
+            },
+            ("unicode_words" | "whitespace", Some(_)) => {
+                return Err(PyValueError::new_err(format!(
+                    "`pattern` can only be given if `method` is 'pattern', got {method}",
+                )));
+            },
+            (v, _) => {
+                return Err(PyValueError::new_err(format!(
+                    "`method` must be one of {{'unicode_words', 'whitespace', 'pattern'}}, got {v}",
+                )));
+            },
+        };
+        let stop_words = stop_words.into_iter().map(PlSmallStr::from).collect();
+        Ok(self
+            .inner
+            .clone()
+            .str()
+            .tokenize(method, lowercase, stop_words)
+            .into())
+    }
+
+    fn str_url_part(&self, part: Wrap<UrlPart>) -> Self {
+        self.inner.clone().str().url_part(part.0).into()
+    }
+
+    #[pyo3(signature = (keys=None))]
+    fn str_url_query_params(&self, keys: Option<Vec<String>>) -> Self {
+        let keys = keys.map(|keys| keys.into_iter().map(PlSmallStr::from).collect());
+        self.inner.clone().str().url_query_params(keys).into()
+    }
+
+    fn str_url_decode_percent(&self) -> Self {
+        self.inner.clone().str().url_decode_percent().into()
+    }
+
     fn str_pad_start(&self, length: PyExpr, fill_char: char) -> Self {
         self.inner
             .clone()
@@ -220,13 +325,29 @@ impl PyExpr {
         self.inner.clone().str().hex_decode(strict).into()
     }
 
-    fn str_base64_encode(&self) -> Self {
-        self.inner.clone().str().base64_encode().into()
+    fn str_base64_encode(&self, alphabet: Wrap<Base64Alphabet>, padding: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .base64_encode_with_options(alphabet.0, padding)
+            .into()
     }
 
     #[cfg(feature = "binary_encoding")]
-    fn str_base64_decode(&self, strict: bool) -> Self {
-        self.inner.clone().str().base64_decode(strict).into()
+    fn str_base64_decode(&self, strict: bool, alphabet: Wrap<Base64Alphabet>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .base64_decode_with_options(alphabet.0, strict)
+            .into()
+    }
+
+    fn str_parse_ip(&self, strict: bool) -> Self {
+        self.inner.clone().str().parse_ip(strict).into()
+    }
+
+    fn str_parse_uuid(&self, strict: bool) -> Self {
+        self.inner.clone().str().parse_uuid(strict).into()
     }
 
     #[pyo3(signature = (base, dtype=Some(Wrap(DataType::Int64)), strict=true))]
@@ -248,6 +369,26 @@ impl PyExpr {
         self.inner.clone().str().json_path_match(pat.inner).into()
     }
 
+    #[cfg(feature = "extract_jsonpath")]
+    #[pyo3(signature = (pat, dtype))]
+    fn str_json_path_match_all(&self, pat: Self, dtype: Option<PyDataTypeExpr>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .json_path_match_all(pat.inner, dtype.map(|dt| dt.inner))
+            .into()
+    }
+
+    #[cfg(feature = "extract_jsonpath")]
+    #[pyo3(signature = (paths, dtype))]
+    fn str_json_extract_many(&self, paths: Vec<String>, dtype: Option<PyDataTypeExpr>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .json_extract_many(paths, dtype.map(|dt| dt.inner))
+            .into()
+    }
+
     fn str_extract(&self, pat: Self, group_index: usize) -> Self {
         self.inner
             .clone()
@@ -331,6 +472,16 @@ impl PyExpr {
             .into()
     }
 
+    #[cfg(feature = "regex")]
+    #[pyo3(signature = (by, n, inclusive, strict))]
+    fn str_splitn_regex(&self, by: Self, n: usize, inclusive: bool, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .splitn_regex(by.inner, n, inclusive, strict)
+            .into()
+    }
+
     fn str_to_decimal(&self, scale: usize) -> Self {
         self.inner.clone().str().to_decimal(scale).into()
     }
@@ -83,6 +83,25 @@ pub fn arg_sort_by(
     )
     .into()
 }
+#[pyfunction]
+#[cfg(feature = "search_sorted")]
+pub fn search_sorted_by(
+    by: Vec<PyExpr>,
+    search_values: Vec<PyExpr>,
+    side: Wrap<SearchSortedSide>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+) -> PyExpr {
+    dsl::search_sorted_by(
+        by.to_exprs(),
+        search_values.to_exprs(),
+        side.0,
+        descending,
+        nulls_last,
+    )
+    .into()
+}
+
 #[pyfunction]
 pub fn arg_where(condition: PyExpr) -> PyExpr {
     dsl::arg_where(condition.inner).into()
@@ -538,6 +557,27 @@ pub fn map_expr(
     map::lazy::map_expr(&pyexpr, lambda, output_type, is_elementwise, returns_scalar)
 }
 
+#[pyfunction]
+#[pyo3(signature = (pyexpr, init, update, merge, finalize, return_dtype, name))]
+pub fn python_aggregation(
+    pyexpr: PyExpr,
+    init: Py<PyAny>,
+    update: Py<PyAny>,
+    merge: Py<PyAny>,
+    finalize: Py<PyAny>,
+    return_dtype: Wrap<DataType>,
+    name: String,
+) -> PyExpr {
+    let agg =
+        map::aggregation::PythonAggregation::new(init, update, merge, finalize, return_dtype.0);
+    Expr::AnonymousAgg {
+        input: vec![pyexpr.inner],
+        function: LazySerde::Deserialized(SpecialEq::new(Arc::new(agg) as Arc<dyn AnonymousAgg>)),
+        fmt_str: Box::new(name.into()),
+    }
+    .into()
+}
+
 #[pyfunction]
 pub fn pearson_corr(a: PyExpr, b: PyExpr) -> PyExpr {
     dsl::pearson_corr(a.inner, b.inner).into()
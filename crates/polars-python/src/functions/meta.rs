@@ -1,5 +1,7 @@
 use polars_core::POOL;
+use polars_core::chunked_array::ops::row_encode::ROW_ENCODING_VERSION;
 use polars_core::fmt::FloatFmt;
+use polars_core::hashing::stable::STABLE_HASH_VERSION;
 use polars_core::prelude::IDX_DTYPE;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -16,6 +18,16 @@ pub fn thread_pool_size() -> usize {
     POOL.current_num_threads()
 }
 
+#[pyfunction]
+pub fn row_encoding_version() -> u32 {
+    ROW_ENCODING_VERSION
+}
+
+#[pyfunction]
+pub fn stable_hash_version() -> u32 {
+    STABLE_HASH_VERSION
+}
+
 #[pyfunction]
 pub fn set_float_fmt(fmt: &str) -> PyResult<()> {
     let fmt = match fmt {
@@ -1,4 +1,5 @@
 use polars_plan::prelude::*;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 
@@ -71,6 +72,68 @@ pub fn __register_startup_deps() {
     }
 }
 
+#[pyfunction]
+#[pyo3(signature = (dumps, loads))]
+pub fn register_udf_serializer(dumps: Option<Py<PyAny>>, loads: Option<Py<PyAny>>) -> PyResult<()> {
+    use polars_utils::pl_serialize::{PythonObjectSerializer, set_python_object_serializer};
+
+    let serializer = match (dumps, loads) {
+        (Some(dumps), Some(loads)) => Some(PythonObjectSerializer { dumps, loads }),
+        (None, None) => None,
+        _ => {
+            return Err(PyValueError::new_err(
+                "'dumps' and 'loads' must either both be set or both be None",
+            ));
+        },
+    };
+    set_python_object_serializer(serializer);
+    Ok(())
+}
+
+#[cfg(feature = "new_streaming")]
+#[pyfunction]
+#[pyo3(signature = (callback))]
+pub fn set_streaming_metrics_callback(callback: Option<Py<PyAny>>) {
+    use std::sync::Arc;
+
+    use polars_lazy::frame::{QueryMetrics, set_query_metrics_callback};
+    use pyo3::types::PyDict;
+
+    let callback = callback.map(|callback| {
+        Arc::new(move |metrics: &QueryMetrics| {
+            Python::attach(|py| {
+                let result = (|| {
+                    let nodes = metrics
+                        .nodes
+                        .iter()
+                        .map(|(name, m)| {
+                            let node = PyDict::new(py);
+                            node.set_item("name", name)?;
+                            node.set_item("rows_received", m.rows_received)?;
+                            node.set_item("morsels_received", m.morsels_received)?;
+                            node.set_item("rows_sent", m.rows_sent)?;
+                            node.set_item("morsels_sent", m.morsels_sent)?;
+                            node.set_item("poll_time_ns", m.total_poll_time_ns)?;
+                            node.set_item("state_update_time_ns", m.total_state_update_time_ns)?;
+                            node.set_item("io_active_time_ns", m.io_total_active_ns)?;
+                            node.set_item("io_bytes_received", m.io_total_bytes_received)?;
+                            node.set_item("io_bytes_sent", m.io_total_bytes_sent)?;
+                            Ok(node)
+                        })
+                        .collect::<PyResult<Vec<_>>>()?;
+                    callback.call1(py, (metrics.elapsed.as_nanos() as u64, nodes))?;
+                    PyResult::Ok(())
+                })();
+                // The query itself succeeded, so don't fail it on a faulty callback.
+                if let Err(err) = result {
+                    err.write_unraisable(py, None);
+                }
+            })
+        }) as _
+    });
+    set_query_metrics_callback(callback);
+}
+
 #[pyfunction]
 pub fn gen_uuid_v7(py: Python) -> Py<PyBytes> {
     PyBytes::new(py, uuid::Uuid::now_v7().as_bytes()).unbind()
@@ -1,7 +1,15 @@
+use polars::lazy::dsl;
 use pyo3::prelude::*;
 
+use crate::PyExpr;
+
 #[pyfunction]
 pub fn set_random_seed(seed: u64) -> PyResult<()> {
     polars_core::random::set_global_random_seed(seed);
     Ok(())
 }
+
+#[pyfunction]
+pub fn uuid4(seed: Option<u64>) -> PyExpr {
+    dsl::uuid4(seed).into()
+}
@@ -1,5 +1,8 @@
+use polars_dtype::categorical::CategoriesScope;
 use pyo3::prelude::*;
 
+use crate::conversion::PyCategories;
+
 #[pyfunction]
 pub fn enable_string_cache() {
     // The string cache no longer exists.
@@ -16,13 +19,19 @@ pub fn using_string_cache() -> bool {
     true
 }
 
-#[pyclass(frozen)]
-pub struct PyStringCacheHolder;
+/// Keeps the given categories entered as the default categories on this thread while alive.
+#[pyclass(frozen, unsendable)]
+pub struct PyStringCacheHolder {
+    _scope: Option<CategoriesScope>,
+}
 
 #[pymethods]
 impl PyStringCacheHolder {
     #[new]
-    fn new() -> Self {
-        Self
+    #[pyo3(signature = (categories=None))]
+    fn new(categories: Option<PyCategories>) -> Self {
+        Self {
+            _scope: categories.map(|c| c.categories().enter_scope()),
+        }
     }
 }
@@ -81,6 +81,23 @@ pub(crate) fn series_to_stream<'py>(
     PyCapsule::new(py, stream, Some(stream_capsule_name))
 }
 
+/// Export an array to a pair of schema and array PyCapsules according to the Arrow PyCapsule
+/// Interface.
+pub(crate) fn array_to_capsules<'py>(
+    array: ArrayRef,
+    field: &ArrowField,
+    py: Python<'py>,
+) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+    let schema = ffi::export_field_to_c(field);
+    let array = ffi::export_array_to_c(array);
+    let schema_capsule_name = CString::new("arrow_schema").unwrap();
+    let array_capsule_name = CString::new("arrow_array").unwrap();
+    Ok((
+        PyCapsule::new(py, schema, Some(schema_capsule_name))?,
+        PyCapsule::new(py, array, Some(array_capsule_name))?,
+    ))
+}
+
 pub(crate) fn dataframe_to_stream<'py>(
     df: &DataFrame,
     py: Python<'py>,
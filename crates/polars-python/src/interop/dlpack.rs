@@ -0,0 +1,357 @@
+//! Export of numeric data through the [DLPack] protocol.
+//!
+//! Consumers that request DLPack 1.0 or later through `max_version` receive a versioned capsule,
+//! which marks shared data as read-only. Older consumers receive a legacy capsule.
+//!
+//! [DLPack]: https://dmlc.github.io/dlpack/latest/python_spec.html
+use std::any::Any;
+use std::ffi::{CStr, c_void};
+
+use polars::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+use pyo3::exceptions::{PyBufferError, PyTypeError};
+use pyo3::ffi;
+use pyo3::prelude::*;
+
+use super::numpy::to_numpy_df::check_df_columns_contiguous;
+use crate::dataframe::PyDataFrame;
+use crate::error::PyPolarsErr;
+use crate::series::PySeries;
+
+const DLTENSOR: &CStr = c"dltensor";
+const DLTENSOR_VERSIONED: &CStr = c"dltensor_versioned";
+const DL_CPU: i32 = 1;
+
+/// The version of the versioned capsules, consumers must support at least its major version.
+const DLPACK_VERSION: DLPackVersion = DLPackVersion { major: 1, minor: 0 };
+const DLPACK_FLAG_BITMASK_READ_ONLY: u64 = 1 << 0;
+const DLPACK_FLAG_BITMASK_IS_COPIED: u64 = 1 << 1;
+
+#[repr(C)]
+struct DLPackVersion {
+    major: u32,
+    minor: u32,
+}
+
+#[repr(C)]
+struct DLDevice {
+    device_type: i32,
+    device_id: i32,
+}
+
+#[repr(C)]
+struct DLDataType {
+    code: u8,
+    bits: u8,
+    lanes: u16,
+}
+
+#[repr(C)]
+struct DLTensor {
+    data: *mut c_void,
+    device: DLDevice,
+    ndim: i32,
+    dtype: DLDataType,
+    shape: *mut i64,
+    strides: *mut i64,
+    byte_offset: u64,
+}
+
+#[repr(C)]
+struct DLManagedTensor {
+    dl_tensor: DLTensor,
+    manager_ctx: *mut c_void,
+    deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+#[repr(C)]
+struct DLManagedTensorVersioned {
+    version: DLPackVersion,
+    manager_ctx: *mut c_void,
+    deleter: Option<unsafe extern "C" fn(*mut DLManagedTensorVersioned)>,
+    flags: u64,
+    dl_tensor: DLTensor,
+}
+
+/// Keeps the exported memory alive until the consumer is done with it.
+struct TensorContext {
+    _owner: Box<dyn Any + Send>,
+    shape: Vec<i64>,
+    strides: Vec<i64>,
+}
+
+unsafe extern "C" fn delete_tensor(tensor: *mut DLManagedTensor) {
+    let tensor = unsafe { Box::from_raw(tensor) };
+    drop(unsafe { Box::from_raw(tensor.manager_ctx as *mut TensorContext) });
+}
+
+unsafe extern "C" fn delete_versioned_tensor(tensor: *mut DLManagedTensorVersioned) {
+    let tensor = unsafe { Box::from_raw(tensor) };
+    drop(unsafe { Box::from_raw(tensor.manager_ctx as *mut TensorContext) });
+}
+
+/// Deletes the tensor if the capsule was never consumed, consumers rename the capsule.
+unsafe extern "C" fn delete_capsule(capsule: *mut ffi::PyObject) {
+    unsafe {
+        if ffi::PyCapsule_IsValid(capsule, DLTENSOR.as_ptr()) == 1 {
+            let tensor = ffi::PyCapsule_GetPointer(capsule, DLTENSOR.as_ptr());
+            delete_tensor(tensor as *mut DLManagedTensor);
+        }
+    }
+}
+
+unsafe extern "C" fn delete_versioned_capsule(capsule: *mut ffi::PyObject) {
+    unsafe {
+        if ffi::PyCapsule_IsValid(capsule, DLTENSOR_VERSIONED.as_ptr()) == 1 {
+            let tensor = ffi::PyCapsule_GetPointer(capsule, DLTENSOR_VERSIONED.as_ptr());
+            delete_versioned_tensor(tensor as *mut DLManagedTensorVersioned);
+        }
+    }
+}
+
+fn dl_dtype(dtype: &DataType) -> PyResult<DLDataType> {
+    use DataType::*;
+    let (code, bits) = match dtype {
+        Int8 => (0, 8),
+        Int16 => (0, 16),
+        Int32 => (0, 32),
+        Int64 => (0, 64),
+        UInt8 => (1, 8),
+        UInt16 => (1, 16),
+        UInt32 => (1, 32),
+        UInt64 => (1, 64),
+        Float16 => (2, 16),
+        Float32 => (2, 32),
+        Float64 => (2, 64),
+        dt => {
+            return Err(PyTypeError::new_err(format!(
+                "cannot export data type {dt} through DLPack, only integer and float data types are supported"
+            )));
+        },
+    };
+    Ok(DLDataType {
+        code,
+        bits,
+        lanes: 1,
+    })
+}
+
+fn check_device(dl_device: Option<(i32, i32)>) -> PyResult<()> {
+    match dl_device {
+        None | Some((DL_CPU, 0)) => Ok(()),
+        Some(device) => Err(PyBufferError::new_err(format!(
+            "cannot export to device {device:?}, only the CPU is supported"
+        ))),
+    }
+}
+
+/// Whether the consumer supports versioned capsules, see [`DLPACK_VERSION`].
+fn is_versioned(max_version: Option<(u32, u32)>) -> bool {
+    matches!(max_version, Some((major, _)) if major >= DLPACK_VERSION.major)
+}
+
+/// Export a contiguous block of memory as a DLPack capsule.
+///
+/// A versioned capsule is exported if the consumer supports it. Its data is marked as read-only
+/// unless it was `copied` for this export.
+#[allow(clippy::too_many_arguments)]
+fn to_capsule<'py>(
+    py: Python<'py>,
+    data: *const c_void,
+    dtype: DLDataType,
+    shape: Vec<i64>,
+    strides: Vec<i64>,
+    owner: Box<dyn Any + Send>,
+    max_version: Option<(u32, u32)>,
+    copied: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let mut ctx = Box::new(TensorContext {
+        _owner: owner,
+        shape,
+        strides,
+    });
+    let dl_tensor = DLTensor {
+        data: data as *mut c_void,
+        device: DLDevice {
+            device_type: DL_CPU,
+            device_id: 0,
+        },
+        ndim: ctx.shape.len() as i32,
+        dtype,
+        shape: ctx.shape.as_mut_ptr(),
+        strides: ctx.strides.as_mut_ptr(),
+        byte_offset: 0,
+    };
+    let manager_ctx = Box::into_raw(ctx) as *mut c_void;
+
+    if is_versioned(max_version) {
+        let flags = if copied {
+            DLPACK_FLAG_BITMASK_IS_COPIED
+        } else {
+            DLPACK_FLAG_BITMASK_READ_ONLY
+        };
+        let tensor = Box::new(DLManagedTensorVersioned {
+            version: DLPACK_VERSION,
+            manager_ctx,
+            deleter: Some(delete_versioned_tensor),
+            flags,
+            dl_tensor,
+        });
+
+        unsafe {
+            let tensor = Box::into_raw(tensor);
+            let capsule = ffi::PyCapsule_New(
+                tensor as *mut c_void,
+                DLTENSOR_VERSIONED.as_ptr(),
+                Some(delete_versioned_capsule),
+            );
+            if capsule.is_null() {
+                delete_versioned_tensor(tensor);
+            }
+            Bound::from_owned_ptr_or_err(py, capsule)
+        }
+    } else {
+        let tensor = Box::new(DLManagedTensor {
+            dl_tensor,
+            manager_ctx,
+            deleter: Some(delete_tensor),
+        });
+
+        unsafe {
+            let tensor = Box::into_raw(tensor);
+            let capsule = ffi::PyCapsule_New(
+                tensor as *mut c_void,
+                DLTENSOR.as_ptr(),
+                Some(delete_capsule),
+            );
+            if capsule.is_null() {
+                delete_tensor(tensor);
+            }
+            Bound::from_owned_ptr_or_err(py, capsule)
+        }
+    }
+}
+
+fn series_data_ptr(s: &Series) -> *const c_void {
+    with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.unpack().unwrap();
+        ca.data_views().next().unwrap().as_ptr() as *const c_void
+    })
+}
+
+#[pymethods]
+impl PySeries {
+    /// Export this Series as a one-dimensional tensor through the DLPack protocol.
+    ///
+    /// The data is shared if it consists of a single chunk, it must not be modified.
+    /// Versioned capsules mark shared data as read-only.
+    #[allow(unused_variables)]
+    #[pyo3(signature = (*, stream=None, max_version=None, dl_device=None, copy=None))]
+    fn __dlpack__<'py>(
+        &self,
+        py: Python<'py>,
+        stream: Option<Py<PyAny>>,
+        max_version: Option<(u32, u32)>,
+        dl_device: Option<(i32, i32)>,
+        copy: Option<bool>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        check_device(dl_device)?;
+        let s = self.series.read().clone();
+        let dtype = dl_dtype(s.dtype())?;
+        if s.null_count() > 0 {
+            return Err(PyBufferError::new_err(
+                "cannot export a Series with null values through DLPack",
+            ));
+        }
+
+        let copied = s.n_chunks() > 1 || copy == Some(true);
+        let s = match (s.n_chunks() > 1, copy) {
+            (true, Some(false)) => {
+                return Err(PyBufferError::new_err(
+                    "copy not allowed: cannot export a Series of multiple chunks without copying data",
+                ));
+            },
+            (true, _) => s.rechunk(),
+            (false, Some(true)) => with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = s.unpack().unwrap();
+                let values = ca.data_views().next().unwrap().to_vec();
+                ChunkedArray::<$T>::from_vec(s.name().clone(), values).into_series()
+            }),
+            (false, _) => s,
+        };
+
+        let data = series_data_ptr(&s);
+        let shape = vec![s.len() as i64];
+        to_capsule(py, data, dtype, shape, vec![1], Box::new(s), max_version, copied)
+    }
+
+    fn __dlpack_device__(&self) -> (i32, i32) {
+        (DL_CPU, 0)
+    }
+}
+
+#[pymethods]
+impl PyDataFrame {
+    /// Export this DataFrame as a two-dimensional tensor through the DLPack protocol.
+    ///
+    /// All columns must have the same numeric data type. The data is shared if the columns are
+    /// back-to-back in memory, it must not be modified. Versioned capsules mark shared data as
+    /// read-only.
+    #[allow(unused_variables)]
+    #[pyo3(signature = (*, stream=None, max_version=None, dl_device=None, copy=None))]
+    fn __dlpack__<'py>(
+        &self,
+        py: Python<'py>,
+        stream: Option<Py<PyAny>>,
+        max_version: Option<(u32, u32)>,
+        dl_device: Option<(i32, i32)>,
+        copy: Option<bool>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        check_device(dl_device)?;
+        let df = self.df.read().clone();
+        let Some(first) = df.columns().first() else {
+            return Err(PyBufferError::new_err(
+                "cannot export a DataFrame without columns through DLPack",
+            ));
+        };
+        let first_dtype = first.dtype().clone();
+        let dtype = dl_dtype(&first_dtype)?;
+        if let Some(c) = df.columns().iter().find(|c| c.dtype() != &first_dtype) {
+            return Err(PyTypeError::new_err(format!(
+                "cannot export columns of different data types through DLPack, got {first_dtype} and {}",
+                c.dtype()
+            )));
+        }
+        if df.columns().iter().any(|c| c.null_count() > 0) {
+            return Err(PyBufferError::new_err(
+                "cannot export a DataFrame with null values through DLPack",
+            ));
+        }
+
+        let shape = vec![df.height() as i64, df.width() as i64];
+        if copy != Some(true) && check_df_columns_contiguous(&df) {
+            // Columns are back-to-back, which is column-major order.
+            let data = series_data_ptr(df.columns()[0].as_materialized_series());
+            let strides = vec![1, df.height() as i64];
+            return to_capsule(py, data, dtype, shape, strides, Box::new(df), max_version, false);
+        }
+        if copy == Some(false) {
+            return Err(PyBufferError::new_err(
+                "copy not allowed: cannot export a DataFrame that is not contiguous in memory without copying data",
+            ));
+        }
+
+        with_match_physical_numeric_polars_type!(&first_dtype, |$T| {
+            let arr = py
+                .detach(|| df.to_ndarray::<$T>(IndexOrder::Fortran))
+                .map_err(PyPolarsErr::from)?;
+            let strides = arr.strides().iter().map(|s| *s as i64).collect();
+            let data = arr.as_ptr() as *const c_void;
+            to_capsule(py, data, dtype, shape, strides, Box::new(arr), max_version, true)
+        })
+    }
+
+    fn __dlpack_device__(&self) -> (i32, i32) {
+        (DL_CPU, 0)
+    }
+}
@@ -1,2 +1,3 @@
 pub mod arrow;
+pub mod dlpack;
 pub mod numpy;
@@ -4,17 +4,19 @@ use numpy::{Element, IntoPyArray, PyArray1};
 use polars_core::prelude::*;
 use polars_core::utils::dtypes_to_supertype;
 use polars_core::with_match_physical_numeric_polars_type;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::{PyList, PyTuple};
 use pyo3::{IntoPyObjectExt, intern};
 
 use super::to_numpy_series::series_to_numpy;
 use super::utils::{
-    create_borrowed_np_array, dtype_supports_view, polars_dtype_to_np_temporal_dtype,
+    create_borrowed_np_array, dtype_supports_view, fill_numeric_nulls,
+    polars_dtype_to_np_temporal_dtype, to_masked_array,
 };
 use crate::conversion::Wrap;
 use crate::dataframe::PyDataFrame;
+use crate::error::PyPolarsErr;
 use crate::utils::EnterPolarsExt;
 
 #[pymethods]
@@ -29,6 +31,19 @@ impl PyDataFrame {
     ) -> PyResult<Py<PyAny>> {
         df_to_numpy(py, &self.df.read(), order.0, writable, allow_copy)
     }
+
+    /// Convert this DataFrame to a NumPy masked array that masks the nulls.
+    ///
+    /// The data is shared when possible, the mask is always materialized.
+    fn to_numpy_masked(
+        &self,
+        py: Python<'_>,
+        order: Wrap<IndexOrder>,
+        writable: bool,
+        allow_copy: bool,
+    ) -> PyResult<Py<PyAny>> {
+        df_to_numpy_masked(py, &self.df.read(), order.0, writable, allow_copy)
+    }
 }
 
 pub(super) fn df_to_numpy(
@@ -78,6 +93,67 @@ pub(super) fn df_to_numpy(
     df_to_numpy_with_copy(py, df, order, writable)
 }
 
+/// Convert a DataFrame to a NumPy masked array, keeping the data type of numeric columns with
+/// nulls.
+fn df_to_numpy_masked(
+    py: Python<'_>,
+    df: &DataFrame,
+    order: IndexOrder,
+    writable: bool,
+    allow_copy: bool,
+) -> PyResult<Py<PyAny>> {
+    if let Some(c) = df.columns().iter().find(|c| c.dtype().is_nested()) {
+        return Err(PyValueError::new_err(format!(
+            "cannot convert a column of data type {} to a masked array",
+            c.dtype()
+        )));
+    }
+    if df.columns().iter().all(|c| c.null_count() == 0) {
+        let data = df_to_numpy(py, df, order, writable, allow_copy)?;
+        return to_masked_array(py, data, None);
+    }
+
+    // The values underlying the nulls are masked, so a view can show them as is.
+    let view = match order {
+        IndexOrder::Fortran => try_df_to_numpy_view(py, df, true),
+        IndexOrder::C => None,
+    };
+    let data = match view {
+        Some(arr) if !writable => arr,
+        Some(arr) => {
+            if !allow_copy {
+                return Err(PyRuntimeError::new_err(
+                    "copy not allowed: cannot create a writable array without copying data",
+                ));
+            }
+            arr.call_method0(py, intern!(py, "copy"))?
+        },
+        None => {
+            if !allow_copy {
+                return Err(PyRuntimeError::new_err(
+                    "copy not allowed: cannot convert to a NumPy array without copying data",
+                ));
+            }
+            let columns = df
+                .columns()
+                .iter()
+                .map(|c| fill_numeric_nulls(c.as_materialized_series()).map(Column::from))
+                .collect::<PolarsResult<Vec<_>>>()
+                .map_err(PyPolarsErr::from)?;
+            let df = unsafe { DataFrame::new_unchecked(df.height(), columns) };
+            df_to_numpy_with_copy(py, &df, order, writable)?
+        },
+    };
+    let mask = df
+        .columns()
+        .iter()
+        .map(|c| c.is_null().into_column())
+        .collect();
+    let mask = unsafe { DataFrame::new_unchecked(df.height(), mask) };
+    let mask = df_to_numpy_with_copy(py, &mask, order, true)?;
+    to_masked_array(py, data, Some(mask))
+}
+
 /// Create a NumPy view of the given DataFrame.
 fn try_df_to_numpy_view(py: Python<'_>, df: &DataFrame, allow_nulls: bool) -> Option<Py<PyAny>> {
     let first_dtype = check_df_dtypes_support_view(df)?;
@@ -122,7 +198,7 @@ fn check_df_dtypes_support_view(df: &DataFrame) -> Option<&DataType> {
     Some(first_dtype)
 }
 /// Returns whether all columns of the dataframe are contiguous in memory.
-fn check_df_columns_contiguous(df: &DataFrame) -> bool {
+pub(crate) fn check_df_columns_contiguous(df: &DataFrame) -> bool {
     let columns = df.columns();
 
     if columns
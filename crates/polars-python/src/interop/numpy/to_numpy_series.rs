@@ -3,17 +3,18 @@ use num_traits::{Float, NumCast};
 use numpy::npyffi::flags;
 use numpy::{Element, PyArray1};
 use polars::prelude::*;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::{IntoPyObjectExt, intern};
 
 use super::to_numpy_df::df_to_numpy;
 use super::utils::{
-    create_borrowed_np_array, dtype_supports_view, polars_dtype_to_np_temporal_dtype,
-    reshape_numpy_array, series_contains_null,
+    create_borrowed_np_array, dtype_supports_view, fill_numeric_nulls,
+    polars_dtype_to_np_temporal_dtype, reshape_numpy_array, series_contains_null, to_masked_array,
 };
 use crate::conversion::ObjectValue;
 use crate::conversion::chunked_array::{decimal_to_pyobject_iter, time_to_pyobject_iter};
+use crate::error::PyPolarsErr;
 use crate::series::PySeries;
 
 #[pymethods]
@@ -27,6 +28,18 @@ impl PySeries {
         series_to_numpy(py, &self.series.read(), writable, allow_copy)
     }
 
+    /// Convert this Series to a NumPy masked array that masks the nulls.
+    ///
+    /// The data is shared when possible, the mask is always materialized.
+    fn to_numpy_masked(
+        &self,
+        py: Python<'_>,
+        writable: bool,
+        allow_copy: bool,
+    ) -> PyResult<Py<PyAny>> {
+        series_to_numpy_masked(py, &self.series.read(), writable, allow_copy)
+    }
+
     /// Create a view of the data as a NumPy ndarray.
     ///
     /// WARNING: The resulting view will show the underlying value for nulls,
@@ -71,6 +84,49 @@ pub(super) fn series_to_numpy(
     Ok(series_to_numpy_with_copy(py, s, writable))
 }
 
+/// Convert a Series to a NumPy masked array, keeping the data type of numeric Series with nulls.
+pub(super) fn series_to_numpy_masked(
+    py: Python<'_>,
+    s: &Series,
+    writable: bool,
+    allow_copy: bool,
+) -> PyResult<Py<PyAny>> {
+    if s.dtype().is_nested() {
+        return Err(PyValueError::new_err(format!(
+            "cannot convert a Series of data type {} to a masked array",
+            s.dtype()
+        )));
+    }
+    if s.null_count() == 0 {
+        let data = series_to_numpy(py, s, writable, allow_copy)?;
+        return to_masked_array(py, data, None);
+    }
+
+    // The values underlying the nulls are masked, so a view can show them as is.
+    let data = match try_series_to_numpy_view(py, s, true, allow_copy) {
+        Some((arr, writable_flag)) if writable && !writable_flag => {
+            if !allow_copy {
+                return Err(PyRuntimeError::new_err(
+                    "copy not allowed: cannot create a writable array without copying data",
+                ));
+            }
+            arr.call_method0(py, intern!(py, "copy"))?
+        },
+        Some((arr, _)) => arr,
+        None => {
+            if !allow_copy {
+                return Err(PyRuntimeError::new_err(
+                    "copy not allowed: cannot convert to a NumPy array without copying data",
+                ));
+            }
+            let s = fill_numeric_nulls(s).map_err(PyPolarsErr::from)?;
+            series_to_numpy_with_copy(py, &s, writable)
+        },
+    };
+    let mask = series_to_numpy_with_copy(py, &s.is_null().into_series(), true);
+    to_masked_array(py, data, Some(mask))
+}
+
 /// Create a NumPy view of the given Series.
 fn try_series_to_numpy_view(
     py: Python<'_>,
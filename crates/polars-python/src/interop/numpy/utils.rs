@@ -7,12 +7,39 @@ use numpy::{Element, PY_ARRAY_API, PyArrayDescr, PyArrayDescrMethods, ToNpyDims,
 use polars_core::prelude::*;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyTuple;
+use pyo3::types::{IntoPyDict, PyTuple};
 
 pub(super) fn get_numpy_module(py: Python) -> PyResult<Bound<PyModule>> {
     PyModule::import(py, intern!(py, "numpy"))
 }
 
+/// Wrap the data in a NumPy masked array, masking the elements where `mask` is set.
+pub(super) fn to_masked_array(
+    py: Python<'_>,
+    data: Py<PyAny>,
+    mask: Option<Py<PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    let ma = get_numpy_module(py)?.getattr(intern!(py, "ma"))?;
+    let mask = match mask {
+        Some(mask) => mask.into_bound(py),
+        None => ma.getattr(intern!(py, "nomask"))?,
+    };
+    let kwargs = [("mask", mask)].into_py_dict(py)?;
+    Ok(ma
+        .call_method(intern!(py, "MaskedArray"), (data,), Some(&kwargs))?
+        .unbind())
+}
+
+/// Replace the nulls of a numeric Series by zeros, so that it is converted to a NumPy array of
+/// its own data type instead of a float array with `nan` values.
+pub(super) fn fill_numeric_nulls(s: &Series) -> PolarsResult<Series> {
+    if s.dtype().is_primitive_numeric() && s.null_count() > 0 {
+        s.fill_null(FillNullStrategy::Zero)
+    } else {
+        Ok(s.clone())
+    }
+}
+
 /// Create a NumPy ndarray view of the data.
 pub(super) unsafe fn create_borrowed_np_array<I>(
     py: Python<'_>,
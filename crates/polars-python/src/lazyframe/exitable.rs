@@ -3,15 +3,16 @@ use pyo3::prelude::*;
 
 use super::PyLazyFrame;
 use crate::PyDataFrame;
+use crate::prelude::*;
 use crate::utils::EnterPolarsExt;
 
 #[pymethods]
 #[cfg(not(target_arch = "wasm32"))]
 impl PyLazyFrame {
-    fn collect_concurrently(&self, py: Python) -> PyResult<PyInProcessQuery> {
+    fn collect_concurrently(&self, py: Python, engine: Wrap<Engine>) -> PyResult<PyInProcessQuery> {
         let ipq = py.enter_polars(|| {
             let ldf = self.ldf.read().clone();
-            ldf.collect_concurrently()
+            ldf.collect_concurrently_with_engine(engine.0)
         })?;
         Ok(PyInProcessQuery { ipq })
     }
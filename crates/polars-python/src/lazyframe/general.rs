@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::ffi::CString;
 use std::num::NonZeroUsize;
+use std::panic::AssertUnwindSafe;
 
 use arrow::ffi::export_iterator;
 use either::Either;
@@ -11,13 +12,14 @@ use polars::io::RowIndex;
 use polars::time::*;
 use polars_core::prelude::*;
 use polars_core::query_result::QueryResult;
+use polars_error::signals::{KeyboardInterrupt, catch_keyboard_interrupt};
 #[cfg(feature = "parquet")]
 use polars_parquet::arrow::write::StatisticsOptions;
 use polars_plan::dsl::ScanSources;
 use polars_plan::plans::{AExpr, HintIR, IR, Sorted};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::python_function::PythonObject;
-use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::exceptions::{PyKeyboardInterrupt, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyCapsule, PyDict, PyDictMethods, PyList};
@@ -33,6 +35,7 @@ use crate::io::cloud_options::OptPyCloudOptions;
 use crate::io::scan_options::PyScanOptions;
 use crate::io::sink_options::PySinkOptions;
 use crate::io::sink_output::PyFileSinkDestination;
+use crate::lazyframe::plan::PyQueryPlanNode;
 use crate::lazyframe::visit::NodeTraverser;
 use crate::prelude::*;
 use crate::utils::{EnterPolarsExt, to_py_err};
@@ -488,6 +491,18 @@ impl PyLazyFrame {
         py.enter_polars(|| self.ldf.read().describe_optimized_plan_tree())
     }
 
+    fn query_plan(&self, py: Python<'_>, optimized: bool) -> PyResult<Py<PyQueryPlanNode>> {
+        let ldf = self.ldf.read().clone();
+        let plan = py.enter_polars(|| {
+            if optimized {
+                ldf.to_alp_optimized()
+            } else {
+                ldf.to_alp()
+            }
+        })?;
+        PyQueryPlanNode::from_plan(py, plan.as_ref())
+    }
+
     fn to_dot(&self, py: Python<'_>, optimized: bool) -> PyResult<String> {
         py.enter_polars(|| self.ldf.read().to_dot(optimized))
     }
@@ -631,25 +646,26 @@ impl PyLazyFrame {
             // We use a tokio spawn_blocking here as it has a high blocking
             // thread pool limit.
             polars_io::pl_async::get_runtime().spawn_blocking(move || {
-                let result = ldf
-                    .collect_with_engine(engine.0)
-                    .map(|r| match r {
-                        QueryResult::Single(df) => df,
-                        // TODO: Should return query results
-                        QueryResult::Multiple(_) => DataFrame::empty(),
-                    })
-                    .map(PyDataFrame::new)
-                    .map_err(PyPolarsErr::from);
+                // Nothing waits on this thread, so we catch keyboard interrupts
+                // here to cancel the query and pass the interrupt to the callback.
+                let result = catch_keyboard_interrupt(AssertUnwindSafe(|| {
+                    ldf.collect_with_engine(engine.0)
+                        .map(|r| match r {
+                            QueryResult::Single(df) => df,
+                            // TODO: Should return query results
+                            QueryResult::Multiple(_) => DataFrame::empty(),
+                        })
+                        .map(PyDataFrame::new)
+                        .map_err(|err| PyErr::from(PyPolarsErr::from(err)))
+                }))
+                .unwrap_or_else(|KeyboardInterrupt| Err(PyKeyboardInterrupt::new_err("")));
 
                 Python::attach(|py| match result {
                     Ok(df) => {
                         lambda.call1(py, (df,)).map_err(|err| err.restore(py)).ok();
                     },
                     Err(err) => {
-                        lambda
-                            .call1(py, (PyErr::from(err),))
-                            .map_err(|err| err.restore(py))
-                            .ok();
+                        lambda.call1(py, (err,)).map_err(|err| err.restore(py)).ok();
                     },
                 });
             });
@@ -680,6 +696,36 @@ impl PyLazyFrame {
         })
     }
 
+    #[cfg(feature = "async")]
+    fn collect_arrow_stream(
+        &self,
+        py: Python<'_>,
+        engine: Wrap<Engine>,
+        maintain_order: bool,
+        chunk_size: Option<NonZeroUsize>,
+        compat_level: PyCompatLevel,
+    ) -> PyResult<PyArrowBatchStream> {
+        py.enter_polars(|| {
+            let mut ldf = self.ldf.read().clone();
+
+            // Resolve the schema before starting, so that invalid queries error here instead of
+            // in the middle of the consumer.
+            let schema = ldf.collect_schema()?.to_arrow(compat_level.0);
+            let dtype = ArrowDataType::Struct(schema.into_iter_values().collect());
+
+            let collect_batches =
+                ldf.collect_batches(engine.0, maintain_order, chunk_size, false)?;
+
+            PolarsResult::Ok(PyArrowBatchStream {
+                inner: Mutex::new(Some(ArrowStreamIterator {
+                    inner: Arc::new(Mutex::new(collect_batches)),
+                    dtype,
+                    compat_level: compat_level.0,
+                })),
+            })
+        })
+    }
+
     #[cfg(feature = "parquet")]
     #[pyo3(signature = (
         target, sink_options, compression, compression_level, statistics, row_group_size, data_page_size,
@@ -909,14 +955,15 @@ impl PyLazyFrame {
         ldf.select_seq(exprs).into()
     }
 
-    fn group_by(&self, by: Vec<PyExpr>, maintain_order: bool) -> PyLazyGroupBy {
+    fn group_by(&self, by: Vec<PyExpr>, maintain_order: bool, nulls_equal: bool) -> PyLazyGroupBy {
         let ldf = self.ldf.read().clone();
         let by = by.to_exprs();
         let lazy_gb = if maintain_order {
             ldf.group_by_stable(by)
         } else {
             ldf.group_by(by)
-        };
+        }
+        .nulls_equal(nulls_equal);
 
         PyLazyGroupBy { lgb: Some(lazy_gb) }
     }
@@ -1435,7 +1482,8 @@ impl PyLazyFrame {
         ldf.with_row_index(name, offset).into()
     }
 
-    #[pyo3(signature = (function, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output))]
+    #[pyo3(signature = (function, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output, preserves_order=false, preserves_row_count=false, preserves_schema=false))]
+    #[allow(clippy::too_many_arguments)]
     fn map_batches(
         &self,
         function: Py<PyAny>,
@@ -1445,6 +1493,9 @@ impl PyLazyFrame {
         streamable: bool,
         schema: Option<Wrap<Schema>>,
         validate_output: bool,
+        preserves_order: bool,
+        preserves_row_count: bool,
+        preserves_schema: bool,
     ) -> Self {
         let mut opt = OptFlags::default();
         opt.set(OptFlags::PREDICATE_PUSHDOWN, predicate_pushdown);
@@ -1452,12 +1503,18 @@ impl PyLazyFrame {
         opt.set(OptFlags::SLICE_PUSHDOWN, slice_pushdown);
         opt.set(OptFlags::NEW_STREAMING, streamable);
 
+        let mut flags = UdfFlags::empty();
+        flags.set(UdfFlags::PRESERVES_ORDER, preserves_order);
+        flags.set(UdfFlags::PRESERVES_ROW_COUNT, preserves_row_count);
+        flags.set(UdfFlags::PRESERVES_SCHEMA, preserves_schema);
+
         self.ldf
             .read()
             .clone()
             .map_python(
                 function.into(),
                 opt,
+                flags,
                 schema.map(|s| Arc::new(s.0)),
                 validate_output,
             )
@@ -1494,11 +1551,24 @@ impl PyLazyFrame {
         Ok(schema_dict)
     }
 
-    fn unnest(&self, columns: PySelector, separator: Option<&str>) -> Self {
+    #[pyo3(signature = (columns, separator, fields, prefix, on_collision))]
+    fn unnest(
+        &self,
+        columns: PySelector,
+        separator: Option<&str>,
+        fields: Option<Vec<PyBackedStr>>,
+        prefix: Option<&str>,
+        on_collision: Wrap<UnnestCollision>,
+    ) -> Self {
+        let options = UnnestOptions {
+            fields: fields.map(|fields| fields.iter().map(|f| PlSmallStr::from_str(f)).collect()),
+            prefix: prefix.map(PlSmallStr::from_str),
+            on_collision: on_collision.0,
+        };
         self.ldf
             .read()
             .clone()
-            .unnest(columns.inner, separator.map(PlSmallStr::from_str))
+            .unnest_with_options(columns.inner, separator.map(PlSmallStr::from_str), options)
             .into()
     }
 
@@ -1523,6 +1593,22 @@ impl PyLazyFrame {
         plan.into()
     }
 
+    fn hint_estimated_rows(&self, n_rows: usize) -> PyResult<Self> {
+        let out = self
+            .ldf
+            .read()
+            .clone()
+            .set_estimated_rows(n_rows)
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
+    fn estimated_rows(&self, py: Python) -> PyResult<(usize, bool)> {
+        let ldf = self.ldf.read().clone();
+        let estimate = py.enter_polars(|| ldf.estimate_cardinality())?;
+        Ok((estimate.rows, estimate.exact))
+    }
+
     fn hint_sorted(
         &self,
         columns: Vec<String>,
@@ -1615,7 +1701,11 @@ impl PyCollectBatches {
 
         let dtype = ArrowDataType::Struct(schema.into_iter_values().collect());
 
-        let iter = Box::new(ArrowStreamIterator::new(self.inner.clone(), dtype.clone()));
+        let iter = Box::new(ArrowStreamIterator::new(
+            self.inner.clone(),
+            dtype.clone(),
+            CompatLevel::newest(),
+        ));
         let field = ArrowField::new(PlSmallStr::EMPTY, dtype, false);
         let stream = export_iterator(iter, field);
         let stream_capsule_name = CString::new("arrow_array_stream").unwrap();
@@ -1623,16 +1713,52 @@ impl PyCollectBatches {
     }
 }
 
+/// Arrow C stream over the batches of a running streaming query.
+///
+/// The stream can only be exported once, as the batches are consumed while reading it.
+#[pyclass(frozen)]
+struct PyArrowBatchStream {
+    inner: Mutex<Option<ArrowStreamIterator>>,
+}
+
+#[pymethods]
+impl PyArrowBatchStream {
+    #[allow(unused_variables)]
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Py<PyAny>>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let Some(iter) = self.inner.lock().take() else {
+            return Err(PyValueError::new_err(
+                "the Arrow stream of this query was already consumed",
+            ));
+        };
+
+        let field = ArrowField::new(PlSmallStr::EMPTY, iter.dtype.clone(), false);
+        let stream = export_iterator(Box::new(iter), field);
+        let stream_capsule_name = CString::new("arrow_array_stream").unwrap();
+        PyCapsule::new(py, stream, Some(stream_capsule_name))
+    }
+}
+
 pub struct ArrowStreamIterator {
     inner: Arc<Mutex<CollectBatches>>,
     dtype: ArrowDataType,
+    compat_level: CompatLevel,
 }
 
 impl ArrowStreamIterator {
-    fn new(inner: Arc<Mutex<CollectBatches>>, schema: ArrowDataType) -> Self {
+    fn new(
+        inner: Arc<Mutex<CollectBatches>>,
+        schema: ArrowDataType,
+        compat_level: CompatLevel,
+    ) -> Self {
         Self {
             inner,
             dtype: schema,
+            compat_level,
         }
     }
 }
@@ -1647,7 +1773,7 @@ impl Iterator for ArrowStreamIterator {
             Some(Err(err)) => Some(Err(err)),
             Some(Ok(df)) => {
                 let height = df.height();
-                let arrays = df.rechunk_into_arrow(CompatLevel::newest());
+                let arrays = df.rechunk_into_arrow(self.compat_level);
                 Some(Ok(Box::new(arrow::array::StructArray::new(
                     self.dtype.clone(),
                     height,
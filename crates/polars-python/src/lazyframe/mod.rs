@@ -2,6 +2,7 @@ mod exitable;
 #[cfg(feature = "pymethods")]
 mod general;
 mod optflags;
+pub mod plan;
 #[cfg(feature = "pymethods")]
 mod serde;
 mod sink;
@@ -0,0 +1,171 @@
+use polars_plan::plans::{IR, IRPlanRef};
+use polars_utils::arena::Node;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::Wrap;
+
+/// A node of a (possibly optimized) query plan.
+///
+/// This is a snapshot of the IR: it does not keep the plan alive and cannot be used to modify
+/// it. Expressions are given in their `explain()` notation.
+#[pyclass(name = "QueryPlanNode", frozen, skip_from_py_object)]
+pub struct PyQueryPlanNode {
+    #[pyo3(get)]
+    kind: &'static str,
+    #[pyo3(get)]
+    schema: Py<PyDict>,
+    #[pyo3(get)]
+    options: Py<PyDict>,
+    #[pyo3(get)]
+    expressions: Py<PyList>,
+    #[pyo3(get)]
+    children: Py<PyList>,
+}
+
+#[pymethods]
+impl PyQueryPlanNode {
+    fn __repr__(&self, py: Python<'_>) -> String {
+        format!(
+            "<QueryPlanNode kind={:?} children={}>",
+            self.kind,
+            self.children.bind(py).len()
+        )
+    }
+}
+
+impl PyQueryPlanNode {
+    pub(crate) fn from_plan(py: Python<'_>, plan: IRPlanRef<'_>) -> PyResult<Py<Self>> {
+        convert_node(py, plan, plan.lp_top)
+    }
+}
+
+fn convert_node(py: Python<'_>, plan: IRPlanRef<'_>, node: Node) -> PyResult<Py<PyQueryPlanNode>> {
+    let ir = plan.lp_arena.get(node);
+
+    let children = ir
+        .inputs()
+        .map(|input| convert_node(py, plan, input))
+        .collect::<PyResult<Vec<_>>>()?;
+    let expressions = ir
+        .exprs()
+        .map(|e| e.display(plan.expr_arena).to_string())
+        .collect::<Vec<_>>();
+    let schema = Wrap((**ir.schema(plan.lp_arena)).clone()).into_pyobject(py)?;
+
+    let options = PyDict::new(py);
+    node_options(ir, &options)?;
+
+    Py::new(
+        py,
+        PyQueryPlanNode {
+            kind: ir.name(),
+            schema: schema.unbind(),
+            options: options.unbind(),
+            expressions: PyList::new(py, expressions)?.unbind(),
+            children: PyList::new(py, children)?.unbind(),
+        },
+    )
+}
+
+fn node_options(ir: &IR, options: &Bound<'_, PyDict>) -> PyResult<()> {
+    match ir {
+        IR::Slice { offset, len, .. } => {
+            options.set_item("offset", offset)?;
+            options.set_item("len", len)?;
+        },
+        IR::Scan {
+            sources,
+            scan_type,
+            predicate,
+            ..
+        } => {
+            let scan_type: &'static str = (&**scan_type).into();
+            options.set_item("scan_type", scan_type)?;
+            options.set_item("n_sources", sources.len())?;
+            options.set_item("has_predicate", predicate.is_some())?;
+        },
+        IR::DataFrameScan {
+            df, output_schema, ..
+        } => {
+            options.set_item("n_rows", df.height())?;
+            options.set_item(
+                "projection",
+                output_schema
+                    .as_ref()
+                    .map(|s| s.iter_names().map(|n| n.as_str()).collect::<Vec<_>>()),
+            )?;
+        },
+        IR::SimpleProjection { columns, .. } => {
+            let columns = columns.iter_names().map(|n| n.as_str()).collect::<Vec<_>>();
+            options.set_item("columns", columns)?;
+        },
+        IR::Select { options: o, .. } | IR::HStack { options: o, .. } => {
+            options.set_item("run_parallel", o.run_parallel)?;
+        },
+        IR::Sort {
+            slice,
+            sort_options,
+            ..
+        } => {
+            options.set_item("descending", &sort_options.descending)?;
+            options.set_item("nulls_last", &sort_options.nulls_last)?;
+            options.set_item("maintain_order", sort_options.maintain_order)?;
+            options.set_item("slice", slice.as_ref().map(|(o, l, _)| (*o, *l)))?;
+        },
+        IR::Cache { id, .. } => {
+            options.set_item("id", id.as_u128())?;
+        },
+        IR::GroupBy {
+            keys,
+            maintain_order,
+            options: o,
+            apply,
+            ..
+        } => {
+            options.set_item("n_keys", keys.len())?;
+            options.set_item("maintain_order", maintain_order)?;
+            options.set_item("slice", o.slice)?;
+            options.set_item("has_apply", apply.is_some())?;
+        },
+        IR::Join {
+            left_on,
+            options: o,
+            ..
+        } => {
+            options.set_item("how", o.args.how.to_string().to_lowercase())?;
+            options.set_item("n_keys", left_on.len())?;
+            options.set_item("suffix", o.args.suffix.as_deref())?;
+            options.set_item("nulls_equal", o.args.nulls_equal)?;
+            options.set_item("slice", o.args.slice)?;
+        },
+        IR::Distinct { options: o, .. } => {
+            let keep: &'static str = o.keep_strategy.into();
+            options.set_item("keep", keep)?;
+            options.set_item(
+                "subset",
+                o.subset
+                    .as_ref()
+                    .map(|s| s.iter().map(|n| n.as_str()).collect::<Vec<_>>()),
+            )?;
+            options.set_item("maintain_order", o.maintain_order)?;
+            options.set_item("slice", o.slice)?;
+        },
+        IR::MapFunction { function, .. } => {
+            options.set_item("function", function.to_string())?;
+        },
+        IR::Union { options: o, .. } => {
+            options.set_item("maintain_order", o.maintain_order)?;
+            options.set_item("slice", o.slice)?;
+        },
+        IR::HConcat { options: o, .. } => {
+            options.set_item("parallel", o.parallel)?;
+        },
+        #[cfg(feature = "merge_sorted")]
+        IR::MergeSorted { key, .. } => {
+            options.set_item("key", key.as_str())?;
+        },
+        _ => {},
+    }
+    Ok(())
+}
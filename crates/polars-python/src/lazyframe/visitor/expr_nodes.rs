@@ -4,10 +4,13 @@ use polars::series::ops::NullBehavior;
 use polars_core::chunked_array::ops::FillNullStrategy;
 #[cfg(feature = "string_normalize")]
 use polars_ops::chunked_array::UnicodeForm;
+use polars_ops::chunked_array::{ModeTies, TokenizeMethod};
 use polars_ops::prelude::RankMethod;
-use polars_ops::series::InterpolationMethod;
 #[cfg(feature = "search_sorted")]
 use polars_ops::series::SearchSortedSide;
+use polars_ops::series::{
+    HashAlgorithm, HllFunction, InterpolateByMethod, InterpolationMethod, WeightedAggregation,
+};
 use polars_plan::plans::{
     DynLiteralValue, IRBooleanFunction, IRFunctionExpr, IRPowFunction, IRRollingFunctionBy,
     IRStringFunction, IRStructFunction, IRTemporalFunction,
@@ -15,7 +18,7 @@ use polars_plan::plans::{
 use polars_plan::prelude::{
     AExpr, GroupbyOptions, IRAggExpr, LiteralValue, Operator, WindowMapping,
 };
-use polars_time::prelude::RollingGroupOptions;
+use polars_time::prelude::{RollingGroupOptions, WeekConvention};
 use polars_time::{ClosedWindow, Duration, DynamicGroupOptions};
 use pyo3::IntoPyObjectExt;
 use pyo3::exceptions::PyNotImplementedError;
@@ -144,8 +147,11 @@ pub enum PyStringFunction {
     LenBytes,
     LenChars,
     Lowercase,
+    Casefold,
     JsonDecode,
     JsonPathMatch,
+    JsonPathMatchAll,
+    JsonExtractMany,
     Replace,
     Reverse,
     PadStart,
@@ -157,6 +163,8 @@ pub enum PyStringFunction {
     HexDecode,
     Base64Encode,
     Base64Decode,
+    ParseIp,
+    ParseUuid,
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -166,6 +174,7 @@ pub enum PyStringFunction {
     SplitExact,
     SplitN,
     Strptime,
+    StrptimeMulti,
     Split,
     SplitRegex,
     ToDecimal,
@@ -176,6 +185,13 @@ pub enum PyStringFunction {
     ReplaceMany,
     EscapeRegex,
     Normalize,
+    LevenshteinDistance,
+    HammingDistance,
+    JaroWinkler,
+    Tokenize,
+    UrlPart,
+    UrlQueryParams,
+    UrlDecodePercent,
 }
 
 #[pymethods]
@@ -877,6 +893,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     IRStringFunction::LenBytes => (PyStringFunction::LenBytes,).into_py_any(py),
                     IRStringFunction::LenChars => (PyStringFunction::LenChars,).into_py_any(py),
                     IRStringFunction::Lowercase => (PyStringFunction::Lowercase,).into_py_any(py),
+                    IRStringFunction::Casefold => (PyStringFunction::Casefold,).into_py_any(py),
                     #[cfg(feature = "extract_jsonpath")]
                     IRStringFunction::JsonDecode(_) => {
                         (PyStringFunction::JsonDecode, <Option<usize>>::None).into_py_any(py)
@@ -885,6 +902,16 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     IRStringFunction::JsonPathMatch => {
                         (PyStringFunction::JsonPathMatch,).into_py_any(py)
                     },
+                    #[cfg(feature = "extract_jsonpath")]
+                    IRStringFunction::JsonPathMatchAll(_) => {
+                        (PyStringFunction::JsonPathMatchAll,).into_py_any(py)
+                    },
+                    #[cfg(feature = "extract_jsonpath")]
+                    IRStringFunction::JsonExtractMany { paths, dtype: _ } => (
+                        PyStringFunction::JsonExtractMany,
+                        paths.iter().map(|p| p.as_str()).collect::<Vec<_>>(),
+                    )
+                        .into_py_any(py),
                     #[cfg(feature = "regex")]
                     IRStringFunction::Replace { n, literal } => {
                         (PyStringFunction::Replace, n, literal).into_py_any(py)
@@ -901,6 +928,46 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     )
                         .into_py_any(py),
                     IRStringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
+                    IRStringFunction::LevenshteinDistance => {
+                        (PyStringFunction::LevenshteinDistance,).into_py_any(py)
+                    },
+                    IRStringFunction::HammingDistance => {
+                        (PyStringFunction::HammingDistance,).into_py_any(py)
+                    },
+                    IRStringFunction::JaroWinkler => {
+                        (PyStringFunction::JaroWinkler,).into_py_any(py)
+                    },
+                    IRStringFunction::Tokenize {
+                        method,
+                        lowercase,
+                        stop_words,
+                    } => (
+                        PyStringFunction::Tokenize,
+                        match method {
+                            TokenizeMethod::UnicodeWords => "unicode_words",
+                            TokenizeMethod::Whitespace => "whitespace",
+                            TokenizeMethod::Pattern(_) => "pattern",
+                        },
+                        match method {
+                            TokenizeMethod::Pattern(pattern) => Some(pattern.as_str()),
+                            _ => None,
+                        },
+                        lowercase,
+                        stop_words.iter().map(|w| w.as_str()).collect::<Vec<_>>(),
+                    )
+                        .into_py_any(py),
+                    IRStringFunction::UrlPart(part) => {
+                        (PyStringFunction::UrlPart, part.name()).into_py_any(py)
+                    },
+                    IRStringFunction::UrlQueryParams(keys) => (
+                        PyStringFunction::UrlQueryParams,
+                        keys.as_ref()
+                            .map(|keys| keys.iter().map(|k| k.as_str()).collect::<Vec<_>>()),
+                    )
+                        .into_py_any(py),
+                    IRStringFunction::UrlDecodePercent => {
+                        (PyStringFunction::UrlDecodePercent,).into_py_any(py)
+                    },
                     IRStringFunction::PadStart { fill_char } => {
                         (PyStringFunction::PadStart, fill_char).into_py_any(py)
                     },
@@ -915,13 +982,19 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     IRStringFunction::HexDecode(strict) => {
                         (PyStringFunction::HexDecode, strict).into_py_any(py)
                     },
-                    IRStringFunction::Base64Encode => {
+                    IRStringFunction::Base64Encode { .. } => {
                         (PyStringFunction::Base64Encode,).into_py_any(py)
                     },
                     #[cfg(feature = "binary_encoding")]
-                    IRStringFunction::Base64Decode(strict) => {
+                    IRStringFunction::Base64Decode { strict, .. } => {
                         (PyStringFunction::Base64Decode, strict).into_py_any(py)
                     },
+                    IRStringFunction::ParseIp { strict } => {
+                        (PyStringFunction::ParseIp, strict).into_py_any(py)
+                    },
+                    IRStringFunction::ParseUuid { strict } => {
+                        (PyStringFunction::ParseUuid, strict).into_py_any(py)
+                    },
                     IRStringFunction::StartsWith => (PyStringFunction::StartsWith,).into_py_any(py),
                     IRStringFunction::StripChars => (PyStringFunction::StripChars,).into_py_any(py),
                     IRStringFunction::StripCharsStart => {
@@ -948,12 +1021,28 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                         options.cache,
                     )
                         .into_py_any(py),
+                    IRStringFunction::StrptimeMulti(_, options) => (
+                        PyStringFunction::StrptimeMulti,
+                        options
+                            .formats
+                            .iter()
+                            .map(|s| s.as_str())
+                            .collect::<Vec<_>>(),
+                        options.best_effort,
+                        options.include_format,
+                        options.strict,
+                        options.exact,
+                        options.cache,
+                    )
+                        .into_py_any(py),
                     IRStringFunction::Split(inclusive) => {
                         (PyStringFunction::Split, inclusive).into_py_any(py)
                     },
-                    IRStringFunction::SplitRegex { inclusive, strict } => {
-                        (PyStringFunction::SplitRegex, inclusive, strict).into_py_any(py)
-                    },
+                    IRStringFunction::SplitRegex {
+                        n,
+                        inclusive,
+                        strict,
+                    } => (PyStringFunction::SplitRegex, inclusive, strict, n).into_py_any(py),
                     IRStringFunction::ToDecimal { scale } => {
                         (PyStringFunction::ToDecimal, scale).into_py_any(py)
                     },
@@ -1019,7 +1108,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     IRTemporalFunction::IsoYear => (PyTemporalFunction::IsoYear,).into_py_any(py),
                     IRTemporalFunction::Quarter => (PyTemporalFunction::Quarter,).into_py_any(py),
                     IRTemporalFunction::Month => (PyTemporalFunction::Month,).into_py_any(py),
-                    IRTemporalFunction::Week => (PyTemporalFunction::Week,).into_py_any(py),
+                    IRTemporalFunction::FiscalYear(_) => {
+                        return Err(PyNotImplementedError::new_err("fiscal_year"));
+                    },
+                    IRTemporalFunction::FiscalQuarter(_) => {
+                        return Err(PyNotImplementedError::new_err("fiscal_quarter"));
+                    },
+                    IRTemporalFunction::Week(WeekConvention::Iso) => {
+                        (PyTemporalFunction::Week,).into_py_any(py)
+                    },
+                    IRTemporalFunction::Week(WeekConvention::Us) => {
+                        return Err(PyNotImplementedError::new_err("week with us convention"));
+                    },
                     IRTemporalFunction::WeekDay => (PyTemporalFunction::WeekDay,).into_py_any(py),
                     IRTemporalFunction::Day => (PyTemporalFunction::Day,).into_py_any(py),
                     IRTemporalFunction::OrdinalDay => {
@@ -1181,6 +1281,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Hash(seed, seed_1, seed_2, seed_3) => {
                     ("hash", seed, seed_1, seed_2, seed_3).into_py_any(py)
                 },
+                IRFunctionExpr::StableHash { seed } => ("stable_hash", seed).into_py_any(py),
+                IRFunctionExpr::HashDigest { algorithm, hex } => match algorithm {
+                    HashAlgorithm::Xxh3 { seed } => ("hash_xxh3", seed, hex).into_py_any(py),
+                    _ => (format!("hash_{}", algorithm.name()), hex).into_py_any(py),
+                },
                 IRFunctionExpr::ArgWhere => ("argwhere",).into_py_any(py),
                 #[cfg(feature = "index_of")]
                 IRFunctionExpr::IndexOf => ("index_of",).into_py_any(py),
@@ -1195,6 +1300,22 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     descending,
                 )
                     .into_py_any(py),
+                #[cfg(feature = "search_sorted")]
+                IRFunctionExpr::SearchSortedBy {
+                    side,
+                    descending,
+                    nulls_last,
+                } => (
+                    "search_sorted_by",
+                    match side {
+                        SearchSortedSide::Any => "any",
+                        SearchSortedSide::Left => "left",
+                        SearchSortedSide::Right => "right",
+                    },
+                    descending,
+                    nulls_last,
+                )
+                    .into_py_any(py),
                 IRFunctionExpr::Range(_) => return Err(PyNotImplementedError::new_err("range")),
                 #[cfg(feature = "trigonometry")]
                 IRFunctionExpr::Trigonometry(trigfun) => {
@@ -1259,9 +1380,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Shift => ("shift",).into_py_any(py),
                 IRFunctionExpr::DropNans => ("drop_nans",).into_py_any(py),
                 IRFunctionExpr::DropNulls => ("drop_nulls",).into_py_any(py),
-                IRFunctionExpr::Mode { maintain_order } => {
-                    ("mode", *maintain_order).into_py_any(py)
+                IRFunctionExpr::Mode {
+                    maintain_order,
+                    ties,
+                } => {
+                    let ties = match ties {
+                        ModeTies::All => "all",
+                        ModeTies::First => "first",
+                        ModeTies::Smallest => "smallest",
+                    };
+                    ("mode", *maintain_order, ties).into_py_any(py)
                 },
+                IRFunctionExpr::Modes(n) => ("modes", n).into_py_any(py),
                 IRFunctionExpr::Skew(bias) => ("skew", bias).into_py_any(py),
                 IRFunctionExpr::Kurtosis(fisher, bias) => {
                     ("kurtosis", fisher, bias).into_py_any(py)
@@ -1290,6 +1420,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                         RankMethod::Dense => "dense",
                         RankMethod::Ordinal => "ordinal",
                         RankMethod::Random => "random",
+                        RankMethod::Percent => "percent",
                     };
                     ("rank", method, options.descending, seed.map(|s| s as i64)).into_py_any(py)
                 },
@@ -1304,6 +1435,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::CumProd { reverse } => ("cum_prod", reverse).into_py_any(py),
                 IRFunctionExpr::CumMin { reverse } => ("cum_min", reverse).into_py_any(py),
                 IRFunctionExpr::CumMax { reverse } => ("cum_max", reverse).into_py_any(py),
+                IRFunctionExpr::CumAggReset { function, reverse } => {
+                    ("cum_agg_reset", function.to_string(), reverse).into_py_any(py)
+                },
                 IRFunctionExpr::Reverse => ("reverse",).into_py_any(py),
                 IRFunctionExpr::ValueCounts {
                     sort,
@@ -1312,7 +1446,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     normalize,
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 IRFunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
-                IRFunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                IRFunctionExpr::ApproxNUnique { precision } => {
+                    ("approx_n_unique", precision).into_py_any(py)
+                },
+                IRFunctionExpr::Hll(f) => match f {
+                    HllFunction::Sketch { precision } => ("hll_sketch", precision).into_py_any(py),
+                    HllFunction::Merge => ("hll_merge",).into_py_any(py),
+                    HllFunction::Estimate => ("hll_estimate",).into_py_any(py),
+                },
+                IRFunctionExpr::ApproxQuantile {
+                    quantile,
+                    compression,
+                } => ("approx_quantile", quantile, compression).into_py_any(py),
                 IRFunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
                 IRFunctionExpr::Diff(null_behaviour) => (
                     "diff",
@@ -1332,7 +1477,17 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                     },
                 )
                     .into_py_any(py),
-                IRFunctionExpr::InterpolateBy => ("interpolate_by",).into_py_any(py),
+                IRFunctionExpr::InterpolateBy(method) => (
+                    "interpolate_by",
+                    match method {
+                        InterpolateByMethod::Linear => "linear",
+                        InterpolateByMethod::Nearest => "nearest",
+                        InterpolateByMethod::Forward => "forward",
+                        InterpolateByMethod::Backward => "backward",
+                        InterpolateByMethod::CubicSpline => "cubic_spline",
+                    },
+                )
+                    .into_py_any(py),
                 IRFunctionExpr::Entropy { base, normalize } => {
                     ("entropy", base, normalize).into_py_any(py)
                 },
@@ -1349,6 +1504,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Truncate { decimals } => ("truncate", decimals).into_py_any(py),
                 IRFunctionExpr::Floor => ("floor",).into_py_any(py),
                 IRFunctionExpr::Ceil => ("ceil",).into_py_any(py),
+                IRFunctionExpr::DecimalArithmetic { .. } => {
+                    return Err(PyNotImplementedError::new_err("decimal arithmetic"));
+                },
+                IRFunctionExpr::CheckedArithmetic { .. } => {
+                    return Err(PyNotImplementedError::new_err("checked arithmetic"));
+                },
                 IRFunctionExpr::Fused(_) => return Err(PyNotImplementedError::new_err("fused")),
                 IRFunctionExpr::ConcatExpr(_) => {
                     return Err(PyNotImplementedError::new_err("concat expr"));
@@ -1356,6 +1517,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<Py<PyAny>> {
                 IRFunctionExpr::Correlation { .. } => {
                     return Err(PyNotImplementedError::new_err("corr"));
                 },
+                IRFunctionExpr::Weighted(agg) => match agg {
+                    WeightedAggregation::Mean => ("weighted_mean",).into_py_any(py),
+                    WeightedAggregation::Var { ddof } => ("weighted_var", ddof).into_py_any(py),
+                    WeightedAggregation::Quantile => ("weighted_quantile",).into_py_any(py),
+                },
                 #[cfg(feature = "peaks")]
                 IRFunctionExpr::PeakMin => ("peak_max",).into_py_any(py),
                 #[cfg(feature = "peaks")]
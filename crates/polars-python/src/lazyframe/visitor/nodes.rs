@@ -623,9 +623,19 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<Py<PyAny>> {
                     predicate_pd: _,
                     projection_pd: _,
                     streamable: _,
+                    flags: _,
                     fmt_str: _,
                 } => return Err(PyNotImplementedError::new_err("opaque rust mapfunction")),
-                FunctionIR::Unnest { columns, separator } => (
+                FunctionIR::FastStatistics { .. } => {
+                    return Err(PyNotImplementedError::new_err(
+                        "fast statistics mapfunction",
+                    ));
+                },
+                FunctionIR::Unnest {
+                    columns,
+                    separator,
+                    options: _,
+                } => (
                     "unnest",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
                     separator.as_ref().map(|s| s.to_string()),
@@ -693,6 +703,7 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<Py<PyAny>> {
                             .collect();
                         ("hint_sorted", sorted_info).into_py_any(py)?
                     },
+                    HintIR::EstimatedRows(n) => ("hint_estimated_rows", *n).into_py_any(py)?,
                 },
             },
         }
@@ -56,6 +56,9 @@ impl PyLazyGroupBy {
 
         let function = PythonObject(lambda);
 
-        Ok(lgb.apply(PlanCallback::new_python(function), schema).into())
+        Ok(lgb
+            .apply(PlanCallback::new_python(function), schema)
+            .map_err(PyPolarsErr::from)?
+            .into())
     }
 }
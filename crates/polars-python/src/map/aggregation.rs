@@ -0,0 +1,224 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars::prelude::*;
+use polars_expr::EvictIdx;
+use polars_expr::reduce::GroupedReduction;
+use polars_plan::dsl::AnonymousAgg;
+use pyo3::prelude::*;
+
+use crate::conversion::any_value::py_object_to_any_value;
+use crate::series::PySeries;
+
+/// The callables that define an aggregation in Python.
+struct PythonAggregationFunctions {
+    init: Py<PyAny>,
+    update: Py<PyAny>,
+    merge: Py<PyAny>,
+    finalize: Py<PyAny>,
+}
+
+/// An aggregation with a Python state per group.
+///
+/// The state of a group is created with `init()` once it receives values, and replaced by
+/// `update(state, values)` and `merge(state, other)`. `finalize(state)` returns the result.
+pub struct PythonGroupedReduction {
+    functions: Arc<PythonAggregationFunctions>,
+    return_dtype: DataType,
+    states: Vec<Option<Py<PyAny>>>,
+    evicted_states: Vec<Option<Py<PyAny>>>,
+}
+
+impl PythonGroupedReduction {
+    fn new_with_states(&self, states: Vec<Option<Py<PyAny>>>) -> Self {
+        Self {
+            functions: self.functions.clone(),
+            return_dtype: self.return_dtype.clone(),
+            states,
+            evicted_states: Vec::new(),
+        }
+    }
+
+    fn take_or_init(&mut self, py: Python<'_>, group_idx: usize) -> PyResult<Py<PyAny>> {
+        match self.states[group_idx].take() {
+            Some(state) => Ok(state),
+            None => self.functions.init.call0(py),
+        }
+    }
+
+    /// Updates the state of a group with the given rows of `values`.
+    fn update_rows(
+        &mut self,
+        py: Python<'_>,
+        values: &Series,
+        group_idx: usize,
+        rows: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let values = PySeries::new(values.take_slice(rows)?);
+        let state = self.take_or_init(py, group_idx)?;
+        let state = self.functions.update.call1(py, (state, values))?;
+        self.states[group_idx] = Some(state);
+        Ok(())
+    }
+}
+
+impl GroupedReduction for PythonGroupedReduction {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(self.new_with_states(Vec::new()))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.states.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.states.resize_with(num_groups as usize, || None);
+    }
+
+    fn update_group(
+        &mut self,
+        values: &[&Column],
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let &[values] = values else { unreachable!() };
+        let values = PySeries::new(values.as_materialized_series().clone());
+        Python::attach(|py| {
+            let state = self.take_or_init(py, group_idx as usize)?;
+            let state = self.functions.update.call1(py, (state, values))?;
+            self.states[group_idx as usize] = Some(state);
+            Ok(())
+        })
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &[&Column],
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let &[values] = values else { unreachable!() };
+        assert!(subset.len() == group_idxs.len());
+        let values = values.as_materialized_series();
+
+        // Collect the rows per group, such that `update` is called once per group instead of
+        // once per row. The rows of an evicted group are flushed before it is evicted.
+        let mut rows: PlHashMap<usize, Vec<IdxSize>> = PlHashMap::new();
+        Python::attach(|py| {
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let group_idx = g.idx();
+                if g.should_evict() {
+                    if let Some(group_rows) = rows.remove(&group_idx) {
+                        self.update_rows(py, values, group_idx, &group_rows)?;
+                    }
+                    let old = self.states[group_idx].take();
+                    self.evicted_states.push(old);
+                }
+                rows.entry(group_idx).or_default().push(*i);
+            }
+            for (group_idx, group_rows) in rows {
+                self.update_rows(py, values, group_idx, &group_rows)?;
+            }
+            Ok(())
+        })
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(subset.len() == group_idxs.len());
+        Python::attach(|py| {
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let Some(other_state) = &other.states[*i as usize] else {
+                    continue;
+                };
+                let state = self.take_or_init(py, *g as usize)?;
+                let state = self
+                    .functions
+                    .merge
+                    .call1(py, (state, other_state.clone_ref(py)))?;
+                self.states[*g as usize] = Some(state);
+            }
+            Ok(())
+        })
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        let states = std::mem::take(&mut self.evicted_states);
+        Box::new(self.new_with_states(states))
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let states = std::mem::take(&mut self.states);
+        let values = Python::attach(|py| {
+            states
+                .into_iter()
+                .map(|state| {
+                    let state = match state {
+                        Some(state) => state,
+                        None => self.functions.init.call0(py)?,
+                    };
+                    let out = self.functions.finalize.call1(py, (state,))?;
+                    py_object_to_any_value(out.bind(py), true, false)
+                })
+                .collect::<PyResult<Vec<_>>>()
+        })?;
+        Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, &self.return_dtype, true)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// An aggregation defined in Python that can be used as an [`AnonymousAgg`].
+pub struct PythonAggregation {
+    reduction: Box<dyn GroupedReduction>,
+    return_dtype: DataType,
+}
+
+impl PythonAggregation {
+    pub fn new(
+        init: Py<PyAny>,
+        update: Py<PyAny>,
+        merge: Py<PyAny>,
+        finalize: Py<PyAny>,
+        return_dtype: DataType,
+    ) -> Self {
+        let functions = Arc::new(PythonAggregationFunctions {
+            init,
+            update,
+            merge,
+            finalize,
+        });
+        let reduction = PythonGroupedReduction {
+            functions,
+            return_dtype: return_dtype.clone(),
+            states: Vec::new(),
+            evicted_states: Vec::new(),
+        };
+        Self {
+            reduction: Box::new(reduction),
+            return_dtype,
+        }
+    }
+}
+
+impl AnonymousAgg for PythonAggregation {
+    fn as_any(&self) -> &dyn Any {
+        // The engine retrieves the reduction by downcasting to it.
+        &self.reduction
+    }
+
+    fn get_field(&self, _input_schema: &Schema, fields: &[Field]) -> PolarsResult<Field> {
+        Ok(Field::new(
+            fields[0].name().clone(),
+            self.return_dtype.clone(),
+        ))
+    }
+}
@@ -1,3 +1,4 @@
+pub mod aggregation;
 pub mod lazy;
 pub mod series;
 
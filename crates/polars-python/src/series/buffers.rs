@@ -9,11 +9,12 @@
 //! Note that String Series have underlying `Utf8View` buffers, which
 //! currently cannot be represented as Series. Since the interchange protocol
 //! cannot handle these buffers anyway and expects bytes and offsets buffers,
-//! operations on String Series will convert from/to such buffers. This
-//! conversion requires data to be copied.
+//! operations on String Series will convert from/to such buffers. The bytes
+//! are shared if they are laid out contiguously, as is the case for strings
+//! imported from Arrow. Otherwise, this conversion requires data to be copied.
 
-use arrow::array::{Array, BooleanArray, PrimitiveArray, Utf8Array};
-use arrow::bitmap::Bitmap;
+use arrow::array::{Array, BooleanArray, PrimitiveArray, Utf8Array, Utf8ViewArray};
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::offset::OffsetsBuffer;
 use arrow::types::NativeType;
 use polars::prelude::*;
@@ -94,12 +95,19 @@ impl PySeries {
     }
 
     /// Return the underlying values, validity, and offsets buffers as Series.
-    fn _get_buffers(&self, py: Python) -> PyResult<(Self, Option<Self>, Option<Self>)> {
+    ///
+    /// Raises if `allow_copy` is `false` and the buffers can only be returned by copying data.
+    #[pyo3(signature = (allow_copy=true))]
+    fn _get_buffers(
+        &self,
+        py: Python,
+        allow_copy: bool,
+    ) -> PyResult<(Self, Option<Self>, Option<Self>)> {
         let s = &self.series.read();
         py.enter_polars(|| match s.dtype().to_physical() {
             dt if dt.is_primitive_numeric() => get_buffers_from_primitive(s),
             DataType::Boolean => get_buffers_from_primitive(s),
-            DataType::String => get_buffers_from_string(s),
+            DataType::String => get_buffers_from_string(s, allow_copy),
             dt => {
                 let msg = format!("`_get_buffers` not implemented for `dtype` {dt}");
                 Err(PyTypeError::new_err(msg))
@@ -132,16 +140,23 @@ fn get_buffers_from_primitive(
 
 /// The underlying buffers for `String` Series cannot be represented in this
 /// format. Instead, the buffers are converted to a values and offsets buffer.
-/// This copies data.
-fn get_buffers_from_string(s: &Series) -> PyResult<(PySeries, Option<PySeries>, Option<PySeries>)> {
-    // We cannot do this zero copy anyway, so rechunk first
+/// The values buffer shares the string data if it is contiguous, otherwise this
+/// copies data.
+fn get_buffers_from_string(
+    s: &Series,
+    allow_copy: bool,
+) -> PyResult<(PySeries, Option<PySeries>, Option<PySeries>)> {
+    // Rechunking only concatenates the views, the string data is not copied.
     let s = s.rechunk();
 
     let ca = s.str().map_err(PyPolarsErr::from)?;
     let arr_binview = ca.downcast_iter().next().unwrap();
 
-    // This is not zero-copy
-    let arr_utf8 = polars_compute::cast::utf8view_to_utf8(arr_binview);
+    let arr_utf8 = match contiguous_string_bytes(arr_binview) {
+        Some(arr_utf8) => arr_utf8,
+        None if allow_copy => polars_compute::cast::utf8view_to_utf8(arr_binview),
+        None => raise_err!("string buffers must be converted", ComputeError),
+    };
 
     let values = get_string_bytes(&arr_utf8)?;
     let validity = get_bitmap(&s);
@@ -150,12 +165,88 @@ fn get_buffers_from_string(s: &Series) -> PyResult<(PySeries, Option<PySeries>,
     Ok((values, validity, Some(offsets)))
 }
 
+/// Describe the strings by offsets into the single data buffer of the array, without copying the
+/// data.
+///
+/// This is only possible if the strings are stored in order and back-to-back, including the
+/// inlined ones, which is the layout of strings that were converted from an offsets-based array.
+fn contiguous_string_bytes(arr: &Utf8ViewArray) -> Option<Utf8Array<i64>> {
+    let [buffer] = &arr.data_buffers()[..] else {
+        return None;
+    };
+    let views = arr.views();
+
+    // The position of the first string is known from the first one that isn't inlined.
+    let (first, view) = views.iter().enumerate().find(|(_, v)| !v.is_inline())?;
+    let preceding = views[..first]
+        .iter()
+        .map(|v| v.length as usize)
+        .sum::<usize>();
+    let start = (view.offset as usize).checked_sub(preceding)?;
+
+    let mut offsets = Vec::with_capacity(views.len() + 1);
+    offsets.push(0i64);
+    let mut end = start;
+    for view in views.iter() {
+        let len = view.length as usize;
+        let is_next = if view.is_inline() {
+            buffer.get(end..end + len) == view.get_inlined_slice()
+        } else {
+            view.buffer_idx == 0 && view.offset as usize == end
+        };
+        if !is_next {
+            return None;
+        }
+        end += len;
+        offsets.push((end - start) as i64);
+    }
+
+    // SAFETY: The offsets are monotonically increasing and in bounds, and the bytes in between
+    // them are the valid UTF-8 strings of the array.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let values = buffer.clone().sliced(start..end);
+    let arr = unsafe {
+        Utf8Array::new_unchecked(
+            ArrowDataType::LargeUtf8,
+            offsets,
+            values,
+            arr.validity().cloned(),
+        )
+    };
+    Some(arr)
+}
+
+/// The interchange protocol describes a single offset for all buffers of a column, which is the
+/// bit offset of the values in their first byte for `Boolean` and zero otherwise. The validity
+/// bitmap is only copied if its own bit offset differs.
 fn get_bitmap(s: &Series) -> Option<PySeries> {
-    if s.null_count() > 0 {
-        Some(s.is_not_null().into_series().into())
-    } else {
-        None
+    if s.null_count() == 0 {
+        return None;
     }
+
+    let chunks = s
+        .chunks()
+        .iter()
+        .map(|arr| {
+            let offset = match arr.as_any().downcast_ref::<BooleanArray>() {
+                Some(arr) => arr.values().as_slice().1,
+                None => 0,
+            };
+            let validity = match arr.validity() {
+                Some(validity) if validity.as_slice().1 == offset => validity.clone(),
+                Some(validity) => {
+                    let mut aligned = MutableBitmap::with_capacity(offset + validity.len());
+                    aligned.extend_constant(offset, false);
+                    aligned.extend_from_bitmap(validity);
+                    Bitmap::from(aligned).sliced(offset, validity.len())
+                },
+                None => Bitmap::new_with_value(true, arr.len()),
+            };
+            BooleanArray::new(ArrowDataType::Boolean, validity, None).to_boxed()
+        })
+        .collect::<Vec<_>>();
+    let validity = Series::try_from((s.name().clone(), chunks)).unwrap();
+    Some(validity.into())
 }
 
 fn get_string_bytes(arr: &Utf8Array<i64>) -> PyResult<PySeries> {
@@ -7,6 +7,7 @@ use num_traits::AsPrimitive;
 use numpy::{Element, PyArray1, PyArrayMethods, PyUntypedArrayMethods};
 use polars::prelude::*;
 use polars_buffer::{Buffer, SharedStorage};
+use polars_core::with_match_physical_numeric_type;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 
@@ -142,6 +143,71 @@ impl PySeries {
             Ok(Series::from_array(name.into(), arr).into())
         }
     }
+
+    /// Construct a PySeries from an object implementing the buffer protocol without copying.
+    ///
+    /// The object is kept alive for as long as its memory is referenced.
+    #[staticmethod]
+    fn _import_buffer(
+        py: Python<'_>,
+        obj: &Bound<'_, PyAny>,
+        dtype: Wrap<DataType>,
+    ) -> PyResult<Self> {
+        let dtype = dtype.0;
+        if !dtype.is_primitive_numeric() {
+            let msg = format!("`_import_buffer` requires a numeric data type, got {dtype}");
+            return Err(PyTypeError::new_err(msg));
+        }
+
+        // The buffer protocol is not part of the limited API of all supported Python versions,
+        // so we let numpy acquire the buffer. `frombuffer` does not copy, and the array keeps
+        // the buffer exported (e.g. preventing a `bytearray` from being resized) while alive.
+        let bytes = py
+            .import("numpy")?
+            .call_method1("frombuffer", (obj, "uint8"))?
+            .cast_into::<PyArray1<u8>>()?;
+
+        let arr = with_match_physical_numeric_type!(dtype, |$T| {
+            buffer_to_arrow::<$T>(&bytes)?.to_boxed()
+        });
+        Ok(Series::from_arrow(PlSmallStr::EMPTY, arr).unwrap().into())
+    }
+}
+
+fn buffer_to_arrow<T: NativeType>(bytes: &Bound<PyArray1<u8>>) -> PyResult<PrimitiveArray<T>> {
+    let ro = bytes.readonly();
+    let vals = ro.as_slice()?;
+    if vals.is_empty() {
+        return Ok(PrimitiveArray::new_empty(T::PRIMITIVE.into()));
+    }
+    if !vals.len().is_multiple_of(size_of::<T>()) {
+        return Err(PyValueError::new_err(format!(
+            "buffer of {} bytes is not a multiple of the item size of {} bytes",
+            vals.len(),
+            size_of::<T>(),
+        )));
+    }
+    if !(vals.as_ptr() as usize).is_multiple_of(align_of::<T>()) {
+        return Err(PyValueError::new_err(format!(
+            "buffer is not aligned to {} bytes",
+            align_of::<T>(),
+        )));
+    }
+
+    // SAFETY: the pointer is aligned and valid for the length in bytes of the buffer.
+    let vals = unsafe {
+        std::slice::from_raw_parts(vals.as_ptr().cast::<T>(), vals.len() / size_of::<T>())
+    };
+    let owner = bytes.clone().unbind();
+    unsafe {
+        let storage = SharedStorage::from_slice_with_owner(vals, owner);
+        let buffer = Buffer::from_storage(storage);
+        Ok(PrimitiveArray::new_unchecked(
+            T::PRIMITIVE.into(),
+            buffer,
+            None,
+        ))
+    }
 }
 
 #[pymethods]
@@ -1,153 +1,37 @@
+use parking_lot::Mutex;
 use polars_core::prelude::*;
 use polars_ffi::version_0::SeriesExport;
 use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyList};
 
 use super::PySeries;
 use crate::error::PyPolarsErr;
 use crate::interop;
-use crate::interop::arrow::to_py::series_to_stream;
+use crate::interop::arrow::to_py::{array_to_capsules, series_to_stream};
 use crate::prelude::*;
+use crate::utils::BufferedValues;
 
 #[pymethods]
 impl PySeries {
     /// Convert this Series to a Python list.
     /// This operation copies data.
     pub fn to_list<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let series = &self.series.read();
-
-        fn to_list_recursive<'py>(py: Python<'py>, series: &Series) -> PyResult<Bound<'py, PyAny>> {
-            let pylist = match series.dtype() {
-                DataType::Boolean => PyList::new(py, series.bool().map_err(PyPolarsErr::from)?)?,
-                DataType::UInt8 => PyList::new(py, series.u8().map_err(PyPolarsErr::from)?)?,
-                DataType::UInt16 => PyList::new(py, series.u16().map_err(PyPolarsErr::from)?)?,
-                DataType::UInt32 => PyList::new(py, series.u32().map_err(PyPolarsErr::from)?)?,
-                DataType::UInt64 => PyList::new(py, series.u64().map_err(PyPolarsErr::from)?)?,
-                DataType::UInt128 => PyList::new(py, series.u128().map_err(PyPolarsErr::from)?)?,
-                DataType::Int8 => PyList::new(py, series.i8().map_err(PyPolarsErr::from)?)?,
-                DataType::Int16 => PyList::new(py, series.i16().map_err(PyPolarsErr::from)?)?,
-                DataType::Int32 => PyList::new(py, series.i32().map_err(PyPolarsErr::from)?)?,
-                DataType::Int64 => PyList::new(py, series.i64().map_err(PyPolarsErr::from)?)?,
-                DataType::Int128 => PyList::new(py, series.i128().map_err(PyPolarsErr::from)?)?,
-                DataType::Float16 => PyList::new(py, series.f16().map_err(PyPolarsErr::from)?)?,
-                DataType::Float32 => PyList::new(py, series.f32().map_err(PyPolarsErr::from)?)?,
-                DataType::Float64 => PyList::new(py, series.f64().map_err(PyPolarsErr::from)?)?,
-                DataType::Categorical(_, _) | DataType::Enum(_, _) => {
-                    with_match_categorical_physical_type!(series.dtype().cat_physical().unwrap(), |$C| {
-                        PyList::new(py, series.cat::<$C>().unwrap().iter_str())?
-                    })
-                },
-                #[cfg(feature = "object")]
-                DataType::Object(_) => {
-                    let v = PyList::empty(py);
-                    for i in 0..series.len() {
-                        let obj: Option<&ObjectValue> = series.get_object(i).map(|any| any.into());
-                        v.append(obj)?;
-                    }
-                    v
-                },
-                DataType::List(_) => {
-                    let v = PyList::empty(py);
-                    let ca = series.list().map_err(PyPolarsErr::from)?;
-                    for opt_s in ca.amortized_iter() {
-                        match opt_s {
-                            None => {
-                                v.append(py.None())?;
-                            },
-                            Some(s) => {
-                                let pylst = to_list_recursive(py, s.as_ref())?;
-                                v.append(pylst)?;
-                            },
-                        }
-                    }
-                    v
-                },
-                DataType::Array(_, _) => {
-                    let v = PyList::empty(py);
-                    let ca = series.array().map_err(PyPolarsErr::from)?;
-                    for opt_s in ca.amortized_iter() {
-                        match opt_s {
-                            None => {
-                                v.append(py.None())?;
-                            },
-                            Some(s) => {
-                                let pylst = to_list_recursive(py, s.as_ref())?;
-                                v.append(pylst)?;
-                            },
-                        }
-                    }
-                    v
-                },
-                DataType::Date => {
-                    let ca = series.date().map_err(PyPolarsErr::from)?;
-                    return Wrap(ca).into_bound_py_any(py);
-                },
-                DataType::Time => {
-                    let ca = series.time().map_err(PyPolarsErr::from)?;
-                    return Wrap(ca).into_bound_py_any(py);
-                },
-                DataType::Datetime(_, _) => {
-                    let ca = series.datetime().map_err(PyPolarsErr::from)?;
-                    return Wrap(ca).into_bound_py_any(py);
-                },
-                DataType::Decimal(_, _) => {
-                    let ca = series.decimal().map_err(PyPolarsErr::from)?;
-                    return Wrap(ca).into_bound_py_any(py);
-                },
-                DataType::String => {
-                    let ca = series.str().map_err(PyPolarsErr::from)?;
-                    return Wrap(ca).into_bound_py_any(py);
-                },
-                DataType::Struct(_) => {
-                    let ca = series.struct_().map_err(PyPolarsErr::from)?;
-                    return Wrap(ca).into_bound_py_any(py);
-                },
-                DataType::Duration(_) => {
-                    let ca = series.duration().map_err(PyPolarsErr::from)?;
-                    return Wrap(ca).into_bound_py_any(py);
-                },
-                DataType::Binary => {
-                    let ca = series.binary().map_err(PyPolarsErr::from)?;
-                    return Wrap(ca).into_bound_py_any(py);
-                },
-                DataType::Null => {
-                    let null: Option<u8> = None;
-                    let n = series.len();
-                    let iter = std::iter::repeat_n(null, n);
-                    use std::iter::RepeatN;
-                    struct NullIter {
-                        iter: RepeatN<Option<u8>>,
-                        n: usize,
-                    }
-                    impl Iterator for NullIter {
-                        type Item = Option<u8>;
-
-                        fn next(&mut self) -> Option<Self::Item> {
-                            self.iter.next()
-                        }
-                        fn size_hint(&self) -> (usize, Option<usize>) {
-                            (self.n, Some(self.n))
-                        }
-                    }
-                    impl ExactSizeIterator for NullIter {}
-
-                    PyList::new(py, NullIter { iter, n })?
-                },
-                DataType::Unknown(_) => {
-                    panic!("to_list not implemented for unknown")
-                },
-                DataType::BinaryOffset => {
-                    unreachable!()
-                },
-                DataType::Extension(_, _) => {
-                    return to_list_recursive(py, series.ext().unwrap().storage());
-                },
-            };
-            Ok(pylist.into_any())
-        }
+        series_to_list(py, &self.series.read())
+    }
 
-        to_list_recursive(py, series)
+    /// Iterate over the values of this Series as Python objects.
+    /// At most `buffer_size` values are converted at a time.
+    pub fn iter_py(&self, buffer_size: usize) -> PyResult<PySeriesIter> {
+        if buffer_size == 0 {
+            return Err(PyValueError::new_err("'buffer_size' must be positive"));
+        }
+        Ok(PySeriesIter {
+            series: self.series.read().clone(),
+            buffer_size,
+            state: Mutex::new(BufferedValues::default()),
+        })
     }
 
     /// Return the underlying Arrow array.
@@ -164,6 +48,46 @@ impl PySeries {
         )
     }
 
+    /// Return the underlying Arrow arrays, one per chunk, without rechunking.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_arrow_chunks(
+        &self,
+        py: Python<'_>,
+        compat_level: PyCompatLevel,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let pyarrow = py.import("pyarrow")?;
+
+        let s = self.series.read();
+        let field = s.field().to_arrow(compat_level.0);
+        (0..s.n_chunks())
+            .map(|i| {
+                interop::arrow::to_py::to_py_array(s.to_arrow(i, compat_level.0), &field, &pyarrow)
+            })
+            .collect()
+    }
+
+    #[allow(unused_variables)]
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Py<PyAny>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        // A single chunk is exported as is, multiple chunks are concatenated into a copy that is
+        // not kept alive by this Series.
+        let s = self.series.read();
+        let s = if s.n_chunks() > 1 {
+            s.rechunk()
+        } else {
+            s.clone()
+        };
+        array_to_capsules(
+            s.to_arrow(0, CompatLevel::newest()),
+            &s.field().to_arrow(CompatLevel::newest()),
+            py,
+        )
+    }
+
     #[allow(unused_variables)]
     #[pyo3(signature = (requested_schema=None))]
     fn __arrow_c_stream__<'py>(
@@ -174,10 +98,192 @@ impl PySeries {
         series_to_stream(&self.series.read(), py)
     }
 
-    pub fn _export(&self, _py: Python<'_>, location: usize) {
-        let export = polars_ffi::version_0::export_series(&self.series.read());
+    /// The `(major, minor)` version of the polars-ffi interface used by `_export` and `_import`.
+    #[staticmethod]
+    pub fn _ffi_version() -> (u16, u16) {
+        polars_ffi::get_version()
+    }
+
+    /// Export the series via polars-ffi.
+    ///
+    /// If the `ffi_version` the consumer was compiled against is given, the export is adapted to
+    /// what that version supports, or fails if it is incompatible.
+    #[pyo3(signature = (location, ffi_version=None))]
+    pub fn _export(
+        &self,
+        _py: Python<'_>,
+        location: usize,
+        ffi_version: Option<(u16, u16)>,
+    ) -> PyResult<()> {
+        let compat_level = match ffi_version {
+            Some((major, minor)) => polars_ffi::negotiate(major, minor)
+                .map_err(PyPolarsErr::from)?
+                .compat_level(),
+            None => CompatLevel::newest(),
+        };
+        let export =
+            polars_ffi::version_0::export_series_with_compat(&self.series.read(), compat_level);
         unsafe {
             (location as *mut SeriesExport).write(export);
         }
+        Ok(())
     }
 }
+
+/// Iterator over the values of a Series that converts at most `buffer_size`
+/// values to Python objects at a time.
+#[pyclass(frozen)]
+pub struct PySeriesIter {
+    series: Series,
+    buffer_size: usize,
+    state: Mutex<BufferedValues>,
+}
+
+#[pymethods]
+impl PySeriesIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        BufferedValues::next(&self.state, py, |offset| {
+            if offset >= self.series.len() {
+                return Ok(None);
+            }
+            let values = self.series.slice(offset as i64, self.buffer_size);
+            let list = series_to_list(py, &values)?.cast_into::<PyList>()?;
+            Ok(Some(list))
+        })
+    }
+}
+
+/// Convert a Series to a Python list, recursing into nested types.
+pub(crate) fn series_to_list<'py>(py: Python<'py>, series: &Series) -> PyResult<Bound<'py, PyAny>> {
+    let pylist = match series.dtype() {
+        DataType::Boolean => PyList::new(py, series.bool().map_err(PyPolarsErr::from)?)?,
+        DataType::UInt8 => PyList::new(py, series.u8().map_err(PyPolarsErr::from)?)?,
+        DataType::UInt16 => PyList::new(py, series.u16().map_err(PyPolarsErr::from)?)?,
+        DataType::UInt32 => PyList::new(py, series.u32().map_err(PyPolarsErr::from)?)?,
+        DataType::UInt64 => PyList::new(py, series.u64().map_err(PyPolarsErr::from)?)?,
+        DataType::UInt128 => PyList::new(py, series.u128().map_err(PyPolarsErr::from)?)?,
+        DataType::Int8 => PyList::new(py, series.i8().map_err(PyPolarsErr::from)?)?,
+        DataType::Int16 => PyList::new(py, series.i16().map_err(PyPolarsErr::from)?)?,
+        DataType::Int32 => PyList::new(py, series.i32().map_err(PyPolarsErr::from)?)?,
+        DataType::Int64 => PyList::new(py, series.i64().map_err(PyPolarsErr::from)?)?,
+        DataType::Int128 => PyList::new(py, series.i128().map_err(PyPolarsErr::from)?)?,
+        DataType::Float16 => PyList::new(py, series.f16().map_err(PyPolarsErr::from)?)?,
+        DataType::Float32 => PyList::new(py, series.f32().map_err(PyPolarsErr::from)?)?,
+        DataType::Float64 => PyList::new(py, series.f64().map_err(PyPolarsErr::from)?)?,
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+            with_match_categorical_physical_type!(series.dtype().cat_physical().unwrap(), |$C| {
+                PyList::new(py, series.cat::<$C>().unwrap().iter_str())?
+            })
+        },
+        #[cfg(feature = "object")]
+        DataType::Object(_) => {
+            let v = PyList::empty(py);
+            for i in 0..series.len() {
+                let obj: Option<&ObjectValue> = series.get_object(i).map(|any| any.into());
+                v.append(obj)?;
+            }
+            v
+        },
+        DataType::List(_) => {
+            let v = PyList::empty(py);
+            let ca = series.list().map_err(PyPolarsErr::from)?;
+            for opt_s in ca.amortized_iter() {
+                match opt_s {
+                    None => {
+                        v.append(py.None())?;
+                    },
+                    Some(s) => {
+                        let pylst = series_to_list(py, s.as_ref())?;
+                        v.append(pylst)?;
+                    },
+                }
+            }
+            v
+        },
+        DataType::Array(_, _) => {
+            let v = PyList::empty(py);
+            let ca = series.array().map_err(PyPolarsErr::from)?;
+            for opt_s in ca.amortized_iter() {
+                match opt_s {
+                    None => {
+                        v.append(py.None())?;
+                    },
+                    Some(s) => {
+                        let pylst = series_to_list(py, s.as_ref())?;
+                        v.append(pylst)?;
+                    },
+                }
+            }
+            v
+        },
+        DataType::Date => {
+            let ca = series.date().map_err(PyPolarsErr::from)?;
+            return Wrap(ca).into_bound_py_any(py);
+        },
+        DataType::Time => {
+            let ca = series.time().map_err(PyPolarsErr::from)?;
+            return Wrap(ca).into_bound_py_any(py);
+        },
+        DataType::Datetime(_, _) => {
+            let ca = series.datetime().map_err(PyPolarsErr::from)?;
+            return Wrap(ca).into_bound_py_any(py);
+        },
+        DataType::Decimal(_, _) => {
+            let ca = series.decimal().map_err(PyPolarsErr::from)?;
+            return Wrap(ca).into_bound_py_any(py);
+        },
+        DataType::String => {
+            let ca = series.str().map_err(PyPolarsErr::from)?;
+            return Wrap(ca).into_bound_py_any(py);
+        },
+        DataType::Struct(_) => {
+            let ca = series.struct_().map_err(PyPolarsErr::from)?;
+            return Wrap(ca).into_bound_py_any(py);
+        },
+        DataType::Duration(_) => {
+            let ca = series.duration().map_err(PyPolarsErr::from)?;
+            return Wrap(ca).into_bound_py_any(py);
+        },
+        DataType::Binary => {
+            let ca = series.binary().map_err(PyPolarsErr::from)?;
+            return Wrap(ca).into_bound_py_any(py);
+        },
+        DataType::Null => {
+            let null: Option<u8> = None;
+            let n = series.len();
+            let iter = std::iter::repeat_n(null, n);
+            use std::iter::RepeatN;
+            struct NullIter {
+                iter: RepeatN<Option<u8>>,
+                n: usize,
+            }
+            impl Iterator for NullIter {
+                type Item = Option<u8>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    self.iter.next()
+                }
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    (self.n, Some(self.n))
+                }
+            }
+            impl ExactSizeIterator for NullIter {}
+
+            PyList::new(py, NullIter { iter, n })?
+        },
+        DataType::Unknown(_) => {
+            panic!("to_list not implemented for unknown")
+        },
+        DataType::BinaryOffset => {
+            unreachable!()
+        },
+        DataType::Extension(_, _) => {
+            return series_to_list(py, series.ext().unwrap().storage());
+        },
+    };
+    Ok(pylist.into_any())
+}
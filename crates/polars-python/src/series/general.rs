@@ -1,3 +1,7 @@
+use std::collections::BTreeMap;
+
+use arrow::compute::aggregate::SeenBuffers;
+use arrow::datatypes::Metadata;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::series::IsSorted;
 use polars_core::utils::flatten::flatten_series;
@@ -20,6 +24,24 @@ impl PySeries {
         py.enter_polars_df(|| Ok(self.series.read().struct_()?.clone().unnest()))
     }
 
+    #[pyo3(signature = (schema, descending, nulls_last))]
+    fn row_decode(
+        &self,
+        py: Python<'_>,
+        schema: Wrap<Schema>,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+    ) -> PyResult<PyDataFrame> {
+        py.enter_polars_df(|| {
+            row_encode::decode_rows_ordered(
+                self.series.read().binary()?,
+                &schema.0,
+                &descending,
+                &nulls_last,
+            )
+        })
+    }
+
     fn struct_fields(&self) -> PyResult<Vec<String>> {
         let s = self.series.read();
         let ca = s.struct_().map_err(PyPolarsErr::from)?;
@@ -57,8 +79,13 @@ impl PySeries {
         Ok(self.clone())
     }
 
-    fn estimated_size(&self) -> usize {
-        self.series.read().estimated_size()
+    fn estimated_size(&self, deep: bool) -> usize {
+        let s = self.series.read();
+        if deep {
+            s.estimated_size_dedup(&mut SeenBuffers::default())
+        } else {
+            s.estimated_size()
+        }
     }
 
     #[cfg(feature = "object")]
@@ -175,6 +202,27 @@ impl PySeries {
         self.series.write().rename(name.into());
     }
 
+    fn metadata(&self) -> Option<BTreeMap<String, String>> {
+        let md = self.series.read().metadata()?;
+        Some(
+            md.iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    #[pyo3(signature = (metadata))]
+    fn with_metadata(&self, metadata: Option<BTreeMap<String, String>>) -> Self {
+        let metadata = metadata.map(|md| {
+            Arc::new(
+                md.into_iter()
+                    .map(|(k, v)| (k.into(), v.into()))
+                    .collect::<Metadata>(),
+            )
+        });
+        self.series.read().clone().with_metadata(metadata).into()
+    }
+
     fn dtype<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         Wrap(self.series.read().dtype().clone()).into_pyobject(py)
     }
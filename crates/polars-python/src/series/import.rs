@@ -1,14 +1,19 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
 use arrow::array::{Array, PrimitiveArray};
 use arrow::ffi;
 use arrow::ffi::{ArrowArray, ArrowArrayStream, ArrowArrayStreamReader, ArrowSchema};
 use polars::prelude::*;
+use polars_compute::cast::CastOptionsImpl;
 use polars_ffi::version_0::SeriesExport;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::pybacked::PyBackedBytes;
-use pyo3::types::{PyCapsule, PyTuple, PyType};
+use pyo3::pybacked::{PyBackedBytes, PyBackedStr};
+use pyo3::types::{PyCapsule, PyDict, PyTuple, PyType};
 
 use super::PySeries;
+use crate::conversion::Wrap;
 use crate::error::PyPolarsErr;
 
 /// Validate PyCapsule has provided name
@@ -89,6 +94,196 @@ pub(crate) fn import_schema_pycapsule(
     }
 }
 
+/// How the types of imported Arrow data are coerced.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ArrowCoercion {
+    /// Import dictionary-encoded data as its value type instead of as `Categorical`.
+    materialize_dictionary: bool,
+    /// Cast string and binary data to the large (`true`) or view (`false`) layout.
+    large_string: Option<bool>,
+    /// Cast `Datetime` and `Duration` data to this time unit.
+    time_unit: Option<TimeUnit>,
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for ArrowCoercion {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let dict = ob.cast::<PyDict>()?;
+        let materialize_dictionary = match dict.get_item("dictionary")? {
+            None => false,
+            Some(v) => match &*v.extract::<PyBackedStr>()? {
+                "categorical" => false,
+                "materialize" => true,
+                v => {
+                    return Err(PyValueError::new_err(format!(
+                        "`dictionary` must be one of {{'categorical', 'materialize'}}, got {v}",
+                    )));
+                },
+            },
+        };
+        let large_string = match dict.get_item("string")? {
+            None => None,
+            Some(v) => match &*v.extract::<PyBackedStr>()? {
+                "large_string" => Some(true),
+                "string_view" => Some(false),
+                v => {
+                    return Err(PyValueError::new_err(format!(
+                        "`string` must be one of {{'large_string', 'string_view'}}, got {v}",
+                    )));
+                },
+            },
+        };
+        let time_unit = dict
+            .get_item("time_unit")?
+            .map(|v| v.extract::<Option<Wrap<TimeUnit>>>())
+            .transpose()?
+            .flatten()
+            .map(|tu| tu.0);
+        Ok(Self {
+            materialize_dictionary,
+            large_string,
+            time_unit,
+        })
+    }
+}
+
+impl ArrowCoercion {
+    fn arrow_dtype(&self, dtype: &ArrowDataType) -> Option<ArrowDataType> {
+        match dtype {
+            ArrowDataType::Dictionary(_, values, _) if self.materialize_dictionary => {
+                Some(values.as_ref().clone())
+            },
+            ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View => self
+                .large_string
+                .map(|large| {
+                    if large {
+                        ArrowDataType::LargeUtf8
+                    } else {
+                        ArrowDataType::Utf8View
+                    }
+                })
+                .filter(|to| to != dtype),
+            ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView => self
+                .large_string
+                .map(|large| {
+                    if large {
+                        ArrowDataType::LargeBinary
+                    } else {
+                        ArrowDataType::BinaryView
+                    }
+                })
+                .filter(|to| to != dtype),
+            _ => None,
+        }
+    }
+
+    fn dtype(&self, dtype: &DataType) -> Option<DataType> {
+        match (dtype, self.time_unit) {
+            (DataType::Datetime(tu, tz), Some(unit)) if *tu != unit => {
+                Some(DataType::Datetime(unit, tz.clone()))
+            },
+            (DataType::Duration(tu), Some(unit)) if *tu != unit => Some(DataType::Duration(unit)),
+            _ => None,
+        }
+    }
+}
+
+/// The coercions applied when importing Arrow data, with per-column overrides that apply to
+/// the fields of imported struct data (i.e. the columns of a frame).
+#[derive(Default)]
+pub(crate) struct ArrowImportOptions {
+    coercion: ArrowCoercion,
+    overrides: HashMap<String, ArrowCoercion>,
+}
+
+impl ArrowImportOptions {
+    fn new(
+        coercion: Option<ArrowCoercion>,
+        overrides: Option<HashMap<String, ArrowCoercion>>,
+    ) -> Self {
+        Self {
+            coercion: coercion.unwrap_or_default(),
+            overrides: overrides.unwrap_or_default(),
+        }
+    }
+
+    fn column(&self, name: &str) -> &ArrowCoercion {
+        self.overrides.get(name).unwrap_or(&self.coercion)
+    }
+
+    /// The Arrow type that arrays are cast to before they are imported.
+    fn arrow_dtype(&self, dtype: &ArrowDataType) -> Option<ArrowDataType> {
+        let ArrowDataType::Struct(fields) = dtype else {
+            return self.coercion.arrow_dtype(dtype);
+        };
+        let mut changed = false;
+        let fields = fields
+            .iter()
+            .map(|f| {
+                let mut f = f.clone();
+                if let Some(dtype) = self.column(&f.name).arrow_dtype(&f.dtype) {
+                    f.dtype = dtype;
+                    changed = true;
+                }
+                f
+            })
+            .collect();
+        changed.then_some(ArrowDataType::Struct(fields))
+    }
+
+    /// The type that the imported Series is cast to.
+    fn dtype(&self, dtype: &DataType) -> Option<DataType> {
+        let DataType::Struct(fields) = dtype else {
+            return self.coercion.dtype(dtype);
+        };
+        let mut changed = false;
+        let fields = fields
+            .iter()
+            .map(|f| {
+                let mut f = f.clone();
+                if let Some(dtype) = self.column(&f.name).dtype(&f.dtype) {
+                    f.dtype = dtype;
+                    changed = true;
+                }
+                f
+            })
+            .collect();
+        changed.then_some(DataType::Struct(fields))
+    }
+
+    /// Create a Series from the imported arrays, coercing their types.
+    fn import(&self, field: &ArrowField, arrays: Vec<Box<dyn Array>>) -> PyResult<Series> {
+        let (field, arrays) = match self.arrow_dtype(field.dtype()) {
+            Some(dtype) => {
+                let arrays = arrays
+                    .iter()
+                    .map(|arr| {
+                        polars_compute::cast::cast(arr.as_ref(), &dtype, CastOptionsImpl::default())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+                    .map_err(PyPolarsErr::from)?;
+                let mut field = field.clone();
+                field.dtype = dtype;
+                (Cow::Owned(field), arrays)
+            },
+            None => (Cow::Borrowed(field), arrays),
+        };
+
+        // Series::try_from fails for an empty vec of chunks
+        let s = if arrays.is_empty() {
+            let polars_dt = DataType::from_arrow_field(&field);
+            Series::new_empty(field.name.clone(), &polars_dt)
+        } else {
+            Series::try_from((field.as_ref(), arrays)).map_err(PyPolarsErr::from)?
+        };
+        match self.dtype(s.dtype()) {
+            Some(dtype) => Ok(s.cast(&dtype).map_err(PyPolarsErr::from)?),
+            None => Ok(s),
+        }
+    }
+}
+
 /// Import `__arrow_c_stream__` across Python boundary.
 fn call_arrow_c_stream<'py>(ob: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyCapsule>> {
     if !ob.hasattr("__arrow_c_stream__")? {
@@ -101,7 +296,10 @@ fn call_arrow_c_stream<'py>(ob: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyCap
     Ok(capsule)
 }
 
-pub(crate) fn import_stream_pycapsule(capsule: &Bound<PyCapsule>) -> PyResult<PySeries> {
+pub(crate) fn import_stream_pycapsule(
+    capsule: &Bound<PyCapsule>,
+    options: &ArrowImportOptions,
+) -> PyResult<PySeries> {
     validate_pycapsule_name(capsule, "arrow_array_stream")?;
 
     // # Safety
@@ -124,29 +322,37 @@ pub(crate) fn import_stream_pycapsule(capsule: &Bound<PyCapsule>) -> PyResult<Py
         produced_arrays.push(array.map_err(PyPolarsErr::from)?);
     }
 
-    // Series::try_from fails for an empty vec of chunks
-    let s = if produced_arrays.is_empty() {
-        let polars_dt = DataType::from_arrow_field(stream.field());
-        Series::new_empty(stream.field().name.clone(), &polars_dt)
-    } else {
-        Series::try_from((stream.field(), produced_arrays)).map_err(PyPolarsErr::from)?
-    };
+    let s = options.import(stream.field(), produced_arrays)?;
     Ok(PySeries::new(s))
 }
 #[pymethods]
 impl PySeries {
     #[classmethod]
-    pub fn from_arrow_c_array(_cls: &Bound<PyType>, ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+    #[pyo3(signature = (ob, coerce=None, coerce_overrides=None))]
+    pub fn from_arrow_c_array(
+        _cls: &Bound<PyType>,
+        ob: &Bound<'_, PyAny>,
+        coerce: Option<ArrowCoercion>,
+        coerce_overrides: Option<HashMap<String, ArrowCoercion>>,
+    ) -> PyResult<Self> {
+        let options = ArrowImportOptions::new(coerce, coerce_overrides);
         let (schema_capsule, array_capsule) = call_arrow_c_array(ob)?;
         let (field, array) = import_array_pycapsules(&schema_capsule, &array_capsule)?;
-        let s = Series::try_from((&field, array)).unwrap();
+        let s = options.import(&field, vec![array])?;
         Ok(PySeries::new(s))
     }
 
     #[classmethod]
-    pub fn from_arrow_c_stream(_cls: &Bound<PyType>, ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+    #[pyo3(signature = (ob, coerce=None, coerce_overrides=None))]
+    pub fn from_arrow_c_stream(
+        _cls: &Bound<PyType>,
+        ob: &Bound<'_, PyAny>,
+        coerce: Option<ArrowCoercion>,
+        coerce_overrides: Option<HashMap<String, ArrowCoercion>>,
+    ) -> PyResult<Self> {
+        let options = ArrowImportOptions::new(coerce, coerce_overrides);
         let capsule = call_arrow_c_stream(ob)?;
-        import_stream_pycapsule(&capsule)
+        import_stream_pycapsule(&capsule, &options)
     }
 
     #[classmethod]
@@ -154,7 +360,15 @@ impl PySeries {
     /// Takes ownership of the [`SeriesExport`] at [`location`]
     /// # Safety
     /// [`location`] should be the address of an allocated and initialized [`SeriesExport`]
-    pub unsafe fn _import(_cls: &Bound<PyType>, location: usize) -> PyResult<Self> {
+    #[pyo3(signature = (location, ffi_version=None))]
+    pub unsafe fn _import(
+        _cls: &Bound<PyType>,
+        location: usize,
+        ffi_version: Option<(u16, u16)>,
+    ) -> PyResult<Self> {
+        if let Some((major, minor)) = ffi_version {
+            polars_ffi::negotiate(major, minor).map_err(PyPolarsErr::from)?;
+        }
         let location = location as *mut SeriesExport;
 
         // # Safety
@@ -1,6 +1,10 @@
+use polars_core::POOL;
+use polars_core::chunked_array::ops::arity::unary_elementwise_values;
+use polars_core::utils::split;
 use pyo3::Python;
 use pyo3::prelude::*;
 use pyo3::types::{PyNone, PyTuple};
+use rayon::prelude::*;
 
 use super::PySeries;
 use crate::error::PyPolarsErr;
@@ -12,12 +16,13 @@ use crate::{apply_all_polars_dtypes, raise_err};
 
 #[pymethods]
 impl PySeries {
-    #[pyo3(signature = (function, return_dtype, skip_nulls))]
+    #[pyo3(signature = (function, return_dtype, skip_nulls, cache_objects=false))]
     fn map_elements(
         &self,
         function: &Bound<PyAny>,
         return_dtype: Option<Wrap<DataType>>,
         skip_nulls: bool,
+        cache_objects: bool,
     ) -> PyResult<PySeries> {
         let series = self.series.read().clone(); // Clone so we don't deadlock on re-entrance.
         let series = series.to_storage();
@@ -36,6 +41,20 @@ impl PySeries {
 
         let return_dtype = return_dtype.map(|dt| dt.0);
 
+        #[cfg(feature = "object")]
+        if cache_objects && matches!(series.dtype(), DataType::Object(_)) {
+            let ca = series
+                .as_any()
+                .downcast_ref::<ObjectChunked<ObjectValue>>()
+                .unwrap();
+            return Python::attach(|py| {
+                call_and_collect_objects_cached(py, ca, function, return_dtype.as_ref(), skip_nulls)
+            })
+            .map(PySeries::from);
+        }
+        #[cfg(not(feature = "object"))]
+        let _ = cache_objects;
+
         Python::attach(|py| {
             let s = match &return_dtype {
                 #[cfg(feature = "object")]
@@ -65,6 +84,81 @@ impl PySeries {
             s.map(PySeries::from)
         })
     }
+
+    /// Map a native C function `return_dtype (*)(arg_dtype)` over the values, keeping nulls.
+    ///
+    /// The function is called from the thread pool without holding the GIL.
+    ///
+    /// # Safety
+    /// `address` must point to a thread-safe function with that signature, that stays alive
+    /// during the call.
+    unsafe fn _map_elements_cfunc(
+        &self,
+        py: Python<'_>,
+        address: usize,
+        arg_dtype: Wrap<DataType>,
+        return_dtype: Wrap<DataType>,
+    ) -> PyResult<Self> {
+        let series = self.series.read().clone();
+        py.enter_polars_series(|| {
+            let series = series.strict_cast(&arg_dtype.0)?;
+            unsafe {
+                match series.dtype() {
+                    DataType::Int32 => map_cfunc_to(series.i32()?, address, &return_dtype.0),
+                    DataType::Int64 => map_cfunc_to(series.i64()?, address, &return_dtype.0),
+                    DataType::UInt32 => map_cfunc_to(series.u32()?, address, &return_dtype.0),
+                    DataType::UInt64 => map_cfunc_to(series.u64()?, address, &return_dtype.0),
+                    DataType::Float32 => map_cfunc_to(series.f32()?, address, &return_dtype.0),
+                    DataType::Float64 => map_cfunc_to(series.f64()?, address, &return_dtype.0),
+                    dt => polars_bail!(InvalidOperation: "unsupported argument type for a native function: {}", dt),
+                }
+            }
+        })
+    }
+}
+
+/// # Safety
+/// See [`PySeries::_map_elements_cfunc`].
+unsafe fn map_cfunc_to<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    address: usize,
+    return_dtype: &DataType,
+) -> PolarsResult<Series> {
+    let out = unsafe {
+        match return_dtype {
+            DataType::Int32 => map_cfunc::<T, Int32Type>(ca, address).into_series(),
+            DataType::Int64 => map_cfunc::<T, Int64Type>(ca, address).into_series(),
+            DataType::UInt32 => map_cfunc::<T, UInt32Type>(ca, address).into_series(),
+            DataType::UInt64 => map_cfunc::<T, UInt64Type>(ca, address).into_series(),
+            DataType::Float32 => map_cfunc::<T, Float32Type>(ca, address).into_series(),
+            DataType::Float64 => map_cfunc::<T, Float64Type>(ca, address).into_series(),
+            dt => {
+                polars_bail!(InvalidOperation: "unsupported return type for a native function: {}", dt)
+            },
+        }
+    };
+    Ok(out)
+}
+
+/// # Safety
+/// See [`PySeries::_map_elements_cfunc`].
+unsafe fn map_cfunc<T: PolarsNumericType, R: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    address: usize,
+) -> ChunkedArray<R> {
+    let f = unsafe { std::mem::transmute::<usize, extern "C" fn(T::Native) -> R::Native>(address) };
+
+    let parts = split(ca, POOL.current_num_threads());
+    let parts = POOL.install(|| {
+        parts
+            .par_iter()
+            .map(|ca| unary_elementwise_values::<T, R, _>(ca, f))
+            .collect::<Vec<_>>()
+    });
+    ChunkedArray::from_chunk_iter(
+        ca.name().clone(),
+        parts.into_iter().flat_map(|ca| ca.downcast_into_iter()),
+    )
 }
 
 #[cfg(feature = "object")]
@@ -99,3 +193,95 @@ where
     }
     Ok(series_from_objects(py, name, objects))
 }
+
+/// Map `lambda` over an Object column, calling it only once per distinct input object.
+///
+/// Inputs are keyed by identity, so repeated references to the same Python object reuse
+/// both the function output and its conversion to the output dtype.
+#[cfg(feature = "object")]
+fn call_and_collect_objects_cached<'py>(
+    py: Python<'py>,
+    ca: &ObjectChunked<ObjectValue>,
+    lambda: &Bound<'py, PyAny>,
+    return_dtype: Option<&DataType>,
+    skip_nulls: bool,
+) -> PyResult<Series> {
+    let name = ca.name().clone();
+    match return_dtype {
+        Some(DataType::Object(_)) => {
+            let objects = call_cached(
+                py,
+                ca,
+                lambda,
+                skip_nulls,
+                || ObjectValue {
+                    inner: PyNone::get(py).to_owned().unbind().into_any(),
+                },
+                |out| {
+                    Ok(ObjectValue {
+                        inner: out.unbind(),
+                    })
+                },
+            )?;
+            Ok(series_from_objects(py, name, objects))
+        },
+        _ => {
+            let avs = call_cached(
+                py,
+                ca,
+                lambda,
+                skip_nulls,
+                || AnyValue::Null,
+                |out| {
+                    let av: Option<Wrap<AnyValue>> = if out.is_none() {
+                        None
+                    } else {
+                        Some(out.extract()?)
+                    };
+                    Ok(av.map(|w| w.0).unwrap_or(AnyValue::Null))
+                },
+            )?;
+            let s = match return_dtype {
+                Some(dtype) => Series::from_any_values_and_dtype(name, &avs, dtype, true),
+                None => Series::from_any_values(name, &avs, true),
+            };
+            Ok(s.map_err(PyPolarsErr::from)?)
+        },
+    }
+}
+
+#[cfg(feature = "object")]
+fn call_cached<'py, R: Clone>(
+    py: Python<'py>,
+    ca: &ObjectChunked<ObjectValue>,
+    lambda: &Bound<'py, PyAny>,
+    skip_nulls: bool,
+    null: impl Fn() -> R,
+    mut convert: impl FnMut(Bound<'py, PyAny>) -> PyResult<R>,
+) -> PyResult<Vec<R>> {
+    // The input column keeps every object alive, so their addresses are stable keys.
+    let mut cache = PlHashMap::<usize, R>::new();
+    let mut out = Vec::with_capacity(ca.len());
+    for opt_val in ca.into_iter() {
+        let key = match opt_val {
+            None if skip_nulls => {
+                out.push(null());
+                continue;
+            },
+            None => 0,
+            Some(val) => val.inner.as_ptr() as usize,
+        };
+        if let Some(cached) = cache.get(&key) {
+            out.push(cached.clone());
+            continue;
+        }
+        let arg = match opt_val {
+            None => PyTuple::new(py, [PyNone::get(py)])?,
+            Some(val) => PyTuple::new(py, [val])?,
+        };
+        let value = convert(lambda.call1(arg)?)?;
+        cache.insert(key, value.clone());
+        out.push(value);
+    }
+    Ok(out)
+}
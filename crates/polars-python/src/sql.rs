@@ -1,9 +1,17 @@
 use parking_lot::RwLock;
-use polars::sql::{SQLContext, extract_table_identifiers};
+use polars::prelude::{AnyValue, DataType, DataTypeSelector, Expr, PolarsResult, Scalar};
+use polars::sql::function_registry::SQLFunctionSignature;
+use polars::sql::{SQLContext, SQLParams, extract_table_identifiers};
 use pyo3::prelude::*;
 
-use crate::PyLazyFrame;
 use crate::error::PyPolarsErr;
+use crate::prelude::Wrap;
+use crate::{PyExpr, PyLazyFrame};
+
+fn to_scalar(value: Wrap<AnyValue<'static>>) -> Scalar {
+    let av = value.0;
+    Scalar::new(av.dtype(), av)
+}
 
 #[pyclass(frozen, skip_from_py_object)]
 #[repr(transparent)]
@@ -28,10 +36,15 @@ impl Clone for PySQLContext {
 impl PySQLContext {
     #[staticmethod]
     #[allow(clippy::new_without_default)]
-    pub fn new() -> PySQLContext {
-        PySQLContext {
-            context: RwLock::new(SQLContext::new()),
+    #[pyo3(signature = (dialect=None))]
+    pub fn new(dialect: Option<&str>) -> PyResult<PySQLContext> {
+        let mut context = SQLContext::new();
+        if let Some(dialect) = dialect {
+            context = context.with_dialect(dialect.parse().map_err(PyPolarsErr::from)?);
         }
+        Ok(PySQLContext {
+            context: RwLock::new(context),
+        })
     }
 
     /// Execute a SQL query in the current SQLContext.
@@ -44,6 +57,65 @@ impl PySQLContext {
             .into())
     }
 
+    /// Execute a parameterized SQL query in the current SQLContext.
+    #[pyo3(signature = (query, positional, named))]
+    pub fn execute_with_params(
+        &self,
+        query: &str,
+        positional: Vec<Wrap<AnyValue<'static>>>,
+        named: Vec<(String, Wrap<AnyValue<'static>>)>,
+    ) -> PyResult<PyLazyFrame> {
+        let mut params = SQLParams::from_positional(positional.into_iter().map(to_scalar));
+        for (name, value) in named {
+            params.insert(name, to_scalar(value));
+        }
+        Ok(self
+            .context
+            .write()
+            .execute_with_params(query, params)
+            .map_err(PyPolarsErr::from)?
+            .into())
+    }
+
+    /// Register a Python function (translating argument expressions into a result
+    /// expression) that can be called by name from SQL.
+    #[pyo3(signature = (name, function, arg_types))]
+    pub fn register_function(
+        &self,
+        name: &str,
+        function: Py<PyAny>,
+        arg_types: Option<Vec<Option<Wrap<DataType>>>>,
+    ) -> PyResult<()> {
+        let to_selector = |dtype: Option<Wrap<DataType>>| match dtype {
+            Some(dtype) => DataTypeSelector::AnyOf([dtype.0].into()),
+            None => DataTypeSelector::Wildcard,
+        };
+        let signature = match arg_types {
+            Some(arg_types) => {
+                SQLFunctionSignature::exact(arg_types.into_iter().map(to_selector).collect())
+            },
+            None => SQLFunctionSignature::variadic(vec![], DataTypeSelector::Wildcard),
+        };
+        let function = move |args: Vec<Expr>| -> PolarsResult<Expr> {
+            Python::attach(|py| {
+                let args: Vec<PyExpr> = args.into_iter().map(PyExpr::from).collect();
+                let out = function.call1(py, (args,))?;
+                let out = out.bind(py).extract::<PyExpr>().map_err(PyErr::from)?;
+                Ok(out.inner)
+            })
+        };
+        self.context
+            .write()
+            .register_function(name, signature, function)
+            .map_err(PyPolarsErr::from)?;
+        Ok(())
+    }
+
+    /// Unregister a function from the current SQLContext.
+    pub fn unregister_function(&self, name: &str) {
+        self.context.write().unregister_function(name)
+    }
+
     /// Get a list of table names registered in the current SQLContext.
     pub fn get_tables(&self) -> PyResult<Vec<String>> {
         Ok(self.context.read().get_tables())
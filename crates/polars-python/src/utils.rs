@@ -1,12 +1,14 @@
 use std::panic::AssertUnwindSafe;
 
+use parking_lot::Mutex;
 use polars::frame::DataFrame;
 use polars::series::IntoSeries;
 use polars_error::PolarsResult;
 use polars_error::signals::{KeyboardInterrupt, catch_keyboard_interrupt};
 use pyo3::exceptions::PyKeyboardInterrupt;
 use pyo3::marker::Ungil;
-use pyo3::{PyErr, PyResult, Python};
+use pyo3::prelude::*;
+use pyo3::types::PyList;
 
 use crate::dataframe::PyDataFrame;
 use crate::error::PyPolarsErr;
@@ -140,3 +142,63 @@ impl EnterPolarsExt for Python<'_> {
         }
     }
 }
+
+/// The values of a Python iterator that are converted, but not yet returned.
+#[derive(Default)]
+pub(crate) struct BufferedValues {
+    /// The number of rows that are converted.
+    pub(crate) offset: usize,
+    pub(crate) buffer: Option<Py<PyList>>,
+    pub(crate) buffer_idx: usize,
+}
+
+impl BufferedValues {
+    /// Return the next value, refilling the buffer with `fill(offset)` when it is exhausted.
+    ///
+    /// The lock is not held while filling, as converting values may release the GIL, during which
+    /// another thread could be waiting for the lock while holding the GIL.
+    pub(crate) fn next<'py, F>(
+        state: &Mutex<Self>,
+        py: Python<'py>,
+        fill: F,
+    ) -> PyResult<Option<Py<PyAny>>>
+    where
+        F: Fn(usize) -> PyResult<Option<Bound<'py, PyList>>>,
+    {
+        loop {
+            let offset = {
+                let mut state = state.lock();
+                if let Some(value) = state.pop(py)? {
+                    return Ok(Some(value));
+                }
+                state.offset
+            };
+
+            let Some(buffer) = fill(offset)? else {
+                return Ok(None);
+            };
+
+            // Another thread may have refilled the buffer in the meantime, in which case its
+            // values are returned first and ours are discarded.
+            let mut state = state.lock();
+            if state.offset == offset {
+                state.offset += buffer.len();
+                state.buffer = Some(buffer.unbind());
+                state.buffer_idx = 0;
+            }
+        }
+    }
+
+    fn pop(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        let Some(buffer) = &self.buffer else {
+            return Ok(None);
+        };
+        let buffer = buffer.bind(py);
+        if self.buffer_idx >= buffer.len() {
+            return Ok(None);
+        }
+        let value = buffer.get_item(self.buffer_idx)?;
+        self.buffer_idx += 1;
+        Ok(Some(value.unbind()))
+    }
+}
@@ -1,4 +1,6 @@
+use std::borrow::Cow;
 use std::ops::Deref;
+use std::str::FromStr;
 use std::sync::RwLock;
 
 use polars_core::frame::row::Row;
@@ -9,25 +11,40 @@ use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::aliases::{PlHashSet, PlIndexSet};
 use polars_utils::format_pl_smallstr;
+use polars_utils::pl_path::PlRefPath;
+use serde::Serialize;
+use sqlparser::ast::helpers::stmt_create_table::CreateTableBuilder;
 use sqlparser::ast::{
-    BinaryOperator as SQLBinaryOperator, CreateTable, CreateTableLikeKind, Delete, Distinct,
-    ExcludeSelectItem, Expr as SQLExpr, Fetch, FromTable, FunctionArg, GroupByExpr, Ident,
+    AnalyzeFormatKind, BinaryOperator as SQLBinaryOperator, CreateTable, CreateTableLikeKind,
+    Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, Fetch, FileFormat, FromTable,
+    FunctionArg, FunctionArgExpr, GroupByExpr, HiveFormat, HiveIOFormat, Ident, Insert,
     JoinConstraint, JoinOperator, LimitClause, NamedWindowDefinition, NamedWindowExpr, ObjectName,
     ObjectType, OrderBy, OrderByKind, Query, RenameSelectItem, Select, SelectFlavor, SelectItem,
     SelectItemQualifiedWildcardKind, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias,
-    TableFactor, TableWithJoins, Truncate, UnaryOperator as SQLUnaryOperator, Value as SQLValue,
-    ValueWithSpan, Values, Visit, WildcardAdditionalOptions, WindowSpec,
+    TableFactor, TableObject, TableSample, TableSampleKind, TableSampleUnit, TableWithJoins,
+    Truncate, UnaryOperator as SQLUnaryOperator, Value as SQLValue, ValueWithSpan, Values, Visit,
+    WildcardAdditionalOptions, WindowSpec,
 };
-use sqlparser::dialect::GenericDialect;
-use sqlparser::parser::{Parser, ParserOptions};
+#[cfg(feature = "pivot")]
+use sqlparser::ast::{ExprWithAlias, NullInclusion, PivotValueSource};
+use sqlparser::dialect::{
+    BigQueryDialect, Dialect, GenericDialect, MySqlDialect, PostgreSqlDialect,
+};
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::{Parser, ParserError, ParserOptions};
+use sqlparser::tokenizer::Token;
 
-use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::function_registry::{
+    DefaultFunctionRegistry, FunctionRegistry, SQLFunctionImpl, SQLFunctionSignature,
+};
+use crate::keywords::all_functions;
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
 use crate::sql_visitors::{
-    QualifyExpression, TableIdentifierCollector, check_for_ambiguous_column_refs,
-    expr_has_window_functions, expr_refers_to_table,
+    QualifyExpression, TableIdentifierCollector, bind_placeholders,
+    check_for_ambiguous_column_refs, expr_has_window_functions, expr_refers_to_table,
+    normalize_dialect,
 };
 use crate::table_functions::PolarsTableFunctions;
 use crate::types::map_sql_dtype_to_polars;
@@ -140,11 +157,158 @@ fn disambiguate_projection_cols(
     Ok(result)
 }
 
+/// Values bound to the placeholders of a parameterized SQL query.
+///
+/// Positional values are referenced as `$1`, `$2`, ... (or `?`, which takes the next
+/// positional value), while named values are referenced as `:name` or `$name`.
+#[derive(Clone, Debug, Default)]
+pub struct SQLParams {
+    positional: Vec<Scalar>,
+    named: PlHashMap<String, Scalar>,
+}
+
+impl SQLParams {
+    /// Create a set of positional parameters.
+    pub fn from_positional(values: impl IntoIterator<Item = Scalar>) -> Self {
+        Self {
+            positional: values.into_iter().collect(),
+            named: Default::default(),
+        }
+    }
+
+    /// Create a set of named parameters.
+    pub fn from_named<S: Into<String>>(values: impl IntoIterator<Item = (S, Scalar)>) -> Self {
+        Self {
+            positional: Default::default(),
+            named: values.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+        }
+    }
+
+    /// Append a positional parameter.
+    pub fn push(&mut self, value: Scalar) {
+        self.positional.push(value);
+    }
+
+    /// Add (or replace) a named parameter.
+    pub fn insert(&mut self, name: impl Into<String>, value: Scalar) {
+        self.named.insert(name.into(), value);
+    }
+
+    /// Returns true if no parameters are set.
+    pub fn is_empty(&self) -> bool {
+        self.positional.is_empty() && self.named.is_empty()
+    }
+
+    /// Get the positional parameter at the given (zero-based) index.
+    pub(crate) fn get_positional(&self, idx: usize) -> Option<&Scalar> {
+        self.positional.get(idx)
+    }
+
+    /// Get the named parameter with the given name.
+    pub(crate) fn get_named(&self, name: &str) -> Option<&Scalar> {
+        self.named.get(name)
+    }
+}
+
+/// The SQL dialect used to parse and interpret queries.
+///
+/// The generic dialect accepts a permissive superset of the common SQL syntaxes; the
+/// others parse queries as the named database does, and adjust:
+/// * identifier quoting (backticks for MySQL and BigQuery, where double quotes delimit
+///   string literals, and double quotes for PostgreSQL).
+/// * the `||` operator (logical OR for MySQL, string concatenation otherwise).
+/// * division (`/` always returns a float for MySQL and BigQuery, while integer
+///   division truncates for PostgreSQL and the generic dialect).
+/// * function-name aliases (such as `UCASE` for MySQL, or `REGEXP_CONTAINS` for BigQuery).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SQLDialect {
+    /// Permissive dialect, accepting a superset of the common SQL syntaxes.
+    #[default]
+    Generic,
+    /// PostgreSQL dialect.
+    PostgreSQL,
+    /// MySQL dialect.
+    MySQL,
+    /// BigQuery (GoogleSQL) dialect.
+    BigQuery,
+}
+
+impl SQLDialect {
+    fn parser_dialect(&self) -> Box<dyn Dialect> {
+        match self {
+            Self::Generic => Box::new(GenericDialect),
+            Self::PostgreSQL => Box::new(PostgreSqlDialect {}),
+            Self::MySQL => Box::new(MySqlDialect {}),
+            Self::BigQuery => Box::new(BigQueryDialect),
+        }
+    }
+
+    /// Returns true if `/` is true (float) division, even for integer operands.
+    pub(crate) fn has_true_division(&self) -> bool {
+        matches!(self, Self::MySQL | Self::BigQuery)
+    }
+
+    /// Returns true if double-quoted values are string literals (not identifiers).
+    pub(crate) fn has_double_quoted_strings(&self) -> bool {
+        matches!(self, Self::MySQL | Self::BigQuery)
+    }
+
+    /// Returns true if `||` is the logical OR operator (not string concatenation).
+    pub(crate) fn has_logical_pipes(&self) -> bool {
+        matches!(self, Self::MySQL)
+    }
+
+    /// Get the name of the (equivalent) Polars SQL function for a dialect-specific
+    /// function name, if there is one.
+    pub(crate) fn function_alias(&self, name: &str) -> Option<&'static str> {
+        let alias = match (self, name) {
+            (Self::MySQL, "ucase") => "upper",
+            (Self::MySQL, "lcase") => "lower",
+            (Self::MySQL, "mid") => "substr",
+            (Self::MySQL, "std") => "stddev",
+            (Self::MySQL | Self::BigQuery, "instr") => "strpos",
+            (Self::BigQuery, "regexp_contains") => "regexp_like",
+            (Self::BigQuery, "byte_length") => "octet_length",
+            _ => return None,
+        };
+        Some(alias)
+    }
+}
+
+impl FromStr for SQLDialect {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "generic" => Self::Generic,
+            "postgres" | "postgresql" => Self::PostgreSQL,
+            "mysql" => Self::MySQL,
+            "bigquery" => Self::BigQuery,
+            _ => polars_bail!(
+                InvalidOperation: "unsupported SQL dialect '{}'; expected one of 'generic', 'postgresql', 'mysql', or 'bigquery'", s
+            ),
+        })
+    }
+}
+
+/// Check if a relation is lateral, eg: it refers to columns of the relation(s)
+/// that precede it (`UNNEST(col)`, `LATERAL UNNEST(col)`, `LATERAL (<query>)`).
+fn is_lateral_relation(relation: &TableFactor) -> bool {
+    match relation {
+        TableFactor::UNNEST { array_exprs, .. } => {
+            array_exprs.iter().any(|e| !matches!(e, SQLExpr::Array(_)))
+        },
+        TableFactor::Function { lateral, .. } | TableFactor::Derived { lateral, .. } => *lateral,
+        _ => false,
+    }
+}
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
     pub(crate) table_map: Arc<RwLock<PlHashMap<String, LazyFrame>>>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
+    pub(crate) sql_functions: PlHashMap<String, (SQLFunctionSignature, SQLFunctionImpl)>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
@@ -152,17 +316,22 @@ pub struct SQLContext {
     table_aliases: PlHashMap<String, String>,
     joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
     pub(crate) named_windows: PlHashMap<String, WindowSpec>,
+    pub(crate) bound_params: PlHashMap<String, Scalar>,
+    pub(crate) dialect: SQLDialect,
 }
 
 impl Default for SQLContext {
     fn default() -> Self {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
+            sql_functions: Default::default(),
             table_map: Default::default(),
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             named_windows: Default::default(),
+            bound_params: Default::default(),
+            dialect: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -231,20 +400,58 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
+        self.execute_with_params(query, SQLParams::default())
+    }
+
+    /// Execute a parameterized SQL query, returning a [`LazyFrame`].
+    ///
+    /// Placeholders in the query are bound to the given [`SQLParams`]; `$1`, `$2`, ...
+    /// refer to positional values by index, `?` takes the next positional value in
+    /// order of appearance, and `:name`/`$name` refer to named values. The values are
+    /// substituted as typed literals after parsing, so they are never interpreted as SQL.
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLParams};
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let params = SQLParams::from_positional([Scalar::from(1i32)]);
+    /// let sql_df = ctx
+    ///     .execute_with_params("SELECT * FROM df WHERE a > $1", params)
+    ///     .unwrap()
+    ///     .collect()
+    ///     .unwrap();
+    /// assert_eq!(sql_df.height(), 2);
+    /// # }
+    ///```
+    pub fn execute_with_params(
+        &mut self,
+        query: &str,
+        params: SQLParams,
+    ) -> PolarsResult<LazyFrame> {
+        let dialect = self.dialect.parser_dialect();
+        let mut parser = Parser::new(dialect.as_ref());
         parser = parser.with_options(ParserOptions {
             trailing_commas: true,
             ..Default::default()
         });
 
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
+        let mut ast = parse_statements(parser.try_with_sql(query).map_err(to_sql_interface_err)?)?;
 
         polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+        let stmt = ast.first_mut().unwrap();
+        normalize_dialect(stmt, self.dialect);
+        self.bound_params = bind_placeholders(stmt, &params)?;
+        let res = self.execute_statement(stmt);
+        self.bound_params.clear();
+        let res = res?;
 
         // Ensure the result uses the proper arenas.
         // This will instantiate new arenas with a new version.
@@ -261,6 +468,13 @@ impl SQLContext {
         Ok(res)
     }
 
+    /// Get the value bound to the given (normalised) placeholder, eg: `$1` or `:name`.
+    pub(crate) fn get_bound_param(&self, placeholder: &str) -> PolarsResult<&Scalar> {
+        self.bound_params.get(placeholder).ok_or_else(
+            || polars_err!(SQLInterface: "no value bound to placeholder '{}'", placeholder),
+        )
+    }
+
     /// Add a function registry to the SQLContext.
     /// The registry provides the ability to add custom functions to the SQLContext.
     pub fn with_function_registry(mut self, function_registry: Arc<dyn FunctionRegistry>) -> Self {
@@ -268,6 +482,89 @@ impl SQLContext {
         self
     }
 
+    /// Set the SQL dialect used to parse and interpret queries.
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLDialect};
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new().with_dialect(SQLDialect::MySQL);
+    /// ctx.register("df", df! { "a" => [1, 2, 3] }.unwrap().lazy());
+    ///
+    /// // backtick-quoted identifiers, and double-quoted strings
+    /// let sql_df = ctx
+    ///     .execute(r#"SELECT `a` / 2 AS half, "x" AS s FROM df"#)
+    ///     .unwrap()
+    ///     .collect()
+    ///     .unwrap();
+    /// assert_eq!(sql_df.column("half").unwrap().f64().unwrap().get(0), Some(0.5));
+    /// # }
+    ///```
+    pub fn with_dialect(mut self, dialect: SQLDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Get the SQL dialect used to parse and interpret queries.
+    pub fn dialect(&self) -> SQLDialect {
+        self.dialect
+    }
+
+    /// Register a function that can be called by name from SQL.
+    ///
+    /// The function translates its argument expressions into a single result expression;
+    /// the number and types of the arguments are checked against the given signature
+    /// before it is called. Function names are case-insensitive, and cannot shadow
+    /// built-in SQL functions.
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_sql::function_registry::SQLFunctionSignature;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// ctx.register_function(
+    ///     "double_it",
+    ///     SQLFunctionSignature::exact(vec![DataTypeSelector::Numeric]),
+    ///     |args: Vec<Expr>| Ok(args[0].clone() * lit(2)),
+    /// )
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df! { "a" => [1, 2, 3] }.unwrap().lazy());
+    /// let sql_df = ctx
+    ///     .execute("SELECT DOUBLE_IT(a) AS b FROM df")
+    ///     .unwrap()
+    ///     .collect()
+    ///     .unwrap();
+    /// assert_eq!(sql_df.column("b").unwrap().i32().unwrap().get(2), Some(6));
+    /// # }
+    ///```
+    pub fn register_function<F>(
+        &mut self,
+        name: &str,
+        signature: SQLFunctionSignature,
+        function: F,
+    ) -> PolarsResult<()>
+    where
+        F: Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync + 'static,
+    {
+        let name = name.to_lowercase();
+        polars_ensure!(
+            !all_functions().contains(&name.as_str()),
+            SQLInterface: "cannot register function '{}'; a built-in SQL function with this name exists", name
+        );
+        self.sql_functions
+            .insert(name, (signature, Arc::new(function)));
+        Ok(())
+    }
+
+    /// Unregister a function previously registered with [`SQLContext::register_function`].
+    pub fn unregister_function(&mut self, name: &str) {
+        self.sql_functions.remove(&name.to_lowercase());
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -286,6 +583,10 @@ impl SQLContext {
             table_map: Arc::new(RwLock::new(self.table_map.read().unwrap().clone())),
             named_windows: self.named_windows.clone(),
             cte_map: self.cte_map.clone(),
+            bound_params: self.bound_params.clone(),
+            function_registry: self.function_registry.clone(),
+            sql_functions: self.sql_functions.clone(),
+            dialect: self.dialect,
 
             ..Default::default()
         }
@@ -304,6 +605,7 @@ impl SQLContext {
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert_into_table(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -686,6 +988,225 @@ impl SQLContext {
         Ok(lf)
     }
 
+    /// Apply a lateral relation to the frame that it is (cross-)joined with; lateral
+    /// relations are `UNNEST` over column references and `LATERAL UNNEST(...)`.
+    fn process_lateral_relation(
+        &self,
+        lf: LazyFrame,
+        relation: &TableFactor,
+    ) -> PolarsResult<LazyFrame> {
+        match relation {
+            TableFactor::UNNEST {
+                alias,
+                array_exprs,
+                with_offset,
+                ..
+            } => self.process_unnest_lateral(lf, alias, array_exprs, *with_offset),
+            TableFactor::Function {
+                lateral: true,
+                name,
+                args,
+                alias,
+            } if name.to_string().eq_ignore_ascii_case("unnest") => {
+                let array_exprs = args
+                    .iter()
+                    .map(|arg| match arg {
+                        FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => Ok(expr.clone()),
+                        other => polars_bail!(
+                            SQLSyntax: "LATERAL UNNEST expects column references, found {}", other
+                        ),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                self.process_unnest_lateral(lf, alias, &array_exprs, false)
+            },
+            TableFactor::Function { name, .. } => {
+                polars_bail!(SQLInterface: "LATERAL table function '{}' is not supported; only UNNEST can be used laterally", name)
+            },
+            _ => polars_bail!(SQLInterface: "LATERAL subqueries are not supported"),
+        }
+    }
+
+    /// Process a `PIVOT` clause; the pivot values must be listed explicitly (so that the
+    /// output schema is static), and the input columns that are not referenced by the
+    /// aggregates or the pivot column are used as the group keys.
+    #[cfg(feature = "pivot")]
+    fn process_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[SQLExpr],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let pivot_col = match value_column {
+            [SQLExpr::Identifier(ident)] => PlSmallStr::from_str(&ident.value),
+            [SQLExpr::CompoundIdentifier(idents)] => {
+                PlSmallStr::from_str(&idents.last().unwrap().value)
+            },
+            _ => {
+                polars_bail!(SQLInterface: "PIVOT expects a single column in the FOR clause")
+            },
+        };
+        let PivotValueSource::List(pivot_values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values (eg: FOR col IN ('a', 'b'))")
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        polars_ensure!(schema.contains(&pivot_col), ColumnNotFound: "PIVOT column '{}' was not found", pivot_col);
+
+        let default_value = default_on_null
+            .map(|e| parse_sql_expr(e, self, Some(&schema)))
+            .transpose()?;
+        let aggs = aggregate_functions
+            .iter()
+            .map(|agg| Ok((parse_sql_expr(&agg.expr, self, Some(&schema))?, agg)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // group by all columns that do not take part in the pivot
+        let mut pivot_cols: PlHashSet<PlSmallStr> = PlHashSet::from_iter([pivot_col.clone()]);
+        for (expr, _) in &aggs {
+            pivot_cols.extend(expr_to_leaf_column_names_iter(expr));
+        }
+        let keys: Vec<Expr> = schema
+            .iter_names()
+            .filter(|name| !pivot_cols.contains(*name))
+            .map(|name| col(name.clone()))
+            .collect();
+
+        // with multiple (or aliased) aggregates, output columns are named "<value>_<agg>"
+        let suffix_agg_names = aggs.len() > 1 || aggs.iter().any(|(_, agg)| agg.alias.is_some());
+        let mut pivot_exprs = Vec::with_capacity(pivot_values.len() * aggs.len());
+        for value in pivot_values {
+            let value_name = match (&value.alias, &value.expr) {
+                (Some(alias), _) => alias.value.clone(),
+                (
+                    None,
+                    SQLExpr::Value(ValueWithSpan {
+                        value: SQLValue::SingleQuotedString(s),
+                        ..
+                    }),
+                ) => s.clone(),
+                (None, expr) => expr.to_string(),
+            };
+            let is_pivot_value =
+                col(pivot_col.clone()).eq(parse_sql_expr(&value.expr, self, Some(&schema))?);
+
+            for (expr, agg) in &aggs {
+                // aggregate only the rows that match the current pivot value
+                let mut pivot_expr = expr.clone().map_expr(|e| match e {
+                    Expr::Column(name) => col(name).filter(is_pivot_value.clone()),
+                    Expr::Len => col(pivot_col.clone()).filter(is_pivot_value.clone()).len(),
+                    e => e,
+                });
+                // pivot values that have no matching rows in a group are NULL
+                pivot_expr = when(is_pivot_value.clone().any(true))
+                    .then(pivot_expr)
+                    .otherwise(lit(NULL));
+                if let Some(default_value) = &default_value {
+                    pivot_expr = pivot_expr.fill_null(default_value.clone());
+                }
+                let name = if suffix_agg_names {
+                    let agg_name = match &agg.alias {
+                        Some(alias) => alias.value.clone(),
+                        None => agg.expr.to_string(),
+                    };
+                    format!("{value_name}_{agg_name}")
+                } else {
+                    value_name.clone()
+                };
+                pivot_exprs.push(pivot_expr.alias(name));
+            }
+        }
+        Ok(if keys.is_empty() {
+            lf.select(pivot_exprs)
+        } else {
+            lf.group_by_stable(keys).agg(pivot_exprs)
+        })
+    }
+
+    /// Process an `UNPIVOT` clause; the unpivoted columns are given explicitly, and
+    /// the remaining columns of the input are retained as the index.
+    #[cfg(feature = "pivot")]
+    fn process_unpivot(
+        &mut self,
+        mut lf: LazyFrame,
+        value: &SQLExpr,
+        name: &Ident,
+        columns: &[ExprWithAlias],
+        null_inclusion: &Option<NullInclusion>,
+    ) -> PolarsResult<LazyFrame> {
+        let SQLExpr::Identifier(value_name) = value else {
+            polars_bail!(SQLInterface: "UNPIVOT expects a single value column name; found {}", value)
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let mut unpivot_cols = PlHashSet::with_capacity(columns.len());
+        let (mut on, mut rename_from, mut rename_to) = (vec![], vec![], vec![]);
+        for c in columns {
+            let col_name = match &c.expr {
+                SQLExpr::Identifier(ident) => PlSmallStr::from_str(&ident.value),
+                other => {
+                    polars_bail!(SQLInterface: "UNPIVOT expects column names in the IN clause; found {}", other)
+                },
+            };
+            polars_ensure!(schema.contains(&col_name), ColumnNotFound: "UNPIVOT column '{}' was not found", col_name);
+            unpivot_cols.insert(col_name.clone());
+            match &c.alias {
+                Some(alias) if alias.value != col_name.as_str() => {
+                    rename_from.push(col_name);
+                    on.push(PlSmallStr::from_str(&alias.value));
+                    rename_to.push(PlSmallStr::from_str(&alias.value));
+                },
+                _ => on.push(col_name),
+            }
+        }
+        let index: Vec<PlSmallStr> = schema
+            .iter_names()
+            .filter(|name| !unpivot_cols.contains(*name))
+            .cloned()
+            .collect();
+
+        if !rename_from.is_empty() {
+            lf = lf.rename(rename_from, rename_to, true);
+        }
+        let value_name = PlSmallStr::from_str(&value_name.value);
+        lf = lf.unpivot(UnpivotArgsDSL {
+            on: Some(Selector::ByName {
+                names: Arc::from(on),
+                strict: true,
+            }),
+            index: Selector::ByName {
+                names: Arc::from(index),
+                strict: true,
+            },
+            variable_name: Some(PlSmallStr::from_str(&name.value)),
+            value_name: Some(value_name.clone()),
+        });
+
+        // note: nulls are excluded by default (as per Snowflake/DuckDB)
+        Ok(match null_inclusion {
+            Some(NullInclusion::IncludeNulls) => lf,
+            _ => lf.filter(col(value_name).is_not_null()),
+        })
+    }
+
+    /// Register a derived table (such as the result of a PIVOT) under its alias, if any.
+    fn register_derived_table(
+        &mut self,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map
+                    .write()
+                    .unwrap()
+                    .insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok(("".to_string(), lf)),
+        }
+    }
+
     fn process_values(&mut self, values: &[Vec<SQLExpr>]) -> PolarsResult<LazyFrame> {
         let frame_rows: Vec<Row> = values.iter().map(|row| {
             let row_data: Result<Vec<_>, _> = row.iter().map(|expr| {
@@ -708,15 +1229,76 @@ impl SQLContext {
     // EXPLAIN SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
-            Statement::Explain { statement, .. } => {
+            Statement::Explain {
+                statement,
+                analyze,
+                format,
+                options,
+                ..
+            } => {
+                let mut analyze = *analyze;
+                let mut format = match format {
+                    Some(
+                        AnalyzeFormatKind::Keyword(format) | AnalyzeFormatKind::Assignment(format),
+                    ) => Some(format.to_string()),
+                    None => None,
+                };
+                // eg: "EXPLAIN (ANALYZE, FORMAT JSON) SELECT ..."
+                for opt in options.iter().flatten() {
+                    match (opt.name.value.to_lowercase().as_str(), &opt.arg) {
+                        ("analyze", None) => analyze = true,
+                        (
+                            "analyze",
+                            Some(SQLExpr::Value(ValueWithSpan {
+                                value: SQLValue::Boolean(b),
+                                ..
+                            })),
+                        ) => analyze = *b,
+                        ("format", Some(SQLExpr::Identifier(ident))) => {
+                            format = Some(ident.value.clone())
+                        },
+                        _ => {
+                            polars_bail!(SQLInterface: "EXPLAIN option '{}' is not supported", opt)
+                        },
+                    }
+                }
+
                 let lf = self.execute_statement(statement)?;
-                let plan = lf.describe_optimized_plan()?;
-                let plan = plan
-                    .split('\n')
-                    .collect::<Series>()
-                    .with_name(PlSmallStr::from_static("Logical Plan"))
-                    .into_column();
-                let df = DataFrame::new_infer_height(vec![plan])?;
+                let df = if analyze {
+                    polars_ensure!(
+                        format.is_none(),
+                        SQLInterface: "EXPLAIN ANALYZE does not support the FORMAT option"
+                    );
+                    // run the query, returning the per-node timings (in microseconds)
+                    let (_, timings) = lf.profile()?;
+                    timings
+                        .lazy()
+                        .with_column((col("end") - col("start")).alias("duration"))
+                        .collect()?
+                } else {
+                    let plan = match format.as_deref().map(str::to_ascii_uppercase).as_deref() {
+                        None | Some("TEXT") => lf
+                            .describe_optimized_plan()?
+                            .split('\n')
+                            .collect::<Series>(),
+                        Some("JSON") => {
+                            let plan = lf.to_alp_optimized()?;
+                            let plan =
+                                ExplainNode::new(&plan.lp_arena, &plan.expr_arena, plan.lp_top);
+                            let json = serde_json::to_string(&plan).map_err(
+                                |e| polars_err!(ComputeError: "failed to serialize plan: {}", e),
+                            )?;
+                            Series::new(PlSmallStr::EMPTY, [json])
+                        },
+                        Some(other) => {
+                            polars_bail!(SQLInterface: "EXPLAIN does not support the '{}' format", other)
+                        },
+                    };
+                    let plan = plan
+                        .with_name(PlSmallStr::from_static("Logical Plan"))
+                        .into_column();
+                    DataFrame::new_infer_height(vec![plan])?
+                };
                 Ok(df.lazy())
             },
             _ => polars_bail!(SQLInterface: "unexpected statement type; expected EXPLAIN"),
@@ -798,6 +1380,122 @@ impl SQLContext {
         }
     }
 
+    // INSERT [INTO | OVERWRITE] <tbl> [(<col>, ...)] <query>
+    fn execute_insert_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            table,
+            table_alias,
+            columns,
+            overwrite,
+            source,
+            assignments,
+            partitioned,
+            on,
+            returning,
+            ..
+        }) = stmt
+        {
+            let error_message: Option<&'static str> = if table_alias.is_some() {
+                Some("INSERT does not support table aliases")
+            } else if partitioned.is_some() {
+                Some("INSERT does not support the PARTITION clause")
+            } else if on.is_some() {
+                Some("INSERT does not support the ON CONFLICT/ON DUPLICATE KEY clause")
+            } else if returning.is_some() {
+                Some("INSERT does not support the RETURNING clause")
+            } else if !assignments.is_empty() {
+                Some("INSERT does not support SET assignments")
+            } else {
+                None
+            };
+            if let Some(msg) = error_message {
+                polars_bail!(SQLInterface: msg);
+            }
+
+            let tbl_name = match table {
+                TableObject::TableName(name) if name.0.len() == 1 => {
+                    name.0.first().unwrap().as_ident().unwrap().value.clone()
+                },
+                _ => {
+                    polars_bail!(SQLInterface: "INSERT expects a single (unqualified) table name; found {}", table)
+                },
+            };
+            let Some(source) = source else {
+                polars_bail!(SQLInterface: "INSERT INTO '{}' expects a query or VALUES clause", tbl_name)
+            };
+            let Some(mut target) = self.table_map.read().unwrap().get(&tbl_name).cloned() else {
+                polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name)
+            };
+            let target_schema = self.get_frame_schema(&mut target)?;
+
+            // Execute the source query in isolation (it may refer to the target table itself)
+            let mut src = self.execute_isolated(|ctx| ctx.execute_query(source))?;
+            let src_schema = self.get_frame_schema(&mut src)?;
+
+            // Map the source columns (positionally) onto the target columns
+            let target_cols: Vec<PlSmallStr> = if columns.is_empty() {
+                target_schema.iter_names().cloned().collect()
+            } else {
+                let mut seen = PlHashSet::with_capacity(columns.len());
+                columns
+                    .iter()
+                    .map(|c| {
+                        let name = PlSmallStr::from_str(c.value.as_str());
+                        polars_ensure!(
+                            target_schema.contains(&name),
+                            ColumnNotFound: "column '{}' does not exist in table '{}'", name, tbl_name
+                        );
+                        polars_ensure!(
+                            seen.insert(name.clone()),
+                            SQLInterface: "column '{}' is specified more than once in INSERT", name
+                        );
+                        Ok(name)
+                    })
+                    .collect::<PolarsResult<_>>()?
+            };
+            polars_ensure!(
+                target_cols.len() == src_schema.len(),
+                SQLInterface: "INSERT INTO '{}' expects {} column(s); the query returns {}",
+                tbl_name, target_cols.len(), src_schema.len()
+            );
+
+            // Coerce to the target schema; target columns that are not inserted get nulls
+            let inserted: PlHashMap<&PlSmallStr, &PlSmallStr> =
+                target_cols.iter().zip(src_schema.iter_names()).collect();
+            let exprs = target_schema
+                .iter()
+                .map(|(name, dtype)| {
+                    let expr = match inserted.get(name) {
+                        Some(src_name) => col((*src_name).clone()),
+                        None => lit(NULL),
+                    };
+                    expr.strict_cast(dtype.clone()).alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            let src = src.select(exprs);
+
+            let lf = if *overwrite {
+                src
+            } else {
+                concat(
+                    [target, src],
+                    UnionArgs {
+                        rechunk: false,
+                        parallel: false,
+                        ..Default::default()
+                    },
+                )?
+            };
+            self.register(&tbl_name, lf);
+
+            let verb = if *overwrite { "OVERWRITE" } else { "INTO" };
+            let df_inserted = df! { "Response" => [format!("INSERT {verb} {tbl_name}")] };
+            Ok(df_inserted.unwrap().lazy())
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        }
+    }
+
     // TRUNCATE <tbl>
     fn execute_truncate_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::Truncate(Truncate {
@@ -877,20 +1575,13 @@ impl SQLContext {
         if !tbl_expr.joins.is_empty() {
             for join in &tbl_expr.joins {
                 // Handle "CROSS JOIN UNNEST(col)" as a lateral join op
-                if let (
-                    JoinOperator::CrossJoin(JoinConstraint::None),
-                    TableFactor::UNNEST {
-                        alias,
-                        array_exprs,
-                        with_offset,
-                        ..
-                    },
-                ) = (&join.join_operator, &join.relation)
+                if matches!(
+                    join.join_operator,
+                    JoinOperator::CrossJoin(JoinConstraint::None)
+                ) && is_lateral_relation(&join.relation)
                 {
-                    if array_exprs.iter().any(|e| !matches!(e, SQLExpr::Array(_))) {
-                        lf = self.process_unnest_lateral(lf, alias, array_exprs, *with_offset)?;
-                        continue;
-                    }
+                    lf = self.process_lateral_relation(lf, &join.relation)?;
+                    continue;
                 }
 
                 let (r_name, mut rf) = self.get_table(&join.relation)?;
@@ -1100,13 +1791,20 @@ impl SQLContext {
             (DataFrame::empty().lazy(), None)
         } else {
             // Note: implicit joins need more work to support properly,
-            // explicit joins are preferred for now (ref: #16662)
+            // explicit joins are preferred for now (ref: #16662); lateral
+            // relations (eg: "FROM tbl, UNNEST(tbl.col) AS x") are supported
             let from = select_stmt.clone().from;
-            if from.len() > 1 {
+            let (tbl_expr, lateral) = from.split_first().unwrap();
+            if !lateral
+                .iter()
+                .all(|t| t.joins.is_empty() && is_lateral_relation(&t.relation))
+            {
                 polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
             }
-            let tbl_expr = from.first().unwrap();
-            let lf = self.execute_from_statement(tbl_expr)?;
+            let mut lf = self.execute_from_statement(tbl_expr)?;
+            for t in lateral {
+                lf = self.process_lateral_relation(lf, &t.relation)?;
+            }
             let base_name = get_table_name(&tbl_expr.relation);
             (lf, base_name)
         };
@@ -1302,11 +2000,28 @@ impl SQLContext {
             },
         };
 
+        // QUALIFY may refer to input columns that are not selected; these are
+        // projected as hidden columns, and dropped again after the QUALIFY filter
+        let mut qualify_hidden_cols: Vec<PlSmallStr> = vec![];
+
         lf = if group_by_keys.is_empty() {
             // The 'having' clause is only valid inside 'group by'
             if select_stmt.having.is_some() {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
             };
+            if let Some(qualify) = &select_stmt.qualify {
+                let (_, column_refs) = QualifyExpression::analyze(qualify);
+                let output_names = projections
+                    .iter()
+                    .map(|p| Ok(p.to_field(&schema)?.name))
+                    .collect::<PolarsResult<PlHashSet<_>>>()?;
+                for c in column_refs {
+                    if schema.contains(&c) && !output_names.contains(c.as_str()) {
+                        projections.push(col(c.as_str()));
+                        qualify_hidden_cols.push(c.into());
+                    }
+                }
+            }
 
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
@@ -1322,8 +2037,9 @@ impl SQLContext {
             // clause then we can project the final column *expressions* directly.
             for p in projections.iter() {
                 let name = p.to_field(schema.deref())?.name.to_string();
-                if select_modifiers.matches_ilike(&name)
-                    && !select_modifiers.exclude.contains(&name)
+                if (select_modifiers.matches_ilike(&name)
+                    && !select_modifiers.exclude.contains(&name))
+                    || qualify_hidden_cols.iter().any(|c| c == name.as_str())
                 {
                     projection_heights |= ExprSqlProjectionHeightBehavior::identify_from_expr(p);
 
@@ -1425,6 +2141,12 @@ impl SQLContext {
 
         // Apply optional QUALIFY clause (filters on window functions).
         lf = self.process_qualify(lf, &select_stmt.qualify, &window_fn_columns)?;
+        if !qualify_hidden_cols.is_empty() {
+            lf = lf.drop(Selector::ByName {
+                names: Arc::from(qualify_hidden_cols),
+                strict: true,
+            });
+        }
 
         // Apply optional DISTINCT clause.
         lf = match &select_stmt.distinct {
@@ -1581,8 +2303,21 @@ impl SQLContext {
                 return Ok(lf.clear());
             }
 
+            // rewrite correlated subqueries into joins (the remainder is applied as a filter)
+            let expr = if invert_filter {
+                Cow::Borrowed(expr)
+            } else {
+                let (decorrelated, remaining) =
+                    self.decorrelate_subqueries(lf, expr, &schema)?;
+                lf = decorrelated;
+                match remaining {
+                    Some(remaining) => Cow::Owned(remaining),
+                    None => return Ok(lf),
+                }
+            };
+
             // ...otherwise parse and apply the filter as normal
-            let mut filter_expression = parse_sql_expr(expr, self, Some(schema).as_deref())?;
+            let mut filter_expression = parse_sql_expr(&expr, self, Some(schema).as_deref())?;
             if filter_expression.clone().meta().has_multiple_outputs() {
                 filter_expression = all_horizontal([filter_expression])?;
             }
@@ -1703,6 +2438,9 @@ impl SQLContext {
             query,
             columns,
             like,
+            hive_formats,
+            file_format,
+            location,
             ..
         }) = stmt
         {
@@ -1710,6 +2448,11 @@ impl SQLContext {
             if *if_not_exists && self.table_map.read().unwrap().contains_key(tbl_name) {
                 polars_bail!(SQLInterface: "relation '{}' already exists", tbl_name);
             }
+            let external_sink =
+                Self::external_table_sink(hive_formats.as_ref(), file_format.as_ref(), location)?;
+            if external_sink.is_some() && query.is_none() {
+                polars_bail!(SQLInterface: "CREATE TABLE with USING/LOCATION requires an AS <query> clause")
+            }
             let lf = match (query, columns.is_empty(), like) {
                 (Some(query), true, None) => {
                     // ----------------------------------------------------
@@ -1765,8 +2508,19 @@ impl SQLContext {
                     )
                 },
             };
-            self.register(tbl_name, lf);
-
+            self.register(tbl_name, lf.clone());
+
+            if let Some((target, file_format)) = external_sink {
+                // ----------------------------------------------------
+                // CREATE TABLE <name> USING <fmt> LOCATION <path> AS <query>
+                // ----------------------------------------------------
+                // The write is only performed when the returned frame is collected.
+                return lf.sink(
+                    SinkDestination::File { target },
+                    file_format,
+                    UnifiedSinkArgs::default(),
+                );
+            }
             let df_created = df! { "Response" => [format!("CREATE TABLE {}", name.0.first().unwrap().as_ident().unwrap().value)] };
             Ok(df_created.unwrap().lazy())
         } else {
@@ -1774,16 +2528,71 @@ impl SQLContext {
         }
     }
 
+    /// Resolve the sink target and write format of a `CREATE TABLE` statement that
+    /// stores its result externally (eg: `USING PARQUET LOCATION 'path'`).
+    fn external_table_sink(
+        hive_formats: Option<&HiveFormat>,
+        file_format: Option<&FileFormat>,
+        location: &Option<String>,
+    ) -> PolarsResult<Option<(SinkTarget, FileWriteFormat)>> {
+        let hive_format = hive_formats.and_then(|hf| match &hf.storage {
+            Some(HiveIOFormat::FileFormat { format }) => Some(format),
+            _ => None,
+        });
+        let hive_location = hive_formats.and_then(|hf| hf.location.as_ref());
+        let (format, path) = match (
+            file_format.or(hive_format),
+            location.as_ref().or(hive_location),
+        ) {
+            (None, None) => return Ok(None),
+            (Some(format), Some(path)) => (format, path),
+            (Some(_), None) => {
+                polars_bail!(SQLInterface: "CREATE TABLE with USING requires a LOCATION")
+            },
+            (None, Some(_)) => {
+                polars_bail!(SQLInterface: "CREATE TABLE with LOCATION requires a USING file format")
+            },
+        };
+        let file_format = match format {
+            #[cfg(feature = "parquet")]
+            FileFormat::PARQUET => {
+                FileWriteFormat::Parquet(Arc::new(ParquetWriteOptions::default()))
+            },
+            #[cfg(feature = "json")]
+            FileFormat::JSONFILE => FileWriteFormat::NDJson(NDJsonWriterOptions::default()),
+            #[cfg(feature = "csv")]
+            FileFormat::TEXTFILE => FileWriteFormat::Csv(CsvWriterOptions::default()),
+            _ => {
+                polars_bail!(SQLInterface: "CREATE TABLE does not support writing {} files", format)
+            },
+        };
+        Ok(Some((
+            SinkTarget::Path(PlRefPath::new(path.as_str())),
+            file_format,
+        )))
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
-                name, alias, args, ..
+                name,
+                alias,
+                args,
+                sample,
+                ..
             } => {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
                 let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                    let lf = match sample {
+                        Some(
+                            TableSampleKind::BeforeTableAlias(sample)
+                            | TableSampleKind::AfterTableAlias(sample),
+                        ) => Self::process_table_sample(lf, sample)?,
+                        None => lf,
+                    };
                     match alias {
                         Some(alias) => {
                             self.table_aliases
@@ -1880,6 +2689,38 @@ impl SQLContext {
                     polars_bail!(SQLSyntax: "UNNEST table must have an alias");
                 }
             },
+            #[cfg(feature = "pivot")]
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (_, lf) = self.get_table(table)?;
+                let lf = self.process_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                self.register_derived_table(lf, alias)
+            },
+            #[cfg(feature = "pivot")]
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                null_inclusion,
+                alias,
+            } => {
+                let (_, lf) = self.get_table(table)?;
+                let lf = self.process_unpivot(lf, value, name, columns, null_inclusion)?;
+                self.register_derived_table(lf, alias)
+            },
             TableFactor::NestedJoin {
                 table_with_joins,
                 alias,
@@ -2309,6 +3150,70 @@ impl SQLContext {
         }
     }
 
+    /// Sample the rows of a table, as given by its `TABLESAMPLE` clause.
+    ///
+    /// All sampling methods (eg: `BERNOULLI`, `SYSTEM`) draw a uniform sample of rows;
+    /// the quantity is a percentage of the table unless given in `ROWS`.
+    fn process_table_sample(lf: LazyFrame, sample: &TableSample) -> PolarsResult<LazyFrame> {
+        #[cfg(feature = "random")]
+        {
+            polars_ensure!(
+                sample.bucket.is_none() && sample.offset.is_none(),
+                SQLInterface: "TABLESAMPLE BUCKET/OFFSET is not supported"
+            );
+            let Some(quantity) = &sample.quantity else {
+                polars_bail!(SQLSyntax: "TABLESAMPLE requires a sample size");
+            };
+            let size = match &quantity.value {
+                SQLExpr::Value(ValueWithSpan {
+                    value: SQLValue::Number(n, _),
+                    ..
+                }) => n.parse::<f64>().ok().filter(|n| *n >= 0.0),
+                _ => None,
+            };
+            let Some(size) = size else {
+                polars_bail!(SQLSyntax: "TABLESAMPLE size must be a non-negative number (found {})", quantity.value);
+            };
+            // all columns must be sampled with the same seed to keep rows intact
+            let seed = match &sample.seed {
+                Some(seed) => match &seed.value {
+                    SQLValue::Number(n, _) => n.parse::<u64>().map_err(
+                        |_| polars_err!(SQLSyntax: "invalid TABLESAMPLE seed: {}", seed.value),
+                    )?,
+                    _ => polars_bail!(SQLSyntax: "invalid TABLESAMPLE seed: {}", seed.value),
+                },
+                None => polars_core::random::get_global_random_u64(),
+            };
+            let sampled = match quantity.unit {
+                Some(TableSampleUnit::Rows) => {
+                    polars_ensure!(
+                        size.fract() == 0.0,
+                        SQLSyntax: "TABLESAMPLE row count must be an integer (found {})", size
+                    );
+                    // a sample larger than the table returns every row
+                    let n = lit(size as IdxSize);
+                    let n = when(len().gt(n.clone())).then(n).otherwise(len());
+                    all().as_expr().sample_n(n, false, false, Some(seed))
+                },
+                Some(TableSampleUnit::Percent) | None => {
+                    polars_ensure!(
+                        size <= 100.0,
+                        SQLSyntax: "TABLESAMPLE percentage must be between 0 and 100 (found {})", size
+                    );
+                    all()
+                        .as_expr()
+                        .sample_frac(lit(size / 100.0), false, false, Some(seed))
+                },
+            };
+            Ok(lf.select([sampled]))
+        }
+        #[cfg(not(feature = "random"))]
+        {
+            let _ = (lf, sample);
+            polars_bail!(SQLInterface: "TABLESAMPLE requires the 'random' feature");
+        }
+    }
+
     fn process_qualified_wildcard(
         &mut self,
         ObjectName(idents): &ObjectName,
@@ -2482,6 +3387,32 @@ fn get_using_cols(op: &JoinOperator) -> Option<impl Iterator<Item = String> + '_
     }
 }
 
+/// An (optimized) plan node with its expressions and input plans, as serialized
+/// by `EXPLAIN FORMAT JSON`.
+#[derive(Serialize)]
+struct ExplainNode {
+    node: &'static str,
+    expressions: Vec<String>,
+    inputs: Vec<ExplainNode>,
+}
+
+impl ExplainNode {
+    fn new(lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>, node: Node) -> Self {
+        let ir = lp_arena.get(node);
+        Self {
+            node: ir.name(),
+            expressions: ir
+                .exprs()
+                .map(|e| e.display(expr_arena).to_string())
+                .collect(),
+            inputs: ir
+                .inputs()
+                .map(|input| Self::new(lp_arena, expr_arena, input))
+                .collect(),
+        }
+    }
+}
+
 /// Extract the table name (or alias) from a TableFactor.
 fn get_table_name(factor: &TableFactor) -> Option<String> {
     match factor {
@@ -2723,6 +3654,98 @@ fn process_join_constraint(
     }
 }
 
+/// Parse all statements of a SQL query.
+///
+/// This follows [`Parser::parse_statements`], but additionally understands the
+/// `CREATE TABLE <name> USING <format> [LOCATION '<path>'] [AS <query>]` form.
+fn parse_statements(mut parser: Parser) -> PolarsResult<Vec<Statement>> {
+    let mut statements = Vec::new();
+    let mut expecting_delimiter = false;
+    loop {
+        // Skip empty statements (between successive statement delimiters).
+        while parser.consume_token(&Token::SemiColon) {
+            expecting_delimiter = false;
+        }
+        let next = parser.peek_token().token;
+        if next == Token::EOF {
+            break;
+        }
+        polars_ensure!(
+            !expecting_delimiter,
+            SQLInterface: "expected end of statement, found: {}", next
+        );
+        let stmt = match parse_create_table_using(&mut parser).map_err(to_sql_interface_err)? {
+            Some(stmt) => stmt,
+            None => parser.parse_statement().map_err(to_sql_interface_err)?,
+        };
+        statements.push(stmt);
+        expecting_delimiter = true;
+    }
+    Ok(statements)
+}
+
+/// Parse `CREATE [OR REPLACE] [TEMPORARY] TABLE [IF NOT EXISTS] <name> USING <format>`,
+/// followed by an optional `LOCATION '<path>'` and `AS <query>`, into the equivalent
+/// Hive-style `STORED AS <format>` statement.
+///
+/// Returns `None` (without consuming any tokens) if the statement doesn't have this form.
+fn parse_create_table_using(parser: &mut Parser) -> Result<Option<Statement>, ParserError> {
+    let Some((or_replace, temporary, if_not_exists, name)) = parser.maybe_parse(|parser| {
+        parser.expect_keyword(Keyword::CREATE)?;
+        let or_replace = parser.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
+        let temporary = parser
+            .parse_one_of_keywords(&[Keyword::TEMP, Keyword::TEMPORARY])
+            .is_some();
+        parser.expect_keyword(Keyword::TABLE)?;
+        let if_not_exists = parser.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = parser.parse_object_name(false)?;
+        parser.expect_keyword(Keyword::USING)?;
+        Ok((or_replace, temporary, if_not_exists, name))
+    })?
+    else {
+        return Ok(None);
+    };
+
+    let format = match parser.next_token().token {
+        Token::Word(w) => w.value,
+        tok => {
+            return Err(ParserError::ParserError(format!(
+                "expected a file format after USING, found: {tok}"
+            )));
+        },
+    };
+    let file_format = match format.to_ascii_uppercase().as_str() {
+        "PARQUET" => FileFormat::PARQUET,
+        "CSV" => FileFormat::TEXTFILE,
+        "JSON" | "NDJSON" => FileFormat::JSONFILE,
+        _ => {
+            return Err(ParserError::ParserError(format!(
+                "CREATE TABLE does not support writing {format} files"
+            )));
+        },
+    };
+    let location = if parser.parse_keyword(Keyword::LOCATION) {
+        Some(parser.parse_literal_string()?)
+    } else {
+        None
+    };
+    let query = if parser.parse_keyword(Keyword::AS) {
+        Some(parser.parse_query()?)
+    } else {
+        None
+    };
+    Ok(Some(
+        CreateTableBuilder::new(name)
+            .or_replace(or_replace)
+            .temporary(temporary)
+            .if_not_exists(if_not_exists)
+            .file_format(Some(file_format))
+            .location(location)
+            .query(query)
+            .build(),
+    ))
+}
+
 /// Extract table identifiers referenced in a SQL query; uses a visitor to
 /// collect all table names that appear in FROM clauses, JOINs, TABLE refs
 /// in set operations, and subqueries.
@@ -2736,11 +3759,7 @@ pub fn extract_table_identifiers(
         trailing_commas: true,
         ..Default::default()
     });
-    let ast = parser
-        .try_with_sql(query)
-        .map_err(to_sql_interface_err)?
-        .parse_statements()
-        .map_err(to_sql_interface_err)?;
+    let ast = parse_statements(parser.try_with_sql(query).map_err(to_sql_interface_err)?)?;
 
     let mut collector = TableIdentifierCollector {
         include_schema,
@@ -2795,7 +3814,7 @@ impl ExprSqlProjectionHeightBehavior {
                 },
                 Literal(v) => !v.is_scalar(),
                 Explode { .. } | Filter { .. } | Gather { .. } | Slice { .. } => true,
-                Agg { .. } | Len => true,
+                Agg { .. } | AnonymousAgg { .. } | Len => true,
                 _ => false,
             }
         }
@@ -1,8 +1,12 @@
 //! This module defines a FunctionRegistry for supported SQL functions and UDFs.
 
-use polars_error::{PolarsResult, polars_bail};
+use std::sync::Arc;
+
+use polars_core::prelude::DataType;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 pub use polars_plan::prelude::FunctionOptions;
 use polars_plan::prelude::udf::UserDefinedFunction;
+use polars_plan::prelude::{DataTypeSelector, Expr};
 /// A registry that holds user defined functions.
 pub trait FunctionRegistry: Send + Sync {
     /// Register a function.
@@ -28,3 +32,77 @@ impl FunctionRegistry for DefaultFunctionRegistry {
         false
     }
 }
+
+/// The implementation of a function registered with [`SQLContext::register_function`];
+/// translates the argument expressions into the result expression.
+///
+/// [`SQLContext::register_function`]: crate::SQLContext::register_function
+pub type SQLFunctionImpl = Arc<dyn Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync>;
+
+/// The arity and argument types of a function registered with
+/// [`SQLContext::register_function`].
+///
+/// Arguments that are dynamic literals (eg: `1` or `'abc'`) and NULL are not
+/// type-checked, as they adapt to the type expected by the function.
+///
+/// [`SQLContext::register_function`]: crate::SQLContext::register_function
+#[derive(Clone, Debug)]
+pub struct SQLFunctionSignature {
+    arg_types: Vec<DataTypeSelector>,
+    variadic: Option<DataTypeSelector>,
+}
+
+impl SQLFunctionSignature {
+    /// A function taking exactly one argument for each of the given types.
+    pub fn exact(arg_types: Vec<DataTypeSelector>) -> Self {
+        Self {
+            arg_types,
+            variadic: None,
+        }
+    }
+
+    /// A function taking the given leading arguments, followed by any number of
+    /// arguments of the `rest` type.
+    pub fn variadic(arg_types: Vec<DataTypeSelector>, rest: DataTypeSelector) -> Self {
+        Self {
+            arg_types,
+            variadic: Some(rest),
+        }
+    }
+
+    /// Check the number of arguments given to the named function.
+    pub(crate) fn check_arity(&self, name: &str, n_args: usize) -> PolarsResult<()> {
+        let n_expected = self.arg_types.len();
+        match self.variadic {
+            None => polars_ensure!(
+                n_args == n_expected,
+                SQLSyntax: "{} expects {} argument{} (found {})",
+                name, n_expected, if n_expected == 1 { "" } else { "s" }, n_args
+            ),
+            Some(_) => polars_ensure!(
+                n_args >= n_expected,
+                SQLSyntax: "{} expects at least {} argument{} (found {})",
+                name, n_expected, if n_expected == 1 { "" } else { "s" }, n_args
+            ),
+        }
+        Ok(())
+    }
+
+    /// Check the type of the (zero-based) `idx`th argument given to the named function.
+    pub(crate) fn check_arg_type(
+        &self,
+        name: &str,
+        idx: usize,
+        dtype: &DataType,
+    ) -> PolarsResult<()> {
+        let expected = self.arg_types.get(idx).or(self.variadic.as_ref());
+        if let Some(expected) = expected {
+            polars_ensure!(
+                dtype.is_null() || expected.matches(dtype),
+                SQLInterface: "invalid type for argument {} of {} (found {})",
+                idx + 1, name, dtype
+            );
+        }
+        Ok(())
+    }
+}
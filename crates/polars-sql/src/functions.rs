@@ -2,8 +2,8 @@ use std::ops::{Add, Sub};
 
 use polars_core::chunked_array::ops::{FillNullStrategy, SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, ExplodeOptions, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail,
-    polars_err,
+    AnyValue, Column, DataType, ExplodeOptions, Field, PolarsResult, QuantileMethod, Scalar,
+    Schema, TimeUnit, polars_bail, polars_err,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "rank")]
@@ -309,6 +309,22 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT DATE_PART('year', col1) FROM df;
     /// SELECT DATE_PART('day', col1) FROM df;
     DatePart,
+    /// SQL 'date_trunc' function.
+    /// Truncates a date (or datetime) to the given precision, such as 'month', 'hour', etc.
+    /// ```sql
+    /// SELECT DATE_TRUNC('month', col1) FROM df;
+    /// SELECT DATE_TRUNC('quarter', col1) FROM df;
+    /// ```
+    DateTrunc,
+    /// SQL 'age' function.
+    /// Returns the difference between two dates/datetimes as a struct of whole "months",
+    /// "days" and the remaining "time"; if only one argument is given, the difference
+    /// between (midnight) today and the argument is returned.
+    /// ```sql
+    /// SELECT AGE(col1, col2) FROM df;
+    /// SELECT AGE(col1) FROM df;
+    /// ```
+    Age,
     /// SQL 'strftime' function.
     /// Converts a datetime to a string using a format string.
     /// ```sql
@@ -783,6 +799,8 @@ pub(crate) enum PolarsSQLFunctions {
     // User-defined
     // ----
     Udf(String),
+    /// Function registered with [`SQLContext::register_function`].
+    Registered(String),
 }
 
 impl PolarsSQLFunctions {
@@ -791,6 +809,7 @@ impl PolarsSQLFunctions {
             "abs",
             "acos",
             "acosd",
+            "age",
             "array_contains",
             "array_get",
             "array_length",
@@ -833,6 +852,7 @@ impl PolarsSQLFunctions {
             "covar_samp",
             "date",
             "date_part",
+            "date_trunc",
             "degrees",
             "dense_rank",
             "ends_with",
@@ -977,8 +997,10 @@ impl PolarsSQLFunctions {
             // ----
             // Temporal functions
             // ----
+            "age" => Self::Age,
             "date" => Self::Date,
             "date_part" => Self::DatePart,
+            "date_trunc" => Self::DateTrunc,
             "strftime" => Self::Strftime,
             "timestamp" | "datetime" => Self::Timestamp,
 
@@ -1067,7 +1089,9 @@ impl PolarsSQLFunctions {
             "columns" => Self::Columns,
 
             other => {
-                if ctx.function_registry.contains(other) {
+                if ctx.sql_functions.contains_key(other) {
+                    Self::Registered(other.to_string())
+                } else if ctx.function_registry.contains(other) {
                     Self::Udf(other.to_string())
                 } else {
                     polars_bail!(SQLInterface: "unsupported function '{}'", other);
@@ -1250,6 +1274,39 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             }),
+            DateTrunc => self.try_visit_binary(|part, e| match part {
+                Expr::Literal(p) if p.extract_str().is_some() => {
+                    let p = p.extract_str().unwrap().to_ascii_lowercase();
+                    let every = match p.as_str() {
+                        "millennium" | "millennia" => "1000y",
+                        "century" | "centuries" => "100y",
+                        "decade" | "decades" => "10y",
+                        "year" | "years" | "y" => "1y",
+                        "quarter" | "quarters" => "1q",
+                        "month" | "months" | "mon" | "mons" => "1mo",
+                        "week" | "weeks" | "isoweek" => "1w",
+                        "day" | "days" | "d" => "1d",
+                        "hour" | "hours" | "h" => "1h",
+                        "minute" | "minutes" | "min" | "mins" | "m" => "1m",
+                        "second" | "seconds" | "sec" | "secs" | "s" => "1s",
+                        "millisecond" | "milliseconds" | "ms" => "1ms",
+                        "microsecond" | "microseconds" | "us" => "1us",
+                        _ => polars_bail!(SQLSyntax: "DATE_TRUNC does not support '{}' part", p),
+                    };
+                    Ok(e.dt().truncate(lit(every)))
+                },
+                _ => polars_bail!(SQLSyntax: "invalid 'part' for DATE_TRUNC ({})", part),
+            }),
+            Age => {
+                let args = extract_args(function)?;
+                match args.len() {
+                    1 => self.visit_unary(|e| age(today(), e)),
+                    2 => self.visit_binary(age),
+                    _ => {
+                        polars_bail!(SQLSyntax: "AGE expects 1-2 arguments (found {})", args.len())
+                    },
+                }
+            },
             Strftime => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1771,6 +1828,7 @@ impl SQLFunctionVisitor<'_> {
             // User-defined
             // ----
             Udf(func_name) => self.visit_udf(&func_name),
+            Registered(func_name) => self.visit_registered_function(&func_name),
         }
     }
 
@@ -1830,6 +1888,31 @@ impl SQLFunctionVisitor<'_> {
             .call(args))
     }
 
+    fn visit_registered_function(&mut self, func_name: &str) -> PolarsResult<Expr> {
+        let (signature, function) = self.ctx.sql_functions[func_name].clone();
+        let args = extract_args(self.func)?
+            .into_iter()
+            .map(|arg| {
+                if let FunctionArgExpr::Expr(e) = arg {
+                    parse_sql_expr(e, self.ctx, self.active_schema)
+                } else {
+                    polars_bail!(SQLInterface: "only expressions are supported as arguments to {}", func_name)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        signature.check_arity(func_name, args.len())?;
+        if let Some(schema) = self.active_schema {
+            for (idx, arg) in args.iter().enumerate() {
+                if !matches!(arg, Expr::Literal(LiteralValue::Dyn(_))) {
+                    let dtype = arg.to_field(schema)?.dtype;
+                    signature.check_arg_type(func_name, idx, &dtype)?;
+                }
+            }
+        }
+        function(args).and_then(|e| self.apply_window_spec(e, &self.func.over))
+    }
+
     /// Validate window frame specifications.
     ///
     /// Polars only supports ROWS frame semantics, and does
@@ -2347,6 +2430,63 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// The current UTC date, determined when the query is executed.
+fn today() -> Expr {
+    lit(0i32).map(
+        |c| {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+            let today = Scalar::new(DataType::Date, AnyValue::Date((secs / 86_400) as i32));
+            Ok(Column::new_scalar(c.name().clone(), today, 1))
+        },
+        |_, field| Ok(Field::new(field.name().clone(), DataType::Date)),
+    )
+}
+
+/// The difference between two dates/datetimes as a struct of whole "months", "days" and the
+/// remaining "time", like the PostgreSQL interval returned by `AGE`.
+///
+/// The fields are subtracted separately, borrowing days from the month of `start` and
+/// borrowing a day if the time of day of `end` is earlier. If `end` is before `start` all the
+/// fields of the (negated) difference in the other direction are negative.
+fn age(end: Expr, start: Expr) -> Expr {
+    let negative = end.clone().lt(start.clone());
+    let hi = when(negative.clone())
+        .then(start.clone())
+        .otherwise(end.clone());
+    let lo = when(negative.clone()).then(end).otherwise(start);
+
+    let time_of_day = |e: Expr| (e.clone() - e.dt().truncate(lit("1d"))).dt();
+    let us = time_of_day(hi.clone()).total_microseconds(false)
+        - time_of_day(lo.clone()).total_microseconds(false);
+    let borrow_day = us.clone().lt(lit(0i64));
+    let us = us + when(borrow_day.clone())
+        .then(lit(86_400_000_000i64))
+        .otherwise(lit(0i64));
+
+    let int = |e: Expr| e.cast(DataType::Int32);
+    let days = int(hi.clone().dt().day()) - int(lo.clone().dt().day()) - int(borrow_day);
+    let borrow_month = days.clone().lt(lit(0i32));
+    let days = days
+        + when(borrow_month.clone())
+            .then(int(lo.clone().dt().days_in_month()))
+            .otherwise(lit(0i32));
+    let months = (int(hi.clone().dt().year()) - int(lo.clone().dt().year())) * lit(12i32)
+        + int(hi.dt().month())
+        - int(lo.dt().month())
+        - int(borrow_month);
+
+    let sign = when(negative).then(lit(-1i32)).otherwise(lit(1i32));
+    as_struct(vec![
+        (months * sign.clone()).alias("months"),
+        (days * sign.clone()).alias("days"),
+        (us * sign.cast(DataType::Int64))
+            .cast(DataType::Duration(TimeUnit::Microseconds))
+            .alias("time"),
+    ])
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -7,8 +7,9 @@ mod functions;
 pub mod keywords;
 mod sql_expr;
 mod sql_visitors;
+mod subqueries;
 mod table_functions;
 mod types;
 
-pub use context::{SQLContext, extract_table_identifiers};
+pub use context::{SQLContext, SQLDialect, SQLParams, extract_table_identifiers};
 pub use sql_expr::sql_expr;
@@ -6,6 +6,7 @@
 //! - all Polars SQL keywords [`all_keywords`]
 //! - all Polars SQL functions [`all_functions`]
 
+use std::cmp::Ordering;
 use std::fmt::Display;
 use std::ops::Div;
 
@@ -233,7 +234,7 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
-            SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
+            SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval)?)),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
             },
@@ -575,37 +576,38 @@ impl SQLExprVisitor<'_> {
 
         // need special handling for interval offsets and comparisons
         let (lhs, mut rhs) = match (left, op, right) {
-            (_, SQLBinaryOperator::Minus, SQLExpr::Interval(v)) => {
-                let duration = interval_to_duration(v, false)?;
-                return Ok(self
-                    .visit_expr(left)?
-                    .dt()
-                    .offset_by(lit(format!("-{duration}"))));
+            (_, SQLBinaryOperator::Minus | SQLBinaryOperator::Plus, SQLExpr::Interval(v))
+                if !matches!(left, SQLExpr::Interval(_)) =>
+            {
+                let negate = matches!(op, SQLBinaryOperator::Minus);
+                return offset_by_interval(self.visit_expr(left)?, v, negate);
             },
-            (_, SQLBinaryOperator::Plus, SQLExpr::Interval(v)) => {
-                let duration = interval_to_duration(v, false)?;
-                return Ok(self
-                    .visit_expr(left)?
-                    .dt()
-                    .offset_by(lit(format!("{duration}"))));
+            (SQLExpr::Interval(v), SQLBinaryOperator::Plus, _)
+                if !matches!(right, SQLExpr::Interval(_)) =>
+            {
+                return offset_by_interval(self.visit_expr(right)?, v, false);
             },
             (SQLExpr::Interval(v1), _, SQLExpr::Interval(v2)) => {
                 // shortcut interval comparison evaluation (-> bool)
-                let d1 = interval_to_duration(v1, false)?;
-                let d2 = interval_to_duration(v2, false)?;
-                let res = match op {
-                    SQLBinaryOperator::Gt => Ok(lit(d1 > d2)),
-                    SQLBinaryOperator::Lt => Ok(lit(d1 < d2)),
-                    SQLBinaryOperator::GtEq => Ok(lit(d1 >= d2)),
-                    SQLBinaryOperator::LtEq => Ok(lit(d1 <= d2)),
-                    SQLBinaryOperator::NotEq => Ok(lit(d1 != d2)),
-                    SQLBinaryOperator::Eq | SQLBinaryOperator::Spaceship => Ok(lit(d1 == d2)),
-                    _ => polars_bail!(SQLInterface: "invalid interval comparison operator"),
+                let ord = compare_intervals(v1, v2)?;
+                let ordered = |f: fn(Ordering) -> bool| match ord {
+                    Some(ord) => Ok(lit(f(ord))),
+                    None => polars_bail!(
+                        SQLInterface: "cannot order intervals {} and {} as the length of their months varies",
+                        v1, v2
+                    ),
                 };
-                if res.is_ok() {
-                    return res;
+                match op {
+                    SQLBinaryOperator::Gt => return ordered(Ordering::is_gt),
+                    SQLBinaryOperator::Lt => return ordered(Ordering::is_lt),
+                    SQLBinaryOperator::GtEq => return ordered(Ordering::is_ge),
+                    SQLBinaryOperator::LtEq => return ordered(Ordering::is_le),
+                    SQLBinaryOperator::NotEq => return Ok(lit(ord != Some(Ordering::Equal))),
+                    SQLBinaryOperator::Eq | SQLBinaryOperator::Spaceship => {
+                        return Ok(lit(ord == Some(Ordering::Equal)));
+                    },
+                    _ => (self.visit_expr(left)?, self.visit_expr(right)?),
                 }
-                (self.visit_expr(left)?, self.visit_expr(right)?)
             },
             _ => (self.visit_expr(left)?, self.visit_expr(right)?),
         };
@@ -639,7 +641,14 @@ impl SQLExprVisitor<'_> {
             // ----
             // Mathematical operators
             // ----
-            SQLBinaryOperator::Divide => lhs / rhs,  // "x / y"
+            SQLBinaryOperator::Divide => {  // "x / y"
+                if self.ctx.dialect.has_true_division() {
+                    binary_expr(lhs, Operator::TrueDivide, rhs)
+                } else {
+                    lhs / rhs
+                }
+            },
+            SQLBinaryOperator::MyIntegerDivide => (lhs / rhs).cast(DataType::Int64),  // "x DIV y"
             SQLBinaryOperator::DuckIntegerDivide => lhs.floor_div(rhs).cast(DataType::Int64),  // "x // y"
             SQLBinaryOperator::Minus => lhs - rhs,  // "x - y"
             SQLBinaryOperator::Modulo => lhs % rhs,  // "x % y"
@@ -955,6 +964,7 @@ impl SQLExprVisitor<'_> {
                 bitstring_to_bytes_literal(b)?
             },
             SQLValue::SingleQuotedString(s) => lit(s.clone()),
+            SQLValue::Placeholder(p) => lit(self.ctx.get_bound_param(p)?.clone()),
             other => {
                 polars_bail!(SQLInterface: "value {:?} is not a supported literal type", other)
             },
@@ -1016,6 +1026,9 @@ impl SQLExprVisitor<'_> {
                 }
             },
             SQLValue::SingleQuotedString(s) => AnyValue::StringOwned(s.as_str().into()),
+            SQLValue::Placeholder(p) if op.is_none() => {
+                self.ctx.get_bound_param(p)?.value().clone().into_static()
+            },
             other => polars_bail!(SQLInterface: "value {:?} is not currently supported", other),
         })
     }
@@ -1316,9 +1329,10 @@ pub fn sql_expr<S: AsRef<str>>(s: S) -> PolarsResult<Expr> {
     })
 }
 
-pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsResult<Duration> {
+/// Parse an SQL interval into its (signed) components; as in PostgreSQL, each part
+/// of the interval string can carry its own sign, eg: `INTERVAL '1 month -3 days'`.
+pub(crate) fn interval_to_durations(interval: &Interval) -> PolarsResult<Vec<Duration>> {
     if interval.last_field.is_some()
-        || interval.leading_field.is_some()
         || interval.leading_precision.is_some()
         || interval.fractional_seconds_precision.is_some()
     {
@@ -1331,26 +1345,114 @@ pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsRe
         SQLExpr::Value(ValueWithSpan {
             value: SQLValue::SingleQuotedString(s),
             ..
-        }) => Some(s),
-        _ => None,
+        }) => s,
+        // eg: "INTERVAL 3 DAYS"
+        SQLExpr::Value(ValueWithSpan {
+            value: SQLValue::Number(n, _),
+            ..
+        }) if interval.leading_field.is_some() => n,
+        _ => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
     };
-    match s {
-        Some(s) if s.contains('-') => {
-            polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
-        },
-        Some(s) => {
-            // years, quarters, and months do not have a fixed duration; these
-            // interval parts can only be used with respect to a reference point
-            let duration = Duration::parse_interval(s);
-            if fixed && duration.months() != 0 {
-                polars_bail!(SQLSyntax: "fixed-duration interval cannot contain years, quarters, or months; found {}", s)
-            };
-            Ok(duration)
-        },
-        None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
+    // eg: "INTERVAL '3' DAY"
+    let s = match &interval.leading_field {
+        Some(field) => format!("{s} {field}"),
+        None => s.clone(),
+    };
+
+    // split into separately-signed parts, eg: "1 month -3 days" -> ["1 month", "-3 days"]
+    let mut parts = vec![];
+    let mut start = 0;
+    for (idx, ch) in s.char_indices() {
+        if (ch == '-' || ch == '+') && !s[start..idx].trim().is_empty() {
+            parts.push(&s[start..idx]);
+            start = idx;
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+        .into_iter()
+        .map(|part| {
+            let part = part.trim();
+            polars_ensure!(!part.is_empty(), SQLSyntax: "invalid interval '{}'", s);
+            Duration::try_parse_interval(part)
+                .map_err(|err| polars_err!(SQLSyntax: "invalid interval '{}': {}", s, err))
+        })
+        .collect()
+}
+
+/// Parse an SQL interval that has a fixed length, so it can't contain years, quarters or months.
+pub(crate) fn interval_to_duration(interval: &Interval) -> PolarsResult<Duration> {
+    let durations = interval_to_durations(interval)?;
+
+    // years, quarters, and months do not have a fixed duration; these
+    // interval parts can only be used with respect to a reference point
+    if durations.iter().any(|d| d.months() != 0) {
+        polars_bail!(SQLSyntax: "fixed-duration interval cannot contain years, quarters, or months; found {}", interval.value)
+    };
+    match durations.as_slice() {
+        [duration] => Ok(*duration),
+        _ => Ok(Duration::parse(&format!("{}ns", interval_months_and_ns(&durations).1))),
     }
 }
 
+const NS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// The signed number of calendar months and of fixed-length nanoseconds in an interval.
+fn interval_months_and_ns(durations: &[Duration]) -> (i64, i64) {
+    durations.iter().fold((0, 0), |(months, ns), d| {
+        let sign = if d.negative() { -1 } else { 1 };
+        let days = d.weeks() * 7 + d.days();
+        (
+            months + sign * d.months(),
+            ns + sign * (days * NS_PER_DAY + d.nanoseconds()),
+        )
+    })
+}
+
+/// Compare two intervals for any reference point, or `None` if the order depends on the
+/// length of the months in the intervals.
+///
+/// Intervals are only equal if they have the same number of months and the same fixed-length
+/// part, eg: `INTERVAL '1 month'` is neither equal to nor ordered against `INTERVAL '30 days'`.
+fn compare_intervals(v1: &Interval, v2: &Interval) -> PolarsResult<Option<Ordering>> {
+    let (months1, ns1) = interval_months_and_ns(&interval_to_durations(v1)?);
+    let (months2, ns2) = interval_months_and_ns(&interval_to_durations(v2)?);
+    let (months, ns) = (months1 - months2, ns1 - ns2);
+    if months == 0 {
+        return Ok(Some(ns.cmp(&0)));
+    }
+    // a calendar month is between 28 and 31 days long
+    let (shortest, longest) = if months > 0 { (28, 31) } else { (31, 28) };
+    let lower = ns + months * shortest * NS_PER_DAY;
+    let upper = ns + months * longest * NS_PER_DAY;
+    Ok(if lower > 0 {
+        Some(Ordering::Greater)
+    } else if upper < 0 {
+        Some(Ordering::Less)
+    } else {
+        None
+    })
+}
+
+/// Offset a temporal expression by an SQL interval; calendar-relative parts of the
+/// interval (months, then days) are applied before fixed-length parts, and offsets
+/// respect the time zone of the expression (if any).
+pub(crate) fn offset_by_interval(
+    expr: Expr,
+    interval: &Interval,
+    negate: bool,
+) -> PolarsResult<Expr> {
+    let mut durations = interval_to_durations(interval)?;
+    durations.sort_by_key(|d| (d.months() == 0, d.weeks() == 0 && d.days() == 0));
+    Ok(durations
+        .into_iter()
+        .filter(|d| !d.is_zero())
+        .fold(expr, |expr, d| {
+            let d = if negate { -d } else { d };
+            expr.dt().offset_by(lit(d.to_string()))
+        }))
+}
+
 pub(crate) fn parse_sql_expr(
     expr: &SQLExpr,
     ctx: &mut SQLContext,
@@ -1399,8 +1501,14 @@ pub(crate) fn parse_extract_date_part(expr: Expr, field: &DateTimeField) -> Pola
                 "nanosecond" | "nanoseconds" | "ns" => &DateTimeField::Nanosecond,
                 #[cfg(feature = "timezones")]
                 "timezone" => &DateTimeField::Timezone,
+                #[cfg(feature = "timezones")]
+                "timezone_hour" => &DateTimeField::TimezoneHour,
+                #[cfg(feature = "timezones")]
+                "timezone_minute" => &DateTimeField::TimezoneMinute,
+                "date" => &DateTimeField::Date,
                 "time" => &DateTimeField::Time,
                 "epoch" => &DateTimeField::Epoch,
+                "julian" => &DateTimeField::Julian,
                 _ => {
                     polars_bail!(SQLSyntax: "EXTRACT/DATE_PART does not support '{}' part", value)
                 },
@@ -1409,7 +1517,7 @@ pub(crate) fn parse_extract_date_part(expr: Expr, field: &DateTimeField) -> Pola
         _ => field,
     };
     Ok(match field {
-        DateTimeField::Millennium => expr.dt().millennium(),
+        DateTimeField::Millennium | DateTimeField::Millenium => expr.dt().millennium(),
         DateTimeField::Century => expr.dt().century(),
         DateTimeField::Decade => expr.dt().year() / typed_lit(10i32),
         DateTimeField::Isoyear => expr.dt().iso_year(),
@@ -1446,9 +1554,27 @@ pub(crate) fn parse_extract_date_part(expr: Expr, field: &DateTimeField) -> Pola
         DateTimeField::Nanosecond | DateTimeField::Nanoseconds => {
             (expr.clone().dt().second() * typed_lit(1_000_000_000f64)) + expr.dt().nanosecond()
         },
+        DateTimeField::Date => expr.dt().date(),
         DateTimeField::Time => expr.dt().time(),
         #[cfg(feature = "timezones")]
         DateTimeField::Timezone => expr.dt().base_utc_offset().dt().total_seconds(false),
+        #[cfg(feature = "timezones")]
+        DateTimeField::TimezoneHour | DateTimeField::TimezoneMinute => {
+            // offset components truncate towards zero (eg: -03:30 => -3, -30)
+            let secs = expr.dt().base_utc_offset().dt().total_seconds(false);
+            let hours =
+                (secs.clone().cast(DataType::Float64) / typed_lit(3600f64)).cast(DataType::Int32);
+            if matches!(field, DateTimeField::TimezoneHour) {
+                hours
+            } else {
+                ((secs - hours.cast(DataType::Int64) * typed_lit(3600i64)) / typed_lit(60i64))
+                    .cast(DataType::Int32)
+            }
+        },
+        DateTimeField::Julian => {
+            // days since the start of the Julian Period (4714-11-24 BC)
+            expr.dt().date().cast(DataType::Int32) + typed_lit(2_440_588i32)
+        },
         DateTimeField::Epoch => {
             expr.clone()
                 .dt()
@@ -6,9 +6,15 @@
 use std::ops::ControlFlow;
 
 use polars_core::prelude::*;
-use sqlparser::ast::{Expr as SQLExpr, ObjectName, Query, SetExpr, Visit, Visitor as SQLVisitor};
+use sqlparser::ast::{
+    BinaryOperator as SQLBinaryOperator, Expr as SQLExpr, Ident, ObjectName, ObjectNamePart, Query,
+    SetExpr, Statement, Value as SQLValue, ValueWithSpan, Visit, VisitMut, Visitor as SQLVisitor,
+    VisitorMut as SQLVisitorMut,
+};
 use sqlparser::keywords::ALL_KEYWORDS;
 
+use crate::context::{SQLDialect, SQLParams};
+
 // ---------------------------------------------------------------------------
 // FindTableIdentifier
 // ---------------------------------------------------------------------------
@@ -263,3 +269,165 @@ impl SQLVisitor for WindowFunctionFinder {
 pub(crate) fn expr_has_window_functions(expr: &SQLExpr) -> bool {
     expr.visit(&mut WindowFunctionFinder).is_break()
 }
+
+// ---------------------------------------------------------------------------
+// ColumnRefCollector
+// ---------------------------------------------------------------------------
+
+/// Visitor that collects all (possibly qualified) column references in a SQL expression.
+struct ColumnRefCollector {
+    refs: Vec<Vec<String>>,
+}
+
+impl SQLVisitor for ColumnRefCollector {
+    type Break = ();
+
+    fn pre_visit_expr(&mut self, expr: &SQLExpr) -> ControlFlow<Self::Break> {
+        match expr {
+            SQLExpr::Identifier(ident) => self.refs.push(vec![ident.value.clone()]),
+            SQLExpr::CompoundIdentifier(idents) if !idents.is_empty() => self
+                .refs
+                .push(idents.iter().map(|i| i.value.clone()).collect()),
+            _ => {},
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Collect the column references of a SQL expression, as identifier paths
+/// (eg: `tbl.col` is returned as `["tbl", "col"]`).
+pub(crate) fn collect_column_refs(expr: &SQLExpr) -> Vec<Vec<String>> {
+    let mut collector = ColumnRefCollector { refs: vec![] };
+    let _ = expr.visit(&mut collector);
+    collector.refs
+}
+
+// ---------------------------------------------------------------------------
+// PlaceholderBinder
+// ---------------------------------------------------------------------------
+
+/// Visitor that resolves the placeholders of a parameterized statement against the
+/// given parameters (anonymous `?` placeholders are normalised to `$n` in-place).
+struct PlaceholderBinder<'a> {
+    params: &'a SQLParams,
+    n_anonymous: usize,
+    bound: PlHashMap<String, Scalar>,
+}
+
+impl PlaceholderBinder<'_> {
+    fn resolve(&mut self, placeholder: &mut String) -> PolarsResult<()> {
+        if placeholder == "?" {
+            self.n_anonymous += 1;
+            *placeholder = format!("${}", self.n_anonymous);
+        }
+        if self.bound.contains_key(placeholder.as_str()) {
+            return Ok(());
+        }
+        let value = match placeholder.split_at_checked(1) {
+            Some(("$", n)) if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()) => {
+                let idx: usize = n.parse().unwrap_or(0);
+                polars_ensure!(
+                    idx > 0,
+                    SQLSyntax: "positional placeholders are 1-indexed; found '{}'", placeholder
+                );
+                self.params.get_positional(idx - 1)
+            },
+            Some(("$" | ":" | "@", name)) if !name.is_empty() => self.params.get_named(name),
+            _ => polars_bail!(SQLSyntax: "unsupported placeholder '{}'", placeholder),
+        };
+        match value {
+            Some(value) => {
+                self.bound.insert(placeholder.clone(), value.clone());
+                Ok(())
+            },
+            None => {
+                polars_bail!(SQLInterface: "no value bound to placeholder '{}'", placeholder)
+            },
+        }
+    }
+}
+
+impl SQLVisitorMut for PlaceholderBinder<'_> {
+    type Break = PolarsError;
+
+    fn pre_visit_expr(&mut self, expr: &mut SQLExpr) -> ControlFlow<Self::Break> {
+        if let SQLExpr::Value(ValueWithSpan {
+            value: SQLValue::Placeholder(placeholder),
+            ..
+        }) = expr
+        {
+            if let Err(err) = self.resolve(placeholder) {
+                return ControlFlow::Break(err);
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Bind the placeholders of a parsed statement to the given parameters, returning
+/// the values keyed by their (normalised) placeholder.
+pub(crate) fn bind_placeholders(
+    stmt: &mut Statement,
+    params: &SQLParams,
+) -> PolarsResult<PlHashMap<String, Scalar>> {
+    if params.is_empty() {
+        return Ok(PlHashMap::new());
+    }
+    let mut binder = PlaceholderBinder {
+        params,
+        n_anonymous: 0,
+        bound: PlHashMap::new(),
+    };
+    match VisitMut::visit(stmt, &mut binder) {
+        ControlFlow::Break(err) => Err(err),
+        ControlFlow::Continue(()) => Ok(binder.bound),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// DialectNormalizer
+// ---------------------------------------------------------------------------
+
+/// Visitor that rewrites dialect-specific syntax into its generic equivalent
+/// (double-quoted string literals, logical `||`, and function-name aliases).
+struct DialectNormalizer {
+    dialect: SQLDialect,
+}
+
+impl SQLVisitorMut for DialectNormalizer {
+    type Break = ();
+
+    fn pre_visit_expr(&mut self, expr: &mut SQLExpr) -> ControlFlow<Self::Break> {
+        match expr {
+            SQLExpr::Value(ValueWithSpan { value, .. })
+                if self.dialect.has_double_quoted_strings() =>
+            {
+                if let SQLValue::DoubleQuotedString(s) = value {
+                    *value = SQLValue::SingleQuotedString(std::mem::take(s));
+                }
+            },
+            SQLExpr::BinaryOp { op, .. }
+                if self.dialect.has_logical_pipes()
+                    && matches!(op, SQLBinaryOperator::StringConcat) =>
+            {
+                *op = SQLBinaryOperator::Or;
+            },
+            SQLExpr::Function(func) => {
+                if let [ObjectNamePart::Identifier(ident)] = func.name.0.as_slice() {
+                    if let Some(alias) = self.dialect.function_alias(&ident.value.to_lowercase()) {
+                        func.name = ObjectName::from(vec![Ident::new(alias)]);
+                    }
+                }
+            },
+            _ => {},
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Rewrite the dialect-specific syntax of a parsed statement in-place.
+pub(crate) fn normalize_dialect(stmt: &mut Statement, dialect: SQLDialect) {
+    if dialect != SQLDialect::Generic {
+        let _ = VisitMut::visit(stmt, &mut DialectNormalizer { dialect });
+    }
+}
@@ -0,0 +1,411 @@
+//! Decorrelation of subqueries in `WHERE` clauses.
+//!
+//! Correlated subqueries reference columns of the outer query, which can't be expressed as an
+//! isolated subplan. Subqueries that are correlated through equality predicates are rewritten
+//! into joins on the correlation keys instead:
+//!
+//! * `EXISTS (...)` / `NOT EXISTS (...)` become a semi / anti join.
+//! * `expr IN (...)` becomes a semi join that also matches on `expr`.
+//! * `expr <op> (SELECT agg ...)` becomes a left join on the per-key aggregate.
+//!
+//! This is done while translating the SQL rather than as an optimizer pass on the IR: a
+//! reference to a column of the outer query has no representation in the DSL or IR, so a
+//! correlated subquery can't be lowered into a plan for such a pass to rewrite. The joins that
+//! are produced are regular joins, which the optimizer pushes predicates and projections into
+//! as usual. Subqueries that are correlated in other ways (e.g. through a non-equality
+//! predicate) are not rewritten and are still executed as an isolated subplan.
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_ops::frame::MaintainOrderJoin;
+use polars_utils::unique_column_name;
+use sqlparser::ast::{
+    BinaryOperator as SQLBinaryOperator, Expr as SQLExpr, GroupByExpr, Ident, Query, Select,
+    SelectItem, SetExpr, TableFactor,
+};
+
+use crate::SQLContext;
+use crate::sql_expr::parse_sql_expr;
+use crate::sql_visitors::collect_column_refs;
+
+/// The correlation of a subquery with the outer query.
+struct Correlation {
+    /// The (simple) subquery select, with the correlation predicates removed.
+    select: Select,
+    query: Query,
+    /// Equality predicates between the inner (subquery) and outer query.
+    keys: Vec<(SQLExpr, SQLExpr)>,
+}
+
+/// Split a SQL expression into its top-level `AND` conjuncts.
+fn split_conjunctions(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: SQLBinaryOperator::And,
+            right,
+        } => {
+            let mut out = split_conjunctions(left);
+            out.extend(split_conjunctions(right));
+            out
+        },
+        SQLExpr::Nested(inner)
+            if matches!(
+                **inner,
+                SQLExpr::BinaryOp {
+                    op: SQLBinaryOperator::And,
+                    ..
+                }
+            ) =>
+        {
+            split_conjunctions(inner)
+        },
+        _ => vec![expr],
+    }
+}
+
+fn combine_conjunctions(exprs: Vec<SQLExpr>) -> Option<SQLExpr> {
+    exprs.into_iter().reduce(|left, right| SQLExpr::BinaryOp {
+        left: Box::new(left),
+        op: SQLBinaryOperator::And,
+        right: Box::new(right),
+    })
+}
+
+fn unnest(expr: &SQLExpr) -> &SQLExpr {
+    match expr {
+        SQLExpr::Nested(inner) => unnest(inner),
+        e => e,
+    }
+}
+
+fn is_count(expr: &SQLExpr) -> bool {
+    matches!(unnest(expr), SQLExpr::Function(f) if f.name.to_string().eq_ignore_ascii_case("count"))
+}
+
+impl SQLContext {
+    /// Rewrite the correlated subqueries in the top-level conjuncts of a `WHERE` clause into
+    /// joins. Returns the joined frame and the conjuncts that still have to be applied as a
+    /// filter (if any).
+    pub(crate) fn decorrelate_subqueries(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &SQLExpr,
+        schema: &SchemaRef,
+    ) -> PolarsResult<(LazyFrame, Option<SQLExpr>)> {
+        let mut remaining = vec![];
+        for conjunct in split_conjunctions(expr) {
+            match self.decorrelate_conjunct(lf.clone(), conjunct, schema)? {
+                Some(decorrelated) => lf = decorrelated,
+                None => remaining.push(conjunct.clone()),
+            }
+        }
+        Ok((lf, combine_conjunctions(remaining)))
+    }
+
+    fn decorrelate_conjunct(
+        &mut self,
+        lf: LazyFrame,
+        expr: &SQLExpr,
+        schema: &SchemaRef,
+    ) -> PolarsResult<Option<LazyFrame>> {
+        match unnest(expr) {
+            #[cfg(feature = "semi_anti_join")]
+            SQLExpr::Exists { subquery, negated } => {
+                let Some(correlation) = self.find_correlation(subquery, schema)? else {
+                    return Ok(None);
+                };
+                let how = if *negated {
+                    JoinType::Anti
+                } else {
+                    JoinType::Semi
+                };
+                self.join_correlated(lf, correlation, None, how, schema)
+                    .map(Some)
+            },
+            #[cfg(feature = "semi_anti_join")]
+            SQLExpr::InSubquery {
+                expr,
+                subquery,
+                negated: false,
+            } => {
+                let Some(correlation) = self.find_correlation(subquery, schema)? else {
+                    return Ok(None);
+                };
+                let [
+                    SelectItem::UnnamedExpr(value) | SelectItem::ExprWithAlias { expr: value, .. },
+                ] = correlation.select.projection.as_slice()
+                else {
+                    return Ok(None);
+                };
+                let value = value.clone();
+                self.join_correlated(
+                    lf,
+                    correlation,
+                    Some((value, (**expr).clone())),
+                    JoinType::Semi,
+                    schema,
+                )
+                .map(Some)
+            },
+            SQLExpr::BinaryOp { left, op, right } => {
+                let (subquery, subquery_is_left) = match (unnest(left), unnest(right)) {
+                    (SQLExpr::Subquery(q), _) => (q, true),
+                    (_, SQLExpr::Subquery(q)) => (q, false),
+                    _ => return Ok(None),
+                };
+                let Some(correlation) = self.find_correlation(subquery, schema)? else {
+                    return Ok(None);
+                };
+                self.join_scalar_subquery(
+                    lf,
+                    correlation,
+                    op,
+                    left,
+                    right,
+                    subquery_is_left,
+                    schema,
+                )
+                .map(Some)
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Determine the correlation keys of a subquery. Returns `None` if the subquery is not
+    /// correlated, or if it is correlated in a way that can't be rewritten into a join.
+    fn find_correlation(
+        &mut self,
+        subquery: &Query,
+        outer_schema: &Schema,
+    ) -> PolarsResult<Option<Correlation>> {
+        let SetExpr::Select(select) = &*subquery.body else {
+            return Ok(None);
+        };
+        if subquery.with.is_some()
+            || subquery.limit_clause.is_some()
+            || subquery.fetch.is_some()
+            || select.from.len() != 1
+            || !select.from[0].joins.is_empty()
+            || select.having.is_some()
+            || !matches!(&select.group_by, GroupByExpr::Expressions(e, _) if e.is_empty())
+        {
+            return Ok(None);
+        }
+        let TableFactor::Table { name, alias, .. } = &select.from[0].relation else {
+            return Ok(None);
+        };
+        let Some(tbl_name) = name.0.last().and_then(|p| p.as_ident()) else {
+            return Ok(None);
+        };
+        let Some(mut inner_lf) = self.get_table_from_current_scope(&tbl_name.value) else {
+            return Ok(None);
+        };
+        let inner_schema = self.get_frame_schema(&mut inner_lf)?;
+        let inner_names = [Some(&tbl_name.value), alias.as_ref().map(|a| &a.name.value)];
+
+        // A column reference belongs to the outer query if it is qualified with a
+        // table that isn't the subquery table, or if only the outer query has it.
+        let is_outer = |path: &Vec<String>| match path.as_slice() {
+            [name] => !inner_schema.contains(name) && outer_schema.contains(name),
+            [tbl, ..] => !inner_names.contains(&Some(tbl)),
+            [] => false,
+        };
+        let refs_outer = |e: &SQLExpr| collect_column_refs(e).iter().any(is_outer);
+        let only_outer = |e: &SQLExpr| {
+            let refs = collect_column_refs(e);
+            !refs.is_empty() && refs.iter().all(is_outer)
+        };
+
+        let mut keys = vec![];
+        let mut local = vec![];
+        if let Some(selection) = &select.selection {
+            for conjunct in split_conjunctions(selection) {
+                if !refs_outer(conjunct) {
+                    local.push(conjunct.clone());
+                    continue;
+                }
+                let SQLExpr::BinaryOp {
+                    left,
+                    op: SQLBinaryOperator::Eq,
+                    right,
+                } = unnest(conjunct)
+                else {
+                    return Ok(None);
+                };
+                if !refs_outer(left) && only_outer(right) {
+                    keys.push(((**left).clone(), (**right).clone()));
+                } else if !refs_outer(right) && only_outer(left) {
+                    keys.push(((**right).clone(), (**left).clone()));
+                } else {
+                    return Ok(None);
+                }
+            }
+        }
+        let projection_refs_outer = select.projection.iter().any(|item| match item {
+            SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. } => refs_outer(e),
+            _ => false,
+        });
+        if keys.is_empty() || projection_refs_outer {
+            return Ok(None);
+        }
+
+        let mut select = (**select).clone();
+        select.selection = combine_conjunctions(local);
+        Ok(Some(Correlation {
+            select,
+            query: subquery.clone(),
+            keys,
+        }))
+    }
+
+    /// Execute the decorrelated subquery, projecting the inner correlation keys (and `value`)
+    /// under fresh names. Returns the frame and the names of the projected keys.
+    fn execute_decorrelated(
+        &mut self,
+        correlation: &Correlation,
+        value: Option<(&SQLExpr, &str)>,
+        group_by_keys: bool,
+    ) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+        let key_names: Vec<PlSmallStr> = correlation
+            .keys
+            .iter()
+            .map(|_| unique_column_name())
+            .collect();
+
+        let mut select = correlation.select.clone();
+        select.projection = correlation
+            .keys
+            .iter()
+            .zip(&key_names)
+            .map(|((inner, _), name)| SelectItem::ExprWithAlias {
+                expr: inner.clone(),
+                alias: Ident::new(name.as_str()),
+            })
+            .chain(value.map(|(expr, name)| SelectItem::ExprWithAlias {
+                expr: expr.clone(),
+                alias: Ident::new(name),
+            }))
+            .collect();
+        if group_by_keys {
+            select.group_by = GroupByExpr::Expressions(
+                correlation
+                    .keys
+                    .iter()
+                    .map(|(inner, _)| inner.clone())
+                    .collect(),
+                vec![],
+            );
+        }
+
+        let mut query = correlation.query.clone();
+        query.body = Box::new(SetExpr::Select(Box::new(select)));
+        query.order_by = None;
+
+        let lf = self.execute_isolated(|ctx| ctx.execute_query_no_ctes(&query))?;
+        Ok((lf, key_names))
+    }
+
+    fn outer_keys(
+        &mut self,
+        correlation: &Correlation,
+        schema: &SchemaRef,
+    ) -> PolarsResult<Vec<Expr>> {
+        correlation
+            .keys
+            .iter()
+            .map(|(_, outer)| parse_sql_expr(outer, self, Some(schema.as_ref())))
+            .collect()
+    }
+
+    #[cfg(feature = "semi_anti_join")]
+    fn join_correlated(
+        &mut self,
+        lf: LazyFrame,
+        correlation: Correlation,
+        in_value: Option<(SQLExpr, SQLExpr)>,
+        how: JoinType,
+        schema: &SchemaRef,
+    ) -> PolarsResult<LazyFrame> {
+        let value_name = unique_column_name();
+        let (inner, key_names) = self.execute_decorrelated(
+            &correlation,
+            in_value
+                .as_ref()
+                .map(|(inner, _)| (inner, value_name.as_str())),
+            false,
+        )?;
+
+        let mut left_on = self.outer_keys(&correlation, schema)?;
+        let mut right_on: Vec<Expr> = key_names.into_iter().map(col).collect();
+        if let Some((_, outer)) = &in_value {
+            left_on.push(parse_sql_expr(outer, self, Some(schema.as_ref()))?);
+            right_on.push(col(value_name));
+        }
+
+        Ok(lf
+            .join_builder()
+            .with(inner)
+            .left_on(left_on)
+            .right_on(right_on)
+            .how(how)
+            .finish())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn join_scalar_subquery(
+        &mut self,
+        lf: LazyFrame,
+        correlation: Correlation,
+        op: &SQLBinaryOperator,
+        left: &SQLExpr,
+        right: &SQLExpr,
+        subquery_is_left: bool,
+        schema: &SchemaRef,
+    ) -> PolarsResult<LazyFrame> {
+        let [SelectItem::UnnamedExpr(value) | SelectItem::ExprWithAlias { expr: value, .. }] =
+            correlation.select.projection.as_slice()
+        else {
+            polars_bail!(SQLSyntax: "SQL subquery returns more than one column");
+        };
+        let value = value.clone();
+
+        let value_name = unique_column_name();
+        let (inner, key_names) =
+            self.execute_decorrelated(&correlation, Some((&value, value_name.as_str())), true)?;
+        let left_on = self.outer_keys(&correlation, schema)?;
+        let right_on: Vec<Expr> = key_names.into_iter().map(col).collect();
+
+        let mut joined = lf
+            .join_builder()
+            .with(inner)
+            .left_on(left_on)
+            .right_on(right_on)
+            .how(JoinType::Left)
+            .maintain_order(MaintainOrderJoin::Left)
+            .finish();
+
+        // Keys without any matching rows have no group; for `COUNT` that means zero, not NULL.
+        if is_count(&value) {
+            joined = joined.with_column(col(value_name.clone()).fill_null(lit(0)));
+        }
+
+        let value_ident = SQLExpr::Identifier(Ident::new(value_name.as_str()));
+        let (left, right) = if subquery_is_left {
+            (value_ident, right.clone())
+        } else {
+            (left.clone(), value_ident)
+        };
+        let predicate = SQLExpr::BinaryOp {
+            left: Box::new(left),
+            op: op.clone(),
+            right: Box::new(right),
+        };
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        let predicate = parse_sql_expr(&predicate, self, Some(joined_schema.as_ref()))?;
+
+        Ok(joined
+            .filter(predicate)
+            .select(schema.iter_names_cloned().map(col).collect::<Vec<_>>()))
+    }
+}
@@ -34,3 +34,46 @@ fn test_describe() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_explain_formats() {
+    let lf = df! {
+      "year"=> [2018, 2019],
+      "sales"=> [1000.0, 1500.0]
+    }
+    .unwrap()
+    .lazy();
+    let mut context = SQLContext::new();
+    context.register("df", lf);
+
+    // JSON plan is returned as a single value
+    for sql in [
+        "EXPLAIN FORMAT JSON SELECT year FROM df WHERE sales > 1200",
+        "EXPLAIN (FORMAT JSON) SELECT year FROM df WHERE sales > 1200",
+    ] {
+        let df = context.execute(sql).unwrap().collect().unwrap();
+        assert_eq!(df.height(), 1);
+        let plan = df.column("Logical Plan").unwrap().str().unwrap().get(0);
+        let plan = plan.unwrap();
+        assert!(plan.starts_with(r#"{"node":"#));
+        assert!(plan.contains(r#""inputs":[{"node":"df""#));
+    }
+
+    // EXPLAIN ANALYZE runs the query, returning per-node timings
+    let df = context
+        .execute("EXPLAIN ANALYZE SELECT year FROM df WHERE sales > 1200")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(
+        df.get_column_names_str(),
+        &["node", "start", "end", "duration"]
+    );
+    assert!(df.height() > 0);
+
+    assert!(
+        context
+            .execute("EXPLAIN FORMAT GRAPHVIZ SELECT * FROM df")
+            .is_err()
+    );
+}
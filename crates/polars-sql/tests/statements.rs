@@ -593,3 +593,177 @@ fn test_compound_invalid_2() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+#[test]
+fn test_insert_into() {
+    let mut ctx = create_ctx();
+    ctx.execute("INSERT INTO df (b) SELECT b * 10 FROM df WHERE b < 3")
+        .unwrap();
+    let actual = ctx
+        .execute("SELECT * FROM df WHERE b < 3 OR b > 9")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "a" => [Some(0i64), Some(0), None, None],
+        "b" => [1i64, 2, 10, 20],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected));
+}
+
+#[test]
+fn test_create_table_using_statements() {
+    // `USING <format>` is recognised in every statement, not only the first one.
+    let query = "
+        SELECT * FROM src1;
+        CREATE TABLE IF NOT EXISTS t1 USING PARQUET LOCATION 't1.parquet' AS SELECT * FROM src2;
+        CREATE OR REPLACE TABLE t2 USING csv LOCATION 't2.csv' AS SELECT * FROM src3;
+    ";
+    let tables = extract_table_identifiers(query, false, true).unwrap();
+    for tbl in ["src1", "src2", "src3"] {
+        assert!(tables.iter().any(|t| t == tbl), "{tbl} not in {tables:?}");
+    }
+
+    let mut ctx = create_ctx();
+    let err = ctx
+        .execute("SELECT * FROM df; CREATE TABLE t USING PARQUET LOCATION 't.parquet' AS SELECT 1")
+        .unwrap_err();
+    assert!(err.to_string().contains("one (and only one) statement"));
+    let err = ctx
+        .execute("CREATE TABLE t USING PARQUET LOCATION 't.parquet' AS SELECT 1 SELECT 2")
+        .unwrap_err();
+    assert!(err.to_string().contains("expected end of statement"));
+}
+
+#[test]
+fn test_execute_with_params() {
+    let mut ctx = create_ctx();
+    let expected = df! { "b" => [3i64, 4] }.unwrap();
+
+    let params = SQLParams::from_positional([Scalar::from(2i64), Scalar::from(5i64)]);
+    for sql in [
+        "SELECT b FROM df WHERE b > $1 AND b < $2",
+        "SELECT b FROM df WHERE b > ? AND b < ?",
+    ] {
+        let actual = ctx
+            .execute_with_params(sql, params.clone())
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert!(actual.equals(&expected));
+    }
+
+    let params = SQLParams::from_named([("lo", Scalar::from(2i64)), ("hi", Scalar::from(5i64))]);
+    let actual = ctx
+        .execute_with_params("SELECT b FROM df WHERE b > :lo AND b < :hi", params)
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(actual.equals(&expected));
+
+    // bound values are never interpreted as SQL
+    let params = SQLParams::from_positional([Scalar::from(PlSmallStr::from("1 OR 1=1"))]);
+    let actual = ctx
+        .execute_with_params("SELECT b FROM df WHERE CAST(b AS VARCHAR) = $1", params)
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(actual.height(), 0);
+
+    // unbound placeholders raise an error
+    assert!(ctx.execute("SELECT b FROM df WHERE b > $1").is_err());
+    assert!(
+        ctx.execute_with_params(
+            "SELECT b FROM df WHERE b > $2",
+            SQLParams::from_positional([Scalar::from(1i64)])
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_execute_with_params_nested() {
+    let mut ctx = create_ctx();
+    let params = SQLParams::from_positional([Scalar::from(6i64), Scalar::from(2i64)]);
+
+    // placeholders are bound inside subqueries and set operations
+    let actual = ctx
+        .execute_with_params(
+            "SELECT b FROM df WHERE b > (SELECT MIN(b) FROM df WHERE b > $1) AND b > $2",
+            params.clone(),
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(actual.equals(&df! { "b" => [8i64, 9] }.unwrap()));
+
+    let actual = ctx
+        .execute_with_params(
+            "SELECT b FROM df WHERE b = $2 UNION ALL SELECT b FROM df WHERE b > $1 ORDER BY b",
+            params,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(actual.equals(&df! { "b" => [2i64, 7, 8, 9] }.unwrap()));
+}
+
+#[test]
+fn test_sql_dialects() {
+    let mut ctx = create_ctx().with_dialect(SQLDialect::MySQL);
+    assert_eq!(ctx.dialect(), SQLDialect::MySQL);
+
+    let actual = ctx
+        .execute(
+            r#"
+            SELECT `b` / 2 AS half_b, `b` DIV 2 AS div_b, UCASE("x") AS s
+            FROM df WHERE b = 3 || b = 5
+            "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "half_b" => [1.5, 2.5],
+        "div_b" => [1i64, 2],
+        "s" => ["X", "X"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+
+    // the dialect also applies within set operations and subqueries
+    let actual = ctx
+        .execute(
+            "SELECT b / 2 AS h FROM df WHERE b = 3
+             UNION ALL
+             SELECT b / 2 AS h FROM df WHERE b < (SELECT MIN(b) / 2 FROM df) + 1",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(actual.equals(&df! { "h" => [1.5, 0.5] }.unwrap()));
+
+    let mut ctx = create_ctx().with_dialect(SQLDialect::BigQuery);
+    let actual = ctx
+        .execute(r#"SELECT REGEXP_CONTAINS("abc", "^a") AS m FROM df LIMIT 1"#)
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(actual.equals(&df! { "m" => [true] }.unwrap()));
+
+    // the generic dialect keeps standard semantics
+    let mut ctx = create_ctx();
+    let actual = ctx
+        .execute("SELECT b / 2 AS b FROM df WHERE b = 3")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(actual.equals(&df! { "b" => [1i64] }.unwrap()));
+
+    assert_eq!(
+        "postgres".parse::<SQLDialect>().unwrap(),
+        SQLDialect::PostgreSQL
+    );
+    assert!("oracle".parse::<SQLDialect>().is_err());
+}
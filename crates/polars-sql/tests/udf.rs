@@ -1,9 +1,9 @@
 use polars_core::prelude::*;
 use polars_lazy::prelude::IntoLazy;
-use polars_plan::dsl::BaseColumnUdf;
+use polars_plan::dsl::{BaseColumnUdf, DataTypeSelector, Expr, lit};
 use polars_plan::prelude::UserDefinedFunction;
 use polars_sql::SQLContext;
-use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::function_registry::{FunctionRegistry, SQLFunctionSignature};
 
 struct MyFunctionRegistry {
     functions: PlHashMap<String, UserDefinedFunction>,
@@ -113,5 +113,84 @@ fn test_udfs() -> PolarsResult<()> {
     }?;
     assert!(expected.equals_missing(&res));
 
+    // registered UDFs are also available in set operations and subqueries
+    let res = ctx
+        .execute(
+            "SELECT my_div(a, b) AS d FROM foo WHERE a = 1
+             UNION ALL
+             SELECT a AS d FROM foo WHERE a > (SELECT MAX(my_div(a, b)) FROM foo)",
+        )?
+        .collect()?;
+    assert!(res.equals_missing(&df! { "d" => &[1, 2, 3] }?));
+
+    Ok(())
+}
+
+#[test]
+fn test_register_function() -> PolarsResult<()> {
+    let mut ctx = SQLContext::new();
+    let df = df! {
+        "a" => &[1, 2, 3],
+        "b" => &[10.0, 20.0, 30.0],
+        "c" => &["x", "y", "z"]
+    }?;
+    ctx.register("df", df.lazy());
+
+    ctx.register_function(
+        "scale_add",
+        SQLFunctionSignature::exact(vec![DataTypeSelector::Numeric, DataTypeSelector::Numeric]),
+        |args: Vec<Expr>| {
+            let [x, y]: [Expr; 2] = args.try_into().unwrap();
+            Ok(x * lit(2) + y)
+        },
+    )?;
+
+    // function names are case-insensitive
+    let res = ctx
+        .execute("SELECT a, Scale_Add(a, b) AS s, scale_add(a, 1) AS t FROM df")?
+        .collect()?;
+    let expected = df! {
+        "a" => &[1, 2, 3],
+        "s" => &[12.0, 24.0, 36.0],
+        "t" => &[3, 5, 7],
+    }?;
+    assert!(expected.equals_missing(&res));
+
+    // registered functions are also available in set operations and subqueries
+    let res = ctx
+        .execute(
+            "SELECT scale_add(a, 1) AS t FROM df WHERE a = 1
+             UNION ALL
+             SELECT a AS t FROM df WHERE a >= (SELECT MIN(scale_add(a, 0)) FROM df)",
+        )?
+        .collect()?;
+    assert!(res.equals_missing(&df! { "t" => &[3, 2, 3] }?));
+
+    // arity is checked
+    assert!(matches!(
+        ctx.execute("SELECT scale_add(a) FROM df"),
+        Err(PolarsError::SQLSyntax(_))
+    ));
+
+    // argument types are checked
+    assert!(matches!(
+        ctx.execute("SELECT scale_add(a, c) FROM df"),
+        Err(PolarsError::SQLInterface(_))
+    ));
+
+    // built-in functions cannot be redefined
+    assert!(
+        ctx.register_function(
+            "UPPER",
+            SQLFunctionSignature::exact(vec![DataTypeSelector::Wildcard]),
+            |mut args: Vec<Expr>| Ok(args.pop().unwrap()),
+        )
+        .is_err()
+    );
+
+    // once unregistered, the function is no longer available
+    ctx.unregister_function("SCALE_ADD");
+    assert!(ctx.execute("SELECT scale_add(a, b) FROM df").is_err());
+
     Ok(())
 }
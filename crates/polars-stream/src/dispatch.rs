@@ -47,10 +47,10 @@ struct StreamingQueryExecutor {
 }
 
 impl Executor for StreamingQueryExecutor {
-    fn execute(&mut self, _cache: &mut ExecutionState) -> PolarsResult<DataFrame> {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         let mut df = { self.executor.try_lock().unwrap().take() }
             .expect("unhandled: execute() more than once")
-            .execute()
+            .execute_cancellable(state.cancel_token())
             .map(|x| x.unwrap_single())?;
 
         if self.rechunk {
@@ -1,10 +1,13 @@
+use std::pin::pin;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crossbeam_channel::Sender;
+use futures::future::Either;
 use parking_lot::Mutex;
 use polars_core::POOL;
 use polars_core::frame::DataFrame;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_err};
 use polars_expr::state::ExecutionState;
 use polars_utils::aliases::PlHashSet;
 use polars_utils::relaxed_cell::RelaxedCell;
@@ -17,6 +20,9 @@ use crate::graph::{Graph, GraphNode, GraphNodeKey, LogicalPipeKey, PortState};
 use crate::metrics::{GraphMetrics, MetricsBuilder};
 use crate::pipe::PhysicalPipe;
 
+/// How often a running phase checks whether the query was cancelled.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 #[derive(Clone)]
 pub struct StreamingExecutionState {
     /// The number of parallel pipelines we have within each stream.
@@ -289,12 +295,25 @@ fn run_subgraph(
             pipe.spawn(scope, &mut join_handles);
         }
 
-        // Wait until all tasks are done.
+        // Wait until all tasks are done, or until the query is cancelled. Returning early
+        // cancels the remaining tasks when the scope is destroyed.
+        let cancel_token = state.in_memory_exec_state.cancel_token();
         polars_io::pl_async::get_runtime().block_on(async move {
-            for handle in join_handles {
-                handle.await?;
+            let tasks = async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            };
+            let cancelled = async move {
+                while !cancel_token.load() {
+                    tokio::time::sleep(CANCEL_POLL_INTERVAL).await;
+                }
+            };
+            match futures::future::select(pin!(tasks), pin!(cancelled)).await {
+                Either::Left((result, _)) => result,
+                Either::Right(((), _)) => Err(polars_err!(ComputeError: "query interrupted")),
             }
-            PolarsResult::Ok(())
         })
     })?;
 
@@ -304,6 +323,7 @@ fn run_subgraph(
 pub fn execute_graph(
     graph: &mut Graph,
     metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    cancel_token: Arc<RelaxedCell<bool>>,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -312,9 +332,11 @@ pub fn execute_graph(
     let (query_tasks_send, query_tasks_recv) = crossbeam_channel::unbounded();
     let (subphase_tasks_send, subphase_tasks_recv) = crossbeam_channel::unbounded();
 
+    let mut in_memory_exec_state = ExecutionState::default();
+    in_memory_exec_state.set_cancel_token(cancel_token);
     let state = StreamingExecutionState {
         num_pipelines,
-        in_memory_exec_state: ExecutionState::default(),
+        in_memory_exec_state,
         query_tasks_send,
         subphase_tasks_send,
     };
@@ -333,6 +355,8 @@ pub fn execute_graph(
 
     let mut pipe_seq_offsets = SecondaryMap::new();
     loop {
+        state.in_memory_exec_state.should_stop()?;
+
         // Update the states.
         if polars_core::config::verbose() {
             eprintln!("polars-stream: updating graph state");
@@ -14,7 +14,9 @@ mod graph;
 pub use graph::{GraphNodeKey, LogicalPipe, LogicalPipeKey};
 pub use skeleton::StreamingQuery;
 mod metrics;
-pub use metrics::{GraphMetrics, NodeMetrics};
+pub use metrics::{
+    GraphMetrics, NodeMetrics, QueryMetrics, QueryMetricsCallback, set_query_metrics_callback,
+};
 mod morsel;
 mod nodes;
 mod physical_plan;
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 pub use polars_io::metrics::{IOMetrics, OptIOMetrics};
@@ -185,3 +185,26 @@ impl MetricsBuilder {
         io_metrics
     }
 }
+
+/// Metrics of a finished streaming query.
+#[derive(Clone)]
+pub struct QueryMetrics {
+    /// Wall time of executing the graph.
+    pub elapsed: Duration,
+    /// The name and metrics of every node in the executed graph.
+    pub nodes: Vec<(String, NodeMetrics)>,
+}
+
+pub type QueryMetricsCallback = Arc<dyn Fn(&QueryMetrics) + Send + Sync>;
+
+static QUERY_METRICS_CALLBACK: RwLock<Option<QueryMetricsCallback>> = RwLock::new(None);
+
+/// Set a function that is called with the [`QueryMetrics`] of every streaming query after it
+/// finished. Metrics are only tracked while a callback is set.
+pub fn set_query_metrics_callback(callback: Option<QueryMetricsCallback>) {
+    *QUERY_METRICS_CALLBACK.write().unwrap() = callback;
+}
+
+pub(crate) fn query_metrics_callback() -> Option<QueryMetricsCallback> {
+    QUERY_METRICS_CALLBACK.read().unwrap().clone()
+}
@@ -1,9 +1,10 @@
 use polars_core::prelude::{AnyValue, IntoColumn};
 use polars_core::utils::last_non_null;
 use polars_error::PolarsResult;
+use polars_ops::prelude::ListCumFunction;
 use polars_ops::series::{
-    cum_count_with_init, cum_max_with_init, cum_min_with_init, cum_prod_with_init,
-    cum_sum_with_init,
+    cum_agg_reset_with_init, cum_count_with_init, cum_max_with_init, cum_min_with_init,
+    cum_prod_with_init, cum_sum_with_init,
 };
 
 use super::ComputeNode;
@@ -15,6 +16,8 @@ use crate::pipe::{RecvPort, SendPort};
 pub struct CumAggNode {
     state: AnyValue<'static>,
     kind: CumAggKind,
+    /// Whether the morsels carry a second boolean column that restarts the aggregation.
+    reset: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -26,11 +29,24 @@ pub enum CumAggKind {
     Prod,
 }
 
+impl CumAggKind {
+    fn to_list_cum_function(self) -> ListCumFunction {
+        match self {
+            CumAggKind::Min => ListCumFunction::Min,
+            CumAggKind::Max => ListCumFunction::Max,
+            CumAggKind::Sum => ListCumFunction::Sum,
+            CumAggKind::Count => ListCumFunction::Count,
+            CumAggKind::Prod => ListCumFunction::Prod,
+        }
+    }
+}
+
 impl CumAggNode {
-    pub fn new(kind: CumAggKind) -> Self {
+    pub fn new(kind: CumAggKind, reset: bool) -> Self {
         Self {
             state: AnyValue::Null,
             kind,
+            reset,
         }
     }
 }
@@ -74,13 +90,20 @@ impl ComputeNode for CumAggNode {
 
         join_handles.push(scope.spawn_task(TaskPriority::High, async move {
             while let Ok(mut m) = recv.recv().await {
-                assert_eq!(m.df().width(), 1);
+                assert_eq!(m.df().width(), 1 + self.reset as usize);
                 if m.df().height() == 0 {
                     continue;
                 }
 
                 let s = m.df()[0].as_materialized_series();
                 let out = match self.kind {
+                    _ if self.reset => cum_agg_reset_with_init(
+                        s,
+                        m.df()[1].bool()?,
+                        self.kind.to_list_cum_function(),
+                        false,
+                        &self.state,
+                    ),
                     CumAggKind::Min => cum_min_with_init(s, false, &self.state),
                     CumAggKind::Max => cum_max_with_init(s, false, &self.state),
                     CumAggKind::Sum => cum_sum_with_init(s, false, &self.state),
@@ -90,13 +113,26 @@ impl ComputeNode for CumAggNode {
                     CumAggKind::Prod => cum_prod_with_init(s, false, &self.state),
                 }?;
 
+                // Only the last run of this morsel continues into the next one.
+                let last_run_start = if self.reset {
+                    let last_reset = m.df()[1].bool()?.iter().rposition(|r| r == Some(true));
+                    if last_reset.is_some() {
+                        self.state = AnyValue::Null;
+                    }
+                    last_reset.unwrap_or(0)
+                } else {
+                    0
+                };
+
                 // Find the last non-null value and set that as the state.
                 let last_non_null_idx = if out.has_nulls() {
                     last_non_null(out.chunks().iter().map(|arr| arr.as_ref()), out.len())
                 } else {
                     Some(out.len() - 1)
                 };
-                if let Some(idx) = last_non_null_idx {
+                if let Some(idx) = last_non_null_idx
+                    && idx >= last_run_start
+                {
                     self.state = out.get(idx).unwrap().into_static();
                 }
                 *m.df_mut() = out.into_column().into_frame();
@@ -10,7 +10,7 @@ use polars_core::utils::arrow;
 use polars_error::PolarsResult;
 use polars_io::ipc::{IpcWriter, IpcWriterOptions};
 use polars_io::utils::file::Writeable;
-use polars_io::{SerWriter, schema_to_arrow_checked};
+use polars_io::{SerWriter, schema_to_arrow_checked, with_column_metadata};
 
 use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
 use crate::nodes::io_sinks::writers::ipc::IpcBatch;
@@ -35,6 +35,12 @@ impl IOWriter {
 
         let (file, sync_on_close) = file.await?;
 
+        let schema = schema_to_arrow_checked(&schema, options.compat_level, "ipc")?;
+        let schema = match ipc_batch_rx.recv().await {
+            Some(IpcBatch::ColumnMetadata(Some(df))) => with_column_metadata(schema, &df),
+            _ => schema,
+        };
+
         match file {
             Writeable::Cloud(cloudwriter) => {
                 // The zero-copy implementation takes ownership of the encoded data and, after
@@ -53,7 +59,6 @@ impl IOWriter {
                 block_offsets += offset;
 
                 // Add schema message.
-                let schema = schema_to_arrow_checked(&schema, options.compat_level, "ipc")?;
                 let encoded_data = EncodedDataBytes {
                     ipc_message: Bytes::from(schema_to_bytes(&schema, &ipc_fields, None)),
                     arrow_data: Bytes::new(),
@@ -100,6 +105,7 @@ impl IOWriter {
                             dictionary_blocks.push(block);
                             block_offsets += meta + data;
                         },
+                        IpcBatch::ColumnMetadata(_) => unreachable!(),
                     }
                 }
 
@@ -127,7 +133,7 @@ impl IOWriter {
                     .with_compression(options.compression)
                     .with_compat_level(options.compat_level)
                     .with_parallel(false)
-                    .batched(&schema, ipc_fields)?;
+                    .batched_arrow(schema, ipc_fields)?;
 
                 while let Some(batch) = ipc_batch_rx.recv().await {
                     match batch {
@@ -140,6 +146,7 @@ impl IOWriter {
                         IpcBatch::Dictionary(dictionary_data) => {
                             ipc_writer.write_encoded_dictionaries(&[dictionary_data])?
                         },
+                        IpcBatch::ColumnMetadata(_) => unreachable!(),
                     }
                 }
 
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::config;
+use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
 use polars_core::utils::arrow::io::ipc::write::{EncodedData, WriteOptions};
 use polars_error::PolarsResult;
@@ -32,6 +33,9 @@ pub struct IpcWriterStarter {
 }
 
 enum IpcBatch {
+    /// Sent before any other batch: the first morsel without rows, if there is one, so that the
+    /// metadata of its columns can be written to the schema.
+    ColumnMetadata(Option<DataFrame>),
     Record(
         async_executor::AbortOnDropHandle<EncodedData>,
         SinkMorselPermit,
@@ -46,8 +46,19 @@ impl RecordBatchEncoder {
         let mut record_batch_arrow_arrays: Vec<Box<dyn Array>> =
             Vec::with_capacity(arrow_converters.len());
 
+        let mut column_metadata_sent = false;
+
         while let Ok(morsel) = morsel_rx.recv().await {
             let (df, permit) = morsel.into_inner();
+
+            if !column_metadata_sent {
+                column_metadata_sent = true;
+                let columns = IpcBatch::ColumnMetadata(Some(df.slice(0, 0)));
+                if ipc_batch_tx.send(columns).await.is_err() {
+                    return Ok(());
+                }
+            }
+
             let height = df.height();
             let columns = df.into_columns();
             let flags = write_statistics_flags.then(|| {
@@ -177,6 +188,10 @@ impl RecordBatchEncoder {
             }
         }
 
+        if !column_metadata_sent {
+            let _ = ipc_batch_tx.send(IpcBatch::ColumnMetadata(None)).await;
+        }
+
         for fut in parallelize_first_to_local(
             TaskPriority::High,
             arrow_converters
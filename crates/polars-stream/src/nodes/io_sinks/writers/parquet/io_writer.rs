@@ -5,6 +5,7 @@ use polars_buffer::Buffer;
 use polars_error::PolarsResult;
 use polars_io::parquet::write::BatchedWriter;
 use polars_io::prelude::KeyValueMetadata;
+use polars_io::with_column_metadata;
 use polars_parquet::write::{Encoding, FileWriter, SchemaDescriptor, WriteOptions};
 
 use crate::async_executor::{self};
@@ -40,10 +41,23 @@ impl IOWriter {
         let (mut file, sync_on_close) = file.await?;
         let mut buffered_file = file.as_buffered();
 
+        // The first row group carries the columns, so their metadata can be written to the schema.
+        let mut first_row_group = match encoded_row_group_rx.recv().await {
+            Some(handle) => Some(handle.await?),
+            None => None,
+        };
+        let mut arrow_schema = Arc::unwrap_or_clone(arrow_schema);
+        if let Some(df) = first_row_group
+            .as_mut()
+            .and_then(|row_group| row_group.column_metadata.take())
+        {
+            arrow_schema = with_column_metadata(arrow_schema, &df);
+        }
+
         let mut parquet_writer = BatchedWriter::new(
             std::sync::Mutex::new(FileWriter::new_with_parquet_schema(
                 &mut *buffered_file,
-                Arc::unwrap_or_clone(arrow_schema),
+                arrow_schema,
                 Arc::unwrap_or_clone(schema_descriptor),
                 write_options,
             )),
@@ -53,12 +67,20 @@ impl IOWriter {
             key_value_metadata,
         );
 
-        while let Some(handle) = encoded_row_group_rx.recv().await {
+        loop {
+            let row_group = match first_row_group.take() {
+                Some(row_group) => row_group,
+                None => match encoded_row_group_rx.recv().await {
+                    Some(handle) => handle.await?,
+                    None => break,
+                },
+            };
             let EncodedRowGroup {
                 num_rows,
                 data,
                 morsel_permit,
-            } = handle.await?;
+                column_metadata: _,
+            } = row_group;
             assert_eq!(data.len(), num_leaf_columns);
             parquet_writer.write_row_group(num_rows as u64, &data)?;
             drop(data);
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use arrow::datatypes::ArrowSchemaRef;
 use polars_buffer::Buffer;
+use polars_core::frame::DataFrame;
 use polars_error::PolarsResult;
 use polars_io::pl_async;
 use polars_io::prelude::{ParquetWriteOptions, get_encodings};
@@ -42,6 +43,9 @@ struct EncodedRowGroup {
     num_rows: usize,
     data: Vec<Vec<CompressedPage>>,
     morsel_permit: SinkMorselPermit,
+    /// For the first row group, its columns without rows, so that their metadata can be written
+    /// to the schema.
+    column_metadata: Option<DataFrame>,
 }
 
 impl FileWriterStarter for ParquetWriterStarter {
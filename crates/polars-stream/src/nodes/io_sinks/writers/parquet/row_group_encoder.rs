@@ -41,7 +41,10 @@ impl RowGroupEncoder {
             num_leaf_columns,
         } = self;
 
+        let mut is_first_row_group = true;
+
         while let Ok(morsel) = morsel_rx.recv().await {
+            let is_first = std::mem::replace(&mut is_first_row_group, false);
             let arrow_schema = Arc::clone(&arrow_schema);
             let schema_descriptor = Arc::clone(&schema_descriptor);
             let encodings = Buffer::clone(&encodings);
@@ -50,6 +53,7 @@ impl RowGroupEncoder {
                 async_executor::spawn(TaskPriority::High, async move {
                     let (df, morsel_permit) = morsel.into_inner();
                     let num_rows = df.height();
+                    let column_metadata = is_first.then(|| df.slice(0, 0));
 
                     let mut data: Vec<Vec<CompressedPage>> = Vec::with_capacity(num_leaf_columns);
 
@@ -107,6 +111,7 @@ impl RowGroupEncoder {
                         num_rows,
                         data,
                         morsel_permit,
+                        column_metadata,
                     })
                 }),
             );
@@ -11,7 +11,7 @@ use polars_core::schema::Schema;
 use polars_core::utils::arrow::io::ipc::read::common::apply_projection;
 use polars_core::utils::arrow::io::ipc::read::{BlockReader, FileMetadata, read_batch};
 use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
-use polars_io::RowIndex;
+use polars_io::{RowIndex, apply_column_metadata};
 use polars_utils::IdxSize;
 use polars_utils::bool::UnsafeBool;
 
@@ -103,6 +103,7 @@ impl RecordBatchDecoder {
                     })
                 }
             }
+            apply_column_metadata(&mut df, &file_metadata.schema);
             df.slice(i64::try_from(slice_offset).unwrap(), slice_len)
         };
 
@@ -2,7 +2,9 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn};
+use polars_core::prelude::{
+    ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn, custom_arrow_metadata,
+};
 use polars_core::series::Series;
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
@@ -289,7 +291,7 @@ fn decode_column(
         }
     }
 
-    // TODO: Also load in the metadata.
+    series.set_metadata(custom_arrow_metadata(arrow_field));
 
     Ok((series.into_column(), pred_true_mask))
 }
@@ -687,6 +689,8 @@ fn decode_column_prefiltered(
         }
     }
 
+    series.set_metadata(custom_arrow_metadata(arrow_field));
+
     let series = if !prefilter {
         series.filter(mask)?
     } else {
@@ -24,6 +24,8 @@ pub mod merge_sorted;
 pub mod multiplexer;
 pub mod negative_slice;
 pub mod ordered_union;
+#[cfg(feature = "rank")]
+pub mod partitioned_rank;
 pub mod peak_minmax;
 pub mod reduce;
 pub mod repeat;
@@ -34,6 +36,7 @@ pub mod rolling_group_by;
 pub mod select;
 pub mod shift;
 pub mod simple_projection;
+pub mod sort;
 pub mod sorted_group_by;
 pub mod streaming_slice;
 pub mod top_k;
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use polars_core::prelude::{IntoColumn, PlSmallStr, Series};
+use polars_ops::series::{PartitionedRanker, RankOptions};
+
+use super::compute_node_prelude::*;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+enum RankState {
+    Sink(Vec<DataFrame>),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+/// Ranks the first column of its input within the partitions given by the other columns.
+///
+/// The values are counted per partition while the input streams in, after which only the distinct
+/// values of every partition are ordered to rank the buffered input.
+pub struct PartitionedRankNode {
+    name: PlSmallStr,
+    ranker: PartitionedRanker,
+    state: RankState,
+}
+
+impl PartitionedRankNode {
+    pub fn new(name: PlSmallStr, options: RankOptions, seed: Option<u64>) -> Self {
+        Self {
+            name,
+            ranker: PartitionedRanker::new(options, seed),
+            state: RankState::Sink(Vec::new()),
+        }
+    }
+
+    fn rank(&mut self, chunks: Vec<DataFrame>) -> PolarsResult<DataFrame> {
+        self.ranker.finish();
+        let mut out = Series::new_empty(self.name.clone(), &self.ranker.dtype());
+        for df in chunks {
+            let (values, partition_by) = df.columns().split_first().unwrap();
+            out.append_owned(self.ranker.rank(values.as_materialized_series(), partition_by)?)?;
+        }
+        Ok(out.into_column().into_frame())
+    }
+}
+
+impl ComputeNode for PartitionedRankNode {
+    fn name(&self) -> &str {
+        "partitioned_rank"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = RankState::Done;
+            },
+            // Input is done, rank the buffered input and transition to being a source.
+            RankState::Sink(chunks) if recv[0] == PortState::Done => {
+                let chunks = std::mem::take(chunks);
+                let df = self.rank(chunks)?;
+                self.state = RankState::Source(InMemorySourceNode::new(
+                    Arc::new(df),
+                    MorselSeq::default(),
+                ));
+            },
+            // Nothing to change.
+            _ => {},
+        }
+
+        // Communicate our state.
+        match &mut self.state {
+            RankState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                if recv[0] != PortState::Done {
+                    recv[0] = PortState::Ready;
+                }
+            },
+            RankState::Source(src) => {
+                recv[0] = PortState::Done;
+                src.update_state(&mut [], send, state)?;
+            },
+            RankState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, RankState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match &mut self.state {
+            RankState::Sink(chunks) => {
+                assert!(send_ports[0].is_none());
+                // Ordinal ranks break ties by the order of the rows.
+                let mut recv = recv_ports[0].take().unwrap().serial();
+                let ranker = &mut self.ranker;
+
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(morsel) = recv.recv().await {
+                        let df = morsel.into_df();
+                        let (values, partition_by) = df.columns().split_first().unwrap();
+                        ranker.update(values.as_materialized_series(), partition_by)?;
+                        chunks.push(df);
+                    }
+                    Ok(())
+                }));
+            },
+            RankState::Source(src) => {
+                assert!(recv_ports[0].is_none());
+                src.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            RankState::Done => unreachable!(),
+        }
+    }
+}
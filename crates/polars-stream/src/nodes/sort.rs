@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use polars_core::prelude::{IsSorted, PlSmallStr, SortMultipleOptions};
+use polars_core::schema::Schema;
+use polars_ooc::{ExternalSortOptions, ExternalSortOutput, ExternalSorter, SortedRunMerger};
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::expression::StreamExpr;
+use crate::morsel::SourceToken;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+enum SortState {
+    Sink(Vec<ExternalSorter>),
+    Source(InMemorySourceNode),
+    Merge {
+        merger: SortedRunMerger,
+        seq: MorselSeq,
+        exhausted: bool,
+    },
+    Done,
+}
+
+/// Sorts its input by expressions, spilling sorted runs to disk if the input exceeds the memory
+/// budget and merging them afterwards.
+pub struct SortNode {
+    input_schema: Arc<Schema>,
+    by: Vec<StreamExpr>,
+    /// The column the output is sorted by if it is sorted by a single column.
+    sorted_column: Option<PlSmallStr>,
+    sort_options: SortMultipleOptions,
+    state: SortState,
+}
+
+impl SortNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        by: Vec<StreamExpr>,
+        sorted_column: Option<PlSmallStr>,
+        sort_options: SortMultipleOptions,
+    ) -> Self {
+        Self {
+            input_schema,
+            by,
+            sorted_column,
+            sort_options,
+            state: SortState::Sink(Vec::new()),
+        }
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // Every pipeline gets its own sorter with an equal share of the memory budget, unless the
+        // order of equal rows must be maintained, then a single sorter receives the input in order.
+        if let SortState::Sink(sorters) = &mut self.state
+            && sorters.is_empty()
+        {
+            let num_sorters = if self.sort_options.maintain_order {
+                1
+            } else {
+                state.num_pipelines
+            };
+            let mut options = ExternalSortOptions::default();
+            options.memory_budget /= num_sorters;
+            *sorters = (0..num_sorters)
+                .map(|_| {
+                    ExternalSorter::new(self.by.len(), self.sort_options.clone(), options.clone())
+                })
+                .collect();
+        }
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, transition to being a source.
+            SortState::Sink(sorters) if recv[0] == PortState::Done => {
+                let mut sorter = sorters.pop().unwrap();
+                for other in sorters.drain(..) {
+                    sorter.combine(other)?;
+                }
+                self.state = match sorter.finish()? {
+                    ExternalSortOutput::InMemory(mut df) => {
+                        if df.width() == 0 {
+                            df = DataFrame::empty_with_schema(&self.input_schema);
+                        }
+                        if let Some(name) = &self.sorted_column
+                            && let Some(idx) = df.get_column_index(name)
+                        {
+                            let flag = if self.sort_options.descending[0] {
+                                IsSorted::Descending
+                            } else {
+                                IsSorted::Ascending
+                            };
+                            // SAFETY: setting the sorted flag doesn't change the schema.
+                            unsafe { df.columns_mut_retain_schema()[idx].set_sorted_flag(flag) };
+                        }
+                        SortState::Source(InMemorySourceNode::new(
+                            Arc::new(df),
+                            MorselSeq::default(),
+                        ))
+                    },
+                    ExternalSortOutput::Spilled(merger) => SortState::Merge {
+                        merger,
+                        seq: MorselSeq::default(),
+                        exhausted: false,
+                    },
+                };
+            },
+            SortState::Merge { exhausted, .. } if *exhausted => {
+                self.state = SortState::Done;
+            },
+            // Nothing to change.
+            _ => {},
+        }
+
+        // Communicate our state.
+        match &mut self.state {
+            SortState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                if recv[0] != PortState::Done {
+                    recv[0] = PortState::Ready;
+                }
+            },
+            SortState::Source(src) => {
+                recv[0] = PortState::Done;
+                src.update_state(&mut [], send, state)?;
+            },
+            SortState::Merge { .. } => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match &mut self.state {
+            SortState::Sink(sorters) => {
+                assert!(send_ports[0].is_none());
+                let recv_port = recv_ports[0].take().unwrap();
+                let receivers = if self.sort_options.maintain_order {
+                    vec![recv_port.serial()]
+                } else {
+                    recv_port.parallel()
+                };
+
+                for (mut recv, sorter) in receivers.into_iter().zip(sorters) {
+                    let by = &*self.by;
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let df = morsel.into_df();
+                            let mut keys = Vec::with_capacity(by.len());
+                            for expr in by {
+                                keys.push(expr.evaluate(&df, &state.in_memory_exec_state).await?);
+                            }
+                            sorter.push(df, keys)?;
+                        }
+                        Ok(())
+                    }));
+                }
+            },
+            SortState::Source(src) => {
+                assert!(recv_ports[0].is_none());
+                src.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            SortState::Merge {
+                merger,
+                seq,
+                exhausted,
+            } => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    let source_token = SourceToken::new();
+                    let wait_group = WaitGroup::default();
+                    while !source_token.stop_requested() {
+                        let Some(df) = merger.next() else {
+                            *exhausted = true;
+                            break;
+                        };
+                        let mut morsel = Morsel::new(df?, *seq, source_token.clone());
+                        morsel.set_consume_token(wait_group.token());
+                        *seq = seq.successor();
+
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+                        wait_group.wait().await;
+                    }
+
+                    Ok(())
+                }));
+            },
+            SortState::Done => unreachable!(),
+        }
+    }
+}
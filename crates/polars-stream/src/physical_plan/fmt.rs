@@ -415,23 +415,32 @@ fn visualize_plan_rec(
         },
         PhysNodeKind::Repeat { value, repeats } => ("repeat".to_owned(), &[*value, *repeats][..]),
         #[cfg(feature = "cum_agg")]
-        PhysNodeKind::CumAgg { input, kind } => {
+        PhysNodeKind::CumAgg { input, kind, reset } => {
             use crate::nodes::cum_agg::CumAggKind;
 
             (
                 format!(
-                    "cum_{}",
+                    "cum_{}{}",
                     match kind {
                         CumAggKind::Min => "min",
                         CumAggKind::Max => "max",
                         CumAggKind::Sum => "sum",
                         CumAggKind::Count => "count",
                         CumAggKind::Prod => "prod",
-                    }
+                    },
+                    if *reset { "_reset" } else { "" }
                 ),
                 &[*input][..],
             )
         },
+        #[cfg(feature = "rank")]
+        PhysNodeKind::PartitionedRank { input, options, .. } => (
+            format!(
+                "partitioned_rank\\nmethod: {:?}, descending: {}",
+                options.method, options.descending
+            ),
+            &[*input][..],
+        ),
         PhysNodeKind::GatherEvery { input, n, offset } => (
             format!("gather_every\\nn: {n}, offset: {offset}"),
             &[*input][..],
@@ -933,7 +933,11 @@ fn lower_exprs_with_ctx(
             #[cfg(feature = "mode")]
             AExpr::Function {
                 input: ref inner_exprs,
-                function: IRFunctionExpr::Mode { maintain_order },
+                function:
+                    IRFunctionExpr::Mode {
+                        maintain_order,
+                        ties: polars_ops::chunked_array::ModeTies::All,
+                    },
                 options: _,
             } => {
                 // Transform:
@@ -1177,7 +1181,59 @@ fn lower_exprs_with_ctx(
                     IRFunctionExpr::CumProd { .. } => CumAggKind::Prod,
                     _ => unreachable!(),
                 };
-                let node_kind = PhysNodeKind::CumAgg { input, kind };
+                let node_kind = PhysNodeKind::CumAgg {
+                    input,
+                    kind,
+                    reset: false,
+                };
+
+                let output_schema = Schema::from_iter([(value_key.clone(), value_dtype.clone())]);
+                let node_key = ctx
+                    .phys_sm
+                    .insert(PhysNode::new(Arc::new(output_schema), node_kind));
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(value_key)));
+            },
+
+            #[cfg(feature = "cum_agg")]
+            ref agg_expr @ AExpr::Function {
+                input: ref inner_exprs,
+                function: IRFunctionExpr::CumAggReset { function, reverse },
+                options: _,
+            } if !reverse => {
+                use polars_ops::prelude::ListCumFunction;
+
+                use crate::nodes::cum_agg::CumAggKind;
+
+                assert_eq!(inner_exprs.len(), 2);
+
+                let input_schema = &ctx.phys_sm[input.node].output_schema;
+
+                let value_key = unique_column_name();
+                let reset_key = unique_column_name();
+                let value_dtype =
+                    agg_expr.to_dtype(&ToFieldContext::new(ctx.expr_arena, input_schema))?;
+
+                let input = build_select_stream_with_ctx(
+                    input,
+                    &[
+                        inner_exprs[0].with_alias(value_key.clone()),
+                        inner_exprs[1].with_alias(reset_key),
+                    ],
+                    ctx,
+                )?;
+                let kind = match function {
+                    ListCumFunction::Min => CumAggKind::Min,
+                    ListCumFunction::Max => CumAggKind::Max,
+                    ListCumFunction::Sum => CumAggKind::Sum,
+                    ListCumFunction::Count => CumAggKind::Count,
+                    ListCumFunction::Prod => CumAggKind::Prod,
+                };
+                let node_kind = PhysNodeKind::CumAgg {
+                    input,
+                    kind,
+                    reset: true,
+                };
 
                 let output_schema = Schema::from_iter([(value_key.clone(), value_dtype.clone())]);
                 let node_key = ctx
@@ -1668,6 +1724,7 @@ fn lower_exprs_with_ctx(
                 let unnest_fn = FunctionIR::Unnest {
                     columns: Arc::new([out_name.clone()]),
                     separator: Some(separator.clone()),
+                    options: Default::default(),
                 };
                 let input_schema = ctx.phys_sm[stream.node].output_schema.clone();
                 let output_schema = unnest_fn.schema(&input_schema)?.into_owned();
@@ -2012,7 +2069,19 @@ fn lower_exprs_with_ctx(
 
             #[cfg(feature = "approx_unique")]
             AExpr::Function {
-                function: IRFunctionExpr::ApproxNUnique,
+                function:
+                    IRFunctionExpr::ApproxNUnique { .. }
+                    | IRFunctionExpr::Hll(polars_ops::series::HllFunction::Sketch { .. }),
+                ..
+            } => {
+                let (trans_stream, trans_expr) = lower_reduce_node(input, expr, ctx)?;
+                input_streams.insert(trans_stream);
+                transformed_exprs.push(trans_expr);
+            },
+
+            #[cfg(feature = "approx_quantile")]
+            AExpr::Function {
+                function: IRFunctionExpr::ApproxQuantile { .. },
                 ..
             } => {
                 let (trans_stream, trans_expr) = lower_reduce_node(input, expr, ctx)?;
@@ -2325,6 +2394,64 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
 
+            // Ranking within partitions only needs the distinct values of every partition to be
+            // ordered, so it doesn't need the groups to be materialized.
+            #[cfg(feature = "rank")]
+            AExpr::Over {
+                function,
+                ref partition_by,
+                order_by: None,
+                mapping: WindowMapping::GroupsToRows,
+            } if !partition_by.is_empty()
+                && partition_by
+                    .iter()
+                    .all(|e| is_elementwise_rec_cached(*e, ctx.expr_arena, ctx.cache))
+                && matches!(
+                    ctx.expr_arena.get(function),
+                    AExpr::Function {
+                        input,
+                        function: IRFunctionExpr::Rank { .. },
+                        ..
+                    } if input.len() == 1
+                        && is_elementwise_rec_cached(input[0].node(), ctx.expr_arena, ctx.cache)
+                ) =>
+            {
+                let AExpr::Function {
+                    input: inner_exprs,
+                    function: IRFunctionExpr::Rank { options, seed },
+                    ..
+                } = ctx.expr_arena.get(function).clone()
+                else {
+                    unreachable!()
+                };
+
+                let input_schema = &ctx.phys_sm[input.node].output_schema;
+                let value_key = unique_column_name();
+                let value_dtype = ctx
+                    .expr_arena
+                    .get(function)
+                    .to_dtype(&ToFieldContext::new(ctx.expr_arena, input_schema))?;
+
+                let select_exprs = std::iter::once(inner_exprs[0].with_alias(value_key.clone()))
+                    .chain(partition_by.iter().map(|e| {
+                        ExprIR::new(*e, OutputName::Alias(unique_column_name()))
+                    }))
+                    .collect_vec();
+                let input = build_select_stream_with_ctx(input, &select_exprs, ctx)?;
+                let node_kind = PhysNodeKind::PartitionedRank {
+                    input,
+                    options,
+                    seed,
+                };
+
+                let output_schema = Schema::from_iter([(value_key.clone(), value_dtype)]);
+                let node_key = ctx
+                    .phys_sm
+                    .insert(PhysNode::new(Arc::new(output_schema), node_kind));
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(value_key)));
+            },
+
             AExpr::AnonymousFunction { .. }
             | AExpr::Function { .. }
             | AExpr::Over { .. }
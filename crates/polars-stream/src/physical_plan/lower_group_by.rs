@@ -357,7 +357,15 @@ fn try_lower_elementwise_scalar_agg_expr(
 
         #[cfg(feature = "approx_unique")]
         AExpr::Function {
-            function: IRFunctionExpr::ApproxNUnique,
+            function:
+                IRFunctionExpr::ApproxNUnique { .. }
+                | IRFunctionExpr::Hll(polars_ops::series::HllFunction::Sketch { .. }),
+            ..
+        } => Some(replace_agg_uniq!(expr)),
+
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            function: IRFunctionExpr::ApproxQuantile { .. },
             ..
         } => Some(replace_agg_uniq!(expr)),
 
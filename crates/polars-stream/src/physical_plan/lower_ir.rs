@@ -456,8 +456,11 @@ pub fn lower_ir(
 
             // See if we can insert a top k.
             let mut limit = u64::MAX;
-            if let Some((0, l, _)) = slice {
-                limit = limit.min(l as u64);
+            if let Some((offset, l, _)) = slice
+                && offset >= 0
+            {
+                // The sort below applies the offset to the top `offset + len` rows.
+                limit = limit.min((offset as u64).saturating_add(l as u64));
             }
             #[allow(clippy::unnecessary_cast)]
             if let Some(l) = sort_options.limit {
@@ -252,6 +252,16 @@ pub enum PhysNodeKind {
     CumAgg {
         input: PhysStream,
         kind: crate::nodes::cum_agg::CumAggKind,
+        /// Whether the input has a second boolean column that restarts the aggregation.
+        reset: bool,
+    },
+
+    /// Ranks the first column of the input within the partitions given by the other columns.
+    #[cfg(feature = "rank")]
+    PartitionedRank {
+        input: PhysStream,
+        options: polars_ops::series::RankOptions,
+        seed: Option<u64>,
     },
 
     // Parameter is the input stream
@@ -517,6 +527,12 @@ fn visit_node_inputs_mut(
                 visit(input);
             },
 
+            #[cfg(feature = "rank")]
+            PhysNodeKind::PartitionedRank { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
             PhysNodeKind::InMemoryJoin {
                 input_left,
                 input_right,
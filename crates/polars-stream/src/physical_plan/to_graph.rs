@@ -546,34 +546,55 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: slice.map(|t| (t.0, t.1, None)),
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                Some(crate::dispatch::build_streaming_query_executor),
-            )?);
 
-            let input_key = to_graph_rec(input.node, ctx)?;
-            ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
-                    input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
-                ),
-                [(input_key, input.port)],
-            )
+            // Sorting without a slice can spill to disk.
+            if slice.is_none() && sort_options.limit.is_none() {
+                let by = by_column
+                    .iter()
+                    .map(|e| create_stream_expr(e, ctx, &input_schema))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let sorted_column = match by_column.as_slice() {
+                    [e] => match ctx.expr_arena.get(e.node()) {
+                        AExpr::Column(name) => Some(name.clone()),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::sort::SortNode::new(input_schema, by, sorted_column, sort_options.clone()),
+                    [(input_key, input.port)],
+                )
+            } else {
+                let lmdf = Arc::new(LateMaterializedDataFrame::default());
+                let mut lp_arena = Arena::default();
+                let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+                let sort_node = lp_arena.add(IR::Sort {
+                    input: df_node,
+                    by_column: by_column.clone(),
+                    slice: slice.map(|t| (t.0, t.1, None)),
+                    sort_options: sort_options.clone(),
+                });
+                let executor = Mutex::new(create_physical_plan(
+                    sort_node,
+                    &mut lp_arena,
+                    ctx.expr_arena,
+                    Some(crate::dispatch::build_streaming_query_executor),
+                )?);
+
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::in_memory_map::InMemoryMapNode::new(
+                        input_schema,
+                        Arc::new(move |df| {
+                            lmdf.set_materialized_dataframe(df);
+                            let mut state = ExecutionState::new();
+                            executor.lock().execute(&mut state)
+                        }),
+                    ),
+                    [(input_key, input.port)],
+                )
+            }
         },
 
         TopK {
@@ -621,10 +642,24 @@ fn to_graph_rec<'a>(
         },
 
         #[cfg(feature = "cum_agg")]
-        CumAgg { input, kind } => {
+        CumAgg { input, kind, reset } => {
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
-                nodes::cum_agg::CumAggNode::new(*kind),
+                nodes::cum_agg::CumAggNode::new(*kind, *reset),
+                [(input_key, input.port)],
+            )
+        },
+
+        #[cfg(feature = "rank")]
+        PartitionedRank {
+            input,
+            options,
+            seed,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            let name = node.output_schema.get_at_index(0).unwrap().0.clone();
+            ctx.graph.add_node(
+                nodes::partitioned_rank::PartitionedRankNode::new(name, *options, *seed),
                 [(input_key, input.port)],
             )
         },
@@ -1325,6 +1360,7 @@ fn to_graph_rec<'a>(
                     let batch_size = Some(get_ideal_morsel_size());
                     let output_schema = output_schema.clone();
 
+                    let is_projected = with_columns.is_some();
                     let with_columns = with_columns.map(|x| {
                         x.into_iter()
                             .map(|x| x.to_string())
@@ -1332,7 +1368,7 @@ fn to_graph_rec<'a>(
                     });
 
                     // Setup the IO plugin generator.
-                    let (generator, can_parse_predicate) = {
+                    let (generator, can_parse_predicate, applied) = {
                         Python::attach(|py| {
                             let pl = PyModule::import(py, intern!(py, "polars")).unwrap();
                             let utils = pl.getattr(intern!(py, "_utils")).unwrap();
@@ -1364,25 +1400,32 @@ fn to_graph_rec<'a>(
                             );
 
                             let generator_init = callable.call1(args)?;
-                            let generator = generator_init.get_item(0).map_err(
-                                |_| polars_err!(ComputeError: "expected tuple got {generator_init}"),
-                            )?;
-                            let can_parse_predicate = generator_init.get_item(1).map_err(
-                                |_| polars_err!(ComputeError: "expected tuple got {generator}"),
-                            )?;
-                            let can_parse_predicate = can_parse_predicate.extract::<bool>().map_err(
-                                |_| polars_err!(ComputeError: "expected bool got {can_parse_predicate}"),
-                            )? && could_serialize_predicate;
+                            let (generator, applied) =
+                                polars_plan::plans::io_plugin_init(generator_init)?;
+                            let can_parse_predicate =
+                                applied.predicate && could_serialize_predicate;
 
                             let generator = generator.into_py_any(py).map_err(
                                 |_| polars_err!(ComputeError: "unable to grab reference to IO plugin generator"),
                             )?;
 
-                            PolarsResult::Ok((generator, can_parse_predicate))
+                            PolarsResult::Ok((generator, can_parse_predicate, applied))
                         })
                     }?;
 
+                    // Apply the projection and `n_rows` ourselves if the plugin didn't.
+                    let simple_projection = simple_projection.or_else(|| {
+                        (is_projected && !applied.projection).then(|| output_schema.clone())
+                    });
+                    let remaining_rows = n_rows
+                        .filter(|_| !applied.n_rows)
+                        .map(RelaxedCell::new_usize);
+
                     let get_batch_fn = Box::new(move |state: &StreamingExecutionState| {
+                        if remaining_rows.as_ref().is_some_and(|r| r.load() == 0) {
+                            return Ok(None);
+                        }
+
                         let df = Python::attach(|py| {
                             match generator.bind(py).call_method0(intern!(py, "__next__")) {
                                 Ok(out) => polars_plan::plans::python_df_to_rust(py, out).map(Some),
@@ -1421,6 +1464,11 @@ fn to_graph_rec<'a>(
                             df = df.filter(mask.bool()?)?;
                         }
 
+                        if let Some(remaining_rows) = &remaining_rows {
+                            df = df.head(Some(remaining_rows.load()));
+                            remaining_rows.fetch_sub(df.height());
+                        }
+
                         Ok(Some(df))
                     }) as Box<_>;
 
@@ -15,7 +15,7 @@ use polars_utils::relaxed_cell::RelaxedCell;
 use slotmap::{SecondaryMap, SlotMap};
 
 use crate::graph::{Graph, GraphNodeKey};
-use crate::metrics::GraphMetrics;
+use crate::metrics::{GraphMetrics, QueryMetrics, query_metrics_callback};
 use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIRContext};
 
 /// Executes the IR with the streaming engine.
@@ -122,6 +122,7 @@ impl StreamingQuery {
 
         let metrics = if std::env::var("POLARS_TRACK_METRICS").as_deref() == Ok("1")
             || std::env::var("POLARS_LOG_METRICS").as_deref() == Ok("1")
+            || query_metrics_callback().is_some()
         {
             crate::async_executor::track_task_metrics(true);
             Some(Arc::default())
@@ -142,6 +143,15 @@ impl StreamingQuery {
     }
 
     pub fn execute(self) -> PolarsResult<QueryResult> {
+        self.execute_cancellable(Arc::default())
+    }
+
+    /// Executes the query, stopping it with an error at earliest convenience once `cancel_token`
+    /// is set.
+    pub fn execute_cancellable(
+        self,
+        cancel_token: Arc<RelaxedCell<bool>>,
+    ) -> PolarsResult<QueryResult> {
         let StreamingQuery {
             top_ir,
             mut graph,
@@ -152,9 +162,29 @@ impl StreamingQuery {
         } = self;
 
         let query_start = Instant::now();
-        let mut results = crate::execute::execute_graph(&mut graph, metrics.clone())?;
+        let mut results = crate::execute::execute_graph(&mut graph, metrics.clone(), cancel_token)?;
         let query_elapsed = query_start.elapsed();
 
+        if let Some(callback) = query_metrics_callback()
+            && let Some(lock) = metrics.as_ref()
+        {
+            let m = lock.lock();
+            let nodes = phys_sm
+                .keys()
+                .filter_map(|phys_node_key| {
+                    let graph_node_key = *phys_to_graph.get(phys_node_key)?;
+                    let node_metrics = m.get(graph_node_key)?;
+                    let name = graph.nodes[graph_node_key].compute.name().to_string();
+                    Some((name, node_metrics.clone()))
+                })
+                .collect();
+            drop(m);
+            callback(&QueryMetrics {
+                elapsed: query_elapsed,
+                nodes,
+            });
+        }
+
         // Print metrics.
         if let Some(lock) = metrics
             && std::env::var("POLARS_LOG_METRICS").as_deref() == Ok("1")
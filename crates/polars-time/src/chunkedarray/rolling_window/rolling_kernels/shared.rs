@@ -2,11 +2,11 @@
 
 use arrow::array::{ArrayRef, PrimitiveArray};
 use arrow::bitmap::MutableBitmap;
+#[cfg(feature = "timezones")]
+use arrow::legacy::time_zone::Tz;
 use arrow::trusted_len::TrustedLen;
 use arrow::types::NativeType;
 use bytemuck::allocation::zeroed_vec;
-#[cfg(feature = "timezones")]
-use chrono_tz::Tz;
 use polars_compute::rolling::no_nulls::RollingAggWindowNoNulls;
 use polars_compute::rolling::nulls::RollingAggWindowNulls;
 use polars_core::prelude::*;
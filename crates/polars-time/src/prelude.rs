@@ -1,5 +1,5 @@
 pub use crate::chunkedarray::*;
-pub use crate::series::TemporalMethods;
+pub use crate::series::{TemporalMethods, WeekConvention};
 pub use crate::windows::bounds::*;
 pub use crate::windows::duration::*;
 pub use crate::windows::group_by::*;
@@ -1,11 +1,35 @@
 use std::ops::Div;
 
 use arrow::temporal_conversions::{MICROSECONDS_IN_DAY, MILLISECONDS_IN_DAY, NANOSECONDS_IN_DAY};
-use polars_core::prelude::arity::unary_elementwise_values;
+use polars_core::prelude::arity::{binary_elementwise_values, unary_elementwise_values};
 use polars_core::prelude::*;
 
 use crate::chunkedarray::*;
 
+/// The convention used to number the weeks of a year.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum WeekConvention {
+    /// Weeks start on Monday and week 1 contains the first Thursday of the year.
+    ///
+    /// The week numbers range from 1 to 53.
+    #[default]
+    Iso,
+    /// Weeks start on Sunday and week 1 contains the 1st of January.
+    ///
+    /// The week numbers range from 1 to 54.
+    Us,
+}
+
+fn ensure_start_month(start_month: i8) -> PolarsResult<()> {
+    polars_ensure!(
+        (1..=12).contains(&start_month),
+        InvalidOperation: "`start_month` must be between 1 and 12, got {}", start_month
+    );
+    Ok(())
+}
+
 pub trait AsSeries {
     fn as_series(&self) -> &Series;
 }
@@ -131,6 +155,26 @@ pub trait TemporalMethods: AsSeries {
         }
     }
 
+    /// Returns the week number starting from 1 according to the given convention.
+    fn week_with_convention(&self, convention: WeekConvention) -> PolarsResult<Int8Chunked> {
+        match convention {
+            WeekConvention::Iso => self.week(),
+            WeekConvention::Us => {
+                let ordinal = self.ordinal_day()?;
+                let weekday = self.weekday()?;
+                Ok(binary_elementwise_values(
+                    &ordinal,
+                    &weekday,
+                    |ordinal: i16, weekday: i8| {
+                        // Weekday of the 1st of January, where sunday = 0.
+                        let first_weekday = ((weekday as i16 % 7 - (ordinal - 1)) % 7 + 7) % 7;
+                        ((ordinal - 1 + first_weekday) / 7 + 1) as i8
+                    },
+                ))
+            },
+        }
+    }
+
     /// Returns the day of year starting from 1.
     ///
     /// The return value ranges from 1 to 366. (The last day of year differs by years.)
@@ -236,6 +280,34 @@ pub trait TemporalMethods: AsSeries {
         }
     }
 
+    /// Returns the fiscal year for fiscal years that start in `start_month`.
+    ///
+    /// A fiscal year is named after the calendar year in which it ends, e.g. the fiscal year
+    /// that starts in April 2024 is the fiscal year 2025.
+    fn fiscal_year(&self, start_month: i8) -> PolarsResult<Int32Chunked> {
+        ensure_start_month(start_month)?;
+        let year = self.year()?;
+        if start_month == 1 {
+            return Ok(year);
+        }
+        let month = self.month()?;
+        Ok(binary_elementwise_values(
+            &year,
+            &month,
+            |year: i32, month: i8| year + (month >= start_month) as i32,
+        ))
+    }
+
+    /// Returns the quarter of the fiscal year for fiscal years that start in `start_month`.
+    /// Quarters range from 1 to 4.
+    fn fiscal_quarter(&self, start_month: i8) -> PolarsResult<Int8Chunked> {
+        ensure_start_month(start_month)?;
+        let month = self.month()?;
+        Ok(unary_elementwise_values(&month, |month| {
+            (month - start_month + 12) % 12 / 3 + 1
+        }))
+    }
+
     /// Extract month from underlying NaiveDateTime representation.
     /// Returns the month number starting from 1.
     ///
@@ -28,7 +28,7 @@ pub(crate) fn try_localize_datetime(
     ambiguous: Ambiguous,
     non_existent: NonExistent,
 ) -> PolarsResult<Option<NaiveDateTime>> {
-    convert_to_naive_local(&chrono_tz::UTC, tz, ndt, ambiguous, non_existent)
+    convert_to_naive_local(&Tz::UTC, tz, ndt, ambiguous, non_existent)
 }
 
 #[cfg(feature = "timezones")]
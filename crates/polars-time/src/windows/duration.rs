@@ -663,7 +663,7 @@ impl Duration {
         match tz {
             #[cfg(feature = "timezones")]
             // for UTC, use fastpath below (same as naive)
-            Some(tz) if tz != &chrono_tz::UTC => {
+            Some(tz) if tz != &Tz::UTC => {
                 let original_dt_utc = _timestamp_to_datetime(t);
                 let original_dt_local = unlocalize_datetime(original_dt_utc, tz);
                 let t = _datetime_to_timestamp(original_dt_local);
@@ -704,7 +704,7 @@ impl Duration {
         let t = match tz {
             #[cfg(feature = "timezones")]
             // for UTC, use fastpath below (same as naive)
-            Some(tz) if tz != &chrono_tz::UTC => {
+            Some(tz) if tz != &Tz::UTC => {
                 _original_dt_utc = Some(_timestamp_to_datetime(t));
                 _original_dt_local = Some(unlocalize_datetime(_original_dt_utc.unwrap(), tz));
                 _datetime_to_timestamp(_original_dt_local.unwrap())
@@ -728,7 +728,7 @@ impl Duration {
         match tz {
             #[cfg(feature = "timezones")]
             // for UTC, use fastpath below (same as naive)
-            Some(tz) if tz != &chrono_tz::UTC => {
+            Some(tz) if tz != &Tz::UTC => {
                 let result_dt_local = _timestamp_to_datetime(result_t_local);
                 let result_dt_utc =
                     self.localize_result_rfc_5545(_original_dt_utc.unwrap(), result_dt_local, tz)?;
@@ -754,7 +754,7 @@ impl Duration {
         let t = match tz {
             #[cfg(feature = "timezones")]
             // for UTC, use fastpath below (same as naive)
-            Some(tz) if tz != &chrono_tz::UTC => {
+            Some(tz) if tz != &Tz::UTC => {
                 original_dt_utc = timestamp_to_datetime(t);
                 original_dt_local = unlocalize_datetime(original_dt_utc, tz);
                 datetime_to_timestamp(original_dt_local)
@@ -811,7 +811,7 @@ impl Duration {
         match tz {
             #[cfg(feature = "timezones")]
             // for UTC, use fastpath below (same as naive)
-            Some(tz) if tz != &chrono_tz::UTC => {
+            Some(tz) if tz != &Tz::UTC => {
                 let result_dt_local = timestamp_to_datetime(t - remainder_days * daily_duration);
                 let result_dt_utc =
                     self.localize_result_rfc_5545(original_dt_utc, result_dt_local, tz)?;
@@ -945,7 +945,7 @@ impl Duration {
             t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
-                Some(tz) if tz != &chrono_tz::UTC => {
+                Some(tz) if tz != &Tz::UTC => {
                     let original_dt_utc = timestamp_to_datetime(t);
                     let original_dt_local = unlocalize_datetime(original_dt_utc, tz);
                     let result_dt_local = Self::add_month(original_dt_local, d.months, d.negative);
@@ -968,7 +968,7 @@ impl Duration {
             t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
-                Some(tz) if tz != &chrono_tz::UTC => {
+                Some(tz) if tz != &Tz::UTC => {
                     let original_dt_utc = timestamp_to_datetime(t);
                     let original_dt_local = unlocalize_datetime(original_dt_utc, tz);
                     let mut result_timestamp_local = datetime_to_timestamp(original_dt_local);
@@ -995,7 +995,7 @@ impl Duration {
             t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
-                Some(tz) if tz != &chrono_tz::UTC => {
+                Some(tz) if tz != &Tz::UTC => {
                     let original_dt_utc = timestamp_to_datetime(t);
                     let original_dt_local = unlocalize_datetime(original_dt_utc, tz);
                     t = datetime_to_timestamp(original_dt_local);
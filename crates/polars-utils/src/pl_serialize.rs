@@ -208,6 +208,40 @@ thread_local! {
     pub static USE_CLOUDPICKLE: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
 }
 
+/// A user-registered serializer for Python objects, consisting of `dumps` and
+/// `loads` callables that take precedence over (cloud)pickle.
+#[cfg(feature = "python")]
+pub struct PythonObjectSerializer {
+    pub dumps: pyo3::Py<pyo3::PyAny>,
+    pub loads: pyo3::Py<pyo3::PyAny>,
+}
+
+#[cfg(feature = "python")]
+static PYTHON_OBJECT_SERIALIZER: std::sync::RwLock<Option<std::sync::Arc<PythonObjectSerializer>>> =
+    std::sync::RwLock::new(None);
+
+/// Register the serializer used for Python objects (e.g. UDFs) in plans, or unregister it by
+/// passing `None`.
+#[cfg(feature = "python")]
+pub fn set_python_object_serializer(serializer: Option<PythonObjectSerializer>) {
+    *PYTHON_OBJECT_SERIALIZER.write().unwrap() = serializer.map(std::sync::Arc::new);
+}
+
+#[cfg(feature = "python")]
+fn python_object_serializer() -> Option<std::sync::Arc<PythonObjectSerializer>> {
+    PYTHON_OBJECT_SERIALIZER.read().unwrap().clone()
+}
+
+/// The method used to serialize a Python object, stored as its first byte.
+#[cfg(feature = "python")]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum PythonSerializeMethod {
+    Pickle = 0,
+    Cloudpickle = 1,
+    Registered = 2,
+}
+
 #[cfg(feature = "python")]
 pub fn python_object_serialize(
     pyobj: &pyo3::Py<pyo3::PyAny>,
@@ -219,29 +253,35 @@ pub fn python_object_serialize(
 
     use crate::python_function::PYTHON3_VERSION;
 
-    let mut use_cloudpickle = USE_CLOUDPICKLE.get();
-    let dumped = Python::attach(|py| {
+    let serializer = python_object_serializer();
+    let (method, dumped) = Python::attach(|py| {
         // Pickle with whatever pickling method was selected.
-        if use_cloudpickle {
+        let (method, dumped) = if let Some(serializer) = &serializer {
+            let dumped = serializer.dumps.bind(py).call1((pyobj.clone_ref(py),))?;
+            (PythonSerializeMethod::Registered, dumped)
+        } else if USE_CLOUDPICKLE.get() {
             let cloudpickle = PyModule::import(py, "cloudpickle")?.getattr("dumps")?;
-            cloudpickle.call1((pyobj.clone_ref(py),))?
+            let dumped = cloudpickle.call1((pyobj.clone_ref(py),))?;
+            (PythonSerializeMethod::Cloudpickle, dumped)
         } else {
             let pickle = PyModule::import(py, "pickle")?.getattr("dumps")?;
             match pickle.call1((pyobj.clone_ref(py),)) {
-                Ok(dumped) => dumped,
+                Ok(dumped) => (PythonSerializeMethod::Pickle, dumped),
                 Err(_) => {
-                    use_cloudpickle = true;
                     let cloudpickle = PyModule::import(py, "cloudpickle")?.getattr("dumps")?;
-                    cloudpickle.call1((pyobj.clone_ref(py),))?
+                    let dumped = cloudpickle.call1((pyobj.clone_ref(py),))?;
+                    (PythonSerializeMethod::Cloudpickle, dumped)
                 },
             }
-        }
-        .extract::<PyBackedBytes>()
-        .map_err(pyo3::PyErr::from)
+        };
+        dumped
+            .extract::<PyBackedBytes>()
+            .map(|dumped| (method, dumped))
+            .map_err(pyo3::PyErr::from)
     })?;
 
     // Write pickle metadata
-    buf.push(use_cloudpickle as u8);
+    buf.push(method as u8);
     buf.extend_from_slice(&*PYTHON3_VERSION);
 
     // Write UDF
@@ -251,15 +291,20 @@ pub fn python_object_serialize(
 
 #[cfg(feature = "python")]
 pub fn python_object_deserialize(buf: &[u8]) -> PolarsResult<pyo3::Py<pyo3::PyAny>> {
-    use polars_error::polars_ensure;
+    use polars_error::{polars_bail, polars_ensure};
     use pyo3::Python;
     use pyo3::types::{PyAnyMethods, PyBytes, PyModule};
 
     use crate::python_function::PYTHON3_VERSION;
 
     // Handle pickle metadata
-    let use_cloudpickle = buf[0] != 0;
-    if use_cloudpickle {
+    let method = match buf[0] {
+        0 => PythonSerializeMethod::Pickle,
+        1 => PythonSerializeMethod::Cloudpickle,
+        2 => PythonSerializeMethod::Registered,
+        v => polars_bail!(ComputeError: "unknown Python object serialization method: {v}"),
+    };
+    if method != PythonSerializeMethod::Pickle {
         let ser_py_version = &buf[1..3];
         let cur_py_version = *PYTHON3_VERSION;
         polars_ensure!(
@@ -272,8 +317,23 @@ pub fn python_object_deserialize(buf: &[u8]) -> PolarsResult<pyo3::Py<pyo3::PyAn
     }
     let buf = &buf[3..];
 
+    let serializer = if method == PythonSerializeMethod::Registered {
+        let serializer = python_object_serializer();
+        polars_ensure!(
+            serializer.is_some(),
+            InvalidOperation:
+            "the UDF was serialized with a registered serializer; register it to deserialize the UDF"
+        );
+        serializer
+    } else {
+        None
+    };
+
     Python::attach(|py| {
-        let loads = PyModule::import(py, "pickle")?.getattr("loads")?;
+        let loads = match &serializer {
+            Some(serializer) => serializer.loads.bind(py).clone(),
+            None => PyModule::import(py, "pickle")?.getattr("loads")?,
+        };
         let arg = (PyBytes::new(py, buf),);
         let python_function = loads.call1(arg)?;
         Ok(python_function.into())
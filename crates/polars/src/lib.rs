@@ -231,6 +231,7 @@
 //!     - `is_in` - Check for membership in [`Series`].
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip).
 //!     - `round_series` - Round underlying float types of [`Series`].
+//!     - `decimal_arithmetic` - Decimal arithmetic with SQL precision/scale rules.
 //!     - `repeat_by` - Repeat element in an Array N times, where N is given by another array.
 //!     - `is_first_distinct` - Check if element is first unique value.
 //!     - `is_last_distinct` - Check if element is last unique value.
@@ -270,6 +271,8 @@
 //!     - `propagate_nans` - NaN propagating min/max aggregations.
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
+//!     - `weighted_agg` - Weighted mean, variance and quantile aggregations.
+//!     - `approx_quantile` - Approximate quantile aggregation with t-digest.
 //!     - `find_many` - Find/replace multiple string patterns at once.
 //! * [`DataFrame`] pretty printing
 //!     - `fmt` - Activate [`DataFrame`] formatting
@@ -1,2 +1,4 @@
 pub use polars_sql::function_registry::*;
-pub use polars_sql::{SQLContext, extract_table_identifiers, keywords, sql_expr};
+pub use polars_sql::{
+    SQLContext, SQLDialect, SQLParams, extract_table_identifiers, keywords, sql_expr,
+};
@@ -1,5 +1,5 @@
 use arrow::array::*;
-use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::compute::aggregate::{SeenBuffers, estimated_bytes_size, estimated_bytes_size_dedup};
 use arrow::datatypes::{ArrowDataType, Field};
 
 #[test]
@@ -30,3 +30,33 @@ fn fixed_size_list() {
     let a = FixedSizeListArray::new(dtype, 2, values, None);
     assert_eq!(6 * size_of::<f32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn utf8_view() {
+    let a = Utf8ViewArray::from_slice_values(["a", "this string is not inlined"]);
+    // Only the visible string bytes are counted by default.
+    assert_eq!(27, estimated_bytes_size(&a));
+    assert_eq!(1, estimated_bytes_size(&a.clone().sliced(0, 1)));
+
+    // The data buffers are counted in full, but only once when they are shared between slices.
+    let mut seen = SeenBuffers::default();
+    assert_eq!(
+        size_of::<View>() + 26,
+        estimated_bytes_size_dedup(&a.clone().sliced(0, 1), &mut seen)
+    );
+    assert_eq!(
+        size_of::<View>(),
+        estimated_bytes_size_dedup(&a.sliced(1, 1), &mut seen)
+    );
+}
+
+#[test]
+fn dedup() {
+    let a = Int32Array::from_slice([1, 2, 3, 4, 5]);
+    let mut seen = SeenBuffers::default();
+    assert_eq!(
+        5 * size_of::<i32>(),
+        estimated_bytes_size_dedup(&a, &mut seen)
+    );
+    assert_eq!(0, estimated_bytes_size_dedup(&a.clone(), &mut seen));
+}
@@ -41,3 +41,41 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+fn test_series_metadata() -> PolarsResult<()> {
+    let md = |kv: &[(&str, &str)]| {
+        let md = kv.iter().map(|(k, v)| ((*k).into(), (*v).into()));
+        Some(Arc::new(md.collect::<arrow::datatypes::Metadata>()))
+    };
+
+    let mut a = Series::new("a".into(), &[1, 2, 3]);
+    a.set_metadata(md(&[("unit", "celsius")]));
+
+    // Metadata survives renames, slices and gathers.
+    a.rename("b".into());
+    assert_eq!(a.metadata(), md(&[("unit", "celsius")]));
+    assert_eq!(a.slice(1, 2).metadata(), md(&[("unit", "celsius")]));
+    assert_eq!(
+        a.take_slice(&[2, 0])?.metadata(),
+        md(&[("unit", "celsius")])
+    );
+
+    // Appending merges metadata, keeping the keys of the left side.
+    let b = Series::new("b".into(), &[4, 5])
+        .with_metadata(md(&[("unit", "kelvin"), ("source", "sensor")]));
+    a.append(&b)?;
+    assert_eq!(
+        a.metadata(),
+        md(&[("unit", "celsius"), ("source", "sensor")])
+    );
+
+    // Metadata doesn't take part in equality.
+    assert_eq!(a, Series::new("b".into(), &[1, 2, 3, 4, 5]));
+
+    // Replacing a column replaces its metadata.
+    let mut df = DataFrame::new(vec![a.into()])?;
+    df.with_column(Series::new("b".into(), &[0; 5]).with_metadata(md(&[("unit", "kelvin")])))?;
+    assert_eq!(df.column("b")?.metadata(), md(&[("unit", "kelvin")]));
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use std::io::{Cursor, Seek, SeekFrom};
 
+use arrow::datatypes::Metadata;
 use polars::prelude::*;
 
 #[test]
@@ -155,3 +156,24 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+fn test_ipc_column_metadata_roundtrip() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let md: Metadata = [("unit".into(), "celsius".into())].into();
+    let days = Column::new("days".into(), [0, 1, 2]);
+    let mut temp = Column::new("temp".into(), [22.1, 19.9, 7.]);
+    temp.set_metadata(Some(Arc::new(md.clone())));
+    let mut df = DataFrame::new_infer_height(vec![days, temp]).unwrap();
+
+    IpcWriter::new(&mut buf).finish(&mut df).unwrap();
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert!(df.equals(&df_read));
+    assert_eq!(df_read.column("days").unwrap().metadata(), None);
+    assert_eq!(
+        df_read.column("temp").unwrap().metadata().as_deref(),
+        Some(&md)
+    );
+}
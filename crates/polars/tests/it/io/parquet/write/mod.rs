@@ -4,6 +4,7 @@ mod sidecar;
 
 use std::io::{Cursor, Read, Seek};
 
+use arrow::datatypes::Metadata;
 use polars::io::SerReader;
 use polars::io::parquet::read::ParquetReader;
 use polars::io::parquet::write::ParquetWriter;
@@ -299,3 +300,22 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+fn test_parquet_column_metadata_roundtrip() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let md: Metadata = [("description".into(), "the b column".into())].into();
+    let a = Column::new("a".into(), [1, 2, 3]);
+    let mut b = Column::new("b".into(), ["x", "y", "z"]);
+    b.set_metadata(Some(Arc::new(md.clone())));
+    let mut df = DataFrame::new_infer_height(vec![a, b])?;
+
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&df_read));
+    assert_eq!(df_read.column("a")?.metadata(), None);
+    assert_eq!(df_read.column("b")?.metadata().as_deref(), Some(&md));
+    Ok(())
+}
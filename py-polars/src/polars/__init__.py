@@ -187,10 +187,12 @@ from polars.functions import (
     ones,
     quantile,
     reduce,
+    register_aggregation,
     repeat,
     rolling_corr,
     rolling_cov,
     row_index,
+    search_sorted_by,
     select,
     self_dtype,
     set_random_seed,
@@ -205,6 +207,7 @@ from polars.functions import (
     time_range,
     time_ranges,
     union,
+    uuid4,
     var,
     when,
     zeros,
@@ -250,11 +253,19 @@ from polars.io.cloud import (
     CredentialProviderFunctionReturn,
     CredentialProviderGCP,
 )
-from polars.lazyframe import GPUEngine, LazyFrame, QueryOptFlags
+from polars.lazyframe import (
+    GPUEngine,
+    LazyFrame,
+    QueryMetrics,
+    QueryOptFlags,
+    set_streaming_metrics_callback,
+)
 from polars.meta import (
     build_info,
     get_index_type,
+    row_encoding_version,
     show_versions,
+    stable_hash_version,
     thread_pool_size,
     threadpool_size,
 )
@@ -456,9 +467,11 @@ __all__ = [
     "nth",
     "quantile",
     "reduce",
+    "register_aggregation",
     "rolling_corr",
     "rolling_cov",
     "row_index",
+    "search_sorted_by",
     "select",
     "std",
     "struct",
@@ -469,6 +482,7 @@ __all__ = [
     "len",
     # polars.functions.random
     "set_random_seed",
+    "uuid4",
     # polars.convert
     "from_arrow",
     "from_dataframe",
@@ -483,7 +497,9 @@ __all__ = [
     # polars.meta
     "build_info",
     "get_index_type",
+    "row_encoding_version",
     "show_versions",
+    "stable_hash_version",
     "thread_pool_size",
     "threadpool_size",
     # polars.sql
@@ -493,6 +509,9 @@ __all__ = [
     "CompatLevel",
     # optimization
     "QueryOptFlags",
+    # streaming metrics
+    "QueryMetrics",
+    "set_streaming_metrics_callback",
 ]
 
 
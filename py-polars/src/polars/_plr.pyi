@@ -3,7 +3,7 @@ from typing import Any, Literal, TypeAlias, overload
 
 from numpy.typing import NDArray
 
-from polars._typing import ArrowSchemaExportable
+from polars._typing import ArrowCoercion, ArrowSchemaExportable
 from polars.io.scan_options._options import ScanOptions
 
 # This file mirrors all the definitions made in the polars-python Rust API.
@@ -69,7 +69,11 @@ QuantileMethod: TypeAlias = Literal[
 RankMethod: TypeAlias = Literal["min", "max", "average", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
+WeekConvention: TypeAlias = Literal["iso", "us"]
 UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
+UnnestCollision: TypeAlias = Literal["raise", "overwrite", "keep"]
+Base64Alphabet: TypeAlias = Literal["base64", "base64url"]
+UrlPart: TypeAlias = Literal["scheme", "host", "path"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 ClosedInterval: TypeAlias = Literal["both", "left", "right", "none"]
 WindowMapping: TypeAlias = Literal["group_to_rows", "join", "explode"]
@@ -106,17 +110,28 @@ class PolarsWarning(Warning): ...
 class PerformanceWarning(PolarsWarning): ...
 class CategoricalRemappingWarning(PerformanceWarning): ...
 class MapWithoutReturnDtypeWarning(PolarsWarning): ...
+class LossyCastWarning(PolarsWarning): ...
 class PanicException(PolarsError): ...
 
 class PySeries:
     # map
     def map_elements(
-        self, function: Any, return_dtype: Any | None, skip_nulls: bool
+        self,
+        function: Any,
+        return_dtype: Any | None,
+        skip_nulls: bool,
+        cache_objects: bool = False,
+    ) -> PySeries: ...
+    def _map_elements_cfunc(
+        self, address: int, arg_dtype: Any, return_dtype: Any
     ) -> PySeries: ...
 
     # general
     def struct_unnest(self) -> PyDataFrame: ...
     def struct_fields(self) -> list[str]: ...
+    def row_decode(
+        self, schema: Any, descending: Sequence[bool], nulls_last: Sequence[bool]
+    ) -> PyDataFrame: ...
     def is_sorted_ascending_flag(self) -> bool: ...
     def is_sorted_descending_flag(self) -> bool: ...
     def can_fast_explode_flag(self) -> bool: ...
@@ -360,7 +375,9 @@ class PySeries:
         owner: Any,
     ) -> PySeries: ...
     def _get_buffer_info(self) -> BufferInfo: ...
-    def _get_buffers(self) -> tuple[PySeries, PySeries | None, PySeries | None]: ...
+    def _get_buffers(
+        self, allow_copy: bool = True
+    ) -> tuple[PySeries, PySeries | None, PySeries | None]: ...
 
     # c_interface
     @staticmethod
@@ -495,6 +512,8 @@ class PySeries:
     @staticmethod
     def new_f64(name: str, array: NDArray1D, nan_is_null: bool) -> PySeries: ...
     @staticmethod
+    def _import_buffer(obj: Any, dtype: DataType) -> PySeries: ...
+    @staticmethod
     def new_opt_bool(name: str, values: Any, _strict: bool) -> PySeries: ...
     @staticmethod
     def new_opt_u8(name: str, obj: Any, strict: bool) -> PySeries: ...
@@ -553,17 +572,45 @@ class PySeries:
 
     # export
     def to_list(self) -> list[Any]: ...
+    def iter_py(self, buffer_size: int) -> PySeriesIter: ...
     def to_arrow(self, compat_level: Any) -> Any: ...
+    def to_arrow_chunks(self, compat_level: Any) -> list[Any]: ...
+    def __arrow_c_array__(self, requested_schema: Any | None) -> tuple[Any, Any]: ...
     def __arrow_c_stream__(self, requested_schema: Any | None) -> Any: ...
-    def _export(self, location: int) -> None: ...
+    def __dlpack__(
+        self,
+        *,
+        stream: Any | None = None,
+        max_version: tuple[int, int] | None = None,
+        dl_device: tuple[int, int] | None = None,
+        copy: bool | None = None,
+    ) -> Any: ...
+    def __dlpack_device__(self) -> tuple[int, int]: ...
+    @staticmethod
+    def _ffi_version() -> tuple[int, int]: ...
+    def _export(
+        self, location: int, ffi_version: tuple[int, int] | None = None
+    ) -> None: ...
 
     # import
     @classmethod
-    def from_arrow_c_array(cls, ob: Any) -> PySeries: ...
+    def from_arrow_c_array(
+        cls,
+        ob: Any,
+        coerce: ArrowCoercion | None = None,
+        coerce_overrides: dict[str, ArrowCoercion] | None = None,
+    ) -> PySeries: ...
     @classmethod
-    def from_arrow_c_stream(cls, ob: Any) -> PySeries: ...
+    def from_arrow_c_stream(
+        cls,
+        ob: Any,
+        coerce: ArrowCoercion | None = None,
+        coerce_overrides: dict[str, ArrowCoercion] | None = None,
+    ) -> PySeries: ...
     @classmethod
-    def _import(cls, location: int) -> PySeries: ...
+    def _import(
+        cls, location: int, ffi_version: tuple[int, int] | None = None
+    ) -> PySeries: ...
 
     # numpy ufunc
     def apply_ufunc_f32(self, lambda_func: Any, allocate_out: bool) -> PySeries: ...
@@ -582,6 +629,7 @@ class PySeries:
 
     # interop
     def to_numpy(self, writable: bool, allow_copy: bool) -> Any: ...
+    def to_numpy_masked(self, writable: bool, allow_copy: bool) -> Any: ...
     def to_numpy_view(self) -> Any | None: ...
     @staticmethod
     def _import_decimal_from_iceberg_binary_repr(
@@ -676,6 +724,14 @@ class PyDataFrame:
         drop_first: bool,
         drop_nulls: bool,
     ) -> PyDataFrame: ...
+    def search_sorted(
+        self,
+        by: Sequence[str],
+        search_values: PyDataFrame,
+        side: Any,
+        descending: Sequence[bool],
+        nulls_last: Sequence[bool],
+    ) -> PySeries: ...
     def null_count(self) -> PyDataFrame: ...
     def map_rows(
         self,
@@ -685,8 +741,12 @@ class PyDataFrame:
     ) -> tuple[Any, bool]: ...
     def shrink_to_fit(self) -> None: ...
     def hash_rows(self, k0: int, k1: int, k2: int, k3: int) -> PySeries: ...
+    def hash_rows_stable(self, seed: int) -> PySeries: ...
     def transpose(
-        self, keep_names_as: str | None, column_names: None | str | Sequence[str]
+        self,
+        keep_names_as: str | None,
+        column_names: None | str | Sequence[str],
+        dtype: DataType | None = None,
     ) -> PyDataFrame: ...
     def upsample(
         self,
@@ -697,10 +757,17 @@ class PyDataFrame:
     ) -> PyDataFrame: ...
     def to_struct(self, name: str, invalid_indices: Sequence[int]) -> PySeries: ...
     def clear(self) -> PyDataFrame: ...
-    def _export_columns(self, location: int) -> None: ...
+    def _export_columns(
+        self, location: int, ffi_version: tuple[int, int] | None = None
+    ) -> None: ...
     @classmethod
-    def _import_columns(cls, location: int, width: int) -> PyDataFrame: ...
+    def _import_columns(
+        cls, location: int, width: int, ffi_version: tuple[int, int] | None = None
+    ) -> PyDataFrame: ...
     def _row_encode(self, opts: Sequence[tuple[bool, bool, bool]]) -> PySeries: ...
+    def row_encode(
+        self, descending: Sequence[bool], nulls_last: Sequence[bool]
+    ) -> PySeries: ...
 
     # construction
     @staticmethod
@@ -726,9 +793,19 @@ class PyDataFrame:
     # export
     def row_tuple(self, idx: int) -> tuple[Any, ...]: ...
     def row_tuples(self) -> list[tuple[Any, ...]]: ...
+    def iter_rows(self, buffer_size: int) -> PyRowIter: ...
     def to_arrow(self, compat_level: Any) -> list[Any]: ...
     def to_pandas(self) -> list[Any]: ...
     def __arrow_c_stream__(self, requested_schema: Any | None) -> Any: ...
+    def __dlpack__(
+        self,
+        *,
+        stream: Any | None = None,
+        max_version: tuple[int, int] | None = None,
+        dl_device: tuple[int, int] | None = None,
+        copy: bool | None = None,
+    ) -> Any: ...
+    def __dlpack_device__(self) -> tuple[int, int]: ...
 
     # io
     @staticmethod
@@ -817,6 +894,12 @@ class PyDataFrame:
         writable: bool,
         allow_copy: bool,
     ) -> Any: ...
+    def to_numpy_masked(
+        self,
+        order: IndexOrder,
+        writable: bool,
+        allow_copy: bool,
+    ) -> Any: ...
 
 class PyLazyFrame:
     @staticmethod
@@ -925,6 +1008,7 @@ class PyLazyFrame:
     def describe_optimized_plan(self) -> str: ...
     def describe_plan_tree(self) -> str: ...
     def describe_optimized_plan_tree(self) -> str: ...
+    def query_plan(self, optimized: bool) -> QueryPlanNode: ...
     def to_dot(self, optimized: bool) -> str: ...
     def to_dot_streaming_phys(self, optimized: bool) -> str: ...
     def sort(
@@ -959,6 +1043,13 @@ class PyLazyFrame:
     def collect_batches(
         self, engine: Any, maintain_order: bool, chunk_size: int | None, lazy: bool
     ) -> PyCollectBatches: ...
+    def collect_arrow_stream(
+        self,
+        engine: Any,
+        maintain_order: bool,
+        chunk_size: int | None,
+        compat_level: CompatLevel,
+    ) -> PyArrowBatchStream: ...
     def sink_parquet(
         self,
         target: SinkTarget,
@@ -1020,7 +1111,9 @@ class PyLazyFrame:
     def remove(self, predicate: PyExpr) -> PyLazyFrame: ...
     def select(self, exprs: Sequence[PyExpr]) -> PyLazyFrame: ...
     def select_seq(self, exprs: Sequence[PyExpr]) -> PyLazyFrame: ...
-    def group_by(self, by: Sequence[PyExpr], maintain_order: bool) -> PyLazyGroupBy: ...
+    def group_by(
+        self, by: Sequence[PyExpr], maintain_order: bool, nulls_equal: bool
+    ) -> PyLazyGroupBy: ...
     def rolling(
         self,
         index_column: PyExpr,
@@ -1146,21 +1239,33 @@ class PyLazyFrame:
         streamable: bool,
         schema: Schema | None,
         validate_output: bool,
+        preserves_order: bool = False,
+        preserves_row_count: bool = False,
+        preserves_schema: bool = False,
     ) -> PyLazyFrame: ...
     def drop(self, columns: PySelector) -> PyLazyFrame: ...
     def cast(self, dtypes: dict[str, DataType], strict: bool) -> PyLazyFrame: ...
     def cast_all(self, dtype: PyDataTypeExpr, strict: bool) -> PyLazyFrame: ...
     def clone(self) -> PyLazyFrame: ...
     def collect_schema(self) -> dict[str, Any]: ...
-    def unnest(self, columns: PySelector, separator: str | None) -> PyLazyFrame: ...
+    def unnest(
+        self,
+        columns: PySelector,
+        separator: str | None,
+        fields: Sequence[str] | None,
+        prefix: str | None,
+        on_collision: UnnestCollision,
+    ) -> PyLazyFrame: ...
     def count(self) -> PyLazyFrame: ...
     def merge_sorted(self, other: PyLazyFrame, key: str) -> PyLazyFrame: ...
     def hint_sorted(
         self, columns: list[str], descending: list[bool], nulls_last: list[bool]
     ) -> PyLazyFrame: ...
+    def hint_estimated_rows(self, n_rows: int) -> PyLazyFrame: ...
+    def estimated_rows(self) -> tuple[int, bool]: ...
 
     # exitable
-    def collect_concurrently(self) -> PyInProcessQuery: ...
+    def collect_concurrently(self, engine: Any) -> PyInProcessQuery: ...
 
     # serde
     def serialize_binary(self, py_f: Any) -> None: ...
@@ -1223,12 +1328,17 @@ class PyExpr:
     def item(self, *, allow_empty: bool) -> PyExpr: ...
     def implode(self, maintain_order: bool) -> PyExpr: ...
     def quantile(self, quantile: PyExpr, interpolation: Any) -> PyExpr: ...
+    def approx_quantile(self, quantile: float, compression: float) -> PyExpr: ...
+    def weighted_mean(self, weights: PyExpr) -> PyExpr: ...
+    def weighted_var(self, weights: PyExpr, ddof: int) -> PyExpr: ...
+    def weighted_quantile(self, weights: PyExpr, quantile: PyExpr) -> PyExpr: ...
     def cut(
         self,
         breaks: Sequence[float],
         labels: Sequence[str] | None,
         left_closed: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> PyExpr: ...
     def qcut(
         self,
@@ -1237,6 +1347,7 @@ class PyExpr:
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> PyExpr: ...
     def qcut_uniform(
         self,
@@ -1245,6 +1356,7 @@ class PyExpr:
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        include_bins: bool,
     ) -> PyExpr: ...
     def rle(self) -> PyExpr: ...
     def rle_id(self) -> PyExpr: ...
@@ -1305,7 +1417,10 @@ class PyExpr:
     def is_close(
         self, other: PyExpr, abs_tol: float, rel_tol: float, nans_equal: bool
     ) -> PyExpr: ...
-    def approx_n_unique(self) -> PyExpr: ...
+    def approx_n_unique(self, precision: int | None) -> PyExpr: ...
+    def hll_sketch(self, precision: int) -> PyExpr: ...
+    def hll_merge(self) -> PyExpr: ...
+    def hll_estimate(self) -> PyExpr: ...
     def is_first_distinct(self) -> PyExpr: ...
     def is_last_distinct(self) -> PyExpr: ...
     def explode(self, *, empty_as_null: bool, keep_nulls: bool) -> PyExpr: ...
@@ -1314,6 +1429,10 @@ class PyExpr:
     def append(self, other: PyExpr, upcast: bool) -> PyExpr: ...
     def rechunk(self) -> PyExpr: ...
     def round(self, decimals: int, mode: Any) -> PyExpr: ...
+    def decimal_arithmetic(
+        self, other: PyExpr, op: Any, rounding: Any, overflow: Any
+    ) -> PyExpr: ...
+    def checked_arithmetic(self, other: PyExpr, op: Any, warn: bool) -> PyExpr: ...
     def round_sig_figs(self, digits: int) -> PyExpr: ...
     def truncate(self, decimals: int) -> PyExpr: ...
     def floor(self) -> PyExpr: ...
@@ -1358,6 +1477,9 @@ class PyExpr:
     def sqrt(self) -> PyExpr: ...
     def cbrt(self) -> PyExpr: ...
     def cum_sum(self, reverse: bool) -> PyExpr: ...
+    def cum_agg_reset(
+        self, reset: PyExpr, function: Any, reverse: bool
+    ) -> PyExpr: ...
     def cum_max(self, reverse: bool) -> PyExpr: ...
     def cum_min(self, reverse: bool) -> PyExpr: ...
     def cum_prod(self, reverse: bool) -> PyExpr: ...
@@ -1367,9 +1489,12 @@ class PyExpr:
     def shrink_dtype(self) -> PyExpr: ...
     def dot(self, other: PyExpr) -> PyExpr: ...
     def reinterpret(self, signed: bool | None, dtype: DataType | None) -> PyExpr: ...
-    def mode(self, *, maintain_order: bool) -> PyExpr: ...
+    def mode(self, *, maintain_order: bool, ties: Any) -> PyExpr: ...
+    def modes(self, n: int) -> PyExpr: ...
     def interpolate(self, method: Any) -> PyExpr: ...
-    def interpolate_by(self, by: PyExpr) -> PyExpr: ...
+    def interpolate_by(
+        self, by: PyExpr, method: Any, max_gap: PyExpr | None
+    ) -> PyExpr: ...
     def lower_bound(self) -> PyExpr: ...
     def upper_bound(self) -> PyExpr: ...
     def rank(self, method: Any, descending: bool, seed: int | None) -> PyExpr: ...
@@ -1414,6 +1539,10 @@ class PyExpr:
     def exp(self) -> PyExpr: ...
     def entropy(self, base: float, normalize: bool) -> PyExpr: ...
     def hash(self, seed: int, seed_1: int, seed_2: int, seed_3: int) -> PyExpr: ...
+    def stable_hash(self, seed: int) -> PyExpr: ...
+    def hash_sha256(self, hex: bool) -> PyExpr: ...
+    def hash_md5(self, hex: bool) -> PyExpr: ...
+    def hash_xxh3(self, seed: int, hex: bool) -> PyExpr: ...
     def set_sorted_flag(self, descending: bool, nulls_last: bool | None) -> PyExpr: ...
     def replace(self, old: PyExpr, new: PyExpr) -> PyExpr: ...
     def replace_strict(
@@ -1475,6 +1604,9 @@ class PyExpr:
     def arr_join(self, separator: PyExpr, ignore_nulls: bool) -> PyExpr: ...
     def arr_contains(self, other: PyExpr, nulls_equal: bool) -> PyExpr: ...
     def arr_count_matches(self, expr: PyExpr) -> PyExpr: ...
+    def arr_dot(self, other: PyExpr) -> PyExpr: ...
+    def arr_l2_norm(self) -> PyExpr: ...
+    def arr_cosine_similarity(self, other: PyExpr) -> PyExpr: ...
     def arr_to_struct(self, name_gen: Any | None = None) -> PyExpr: ...
     def arr_slice(
         self, offset: PyExpr, length: PyExpr | None = None, as_array: bool = False
@@ -1490,15 +1622,16 @@ class PyExpr:
     def bin_ends_with(self, sub: PyExpr) -> PyExpr: ...
     def bin_starts_with(self, sub: PyExpr) -> PyExpr: ...
     def bin_hex_decode(self, strict: bool) -> PyExpr: ...
-    def bin_base64_decode(self, strict: bool) -> PyExpr: ...
+    def bin_base64_decode(self, strict: bool, alphabet: Base64Alphabet) -> PyExpr: ...
     def bin_hex_encode(self) -> PyExpr: ...
-    def bin_base64_encode(self) -> PyExpr: ...
+    def bin_base64_encode(self, alphabet: Base64Alphabet, padding: bool) -> PyExpr: ...
     def bin_reinterpret(self, dtype: PyDataTypeExpr, kind: str) -> PyExpr: ...
     def bin_size_bytes(self) -> PyExpr: ...
     def bin_slice(self, offset: PyExpr, length: PyExpr) -> PyExpr: ...
     def bin_head(self, n: PyExpr) -> PyExpr: ...
     def bin_tail(self, n: PyExpr) -> PyExpr: ...
     def bin_get(self, index: PyExpr, null_on_oob: bool) -> PyExpr: ...
+    def bin_to_uuid_str(self) -> PyExpr: ...
 
     # bitwise
     def bitwise_count_ones(self) -> PyExpr: ...
@@ -1513,6 +1646,8 @@ class PyExpr:
 
     # categorical
     def cat_get_categories(self) -> PyExpr: ...
+    def cat_add_categories(self, categories: Sequence[str]) -> PyExpr: ...
+    def cat_union_categories(self, other: PyExpr) -> PyExpr: ...
     def cat_len_bytes(self) -> PyExpr: ...
     def cat_len_chars(self) -> PyExpr: ...
     def cat_starts_with(self, prefix: str) -> PyExpr: ...
@@ -1561,9 +1696,11 @@ class PyExpr:
     def dt_is_leap_year(self) -> PyExpr: ...
     def dt_iso_year(self) -> PyExpr: ...
     def dt_quarter(self) -> PyExpr: ...
+    def dt_fiscal_year(self, start_month: int) -> PyExpr: ...
+    def dt_fiscal_quarter(self, start_month: int) -> PyExpr: ...
     def dt_month(self) -> PyExpr: ...
     def dt_days_in_month(self) -> PyExpr: ...
-    def dt_week(self) -> PyExpr: ...
+    def dt_week(self, convention: WeekConvention) -> PyExpr: ...
     def dt_weekday(self) -> PyExpr: ...
     def dt_day(self) -> PyExpr: ...
     def dt_ordinal_day(self) -> PyExpr: ...
@@ -1591,11 +1728,47 @@ class PyExpr:
     def list_arg_max(self) -> PyExpr: ...
     def list_arg_min(self) -> PyExpr: ...
     def list_contains(self, other: PyExpr, nulls_equal: bool) -> PyExpr: ...
+    def list_index_of(self, value: PyExpr) -> PyExpr: ...
     def list_count_matches(self, expr: PyExpr) -> PyExpr: ...
     def list_diff(self, n: int, null_behavior: NullBehavior) -> PyExpr: ...
+    def list_cum_sum(self, reverse: bool) -> PyExpr: ...
+    def list_cum_prod(self, reverse: bool) -> PyExpr: ...
+    def list_cum_min(self, reverse: bool) -> PyExpr: ...
+    def list_cum_max(self, reverse: bool) -> PyExpr: ...
+    def list_cum_count(self, reverse: bool) -> PyExpr: ...
+    def list_rolling_min(
+        self, window_size: int, min_periods: int | None = None, center: bool = False
+    ) -> PyExpr: ...
+    def list_rolling_max(
+        self, window_size: int, min_periods: int | None = None, center: bool = False
+    ) -> PyExpr: ...
+    def list_rolling_mean(
+        self, window_size: int, min_periods: int | None = None, center: bool = False
+    ) -> PyExpr: ...
+    def list_rolling_sum(
+        self, window_size: int, min_periods: int | None = None, center: bool = False
+    ) -> PyExpr: ...
+    def list_rolling_median(
+        self, window_size: int, min_periods: int | None = None, center: bool = False
+    ) -> PyExpr: ...
+    def list_rolling_std(
+        self,
+        window_size: int,
+        min_periods: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> PyExpr: ...
+    def list_rolling_var(
+        self,
+        window_size: int,
+        min_periods: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> PyExpr: ...
     def list_eval(self, expr: PyExpr, _parallel: bool) -> PyExpr: ...
     def list_agg(self, expr: PyExpr) -> PyExpr: ...
     def list_filter(self, predicate: PyExpr) -> PyExpr: ...
+    def list_find(self, predicate: PyExpr) -> PyExpr: ...
     def list_get(self, index: PyExpr, null_on_oob: bool) -> PyExpr: ...
     def list_join(self, separator: PyExpr, ignore_nulls: bool) -> PyExpr: ...
     def list_len(self) -> PyExpr: ...
@@ -1629,7 +1802,9 @@ class PyExpr:
     def list_to_struct_fixed_width(self, names: Sequence[str]) -> PyExpr: ...
     def list_n_unique(self) -> PyExpr: ...
     def list_unique(self, maintain_order: bool) -> PyExpr: ...
-    def list_set_operation(self, other: PyExpr, operation: SetOperation) -> PyExpr: ...
+    def list_set_operation(
+        self, other: PyExpr, operation: SetOperation, multiset: bool
+    ) -> PyExpr: ...
 
     # meta
     def meta_eq(self, other: PyExpr) -> bool: ...
@@ -1821,6 +1996,7 @@ class PyExpr:
         weights: Sequence[float] | None = None,
         min_periods: int | None = None,
         center: bool = False,
+        batch_size: int | None = None,
     ) -> PyExpr: ...
 
     # serde
@@ -1852,6 +2028,18 @@ class PyExpr:
         cache: bool,
         ambiguous: PyExpr,
     ) -> PyExpr: ...
+    def str_to_datetime_multi(
+        self,
+        formats: Sequence[str],
+        time_unit: TimeUnit | None,
+        time_zone: TimeZone | None,
+        strict: bool,
+        exact: bool,
+        cache: bool,
+        best_effort: bool,
+        include_format: bool,
+        ambiguous: PyExpr,
+    ) -> PyExpr: ...
     def str_to_time(
         self,
         format: str | None = None,
@@ -1868,6 +2056,7 @@ class PyExpr:
     def str_tail(self, n: PyExpr) -> PyExpr: ...
     def str_to_uppercase(self) -> PyExpr: ...
     def str_to_lowercase(self) -> PyExpr: ...
+    def str_casefold(self) -> PyExpr: ...
     def str_to_titlecase(self) -> PyExpr: ...
     def str_len_bytes(self) -> PyExpr: ...
     def str_len_chars(self) -> PyExpr: ...
@@ -1877,6 +2066,19 @@ class PyExpr:
     def str_replace_all(self, pat: PyExpr, val: PyExpr, literal: bool) -> PyExpr: ...
     def str_normalize(self, form: UnicodeForm) -> PyExpr: ...
     def str_reverse(self) -> PyExpr: ...
+    def str_levenshtein_distance(self, other: PyExpr) -> PyExpr: ...
+    def str_hamming(self, other: PyExpr) -> PyExpr: ...
+    def str_jaro_winkler(self, other: PyExpr) -> PyExpr: ...
+    def str_tokenize(
+        self,
+        method: str,
+        pattern: str | None,
+        lowercase: bool,
+        stop_words: Sequence[str],
+    ) -> PyExpr: ...
+    def str_url_part(self, part: UrlPart) -> PyExpr: ...
+    def str_url_query_params(self, keys: Sequence[str] | None = None) -> PyExpr: ...
+    def str_url_decode_percent(self) -> PyExpr: ...
     def str_pad_start(self, length: PyExpr, fill_char: str) -> PyExpr: ...
     def str_pad_end(self, length: PyExpr, fill_char: str) -> PyExpr: ...
     def str_zfill(self, length: PyExpr) -> PyExpr: ...
@@ -1890,8 +2092,10 @@ class PyExpr:
     def str_starts_with(self, sub: PyExpr) -> PyExpr: ...
     def str_hex_encode(self) -> PyExpr: ...
     def str_hex_decode(self, strict: bool) -> PyExpr: ...
-    def str_base64_encode(self) -> PyExpr: ...
-    def str_base64_decode(self, strict: bool) -> PyExpr: ...
+    def str_base64_encode(self, alphabet: Base64Alphabet, padding: bool) -> PyExpr: ...
+    def str_base64_decode(self, strict: bool, alphabet: Base64Alphabet) -> PyExpr: ...
+    def str_parse_ip(self, strict: bool) -> PyExpr: ...
+    def str_parse_uuid(self, strict: bool) -> PyExpr: ...
     def str_to_integer(
         self, base: PyExpr, dtype: Any | None = None, strict: bool = True
     ) -> PyExpr: ...
@@ -1899,6 +2103,12 @@ class PyExpr:
         self, dtype: PyDataTypeExpr | None = None, infer_schema_len: int | None = None
     ) -> PyExpr: ...
     def str_json_path_match(self, pat: PyExpr) -> PyExpr: ...
+    def str_json_path_match_all(
+        self, pat: PyExpr, dtype: PyDataTypeExpr | None
+    ) -> PyExpr: ...
+    def str_json_extract_many(
+        self, paths: Sequence[str], dtype: PyDataTypeExpr | None
+    ) -> PyExpr: ...
     def str_extract(self, pat: PyExpr, group_index: int) -> PyExpr: ...
     def str_extract_all(self, pat: PyExpr) -> PyExpr: ...
     def str_extract_groups(self, pat: str) -> PyExpr: ...
@@ -1910,6 +2120,9 @@ class PyExpr:
     def str_splitn(self, by: PyExpr, n: int) -> PyExpr: ...
     def str_split_regex(self, by: PyExpr, strict: bool) -> PyExpr: ...
     def str_split_regex_inclusive(self, by: PyExpr, strict: bool) -> PyExpr: ...
+    def str_splitn_regex(
+        self, by: PyExpr, n: int, inclusive: bool, strict: bool
+    ) -> PyExpr: ...
     def str_to_decimal(self, scale: int) -> PyExpr: ...
     def str_contains_any(
         self,
@@ -1953,6 +2166,10 @@ class PyExpr:
     def ext_to(self, dtype: PyDataTypeExpr) -> PyExpr: ...
     def ext_storage(self) -> PyExpr: ...
 
+    # ip
+    def ip_is_in_subnet(self, subnets: Sequence[str]) -> PyExpr: ...
+    def ip_to_int(self) -> PyExpr: ...
+
 class PyDataTypeExpr:
     def __init__(self, inner: Any) -> None: ...
     @staticmethod
@@ -2049,6 +2266,18 @@ class PySelector:
     def all() -> PySelector: ...
     def hash(self) -> int: ...
 
+class QueryPlanNode:
+    @property
+    def kind(self) -> str: ...
+    @property
+    def schema(self) -> dict[str, Any]: ...
+    @property
+    def options(self) -> dict[str, Any]: ...
+    @property
+    def expressions(self) -> list[str]: ...
+    @property
+    def children(self) -> list[QueryPlanNode]: ...
+
 class PyOptFlags:
     def __init__(self) -> None: ...
     @staticmethod
@@ -2125,6 +2354,13 @@ def arg_sort_by(
     maintain_order: bool,
 ) -> PyExpr: ...
 def arg_where(condition: PyExpr) -> PyExpr: ...
+def search_sorted_by(
+    by: Sequence[PyExpr],
+    search_values: Sequence[PyExpr],
+    side: Any,
+    descending: Sequence[bool],
+    nulls_last: Sequence[bool],
+) -> PyExpr: ...
 def as_struct(exprs: Sequence[PyExpr]) -> PyExpr: ...
 def field(names: Sequence[str]) -> PyExpr: ...
 def coalesce(exprs: Sequence[PyExpr]) -> PyExpr: ...
@@ -2210,6 +2446,15 @@ def map_expr(
     is_elementwise: bool,
     returns_scalar: bool,
 ) -> PyExpr: ...
+def python_aggregation(
+    pyexpr: PyExpr,
+    init: Any,
+    update: Any,
+    merge: Any,
+    finalize: Any,
+    return_dtype: Any,
+    name: str,
+) -> PyExpr: ...
 def pearson_corr(a: PyExpr, b: PyExpr) -> PyExpr: ...
 def reduce(
     lambda_func: Any,
@@ -2254,6 +2499,8 @@ def write_clipboard_string(s: str) -> None: ...
 # functions.meta
 def get_index_type() -> Any: ...
 def thread_pool_size() -> int: ...
+def row_encoding_version() -> int: ...
+def stable_hash_version() -> int: ...
 def set_float_fmt(fmt: FloatFmt) -> None: ...
 def get_float_fmt() -> str: ...
 def set_float_precision(precision: int | None) -> None: ...
@@ -2281,9 +2528,16 @@ def register_plugin_function(
 ) -> PyExpr: ...
 def __register_startup_deps() -> None: ...
 def gen_uuid_v7() -> bytes: ...
+def register_udf_serializer(
+    dumps: Callable[[Any], bytes] | None, loads: Callable[[bytes], Any] | None
+) -> None: ...
+def set_streaming_metrics_callback(
+    callback: Callable[[int, list[dict[str, Any]]], None] | None,
+) -> None: ...
 
 # functions.random
 def set_random_seed(seed: int) -> None: ...
+def uuid4(seed: int | None) -> PyExpr: ...
 
 # functions.range
 def int_range(
@@ -2335,7 +2589,8 @@ def linear_spaces(
 ) -> PyExpr: ...
 
 # functions.string_cache
-class PyStringCacheHolder: ...
+class PyStringCacheHolder:
+    def __init__(self, categories: PyCategories | None = None) -> None: ...
 
 def enable_string_cache() -> None: ...
 def disable_string_cache() -> None: ...
@@ -2389,6 +2644,8 @@ class PyCategories:
     @staticmethod
     def global_categories() -> PyCategories: ...
     @staticmethod
+    def scoped_or_global() -> PyCategories: ...
+    @staticmethod
     def random(namespace: str, physical: str) -> PyCategories: ...
     def __eq__(self, other: PyCategories) -> bool: ...  # type: ignore[override]
     def __hash__(self) -> int: ...
@@ -2462,8 +2719,21 @@ class PyCatalogClient:
 # sql
 class PySQLContext:
     @staticmethod
-    def new() -> PySQLContext: ...
+    def new(dialect: str | None = None) -> PySQLContext: ...
     def execute(self, query: str) -> PyLazyFrame: ...
+    def execute_with_params(
+        self,
+        query: str,
+        positional: Sequence[Any],
+        named: Sequence[tuple[str, Any]],
+    ) -> PyLazyFrame: ...
+    def register_function(
+        self,
+        name: str,
+        function: Callable[[list[PyExpr]], PyExpr],
+        arg_types: Sequence[DataType | None] | None,
+    ) -> None: ...
+    def unregister_function(self, name: str) -> None: ...
     def get_tables(self) -> list[str]: ...
     def register(self, name: str, lf: PyLazyFrame) -> None: ...
     def unregister(self, name: str) -> None: ...
@@ -2551,3 +2821,15 @@ class PyCollectBatches:
 
     # Export
     def __arrow_c_stream__(self, requested_schema: object | None = None) -> object: ...
+
+class PyArrowBatchStream:
+    # Export
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object: ...
+
+class PySeriesIter:
+    def __iter__(self) -> PySeriesIter: ...
+    def __next__(self) -> Any: ...
+
+class PyRowIter:
+    def __iter__(self) -> PyRowIter: ...
+    def __next__(self) -> tuple[Any, ...]: ...
@@ -105,6 +105,10 @@ CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CumAggFunction: TypeAlias = Literal["sum", "prod", "min", "max", "count"]
+CheckedArithmeticOp: TypeAlias = Literal["add", "sub", "mul"]
+DecimalArithmeticOp: TypeAlias = Literal["add", "sub", "mul", "div"]
+DecimalOverflow: TypeAlias = Literal["raise", "null"]
 ColumnMapping: TypeAlias = tuple[
     Literal["iceberg-column-mapping"],
     # This is "pa.Schema". Not typed as that causes pyright strict type checking
@@ -129,6 +133,7 @@ Label: TypeAlias = Literal["left", "right", "datapoint"]
 MaintainOrderJoin: TypeAlias = Literal[
     "none", "left", "right", "left_right", "right_left"
 ]
+ModeTies: TypeAlias = Literal["all", "first", "smallest"]
 NdjsonCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 NonExistent: TypeAlias = Literal["raise", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
@@ -144,10 +149,13 @@ PivotAgg: TypeAlias = Literal[
 QuantileMethod: TypeAlias = Literal[
     "nearest", "higher", "lower", "midpoint", "linear", "equiprobable"
 ]
-RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
+RankMethod: TypeAlias = Literal[
+    "average", "min", "max", "dense", "ordinal", "random", "percent"
+]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
 RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero", "to_zero"]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
+HashDigestOutput: TypeAlias = Literal["hex", "binary"]
 Endianness: TypeAlias = Literal["little", "big"]
 SizeUnit: TypeAlias = Literal[
     "b",
@@ -174,15 +182,21 @@ StartBy: TypeAlias = Literal[
 ]
 SyncOnCloseMethod: TypeAlias = Literal["data", "all"]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
+TokenizeMethod: TypeAlias = Literal["unicode_words", "whitespace", "pattern"]
 UnicodeForm: TypeAlias = Literal["NFC", "NFKC", "NFD", "NFKD"]
 UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
+UnnestCollision: TypeAlias = Literal["raise", "overwrite", "keep"]
 UnstackDirection: TypeAlias = Literal["vertical", "horizontal"]
+WeekConvention: TypeAlias = Literal["iso", "us"]
 MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 
 # The following have a Rust enum equivalent with a different name
 AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
 InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
+InterpolateByMethod: TypeAlias = Literal[
+    "linear", "nearest", "forward", "backward", "cubic_spline"
+]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "right", "full", "semi", "anti", "cross", "outer"
 ]  # JoinType
@@ -210,7 +224,7 @@ JaxExportType: TypeAlias = Literal["array", "dict"]
 Orientation: TypeAlias = Literal["col", "row"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 TorchExportType: TypeAlias = Literal["tensor", "dataset", "dict"]
-TransferEncoding: TypeAlias = Literal["hex", "base64"]
+TransferEncoding: TypeAlias = Literal["hex", "base64", "base64url"]
 WindowMappingStrategy: TypeAlias = Literal["group_to_rows", "join", "explode"]
 ExplainFormat: TypeAlias = Literal["plain", "tree"]
 
@@ -261,6 +275,17 @@ BufferInfo: TypeAlias = tuple[int, int, int]
 ExcelSpreadsheetEngine: TypeAlias = Literal["calamine", "openpyxl", "xlsx2csv"]
 
 
+class ArrowCoercion(TypedDict, total=False):
+    """How the types of imported Arrow data are coerced."""
+
+    # Import dictionary-encoded data as `Categorical` or as its value type.
+    dictionary: Literal["categorical", "materialize"]
+    # Cast string and binary data to the large or the view layout.
+    string: Literal["large_string", "string_view"]
+    # Cast `Datetime` and `Duration` data to this time unit (`None` keeps it).
+    time_unit: TimeUnit | None
+
+
 class SeriesBuffers(TypedDict):
     """Underlying buffers of a Series."""
 
@@ -354,12 +379,14 @@ __all__ = [
     "Alignment",
     "Ambiguous",
     "ArrowArrayExportable",
+    "ArrowCoercion",
     "ArrowStreamExportable",
     "AsofJoinStrategy",
     "AvroCompression",
     "BooleanMask",
     "BufferInfo",
     "CategoricalOrdering",
+    "CheckedArithmeticOp",
     "ClosedInterval",
     "ColumnFormatDict",
     "ColumnNameOrSelector",
@@ -372,10 +399,13 @@ __all__ = [
     "CorrelationMethod",
     "CsvEncoding",
     "CsvQuoteStyle",
+    "CumAggFunction",
     "Cursor",
     "DbReadEngine",
     "DbWriteEngine",
     "DbWriteMode",
+    "DecimalArithmeticOp",
+    "DecimalOverflow",
     "DeprecationType",
     "Endianness",
     "EngineType",
@@ -387,7 +417,9 @@ __all__ = [
     "FloatFmt",
     "FrameInitTypes",
     "FrameType",
+    "HashDigestOutput",
     "IndexOrder",
+    "InterpolateByMethod",
     "InterpolationMethod",
     "IntoExpr",
     "IntoExprColumn",
@@ -400,6 +432,7 @@ __all__ = [
     "ListToStructWidthStrategy",
     "MaintainOrderJoin",
     "MapElementsStrategy",
+    "ModeTies",
     "MultiColSelector",
     "MultiIndexSelector",
     "MultiNameSelector",
@@ -438,11 +471,14 @@ __all__ = [
     "SyncOnCloseMethod",
     "TemporalLiteral",
     "TimeUnit",
+    "TokenizeMethod",
     "TorchExportType",
     "TransferEncoding",
     "UnicodeForm",
     "UniqueKeepStrategy",
+    "UnnestCollision",
     "UnstackDirection",
+    "WeekConvention",
     "WindowMappingStrategy",
 ]
 
@@ -10,8 +10,10 @@ with contextlib.suppress(ImportError):
     from polars._plr import PySeries
 
 if TYPE_CHECKING:
-    from polars import DataFrame
-    from polars._typing import SchemaDefinition, SchemaDict
+    from collections.abc import Mapping
+
+    from polars import DataFrame, Series
+    from polars._typing import ArrowCoercion, SchemaDefinition, SchemaDict
 
 
 def is_pycapsule(obj: Any) -> bool:
@@ -22,26 +24,55 @@ def is_pycapsule(obj: Any) -> bool:
     )
 
 
+def _merge_coerce_overrides(
+    coerce: ArrowCoercion | None,
+    coerce_overrides: Mapping[str, ArrowCoercion] | None,
+) -> dict[str, ArrowCoercion] | None:
+    # per-column overrides only replace the options they set
+    if not coerce_overrides:
+        return None
+    return {name: {**(coerce or {}), **opts} for name, opts in coerce_overrides.items()}
+
+
+def pycapsule_to_series(
+    obj: Any,
+    *,
+    coerce: ArrowCoercion | None = None,
+    coerce_overrides: Mapping[str, ArrowCoercion] | None = None,
+) -> Series:
+    """Convert PyCapsule object to Series."""
+    overrides = _merge_coerce_overrides(coerce, coerce_overrides)
+    if hasattr(obj, "__arrow_c_array__"):
+        return wrap_s(PySeries.from_arrow_c_array(obj, coerce, overrides))
+    elif hasattr(obj, "__arrow_c_stream__"):
+        return wrap_s(PySeries.from_arrow_c_stream(obj, coerce, overrides))
+    msg = f"object does not support PyCapsule interface; found {obj!r} "
+    raise TypeError(msg)
+
+
 def pycapsule_to_frame(
     obj: Any,
     *,
     schema: SchemaDefinition | None = None,
     schema_overrides: SchemaDict | None = None,
     rechunk: bool = False,
+    coerce: ArrowCoercion | None = None,
+    coerce_overrides: Mapping[str, ArrowCoercion] | None = None,
 ) -> DataFrame:
     """Convert PyCapsule object to DataFrame."""
+    overrides = _merge_coerce_overrides(coerce, coerce_overrides)
     if hasattr(obj, "__arrow_c_array__"):
         # This uses the fact that PySeries.from_arrow_c_array will create a
         # struct-typed Series. Then we unpack that to a DataFrame.
         tmp_col_name = ""
-        s = wrap_s(PySeries.from_arrow_c_array(obj))
+        s = wrap_s(PySeries.from_arrow_c_array(obj, coerce, overrides))
         df = s.to_frame(tmp_col_name).unnest(tmp_col_name)
 
     elif hasattr(obj, "__arrow_c_stream__"):
         # This uses the fact that PySeries.from_arrow_c_stream will create a
         # struct-typed Series. Then we unpack that to a DataFrame.
         tmp_col_name = ""
-        s = wrap_s(PySeries.from_arrow_c_stream(obj))
+        s = wrap_s(PySeries.from_arrow_c_stream(obj, coerce, overrides))
         df = s.to_frame(tmp_col_name).unnest(tmp_col_name)
     else:
         msg = f"object does not support PyCapsule interface; found {obj!r} "
@@ -1,12 +1,16 @@
 from __future__ import annotations
 
+import contextlib
 from inspect import isfunction
-from typing import TYPE_CHECKING, Generic, TypeVar
+from typing import TYPE_CHECKING, Any, Generic, TypeVar
 from warnings import warn
 
 import polars._reexport as pl
 from polars._utils.various import find_stacklevel
 
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
 if TYPE_CHECKING:
     from collections.abc import Callable
 
@@ -18,6 +22,7 @@ __all__ = [
     "register_expr_namespace",
     "register_lazyframe_namespace",
     "register_series_namespace",
+    "register_udf_serializer",
 ]
 
 # do not allow override of polars' own namespaces (as registered by '_accessors')
@@ -374,3 +379,50 @@ def register_series_namespace(name: str) -> Callable[[type[NS]], type[NS]]:
     ]
     """
     return _create_namespace(name, pl.Series)
+
+
+def register_udf_serializer(
+    dumps: Callable[[Any], bytes] | None,
+    loads: Callable[[bytes], Any] | None,
+) -> None:
+    """
+    Register the serializer used for Python UDFs in serialized queries.
+
+    By default, Python objects such as the functions passed to `map_elements` or
+    `map_batches` are serialized with `pickle`, falling back to `cloudpickle`. A
+    registered serializer takes precedence, which allows e.g. distributed schedulers
+    to ship LazyFrames and expressions containing UDFs with their own serializer.
+
+    The same serializer must be registered in the process that deserializes the
+    query. Pass `None` for both arguments to restore the default behavior.
+
+    Parameters
+    ----------
+    dumps
+        Function converting a Python object to bytes.
+    loads
+        Function converting the bytes returned by `dumps` back to a Python object.
+
+    Examples
+    --------
+    >>> import pickle
+    >>> pl.api.register_udf_serializer(pickle.dumps, pickle.loads)
+    >>> lf = pl.LazyFrame({"a": [1, 2]}).select(
+    ...     pl.col("a").map_batches(abs, return_dtype=pl.Int64)
+    ... )
+    >>> pickle.loads(pickle.dumps(lf)).collect()
+    shape: (2, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 1   │
+    │ 2   │
+    └─────┘
+    >>> pl.api.register_udf_serializer(None, None)
+    """
+    if (dumps is None) != (loads is None):
+        msg = "`dumps` and `loads` must either both be set or both be None"
+        raise ValueError(msg)
+    plr.register_udf_serializer(dumps, loads)
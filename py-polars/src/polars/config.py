@@ -73,8 +73,10 @@ _POLARS_CFG_ENV_VARS: Final[set[str]] = {
     "POLARS_FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION",
     "POLARS_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "POLARS_FMT_TABLE_ROUNDED_CORNERS",
+    "POLARS_LOSSY_CASTS",
     "POLARS_STREAMING_CHUNK_SIZE",
     "POLARS_TABLE_WIDTH",
+    "POLARS_TZDB",
     "POLARS_VERBOSE",
     "POLARS_MAX_EXPR_DEPTH",
     "POLARS_ENGINE_AFFINITY",
@@ -106,6 +108,7 @@ class ConfigParameters(TypedDict, total=False):
     fmt_float: FloatFmt | None
     fmt_str_lengths: int | None
     fmt_table_cell_list_len: int | None
+    lossy_casts: Literal["ignore", "warn", "raise"] | None
     streaming_chunk_size: int | None
     tbl_cell_alignment: Alignment | None
     tbl_cell_numeric_alignment: Alignment | None
@@ -120,6 +123,7 @@ class ConfigParameters(TypedDict, total=False):
     tbl_rows: int | None
     tbl_width_chars: int | None
     trim_decimal_zeros: bool | None
+    tzdb: str | Path | None
     verbose: bool | None
     expr_depth_warning: int
 
@@ -131,6 +135,7 @@ class ConfigParameters(TypedDict, total=False):
     set_fmt_float: FloatFmt | None
     set_fmt_str_lengths: int | None
     set_fmt_table_cell_list_len: int | None
+    set_lossy_casts: Literal["ignore", "warn", "raise"] | None
     set_streaming_chunk_size: int | None
     set_tbl_cell_alignment: Alignment | None
     set_tbl_cell_numeric_alignment: Alignment | None
@@ -145,6 +150,7 @@ class ConfigParameters(TypedDict, total=False):
     set_tbl_rows: int | None
     set_tbl_width_chars: int | None
     set_trim_decimal_zeros: bool | None
+    set_tzdb: str | Path | None
     set_verbose: bool | None
     set_expr_depth_warning: int
     set_engine_affinity: EngineType | None
@@ -887,6 +893,43 @@ class Config(contextlib.ContextDecorator):
         plr.config_reload_env_var("POLARS_FMT_TABLE_CELL_LIST_LEN")
         return cls
 
+    @classmethod
+    def set_lossy_casts(
+        cls, mode: Literal["ignore", "warn", "raise"] | None = "warn"
+    ) -> type[Config]:
+        """
+        Set how implicit casts that may lose information are reported.
+
+        Polars implicitly casts columns to a common supertype, for instance when
+        adding an `Int64` and a `Float32` column. Some of those casts can't
+        represent every value of the original type exactly.
+
+        Parameters
+        ----------
+        mode : {'ignore', 'warn', 'raise'}
+            * "ignore": don't report lossy implicit casts (default).
+            * "warn": issue a :class:`LossyCastWarning`.
+            * "raise": raise an :class:`InvalidOperationError`.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {"a": [1, 2]}, schema={"a": pl.Int64}
+        ... ).with_columns(b=pl.lit(0.5, dtype=pl.Float32))
+        >>> with pl.Config(lossy_casts="warn"):  # doctest: +SKIP
+        ...     lf.select(pl.col("a") + pl.col("b")).collect()
+        LossyCastWarning: implicit cast from i64 to f32 may lose information: integers above 2^24 can't be represented exactly
+        """  # noqa: W505
+        if mode is None:
+            os.environ.pop("POLARS_LOSSY_CASTS", None)
+        elif mode not in ("ignore", "warn", "raise"):
+            msg = f"invalid `lossy_casts` mode: {mode!r}"
+            raise ValueError(msg)
+        else:
+            os.environ["POLARS_LOSSY_CASTS"] = mode
+        plr.config_reload_env_var("POLARS_LOSSY_CASTS")
+        return cls
+
     @classmethod
     def set_streaming_chunk_size(cls, size: int | None) -> type[Config]:
         """
@@ -1437,6 +1480,48 @@ class Config(contextlib.ContextDecorator):
         plr.set_trim_decimal_zeros(active)
         return cls
 
+    @classmethod
+    def set_tzdb(cls, source: str | Path | None = "bundled") -> type[Config]:
+        """
+        Set where the IANA time zone database is read from.
+
+        This affects every operation that resolves a time zone name, such as
+        `dt.convert_time_zone` and `dt.replace_time_zone`. Time zones read from a
+        directory are loaded once and kept for the rest of the session.
+
+        Parameters
+        ----------
+        source : {'bundled', 'system'} or path
+            * "bundled": the database compiled into Polars (default).
+            * "system": the database of the operating system, found through the
+              `TZDIR` environment variable or the usual locations such as
+              `/usr/share/zoneinfo`.
+            * A path to a directory of compiled TZif files, laid out like
+              `/usr/share/zoneinfo`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> s = pl.Series([datetime(2024, 7, 1)]).dt.replace_time_zone("UTC")
+        >>> with pl.Config(tzdb="system"):  # doctest: +SKIP
+        ...     s.dt.convert_time_zone("Europe/Amsterdam")
+        shape: (1,)
+        Series: '' [datetime[μs, Europe/Amsterdam]]
+        [
+            2024-07-01 02:00:00 CEST
+        ]
+        """
+        if source is None:
+            os.environ.pop("POLARS_TZDB", None)
+        elif source in ("bundled", "system"):
+            os.environ["POLARS_TZDB"] = str(source)
+        else:
+            os.environ["POLARS_TZDB"] = normalize_filepath(
+                source, check_not_directory=False
+            )
+        plr.config_reload_env_var("POLARS_TZDB")
+        return cls
+
     @classmethod
     def set_verbose(cls, active: bool | None = True) -> type[Config]:
         """
@@ -25,7 +25,11 @@ from polars._utils.deprecation import (
     deprecate_renamed_parameter,
     issue_deprecation_warning,
 )
-from polars._utils.pycapsule import is_pycapsule, pycapsule_to_frame
+from polars._utils.pycapsule import (
+    is_pycapsule,
+    pycapsule_to_frame,
+    pycapsule_to_series,
+)
 from polars._utils.various import (
     _cast_repr_strings_with_schema,
     issue_warning,
@@ -43,6 +47,7 @@ if TYPE_CHECKING:
     from polars._dependencies import torch
     from polars._typing import (
         ArrowArrayExportable,
+        ArrowCoercion,
         ArrowStreamExportable,
         Orientation,
         PolarsDataType,
@@ -471,6 +476,8 @@ def from_arrow(
     *,
     schema_overrides: SchemaDict | None = None,
     rechunk: bool = True,
+    coerce: ArrowCoercion | None = None,
+    coerce_overrides: Mapping[str, ArrowCoercion] | None = None,
 ) -> DataFrame | Series:
     """
     Create a DataFrame or Series from an Arrow Table or Array.
@@ -501,6 +508,23 @@ def from_arrow(
         any dtypes inferred from the schema param will be overridden.
     rechunk : bool, default True
         Make sure that all data is in contiguous memory.
+    coerce : dict, default None
+        How to coerce the incoming Arrow types, with the following (optional) keys:
+
+        * `"dictionary"`: import dictionary-encoded data as `"categorical"`
+          (default), or `"materialize"` it to its value type.
+        * `"string"`: cast string and binary data to the `"large_string"` or the
+          `"string_view"` layout before importing it; by default the incoming
+          layout is kept. Casting string views to `"large_string"` copies them
+          into contiguous buffers, so the imported data no longer references the
+          (possibly larger) buffers of the producer.
+        * `"time_unit"`: cast `Datetime` and `Duration` data to this time unit;
+          `None` (default) keeps the incoming time unit.
+
+        Setting this imports PyArrow data through the Arrow PyCapsule interface.
+    coerce_overrides : dict, default None
+        Per-column coercion options, mapping column names to options as described
+        for `coerce` that override the ones set there.
 
     Returns
     -------
@@ -537,12 +561,20 @@ def from_arrow(
         3
     ]
     """  # noqa: W505
-    if is_pycapsule(data) and not _check_for_pyarrow(data):
+    if is_pycapsule(data) and (
+        not _check_for_pyarrow(data)
+        or (
+            (coerce is not None or coerce_overrides)
+            and isinstance(data, (pa.Table, pa.RecordBatch))
+        )
+    ):
         return pycapsule_to_frame(
             data,
             schema=schema,
             schema_overrides=schema_overrides,
             rechunk=rechunk,
+            coerce=coerce,
+            coerce_overrides=coerce_overrides,
         )
 
     elif isinstance(data, (pa.Table, pa.RecordBatch)):
@@ -556,7 +588,14 @@ def from_arrow(
         )
     elif isinstance(data, (pa.Array, pa.ChunkedArray)):
         name = getattr(data, "_name", "") or ""
-        s = wrap_s(arrow_to_pyseries(name, data, rechunk=rechunk))
+        if coerce is not None or coerce_overrides:
+            s = pycapsule_to_series(
+                data, coerce=coerce, coerce_overrides=coerce_overrides
+            ).alias(name)
+            if rechunk:
+                s = s.rechunk()
+        else:
+            s = wrap_s(arrow_to_pyseries(name, data, rechunk=rechunk))
         s = pl.DataFrame(
             data=s,
             schema=schema,
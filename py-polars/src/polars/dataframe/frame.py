@@ -71,6 +71,7 @@ from polars._utils.serde import serialize_polars_object
 from polars._utils.unstable import issue_unstable_warning, unstable
 from polars._utils.various import (
     _in_notebook,
+    extend_bool,
     is_bool_sequence,
     no_default,
     normalize_filepath,
@@ -100,6 +101,7 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    parse_into_dtype,
 )
 from polars.datatypes.group import INTEGER_DTYPES
 from polars.exceptions import (
@@ -181,6 +183,7 @@ if TYPE_CHECKING:
         RowTotalsDefinition,
         SchemaDefinition,
         SchemaDict,
+        SearchSortedSide,
         SelectorType,
         SerializationFormat,
         SingleColSelector,
@@ -1614,6 +1617,32 @@ class DataFrame:
         """
         return self._df.__arrow_c_stream__(requested_schema)
 
+    def __dlpack__(
+        self,
+        *,
+        stream: int | None = None,
+        max_version: tuple[int, int] | None = None,
+        dl_device: tuple[int, int] | None = None,
+        copy: bool | None = None,
+    ) -> object:
+        """
+        Export a numeric DataFrame as a two-dimensional tensor via the DLPack protocol.
+
+        All columns must have the same numeric data type and contain no null values.
+        The data is shared without copying if the columns are contiguous in memory.
+        Consumers that request DLPack 1.0 or later receive a versioned capsule, which
+        marks shared data as read-only.
+
+        https://dmlc.github.io/dlpack/latest/python_spec.html
+        """
+        return self._df.__dlpack__(
+            stream=stream, max_version=max_version, dl_device=dl_device, copy=copy
+        )
+
+    def __dlpack_device__(self) -> tuple[int, int]:
+        """Return the device of the DataFrame data for the DLPack protocol (the CPU)."""
+        return self._df.__dlpack_device__()
+
     def _repr_html_(self, *, _from_series: bool = False) -> str:
         """
         Format output data in HTML for display in Jupyter Notebooks.
@@ -1921,6 +1950,7 @@ class DataFrame:
         order: IndexOrder = "fortran",
         writable: bool = False,
         allow_copy: bool = True,
+        masked: bool = False,
         structured: bool = False,
         use_pyarrow: bool | None = None,
     ) -> np.ndarray[Any, Any]:
@@ -1952,6 +1982,13 @@ class DataFrame:
         allow_copy
             Allow memory to be copied to perform the conversion. If set to `False`,
             causes conversions that are not zero-copy to fail.
+        masked
+            Return a `masked array`_ that masks the null values. Numeric data keeps
+            its data type instead of being cast to a float type with `nan` representing
+            a null value, and is not copied if it would otherwise be zero copy. The mask
+            is always materialized.
+
+            .. _masked array: https://numpy.org/doc/stable/reference/maskedarray.html
         structured
             Return a `structured array`_ with a data type that corresponds to the
             DataFrame schema. If set to `False` (default), a 2D ndarray is
@@ -2039,6 +2076,14 @@ class DataFrame:
                 version="0.20.28",
             )
 
+        if masked:
+            if structured:
+                msg = "cannot create a structured array that is masked"
+                raise ValueError(msg)
+            return self._df.to_numpy_masked(
+                order, writable=writable, allow_copy=allow_copy
+            )
+
         if structured:
             if not allow_copy and not self.is_empty():
                 msg = "copy not allowed: cannot create structured array without copying data"
@@ -5008,7 +5053,9 @@ class DataFrame:
             )
             return None
 
-    def estimated_size(self, unit: SizeUnit = "b") -> int | float:
+    def estimated_size(
+        self, unit: SizeUnit = "b", *, deep: bool = False
+    ) -> int | float:
         """
         Return an estimation of the total (heap) allocated size of the `DataFrame`.
 
@@ -5027,13 +5074,20 @@ class DataFrame:
 
         Notes
         -----
-        For data with Object dtype, the estimated size only reports the pointer
-        size, which is a huge underestimation.
+        For data with Object dtype, the estimated size includes the size of the
+        Python objects as reported by :func:`sys.getsizeof`. This is a shallow size
+        (objects referenced by those objects are not included), and an object that
+        occurs in multiple rows is counted once per row.
 
         Parameters
         ----------
         unit : {'b', 'kb', 'mb', 'gb', 'tb'}
             Scale the returned size to the given unit.
+        deep
+            Count all the storage referenced by the `DataFrame` instead of the visible
+            size of its buffers. This includes the full data buffers of string and
+            binary views and the size of the categorical mappings. Storage shared
+            between chunks or columns is only counted once.
 
         Examples
         --------
@@ -5050,7 +5104,7 @@ class DataFrame:
         >>> df.estimated_size("mb")
         17.0601749420166
         """
-        sz = self._df.estimated_size()
+        sz = self._df.estimated_size(deep)
         return scale_bytes(sz, unit)
 
     def transpose(
@@ -5059,6 +5113,7 @@ class DataFrame:
         include_header: bool = False,
         header_name: str = "column",
         column_names: str | Iterable[str] | None = None,
+        dtype: PolarsDataType | None = None,
     ) -> DataFrame:
         """
         Transpose a DataFrame over the diagonal.
@@ -5073,6 +5128,9 @@ class DataFrame:
         column_names
             Optional iterable yielding strings or a string naming an existing column.
             These will name the value (non-header) columns in the transposed data.
+        dtype
+            The data type of the transposed (non-header) columns. All columns are
+            strictly cast to it. If not set, the supertype of all columns is used.
 
         Notes
         -----
@@ -5173,7 +5231,10 @@ class DataFrame:
             column_names_ = [next(column_names) for _ in range(self.height)]
         else:
             column_names_ = column_names  # type: ignore[assignment]
-        return self._from_pydf(self._df.transpose(keep_names_as, column_names_))
+        dtype_ = None if dtype is None else parse_into_dtype(dtype)
+        return self._from_pydf(
+            self._df.transpose(keep_names_as, column_names_, dtype_)
+        )
 
     def reverse(self) -> DataFrame:
         """
@@ -6123,6 +6184,73 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
+    def search_sorted(
+        self,
+        values: DataFrame,
+        by: str | Sequence[str] | None = None,
+        *,
+        side: SearchSortedSide = "any",
+        descending: bool | Sequence[bool] = False,
+        nulls_last: bool | Sequence[bool] = False,
+    ) -> Series:
+        """
+        Find the indices where rows should be inserted into this frame to keep order.
+
+        The rows are compared lexicographically on the `by` columns: by the first
+        column, then by the second column for equal values of the first column, and so
+        on. This frame must be sorted by the `by` columns, e.g. with :meth:`sort` using
+        the same `descending` and `nulls_last` arguments.
+
+        Parameters
+        ----------
+        values
+            The rows to search, which must contain the `by` columns.
+        by
+            Name(s) of the sorted columns. Defaults to the columns of `values`.
+        side : {'any', 'left', 'right'}
+            If 'any', the index of the first suitable location found is given.
+            If 'left', the index of the leftmost suitable location found is given.
+            If 'right', return the rightmost suitable location found is given.
+        descending
+            Whether the `by` columns are sorted in descending order. Can be specified
+            per column by passing a sequence of booleans.
+        nulls_last
+            Whether the null values of the `by` columns are placed last. Can be
+            specified per column by passing a sequence of booleans.
+
+        See Also
+        --------
+        polars.search_sorted_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "symbol": ["a", "a", "b", "b"],
+        ...         "ts": [1, 3, 2, 4],
+        ...         "price": [1.0, 2.0, 3.0, 4.0],
+        ...     }
+        ... )
+        >>> df.search_sorted(pl.DataFrame({"symbol": ["a", "b"], "ts": [2, 5]}))
+        shape: (2,)
+        Series: 'symbol' [u32]
+        [
+            1
+            4
+        ]
+        """
+        if by is None:
+            by = values.columns
+        elif isinstance(by, str):
+            by = [by]
+        else:
+            by = list(by)
+        descending = extend_bool(descending, len(by), "descending", "by")
+        nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
+        return wrap_s(
+            self._df.search_sorted(by, values._df, side, descending, nulls_last)
+        )
+
     def sql(self, query: str, *, table_name: str = "self") -> DataFrame:
         """
         Execute a SQL query against the DataFrame.
@@ -7101,6 +7229,7 @@ class DataFrame:
         self,
         *by: IntoExpr | Iterable[IntoExpr],
         maintain_order: bool = False,
+        nulls_equal: bool = True,
         **named_by: IntoExpr,
     ) -> GroupBy:
         """
@@ -7120,6 +7249,9 @@ class DataFrame:
             .. note::
                 Within each group, the order of rows is always preserved, regardless
                 of this argument.
+        nulls_equal
+            Group rows with null keys together. If `False`, every row that has a
+            null in any of its keys forms a group of its own.
         **named_by
             Additional columns to group by, specified as keyword arguments.
             The columns will be renamed to the keyword used.
@@ -7245,7 +7377,12 @@ class DataFrame:
                 )
                 raise TypeError(msg)
         return GroupBy(
-            self, *by, **named_by, maintain_order=maintain_order, predicates=None
+            self,
+            *by,
+            **named_by,
+            maintain_order=maintain_order,
+            predicates=None,
+            nulls_equal=nulls_equal,
         )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -11990,18 +12127,16 @@ class DataFrame:
         """
         # load into the local namespace for a (minor) performance boost in the hot loops
         columns, get_row, dict_, zip_ = self.columns, self.row, dict, zip
-        has_object = Object in self.dtypes
 
         # note: buffering rows results in a 2-4x speedup over individual calls
         # to ".row(i)", so it should only be disabled in extremely specific cases.
-        if buffer_size and not has_object:
-            for offset in range(0, self.height, buffer_size):
-                zerocopy_slice = self.slice(offset, buffer_size)
-                if named:
-                    for row in zerocopy_slice.rows(named=False):
-                        yield dict_(zip_(columns, row))
-                else:
-                    yield from zerocopy_slice.rows(named=False)
+        if buffer_size:
+            rows = self._df.iter_rows(buffer_size)
+            if named:
+                for row in rows:
+                    yield dict_(zip_(columns, row))
+            else:
+                yield from rows
         elif named:
             for i in range(self.height):
                 yield dict_(zip_(columns, get_row(i)))
@@ -12171,6 +12306,8 @@ class DataFrame:
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        stable: bool = False,
     ) -> Series:
         """
         Hash and combine the rows in this DataFrame.
@@ -12187,12 +12324,21 @@ class DataFrame:
             Random seed parameter. Defaults to `seed` if not set.
         seed_3
             Random seed parameter. Defaults to `seed` if not set.
+        stable
+            Use a hash algorithm that gives the same results across Polars versions
+            and platforms, for hashes that are persisted, such as partition or
+            deduplication keys. Only `seed` is used.
 
         Notes
         -----
-        This implementation of `hash_rows` does not guarantee stable results
-        across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        Unless `stable` is set, this implementation of `hash_rows` does not guarantee
+        stable results across different Polars versions. Its stability is only
+        guaranteed within a single version.
+
+        With `stable=True`, every value is hashed as with :meth:`Expr.hash`, after
+        which the little-endian bytes of the hashes of a row are concatenated in column
+        order and hashed again with 64-bit XXH3 seeded with `seed`. The hashes only
+        change together with the :func:`stable_hash_version`.
 
         Examples
         --------
@@ -12212,12 +12358,68 @@ class DataFrame:
             2047317070637311557
         ]
         """
+        if stable:
+            if seed_1 is not None or seed_2 is not None or seed_3 is not None:
+                msg = "`seed_1`, `seed_2` and `seed_3` cannot be set when `stable=True`"
+                raise ValueError(msg)
+            return wrap_s(self._df.hash_rows_stable(seed))
         k0 = seed
         k1 = seed_1 if seed_1 is not None else seed
         k2 = seed_2 if seed_2 is not None else seed
         k3 = seed_3 if seed_3 is not None else seed
         return wrap_s(self._df.hash_rows(k0, k1, k2, k3))
 
+    def row_encode(
+        self,
+        *,
+        descending: bool | Sequence[bool] = False,
+        nulls_last: bool | Sequence[bool] = False,
+    ) -> Series:
+        """
+        Encode the rows of this DataFrame to order-preserving binary keys.
+
+        Comparing the keys bytewise gives the same order as sorting the DataFrame by
+        all of its columns with the given `descending` and `nulls_last`. The keys can
+        be decoded back with :meth:`Series.row_decode`.
+
+        Parameters
+        ----------
+        descending
+            Encode in descending order. Specify a list of booleans to set the order
+            per column.
+        nulls_last
+            Place null values last. Specify a list of booleans to set the null
+            placement per column.
+
+        Notes
+        -----
+        Unlike :meth:`hash_rows`, the encoding is stable across Polars versions: keys
+        created by one version decode to the same values and compare the same in any
+        other version with the same :func:`row_encoding_version`. This makes the keys
+        suitable to persist, e.g. in an external key-value store. Select the columns to
+        encode first, the keys only depend on the values and data types of the columns,
+        not on their names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [2, None, 1], "b": ["x", "y", "z"]})
+        >>> keys = df.row_encode(descending=[True, False])
+        >>> df[keys.arg_sort()]
+        shape: (3, 2)
+        ┌──────┬─────┐
+        │ a    ┆ b   │
+        │ ---  ┆ --- │
+        │ i64  ┆ str │
+        ╞══════╪═════╡
+        │ null ┆ y   │
+        │ 2    ┆ x   │
+        │ 1    ┆ z   │
+        └──────┴─────┘
+        """
+        descending = extend_bool(descending, self.width, "descending", "columns")
+        nulls_last = extend_bool(nulls_last, self.width, "nulls_last", "columns")
+        return wrap_s(self._df.row_encode(descending, nulls_last))
+
     def interpolate(self) -> DataFrame:
         """
         Interpolate intermediate values. The interpolation method is linear.
@@ -12297,6 +12499,9 @@ class DataFrame:
         columns: ColumnNameOrSelector | Collection[ColumnNameOrSelector],
         *more_columns: ColumnNameOrSelector,
         separator: str | None = None,
+        fields: str | Sequence[str] | None = None,
+        prefix: str | None = None,
+        on_collision: UnnestCollision = "raise",
     ) -> DataFrame:
         """
         Decompose struct columns into separate columns for each of their fields.
@@ -12313,6 +12518,19 @@ class DataFrame:
         separator
             Rename output column names as combination of the struct column name,
             name separator and field name.
+        fields
+            Only unnest these fields, the other fields of the struct are dropped.
+        prefix
+            Prefix the output column names with this instead of the struct column
+            name. If `separator` is set, it is placed between the prefix and the
+            field name.
+        on_collision : {'raise', 'overwrite', 'keep'}
+            What to do when an unnested field gets the name of a column that is
+            already in the output.
+
+            - 'raise': Raise a `DuplicateError`.
+            - 'overwrite': The field replaces the existing column.
+            - 'keep': The existing column is kept and the field is dropped.
 
         Examples
         --------
@@ -12370,12 +12588,54 @@ class DataFrame:
         │ foo    ┆ 1    ┆ a    ┆ true ┆ [1, 2]    ┆ baz   │
         │ bar    ┆ 2    ┆ b    ┆ null ┆ [3]       ┆ womp  │
         └────────┴──────┴──────┴──────┴───────────┴───────┘
+
+        Only unnest some of the fields and prefix their names:
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": [1, 2],
+        ...         "t": [
+        ...             {"id": 10, "x": "a", "y": 1.5},
+        ...             {"id": 20, "x": "b", "y": 2.5},
+        ...         ],
+        ...     }
+        ... )
+        >>> df.unnest("t", fields=["id", "x"], prefix="t_")
+        shape: (2, 3)
+        ┌─────┬──────┬─────┐
+        │ id  ┆ t_id ┆ t_x │
+        │ --- ┆ ---  ┆ --- │
+        │ i64 ┆ i64  ┆ str │
+        ╞═════╪══════╪═════╡
+        │ 1   ┆ 10   ┆ a   │
+        │ 2   ┆ 20   ┆ b   │
+        └─────┴──────┴─────┘
+
+        Replace existing columns with the fields that have the same name:
+
+        >>> df.unnest("t", on_collision="overwrite")
+        shape: (2, 3)
+        ┌─────┬─────┬─────┐
+        │ id  ┆ x   ┆ y   │
+        │ --- ┆ --- ┆ --- │
+        │ i64 ┆ str ┆ f64 │
+        ╞═════╪═════╪═════╡
+        │ 10  ┆ a   ┆ 1.5 │
+        │ 20  ┆ b   ┆ 2.5 │
+        └─────┴─────┴─────┘
         """
         from polars.lazyframe.opt_flags import QueryOptFlags
 
         return (
             self.lazy()
-            .unnest(columns, *more_columns, separator=separator)
+            .unnest(
+                columns,
+                *more_columns,
+                separator=separator,
+                fields=fields,
+                prefix=prefix,
+                on_collision=on_collision,
+            )
             .collect(optimizations=QueryOptFlags._eager())
         )
 
@@ -44,6 +44,7 @@ class GroupBy:
         *by: IntoExpr | Iterable[IntoExpr],
         maintain_order: bool,
         predicates: Iterable[Any] | None,
+        nulls_equal: bool = True,
         **named_by: IntoExpr,
     ) -> None:
         """
@@ -63,6 +64,9 @@ class GroupBy:
             This is slower than a default group by.
         predicates
             Predicate expressions to filter groups after aggregation.
+        nulls_equal
+            Group rows with null keys together. If `False`, every row with a null
+            key forms a group of its own.
         **named_by
             Additional column(s) to group by, specified as keyword arguments.
             The columns will be named as the keyword used.
@@ -72,10 +76,14 @@ class GroupBy:
         self.named_by = named_by
         self.maintain_order = maintain_order
         self.predicates = predicates
+        self.nulls_equal = nulls_equal
 
     def _lgb(self) -> LazyGroupBy:
         group_by = self.df.lazy().group_by(
-            *self.by, **self.named_by, maintain_order=self.maintain_order
+            *self.by,
+            **self.named_by,
+            maintain_order=self.maintain_order,
+            nulls_equal=self.nulls_equal,
         )
         if self.predicates:
             return group_by.having(self.predicates)
@@ -122,7 +130,12 @@ class GroupBy:
         groups_df = (
             self.df.lazy()
             .with_row_index("__POLARS_GB_ROW_INDEX")
-            .group_by(*self.by, **self.named_by, maintain_order=self.maintain_order)
+            .group_by(
+                *self.by,
+                **self.named_by,
+                maintain_order=self.maintain_order,
+                nulls_equal=self.nulls_equal,
+            )
             .agg(F.first().alias(temp_col))
             .collect(optimizations=QueryOptFlags.none())
         )
@@ -184,6 +197,7 @@ class GroupBy:
             *self.by,
             maintain_order=self.maintain_order,
             predicates=_chain_predicates(self.predicates, predicates),
+            nulls_equal=self.nulls_equal,
             **self.named_by,
         )
 
@@ -365,6 +379,9 @@ class GroupBy:
         if self.named_by:
             msg = "cannot call `map_groups` when grouping by named expressions"
             raise TypeError(msg)
+        if not self.nulls_equal:
+            msg = "cannot call `map_groups` with `nulls_equal=False`"
+            raise TypeError(msg)
         by = list(_parse_inputs_as_iterable(self.by))
         if not all(isinstance(c, str) for c in by):
             msg = "cannot call `map_groups` when grouping by an expression"
@@ -859,7 +859,8 @@ class Categorical(DataType):
         The categories used for this type; must be a :py:class:`Categories`
         instance, or a string which is interpreted as the name of a
         :py:class:`Categories`. If not provided, the global categories
-        (`pl.Categories()`) are used.
+        (`pl.Categories()`) are used, unless a scoped :class:`StringCache` is
+        active, in which case the categories of that scope are used.
 
         For legacy reasons if the string is either `"physical"` or `"lexical"`,
         it is ignored and a warning is issued. If you wish to use a `Categories`
@@ -911,7 +912,9 @@ class Categorical(DataType):
 
         self.ordering = "lexical"
         if categories is None:
-            self.categories = Categories()
+            self.categories = Categories._from_py_categories(
+                PyCategories.scoped_or_global()
+            )
         else:
             self.categories = categories
 
@@ -924,7 +927,7 @@ class Categorical(DataType):
     def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
         # allow comparing object instances to class
         if type(other) is DataTypeClass and issubclass(other, Categorical):
-            return self.categories.is_global()
+            return self.categories._categories == PyCategories.scoped_or_global()
         elif isinstance(other, Categorical):
             return self.categories == other.categories
         else:
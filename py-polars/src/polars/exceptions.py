@@ -5,6 +5,7 @@ try:
         ComputeError,
         DuplicateError,
         InvalidOperationError,
+        LossyCastWarning,
         MapWithoutReturnDtypeWarning,
         NoDataError,
         OutOfBoundsError,
@@ -104,6 +105,9 @@ except ImportError:
     class MapWithoutReturnDtypeWarning(PolarsWarning):  # type: ignore[no-redef]
         """Warning issued when `map_elements` is performed without specifying the return dtype."""  # noqa: W505
 
+    class LossyCastWarning(PolarsWarning):  # type: ignore[no-redef]
+        """Warning issued when an implicit cast may lose information."""
+
 
 class RowsError(PolarsError):
     """Exception raised when the number of returned rows does not match expectation."""
@@ -221,6 +225,7 @@ __all__ = [
     "ChronoFormatWarning",
     "CustomUFuncWarning",
     "DataOrientationWarning",
+    "LossyCastWarning",
     "MapWithoutReturnDtypeWarning",
     "PerformanceWarning",
     "PolarsInefficientMapWarning",
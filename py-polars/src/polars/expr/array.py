@@ -867,6 +867,123 @@ class ExprArrayNameSpace:
         element_pyexpr = parse_into_expression(element, str_as_lit=True)
         return wrap_expr(self._pyexpr.arr_count_matches(element_pyexpr))
 
+    def dot(self, other: IntoExpr) -> Expr:
+        """
+        Compute the dot product of every sub-array with the sub-array in `other`.
+
+        The result is null if either sub-array is null or contains a null.
+
+        Parameters
+        ----------
+        other
+            Array expression of the same width. A list, such as a single vector
+            literal, is converted to an array of the same width.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float32` if all inputs are
+            :class:`Float32`, and :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1.0, 0.0], [3.0, 4.0], [0.0, 0.0]],
+        ...         "b": [[0.0, 1.0], [1.0, 0.0], [1.0, 1.0]],
+        ...     },
+        ...     schema={"a": pl.Array(pl.Float64, 2), "b": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.with_columns(dot=pl.col("a").arr.dot("b"))
+        shape: (3, 3)
+        ┌───────────────┬───────────────┬─────┐
+        │ a             ┆ b             ┆ dot │
+        │ ---           ┆ ---           ┆ --- │
+        │ array[f64, 2] ┆ array[f64, 2] ┆ f64 │
+        ╞═══════════════╪═══════════════╪═════╡
+        │ [1.0, 0.0]    ┆ [0.0, 1.0]    ┆ 0.0 │
+        │ [3.0, 4.0]    ┆ [1.0, 0.0]    ┆ 3.0 │
+        │ [0.0, 0.0]    ┆ [1.0, 1.0]    ┆ 0.0 │
+        └───────────────┴───────────────┴─────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_dot(other_pyexpr))
+
+    def l2_norm(self) -> Expr:
+        """
+        Compute the Euclidean (L2) norm of every sub-array.
+
+        The result is null if the sub-array is null or contains a null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float32` if all inputs are
+            :class:`Float32`, and :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1.0, 0.0], [3.0, 4.0], [0.0, 0.0]]},
+        ...     schema={"a": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.with_columns(norm=pl.col("a").arr.l2_norm())
+        shape: (3, 2)
+        ┌───────────────┬──────┐
+        │ a             ┆ norm │
+        │ ---           ┆ ---  │
+        │ array[f64, 2] ┆ f64  │
+        ╞═══════════════╪══════╡
+        │ [1.0, 0.0]    ┆ 1.0  │
+        │ [3.0, 4.0]    ┆ 5.0  │
+        │ [0.0, 0.0]    ┆ 0.0  │
+        └───────────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.arr_l2_norm())
+
+    def cosine_similarity(self, other: IntoExpr) -> Expr:
+        """
+        Compute the cosine similarity of every sub-array with the sub-array in `other`.
+
+        The result is null if either sub-array is null or contains a null, and NaN
+        if either sub-array only contains zeros.
+
+        Parameters
+        ----------
+        other
+            Array expression of the same width. A list, such as a single vector
+            literal, is converted to an array of the same width.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float32` if all inputs are
+            :class:`Float32`, and :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1.0, 0.0], [3.0, 4.0], [0.0, 0.0]],
+        ...         "b": [[0.0, 1.0], [1.0, 0.0], [1.0, 1.0]],
+        ...     },
+        ...     schema={"a": pl.Array(pl.Float64, 2), "b": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.with_columns(cos=pl.col("a").arr.cosine_similarity("b"))
+        shape: (3, 3)
+        ┌───────────────┬───────────────┬─────┐
+        │ a             ┆ b             ┆ cos │
+        │ ---           ┆ ---           ┆ --- │
+        │ array[f64, 2] ┆ array[f64, 2] ┆ f64 │
+        ╞═══════════════╪═══════════════╪═════╡
+        │ [1.0, 0.0]    ┆ [0.0, 1.0]    ┆ 0.0 │
+        │ [3.0, 4.0]    ┆ [1.0, 0.0]    ┆ 0.6 │
+        │ [0.0, 0.0]    ┆ [1.0, 1.0]    ┆ NaN │
+        └───────────────┴───────────────┴─────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.arr_cosine_similarity(other_pyexpr))
+
     def to_struct(
         self, fields: Sequence[str] | Callable[[int], str] | None = None
     ) -> Expr:
@@ -175,8 +175,10 @@ class ExprBinaryNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            Base64 that uses `-` and `_` instead of `+` and `/`. Base64 values are
+            accepted with or without `=` padding.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -210,20 +212,27 @@ class ExprBinaryNameSpace:
         """
         if encoding == "hex":
             return wrap_expr(self._pyexpr.bin_hex_decode(strict))
-        elif encoding == "base64":
-            return wrap_expr(self._pyexpr.bin_base64_decode(strict))
+        elif encoding in ("base64", "base64url"):
+            return wrap_expr(self._pyexpr.bin_base64_decode(strict, encoding))
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base64url'}, "
+                f"got {encoding!r}"
+            )
             raise ValueError(msg)
 
-    def encode(self, encoding: TransferEncoding) -> Expr:
+    def encode(self, encoding: TransferEncoding, *, padding: bool = True) -> Expr:
         r"""
         Encode a value using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            Base64 that uses `-` and `_` instead of `+` and `/`.
+        padding
+            Pad Base64 encoded values with `=` to a multiple of four characters.
+            Ignored for hex encoding.
 
         Returns
         -------
@@ -254,10 +263,13 @@ class ExprBinaryNameSpace:
         """
         if encoding == "hex":
             return wrap_expr(self._pyexpr.bin_hex_encode())
-        elif encoding == "base64":
-            return wrap_expr(self._pyexpr.bin_base64_encode())
+        elif encoding in ("base64", "base64url"):
+            return wrap_expr(self._pyexpr.bin_base64_encode(encoding, padding))
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base64url'}, "
+                f"got {encoding!r}"
+            )
             raise ValueError(msg)
 
     def size(self, unit: SizeUnit = "b") -> Expr:
@@ -518,3 +530,23 @@ class ExprBinaryNameSpace:
         """
         index_pyexpr = parse_into_expression(index)
         return wrap_expr(self._pyexpr.bin_get(index_pyexpr, null_on_oob))
+
+    def to_uuid_str(self) -> Expr:
+        """
+        Format UUIDs stored as 16 bytes in their canonical hyphenated form.
+
+        This is the inverse of :meth:`Expr.str.parse_uuid`. Values that are not
+        exactly 16 bytes long raise an error.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"id": ["67E55044-10B1-426F-9247-BB680E5FE0C8"]})
+        >>> df.select(pl.col("id").str.parse_uuid().bin.to_uuid_str()).item()
+        '67e55044-10b1-426f-9247-bb680e5fe0c8'
+        """
+        return wrap_expr(self._pyexpr.bin_to_uuid_str())
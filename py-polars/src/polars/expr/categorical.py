@@ -2,11 +2,15 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
+from polars._utils.parse import parse_into_expression
 from polars._utils.various import qualified_type_name
 from polars._utils.wrap import wrap_expr
 
 if TYPE_CHECKING:
+    from collections.abc import Iterable
+
     from polars import Expr
+    from polars._typing import IntoExprColumn
 
 
 class ExprCatNameSpace:
@@ -40,6 +44,71 @@ class ExprCatNameSpace:
         """
         return wrap_expr(self._pyexpr.cat_get_categories())
 
+    def add_categories(self, categories: str | Iterable[str]) -> Expr:
+        """
+        Add categories to the data type without recoding the existing values.
+
+        For an :class:`Enum`, the new categories are appended after the existing
+        categories, skipping those that already exist. The existing values keep
+        their physical representation, so this is much cheaper than casting to a
+        new :class:`Enum`. For a :class:`Categorical`, the categories are inserted
+        into its mapping and the data type stays the same.
+
+        Parameters
+        ----------
+        categories
+            The categories to add.
+
+        See Also
+        --------
+        union_categories
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"size": pl.Series(["S", "M", "S"], dtype=pl.Enum(["S", "M"]))}
+        ... )
+        >>> df.select(pl.col("size").cat.add_categories(["L", "XL"])).schema
+        Schema({'size': Enum(categories=['S', 'M', 'L', 'XL'])})
+        """
+        if isinstance(categories, str):
+            categories = [categories]
+        return wrap_expr(self._pyexpr.cat_add_categories(list(categories)))
+
+    def union_categories(self, other: IntoExprColumn) -> Expr:
+        """
+        Add the categories of another Enum or Categorical without recoding.
+
+        The categories of `other` that do not exist yet are appended after the
+        existing categories, in the order in which they appear in `other`. Only
+        the data type of `other` is used, not its values.
+
+        Parameters
+        ----------
+        other
+            An Enum or Categorical expression whose categories to add. Accepts
+            expression input. Strings are parsed as column names. The categories of
+            a Categorical can only be added to another Categorical, as they are not
+            known until the query is executed.
+
+        See Also
+        --------
+        add_categories
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": pl.Series(["x", "y"], dtype=pl.Enum(["x", "y"])),
+        ...         "b": pl.Series(["z", "x"], dtype=pl.Enum(["z", "x"])),
+        ...     }
+        ... )
+        >>> df.select(pl.col("a").cat.union_categories("b")).schema
+        Schema({'a': Enum(categories=['x', 'y', 'z'])})
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.cat_union_categories(other_pyexpr))
+
     def len_bytes(self) -> Expr:
         """
         Return the byte-length of the string representation of each value.
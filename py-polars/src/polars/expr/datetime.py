@@ -14,6 +14,41 @@ from polars._utils.wrap import wrap_expr
 from polars.datatypes import DTYPE_TEMPORAL_UNITS, Date, Int32, Int64
 from polars.functions.business import _holidays_to_expr
 
+_MONTHS = {
+    name: idx
+    for idx, names in enumerate(
+        [
+            ("jan", "january"),
+            ("feb", "february"),
+            ("mar", "march"),
+            ("apr", "april"),
+            ("may",),
+            ("jun", "june"),
+            ("jul", "july"),
+            ("aug", "august"),
+            ("sep", "september"),
+            ("oct", "october"),
+            ("nov", "november"),
+            ("dec", "december"),
+        ],
+        start=1,
+    )
+    for name in names
+}
+
+
+def _parse_start_month(start: int | str) -> int:
+    if isinstance(start, str):
+        month = _MONTHS.get(start.lower())
+        if month is None:
+            msg = f"`start` must be a month name such as 'apr', got {start!r}"
+            raise ValueError(msg)
+        return month
+    if isinstance(start, int) and 1 <= start <= 12:
+        return start
+    msg = f"`start` must be a month name or an integer between 1 and 12, got {start!r}"
+    raise ValueError(msg)
+
 if TYPE_CHECKING:
     import sys
     from collections.abc import Iterable
@@ -27,6 +62,7 @@ if TYPE_CHECKING:
         NonExistent,
         Roll,
         TimeUnit,
+        WeekConvention,
     )
 
     if sys.version_info >= (3, 13):
@@ -1103,6 +1139,96 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_quarter())
 
+    def fiscal_year(self, start: int | str = "jan") -> Expr:
+        """
+        Extract the fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        A fiscal year is named after the calendar year in which it ends, so the
+        fiscal year starting in April 2024 is fiscal year 2025.
+
+        Parameters
+        ----------
+        start
+            The month in which the fiscal year starts, given either as a month
+            name (e.g. `"apr"` or `"april"`) or as an integer from 1 to 12.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        See Also
+        --------
+        fiscal_quarter
+        year
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 3, 31), date(2024, 4, 1), date(2024, 12, 31)]}
+        ... )
+        >>> df.with_columns(pl.col("date").dt.fiscal_year(start="apr").alias("fy"))
+        shape: (3, 2)
+        ┌────────────┬──────┐
+        │ date       ┆ fy   │
+        │ ---        ┆ ---  │
+        │ date       ┆ i32  │
+        ╞════════════╪══════╡
+        │ 2024-03-31 ┆ 2024 │
+        │ 2024-04-01 ┆ 2025 │
+        │ 2024-12-31 ┆ 2025 │
+        └────────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_year(_parse_start_month(start)))
+
+    def fiscal_quarter(self, start: int | str = "jan") -> Expr:
+        """
+        Extract the quarter of the fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the quarter ranging from 1 to 4, where quarter 1 starts in the
+        month `start`.
+
+        Parameters
+        ----------
+        start
+            The month in which the fiscal year starts, given either as a month
+            name (e.g. `"apr"` or `"april"`) or as an integer from 1 to 12.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        See Also
+        --------
+        fiscal_year
+        quarter
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 3, 31), date(2024, 4, 1), date(2024, 12, 31)]}
+        ... )
+        >>> df.with_columns(pl.col("date").dt.fiscal_quarter(start="apr").alias("fq"))
+        shape: (3, 2)
+        ┌────────────┬─────┐
+        │ date       ┆ fq  │
+        │ ---        ┆ --- │
+        │ date       ┆ i8  │
+        ╞════════════╪═════╡
+        │ 2024-03-31 ┆ 4   │
+        │ 2024-04-01 ┆ 1   │
+        │ 2024-12-31 ┆ 3   │
+        └────────────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_quarter(_parse_start_month(start)))
+
     def month(self) -> Expr:
         """
         Extract month from underlying Date representation.
@@ -1176,14 +1302,25 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_days_in_month())
 
-    def week(self) -> Expr:
+    def week(self, convention: WeekConvention = "iso") -> Expr:
         """
         Extract the week from the underlying Date representation.
 
         Applies to Date and Datetime columns.
 
-        Returns the ISO week number starting from 1.
-        The return value ranges from 1 to 53. (The last week of year differs by years.)
+        Returns the week number starting from 1.
+
+        Parameters
+        ----------
+        convention : {'iso', 'us'}
+            The convention used to number the weeks.
+
+            - 'iso': ISO 8601 weeks, which start on Monday. Week 1 is the week
+              containing the first Thursday of the year, so the first days of
+              January may belong to the last week of the previous year. The
+              return value ranges from 1 to 53.
+            - 'us': weeks start on Sunday and week 1 is the week containing
+              January 1st. The return value ranges from 1 to 54.
 
         Returns
         -------
@@ -1207,8 +1344,28 @@ class ExprDateTimeNameSpace:
         │ 2001-06-30 ┆ 26   │
         │ 2001-12-27 ┆ 52   │
         └────────────┴──────┘
-        """
-        return wrap_expr(self._pyexpr.dt_week())
+
+        Number the weeks according to the US convention.
+
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2022, 1, 1), date(2022, 1, 2), date(2022, 1, 3)]}
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("date").dt.week().alias("iso"),
+        ...     pl.col("date").dt.week(convention="us").alias("us"),
+        ... )
+        shape: (3, 3)
+        ┌────────────┬─────┬─────┐
+        │ date       ┆ iso ┆ us  │
+        │ ---        ┆ --- ┆ --- │
+        │ date       ┆ i8  ┆ i8  │
+        ╞════════════╪═════╪═════╡
+        │ 2022-01-01 ┆ 52  ┆ 1   │
+        │ 2022-01-02 ┆ 52  ┆ 2   │
+        │ 2022-01-03 ┆ 1   ┆ 2   │
+        └────────────┴─────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.dt_week(convention))
 
     def weekday(self) -> Expr:
         """
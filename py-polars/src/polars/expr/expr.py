@@ -62,6 +62,7 @@ from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
 from polars.expr.ext import ExprExtensionNameSpace
+from polars.expr.ip import ExprIPNameSpace
 from polars.expr.list import ExprListNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
@@ -90,12 +91,19 @@ if TYPE_CHECKING:
 
     from polars import DataFrame, LazyFrame, Series
     from polars._typing import (
+        CheckedArithmeticOp,
         ClosedInterval,
+        CumAggFunction,
+        DecimalArithmeticOp,
+        DecimalOverflow,
         FillNullStrategy,
+        HashDigestOutput,
+        InterpolateByMethod,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
         MapElementsStrategy,
+        ModeTies,
         NullBehavior,
         NumericLiteral,
         PolarsDataType,
@@ -143,6 +151,7 @@ class Expr:
         "cat",
         "dt",
         "ext",
+        "ip",
         "list",
         "meta",
         "name",
@@ -291,6 +300,15 @@ class Expr:
         """
         return ExprExtensionNameSpace(self)
 
+    @property
+    def ip(self) -> ExprIPNameSpace:
+        """
+        Create an object namespace of all IP address related expressions.
+
+        See the individual method pages for full details.
+        """
+        return ExprIPNameSpace(self)
+
     @classmethod
     def _from_pyexpr(cls, pyexpr: PyExpr) -> Expr:
         expr = cls.__new__(cls)
@@ -1778,6 +1796,103 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.cum_count(reverse))
 
+    def cum_sum_reset(self, when: IntoExpr, *, reverse: bool = False) -> Expr:
+        """
+        Get the cumulative sum, restarting it wherever a condition is true.
+
+        Parameters
+        ----------
+        when
+            Boolean expression that starts a new sum at the rows where it is true.
+            Null values do not start a new sum. Strings are parsed as column names.
+        reverse
+            Reverse the operation.
+
+        See Also
+        --------
+        cum_sum
+        cum_agg_over_runs
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [1, 2, 3, 4, 5], "reset": [False, False, True, False, True]}
+        ... )
+        >>> df.with_columns(cum_sum=pl.col("a").cum_sum_reset("reset"))
+        shape: (5, 3)
+        ┌─────┬───────┬─────────┐
+        │ a   ┆ reset ┆ cum_sum │
+        │ --- ┆ ---   ┆ ---     │
+        │ i64 ┆ bool  ┆ i64     │
+        ╞═════╪═══════╪═════════╡
+        │ 1   ┆ false ┆ 1       │
+        │ 2   ┆ false ┆ 3       │
+        │ 3   ┆ true  ┆ 3       │
+        │ 4   ┆ false ┆ 7       │
+        │ 5   ┆ true  ┆ 5       │
+        └─────┴───────┴─────────┘
+        """
+        return self.cum_agg_over_runs("sum", when=when, reverse=reverse)
+
+    def cum_agg_over_runs(
+        self,
+        function: CumAggFunction,
+        *,
+        when: IntoExpr | None = None,
+        by: IntoExpr | None = None,
+        reverse: bool = False,
+    ) -> Expr:
+        """
+        Get a cumulative aggregation that restarts at the start of every run.
+
+        The runs are either started by a boolean condition, or are the runs of equal
+        consecutive values of another expression. Unlike computing the aggregation
+        over :meth:`rle_id` groups, this can be executed by the streaming engine.
+
+        Parameters
+        ----------
+        function : {'sum', 'prod', 'min', 'max', 'count'}
+            The cumulative aggregation to compute.
+        when
+            Boolean expression that starts a new run at the rows where it is true.
+            Null values do not start a new run. Strings are parsed as column names.
+        by
+            Expression whose runs of equal consecutive values determine the runs.
+            Strings are parsed as column names. Exactly one of `when` and `by` must be
+            given.
+        reverse
+            Reverse the operation within every run.
+
+        See Also
+        --------
+        cum_sum_reset
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"key": ["a", "a", "b", "b", "a"], "x": [3, 1, 4, 1, 5]})
+        >>> df.with_columns(cum_max=pl.col("x").cum_agg_over_runs("max", by="key"))
+        shape: (5, 3)
+        ┌─────┬─────┬─────────┐
+        │ key ┆ x   ┆ cum_max │
+        │ --- ┆ --- ┆ ---     │
+        │ str ┆ i64 ┆ i64     │
+        ╞═════╪═════╪═════════╡
+        │ a   ┆ 3   ┆ 3       │
+        │ a   ┆ 1   ┆ 3       │
+        │ b   ┆ 4   ┆ 4       │
+        │ b   ┆ 1   ┆ 4       │
+        │ a   ┆ 5   ┆ 5       │
+        └─────┴─────┴─────────┘
+        """
+        if (when is None) == (by is None):
+            msg = "exactly one of `when` and `by` must be given"
+            raise ValueError(msg)
+        if by is not None:
+            by = wrap_expr(parse_into_expression(by))
+            when = by.ne_missing(by.shift())
+        when_pyexpr = parse_into_expression(when)
+        return wrap_expr(self._pyexpr.cum_agg_reset(when_pyexpr, function, reverse))
+
     def floor(self) -> Expr:
         """
         Rounds down to the nearest integer value.
@@ -2031,7 +2146,7 @@ class Expr:
         other_pyexpr = parse_into_expression(other)
         return wrap_expr(self._pyexpr.dot(other_pyexpr))
 
-    def mode(self, *, maintain_order: bool = False) -> Expr:
+    def mode(self, *, maintain_order: bool = False, ties: ModeTies = "all") -> Expr:
         """
         Compute the most occurring value(s).
 
@@ -2041,6 +2156,18 @@ class Expr:
         ----------
         maintain_order
             Maintain order of data. This requires more work.
+        ties : {'all', 'first', 'smallest'}
+            Which value(s) to return if multiple values occur equally often.
+
+            - 'all': return all of them.
+            - 'first': return the one that occurs first.
+            - 'smallest': return the smallest one, nulls sort last.
+
+            With 'first' and 'smallest' the result is always a single value.
+
+        See Also
+        --------
+        modes
 
         Examples
         --------
@@ -2059,8 +2186,55 @@ class Expr:
         ╞═════╪═════╡
         │ 1   ┆ 1   │
         └─────┴─────┘
+
+        Pick a single value if multiple values occur equally often.
+
+        >>> df = pl.DataFrame({"a": [2, 2, 1, 1, 3]})
+        >>> df.select(
+        ...     first=pl.col("a").mode(ties="first"),
+        ...     smallest=pl.col("a").mode(ties="smallest"),
+        ... )
+        shape: (1, 2)
+        ┌───────┬──────────┐
+        │ first ┆ smallest │
+        │ ---   ┆ ---      │
+        │ i64   ┆ i64      │
+        ╞═══════╪══════════╡
+        │ 2     ┆ 1        │
+        └───────┴──────────┘
         """
-        return wrap_expr(self._pyexpr.mode(maintain_order=maintain_order))
+        return wrap_expr(self._pyexpr.mode(maintain_order=maintain_order, ties=ties))
+
+    def modes(self, n: int) -> Expr:
+        """
+        Get the `n` most occurring values as a list.
+
+        The values are ordered by descending number of occurrences. Values that occur
+        equally often are ordered by their first occurrence.
+
+        Parameters
+        ----------
+        n
+            Number of values to return.
+
+        See Also
+        --------
+        mode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [2, 2, 1, 1, 3]})
+        >>> df.select(pl.col("a").modes(2))
+        shape: (1, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [2, 1]    │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.modes(n))
 
     def cast(
         self,
@@ -2083,6 +2257,16 @@ class Expr:
             If True numeric casts wrap overflowing values instead of
             marking the cast as invalid.
 
+        Notes
+        -----
+        Nested data types are cast recursively:
+
+        * A `List` can be cast to an `Array` and vice versa. Lists that do not
+          have the width of the `Array` raise, or become null if `strict=False`.
+        * A `Struct` is cast to another `Struct` by field name. Fields can be
+          reordered or left out, fields missing from the input are filled with
+          nulls, and each field is cast to the data type of its target field.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -2267,7 +2451,9 @@ class Expr:
             Column(s) used to determine the largest elements.
             Accepts expression input. Strings are parsed as column names.
         k
-            Number of elements to return.
+            Number of elements to return. Accepts expression input, which must be a
+            single value or constant, such as a column that is constant within each
+            group of a `group_by`.
         reverse
             Consider the `k` smallest elements of the `by` column(s) (instead of the `k`
             largest). This can be specified per column by passing a sequence of
@@ -2358,6 +2544,25 @@ class Expr:
         │ Banana ┆ 6   ┆ 1   │
         │ Banana ┆ 5   ┆ 2   │
         └────────┴─────┴─────┘
+
+        Get a different number of rows per group, given by another column.
+
+        >>> n = {"Apple": 1, "Orange": 1, "Banana": 2}
+        >>> (
+        ...     df.with_columns(n=pl.col("c").replace_strict(n))
+        ...     .group_by("c", maintain_order=True)
+        ...     .agg(pl.col("a").top_k_by("b", k=pl.col("n")).sort())
+        ... )
+        shape: (3, 2)
+        ┌────────┬───────────┐
+        │ c      ┆ a         │
+        │ ---    ┆ ---       │
+        │ str    ┆ list[i64] │
+        ╞════════╪═══════════╡
+        │ Apple  ┆ [1]       │
+        │ Orange ┆ [2]       │
+        │ Banana ┆ [5, 6]    │
+        └────────┴───────────┘
         """  # noqa: W505
         k_pyexpr = parse_into_expression(k)
         by_pyexprs = parse_into_list_of_expressions(by)
@@ -2445,7 +2650,9 @@ class Expr:
             Column(s) used to determine the smallest elements.
             Accepts expression input. Strings are parsed as column names.
         k
-            Number of elements to return.
+            Number of elements to return. Accepts expression input, which must be a
+            single value or constant, such as a column that is constant within each
+            group of a `group_by`.
         reverse
             Consider the `k` largest elements of the `by` column(s) (instead of the `k`
             smallest). This can be specified per column by passing a sequence of
@@ -3612,12 +3819,25 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.n_unique())
 
-    def approx_n_unique(self) -> Expr:
+    def approx_n_unique(self, precision: int | None = None) -> Expr:
         """
         Approximate count of unique values.
 
         This is done using the HyperLogLog++ algorithm for cardinality estimation.
 
+        Parameters
+        ----------
+        precision
+            Use a HyperLogLog sketch with `2^precision` registers, which must be
+            between 4 and 18. A higher precision gives a smaller error, the relative
+            standard error is about `1.04 / sqrt(2^precision)`, at the cost of
+            `2^precision` bytes of memory per group. By default the precision is
+            chosen by the engine.
+
+        See Also
+        --------
+        hll_sketch
+
         Examples
         --------
         >>> df = pl.DataFrame({"n": [1, 1, 2]})
@@ -3644,7 +3864,112 @@ class Expr:
         │ 1000  ┆ 1005   │
         └───────┴────────┘
         """
-        return wrap_expr(self._pyexpr.approx_n_unique())
+        return wrap_expr(self._pyexpr.approx_n_unique(precision))
+
+    def hll_sketch(self, precision: int = 14) -> Expr:
+        """
+        Get the HyperLogLog sketch of the values as a binary.
+
+        Sketches can be stored and merged with :meth:`hll_merge`, also across groups,
+        datasets and processes. Use :meth:`hll_estimate` to get the approximate count
+        of unique values of a sketch.
+
+        Values are hashed with XXH3 and a fixed seed, and categorical values by their
+        string, so sketches don't depend on the process or engine that built them.
+        The sketch starts with the bytes `b"HLL"` and a format version, sketches of
+        another version can't be merged or estimated.
+
+        Parameters
+        ----------
+        precision
+            The sketch has `2^precision` registers, which must be between 4 and 18. A
+            higher precision gives a smaller error, the relative standard error is
+            about `1.04 / sqrt(2^precision)`. Only sketches of the same precision can
+            be merged.
+
+        See Also
+        --------
+        approx_n_unique
+        hll_merge
+        hll_estimate
+
+        Examples
+        --------
+        >>> df1 = pl.DataFrame({"id": [1, 2, 3]})
+        >>> df2 = pl.DataFrame({"id": [3, 4]})
+        >>> sketches = pl.concat(
+        ...     [df.select(pl.col("id").hll_sketch()) for df in (df1, df2)]
+        ... )
+        >>> sketches.select(pl.col("id").hll_merge().hll_estimate())
+        shape: (1, 1)
+        ┌─────┐
+        │ id  │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 4   │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.hll_sketch(precision))
+
+    def hll_merge(self) -> Expr:
+        """
+        Merge binary HyperLogLog sketches into a single sketch.
+
+        The sketches must have been created with :meth:`hll_sketch` with the same
+        precision. Null values are ignored.
+
+        See Also
+        --------
+        hll_sketch
+        hll_estimate
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"day": [1, 1, 2, 2], "user": ["a", "b", "b", "c"]})
+        >>> daily = df.group_by("day").agg(pl.col("user").hll_sketch())
+        >>> daily.select(pl.col("user").hll_merge().hll_estimate())
+        shape: (1, 1)
+        ┌──────┐
+        │ user │
+        │ ---  │
+        │ u32  │
+        ╞══════╡
+        │ 3    │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.hll_merge())
+
+    def hll_estimate(self) -> Expr:
+        """
+        Get the approximate count of unique values of every binary HyperLogLog sketch.
+
+        The sketches must have been created with :meth:`hll_sketch` or
+        :meth:`hll_merge`.
+
+        See Also
+        --------
+        hll_sketch
+        hll_merge
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"day": [1, 1, 2, 2], "user": ["a", "b", "b", "c"]})
+        >>> daily = df.group_by("day", maintain_order=True).agg(
+        ...     pl.col("user").hll_sketch()
+        ... )
+        >>> daily.select("day", pl.col("user").hll_estimate())
+        shape: (2, 2)
+        ┌─────┬──────┐
+        │ day ┆ user │
+        │ --- ┆ ---  │
+        │ i64 ┆ u32  │
+        ╞═════╪══════╡
+        │ 1   ┆ 2    │
+        │ 2   ┆ 2    │
+        └─────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.hll_estimate())
 
     def null_count(self) -> Expr:
         """
@@ -4440,6 +4765,153 @@ class Expr:
         quantile_pyexpr = parse_into_expression(quantile)
         return wrap_expr(self._pyexpr.quantile(quantile_pyexpr, interpolation))
 
+    def approx_quantile(self, quantile: float, compression: float = 100.0) -> Expr:
+        """
+        Get the approximate quantile value with a t-digest.
+
+        The t-digest summarizes the values in a bounded number of centroids, which
+        are merged across chunks, groups and streaming partitions. This makes it much
+        cheaper than :meth:`quantile` on large data, while staying close to the exact
+        value, especially for quantiles near 0.0 and 1.0. The result equals the
+        `"linear"` quantile if there are few values. Null and NaN values are ignored.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        compression
+            Bound on the number of centroids, which must be positive. A higher
+            compression gives a smaller error, at the cost of more memory.
+
+        See Also
+        --------
+        quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.3))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        └─────┘
+        >>> df = pl.DataFrame({"g": ["a", "a", "a", "b", "b"], "x": [1, 2, 3, 4, 6]})
+        >>> df.group_by("g", maintain_order=True).agg(pl.col("x").approx_quantile(0.5))
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ g   ┆ x   │
+        │ --- ┆ --- │
+        │ str ┆ f64 │
+        ╞═════╪═════╡
+        │ a   ┆ 2.0 │
+        │ b   ┆ 5.0 │
+        └─────┴─────┘
+        """
+        return wrap_expr(self._pyexpr.approx_quantile(quantile, compression))
+
+    def weighted_mean(self, weights: IntoExpr) -> Expr:
+        """
+        Get the weighted mean.
+
+        Rows where the value or the weight is null are ignored. The result is null if
+        the remaining weights sum to zero.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values.
+            Accepts expression input. Strings are parsed as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"g": ["a", "a", "b", "b"], "x": [1, 2, 3, 4], "w": [1, 3, 1, None]}
+        ... )
+        >>> df.group_by("g", maintain_order=True).agg(pl.col("x").weighted_mean("w"))
+        shape: (2, 2)
+        ┌─────┬──────┐
+        │ g   ┆ x    │
+        │ --- ┆ ---  │
+        │ str ┆ f64  │
+        ╞═════╪══════╡
+        │ a   ┆ 1.75 │
+        │ b   ┆ 3.0  │
+        └─────┴──────┘
+        """
+        weights_pyexpr = parse_into_expression(weights)
+        return wrap_expr(self._pyexpr.weighted_mean(weights_pyexpr))
+
+    def weighted_var(self, weights: IntoExpr, ddof: int = 1) -> Expr:
+        """
+        Get the weighted variance, treating the weights as frequencies.
+
+        Rows where the value or the weight is null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values.
+            Accepts expression input. Strings are parsed as column names.
+        ddof
+            “Delta Degrees of Freedom”: the divisor used in the calculation is W - ddof,
+            where W represents the sum of the weights.
+            By default ddof is 1.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [1, 2, 4], "w": [1, 2, 1]})
+        >>> df.select(pl.col("x").weighted_var("w"))
+        shape: (1, 1)
+        ┌──────────┐
+        │ x        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ 1.583333 │
+        └──────────┘
+        """
+        weights_pyexpr = parse_into_expression(weights)
+        return wrap_expr(self._pyexpr.weighted_var(weights_pyexpr, ddof))
+
+    def weighted_quantile(self, weights: IntoExpr, quantile: float | Expr) -> Expr:
+        """
+        Get the weighted quantile value.
+
+        This is the smallest value for which the weights of all values up to and
+        including it sum to at least `quantile` times the total weight. Rows where the
+        value or the weight is null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values.
+            Accepts expression input. Strings are parsed as column names.
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [1, 2, 4], "w": [1, 2, 1]})
+        >>> df.select(pl.col("x").weighted_quantile("w", 0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ x   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 2.0 │
+        └─────┘
+        """
+        weights_pyexpr = parse_into_expression(weights)
+        quantile_pyexpr = parse_into_expression(quantile)
+        return wrap_expr(
+            self._pyexpr.weighted_quantile(weights_pyexpr, quantile_pyexpr)
+        )
+
     @unstable()
     def cut(
         self,
@@ -4448,6 +4920,7 @@ class Expr:
         labels: Sequence[str_] | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        include_bins: bool = False,
     ) -> Expr:
         """
         Bin continuous values into discrete categories.
@@ -4469,12 +4942,17 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        include_bins
+            Return a :class:`Struct` with the `category`, the `lower` and `upper`
+            edge and the `bin_index` of the bin each observation falls in, instead
+            of only the category. Cannot be combined with `include_breaks`.
 
         Returns
         -------
         Expr
-            Expression of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise an expression of data type :class:`Struct`.
+            Expression of data type :class:`Categorical` if `include_breaks` and
+            `include_bins` are set to `False` (default), otherwise an expression of
+            data type :class:`Struct`.
 
         See Also
         --------
@@ -4518,8 +4996,28 @@ class Expr:
         │ 1   ┆ 1.0        ┆ (-1, 1]    │
         │ 2   ┆ inf        ┆ (1, inf]   │
         └─────┴────────────┴────────────┘
+
+        Add the category together with the edges and index of its bin.
+
+        >>> df.with_columns(
+        ...     pl.col("foo").cut([-1, 1], include_bins=True).alias("cut")
+        ... ).unnest("cut")
+        shape: (5, 5)
+        ┌─────┬────────────┬───────┬───────┬───────────┐
+        │ foo ┆ category   ┆ lower ┆ upper ┆ bin_index │
+        │ --- ┆ ---        ┆ ---   ┆ ---   ┆ ---       │
+        │ i64 ┆ cat        ┆ f64   ┆ f64   ┆ u32       │
+        ╞═════╪════════════╪═══════╪═══════╪═══════════╡
+        │ -2  ┆ (-inf, -1] ┆ -inf  ┆ -1.0  ┆ 0         │
+        │ -1  ┆ (-inf, -1] ┆ -inf  ┆ -1.0  ┆ 0         │
+        │ 0   ┆ (-1, 1]    ┆ -1.0  ┆ 1.0   ┆ 1         │
+        │ 1   ┆ (-1, 1]    ┆ -1.0  ┆ 1.0   ┆ 1         │
+        │ 2   ┆ (1, inf]   ┆ 1.0   ┆ inf   ┆ 2         │
+        └─────┴────────────┴───────┴───────┴───────────┘
         """
-        return wrap_expr(self._pyexpr.cut(breaks, labels, left_closed, include_breaks))
+        return wrap_expr(
+            self._pyexpr.cut(breaks, labels, left_closed, include_breaks, include_bins)
+        )
 
     @unstable()
     def qcut(
@@ -4530,6 +5028,7 @@ class Expr:
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        include_bins: bool = False,
     ) -> Expr:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -4556,12 +5055,17 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        include_bins
+            Return a :class:`Struct` with the `category`, the `lower` and `upper`
+            edge and the `bin_index` of the bin each observation falls in, instead
+            of only the category. Cannot be combined with `include_breaks`.
 
         Returns
         -------
         Expr
-            Expression of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise an expression of data type :class:`Struct`.
+            Expression of data type :class:`Categorical` if `include_breaks` and
+            `include_bins` are set to `False` (default), otherwise an expression of
+            data type :class:`Struct`.
 
         See Also
         --------
@@ -4629,11 +5133,21 @@ class Expr:
         """
         if isinstance(quantiles, int):
             pyexpr = self._pyexpr.qcut_uniform(
-                quantiles, labels, left_closed, allow_duplicates, include_breaks
+                quantiles,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                include_bins,
             )
         else:
             pyexpr = self._pyexpr.qcut(
-                quantiles, labels, left_closed, allow_duplicates, include_breaks
+                quantiles,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                include_bins,
             )
 
         return wrap_expr(pyexpr)
@@ -5000,6 +5514,7 @@ Consider using {self}.implode() instead"""
         pass_name: bool = False,
         strategy: MapElementsStrategy = "thread_local",
         returns_scalar: bool = False,
+        cache_objects: bool = False,
     ) -> Expr:
         """
         Map a custom/user-defined function (UDF) to each element of a column.
@@ -5051,6 +5566,10 @@ Consider using {self}.implode() instead"""
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
+        cache_objects
+            Only applies to columns of dtype :class:`Object`. Call the function once
+            per distinct Python object (compared by identity) and reuse the result
+            wherever the same object occurs again.
 
         Notes
         -----
@@ -5069,6 +5588,11 @@ Consider using {self}.implode() instead"""
           depend on state other than its arguments. Polars may call the function
           with arbitrary input data.
 
+        * Some compiled functions are called natively instead of through Python,
+          if `skip_nulls=True`: a numba `@cfunc` taking and returning a single
+          (32 or 64 bit) number is called in parallel without holding the GIL, and a
+          unary `pyarrow.compute` function is called once per batch of data.
+
         Examples
         --------
         >>> df = pl.DataFrame(
@@ -5199,7 +5723,10 @@ Consider using {self}.implode() instead"""
                 with warnings.catch_warnings():
                     warnings.simplefilter("ignore", PolarsInefficientMapWarning)
                     return x.map_elements(
-                        inner, return_dtype=return_dtype, skip_nulls=skip_nulls
+                        inner,
+                        return_dtype=return_dtype,
+                        skip_nulls=skip_nulls,
+                        cache_objects=cache_objects,
                     )
 
         else:
@@ -5210,7 +5737,10 @@ Consider using {self}.implode() instead"""
                     warnings.simplefilter("ignore", PolarsInefficientMapWarning)
 
                     return x.map_elements(
-                        function, return_dtype=return_dtype, skip_nulls=skip_nulls
+                        function,
+                        return_dtype=return_dtype,
+                        skip_nulls=skip_nulls,
+                        cache_objects=cache_objects,
                     )
 
         if strategy == "thread_local":
@@ -6219,6 +6749,138 @@ Consider using {self}.implode() instead"""
         """
         return self.__truediv__(other)
 
+    def decimal_arithmetic(
+        self,
+        other: IntoExpr,
+        op: DecimalArithmeticOp,
+        *,
+        rounding: RoundMode = "half_to_even",
+        overflow: DecimalOverflow = "raise",
+    ) -> Expr:
+        """
+        Apply an arithmetic operation on Decimal data with SQL precision/scale rules.
+
+        Unlike the arithmetic operators, which always return a Decimal with the
+        maximum precision and the largest scale of the operands, the precision and
+        scale of the result are derived from those of the operands, following the
+        rules used by SQL engines:
+
+        * *add*, *sub*: scale ``max(s1, s2)``, precision
+          ``max(p1 - s1, p2 - s2) + max(s1, s2) + 1``.
+        * *mul*: scale ``s1 + s2``, precision ``p1 + p2 + 1``.
+        * *div*: scale ``max(6, s1 + p2 + 1)``, precision ``p1 - s1 + s2 + scale``.
+
+        If the precision exceeds 38, it is capped at 38 and the scale is reduced to
+        keep the integral digits, while keeping at least 6 digits of scale (or the
+        original scale if that is smaller). The result is then rounded to that scale.
+
+        Integer operands are treated as Decimals with scale 0. The operators don't
+        apply these rules; in particular, dividing an integer by a Decimal with ``/``
+        returns a Float64.
+
+        Parameters
+        ----------
+        other
+            Decimal or integer value; accepts expression input.
+        op : {'add', 'sub', 'mul', 'div'}
+            The arithmetic operation to apply.
+        rounding : {'half_to_even', 'half_away_from_zero', 'to_zero'}
+            How the result is rounded to its scale, see :meth:`round`.
+        overflow : {'raise', 'null'}
+            What to do when a result does not fit the result precision.
+
+        See Also
+        --------
+        add
+        mul
+        sub
+        truediv
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["1.00", "2.50", "-7.25"], "b": ["3", "4", "3"]},
+        ...     schema_overrides={"a": pl.Decimal(5, 2), "b": pl.Decimal(3, 0)},
+        ... )
+        >>> df.select(
+        ...     pl.col("a").decimal_arithmetic(pl.col("b"), "mul").alias("mul"),
+        ...     pl.col("a").decimal_arithmetic(pl.col("b"), "div").alias("div"),
+        ...     pl.col("a")
+        ...     .decimal_arithmetic(pl.col("b"), "div", rounding="to_zero")
+        ...     .alias("div_to_zero"),
+        ... )
+        shape: (3, 3)
+        ┌──────────────┬──────────────┬──────────────┐
+        │ mul          ┆ div          ┆ div_to_zero  │
+        │ ---          ┆ ---          ┆ ---          │
+        │ decimal[9,2] ┆ decimal[9,6] ┆ decimal[9,6] │
+        ╞══════════════╪══════════════╪══════════════╡
+        │ 3.00         ┆ 0.333333     ┆ 0.333333     │
+        │ 10.00        ┆ 0.625000     ┆ 0.625000     │
+        │ -21.75       ┆ -2.416667    ┆ -2.416666    │
+        └──────────────┴──────────────┴──────────────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(
+            self._pyexpr.decimal_arithmetic(other_pyexpr, op, rounding, overflow)
+        )
+
+    def checked_arithmetic(
+        self,
+        other: IntoExpr,
+        op: CheckedArithmeticOp,
+        *,
+        warn: bool = False,
+    ) -> Expr:
+        """
+        Apply an integer arithmetic operation that produces null on overflow.
+
+        The arithmetic operators wrap around when the result does not fit the
+        integer data type. This method instead sets such results to null, so a
+        pipeline can continue and deal with the overflowed values afterwards.
+
+        Both operands are cast to their supertype before the operation.
+
+        Parameters
+        ----------
+        other
+            Integer value; accepts expression input.
+        op : {'add', 'sub', 'mul'}
+            The arithmetic operation to apply.
+        warn
+            Raise a warning with the number of values that overflowed, if any.
+
+        See Also
+        --------
+        add
+        mul
+        sub
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [100, -100, 5], "b": [100, 100, 5]},
+        ...     schema={"a": pl.Int8, "b": pl.Int8},
+        ... )
+        >>> df.select(
+        ...     pl.col("a").checked_arithmetic(pl.col("b"), "add").alias("add"),
+        ...     pl.col("a").checked_arithmetic(pl.col("b"), "sub").alias("sub"),
+        ...     pl.col("a").checked_arithmetic(pl.col("b"), "mul").alias("mul"),
+        ... )
+        shape: (3, 3)
+        ┌──────┬──────┬──────┐
+        │ add  ┆ sub  ┆ mul  │
+        │ ---  ┆ ---  ┆ ---  │
+        │ i8   ┆ i8   ┆ i8   │
+        ╞══════╪══════╪══════╡
+        │ null ┆ 0    ┆ null │
+        │ 0    ┆ null ┆ null │
+        │ 10   ┆ 0    ┆ 25   │
+        └──────┴──────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.checked_arithmetic(other_pyexpr, op, warn))
+
     def pow(self, exponent: IntoExprColumn | int | float) -> Expr:
         """
         Method equivalent of exponentiation operator `expr ** exponent`.
@@ -6599,6 +7261,8 @@ Consider using {self}.implode() instead"""
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        stable: bool = False,
     ) -> Expr:
         """
         Hash the elements in the selection.
@@ -6615,12 +7279,21 @@ Consider using {self}.implode() instead"""
             Random seed parameter. Defaults to `seed` if not set.
         seed_3
             Random seed parameter. Defaults to `seed` if not set.
+        stable
+            Use a hash algorithm that gives the same results across Polars versions
+            and platforms, for hashes that are persisted, such as partition or
+            deduplication keys. Only `seed` is used.
 
         Notes
         -----
-        This implementation of `hash` does not guarantee stable results
-        across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        Unless `stable` is set, this implementation of `hash` does not guarantee
+        stable results across different Polars versions. Its stability is only
+        guaranteed within a single version.
+
+        With `stable=True`, every value is encoded as a validity byte followed by its
+        little-endian payload, which is hashed with 64-bit XXH3 seeded with `seed`.
+        The encoding of every data type is described by :func:`stable_hash_version`,
+        the hashes only change together with that version.
 
         Examples
         --------
@@ -6642,12 +7315,134 @@ Consider using {self}.implode() instead"""
         │ 11638928888656214026 ┆ 13382926553367784577 │
         └──────────────────────┴──────────────────────┘
         """
+        if stable:
+            if seed_1 is not None or seed_2 is not None or seed_3 is not None:
+                msg = "`seed_1`, `seed_2` and `seed_3` cannot be set when `stable=True`"
+                raise ValueError(msg)
+            return wrap_expr(self._pyexpr.stable_hash(seed))
         k0 = seed
         k1 = seed_1 if seed_1 is not None else seed
         k2 = seed_2 if seed_2 is not None else seed
         k3 = seed_3 if seed_3 is not None else seed
         return wrap_expr(self._pyexpr.hash(k0, k1, k2, k3))
 
+    def hash_sha256(self, *, output: HashDigestOutput = "hex") -> Expr:
+        """
+        Compute the SHA-256 digest of every string or binary value.
+
+        Strings are hashed as their UTF-8 encoded bytes and nulls are propagated.
+
+        Parameters
+        ----------
+        output : {'hex', 'binary'}
+            Return the digest as a lowercase hexadecimal string (default) or as raw
+            bytes in a `Binary` column.
+
+        See Also
+        --------
+        hash_md5
+        hash_xxh3
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"fruit": ["apple", "banana", None]})
+        >>> df.with_columns(sha256=pl.col("fruit").hash_sha256())
+        shape: (3, 2)
+        ┌────────┬───────────────────────────────────┐
+        │ fruit  ┆ sha256                            │
+        │ ---    ┆ ---                               │
+        │ str    ┆ str                               │
+        ╞════════╪═══════════════════════════════════╡
+        │ apple  ┆ 3a7bd3e2360a3d29eea436fcfb7e44c7… │
+        │ banana ┆ b493d48364afe44d11c0165cf470a416… │
+        │ null   ┆ null                              │
+        └────────┴───────────────────────────────────┘
+        """
+        if output not in ("hex", "binary"):
+            msg = f"`output` must be one of {{'hex', 'binary'}}, got {output!r}"
+            raise ValueError(msg)
+        return wrap_expr(self._pyexpr.hash_sha256(output == "hex"))
+
+    def hash_md5(self, *, output: HashDigestOutput = "hex") -> Expr:
+        """
+        Compute the MD5 digest of every string or binary value.
+
+        Strings are hashed as their UTF-8 encoded bytes and nulls are propagated.
+
+        Parameters
+        ----------
+        output : {'hex', 'binary'}
+            Return the digest as a lowercase hexadecimal string (default) or as raw
+            bytes in a `Binary` column.
+
+        Notes
+        -----
+        MD5 is not collision resistant and should not be used for security purposes;
+        prefer :meth:`hash_sha256` for that.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"fruit": ["apple", "banana", None]})
+        >>> df.with_columns(md5=pl.col("fruit").hash_md5())
+        shape: (3, 2)
+        ┌────────┬──────────────────────────────────┐
+        │ fruit  ┆ md5                              │
+        │ ---    ┆ ---                              │
+        │ str    ┆ str                              │
+        ╞════════╪══════════════════════════════════╡
+        │ apple  ┆ 1f3870be274f6c49b3e31a0c6728957f │
+        │ banana ┆ 72b302bf297a228a75730123efef7c41 │
+        │ null   ┆ null                             │
+        └────────┴──────────────────────────────────┘
+        """
+        if output not in ("hex", "binary"):
+            msg = f"`output` must be one of {{'hex', 'binary'}}, got {output!r}"
+            raise ValueError(msg)
+        return wrap_expr(self._pyexpr.hash_md5(output == "hex"))
+
+    def hash_xxh3(self, seed: int = 0, *, output: HashDigestOutput = "hex") -> Expr:
+        """
+        Compute the 64-bit XXH3 digest of every string or binary value.
+
+        Strings are hashed as their UTF-8 encoded bytes and nulls are propagated.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function.
+        output : {'hex', 'binary'}
+            Return the digest as a lowercase hexadecimal string (default) or as raw
+            bytes in a `Binary` column.
+
+        Notes
+        -----
+        Unlike :meth:`hash`, the result is stable across Polars versions and platforms.
+        The hex digest is the 64-bit hash in big-endian byte order.
+
+        Examples
+        --------
+        Use the hash as a compact deduplication key.
+
+        >>> df = pl.DataFrame({"fruit": ["apple", "banana", "apple"]})
+        >>> df.with_columns(
+        ...     is_duplicate=pl.col("fruit").hash_xxh3(seed=42).is_duplicated()
+        ... )
+        shape: (3, 2)
+        ┌────────┬──────────────┐
+        │ fruit  ┆ is_duplicate │
+        │ ---    ┆ ---          │
+        │ str    ┆ bool         │
+        ╞════════╪══════════════╡
+        │ apple  ┆ true         │
+        │ banana ┆ false        │
+        │ apple  ┆ true         │
+        └────────┴──────────────┘
+        """
+        if output not in ("hex", "binary"):
+            msg = f"`output` must be one of {{'hex', 'binary'}}, got {output!r}"
+            raise ValueError(msg)
+        return wrap_expr(self._pyexpr.hash_xxh3(seed, output == "hex"))
+
     def reinterpret(
         self,
         *,
@@ -6810,16 +7605,39 @@ Consider using {self}.implode() instead"""
         """
         return wrap_expr(self._pyexpr.interpolate(method))
 
-    def interpolate_by(self, by: IntoExpr) -> Expr:
+    def interpolate_by(
+        self,
+        by: IntoExpr,
+        *,
+        method: InterpolateByMethod = "linear",
+        max_gap: int | float | timedelta | IntoExpr | None = None,
+    ) -> Expr:
         """
         Fill null values using interpolation based on another column.
 
-        Nulls at the beginning and end of the series remain null.
+        Nulls at the beginning and end of the series remain null, unless they are
+        filled by the `'forward'` or `'backward'` method.
 
         Parameters
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'forward', 'backward', 'cubic_spline'}
+            Interpolation method.
+
+            - 'linear': linear interpolation between the surrounding values.
+            - 'nearest': the surrounding value that is closest in `by`.
+            - 'forward': the previous value.
+            - 'backward': the next value.
+            - 'cubic_spline': a natural cubic spline through all non-null values.
+
+            The `'linear'` and `'cubic_spline'` methods return a float column, the
+            other methods preserve the data type.
+        max_gap
+            Only fill nulls when the distance in `by` is at most this value. For the
+            `'forward'` and `'backward'` methods this is the distance to the value
+            that is propagated, for the other methods the distance between the
+            surrounding non-null values. Use a timedelta if `by` is temporal.
 
         Examples
         --------
@@ -6843,9 +7661,31 @@ Consider using {self}.implode() instead"""
         │ null ┆ 7   ┆ 2.714286       │
         │ 3    ┆ 8   ┆ 3.0            │
         └──────┴─────┴────────────────┘
+
+        Fill null values with the nearest value, or propagate the previous value if
+        it is at most 2 apart in `b`.
+
+        >>> df.with_columns(
+        ...     nearest=pl.col("a").interpolate_by("b", method="nearest"),
+        ...     forward=pl.col("a").interpolate_by("b", method="forward", max_gap=2),
+        ... )
+        shape: (4, 4)
+        ┌──────┬─────┬─────────┬─────────┐
+        │ a    ┆ b   ┆ nearest ┆ forward │
+        │ ---  ┆ --- ┆ ---     ┆ ---     │
+        │ i64  ┆ i64 ┆ i64     ┆ i64     │
+        ╞══════╪═════╪═════════╪═════════╡
+        │ 1    ┆ 1   ┆ 1       ┆ 1       │
+        │ null ┆ 2   ┆ 1       ┆ 1       │
+        │ null ┆ 7   ┆ 3       ┆ null    │
+        │ 3    ┆ 8   ┆ 3       ┆ 3       │
+        └──────┴─────┴─────────┴─────────┘
         """
         by_pyexpr = parse_into_expression(by)
-        return wrap_expr(self._pyexpr.interpolate_by(by_pyexpr))
+        max_gap_pyexpr = None if max_gap is None else parse_into_expression(max_gap)
+        return wrap_expr(
+            self._pyexpr.interpolate_by(by_pyexpr, method, max_gap_pyexpr)
+        )
 
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
@@ -9300,6 +10140,7 @@ Consider using {self}.implode() instead"""
         *,
         min_samples: int | None = None,
         center: bool = False,
+        batch_size: int | None = None,
     ) -> Expr:
         """
         Compute a custom rolling window function.
@@ -9314,7 +10155,9 @@ Consider using {self}.implode() instead"""
         Parameters
         ----------
         function
-            Custom aggregation function.
+            Custom aggregation function. If `batch_size` is set, it is called with an
+            :class:`Array` Series with a window per row instead, and has to return a
+            value per window.
         window_size
             The length of the window in number of elements.
         weights
@@ -9325,6 +10168,11 @@ Consider using {self}.implode() instead"""
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
+        batch_size
+            Call `function` with up to this many windows at once, which amortizes the
+            overhead of calling into Python for vectorized functions. Windows of
+            different lengths, such as those at the start of the data, are passed in
+            separate batches.
 
         Warnings
         --------
@@ -9348,6 +10196,28 @@ Consider using {self}.implode() instead"""
         │ 11.0 │
         │ 17.0 │
         └──────┘
+
+        Pass many windows at once to a vectorized function with `batch_size`.
+
+        >>> df.select(
+        ...     pl.col("a").rolling_map(
+        ...         lambda windows: nansum(windows.to_numpy(), axis=1),
+        ...         window_size=3,
+        ...         batch_size=1024,
+        ...     )
+        ... )
+        shape: (5, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ 22.0 │
+        │ 11.0 │
+        │ 17.0 │
+        └──────┘
         """
         if min_samples is None:
             min_samples = window_size
@@ -9357,10 +10227,14 @@ Consider using {self}.implode() instead"""
             rv = function(s)
             if isinstance(rv, pl.Series):
                 return rv._s
+            if batch_size is not None:
+                return pl.Series(rv)._s
             return pl.Series([rv])._s
 
         return wrap_expr(
-            self._pyexpr.rolling_map(_wrap, window_size, weights, min_samples, center)
+            self._pyexpr.rolling_map(
+                _wrap, window_size, weights, min_samples, center, batch_size
+            )
         )
 
     def abs(self) -> Expr:
@@ -9403,7 +10277,7 @@ Consider using {self}.implode() instead"""
 
         Parameters
         ----------
-        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random', 'percent'}
             The method used to assign ranks to tied elements.
             The following methods are available (default is 'average'):
 
@@ -9421,6 +10295,9 @@ Consider using {self}.implode() instead"""
               the order that the values occur in the Series.
             - 'random' : Like 'ordinal', but the rank for ties is not dependent
               on the order that the values occur in the Series.
+            - 'percent' : The relative rank `(min_rank - 1) / (n - 1)` of each
+              value, where `n` is the number of non-null values. This ranges from
+              0.0 to 1.0 and is referred to as `PERCENT_RANK` in SQL.
         descending
             Rank in descending order.
         seed
@@ -0,0 +1,92 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import Expr
+
+
+class ExprIPNameSpace:
+    """
+    Namespace for IP address related expressions.
+
+    The expressions operate on the binary representation of IP addresses produced by
+    :meth:`Expr.str.parse_ip`.
+    """
+
+    _accessor = "ip"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def is_in_subnet(self, subnet: str | Sequence[str]) -> Expr:
+        """
+        Check whether the IP addresses lie within any of the given subnets.
+
+        Parameters
+        ----------
+        subnet
+            One or more IPv4 or IPv6 subnets in CIDR notation, such as
+            `"10.0.0.0/8"`. A plain address is the subnet of just that address.
+            IPv4 addresses never lie within an IPv6 subnet and vice versa.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["10.1.2.3", "192.168.0.1", "2001:db8::1", None]})
+        >>> df.with_columns(
+        ...     private=pl.col("ip")
+        ...     .str.parse_ip()
+        ...     .ip.is_in_subnet(["10.0.0.0/8", "192.168.0.0/16"])
+        ... )
+        shape: (4, 2)
+        ┌─────────────┬─────────┐
+        │ ip          ┆ private │
+        │ ---         ┆ ---     │
+        │ str         ┆ bool    │
+        ╞═════════════╪═════════╡
+        │ 10.1.2.3    ┆ true    │
+        │ 192.168.0.1 ┆ true    │
+        │ 2001:db8::1 ┆ false   │
+        │ null        ┆ null    │
+        └─────────────┴─────────┘
+        """
+        subnets = [subnet] if isinstance(subnet, str) else list(subnet)
+        return wrap_expr(self._pyexpr.ip_is_in_subnet(subnets))
+
+    def to_int(self) -> Expr:
+        """
+        Convert the IP addresses to their integer value.
+
+        IPv4 addresses map to the range of a 32-bit unsigned integer, IPv6 addresses to
+        the full 128-bit range.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt128`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["10.0.0.1", "192.168.0.1", "::1"]})
+        >>> df.with_columns(int=pl.col("ip").str.parse_ip().ip.to_int())
+        shape: (3, 2)
+        ┌─────────────┬────────────┐
+        │ ip          ┆ int        │
+        │ ---         ┆ ---        │
+        │ str         ┆ u128       │
+        ╞═════════════╪════════════╡
+        │ 10.0.0.1    ┆ 167772161  │
+        │ 192.168.0.1 ┆ 3232235521 │
+        │ ::1         ┆ 1          │
+        └─────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_to_int())
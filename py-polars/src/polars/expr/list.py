@@ -771,6 +771,49 @@ class ExprListNameSpace:
         item_pyexpr = parse_into_expression(item, str_as_lit=True)
         return wrap_expr(self._pyexpr.list_contains(item_pyexpr, nulls_equal))
 
+    def index_of(self, value: IntoExpr) -> Expr:
+        """
+        Get the index of the first occurrence of `value` in every sublist.
+
+        Returns null if the value is not found or if the sublist is null.
+
+        Parameters
+        ----------
+        value
+            Value to find. If this is an expression producing a value per row, every
+            sublist is searched for its own value. A null value matches the first
+            null element. A value that doesn't fit the data type of the elements,
+            e.g. because it is out of range, is never found.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32` or :class:`UInt64`
+            (depending on compilation).
+
+        See Also
+        --------
+        find : Find the index of the first element that matches a predicate.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 2, 1], [], [1, 2, 1], None]})
+        >>> df.with_columns(index_of=pl.col("a").list.index_of(1))
+        shape: (4, 2)
+        ┌───────────┬──────────┐
+        │ a         ┆ index_of │
+        │ ---       ┆ ---      │
+        │ list[i64] ┆ u32      │
+        ╞═══════════╪══════════╡
+        │ [3, 2, 1] ┆ 2        │
+        │ []        ┆ null     │
+        │ [1, 2, 1] ┆ 0        │
+        │ null      ┆ null     │
+        └───────────┴──────────┘
+        """
+        value_pyexpr = parse_into_expression(value, str_as_lit=True)
+        return wrap_expr(self._pyexpr.list_index_of(value_pyexpr))
+
     def join(self, separator: IntoExprColumn, *, ignore_nulls: bool = True) -> Expr:
         """
         Join all string items in a sublist and place a separator between them.
@@ -932,6 +975,438 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_diff(n, null_behavior))
 
+    def cum_sum(self, *, reverse: bool = False) -> Expr:
+        """
+        Get the cumulative sum within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 1, 2], [4, 5]]})
+        >>> df.with_columns(cum_sum=pl.col("a").list.cum_sum())
+        shape: (2, 2)
+        ┌───────────┬───────────┐
+        │ a         ┆ cum_sum   │
+        │ ---       ┆ ---       │
+        │ list[i64] ┆ list[i64] │
+        ╞═══════════╪═══════════╡
+        │ [3, 1, 2] ┆ [3, 4, 6] │
+        │ [4, 5]    ┆ [4, 9]    │
+        └───────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_cum_sum(reverse))
+
+    def cum_prod(self, *, reverse: bool = False) -> Expr:
+        """
+        Get the cumulative product within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 1, 2], [4, 5]]})
+        >>> df.with_columns(cum_prod=pl.col("a").list.cum_prod())
+        shape: (2, 2)
+        ┌───────────┬───────────┐
+        │ a         ┆ cum_prod  │
+        │ ---       ┆ ---       │
+        │ list[i64] ┆ list[i64] │
+        ╞═══════════╪═══════════╡
+        │ [3, 1, 2] ┆ [3, 3, 6] │
+        │ [4, 5]    ┆ [4, 20]   │
+        └───────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_cum_prod(reverse))
+
+    def cum_min(self, *, reverse: bool = False) -> Expr:
+        """
+        Get the cumulative minimum within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 1, 2], [4, 5]]})
+        >>> df.with_columns(cum_min=pl.col("a").list.cum_min())
+        shape: (2, 2)
+        ┌───────────┬───────────┐
+        │ a         ┆ cum_min   │
+        │ ---       ┆ ---       │
+        │ list[i64] ┆ list[i64] │
+        ╞═══════════╪═══════════╡
+        │ [3, 1, 2] ┆ [3, 1, 1] │
+        │ [4, 5]    ┆ [4, 4]    │
+        └───────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_cum_min(reverse))
+
+    def cum_max(self, *, reverse: bool = False) -> Expr:
+        """
+        Get the cumulative maximum within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 1, 2], [4, 5]]})
+        >>> df.with_columns(cum_max=pl.col("a").list.cum_max())
+        shape: (2, 2)
+        ┌───────────┬───────────┐
+        │ a         ┆ cum_max   │
+        │ ---       ┆ ---       │
+        │ list[i64] ┆ list[i64] │
+        ╞═══════════╪═══════════╡
+        │ [3, 1, 2] ┆ [3, 3, 3] │
+        │ [4, 5]    ┆ [4, 5]    │
+        └───────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_cum_max(reverse))
+
+    def cum_count(self, *, reverse: bool = False) -> Expr:
+        """
+        Get the cumulative count of the non-null values within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, None, 3], [None]]})
+        >>> df.with_columns(cum_count=pl.col("a").list.cum_count())
+        shape: (2, 2)
+        ┌──────────────┬───────────┐
+        │ a            ┆ cum_count │
+        │ ---          ┆ ---       │
+        │ list[i64]    ┆ list[u32] │
+        ╞══════════════╪═══════════╡
+        │ [1, null, 3] ┆ [1, 1, 2] │
+        │ [null]       ┆ [0]       │
+        └──────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_cum_count(reverse))
+
+    def rolling_min(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling minimum within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 6]]})
+        >>> df.with_columns(rolling_min=pl.col("a").list.rolling_min(2))
+        shape: (2, 2)
+        ┌───────────┬──────────────┐
+        │ a         ┆ rolling_min  │
+        │ ---       ┆ ---          │
+        │ list[i64] ┆ list[i64]    │
+        ╞═══════════╪══════════════╡
+        │ [1, 2, 3] ┆ [null, 1, 2] │
+        │ [4, 6]    ┆ [null, 4]    │
+        └───────────┴──────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.list_rolling_min(window_size, min_samples, center)
+        )
+
+    def rolling_max(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling maximum within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 6]]})
+        >>> df.with_columns(rolling_max=pl.col("a").list.rolling_max(2))
+        shape: (2, 2)
+        ┌───────────┬──────────────┐
+        │ a         ┆ rolling_max  │
+        │ ---       ┆ ---          │
+        │ list[i64] ┆ list[i64]    │
+        ╞═══════════╪══════════════╡
+        │ [1, 2, 3] ┆ [null, 2, 3] │
+        │ [4, 6]    ┆ [null, 6]    │
+        └───────────┴──────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.list_rolling_max(window_size, min_samples, center)
+        )
+
+    def rolling_mean(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling mean within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 6]]})
+        >>> df.with_columns(rolling_mean=pl.col("a").list.rolling_mean(2))
+        shape: (2, 2)
+        ┌───────────┬──────────────────┐
+        │ a         ┆ rolling_mean     │
+        │ ---       ┆ ---              │
+        │ list[i64] ┆ list[f64]        │
+        ╞═══════════╪══════════════════╡
+        │ [1, 2, 3] ┆ [null, 1.5, 2.5] │
+        │ [4, 6]    ┆ [null, 5.0]      │
+        └───────────┴──────────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.list_rolling_mean(window_size, min_samples, center)
+        )
+
+    def rolling_sum(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling sum within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 6]]})
+        >>> df.with_columns(rolling_sum=pl.col("a").list.rolling_sum(2))
+        shape: (2, 2)
+        ┌───────────┬──────────────┐
+        │ a         ┆ rolling_sum  │
+        │ ---       ┆ ---          │
+        │ list[i64] ┆ list[i64]    │
+        ╞═══════════╪══════════════╡
+        │ [1, 2, 3] ┆ [null, 3, 5] │
+        │ [4, 6]    ┆ [null, 10]   │
+        └───────────┴──────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.list_rolling_sum(window_size, min_samples, center)
+        )
+
+    def rolling_median(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling median within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 6]]})
+        >>> df.with_columns(rolling_median=pl.col("a").list.rolling_median(2))
+        shape: (2, 2)
+        ┌───────────┬──────────────────┐
+        │ a         ┆ rolling_median   │
+        │ ---       ┆ ---              │
+        │ list[i64] ┆ list[f64]        │
+        ╞═══════════╪══════════════════╡
+        │ [1, 2, 3] ┆ [null, 1.5, 2.5] │
+        │ [4, 6]    ┆ [null, 5.0]      │
+        └───────────┴──────────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.list_rolling_median(window_size, min_samples, center)
+        )
+
+    def rolling_std(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> Expr:
+        """
+        Apply a rolling standard deviation within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 6]]})
+        >>> df.with_columns(rolling_std=pl.col("a").list.rolling_std(2))
+        shape: (2, 2)
+        ┌───────────┬────────────────────────────┐
+        │ a         ┆ rolling_std                │
+        │ ---       ┆ ---                        │
+        │ list[i64] ┆ list[f64]                  │
+        ╞═══════════╪════════════════════════════╡
+        │ [1, 2, 3] ┆ [null, 0.707107, 0.707107] │
+        │ [4, 6]    ┆ [null, 1.414214]           │
+        └───────────┴────────────────────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.list_rolling_std(window_size, min_samples, center, ddof)
+        )
+
+    def rolling_var(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> Expr:
+        """
+        Apply a rolling variance within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3], [4, 6]]})
+        >>> df.with_columns(rolling_var=pl.col("a").list.rolling_var(2))
+        shape: (2, 2)
+        ┌───────────┬──────────────────┐
+        │ a         ┆ rolling_var      │
+        │ ---       ┆ ---              │
+        │ list[i64] ┆ list[f64]        │
+        ╞═══════════╪══════════════════╡
+        │ [1, 2, 3] ┆ [null, 0.5, 0.5] │
+        │ [4, 6]    ┆ [null, 2.0]      │
+        └───────────┴──────────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.list_rolling_var(window_size, min_samples, center, ddof)
+        )
+
     def shift(self, n: int | IntoExprColumn = 1) -> Expr:
         """
         Shift list values by the given number of indices.
@@ -1367,7 +1842,48 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_filter(predicate._pyexpr))
 
-    def set_union(self, other: IntoExpr | Collection[Any]) -> Expr:
+    def find(self, predicate: Expr) -> Expr:
+        """
+        Get the index of the first element in every sublist that matches a predicate.
+
+        Returns null if no element matches or if the sublist is null.
+
+        Parameters
+        ----------
+        predicate
+            A boolean expression that is evaluated per list element.
+            You can refer to the current element with `pl.element()`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32` or :class:`UInt64`
+            (depending on compilation).
+
+        See Also
+        --------
+        index_of : Find the index of the first occurrence of a value.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 8, 3], [5, 7], [4]]})
+        >>> df.with_columns(find=pl.col("a").list.find(pl.element() > 4))
+        shape: (3, 2)
+        ┌───────────┬──────┐
+        │ a         ┆ find │
+        │ ---       ┆ ---  │
+        │ list[i64] ┆ u32  │
+        ╞═══════════╪══════╡
+        │ [1, 8, 3] ┆ 1    │
+        │ [5, 7]    ┆ 0    │
+        │ [4]       ┆ null │
+        └───────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.list_find(predicate._pyexpr))
+
+    def set_union(
+        self, other: IntoExpr | Collection[Any], *, multiset: bool = False
+    ) -> Expr:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
 
@@ -1375,6 +1891,10 @@ class ExprListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Treat the lists as multisets: duplicates are kept and every occurrence
+            is matched against a single occurrence in `other`. The output follows
+            the order of this list.
 
         Examples
         --------
@@ -1405,9 +1925,13 @@ class ExprListNameSpace:
             other_pyexpr = F.lit(other)._pyexpr
         else:
             other_pyexpr = parse_into_expression(other)
-        return wrap_expr(self._pyexpr.list_set_operation(other_pyexpr, "union"))
+        return wrap_expr(
+            self._pyexpr.list_set_operation(other_pyexpr, "union", multiset)
+        )
 
-    def set_difference(self, other: IntoExpr | Collection[Any]) -> Expr:
+    def set_difference(
+        self, other: IntoExpr | Collection[Any], *, multiset: bool = False
+    ) -> Expr:
         """
         Compute the SET DIFFERENCE between the elements in this list and the elements of `other`.
 
@@ -1415,6 +1939,10 @@ class ExprListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Treat the lists as multisets: duplicates are kept and every occurrence
+            is matched against a single occurrence in `other`. The output follows
+            the order of this list.
 
         Examples
         --------
@@ -1437,6 +1965,22 @@ class ExprListNameSpace:
         │ [5, 6, 7] ┆ [6, 8]       ┆ [5, 7]     │
         └───────────┴──────────────┴────────────┘
 
+        With `multiset=True` every occurrence in `b` removes a single occurrence:
+
+        >>> df = pl.DataFrame({"a": [[1, 1, 2], [3, 2, 3]], "b": [[1], [3, 3, 3]]})
+        >>> df.with_columns(
+        ...     difference=pl.col("a").list.set_difference("b", multiset=True)
+        ... )
+        shape: (2, 3)
+        ┌───────────┬───────────┬────────────┐
+        │ a         ┆ b         ┆ difference │
+        │ ---       ┆ ---       ┆ ---        │
+        │ list[i64] ┆ list[i64] ┆ list[i64]  │
+        ╞═══════════╪═══════════╪════════════╡
+        │ [1, 1, 2] ┆ [1]       ┆ [1, 2]     │
+        │ [3, 2, 3] ┆ [3, 3, 3] ┆ [2]        │
+        └───────────┴───────────┴────────────┘
+
         See Also
         --------
         polars.Expr.list.diff: Calculates the n-th discrete difference of every sublist.
@@ -1447,9 +1991,13 @@ class ExprListNameSpace:
             other_pyexpr = F.lit(other)._pyexpr
         else:
             other_pyexpr = parse_into_expression(other)
-        return wrap_expr(self._pyexpr.list_set_operation(other_pyexpr, "difference"))
+        return wrap_expr(
+            self._pyexpr.list_set_operation(other_pyexpr, "difference", multiset)
+        )
 
-    def set_intersection(self, other: IntoExpr | Collection[Any]) -> Expr:
+    def set_intersection(
+        self, other: IntoExpr | Collection[Any], *, multiset: bool = False
+    ) -> Expr:
         """
         Compute the SET INTERSECTION between the elements in this list and the elements of `other`.
 
@@ -1457,6 +2005,10 @@ class ExprListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Treat the lists as multisets: duplicates are kept and every occurrence
+            is matched against a single occurrence in `other`. The output follows
+            the order of this list.
 
         Examples
         --------
@@ -1478,6 +2030,24 @@ class ExprListNameSpace:
         │ [null, 3] ┆ [3, 4, null] ┆ [null, 3]    │
         │ [5, 6, 7] ┆ [6, 8]       ┆ [6]          │
         └───────────┴──────────────┴──────────────┘
+
+        With `multiset=True` duplicates are matched one by one:
+
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 1, 2], [3, 2, 3]], "b": [[1, 1, 3], [3, 3, 3]]}
+        ... )
+        >>> df.with_columns(
+        ...     intersection=pl.col("a").list.set_intersection("b", multiset=True)
+        ... )
+        shape: (2, 3)
+        ┌───────────┬───────────┬──────────────┐
+        │ a         ┆ b         ┆ intersection │
+        │ ---       ┆ ---       ┆ ---          │
+        │ list[i64] ┆ list[i64] ┆ list[i64]    │
+        ╞═══════════╪═══════════╪══════════════╡
+        │ [1, 1, 2] ┆ [1, 1, 3] ┆ [1, 1]       │
+        │ [3, 2, 3] ┆ [3, 3, 3] ┆ [3, 3]       │
+        └───────────┴───────────┴──────────────┘
         """  # noqa: W505
         if isinstance(other, Collection) and not isinstance(other, str):
             if not isinstance(other, (Sequence, pl.Series, pl.DataFrame)):
@@ -1485,9 +2055,13 @@ class ExprListNameSpace:
             other_pyexpr = F.lit(other)._pyexpr
         else:
             other_pyexpr = parse_into_expression(other)
-        return wrap_expr(self._pyexpr.list_set_operation(other_pyexpr, "intersection"))
+        return wrap_expr(
+            self._pyexpr.list_set_operation(other_pyexpr, "intersection", multiset)
+        )
 
-    def set_symmetric_difference(self, other: IntoExpr | Collection[Any]) -> Expr:
+    def set_symmetric_difference(
+        self, other: IntoExpr | Collection[Any], *, multiset: bool = False
+    ) -> Expr:
         """
         Compute the SET SYMMETRIC DIFFERENCE between the elements in this list and the elements of `other`.
 
@@ -1495,6 +2069,10 @@ class ExprListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Treat the lists as multisets: duplicates are kept and every occurrence
+            is matched against a single occurrence in `other`. The output follows
+            the order of this list.
 
         Examples
         --------
@@ -1524,5 +2102,7 @@ class ExprListNameSpace:
         else:
             other_pyexpr = parse_into_expression(other)
         return wrap_expr(
-            self._pyexpr.list_set_operation(other_pyexpr, "symmetric_difference")
+            self._pyexpr.list_set_operation(
+                other_pyexpr, "symmetric_difference", multiset
+            )
         )
@@ -16,11 +16,20 @@ from polars._utils.various import (
     qualified_type_name,
 )
 from polars._utils.wrap import wrap_expr
-from polars.datatypes import Date, Datetime, Int64, Time, parse_into_datatype_expr
+from polars.datatypes import (
+    Date,
+    Datetime,
+    Int64,
+    Struct,
+    Time,
+    parse_into_datatype_expr,
+)
 from polars.exceptions import ChronoFormatWarning
+from polars.expr.url import ExprUrlNameSpace
 
 if TYPE_CHECKING:
     import sys
+    from collections.abc import Iterable, Sequence
 
     from polars import Expr
     from polars._typing import (
@@ -31,6 +40,7 @@ if TYPE_CHECKING:
         PolarsIntegerType,
         PolarsTemporalType,
         TimeUnit,
+        TokenizeMethod,
         TransferEncoding,
         UnicodeForm,
     )
@@ -50,6 +60,15 @@ class ExprStringNameSpace:
     def __init__(self, expr: Expr) -> None:
         self._pyexpr = expr._pyexpr
 
+    @property
+    def url(self) -> ExprUrlNameSpace:
+        """
+        Create an object namespace of all URL related expressions.
+
+        See the individual method pages for full details.
+        """
+        return ExprUrlNameSpace(wrap_expr(self._pyexpr))
+
     def to_date(
         self,
         format: str | None = None,
@@ -97,7 +116,7 @@ class ExprStringNameSpace:
 
     def to_datetime(
         self,
-        format: str | None = None,
+        format: str | Sequence[str] | None = None,
         *,
         time_unit: TimeUnit | None = None,
         time_zone: str | None = None,
@@ -105,6 +124,8 @@ class ExprStringNameSpace:
         exact: bool = True,
         cache: bool = True,
         ambiguous: Ambiguous | Expr = "raise",
+        best_effort: bool = False,
+        include_format: bool = False,
     ) -> Expr:
         """
         Convert a String column into a Datetime column.
@@ -116,11 +137,14 @@ class ExprStringNameSpace:
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for the full specification. Example: `"%Y-%m-%d %H:%M:%S"`.
             If set to None (default), the format is inferred from the data.
+            A sequence of formats may also be given; these are tried in order for
+            every value, and the first format that parses the value is used.
         time_unit : {None, 'us', 'ns', 'ms'}
             Unit of time for the resulting Datetime column. If set to None (default),
             the time unit is inferred from the format string if given, eg:
             `"%F %T%.3f"` => `Datetime("ms")`. If no fractional second component is
-            found, the default is `"us"`.
+            found, the default is `"us"`. With several formats, the finest time unit
+            required by any of them is used.
         time_zone
             Time zone for the resulting Datetime column. Rules are:
 
@@ -151,6 +175,13 @@ class ExprStringNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+        best_effort
+            Infer the format of the values that could not be parsed with any of the
+            given formats (repeatedly, as the values may use several other formats).
+        include_format
+            Return a struct with a `value` field holding the parsed datetime, and a
+            `format` field recording which of the given formats parsed it (this is
+            null for values that were parsed by `best_effort` inference).
 
         Examples
         --------
@@ -162,18 +193,65 @@ class ExprStringNameSpace:
                 2020-01-01 01:00:00 UTC
                 2020-01-01 02:00:00 UTC
         ]
+
+        Parse values that mix several formats, inferring the format of any values
+        that none of the given formats can parse:
+
+        >>> s = pl.Series(
+        ...     ["2024-01-31 10:15", "31/01/2024 08:00", "2024-01-31T09:30:00"]
+        ... )
+        >>> s.str.to_datetime(
+        ...     ["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M"],
+        ...     best_effort=True,
+        ...     include_format=True,
+        ... ).struct.unnest()
+        shape: (3, 2)
+        ┌─────────────────────┬────────────────┐
+        │ value               ┆ format         │
+        │ ---                 ┆ ---            │
+        │ datetime[μs]        ┆ str            │
+        ╞═════════════════════╪════════════════╡
+        │ 2024-01-31 10:15:00 ┆ %Y-%m-%d %H:%M │
+        │ 2024-01-31 08:00:00 ┆ %d/%m/%Y %H:%M │
+        │ 2024-01-31 09:30:00 ┆ null           │
+        └─────────────────────┴────────────────┘
         """
-        _validate_format_argument(format)
         if not isinstance(ambiguous, pl.Expr):
             ambiguous = F.lit(ambiguous)
+        single_format = isinstance(format, str) or format is None
+        if single_format and not (best_effort or include_format):
+            _validate_format_argument(format)
+            return wrap_expr(
+                self._pyexpr.str_to_datetime(
+                    format,
+                    time_unit,
+                    time_zone,
+                    strict,
+                    exact,
+                    cache,
+                    ambiguous._pyexpr,
+                )
+            )
+
+        if format is None:
+            formats = []
+            best_effort = True
+        elif isinstance(format, str):
+            formats = [format]
+        else:
+            formats = list(format)
+        for fmt in formats:
+            _validate_format_argument(fmt)
         return wrap_expr(
-            self._pyexpr.str_to_datetime(
-                format,
+            self._pyexpr.str_to_datetime_multi(
+                formats,
                 time_unit,
                 time_zone,
                 strict,
                 exact,
                 cache,
+                best_effort,
+                include_format,
                 ambiguous._pyexpr,
             )
         )
@@ -504,6 +582,32 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_to_lowercase())
 
+    def casefold(self) -> Expr:
+        """
+        Apply full Unicode case folding to the strings.
+
+        Case folding is intended for caseless matching: unlike :meth:`to_lowercase`,
+        it also maps characters such as "ß" to "ss" and "ﬁ" to "fi", so that strings
+        which only differ by case compare as equal after folding. Combine with
+        :meth:`normalize` to also ignore differences in Unicode composition.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"word": ["Straße", "STRASSE", "ﬁle"]})
+        >>> df.with_columns(folded=pl.col("word").str.casefold())
+        shape: (3, 2)
+        ┌─────────┬─────────┐
+        │ word    ┆ folded  │
+        │ ---     ┆ ---     │
+        │ str     ┆ str     │
+        ╞═════════╪═════════╡
+        │ Straße  ┆ strasse │
+        │ STRASSE ┆ strasse │
+        │ ﬁle     ┆ file    │
+        └─────────┴─────────┘
+        """  # noqa: RUF002
+        return wrap_expr(self._pyexpr.str_casefold())
+
     def to_titlecase(self) -> Expr:
         """
         Modify strings to their titlecase equivalent.
@@ -1313,12 +1417,19 @@ class ExprStringNameSpace:
         dtype_expr = parse_into_datatype_expr(dtype)._pydatatype_expr
         return wrap_expr(self._pyexpr.str_json_decode(dtype_expr))
 
-    def json_path_match(self, json_path: IntoExprColumn) -> Expr:
+    def json_path_match(
+        self,
+        json_path: IntoExprColumn,
+        *,
+        all_matches: bool = False,
+        dtype: PolarsDataType | pl.DataTypeExpr | None = None,
+    ) -> Expr:
         """
         Extract the first match from a JSON string using the provided JSONPath.
 
-        Throws errors if invalid JSON strings are encountered. All return values
-        are cast to :class:`String`, regardless of the original value.
+        Throws errors if invalid JSON strings are encountered. Unless a `dtype` is
+        given, all return values are cast to :class:`String`, regardless of the
+        original value.
 
         Documentation on the JSONPath standard can be found
         `here <https://goessner.net/articles/JsonPath/>`_.
@@ -1327,12 +1438,17 @@ class ExprStringNameSpace:
         ----------
         json_path
             A valid JSONPath query string.
+        all_matches
+            Return all matches as a list instead of only the first match.
+        dtype
+            Decode the matched values as this data type.
 
         Returns
         -------
         Expr
-            Expression of data type :class:`String`. Contains null values if original
-            value is null or the json_path returns nothing.
+            Expression of data type :class:`String`, or `dtype` if given. Contains
+            null values if original value is null or the json_path returns nothing.
+            With `all_matches`, this is a :class:`List` of these values.
 
         Examples
         --------
@@ -1352,9 +1468,89 @@ class ExprStringNameSpace:
         │ {"a":2.1}  ┆ 2.1     │
         │ {"a":true} ┆ true    │
         └────────────┴─────────┘
+
+        Extract all matches as a list of integers:
+
+        >>> df = pl.DataFrame({"json_val": ['{"a":[1,2,3]}', '{"a":[]}', None]})
+        >>> df.with_columns(
+        ...     matched=pl.col("json_val").str.json_path_match(
+        ...         "$.a[*]", all_matches=True, dtype=pl.Int64
+        ...     )
+        ... )
+        shape: (3, 2)
+        ┌───────────────┬───────────┐
+        │ json_val      ┆ matched   │
+        │ ---           ┆ ---       │
+        │ str           ┆ list[i64] │
+        ╞═══════════════╪═══════════╡
+        │ {"a":[1,2,3]} ┆ [1, 2, 3] │
+        │ {"a":[]}      ┆ []        │
+        │ null          ┆ null      │
+        └───────────────┴───────────┘
         """
         json_path_pyexpr = parse_into_expression(json_path, str_as_lit=True)
-        return wrap_expr(self._pyexpr.str_json_path_match(json_path_pyexpr))
+        if not all_matches and dtype is None:
+            return wrap_expr(self._pyexpr.str_json_path_match(json_path_pyexpr))
+
+        dtype_expr = (
+            None if dtype is None else parse_into_datatype_expr(dtype)._pydatatype_expr
+        )
+        matches = wrap_expr(
+            self._pyexpr.str_json_path_match_all(json_path_pyexpr, dtype_expr)
+        )
+        return matches if all_matches else matches.list.first()
+
+    def json_extract_many(
+        self, paths: Sequence[str] | Mapping[str, PolarsDataType]
+    ) -> Expr:
+        """
+        Extract the first match of each of the given JSONPaths into a struct.
+
+        Every JSON string is parsed only once, which is faster than calling
+        :meth:`json_path_match` for each path.
+
+        Parameters
+        ----------
+        paths
+            The JSONPath query strings. The fields of the resulting struct are named
+            after them. If a mapping is given, the matches of every path are decoded
+            as the data type it maps to. Otherwise they are cast to :class:`String`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct`.
+
+        See Also
+        --------
+        json_path_match
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"json": ['{"a":1,"b":{"c":"x"}}', '{"a":2,"b":{"c":"y"}}']}
+        ... )
+        >>> df.select(
+        ...     pl.col("json")
+        ...     .str.json_extract_many({"$.a": pl.Int64, "$.b.c": pl.String})
+        ...     .struct.unnest()
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ $.a ┆ $.b.c │
+        │ --- ┆ ---   │
+        │ i64 ┆ str   │
+        ╞═════╪═══════╡
+        │ 1   ┆ x     │
+        │ 2   ┆ y     │
+        └─────┴───────┘
+        """
+        if isinstance(paths, Mapping):
+            dtype = parse_into_datatype_expr(Struct(paths))._pydatatype_expr
+            return wrap_expr(self._pyexpr.str_json_extract_many(list(paths), dtype))
+        paths = [paths] if isinstance(paths, str) else list(paths)
+        return wrap_expr(self._pyexpr.str_json_extract_many(paths, None))
+
 
     def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Expr:
         r"""
@@ -1362,8 +1558,10 @@ class ExprStringNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            Base64 that uses `-` and `_` instead of `+` and `/`. Base64 values are
+            accepted with or without `=` padding.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -1390,20 +1588,27 @@ class ExprStringNameSpace:
         """
         if encoding == "hex":
             return wrap_expr(self._pyexpr.str_hex_decode(strict))
-        elif encoding == "base64":
-            return wrap_expr(self._pyexpr.str_base64_decode(strict))
+        elif encoding in ("base64", "base64url"):
+            return wrap_expr(self._pyexpr.str_base64_decode(strict, encoding))
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base64url'}, "
+                f"got {encoding!r}"
+            )
             raise ValueError(msg)
 
-    def encode(self, encoding: TransferEncoding) -> Expr:
+    def encode(self, encoding: TransferEncoding, *, padding: bool = True) -> Expr:
         """
         Encode values using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            Base64 that uses `-` and `_` instead of `+` and `/`.
+        padding
+            Pad Base64 encoded values with `=` to a multiple of four characters.
+            Ignored for hex encoding.
 
         Returns
         -------
@@ -1427,12 +1632,90 @@ class ExprStringNameSpace:
         """
         if encoding == "hex":
             return wrap_expr(self._pyexpr.str_hex_encode())
-        elif encoding == "base64":
-            return wrap_expr(self._pyexpr.str_base64_encode())
+        elif encoding in ("base64", "base64url"):
+            return wrap_expr(self._pyexpr.str_base64_encode(encoding, padding))
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base64url'}, "
+                f"got {encoding!r}"
+            )
             raise ValueError(msg)
 
+    def parse_ip(self, *, strict: bool = True) -> Expr:
+        r"""
+        Parse IPv4 and IPv6 addresses.
+
+        The addresses are stored as 4 (IPv4) or 16 (IPv6) bytes in network byte order,
+        which is the input expected by the expressions of the :attr:`Expr.ip`
+        namespace.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a value is not a valid IP address, otherwise mask out
+            with a null value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["192.168.0.1", "172.16.0.1", "not an ip"]})
+        >>> df.with_columns(parsed=pl.col("ip").str.parse_ip(strict=False))
+        shape: (3, 2)
+        ┌─────────────┬─────────────────────┐
+        │ ip          ┆ parsed              │
+        │ ---         ┆ ---                 │
+        │ str         ┆ binary              │
+        ╞═════════════╪═════════════════════╡
+        │ 192.168.0.1 ┆ b"\xc0\xa8\x00\x01" │
+        │ 172.16.0.1  ┆ b"\xac\x10\x00\x01" │
+        │ not an ip   ┆ null                │
+        └─────────────┴─────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_parse_ip(strict))
+
+    def parse_uuid(self, *, strict: bool = True) -> Expr:
+        """
+        Parse UUIDs.
+
+        The UUIDs are stored as 16 bytes, which is more compact and faster to compare
+        than their string form. Besides the canonical hyphenated form, the simple,
+        braced and URN forms are accepted, in any case. Use :meth:`Expr.bin.to_uuid_str`
+        to convert back to the canonical form.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a value is not a valid UUID, otherwise mask out with a
+            null value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"id": ["67E55044-10B1-426F-9247-BB680E5FE0C8", "not a uuid", None]}
+        ... )
+        >>> df.select(pl.col("id").str.parse_uuid(strict=False).bin.encode("hex"))
+        shape: (3, 1)
+        ┌──────────────────────────────────┐
+        │ id                               │
+        │ ---                              │
+        │ str                              │
+        ╞══════════════════════════════════╡
+        │ 67e5504410b1426f9247bb680e5fe0c8 │
+        │ null                             │
+        │ null                             │
+        └──────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_parse_uuid(strict))
+
     def extract(self, pattern: IntoExprColumn, group_index: int = 1) -> Expr:
         r"""
         Extract the target capture group from provided patterns.
@@ -2000,6 +2283,89 @@ class ExprStringNameSpace:
         by_pyexpr = parse_into_expression(by, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_splitn(by_pyexpr, n))
 
+    def split_regex(
+        self,
+        pattern: str | Expr,
+        n: int | None = None,
+        *,
+        inclusive: bool = False,
+        strict: bool = True,
+    ) -> Expr:
+        r"""
+        Split the string by a regex pattern.
+
+        Unlike :meth:`splitn`, the result is always a list of strings, so values
+        with fewer than `n-1` matches produce shorter lists rather than nulls.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_. An empty pattern splits the
+            string into individual characters.
+        n
+            Max number of items to return. If the number of possible splits is `n-1`
+            or greater, the last (nth) item will contain the remainder of the string.
+            By default there is no limit.
+        inclusive
+            If True, keep each match at the end of the preceding item.
+        strict
+            Raise an error if the underlying pattern is not a valid regex,
+            otherwise mask out with a null value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(String)`.
+
+        See Also
+        --------
+        split : Split the string by a substring (or regex, with `literal=False`).
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": ["foo  bar baz", "one   two", None]})
+        >>> df.with_columns(
+        ...     words=pl.col("s").str.split_regex(r"\s+"),
+        ...     split_2=pl.col("s").str.split_regex(r"\s+", n=2),
+        ... )
+        shape: (3, 3)
+        ┌──────────────┬───────────────────────┬────────────────────┐
+        │ s            ┆ words                 ┆ split_2            │
+        │ ---          ┆ ---                   ┆ ---                │
+        │ str          ┆ list[str]             ┆ list[str]          │
+        ╞══════════════╪═══════════════════════╪════════════════════╡
+        │ foo  bar baz ┆ ["foo", "bar", "baz"] ┆ ["foo", "bar baz"] │
+        │ one   two    ┆ ["one", "two"]        ┆ ["one", "two"]     │
+        │ null         ┆ null                  ┆ null               │
+        └──────────────┴───────────────────────┴────────────────────┘
+
+        Keep the matched digits with the preceding items:
+
+        >>> df = pl.DataFrame({"s": ["a1b22c", "x", "7y8"]})
+        >>> df.with_columns(parts=pl.col("s").str.split_regex(r"\d+", inclusive=True))
+        shape: (3, 2)
+        ┌────────┬────────────────────┐
+        │ s      ┆ parts              │
+        │ ---    ┆ ---                │
+        │ str    ┆ list[str]          │
+        ╞════════╪════════════════════╡
+        │ a1b22c ┆ ["a1", "b22", "c"] │
+        │ x      ┆ ["x"]              │
+        │ 7y8    ┆ ["7", "y8"]        │
+        └────────┴────────────────────┘
+        """
+        pattern_pyexpr = parse_into_expression(pattern, str_as_lit=True)
+        if n is not None:
+            return wrap_expr(
+                self._pyexpr.str_splitn_regex(pattern_pyexpr, n, inclusive, strict)
+            )
+        if inclusive:
+            return wrap_expr(
+                self._pyexpr.str_split_regex_inclusive(pattern_pyexpr, strict)
+            )
+        return wrap_expr(self._pyexpr.str_split_regex(pattern_pyexpr, strict))
+
     def replace(
         self,
         pattern: str | Expr,
@@ -2266,6 +2632,221 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_reverse())
 
+    def levenshtein_distance(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Levenshtein (edit) distance to another string.
+
+        The distance is the minimum number of single-character insertions, deletions
+        and substitutions needed to change one string into the other.
+
+        Parameters
+        ----------
+        other
+            The string (or string expression) to compare against; comparisons
+            are made by character, and null values result in null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        hamming
+        jaro_winkler
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "martha", None],
+        ...         "b": ["sitting", "lawn", "marhta", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.levenshtein_distance(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────┐
+        │ a      ┆ b       ┆ dist │
+        │ ---    ┆ ---     ┆ ---  │
+        │ str    ┆ str     ┆ u32  │
+        ╞════════╪═════════╪══════╡
+        │ kitten ┆ sitting ┆ 3    │
+        │ flaw   ┆ lawn    ┆ 2    │
+        │ martha ┆ marhta  ┆ 2    │
+        │ null   ┆ x       ┆ null │
+        └────────┴─────────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein_distance(other_pyexpr))
+
+    def hamming(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Hamming distance to another string.
+
+        The distance is the number of positions at which the characters differ;
+        strings with a different number of characters result in null.
+
+        Parameters
+        ----------
+        other
+            The string (or string expression) to compare against; comparisons
+            are made by character, and null values result in null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        levenshtein_distance
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "martha", None],
+        ...         "b": ["sitting", "lawn", "marhta", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.hamming(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────┐
+        │ a      ┆ b       ┆ dist │
+        │ ---    ┆ ---     ┆ ---  │
+        │ str    ┆ str     ┆ u32  │
+        ╞════════╪═════════╪══════╡
+        │ kitten ┆ sitting ┆ null │
+        │ flaw   ┆ lawn    ┆ 4    │
+        │ martha ┆ marhta  ┆ 2    │
+        │ null   ┆ x       ┆ null │
+        └────────┴─────────┴──────┘
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other_pyexpr))
+
+    def jaro_winkler(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity ranges from 0 (no similarity) to 1 (exact match), with
+        strings that share a common prefix (of up to 4 characters) scoring higher.
+
+        Parameters
+        ----------
+        other
+            The string (or string expression) to compare against; comparisons
+            are made by character, and null values result in null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        See Also
+        --------
+        levenshtein_distance
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "martha", None],
+        ...         "b": ["sitting", "lawn", "marhta", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(similarity=pl.col("a").str.jaro_winkler(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬────────────┐
+        │ a      ┆ b       ┆ similarity │
+        │ ---    ┆ ---     ┆ ---        │
+        │ str    ┆ str     ┆ f64        │
+        ╞════════╪═════════╪════════════╡
+        │ kitten ┆ sitting ┆ 0.746032   │
+        │ flaw   ┆ lawn    ┆ 0.833333   │
+        │ martha ┆ marhta  ┆ 0.961111   │
+        │ null   ┆ x       ┆ null       │
+        └────────┴─────────┴────────────┘
+        """
+        other_pyexpr = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other_pyexpr))
+
+    def tokenize(
+        self,
+        method: TokenizeMethod = "unicode_words",
+        *,
+        pattern: str | None = None,
+        lowercase: bool = False,
+        stop_words: Iterable[str] | None = None,
+    ) -> Expr:
+        """
+        Split each string into a list of tokens.
+
+        Parameters
+        ----------
+        method : {'unicode_words', 'whitespace', 'pattern'}
+            How to split the strings into tokens:
+
+            - `'unicode_words'` (default): words as delimited by the Unicode word
+              boundary rules (UAX #29); punctuation and whitespace are skipped.
+            - `'whitespace'`: runs of non-whitespace characters.
+            - `'pattern'`: every (non-overlapping) match of the regular expression
+              given by `pattern`.
+        pattern
+            The regular expression to match tokens with; must be given if and only
+            if `method` is `'pattern'`.
+        lowercase
+            Lowercase the strings before tokenizing them.
+        stop_words
+            Tokens to remove from the output; these are compared after lowercasing,
+            so should be given in lowercase if `lowercase` is set.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List(String)`.
+
+        See Also
+        --------
+        split
+        extract_all
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["The cat sat.", "Cats can't FLY!", None]})
+        >>> df.with_columns(
+        ...     tokens=pl.col("text").str.tokenize(lowercase=True, stop_words=["the"])
+        ... )
+        shape: (3, 2)
+        ┌─────────────────┬──────────────────────────┐
+        │ text            ┆ tokens                   │
+        │ ---             ┆ ---                      │
+        │ str             ┆ list[str]                │
+        ╞═════════════════╪══════════════════════════╡
+        │ The cat sat.    ┆ ["cat", "sat"]           │
+        │ Cats can't FLY! ┆ ["cats", "can't", "fly"] │
+        │ null            ┆ null                     │
+        └─────────────────┴──────────────────────────┘
+
+        Split on whitespace only, keeping punctuation attached to the tokens:
+
+        >>> df.select(pl.col("text").str.tokenize("whitespace"))
+        shape: (3, 1)
+        ┌───────────────────────────┐
+        │ text                      │
+        │ ---                       │
+        │ list[str]                 │
+        ╞═══════════════════════════╡
+        │ ["The", "cat", "sat."]    │
+        │ ["Cats", "can't", "FLY!"] │
+        │ null                      │
+        └───────────────────────────┘
+        """
+        stop_words = [] if stop_words is None else list(stop_words)
+        return wrap_expr(
+            self._pyexpr.str_tokenize(method, pattern, lowercase, stop_words)
+        )
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Expr:
@@ -38,7 +38,9 @@ class ExprStructNameSpace:
         Parameters
         ----------
         name
-            Name of the struct field to retrieve.
+            Name of the struct field to retrieve. A dotted path like `"a.b"`
+            retrieves field `b` of the struct field `a`, unless there is a field
+            named `"a.b"`.
         *more_names
             Additional struct field names.
 
@@ -129,6 +131,20 @@ class ExprStructNameSpace:
         │ 2   ┆ cd  │
         └─────┴─────┘
 
+        Retrieve a field of a nested struct with a dotted path:
+
+        >>> df = pl.DataFrame({"a": [{"b": {"c": 1}}, {"b": {"c": 2}}]})
+        >>> df.select(pl.col("a").struct.field("b.c"))
+        shape: (2, 1)
+        ┌─────┐
+        │ c   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 2   │
+        └─────┘
+
         Notes
         -----
         The `struct` namespace has implemented `__getitem__`
@@ -152,11 +168,30 @@ class ExprStructNameSpace:
 
         return wrap_expr(self._pyexpr.struct_field_by_name(name))
 
-    def unnest(self) -> Expr:
+    def unnest(
+        self,
+        fields: str | Sequence[str] | None = None,
+        *,
+        prefix: str | None = None,
+        separator: str | None = None,
+    ) -> Expr:
         """
         Expand the struct into its individual fields.
 
-        Alias for `Expr.struct.field("*")`.
+        Without arguments, this is an alias for `Expr.struct.field("*")`.
+
+        Parameters
+        ----------
+        fields
+            Only expand these fields. By default all fields are expanded.
+        prefix
+            Prefix the names of the fields with this. Defaults to the name of the
+            struct if `separator` is set.
+        separator
+            Place this between the prefix and the field name.
+
+        Examples
+        --------
 
         >>> df = pl.DataFrame(
         ...     {
@@ -186,8 +221,30 @@ class ExprStructNameSpace:
         │ 1   ┆ ab  ┆ true ┆ [1, 2]    │
         │ 2   ┆ cd  ┆ null ┆ [3]       │
         └─────┴─────┴──────┴───────────┘
+
+        Only expand some of the fields and prefix their names:
+
+        >>> df.select(pl.col("struct_col").struct.unnest(["aaa", "ccc"], prefix="s_"))
+        shape: (2, 2)
+        ┌───────┬───────┐
+        │ s_aaa ┆ s_ccc │
+        │ ---   ┆ ---   │
+        │ i64   ┆ bool  │
+        ╞═══════╪═══════╡
+        │ 1     ┆ true  │
+        │ 2     ┆ null  │
+        └───────┴───────┘
         """
-        return self.field("*")
+        if fields is None:
+            expr = self.field("*")
+        else:
+            expr = self.field([fields] if isinstance(fields, str) else list(fields))
+
+        if prefix is None and separator is not None:
+            prefix = wrap_expr(self._pyexpr).meta.output_name()
+        if prefix is not None:
+            expr = expr.name.prefix(f"{prefix}{separator or ''}")
+        return expr
 
     def rename_fields(self, names: Sequence[str]) -> Expr:
         """
@@ -334,6 +391,21 @@ class ExprStructNameSpace:
         │ 3.0 ┆ 16  ┆ 48    ┆ 3        │
         └─────┴─────┴───────┴──────────┘
 
+        Fields of nested structs can be addressed with a dotted path:
+
+        >>> df = pl.DataFrame({"s": [{"meta": {"tags": ["a", "b"]}}]})
+        >>> df.select(
+        ...     pl.col("s").struct.with_fields(pl.field("meta.tags").list.len())
+        ... ).unnest("s").unnest("meta")
+        shape: (1, 1)
+        ┌──────┐
+        │ tags │
+        │ ---  │
+        │ u32  │
+        ╞══════╡
+        │ 2    │
+        └──────┘
+
         Parameters
         ----------
         *exprs
@@ -344,6 +416,11 @@ class ExprStructNameSpace:
             Additional fields to add, specified as keyword arguments.
             The columns will be renamed to the keyword used.
 
+        Notes
+        -----
+        A field whose name is a dotted path like `"a.b"` updates (or adds) field
+        `b` in the struct field `a`, unless the struct has a field named `"a.b"`.
+
         See Also
         --------
         field
@@ -0,0 +1,219 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import Expr
+
+
+class ExprUrlNameSpace:
+    """
+    Namespace for URL related expressions.
+
+    Only absolute URLs, such as `"https://pola.rs/posts/"`, are parsed. Relative
+    references and other values that are not valid URLs give null.
+    """
+
+    _accessor = "str.url"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def scheme(self) -> Expr:
+        """
+        Extract the scheme of the URLs.
+
+        The scheme is normalized to lowercase.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pola.rs/posts/",
+        ...             "http://LOCALHOST:8080/api",
+        ...             "/relative/path",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(scheme=pl.col("url").str.url.scheme())
+        shape: (3, 2)
+        ┌───────────────────────────┬────────┐
+        │ url                       ┆ scheme │
+        │ ---                       ┆ ---    │
+        │ str                       ┆ str    │
+        ╞═══════════════════════════╪════════╡
+        │ https://pola.rs/posts/    ┆ https  │
+        │ http://LOCALHOST:8080/api ┆ http   │
+        │ /relative/path            ┆ null   │
+        └───────────────────────────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_part("scheme"))
+
+    def host(self) -> Expr:
+        """
+        Extract the host of the URLs.
+
+        The host is the domain name or IP address, without the port. Domain names of
+        special schemes such as `http` are normalized to lowercase. URLs without a host,
+        such as `"mailto:someone@pola.rs"`, give null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pola.rs/posts/",
+        ...             "http://LOCALHOST:8080/api",
+        ...             "/relative/path",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(host=pl.col("url").str.url.host())
+        shape: (3, 2)
+        ┌───────────────────────────┬───────────┐
+        │ url                       ┆ host      │
+        │ ---                       ┆ ---       │
+        │ str                       ┆ str       │
+        ╞═══════════════════════════╪═══════════╡
+        │ https://pola.rs/posts/    ┆ pola.rs   │
+        │ http://LOCALHOST:8080/api ┆ localhost │
+        │ /relative/path            ┆ null      │
+        └───────────────────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_part("host"))
+
+    def path(self) -> Expr:
+        """
+        Extract the path of the URLs.
+
+        The path is returned percent-encoded, use :meth:`decode_percent` to decode it.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pola.rs/posts/",
+        ...             "http://LOCALHOST:8080/api",
+        ...             "/relative/path",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(path=pl.col("url").str.url.path())
+        shape: (3, 2)
+        ┌───────────────────────────┬─────────┐
+        │ url                       ┆ path    │
+        │ ---                       ┆ ---     │
+        │ str                       ┆ str     │
+        ╞═══════════════════════════╪═════════╡
+        │ https://pola.rs/posts/    ┆ /posts/ │
+        │ http://LOCALHOST:8080/api ┆ /api    │
+        │ /relative/path            ┆ null    │
+        └───────────────────────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_part("path"))
+
+    def query_params(self, keys: str | Sequence[str] | None = None) -> Expr:
+        """
+        Decode the query parameters of the URLs.
+
+        Keys and values are percent-decoded and `+` is decoded as a space.
+
+        Parameters
+        ----------
+        keys
+            The keys to extract. If given, the result is a struct with a field per key
+            holding the first value of that key, or null if the key does not occur.
+            Otherwise the result is a list of `{key, value}` structs holding all
+            parameters in order of appearance.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` if `keys` is given, otherwise of
+            data type :class:`List` of :class:`Struct`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"url": ["https://a.com/?q=polars&page=2", "https://a.com/?q=a+b"]}
+        ... )
+        >>> df.select(pl.col("url").str.url.query_params(["q", "page"])).unnest("url")
+        shape: (2, 2)
+        ┌────────┬──────┐
+        │ q      ┆ page │
+        │ ---    ┆ ---  │
+        │ str    ┆ str  │
+        ╞════════╪══════╡
+        │ polars ┆ 2    │
+        │ a b    ┆ null │
+        └────────┴──────┘
+
+        Without `keys`, all parameters are returned.
+
+        >>> df.select(pl.col("url").str.url.query_params())
+        shape: (2, 1)
+        ┌────────────────────────────────┐
+        │ url                            │
+        │ ---                            │
+        │ list[struct[2]]                │
+        ╞════════════════════════════════╡
+        │ [{"q","polars"}, {"page","2"}] │
+        │ [{"q","a b"}]                  │
+        └────────────────────────────────┘
+        """
+        if isinstance(keys, str):
+            keys = [keys]
+        elif keys is not None:
+            keys = list(keys)
+        return wrap_expr(self._pyexpr.str_url_query_params(keys))
+
+    def decode_percent(self) -> Expr:
+        """
+        Decode the `%XX` escapes of the values.
+
+        The values do not need to be URLs, which makes this suitable for decoding the
+        result of :meth:`path`. Unlike :meth:`query_params`, `+` is left as is. Values
+        that do not decode to valid UTF-8 give null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"encoded": ["caf%C3%A9", "a%20b+c", "%FF"]})
+        >>> df.with_columns(decoded=pl.col("encoded").str.url.decode_percent())
+        shape: (3, 2)
+        ┌───────────┬─────────┐
+        │ encoded   ┆ decoded │
+        │ ---       ┆ ---     │
+        │ str       ┆ str     │
+        ╞═══════════╪═════════╡
+        │ caf%C3%A9 ┆ café    │
+        │ a%20b+c   ┆ a b+c   │
+        │ %FF       ┆ null    │
+        └───────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_decode_percent())
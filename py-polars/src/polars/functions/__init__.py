@@ -64,9 +64,11 @@ from polars.functions.lazy import (
     nth,
     quantile,
     reduce,
+    register_aggregation,
     rolling_corr,
     rolling_cov,
     row_index,
+    search_sorted_by,
     select,
     sql_expr,
     std,
@@ -75,7 +77,7 @@ from polars.functions.lazy import (
 )
 from polars.functions.len import len
 from polars.functions.lit import lit
-from polars.functions.random import set_random_seed
+from polars.functions.random import set_random_seed, uuid4
 from polars.functions.range import (
     arange,
     date_range,
@@ -174,11 +176,14 @@ __all__ = [
     "nth",
     "quantile",
     "reduce",
+    "register_aggregation",
     "rolling_corr",
     "rolling_cov",
     "row_index",
+    "search_sorted_by",
     "select",
     "set_random_seed",
+    "uuid4",
     "std",
     "struct",
     "tail",
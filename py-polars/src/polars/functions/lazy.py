@@ -24,7 +24,7 @@ from polars._utils.unstable import issue_unstable_warning, unstable
 from polars._utils.various import extend_bool, qualified_type_name
 from polars._utils.wrap import wrap_df, wrap_expr, wrap_s
 from polars.datatypes import DTYPE_TEMPORAL_UNITS, Date, Datetime
-from polars.datatypes._parse import parse_into_datatype_expr
+from polars.datatypes._parse import parse_into_datatype_expr, parse_into_dtype
 from polars.lazyframe.opt_flags import (
     DEFAULT_QUERY_OPT_FLAGS,
     forward_old_opt_flags,
@@ -47,6 +47,7 @@ if TYPE_CHECKING:
         IntoExpr,
         PolarsDataType,
         QuantileMethod,
+        SearchSortedSide,
     )
     from polars.lazyframe.opt_flags import (
         QueryOptFlags,
@@ -1313,6 +1314,90 @@ def map_groups(
     )
 
 
+@unstable()
+def register_aggregation(
+    name: str,
+    *,
+    init: Callable[[], Any],
+    update: Callable[[Any, Series], Any],
+    merge: Callable[[Any, Any], Any],
+    finalize: Callable[[Any], Any],
+    return_dtype: PolarsDataType,
+) -> Callable[[IntoExpr], Expr]:
+    """
+    Register a custom aggregation that keeps a Python state per group.
+
+    Unlike :func:`map_groups`, the values of a group are never materialized at once.
+    The engine instead folds batches of values into the state of their group, and
+    merges the states of a group that were computed in parallel.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        Name of the aggregation, used when displaying the query plan.
+    init
+        Function that returns the state of a group without any values.
+    update
+        Function that folds a Series of values of a group into its state, and
+        returns the new state.
+    merge
+        Function that combines two states of the same group, and returns the
+        combined state.
+    finalize
+        Function that returns the result of a group from its state.
+    return_dtype
+        Data type of the result.
+
+    Returns
+    -------
+    Callable
+        Function that applies the aggregation to an expression.
+
+    Notes
+    -----
+    The functions must not depend on the order in which values are passed to
+    `update`, nor on how they are split over states that are merged.
+
+    Examples
+    --------
+    >>> product = pl.register_aggregation(
+    ...     "product",
+    ...     init=lambda: 1,
+    ...     update=lambda state, s: state * s.product(),
+    ...     merge=lambda a, b: a * b,
+    ...     finalize=lambda state: state,
+    ...     return_dtype=pl.Int64,
+    ... )
+    >>> df = pl.DataFrame({"g": [1, 1, 2, 2, 2], "x": [1, 2, 3, 4, 5]})
+    >>> df.group_by("g").agg(product("x")).sort("g")
+    shape: (2, 2)
+    ┌─────┬─────┐
+    │ g   ┆ x   │
+    │ --- ┆ --- │
+    │ i64 ┆ i64 │
+    ╞═════╪═════╡
+    │ 1   ┆ 2   │
+    │ 2   ┆ 60  │
+    └─────┴─────┘
+    """
+    dtype = parse_into_dtype(return_dtype)
+
+    def _update(state: Any, pys: plr.PySeries) -> Any:
+        return update(state, wrap_s(pys))
+
+    def aggregation(expr: IntoExpr) -> Expr:
+        pyexpr = parse_into_expression(expr)
+        return wrap_expr(
+            plr.python_aggregation(pyexpr, init, _update, merge, finalize, dtype, name)
+        )
+
+    return aggregation
+
+
 def _row_encode(
     exprs: pl.Selector | pl.Expr | Sequence[str | pl.Expr],
     *,
@@ -2013,6 +2098,72 @@ def arg_sort_by(
     )
 
 
+def search_sorted_by(
+    by: IntoExpr | Iterable[IntoExpr],
+    values: IntoExpr | Iterable[IntoExpr],
+    *,
+    side: SearchSortedSide = "any",
+    descending: bool | Sequence[bool] = False,
+    nulls_last: bool | Sequence[bool] = False,
+) -> Expr:
+    """
+    Find the indices where rows should be inserted into sorted columns to keep order.
+
+    The rows are compared lexicographically: by the first column, then by the second
+    column for equal values of the first column, and so on. This allows searching on
+    compound keys, such as a symbol and a timestamp.
+
+    Parameters
+    ----------
+    by
+        Column(s) that are sorted, e.g. with :meth:`DataFrame.sort` using the same
+        `descending` and `nulls_last` arguments. Accepts expression input. Strings
+        are parsed as column names.
+    values
+        Column(s) of the rows to search, one for every column in `by`. Accepts
+        expression input. Strings are parsed as column names.
+    side : {'any', 'left', 'right'}
+        If 'any', the index of the first suitable location found is given.
+        If 'left', the index of the leftmost suitable location found is given.
+        If 'right', return the rightmost suitable location found is given.
+    descending
+        Whether the `by` columns are sorted in descending order. Can be specified
+        per column by passing a sequence of booleans.
+    nulls_last
+        Whether the null values of the `by` columns are placed last. Can be specified
+        per column by passing a sequence of booleans.
+
+    See Also
+    --------
+    Expr.search_sorted
+    DataFrame.search_sorted
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"symbol": ["a", "a", "b", "b"], "ts": [1, 3, 2, 4]})
+    >>> keys = pl.DataFrame({"symbol": ["a", "b"], "ts": [2, 5]})
+    >>> df.select(
+    ...     pl.search_sorted_by(
+    ...         ["symbol", "ts"], [pl.lit(keys["symbol"]), pl.lit(keys["ts"])]
+    ...     )
+    ... )
+    shape: (2, 1)
+    ┌────────┐
+    │ symbol │
+    │ ---    │
+    │ u32    │
+    ╞════════╡
+    │ 1      │
+    │ 4      │
+    └────────┘
+    """
+    by = parse_into_list_of_expressions(by)
+    values = parse_into_list_of_expressions(values)
+    descending = extend_bool(descending, len(by), "descending", "by")
+    nulls_last = extend_bool(nulls_last, len(by), "nulls_last", "by")
+    return wrap_expr(plr.search_sorted_by(by, values, side, descending, nulls_last))
+
+
 @overload
 def collect_all(
     lazy_frames: Iterable[LazyFrame],
@@ -1,10 +1,16 @@
 from __future__ import annotations
 
 import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     import polars._plr as plr
 
+if TYPE_CHECKING:
+    from polars import Expr
+
 
 def set_random_seed(seed: int) -> None:
     r"""
@@ -20,3 +26,41 @@ def set_random_seed(seed: int) -> None:
         random number generator.
     """
     plr.set_random_seed(seed)
+
+
+def uuid4(*, seed: int | None = None) -> Expr:
+    """
+    Generate a random (version 4) UUID for every row.
+
+    The UUIDs are returned in their canonical lowercase hyphenated form, use
+    :meth:`Expr.str.parse_uuid` to store them as 16 bytes instead.
+
+    Parameters
+    ----------
+    seed
+        Seed for the random number generator. If set to None (default), the
+        global random number generator is used, see :func:`set_random_seed`.
+        The UUID of a row is derived from the seed and the position of the row,
+        so rows in different groups never share a UUID.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`String`.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, 3]})
+    >>> df.with_columns(pl.uuid4(seed=0))  # doctest: +IGNORE_RESULT
+    shape: (3, 2)
+    ┌─────┬───────────────────────────────────┐
+    │ a   ┆ uuid                              │
+    │ --- ┆ ---                               │
+    │ i64 ┆ str                               │
+    ╞═════╪═══════════════════════════════════╡
+    │ 1   ┆ 5a8e9c1b-3f2d-4b7a-9e6c-0d41f7a2… │
+    │ 2   ┆ c03b7f5e-91a4-4d6e-8b2f-7e1c5a9d… │
+    │ 3   ┆ 1e6d2a4f-b8c7-4f03-a5d9-3c8e0b6f… │
+    └─────┴───────────────────────────────────┘
+    """
+    return wrap_expr(plr.uuid4(seed))
@@ -2,7 +2,8 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
-from polars.datatypes import Boolean, Categorical, Enum, String
+from polars.datatypes import Boolean, Categorical, Enum
+from polars.exceptions import ComputeError
 from polars.interchange.buffer import PolarsBuffer
 from polars.interchange.protocol import (
     Column,
@@ -150,13 +151,11 @@ class PolarsColumn(Column):
 
     def get_buffers(self) -> ColumnBuffers:
         """Return a dictionary containing the underlying buffers."""
-        dtype = self._col.dtype
-
-        if dtype == String and not self._allow_copy:
+        try:
+            buffers = self._col._get_buffers(allow_copy=self._allow_copy)
+        except ComputeError:
             msg = "string buffers must be converted"
-            raise CopyNotAllowedError(msg)
-
-        buffers = self._col._get_buffers()
+            raise CopyNotAllowedError(msg) from None
 
         return {
             "data": self._wrap_data_buffer(buffers["values"]),
@@ -1,9 +1,11 @@
 from __future__ import annotations
 
+import itertools
 import os
 import sys
 from collections.abc import Callable, Iterator
-from typing import TYPE_CHECKING
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any
 
 import polars._reexport as pl
 from polars._utils.unstable import unstable
@@ -15,15 +17,51 @@ if TYPE_CHECKING:
     from polars._typing import SchemaDict
 
 
+@dataclass
+class IOSourcePushdown:
+    """
+    The pushdowns that Polars offers to an IO source.
+
+    An IO source registered with `pushdown_info=True` receives this object. Each
+    pushdown is optional: set the corresponding `applied_*` attribute to `True`
+    for the ones the source applied, and Polars applies the others to the
+    produced DataFrames.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+
+    #: Columns that are projected, or `None` if all columns are needed.
+    with_columns: list[str] | None
+    #: Predicate to filter the rows by, or `None`.
+    predicate: Expr | None
+    #: The predicate in the format of :meth:`Expr.serialize`, e.g. to forward it
+    #: to another process. `None` if there is no predicate.
+    serialized_predicate: bytes | None
+    #: The number of rows to materialize, or `None` to read all rows.
+    n_rows: int | None
+    #: A hint of the ideal number of rows per produced DataFrame.
+    batch_size: int | None
+    #: Whether the source only produces the columns in `with_columns`.
+    applied_projection: bool = False
+    #: Whether the source only produces the rows matching `predicate`.
+    applied_predicate: bool = False
+    #: Whether the source produces at most `n_rows` rows.
+    applied_n_rows: bool = False
+
+
 @unstable()
 def register_io_source(
     io_source: Callable[
         [list[str] | None, Expr | None, int | None, int | None], Iterator[DataFrame]
-    ],
+    ]
+    | Callable[[IOSourcePushdown], Iterator[DataFrame]],
     *,
     schema: Callable[[], SchemaDict] | SchemaDict,
     validate_schema: bool = False,
     is_pure: bool = False,
+    pushdown_info: bool = False,
 ) -> LazyFrame:
     """
     Register your IO plugin and initialize a LazyFrame.
@@ -55,6 +93,9 @@ def register_io_source(
 
         The function should return a an iterator/generator
         that produces DataFrames.
+
+        If `pushdown_info` is set, the function instead accepts a single
+        :class:`IOSourcePushdown` argument.
     schema
         Schema or function that when called produces the schema that the reader
         will produce before projection pushdown.
@@ -66,10 +107,40 @@ def register_io_source(
         Whether the IO source is pure. Repeated occurrences of same IO source in
         a LazyFrame plan can be de-duplicated during optimization if they are
         pure.
+    pushdown_info
+        Pass the pushdowns as an :class:`IOSourcePushdown` to `io_source`, which
+        then declares on that object which of them it applied. The others are
+        applied by Polars, so the source is free to ignore them.
+
+        The declarations are read once the source produced its first DataFrame
+        (or finished without producing any).
 
     Returns
     -------
     LazyFrame
+
+    Examples
+    --------
+    >>> from polars.io.plugins import IOSourcePushdown, register_io_source
+    >>> def source(pushdown):
+    ...     df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    ...     if pushdown.with_columns is not None:
+    ...         df = df.select(pushdown.with_columns)
+    ...         pushdown.applied_projection = True
+    ...     yield df
+    >>> lf = register_io_source(
+    ...     source, schema={"a": pl.Int64, "b": pl.Int64}, pushdown_info=True
+    ... )
+    >>> lf.select("b").head(2).collect()
+    shape: (2, 1)
+    ┌─────┐
+    │ b   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 4   │
+    │ 5   │
+    └─────┘
     """
 
     def wrap(
@@ -77,7 +148,7 @@ def register_io_source(
         predicate: bytes | None,
         n_rows: int | None,
         batch_size: int | None,
-    ) -> tuple[Iterator[DataFrame], bool]:
+    ) -> tuple[Iterator[DataFrame], bool, bool, bool]:
         parsed_predicate_success = True
         parsed_predicate = None
         if predicate:
@@ -91,9 +162,32 @@ def register_io_source(
                     )
                 parsed_predicate_success = False
 
-        return io_source(
-            with_columns, parsed_predicate, n_rows, batch_size
-        ), parsed_predicate_success
+        if not pushdown_info:
+            batches = io_source(  # type: ignore[call-arg]
+                with_columns, parsed_predicate, n_rows, batch_size
+            )
+            return batches, parsed_predicate_success, True, True
+
+        pushdown = IOSourcePushdown(
+            with_columns=with_columns,
+            predicate=parsed_predicate,
+            serialized_predicate=predicate if parsed_predicate_success else None,
+            n_rows=n_rows,
+            batch_size=batch_size,
+        )
+        batches = iter(io_source(pushdown))  # type: ignore[call-arg]
+        # Run the source up to its first batch, so that a generator has declared
+        # the pushdowns it applies.
+        first: Any = next(batches, None)
+        if first is not None:
+            batches = itertools.chain([first], batches)
+
+        return (
+            batches,
+            parsed_predicate_success and pushdown.applied_predicate,
+            pushdown.applied_projection,
+            pushdown.applied_n_rows,
+        )
 
     return pl.LazyFrame._scan_python_function(
         schema=schema,
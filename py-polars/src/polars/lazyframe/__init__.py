@@ -1,9 +1,17 @@
 from polars.lazyframe.engine_config import GPUEngine
 from polars.lazyframe.frame import LazyFrame
+from polars.lazyframe.metrics import (
+    OperatorMetrics,
+    QueryMetrics,
+    set_streaming_metrics_callback,
+)
 from polars.lazyframe.opt_flags import QueryOptFlags
 
 __all__ = [
     "GPUEngine",
     "LazyFrame",
+    "OperatorMetrics",
+    "QueryMetrics",
     "QueryOptFlags",
+    "set_streaming_metrics_callback",
 ]
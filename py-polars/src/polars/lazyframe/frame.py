@@ -69,6 +69,7 @@ from polars.datatypes import (
     N_INFER_DEFAULT,
     Boolean,
     Categorical,
+    Categories,
     Date,
     Datetime,
     Duration,
@@ -120,9 +121,10 @@ if TYPE_CHECKING:
     import polars.io.iceberg
     from polars.io.partition import PartitionBy
     from polars.lazyframe.opt_flags import QueryOptFlags
+    from polars.string_cache import StringCache
 
     with contextlib.suppress(ImportError):  # Module not available when building docs
-        from polars._plr import PyExpr, PySelector
+        from polars._plr import PyExpr, PySelector, QueryPlanNode
 
     with contextlib.suppress(ImportError):  # Module not available when building docs
         import polars._plr as plr
@@ -132,6 +134,7 @@ if TYPE_CHECKING:
     from polars._typing import (
         Alignment,
         ArrowSchemaExportable,
+        ArrowStreamExportable,
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
@@ -162,6 +165,7 @@ if TYPE_CHECKING:
         StorageOptionsDict,
         SyncOnCloseMethod,
         UniqueKeepStrategy,
+        UnnestCollision,
     )
     from polars.config import TableFormatNames
     from polars.io.cloud import CredentialProviderFunction
@@ -1430,6 +1434,53 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         else:
             return self._ldf.describe_plan()
 
+    @unstable()
+    def query_plan(
+        self,
+        *,
+        optimized: bool = True,
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> QueryPlanNode:
+        """
+        Return the query plan as a tree of nodes.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This is the structured counterpart of :meth:`explain`. Every node has:
+
+        - `kind`: the type of the node (e.g. `"filter"`, `"join"`, `"df"`).
+        - `schema`: the output schema of the node as a `{name: dtype}` dict.
+        - `options`: node-specific options as a dict (e.g. `how` for joins).
+        - `expressions`: the expressions of the node, in the notation of
+          :meth:`explain`.
+        - `children`: the input nodes.
+
+        Parameters
+        ----------
+        optimized
+            Return the optimized query plan. Defaults to `True`.
+        optimizations
+            The optimization passes done during query optimization.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        >>> plan = lf.filter(pl.col("a") > 1).query_plan(optimized=False)
+        >>> plan.kind
+        'filter'
+        >>> plan.expressions
+        ['[(col("a")) > (1)]']
+        >>> [child.kind for child in plan.children]
+        ['df']
+        """
+        if optimized:
+            ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        else:
+            ldf = self._ldf
+        return ldf.query_plan(optimized)
+
     @deprecate_streaming_parameter()
     @forward_old_opt_flags()
     def show_graph(
@@ -2457,7 +2508,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
         if background:
             issue_unstable_warning("background mode is considered unstable.")
-            return InProcessQuery(ldf.collect_concurrently())
+            return InProcessQuery(ldf.collect_concurrently(engine))
 
         # Only for testing purposes
         callback = _kwargs.get("post_opt_callback", callback)
@@ -4301,6 +4352,89 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return CollectBatches(inner)
 
+    @unstable()
+    def collect_arrow_stream(
+        self,
+        *,
+        chunk_size: int | None = None,
+        maintain_order: bool = True,
+        compat_level: CompatLevel | None = None,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> ArrowStreamExportable:
+        """
+        Evaluate the query in streaming mode and get the result as an Arrow stream.
+
+        The query starts executing immediately. The returned object implements the
+        Arrow PyCapsule interface (`__arrow_c_stream__`), so that consumers such as
+        `pyarrow.RecordBatchReader.from_stream` or DuckDB can read the record batches
+        as they are produced, without materializing the full result as a
+        :class:`DataFrame`. See
+        https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html.
+
+        The stream can only be consumed once.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        chunk_size
+            The number of rows that are buffered before a record batch is given.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+        compat_level
+            Use a specific compatibility level
+            when exporting Polars' internal data structures.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine. Polars will also
+            attempt to use the engine set by the `POLARS_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the polars streaming
+            engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+        See Also
+        --------
+        collect_batches
+
+        Examples
+        --------
+        >>> import pyarrow as pa
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        >>> reader = pa.RecordBatchReader.from_stream(lf.collect_arrow_stream())
+        >>> reader.read_all()
+        pyarrow.Table
+        a: int64
+        b: large_string
+        ----
+        a: [[1,2,3]]
+        b: [["x","y","z"]]
+        """
+        engine = _select_engine(engine)
+
+        if engine == "auto":
+            engine = "streaming"
+
+        compat_level_py: int | bool
+        if compat_level is None:
+            compat_level_py = False
+        elif isinstance(compat_level, CompatLevel):
+            compat_level_py = compat_level._version
+
+        ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        return ldf.collect_arrow_stream(
+            engine=engine,
+            maintain_order=maintain_order,
+            chunk_size=chunk_size,
+            compat_level=compat_level_py,
+        )
+
     @deprecated(
         "`LazyFrame.fetch` is deprecated; use `LazyFrame.collect` "
         "instead, in conjunction with a call to `head`."
@@ -4365,6 +4499,60 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self._from_pyldf(self._ldf.cache())
 
+    @unstable()
+    def with_string_cache(self, string_cache: StringCache) -> LazyFrame:
+        """
+        Attach a scoped string cache to this LazyFrame.
+
+        The :class:`Categorical` columns of this LazyFrame that use the global
+        categories are cast to the categories of the string cache. LazyFrames with
+        the same string cache attached can be combined (e.g. joined), while their
+        categories are kept out of the global categories of unrelated queries.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        string_cache
+            A :class:`StringCache` created with `scoped=True`.
+
+        Examples
+        --------
+        >>> cache = pl.StringCache(scoped=True)
+        >>> lf1 = pl.LazyFrame({"k": ["a", "b"]}).cast({"k": pl.Categorical})
+        >>> lf2 = pl.LazyFrame({"k": ["b", "a"], "v": [1, 2]})
+        >>> lf1.with_string_cache(cache).join(
+        ...     lf2.cast({"k": pl.Categorical}).with_string_cache(cache),
+        ...     on="k",
+        ...     maintain_order="left",
+        ... ).collect()
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ k   ┆ v   │
+        │ --- ┆ --- │
+        │ cat ┆ i64 │
+        ╞═════╪═════╡
+        │ a   ┆ 2   │
+        │ b   ┆ 1   │
+        └─────┴─────┘
+        """
+        categories = string_cache.categories
+        if categories is None:
+            msg = "only a scoped string cache can be attached to a LazyFrame"
+            raise ValueError(msg)
+
+        global_categorical = Categorical(Categories())
+        scoped_categorical = Categorical(categories)
+        return self.cast(
+            {
+                name: scoped_categorical
+                for name, dtype in self.collect_schema().items()
+                if dtype == global_categorical
+            }
+        )
+
     def cast(
         self,
         dtypes: (
@@ -5088,6 +5276,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         *by: IntoExpr | Iterable[IntoExpr],
         maintain_order: bool = False,
+        nulls_equal: bool = True,
         **named_by: IntoExpr,
     ) -> LazyGroupBy:
         """
@@ -5103,6 +5292,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             This is slower than a default group by.
             Setting this to `True` blocks the possibility
             to run on the streaming engine.
+        nulls_equal
+            Group rows with null keys together. If `False`, every row that has a
+            null in any of its keys forms a group of its own.
         **named_by
             Additional columns to group by, specified as keyword arguments.
             The columns will be renamed to the keyword used.
@@ -5189,7 +5381,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 )
                 raise TypeError(msg)
         exprs = parse_into_list_of_expressions(*by, **named_by)
-        lgb = self._ldf.group_by(exprs, maintain_order)
+        lgb = self._ldf.group_by(exprs, maintain_order, nulls_equal)
         return LazyGroupBy(lgb)
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -8507,6 +8699,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         schema: None | SchemaDict = None,
         validate_output_schema: bool = True,
         streamable: bool = False,
+        preserves_order: bool = False,
+        preserves_row_count: bool = False,
+        preserves_schema: bool = False,
     ) -> LazyFrame:
         """
         Apply a custom function.
@@ -8538,6 +8733,17 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             streaming engine. That means that the function must produce the same result
             when it is executed in batches or when it is be executed on the full
             dataset.
+        preserves_order
+            Declare that the function does not reorder rows: output row `i` is
+            derived from input row `i`.
+        preserves_row_count
+            Declare that every input row produces exactly one output row, without
+            looking at the other rows. Together with `preserves_order` this allows
+            slices (e.g. `head`) to be pushed past this node.
+        preserves_schema
+            Declare that the function returns the schema of its input and computes
+            every column independently. This allows projections to be pushed past
+            this node.
 
         Warnings
         --------
@@ -8596,6 +8802,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 streamable=streamable,
                 schema=schema,
                 validate_output=validate_output_schema,
+                preserves_order=preserves_order,
+                preserves_row_count=preserves_row_count,
+                preserves_schema=preserves_schema,
             )
         )
 
@@ -8634,6 +8843,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         columns: ColumnNameOrSelector | Collection[ColumnNameOrSelector],
         *more_columns: ColumnNameOrSelector,
         separator: str | None = None,
+        fields: str | Sequence[str] | None = None,
+        prefix: str | None = None,
+        on_collision: UnnestCollision = "raise",
     ) -> LazyFrame:
         """
         Decompose struct columns into separate columns for each of their fields.
@@ -8650,6 +8862,19 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         separator
             Rename output column names as combination of the struct column name,
             name separator and field name.
+        fields
+            Only unnest these fields, the other fields of the struct are dropped.
+        prefix
+            Prefix the output column names with this instead of the struct column
+            name. If `separator` is set, it is placed between the prefix and the
+            field name.
+        on_collision : {'raise', 'overwrite', 'keep'}
+            What to do when an unnested field gets the name of a column that is
+            already in the output.
+
+            - 'raise': Raise a `DuplicateError`.
+            - 'overwrite': The field replaces the existing column.
+            - 'keep': The existing column is kept and the field is dropped.
 
         Examples
         --------
@@ -8707,11 +8932,52 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ foo    ┆ 1    ┆ a    ┆ true ┆ [1, 2]    ┆ baz   │
         │ bar    ┆ 2    ┆ b    ┆ null ┆ [3]       ┆ womp  │
         └────────┴──────┴──────┴──────┴───────────┴───────┘
+
+        Only unnest some of the fields and prefix their names:
+
+        >>> df = pl.LazyFrame(
+        ...     {
+        ...         "id": [1, 2],
+        ...         "t": [
+        ...             {"id": 10, "x": "a", "y": 1.5},
+        ...             {"id": 20, "x": "b", "y": 2.5},
+        ...         ],
+        ...     }
+        ... )
+        >>> df.unnest("t", fields=["id", "x"], prefix="t_").collect()
+        shape: (2, 3)
+        ┌─────┬──────┬─────┐
+        │ id  ┆ t_id ┆ t_x │
+        │ --- ┆ ---  ┆ --- │
+        │ i64 ┆ i64  ┆ str │
+        ╞═════╪══════╪═════╡
+        │ 1   ┆ 10   ┆ a   │
+        │ 2   ┆ 20   ┆ b   │
+        └─────┴──────┴─────┘
+
+        Replace existing columns with the fields that have the same name:
+
+        >>> df.unnest("t", on_collision="overwrite").collect()
+        shape: (2, 3)
+        ┌─────┬─────┬─────┐
+        │ id  ┆ x   ┆ y   │
+        │ --- ┆ --- ┆ --- │
+        │ i64 ┆ str ┆ f64 │
+        ╞═════╪═════╪═════╡
+        │ 10  ┆ a   ┆ 1.5 │
+        │ 20  ┆ b   ┆ 2.5 │
+        └─────┴─────┴─────┘
         """
         subset = parse_list_into_selector(columns) | parse_list_into_selector(
             more_columns
         )
-        return self._from_pyldf(self._ldf.unnest(subset._pyselector, separator))
+        if isinstance(fields, str):
+            fields = [fields]
+        return self._from_pyldf(
+            self._ldf.unnest(
+                subset._pyselector, separator, fields, prefix, on_collision
+            )
+        )
 
     def merge_sorted(self, other: LazyFrame, key: str) -> LazyFrame:
         """
@@ -8836,6 +9102,60 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return self._from_pyldf(self._ldf.hint_sorted(cs, descending=ds, nulls_last=nl))
 
+    @unstable()
+    def set_estimated_rows(self, n: int) -> LazyFrame:
+        """
+        Hint the optimizer about the number of rows this LazyFrame produces.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The estimate overrides the optimizer's own cardinality estimate for the
+        query up to this point. The streaming engine uses these estimates to pick
+        the build side of joins. It never affects the result of the query.
+
+        Parameters
+        ----------
+        n
+            The estimated number of rows.
+
+        See Also
+        --------
+        estimated_rows
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3]}).select(pl.col("a").explode())
+        >>> lf.set_estimated_rows(1_000).estimated_rows()
+        (1000, False)
+        """
+        return self._from_pyldf(self._ldf.hint_estimated_rows(n))
+
+    @unstable()
+    def estimated_rows(self) -> tuple[int, bool]:
+        """
+        Estimate the number of rows the optimized query produces.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        tuple
+            The estimated number of rows and whether that number is exact.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3, 4]})
+        >>> lf.estimated_rows()
+        (4, True)
+        >>> lf.head(2).estimated_rows()
+        (2, True)
+        """
+        return self._ldf.estimated_rows()
+
     @unstable()
     def update(
         self,
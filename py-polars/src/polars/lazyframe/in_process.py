@@ -38,5 +38,9 @@ class InProcessQuery:
             return None
 
     def fetch_blocking(self) -> DataFrame:
-        """Await the result synchronously."""
+        """
+        Await the result synchronously.
+
+        A `KeyboardInterrupt` (Ctrl-C) while waiting cancels the query.
+        """
         return wrap_df(self._inner.fetch_blocking())
@@ -0,0 +1,116 @@
+from __future__ import annotations
+
+import contextlib
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any
+
+from polars._utils.unstable import unstable
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+
+@dataclass(frozen=True)
+class OperatorMetrics:
+    """
+    Runtime metrics of a single operator of a streaming query.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+
+    #: Name of the operator, e.g. `"filter"` or `"in-memory-sink"`.
+    name: str
+    #: Number of rows the operator received from its inputs.
+    rows_received: int
+    #: Number of batches (morsels) the operator received from its inputs.
+    morsels_received: int
+    #: Number of rows the operator sent to its outputs.
+    rows_sent: int
+    #: Number of batches (morsels) the operator sent to its outputs.
+    morsels_sent: int
+    #: Total time spent computing, summed over all threads.
+    poll_time_ns: int
+    #: Total time spent in state updates between execution phases.
+    state_update_time_ns: int
+    #: Total time IO of the operator was in flight.
+    io_active_time_ns: int
+    #: Number of bytes the operator read.
+    io_bytes_received: int
+    #: Number of bytes the operator wrote.
+    io_bytes_sent: int
+
+    @property
+    def total_time_ns(self) -> int:
+        """Total compute time of the operator, summed over all threads."""
+        return self.poll_time_ns + self.state_update_time_ns
+
+
+@dataclass(frozen=True)
+class QueryMetrics:
+    """
+    Runtime metrics of a finished streaming query.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+
+    #: Wall time of executing the query.
+    elapsed_ns: int
+    #: Metrics of every operator of the query.
+    operators: list[OperatorMetrics]
+
+
+@unstable()
+def set_streaming_metrics_callback(
+    callback: Callable[[QueryMetrics], None] | None,
+) -> None:
+    """
+    Call a function with the runtime metrics of every streaming query.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    After a query that ran on the streaming engine finished, `callback` is called
+    with its :class:`QueryMetrics`. Metrics are only tracked while a callback is
+    set, so this adds a small overhead. Exceptions raised by the callback are
+    reported as unraisable exceptions and don't fail the query.
+
+    The callback may be called from another thread than the one that started the
+    query, and is called for all streaming queries in the process.
+
+    Parameters
+    ----------
+    callback
+        Function that receives the metrics, or `None` to stop tracking metrics.
+
+    Examples
+    --------
+    >>> metrics = []
+    >>> pl.set_streaming_metrics_callback(metrics.append)
+    >>> pl.LazyFrame({"a": [1, 2, 3]}).filter(pl.col("a") > 1).collect(
+    ...     engine="streaming"
+    ... )  # doctest: +IGNORE_RESULT
+    >>> pl.set_streaming_metrics_callback(None)
+    >>> sum(op.rows_sent for op in metrics[0].operators)  # doctest: +SKIP
+    5
+    """
+    if callback is None:
+        plr.set_streaming_metrics_callback(None)
+        return
+
+    def on_metrics(elapsed_ns: int, operators: list[dict[str, Any]]) -> None:
+        callback(
+            QueryMetrics(
+                elapsed_ns=elapsed_ns,
+                operators=[OperatorMetrics(**op) for op in operators],
+            )
+        )
+
+    plr.set_streaming_metrics_callback(on_metrics)
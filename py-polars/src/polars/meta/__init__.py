@@ -1,6 +1,7 @@
 """Public functions that provide information about the Polars package or the environment it runs in."""  # noqa: W505
 
 from polars.meta.build import build_info
+from polars.meta.encoding import row_encoding_version, stable_hash_version
 from polars.meta.index_type import get_index_type
 from polars.meta.thread_pool import thread_pool_size, threadpool_size
 from polars.meta.versions import show_versions
@@ -8,7 +9,9 @@ from polars.meta.versions import show_versions
 __all__ = [
     "build_info",
     "get_index_type",
+    "row_encoding_version",
     "show_versions",
+    "stable_hash_version",
     "thread_pool_size",
     "threadpool_size",
 ]
@@ -0,0 +1,59 @@
+from __future__ import annotations
+
+import contextlib
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+
+def row_encoding_version() -> int:
+    """
+    Return the version of the row encoding used by :meth:`DataFrame.row_encode`.
+
+    Keys encoded with the same version decode to the same values and compare the
+    same across Polars releases. The version is increased whenever the encoding
+    changes, so store it alongside persisted keys to detect keys that have to be
+    re-encoded.
+
+    Examples
+    --------
+    >>> pl.row_encoding_version()
+    1
+    """
+    return plr.row_encoding_version()
+
+
+def stable_hash_version() -> int:
+    """
+    Return the version of the stable hash algorithm used by `hash(stable=True)`.
+
+    The hashes of :meth:`Expr.hash`, :meth:`Series.hash` and
+    :meth:`DataFrame.hash_rows` with `stable=True` only change together with this
+    version, so they can be persisted, e.g. as partition or deduplication keys.
+
+    Version 1 hashes the encoding of every value with 64-bit XXH3, seeded with the
+    given seed. A null is encoded as the byte `0`, any other value as the byte `1`
+    followed by its payload:
+
+    - `Boolean`: one byte, `0` or `1`.
+    - Integers: the little-endian bytes of the value, in the width of its data type.
+    - `Float32` and `Float64`: the little-endian bytes of the IEEE 754
+      representation, with `-0.0` written as `0.0` and every NaN as the canonical
+      quiet NaN. `Float16` is hashed as `Float32`.
+    - `String` and `Binary`: the bytes of the value, UTF-8 for strings.
+    - `Categorical` and `Enum`: the string value.
+    - `Date`, `Datetime`, `Duration`, `Time` and `Decimal`: the physical integer.
+      Time units, time zones and scales are not part of the hash.
+    - `List` and `Array`: the number of elements as a little-endian 64-bit integer,
+      followed by the little-endian hash of every element.
+    - `Struct`: the little-endian hash of every field.
+
+    A row is hashed by hashing the little-endian hashes of its values in column
+    order, with the same seed.
+
+    Examples
+    --------
+    >>> pl.stable_hash_version()
+    1
+    """
+    return plr.stable_hash_version()
@@ -707,6 +707,91 @@ class ArrayNameSpace:
 
         """
 
+    def dot(self, other: IntoExpr) -> Series:
+        """
+        Compute the dot product of every sub-array with the sub-array in `other`.
+
+        The result is null if either sub-array is null or contains a null.
+
+        Parameters
+        ----------
+        other
+            Array Series of the same width. A list, such as a single vector, is
+            converted to an array of the same width.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float32` if all inputs are
+            :class:`Float32`, and :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [3, 4]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.dot([1.0, 1.0])
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            3.0
+            7.0
+        ]
+        """
+
+    def l2_norm(self) -> Series:
+        """
+        Compute the Euclidean (L2) norm of every sub-array.
+
+        The result is null if the sub-array is null or contains a null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float32` if all inputs are
+            :class:`Float32`, and :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 4], [0, 0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.l2_norm()
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            5.0
+            0.0
+        ]
+        """
+
+    def cosine_similarity(self, other: IntoExpr) -> Series:
+        """
+        Compute the cosine similarity of every sub-array with the sub-array in `other`.
+
+        The result is null if either sub-array is null or contains a null, and NaN
+        if either sub-array only contains zeros.
+
+        Parameters
+        ----------
+        other
+            Array Series of the same width. A list, such as a single vector, is
+            converted to an array of the same width.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float32` if all inputs are
+            :class:`Float32`, and :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 4], [0, 2]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.cosine_similarity([1.0, 0.0])
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            0.6
+            0.0
+        ]
+        """
+
     def to_struct(
         self,
         fields: Callable[[int], str] | Sequence[str] | None = None,
@@ -102,8 +102,10 @@ class BinaryNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            Base64 that uses `-` and `_` instead of `+` and `/`. Base64 values are
+            accepted with or without `=` padding.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -150,16 +152,32 @@ class BinaryNameSpace:
             b"\xff\xff\x00"
             null
         ]
+
+        Decode URL safe Base64 values, which are often stored without padding.
+
+        >>> s = pl.Series("token", [b"-_8", b"-_8=", b"+/8="])
+        >>> s.bin.decode("base64url", strict=False)
+        shape: (3,)
+        Series: 'token' [binary]
+        [
+            b"\xfb\xff"
+            b"\xfb\xff"
+            null
+        ]
         """
 
-    def encode(self, encoding: TransferEncoding) -> Series:
+    def encode(self, encoding: TransferEncoding, *, padding: bool = True) -> Series:
         r"""
         Encode values using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            Base64 that uses `-` and `_` instead of `+` and `/`.
+        padding
+            Pad Base64 encoded values with `=` to a multiple of four characters.
+            Ignored for hex encoding.
 
         Returns
         -------
@@ -190,6 +208,17 @@ class BinaryNameSpace:
             "//8A"
             "AAD/"
         ]
+
+        Encode values using URL safe Base64 encoding without padding.
+
+        >>> s = pl.Series("colors", [b"\xff\xff", b"\xfb\xef\xbe"])
+        >>> s.bin.encode("base64url", padding=False)
+        shape: (2,)
+        Series: 'colors' [str]
+        [
+            "__8"
+            "----"
+        ]
         """
 
     def size(self, unit: SizeUnit = "b") -> Series:
@@ -381,3 +410,23 @@ class BinaryNameSpace:
                 b"\x00\xff"
         ]
         """
+
+    def to_uuid_str(self) -> Series:
+        """
+        Format UUIDs stored as 16 bytes in their canonical hyphenated form.
+
+        This is the inverse of :meth:`Series.str.parse_uuid`. Values that are not
+        exactly 16 bytes long raise an error.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> b = bytes.fromhex("67e5504410b1426f9247bb680e5fe0c8")
+        >>> s = pl.Series("id", [b, None])
+        >>> s.bin.to_uuid_str().to_list()
+        ['67e55044-10b1-426f-9247-bb680e5fe0c8', None]
+        """
@@ -7,8 +7,11 @@ from polars._utils.wrap import wrap_s
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
+    from collections.abc import Iterable
+
     from polars import Series
     from polars._plr import PySeries
+    from polars._typing import IntoExprColumn
 
 
 @expr_dispatch
@@ -41,6 +44,58 @@ class CatNameSpace:
         "`cat.is_local()` is deprecated; Categoricals no longer have a local scope. "
         "This method will be removed in Polars 2.0."
     )
+    def add_categories(self, categories: str | Iterable[str]) -> Series:
+        """
+        Add categories to the data type without recoding the existing values.
+
+        For an :class:`Enum`, the new categories are appended after the existing
+        categories, skipping those that already exist. The existing values keep
+        their physical representation, so this is much cheaper than casting to a
+        new :class:`Enum`. For a :class:`Categorical`, the categories are inserted
+        into its mapping and the data type stays the same.
+
+        Parameters
+        ----------
+        categories
+            The categories to add.
+
+        See Also
+        --------
+        union_categories
+
+        Examples
+        --------
+        >>> s = pl.Series("size", ["S", "M", "S"], dtype=pl.Enum(["S", "M"]))
+        >>> s.cat.add_categories(["L", "XL"]).dtype
+        Enum(categories=['S', 'M', 'L', 'XL'])
+        """
+
+    def union_categories(self, other: IntoExprColumn) -> Series:
+        """
+        Add the categories of another Enum or Categorical without recoding.
+
+        The categories of `other` that do not exist yet are appended after the
+        existing categories, in the order in which they appear in `other`. Only
+        the data type of `other` is used, not its values.
+
+        Parameters
+        ----------
+        other
+            An Enum or Categorical Series whose categories to add. The categories of
+            a Categorical can only be added to another Categorical.
+
+        See Also
+        --------
+        add_categories
+
+        Examples
+        --------
+        >>> s = pl.Series(["x", "y"], dtype=pl.Enum(["x", "y"]))
+        >>> other = pl.Series(["z", "x"], dtype=pl.Enum(["z", "x"]))
+        >>> s.cat.union_categories(other).dtype
+        Enum(categories=['x', 'y', 'z'])
+        """
+
     def is_local(self) -> bool:
         """
         Return whether or not the column is a local categorical.
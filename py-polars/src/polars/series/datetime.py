@@ -23,6 +23,7 @@ if TYPE_CHECKING:
         Roll,
         TemporalLiteral,
         TimeUnit,
+        WeekConvention,
     )
 
     if sys.version_info >= (3, 13):
@@ -648,6 +649,78 @@ class DateTimeNameSpace:
         ]
         """
 
+    def fiscal_year(self, start: int | str = "jan") -> Series:
+        """
+        Extract the fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        A fiscal year is named after the calendar year in which it ends, so the
+        fiscal year starting in April 2024 is fiscal year 2025.
+
+        Parameters
+        ----------
+        start
+            The month in which the fiscal year starts, given either as a month
+            name (e.g. `"apr"` or `"april"`) or as an integer from 1 to 12.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 3, 31), date(2024, 4, 1), date(2024, 12, 31)]
+        ... )
+        >>> s.dt.fiscal_year(start="apr")
+        shape: (3,)
+        Series: 'date' [i32]
+        [
+                2024
+                2025
+                2025
+        ]
+        """
+
+    def fiscal_quarter(self, start: int | str = "jan") -> Series:
+        """
+        Extract the quarter of the fiscal year from underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the quarter ranging from 1 to 4, where quarter 1 starts in the
+        month `start`.
+
+        Parameters
+        ----------
+        start
+            The month in which the fiscal year starts, given either as a month
+            name (e.g. `"apr"` or `"april"`) or as an integer from 1 to 12.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 3, 31), date(2024, 4, 1), date(2024, 12, 31)]
+        ... )
+        >>> s.dt.fiscal_quarter(start="apr")
+        shape: (3,)
+        Series: 'date' [i8]
+        [
+                4
+                1
+                3
+        ]
+        """
+
     def month(self) -> Series:
         """
         Extract the month from the underlying date representation.
@@ -714,14 +787,25 @@ class DateTimeNameSpace:
         ]
         """
 
-    def week(self) -> Series:
+    def week(self, convention: WeekConvention = "iso") -> Series:
         """
         Extract the week from the underlying date representation.
 
         Applies to Date and Datetime columns.
 
-        Returns the ISO week number starting from 1.
-        The return value ranges from 1 to 53. (The last week of year differs by years.)
+        Returns the week number starting from 1.
+
+        Parameters
+        ----------
+        convention : {'iso', 'us'}
+            The convention used to number the weeks.
+
+            - 'iso': ISO 8601 weeks, which start on Monday. Week 1 is the week
+              containing the first Thursday of the year, so the first days of
+              January may belong to the last week of the previous year. The
+              return value ranges from 1 to 53.
+            - 'us': weeks start on Sunday and week 1 is the week containing
+              January 1st. The return value ranges from 1 to 54.
 
         Returns
         -------
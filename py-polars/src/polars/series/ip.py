@@ -0,0 +1,75 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import Series
+    from polars._plr import PySeries
+
+
+@expr_dispatch
+class IPNameSpace:
+    """Series.ip namespace."""
+
+    _accessor = "ip"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def is_in_subnet(self, subnet: str | Sequence[str]) -> Series:
+        """
+        Check whether the IP addresses lie within any of the given subnets.
+
+        The Series must hold IP addresses parsed by :meth:`Series.str.parse_ip`.
+
+        Parameters
+        ----------
+        subnet
+            One or more IPv4 or IPv6 subnets in CIDR notation, such as
+            `"10.0.0.0/8"`. A plain address is the subnet of just that address.
+            IPv4 addresses never lie within an IPv6 subnet and vice versa.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["10.1.2.3", "8.8.8.8", None])
+        >>> s.str.parse_ip().ip.is_in_subnet("10.0.0.0/8")
+        shape: (3,)
+        Series: 'ip' [bool]
+        [
+            true
+            false
+            null
+        ]
+        """
+
+    def to_int(self) -> Series:
+        """
+        Convert the IP addresses to their integer value.
+
+        The Series must hold IP addresses parsed by :meth:`Series.str.parse_ip`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt128`.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["10.0.0.1", "::1"])
+        >>> s.str.parse_ip().ip.to_int()
+        shape: (2,)
+        Series: 'ip' [u128]
+        [
+            167772161
+            1
+        ]
+        """
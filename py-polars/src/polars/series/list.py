@@ -630,6 +630,44 @@ class ListNameSpace:
         ]
         """
 
+    def index_of(self, value: IntoExpr) -> Series:
+        """
+        Get the index of the first occurrence of `value` in every sublist.
+
+        Returns null if the value is not found or if the sublist is null.
+
+        Parameters
+        ----------
+        value
+            Value to find. If this is a Series with a value per row, every sublist is
+            searched for its own value. A null value matches the first null element.
+            A value that doesn't fit the data type of the elements, e.g. because it is
+            out of range, is never found.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32` or :class:`UInt64`
+            (depending on compilation).
+
+        See Also
+        --------
+        find : Find the index of the first element that matches a predicate.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 2, 1], [], [1, 2, 1], None])
+        >>> s.list.index_of(1)
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+            2
+            null
+            0
+            null
+        ]
+        """
+
     def arg_min(self) -> Series:
         """
         Retrieve the index of the minimal value in every sublist.
@@ -713,6 +751,376 @@ class ListNameSpace:
         ]
         """
 
+    def cum_sum(self, *, reverse: bool = False) -> Series:
+        """
+        Get the cumulative sum within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 2], [4, 5]])
+        >>> s.list.cum_sum()
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [3, 4, 6]
+            [4, 9]
+        ]
+        """
+
+    def cum_prod(self, *, reverse: bool = False) -> Series:
+        """
+        Get the cumulative product within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 2], [4, 5]])
+        >>> s.list.cum_prod()
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [3, 3, 6]
+            [4, 20]
+        ]
+        """
+
+    def cum_min(self, *, reverse: bool = False) -> Series:
+        """
+        Get the cumulative minimum within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 2], [4, 5]])
+        >>> s.list.cum_min()
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [3, 1, 1]
+            [4, 4]
+        ]
+        """
+
+    def cum_max(self, *, reverse: bool = False) -> Series:
+        """
+        Get the cumulative maximum within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 2], [4, 5]])
+        >>> s.list.cum_max()
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [3, 3, 3]
+            [4, 5]
+        ]
+        """
+
+    def cum_count(self, *, reverse: bool = False) -> Series:
+        """
+        Get the cumulative count of the non-null values within every sublist.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation, accumulating from the end of every sublist.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, None, 3], [None]])
+        >>> s.list.cum_count()
+        shape: (2,)
+        Series: 'a' [list[u32]]
+        [
+            [1, 1, 2]
+            [0]
+        ]
+        """
+
+    def rolling_min(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Apply a rolling minimum within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 6]])
+        >>> s.list.rolling_min(2)
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 1, 2]
+            [null, 4]
+        ]
+        """
+
+    def rolling_max(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Apply a rolling maximum within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 6]])
+        >>> s.list.rolling_max(2)
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 2, 3]
+            [null, 6]
+        ]
+        """
+
+    def rolling_mean(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Apply a rolling mean within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 6]])
+        >>> s.list.rolling_mean(2)
+        shape: (2,)
+        Series: 'a' [list[f64]]
+        [
+            [null, 1.5, 2.5]
+            [null, 5.0]
+        ]
+        """
+
+    def rolling_sum(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Apply a rolling sum within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 6]])
+        >>> s.list.rolling_sum(2)
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [null, 3, 5]
+            [null, 10]
+        ]
+        """
+
+    def rolling_median(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Apply a rolling median within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 6]])
+        >>> s.list.rolling_median(2)
+        shape: (2,)
+        Series: 'a' [list[f64]]
+        [
+            [null, 1.5, 2.5]
+            [null, 5.0]
+        ]
+        """
+
+    def rolling_std(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> Series:
+        """
+        Apply a rolling standard deviation within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 6]])
+        >>> s.list.rolling_std(2)
+        shape: (2,)
+        Series: 'a' [list[f64]]
+        [
+            [null, 0.707107, 0.707107]
+            [null, 1.414214]
+        ]
+        """
+
+    def rolling_var(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> Series:
+        """
+        Apply a rolling variance within every sublist.
+
+        A window of length `window_size` traverses the values of every sublist; the
+        window at a given position includes the value itself and the
+        `window_size - 1` values before it. Every output sublist has the same length
+        as its input sublist.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3], [4, 6]])
+        >>> s.list.rolling_var(2)
+        shape: (2,)
+        Series: 'a' [list[f64]]
+        [
+            [null, 0.5, 0.5]
+            [null, 2.0]
+        ]
+        """
+
     def shift(self, n: int | IntoExprColumn = 1) -> Series:
         """
         Shift list values by the given number of indices.
@@ -1055,7 +1463,44 @@ class ListNameSpace:
         ]
         """  # noqa: W505
 
-    def set_union(self, other: Series | Collection[Any]) -> Series:
+    def find(self, predicate: Expr) -> Series:
+        """
+        Get the index of the first element in every sublist that matches a predicate.
+
+        Returns null if no element matches or if the sublist is null.
+
+        Parameters
+        ----------
+        predicate
+            A boolean expression evaluated on each list element.
+            Use `pl.element()` to refer to the current element.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32` or :class:`UInt64`
+            (depending on compilation).
+
+        See Also
+        --------
+        index_of : Find the index of the first occurrence of a value.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 8, 3], [5, 7], [4]])
+        >>> s.list.find(pl.element() > 4)
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+            1
+            0
+            null
+        ]
+        """
+
+    def set_union(
+        self, other: Series | Collection[Any], *, multiset: bool = False
+    ) -> Series:
         """
         Compute the SET UNION between the elements in this list and the elements of `other`.
 
@@ -1063,6 +1508,10 @@ class ListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Treat the lists as multisets: duplicates are kept and every occurrence
+            is matched against a single occurrence in `other`. The output follows
+            the order of this list.
 
         Examples
         --------
@@ -1079,7 +1528,9 @@ class ListNameSpace:
         ]
         """  # noqa: W505
 
-    def set_difference(self, other: Series | Collection[Any]) -> Series:
+    def set_difference(
+        self, other: Series | Collection[Any], *, multiset: bool = False
+    ) -> Series:
         """
         Compute the SET DIFFERENCE between the elements in this list and the elements of `other`.
 
@@ -1087,6 +1538,10 @@ class ListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Treat the lists as multisets: duplicates are kept and every occurrence
+            is matched against a single occurrence in `other`. The output follows
+            the order of this list.
 
         See Also
         --------
@@ -1107,7 +1562,9 @@ class ListNameSpace:
         ]
         """  # noqa: W505
 
-    def set_intersection(self, other: Series | Collection[Any]) -> Series:
+    def set_intersection(
+        self, other: Series | Collection[Any], *, multiset: bool = False
+    ) -> Series:
         """
         Compute the SET INTERSECTION between the elements in this list and the elements of `other`.
 
@@ -1115,6 +1572,10 @@ class ListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Treat the lists as multisets: duplicates are kept and every occurrence
+            is matched against a single occurrence in `other`. The output follows
+            the order of this list.
 
         Examples
         --------
@@ -1131,7 +1592,9 @@ class ListNameSpace:
         ]
         """  # noqa: W505
 
-    def set_symmetric_difference(self, other: Series | Collection[Any]) -> Series:
+    def set_symmetric_difference(
+        self, other: Series | Collection[Any], *, multiset: bool = False
+    ) -> Series:
         """
         Compute the SET SYMMETRIC DIFFERENCE between the elements in this list and the elements of `other`.
 
@@ -1139,6 +1602,10 @@ class ListNameSpace:
         ----------
         other
             Right hand side of the set operation.
+        multiset
+            Treat the lists as multisets: duplicates are kept and every occurrence
+            is matched against a single occurrence in `other`. The output follows
+            the order of this list.
 
         Examples
         --------
@@ -59,6 +59,7 @@ from polars._utils.unstable import unstable
 from polars._utils.various import (
     BUILDING_SPHINX_DOCS,
     _is_generator,
+    extend_bool,
     no_default,
     parse_version,
     qualified_type_name,
@@ -99,16 +100,24 @@ from polars.datatypes import (
 from polars.datatypes._utils import dtype_to_init_repr
 from polars.exceptions import ComputeError, ModuleUpgradeRequiredError, ShapeError
 from polars.interchange.protocol import CompatLevel
+from polars.schema import Schema
 from polars.series.array import ArrayNameSpace
 from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
 from polars.series.datetime import DateTimeNameSpace
 from polars.series.ext import ExtensionNameSpace
+from polars.series.ip import IPNameSpace
 from polars.series.list import ListNameSpace
 from polars.series.plotting import SeriesPlot
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
-from polars.series.utils import expr_dispatch, get_ffi_func
+from polars.series.utils import (
+    expr_dispatch,
+    get_cfunc_signature,
+    get_ffi_func,
+    get_native_ufunc,
+    is_arrow_compute_function,
+)
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars._plr import PyDataFrame, PySeries
@@ -133,12 +142,18 @@ if TYPE_CHECKING:
         ArrowArrayExportable,
         ArrowStreamExportable,
         BufferInfo,
+        CheckedArithmeticOp,
         ClosedInterval,
         ComparisonOperator,
+        DecimalArithmeticOp,
+        DecimalOverflow,
         FillNullStrategy,
+        HashDigestOutput,
+        InterpolateByMethod,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
+        ModeTies,
         MultiIndexSelector,
         NonNestedLiteral,
         NullBehavior,
@@ -148,6 +163,7 @@ if TYPE_CHECKING:
         QuantileMethod,
         RankMethod,
         RoundMode,
+        SchemaDict,
         SearchSortedSide,
         SeriesBuffers,
         SingleIndexSelector,
@@ -272,6 +288,7 @@ class Series:
         "cat",
         "dt",
         "ext",
+        "ip",
         "list",
         "plot",
         "str",
@@ -424,6 +441,11 @@ class Series:
         """Create an object namespace of all extension type related methods."""
         return ExtensionNameSpace(self)
 
+    @property
+    def ip(self) -> IPNameSpace:
+        """Create an object namespace of all IP address related methods."""
+        return IPNameSpace(self)
+
     @property
     @unstable()
     def plot(self) -> SeriesPlot:
@@ -518,8 +540,10 @@ class Series:
         return cls._from_pyseries(PySeries._import_arrow_from_c(name, pointers))
 
     @classmethod
-    def _import(cls, pointer: int) -> Self:
-        return cls._from_pyseries(PySeries._import(pointer))
+    def _import(
+        cls, pointer: int, ffi_version: tuple[int, int] | None = None
+    ) -> Self:
+        return cls._from_pyseries(PySeries._import(pointer, ffi_version))
 
     def _export_arrow_to_c(self, out_ptr: int, out_schema_ptr: int) -> None:
         """
@@ -569,7 +593,7 @@ class Series:
         """
         return self._s._get_buffer_info()
 
-    def _get_buffers(self) -> SeriesBuffers:
+    def _get_buffers(self, *, allow_copy: bool = True) -> SeriesBuffers:
         """
         Return the underlying values, validity, and offsets buffers as Series.
 
@@ -577,6 +601,12 @@ class Series:
         The validity buffer may not exist if the column contains no null values.
         The offsets buffer only exists for Series of data type `String` and `List`.
 
+        Parameters
+        ----------
+        allow_copy
+            Allow data to be copied. If set to `False`, a ComputeError is raised if
+            the buffers cannot be returned without copying data.
+
         Returns
         -------
         dict
@@ -587,12 +617,14 @@ class Series:
         --------
         The underlying buffers for `String` Series cannot be represented in this
         format. Instead, the buffers are converted to a values and offsets buffer.
+        The string data is only copied if it is not stored contiguously, which it is
+        for strings imported from Arrow.
 
         Notes
         -----
         This method is mainly intended for use with the dataframe interchange protocol.
         """
-        buffers = self._s._get_buffers()
+        buffers = self._s._get_buffers(allow_copy)
         keys = ("values", "validity", "offsets")
         return {  # type: ignore[return-value]
             k: self._from_pyseries(b) if b is not None else b
@@ -631,6 +663,51 @@ class Series:
         """
         return cls._from_pyseries(PySeries._from_buffer(dtype, buffer_info, owner))
 
+    @classmethod
+    def _import_buffer(cls, obj: Any, dtype: PolarsDataType) -> Self:
+        """
+        Construct a Series from an object implementing the Python buffer protocol.
+
+        The data is not copied; the Series keeps a reference to `obj` for as long as
+        its memory is in use.
+
+        Parameters
+        ----------
+        obj
+            An object exposing a C-contiguous buffer, such as `bytes`, `mmap.mmap`
+            or `array.array`.
+        dtype
+            The numeric data type the buffer is interpreted as.
+
+        Returns
+        -------
+        Series
+
+        Raises
+        ------
+        TypeError
+            When the given `dtype` is not numeric.
+        ValueError
+            When the size or alignment of the buffer does not match the `dtype`.
+
+        Warnings
+        --------
+        The buffer must not be modified while it is referenced by the Series.
+
+        Notes
+        -----
+        This requires `numpy` to be installed to access the buffer.
+
+        Examples
+        --------
+        >>> import array
+        >>> buf = array.array("d", [1.0, 2.5, 4.0])
+        >>> pl.Series._import_buffer(buf, pl.Float64).to_list()
+        [1.0, 2.5, 4.0]
+        """
+        dtype = parse_into_dtype(dtype)
+        return cls._from_pyseries(PySeries._import_buffer(obj, dtype))
+
     @classmethod
     def _from_buffers(
         cls,
@@ -728,6 +805,32 @@ class Series:
             out["FAST_EXPLODE"] = self._s.can_fast_explode_flag()
         return out
 
+    @property
+    def metadata(self) -> dict[str_, str_]:
+        """
+        Get the custom key-value metadata of this Series.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The metadata is kept when the Series is renamed, sliced, gathered from or
+        appended to, and when a column is replaced by `with_columns`. It is not
+        part of the schema of a `LazyFrame`, so it is not written to or read from
+        files.
+
+        See Also
+        --------
+        with_metadata
+
+        Examples
+        --------
+        >>> s = pl.Series("speed", [1.0, 2.5]).with_metadata({"unit": "m/s"})
+        >>> s.metadata
+        {'unit': 'm/s'}
+        """
+        return self._s.metadata() or {}
+
     @property
     def name(self) -> str_:
         """
@@ -1459,9 +1562,9 @@ class Series:
             for idx in range(self.len()):
                 yield get_index(idx)
         else:
+            # values are converted to Python objects on demand, one buffer at a time
             buffer_size = 25_000
-            for offset in range(0, self.len(), buffer_size):
-                yield from self.slice(offset, buffer_size).to_list()
+            yield from self._s.iter_py(buffer_size)
 
     @overload
     def __getitem__(self, key: SingleIndexSelector) -> Any: ...
@@ -1591,7 +1694,17 @@ class Series:
     def __array_ufunc__(
         self, ufunc: np.ufunc, method: str_, *inputs: Any, **kwargs: Any
     ) -> Series:
-        """Numpy universal functions."""
+        """
+        Numpy universal functions.
+
+        Common elementwise ufuncs (arithmetic, comparisons, exponentials, logarithms
+        and trigonometric functions) on numeric data are evaluated with the native
+        Polars kernels, which propagate missing values. Other ufuncs are applied by
+        numpy on the underlying data.
+        """
+        if (native := get_native_ufunc(ufunc, method, inputs, kwargs)) is not None:
+            return native(*inputs)
+
         if self._s.n_chunks() > 1:
             self._s.rechunk(in_place=True)
 
@@ -1713,6 +1826,46 @@ class Series:
         """
         return self._s.__arrow_c_stream__(requested_schema)
 
+    def __arrow_c_array__(
+        self, requested_schema: object | None = None
+    ) -> tuple[object, object]:
+        """
+        Export a Series via the Arrow PyCapsule Interface as a single array.
+
+        If the Series contains only a single chunk this operation is zero copy,
+        otherwise the chunks are concatenated. Use `__arrow_c_stream__` to export
+        every chunk without copying.
+
+        https://arrow.apache.org/docs/dev/format/CDataInterface/PyCapsuleInterface.html
+        """
+        return self._s.__arrow_c_array__(requested_schema)
+
+    def __dlpack__(
+        self,
+        *,
+        stream: int | None = None,
+        max_version: tuple[int, int] | None = None,
+        dl_device: tuple[int, int] | None = None,
+        copy: bool | None = None,
+    ) -> object:
+        """
+        Export a numeric Series via the DLPack protocol.
+
+        The data is shared without copying if the Series consists of a single chunk
+        and contains no null values. Series with null values or a non-numeric data
+        type cannot be exported. Consumers that request DLPack 1.0 or later receive a
+        versioned capsule, which marks shared data as read-only.
+
+        https://dmlc.github.io/dlpack/latest/python_spec.html
+        """
+        return self._s.__dlpack__(
+            stream=stream, max_version=max_version, dl_device=dl_device, copy=copy
+        )
+
+    def __dlpack_device__(self) -> tuple[int, int]:
+        """Return the device of the Series data for the DLPack protocol (the CPU)."""
+        return self._s.__dlpack_device__()
+
     def _repr_html_(self) -> str_:
         """Format output data in HTML for display in Jupyter Notebooks."""
         return self.to_frame()._repr_html_(_from_series=True)
@@ -1744,7 +1897,9 @@ class Series:
 
         return self._s.get_index_signed(index)
 
-    def estimated_size(self, unit: SizeUnit = "b") -> int | float:
+    def estimated_size(
+        self, unit: SizeUnit = "b", *, deep: bool = False
+    ) -> int | float:
         """
         Return an estimation of the total (heap) allocated size of the Series.
 
@@ -1763,13 +1918,20 @@ class Series:
 
         Notes
         -----
-        For data with Object dtype, the estimated size only reports the pointer
-        size, which is a huge underestimation.
+        For data with Object dtype, the estimated size includes the size of the
+        Python objects as reported by :func:`sys.getsizeof`. This is a shallow size
+        (objects referenced by those objects are not included), and an object that
+        occurs in multiple rows is counted once per row.
 
         Parameters
         ----------
         unit : {'b', 'kb', 'mb', 'gb', 'tb'}
             Scale the returned size to the given unit.
+        deep
+            Count all the storage referenced by the Series instead of the visible
+            size of its buffers. This includes the full data buffers of string and
+            binary views and the size of the categorical mappings. Storage shared
+            between chunks is only counted once.
 
         Examples
         --------
@@ -1779,7 +1941,7 @@ class Series:
         >>> s.estimated_size("mb")
         3.814697265625
         """
-        sz = self._s.estimated_size()
+        sz = self._s.estimated_size(deep)
         return scale_bytes(sz, unit)
 
     def sqrt(self) -> Series:
@@ -2457,6 +2619,110 @@ class Series:
         """  # noqa: W505
         return self._s.quantile(quantile, interpolation)
 
+    def approx_quantile(
+        self, quantile: float, compression: float = 100.0
+    ) -> float | None:
+        """
+        Get the approximate quantile value of this Series with a t-digest.
+
+        Null and NaN values are ignored. See :meth:`Expr.approx_quantile`.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        compression
+            Bound on the number of centroids, which must be positive. A higher
+            compression gives a smaller error, at the cost of more memory.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3, 4])
+        >>> s.approx_quantile(0.5)
+        2.5
+        """
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).approx_quantile(quantile, compression))
+            .item()
+        )
+
+    def weighted_mean(self, weights: IntoExpr) -> float | None:
+        """
+        Get the weighted mean of this Series.
+
+        Rows where the value or the weight is null are ignored. The result is null if
+        the remaining weights sum to zero.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 4])
+        >>> s.weighted_mean(pl.Series([1, 2, 1]))
+        2.25
+        """
+        return (
+            self.to_frame().select_seq(F.col(self.name).weighted_mean(weights)).item()
+        )
+
+    def weighted_var(self, weights: IntoExpr, ddof: int = 1) -> float | None:
+        """
+        Get the weighted variance of this Series, treating the weights as frequencies.
+
+        Rows where the value or the weight is null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values.
+        ddof
+            “Delta Degrees of Freedom”: the divisor used in the calculation is W - ddof,
+            where W represents the sum of the weights.
+            By default ddof is 1.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 4])
+        >>> s.weighted_var(pl.Series([1, 2, 1]), ddof=0)
+        1.1875
+        """
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).weighted_var(weights, ddof))
+            .item()
+        )
+
+    def weighted_quantile(self, weights: IntoExpr, quantile: float) -> float | None:
+        """
+        Get the weighted quantile value of this Series.
+
+        This is the smallest value for which the weights of all values up to and
+        including it sum to at least `quantile` times the total weight. Rows where the
+        value or the weight is null are ignored.
+
+        Parameters
+        ----------
+        weights
+            Non-negative weights of the values.
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 4])
+        >>> s.weighted_quantile(pl.Series([1, 2, 1]), 0.8)
+        4.0
+        """
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).weighted_quantile(weights, quantile))
+            .item()
+        )
+
     def to_dummies(
         self,
         *,
@@ -2528,6 +2794,7 @@ class Series:
         labels: Sequence[str_] | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        include_bins: bool = False,
     ) -> Series:
         """
         Bin continuous values into discrete categories.
@@ -2549,12 +2816,17 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        include_bins
+            Return a :class:`Struct` with the `category`, the `lower` and `upper`
+            edge and the `bin_index` of the bin each observation falls in, instead
+            of only the category. Cannot be combined with `include_breaks`.
 
         Returns
         -------
         Series
-            Series of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise a Series of data type :class:`Struct`.
+            Series of data type :class:`Categorical` if `include_breaks` and
+            `include_bins` are set to `False` (default), otherwise a Series of
+            data type :class:`Struct`.
 
         See Also
         --------
@@ -2592,6 +2864,23 @@ class Series:
         │ 1   ┆ 1.0        ┆ (-1, 1]    │
         │ 2   ┆ inf        ┆ (1, inf]   │
         └─────┴────────────┴────────────┘
+
+        Create a DataFrame with the category and the edges and index of its bin.
+
+        >>> cut = s.cut([-1, 1], include_bins=True).alias("cut")
+        >>> s.to_frame().with_columns(cut).unnest("cut")
+        shape: (5, 5)
+        ┌─────┬────────────┬───────┬───────┬───────────┐
+        │ foo ┆ category   ┆ lower ┆ upper ┆ bin_index │
+        │ --- ┆ ---        ┆ ---   ┆ ---   ┆ ---       │
+        │ i64 ┆ cat        ┆ f64   ┆ f64   ┆ u32       │
+        ╞═════╪════════════╪═══════╪═══════╪═══════════╡
+        │ -2  ┆ (-inf, -1] ┆ -inf  ┆ -1.0  ┆ 0         │
+        │ -1  ┆ (-inf, -1] ┆ -inf  ┆ -1.0  ┆ 0         │
+        │ 0   ┆ (-1, 1]    ┆ -1.0  ┆ 1.0   ┆ 1         │
+        │ 1   ┆ (-1, 1]    ┆ -1.0  ┆ 1.0   ┆ 1         │
+        │ 2   ┆ (1, inf]   ┆ 1.0   ┆ inf   ┆ 2         │
+        └─────┴────────────┴───────┴───────┴───────────┘
         """
 
     @unstable()
@@ -2603,6 +2892,7 @@ class Series:
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        include_bins: bool = False,
     ) -> Series:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -2629,12 +2919,17 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        include_bins
+            Return a :class:`Struct` with the `category`, the `lower` and `upper`
+            edge and the `bin_index` of the bin each observation falls in, instead
+            of only the category. Cannot be combined with `include_breaks`.
 
         Returns
         -------
         Series
-            Series of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise a Series of data type :class:`Struct`.
+            Series of data type :class:`Categorical` if `include_breaks` and
+            `include_bins` are set to `False` (default), otherwise a Series of
+            data type :class:`Struct`.
 
         See Also
         --------
@@ -3050,6 +3345,35 @@ class Series:
         """
         return self.alias(name)
 
+    @unstable()
+    def with_metadata(self, metadata: dict[str_, str_] | None) -> Series:
+        """
+        Return a copy of this Series with different custom key-value metadata.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        metadata
+            The new metadata, e.g. the unit or provenance of the data. Pass `None`
+            or an empty dictionary to remove the metadata.
+
+        See Also
+        --------
+        metadata
+
+        Examples
+        --------
+        >>> s = pl.Series("speed", [1.0, 2.5]).with_metadata({"unit": "m/s"})
+        >>> s.metadata
+        {'unit': 'm/s'}
+        >>> s.with_metadata(None).metadata
+        {}
+        """
+        return self._from_pyseries(self._s.with_metadata(metadata))
+
     def chunk_lengths(self) -> list_[int]:
         """
         Get the length of each individual chunk.
@@ -4857,6 +5181,7 @@ class Series:
         *,
         writable: bool = False,
         allow_copy: bool = True,
+        masked: bool = False,
         use_pyarrow: bool | None = None,
         zero_copy_only: bool | None = None,
     ) -> np.ndarray[Any, Any]:
@@ -4880,6 +5205,13 @@ class Series:
         allow_copy
             Allow memory to be copied to perform the conversion. If set to `False`,
             causes conversions that are not zero-copy to fail.
+        masked
+            Return a `masked array`_ that masks the null values. Numeric data keeps
+            its data type instead of being cast to a float type with `nan` representing
+            a null value, and is not copied if it would otherwise be zero copy. The mask
+            is always materialized.
+
+            .. _masked array: https://numpy.org/doc/stable/reference/maskedarray.html
 
         use_pyarrow
             First convert to PyArrow, then call `pyarrow.Array.to_numpy
@@ -4931,6 +5263,14 @@ class Series:
         ...
         RuntimeError: copy not allowed: cannot convert to a NumPy array without copying data
 
+        Set `masked=True` to keep the data type and mask the null values instead.
+
+        >>> arr = s.to_numpy(masked=True)
+        >>> arr.dtype
+        dtype('uint16')
+        >>> arr.mask
+        array([False, False,  True])
+
         Series of data type `Array` and `Struct` will result in an array with more than
         one dimension.
 
@@ -4947,6 +5287,9 @@ class Series:
             )
             allow_copy = not zero_copy_only
 
+        if masked:
+            return self._s.to_numpy_masked(writable=writable, allow_copy=allow_copy)
+
         if use_pyarrow is not None:
             issue_deprecation_warning(
                 "the `use_pyarrow` parameter for `Series.to_numpy` is deprecated."
@@ -5109,6 +5452,37 @@ class Series:
             raise TypeError(msg)
         return self._s.to_arrow(compat_level_py)
 
+    def to_arrow_chunks(
+        self, *, compat_level: CompatLevel | None = None
+    ) -> list[pa.Array]:
+        """
+        Return the underlying Arrow arrays, one per chunk.
+
+        Unlike :meth:`to_arrow`, the Series is not rechunked, so this operation is
+        always zero copy.
+
+        Parameters
+        ----------
+        compat_level
+            Use a specific compatibility level
+            when exporting Polars' internal data structures.
+
+        Examples
+        --------
+        >>> s = pl.concat([pl.Series("a", [1, 2]), pl.Series("a", [3])], rechunk=False)
+        >>> [len(chunk) for chunk in s.to_arrow_chunks()]
+        [2, 1]
+        """
+        compat_level_py: int | bool
+        if compat_level is None:
+            compat_level_py = False
+        elif isinstance(compat_level, CompatLevel):
+            compat_level_py = compat_level._version
+        else:
+            msg = f"`compat_level` has invalid type: {qualified_type_name(compat_level)!r}"
+            raise TypeError(msg)
+        return self._s.to_arrow_chunks(compat_level_py)
+
     def to_pandas(
         self, *, use_pyarrow_extension_array: bool = False, **kwargs: Any
     ) -> pd.Series[Any]:
@@ -5779,6 +6153,78 @@ class Series:
         ]
         """
 
+    def decimal_arithmetic(
+        self,
+        other: Series | int,
+        op: DecimalArithmeticOp,
+        *,
+        rounding: RoundMode = "half_to_even",
+        overflow: DecimalOverflow = "raise",
+    ) -> Series:
+        """
+        Apply an arithmetic operation on Decimal data with SQL precision/scale rules.
+
+        See :meth:`Expr.decimal_arithmetic` for the rules deriving the precision and
+        scale of the result.
+
+        Parameters
+        ----------
+        other
+            Decimal or integer value.
+        op : {'add', 'sub', 'mul', 'div'}
+            The arithmetic operation to apply.
+        rounding : {'half_to_even', 'half_away_from_zero', 'to_zero'}
+            How the result is rounded to its scale, see :meth:`round`.
+        overflow : {'raise', 'null'}
+            What to do when a result does not fit the result precision.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1.00", "2.50", "-7.25"], dtype=pl.Decimal(5, 2))
+        >>> s.decimal_arithmetic(3, "mul")
+        shape: (3,)
+        Series: '' [decimal[16,2]]
+        [
+                3.00
+                7.50
+                -21.75
+        ]
+        """
+
+    def checked_arithmetic(
+        self,
+        other: Series | int,
+        op: CheckedArithmeticOp,
+        *,
+        warn: bool = False,
+    ) -> Series:
+        """
+        Apply an integer arithmetic operation that produces null on overflow.
+
+        See :meth:`Expr.checked_arithmetic`.
+
+        Parameters
+        ----------
+        other
+            Integer value.
+        op : {'add', 'sub', 'mul'}
+            The arithmetic operation to apply.
+        warn
+            Raise a warning with the number of values that overflowed, if any.
+
+        Examples
+        --------
+        >>> s = pl.Series([100, -100, 5], dtype=pl.Int8)
+        >>> s.checked_arithmetic(100, "add")
+        shape: (3,)
+        Series: '' [i8]
+        [
+                null
+                0
+                105
+        ]
+        """
+
     def dot(self, other: Series | ArrayLike) -> int | float | None:
         """
         Compute the dot/inner product between two Series.
@@ -5803,7 +6249,7 @@ class Series:
             raise ShapeError(msg)
         return self._s.dot(other._s)
 
-    def mode(self, *, maintain_order: bool = False) -> Series:
+    def mode(self, *, maintain_order: bool = False, ties: ModeTies = "all") -> Series:
         """
         Compute the most occurring value(s).
 
@@ -5813,6 +6259,18 @@ class Series:
         ----------
         maintain_order
             Maintain order of data. This requires more work.
+        ties : {'all', 'first', 'smallest'}
+            Which value(s) to return if multiple values occur equally often.
+
+            - 'all': return all of them.
+            - 'first': return the one that occurs first.
+            - 'smallest': return the smallest one, nulls sort last.
+
+            With 'first' and 'smallest' the result is always a single value.
+
+        See Also
+        --------
+        modes
 
         Examples
         --------
@@ -5823,8 +6281,45 @@ class Series:
         [
                 2
         ]
+        >>> s = pl.Series("a", [3, 3, 1, 1, 2])
+        >>> s.mode(ties="smallest")
+        shape: (1,)
+        Series: 'a' [i64]
+        [
+                1
+        ]
         """
 
+    def modes(self, n: int) -> Series:
+        """
+        Get the `n` most occurring values.
+
+        The values are ordered by descending number of occurrences. Values that occur
+        equally often are ordered by their first occurrence.
+
+        Parameters
+        ----------
+        n
+            Number of values to return.
+
+        See Also
+        --------
+        mode
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [2, 2, 1, 1, 1, 3])
+        >>> s.modes(2)
+        shape: (2,)
+        Series: 'a' [i64]
+        [
+                1
+                2
+        ]
+        """
+        out = self.to_frame().select_seq(F.col(self.name).modes(n)).item()
+        return out.alias(self.name)
+
     def sign(self) -> Series:
         """
         Compute the element-wise sign function on numeric types.
@@ -6089,6 +6584,7 @@ class Series:
         return_dtype: PolarsDataType | None = None,
         *,
         skip_nulls: bool = True,
+        cache_objects: bool = False,
     ) -> Self:
         """
         Map a custom/user-defined function (UDF) over elements in this Series.
@@ -6132,6 +6628,11 @@ class Series:
             Nulls will be skipped and not passed to the python function.
             This is faster because python can be skipped and because we call
             more specialized functions.
+        cache_objects
+            Only applies to Series of dtype :class:`Object`. Call the function once
+            per distinct Python object (compared by identity) and reuse the result,
+            including its conversion to `return_dtype`, wherever the same object
+            occurs again. This can give large speedups on low-cardinality data.
 
         Warnings
         --------
@@ -6147,6 +6648,11 @@ class Series:
         * A UDF passed to `map_elements` must be pure, meaning that it cannot modify
           or depend on state other than its arguments.
 
+        * Some compiled functions are called natively instead of through Python,
+          if `skip_nulls=True`: a numba `@cfunc` taking and returning a single
+          (32 or 64 bit) number is called in parallel without holding the GIL, and a
+          unary `pyarrow.compute` function is called once on the whole Series.
+
 
         Examples
         --------
@@ -6171,10 +6677,26 @@ class Series:
         else:
             pl_return_dtype = parse_into_dtype(return_dtype)
 
+        if skip_nulls:
+            if (cfunc := get_cfunc_signature(function)) is not None:
+                address, arg_dtype, cfunc_return_dtype = cfunc
+                out = self._from_pyseries(
+                    self._s._map_elements_cfunc(address, arg_dtype, cfunc_return_dtype)
+                )
+                return out if pl_return_dtype is None else out.cast(pl_return_dtype)
+            if is_arrow_compute_function(function):
+                out = self._from_pyseries(
+                    pl.Series(self.name, function(self.to_arrow()))._s
+                )
+                return out if pl_return_dtype is None else out.cast(pl_return_dtype)
+
         warn_on_inefficient_map(function, columns=[self.name], map_target="series")
         return self._from_pyseries(
             self._s.map_elements(
-                function, return_dtype=pl_return_dtype, skip_nulls=skip_nulls
+                function,
+                return_dtype=pl_return_dtype,
+                skip_nulls=skip_nulls,
+                cache_objects=cache_objects,
             )
         )
 
@@ -7393,6 +7915,7 @@ class Series:
         *,
         min_samples: int | None = None,
         center: bool = False,
+        batch_size: int | None = None,
     ) -> Series:
         """
         Compute a custom rolling window function.
@@ -7407,7 +7930,9 @@ class Series:
         Parameters
         ----------
         function
-            Custom aggregation function.
+            Custom aggregation function. If `batch_size` is set, it is called with an
+            :class:`Array` Series with a window per row instead, and has to return a
+            value per window.
         window_size
             The length of the window in number of elements.
         weights
@@ -7418,6 +7943,11 @@ class Series:
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
+        batch_size
+            Call `function` with up to this many windows at once, which amortizes the
+            overhead of calling into Python for vectorized functions. Windows of
+            different lengths, such as those at the start of the data, are passed in
+            separate batches.
 
         Warnings
         --------
@@ -7438,6 +7968,23 @@ class Series:
                 11.0
                 17.0
         ]
+
+        Pass many windows at once to a vectorized function with `batch_size`.
+
+        >>> s.rolling_map(
+        ...     lambda windows: nansum(windows.to_numpy(), axis=1),
+        ...     window_size=3,
+        ...     batch_size=1024,
+        ... )
+        shape: (5,)
+        Series: '' [f64]
+        [
+                null
+                null
+                22.0
+                11.0
+                17.0
+        ]
         """
 
     @unstable()
@@ -8199,6 +8746,8 @@ class Series:
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        stable: bool = False,
     ) -> Series:
         """
         Hash the Series.
@@ -8215,12 +8764,21 @@ class Series:
             Random seed parameter. Defaults to `seed` if not set.
         seed_3
             Random seed parameter. Defaults to `seed` if not set.
+        stable
+            Use a hash algorithm that gives the same results across Polars versions
+            and platforms, for hashes that are persisted, such as partition or
+            deduplication keys. Only `seed` is used.
 
         Notes
         -----
-        This implementation of `hash` does not guarantee stable results
-        across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        Unless `stable` is set, this implementation of `hash` does not guarantee
+        stable results across different Polars versions. Its stability is only
+        guaranteed within a single version.
+
+        With `stable=True`, every value is encoded as a validity byte followed by its
+        little-endian payload, which is hashed with 64-bit XXH3 seeded with `seed`.
+        The encoding of every data type is described by :func:`stable_hash_version`,
+        the hashes only change together with that version.
 
         Examples
         --------
@@ -8235,6 +8793,137 @@ class Series:
         ]
         """
 
+    def hash_sha256(self, *, output: HashDigestOutput = "hex") -> Series:
+        """
+        Compute the SHA-256 digest of every string or binary value.
+
+        Strings are hashed as their UTF-8 encoded bytes and nulls are propagated.
+
+        Parameters
+        ----------
+        output : {'hex', 'binary'}
+            Return the digest as a lowercase hexadecimal string (default) or as raw
+            bytes in a `Binary` column.
+
+        See Also
+        --------
+        hash_md5
+        hash_xxh3
+
+        Examples
+        --------
+        >>> s = pl.Series("fruit", ["apple", "banana", None])
+        >>> s.hash_sha256()
+        shape: (3,)
+        Series: 'fruit' [str]
+        [
+            "3a7bd3e2360a3d29eea436fcfb7e44c7…"
+            "b493d48364afe44d11c0165cf470a416…"
+            null
+        ]
+        """
+
+    def hash_md5(self, *, output: HashDigestOutput = "hex") -> Series:
+        """
+        Compute the MD5 digest of every string or binary value.
+
+        Strings are hashed as their UTF-8 encoded bytes and nulls are propagated.
+
+        Parameters
+        ----------
+        output : {'hex', 'binary'}
+            Return the digest as a lowercase hexadecimal string (default) or as raw
+            bytes in a `Binary` column.
+
+        Notes
+        -----
+        MD5 is not collision resistant and should not be used for security purposes;
+        prefer :meth:`hash_sha256` for that.
+
+        Examples
+        --------
+        >>> s = pl.Series("fruit", ["apple", "banana", None])
+        >>> s.hash_md5()
+        shape: (3,)
+        Series: 'fruit' [str]
+        [
+            "1f3870be274f6c49b3e31a0c6728957f"
+            "72b302bf297a228a75730123efef7c41"
+            null
+        ]
+        """
+
+    def hash_xxh3(self, seed: int = 0, *, output: HashDigestOutput = "hex") -> Series:
+        """
+        Compute the 64-bit XXH3 digest of every string or binary value.
+
+        Strings are hashed as their UTF-8 encoded bytes and nulls are propagated.
+
+        Parameters
+        ----------
+        seed
+            Seed of the hash function.
+        output : {'hex', 'binary'}
+            Return the digest as a lowercase hexadecimal string (default) or as raw
+            bytes in a `Binary` column.
+
+        Notes
+        -----
+        Unlike :meth:`hash`, the result is stable across Polars versions and platforms.
+        The hex digest is the 64-bit hash in big-endian byte order.
+
+        Examples
+        --------
+        >>> s = pl.Series("fruit", ["apple", "banana", "apple"])
+        >>> s.hash_xxh3(seed=42).is_duplicated()
+        shape: (3,)
+        Series: 'fruit' [bool]
+        [
+            true
+            false
+            true
+        ]
+        """
+
+    def row_decode(
+        self,
+        schema: SchemaDict,
+        *,
+        descending: bool | Sequence[bool] = False,
+        nulls_last: bool | Sequence[bool] = False,
+    ) -> DataFrame:
+        """
+        Decode binary keys created by :meth:`DataFrame.row_encode`.
+
+        Parameters
+        ----------
+        schema
+            The schema of the encoded DataFrame.
+        descending
+            Whether the keys were encoded in descending order. Must be the same as
+            used for encoding.
+        nulls_last
+            Whether the keys were encoded with null values last. Must be the same as
+            used for encoding.
+
+        Notes
+        -----
+        The encoding is stable across Polars versions, so keys can be decoded by a
+        different version than the one that created them, as long as the
+        :func:`row_encoding_version` is the same.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [2, None, 1], "b": ["x", "y", "z"]})
+        >>> keys = df.row_encode(descending=[True, False])
+        >>> keys.row_decode(df.schema, descending=[True, False]).equals(df)
+        True
+        """
+        schema = Schema(schema)
+        descending = extend_bool(descending, len(schema), "descending", "schema")
+        nulls_last = extend_bool(nulls_last, len(schema), "nulls_last", "schema")
+        return wrap_df(self._s.row_decode(schema, descending, nulls_last))
+
     def reinterpret(
         self,
         *,
@@ -8313,16 +9002,39 @@ class Series:
         ]
         """
 
-    def interpolate_by(self, by: IntoExpr) -> Series:
+    def interpolate_by(
+        self,
+        by: IntoExpr,
+        *,
+        method: InterpolateByMethod = "linear",
+        max_gap: int | float | timedelta | IntoExpr | None = None,
+    ) -> Series:
         """
         Interpolate intermediate values with x-coordinate based on another column.
 
-        Nulls at the beginning and end of the series remain null.
+        Nulls at the beginning and end of the series remain null, unless they are
+        filled by the `'forward'` or `'backward'` method.
 
         Parameters
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'forward', 'backward', 'cubic_spline'}
+            Interpolation method.
+
+            - 'linear': linear interpolation between the surrounding values.
+            - 'nearest': the surrounding value that is closest in `by`.
+            - 'forward': the previous value.
+            - 'backward': the next value.
+            - 'cubic_spline': a natural cubic spline through all non-null values.
+
+            The `'linear'` and `'cubic_spline'` methods return a float Series, the
+            other methods preserve the data type.
+        max_gap
+            Only fill nulls when the distance in `by` is at most this value. For the
+            `'forward'` and `'backward'` methods this is the distance to the value
+            that is propagated, for the other methods the distance between the
+            surrounding non-null values. Use a timedelta if `by` is temporal.
 
         Examples
         --------
@@ -8339,6 +9051,18 @@ class Series:
             2.714286
             3.0
         ]
+
+        Propagate the previous value if it is at most 2 apart in `by`.
+
+        >>> s.interpolate_by(by, method="forward", max_gap=2)
+        shape: (4,)
+        Series: '' [i64]
+        [
+            1
+            1
+            null
+            3
+        ]
         """
 
     def abs(self) -> Series:
@@ -8372,7 +9096,7 @@ class Series:
 
         Parameters
         ----------
-        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random'}
+        method : {'average', 'min', 'max', 'dense', 'ordinal', 'random', 'percent'}
             The method used to assign ranks to tied elements.
             The following methods are available (default is 'average'):
 
@@ -8390,6 +9114,9 @@ class Series:
               the order that the values occur in the Series.
             - 'random' : Like 'ordinal', but the rank for ties is not dependent
               on the order that the values occur in the Series.
+            - 'percent' : The relative rank `(min_rank - 1) / (n - 1)` of each
+              value, where `n` is the number of non-null values. This ranges from
+              0.0 to 1.0 and is referred to as `PERCENT_RANK` in SQL.
         descending
             Rank in descending order.
         seed
@@ -9586,13 +10313,85 @@ class Series:
         """
         return self._s.last(ignore_nulls=ignore_nulls)
 
-    def approx_n_unique(self) -> PythonLiteral | None:
+    def approx_n_unique(self, precision: int | None = None) -> PythonLiteral | None:
         """
         Approximate count of unique values.
 
         This is done using the HyperLogLog++ algorithm for cardinality estimation.
+
+        Parameters
+        ----------
+        precision
+            Use a HyperLogLog sketch with `2^precision` registers, which must be
+            between 4 and 18. A higher precision gives a smaller error, the relative
+            standard error is about `1.04 / sqrt(2^precision)`. By default the
+            precision is chosen by the engine.
+        """
+        if precision is None:
+            return self._s.approx_n_unique()
+        return (
+            self.to_frame()
+            .select_seq(F.col(self.name).approx_n_unique(precision))
+            .item()
+        )
+
+    def hll_sketch(self, precision: int = 14) -> bytes:
+        """
+        Get the HyperLogLog sketch of the values as a binary.
+
+        Sketches can be stored and merged with :meth:`hll_merge`, also across datasets
+        and processes. Use :meth:`hll_estimate` to get the approximate count of unique
+        values of a sketch.
+
+        Values are hashed with XXH3 and a fixed seed, and categorical values by their
+        string, see :meth:`Expr.hll_sketch`.
+
+        Parameters
+        ----------
+        precision
+            The sketch has `2^precision` registers, which must be between 4 and 18. A
+            higher precision gives a smaller error, the relative standard error is
+            about `1.04 / sqrt(2^precision)`. Only sketches of the same precision can
+            be merged.
+
+        Examples
+        --------
+        >>> s1 = pl.Series("id", [1, 2, 3])
+        >>> s2 = pl.Series("id", [3, 4])
+        >>> sketches = pl.Series([s1.hll_sketch(), s2.hll_sketch()])
+        >>> pl.Series([sketches.hll_merge()]).hll_estimate().item()
+        4
+        """
+        return self.to_frame().select_seq(F.col(self.name).hll_sketch(precision)).item()
+
+    def hll_merge(self) -> bytes | None:
+        """
+        Merge binary HyperLogLog sketches into a single sketch.
+
+        The sketches must have been created with :meth:`hll_sketch` with the same
+        precision. Null values are ignored, the result is `None` if all values are
+        null.
+        """
+        return self.to_frame().select_seq(F.col(self.name).hll_merge()).item()
+
+    def hll_estimate(self) -> Series:
+        """
+        Get the approximate count of unique values of every binary HyperLogLog sketch.
+
+        The sketches must have been created with :meth:`hll_sketch` or
+        :meth:`hll_merge`.
+
+        Examples
+        --------
+        >>> s = pl.Series("id", [1, 2, 2, 3])
+        >>> pl.Series("sketch", [s.hll_sketch(), None]).hll_estimate()
+        shape: (2,)
+        Series: 'sketch' [u32]
+        [
+                3
+                null
+        ]
         """
-        return self._s.approx_n_unique()
 
     def _row_encode(
         self,
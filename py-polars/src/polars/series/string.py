@@ -11,11 +11,12 @@ from polars._utils.wrap import wrap_s
 from polars.datatypes import Int64
 from polars.datatypes.classes import Datetime
 from polars.datatypes.constants import N_INFER_DEFAULT
+from polars.series.url import UrlNameSpace
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
     import sys
-    from collections.abc import Mapping
+    from collections.abc import Iterable, Mapping, Sequence
 
     from polars import Expr, Series
     from polars._plr import PySeries
@@ -27,6 +28,7 @@ if TYPE_CHECKING:
         PolarsIntegerType,
         PolarsTemporalType,
         TimeUnit,
+        TokenizeMethod,
         TransferEncoding,
         UnicodeForm,
     )
@@ -47,6 +49,11 @@ class StringNameSpace:
     def __init__(self, series: Series) -> None:
         self._s: PySeries = series._s
 
+    @property
+    def url(self) -> UrlNameSpace:
+        """Create an object namespace of all URL related methods."""
+        return UrlNameSpace(wrap_s(self._s))
+
     def to_date(
         self,
         format: str | None = None,
@@ -92,7 +99,7 @@ class StringNameSpace:
 
     def to_datetime(
         self,
-        format: str | None = None,
+        format: str | Sequence[str] | None = None,
         *,
         time_unit: TimeUnit | None = None,
         time_zone: str | None = None,
@@ -100,6 +107,8 @@ class StringNameSpace:
         exact: bool = True,
         cache: bool = True,
         ambiguous: Ambiguous | pl.Series = "raise",
+        best_effort: bool = False,
+        include_format: bool = False,
     ) -> pl.Series:
         """
         Convert a String column into a Datetime column.
@@ -111,11 +120,14 @@ class StringNameSpace:
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for the full specification. Example: `"%Y-%m-%d %H:%M:%S"`.
             If set to None (default), the format is inferred from the data.
+            A sequence of formats may also be given; these are tried in order for
+            every value, and the first format that parses the value is used.
         time_unit : {None, 'us', 'ns', 'ms'}
             Unit of time for the resulting Datetime column. If set to None (default),
             the time unit is inferred from the format string if given, eg:
             `"%F %T%.3f"` => `Datetime("ms")`. If no fractional second component is
-            found, the default is `"us"`.
+            found, the default is `"us"`. With several formats, the finest time unit
+            required by any of them is used.
         time_zone
             Time zone for the resulting Datetime column. Rules are:
 
@@ -146,6 +158,13 @@ class StringNameSpace:
             - `'earliest'`: use the earliest datetime
             - `'latest'`: use the latest datetime
             - `'null'`: set to null
+        best_effort
+            Infer the format of the values that could not be parsed with any of the
+            given formats (repeatedly, as the values may use several other formats).
+        include_format
+            Return a struct with a `value` field holding the parsed datetime, and a
+            `format` field recording which of the given formats parsed it (this is
+            null for values that were parsed by `best_effort` inference).
 
         Examples
         --------
@@ -157,8 +176,25 @@ class StringNameSpace:
                 2020-01-01 01:00:00 UTC
                 2020-01-01 02:00:00 UTC
         ]
+
+        Parse values that mix several formats, recording which format matched:
+
+        >>> s = pl.Series(["2024-01-31 10:15", "31/01/2024 08:00"])
+        >>> s.str.to_datetime(
+        ...     ["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M"], include_format=True
+        ... ).struct.field("format")
+        shape: (2,)
+        Series: 'format' [str]
+        [
+                "%Y-%m-%d %H:%M"
+                "%d/%m/%Y %H:%M"
+        ]
         """
-        if format is None and time_zone is None:
+        if (
+            format is None
+            and time_zone is None
+            and not (best_effort or include_format)
+        ):
             if isinstance(ambiguous, str):
                 ambiguous_s = pl.Series([ambiguous])
             else:
@@ -186,6 +222,8 @@ class StringNameSpace:
                         exact=exact,
                         cache=cache,
                         ambiguous=ambiguous_expr,
+                        best_effort=best_effort,
+                        include_format=include_format,
                     )
                 )
                 .to_series()
@@ -685,8 +723,10 @@ class StringNameSpace:
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            Base64 that uses `-` and `_` instead of `+` and `/`. Base64 values are
+            accepted with or without `=` padding.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -709,14 +749,18 @@ class StringNameSpace:
         ]
         """
 
-    def encode(self, encoding: TransferEncoding) -> Series:
+    def encode(self, encoding: TransferEncoding, *, padding: bool = True) -> Series:
         """
         Encode a value using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            Base64 that uses `-` and `_` instead of `+` and `/`.
+        padding
+            Pad Base64 encoded values with `=` to a multiple of four characters.
+            Ignored for hex encoding.
 
         Returns
         -------
@@ -736,6 +780,69 @@ class StringNameSpace:
         ]
         """
 
+    def parse_ip(self, *, strict: bool = True) -> Series:
+        r"""
+        Parse IPv4 and IPv6 addresses.
+
+        The addresses are stored as 4 (IPv4) or 16 (IPv6) bytes in network byte order,
+        which is the input expected by the methods of the :attr:`Series.ip`
+        namespace.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a value is not a valid IP address, otherwise mask out
+            with a null value.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["192.168.0.1", "not an ip"])
+        >>> s.str.parse_ip(strict=False)
+        shape: (2,)
+        Series: 'ip' [binary]
+        [
+            b"\xc0\xa8\x00\x01"
+            null
+        ]
+        """
+
+    def parse_uuid(self, *, strict: bool = True) -> Series:
+        """
+        Parse UUIDs.
+
+        The UUIDs are stored as 16 bytes, which is more compact and faster to compare
+        than their string form. Besides the canonical hyphenated form, the simple,
+        braced and URN forms are accepted, in any case. Use
+        :meth:`Series.bin.to_uuid_str` to convert back to the canonical form.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a value is not a valid UUID, otherwise mask out with a
+            null value.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series("id", ["67e55044-10b1-426f-9247-bb680e5fe0c8", "not a uuid"])
+        >>> s.str.parse_uuid(strict=False).bin.encode("hex")
+        shape: (2,)
+        Series: 'id' [str]
+        [
+            "67e5504410b1426f9247bb680e5fe0c8"
+            null
+        ]
+        """
+
     def json_decode(
         self,
         dtype: PolarsDataType | None = None,
@@ -783,12 +890,18 @@ class StringNameSpace:
 
         return wrap_s(self._s.str_json_decode(infer_schema_length))
 
-    def json_path_match(self, json_path: IntoExprColumn) -> Series:
+    def json_path_match(
+        self,
+        json_path: IntoExprColumn,
+        *,
+        all_matches: bool = False,
+        dtype: PolarsDataType | pl.DataTypeExpr | None = None,
+    ) -> Series:
         """
         Extract the first match of JSON string with provided JSONPath expression.
 
-        Throw errors if encounter invalid JSON strings.
-        All return values will be cast to String regardless of the original value.
+        Throw errors if encounter invalid JSON strings. Unless a `dtype` is given,
+        all return values will be cast to String regardless of the original value.
 
         Documentation on JSONPath standard can be found
         `here <https://goessner.net/articles/JsonPath/>`_.
@@ -797,12 +910,17 @@ class StringNameSpace:
         ----------
         json_path
             A valid JSON path query string.
+        all_matches
+            Return all matches as a list instead of only the first match.
+        dtype
+            Decode the matched values as this data type.
 
         Returns
         -------
         Series
-            Series of data type :class:`String`. Contains null values if the original
-            value is null or the json_path returns nothing.
+            Series of data type :class:`String`, or `dtype` if given. Contains null
+            values if the original value is null or the json_path returns nothing.
+            With `all_matches`, this is a :class:`List` of these values.
 
         Examples
         --------
@@ -819,6 +937,59 @@ class StringNameSpace:
             "2.1"
             "true"
         ]
+
+        Extract all matches as a list of integers:
+
+        >>> s = pl.Series("json_val", ['{"a":[1,2,3]}', '{"a":[]}', None])
+        >>> s.str.json_path_match("$.a[*]", all_matches=True, dtype=pl.Int64)
+        shape: (3,)
+        Series: 'json_val' [list[i64]]
+        [
+            [1, 2, 3]
+            []
+            null
+        ]
+        """
+
+    def json_extract_many(
+        self, paths: Sequence[str] | Mapping[str, PolarsDataType]
+    ) -> Series:
+        """
+        Extract the first match of each of the given JSONPaths into a struct.
+
+        Every JSON string is parsed only once, which is faster than calling
+        :meth:`json_path_match` for each path.
+
+        Parameters
+        ----------
+        paths
+            The JSONPath query strings. The fields of the resulting struct are named
+            after them. If a mapping is given, the matches of every path are decoded
+            as the data type it maps to. Otherwise they are cast to :class:`String`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct`.
+
+        See Also
+        --------
+        json_path_match
+
+        Examples
+        --------
+        >>> s = pl.Series("json", ['{"a":1,"b":{"c":"x"}}', '{"a":2,"b":{"c":"y"}}'])
+        >>> dtypes = {"$.a": pl.Int64, "$.b.c": pl.String}
+        >>> s.str.json_extract_many(dtypes).struct.unnest()
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ $.a ┆ $.b.c │
+        │ --- ┆ ---   │
+        │ i64 ┆ str   │
+        ╞═════╪═══════╡
+        │ 1   ┆ x     │
+        │ 2   ┆ y     │
+        └─────┴───────┘
         """
 
     def extract(self, pattern: IntoExprColumn, group_index: int = 1) -> Series:
@@ -1191,6 +1362,54 @@ class StringNameSpace:
             :class:`String`.
         """
 
+    def split_regex(
+        self,
+        pattern: str | Expr,
+        n: int | None = None,
+        *,
+        inclusive: bool = False,
+        strict: bool = True,
+    ) -> Series:
+        r"""
+        Split the string by a regex pattern.
+
+        Unlike :meth:`splitn`, the result is always a list of strings, so values
+        with fewer than `n-1` matches produce shorter lists rather than nulls.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_. An empty pattern splits the
+            string into individual characters.
+        n
+            Max number of items to return. If the number of possible splits is `n-1`
+            or greater, the last (nth) item will contain the remainder of the string.
+            By default there is no limit.
+        inclusive
+            If True, keep each match at the end of the preceding item.
+        strict
+            Raise an error if the underlying pattern is not a valid regex,
+            otherwise mask out with a null value.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(String)`.
+
+        Examples
+        --------
+        >>> s = pl.Series("s", ["foo  bar baz", "one   two", None])
+        >>> s.str.split_regex(r"\s+", n=2)
+        shape: (3,)
+        Series: 's' [list[str]]
+        [
+            ["foo", "bar baz"]
+            ["one", "two"]
+            null
+        ]
+        """
+
     def replace(
         self, pattern: str, value: str, *, literal: bool = False, n: int = 1
     ) -> Series:
@@ -1656,6 +1875,28 @@ class StringNameSpace:
         ]
         """
 
+    def casefold(self) -> Series:
+        """
+        Apply full Unicode case folding to the strings.
+
+        Case folding is intended for caseless matching: unlike :meth:`to_lowercase`,
+        it also maps characters such as "ß" to "ss" and "ﬁ" to "fi", so that strings
+        which only differ by case compare as equal after folding. Combine with
+        :meth:`normalize` to also ignore differences in Unicode composition.
+
+        Examples
+        --------
+        >>> s = pl.Series("word", ["Straße", "STRASSE", "ﬁle"])
+        >>> s.str.casefold()
+        shape: (3,)
+        Series: 'word' [str]
+        [
+            "strasse"
+            "strasse"
+            "file"
+        ]
+        """  # noqa: RUF002
+
     def to_uppercase(self) -> Series:
         """
         Modify strings to their uppercase equivalent.
@@ -1719,6 +1960,150 @@ class StringNameSpace:
         ]
         """
 
+    def levenshtein_distance(self, other: IntoExpr) -> Series:
+        """
+        Compute the Levenshtein (edit) distance to another string.
+
+        The distance is the minimum number of single-character insertions, deletions
+        and substitutions needed to change one string into the other.
+
+        Parameters
+        ----------
+        other
+            The string (or string Series/expression) to compare against; comparisons
+            are made by character, and null values result in null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["kitten", "flaw", "martha", None])
+        >>> s.str.levenshtein_distance(pl.Series(["sitting", "lawn", "marhta", "x"]))
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+            3
+            2
+            2
+            null
+        ]
+        """
+
+    def hamming(self, other: IntoExpr) -> Series:
+        """
+        Compute the Hamming distance to another string.
+
+        The distance is the number of positions at which the characters differ;
+        strings with a different number of characters result in null.
+
+        Parameters
+        ----------
+        other
+            The string (or string Series/expression) to compare against; comparisons
+            are made by character, and null values result in null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["kitten", "flaw", "martha", None])
+        >>> s.str.hamming(pl.Series(["sitting", "lawn", "marhta", "x"]))
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+            null
+            4
+            2
+            null
+        ]
+        """
+
+    def jaro_winkler(self, other: IntoExpr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity ranges from 0 (no similarity) to 1 (exact match), with
+        strings that share a common prefix (of up to 4 characters) scoring higher.
+
+        Parameters
+        ----------
+        other
+            The string (or string Series/expression) to compare against; comparisons
+            are made by character, and null values result in null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["kitten", "flaw", "martha", None])
+        >>> s.str.jaro_winkler(pl.Series(["sitting", "lawn", "marhta", "x"]))
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+            0.746032
+            0.833333
+            0.961111
+            null
+        ]
+        """
+
+    def tokenize(
+        self,
+        method: TokenizeMethod = "unicode_words",
+        *,
+        pattern: str | None = None,
+        lowercase: bool = False,
+        stop_words: Iterable[str] | None = None,
+    ) -> Series:
+        """
+        Split each string into a list of tokens.
+
+        Parameters
+        ----------
+        method : {'unicode_words', 'whitespace', 'pattern'}
+            How to split the strings into tokens:
+
+            - `'unicode_words'` (default): words as delimited by the Unicode word
+              boundary rules (UAX #29); punctuation and whitespace are skipped.
+            - `'whitespace'`: runs of non-whitespace characters.
+            - `'pattern'`: every (non-overlapping) match of the regular expression
+              given by `pattern`.
+        pattern
+            The regular expression to match tokens with; must be given if and only
+            if `method` is `'pattern'`.
+        lowercase
+            Lowercase the strings before tokenizing them.
+        stop_words
+            Tokens to remove from the output; these are compared after lowercasing,
+            so should be given in lowercase if `lowercase` is set.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List(String)`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["The cat sat.", "Cats can't FLY!", None])
+        >>> s.str.tokenize(lowercase=True, stop_words=["the"])
+        shape: (3,)
+        Series: '' [list[str]]
+        [
+            ["cat", "sat"]
+            ["cats", "can't", "fly"]
+            null
+        ]
+        """
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Series:
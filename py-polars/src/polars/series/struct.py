@@ -67,7 +67,8 @@ class StructNameSpace:
         Parameters
         ----------
         name
-            Name of the field.
+            Name of the field. A dotted path like `"a.b"` retrieves field `b` of
+            the struct field `a`, unless there is a field named `"a.b"`.
 
         Examples
         --------
@@ -0,0 +1,151 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import Series
+    from polars._plr import PySeries
+
+
+@expr_dispatch
+class UrlNameSpace:
+    """Series.str.url namespace."""
+
+    _accessor = "str.url"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def scheme(self) -> Series:
+        """
+        Extract the scheme of the URLs.
+
+        The scheme is normalized to lowercase. Values that are not absolute URLs give
+        null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["https://pola.rs/posts/", "HTTP://localhost/", "/relative"])
+        >>> s.str.url.scheme()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "https"
+            "http"
+            null
+        ]
+        """
+
+    def host(self) -> Series:
+        """
+        Extract the host of the URLs.
+
+        The host is the domain name or IP address, without the port. Values that are
+        not absolute URLs or that have no host give null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["https://pola.rs/posts/", "mailto:someone@pola.rs"])
+        >>> s.str.url.host()
+        shape: (2,)
+        Series: '' [str]
+        [
+            "pola.rs"
+            null
+        ]
+        """
+
+    def path(self) -> Series:
+        """
+        Extract the path of the URLs.
+
+        The path is returned percent-encoded. Values that are not absolute URLs give
+        null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["https://pola.rs/posts/?page=2", "https://pola.rs/a%20b"])
+        >>> s.str.url.path()
+        shape: (2,)
+        Series: '' [str]
+        [
+            "/posts/"
+            "/a%20b"
+        ]
+        """
+
+    def query_params(self, keys: str | Sequence[str] | None = None) -> Series:
+        """
+        Decode the query parameters of the URLs.
+
+        Keys and values are percent-decoded and `+` is decoded as a space.
+
+        Parameters
+        ----------
+        keys
+            The keys to extract. If given, the result is a struct with a field per key
+            holding the first value of that key, or null if the key does not occur.
+            Otherwise the result is a list of `{key, value}` structs holding all
+            parameters in order of appearance.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct` if `keys` is given, otherwise of data
+            type :class:`List` of :class:`Struct`.
+
+        Examples
+        --------
+        >>> s = pl.Series("url", ["https://a.com/?q=polars&page=2", "https://a.com/"])
+        >>> s.str.url.query_params("q")
+        shape: (2,)
+        Series: 'url' [struct[1]]
+        [
+            {"polars"}
+            {null}
+        ]
+        """
+
+    def decode_percent(self) -> Series:
+        """
+        Decode the `%XX` escapes of the values.
+
+        Unlike :meth:`query_params`, `+` is left as is. Values that do not decode to
+        valid UTF-8 give null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["caf%C3%A9", "a%20b+c", "%FF"])
+        >>> s.str.url.decode_percent()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "café"
+            "a b+c"
+            null
+        ]
+        """
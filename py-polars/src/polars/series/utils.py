@@ -1,14 +1,25 @@
 from __future__ import annotations
 
+import functools
 import inspect
+import operator
 import sys
 from functools import wraps
 from typing import TYPE_CHECKING, Any, TypeVar
 
 import polars._reexport as pl
 from polars import functions as F
+from polars._dependencies import numpy as np
 from polars._utils.wrap import wrap_s
-from polars.datatypes import dtype_to_ffiname
+from polars.datatypes import (
+    Float32,
+    Float64,
+    Int32,
+    Int64,
+    UInt32,
+    UInt64,
+    dtype_to_ffiname,
+)
 
 if TYPE_CHECKING:
     from collections.abc import Callable
@@ -64,9 +75,9 @@ def _expr_lookup(namespace: str | None) -> set[tuple[str | None, str, tuple[str,
     expr = pl.Expr()
     expr._pyexpr = None  # type: ignore[assignment]
 
-    # optional indirection to "expr.str", "expr.dt", etc
+    # optional indirection to "expr.str", "expr.dt", "expr.str.url", etc
     if namespace is not None:
-        expr = getattr(expr, namespace)
+        expr = _get_namespace(expr, namespace)
 
     lookup = set()
     for name in dir(expr):
@@ -84,6 +95,13 @@ def _expr_lookup(namespace: str | None) -> set[tuple[str | None, str, tuple[str,
     return lookup
 
 
+def _get_namespace(expr: Any, namespace: str) -> Any:
+    """Resolve a (possibly nested) namespace such as "str" or "str.url"."""
+    for accessor in namespace.split("."):
+        expr = getattr(expr, accessor)
+    return expr
+
+
 def _undecorated(function: Callable[P, T]) -> Callable[P, T]:
     """Return the given function without any decorators."""
     while hasattr(function, "__wrapped__"):
@@ -99,7 +117,7 @@ def call_expr(func: SeriesMethod) -> SeriesMethod:
         s = wrap_s(self._s)
         expr = F.col(s.name)
         if (namespace := getattr(self, "_accessor", None)) is not None:
-            expr = getattr(expr, namespace)
+            expr = _get_namespace(expr, namespace)
         f = getattr(expr, func.__name__)
         return s.to_frame().select_seq(f(*args, **kwargs)).to_series()
 
@@ -174,6 +192,105 @@ def get_ffi_func(
     return getattr(obj, fname, None)
 
 
+@functools.lru_cache(maxsize=1)
+def _native_ufuncs() -> dict[Any, Callable[..., Series]]:
+    # Elementwise numpy ufuncs that have an equivalent native Polars operation.
+    return {
+        # arithmetic
+        np.add: operator.add,
+        np.subtract: operator.sub,
+        np.multiply: operator.mul,
+        np.true_divide: operator.truediv,
+        np.floor_divide: operator.floordiv,
+        np.remainder: operator.mod,
+        np.negative: operator.neg,
+        np.absolute: operator.abs,
+        # comparison
+        np.equal: operator.eq,
+        np.not_equal: operator.ne,
+        np.less: operator.lt,
+        np.less_equal: operator.le,
+        np.greater: operator.gt,
+        np.greater_equal: operator.ge,
+        # exponential and logarithmic
+        np.exp: lambda s: s.exp(),
+        np.log: lambda s: s.log(),
+        np.log10: lambda s: s.log10(),
+        np.log1p: lambda s: s.log1p(),
+        np.sqrt: lambda s: s.sqrt(),
+        np.cbrt: lambda s: s.cbrt(),
+        # trigonometric
+        np.sin: lambda s: s.sin(),
+        np.cos: lambda s: s.cos(),
+        np.tan: lambda s: s.tan(),
+        np.arcsin: lambda s: s.arcsin(),
+        np.arccos: lambda s: s.arccos(),
+        np.arctan: lambda s: s.arctan(),
+        np.sinh: lambda s: s.sinh(),
+        np.cosh: lambda s: s.cosh(),
+        np.tanh: lambda s: s.tanh(),
+    }
+
+
+def get_native_ufunc(
+    ufunc: Any, method: str, inputs: tuple[Any, ...], kwargs: dict[str, Any]
+) -> Callable[..., Series] | None:
+    """
+    Get the native Polars implementation of a numpy ufunc call, if there is one.
+
+    Only plain calls of elementwise ufuncs on numeric Series and Python scalars are
+    dispatched natively; anything else (e.g. `out=`, `dtype=` or `where=` arguments,
+    or numpy array inputs) has to go through numpy.
+    """
+    if method != "__call__" or kwargs:
+        return None
+    for arg in inputs:
+        if isinstance(arg, pl.Series):
+            if not arg.dtype.is_numeric():
+                return None
+        elif not isinstance(arg, (int, float)):
+            return None
+    return _native_ufuncs().get(ufunc)
+
+
+_NUMBA_TYPES_TO_DTYPES: dict[str, PolarsDataType] = {
+    "int32": Int32,
+    "int64": Int64,
+    "uint32": UInt32,
+    "uint64": UInt64,
+    "float32": Float32,
+    "float64": Float64,
+}
+
+
+def get_cfunc_signature(
+    function: Any,
+) -> tuple[int, PolarsDataType, PolarsDataType] | None:
+    """
+    Get the address and signature of a numba `@cfunc` taking and returning a number.
+
+    Such functions can be called by Polars without holding the GIL.
+    """
+    if not type(function).__module__.startswith("numba"):
+        return None
+    address = getattr(function, "address", None)
+    sig = getattr(function, "_sig", None)
+    if not isinstance(address, int) or sig is None or len(sig.args) != 1:
+        return None
+
+    arg_dtype = _NUMBA_TYPES_TO_DTYPES.get(str(sig.args[0]))
+    return_dtype = _NUMBA_TYPES_TO_DTYPES.get(str(sig.return_type))
+    if arg_dtype is None or return_dtype is None:
+        return None
+    return address, arg_dtype, return_dtype
+
+
+def is_arrow_compute_function(function: Any) -> bool:
+    """Whether `function` is a unary `pyarrow.compute` function."""
+    meta = getattr(function, "__arrow_compute_function__", None)
+    return isinstance(meta, dict) and meta.get("arity") == 1
+
+
 def _with_no_check_length(func: Callable[..., Any]) -> Any:
     from polars._plr import check_length
 
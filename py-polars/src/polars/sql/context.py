@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import contextlib
+from collections.abc import Mapping
 from typing import (
     TYPE_CHECKING,
     Generic,
@@ -15,7 +16,7 @@ from polars._utils.deprecation import deprecate_renamed_parameter
 from polars._utils.pycapsule import is_pycapsule
 from polars._utils.unstable import issue_unstable_warning
 from polars._utils.various import _get_stack_locals, qualified_type_name
-from polars._utils.wrap import wrap_ldf
+from polars._utils.wrap import wrap_expr, wrap_ldf
 from polars.convert import from_arrow, from_pandas
 from polars.dataframe import DataFrame
 from polars.lazyframe import LazyFrame
@@ -26,10 +27,14 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
 if TYPE_CHECKING:
     import sys
-    from collections.abc import Callable, Collection, Mapping
+    from collections.abc import Callable, Collection, Sequence
     from types import TracebackType
     from typing import Any, Final, Literal, TypeAlias
 
+    from polars._plr import PyExpr
+    from polars._typing import PolarsDataType
+    from polars.expr import Expr
+
     if sys.version_info >= (3, 11):
         from typing import Self
     else:
@@ -44,6 +49,8 @@ if TYPE_CHECKING:
         | pa.Table
         | pa.RecordBatch
     )
+    SQLParams: TypeAlias = Sequence[Any] | Mapping[str, Any]
+    SQLDialect: TypeAlias = Literal["generic", "postgresql", "mysql", "bigquery"]
 
 __all__ = ["SQLContext"]
 
@@ -115,6 +122,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         eager: Literal[False] = False,
+        dialect: SQLDialect | None = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -125,6 +133,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         eager: Literal[True],
+        dialect: SQLDialect | None = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -135,6 +144,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         eager: bool,
+        dialect: SQLDialect | None = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -145,6 +155,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = False,
         eager: bool = False,
+        dialect: SQLDialect | None = None,
         **named_frames: CompatibleFrameType | None,
     ) -> None:
         """
@@ -168,6 +179,13 @@ class SQLContext(Generic[FrameType]):
             If True, returns execution results as `DataFrame` instead of `LazyFrame`.
             (Note that the query itself is always executed in lazy-mode; this parameter
             impacts whether :meth:`execute` returns an eager or lazy result frame).
+        dialect : {'generic', 'postgresql', 'mysql', 'bigquery'}
+            The SQL dialect used to parse and interpret queries (default: "generic",
+            a permissive superset of the common SQL syntaxes). The other dialects
+            adjust identifier quoting (backticks for "mysql" and "bigquery", where
+            double quotes delimit strings), the `||` operator (logical OR for "mysql"),
+            division (`/` always returns a float for "mysql" and "bigquery"), and
+            recognise dialect-specific function names (such as `UCASE` for "mysql").
         **named_frames
             Named eager/lazy frames, provided as kwargs.
 
@@ -187,11 +205,27 @@ class SQLContext(Generic[FrameType]):
         │ x   ┆ 2     │
         │ z   ┆ 6     │
         └─────┴───────┘
+
+        Use MySQL syntax (backtick-quoted identifiers, float division, `UCASE`):
+
+        >>> pl.SQLContext(frame=lf, dialect="mysql").execute(
+        ...     "SELECT `a` / 2 AS half_a, UCASE(b) AS b FROM frame WHERE a > 1",
+        ...     eager=True,
+        ... )
+        shape: (2, 2)
+        ┌────────┬──────┐
+        │ half_a ┆ b    │
+        │ ---    ┆ ---  │
+        │ f64    ┆ str  │
+        ╞════════╪══════╡
+        │ 1.0    ┆ null │
+        │ 1.5    ┆ Z    │
+        └────────┴──────┘
         """
         issue_unstable_warning(
             "`SQLContext` is considered **unstable**, although it is close to being considered stable."
         )
-        self._ctxt = PySQLContext.new()
+        self._ctxt = PySQLContext.new(dialect)
         self._eager_execution = eager
 
         frames = dict(frames or {})
@@ -311,41 +345,73 @@ class SQLContext(Generic[FrameType]):
 
     @overload
     def execute(
-        self: SQLContext[DataFrame], query: str, *, eager: None = ...
+        self: SQLContext[DataFrame],
+        query: str,
+        *,
+        eager: None = ...,
+        params: SQLParams | None = ...,
     ) -> DataFrame: ...
 
     @overload
     def execute(
-        self: SQLContext[DataFrame], query: str, *, eager: Literal[False]
+        self: SQLContext[DataFrame],
+        query: str,
+        *,
+        eager: Literal[False],
+        params: SQLParams | None = ...,
     ) -> LazyFrame: ...
 
     @overload
     def execute(
-        self: SQLContext[DataFrame], query: str, *, eager: Literal[True]
+        self: SQLContext[DataFrame],
+        query: str,
+        *,
+        eager: Literal[True],
+        params: SQLParams | None = ...,
     ) -> DataFrame: ...
 
     @overload
     def execute(
-        self: SQLContext[LazyFrame], query: str, *, eager: None = ...
+        self: SQLContext[LazyFrame],
+        query: str,
+        *,
+        eager: None = ...,
+        params: SQLParams | None = ...,
     ) -> LazyFrame: ...
 
     @overload
     def execute(
-        self: SQLContext[LazyFrame], query: str, *, eager: Literal[False]
+        self: SQLContext[LazyFrame],
+        query: str,
+        *,
+        eager: Literal[False],
+        params: SQLParams | None = ...,
     ) -> LazyFrame: ...
 
     @overload
     def execute(
-        self: SQLContext[LazyFrame], query: str, *, eager: Literal[True]
+        self: SQLContext[LazyFrame],
+        query: str,
+        *,
+        eager: Literal[True],
+        params: SQLParams | None = ...,
     ) -> DataFrame: ...
 
     @overload
     def execute(
-        self, query: str, *, eager: bool | None = ...
+        self,
+        query: str,
+        *,
+        eager: bool | None = ...,
+        params: SQLParams | None = ...,
     ) -> LazyFrame | DataFrame: ...
 
     def execute(
-        self, query: str, *, eager: bool | None = None
+        self,
+        query: str,
+        *,
+        eager: bool | None = None,
+        params: SQLParams | None = None,
     ) -> LazyFrame | DataFrame:
         """
         Parse the given SQL query and execute it against the registered frame data.
@@ -359,6 +425,12 @@ class SQLContext(Generic[FrameType]):
             If unset, the value of the init-time "eager" parameter will be used.
             Note that the query itself is always executed in lazy-mode; this
             parameter only impacts the type of the returned frame.
+        params
+            Values to bind to placeholders in the query; a sequence binds positional
+            placeholders (`$1`, `$2`, ... or `?`, in order of appearance) and a mapping
+            binds named placeholders (`:name` or `$name`). Values are substituted as
+            typed literals after the query is parsed, so they are never interpreted
+            as SQL.
 
         Examples
         --------
@@ -424,8 +496,31 @@ class SQLContext(Generic[FrameType]):
         │ 1990   ┆ 232338648   ┆ 3       │
         │ 1970   ┆ 134821952   ┆ 1       │
         └────────┴─────────────┴─────────┘
+
+        Bind values to placeholders in a parameterized query:
+
+        >>> ctx.execute(
+        ...     "SELECT title FROM films WHERE release_year = $1 AND imdb_score > $2",
+        ...     params=[1994, 9.0],
+        ...     eager=True,
+        ... )
+        shape: (1, 1)
+        ┌──────────────────────────┐
+        │ title                    │
+        │ ---                      │
+        │ str                      │
+        ╞══════════════════════════╡
+        │ The Shawshank Redemption │
+        └──────────────────────────┘
         """
-        res = wrap_ldf(self._ctxt.execute(query))
+        if params is None:
+            res = wrap_ldf(self._ctxt.execute(query))
+        elif isinstance(params, Mapping):
+            res = wrap_ldf(
+                self._ctxt.execute_with_params(query, [], list(params.items()))
+            )
+        else:
+            res = wrap_ldf(self._ctxt.execute_with_params(query, list(params), []))
         return res.collect() if (eager or self._eager_execution) else res
 
     def register(self, name: str, frame: CompatibleFrameType | None) -> Self:
@@ -623,6 +718,89 @@ class SQLContext(Generic[FrameType]):
             self._ctxt.unregister(nm)
         return self
 
+    def register_function(
+        self,
+        name: str,
+        function: Callable[..., Expr],
+        *,
+        arg_types: Sequence[PolarsDataType | None] | None = None,
+    ) -> Self:
+        """
+        Register a function that can be called by name from SQL.
+
+        The function is called with the (translated) SQL arguments as expressions,
+        and must return a single expression; it is called once when the query is
+        translated, not once per row.
+
+        Parameters
+        ----------
+        name
+            Name of the SQL function (case-insensitive); built-in SQL functions
+            cannot be redefined.
+        function
+            Function that takes the argument expressions and returns an expression.
+        arg_types
+            The expected dtype of each argument; if given, the function must be called
+            with exactly this many arguments, and the dtypes of any non-literal
+            arguments are checked (use `None` to accept any dtype for an argument).
+
+        See Also
+        --------
+        unregister_function
+
+        Examples
+        --------
+        >>> def haversine(lat1, lon1, lat2, lon2):
+        ...     dlat, dlon = (lat2 - lat1).radians(), (lon2 - lon1).radians()
+        ...     a = (dlat / 2).sin() ** 2 + (
+        ...         lat1.radians().cos() * lat2.radians().cos() * (dlon / 2).sin() ** 2
+        ...     )
+        ...     return 2 * 6371.0 * a.sqrt().arcsin()
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "city": ["Berlin", "Lisbon"],
+        ...         "lat": [52.52, 38.72],
+        ...         "lon": [13.40, -9.14],
+        ...     }
+        ... )
+        >>> ctx = pl.SQLContext(cities=df).register_function(
+        ...     "haversine", haversine, arg_types=[pl.Float64] * 4
+        ... )
+        >>> ctx.execute(
+        ...     "SELECT city, HAVERSINE(lat, lon, 48.86, 2.35) AS km FROM cities",
+        ...     eager=True,
+        ... ).with_columns(pl.col("km").round(0))
+        shape: (2, 2)
+        ┌────────┬────────┐
+        │ city   ┆ km     │
+        │ ---    ┆ ---    │
+        │ str    ┆ f64    │
+        ╞════════╪════════╡
+        │ Berlin ┆ 877.0  │
+        │ Lisbon ┆ 1453.0 │
+        └────────┴────────┘
+        """
+
+        def translate(args: list[PyExpr]) -> PyExpr:
+            return function(*(wrap_expr(e) for e in args))._pyexpr
+
+        self._ctxt.register_function(
+            name, translate, None if arg_types is None else list(arg_types)
+        )
+        return self
+
+    def unregister_function(self, name: str) -> Self:
+        """
+        Unregister a function that was registered with :meth:`register_function`.
+
+        Parameters
+        ----------
+        name
+            Name of the SQL function to unregister.
+        """
+        self._ctxt.unregister_function(name)
+        return self
+
     def tables(self) -> list[str]:
         """
         Return a list of the registered table names.
@@ -11,6 +11,8 @@ if TYPE_CHECKING:
     import sys
     from types import TracebackType
 
+    from polars.datatypes import Categories
+
     if sys.version_info >= (3, 11):
         from typing import Self
     else:
@@ -29,12 +31,25 @@ class StringCache(contextlib.ContextDecorator):
     """
     Context manager for enabling and disabling the global string cache.
 
+    Parameters
+    ----------
+    scoped
+        Use a string cache of its own instead of the global one. :class:`Categorical`
+        data types created while this context is active, without explicitly passing
+        :class:`Categories`, use the categories of this string cache. Re-entering the
+        same `StringCache` reuses its categories, so categoricals created in different
+        queries under it can still be combined, while they don't share (or grow) the
+        global categories used by unrelated queries.
+
     :class:`Categorical` columns created under the same global string cache have
     the same underlying physical value when string values are equal. This allows the
     columns to be concatenated or used in a join operation, for example.
 
     Notes
     -----
+    A scoped string cache is only active on the thread that entered it, and only
+    applies to data types created while it is active.
+
     Enabling the global string cache introduces some overhead.
     The amount of overhead depends on the number of categories in your data.
     It is advised to enable the global string cache only when strictly necessary.
@@ -73,10 +88,43 @@ class StringCache(contextlib.ContextDecorator):
     ...     s1 = pl.Series("color", ["red", "green", "red"], dtype=pl.Categorical)
     ...     s2 = pl.Series("color", ["blue", "red", "green"], dtype=pl.Categorical)
     ...     return pl.concat([s1, s2])
+
+    Use a scoped string cache to keep the categories of a query separate from the
+    global categories, while sharing them between queries using the same cache.
+
+    >>> cache = pl.StringCache(scoped=True)
+    >>> with cache:
+    ...     lf1 = pl.LazyFrame({"k": ["a", "b"]}, schema={"k": pl.Categorical})
+    >>> with cache:
+    ...     lf2 = pl.LazyFrame(
+    ...         {"k": ["b", "a"], "v": [1, 2]}, schema={"k": pl.Categorical}
+    ...     )
+    >>> lf1.join(lf2, on="k", maintain_order="left").collect()
+    shape: (2, 2)
+    ┌─────┬─────┐
+    │ k   ┆ v   │
+    │ --- ┆ --- │
+    │ cat ┆ i64 │
+    ╞═════╪═════╡
+    │ a   ┆ 2   │
+    │ b   ┆ 1   │
+    └─────┴─────┘
     """
 
+    def __init__(self, *, scoped: bool = False) -> None:
+        from polars.datatypes import Categories
+
+        self._categories = Categories.random() if scoped else None
+
+    @property
+    def categories(self) -> Categories | None:
+        """The :class:`Categories` of a scoped string cache, `None` otherwise."""
+        return self._categories
+
     def __enter__(self) -> Self:
-        self._string_cache = PyStringCacheHolder()
+        self._string_cache = PyStringCacheHolder(
+            None if self._categories is None else self._categories._categories
+        )
         return self
 
     def __exit__(
@@ -215,6 +215,7 @@ def test_iter_rows() -> None:
         rechunk=False,
     )
     assert df.n_chunks() == 2
+    assert list(df.iter_rows(buffer_size=3)) == [(0, "a"), (1, "b"), (2, "c"), (3, "d")]
     assert df.to_dicts() == [
         {"id": 0, "values": "a"},
         {"id": 1, "values": "b"},
@@ -223,6 +224,15 @@ def test_iter_rows() -> None:
     ]
 
 
+def test_iter_rows_object() -> None:
+    obj = object()
+    df = pl.DataFrame(
+        {"a": [1, 2], "b": [obj, None]}, schema={"a": pl.Int64, "b": pl.Object}
+    )
+    assert list(df.iter_rows(buffer_size=1)) == [(1, obj), (2, None)]
+    assert list(df.iter_rows(named=True)) == [{"a": 1, "b": obj}, {"a": 2, "b": None}]
+
+
 @pytest.mark.parametrize("primitive", INTEGER_DTYPES)
 def test_row_constructor_schema(primitive: pl.DataType) -> None:
     result = pl.DataFrame(data=[[1], [2], [3]], schema={"d": primitive}, orient="row")
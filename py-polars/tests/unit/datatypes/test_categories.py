@@ -157,3 +157,67 @@ def test_categories_pickle_24690() -> None:
     assert categories.name() == "name"
     assert categories.namespace() == "namespace"
     assert categories.physical() == pl.UInt8
+
+
+def test_scoped_string_cache() -> None:
+    cache = pl.StringCache(scoped=True)
+    assert cache.categories is not None
+    assert not cache.categories.is_global()
+
+    with cache:
+        df1 = pl.DataFrame({"k": ["a", "b"]}, schema={"k": pl.Categorical})
+        assert pl.Categorical() == pl.Categorical
+        assert pl.Categorical().categories == cache.categories
+    with cache:
+        df2 = pl.DataFrame(
+            {"k": ["b", "c"], "v": [1, 2]}, schema={"k": pl.Categorical}
+        )
+
+    assert df1.schema["k"] == pl.Categorical(cache.categories)
+    assert df1.schema["k"] != pl.Categorical
+    assert pl.Categorical().categories.is_global()
+
+    # Categoricals in the same scope can be joined.
+    out = df1.lazy().join(df2.lazy(), on="k").collect()
+    assert out.rows() == [("b", 1)]
+
+    # Categoricals outside of the scope use the global categories.
+    df3 = pl.DataFrame({"k": ["b"]}, schema={"k": pl.Categorical})
+    with pytest.raises(SchemaError):
+        pl.concat([df1, df3])
+
+    # Scopes nest and are unwound on exit.
+    other = pl.StringCache(scoped=True)
+    with cache, other:
+        assert pl.Categorical().categories == other.categories
+    assert pl.Categorical().categories.is_global()
+
+    # Scopes can be exited in any order.
+    outer = pl.StringCache(scoped=True)
+    inner = pl.StringCache(scoped=True)
+    outer.__enter__()
+    inner.__enter__()
+    outer.__exit__(None, None, None)
+    assert pl.Categorical().categories == inner.categories
+    inner.__exit__(None, None, None)
+    assert pl.Categorical().categories.is_global()
+
+
+def test_lazyframe_with_string_cache() -> None:
+    cache = pl.StringCache(scoped=True)
+    lf1 = pl.LazyFrame({"k": ["a", "b"], "s": ["x", "y"]}).cast({"k": pl.Categorical})
+    lf2 = pl.LazyFrame({"k": ["b"], "v": [1]}).cast({"k": pl.Categorical})
+
+    lf1 = lf1.with_string_cache(cache)
+    lf2 = lf2.with_string_cache(cache)
+    assert lf1.collect_schema() == {
+        "k": pl.Categorical(cache.categories),
+        "s": pl.String,
+    }
+
+    out = lf1.join(lf2, on="k").collect()
+    assert out.rows() == [("b", "y", 1)]
+    assert out.schema["k"] == pl.Categorical(cache.categories)
+
+    with pytest.raises(ValueError, match="only a scoped string cache"):
+        lf1.with_string_cache(pl.StringCache())
@@ -19,6 +19,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from collections.abc import Callable
 
+    from polars._typing import RoundMode
     from tests.conftest import PlMonkeyPatch
 
 
@@ -930,3 +931,126 @@ def test_product_decimal_26721() -> None:
     out = df.select(pl.col.x.product())
     expected = pl.DataFrame({"x": ["1.25512"]}).cast(pl.Decimal(precision=38, scale=5))
     assert_frame_equal(out, expected)
+
+
+def test_decimal_arithmetic_prec_scale() -> None:
+    df = pl.DataFrame(
+        {"a": [D("1.25"), D("-2.50")], "b": [D("3.000"), D("0.125")]},
+        schema={"a": pl.Decimal(5, 2), "b": pl.Decimal(4, 3)},
+    )
+    out = df.select(
+        add=pl.col("a").decimal_arithmetic(pl.col("b"), "add"),
+        sub=pl.col("a").decimal_arithmetic(pl.col("b"), "sub"),
+        mul=pl.col("a").decimal_arithmetic(pl.col("b"), "mul"),
+        div=pl.col("a").decimal_arithmetic(pl.col("b"), "div"),
+    )
+    assert out.schema == pl.Schema(
+        {
+            "add": pl.Decimal(7, 3),
+            "sub": pl.Decimal(7, 3),
+            "mul": pl.Decimal(10, 5),
+            "div": pl.Decimal(13, 7),
+        }
+    )
+    assert out.to_dict(as_series=False) == {
+        "add": [D("4.250"), D("-2.375")],
+        "sub": [D("-1.750"), D("-2.625")],
+        "mul": [D("3.75000"), D("-0.31250")],
+        "div": [D("0.4166667"), D("-20.0000000")],
+    }
+
+
+def test_decimal_arithmetic_bounded_scale() -> None:
+    s = pl.Series([D("1")], dtype=pl.Decimal(38, 0))
+    out = s.decimal_arithmetic(pl.Series([D("3")], dtype=pl.Decimal(38, 0)), "div")
+    assert out.dtype == pl.Decimal(38, 6)
+    assert out.item() == D("0.333333")
+
+    s = pl.Series([D("1.5")], dtype=pl.Decimal(38, 10))
+    out = s.decimal_arithmetic(s, "mul")
+    assert out.dtype == pl.Decimal(38, 6)
+    assert out.item() == D("2.25")
+
+
+@pytest.mark.parametrize(
+    ("rounding", "expected"),
+    [
+        ("half_to_even", ["0.000000", "0.000002", "0.000000", "0.666667"]),
+        ("half_away_from_zero", ["0.000001", "0.000002", "-0.000001", "0.666667"]),
+        ("to_zero", ["0.000000", "0.000001", "0.000000", "0.666666"]),
+    ],
+)
+def test_decimal_arithmetic_rounding(rounding: RoundMode, expected: list[str]) -> None:
+    dt = pl.Decimal(38, 0)
+    s = pl.Series([D("1"), D("3"), D("-1"), D("2")], dtype=dt)
+    other = pl.Series([D("2000000"), D("2000000"), D("2000000"), D("3")], dtype=dt)
+    out = s.decimal_arithmetic(other, "div", rounding=rounding)
+    assert out.dtype == pl.Decimal(38, 6)
+    assert out.to_list() == [D(v) for v in expected]
+
+
+def test_decimal_arithmetic_overflow() -> None:
+    s = pl.Series([D("99.9"), D("1.0"), None], dtype=pl.Decimal(3, 1))
+    other = pl.Series([D("99.9"), D("0"), D("1.0")], dtype=pl.Decimal(3, 1))
+
+    with pytest.raises(pl.exceptions.ComputeError, match="division by zero"):
+        s.decimal_arithmetic(other, "div")
+
+    big = pl.Series([D("9" * 38)], dtype=pl.Decimal(38, 0))
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        big.decimal_arithmetic(big, "add")
+    out = big.decimal_arithmetic(big, "add", overflow="null")
+    assert out.dtype == pl.Decimal(38, 0)
+    assert out.to_list() == [None]
+
+    out = s.decimal_arithmetic(other, "mul", overflow="null")
+    assert out.to_list() == [D("9980.01"), D("0.00"), None]
+
+
+def test_decimal_arithmetic_int_operand() -> None:
+    df = pl.DataFrame(
+        {"a": [D("1.50"), D("-2.25")], "i": [2, 3]},
+        schema={"a": pl.Decimal(5, 2), "i": pl.Int32},
+    )
+    out = df.select(
+        mul=pl.col("a").decimal_arithmetic(pl.col("i"), "mul"),
+        rdiv=pl.col("i").decimal_arithmetic(pl.col("a"), "div"),
+    )
+    assert out.schema == pl.Schema(
+        {"mul": pl.Decimal(16, 2), "rdiv": pl.Decimal(18, 6)}
+    )
+    assert out.to_dict(as_series=False) == {
+        "mul": [D("3.00"), D("-6.75")],
+        "rdiv": [D("1.333333"), D("-1.333333")],
+    }
+
+    with pytest.raises(InvalidOperationError, match="cast to Decimal first"):
+        df.select(pl.col("a").decimal_arithmetic(pl.lit(1.5), "add"))
+
+
+def test_decimal_int_truediv() -> None:
+    df = pl.DataFrame(
+        {"i": [1, 2], "d": [D("3.00"), D("4.00")]},
+        schema={"i": pl.Int64, "d": pl.Decimal(10, 2)},
+    )
+    q = df.lazy().select(pl.col("i") / pl.col("d"))
+    assert q.collect_schema()["i"] == pl.Float64
+    assert q.collect().to_series().to_list() == [1 / 3, 0.5]
+
+    # `decimal_arithmetic` keeps the division in decimals.
+    out = df.select(pl.col("i").decimal_arithmetic(pl.col("d"), "div"))
+    assert out.schema["i"] == pl.Decimal(32, 11)
+    assert out.to_series().to_list() == [D("0.33333333333"), D("0.50000000000")]
+
+
+def test_decimal_operators_keep_max_precision() -> None:
+    df = pl.DataFrame(
+        {"a": [D("1.00"), D("2.50")], "b": [D("3"), D("4")]},
+        schema={"a": pl.Decimal(5, 2), "b": pl.Decimal(3, 0)},
+    )
+    out = df.select(
+        (pl.col("a") * pl.col("b")).alias("op"),
+        pl.col("a").decimal_arithmetic(pl.col("b"), "mul").alias("sql"),
+    )
+    assert out.schema == {"op": pl.Decimal(38, 2), "sql": pl.Decimal(9, 2)}
+    assert out["op"].to_list() == out["sql"].to_list() == [D("3.00"), D("10.00")]
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import io
+import sys
 from pathlib import Path
 from uuid import uuid4
 
@@ -247,16 +248,48 @@ def test_object_sort_scalar_19925() -> None:
 
 
 def test_object_estimated_size() -> None:
+    ob = "random python object, not a string"
     df = pl.DataFrame(
         [
-            ["3", "random python object, not a string"],
+            ["3", ob],
         ],
         schema={"name": pl.String, "ob": pl.Object},
         orient="row",
     )
 
-    # is a huge underestimation
-    assert df.estimated_size() == 9
+    # string data, object pointer and the python object itself
+    assert df.estimated_size() == 1 + 8 + sys.getsizeof(ob)
+
+
+def test_object_estimated_size_sizeof_hook() -> None:
+    class Blob:
+        def __init__(self, n: int) -> None:
+            self.n = n
+
+        def __sizeof__(self) -> int:
+            return self.n
+
+    s = pl.Series([Blob(1000), Blob(2000)], dtype=pl.Object)
+    gc_overhead = sys.getsizeof(Blob(0))
+    assert s.estimated_size() == 2 * 8 + 3000 + 2 * gc_overhead
+
+
+def test_object_estimated_size_sampled() -> None:
+    calls = 0
+
+    class Blob:
+        def __sizeof__(self) -> int:
+            nonlocal calls
+            calls += 1
+            return 100
+
+    size = sys.getsizeof(Blob())
+    calls = 0
+
+    # Only a sample of the objects is asked for its size.
+    s = pl.Series([Blob() for _ in range(1000)], dtype=pl.Object)
+    assert s.estimated_size() == 1000 * 8 + 1000 * size
+    assert 0 < calls <= 8
 
 
 def test_object_polars_dtypes_20572() -> None:
@@ -12,7 +12,11 @@ import pytest
 
 import polars as pl
 import polars.selectors as cs
-from polars.exceptions import DuplicateError, InvalidOperationError
+from polars.exceptions import (
+    DuplicateError,
+    InvalidOperationError,
+    StructFieldNotFoundError,
+)
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
@@ -150,6 +154,60 @@ def test_struct_unnest_multiple() -> None:
     assert_frame_equal(result, df)
 
 
+def test_struct_unnest_fields_prefix() -> None:
+    df = pl.DataFrame(
+        {"s": [{"a": 1, "b": "x", "c": 1.0}, {"a": 2, "b": "y", "c": 2.0}]}
+    )
+    expected = pl.DataFrame({"p_a": [1, 2], "p_c": [1.0, 2.0]})
+    assert_frame_equal(df.unnest("s", fields=["a", "c"], prefix="p_"), expected)
+
+    result = df.unnest("s", fields="b", prefix="p", separator=".")
+    assert_frame_equal(result, pl.DataFrame({"p.b": ["x", "y"]}))
+
+    result = df.unnest("s", fields="b", separator=".")
+    assert_frame_equal(result, pl.DataFrame({"s.b": ["x", "y"]}))
+
+    lf = df.lazy().unnest("s", fields=["c", "a"])
+    assert lf.collect_schema() == {"a": pl.Int64, "c": pl.Float64}
+    assert_frame_equal(lf.collect(), df.unnest("s").select("a", "c"))
+
+    with pytest.raises(StructFieldNotFoundError):
+        df.unnest("s", fields=["d"])
+
+
+def test_struct_unnest_on_collision() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2],
+            "s": [{"a": 10, "b": 30}, {"a": 20, "b": 40}],
+            "b": [5, 6],
+        }
+    )
+    with pytest.raises(DuplicateError):
+        df.unnest("s")
+
+    expected = pl.DataFrame({"a": [10, 20], "b": [30, 40]})
+    result = df.unnest("s", on_collision="overwrite")
+    assert_frame_equal(result, expected)
+    assert df.lazy().unnest("s", on_collision="overwrite").collect_schema() == {
+        "a": pl.Int64,
+        "b": pl.Int64,
+    }
+
+    expected = pl.DataFrame({"a": [1, 2], "b": [5, 6]})
+    result = df.unnest("s", on_collision="keep")
+    assert_frame_equal(result, expected)
+    assert df.lazy().unnest("s", on_collision="keep").collect_schema() == {
+        "a": pl.Int64,
+        "b": pl.Int64,
+    }
+
+    # Filters on overwritten columns are not pushed below the unnest.
+    lf = df.lazy().unnest("s", on_collision="overwrite")
+    result = lf.filter(pl.col("a") == 10).collect()
+    assert_frame_equal(result, pl.DataFrame({"a": [10], "b": [30]}))
+
+
 def test_struct_function_expansion() -> None:
     df = pl.DataFrame(
         {"a": [1, 2, 3, 4], "b": ["one", "two", "three", "four"], "c": [9, 8, 7, 6]}
@@ -3,6 +3,7 @@ from __future__ import annotations
 from datetime import datetime
 from typing import TYPE_CHECKING
 
+import pyarrow as pa
 import pytest
 
 import polars as pl
@@ -245,6 +246,21 @@ def test_get_buffers_with_validity_and_offsets() -> None:
     assert offsets_dtype == (DtypeKind.INT, 64, "l", "=")
 
 
+def test_get_buffers_sliced_validity() -> None:
+    s = pl.Series([1, None, 3, None, 5, 6, None, 8, 9, None], dtype=pl.Int32)[3:]
+    col = PolarsColumn(s)
+
+    out = col.get_buffers()
+
+    validity = out["validity"]
+    assert validity is not None
+    val_buffer, _ = validity
+    # The validity is aligned with the data buffer, which has no offset.
+    assert col.offset == 0
+    assert val_buffer._data._get_buffer_info()[1] == 0
+    assert_series_equal(val_buffer._data, s.is_not_null(), check_names=False)
+
+
 def test_get_buffers_chunked_bitmask() -> None:
     s = pl.Series([True, False], dtype=pl.Boolean)
     s_chunked = pl.concat([s[:1], s[1:]], rechunk=False)
@@ -265,6 +281,30 @@ def test_get_buffers_string_zero_copy_fails() -> None:
         col.get_buffers()
 
 
+def test_get_buffers_string_from_arrow_zero_copy() -> None:
+    arr = pa.array(
+        ["a long string value", None, "b", "another long string"],
+        type=pa.large_string(),
+    )
+    s = pl.Series("a", arr)
+    col = PolarsColumn(s, allow_copy=False)
+
+    out = col.get_buffers()
+
+    data_buffer, _ = out["data"]
+    assert data_buffer.ptr == arr.buffers()[2].address
+    expected = b"a long string value" + b"b" + b"another long string"
+    assert bytes(data_buffer._data.to_list()) == expected
+
+    offsets = out["offsets"]
+    assert offsets is not None
+    assert offsets[0]._data.to_list() == [0, 19, 19, 20, 39]
+
+    validity = out["validity"]
+    assert validity is not None
+    assert_series_equal(validity[0]._data, s.is_not_null(), check_names=False)
+
+
 @pytest.mark.parametrize("allow_copy", [False, True])
 def test_get_buffers_categorical(allow_copy: bool) -> None:
     s = pl.Series("a", ["c", "b"], dtype=pl.Categorical)
@@ -264,6 +264,22 @@ def test_to_dataframe_pyarrow_boolean_midbyte_slice() -> None:
     assert_frame_equal(result, df)
 
 
+def test_to_dataframe_pyarrow_midbyte_slice_with_nulls() -> None:
+    df = pl.DataFrame(
+        {
+            "int": [1, None, 3, None, 5, 6, None, 8, 9, None],
+            "bool": [True, None, False, None, True, True, None, False, True, None],
+            "str": ["a", None, "c", None, "e", "f", None, "h", "i", None],
+        }
+    )[3:]
+    dfi = df.__dataframe__()
+
+    df_pa = pa.interchange.from_dataframe(dfi)
+    result: pl.DataFrame = pl.from_arrow(df_pa)  # type: ignore[assignment]
+
+    assert_frame_equal(result, df)
+
+
 @pytest.mark.skipif(
     parse_version(pd.__version__) < (2, 2),
     reason="Pandas versions < 2.2 do not implement the required conversions",
@@ -306,3 +306,22 @@ def test_to_numpy_array_shape_23426() -> None:
     )
 
     assert_frame_equal(df, pl.from_numpy(df.to_numpy(structured=True)))
+
+
+@pytest.mark.parametrize("order", ["c", "fortran"])
+def test_df_to_numpy_masked(order: IndexOrder) -> None:
+    df = pl.DataFrame(
+        {"a": [1, None, 3], "b": [None, 5, 6]}, schema={"a": pl.Int16, "b": pl.Int16}
+    )
+    result = df.to_numpy(order=order, masked=True)
+
+    assert isinstance(result, np.ma.MaskedArray)
+    assert result.dtype == np.int16
+    assert_array_equal(result.mask, [[False, True], [True, False], [False, False]])
+    assert result.tolist() == [[1, None], [None, 5], [3, 6]]
+
+
+def test_df_to_numpy_masked_structured() -> None:
+    df = pl.DataFrame({"a": [1, None]})
+    with pytest.raises(ValueError, match="structured"):
+        df.to_numpy(masked=True, structured=True)
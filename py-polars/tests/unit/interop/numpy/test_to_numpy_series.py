@@ -488,3 +488,53 @@ def test_to_numpy_series_indexed_18986() -> None:
     assert (
         df.to_numpy()[2] == np.array([None])
     ).all()  # this one is strange, but only option in numpy?
+
+
+@pytest.mark.parametrize(
+    ("dtype", "expected_dtype"),
+    [
+        (pl.Int8, np.int8),
+        (pl.UInt64, np.uint64),
+        (pl.Float32, np.float32),
+        (pl.Datetime("ms"), np.dtype("datetime64[ms]")),
+        (pl.Duration("us"), np.dtype("timedelta64[us]")),
+    ],
+)
+def test_series_to_numpy_masked_zero_copy(
+    dtype: PolarsDataType, expected_dtype: npt.DTypeLike
+) -> None:
+    s = pl.Series([1, None, 3]).cast(dtype)
+    result = s.to_numpy(masked=True, allow_copy=False)
+
+    assert isinstance(result, np.ma.MaskedArray)
+    assert result.dtype == expected_dtype
+    assert_array_equal(result.mask, [False, True, False])
+    assert_array_equal(result.compressed(), s.drop_nulls().to_numpy())
+    assert_zero_copy(s, result.data)
+    assert not result.flags.writeable
+
+
+def test_series_to_numpy_masked_copy() -> None:
+    # Chunked data is copied, and keeps its data type.
+    s = pl.concat([pl.Series([1, None]), pl.Series([3])], rechunk=False)
+    with pytest.raises(RuntimeError, match="copy not allowed"):
+        s.to_numpy(masked=True, allow_copy=False)
+    result = s.to_numpy(masked=True)
+    assert result.dtype == np.int64
+    assert result.tolist() == [1, None, 3]
+
+    result = pl.Series(["a", None]).to_numpy(masked=True)
+    assert result.tolist() == ["a", None]
+
+    result = pl.Series([1, None]).to_numpy(masked=True, writable=True)
+    assert result.flags.writeable
+
+    # Without nulls nothing is masked.
+    result = pl.Series([1, 2]).to_numpy(masked=True)
+    assert result.mask is np.ma.nomask
+
+
+def test_series_to_numpy_masked_nested() -> None:
+    s = pl.Series([[1], None], dtype=pl.Array(pl.Int64, 1))
+    with pytest.raises(ValueError, match="masked array"):
+        s.to_numpy(masked=True)
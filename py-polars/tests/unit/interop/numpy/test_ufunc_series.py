@@ -116,6 +116,51 @@ def test_ufunc() -> None:
     )
 
 
+def test_ufunc_native_null_handling() -> None:
+    a = pl.Series("a", [1, None, 3, 4])
+    b = pl.Series("b", [2, 2, None, 4])
+
+    assert_series_equal(
+        cast("pl.Series", np.add(a, b)), pl.Series("a", [3, None, None, 8])
+    )
+    assert_series_equal(
+        cast("pl.Series", np.subtract(10, a)), pl.Series("a", [9, None, 7, 6])
+    )
+    assert_series_equal(
+        cast("pl.Series", np.true_divide(a, 2)),
+        pl.Series("a", [0.5, None, 1.5, 2.0]),
+    )
+    assert_series_equal(
+        cast("pl.Series", np.less(a, b)), pl.Series("a", [True, None, None, False])
+    )
+    assert_series_equal(
+        cast("pl.Series", np.greater_equal(3, a)),
+        pl.Series("a", [True, None, True, False]),
+    )
+    assert_series_equal(
+        cast("pl.Series", np.negative(a)), pl.Series("a", [-1, None, -3, -4])
+    )
+
+
+def test_ufunc_native_large_integers() -> None:
+    # Native dispatch doesn't round-trip through floats.
+    s = pl.Series("a", [2**62 + 1, None], dtype=pl.Int64)
+    assert_series_equal(
+        cast("pl.Series", np.add(s, 1)),
+        pl.Series("a", [2**62 + 2, None], dtype=pl.Int64),
+    )
+
+
+def test_ufunc_native_math() -> None:
+    s = pl.Series("a", [1.0, None, 4.0], dtype=pl.Float32)
+    assert_series_equal(
+        cast("pl.Series", np.sqrt(s)),
+        pl.Series("a", [1.0, None, 2.0], dtype=pl.Float32),
+    )
+    result = cast("pl.Series", np.log(pl.Series("a", [1, None, 1])))
+    assert_series_equal(result, pl.Series("a", [0.0, None, 0.0]))
+
+
 def test_numpy_string_array() -> None:
     s_str = pl.Series("a", ["aa", "bb", "cc", "dd"], dtype=pl.String)
     assert_array_equal(
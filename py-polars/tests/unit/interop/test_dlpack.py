@@ -0,0 +1,140 @@
+from __future__ import annotations
+
+import numpy as np
+import pytest
+from numpy.testing import assert_array_equal
+
+import polars as pl
+from polars._utils.various import parse_version
+
+
+@pytest.mark.parametrize(
+    "dtype", [pl.Int8, pl.Int32, pl.UInt16, pl.UInt64, pl.Float32, pl.Float64]
+)
+def test_series_dlpack(dtype: pl.DataType) -> None:
+    s = pl.Series([1, 2, 3], dtype=dtype)
+    result = np.from_dlpack(s)
+
+    assert result.dtype == s.to_numpy().dtype
+    assert_array_equal(result, [1, 2, 3])
+    assert s.__dlpack_device__() == (1, 0)
+
+
+def test_series_dlpack_zero_copy() -> None:
+    s = pl.Series([1.0, 2.0, 3.0])
+    assert np.from_dlpack(s).ctypes.data == s.to_numpy().ctypes.data
+
+
+@pytest.mark.parametrize(
+    ("max_version", "name"),
+    [
+        (None, "dltensor"),
+        ((0, 8), "dltensor"),
+        ((1, 0), "dltensor_versioned"),
+        ((1, 1), "dltensor_versioned"),
+    ],
+)
+def test_series_dlpack_max_version(
+    max_version: tuple[int, int] | None, name: str
+) -> None:
+    s = pl.Series([1, 2, 3])
+    capsule = s.__dlpack__(max_version=max_version)
+    assert f'capsule object "{name}"' in repr(capsule)
+
+    df = s.to_frame()
+    capsule = df.__dlpack__(max_version=max_version)
+    assert f'capsule object "{name}"' in repr(capsule)
+
+
+@pytest.mark.skipif(
+    parse_version(np.__version__) < (2, 1),
+    reason="NumPy versions < 2.1 do not request versioned capsules",
+)
+def test_series_dlpack_read_only() -> None:
+    s = pl.Series([1.0, 2.0, 3.0])
+    assert not np.from_dlpack(s).flags.writeable
+
+    # Copies are owned by the consumer.
+    result = np.from_dlpack(s, copy=True)
+    assert result.flags.writeable
+
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    assert np.from_dlpack(df).flags.writeable
+
+
+def test_series_dlpack_sliced() -> None:
+    s = pl.Series(range(10))[3:6]
+    assert_array_equal(np.from_dlpack(s), [3, 4, 5])
+
+
+def test_series_dlpack_copy() -> None:
+    s = pl.Series([1, 2, 3])
+    result = np.from_dlpack(s, copy=True)
+
+    assert result.ctypes.data != s.to_numpy().ctypes.data
+    assert_array_equal(result, [1, 2, 3])
+
+
+def test_series_dlpack_chunked() -> None:
+    s = pl.concat([pl.Series([1, 2]), pl.Series([3])], rechunk=False)
+    assert_array_equal(np.from_dlpack(s), [1, 2, 3])
+
+    with pytest.raises(BufferError, match="copy not allowed"):
+        s.__dlpack__(copy=False)
+
+
+def test_series_dlpack_nulls() -> None:
+    s = pl.Series([1, None, 3])
+    with pytest.raises(BufferError, match="null values"):
+        np.from_dlpack(s)
+
+
+@pytest.mark.parametrize(
+    "s",
+    [
+        pl.Series(["a", "b"]),
+        pl.Series([True, False]),
+        pl.Series([[1], [2]]),
+        pl.Series([1, 2], dtype=pl.Int128),
+    ],
+)
+def test_series_dlpack_unsupported_dtype(s: pl.Series) -> None:
+    with pytest.raises(TypeError, match="cannot export data type"):
+        s.__dlpack__()
+
+
+def test_dataframe_dlpack() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    result = np.from_dlpack(df)
+
+    assert result.shape == (3, 2)
+    assert_array_equal(result, [[1, 4], [2, 5], [3, 6]])
+    assert df.__dlpack_device__() == (1, 0)
+
+
+def test_dataframe_dlpack_zero_copy() -> None:
+    s = pl.Series([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    df = pl.DataFrame({"a": s[:3], "b": s[3:]})
+    result = np.from_dlpack(df)
+
+    assert result.ctypes.data == s.to_numpy().ctypes.data
+    assert result.flags["F_CONTIGUOUS"]
+    assert_array_equal(result, [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]])
+
+
+def test_dataframe_dlpack_not_contiguous() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    with pytest.raises(BufferError, match="copy not allowed"):
+        df.__dlpack__(copy=False)
+
+
+def test_dataframe_dlpack_mixed_dtypes() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": [1.0, 2.0]})
+    with pytest.raises(TypeError, match="different data types"):
+        np.from_dlpack(df)
+
+
+def test_dataframe_dlpack_nulls() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": [1, None]})
+    with pytest.raises(BufferError, match="null values"):
+        np.from_dlpack(df)
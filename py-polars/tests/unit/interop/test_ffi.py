@@ -0,0 +1,58 @@
+from __future__ import annotations
+
+import ctypes
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+# `SeriesExport` consists of five pointer-sized fields.
+SERIES_EXPORT_SIZE = 5 * ctypes.sizeof(ctypes.c_void_p)
+
+
+def test_ffi_series_roundtrip() -> None:
+    s = pl.Series("a", ["x", None, "z"])
+    buf = ctypes.create_string_buffer(SERIES_EXPORT_SIZE)
+    location = ctypes.addressof(buf)
+
+    version = s._s._ffi_version()
+    s._s._export(location, version)
+    assert_series_equal(pl.Series._import(location, version), s)
+
+
+def test_ffi_series_older_minor_version() -> None:
+    # Peers compiled against minor version 0 don't support string views, so the
+    # data is exported in the large-string layout, which still round-trips.
+    s = pl.Series("a", ["x", None, "z"])
+    buf = ctypes.create_string_buffer(SERIES_EXPORT_SIZE)
+    location = ctypes.addressof(buf)
+
+    major, _ = s._s._ffi_version()
+    s._s._export(location, (major, 0))
+    assert_series_equal(pl.Series._import(location, (major, 0)), s)
+
+
+def test_ffi_dataframe_roundtrip() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    buf = ctypes.create_string_buffer(df.width * SERIES_EXPORT_SIZE)
+    location = ctypes.addressof(buf)
+
+    version = df.to_series()._s._ffi_version()
+    df._df._export_columns(location, version)
+    out = pl.DataFrame._from_pydf(
+        df._df._import_columns(location, df.width, version)
+    )
+    assert_frame_equal(out, df)
+
+
+@pytest.mark.parametrize("offset", [(1, 0), (0, 1)])
+def test_ffi_incompatible_version(offset: tuple[int, int]) -> None:
+    s = pl.Series("a", [1, 2, 3])
+    major, minor = s._s._ffi_version()
+    version = (major + offset[0], minor + offset[1])
+
+    buf = ctypes.create_string_buffer(SERIES_EXPORT_SIZE)
+    with pytest.raises(ComputeError, match="incompatible polars FFI version"):
+        s._s._export(ctypes.addressof(buf), version)
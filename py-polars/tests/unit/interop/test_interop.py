@@ -3,7 +3,7 @@ from __future__ import annotations
 import io
 from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
-from typing import TYPE_CHECKING, Any, cast
+from typing import TYPE_CHECKING, Any, Literal, cast
 
 import numpy as np
 import pandas as pd
@@ -1174,6 +1174,104 @@ def test_to_arrow_24142() -> None:
     df.to_arrow(compat_level=CompatLevel.oldest())
 
 
+def test_from_arrow_pycapsule_coerce() -> None:
+    tbl = pa.table(
+        {
+            "cat": pa.array(["x", "y", "x"]).dictionary_encode(),
+            "ts": pa.array([0, 1_000, 2_000], pa.timestamp("ns")),
+            "dur": pa.array([0, 1_000, 2_000], pa.duration("ns")),
+        }
+    )
+    stream = PyCapsuleStreamHolder(tbl)
+
+    df = cast("pl.DataFrame", pl.from_arrow(stream))
+    assert df.schema == pl.Schema(
+        {"cat": pl.Categorical, "ts": pl.Datetime("ns"), "dur": pl.Duration("ns")}
+    )
+
+    df = cast(
+        "pl.DataFrame",
+        pl.from_arrow(
+            stream,
+            coerce={"dictionary": "materialize", "time_unit": "us"},
+            coerce_overrides={"dur": {"time_unit": None}},
+        ),
+    )
+    assert df.schema == pl.Schema(
+        {"cat": pl.String, "ts": pl.Datetime("us"), "dur": pl.Duration("ns")}
+    )
+    assert df["cat"].to_list() == ["x", "y", "x"]
+    assert df["ts"].to_list() == [
+        datetime(1970, 1, 1),
+        datetime(1970, 1, 1, microsecond=1),
+        datetime(1970, 1, 1, microsecond=2),
+    ]
+
+    # a pyarrow table is imported through the pycapsule interface when coercing
+    df = cast(
+        "pl.DataFrame",
+        pl.from_arrow(tbl, coerce_overrides={"cat": {"dictionary": "materialize"}}),
+    )
+    assert df.schema == pl.Schema(
+        {"cat": pl.String, "ts": pl.Datetime("ns"), "dur": pl.Duration("ns")}
+    )
+
+    with pytest.raises(ValueError, match="`dictionary` must be one of"):
+        pl.from_arrow(stream, coerce={"dictionary": "decode"})  # type: ignore[typeddict-item]
+
+
+@pytest.mark.parametrize("layout", ["large_string", "string_view"])
+def test_from_arrow_pycapsule_coerce_string(
+    layout: Literal["large_string", "string_view"],
+) -> None:
+    tbl = pa.table(
+        {
+            "s": pa.array(["a", None, "a longer string value"], pa.string_view()),
+            "l": pa.array(["x", "y", None], pa.large_string()),
+            "b": pa.array([b"x", None, b"z"], pa.binary_view()),
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "s": ["a", None, "a longer string value"],
+            "l": ["x", "y", None],
+            "b": [b"x", None, b"z"],
+        }
+    )
+    df = cast(
+        "pl.DataFrame",
+        pl.from_arrow(PyCapsuleStreamHolder(tbl), coerce={"string": layout}),
+    )
+    assert_frame_equal(df, expected)
+
+    with pytest.raises(ValueError, match="`string` must be one of"):
+        pl.from_arrow(tbl, coerce={"string": "utf8"})  # type: ignore[typeddict-item]
+
+
+def test_from_arrow_array_coerce() -> None:
+    arr = pa.array(["x", "y", "x"]).dictionary_encode()
+    assert cast("pl.Series", pl.from_arrow(arr)).dtype == pl.Categorical
+
+    s = cast("pl.Series", pl.from_arrow(arr, coerce={"dictionary": "materialize"}))
+    assert_series_equal(s, pl.Series(["x", "y", "x"]))
+
+    ts = pa.chunked_array(
+        [
+            pa.array([0, 1_000], pa.timestamp("ns")),
+            pa.array([2_000], pa.timestamp("ns")),
+        ]
+    )
+    s = cast(
+        "pl.Series",
+        pl.from_arrow(ts, schema={"ts": None}, coerce={"time_unit": "us"}),
+    )
+    assert_series_equal(s, pl.Series("ts", [0, 1, 2]).cast(pl.Datetime("us")))
+
+    arr = pa.array(["a", "b"], pa.string_view())
+    s = cast("pl.Series", pl.from_arrow(arr, coerce={"string": "large_string"}))
+    assert_series_equal(s, pl.Series(["a", "b"]))
+
+
 def test_pycapsule_stream_interface_all_types() -> None:
     """Test all data types via Arrow C Stream PyCapsule interface."""
     df = pl.DataFrame(
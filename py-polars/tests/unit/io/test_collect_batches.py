@@ -68,6 +68,40 @@ def test_chunk_size() -> None:
         assert_frame_equal(f, expected)
 
 
+def test_collect_arrow_stream() -> None:
+    pa = pytest.importorskip("pyarrow")
+
+    df = pl.DataFrame({"a": range(113), "b": [str(i) for i in range(113)]})
+    stream = df.lazy().collect_arrow_stream(chunk_size=17)
+
+    reader = pa.RecordBatchReader.from_stream(stream)
+    assert reader.schema == pa.schema([("a", pa.int64()), ("b", pa.large_string())])
+    batches = list(reader)
+    assert all(rb.num_rows <= 17 for rb in batches)
+    assert_frame_equal(pl.DataFrame(pa.Table.from_batches(batches)), df)
+
+    # The batches are consumed while reading, so the stream can't be exported again.
+    with pytest.raises(ValueError, match="already consumed"):
+        stream.__arrow_c_stream__()
+
+
+def test_collect_arrow_stream_compat_level() -> None:
+    pa = pytest.importorskip("pyarrow")
+
+    lf = pl.LazyFrame({"a": ["x", "y"]})
+    reader = pa.RecordBatchReader.from_stream(
+        lf.collect_arrow_stream(compat_level=pl.CompatLevel.newest())
+    )
+    assert reader.schema == pa.schema([("a", pa.string_view())])
+    assert reader.read_all().column("a").to_pylist() == ["x", "y"]
+
+
+def test_collect_arrow_stream_invalid_query() -> None:
+    lf = pl.LazyFrame({"a": [1]}).select(pl.col("b"))
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        lf.collect_arrow_stream()
+
+
 @pytest.mark.slow
 def test_collect_batches_releases_gil_26031() -> None:
     out = subprocess.check_output(
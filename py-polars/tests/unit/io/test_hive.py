@@ -142,6 +142,42 @@ def test_hive_partitioned_predicate_pushdown_skips_correct_number_of_files(
     assert result.to_dict(as_series=False) == expected
 
 
+@pytest.mark.write_disk
+@pytest.mark.may_fail_auto_streaming
+@pytest.mark.may_fail_cloud  # reason: inspects logs
+def test_hive_partitioned_predicate_pushdown_non_equality(
+    tmp_path: Path, plmonkeypatch: PlMonkeyPatch, capfd: Any
+) -> None:
+    plmonkeypatch.setenv("POLARS_VERBOSE", "1")
+    df = pl.DataFrame(
+        {
+            "date": [date(2023, 12, 31), date(2024, 1, 1), date(2024, 2, 1)],
+            "region": ["eu-west", "eu-north", "us-east"],
+            "x": [1, 2, 3],
+        }
+    )
+    df.write_parquet(tmp_path, partition_by=["date", "region"])
+    lf = pl.scan_parquet(tmp_path)
+    capfd.readouterr()
+
+    q = lf.filter(pl.col("date") >= date(2024, 1, 1))
+    assert_frame_equal(q.collect(), df.slice(1), check_column_order=False)
+    assert "allows skipping 1 / 3" in capfd.readouterr().err
+
+    q = lf.filter(pl.col("region").str.starts_with("eu"))
+    assert_frame_equal(q.collect(), df.head(2), check_column_order=False)
+    assert "allows skipping 1 / 3" in capfd.readouterr().err
+
+    # Parts mixing hive and non-hive columns still prune on the hive columns.
+    q = lf.filter(
+        ((pl.col("date") >= date(2024, 2, 1)) & (pl.col("x") > 0))
+        | pl.col("region").is_in(["eu-west"])
+    )
+    expected = df.filter(pl.col("x") != 2)
+    assert_frame_equal(q.collect().sort("x"), expected, check_column_order=False)
+    assert "allows skipping 1 / 3" in capfd.readouterr().err
+
+
 @pytest.mark.write_disk
 def test_hive_streaming_pushdown_is_in_22212(tmp_path: Path) -> None:
     (
@@ -313,3 +313,53 @@ def test_io_plugin_object_dtype_25740() -> None:
     out = lf.collect()
     assert out.schema == df.schema
     assert out.to_dict(as_series=False) == {"a": [dummy, None]}
+
+
+@pytest.mark.parametrize("apply", [False, True])
+def test_io_plugin_pushdown_info(apply: bool) -> None:
+    from polars.io.plugins import IOSourcePushdown
+
+    df = pl.DataFrame({"a": [1, 2, 3, 4, 5], "b": [5, 4, 3, 2, 1], "c": ["x"] * 5})
+    seen: list[IOSourcePushdown] = []
+
+    def source(pushdown: IOSourcePushdown) -> Iterator[pl.DataFrame]:
+        seen.append(pushdown)
+        out = df
+        if apply:
+            if pushdown.predicate is not None:
+                out = out.filter(pushdown.predicate)
+                pushdown.applied_predicate = True
+            if pushdown.with_columns is not None:
+                out = out.select(pushdown.with_columns)
+                pushdown.applied_projection = True
+            if pushdown.n_rows is not None:
+                out = out.head(pushdown.n_rows)
+                pushdown.applied_n_rows = True
+        # Yield single rows so that an ignored `n_rows` is noticeable.
+        yield from out.iter_slices(n_rows=1)
+
+    lf = register_io_source(source, schema=df.schema, pushdown_info=True)
+
+    result = lf.filter(pl.col("a") > 2).select("b").collect()
+    assert_frame_equal(result, pl.DataFrame({"b": [3, 2, 1]}))
+    pushdown = seen[-1]
+    assert pushdown.predicate is not None
+    assert pushdown.serialized_predicate is not None
+    assert pl.Expr.deserialize(pushdown.serialized_predicate).meta.eq(
+        pushdown.predicate
+    )
+    assert pushdown.with_columns is not None
+    assert set(pushdown.with_columns) >= {"b"}
+
+    result = lf.select("c", "a").head(2).collect()
+    assert_frame_equal(result, pl.DataFrame({"c": ["x", "x"], "a": [1, 2]}))
+    assert seen[-1].n_rows == 2
+
+
+def test_io_plugin_pushdown_info_empty() -> None:
+    def source(pushdown: object) -> Iterator[pl.DataFrame]:
+        yield from []
+
+    schema = pl.Schema({"a": pl.Int64, "b": pl.String})
+    lf = register_io_source(source, schema=schema, pushdown_info=True)
+    assert lf.select("b").collect().schema == pl.Schema({"b": pl.String})
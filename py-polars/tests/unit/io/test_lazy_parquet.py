@@ -1889,3 +1889,65 @@ def test_sink_parquet_lazy_and_collect() -> None:
     assert q.collect().shape == (0, 0)
 
     assert_frame_equal(pl.scan_parquet(f).collect(), df)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_min_max_from_parquet_statistics(tmp_path: Path, engine: Any) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [5, None, -3, 8, None, None],
+            "t": [datetime(2024, 1, d) for d in (4, 1, 6, 2, 3, 5)],
+        }
+    )
+    df.slice(0, 4).write_parquet(tmp_path / "0.parquet", row_group_size=2)
+    # Row groups with only nulls don't have a min or max.
+    df.slice(4, 2).write_parquet(tmp_path / "1.parquet")
+    # Without statistics, the column is read.
+    pl.DataFrame({"a": [-10], "t": [datetime(2023, 1, 1)]}).write_parquet(
+        tmp_path / "2.parquet", statistics=False
+    )
+
+    q = pl.scan_parquet(tmp_path).select(
+        pl.col("a").min(),
+        a_max=pl.col("a").max(),
+        t_max=pl.col("t").max(),
+        n=pl.len(),
+    )
+    assert "FAST STATISTICS" in q.explain()
+
+    expected = pl.DataFrame(
+        {"a": [-10], "a_max": [8], "t_max": [datetime(2024, 1, 6)], "n": [7]},
+        schema_overrides={"n": pl.get_index_type()},
+    )
+    assert_frame_equal(q.collect(engine=engine), expected)
+    assert_frame_equal(
+        q.collect(optimizations=pl.QueryOptFlags(projection_pushdown=False)),
+        expected,
+    )
+
+
+@pytest.mark.write_disk
+def test_min_max_from_parquet_statistics_hive(tmp_path: Path) -> None:
+    for p, values in [(1, [3, 7]), (2, [-1, 4])]:
+        (tmp_path / f"p={p}").mkdir()
+        pl.DataFrame({"a": values}).write_parquet(tmp_path / f"p={p}" / "0.parquet")
+
+    lf = pl.scan_parquet(tmp_path, hive_partitioning=True)
+    q = lf.select(pl.col("a").min(), a_max=pl.col("a").max(), n=pl.len())
+    assert "FAST STATISTICS" in q.explain()
+    expected = pl.DataFrame(
+        {"a": [-1], "a_max": [7], "n": [4]},
+        schema_overrides={"n": pl.get_index_type()},
+    )
+    assert_frame_equal(q.collect(), expected)
+
+    # The partition values aren't stored in the files, so those are read from the scan.
+    q = lf.select(pl.col("p").max(), pl.col("a").max())
+    assert "FAST STATISTICS" not in q.explain()
+    assert q.collect().rows() == [(2, 7)]
+
+    # As are filters on the partition columns.
+    q = lf.filter(pl.col("p") == 1).select(pl.col("a").max())
+    assert "FAST STATISTICS" not in q.explain()
+    assert q.collect().item() == 7
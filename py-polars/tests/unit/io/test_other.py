@@ -173,3 +173,32 @@ def test_no_glob(
 
     for func in scan_funcs:
         assert_frame_equal(func(paths[0], glob=False).lazy().collect(), df)  # type: ignore[call-arg]
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("read_func", "write_func"),
+    [
+        (pl.read_parquet, pl.DataFrame.write_parquet),
+        (pl.read_ipc, pl.DataFrame.write_ipc),
+    ],
+)
+def test_column_metadata_round_trip(
+    read_func: Callable[..., pl.DataFrame],
+    write_func: Callable[[pl.DataFrame, Path], None],
+    tmp_path: Path,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    path = tmp_path / "data"
+
+    a = pl.Series("a", [1, 2, 3]).with_metadata({"unit": "m/s"})
+    df = pl.DataFrame([a, pl.Series("b", ["x", "y", "z"])])
+    write_func(df, path)
+
+    out = read_func(path)
+    assert_frame_equal(out, df)
+    assert out["a"].metadata == {"unit": "m/s"}
+    assert out["b"].metadata == {}
+
+    out = read_func(path, columns=["b", "a"])
+    assert out["a"].metadata == {"unit": "m/s"}
@@ -4,13 +4,17 @@ import io
 import pickle
 import re
 from datetime import datetime, timedelta
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
 import polars as pl
-from polars.exceptions import SchemaError
+from polars.exceptions import PolarsError, SchemaError
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
 
 def test_pickling_simple_expression() -> None:
     e = pl.col("foo").sum()
@@ -161,6 +165,52 @@ def test_pickle_lazyframe_nested_function_udf() -> None:
     assert q.collect()["a"].to_list() == [2, 4, 6]
 
 
+@pytest.fixture
+def udf_serializer() -> Iterator[list[Any]]:
+    calls: list[Any] = []
+
+    def dumps(obj: Any) -> bytes:
+        calls.append(obj)
+        return b"custom" + pickle.dumps(obj)
+
+    def loads(b: bytes) -> Any:
+        assert b.startswith(b"custom")
+        return pickle.loads(b[len(b"custom") :])
+
+    pl.api.register_udf_serializer(dumps, loads)
+    try:
+        yield calls
+    finally:
+        pl.api.register_udf_serializer(None, None)
+
+
+def test_pickle_udf_registered_serializer(udf_serializer: list[Any]) -> None:
+    q = (
+        pl.LazyFrame({"a": [1, 2, 3]})
+        .map_batches(df_times2)
+        .with_columns(b=pl.col("a").map_elements(str, return_dtype=pl.String))
+    )
+    b = pickle.dumps(q)
+    assert len(udf_serializer) == 2
+
+    q = pickle.loads(b)
+    expected = pl.DataFrame({"a": [2, 4, 6], "b": ["2", "4", "6"]})
+    assert_frame_equal(q.collect(), expected)
+
+
+def test_pickle_udf_registered_serializer_missing(udf_serializer: list[Any]) -> None:
+    b = pickle.dumps(pl.col("a").map_batches(times2))
+    pl.api.register_udf_serializer(None, None)
+
+    with pytest.raises(PolarsError, match="serialized with a registered serializer"):
+        pickle.loads(b)
+
+
+def test_register_udf_serializer_invalid() -> None:
+    with pytest.raises(ValueError, match="must either both be set or both be None"):
+        pl.api.register_udf_serializer(pickle.dumps, None)
+
+
 def test_serde_categorical_series_10586() -> None:
     s = pl.Series(["a", "b", "b", "a", "c"], dtype=pl.Categorical)
     loaded_s = pickle.loads(pickle.dumps(s))
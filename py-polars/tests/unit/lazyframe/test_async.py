@@ -209,3 +209,16 @@ def test_gevent_collect_async_spawn(
         return result_greenlet.get().get()
 
     _gevent_run(main, raises)
+
+
+def test_cancel_background_collect_streaming() -> None:
+    lf = pl.LazyFrame({"a": range(100_000)})
+    q = lf.join(lf, how="cross").select(pl.col("a").sum())
+
+    start = time.perf_counter()
+    query = q.collect(background=True, engine="streaming")
+    time.sleep(0.1)
+    query.cancel()
+    with pytest.raises(pl.exceptions.ComputeError, match="query interrupted"):
+        query.fetch_blocking()
+    assert time.perf_counter() - start < 5
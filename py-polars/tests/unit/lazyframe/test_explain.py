@@ -37,3 +37,42 @@ def test_lf_explain_tree_format_deprecated() -> None:
 
     with pytest.deprecated_call():
         lf.explain(tree_format=True)
+
+
+@pytest.mark.filterwarnings("ignore::polars.exceptions.UnstableWarning")
+def test_lf_query_plan() -> None:
+    left = pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    right = pl.LazyFrame({"a": [1, 2], "c": [1.0, 2.0]})
+    lf = left.filter(pl.col("a") > 1).join(right, on="a", how="left").slice(0, 2)
+
+    plan = lf.query_plan(optimized=False)
+    assert plan.kind == "slice"
+    assert plan.options == {"offset": 0, "len": 2}
+    assert plan.schema == {"a": pl.Int64, "b": pl.String, "c": pl.Float64}
+
+    (join,) = plan.children
+    assert join.kind == "join"
+    assert join.options["how"] == "left"
+    assert join.expressions == ['col("a")', 'col("a")']
+
+    filter, df = join.children
+    assert filter.kind == "filter"
+    assert filter.expressions == ['[(col("a")) > (1)]']
+    assert [c.kind for c in filter.children] == ["df"]
+    assert df.kind == "df"
+    assert df.options["n_rows"] == 2
+    assert df.children == []
+
+
+@pytest.mark.filterwarnings("ignore::polars.exceptions.UnstableWarning")
+def test_lf_query_plan_optimized() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    plan = lf.select("a").filter(pl.col("a") > 1).query_plan()
+
+    def kinds(node: pl._plr.QueryPlanNode) -> list[str]:
+        return [node.kind] + [k for c in node.children for k in kinds(c)]
+
+    # the filter is pushed down into the scan
+    assert "filter" not in kinds(plan)
+    assert kinds(plan)[-1] == "df"
+    assert plan.schema == {"a": pl.Int64}
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import os
+from datetime import datetime
 from pathlib import Path
 from textwrap import dedent
 from typing import TYPE_CHECKING, Any
@@ -11,6 +12,7 @@ import polars as pl
 import polars._plr as plr
 from polars._utils.unstable import issue_unstable_warning
 from polars.config import _POLARS_CFG_ENV_VARS
+from polars.testing import assert_series_equal
 
 if TYPE_CHECKING:
     from collections.abc import Iterator
@@ -938,6 +940,52 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
     assert len(recwarn) == 1
 
 
+def test_lossy_casts(recwarn: pytest.WarningsRecorder) -> None:
+    lf = pl.LazyFrame(
+        {"a": [1, 2], "b": [0.5, 1.5]}, schema={"a": pl.Int64, "b": pl.Float64}
+    )
+    q = lf.select(pl.col("a") + pl.col("b"))
+
+    q.collect()
+    assert len(recwarn) == 0
+
+    with pl.Config(lossy_casts="warn"):
+        with pytest.warns(pl.exceptions.LossyCastWarning, match="i64 to f64"):
+            assert q.collect().to_series().to_list() == [1.5, 3.5]
+
+        # Literals are not reported.
+        q_lit = lf.select(pl.col("b") + pl.lit(1, dtype=pl.Int64))
+        q_lit.collect()
+        assert len(recwarn) == 0
+
+    with (
+        pl.Config(lossy_casts="raise"),
+        pytest.raises(pl.exceptions.InvalidOperationError, match="may lose"),
+    ):
+        q.collect()
+
+    with pytest.raises(ValueError, match="invalid `lossy_casts` mode"):
+        pl.Config.set_lossy_casts("error")  # type: ignore[arg-type]
+
+
+def test_tzdb(tmp_path: Path) -> None:
+    s = pl.Series([datetime(2024, 7, 1)]).dt.replace_time_zone("UTC")
+
+    with pl.Config(tzdb=tmp_path):
+        # UTC doesn't depend on the database.
+        assert_series_equal(s.dt.convert_time_zone("UTC"), s)
+        with pytest.raises(pl.exceptions.ComputeError, match="unable to read"):
+            s.dt.convert_time_zone("Europe/Amsterdam")
+
+    system_file = Path("/usr/share/zoneinfo/Europe/Amsterdam")
+    if system_file.is_file():
+        (tmp_path / "Europe").mkdir()
+        (tmp_path / "Europe" / "Amsterdam").write_bytes(system_file.read_bytes())
+        with pl.Config(tzdb=tmp_path):
+            out = s.dt.convert_time_zone("Europe/Amsterdam").dt.hour()
+        assert out.item() == 2
+
+
 @pytest.mark.parametrize(
     ("environment_variable", "config_setting", "value", "expected"),
     [
@@ -995,8 +1043,10 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
             True,
             "1",
         ),
+        ("POLARS_LOSSY_CASTS", "set_lossy_casts", "warn", "warn"),
         ("POLARS_STREAMING_CHUNK_SIZE", "set_streaming_chunk_size", 100, "100"),
         ("POLARS_TABLE_WIDTH", "set_tbl_width_chars", 80, "80"),
+        ("POLARS_TZDB", "set_tzdb", "system", "system"),
         ("POLARS_VERBOSE", "set_verbose", True, "1"),
         ("POLARS_WARN_UNSTABLE", "warn_unstable", True, "1"),
     ],
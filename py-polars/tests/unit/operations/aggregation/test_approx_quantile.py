@@ -0,0 +1,82 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import numpy as np
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
+
+@pytest.mark.parametrize("quantile", [0.0, 0.1, 0.25, 0.5, 0.9, 1.0])
+def test_approx_quantile_small_is_exact(quantile: float) -> None:
+    s = pl.Series("a", [5, None, 1, 4, 2, 3])
+    expected = s.quantile(quantile, "linear")
+    assert s.approx_quantile(quantile) == pytest.approx(expected)
+
+
+def test_approx_quantile_dtypes() -> None:
+    df = pl.DataFrame(
+        {
+            "f32": pl.Series([1.0, 2.0, float("nan")], dtype=pl.Float32),
+            "i8": pl.Series([1, 2, 3], dtype=pl.Int8),
+            "null": [None, None, None],
+        }
+    )
+    result = df.select(pl.all().approx_quantile(0.5))
+    expected = pl.DataFrame(
+        {"f32": [1.5], "i8": [2.0], "null": [None]},
+        schema={"f32": pl.Float32, "i8": pl.Float64, "null": pl.Float64},
+    )
+    assert_frame_equal(result, expected)
+    lf = df.lazy().select(pl.all().approx_quantile(0.5))
+    assert lf.collect_schema() == result.schema
+
+    with pytest.raises(InvalidOperationError, match="approx_quantile"):
+        pl.Series(["a"]).approx_quantile(0.5)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_approx_quantile_large(engine: EngineType) -> None:
+    rng = np.random.default_rng(0)
+    values = rng.normal(size=200_000)
+    lf = pl.LazyFrame({"a": values})
+
+    quantiles = [0.001, 0.01, 0.5, 0.99, 0.999]
+    result = lf.select(
+        pl.col("a").approx_quantile(q).alias(str(q)) for q in quantiles
+    ).collect(engine=engine)
+    for q in quantiles:
+        assert result[str(q)].item() == pytest.approx(np.quantile(values, q), abs=0.02)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_approx_quantile_group_by(engine: EngineType) -> None:
+    n = 10_000
+    lf = pl.LazyFrame({"g": [i % 3 for i in range(n)], "x": range(n)})
+    result = (
+        lf.group_by("g")
+        .agg(pl.col("x").approx_quantile(0.5, compression=200))
+        .sort("g")
+        .collect(engine=engine)
+    )
+    expected = (
+        lf.group_by("g")
+        .agg(pl.col("x").quantile(0.5, "linear"))
+        .sort("g")
+        .collect(engine=engine)
+    )
+    assert_frame_equal(result, expected, abs_tol=n * 0.005)
+
+
+def test_approx_quantile_invalid_args() -> None:
+    s = pl.Series("a", [1, 2, 3])
+    with pytest.raises(ComputeError, match="quantile"):
+        s.approx_quantile(1.5)
+    with pytest.raises(ComputeError, match="compression"):
+        s.approx_quantile(0.5, compression=0)
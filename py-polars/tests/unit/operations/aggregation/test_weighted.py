@@ -0,0 +1,97 @@
+from __future__ import annotations
+
+import numpy as np
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, ShapeError
+from polars.testing import assert_frame_equal
+
+
+def test_weighted_mean() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3],
+            "x": [1.0, 2.0, None, 4.0, 8.0, 5.0],
+            "w": [1, 3, 2, None, 2, 0],
+        }
+    )
+    result = df.group_by("g", maintain_order=True).agg(pl.col("x").weighted_mean("w"))
+    expected = pl.DataFrame({"g": [1, 2, 3], "x": [1.75, 8.0, None]})
+    assert_frame_equal(result, expected)
+
+    result = df.select(pl.col("x").weighted_mean("w").over("g"))
+    expected = pl.DataFrame({"x": [1.75, 1.75, 1.75, 8.0, 8.0, None]})
+    assert_frame_equal(result, expected)
+
+    assert df["x"].weighted_mean(df["w"]) == pytest.approx(23 / 6)
+
+
+def test_weighted_var() -> None:
+    rng = np.random.default_rng(0)
+    x = rng.normal(size=50)
+    w = rng.integers(1, 5, size=50)
+    s = pl.Series("x", x)
+
+    # Integer weights are frequencies, so this equals the variance of the repeated
+    # values.
+    expected = np.repeat(x, w)
+    assert s.weighted_var(pl.Series(w)) == pytest.approx(np.var(expected, ddof=1))
+    assert s.weighted_var(pl.Series(w), ddof=0) == pytest.approx(np.var(expected))
+
+    df = pl.DataFrame({"x": [1.0, 2.0], "w": [1, 0]})
+    assert df.select(pl.col("x").weighted_var("w")).item() is None
+    assert df.select(pl.col("x").weighted_var("w", ddof=0)).item() == 0.0
+
+
+@pytest.mark.parametrize(
+    ("quantile", "expected"),
+    [(0.0, 1.0), (0.25, 1.0), (0.26, 2.0), (0.5, 2.0), (0.8, 4.0), (1.0, 4.0)],
+)
+def test_weighted_quantile(quantile: float, expected: float) -> None:
+    s = pl.Series("x", [4, 1, None, 2])
+    weights = pl.Series([1, 1, 5, 2])
+    assert s.weighted_quantile(weights, quantile) == expected
+
+
+def test_weighted_quantile_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b"],
+            "x": [1, 2, 3, 10, 20],
+            "w": [1.0, 1.0, 2.0, 3.0, 1.0],
+        }
+    )
+    result = df.group_by("g", maintain_order=True).agg(
+        median=pl.col("x").weighted_quantile("w", 0.5),
+        mean=pl.col("x").weighted_mean("w"),
+    )
+    expected = pl.DataFrame(
+        {"g": ["a", "b"], "median": [2.0, 10.0], "mean": [2.25, 12.5]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_weighted_dtype() -> None:
+    lf = pl.LazyFrame(
+        {"f32": [1.0, 2.0], "i": [1, 2], "w": [1, 1]},
+        schema_overrides={"f32": pl.Float32},
+    )
+    result = lf.select(
+        pl.col("f32").weighted_mean("w"),
+        pl.col("i").weighted_var("w"),
+        pl.col("i").weighted_quantile("w", 0.5).alias("q"),
+    )
+    expected_schema = {"f32": pl.Float32, "i": pl.Float64, "q": pl.Float64}
+    assert result.collect_schema() == expected_schema
+    assert result.collect().schema == expected_schema
+
+
+def test_weighted_invalid() -> None:
+    s = pl.Series("x", [1.0, 2.0, 3.0])
+    with pytest.raises(ComputeError, match="weights must be non-negative"):
+        s.weighted_mean(pl.Series([1, -1, 1]))
+    with pytest.raises(ComputeError, match="quantile should be between"):
+        s.weighted_quantile(pl.Series([1, 1, 1]), 1.5)
+    with pytest.raises(ShapeError, match="same length"):
+        pl.select(pl.lit(s).weighted_mean(pl.Series([1, 1])))
@@ -983,3 +983,34 @@ def test_log_broadcast(dtype: pl.DataType) -> None:
         pl.Series("a", [81], dtype=dtype).log(b),
         pl.Series("a", [4, 4, 2, 4, 2], dtype=dtype),
     )
+
+
+@pytest.mark.parametrize("dtype", INTEGER_DTYPES)
+def test_checked_arithmetic_null_on_overflow(dtype: PolarsIntegerType) -> None:
+    empty = pl.Series([], dtype=dtype)
+    lo, hi = empty.lower_bound().item(), empty.upper_bound().item()
+    s = pl.Series("a", [hi, lo, 2, None], dtype=dtype)
+
+    assert s.checked_arithmetic(1, "add").to_list() == [None, lo + 1, 3, None]
+    assert s.checked_arithmetic(1, "sub").to_list() == [hi - 1, None, 1, None]
+    assert s.checked_arithmetic(2, "mul").to_list() == [
+        None,
+        None if lo else 0,
+        4,
+        None,
+    ]
+
+
+def test_checked_arithmetic_warn() -> None:
+    df = pl.DataFrame({"a": [127, -128, 1]}, schema={"a": pl.Int8})
+
+    with pytest.warns(UserWarning, match="2 value\\(s\\) overflowed in checked mul"):
+        result = df.select(pl.col("a").checked_arithmetic(2, "mul", warn=True))
+    assert result.to_series().to_list() == [None, None, 2]
+
+    # The operands are cast to their supertype first.
+    result = df.select(pl.col("a").checked_arithmetic(pl.lit(2, pl.Int16), "mul"))
+    assert result.to_series().to_list() == [254, -256, 2]
+
+    with pytest.raises(InvalidOperationError, match="only supported for integers"):
+        pl.Series([1.0]).checked_arithmetic(1, "add")
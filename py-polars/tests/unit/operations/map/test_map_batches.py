@@ -171,3 +171,36 @@ def test_map_batches_no_return_dtype_25601(
     )
     expected = pl.DataFrame({"colx": expected_data})
     assert_frame_equal(result, expected)
+
+
+def test_lazy_map_batches_preserving_flags_pushdown() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": ["a", "b", "c", "d"]})
+    seen: list[pl.DataFrame] = []
+
+    def udf(df: pl.DataFrame) -> pl.DataFrame:
+        seen.append(df)
+        return df
+
+    # Without declared properties the UDF is an optimization barrier.
+    q = lf.map_batches(udf, predicate_pushdown=False, projection_pushdown=False)
+    assert_frame_equal(q.head(2).collect(), lf.head(2).collect())
+    assert seen[-1].shape == (4, 2)
+
+    q = lf.map_batches(
+        udf,
+        predicate_pushdown=False,
+        projection_pushdown=False,
+        preserves_order=True,
+        preserves_row_count=True,
+    )
+    assert_frame_equal(q.head(2).collect(), lf.head(2).collect())
+    assert seen[-1].shape == (2, 2)
+
+    q = lf.map_batches(
+        udf,
+        predicate_pushdown=False,
+        projection_pushdown=False,
+        preserves_schema=True,
+    )
+    assert_frame_equal(q.select("a").collect(), lf.select("a").collect())
+    assert seen[-1].columns == ["a"]
@@ -425,3 +425,81 @@ def test_map_elements_list_dtype_24006() -> None:
 def test_map_elements_reentrant_mutable_no_deadlock() -> None:
     s = pl.Series("a", [1, 2, 3])
     s.map_elements(lambda _: s.rechunk(in_place=True)[0])
+
+
+@pytest.mark.parametrize("return_dtype", [None, pl.String, pl.Object])
+def test_map_elements_cache_objects(return_dtype: pl.DataType | None) -> None:
+    class Tag:
+        def __init__(self, name: str) -> None:
+            self.name = name
+
+    a, b = Tag("a"), Tag("b")
+    s = pl.Series("tags", [a, b, None, a, a, b], dtype=pl.Object)
+
+    calls: list[str] = []
+
+    def f(tag: Tag) -> str:
+        calls.append(tag.name)
+        return tag.name.upper()
+
+    result = s.map_elements(f, return_dtype=return_dtype, cache_objects=True)
+    assert calls == ["a", "b"]
+    assert result.to_list() == ["A", "B", None, "A", "A", "B"]
+
+    calls.clear()
+    s.map_elements(f, return_dtype=return_dtype)
+    assert calls == ["a", "b", "a", "a", "b"]
+
+
+def test_map_elements_cache_objects_expr() -> None:
+    class Tag:
+        pass
+
+    tag = Tag()
+    df = pl.DataFrame({"t": pl.Series([tag] * 4, dtype=pl.Object)})
+
+    calls = 0
+
+    def f(_: Tag) -> int:
+        nonlocal calls
+        calls += 1
+        return 1
+
+    out = df.select(
+        pl.col("t").map_elements(f, return_dtype=pl.Int64, cache_objects=True)
+    )
+    assert calls == 1
+    assert out["t"].to_list() == [1, 1, 1, 1]
+
+
+def test_map_elements_numba_cfunc() -> None:
+    numba = pytest.importorskip("numba", exc_type=ImportError)
+
+    @numba.cfunc("float64(int64)")  # type: ignore[untyped-decorator]
+    def half(x: int) -> float:
+        return x / 2
+
+    s = pl.Series("a", [1, None, 4, 5], dtype=pl.Int32)
+    assert_series_equal(s.map_elements(half), pl.Series("a", [0.5, None, 2.0, 2.5]))
+    assert_series_equal(
+        s.map_elements(half, return_dtype=pl.Float32),
+        pl.Series("a", [0.5, None, 2.0, 2.5], dtype=pl.Float32),
+    )
+
+    df = pl.DataFrame({"a": range(100_000)})
+    result = df.select(pl.col("a").map_elements(half, return_dtype=pl.Float64))
+    assert result["a"].sum() == sum(range(100_000)) / 2
+
+
+def test_map_elements_arrow_compute_function() -> None:
+    pc = pytest.importorskip("pyarrow.compute")
+
+    s = pl.Series("a", ["x", None, "yz"])
+    assert_series_equal(
+        s.map_elements(pc.utf8_upper, return_dtype=pl.String),
+        pl.Series("a", ["X", None, "YZ"]),
+    )
+    assert_series_equal(
+        s.map_elements(pc.utf8_length, return_dtype=pl.Int64),
+        pl.Series("a", [1, None, 2], dtype=pl.Int64),
+    )
@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
+
+    from polars._typing import EngineType
+
+
+@pytest.fixture
+def python_mean() -> Callable[[pl.Expr | str], pl.Expr]:
+    def update(state: tuple[float, int], s: pl.Series) -> tuple[float, int]:
+        return (state[0] + s.sum(), state[1] + s.count())
+
+    def merge(a: tuple[float, int], b: tuple[float, int]) -> tuple[float, int]:
+        return (a[0] + b[0], a[1] + b[1])
+
+    def finalize(state: tuple[float, int]) -> float | None:
+        return state[0] / state[1] if state[1] else None
+
+    return pl.register_aggregation(
+        "python_mean",
+        init=lambda: (0.0, 0),
+        update=update,
+        merge=merge,
+        finalize=finalize,
+        return_dtype=pl.Float64,
+    )
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_register_aggregation_group_by(
+    python_mean: Callable[[pl.Expr | str], pl.Expr], engine: EngineType
+) -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 2, 1, 3, 2, 1],
+            "x": [1, 2, None, 4, 5, 6],
+        }
+    )
+
+    result = (
+        lf.group_by("g")
+        .agg(python_mean("x"), pl.col("x").mean().alias("expected"))
+        .sort("g")
+        .collect(engine=engine)
+    )
+
+    assert_frame_equal(result.select("g", "x"), result.select("g", x="expected"))
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_register_aggregation_many_groups(
+    python_mean: Callable[[pl.Expr | str], pl.Expr], engine: EngineType
+) -> None:
+    n = 10_000
+    lf = pl.LazyFrame({"g": [i % 1000 for i in range(n)], "x": range(n)})
+
+    result = (
+        lf.group_by("g")
+        .agg(python_mean(pl.col("x") * 2))
+        .sort("g")
+        .collect(engine=engine)
+    )
+    expected = lf.group_by("g").agg((pl.col("x") * 2).mean()).sort("g").collect()
+
+    assert_frame_equal(result, expected)
+
+
+def test_register_aggregation_select(
+    python_mean: Callable[[pl.Expr | str], pl.Expr],
+) -> None:
+    df = pl.DataFrame({"x": [1, 2, 3, 6]})
+    assert df.select(python_mean("x")).to_dict(as_series=False) == {"x": [3.0]}
+
+    empty = df.clear()
+    assert empty.select(python_mean("x")).to_dict(as_series=False) == {"x": [None]}
+
+
+def test_register_aggregation_schema(
+    python_mean: Callable[[pl.Expr | str], pl.Expr],
+) -> None:
+    lf = pl.LazyFrame({"g": ["a"], "x": [1]})
+    q = lf.group_by("g").agg(python_mean("x").alias("mean"))
+    assert q.collect_schema() == pl.Schema({"g": pl.String, "mean": pl.Float64})
+
+
+def test_register_aggregation_error() -> None:
+    def update(state: Any, s: pl.Series) -> Any:
+        msg = "update failed"
+        raise ValueError(msg)
+
+    fail = pl.register_aggregation(
+        "fail",
+        init=lambda: None,
+        update=update,
+        merge=lambda a, b: a,
+        finalize=lambda state: state,
+        return_dtype=pl.Int64,
+    )
+    df = pl.DataFrame({"g": [1, 2], "x": [1, 2]})
+    with pytest.raises(ValueError, match="update failed"):
+        df.group_by("g").agg(fail("x"))
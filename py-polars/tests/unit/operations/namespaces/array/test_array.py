@@ -1,12 +1,13 @@
 from __future__ import annotations
 
 import datetime
+import math
 from typing import Any
 
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, InvalidOperationError
+from polars.exceptions import ComputeError, InvalidOperationError, ShapeError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -700,3 +701,76 @@ def test_array_get_broadcast_26217() -> None:
         {"literal": [42, 13, 37, 13, 37, 42, 13]}, schema={"literal": pl.UInt8}
     )
     assert_frame_equal(out, expected)
+
+
+def test_arr_linalg() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [4, 5, 6], None, [1, None, 3], [0, 0, 0]],
+            "b": [[1, 0, 1], [2, 2, 2], [1, 1, 1], [1, 1, 1], [1, 2, 3]],
+        },
+        schema={"a": pl.Array(pl.Int64, 3), "b": pl.Array(pl.Int64, 3)},
+    )
+    out = df.select(
+        dot=pl.col("a").arr.dot("b"),
+        norm=pl.col("a").arr.l2_norm(),
+        cos=pl.col("a").arr.cosine_similarity("b"),
+    )
+    assert out.schema == {"dot": pl.Float64, "norm": pl.Float64, "cos": pl.Float64}
+    assert out["dot"].to_list() == [4.0, 30.0, None, None, 0.0]
+    assert_series_equal(
+        out["norm"],
+        pl.Series("norm", [14**0.5, 77**0.5, None, None, 0.0]),
+    )
+    cos = out["cos"].to_list()
+    assert cos[0] == pytest.approx(4 / (14**0.5 * 2**0.5))
+    assert cos[1] == pytest.approx(30 / (77**0.5 * 12**0.5))
+    assert cos[2:4] == [None, None]
+    assert math.isnan(cos[4])
+
+    # Broadcast a vector literal.
+    assert df.select(pl.col("b").arr.dot([1.0, 2.0, 3.0]))["b"].to_list() == [
+        4.0,
+        12.0,
+        6.0,
+        6.0,
+        14.0,
+    ]
+
+
+def test_arr_linalg_wide_float32() -> None:
+    width = 21
+    a = [[float(i + j) for j in range(width)] for i in range(3)]
+    b = [[float(i - j) for j in range(width)] for i in range(3)]
+    s_a = pl.Series("a", a, dtype=pl.Array(pl.Float32, width))
+    s_b = pl.Series("b", b, dtype=pl.Array(pl.Float32, width))
+
+    out = s_a.arr.dot(s_b)
+    assert out.dtype == pl.Float32
+    expected = [sum(x * y for x, y in zip(ra, rb)) for ra, rb in zip(a, b)]
+    assert_series_equal(out, pl.Series("a", expected, dtype=pl.Float32))
+
+    assert s_a.arr.l2_norm().dtype == pl.Float32
+    s_b = s_b.cast(pl.Array(pl.Float64, width))
+    assert s_a.arr.cosine_similarity(s_b).dtype == pl.Float64
+
+
+def test_arr_linalg_width_mismatch() -> None:
+    a = pl.Series([[1.0, 2.0]], dtype=pl.Array(pl.Float64, 2))
+    b = pl.Series([[1.0, 2.0, 3.0]], dtype=pl.Array(pl.Float64, 3))
+    with pytest.raises(ShapeError, match="same width"):
+        a.arr.dot(b)
+
+
+def test_arr_linalg_length_mismatch() -> None:
+    dtype = pl.Array(pl.Float64, 2)
+    a = pl.Series("a", [[1.0, 2.0]], dtype=dtype)
+    empty = pl.Series("b", [], dtype=dtype)
+
+    # A single row is broadcast, also against no rows.
+    assert_series_equal(a.arr.dot(empty), pl.Series("a", [], dtype=pl.Float64))
+    assert_series_equal(empty.arr.dot(a), pl.Series("b", [], dtype=pl.Float64))
+
+    b = pl.Series("b", [[1.0, 2.0]] * 3, dtype=dtype)
+    with pytest.raises(ShapeError):
+        b.slice(0, 2).arr.cosine_similarity(b)
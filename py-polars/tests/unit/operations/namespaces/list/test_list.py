@@ -451,6 +451,97 @@ def test_list_diff() -> None:
     assert s.list.diff().to_list() == expected.to_list()
 
 
+def test_list_cum_agg() -> None:
+    df = pl.DataFrame({"a": [[3, 1, 2], [4, None, 5], [], None]})
+    out = df.select(
+        cum_sum=pl.col("a").list.cum_sum(),
+        cum_prod=pl.col("a").list.cum_prod(),
+        cum_min=pl.col("a").list.cum_min(),
+        cum_max=pl.col("a").list.cum_max(),
+        cum_count=pl.col("a").list.cum_count(),
+        cum_sum_rev=pl.col("a").list.cum_sum(reverse=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "cum_sum": [[3, 4, 6], [4, None, 9], [], None],
+            "cum_prod": [[3, 3, 6], [4, None, 20], [], None],
+            "cum_min": [[3, 1, 1], [4, None, 4], [], None],
+            "cum_max": [[3, 3, 3], [4, None, 5], [], None],
+            "cum_count": [[1, 2, 3], [1, 1, 2], [], None],
+            "cum_sum_rev": [[6, 3, 2], [9, None, 5], [], None],
+        },
+        schema_overrides={"cum_count": pl.List(pl.UInt32)},
+    )
+    assert_frame_equal(out, expected)
+
+    # matches evaluating the equivalent expression on every sublist
+    for name in ["cum_sum", "cum_prod", "cum_min", "cum_max", "cum_count"]:
+        assert_series_equal(
+            df.select(getattr(pl.col("a").list, name)())["a"],
+            df.select(pl.col("a").list.eval(getattr(pl.element(), name)()))["a"],
+        )
+
+    lf = df.lazy().select(pl.col("a").cast(pl.List(pl.Int8)).list.cum_sum())
+    assert lf.collect_schema() == lf.collect().schema == {"a": pl.List(pl.Int64)}
+
+
+def test_list_rolling() -> None:
+    s = pl.Series("a", [[1, 2, 3, 4], [5, None, 7], [8], [], None])
+    assert s.list.rolling_sum(2).to_list() == [
+        [None, 3, 5, 7],
+        [None, None, None],
+        [None],
+        [],
+        None,
+    ]
+    assert s.list.rolling_sum(2, min_samples=1).to_list() == [
+        [1, 3, 5, 7],
+        [5, 5, 7],
+        [8],
+        [],
+        None,
+    ]
+    assert s.list.rolling_mean(3, center=True).to_list() == [
+        [None, 2.0, 3.0, None],
+        [None, None, None],
+        [None],
+        [],
+        None,
+    ]
+    assert s.list.rolling_min(2).to_list()[0] == [None, 1, 2, 3]
+    assert s.list.rolling_max(2).to_list()[0] == [None, 2, 3, 4]
+
+    # matches evaluating the equivalent expression on every sublist
+    df = s.to_frame()
+    for name, kwargs in [
+        ("rolling_min", {}),
+        ("rolling_max", {"center": True}),
+        ("rolling_sum", {}),
+        ("rolling_mean", {}),
+        ("rolling_median", {}),
+        ("rolling_std", {"ddof": 0}),
+        ("rolling_var", {}),
+    ]:
+        list_expr = getattr(pl.col("a").list, name)(3, min_samples=1, **kwargs)
+        eval_expr = getattr(pl.element(), name)(3, min_samples=1, **kwargs)
+        assert_series_equal(
+            df.select(list_expr).to_series(),
+            df.select(pl.col("a").list.eval(eval_expr)).to_series(),
+        )
+
+    lf = df.lazy().select(
+        sum=pl.col("a").list.rolling_sum(2),
+        mean=pl.col("a").list.rolling_mean(2),
+        std=pl.col("a").cast(pl.List(pl.Float32)).list.rolling_std(2),
+    )
+    assert lf.collect_schema() == lf.collect().schema
+    assert lf.collect_schema() == {
+        "sum": pl.List(pl.Int64),
+        "mean": pl.List(pl.Float64),
+        "std": pl.List(pl.Float32),
+    }
+
+
 def test_slice() -> None:
     vals = [[1, 2, 3, 4], [10, 2, 1]]
     s = pl.Series("a", vals)
@@ -778,6 +869,57 @@ def test_list_count_matches_wildcard_expansion() -> None:
     }
 
 
+def test_list_index_of() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [4, 5, 4], [None, 6], [7], None],
+            "v": [3, 4, None, 8, 1],
+        }
+    )
+    out = df.select(
+        scalar=pl.col("a").list.index_of(4),
+        per_row=pl.col("a").list.index_of("v"),
+        null=pl.col("a").list.index_of(None),
+    )
+    expected = pl.DataFrame(
+        {
+            "scalar": [None, 0, None, None, None],
+            "per_row": [2, 0, 0, None, None],
+            "null": [None, None, 0, None, None],
+        },
+        schema={
+            "scalar": pl.get_index_type(),
+            "per_row": pl.get_index_type(),
+            "null": pl.get_index_type(),
+        },
+    )
+    assert_frame_equal(out, expected)
+
+    # Sliced lists must be searched relative to their own offsets.
+    out = df.slice(1, 2).select(pl.col("a").list.index_of("v"))
+    assert out["a"].to_list() == [0, 0]
+
+    s = pl.Series("s", [["x", "y"], ["y", "y"], []])
+    assert s.list.index_of("y").to_list() == [1, 0, None]
+
+    # Needles that don't fit the elements are never found, not even as null.
+    s = pl.Series("s", [[1, None], [255]], dtype=pl.List(pl.UInt8))
+    assert s.list.index_of(300).to_list() == [None, None]
+    assert s.list.index_of(pl.lit(-1, pl.Int64)).to_list() == [None, None]
+    assert s.list.index_of(pl.Series([1, 1000])).to_list() == [0, None]
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        s.list.index_of(1.5)
+
+
+def test_list_find() -> None:
+    s = pl.Series("a", [[1, 8, 3], [5, None, 7], [], None, [None, 2]])
+    assert s.list.find(pl.element() > 4).to_list() == [1, 0, None, None, None]
+    assert s.list.find(pl.element().is_null()).to_list() == [None, 1, None, None, 0]
+
+    with pytest.raises(InvalidOperationError, match="must evaluate to booleans"):
+        s.list.find(pl.element() + 1)
+
+
 def test_list_gather_oob_10079() -> None:
     df = pl.DataFrame(
         {
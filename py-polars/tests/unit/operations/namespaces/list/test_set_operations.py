@@ -248,3 +248,55 @@ def test_broadcast_sliced() -> None:
     expected = pl.DataFrame({"a": [[2], [3, 4]]})
 
     assert_frame_equal(out, expected)
+
+
+def test_list_set_operations_multiset() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 1, 2, 3], [3, None, None], []],
+            "b": [[1, 2, 2], [None], [1]],
+        }
+    )
+
+    out = df.select(
+        intersection=pl.col("a").list.set_intersection("b", multiset=True),
+        union=pl.col("a").list.set_union("b", multiset=True),
+        difference=pl.col("a").list.set_difference("b", multiset=True),
+        sdiff=pl.col("a").list.set_symmetric_difference("b", multiset=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "intersection": [[1, 2], [None], []],
+            "union": [[1, 1, 2, 3, 2], [3, None, None], [1]],
+            "difference": [[1, 3], [3, None], []],
+            "sdiff": [[1, 3, 2], [3, None], [1]],
+        },
+        schema={
+            "intersection": pl.List(pl.Int64),
+            "union": pl.List(pl.Int64),
+            "difference": pl.List(pl.Int64),
+            "sdiff": pl.List(pl.Int64),
+        },
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_list_set_operations_multiset_broadcast() -> None:
+    df = pl.DataFrame({"a": [["x", "y", "x"], ["y"]]})
+    rhs = pl.lit(pl.Series([["x", "y", "y"]]))
+
+    out = df.select(
+        intersection=pl.col("a").list.set_intersection(rhs, multiset=True),
+        union=pl.col("a").list.set_union(rhs, multiset=True),
+        difference=pl.col("a").list.set_difference(rhs, multiset=True),
+        rev_difference=rhs.list.set_difference("a", multiset=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "intersection": [["x", "y"], ["y"]],
+            "union": [["x", "y", "x", "y"], ["y", "x", "y"]],
+            "difference": [["x"], []],
+            "rev_difference": [["y"], ["x", "y"]],
+        }
+    )
+    assert_frame_equal(out, expected)
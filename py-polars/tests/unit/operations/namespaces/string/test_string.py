@@ -268,6 +268,17 @@ def test_str_decode() -> None:
     assert_series_equal(base64_encoded.str.decode("base64"), expected)
 
 
+def test_str_base64url() -> None:
+    s = pl.Series(["ÿ?", "foo", None])
+
+    encoded = s.str.encode("base64url", padding=False)
+    assert encoded.to_list() == ["w78_", "Zm9v", None]
+    assert s.str.encode("base64").to_list() == ["w78/", "Zm9v", None]
+
+    assert encoded.str.decode("base64url").to_list() == ["ÿ?".encode(), b"foo", None]
+    assert pl.Series(["w78/"]).str.decode("base64url", strict=False).to_list() == [None]
+
+
 def test_str_decode_exception() -> None:
     s = pl.Series(["not a valid", "626172", None])
     with pytest.raises(ComputeError):
@@ -894,6 +905,57 @@ def test_json_path_match() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_json_path_match_all_matches() -> None:
+    df = pl.DataFrame(
+        {
+            "str": ['{"a":[1,2,null]}', '{"a":[]}', None, '{"a":[{"b":3}]}'],
+            "pat": ["$.a[*]", "$.a[*]", "$.a[*]", "$.a[0].b"],
+        }
+    )
+    out = df.select(
+        untyped=pl.col("str").str.json_path_match("$.a[*]", all_matches=True),
+        typed=pl.col("str").str.json_path_match(
+            pl.col("pat"), all_matches=True, dtype=pl.Int64
+        ),
+        first=pl.col("str").str.json_path_match(pl.col("pat"), dtype=pl.Int64),
+    )
+    expected = pl.DataFrame(
+        {
+            "untyped": [["1", "2", None], [], None, ['{"b":3}']],
+            "typed": [[1, 2, None], [], None, [3]],
+            "first": [1, None, None, 3],
+        },
+        schema={
+            "untyped": pl.List(pl.String),
+            "typed": pl.List(pl.Int64),
+            "first": pl.Int64,
+        },
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_json_extract_many() -> None:
+    s = pl.Series("json", ['{"a":1,"b":{"c":"x"}}', '{"a":2.5}', None])
+
+    out = s.str.json_extract_many(["$.a", "$.b.c"])
+    expected = pl.Series(
+        "json",
+        [{"$.a": "1", "$.b.c": "x"}, {"$.a": "2.5", "$.b.c": None}, None],
+        dtype=pl.Struct({"$.a": pl.String, "$.b.c": pl.String}),
+    )
+    assert_series_equal(out, expected)
+
+    out = s.str.json_extract_many(
+        {"$.a": pl.Float64, "$.b": pl.Struct({"c": pl.String})}
+    )
+    expected = pl.Series(
+        "json",
+        [{"$.a": 1.0, "$.b": {"c": "x"}}, {"$.a": 2.5, "$.b": None}, None],
+        dtype=pl.Struct({"$.a": pl.Float64, "$.b": pl.Struct({"c": pl.String})}),
+    )
+    assert_series_equal(out, expected)
+
+
 def test_str_json_path_match_wrong_length() -> None:
     df = pl.DataFrame({"num": ["-10", "-1", "0"]})
     with pytest.raises((ShapeError, ComputeError)):
@@ -1001,6 +1063,35 @@ def test_contains() -> None:
         )
 
 
+@pytest.mark.parametrize(
+    ("pattern", "simplified"),
+    [
+        ("^foo$", "=="),
+        ("^foo", "str.starts_with"),
+        (r"foo\.$", "str.ends_with"),
+        (r"\(foo", "str.contains"),
+        ("^", "str.starts_with"),
+        ("fo+", None),
+        (r"^\d", None),
+        ("^foo|bar$", None),
+    ],
+)
+def test_contains_anchored_regex_simplified(
+    pattern: str, simplified: str | None
+) -> None:
+    lf = pl.LazyFrame(
+        {"text": ["foo", "foo.", "(foo", "foobar", "barfoo", "fooo", "1foo", "", None]}
+    )
+    q = lf.select(pl.col("text").str.contains(pattern))
+
+    plan = q.explain()
+    assert (simplified or "str.contains") in plan
+    if simplified not in (None, "str.contains"):
+        assert "str.contains" not in plan
+
+    assert_frame_equal(q.collect(), q.collect(no_optimization=True))
+
+
 def test_contains_expr() -> None:
     df = pl.DataFrame(
         {
@@ -2082,6 +2173,21 @@ def test_string_normalize_wrong_input() -> None:
         pl.Series(["01²"], dtype=pl.String).str.normalize("foobar")  # type: ignore[arg-type]
 
 
+def test_string_casefold() -> None:
+    words = ["Straße", "STRASSE", "ﬁle", "ΣΊΣΥΦΟΣ", "ꭰꮃ", "İı", "MaSSe", ""]  # noqa: RUF001
+    s = pl.Series(words + [None])
+
+    # matches the full case folding applied by Python
+    expected = pl.Series([w.casefold() for w in words] + [None])
+    assert_series_equal(s.str.casefold(), expected)
+    assert_series_equal(pl.select(pl.lit(s).str.casefold()).to_series(), expected)
+
+    df = pl.DataFrame({"a": ["Maße", "STRASSE"], "b": ["MASSE", "strasse"]})
+    assert df.select(
+        pl.col("a").str.casefold() == pl.col("b").str.casefold()
+    ).to_series().to_list() == [True, True]
+
+
 def test_to_integer_unequal_lengths_22034() -> None:
     s = pl.Series("a", ["1", "2", "3"], pl.String)
     with pytest.raises(pl.exceptions.ShapeError):
@@ -2315,3 +2421,116 @@ def test_str_split_regex_scalar_string_expr() -> None:
     )
 
     assert_frame_equal(out, expected)
+
+
+def test_str_split_regex_n() -> None:
+    s = pl.Series("s", ["a b  c   d", "a", "", None])
+
+    assert s.str.split_regex(r"\s+").to_list() == [
+        ["a", "b", "c", "d"],
+        ["a"],
+        [""],
+        None,
+    ]
+    assert s.str.split_regex(r"\s+", n=2).to_list() == [
+        ["a", "b  c   d"],
+        ["a"],
+        [""],
+        None,
+    ]
+    assert s.str.split_regex(r"\s+", n=2, inclusive=True).to_list() == [
+        ["a ", "b  c   d"],
+        ["a"],
+        [""],
+        None,
+    ]
+    assert s.str.split_regex(r"\s+", n=1).to_list() == [
+        ["a b  c   d"],
+        ["a"],
+        [""],
+        None,
+    ]
+
+    df = pl.DataFrame({"s": ["abc", "x1y2z"], "pat": ["", r"\d"]})
+    out = df.select(pl.col("s").str.split_regex(pl.col("pat"), n=2))
+    assert out["s"].to_list() == [["a", "bc"], ["x", "y2z"]]
+
+    with pytest.raises(ComputeError):
+        df.select(pl.col("s").str.split_regex("(", n=2))
+    out = df.select(pl.col("s").str.split_regex("(", n=2, strict=False))
+    assert out["s"].to_list() == [None, None]
+
+
+def test_str_fuzzy_matching() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "flaw", "naïve", "", None, "abc"],
+            "b": ["sitting", "lawn", "naive", "", "x", None],
+        }
+    )
+    out = df.select(
+        lev=pl.col("a").str.levenshtein_distance(pl.col("b")),
+        ham=pl.col("a").str.hamming(pl.col("b")),
+        jw=pl.col("a").str.jaro_winkler(pl.col("b")),
+    )
+    expected = pl.DataFrame(
+        {
+            "lev": [3, 2, 1, 0, None, None],
+            "ham": [None, 4, 1, 0, None, None],
+            "jw": [0.746032, 0.833333, 0.893333, 1.0, None, None],
+        },
+        schema={"lev": pl.UInt32, "ham": pl.UInt32, "jw": pl.Float64},
+    )
+    assert_frame_equal(out, expected, abs_tol=1e-6)
+
+    # column vs literal, and literal vs column
+    s = pl.Series("s", ["martha", "marhta", "mart"])
+    assert s.str.levenshtein_distance("martha").to_list() == [0, 2, 2]
+    assert s.str.hamming("martha").to_list() == [0, 2, None]
+    assert pl.select(
+        pl.lit("martha").str.jaro_winkler(pl.Series(["marhta"]))
+    ).item() == pytest.approx(0.961111, abs=1e-6)
+
+    with pytest.raises(ShapeError):
+        df.select(pl.col("a").str.levenshtein_distance(pl.Series(["a", "b"])))
+
+
+def test_str_tokenize() -> None:
+    s = pl.Series("s", ["The quick (\"brown\") fox can't jump 3.5 feet!", "", None])
+    assert s.str.tokenize().to_list() == [
+        ["The", "quick", "brown", "fox", "can't", "jump", "3.5", "feet"],
+        [],
+        None,
+    ]
+    assert s.str.tokenize(lowercase=True, stop_words=["the", "fox"]).to_list() == [
+        ["quick", "brown", "can't", "jump", "3.5", "feet"],
+        [],
+        None,
+    ]
+    assert s.str.tokenize("whitespace").to_list() == [
+        ["The", "quick", '("brown")', "fox", "can't", "jump", "3.5", "feet!"],
+        [],
+        None,
+    ]
+    out = s.str.tokenize("pattern", pattern=r"[a-z]+", lowercase=True, stop_words={"t"})
+    assert out.to_list() == [
+        ["the", "quick", "brown", "fox", "can", "jump", "feet"],
+        [],
+        None,
+    ]
+
+    df = pl.DataFrame({"text": ["Straße und Café", "Привет, мир"]})
+    out = df.select(pl.col("text").str.tokenize(lowercase=True))
+    assert out.schema == pl.Schema({"text": pl.List(pl.String)})
+    assert out["text"].to_list() == [["straße", "und", "café"], ["привет", "мир"]]
+
+    with pytest.raises(ComputeError):
+        s.str.tokenize("pattern", pattern="(")
+
+    # Unknown methods are not taken as a regular expression.
+    with pytest.raises(ValueError, match="`method` must be one of"):
+        s.str.tokenize("[a-z]+")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="`pattern` must be given"):
+        s.str.tokenize("pattern")
+    with pytest.raises(ValueError, match="`pattern` can only be given"):
+        s.str.tokenize("whitespace", pattern=r"\w+")
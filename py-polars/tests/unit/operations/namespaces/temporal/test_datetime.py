@@ -841,6 +841,60 @@ def test_quarter() -> None:
     ).dt.quarter().to_list() == [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]
 
 
+@pytest.mark.parametrize(
+    ("dt", "iso", "us"),
+    [
+        (date(2022, 1, 1), 52, 1),
+        (date(2022, 1, 2), 52, 2),
+        (date(2022, 1, 3), 1, 2),
+        (date(2022, 12, 31), 52, 53),
+        (date(2023, 1, 1), 52, 1),
+        (date(2000, 12, 31), 52, 54),
+    ],
+)
+def test_week_convention(dt: date, iso: int, us: int) -> None:
+    s = pl.Series([dt])
+    assert s.dt.week().item() == iso
+    assert s.dt.week(convention="iso").item() == iso
+    assert s.dt.week(convention="us").item() == us
+    assert s.cast(pl.Datetime).dt.week(convention="us").item() == us
+
+
+def test_week_invalid_convention() -> None:
+    with pytest.raises(ValueError, match="`convention` must be one of"):
+        pl.Series([date(2022, 1, 1)]).dt.week(convention="eu")  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize("start", ["apr", "April", 4])
+def test_fiscal_year_quarter(start: str | int) -> None:
+    s = pl.datetime_range(
+        datetime(2022, 1, 1), datetime(2022, 12, 1), "1mo", eager=True
+    )
+    assert s.dt.fiscal_year(start=start).to_list() == [2022] * 3 + [2023] * 9
+    assert s.dt.fiscal_quarter(start=start).to_list() == [
+        4, 4, 4, 1, 1, 1, 2, 2, 2, 3, 3, 3
+    ]  # fmt: skip
+
+    df = pl.DataFrame({"date": s}).lazy()
+    result = df.select(
+        fy=pl.col("date").dt.fiscal_year(start=start),
+        fq=pl.col("date").dt.fiscal_quarter(start=start),
+    )
+    assert result.collect_schema() == {"fy": pl.Int32, "fq": pl.Int8}
+
+
+def test_fiscal_year_quarter_default_start() -> None:
+    s = pl.Series([date(2022, 1, 1), date(2022, 12, 31), None])
+    assert_series_equal(s.dt.fiscal_year(), s.dt.year())
+    assert_series_equal(s.dt.fiscal_quarter(), s.dt.quarter())
+
+
+@pytest.mark.parametrize("start", ["foo", 0, 13])
+def test_fiscal_year_invalid_start(start: str | int) -> None:
+    with pytest.raises(ValueError, match="`start` must be"):
+        pl.col("date").dt.fiscal_year(start=start)
+
+
 def test_offset_by() -> None:
     df = pl.DataFrame(
         {
@@ -329,3 +329,105 @@ def test_to_datetime_inexact_unicode_multibyte() -> None:
     assert_frame_equal(
         out, pl.DataFrame({"a": [datetime(2020, 2, 3, 12, 53, 11), None]})
     )
+
+
+def test_to_datetime_multiple_formats() -> None:
+    s = pl.Series(["2024-01-31 10:15", "31/01/2024 08:00", None, "01/02/2024 07:30"])
+    result = s.str.to_datetime(["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M"])
+    expected = pl.Series(
+        [
+            datetime(2024, 1, 31, 10, 15),
+            datetime(2024, 1, 31, 8),
+            None,
+            datetime(2024, 2, 1, 7, 30),
+        ]
+    )
+    assert_series_equal(result, expected)
+
+    # formats are tried in order, so the first matching format wins
+    result = s.str.to_datetime(["%Y-%m-%d %H:%M", "%m/%d/%Y %H:%M", "%d/%m/%Y %H:%M"])
+    assert result[3] == datetime(2024, 1, 2, 7, 30)
+    assert result[1] == datetime(2024, 1, 31, 8)
+
+    # a format needing nanosecond precision determines the time unit
+    result = pl.Series(["2024-01-31 10:15:00.123456789", "2024-01-31"]).str.to_datetime(
+        ["%Y-%m-%d %H:%M:%S%.9f", "%Y-%m-%d"]
+    )
+    assert result.dtype == pl.Datetime("ns")
+    assert result.to_list() == [
+        datetime(2024, 1, 31, 10, 15, 0, 123456),
+        datetime(2024, 1, 31),
+    ]
+
+
+def test_to_datetime_multiple_formats_strict() -> None:
+    s = pl.Series(["2024-01-31 10:15", "not a date"])
+    formats = ["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M"]
+    with pytest.raises(InvalidOperationError, match="not a date"):
+        s.str.to_datetime(formats)
+
+    result = s.str.to_datetime(formats, strict=False)
+    assert result.to_list() == [datetime(2024, 1, 31, 10, 15), None]
+
+
+def test_to_datetime_best_effort() -> None:
+    s = pl.Series(["2024-01-31 10:15", "31/01/2024 08:00", "2024-01-31T09:30:00"])
+    result = s.str.to_datetime(["%Y-%m-%d %H:%M"], best_effort=True)
+    assert result.to_list() == [
+        datetime(2024, 1, 31, 10, 15),
+        datetime(2024, 1, 31, 8),
+        datetime(2024, 1, 31, 9, 30),
+    ]
+
+    # without any format, every value is inferred
+    result = s.str.to_datetime(best_effort=True)
+    assert result.to_list() == [
+        datetime(2024, 1, 31, 10, 15),
+        datetime(2024, 1, 31, 8),
+        datetime(2024, 1, 31, 9, 30),
+    ]
+
+
+def test_to_datetime_include_format() -> None:
+    df = pl.DataFrame(
+        {"a": ["2024-01-31 10:15", "31/01/2024 08:00", "2024-01-31T09:30:00", "x"]}
+    )
+    formats = ["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M"]
+    result = df.select(
+        pl.col("a").str.to_datetime(
+            formats, best_effort=True, include_format=True, strict=False
+        )
+    ).unnest("a")
+    expected = pl.DataFrame(
+        {
+            "value": [
+                datetime(2024, 1, 31, 10, 15),
+                datetime(2024, 1, 31, 8),
+                datetime(2024, 1, 31, 9, 30),
+                None,
+            ],
+            "format": ["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M", None, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    # a single format can also report the match
+    result = df.head(1).select(
+        pl.col("a").str.to_datetime(formats[0], include_format=True)
+    )
+    assert result.schema == pl.Schema(
+        {"a": pl.Struct({"value": pl.Datetime("us"), "format": pl.String})}
+    )
+
+
+def test_to_datetime_multiple_formats_time_zones() -> None:
+    s = pl.Series(["2024-01-31 10:15+01:00", "31/01/2024 08:00+00:00"])
+    result = s.str.to_datetime(["%Y-%m-%d %H:%M%#z", "%d/%m/%Y %H:%M%#z"])
+    assert result.dtype == pl.Datetime("us", "UTC")
+    assert result.to_list() == [
+        datetime(2024, 1, 31, 9, 15, tzinfo=ZoneInfo("UTC")),
+        datetime(2024, 1, 31, 8, tzinfo=ZoneInfo("UTC")),
+    ]
+
+    with pytest.raises(InvalidOperationError, match="cannot mix formats"):
+        s.str.to_datetime(["%Y-%m-%d %H:%M%#z", "%d/%m/%Y %H:%M"])
@@ -11,7 +11,7 @@ from hypothesis import given
 from hypothesis import strategies as st
 
 import polars as pl
-from polars.exceptions import InvalidOperationError
+from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
@@ -118,6 +118,45 @@ def test_base64_decode() -> None:
     assert df["data"].bin.decode("base64").to_list() == [b"asd", b"qwe"]
 
 
+def test_base64url_padding() -> None:
+    s = pl.Series("data", [b"\xfb\xff", b"\xfb\xef\xbe", b"", None])
+
+    assert s.bin.encode("base64").to_list() == ["+/8=", "++++", "", None]
+    assert s.bin.encode("base64url").to_list() == ["-_8=", "----", "", None]
+    assert s.bin.encode("base64url", padding=False).to_list() == [
+        "-_8",
+        "----",
+        "",
+        None,
+    ]
+
+    # Padding is optional when decoding.
+    encoded = pl.Series("data", [b"-_8", b"-_8=", b"----"])
+    assert encoded.bin.decode("base64url").to_list() == [
+        b"\xfb\xff",
+        b"\xfb\xff",
+        b"\xfb\xef\xbe",
+    ]
+
+
+def test_bin_decode_strict_across_chunks() -> None:
+    s = pl.concat(
+        [pl.Series("data", [b"YXNk", b"not base64!"]), pl.Series("data", [b"cXdl"])],
+        rechunk=False,
+    )
+    assert s.n_chunks() == 2
+
+    with pytest.raises(ComputeError, match=r"invalid `base64` encoding found in value"):
+        s.bin.decode("base64")
+
+    result = s.bin.decode("base64", strict=False)
+    assert result.to_list() == [b"asd", None, b"qwe"]
+    assert result.n_chunks() == 2
+
+    with pytest.raises(ValueError, match="`encoding` must be one of"):
+        s.bin.decode("base32")  # type: ignore[arg-type]
+
+
 def test_hex_encode() -> None:
     df = pl.DataFrame({"data": [b"asd", b"qwe"]})
 
@@ -132,7 +171,7 @@ def test_hex_decode() -> None:
 
 @pytest.mark.parametrize(
     "encoding",
-    ["hex", "base64"],
+    ["hex", "base64", "base64url"],
 )
 def test_compare_encode_between_lazy_and_eager_6814(encoding: TransferEncoding) -> None:
     df = pl.DataFrame({"x": [b"aa", b"bb", b"cc"]})
@@ -147,7 +186,7 @@ def test_compare_encode_between_lazy_and_eager_6814(encoding: TransferEncoding)
 
 @pytest.mark.parametrize(
     "encoding",
-    ["hex", "base64"],
+    ["hex", "base64", "base64url"],
 )
 def test_compare_decode_between_lazy_and_eager_6814(encoding: TransferEncoding) -> None:
     df = pl.DataFrame({"x": [b"d3d3", b"abcd", b"1234"]})
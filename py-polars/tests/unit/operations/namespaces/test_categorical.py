@@ -250,3 +250,68 @@ def test_cat_order_flag_csv_read_23823() -> None:
         schema_overrides={"colx": pl.Categorical},
     )
     assert_frame_equal(expected, lf.sort("colx", descending=False).collect())
+
+
+def test_cat_add_categories_enum() -> None:
+    s = pl.Series("a", ["x", "y", None, "x"], dtype=pl.Enum(["x", "y"]))
+    out = s.cat.add_categories(["z", "x", "w", "z"])
+
+    assert out.dtype == pl.Enum(["x", "y", "z", "w"])
+    assert out.to_list() == ["x", "y", None, "x"]
+    assert_series_equal(out.to_physical(), s.to_physical())
+
+    out = out.cast(pl.String).cast(out.dtype)
+    assert_series_equal(out.cat.add_categories("y"), out)
+
+    df = pl.DataFrame({"a": s}).lazy()
+    q = df.select(pl.col("a").cat.add_categories(["z"]))
+    assert q.collect_schema() == {"a": pl.Enum(["x", "y", "z"])}
+    assert q.collect().schema == q.collect_schema()
+
+
+def test_cat_add_categories_enum_widens_physical() -> None:
+    categories = [str(i) for i in range(255)]
+    s = pl.Series(["0", "254", None], dtype=pl.Enum(categories))
+    assert s.to_physical().dtype == pl.UInt8
+
+    out = s.cat.add_categories(["255"])
+    assert out.dtype == pl.Enum([*categories, "255"])
+    assert out.to_physical().dtype == pl.UInt16
+    assert out.to_list() == ["0", "254", None]
+    assert out.to_physical().to_list() == [0, 254, None]
+
+
+def test_cat_add_categories_categorical() -> None:
+    s = pl.Series(["x", "y"], dtype=pl.Categorical)
+    out = s.cat.add_categories(["add_categories_new"])
+    assert out.dtype == pl.Categorical
+    assert_series_equal(out, s)
+    assert "add_categories_new" in out.cat.get_categories().to_list()
+
+
+def test_cat_union_categories() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series(["x", "y"], dtype=pl.Enum(["x", "y"])),
+            "b": pl.Series(["z", "x"], dtype=pl.Enum(["z", "x"])),
+        }
+    )
+    out = df.select(pl.col("a").cat.union_categories("b"))
+    assert out.schema == {"a": pl.Enum(["x", "y", "z"])}
+    assert out["a"].to_list() == ["x", "y"]
+
+    out = df["b"].cat.union_categories(df["a"])
+    assert out.dtype == pl.Enum(["z", "x", "y"])
+    assert out.to_list() == ["z", "x"]
+
+    # The categories of a Categorical are only known at execution time.
+    lf = df.lazy().with_columns(c=pl.col("a").cast(pl.Categorical))
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        lf.select(pl.col("a").cat.union_categories("c")).collect_schema()
+    out = lf.select(pl.col("c").cat.union_categories("a")).collect()
+    assert out.schema == {"c": pl.Categorical()}
+
+
+def test_cat_add_categories_invalid_dtype() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        pl.Series(["x"]).cat.add_categories(["y"])
@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import ipaddress
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_str_parse_ip() -> None:
+    values = ["192.168.0.1", "2001:db8::ff00:42:8329", None, "::ffff:1.2.3.4"]
+    s = pl.Series("ip", values)
+
+    expected = pl.Series(
+        "ip",
+        [None if v is None else ipaddress.ip_address(v).packed for v in values],
+        dtype=pl.Binary,
+    )
+    assert_series_equal(s.str.parse_ip(), expected)
+
+
+def test_str_parse_ip_strict() -> None:
+    s = pl.Series("ip", ["10.0.0.1", "10.0.0.256", "not an ip", " 10.0.0.1"])
+
+    with pytest.raises(ComputeError, match="invalid IP address"):
+        s.str.parse_ip()
+
+    out = s.str.parse_ip(strict=False)
+    assert out.to_list() == [bytes([10, 0, 0, 1]), None, None, None]
+
+
+@pytest.mark.parametrize(
+    ("subnet", "expected"),
+    [
+        ("10.0.0.0/8", [True, False, False, False, None]),
+        ("10.1.2.3/8", [True, False, False, False, None]),
+        (["10.0.0.0/8", "192.168.0.0/16"], [True, True, False, False, None]),
+        ("192.168.1.7", [False, True, False, False, None]),
+        ("2001:db8::/32", [False, False, True, False, None]),
+        ("0.0.0.0/0", [True, True, False, True, None]),
+        ([], [False, False, False, False, None]),
+    ],
+)
+def test_ip_is_in_subnet(subnet: str | list[str], expected: list[bool | None]) -> None:
+    s = pl.Series("ip", ["10.1.2.3", "192.168.1.7", "2001:db8::1", "8.8.8.8", None])
+    out = s.str.parse_ip().ip.is_in_subnet(subnet)
+    assert_series_equal(out, pl.Series("ip", expected, dtype=pl.Boolean))
+
+
+@pytest.mark.parametrize("subnet", ["10.0.0.0/33", "10.0.0/8", "10.0.0.0/", "::/129"])
+def test_ip_is_in_subnet_invalid(subnet: str) -> None:
+    with pytest.raises(InvalidOperationError):
+        pl.col("ip").ip.is_in_subnet(subnet)
+
+
+def test_ip_to_int() -> None:
+    values = ["0.0.0.0", "10.0.0.1", "255.255.255.255", "::1", "ffff::", None]
+    df = pl.DataFrame({"ip": values})
+
+    out = df.select(pl.col("ip").str.parse_ip().ip.to_int())
+    expected = pl.Series(
+        "ip",
+        [None if v is None else int(ipaddress.ip_address(v)) for v in values],
+        dtype=pl.UInt128,
+    )
+    assert_series_equal(out.to_series(), expected)
+
+
+def test_ip_invalid_binary_length() -> None:
+    s = pl.Series("ip", [b"\x0a\x00\x00"])
+    with pytest.raises(InvalidOperationError, match="4 or 16 bytes"):
+        s.ip.to_int()
+    with pytest.raises(InvalidOperationError, match="4 or 16 bytes"):
+        s.ip.is_in_subnet("10.0.0.0/8")
+
+
+def test_ip_schema() -> None:
+    lf = pl.LazyFrame({"ip": ["10.0.0.1"]}).select(
+        parsed=pl.col("ip").str.parse_ip(),
+        private=pl.col("ip").str.parse_ip().ip.is_in_subnet("10.0.0.0/8"),
+        int=pl.col("ip").str.parse_ip().ip.to_int(),
+    )
+    assert lf.collect_schema() == pl.Schema(
+        {"parsed": pl.Binary, "private": pl.Boolean, "int": pl.UInt128}
+    )
+    assert lf.collect().row(0) == (bytes([10, 0, 0, 1]), True, 167772161)
@@ -6,7 +6,12 @@ from collections import OrderedDict
 import pytest
 
 import polars as pl
-from polars.exceptions import ColumnNotFoundError, InvalidOperationError
+from polars.exceptions import (
+    ColumnNotFoundError,
+    DuplicateError,
+    InvalidOperationError,
+    StructFieldNotFoundError,
+)
 from polars.testing import assert_frame_equal, assert_series_equal
 
 
@@ -180,3 +185,93 @@ def test_json_encode_categorical(dtype: pl.DataType) -> None:
         s.to_frame().select(c=pl.struct("a").struct.json_encode()).to_series(),
         pl.Series("c", ['{"a":"foo"}', '{"a":"bar"}'], pl.String),
     )
+
+
+def test_struct_field_nested_path() -> None:
+    df = pl.DataFrame(
+        {"s": [{"a": {"b": {"c": 1}}, "a.b": 10}, {"a": {"b": {"c": 2}}, "a.b": 20}]}
+    )
+    assert_frame_equal(
+        df.select(pl.col("s").struct.field("a.b.c")),
+        pl.DataFrame({"c": [1, 2]}),
+    )
+    # A field named like the path takes precedence.
+    assert_series_equal(
+        df.select(pl.col("s").struct.field("a.b")).to_series(),
+        pl.Series("a.b", [10, 20]),
+    )
+    assert_series_equal(df.to_series().struct.field("a.b.c"), pl.Series("c", [1, 2]))
+
+    with pytest.raises(StructFieldNotFoundError):
+        df.select(pl.col("s").struct.field("a.x"))
+
+
+def test_struct_with_fields_nested_path() -> None:
+    df = pl.DataFrame(
+        {
+            "s": [
+                {"id": 1, "meta": {"tags": ["a", "b"], "score": 1.0}},
+                {"id": 2, "meta": None},
+                None,
+            ]
+        }
+    )
+    result = df.select(
+        pl.col("s").struct.with_fields(
+            pl.field("meta.tags").list.len(),
+            (pl.field("meta.score") * 2).alias("meta.best"),
+        )
+    )
+    expected = pl.DataFrame(
+        {
+            "s": [
+                {"id": 1, "meta": {"tags": 2, "score": 1.0, "best": 2.0}},
+                {"id": 2, "meta": None},
+                None,
+            ]
+        },
+        schema={
+            "s": pl.Struct(
+                {
+                    "id": pl.Int64,
+                    "meta": pl.Struct(
+                        {
+                            "tags": pl.get_index_type(),
+                            "score": pl.Float64,
+                            "best": pl.Float64,
+                        }
+                    ),
+                }
+            )
+        },
+    )
+    assert_frame_equal(result, expected)
+
+    assert_series_equal(
+        df.select(pl.col("s").struct.with_fields(pl.field("meta.tags").list.len()))
+        .to_series()
+        .struct.field("meta.tags"),
+        pl.Series("tags", [2, None, None], pl.get_index_type()),
+    )
+
+    with pytest.raises(DuplicateError):
+        df.select(
+            pl.col("s").struct.with_fields(
+                pl.field("meta"), pl.field("meta.tags").list.len()
+            )
+        )
+
+
+def test_struct_unnest_expr_fields_prefix() -> None:
+    df = pl.DataFrame({"s": [{"a": 1, "b": "x"}, {"a": 2, "b": "y"}]})
+    assert_frame_equal(
+        df.select(pl.col("s").struct.unnest("b")), pl.DataFrame({"b": ["x", "y"]})
+    )
+    assert_frame_equal(
+        df.select(pl.col("s").struct.unnest(prefix="p_")),
+        pl.DataFrame({"p_a": [1, 2], "p_b": ["x", "y"]}),
+    )
+    assert_frame_equal(
+        df.select(pl.col("s").struct.unnest(["a"], separator=".")),
+        pl.DataFrame({"s.a": [1, 2]}),
+    )
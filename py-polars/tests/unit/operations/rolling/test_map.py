@@ -6,6 +6,7 @@ import numpy as np
 import pytest
 
 import polars as pl
+from polars.exceptions import ShapeError
 from polars.testing import assert_series_equal
 from tests.unit.conftest import FLOAT_DTYPES, INTEGER_DTYPES
 
@@ -194,6 +195,51 @@ def test_rolling_map_rolling_std() -> None:
     assert_series_equal(result, expected)
 
 
+@pytest.mark.parametrize("batch_size", [1, 2, 100])
+@pytest.mark.parametrize("center", [False, True])
+def test_rolling_map_batch_size(batch_size: int, center: bool) -> None:
+    s = pl.Series("A", [1.0, None, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0])
+
+    result = s.rolling_map(
+        lambda windows: windows.arr.sum(),
+        window_size=3,
+        weights=[1.0, 2.0, 3.0],
+        min_samples=2,
+        center=center,
+        batch_size=batch_size,
+    )
+
+    expected = s.rolling_map(
+        lambda s: s.sum(),
+        window_size=3,
+        weights=[1.0, 2.0, 3.0],
+        min_samples=2,
+        center=center,
+    )
+    assert_series_equal(result, expected)
+
+
+def test_rolling_map_batch_size_calls() -> None:
+    s = pl.Series("A", range(10))
+    batches = []
+
+    def f(windows: pl.Series) -> np.ndarray[tuple[int], np.dtype[np.int64]]:
+        batches.append(windows.dtype)
+        return windows.to_numpy().sum(axis=1)
+
+    result = s.rolling_map(f, window_size=3, batch_size=4)
+
+    expected = s.rolling_sum(window_size=3)
+    assert_series_equal(result, expected)
+    assert batches == [pl.Array(pl.Int64, 3)] * 2
+
+
+def test_rolling_map_batch_size_wrong_length() -> None:
+    s = pl.Series("A", range(10))
+    with pytest.raises(ShapeError):
+        s.rolling_map(lambda windows: windows[:1], window_size=3, batch_size=4)
+
+
 def test_map_rows_object_dtype_25730() -> None:
     df = pl.DataFrame({"id": [1, 2], "symbol": ["A", "B"]})
     out = df.select(
@@ -1072,3 +1072,51 @@ def test_strict_cast_nested() -> None:
         df.cast(struct, strict=False),
         pl.DataFrame({"a": [{"x": 42}, {"x": None}]}, schema={"a": struct}),
     )
+
+
+def test_cast_list_to_array_width_mismatch() -> None:
+    s = pl.Series([[1, 2], [3], None, [4, 5, 6]], dtype=pl.List(pl.Int64))
+
+    with pytest.raises(ComputeError, match="not all elements have the specified width"):
+        s.cast(pl.Array(pl.Int32, 2))
+
+    out = s.cast(pl.Array(pl.Int32, 2), strict=False)
+    assert out.dtype == pl.Array(pl.Int32, 2)
+    assert out.to_list() == [[1, 2], None, None, None]
+
+
+def test_cast_list_array_logical_inner() -> None:
+    s = pl.Series([["a", "b"], ["b", "a"]], dtype=pl.List(pl.String))
+    dtype = pl.Array(pl.Enum(["a", "b"]), 2)
+
+    out = s.cast(dtype)
+    assert out.dtype == dtype
+    assert out.to_list() == [["a", "b"], ["b", "a"]]
+
+    out = out.cast(pl.List(pl.String)).cast(pl.List(pl.Categorical))
+    assert out.dtype == pl.List(pl.Categorical)
+    assert out.to_list() == [["a", "b"], ["b", "a"]]
+
+    out = pl.Series([[1, 2]], dtype=pl.Array(pl.Int32, 2)).cast(pl.List(pl.Date))
+    assert out.to_list() == [[date(1970, 1, 2), date(1970, 1, 3)]]
+
+
+def test_cast_struct_reorder_subset() -> None:
+    df = pl.DataFrame({"s": [{"a": 1, "b": "2", "c": 3.0}]})
+    dtype = pl.Struct({"b": pl.Int8, "a": pl.String, "d": pl.Boolean})
+
+    out = df.lazy().select(pl.col("s").cast(dtype)).collect()
+    assert out.schema == pl.Schema({"s": dtype})
+    assert out.to_dict(as_series=False) == {"s": [{"b": 2, "a": "1", "d": None}]}
+
+
+def test_cast_list_of_struct_reshape() -> None:
+    s = pl.Series([[{"x": 1, "y": "a"}], [{"x": 2, "y": "b"}, None]])
+    dtype = pl.List(pl.Struct({"y": pl.Categorical, "x": pl.Float64}))
+
+    out = pl.select(pl.lit(s).cast(dtype)).to_series()
+    assert out.dtype == dtype
+    assert out.to_list() == [[{"y": "a", "x": 1.0}], [{"y": "b", "x": 2.0}, None]]
+
+    out = s.cast(pl.Array(pl.Struct({"x": pl.Int8}), 1), strict=False)
+    assert out.to_list() == [[{"x": 1}], None]
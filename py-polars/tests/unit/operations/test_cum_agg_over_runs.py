@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_cum_sum_reset() -> None:
+    df = pl.DataFrame(
+        {
+            "x": [1, 2, None, 4, 5, 6],
+            "reset": [True, False, True, None, True, False],
+        }
+    )
+    result = df.select(
+        pl.col("x").cum_sum_reset("reset").alias("forward"),
+        pl.col("x").cum_sum_reset("reset", reverse=True).alias("reverse"),
+    )
+    expected = pl.DataFrame(
+        {
+            "forward": [1, 3, None, 4, 5, 11],
+            "reverse": [3, 2, None, 4, 11, 6],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize(
+    ("function", "expected"),
+    [
+        ("sum", [3, 4, 4, 5, 5, 14]),
+        ("prod", [3, 3, 4, 4, 5, 45]),
+        ("min", [3, 1, 4, 1, 5, 5]),
+        ("max", [3, 3, 4, 4, 5, 9]),
+        ("count", [1, 2, 1, 2, 1, 2]),
+    ],
+)
+def test_cum_agg_over_runs_by(function: str, expected: list[int]) -> None:
+    df = pl.DataFrame({"key": ["a", "a", "b", "b", "a", "a"], "x": [3, 1, 4, 1, 5, 9]})
+    result = df.select(
+        pl.col("x").cum_agg_over_runs(function, by="key")  # type: ignore[arg-type]
+    ).to_series()
+    assert result.to_list() == expected
+
+
+def test_cum_agg_over_runs_streaming() -> None:
+    n = 10_000
+    lf = pl.LazyFrame({"x": range(n)}).with_columns(reset=pl.col("x") % 7 == 0)
+    q = lf.select(
+        pl.col("x").cum_sum_reset("reset").alias("sum"),
+        pl.col("x").cum_agg_over_runs("count", when="reset").alias("count"),
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+    assert_series_equal(
+        q.collect()["count"],
+        pl.Series("count", [i % 7 + 1 for i in range(n)], dtype=pl.get_index_type()),
+    )
+
+
+def test_cum_agg_over_runs_invalid() -> None:
+    with pytest.raises(ValueError, match="exactly one of `when` and `by`"):
+        pl.col("x").cum_agg_over_runs("sum")
+    with pytest.raises(ValueError, match="exactly one of `when` and `by`"):
+        pl.col("x").cum_agg_over_runs("sum", when="a", by="b")
@@ -3,6 +3,7 @@ from __future__ import annotations
 import pytest
 
 import polars as pl
+from polars.exceptions import InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 inf = float("inf")
@@ -72,6 +73,48 @@ def test_cut_include_breaks_lazy_schema() -> None:
     assert_frame_equal(result, expected, categorical_as_str=True)
 
 
+def test_cut_include_bins() -> None:
+    s = pl.Series("a", [-2, -1, 0, None, 1, 2])
+
+    out = s.cut([-1.5, 0.25, 1.0], labels=["a", "b", "c", "d"], include_bins=True)
+
+    expected = pl.DataFrame(
+        {
+            "category": ["a", "b", "b", None, "c", "d"],
+            "lower": [-inf, -1.5, -1.5, None, 0.25, 1.0],
+            "upper": [-1.5, 0.25, 0.25, None, 1.0, inf],
+            "bin_index": pl.Series([0, 1, 1, None, 2, 3], dtype=pl.get_index_type()),
+        },
+        schema_overrides={"category": pl.Categorical},
+    ).to_struct("a")
+    assert_series_equal(out, expected, categorical_as_str=True)
+
+
+def test_cut_include_bins_lazy_schema() -> None:
+    lf = pl.LazyFrame({"a": [-2, -1, 0, 1, 2]})
+
+    result = lf.select(
+        pl.col("a").cut([-1, 1], left_closed=True, include_bins=True).alias("cut")
+    ).unnest("cut")
+
+    expected = pl.LazyFrame(
+        {
+            "category": ["[-inf, -1)", "[-1, 1)", "[-1, 1)", "[1, inf)", "[1, inf)"],
+            "lower": [-inf, -1.0, -1.0, 1.0, 1.0],
+            "upper": [-1.0, 1.0, 1.0, inf, inf],
+            "bin_index": pl.Series([0, 1, 1, 2, 2], dtype=pl.get_index_type()),
+        },
+        schema_overrides={"category": pl.Categorical},
+    )
+    assert_frame_equal(result, expected, categorical_as_str=True)
+
+
+def test_cut_include_bins_and_breaks() -> None:
+    s = pl.Series("a", [-2, -1, 0, 1, 2])
+    with pytest.raises(InvalidOperationError, match="cannot both be set"):
+        s.cut([0], include_breaks=True, include_bins=True)
+
+
 def test_cut_null_values() -> None:
     s = pl.Series([-1.0, None, 1.0, 2.0, None, 8.0, 4.0])
 
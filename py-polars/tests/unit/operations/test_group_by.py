@@ -2973,3 +2973,52 @@ def test_group_by_agg_get_oob_error_26747() -> None:
 
     with pytest.raises(ComputeError, match="get index is out of bounds"):
         df.group_by("x").agg(y=pl.col.x.get(100))
+
+
+def test_group_by_nulls_equal() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, 1, None, 2],
+            "b": ["x", "y", "x", None, "z"],
+            "c": [1, 2, 3, 4, 5],
+        }
+    )
+
+    result = df.group_by("a", maintain_order=True).agg(pl.col("c").sum())
+    expected = pl.DataFrame({"a": [1, None, 2], "c": [4, 6, 5]})
+    assert_frame_equal(result, expected)
+
+    result = df.group_by("a", maintain_order=True, nulls_equal=False).agg(
+        pl.col("c").sum()
+    )
+    expected = pl.DataFrame({"a": [1, None, None, 2], "c": [4, 2, 4, 5]})
+    assert_frame_equal(result, expected)
+
+    # A null in any of the keys puts the row in its own group.
+    result = (
+        df.lazy()
+        .group_by("a", "b", maintain_order=True, nulls_equal=False)
+        .agg(pl.len())
+        .collect()
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, None, None, 2],
+            "b": ["x", "y", None, "z"],
+            "len": pl.Series([2, 1, 1, 1], dtype=get_index_type()),
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_map_groups_unsupported_options() -> None:
+    lf = pl.LazyFrame({"a": [1, None, 1], "b": [1, 2, 3]})
+
+    with pytest.raises(InvalidOperationError, match="`nulls_equal\\(false\\)`"):
+        lf.group_by("a", nulls_equal=False).map_groups(lambda df: df, schema=None)
+
+    with pytest.raises(InvalidOperationError, match="`having` predicates"):
+        lf.group_by("a").having(pl.len() > 1).map_groups(lambda df: df, schema=None)
+
+    with pytest.raises(TypeError, match="`nulls_equal=False`"):
+        lf.collect().group_by("a", nulls_equal=False).map_groups(lambda df: df)
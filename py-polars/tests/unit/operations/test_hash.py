@@ -1,5 +1,14 @@
+import hashlib
+from typing import TYPE_CHECKING, Any
+
+import pytest
+
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Callable
 
 
 def test_hash_struct() -> None:
@@ -39,3 +48,96 @@ def test_hash_cat_stable() -> None:
         df1_struct.select(pl.col.struct.hash()),
         df2_struct.select(pl.col.struct.hash()),
     )
+
+
+@pytest.mark.parametrize(
+    ("method", "hashlib_fn"),
+    [("hash_sha256", hashlib.sha256), ("hash_md5", hashlib.md5)],
+)
+def test_hash_digest_matches_hashlib(method: str, hashlib_fn: Callable[[bytes], Any]) -> None:
+    values = ["apple", "", None, "ünïcödé"]
+    s = pl.Series("a", values)
+
+    digests = [None if v is None else hashlib_fn(v.encode()) for v in values]
+    expected_hex = [None if d is None else d.hexdigest() for d in digests]
+    assert getattr(s, method)().to_list() == expected_hex
+
+    expected_bin = [None if d is None else d.digest() for d in digests]
+    out = getattr(s, method)(output="binary")
+    assert out.dtype == pl.Binary
+    assert out.to_list() == expected_bin
+
+    # Binary input hashes the raw bytes.
+    assert_series_equal(
+        getattr(s.cast(pl.Binary), method)(), pl.Series("a", expected_hex)
+    )
+
+
+def test_hash_xxh3() -> None:
+    df = pl.DataFrame({"a": ["apple", "banana", "apple", None]})
+    out = df.select(
+        h=pl.col("a").hash_xxh3(),
+        h_seeded=pl.col("a").hash_xxh3(seed=42),
+        h_bin=pl.col("a").hash_xxh3(output="binary"),
+        h_from_bin=pl.col("a").cast(pl.Binary).hash_xxh3(),
+    )
+    assert out.schema == pl.Schema(
+        {
+            "h": pl.String,
+            "h_seeded": pl.String,
+            "h_bin": pl.Binary,
+            "h_from_bin": pl.String,
+        }
+    )
+
+    h = out["h"].to_list()
+    assert h[0] == h[2]
+    assert h[0] != h[1]
+    assert h[3] is None
+    assert all(len(v) == 16 for v in h[:3])
+    assert out["h_seeded"][0] != h[0]
+    assert out["h_bin"].bin.encode("hex").to_list() == h
+    assert out["h_from_bin"].to_list() == h
+
+
+def test_hash_digest_invalid() -> None:
+    with pytest.raises(InvalidOperationError, match="string and binary"):
+        pl.Series([1, 2]).hash_sha256()
+    with pytest.raises(ValueError, match="`output` must be one of"):
+        pl.col("a").hash_md5(output="base64")  # type: ignore[arg-type]
+
+
+def test_hash_stable() -> None:
+    assert pl.stable_hash_version() == 1
+
+    # A value is hashed as a validity byte followed by its payload with XXH3.
+    s = pl.Series("a", ["x", None])
+    encoded = pl.Series("a", [b"\x01x", b"\x00"])
+    expected = [
+        int.from_bytes(h, "big")
+        for h in encoded.hash_xxh3(seed=7, output="binary").to_list()
+    ]
+    assert s.hash(7, stable=True).to_list() == expected
+    assert pl.select(pl.lit("x").hash(7, stable=True)).item() == expected[0]
+
+    assert pl.Series([0.0, -0.0]).hash(stable=True).n_unique() == 1
+
+    # A row is hashed as the little-endian hashes of its values.
+    df = pl.DataFrame({"a": [1, None], "b": [["x"], []]})
+    columns = df.select(pl.all().hash(3, stable=True))
+    rows = pl.Series(
+        [
+            b"".join(h.to_bytes(8, "little") for h in row)
+            for row in columns.iter_rows()
+        ]
+    )
+    expected = [
+        int.from_bytes(h, "big")
+        for h in rows.hash_xxh3(seed=3, output="binary").to_list()
+    ]
+    assert df.hash_rows(3, stable=True).to_list() == expected
+
+    with pytest.raises(ValueError, match="cannot be set"):
+        pl.col("a").hash(1, 2, stable=True)
+    with pytest.raises(ValueError, match="cannot be set"):
+        df.hash_rows(1, seed_3=2, stable=True)
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date
+from datetime import date, datetime, timedelta
 from typing import TYPE_CHECKING
 
 import hypothesis.strategies as st
@@ -14,7 +14,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import column, dataframes
 
 if TYPE_CHECKING:
-    from polars._typing import PolarsDataType
+    from polars._typing import InterpolateByMethod, PolarsDataType
 
 
 @pytest.mark.parametrize(
@@ -250,3 +250,121 @@ def test_interpolate_by_invalid() -> None:
         match="null values in `by` column are not yet supported in 'interpolate_by'",
     ):
         s.interpolate_by(by)
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        ("linear", [1.0, 1.5, 2.5, 3.0, None]),
+        ("nearest", [1, 1, 3, 3, None]),
+        ("forward", [1, 1, 1, 3, 3]),
+        ("backward", [1, 3, 3, 3, None]),
+        ("cubic_spline", [1.0, 1.5, 2.5, 3.0, None]),
+    ],
+)
+def test_interpolate_by_method(
+    method: InterpolateByMethod, expected: list[float | None]
+) -> None:
+    df = pl.DataFrame({"a": [1, None, None, 3, None], "b": [0, 1, 3, 4, 6]})
+    expected_s = pl.Series("a", expected)
+
+    result = df.select(pl.col("a").interpolate_by("b", method=method))
+    assert_series_equal(result["a"], expected_s)
+    assert df.lazy().select(
+        pl.col("a").interpolate_by("b", method=method)
+    ).collect_schema() == {"a": expected_s.dtype}
+
+    result = (
+        df.reverse()
+        .with_columns(pl.col("a").interpolate_by("b", method=method))
+        .reverse()
+    )
+    assert_series_equal(result["a"], expected_s)
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        ("linear", [1.0, None, None, 4.0, 5.0, 6.0]),
+        ("nearest", [1, None, None, 4, 6, 6]),
+        ("forward", [1, 1, None, 4, 4, 6]),
+        ("backward", [1, None, 4, 4, 6, 6]),
+    ],
+)
+def test_interpolate_by_max_gap(
+    method: InterpolateByMethod, expected: list[float | None]
+) -> None:
+    s = pl.Series("a", [1, None, None, 4, None, 6])
+    by = pl.Series("b", [0, 1, 3, 4, 5, 6])
+    result = s.interpolate_by(by, method=method, max_gap=2)
+    assert_series_equal(result, pl.Series("a", expected))
+
+
+@pytest.mark.parametrize("dtype", [pl.Date, pl.Datetime("ms"), pl.Datetime("ns")])
+def test_interpolate_by_max_gap_temporal(dtype: PolarsDataType) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1.0, None, 3.0, None, None, 9.0],
+            "b": [
+                datetime(2020, 1, 1),
+                datetime(2020, 1, 2),
+                datetime(2020, 1, 3),
+                datetime(2020, 1, 4),
+                datetime(2020, 1, 5),
+                datetime(2020, 1, 6),
+            ],
+        }
+    ).with_columns(pl.col("b").cast(dtype))
+
+    result = df.select(
+        pl.col("a").interpolate_by("b", max_gap=timedelta(days=2)).alias("linear"),
+        pl.col("a")
+        .interpolate_by("b", method="forward", max_gap=timedelta(days=1))
+        .alias("forward"),
+    )
+    expected = pl.DataFrame(
+        {
+            "linear": [1.0, 2.0, 3.0, None, None, 9.0],
+            "forward": [1.0, 1.0, 3.0, 3.0, None, 9.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_interpolate_by_cubic_spline() -> None:
+    s = pl.Series("a", [0.0, None, 1.0, None, 0.0])
+    by = pl.Series("b", [0, 1, 2, 2.5, 3])
+    result = s.interpolate_by(by, method="cubic_spline")
+    # The natural cubic spline through (0, 0), (2, 1) and (3, 0).
+    assert_series_equal(
+        result, pl.Series("a", [0.0, 0.875, 1.0, 0.59375, 0.0]), abs_tol=1e-12
+    )
+
+    # Straight lines are reproduced exactly.
+    s = pl.Series("a", [1.0, None, 5.0, None, None, 11.0], dtype=pl.Float32)
+    result = s.interpolate_by(pl.Series([0, 1, 2, 3, 4, 5]), method="cubic_spline")
+    expected = pl.Series("a", [1.0, 3.0, 5.0, 7.0, 9.0, 11.0], dtype=pl.Float32)
+    assert_series_equal(result, expected)
+
+
+def test_interpolate_by_preserves_dtype() -> None:
+    s = pl.Series(["x", None, "y", None])
+    by = pl.Series([1, 2, 4, 5])
+    assert s.interpolate_by(by, method="forward").to_list() == ["x", "x", "y", "y"]
+    assert s.interpolate_by(by, method="nearest").to_list() == ["x", "x", "y", None]
+
+
+def test_interpolate_by_method_invalid() -> None:
+    s = pl.Series([1, None, 3])
+    by = pl.Series([1, 2, 3])
+    with pytest.raises(ValueError, match="interpolation `method` must be one of"):
+        s.interpolate_by(by, method="quadratic")  # type: ignore[arg-type]
+    with pytest.raises(InvalidOperationError, match="must be non-negative"):
+        s.interpolate_by(by, max_gap=-1)
+    with pytest.raises(InvalidOperationError, match="requires `by` to be Date"):
+        s.interpolate_by(by, max_gap=timedelta(days=1))
+
+    s = pl.Series([1.0, None, 3.0, 4.0])
+    by = pl.Series([1, 2, 3, 3])
+    with pytest.raises(InvalidOperationError, match="to be unique"):
+        s.interpolate_by(by, method="cubic_spline")
@@ -360,6 +360,26 @@ def test_is_in_list_rhs() -> None:
     )
 
 
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_is_in_list_rhs_long_lists(nulls_equal: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "x": [1, 100, 50, None, 7],
+            "allowed": [
+                list(range(100)),
+                list(range(100)),
+                [*range(40), None],
+                [*range(40), None],
+                [7],
+            ],
+        }
+    )
+    result = df.select(pl.col("x").is_in(pl.col("allowed"), nulls_equal=nulls_equal))
+    missing_value = True if nulls_equal else None
+    expected = pl.DataFrame({"x": [True, False, False, missing_value, True]})
+    assert_frame_equal(result, expected)
+
+
 @pytest.mark.parametrize("dtype", [pl.Float32, pl.Float64])
 def test_is_in_float(dtype: PolarsDataType) -> None:
     s = pl.Series([float("nan"), 0.0], dtype=dtype)
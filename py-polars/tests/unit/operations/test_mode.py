@@ -1,7 +1,14 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
 import pytest
 
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from polars._typing import ModeTies
 
 
 @pytest.mark.parametrize("maintain_order", [False, True])
@@ -35,3 +42,46 @@ def test_mode(maintain_order: bool) -> None:
         pl.Series([None], dtype=pl.Boolean),
         check_order=maintain_order,
     )
+
+
+@pytest.mark.parametrize(
+    ("ties", "expected"),
+    [("all", [3, None, 1]), ("first", [3]), ("smallest", [1])],
+)
+def test_mode_ties(ties: ModeTies, expected: list[int | None]) -> None:
+    s = pl.Series("a", [3, None, 1, 3, None, 1, 2])
+    assert_series_equal(
+        s.mode(maintain_order=True, ties=ties),
+        pl.Series("a", expected),
+    )
+
+
+def test_mode_ties_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 1, 2, 2], "x": [5, 4, 4, 5, 7, 8]})
+    result = df.group_by("g", maintain_order=True).agg(
+        first=pl.col("x").mode(ties="first"),
+        smallest=pl.col("x").mode(ties="smallest"),
+    )
+    expected = pl.DataFrame({"g": [1, 2], "first": [5, 7], "smallest": [4, 7]})
+    assert_frame_equal(result, expected)
+
+    assert_series_equal(
+        pl.Series("a", [], dtype=pl.Int64).mode(ties="smallest"),
+        pl.Series("a", [None], dtype=pl.Int64),
+    )
+
+
+def test_modes() -> None:
+    s = pl.Series("a", [2, 5, 1, 5, 1, 1, 2, 7])
+    assert_series_equal(s.modes(3), pl.Series("a", [1, 2, 5]))
+    assert_series_equal(s.modes(10), pl.Series("a", [1, 2, 5, 7]))
+    assert_series_equal(s.modes(0), pl.Series("a", [], dtype=pl.Int64))
+
+    df = pl.DataFrame({"g": ["x", "x", "x", "y"], "v": ["b", "a", "a", None]})
+    result = df.group_by("g", maintain_order=True).agg(pl.col("v").modes(2))
+    expected = pl.DataFrame({"g": ["x", "y"], "v": [["a", "b"], [None]]})
+    assert_frame_equal(result, expected)
+
+    lf = df.lazy().select(pl.col("v").modes(2))
+    assert lf.collect_schema() == {"v": pl.List(pl.String)}
+    assert lf.collect().to_dict(as_series=False) == {"v": [["a", "b"]]}
@@ -82,6 +82,48 @@ def test_qcut_include_breaks_lazy_schema() -> None:
     assert_frame_equal(result, expected, categorical_as_str=True)
 
 
+def test_qcut_include_bins() -> None:
+    s = pl.int_range(-2, 3, eager=True).alias("a")
+
+    out = s.qcut([0.25, 0.75], labels=["a", "b", "c"], include_bins=True)
+
+    expected = pl.DataFrame(
+        {
+            "category": ["a", "a", "b", "b", "c"],
+            "lower": [-inf, -inf, -1.0, -1.0, 1.0],
+            "upper": [-1.0, -1.0, 1.0, 1.0, inf],
+            "bin_index": pl.Series([0, 0, 1, 1, 2], dtype=pl.get_index_type()),
+        },
+        schema_overrides={"category": pl.Categorical},
+    ).to_struct("a")
+    assert_series_equal(out, expected, categorical_as_str=True)
+
+    out = s.qcut(2, include_bins=True).struct.field("bin_index")
+    assert out.to_list() == [0, 0, 0, 1, 1]
+
+
+def test_qcut_include_bins_lazy_schema() -> None:
+    lf = pl.LazyFrame({"a": [-2, -1, 0, 1, 2]})
+    result = lf.select(pl.col("a").qcut(2, include_bins=True))
+    assert result.collect_schema() == result.collect().schema
+
+
+def test_qcut_full_null_include_bins() -> None:
+    s = pl.Series("a", [None, None], dtype=pl.Int64)
+
+    result = s.qcut([0.25, 0.50], labels=["1", "2", "3"], include_bins=True)
+
+    assert result.dtype == pl.Struct(
+        {
+            "category": pl.Categorical,
+            "lower": pl.Float64,
+            "upper": pl.Float64,
+            "bin_index": pl.get_index_type(),
+        }
+    )
+    assert result.null_count() == 2
+
+
 def test_qcut_null_values() -> None:
     s = pl.Series([-1.0, None, 1.0, 2.0, None, 8.0, 4.0])
 
@@ -116,3 +116,46 @@ def test_rank_series() -> None:
 
     assert s.rank(method="average").dtype == pl.Float64
     assert s.rank(method="max").dtype == pl.get_index_type()
+
+
+def test_rank_percent() -> None:
+    s = pl.Series("a", [3, 6, 1, 1, None, 6])
+    assert_series_equal(
+        s.rank("percent"),
+        pl.Series("a", [0.5, 0.75, 0.0, 0.0, None, 0.75]),
+    )
+    assert_series_equal(pl.Series("a", [7]).rank("percent"), pl.Series("a", [0.0]))
+
+
+@pytest.mark.parametrize(
+    "method", ["average", "min", "max", "dense", "ordinal", "percent"]
+)
+@pytest.mark.parametrize("descending", [False, True])
+def test_rank_over_partitions(method: str, descending: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a", "b", "a", None, None],
+            "h": [1, 1, 1, 2, 2, 2, 1, 1, 1],
+            "x": [1, 2, 3, 2, None, 3, 0, 5, 5],
+        }
+    )
+    for partition_by in (["g"], ["g", "h"]):
+        q = df.lazy().select(
+            pl.col("x")
+            .rank(method, descending=descending)  # type: ignore[arg-type]
+            .over(partition_by)
+        )
+        expected = (
+            df.with_row_index()
+            .group_by(partition_by, maintain_order=True)
+            .agg(
+                pl.col("index"),
+                pl.col("x").rank(method, descending=descending),  # type: ignore[arg-type]
+            )
+            .explode("index", "x")
+            .sort("index")
+            .select("x")
+        )
+        for engine in ("in-memory", "streaming"):
+            result = q.collect(engine=engine)  # type: ignore[arg-type]
+            assert_frame_equal(result, expected, check_dtypes=False)
@@ -399,3 +399,38 @@ def test_order_enum() -> None:
     assert_order_series(["a", "b", "c"], ["c", "b", "a"], dtype)
     assert_order_series([None], [None], dtype)
     assert_order_series([None], ["a"], dtype)
+
+
+def test_row_encode_public_api() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([1, None, -1], dtype=pl.Int32),
+            "b": ["x", "y", None],
+            "c": pl.Series([1, 2, 3], dtype=pl.Date),
+        }
+    )
+    for descending, nulls_last in [(False, False), ([True, False, True], True)]:
+        keys = df.row_encode(descending=descending, nulls_last=nulls_last)
+        assert keys.dtype == pl.Binary
+        assert_frame_equal(
+            keys.row_decode(df.schema, descending=descending, nulls_last=nulls_last),
+            df,
+        )
+        assert_frame_equal(
+            df[keys.arg_sort()],
+            df.sort(df.columns, descending=descending, nulls_last=nulls_last),
+        )
+
+    # The encoding is stable, these bytes must not change without bumping the version.
+    assert pl.row_encoding_version() == 1
+    keys = df.select("a").row_encode()
+    assert keys.to_list() == [
+        b"\x01\x80\x00\x00\x01",
+        b"\x00" * 5,
+        b"\x01\x7f\xff\xff\xff",
+    ]
+    keys = df.select("b").row_encode(descending=True, nulls_last=True)
+    assert keys.to_list() == [b"\x85\xfe", b"\x84\xfe", b"\xff"]
+
+    with pytest.raises(ValueError, match="length of `descending`"):
+        df.row_encode(descending=[True])
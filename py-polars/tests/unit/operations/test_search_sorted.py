@@ -1,7 +1,13 @@
+from __future__ import annotations
+
+import bisect
+from typing import Literal
+
 import numpy as np
 import pytest
 
 import polars as pl
+from polars.exceptions import ColumnNotFoundError, InvalidOperationError
 from polars.testing import assert_series_equal
 
 
@@ -99,3 +105,59 @@ def test_raise_literal_numeric_search_sorted_18096() -> None:
 def test_search_sorted_typing_26937() -> None:
     targets: list[float] = [0.1, 0.3, 0.8]
     indices = pl.Series().search_sorted(targets)
+
+
+@pytest.mark.parametrize("side", ["left", "right"])
+@pytest.mark.parametrize("descending", [False, True])
+def test_search_sorted_by(side: Literal["left", "right"], descending: bool) -> None:
+    rng = np.random.default_rng(0)
+    df = pl.DataFrame(
+        {
+            "symbol": rng.choice(["a", "b", "c"], 100),
+            "ts": rng.integers(0, 20, 100),
+        }
+    ).sort("symbol", "ts", descending=[False, descending])
+    keys = pl.DataFrame(
+        {"symbol": rng.choice(["0", "a", "b", "c", "d"], 50), "ts": range(-5, 45)}
+    )
+
+    def key(symbol: str, ts: int) -> tuple[str, int]:
+        return (symbol, -ts if descending else ts)
+
+    rows = [key(*row) for row in df.iter_rows()]
+    bisect_fn = bisect.bisect_left if side == "left" else bisect.bisect_right
+    expected = [bisect_fn(rows, key(*row)) for row in keys.iter_rows()]
+
+    result = df.search_sorted(keys, side=side, descending=[False, descending])
+    assert result.to_list() == expected
+
+    result = df.select(
+        pl.search_sorted_by(
+            ["symbol", "ts"],
+            [pl.lit(keys["symbol"]), pl.lit(keys["ts"])],
+            side=side,
+            descending=[False, descending],
+        )
+    ).to_series()
+    assert result.to_list() == expected
+
+
+def test_search_sorted_by_nulls_last() -> None:
+    df = pl.DataFrame({"a": [1, 1, 2, 2, None], "b": [1.0, None, 0.5, 2.0, 0.0]})
+    df = df.sort("a", "b", nulls_last=True)
+    keys = pl.DataFrame({"a": [1, 2, None, 3], "b": [None, 1.0, 0.0, 0.0]})
+    result = df.search_sorted(keys, side="left", nulls_last=True)
+    assert_series_equal(result, pl.Series("a", [1, 3, 4, 4], dtype=pl.get_index_type()))
+
+    result = df.search_sorted(keys.select("a"), by="a", side="right", nulls_last=True)
+    assert result.to_list() == [2, 4, 5, 4]
+
+
+def test_search_sorted_by_invalid() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": [3, 4]})
+    with pytest.raises(InvalidOperationError, match="same number of sorted and search columns"):
+        df.select(pl.search_sorted_by(["a", "b"], ["a"]))
+    with pytest.raises(ValueError, match="descending"):
+        df.search_sorted(df, descending=[True, False, True])
+    with pytest.raises(ColumnNotFoundError):
+        df.search_sorted(df.select("a"), by=["a", "b"])
@@ -1289,3 +1289,21 @@ def test_sort_by_empty_list_eval_25433() -> None:
     out = df.select(pl.col.a.list.eval(pl.element().sort_by(pl.element())))
     expected = pl.DataFrame({"a": [sorted(some_list), []]})
     assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize(("offset", "length"), [(0, 3), (2, 3), (5, 10), (-3, 2)])
+def test_sort_slice_top_k_after_group_by(
+    engine: Any, descending: bool, offset: int, length: int
+) -> None:
+    df = pl.DataFrame({"g": [i % 7 for i in range(50)], "v": range(50)})
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(pl.col("v").sum())
+        .sort("v", descending=descending)
+    )
+
+    expected = q.collect().slice(offset, length)
+    assert_frame_equal(q.slice(offset, length).collect(engine=engine), expected)
@@ -579,6 +579,37 @@ def test_bottom_k_by() -> None:
     )
 
 
+@pytest.mark.parametrize("k", [pl.col("k"), pl.col("k").first()])
+def test_top_k_by_multiple_per_group_k(k: pl.Expr) -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "b", "b"],
+            "score": [1, 3, 3, 5, 2, 5, 1],
+            "tie": [9, 1, 2, 3, 4, 1, 0],
+            "k": [2, 2, 2, 3, 3, 3, 3],
+        }
+    )
+    result = df.group_by("g", maintain_order=True).agg(
+        top=pl.col("tie").top_k_by(["score", "tie"], k=k, reverse=[False, True]).sort(),
+        bottom=pl.col("tie").bottom_k_by(["score", "tie"], k=k).sort(),
+    )
+    expected = pl.DataFrame(
+        {"g": ["a", "b"], "top": [[1, 2], [1, 3, 4]], "bottom": [[1, 9], [0, 1, 4]]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_top_k_by_non_constant_k() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "k": [1, 1, 2]})
+    with pytest.raises(ComputeError, match="`k` must be constant"):
+        df.select(pl.col("a").top_k_by("a", k=pl.col("k")))
+    with pytest.raises(ComputeError, match="`k` must be constant"):
+        df.select(pl.col("a").top_k(k=pl.col("k")))
+
+    result = df.head(2).select(pl.col("a").top_k_by("a", k=pl.col("k")))
+    assert result.to_series().to_list() == [2]
+
+
 def test_sort_head_maintain_order() -> None:
     df = pl.DataFrame(
         {"x": [2, 0, 8, 0, 0, 0, 7, 0, 9, 0], "y": [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]}
@@ -210,3 +210,26 @@ def test_transpose_duplicate_names_24907() -> None:
         pl.exceptions.DuplicateError, match="name 'x' has more than one"
     ):
         df.transpose(column_names=["x", "x", "x"])
+
+
+def test_transpose_dtype() -> None:
+    df = pl.DataFrame(
+        {"a": [1.5, 2.5], "b": [10.0, 20.0]}, schema={"a": pl.Float32, "b": pl.Float32}
+    )
+
+    # A uniform dtype is preserved.
+    assert df.transpose().dtypes == [pl.Float32, pl.Float32]
+
+    result = df.transpose(include_header=True, dtype=pl.Float64)
+    expected = pl.DataFrame(
+        {"column": ["a", "b"], "column_0": [1.5, 10.0], "column_1": [2.5, 20.0]}
+    )
+    assert_frame_equal(result, expected)
+
+    df = pl.DataFrame({"a": [1, 2], "b": ["3", "x"]})
+    assert_frame_equal(
+        df.head(1).transpose(dtype=pl.Int8),
+        pl.DataFrame({"column_0": [1, 3]}, schema={"column_0": pl.Int8}),
+    )
+    with pytest.raises(InvalidOperationError):
+        df.transpose(dtype=pl.Int8)
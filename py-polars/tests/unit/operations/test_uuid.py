@@ -0,0 +1,103 @@
+from __future__ import annotations
+
+import uuid
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_uuid4() -> None:
+    df = pl.DataFrame({"a": range(100)})
+    out = df.with_columns(pl.uuid4())
+
+    assert out.schema == {"a": pl.Int64, "uuid": pl.String}
+    assert out["uuid"].n_unique() == 100
+    for value in out["uuid"]:
+        parsed = uuid.UUID(value)
+        assert parsed.version == 4
+        assert str(parsed) == value
+
+    assert df.lazy().with_columns(pl.uuid4()).collect_schema() == out.schema
+
+
+def test_uuid4_seed() -> None:
+    df = pl.DataFrame({"a": range(10)})
+    assert_frame_equal(
+        df.with_columns(pl.uuid4(seed=1)), df.with_columns(pl.uuid4(seed=1))
+    )
+    assert not df.with_columns(pl.uuid4(seed=1)).equals(
+        df.with_columns(pl.uuid4(seed=2))
+    )
+
+    # pl.set_random_seed should lead to reproducible results.
+    pl.set_random_seed(1)
+    result1 = df.select(pl.uuid4()).to_series()
+    pl.set_random_seed(1)
+    result2 = df.select(pl.uuid4()).to_series()
+    assert_series_equal(result1, result2)
+
+
+def test_uuid4_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 2, 2]})
+    out = df.group_by("g", maintain_order=True).agg(pl.uuid4())
+
+    assert out["uuid"].list.len().to_list() == [2, 3]
+    assert out["uuid"].explode().n_unique() == 5
+
+    # Every row gets its own UUID, also if a seed is given.
+    out = df.group_by("g", maintain_order=True).agg(pl.uuid4(seed=0))
+    assert out["uuid"].list.len().to_list() == [2, 3]
+    assert out["uuid"].explode().n_unique() == 5
+    assert_series_equal(
+        out["uuid"].explode(),
+        df.select(pl.uuid4(seed=0)).to_series().alias("uuid"),
+        check_order=False,
+    )
+
+    out = df.select(pl.uuid4(seed=0).over("g"))
+    assert out["uuid"].n_unique() == 5
+
+
+def test_parse_uuid_roundtrip() -> None:
+    values = [str(uuid.UUID(int=i * 0x1234_5678_9ABC_DEF0)) for i in range(5)]
+    s = pl.Series("id", [*values, None])
+
+    parsed = s.str.parse_uuid()
+    assert parsed.dtype == pl.Binary
+    assert parsed.to_list() == [uuid.UUID(v).bytes for v in values] + [None]
+    assert_series_equal(parsed.bin.to_uuid_str(), s)
+
+
+@pytest.mark.parametrize(
+    "value",
+    [
+        "67E55044-10B1-426F-9247-BB680E5FE0C8",
+        "67e5504410b1426f9247bb680e5fe0c8",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ],
+)
+def test_parse_uuid_forms(value: str) -> None:
+    out = pl.Series([value]).str.parse_uuid().bin.to_uuid_str()
+    assert out.item() == "67e55044-10b1-426f-9247-bb680e5fe0c8"
+
+
+def test_parse_uuid_invalid() -> None:
+    s = pl.Series("id", ["67e55044-10b1-426f-9247-bb680e5fe0c8", "not a uuid"])
+
+    with pytest.raises(ComputeError, match="invalid UUID"):
+        s.str.parse_uuid()
+    assert s.str.parse_uuid(strict=False).to_list() == [
+        uuid.UUID("67e55044-10b1-426f-9247-bb680e5fe0c8").bytes,
+        None,
+    ]
+
+
+def test_to_uuid_str_invalid_length() -> None:
+    s = pl.Series("id", [b"\x01\x02\x03"])
+
+    with pytest.raises(InvalidOperationError, match="16 bytes"):
+        s.bin.to_uuid_str()
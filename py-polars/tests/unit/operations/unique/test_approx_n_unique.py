@@ -1,8 +1,16 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
 import pytest
 
 import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing.asserts.frame import assert_frame_equal
 
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
 
 def test_df_approx_n_unique_deprecated() -> None:
     df = pl.DataFrame({"a": [1, 2, 2], "b": [2, 2, 2]})
@@ -18,3 +26,83 @@ def test_lf_approx_n_unique_deprecated() -> None:
         result = df.approx_n_unique()
     expected = pl.LazyFrame({"a": [2], "b": [1]}).cast(pl.get_index_type())
     assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("precision", [4, 10, 18])
+def test_approx_n_unique_precision(precision: int) -> None:
+    s = pl.Series("a", range(10_000))
+    margin = 6 * 1.04 / (2**precision) ** 0.5
+    result = s.approx_n_unique(precision)
+    assert result == pytest.approx(10_000, rel=margin)
+
+    lf = s.to_frame().lazy().select(pl.col("a").approx_n_unique(precision))
+    assert lf.collect(engine="in-memory").item() == result
+    assert lf.collect(engine="streaming").item() == result
+
+
+def test_approx_n_unique_precision_invalid() -> None:
+    with pytest.raises(InvalidOperationError, match="`precision` must be between"):
+        pl.select(pl.lit(1).approx_n_unique(20))
+    with pytest.raises(InvalidOperationError, match="`precision` must be between"):
+        pl.select(pl.lit(1).hll_sketch(3))
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_hll_sketch_merge(engine: EngineType) -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2, 2, 3],
+            "x": ["a", "b", "b", "c", None, "c"],
+        }
+    )
+    sketches = (
+        df.lazy()
+        .group_by("g")
+        .agg(pl.col("x").hll_sketch(12))
+        .sort("g")
+        .collect(engine=engine)
+    )
+    assert sketches.schema == {"g": pl.Int64, "x": pl.Binary}
+    assert sketches["x"].hll_estimate().to_list() == [2, 3, 1]
+
+    # Merging the sketches of the groups gives the sketch of all values.
+    assert sketches["x"].hll_merge() == df["x"].hll_sketch(12)
+    assert pl.Series([sketches["x"].hll_merge()]).hll_estimate().item() == 4
+
+
+def test_hll_sketch_cross_dataset() -> None:
+    s1 = pl.Series("id", range(1000))
+    s2 = pl.Series("id", range(500, 1500))
+    sketches = pl.Series([s1.hll_sketch(), None, s2.hll_sketch()])
+    merged = sketches.hll_merge()
+    assert merged is not None
+    assert pl.Series([merged]).hll_estimate().item() == pytest.approx(1500, rel=0.05)
+
+    assert pl.Series([None], dtype=pl.Binary).hll_merge() is None
+
+    with pytest.raises(ComputeError, match="different precisions"):
+        pl.Series([s1.hll_sketch(10), s1.hll_sketch(12)]).hll_merge()
+    with pytest.raises(ComputeError, match="invalid HyperLogLog sketch"):
+        pl.Series([b"not a sketch"]).hll_estimate()
+
+
+def test_hll_sketch_stable_hash() -> None:
+    # Categoricals are hashed by their string, not by their physical ids.
+    a = pl.Series(["x", "y"], dtype=pl.Categorical(pl.Categories.random()))
+    dtype = pl.Categorical(pl.Categories.random())
+    pl.Series(["z"], dtype=dtype)
+    b = pl.Series(["x", "y"], dtype=dtype)
+    assert a.hll_sketch() == b.hll_sketch() == a.cast(pl.String).hll_sketch()
+    assert (
+        a.to_frame().lazy().select(pl.all().hll_sketch()).collect(engine="streaming")
+    ).item() == a.hll_sketch()
+
+    # Integers are hashed by value, regardless of their width.
+    assert pl.Series([1, 2], dtype=pl.Int8).hll_sketch() == (
+        pl.Series([1, 2], dtype=pl.UInt64).hll_sketch()
+    )
+
+    sketch = pl.Series([1]).hll_sketch(4)
+    assert sketch[:5] == b"HLL\x01\x04"
+    with pytest.raises(ComputeError, match="unsupported HyperLogLog sketch version"):
+        pl.Series([b"HLL\x02" + sketch[4:]]).hll_estimate()
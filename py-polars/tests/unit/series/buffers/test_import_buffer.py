@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import array
+import mmap
+from pathlib import Path
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_series_equal
+
+
+@pytest.mark.parametrize(
+    ("typecode", "dtype"),
+    [
+        ("b", pl.Int8),
+        ("H", pl.UInt16),
+        ("i", pl.Int32),
+        ("q", pl.Int64),
+        ("f", pl.Float32),
+        ("d", pl.Float64),
+    ],
+)
+def test_import_buffer_array(typecode: str, dtype: pl.DataType) -> None:
+    values = [1, 2, 3, 4]
+    buf = array.array(typecode, values)
+
+    result = pl.Series._import_buffer(buf, dtype)
+    assert_series_equal(result, pl.Series(values, dtype=dtype))
+
+
+def test_import_buffer_bytes() -> None:
+    buf = (1).to_bytes(4, "little") + (2).to_bytes(4, "little")
+    result = pl.Series._import_buffer(buf, pl.UInt32)
+    assert result.to_list() == [1, 2]
+
+    assert pl.Series._import_buffer(b"", pl.Int64).to_list() == []
+
+
+def test_import_buffer_zero_copy() -> None:
+    buf = bytearray(8)
+    s = pl.Series._import_buffer(buf, pl.Int64)
+
+    # the buffer stays exported while the Series references it
+    with pytest.raises(BufferError):
+        buf.extend(b"\x00")
+
+    del s
+    buf.extend(b"\x00")
+
+
+def test_import_buffer_mmap(tmp_path: Path) -> None:
+    path = tmp_path / "data.bin"
+    path.write_bytes(array.array("d", [0.5, 1.5, 2.5]).tobytes())
+
+    with path.open("rb") as f:
+        mm = mmap.mmap(f.fileno(), 0, access=mmap.ACCESS_READ)
+        s = pl.Series._import_buffer(mm, pl.Float64)
+        assert s.to_list() == [0.5, 1.5, 2.5]
+
+        # the mapping can't be closed while the Series references it
+        with pytest.raises(BufferError):
+            mm.close()
+        del s
+        mm.close()
+
+
+def test_import_buffer_errors() -> None:
+    with pytest.raises(TypeError, match="requires a numeric data type"):
+        pl.Series._import_buffer(b"abc", pl.String)
+
+    with pytest.raises(ValueError, match="not a multiple of the item size"):
+        pl.Series._import_buffer(b"abc", pl.Int16)
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import math
+from concurrent.futures import ThreadPoolExecutor
 from datetime import date, datetime, time, timedelta
 from decimal import Decimal
 from typing import TYPE_CHECKING, Any, cast
@@ -667,6 +668,32 @@ def test_pycapsule_interface() -> None:
     assert out_arr == pa.array([1, 2, 3, None])
 
 
+def test_pycapsule_array_interface() -> None:
+    a = pl.Series("a", [1, 2, 3, None])
+    assert pa.array(a) == pa.array([1, 2, 3, None])
+
+    # Multiple chunks are concatenated, without rechunking the Series itself.
+    b = pl.concat([a, pl.Series("a", [4])], rechunk=False)
+    assert pa.array(b) == pa.array([1, 2, 3, None, 4])
+    assert b.n_chunks() == 2
+
+
+def test_to_arrow_chunks() -> None:
+    s = pl.concat(
+        [pl.Series("a", [1, 2, None]), pl.Series("a", [4]), pl.Series("a", [5, 6])],
+        rechunk=False,
+    )
+    chunks = s.to_arrow_chunks()
+    assert s.n_chunks() == 3
+    assert chunks == [pa.array([1, 2, None]), pa.array([4]), pa.array([5, 6])]
+
+    strings = pl.Series("s", ["x", None]).to_arrow_chunks()
+    assert strings == [pa.array(["x", None], pa.large_string())]
+    assert pl.Series("s", ["x", None]).to_arrow_chunks(
+        compat_level=pl.CompatLevel.newest()
+    ) == [pa.array(["x", None], pa.string_view())]
+
+
 def test_get() -> None:
     a = pl.Series("a", [1, 2, 3])
     pos_idxs = pl.Series("idxs", [2, 0, 1, 0], dtype=pl.Int8)
@@ -929,6 +956,33 @@ def test_iter() -> None:
     assert sum(s) == 6
 
 
+def test_iter_buffered() -> None:
+    s = pl.concat(
+        [pl.Series("a", [1, None, 3]), pl.Series("a", [4, 5])], rechunk=False
+    )
+    assert list(s._s.iter_py(2)) == [1, None, 3, 4, 5]
+    assert list(s.clear()._s.iter_py(2)) == []
+
+    s = pl.Series("s", [{"a": 1}, None, {"a": 3}])
+    assert list(s._s.iter_py(1)) == [{"a": 1}, None, {"a": 3}]
+
+    with pytest.raises(ValueError, match="'buffer_size' must be positive"):
+        s._s.iter_py(0)
+
+
+def test_iter_buffered_threads() -> None:
+    # Converting values may release the GIL, which must not deadlock with other
+    # threads that consume the same iterator.
+    s = pl.Series("a", [[i] for i in range(10_000)])
+    it = s._s.iter_py(7)
+
+    with ThreadPoolExecutor(max_workers=4) as pool:
+        results = pool.map(lambda _: list(it), range(4))
+        values = [v for result in results for v in result]
+
+    assert sorted(values) == s.to_list()
+
+
 def test_empty() -> None:
     a = pl.Series(dtype=pl.Int8)
     assert a.dtype == pl.Int8
@@ -2458,3 +2512,21 @@ def test_multiply_int_series_by_timedelta_26205() -> None:
         [timedelta(seconds=5), timedelta(seconds=10), timedelta(seconds=15)]
     )
     assert_series_equal(expected, result)
+
+
+def test_series_metadata() -> None:
+    s = pl.Series("a", [1, 2, 3]).with_metadata({"unit": "m/s"})
+    assert s.metadata == {"unit": "m/s"}
+    assert s.rename("b").slice(1).metadata == {"unit": "m/s"}
+    assert s.with_metadata(None).metadata == {}
+
+    # Metadata doesn't take part in equality.
+    assert_series_equal(s, pl.Series("a", [1, 2, 3]))
+
+    # Selecting a column keeps its metadata, computing new values drops it.
+    df = pl.DataFrame([s])
+    assert df.select(pl.col("a").alias("b"))["b"].metadata == {"unit": "m/s"}
+    assert df.with_columns(pl.col("a") * 2)["a"].metadata == {}
+    assert df.with_columns(pl.col("a").abs())["a"].metadata == {}
+    df = df.with_columns(df["a"].with_metadata({"source": "x"}))
+    assert df["a"].metadata == {"source": "x"}
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from datetime import date, datetime, timedelta
+from zoneinfo import ZoneInfo
 
 import pytest
 
@@ -145,12 +146,27 @@ def test_fixed_intervals() -> None:
 
         assert_frame_equal(expected, out)
 
-        # TODO: negative intervals
-        with pytest.raises(
-            SQLInterfaceError,
-            match="minus signs are not yet supported in interval strings; found '-7d'",
-        ):
-            ctx.execute("SELECT INTERVAL '-7d' AS one_week_ago FROM df")
+        # negative intervals (each part can have its own sign)
+        out = ctx.execute(
+            """
+            SELECT
+              INTERVAL '-7d' AS i1,
+              INTERVAL '1 day -2 hours' AS i2,
+              INTERVAL '-1 hour +30 minutes' AS i3,
+              INTERVAL '3' DAY AS i4
+            FROM df
+            """
+        )
+        expected = pl.DataFrame(
+            {
+                "i1": [timedelta(days=-7)],
+                "i2": [timedelta(hours=22)],
+                "i3": [timedelta(minutes=-30)],
+                "i4": [timedelta(days=3)],
+            },
+        ).cast(pl.Duration("ns"))
+
+        assert_frame_equal(expected, out)
 
         with pytest.raises(
             SQLSyntaxError,
@@ -210,6 +226,44 @@ def test_interval_offsets() -> None:
     }
 
 
+def test_interval_offsets_signed_and_tz_aware() -> None:
+    df = pl.DataFrame(
+        {
+            "dtm": [
+                datetime(2024, 1, 31, 12),
+                datetime(2024, 3, 30, 12),
+            ],
+        }
+    ).with_columns(pl.col("dtm").dt.replace_time_zone("Europe/Amsterdam"))
+
+    out = df.sql(
+        """
+        SELECT
+            dtm + INTERVAL '1 month -3 days' AS mixed,
+            INTERVAL '1 day' + dtm AS lhs_interval,
+            dtm - INTERVAL '-2 hours' AS minus_negative
+        FROM self
+        """
+    )
+    tz = ZoneInfo("Europe/Amsterdam")
+    assert out.to_dict(as_series=False) == {
+        # months are applied first (clamped to month-end), then days
+        "mixed": [
+            datetime(2024, 2, 26, 12, tzinfo=tz),
+            datetime(2024, 4, 27, 12, tzinfo=tz),
+        ],
+        # calendar days are DST-aware (the clocks change on 2024-03-31)
+        "lhs_interval": [
+            datetime(2024, 2, 1, 12, tzinfo=tz),
+            datetime(2024, 3, 31, 12, tzinfo=tz),
+        ],
+        "minus_negative": [
+            datetime(2024, 1, 31, 14, tzinfo=tz),
+            datetime(2024, 3, 30, 14, tzinfo=tz),
+        ],
+    }
+
+
 @pytest.mark.parametrize(
     ("interval_comparison", "expected_result"),
     [
@@ -224,6 +278,10 @@ def test_interval_offsets() -> None:
         ("INTERVAL '8 weeks' = INTERVAL '2 months'", False),
         ("INTERVAL '1 year' != INTERVAL '365 days'", True),
         ("INTERVAL '1 year' = INTERVAL '1 year'", True),
+        ("INTERVAL '1 month' > INTERVAL '27 days'", True),
+        ("INTERVAL '1 month' < INTERVAL '32 days'", True),
+        ("INTERVAL '1 month -3 days' > INTERVAL '24 days'", True),
+        ("INTERVAL '-1 month' < INTERVAL '-27 days'", True),
     ],
 )
 def test_interval_comparisons(interval_comparison: str, expected_result: bool) -> None:
@@ -232,6 +290,14 @@ def test_interval_comparisons(interval_comparison: str, expected_result: bool) -
         assert res.collect().to_dict(as_series=False) == {"res": [expected_result]}
 
 
+def test_interval_comparison_depends_on_month_length() -> None:
+    with (
+        pl.SQLContext() as ctx,
+        pytest.raises(SQLInterfaceError, match="length of their months varies"),
+    ):
+        ctx.execute("SELECT INTERVAL '1 month' < INTERVAL '30 days' AS res")
+
+
 def test_select_literals_no_table() -> None:
     res = pl.sql("SELECT 1 AS one, '2' AS two, 3.0 AS three", eager=True)
     assert res.to_dict(as_series=False) == {
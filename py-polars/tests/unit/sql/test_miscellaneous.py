@@ -7,7 +7,12 @@ from typing import TYPE_CHECKING, Any
 import pytest
 
 import polars as pl
-from polars.exceptions import ColumnNotFoundError, SQLInterfaceError, SQLSyntaxError
+from polars.exceptions import (
+    ColumnNotFoundError,
+    InvalidOperationError,
+    SQLInterfaceError,
+    SQLSyntaxError,
+)
 from polars.testing import assert_frame_equal
 from tests.unit.utils.pycapsule_utils import PyCapsuleStreamHolder
 
@@ -624,3 +629,118 @@ def test_unsupported_select_clauses(query: str) -> None:
         ),
     ):
         ctx.execute(query)
+
+
+@pytest.mark.parametrize(
+    ("query", "params"),
+    [
+        ("SELECT * FROM df WHERE dt >= $1 AND name <> $2", [date(2020, 1, 1), "b"]),
+        ("SELECT * FROM df WHERE dt >= ? AND name <> ?", [date(2020, 1, 1), "b"]),
+        (
+            "SELECT * FROM df WHERE dt >= :start AND name <> $excluded",
+            {"start": date(2020, 1, 1), "excluded": "b"},
+        ),
+    ],
+)
+def test_sql_bind_params(query: str, params: Any) -> None:
+    df = pl.DataFrame(
+        {
+            "name": ["a", "b", "c"],
+            "dt": [date(2019, 5, 1), date(2020, 6, 1), date(2021, 7, 1)],
+        }
+    )
+    with pl.SQLContext(df=df) as ctx:
+        res = ctx.execute(query, params=params, eager=True)
+    assert_frame_equal(res, df.slice(2))
+
+
+def test_sql_bind_params_not_interpolated() -> None:
+    df = pl.DataFrame({"name": ["a", "b"]})
+    with pl.SQLContext(df=df) as ctx:
+        res = ctx.execute(
+            "SELECT * FROM df WHERE name = $1",
+            params=["a' OR name <> 'a"],
+            eager=True,
+        )
+        assert res.is_empty()
+
+        for params in (None, [], {"other": 1}):
+            with pytest.raises(
+                SQLInterfaceError, match=r"no value bound to placeholder '\$1'"
+            ):
+                ctx.execute("SELECT * FROM df WHERE name = $1", params=params)
+
+
+def test_sql_register_function() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [10.0, 20.0, 30.0], "c": ["x", "y", "z"]})
+
+    def scale_add(x: pl.Expr, y: pl.Expr) -> pl.Expr:
+        return x * 2 + y
+
+    with pl.SQLContext(df=df) as ctx:
+        ctx.register_function("scale_add", scale_add, arg_types=[pl.Int64, None])
+        res = ctx.execute(
+            "SELECT a, SCALE_ADD(a, b) AS s, Scale_Add(a, 1) AS t FROM df",
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {
+            "a": [1, 2, 3],
+            "s": [12.0, 24.0, 36.0],
+            "t": [3, 5, 7],
+        }
+
+        with pytest.raises(SQLSyntaxError, match="expects 2 arguments"):
+            ctx.execute("SELECT scale_add(a) FROM df")
+
+        with pytest.raises(SQLInterfaceError, match="invalid type for argument 1"):
+            ctx.execute("SELECT scale_add(b, a) FROM df")
+
+        with pytest.raises(SQLInterfaceError, match="built-in SQL function"):
+            ctx.register_function("upper", scale_add)
+
+        # without `arg_types`, any number of arguments is accepted
+        ctx.register_function("concat_all", lambda *e: pl.concat_str(e))
+        res = ctx.execute("SELECT CONCAT_ALL(c, c, c) AS cc FROM df", eager=True)
+        assert res["cc"].to_list() == ["xxx", "yyy", "zzz"]
+
+        ctx.unregister_function("scale_add")
+        with pytest.raises(SQLInterfaceError):
+            ctx.execute("SELECT scale_add(a, b) FROM df")
+
+
+def test_sql_dialects() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+
+    with pl.SQLContext(df=df, dialect="mysql") as ctx:
+        res = ctx.execute(
+            """
+            SELECT `a` / 2 AS half, `a` DIV 2 AS idiv, UCASE(b) AS b
+            FROM df WHERE b = "x" || a = 3
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {
+            "half": [0.5, 1.5],
+            "idiv": [0, 1],
+            "b": ["X", "Z"],
+        }
+
+    with pl.SQLContext(df=df, dialect="bigquery") as ctx:
+        res = ctx.execute(
+            'SELECT a FROM df WHERE REGEXP_CONTAINS(b, "[xz]")', eager=True
+        )
+        assert res["a"].to_list() == [1, 3]
+
+    # generic/postgres dialects keep standard semantics
+    for dialect in ("generic", "postgresql"):
+        with pl.SQLContext(df=df, dialect=dialect) as ctx:  # type: ignore[arg-type]
+            res = ctx.execute(
+                """SELECT a / 2 AS half, b || 'x' AS "b" FROM df""", eager=True
+            )
+            assert res.to_dict(as_series=False) == {
+                "half": [0, 1, 1],
+                "b": ["xx", "yx", "zx"],
+            }
+
+    with pytest.raises(InvalidOperationError, match="unsupported SQL dialect"):
+        pl.SQLContext(dialect="oracle")  # type: ignore[arg-type]
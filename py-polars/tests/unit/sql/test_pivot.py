@@ -0,0 +1,150 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def df_sales() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "region": ["north", "north", "south", "south", "north", "east"],
+            "quarter": ["Q1", "Q2", "Q1", "Q1", "Q1", "Q3"],
+            "amount": [10, 20, 30, 40, 50, 60],
+        }
+    )
+
+
+def test_pivot(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT * FROM self
+        PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2', 'Q4'))
+        ORDER BY region
+        """
+    )
+    assert_frame_equal(
+        res,
+        pl.DataFrame(
+            {
+                "region": ["east", "north", "south"],
+                "Q1": [None, 60, 70],
+                "Q2": [None, 20, None],
+                "Q4": [None, None, None],
+            },
+            schema_overrides={"Q4": pl.Int64},
+        ),
+    )
+
+
+def test_pivot_multiple_aggregates(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT * FROM self
+        PIVOT (
+          SUM(amount) AS total, COUNT(*) AS n
+          FOR quarter IN ('Q1' AS first, 'Q2' AS second)
+        )
+        ORDER BY region
+        """
+    )
+    assert res.columns == [
+        "region",
+        "first_total",
+        "first_n",
+        "second_total",
+        "second_n",
+    ]
+    assert res.rows() == [
+        ("east", None, None, None, None),
+        ("north", 60, 2, 20, 1),
+        ("south", 70, 2, None, None),
+    ]
+
+
+def test_pivot_without_group_keys(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT * FROM (SELECT quarter, amount FROM self)
+        PIVOT (MAX(amount) FOR quarter IN ('Q1', 'Q3'))
+        """
+    )
+    assert res.to_dict(as_series=False) == {"Q1": [50], "Q3": [60]}
+
+
+def test_pivot_default_on_null(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT * FROM self
+        PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2') DEFAULT ON NULL (0))
+        ORDER BY region
+        """
+    )
+    assert res.rows() == [("east", 0, 0), ("north", 60, 20), ("south", 70, 0)]
+
+
+def test_pivot_errors(df_sales: pl.DataFrame) -> None:
+    with pytest.raises(SQLInterfaceError, match="explicit list of values"):
+        df_sales.sql("SELECT * FROM self PIVOT (SUM(amount) FOR quarter IN (ANY))")
+
+
+def test_unpivot() -> None:
+    df = pl.DataFrame(
+        {
+            "id": [1, 2],
+            "jan": [10, None],
+            "feb": [30, 40],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT * FROM self
+        UNPIVOT (sales FOR month IN (jan, feb))
+        ORDER BY id, month
+        """
+    )
+    assert_frame_equal(
+        res,
+        pl.DataFrame(
+            {
+                "id": [1, 1, 2],
+                "month": ["feb", "jan", "feb"],
+                "sales": [30, 10, 40],
+            }
+        ),
+    )
+
+    res = df.sql(
+        """
+        SELECT * FROM self
+        UNPIVOT INCLUDE NULLS (sales FOR month IN (jan AS january, feb))
+        ORDER BY id, month
+        """
+    )
+    assert res.rows() == [
+        (1, "feb", 30),
+        (1, "january", 10),
+        (2, "feb", 40),
+        (2, "january", None),
+    ]
+
+
+def test_pivot_unpivot_roundtrip(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT * FROM (
+          SELECT * FROM self
+          PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2'))
+        ) AS p
+        UNPIVOT (amount FOR quarter IN (Q1, Q2))
+        ORDER BY region, quarter
+        """
+    )
+    assert res.rows() == [
+        ("north", "Q1", 60),
+        ("north", "Q2", 20),
+        ("south", "Q1", 70),
+    ]
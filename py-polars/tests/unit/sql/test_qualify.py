@@ -286,6 +286,37 @@ def test_qualify_with_where_clause(df_test: pl.DataFrame) -> None:
     )
 
 
+@pytest.mark.parametrize("order_by", ["", "ORDER BY id"])
+def test_qualify_unselected_columns(df_test: pl.DataFrame, order_by: str) -> None:
+    # QUALIFY can reference input columns that are not in the SELECT list
+    assert_sql_matches(
+        {"df": df_test},
+        query=f"""
+            SELECT id
+            FROM df
+            QUALIFY ROW_NUMBER() OVER (PARTITION BY category ORDER BY value DESC) = 1
+            {order_by}
+        """,
+        compare_with="duckdb",
+        expected={"id": [2, 6]},
+        check_row_order=False,
+    )
+
+
+def test_qualify_distinct_unselected_columns(df_test: pl.DataFrame) -> None:
+    assert_sql_matches(
+        {"df": df_test},
+        query="""
+            SELECT DISTINCT category
+            FROM df
+            QUALIFY value > AVG(value) OVER (PARTITION BY category)
+            ORDER BY category
+        """,
+        compare_with="duckdb",
+        expected={"category": ["A", "B"]},
+    )
+
+
 def test_qualify_expected_errors(df_test: pl.DataFrame) -> None:
     ctx = pl.SQLContext(df=df_test, eager=True)
     with pytest.raises(
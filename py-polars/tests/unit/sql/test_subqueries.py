@@ -285,3 +285,78 @@ def test_derived_table_alias_errors() -> None:
             query="SELECT a FROM (SELECT a, b FROM df) ORDER BY sq.a",
             eager=True,
         )
+
+
+def test_correlated_subqueries() -> None:
+    customers = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4],
+            "name": ["ann", "bob", "cal", "dee"],
+        }
+    )
+    orders = pl.DataFrame(
+        {
+            "customer_id": [1, 1, 2, 3, 3, 3],
+            "amount": [10, 30, 5, 20, 40, 60],
+            "kind": ["a", "b", "a", "b", "b", "a"],
+        }
+    )
+    with pl.SQLContext(customers=customers, orders=orders) as ctx:
+        # EXISTS / NOT EXISTS
+        res = ctx.execute(
+            """
+            SELECT name FROM customers c
+            WHERE EXISTS (
+              SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.amount > 25
+            )
+            ORDER BY name
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {"name": ["ann", "cal"]}
+
+        res = ctx.execute(
+            """
+            SELECT name FROM customers c
+            WHERE NOT EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id)
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {"name": ["dee"]}
+
+        # correlated IN
+        res = ctx.execute(
+            """
+            SELECT name FROM customers c
+            WHERE 'b' IN (SELECT kind FROM orders o WHERE o.customer_id = c.id)
+              AND id > 1
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {"name": ["cal"]}
+
+        # correlated scalar subqueries
+        res = ctx.execute(
+            """
+            SELECT customer_id, amount FROM orders o1
+            WHERE amount > (
+              SELECT AVG(amount) FROM orders o2 WHERE o2.customer_id = o1.customer_id
+            )
+            ORDER BY customer_id, amount
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {
+            "customer_id": [1, 3],
+            "amount": [30, 60],
+        }
+
+        res = ctx.execute(
+            """
+            SELECT name FROM customers c
+            WHERE (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id) < 2
+            ORDER BY name
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {"name": ["bob", "dee"]}
@@ -1,13 +1,14 @@
 from __future__ import annotations
 
+import json
 import re
 from datetime import date
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError
+from polars.exceptions import InvalidOperationError, SQLInterfaceError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -64,6 +65,108 @@ def test_create_table_from_file_io(io_files_path: Path) -> None:
         assert df.shape == (135, 4)
 
 
+@pytest.mark.parametrize(
+    ("file_format", "scan_fn"),
+    [
+        ("USING PARQUET", pl.scan_parquet),
+        ("USING csv", pl.scan_csv),
+        ("USING JSON", pl.scan_ndjson),
+        ("STORED AS PARQUET", pl.scan_parquet),
+        ("STORED AS JSONFILE", pl.scan_ndjson),
+        ("STORED AS TEXTFILE", pl.scan_csv),
+    ],
+)
+def test_create_table_using(
+    file_format: str,
+    scan_fn: Any,
+    test_frame: pl.LazyFrame,
+    tmp_path: Path,
+) -> None:
+    path = tmp_path / "out.data"
+    with pl.SQLContext(frame_data=test_frame) as ctx:
+        res = ctx.execute(
+            f"""
+            CREATE TABLE IF NOT EXISTS tbl {file_format} LOCATION '{path}'
+            AS SELECT x, y FROM frame_data WHERE x > 1
+            """
+        )
+        # the write is deferred until the returned frame is collected
+        assert not path.exists()
+        res.collect()
+
+        expected = pl.DataFrame({"x": [2, 3], "y": ["bbb", "ccc"]})
+        assert_frame_equal(scan_fn(path).collect(), expected, check_dtypes=False)
+        assert_frame_equal(
+            ctx.execute("SELECT * FROM tbl", eager=True),
+            expected,
+            check_dtypes=False,
+        )
+
+
+def test_create_table_using_errors(test_frame: pl.LazyFrame, tmp_path: Path) -> None:
+    with pl.SQLContext(frame_data=test_frame) as ctx:
+        with pytest.raises(SQLInterfaceError, match="requires a LOCATION"):
+            ctx.execute("CREATE TABLE tbl USING PARQUET AS SELECT * FROM frame_data")
+        with pytest.raises(SQLInterfaceError, match="requires an AS <query>"):
+            ctx.execute(
+                f"""
+                CREATE TABLE tbl (x INT) STORED AS TEXTFILE
+                LOCATION '{tmp_path / "out.csv"}'
+                """
+            )
+        with pytest.raises(SQLInterfaceError, match="requires an AS <query>"):
+            ctx.execute(f"CREATE TABLE tbl USING CSV LOCATION '{tmp_path / 'x.csv'}'")
+        for file_format in ("USING DELTA", "STORED AS ORC"):
+            with pytest.raises(SQLInterfaceError, match="does not support writing"):
+                ctx.execute(
+                    f"""
+                    CREATE TABLE tbl {file_format} LOCATION '{tmp_path / "out"}'
+                    AS SELECT * FROM frame_data
+                    """
+                )
+
+
+def test_create_table_using_if_not_exists(
+    test_frame: pl.LazyFrame, tmp_path: Path
+) -> None:
+    path = tmp_path / "out.parquet"
+    with pl.SQLContext(frame_data=test_frame) as ctx:
+        query = f"""
+            CREATE TABLE IF NOT EXISTS tbl USING PARQUET LOCATION '{path}'
+            AS SELECT x FROM frame_data
+        """
+        ctx.execute(query).collect()
+        assert_frame_equal(pl.read_parquet(path), test_frame.select("x").collect())
+
+        with pytest.raises(SQLInterfaceError, match="relation 'tbl' already exists"):
+            ctx.execute(query)
+
+
+def test_create_table_using_multiple_statements(
+    test_frame: pl.LazyFrame, tmp_path: Path
+) -> None:
+    path = tmp_path / "out.parquet"
+    with pl.SQLContext(frame_data=test_frame) as ctx:
+        with pytest.raises(SQLInterfaceError, match=r"one \(and only one\) statement"):
+            ctx.execute(
+                f"""
+                SELECT * FROM frame_data;
+                CREATE TABLE tbl USING PARQUET LOCATION '{path}'
+                AS SELECT * FROM frame_data
+                """
+            )
+
+    # the table identifiers are also found through a `USING` statement
+    frame_data = test_frame  # noqa: F841
+    pl.sql(
+        f"""
+        CREATE TABLE tbl USING PARQUET LOCATION '{path}'
+        AS SELECT x, y FROM frame_data;
+        """
+    ).collect()
+    assert_frame_equal(pl.read_parquet(path), test_frame.select("x", "y").collect())
+
+
 @pytest.mark.parametrize(
     ("delete_constraint", "expected_ids"),
     [
@@ -131,6 +234,87 @@ def test_explain_query(test_frame: pl.LazyFrame) -> None:
         )
 
 
+def test_explain_json_and_analyze(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        for query in (
+            "EXPLAIN FORMAT JSON SELECT x FROM frame WHERE x > 2",
+            "EXPLAIN (FORMAT JSON) SELECT x FROM frame WHERE x > 2",
+        ):
+            plan = json.loads(ctx.execute(query).item())
+            assert set(plan) == {"node", "expressions", "inputs"}
+
+        res = ctx.execute("EXPLAIN ANALYZE SELECT x FROM frame WHERE x > 2")
+        assert res.columns == ["node", "start", "end", "duration"]
+        assert res.height > 0
+        assert_frame_equal(
+            ctx.execute("EXPLAIN (ANALYZE) SELECT x FROM frame").select("node").head(1),
+            pl.DataFrame({"node": ["optimization"]}),
+        )
+
+        with pytest.raises(SQLInterfaceError, match="does not support the 'GRAPHVIZ'"):
+            ctx.execute("EXPLAIN FORMAT GRAPHVIZ SELECT * FROM frame")
+
+        with pytest.raises(SQLInterfaceError, match="does not support the FORMAT"):
+            ctx.execute("EXPLAIN (ANALYZE, FORMAT JSON) SELECT * FROM frame")
+
+
+def test_insert_into(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute("INSERT INTO frame SELECT * FROM frame WHERE x > 2")
+        assert_frame_equal(res, pl.DataFrame({"Response": ["INSERT INTO frame"]}))
+
+        # values are coerced to the target schema; omitted columns are null
+        ctx.execute("INSERT INTO frame (y, x) VALUES ('ddd', 4), ('eee', 5)")
+        ctx.execute("INSERT INTO frame SELECT 6 AS a, 'fff' AS b, DATE '2020-01-01'")
+
+        res = ctx.execute("SELECT * FROM frame")
+        expected = pl.DataFrame(
+            {
+                "x": [1, 2, 3, 3, 4, 5, 6],
+                "y": ["aaa", "bbb", "ccc", "ccc", "ddd", "eee", "fff"],
+                "z": [
+                    date(2000, 12, 31),
+                    date(1978, 11, 15),
+                    date(2077, 10, 20),
+                    date(2077, 10, 20),
+                    None,
+                    None,
+                    date(2020, 1, 1),
+                ],
+            },
+            schema_overrides={"x": pl.UInt8},
+        )
+        assert_frame_equal(res, expected)
+
+        ctx.execute("INSERT OVERWRITE frame SELECT * FROM frame WHERE x = 1")
+        assert_frame_equal(ctx.execute("SELECT * FROM frame"), expected.head(1))
+
+
+@pytest.mark.parametrize(
+    ("insert_sql", "error"),
+    [
+        ("INSERT INTO missing VALUES (1)", "table 'missing' does not exist"),
+        ("INSERT INTO frame VALUES (1, 'a')", "expects 3 column"),
+        ("INSERT INTO frame (x, x) VALUES (1, 2)", "specified more than once"),
+    ],
+)
+def test_insert_into_errors(
+    insert_sql: str, error: str, test_frame: pl.LazyFrame
+) -> None:
+    with (
+        pl.SQLContext(frame=test_frame) as ctx,
+        pytest.raises(SQLInterfaceError, match=error),
+    ):
+        ctx.execute(insert_sql)
+
+
+def test_insert_into_invalid_values(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame) as ctx:
+        ctx.execute("INSERT INTO frame (x) VALUES (1000)")
+        with pytest.raises(InvalidOperationError):
+            ctx.execute("SELECT * FROM frame", eager=True)
+
+
 def test_show_tables(test_frame: pl.LazyFrame) -> None:
     # 'show tables' lists all tables registered with the sql context in sorted order
     with pl.SQLContext(
@@ -0,0 +1,70 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLSyntaxError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "id": range(100),
+            "value": [float(n) * 1.5 for n in range(100)],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    ("sample", "expected_height"),
+    [
+        ("TABLESAMPLE BERNOULLI (10 PERCENT)", 10),
+        ("TABLESAMPLE BERNOULLI (25)", 25),
+        ("TABLESAMPLE SYSTEM (7 ROWS)", 7),
+        ("TABLESAMPLE (0 PERCENT)", 0),
+        ("TABLESAMPLE BERNOULLI (100 PERCENT)", 100),
+        ("TABLESAMPLE SYSTEM (500 ROWS)", 100),
+    ],
+)
+def test_tablesample(df: pl.DataFrame, sample: str, expected_height: int) -> None:
+    res = df.sql(f"SELECT * FROM self {sample}")
+    assert res.height == expected_height
+
+    # sampled rows are kept intact, and are unique
+    assert res["id"].n_unique() == expected_height
+    assert_frame_equal(res.sort("id"), df.filter(pl.col("id").is_in(res["id"])))
+
+
+def test_tablesample_seed(df: pl.DataFrame) -> None:
+    with pl.SQLContext(tbl=df) as ctx:
+        query = "SELECT id FROM tbl TABLESAMPLE BERNOULLI (20 PERCENT) REPEATABLE (42)"
+        res1 = ctx.execute(query, eager=True)
+        res2 = ctx.execute(query, eager=True)
+        assert res1.height == 20
+        assert_frame_equal(res1, res2)
+
+        # sample applies before WHERE and aggregation
+        res = ctx.execute(
+            """
+            SELECT COUNT(*) AS n FROM tbl AS t TABLESAMPLE SYSTEM (30 ROWS)
+            WHERE t.id >= 0
+            """,
+            eager=True,
+        )
+        assert res.item() == 30
+
+
+@pytest.mark.parametrize(
+    ("sample", "match"),
+    [
+        ("TABLESAMPLE BERNOULLI (150 PERCENT)", "between 0 and 100"),
+        ("TABLESAMPLE SYSTEM (2.5 ROWS)", "must be an integer"),
+        ("TABLESAMPLE BERNOULLI (-1)", "non-negative number"),
+        ("TABLESAMPLE BERNOULLI (id)", "non-negative number"),
+    ],
+)
+def test_tablesample_errors(df: pl.DataFrame, sample: str, match: str) -> None:
+    with pytest.raises(SQLSyntaxError, match=match):
+        df.sql(f"SELECT * FROM self {sample}")
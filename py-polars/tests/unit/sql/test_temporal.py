@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta, timezone
 from typing import Any, Literal
 
 import pytest
@@ -104,11 +104,17 @@ def test_datetime_to_time(time_unit: Literal["ns", "us", "ms"]) -> None:
             pl.Float64,
             [3123456000.0, 45987654000.0, 59555555000.0],
         ),
+        (
+            ["date"],
+            pl.Date,
+            [date(2024, 1, 7), date(2020, 12, 30), date(2006, 1, 1)],
+        ),
         (
             ["time"],
             pl.Time,
             [time(1, 2, 3, 123456), time(10, 30, 45, 987654), time(23, 59, 59, 555555)],
         ),
+        (["julian"], pl.Int32, [2460317, 2459214, 2453737]),
         (
             ["epoch"],
             pl.Float64,
@@ -158,6 +164,128 @@ def test_extract_errors() -> None:
             ctx.execute("SELECT DATE_PART('week(tuesday)', dt) FROM frame_data")
 
 
+def test_extract_timezone_parts() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [
+                datetime(2024, 1, 7, 12),
+                datetime(2024, 7, 7, 12),
+            ],
+        }
+    )
+    with pl.SQLContext(eager=True) as ctx:
+        for tz, expected_hour, expected_minute in (
+            ("Asia/Kolkata", [5, 5], [30, 30]),
+            ("America/St_Johns", [-3, -3], [-30, -30]),
+        ):
+            ctx.register(
+                "frame_data",
+                df.with_columns(pl.col("dt").dt.replace_time_zone(tz)),
+            )
+            res = ctx.execute(
+                """
+                SELECT
+                  EXTRACT(timezone_hour FROM dt) AS tz_hour,
+                  DATE_PART('timezone_minute', dt) AS tz_minute
+                FROM frame_data
+                """
+            )
+            assert res.to_dict(as_series=False) == {
+                "tz_hour": expected_hour,
+                "tz_minute": expected_minute,
+            }
+
+
+@pytest.mark.parametrize(
+    ("part", "expected"),
+    [
+        ("millennium", [datetime(2000, 1, 1), datetime(1000, 1, 1)]),
+        ("century", [datetime(2000, 1, 1), datetime(1900, 1, 1)]),
+        ("decade", [datetime(2020, 1, 1), datetime(1990, 1, 1)]),
+        ("year", [datetime(2024, 1, 1), datetime(1999, 1, 1)]),
+        ("quarter", [datetime(2024, 7, 1), datetime(1999, 10, 1)]),
+        ("month", [datetime(2024, 8, 1), datetime(1999, 12, 1)]),
+        ("week", [datetime(2024, 8, 19), datetime(1999, 12, 27)]),
+        ("day", [datetime(2024, 8, 22), datetime(1999, 12, 31)]),
+        ("hour", [datetime(2024, 8, 22, 10), datetime(1999, 12, 31, 23)]),
+        ("minute", [datetime(2024, 8, 22, 10, 23), datetime(1999, 12, 31, 23, 59)]),
+        (
+            "second",
+            [datetime(2024, 8, 22, 10, 23, 45), datetime(1999, 12, 31, 23, 59, 59)],
+        ),
+    ],
+)
+def test_date_trunc(part: str, expected: list[datetime]) -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [
+                datetime(2024, 8, 22, 10, 23, 45, 123456),
+                datetime(1999, 12, 31, 23, 59, 59, 999999),
+            ],
+        }
+    )
+    res = df.sql(f"SELECT DATE_TRUNC('{part}', dt) AS dt FROM self")
+    assert res["dt"].to_list() == expected
+
+
+def test_date_trunc_tz_aware() -> None:
+    df = pl.DataFrame({"dt": [datetime(2024, 3, 31, 12, 30)]}).with_columns(
+        pl.col("dt").dt.replace_time_zone("Europe/Amsterdam")
+    )
+    res = df.sql(
+        "SELECT DATE_TRUNC('day', dt) AS d, DATE_TRUNC('month', dt) AS m FROM self"
+    )
+    expected = pl.DataFrame(
+        {"d": [datetime(2024, 3, 31)], "m": [datetime(2024, 3, 1)]}
+    ).with_columns(pl.all().dt.replace_time_zone("Europe/Amsterdam"))
+    assert_frame_equal(res, expected)
+
+    with pytest.raises(SQLSyntaxError, match="DATE_TRUNC does not support 'fortnight'"):
+        df.sql("SELECT DATE_TRUNC('fortnight', dt) FROM self")
+
+
+def test_age() -> None:
+    df = pl.DataFrame(
+        {
+            "start": [
+                datetime(2020, 1, 1, 10),
+                datetime(2024, 6, 30, 23),
+                datetime(1957, 6, 13),
+                datetime(2001, 4, 10),
+            ],
+            "end": [
+                datetime(2020, 1, 3, 12),
+                datetime(2024, 7, 1, 0, 15),
+                datetime(2001, 4, 10),
+                datetime(1957, 6, 13),
+            ],
+        }
+    )
+    res = df.sql("SELECT AGE(end, start) AS age FROM self")
+    assert res.schema["age"] == pl.Struct(
+        {"months": pl.Int32, "days": pl.Int32, "time": pl.Duration("us")}
+    )
+    assert res["age"].to_list() == [
+        {"months": 0, "days": 2, "time": timedelta(hours=2)},
+        {"months": 0, "days": 0, "time": timedelta(hours=1, minutes=15)},
+        # 43 years, 9 months and 27 days, as in PostgreSQL
+        {"months": 525, "days": 27, "time": timedelta(0)},
+        {"months": -525, "days": -27, "time": timedelta(0)},
+    ]
+
+    # "today" is determined when the query is executed
+    lf = pl.sql("SELECT AGE(DATE '2000-01-01') AS age")
+    today = datetime.now(tz=timezone.utc).date()
+    assert lf.collect()["age"].item() == {
+        "months": (today.year - 2000) * 12 + today.month - 1,
+        "days": today.day - 1,
+        "time": timedelta(0),
+    }
+
+    with pytest.raises(SQLSyntaxError, match="AGE expects 1-2 arguments"):
+        df.sql("SELECT AGE(end, start, end) FROM self")
+
+
 @pytest.mark.parametrize(
     ("dt", "expected"),
     [
@@ -170,3 +170,74 @@ def test_unnest_select_height_filter_order_by() -> None:
         ),
         pl.Series("list", [4, 5, 6, 1, 2, 3]).to_frame(),
     )
+
+
+@pytest.mark.parametrize(
+    "from_clause",
+    [
+        "tbl, UNNEST(tbl.items) AS item",
+        "tbl, LATERAL UNNEST(tbl.items) AS item",
+        "tbl CROSS JOIN LATERAL UNNEST(items) AS item",
+    ],
+)
+def test_unnest_lateral(from_clause: str) -> None:
+    tbl = pl.DataFrame({"id": [1, 2], "items": [[100, 200], [300, 400, 500]]})
+    assert_sql_matches(
+        frames={"tbl": tbl},
+        query=f"""
+            SELECT id, item
+            FROM {from_clause}
+            ORDER BY id DESC, item ASC
+        """,
+        compare_with="duckdb",
+        expected={
+            "id": [2, 2, 2, 1, 1],
+            "item": [300, 400, 500, 100, 200],
+        },
+    )
+
+
+def test_unnest_lateral_struct_elements() -> None:
+    tbl = pl.DataFrame(
+        {
+            "id": [1, 2],
+            "items": [
+                [{"name": "x", "qty": 5}],
+                [{"name": "y", "qty": 1}, {"name": "z", "qty": 3}],
+            ],
+        }
+    )
+    res = tbl.sql(
+        """
+        SELECT id, item.name AS name, item.qty * 10 AS qty
+        FROM self, UNNEST(self.items) AS item
+        WHERE item.qty > 1
+        ORDER BY name
+        """
+    )
+    assert_frame_equal(
+        res,
+        pl.DataFrame({"id": [1, 2], "name": ["x", "z"], "qty": [50, 30]}),
+    )
+
+
+def test_unnest_lateral_errors() -> None:
+    tbl = pl.DataFrame({"id": [1, 2], "items": [[1, 2], [3]]})
+    with pl.SQLContext(tbl=tbl, other=tbl) as ctx:
+        with pytest.raises(
+            SQLInterfaceError,
+            match="multiple tables in FROM clause are not currently supported",
+        ):
+            ctx.execute("SELECT * FROM tbl, other")
+
+        with pytest.raises(
+            SQLInterfaceError,
+            match="LATERAL table function 'read_csv' is not supported",
+        ):
+            ctx.execute("SELECT * FROM tbl, LATERAL read_csv(tbl.id) AS x")
+
+        with pytest.raises(
+            SQLInterfaceError,
+            match="LATERAL subqueries are not supported",
+        ):
+            ctx.execute("SELECT * FROM tbl, LATERAL (SELECT tbl.id) AS x")
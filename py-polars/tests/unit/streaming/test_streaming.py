@@ -412,3 +412,28 @@ def test_streaming_boolean_multiply_dtype_24609() -> None:
     assert (
         lf.collect(engine="streaming").schema == lf.collect(engine="in-memory").schema
     )
+
+
+def test_streaming_metrics_callback() -> None:
+    metrics: list[pl.QueryMetrics] = []
+    pl.set_streaming_metrics_callback(metrics.append)
+    try:
+        result = (
+            pl.LazyFrame({"a": [1, 2, 3, 4]})
+            .filter(pl.col("a") > 1)
+            .collect(engine="streaming")
+        )
+    finally:
+        pl.set_streaming_metrics_callback(None)
+
+    assert result.height == 3
+    assert len(metrics) == 1
+    assert metrics[0].elapsed_ns > 0
+    operators = metrics[0].operators
+    assert len(operators) > 0
+    assert all(op.name for op in operators)
+    assert sum(op.rows_sent for op in operators) > 0
+
+    # Metrics are no longer reported once the callback is unset.
+    pl.LazyFrame({"a": [1]}).collect(engine="streaming")
+    assert len(metrics) == 1
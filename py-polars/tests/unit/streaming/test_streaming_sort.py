@@ -13,6 +13,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from tests.conftest import PlMonkeyPatch
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -183,3 +185,65 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("descending", [False, True])
+def test_sort_spill_to_disk(
+    descending: bool,
+    tmp_path: Path,
+    plmonkeypatch: PlMonkeyPatch,
+) -> None:
+    np.random.seed(0)
+    df = pl.DataFrame(
+        {
+            "a": np.random.permutation(10_000),
+            "b": [None if i % 7 == 0 else str(i) for i in range(10_000)],
+        }
+    )
+    expected_a = df.sort("a", descending=descending)
+    expected_ba = df.sort(["b", "a"], descending=descending, nulls_last=True)
+    expected_expr = df.sort(
+        pl.col("a") % 10, descending=descending, maintain_order=True
+    )
+
+    plmonkeypatch.setenv("POLARS_OOC_SORT_MEMORY_BUDGET", "1")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_DIR", str(tmp_path))
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+
+    result = df.lazy().sort("a", descending=descending).collect(engine="streaming")
+    assert_frame_equal(result, expected_a)
+
+    result = (
+        df.lazy()
+        .sort(["b", "a"], descending=descending, nulls_last=True)
+        .collect(engine="streaming")
+    )
+    assert_frame_equal(result, expected_ba)
+
+    result = (
+        df.lazy()
+        .sort(pl.col("a") % 10, descending=descending, maintain_order=True)
+        .collect(engine="streaming")
+    )
+    assert_frame_equal(result, expected_expr)
+
+    # The spilled runs are removed afterwards.
+    assert list(tmp_path.iterdir()) == []
+
+
+@pytest.mark.write_disk
+def test_sort_spill_dir_unwritable(
+    tmp_path: Path, plmonkeypatch: PlMonkeyPatch
+) -> None:
+    df = pl.DataFrame({"a": np.random.permutation(1_000)})
+
+    # A directory can't be created below a file, so the sort stays in memory.
+    spill_file = tmp_path / "file"
+    spill_file.touch()
+    plmonkeypatch.setenv("POLARS_OOC_SORT_MEMORY_BUDGET", "1")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_DIR", str(spill_file / "spill"))
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+
+    result = df.lazy().sort("a").collect(engine="streaming")
+    assert_frame_equal(result, df.sort("a"))
@@ -177,6 +177,23 @@ def test_estimated_size_sliced_list_25068() -> None:
     assert df.slice(5000).estimated_size() / df.estimated_size() <= 0.5
 
 
+def test_estimated_size_deep() -> None:
+    s = pl.Series("s", [f"a long string value {i}" for i in range(1000)])
+
+    # A slice of strings keeps referencing the full data buffers.
+    sliced = s.slice(0, 10)
+    assert sliced.estimated_size(deep=True) > 10 * sliced.estimated_size()
+
+    # Storage shared between columns is only counted once.
+    df = pl.DataFrame([s, s.alias("t")])
+    assert df.estimated_size() == 2 * s.estimated_size()
+    assert df.estimated_size(deep=True) == s.estimated_size(deep=True)
+
+    # The categorical mapping is counted as well.
+    cat = pl.Series(["a", "b"], dtype=pl.Categorical)
+    assert cat.estimated_size(deep=True) > cat.estimated_size()
+
+
 @pytest.mark.parametrize(
     ("v1", "v2"),
     [